@@ -1,6 +1,9 @@
 //! A/B comparison tools for audio signals
 
 use crate::fft::{Fft, Window};
+use crate::spectrogram::StftAnalyzer;
+use crate::spectrum::welch_psd;
+use crate::xcorr::{peak_lag, xcorr_fft};
 
 /// Compute spectral correlation between two signals
 ///
@@ -121,6 +124,216 @@ pub fn snr_db(reference: &[f32], test: &[f32]) -> f32 {
     }
 }
 
+/// Time and polarity offset of a test signal relative to a reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    /// Samples by which the test signal lags the reference (negative = leads).
+    pub lag: i32,
+    /// Whether the test signal is polarity-inverted relative to the reference.
+    pub inverted: bool,
+}
+
+/// Estimate the lag and polarity of `test` relative to `reference`.
+///
+/// Cross-correlates (FFT) the first `window` samples of each signal and takes
+/// the lag of maximum absolute correlation within ±`max_lag`. A negative
+/// peak means the test signal is polarity-inverted.
+pub fn align(reference: &[f32], test: &[f32], max_lag: usize, window: usize) -> Alignment {
+    let reference = &reference[..window.min(reference.len())];
+    let test = &test[..window.min(test.len())];
+    if reference.is_empty() || test.is_empty() {
+        return Alignment {
+            lag: 0,
+            inverted: false,
+        };
+    }
+    let correlation = xcorr_fft(reference, test, max_lag);
+    let (lag, value) = peak_lag(&correlation, max_lag);
+    Alignment {
+        lag,
+        inverted: value < 0.0,
+    }
+}
+
+/// Null-test difference `reference[n] − test[n + lag]` over the overlap.
+///
+/// The test signal is shifted by `alignment.lag` and polarity-corrected
+/// before subtracting, so identical material cancels to silence. The output
+/// starts where both signals overlap.
+pub fn null_difference(reference: &[f32], test: &[f32], alignment: Alignment) -> Vec<f32> {
+    let (reference, test) = if alignment.lag >= 0 {
+        (reference, &test[(alignment.lag as usize).min(test.len())..])
+    } else {
+        (
+            &reference[(alignment.lag.unsigned_abs() as usize).min(reference.len())..],
+            test,
+        )
+    };
+    let sign = if alignment.inverted { -1.0 } else { 1.0 };
+    reference
+        .iter()
+        .zip(test)
+        .map(|(r, t)| r - sign * t)
+        .collect()
+}
+
+/// Level floor of a [`DifferenceSpectrogram`], in dB below the loudest cell
+/// of either signal.
+///
+/// Cells quieter than this in both signals compare as equal, so noise floor
+/// and dither don't dominate the plot.
+pub const DIFFERENCE_FLOOR_DB: f32 = -90.0;
+
+/// Time-frequency level difference between two time-aligned signals.
+#[derive(Debug, Clone)]
+pub struct DifferenceSpectrogram {
+    /// Frame start times in seconds, on the reference's timeline.
+    pub times: Vec<f32>,
+    /// Bin frequencies in Hz.
+    pub frequencies: Vec<f32>,
+    /// Test minus reference level in dB, `[frame][bin]`.
+    pub difference_db: Vec<Vec<f32>>,
+}
+
+impl DifferenceSpectrogram {
+    /// Cell with the largest absolute difference, as
+    /// `(time_s, frequency_hz, difference_db)`.
+    pub fn peak(&self) -> Option<(f32, f32, f32)> {
+        let mut peak: Option<(f32, f32, f32)> = None;
+        for (frame, row) in self.difference_db.iter().enumerate() {
+            for (bin, &db) in row.iter().enumerate() {
+                if peak.is_none_or(|(_, _, p)| db.abs() > p.abs()) {
+                    peak = Some((self.times[frame], self.frequencies[bin], db));
+                }
+            }
+        }
+        peak
+    }
+
+    /// Mean absolute difference over all cells, in dB.
+    pub fn mean_abs_db(&self) -> f32 {
+        let cells = self.difference_db.iter().map(Vec::len).sum::<usize>();
+        if cells == 0 {
+            return 0.0;
+        }
+        let total: f32 = self.difference_db.iter().flatten().map(|d| d.abs()).sum();
+        total / cells as f32
+    }
+}
+
+/// Per-cell level difference between the STFTs of `test` and `reference`.
+///
+/// `test` is shifted by `alignment.lag` first, as in [`null_difference`];
+/// polarity does not affect magnitudes. Both signals are analyzed over
+/// their overlap with a Hann window and 50% overlap, and each cell holds
+/// `20·log10(|T| / |R|)`, with magnitudes clamped to
+/// [`DIFFERENCE_FLOOR_DB`] below the loudest cell of either signal.
+/// Positive values mean the test signal is louder there.
+pub fn difference_spectrogram(
+    reference: &[f32],
+    test: &[f32],
+    alignment: Alignment,
+    sample_rate: f32,
+    fft_size: usize,
+) -> DifferenceSpectrogram {
+    let (reference, test, offset) = if alignment.lag >= 0 {
+        (
+            reference,
+            &test[(alignment.lag as usize).min(test.len())..],
+            0,
+        )
+    } else {
+        let skip = (alignment.lag.unsigned_abs() as usize).min(reference.len());
+        (&reference[skip..], test, skip)
+    };
+    let len = reference.len().min(test.len());
+
+    let analyzer = StftAnalyzer::default_for_sample_rate(sample_rate, fft_size);
+    let reference = analyzer.analyze(&reference[..len]);
+    let test = analyzer.analyze(&test[..len]);
+
+    let loudest = reference
+        .data
+        .iter()
+        .chain(&test.data)
+        .flatten()
+        .fold(0.0f32, |max, &m| max.max(m));
+    let floor = (loudest * 10.0f32.powf(DIFFERENCE_FLOOR_DB / 20.0)).max(1e-20);
+
+    let difference_db = reference
+        .data
+        .iter()
+        .zip(&test.data)
+        .map(|(r, t)| {
+            r.iter()
+                .zip(t)
+                .map(|(&r, &t)| 20.0 * (t.max(floor) / r.max(floor)).log10())
+                .collect()
+        })
+        .collect();
+
+    DifferenceSpectrogram {
+        times: (0..reference.num_frames)
+            .map(|frame| reference.frame_to_time(frame) + offset as f32 / sample_rate)
+            .collect(),
+        frequencies: (0..reference.num_bins)
+            .map(|bin| reference.bin_to_freq(bin))
+            .collect(),
+        difference_db,
+    }
+}
+
+/// Mean absolute difference in dB between the 1/3-octave band levels of two
+/// signals.
+///
+/// Each signal's PSD is estimated with Welch's method (`fft_size` segments,
+/// 50% overlap, Hann window) and averaged in power within 1/3-octave bands
+/// from 20 Hz to 20 kHz (or Nyquist). Band-level comparison ignores fine
+/// harmonic structure, so it tracks tonal balance (EQ curve, distortion
+/// brightness) rather than exact waveform match. Returns 0 for identical
+/// spectra.
+pub fn band_distance_db(
+    signal_a: &[f32],
+    signal_b: &[f32],
+    sample_rate: f32,
+    fft_size: usize,
+) -> f32 {
+    let (freqs, psd_a) = welch_psd(signal_a, sample_rate, fft_size, 0.5, Window::Hann);
+    let (_, psd_b) = welch_psd(signal_b, sample_rate, fft_size, 0.5, Window::Hann);
+
+    let top = 20_000.0f32.min(sample_rate * 0.5);
+    let mut total = 0.0;
+    let mut bands = 0;
+    let mut center = 20.0f32;
+    while center <= top {
+        let (lo, hi) = (
+            center * 2f32.powf(-1.0 / 6.0),
+            center * 2f32.powf(1.0 / 6.0),
+        );
+        let band_level = |psd: &[f32]| {
+            let (sum, count) = freqs
+                .iter()
+                .zip(psd)
+                .filter(|(f, _)| **f >= lo && **f < hi)
+                .fold((0.0f64, 0), |(sum, count), (_, db)| {
+                    (sum + 10f64.powf(*db as f64 / 10.0), count + 1)
+                });
+            (count > 0).then(|| 10.0 * (sum / count as f64).max(1e-20).log10())
+        };
+        if let (Some(a), Some(b)) = (band_level(&psd_a), band_level(&psd_b)) {
+            total += (a - b).abs();
+            bands += 1;
+        }
+        center *= 2f32.powf(1.0 / 3.0);
+    }
+
+    if bands == 0 {
+        0.0
+    } else {
+        (total / bands as f64) as f32
+    }
+}
+
 /// Compare envelopes (amplitude over time)
 ///
 /// Returns correlation between amplitude envelopes
@@ -206,4 +419,93 @@ mod tests {
         let snr = snr_db(&reference, &test);
         assert!(snr > 100.0, "Identical signals should have very high SNR");
     }
+
+    #[test]
+    fn null_of_delayed_copy_cancels() {
+        let reference: Vec<f32> = (0..4096)
+            .map(|i| (i as f32 * 0.013).sin() * (i as f32 * 0.0007).cos())
+            .collect();
+        let mut test = vec![0.0; 37];
+        test.extend_from_slice(&reference);
+
+        let alignment = align(&reference, &test, 100, 2048);
+        assert_eq!(
+            alignment,
+            Alignment {
+                lag: 37,
+                inverted: false
+            }
+        );
+        let diff = null_difference(&reference, &test, alignment);
+        assert_eq!(diff.len(), reference.len());
+        assert!(diff.iter().all(|d| d.abs() < 1e-6));
+    }
+
+    #[test]
+    fn null_handles_leading_inverted_test() {
+        let reference: Vec<f32> = (0..4096).map(|i| (i as f32 * 0.021).sin()).collect();
+        let test: Vec<f32> = reference[12..].iter().map(|x| -x).collect();
+
+        let alignment = align(&reference, &test, 50, 4096);
+        assert_eq!(alignment.lag, -12);
+        assert!(alignment.inverted);
+        let diff = null_difference(&reference, &test, alignment);
+        assert!(diff.iter().all(|d| d.abs() < 1e-6));
+    }
+
+    #[test]
+    fn band_distance_tracks_gain() {
+        let noise: Vec<f32> = (0..48000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 8) as f32 / (1 << 24) as f32 - 0.5)
+            .collect();
+        let quieter: Vec<f32> = noise.iter().map(|x| x * 0.5).collect();
+
+        assert!(band_distance_db(&noise, &noise, 48000.0, 4096) < 1e-3);
+        let d = band_distance_db(&noise, &quieter, 48000.0, 4096);
+        assert!((d - 6.02).abs() < 0.05, "expected ~6 dB, got {d}");
+    }
+
+    #[test]
+    fn difference_spectrogram_locates_divergence() {
+        let sample_rate = 48000.0;
+        let noise: Vec<f32> = (0..48000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 8) as f32 / (1 << 24) as f32 - 0.5)
+            .collect();
+        // Test is the reference delayed by 100 samples, 6 dB louder in the
+        // second half.
+        let mut test = vec![0.0; 100];
+        test.extend(
+            noise
+                .iter()
+                .enumerate()
+                .map(|(i, x)| if i < 24000 { *x } else { x * 2.0 }),
+        );
+
+        let alignment = align(&noise, &test, 500, 48000);
+        assert_eq!(alignment.lag, 100);
+        let diff = difference_spectrogram(&noise, &test, alignment, sample_rate, 1024);
+        assert_eq!(diff.frequencies.len(), 513);
+
+        for (time, row) in diff.times.iter().zip(&diff.difference_db) {
+            let mean = row.iter().sum::<f32>() / row.len() as f32;
+            if *time + 1024.0 / sample_rate < 0.5 {
+                assert!(mean.abs() < 1e-3, "frame at {time} s: {mean} dB");
+            } else if *time > 0.5 {
+                assert!((mean - 6.02).abs() < 0.05, "frame at {time} s: {mean} dB");
+            }
+        }
+        let (time, _, db) = diff.peak().unwrap();
+        assert!(time > 0.45 && db > 0.0);
+        assert!(diff.mean_abs_db() > 2.0);
+    }
+
+    #[test]
+    fn difference_spectrogram_of_identical_signals_is_flat() {
+        let signal: Vec<f32> = (0..8192).map(|i| (i as f32 * 0.05).sin()).collect();
+        let inverted: Vec<f32> = signal.iter().map(|x| -x).collect();
+        let alignment = align(&signal, &inverted, 10, 8192);
+        let diff = difference_spectrogram(&signal, &inverted, alignment, 48000.0, 512);
+        assert!(!diff.times.is_empty());
+        assert!(diff.difference_db.iter().flatten().all(|d| d.abs() < 1e-3));
+    }
 }
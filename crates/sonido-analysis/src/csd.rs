@@ -0,0 +1,301 @@
+//! Cumulative spectral decay (waterfall) analysis of impulse responses
+//!
+//! A CSD shows how the spectrum of an impulse response dies away: slice `k`
+//! is the spectrum of the IR from `t_k = k × step` after the main peak to a
+//! fixed end point, so each slice holds only the energy still ringing at
+//! `t_k`. Broadband decay falls away evenly; resonances (cabinet modes,
+//! metallic reverb tails, room modes) show up as ridges that outlast their
+//! neighbours.
+//!
+//! Each slice starts with a short half-Hann rise window to suppress the
+//! truncation edge, and ends with a half-Hann fade at the fixed end point.
+//!
+//! ## Reference
+//!
+//! Bunton, J. D. & Small, R. H. (1982). "Cumulative Spectra, Tone Bursts, and
+//! Apodization." *Journal of the Audio Engineering Society* 30(6), 386–395.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use sonido_analysis::csd::CsdAnalyzer;
+//!
+//! let csd = CsdAnalyzer::new(48000.0, 0.05, 30, 0.001).analyze(&ir);
+//! for (t, slice) in csd.times.iter().zip(&csd.magnitude_db) {
+//!     println!("{:.1} ms: {:.1} dB at 1 kHz", t * 1000.0, slice[csd.bin_for(1000.0)]);
+//! }
+//! ```
+
+use crate::fft::Fft;
+use std::f32::consts::PI;
+
+/// Cumulative spectral decay result.
+#[derive(Debug, Clone)]
+pub struct Csd {
+    /// Bin center frequencies in Hz (DC to Nyquist).
+    pub frequencies: Vec<f32>,
+    /// Slice start times in seconds after the IR peak.
+    pub times: Vec<f32>,
+    /// Magnitude `[slice][bin]` in dB relative to the first slice's maximum.
+    pub magnitude_db: Vec<Vec<f32>>,
+}
+
+impl Csd {
+    /// Index of the bin closest to `freq_hz`.
+    pub fn bin_for(&self, freq_hz: f32) -> usize {
+        let bin_width = self.frequencies.get(1).copied().unwrap_or(1.0);
+        ((freq_hz / bin_width).round() as usize).min(self.frequencies.len().saturating_sub(1))
+    }
+
+    /// Time for each bin to fall `drop_db` below its first-slice level.
+    ///
+    /// `None` means the bin had not decayed that far by the last slice.
+    pub fn decay_times(&self, drop_db: f32) -> Vec<Option<f32>> {
+        let Some(first) = self.magnitude_db.first() else {
+            return Vec::new();
+        };
+        (0..first.len())
+            .map(|bin| {
+                let threshold = first[bin] - drop_db;
+                self.magnitude_db
+                    .iter()
+                    .zip(&self.times)
+                    .find(|(slice, _)| slice[bin] <= threshold)
+                    .map(|(_, &t)| t)
+            })
+            .collect()
+    }
+
+    /// The `count` loudest frequencies in `[min_freq, max_freq]` in the
+    /// slice nearest `time_secs`, i.e. what is still ringing by then.
+    ///
+    /// Returns `(frequency, level dB)` pairs, loudest first. Picks are kept
+    /// at least 1/3 octave apart so one broad ridge is reported once.
+    pub fn ringing_frequencies(
+        &self,
+        time_secs: f32,
+        min_freq: f32,
+        max_freq: f32,
+        count: usize,
+    ) -> Vec<(f32, f32)> {
+        let Some(slice) = self
+            .times
+            .iter()
+            .zip(&self.magnitude_db)
+            .min_by(|(a, _), (b, _)| (*a - time_secs).abs().total_cmp(&(*b - time_secs).abs()))
+            .map(|(_, slice)| slice)
+        else {
+            return Vec::new();
+        };
+
+        let mut candidates: Vec<(f32, f32)> = self
+            .frequencies
+            .iter()
+            .copied()
+            .zip(slice.iter().copied())
+            .filter(|(f, _)| (min_freq..=max_freq).contains(f) && *f > 0.0)
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let third_octave = 2f32.powf(1.0 / 3.0);
+        let mut picks: Vec<(f32, f32)> = Vec::with_capacity(count);
+        for (freq, level) in candidates {
+            if picks.len() == count {
+                break;
+            }
+            let distinct = picks
+                .iter()
+                .all(|(p, _)| (freq / p).max(p / freq) >= third_octave);
+            if distinct {
+                picks.push((freq, level));
+            }
+        }
+        picks
+    }
+}
+
+/// Computes [`Csd`] waterfalls from impulse responses.
+pub struct CsdAnalyzer {
+    sample_rate: f32,
+    window_secs: f32,
+    slices: usize,
+    step_secs: f32,
+    rise_secs: f32,
+    fft_size: Option<usize>,
+}
+
+impl CsdAnalyzer {
+    /// Create a new CSD analyzer
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `window_secs` - Analysis span after the IR peak (the fixed end point)
+    /// * `slices` - Number of slices
+    /// * `step_secs` - Time between slice starts
+    pub fn new(sample_rate: f32, window_secs: f32, slices: usize, step_secs: f32) -> Self {
+        Self {
+            sample_rate,
+            window_secs,
+            slices: slices.max(1),
+            step_secs,
+            rise_secs: 0.0005,
+            fft_size: None,
+        }
+    }
+
+    /// Set the rise window length at the start of each slice (default 0.5 ms)
+    pub fn with_rise_time(mut self, rise_secs: f32) -> Self {
+        self.rise_secs = rise_secs.max(0.0);
+        self
+    }
+
+    /// Set the FFT size (default: next power of two of the window, min 4096)
+    pub fn with_fft_size(mut self, fft_size: usize) -> Self {
+        self.fft_size = Some(fft_size);
+        self
+    }
+
+    /// Compute the waterfall of `ir`, starting at its absolute peak.
+    pub fn analyze(&self, ir: &[f32]) -> Csd {
+        let peak = ir
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+            .map_or(0, |(i, _)| i);
+
+        let window_len = ((self.window_secs * self.sample_rate) as usize).max(2);
+        let end = (peak + window_len).min(ir.len());
+        let fft_size = self
+            .fft_size
+            .unwrap_or_else(|| window_len.next_power_of_two().max(4096));
+        let fft = Fft::new(fft_size);
+        let num_bins = fft_size / 2 + 1;
+
+        let step = self.step_secs * self.sample_rate;
+        let rise_len = (self.rise_secs * self.sample_rate) as usize;
+        let fade_len = (window_len / 8).max(1);
+
+        let mut times = Vec::with_capacity(self.slices);
+        let mut magnitude_db = Vec::with_capacity(self.slices);
+        let mut reference_db = None;
+
+        for k in 0..self.slices {
+            let start = peak + (k as f32 * step) as usize;
+            if start >= end {
+                break;
+            }
+
+            let mut frame = vec![0.0f32; fft_size];
+            let len = (end - start).min(fft_size);
+            frame[..len].copy_from_slice(&ir[start..start + len]);
+            // The first slice keeps the IR's own onset; later slices get a
+            // rise window so the truncation edge does not splatter.
+            if k > 0 {
+                half_hann_in(&mut frame[..len], rise_len);
+            }
+            // Fade toward the fixed end point (shortened as slices shrink)
+            let remaining = end - start;
+            half_hann_out(&mut frame[..len], fade_len.min(remaining / 2).max(1));
+
+            let spectrum = fft.forward(&frame);
+            let db: Vec<f32> = spectrum
+                .iter()
+                .take(num_bins)
+                .map(|c| 20.0 * c.norm().max(1e-10).log10())
+                .collect();
+
+            let reference =
+                *reference_db.get_or_insert_with(|| db.iter().copied().fold(f32::MIN, f32::max));
+            magnitude_db.push(db.iter().map(|v| v - reference).collect());
+            times.push(start.saturating_sub(peak) as f32 / self.sample_rate);
+        }
+
+        let bin_width = self.sample_rate / fft_size as f32;
+        Csd {
+            frequencies: (0..num_bins).map(|i| i as f32 * bin_width).collect(),
+            times,
+            magnitude_db,
+        }
+    }
+}
+
+/// Multiply the first `len` samples by a rising half-Hann.
+fn half_hann_in(frame: &mut [f32], len: usize) {
+    let len = len.min(frame.len());
+    for (i, s) in frame.iter_mut().take(len).enumerate() {
+        *s *= 0.5 - 0.5 * (PI * i as f32 / len as f32).cos();
+    }
+}
+
+/// Multiply the last `len` samples by a falling half-Hann.
+fn half_hann_out(frame: &mut [f32], len: usize) {
+    let n = frame.len();
+    let len = len.min(n);
+    for i in 0..len {
+        frame[n - 1 - i] *= 0.5 - 0.5 * (PI * i as f32 / len as f32).cos();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An impulse plus a slowly decaying 1 kHz resonance.
+    fn ringing_ir(sample_rate: f32) -> Vec<f32> {
+        let mut ir: Vec<f32> = (0..4800)
+            .map(|n| {
+                let t = n as f32 / sample_rate;
+                0.3 * (2.0 * PI * 1000.0 * t).sin() * (-t / 0.03).exp()
+            })
+            .collect();
+        ir[0] = 1.0;
+        ir
+    }
+
+    #[test]
+    fn slices_follow_peak_and_step() {
+        let csd = CsdAnalyzer::new(48000.0, 0.05, 10, 0.002).analyze(&ringing_ir(48000.0));
+        assert_eq!(csd.times.len(), 10);
+        assert_eq!(csd.magnitude_db.len(), 10);
+        assert!((csd.times[3] - 0.006).abs() < 1e-4);
+        // First slice is normalized so its maximum is 0 dB
+        let max = csd.magnitude_db[0].iter().copied().fold(f32::MIN, f32::max);
+        assert!(max.abs() < 1e-4);
+    }
+
+    #[test]
+    fn resonance_outlasts_broadband_energy() {
+        let csd = CsdAnalyzer::new(48000.0, 0.08, 40, 0.002).analyze(&ringing_ir(48000.0));
+        let late = csd.magnitude_db.last().unwrap();
+        let resonance = late[csd.bin_for(1000.0)];
+        let neighbour = late[csd.bin_for(5000.0)];
+        assert!(
+            resonance > neighbour + 20.0,
+            "1 kHz {resonance:.1} dB vs 5 kHz {neighbour:.1} dB"
+        );
+
+        let decays = csd.decay_times(20.0);
+        assert!(decays[csd.bin_for(5000.0)].unwrap() < 0.005);
+        assert!(decays[csd.bin_for(1000.0)].is_none_or(|t| t > 0.02));
+    }
+
+    #[test]
+    fn ringing_frequencies_find_the_resonance() {
+        let csd = CsdAnalyzer::new(48000.0, 0.08, 40, 0.002).analyze(&ringing_ir(48000.0));
+        let picks = csd.ringing_frequencies(0.04, 100.0, 20_000.0, 3);
+        assert_eq!(picks.len(), 3);
+        assert!((picks[0].0 - 1000.0).abs() < 50.0, "{picks:?}");
+        assert!(picks[0].1 > picks[1].1 + 10.0, "{picks:?}");
+        for pair in picks.windows(2) {
+            let ratio = pair[1].0 / pair[0].0;
+            assert!(ratio.max(1.0 / ratio) >= 2f32.powf(1.0 / 3.0));
+        }
+    }
+
+    #[test]
+    fn short_ir_stops_early() {
+        let ir = vec![1.0, 0.5, 0.25, 0.125];
+        let csd = CsdAnalyzer::new(48000.0, 0.05, 10, 0.001).analyze(&ir);
+        assert_eq!(csd.times.len(), 1);
+    }
+}
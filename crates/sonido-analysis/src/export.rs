@@ -4,8 +4,10 @@
 //! - FRD format (frequency response data, compatible with REW)
 //! - CSV format for generic data exchange
 //! - PGM format for spectrogram images
+//! - PNG waterfall plots for cumulative spectral decay
+//! - CSV and PNG heatmaps of A/B difference spectrograms
 
-use crate::{Spectrogram, ThdResult, TransferFunction};
+use crate::{Csd, DifferenceSpectrogram, Spectrogram, ThdResult, TransferFunction};
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 
@@ -294,6 +296,348 @@ pub fn export_frd_extended(tf: &TransferFunction, path: impl AsRef<Path>) -> std
     Ok(())
 }
 
+/// Export a cumulative spectral decay to CSV.
+///
+/// Layout matches [`export_spectrogram_csv`]: a header row of bin
+/// frequencies, then one row per slice starting with its time in seconds.
+/// Values are in dB relative to the first slice's maximum.
+pub fn export_csd_csv(csd: &Csd, path: impl AsRef<Path>) -> std::io::Result<()> {
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+
+    write!(file, "time_s")?;
+    for freq in &csd.frequencies {
+        write!(file, ",{:.2}", freq)?;
+    }
+    writeln!(file)?;
+
+    for (time, slice) in csd.times.iter().zip(&csd.magnitude_db) {
+        write!(file, "{:.6}", time)?;
+        for db in slice {
+            write!(file, ",{:.3}", db)?;
+        }
+        writeln!(file)?;
+    }
+
+    file.flush()
+}
+
+/// Appearance of a [`export_csd_png`] waterfall plot.
+#[derive(Debug, Clone, Copy)]
+pub struct WaterfallStyle {
+    /// Image width in pixels.
+    pub width: usize,
+    /// Image height in pixels.
+    pub height: usize,
+    /// Vertical range in dB below the first slice's maximum.
+    pub db_range: f32,
+    /// Lowest plotted frequency in Hz (log axis).
+    pub min_freq: f32,
+    /// Highest plotted frequency in Hz.
+    pub max_freq: f32,
+}
+
+impl Default for WaterfallStyle {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 700,
+            db_range: 40.0,
+            min_freq: 20.0,
+            max_freq: 20_000.0,
+        }
+    }
+}
+
+/// Export a cumulative spectral decay as a PNG waterfall plot.
+///
+/// Frequency runs left to right on a log axis, level bottom to top, and
+/// later slices recede up and to the right. Slices are painted back to
+/// front with the area under each curve filled, so nearer slices hide the
+/// ones behind them. Faint gridlines mark each frequency decade and every
+/// 10 dB of the front slice.
+pub fn export_csd_png(
+    csd: &Csd,
+    path: impl AsRef<Path>,
+    style: &WaterfallStyle,
+) -> std::io::Result<()> {
+    const BACKGROUND: [u8; 3] = [250, 250, 250];
+    const GRID: [u8; 3] = [215, 215, 215];
+    const FRONT: [f32; 3] = [25.0, 60.0, 170.0];
+    const BACK: [f32; 3] = [170.0, 195.0, 235.0];
+
+    let (width, height) = (style.width.max(64), style.height.max(64));
+    let mut pixels = vec![BACKGROUND; width * height];
+    let margin = 16;
+    let slices = csd.magnitude_db.len();
+    // Each slice recedes by a fixed offset; the whole stack spans 25% of
+    // the width and 35% of the height.
+    let (depth_x, depth_y) = ((width - 2 * margin) / 4, (height - 2 * margin) * 35 / 100);
+    let plot_w = width - 2 * margin - depth_x;
+    let plot_h = height - 2 * margin - depth_y;
+    let base_y = height - margin - 1;
+
+    let log_min = style.min_freq.max(1.0).ln();
+    let log_span = (style.max_freq.max(style.min_freq * 2.0).ln() - log_min).max(1e-3);
+    let bin_width = csd.frequencies.get(1).copied().unwrap_or(1.0);
+    // Level of one pixel column: the loudest bin it spans, or a linear
+    // interpolation where a bin is wider than a column (low frequencies).
+    let column_db = |slice: &[f32], x: usize| -> Option<f32> {
+        let freq = |x: f32| (log_min + log_span * x / plot_w as f32).exp() / bin_width;
+        let (lo, hi) = (freq(x as f32), freq(x as f32 + 1.0));
+        if hi.floor() > lo.ceil() {
+            let bins = slice.get(lo.ceil() as usize..(hi.floor() as usize + 1).min(slice.len()))?;
+            return bins.iter().copied().reduce(f32::max);
+        }
+        let center = (lo + hi) * 0.5;
+        let i = center.floor() as usize;
+        let frac = center - i as f32;
+        Some(slice.get(i)? * (1.0 - frac) + slice.get(i + 1)? * frac)
+    };
+    let level_height =
+        |db: f32| ((db + style.db_range) / style.db_range).clamp(0.0, 1.0) * plot_h as f32;
+
+    // Grid on the front plane
+    for decade in [10.0f32, 100.0, 1000.0, 10_000.0] {
+        if decade > style.min_freq && decade < style.max_freq {
+            let x = margin + ((decade.ln() - log_min) / log_span * plot_w as f32) as usize;
+            for y in base_y - plot_h..=base_y {
+                pixels[y * width + x] = GRID;
+            }
+        }
+    }
+    for step in 0..=(style.db_range / 10.0) as usize {
+        let y = base_y - level_height(-(step as f32) * 10.0) as usize;
+        for x in margin..margin + plot_w {
+            pixels[y * width + x] = GRID;
+        }
+    }
+
+    for k in (0..slices).rev() {
+        let depth = if slices > 1 {
+            k as f32 / (slices - 1) as f32
+        } else {
+            0.0
+        };
+        let (dx, dy) = (
+            (depth * depth_x as f32) as usize,
+            (depth * depth_y as f32) as usize,
+        );
+        let line = [0, 1, 2].map(|c| (FRONT[c] + (BACK[c] - FRONT[c]) * depth) as u8);
+        // Fill is the line colour blended 1:3 toward the background
+        let fill = [0, 1, 2].map(|c| ((line[c] as u16 + 3 * BACKGROUND[c] as u16) / 4) as u8);
+        let slice = &csd.magnitude_db[k];
+        let slice_base = base_y - dy;
+
+        let mut previous: Option<usize> = None;
+        for x in 0..plot_w {
+            let Some(db) = column_db(slice, x) else {
+                previous = None;
+                continue;
+            };
+            let y = slice_base - level_height(db) as usize;
+            let px = margin + dx + x;
+            for yy in y..=slice_base {
+                pixels[yy * width + px] = fill;
+            }
+            // Join to the previous column so steep slopes stay continuous
+            let (top, bottom) = match previous {
+                Some(p) => (y.min(p), y.max(p)),
+                None => (y, y),
+            };
+            for yy in top..=bottom {
+                pixels[yy * width + px] = line;
+            }
+            pixels[slice_base * width + px] = line;
+            previous = Some(y);
+        }
+    }
+
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    write_png_rgb(&mut file, width, height, &pixels)?;
+    file.flush()
+}
+
+/// Export a difference spectrogram to CSV.
+///
+/// Same layout as [`export_spectrogram_csv`]: one row per frame, a
+/// `time_s` column, then one column per frequency bin holding the test
+/// minus reference level in dB.
+pub fn export_difference_csv(
+    diff: &DifferenceSpectrogram,
+    path: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+
+    write!(file, "time_s")?;
+    for freq in &diff.frequencies {
+        write!(file, ",{:.2}", freq)?;
+    }
+    writeln!(file)?;
+
+    for (time, row) in diff.times.iter().zip(&diff.difference_db) {
+        write!(file, "{:.6}", time)?;
+        for db in row {
+            write!(file, ",{:.3}", db)?;
+        }
+        writeln!(file)?;
+    }
+
+    file.flush()
+}
+
+/// Appearance of a [`export_difference_png`] heatmap.
+#[derive(Debug, Clone, Copy)]
+pub struct HeatmapStyle {
+    /// Image width in pixels.
+    pub width: usize,
+    /// Image height in pixels.
+    pub height: usize,
+    /// Difference in dB that maps to full colour (clamped beyond).
+    pub db_range: f32,
+    /// Lowest plotted frequency in Hz (log axis).
+    pub min_freq: f32,
+    /// Highest plotted frequency in Hz.
+    pub max_freq: f32,
+}
+
+impl Default for HeatmapStyle {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 600,
+            db_range: 12.0,
+            min_freq: 20.0,
+            max_freq: 20_000.0,
+        }
+    }
+}
+
+/// Export a difference spectrogram as a PNG heatmap.
+///
+/// Time runs left to right, frequency bottom to top on a log axis. The
+/// colour map diverges from white (no difference) to red where the test
+/// signal is louder and blue where it is quieter, saturating at
+/// ±`style.db_range`. Each pixel shows the largest-magnitude difference
+/// among the cells it covers, so narrow divergences survive downscaling.
+/// Faint gridlines mark each frequency decade.
+pub fn export_difference_png(
+    diff: &DifferenceSpectrogram,
+    path: impl AsRef<Path>,
+    style: &HeatmapStyle,
+) -> std::io::Result<()> {
+    const NEUTRAL: [f32; 3] = [255.0, 255.0, 255.0];
+    const LOUDER: [f32; 3] = [200.0, 30.0, 30.0];
+    const QUIETER: [f32; 3] = [30.0, 70.0, 200.0];
+    const GRID: [u8; 3] = [190, 190, 190];
+
+    let (width, height) = (style.width.max(16), style.height.max(16));
+    let mut pixels = vec![[255u8; 3]; width * height];
+    let frames = diff.difference_db.len();
+    let bin_width = diff.frequencies.get(1).copied().unwrap_or(1.0);
+    let log_min = style.min_freq.max(1.0).ln();
+    let log_span = (style.max_freq.max(style.min_freq * 2.0).ln() - log_min).max(1e-3);
+    let row_bin = |y: f32| (log_min + log_span * y / height as f32).exp() / bin_width;
+    let widest = |a: f32, b: f32| if b.abs() > a.abs() { b } else { a };
+
+    if frames > 0 {
+        for x in 0..width {
+            let first = x * frames / width;
+            let last = ((x + 1) * frames / width).max(first + 1).min(frames);
+            for y in 0..height {
+                // y counts up from the bottom of the image
+                let (lo, hi) = (row_bin(y as f32), row_bin(y as f32 + 1.0));
+                let bins = lo.round() as usize..(hi.round() as usize).max(lo.round() as usize + 1);
+                let db = diff.difference_db[first..last]
+                    .iter()
+                    .flat_map(|row| row.get(bins.clone()).unwrap_or(&[]))
+                    .copied()
+                    .fold(0.0, widest);
+                let t = (db.abs() / style.db_range.max(1e-3)).min(1.0);
+                let target = if db >= 0.0 { LOUDER } else { QUIETER };
+                pixels[(height - 1 - y) * width + x] =
+                    [0, 1, 2].map(|c| (NEUTRAL[c] + (target[c] - NEUTRAL[c]) * t) as u8);
+            }
+        }
+    }
+
+    for decade in [10.0f32, 100.0, 1000.0, 10_000.0] {
+        if decade > style.min_freq && decade < style.max_freq {
+            let y = ((decade.ln() - log_min) / log_span * height as f32) as usize;
+            let row = height - 1 - y.min(height - 1);
+            for x in (0..width).step_by(2) {
+                let pixel = &mut pixels[row * width + x];
+                if *pixel == [255; 3] {
+                    *pixel = GRID;
+                }
+            }
+        }
+    }
+
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    write_png_rgb(&mut file, width, height, &pixels)?;
+    file.flush()
+}
+
+/// Write an 8-bit RGB PNG using uncompressed (stored) deflate blocks.
+fn write_png_rgb(
+    out: &mut impl Write,
+    width: usize,
+    height: usize,
+    pixels: &[[u8; 3]],
+) -> std::io::Result<()> {
+    // Raw scanlines, each prefixed by filter type 0 (None)
+    let mut raw = Vec::with_capacity(height * (1 + width * 3));
+    for row in pixels.chunks(width) {
+        raw.push(0);
+        raw.extend(row.iter().flatten());
+    }
+
+    // zlib stream: header, stored blocks of at most 65535 bytes, Adler-32
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(65_535).peekable();
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        zlib.push(blocks.peek().is_none() as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in &raw {
+        a = (a + byte as u32) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    zlib.extend(((b << 16) | a).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]); // 8-bit depth, truecolor, no interlace
+
+    out.write_all(&[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'])?;
+    write_png_chunk(out, *b"IHDR", &header)?;
+    write_png_chunk(out, *b"IDAT", &zlib)?;
+    write_png_chunk(out, *b"IEND", &[])
+}
+
+fn write_png_chunk(out: &mut impl Write, kind: [u8; 4], data: &[u8]) -> std::io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(&kind)?;
+    out.write_all(data)?;
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in kind.iter().chain(data) {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    out.write_all(&(!crc).to_be_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,4 +756,81 @@ mod tests {
         assert!(content.contains("3 3"), "Should have width 3 height 3");
         assert!(content.contains("255"), "Should have max value 255");
     }
+
+    #[test]
+    fn test_csd_csv_and_png_export() {
+        let csd = Csd {
+            frequencies: vec![0.0, 1000.0, 2000.0, 3000.0],
+            times: vec![0.0, 0.001],
+            magnitude_db: vec![
+                vec![-3.0, 0.0, -6.0, -12.0],
+                vec![-20.0, -10.0, -30.0, -40.0],
+            ],
+        };
+        let dir = tempfile::tempdir().unwrap();
+
+        let csv = dir.path().join("csd.csv");
+        export_csd_csv(&csd, &csv).unwrap();
+        let content = std::fs::read_to_string(&csv).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("time_s,0.00,1000.00"));
+        assert!(lines[2].starts_with("0.001000,-20.000"));
+
+        let png = dir.path().join("csd.png");
+        let style = WaterfallStyle {
+            width: 200,
+            height: 120,
+            min_freq: 500.0,
+            max_freq: 4000.0,
+            ..WaterfallStyle::default()
+        };
+        export_csd_png(&csd, &png, &style).unwrap();
+        let bytes = std::fs::read(&png).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&bytes[12..16], b"IHDR");
+        assert_eq!(u32::from_be_bytes(bytes[16..20].try_into().unwrap()), 200);
+        assert_eq!(u32::from_be_bytes(bytes[20..24].try_into().unwrap()), 120);
+        assert_eq!(&bytes[bytes.len() - 8..bytes.len() - 4], b"IEND");
+        // Raw size: 120 rows of (filter byte + 200 RGB pixels), stored
+        // uncompressed, so the file is at least that large.
+        assert!(bytes.len() > 120 * (1 + 200 * 3));
+    }
+
+    #[test]
+    fn test_difference_csv_and_png_export() {
+        let diff = DifferenceSpectrogram {
+            times: vec![0.0, 0.01, 0.02],
+            frequencies: vec![0.0, 1000.0, 2000.0, 3000.0],
+            difference_db: vec![
+                vec![0.0, 0.0, 0.0, 0.0],
+                vec![0.0, 6.0, 0.0, -3.0],
+                vec![0.0, 0.0, -24.0, 0.0],
+            ],
+        };
+        let dir = tempfile::tempdir().unwrap();
+
+        let csv = dir.path().join("diff.csv");
+        export_difference_csv(&diff, &csv).unwrap();
+        let content = std::fs::read_to_string(&csv).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("time_s,0.00,1000.00"));
+        assert_eq!(lines[2], "0.010000,0.000,6.000,0.000,-3.000");
+
+        let png = dir.path().join("diff.png");
+        let style = HeatmapStyle {
+            width: 90,
+            height: 60,
+            min_freq: 500.0,
+            max_freq: 4000.0,
+            ..HeatmapStyle::default()
+        };
+        export_difference_png(&diff, &png, &style).unwrap();
+        let bytes = std::fs::read(&png).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(u32::from_be_bytes(bytes[16..20].try_into().unwrap()), 90);
+        assert_eq!(u32::from_be_bytes(bytes[20..24].try_into().unwrap()), 60);
+        assert_eq!(&bytes[bytes.len() - 8..bytes.len() - 4], b"IEND");
+    }
 }
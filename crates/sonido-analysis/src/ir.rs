@@ -393,6 +393,7 @@ fn calculate_edc_correlation(edc: &[f32], start_db: f32, end_db: f32) -> f32 {
 /// | `end_freq`      | 20 000 Hz| [1 k, 24 k] Hz |
 /// | `duration_secs` | 5 s      | [1, 30] s      |
 /// | `sample_rate`   | 48 000 Hz| [22 050, 96 k] |
+#[derive(Debug, Clone, Copy)]
 pub struct SweepConfig {
     /// Sweep start frequency in Hz.  Typical: 20 Hz.
     pub start_freq: f32,
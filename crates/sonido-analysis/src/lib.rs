@@ -10,6 +10,7 @@
 //! - [`compare`] - A/B comparison tools
 //! - [`distortion`] - THD, THD+N, and IMD analysis
 //! - [`spectrogram`] - STFT-based time-frequency analysis
+//! - [`csd`] - Cumulative spectral decay (waterfall) of impulse responses
 //! - [`constant_q`] - Constant-Q transform for pitch-based analysis
 //! - [`filterbank`] - Bandpass filter bank for frequency band extraction
 //! - [`hilbert`] - Hilbert transform for analytic signals
@@ -22,7 +23,11 @@
 //! - [`mod@resample`] - Rational resampling via polyphase filter (decimate, interpolate, P/Q)
 //! - [`loudness`] - ITU-R BS.1770-4 LUFS metering (momentary, short-term, integrated, true peak)
 //! - [`pitch`] - YIN pitch detection: `detect_pitch(buffer, sample_rate) -> Option<PitchResult>`
+//! - [`measurement`] - One-pass sweep + stepped-level measurement of hardware (latency, IR, response, THD vs level)
+//! - [`optimize`] - Hooke–Jeeves pattern search for fitting normalized parameters
 //! - [`stereo`] - Stereo Pearson correlation meter with sliding window
+//! - [`monocheck`] - Mono compatibility: per-band mono-sum loss, correlation over time, effect probes
+//! - [`tilt`] - Spectral tilt: octave-band LTAS against a pink-noise reference slope
 //!
 //! ## Target Use Case
 //!
@@ -76,6 +81,7 @@
 pub mod cfc;
 pub mod compare;
 pub mod constant_q;
+pub mod csd;
 pub mod ddc;
 pub mod distortion;
 pub mod dynamics;
@@ -86,19 +92,27 @@ pub mod hilbert;
 pub mod ir;
 pub mod lms;
 pub mod loudness;
+pub mod measurement;
+pub mod monocheck;
+pub mod optimize;
 pub mod phase;
 pub mod pitch;
 pub mod resample;
 pub mod spectrogram;
 pub mod spectrum;
 pub mod stereo;
+pub mod tilt;
 pub mod transfer_fn;
 pub mod xcorr;
 
 // Re-export main types
 pub use cfc::{Comodulogram, PacAnalyzer, PacMethod, PacResult};
-pub use compare::{spectral_correlation, spectral_difference};
+pub use compare::{
+    Alignment, DifferenceSpectrogram, align, band_distance_db, difference_spectrogram,
+    null_difference, spectral_correlation, spectral_difference,
+};
 pub use constant_q::{Chromagram, ConstantQTransform, CqtResult, CqtSpectrogram};
+pub use csd::{Csd, CsdAnalyzer};
 pub use distortion::{ImdAnalyzer, ImdResult, ThdAnalyzer, ThdResult, generate_test_tone};
 pub use dynamics::{
     DynamicsAnalysis, analyze_dynamics, crest_factor, crest_factor_db, peak, peak_db, rms, rms_db,
@@ -114,9 +128,13 @@ pub use transfer_fn::{Resonance, TransferFunction};
 // DSP primitives
 pub use ddc::Ddc;
 pub use lms::{LmsFilter, NlmsFilter};
-pub use loudness::LufsMeter;
+pub use loudness::{LoudnessSummary, LufsMeter, measure_loudness};
+pub use measurement::{LevelPoint, MeasurementPlan, MeasurementReport};
+pub use monocheck::{MonoCheck, MonoReport, analyze_mono, probe_effect};
+pub use optimize::{PatternSearch, SearchResult};
 pub use phase::{PhaseTracker, unwrap_phase, unwrap_phase_quality, unwrap_phase_tol};
 pub use pitch::{PitchResult, detect_pitch};
 pub use resample::{decimate, design_lowpass, interpolate, resample};
 pub use stereo::StereoCorrelation;
+pub use tilt::{TiltAnalyzer, TiltReport, analyze_tilt};
 pub use xcorr::{peak_lag, xcorr_direct, xcorr_fft, xcorr_normalized};
@@ -410,6 +410,55 @@ impl LufsMeter {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+//  Whole-program measurement
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Loudness statistics for a complete program (file).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoudnessSummary {
+    /// Gated integrated loudness in LUFS.
+    pub integrated: f32,
+    /// Highest momentary (400 ms) loudness in LUFS.
+    pub max_momentary: f32,
+    /// Highest short-term (3 s) loudness in LUFS.
+    pub max_short_term: f32,
+    /// True peak in dBTP.
+    pub true_peak_dbtp: f32,
+}
+
+/// Measure a complete program with a fresh [`LufsMeter`].
+///
+/// `right = None` measures `left` as a single mono channel (BS.1770 weights
+/// a lone channel once, so this reads 3 dB below the same signal fed as
+/// dual mono). Maximum momentary and short-term values are sampled every
+/// 100 ms. Loudness values are `f32::NEG_INFINITY` when the program is
+/// shorter than one 400 ms block or fully gated.
+pub fn measure_loudness(left: &[f32], right: Option<&[f32]>, sample_rate: f32) -> LoudnessSummary {
+    let mut meter = LufsMeter::new(sample_rate);
+    let hop = ((sample_rate * 0.1) as usize).max(1);
+    let silence = vec![0.0f32; hop];
+    let mut max_momentary = f32::NEG_INFINITY;
+    let mut max_short_term = f32::NEG_INFINITY;
+
+    for (i, l) in left.chunks(hop).enumerate() {
+        let r = match right {
+            Some(right) => &right[i * hop..i * hop + l.len()],
+            None => &silence[..l.len()],
+        };
+        meter.push_samples(l, r);
+        max_momentary = max_momentary.max(meter.momentary());
+        max_short_term = max_short_term.max(meter.short_term());
+    }
+
+    LoudnessSummary {
+        integrated: meter.integrated(),
+        max_momentary,
+        max_short_term,
+        true_peak_dbtp: meter.true_peak_dbtp(),
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Tests
 // ═══════════════════════════════════════════════════════════════════════════
@@ -500,4 +549,33 @@ mod tests {
         assert!(meter.momentary().is_infinite());
         assert!(meter.integrated().is_infinite());
     }
+
+    #[test]
+    fn measure_loudness_dual_mono_is_3db_above_mono() {
+        let sr = 48000.0f32;
+        let sig: Vec<f32> = sine(1000.0, sr, (sr * 4.0) as usize)
+            .iter()
+            .map(|x| x * 0.5)
+            .collect();
+        let stereo = measure_loudness(&sig, Some(&sig), sr);
+        let mono = measure_loudness(&sig, None, sr);
+        // 1 kHz at -6 dBFS in both channels reads about -6 LUFS.
+        assert!(
+            (stereo.integrated + 6.0).abs() < 0.5,
+            "integrated = {}",
+            stereo.integrated
+        );
+        assert!((stereo.integrated - mono.integrated - 3.01).abs() < 0.1);
+        assert!(stereo.max_momentary >= stereo.integrated - 0.1);
+        assert!(stereo.max_short_term.is_finite());
+        assert!((stereo.true_peak_dbtp + 6.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn measure_loudness_short_program_is_unmeasured() {
+        let sig = vec![0.5f32; 100];
+        let summary = measure_loudness(&sig, None, 48000.0);
+        assert_eq!(summary.integrated, f32::NEG_INFINITY);
+        assert_eq!(summary.max_momentary, f32::NEG_INFINITY);
+    }
 }
@@ -0,0 +1,383 @@
+//! One-pass hardware measurement: sweep response plus stepped-level THD.
+//!
+//! A [`MeasurementPlan`] lays out a single stimulus that is played through
+//! the device under test and recorded back:
+//!
+//! ```text
+//! | lead-in | log sweep | gap | tone L0 | gap | tone L1 | gap | ... |
+//! ```
+//!
+//! [`MeasurementPlan::analyze`] turns the recording into a
+//! [`MeasurementReport`]:
+//!
+//! - **Latency and polarity** from the peak of the deconvolved sweep
+//!   response (Farina inverse filter, see [`SineSweep::compute_ir`])
+//! - **Impulse response**, scaled so a direct loopback peaks at 1.0
+//! - **Frequency response** over the sweep band, from the cross-spectral
+//!   transfer function of the latency-aligned sweep ([`TransferFunction`])
+//! - **Level steps**: output level, gain, and THD of a sine at each input
+//!   level, for gain-staging and clipping-point analysis
+//!
+//! The round-trip latency must be shorter than `gap_secs`, since each
+//! segment is located by its scheduled offset plus the measured latency.
+
+use crate::distortion::ThdAnalyzer;
+use crate::dynamics::rms_db;
+use crate::ir::{SineSweep, SweepConfig};
+use crate::transfer_fn::TransferFunction;
+use std::f32::consts::PI;
+
+/// Fade applied to the ends of every stimulus segment, in seconds.
+const FADE_SECS: f32 = 0.005;
+/// Settling time skipped at the start of each recorded tone, in seconds.
+const SETTLE_SECS: f32 = 0.1;
+/// Impulse response kept before the main peak, in seconds.
+const IR_PRE_ROLL_SECS: f32 = 0.002;
+
+/// Layout of a measurement stimulus.
+#[derive(Debug, Clone)]
+pub struct MeasurementPlan {
+    /// Sweep range, duration, and sample rate (the plan's sample rate).
+    pub sweep: SweepConfig,
+    /// Sweep peak level in dBFS.
+    pub sweep_level_db: f32,
+    /// Test tone frequency for the level steps, in Hz.
+    pub tone_freq: f32,
+    /// Peak level of each tone step in dBFS, in playback order.
+    pub tone_levels_db: Vec<f32>,
+    /// Duration of each tone step in seconds.
+    pub tone_secs: f32,
+    /// Silence before the sweep in seconds.
+    pub lead_in_secs: f32,
+    /// Silence after the sweep and after each tone in seconds.
+    pub gap_secs: f32,
+    /// Length of the impulse response kept in the report, in seconds.
+    pub ir_secs: f32,
+    /// FFT size for the frequency response and THD analysis.
+    pub fft_size: usize,
+}
+
+impl Default for MeasurementPlan {
+    fn default() -> Self {
+        Self {
+            sweep: SweepConfig::default(),
+            sweep_level_db: -12.0,
+            tone_freq: 1000.0,
+            tone_levels_db: (0..=7).map(|i| -42.0 + 6.0 * i as f32).collect(),
+            tone_secs: 1.0,
+            lead_in_secs: 0.5,
+            gap_secs: 1.0,
+            ir_secs: 0.5,
+            fft_size: 8192,
+        }
+    }
+}
+
+/// Output level, gain, and distortion at one tone step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelPoint {
+    /// Input peak level in dBFS.
+    pub input_db: f32,
+    /// Output RMS level in dBFS.
+    pub output_rms_db: f32,
+    /// Output RMS minus input RMS, in dB.
+    pub gain_db: f32,
+    /// Total harmonic distortion as a ratio (0.01 = 1%).
+    pub thd_ratio: f32,
+}
+
+/// Result of [`MeasurementPlan::analyze`].
+pub struct MeasurementReport {
+    /// Round-trip latency in samples.
+    pub latency_samples: usize,
+    /// True if the device inverts polarity.
+    pub inverted: bool,
+    /// Linear impulse response starting just before the main peak.
+    pub impulse_response: Vec<f32>,
+    /// Frequency response limited to the sweep band.
+    pub response: TransferFunction,
+    /// One entry per tone step, in plan order.
+    pub levels: Vec<LevelPoint>,
+}
+
+impl MeasurementReport {
+    /// Gain at the quietest tone step, in dB.
+    pub fn small_signal_gain_db(&self) -> Option<f32> {
+        self.levels
+            .iter()
+            .min_by(|a, b| a.input_db.total_cmp(&b.input_db))
+            .map(|p| p.gain_db)
+    }
+
+    /// Lowest input level whose THD reaches `thd_ratio` (e.g. 0.01 for 1%).
+    pub fn input_level_at_thd(&self, thd_ratio: f32) -> Option<f32> {
+        self.levels
+            .iter()
+            .filter(|p| p.thd_ratio >= thd_ratio)
+            .map(|p| p.input_db)
+            .min_by(f32::total_cmp)
+    }
+}
+
+impl MeasurementPlan {
+    /// Sample rate of the stimulus in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sweep.sample_rate
+    }
+
+    fn samples(&self, secs: f32) -> usize {
+        (secs * self.sample_rate()) as usize
+    }
+
+    fn sweep_generator(&self) -> SineSweep {
+        SineSweep::new(
+            self.sweep.sample_rate,
+            self.sweep.start_freq,
+            self.sweep.end_freq,
+            self.sweep.duration_secs,
+        )
+    }
+
+    /// Start offset of tone step `index` within the stimulus.
+    fn tone_start(&self, index: usize) -> usize {
+        let sweep_end = self.samples(self.lead_in_secs) + self.sweep_generator().num_samples();
+        let step = self.samples(self.tone_secs) + self.samples(self.gap_secs);
+        sweep_end + self.samples(self.gap_secs) + index * step
+    }
+
+    /// Total stimulus length in samples.
+    pub fn len(&self) -> usize {
+        self.tone_start(self.tone_levels_db.len())
+    }
+
+    /// True if the plan produces no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Build the mono stimulus.
+    pub fn stimulus(&self) -> Vec<f32> {
+        let mut out = vec![0.0; self.len()];
+        let fade = self.samples(FADE_SECS);
+
+        let lead_in = self.samples(self.lead_in_secs);
+        let sweep_gain = db_to_gain(self.sweep_level_db);
+        let mut sweep = self.sweep_generator().generate();
+        apply_fades(&mut sweep, fade);
+        for (o, s) in out[lead_in..].iter_mut().zip(&sweep) {
+            *o = s * sweep_gain;
+        }
+
+        let tone_len = self.samples(self.tone_secs);
+        let omega = 2.0 * PI * self.tone_freq / self.sample_rate();
+        for (i, &level) in self.tone_levels_db.iter().enumerate() {
+            let gain = db_to_gain(level);
+            let mut tone: Vec<f32> = (0..tone_len)
+                .map(|n| gain * (omega * n as f32).sin())
+                .collect();
+            apply_fades(&mut tone, fade);
+            let start = self.tone_start(i);
+            out[start..start + tone_len].copy_from_slice(&tone);
+        }
+
+        out
+    }
+
+    /// Analyze a recording of [`stimulus`](Self::stimulus) played through
+    /// the device. Missing samples at the end are treated as silence.
+    pub fn analyze(&self, recorded: &[f32]) -> MeasurementReport {
+        let mut recorded = recorded.to_vec();
+        recorded.resize(recorded.len().max(self.len()), 0.0);
+        let sample_rate = self.sample_rate();
+
+        // Deconvolve the sweep section (including the following gap so the
+        // delayed response is captured) against the reference sweep.
+        let lead_in = self.samples(self.lead_in_secs);
+        let generator = self.sweep_generator();
+        let sweep_len = generator.num_samples();
+        let section_end = lead_in + sweep_len + self.samples(self.gap_secs);
+        let sweep_gain = db_to_gain(self.sweep_level_db);
+        let mut reference = generator.generate();
+        apply_fades(&mut reference, self.samples(FADE_SECS));
+        let reference_peak = generator
+            .compute_ir(&reference)
+            .iter()
+            .fold(0.0f32, |m, s| m.max(s.abs()))
+            * sweep_gain;
+
+        let ir = generator.compute_ir(&recorded[..section_end]);
+        let zero_lag = lead_in + sweep_len - 1;
+        let (peak_index, peak_value) = ir[zero_lag..]
+            .iter()
+            .enumerate()
+            .take(self.samples(self.gap_secs))
+            .fold((0, 0.0f32), |(bi, bv), (i, &v)| {
+                if v.abs() > bv.abs() { (i, v) } else { (bi, bv) }
+            });
+        let latency_samples = peak_index;
+
+        let pre_roll = self
+            .samples(IR_PRE_ROLL_SECS)
+            .min(zero_lag + latency_samples);
+        let ir_start = zero_lag + latency_samples - pre_roll;
+        let ir_end = (ir_start + self.samples(self.ir_secs)).min(ir.len());
+        let scale = 1.0 / reference_peak.max(1e-12);
+        let impulse_response = ir[ir_start..ir_end].iter().map(|s| s * scale).collect();
+
+        // Frequency response from the aligned sweep, cropped to its band
+        let aligned = &recorded[lead_in + latency_samples..lead_in + latency_samples + sweep_len];
+        let stimulus: Vec<f32> = reference.iter().map(|s| s * sweep_gain).collect();
+        let full = TransferFunction::measure(&stimulus, aligned, sample_rate, self.fft_size, 0.5);
+        let response = crop_band(full, self.sweep.start_freq, self.sweep.end_freq);
+
+        // Level steps
+        let analyzer = ThdAnalyzer::new(sample_rate, self.fft_size);
+        let settle = self.samples(SETTLE_SECS);
+        let tone_len = self.samples(self.tone_secs);
+        let levels = self
+            .tone_levels_db
+            .iter()
+            .enumerate()
+            .map(|(i, &input_db)| {
+                let start = self.tone_start(i) + latency_samples + settle;
+                let end = (self.tone_start(i) + latency_samples + tone_len)
+                    .saturating_sub(self.samples(FADE_SECS))
+                    .max(start);
+                let segment = &recorded[start..end];
+                let output_rms_db = rms_db(segment);
+                // A sine's RMS sits 3.01 dB below its peak
+                let input_rms_db = input_db - 3.0103;
+                let thd_ratio = analyzer.analyze(segment, self.tone_freq).thd_ratio;
+                LevelPoint {
+                    input_db,
+                    output_rms_db,
+                    gain_db: output_rms_db - input_rms_db,
+                    thd_ratio,
+                }
+            })
+            .collect();
+
+        MeasurementReport {
+            latency_samples,
+            inverted: peak_value < 0.0,
+            impulse_response,
+            response,
+            levels,
+        }
+    }
+}
+
+fn db_to_gain(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// Linear fade-in and fade-out of `len` samples.
+fn apply_fades(signal: &mut [f32], len: usize) {
+    let len = len.min(signal.len() / 2);
+    let n = signal.len();
+    for i in 0..len {
+        let g = i as f32 / len as f32;
+        signal[i] *= g;
+        signal[n - 1 - i] *= g;
+    }
+}
+
+/// Keep only the bins in `[low, high]` Hz.
+fn crop_band(tf: TransferFunction, low: f32, high: f32) -> TransferFunction {
+    let keep: Vec<usize> = tf
+        .frequencies
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| (low..=high).contains(*f))
+        .map(|(i, _)| i)
+        .collect();
+    TransferFunction {
+        frequencies: keep.iter().map(|&i| tf.frequencies[i]).collect(),
+        magnitude_db: keep.iter().map(|&i| tf.magnitude_db[i]).collect(),
+        phase_rad: keep.iter().map(|&i| tf.phase_rad[i]).collect(),
+        coherence: keep.iter().map(|&i| tf.coherence[i]).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn short_plan() -> MeasurementPlan {
+        MeasurementPlan {
+            sweep: SweepConfig {
+                duration_secs: 1.0,
+                ..SweepConfig::default()
+            },
+            tone_levels_db: vec![-30.0, -12.0, 0.0],
+            tone_secs: 0.5,
+            lead_in_secs: 0.1,
+            gap_secs: 0.2,
+            ir_secs: 0.05,
+            ..MeasurementPlan::default()
+        }
+    }
+
+    /// Delayed copy with gain and polarity, run through `shape`.
+    fn device(stimulus: &[f32], delay: usize, shape: impl Fn(f32) -> f32) -> Vec<f32> {
+        let mut out = vec![0.0; delay];
+        out.extend(stimulus.iter().map(|&s| shape(s)));
+        out
+    }
+
+    #[test]
+    fn stimulus_layout_matches_plan() {
+        let plan = short_plan();
+        let stimulus = plan.stimulus();
+        assert_eq!(stimulus.len(), plan.len());
+        // Lead-in is silent; the loudest tone peaks at 0 dBFS
+        assert!(
+            stimulus[..plan.samples(plan.lead_in_secs)]
+                .iter()
+                .all(|&s| s == 0.0)
+        );
+        let peak = stimulus.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        assert!((peak - 1.0).abs() < 1e-3, "peak {peak}");
+    }
+
+    #[test]
+    fn loopback_measures_latency_and_unity_gain() {
+        let plan = short_plan();
+        let recorded = device(&plan.stimulus(), 123, |s| 0.5 * s);
+        let report = plan.analyze(&recorded);
+
+        assert_eq!(report.latency_samples, 123);
+        assert!(!report.inverted);
+        let ir_peak = report
+            .impulse_response
+            .iter()
+            .fold(0.0f32, |m, s| m.max(s.abs()));
+        assert!((ir_peak - 0.5).abs() < 0.05, "IR peak {ir_peak}");
+
+        let mid = report.response.magnitude_at(1000.0);
+        assert!((mid + 6.02).abs() < 0.5, "1 kHz response {mid} dB");
+        for point in &report.levels {
+            assert!((point.gain_db + 6.02).abs() < 0.2, "{point:?}");
+            assert!(point.thd_ratio < 0.001, "{point:?}");
+        }
+        assert!(report.input_level_at_thd(0.01).is_none());
+    }
+
+    #[test]
+    fn clipping_device_reports_thd_and_polarity() {
+        let plan = short_plan();
+        let recorded = device(&plan.stimulus(), 40, |s| -(4.0 * s).tanh());
+        let report = plan.analyze(&recorded);
+
+        assert_eq!(report.latency_samples, 40);
+        assert!(report.inverted);
+        let quiet = report.levels[0];
+        let loud = report.levels[2];
+        assert!(quiet.thd_ratio < 0.01, "{quiet:?}");
+        assert!(loud.thd_ratio > 0.1, "{loud:?}");
+        // Compression: gain falls as the level rises
+        assert!(loud.gain_db < quiet.gain_db - 6.0);
+        assert_eq!(report.input_level_at_thd(0.01), Some(-12.0));
+        assert!((report.small_signal_gain_db().unwrap() - 12.04).abs() < 0.5);
+    }
+}
@@ -0,0 +1,455 @@
+//! Mono compatibility checking.
+//!
+//! Summing a stereo mix to mono (phone speakers, club PAs, broadcast) cancels
+//! whatever the two channels carry out of phase. [`MonoCheck`] measures how
+//! much: for each third-octave band it compares the power of the mono sum
+//! `(L + R) / 2` with the mean channel power `(|L|² + |R|²) / 2`, and it
+//! records the L/R correlation over time.
+//!
+//! ## Reading the loss
+//!
+//! | Channels | Band loss |
+//! |----------|-----------|
+//! | Identical (mono) | 0 dB |
+//! | Uncorrelated (wide reverb, independent noise) | 3 dB |
+//! | Partly cancelling (short Haas delays, phase-inverted widening) | > 3 dB |
+//! | Polarity-inverted | total |
+//!
+//! Losses above [`MONO_LOSS_THRESHOLD_DB`] mean the sum is cancelling, not
+//! just decorrelated; those bands go thin or hollow in mono.
+//!
+//! [`probe_effect`] runs a single effect on mono noise and reports what it
+//! does to mono compatibility, so a chain can be checked effect by effect.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use sonido_analysis::monocheck::analyze_mono;
+//!
+//! // Right channel polarity-inverted: everything cancels in mono.
+//! let left: Vec<f32> = (0..48000).map(|i| (i as f32 * 0.05).sin()).collect();
+//! let right: Vec<f32> = left.iter().map(|s| -s).collect();
+//! let report = analyze_mono(&left, &right, 48000.0);
+//! assert!(report.correlation < -0.99);
+//! assert!(report.problem_bands(3.0).count() > 0);
+//! ```
+
+use crate::fft::{Fft, Window};
+use sonido_core::Effect;
+
+/// Band loss in dB above which summing to mono is cancelling (the loss of
+/// fully uncorrelated channels).
+pub const MONO_LOSS_THRESHOLD_DB: f32 = 3.0;
+
+/// Band loss in dB at which an effect is flagged by [`MonoReport::is_problematic`]:
+/// a cancellation notch rather than the few dB of a decorrelated effect.
+pub const EFFECT_FLAG_LOSS_DB: f32 = 6.0;
+
+/// Loss reported for bands that cancel completely.
+pub const MAX_LOSS_DB: f32 = 60.0;
+
+/// FFT length for band powers (~85 ms at 48 kHz).
+const FFT_SIZE: usize = 4096;
+
+/// Correlation timeline resolution in seconds.
+const SEGMENT_SECS: f32 = 0.1;
+
+/// Bands quieter than this relative to the loudest band are not reported.
+const BAND_FLOOR: f64 = 1e-6;
+
+/// Third-octave band centres (ISO 266), 25 Hz to 16 kHz.
+const BAND_CENTRES_HZ: [f32; 29] = [
+    25.0, 31.5, 40.0, 50.0, 63.0, 80.0, 100.0, 125.0, 160.0, 200.0, 250.0, 315.0, 400.0, 500.0,
+    630.0, 800.0, 1000.0, 1250.0, 1600.0, 2000.0, 2500.0, 3150.0, 4000.0, 5000.0, 6300.0, 8000.0,
+    10000.0, 12500.0, 16000.0,
+];
+
+/// Correlation of one timeline segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorrelationPoint {
+    /// Segment start in seconds.
+    pub time_secs: f32,
+    /// Pearson L/R correlation in `[-1, 1]` (0 when silent).
+    pub correlation: f32,
+}
+
+/// Mono-sum loss of one frequency band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandLoss {
+    /// Band centre in Hz.
+    pub centre_hz: f32,
+    /// Lower band edge in Hz.
+    pub low_hz: f32,
+    /// Upper band edge in Hz.
+    pub high_hz: f32,
+    /// Power lost by summing to mono in dB (0 = none, 3 = uncorrelated),
+    /// capped at [`MAX_LOSS_DB`].
+    pub loss_db: f32,
+}
+
+/// Result of a mono compatibility check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonoReport {
+    /// L/R correlation over the whole signal.
+    pub correlation: f32,
+    /// Lowest segment correlation in the timeline (`1.0` if empty).
+    pub min_correlation: f32,
+    /// Correlation per [`SEGMENT_SECS`]-long segment.
+    pub timeline: Vec<CorrelationPoint>,
+    /// Broadband mono-sum loss in dB.
+    pub loss_db: f32,
+    /// Per-band losses, low to high; bands without signal are omitted.
+    pub bands: Vec<BandLoss>,
+}
+
+impl MonoReport {
+    /// Bands losing more than `threshold_db` when summed to mono.
+    pub fn problem_bands(&self, threshold_db: f32) -> impl Iterator<Item = &BandLoss> {
+        self.bands.iter().filter(move |b| b.loss_db > threshold_db)
+    }
+
+    /// The band losing the most, if any band has signal.
+    pub fn worst_band(&self) -> Option<&BandLoss> {
+        self.bands
+            .iter()
+            .max_by(|a, b| a.loss_db.total_cmp(&b.loss_db))
+    }
+
+    /// Whether the signal cancels badly in mono: some band loses
+    /// [`EFFECT_FLAG_LOSS_DB`] or more. (A broadband correlation of -0.5
+    /// already loses 6 dB, so strongly negative correlation is caught too.)
+    pub fn is_problematic(&self) -> bool {
+        self.worst_band()
+            .is_some_and(|b| b.loss_db >= EFFECT_FLAG_LOSS_DB)
+    }
+}
+
+/// Running power sums for one band or segment.
+#[derive(Debug, Clone, Copy, Default)]
+struct Powers {
+    left: f64,
+    right: f64,
+    /// Σ L·R for correlation, or Σ |(L + R) / 2|² for band powers.
+    cross: f64,
+}
+
+impl Powers {
+    fn correlation(&self) -> f32 {
+        let denom = self.left * self.right;
+        if denom <= 0.0 {
+            0.0
+        } else {
+            (self.cross / denom.sqrt()).clamp(-1.0, 1.0) as f32
+        }
+    }
+
+    /// Loss of `cross` (mono power) against the mean channel power.
+    fn loss_db(&self) -> f32 {
+        let stereo = (self.left + self.right) * 0.5;
+        if self.cross <= stereo * 1e-6 {
+            MAX_LOSS_DB
+        } else {
+            ((10.0 * (stereo / self.cross).log10()) as f32).min(MAX_LOSS_DB)
+        }
+    }
+}
+
+/// Streaming mono compatibility analyzer.
+///
+/// Feed stereo audio with [`push_samples`](Self::push_samples) and read a
+/// [`MonoReport`] at any time. Band powers come from Hann-windowed FFT frames
+/// with 50% overlap; a partial trailing frame is not analyzed.
+pub struct MonoCheck {
+    sample_rate: f32,
+    fft: Fft,
+    window: Vec<f32>,
+    /// Unanalyzed input, at most one frame.
+    pending_left: Vec<f32>,
+    pending_right: Vec<f32>,
+    /// FFT bin range of each band.
+    band_bins: Vec<(usize, usize)>,
+    bands: Vec<Powers>,
+    total: Powers,
+    segment: Powers,
+    segment_len: usize,
+    segment_filled: usize,
+    timeline: Vec<CorrelationPoint>,
+}
+
+impl MonoCheck {
+    /// Create an analyzer for audio at `sample_rate`.
+    pub fn new(sample_rate: f32) -> Self {
+        let bin_hz = sample_rate / FFT_SIZE as f32;
+        let nyquist_bin = FFT_SIZE / 2;
+        let band_bins: Vec<(usize, usize)> = BAND_CENTRES_HZ
+            .iter()
+            .map(|&centre| {
+                let (low, high) = band_edges(centre);
+                let lo = ((low / bin_hz).ceil() as usize).max(1);
+                let hi = ((high / bin_hz).ceil() as usize).min(nyquist_bin);
+                (lo, hi.max(lo + 1))
+            })
+            .take_while(|&(lo, _)| lo < nyquist_bin)
+            .collect();
+        Self {
+            sample_rate,
+            fft: Fft::new(FFT_SIZE),
+            window: Window::Hann.coefficients(FFT_SIZE),
+            pending_left: Vec::with_capacity(FFT_SIZE),
+            pending_right: Vec::with_capacity(FFT_SIZE),
+            bands: vec![Powers::default(); band_bins.len()],
+            band_bins,
+            total: Powers::default(),
+            segment: Powers::default(),
+            segment_len: ((sample_rate * SEGMENT_SECS) as usize).max(1),
+            segment_filled: 0,
+            timeline: Vec::new(),
+        }
+    }
+
+    /// Sample rate this analyzer was created with, in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Feed stereo samples. `right` must be as long as `left`.
+    pub fn push_samples(&mut self, left: &[f32], right: &[f32]) {
+        debug_assert_eq!(left.len(), right.len());
+        for (&l, &r) in left.iter().zip(right) {
+            let (l, r) = (l as f64, r as f64);
+            for acc in [&mut self.total, &mut self.segment] {
+                acc.left += l * l;
+                acc.right += r * r;
+                acc.cross += l * r;
+            }
+            self.segment_filled += 1;
+            if self.segment_filled == self.segment_len {
+                self.timeline.push(CorrelationPoint {
+                    time_secs: self.timeline.len() as f32 * SEGMENT_SECS,
+                    correlation: self.segment.correlation(),
+                });
+                self.segment = Powers::default();
+                self.segment_filled = 0;
+            }
+        }
+
+        let mut offset = 0;
+        while offset < left.len() {
+            let take = (FFT_SIZE - self.pending_left.len()).min(left.len() - offset);
+            self.pending_left
+                .extend_from_slice(&left[offset..offset + take]);
+            self.pending_right
+                .extend_from_slice(&right[offset..offset + take]);
+            offset += take;
+            if self.pending_left.len() == FFT_SIZE {
+                self.analyze_frame();
+                // 50% overlap
+                self.pending_left.drain(..FFT_SIZE / 2);
+                self.pending_right.drain(..FFT_SIZE / 2);
+            }
+        }
+    }
+
+    fn analyze_frame(&mut self) {
+        let windowed = |samples: &[f32]| -> Vec<f32> {
+            samples
+                .iter()
+                .zip(&self.window)
+                .map(|(s, w)| s * w)
+                .collect()
+        };
+        let left = self.fft.forward(&windowed(&self.pending_left));
+        let right = self.fft.forward(&windowed(&self.pending_right));
+        for (band, &(lo, hi)) in self.bands.iter_mut().zip(&self.band_bins) {
+            for bin in lo..hi {
+                let (l, r) = (left[bin], right[bin]);
+                band.left += l.norm_sqr() as f64;
+                band.right += r.norm_sqr() as f64;
+                band.cross += ((l + r) * 0.5).norm_sqr() as f64;
+            }
+        }
+    }
+
+    /// Report on everything pushed so far.
+    pub fn report(&self) -> MonoReport {
+        let loudest = self
+            .bands
+            .iter()
+            .map(|b| b.left + b.right)
+            .fold(0.0, f64::max);
+        let bands = self
+            .bands
+            .iter()
+            .zip(BAND_CENTRES_HZ)
+            .filter(|(b, _)| loudest > 0.0 && b.left + b.right > loudest * BAND_FLOOR)
+            .map(|(b, centre)| {
+                let (low_hz, high_hz) = band_edges(centre);
+                BandLoss {
+                    centre_hz: centre,
+                    low_hz,
+                    high_hz,
+                    loss_db: b.loss_db(),
+                }
+            })
+            .collect();
+
+        let mut mono = self.total;
+        // ((L + R) / 2)² = (L² + R² + 2·L·R) / 4
+        mono.cross = (self.total.left + self.total.right + 2.0 * self.total.cross) * 0.25;
+        MonoReport {
+            correlation: self.total.correlation(),
+            min_correlation: self
+                .timeline
+                .iter()
+                .map(|p| p.correlation)
+                .fold(1.0, f32::min),
+            timeline: self.timeline.clone(),
+            loss_db: if self.total.left + self.total.right > 0.0 {
+                mono.loss_db()
+            } else {
+                0.0
+            },
+            bands,
+        }
+    }
+
+    /// Clear all accumulated state.
+    pub fn reset(&mut self) {
+        self.pending_left.clear();
+        self.pending_right.clear();
+        self.bands.fill(Powers::default());
+        self.total = Powers::default();
+        self.segment = Powers::default();
+        self.segment_filled = 0;
+        self.timeline.clear();
+    }
+}
+
+/// Third-octave band edges around `centre_hz`.
+fn band_edges(centre_hz: f32) -> (f32, f32) {
+    let half = 2.0_f32.powf(1.0 / 6.0);
+    (centre_hz / half, centre_hz * half)
+}
+
+/// Check a complete stereo signal.
+pub fn analyze_mono(left: &[f32], right: &[f32], sample_rate: f32) -> MonoReport {
+    let mut check = MonoCheck::new(sample_rate);
+    check.push_samples(left, right);
+    check.report()
+}
+
+/// Probe length in seconds, after [`PROBE_SETTLE_SECS`].
+const PROBE_SECS: f32 = 2.0;
+
+/// Probe time discarded while smoothed parameters settle, in seconds.
+const PROBE_SETTLE_SECS: f32 = 0.25;
+
+/// Run `effect` on mono white noise and check its output.
+///
+/// The input is identical on both channels, so any loss is the effect's
+/// doing; [`MonoReport::is_problematic`] flags effects that cancel in mono.
+/// The effect is reset first and left holding the probe's state.
+pub fn probe_effect(effect: &mut dyn Effect, sample_rate: f32) -> MonoReport {
+    const BLOCK: usize = 512;
+    effect.reset();
+    let mut check = MonoCheck::new(sample_rate);
+    let settle = (PROBE_SETTLE_SECS * sample_rate) as usize;
+    let total = settle + (PROBE_SECS * sample_rate) as usize;
+
+    // Deterministic noise so probes are repeatable
+    let mut seed: u32 = 0x1234_5678;
+    let mut input = [0.0_f32; BLOCK];
+    let (mut left, mut right) = ([0.0_f32; BLOCK], [0.0_f32; BLOCK]);
+    let mut done = 0;
+    while done < total {
+        for s in &mut input {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            *s = ((seed >> 8) as f32 / (1 << 24) as f32 - 0.5) * 0.5;
+        }
+        effect.process_block_stereo(&input, &input, &mut left, &mut right);
+        let skip = settle.saturating_sub(done).min(BLOCK);
+        check.push_samples(&left[skip..], &right[skip..]);
+        done += BLOCK;
+    }
+    check.report()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(len: usize, mut seed: u32) -> Vec<f32> {
+        (0..len)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mono_signal_loses_nothing() {
+        let signal = noise(48000, 1);
+        let report = analyze_mono(&signal, &signal, 48000.0);
+        assert!(report.correlation > 0.999);
+        assert!(report.loss_db.abs() < 0.01);
+        assert!(report.bands.iter().all(|b| b.loss_db.abs() < 0.01));
+        assert!(!report.is_problematic());
+        assert_eq!(report.timeline.len(), 10);
+    }
+
+    #[test]
+    fn uncorrelated_channels_lose_three_db() {
+        let report = analyze_mono(&noise(96000, 1), &noise(96000, 2), 48000.0);
+        assert!(report.correlation.abs() < 0.05);
+        assert!(
+            (report.loss_db - 3.0).abs() < 0.2,
+            "loss = {}",
+            report.loss_db
+        );
+        assert!(!report.is_problematic());
+    }
+
+    #[test]
+    fn short_delay_notches_are_flagged() {
+        // 1 ms Haas delay: comb notches at 500 Hz, 1.5 kHz, ...
+        let left = noise(96000, 3);
+        let right: Vec<f32> = (0..left.len())
+            .map(|i| if i >= 48 { left[i - 48] } else { 0.0 })
+            .collect();
+        let report = analyze_mono(&left, &right, 48000.0);
+        let notch = report.bands.iter().find(|b| b.centre_hz == 500.0).unwrap();
+        assert!(notch.loss_db > 10.0, "500 Hz loss = {}", notch.loss_db);
+        let low = report.bands.iter().find(|b| b.centre_hz == 63.0).unwrap();
+        assert!(low.loss_db < 1.0);
+        assert!(report.is_problematic());
+    }
+
+    #[test]
+    fn inverted_polarity_is_total_loss() {
+        let left = noise(48000, 4);
+        let right: Vec<f32> = left.iter().map(|s| -s).collect();
+        let report = analyze_mono(&left, &right, 48000.0);
+        assert_eq!(report.loss_db, MAX_LOSS_DB);
+        assert!(report.min_correlation < -0.99);
+        assert_eq!(
+            report.problem_bands(MONO_LOSS_THRESHOLD_DB).count(),
+            report.bands.len()
+        );
+    }
+
+    #[test]
+    fn probe_passes_a_mono_effect() {
+        struct Gain;
+        impl Effect for Gain {
+            fn process(&mut self, input: f32) -> f32 {
+                input * 0.5
+            }
+            fn set_sample_rate(&mut self, _: f32) {}
+            fn reset(&mut self) {}
+        }
+        let report = probe_effect(&mut Gain, 48000.0);
+        assert!(!report.is_problematic());
+        assert!(report.loss_db.abs() < 0.01);
+    }
+}
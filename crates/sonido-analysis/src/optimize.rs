@@ -0,0 +1,197 @@
+//! Derivative-free parameter search on the unit hypercube.
+//!
+//! [`PatternSearch`] minimizes a cost function of `n` normalized parameters
+//! (each in `[0, 1]`) using Hooke–Jeeves pattern search: exploratory moves of
+//! ±`step` along each axis, a pattern move along the improving direction, and
+//! step halving when no move improves. It needs no gradients, tolerates noisy
+//! costs, and stays inside the bounds, which suits fitting effect parameters
+//! to a reference recording where each evaluation renders audio.
+//!
+//! ## Reference
+//!
+//! Hooke, R. & Jeeves, T. A. (1961). "Direct Search Solution of Numerical and
+//! Statistical Problems." *Journal of the ACM* 8(2), 212–229.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use sonido_analysis::optimize::PatternSearch;
+//!
+//! let result = PatternSearch::default().minimize(&[0.5, 0.5], |x| {
+//!     (x[0] - 0.2).powi(2) + (x[1] - 0.7).powi(2)
+//! });
+//! assert!((result.params[0] - 0.2).abs() < 0.01);
+//! assert!((result.params[1] - 0.7).abs() < 0.01);
+//! ```
+
+/// Result of a [`PatternSearch::minimize`] run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    /// Best normalized parameters found, each in `[0, 1]`.
+    pub params: Vec<f32>,
+    /// Cost at [`params`](Self::params).
+    pub cost: f32,
+    /// Number of cost evaluations performed.
+    pub evaluations: usize,
+}
+
+/// Hooke–Jeeves pattern search over normalized parameters.
+///
+/// # Invariants
+///
+/// - `initial_step` > `min_step` > 0
+/// - Every evaluated point lies in `[0, 1]ⁿ`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternSearch {
+    /// Starting exploratory step (normalized units).
+    pub initial_step: f32,
+    /// The search stops once the step shrinks below this.
+    pub min_step: f32,
+    /// The search stops after this many cost evaluations.
+    pub max_evaluations: usize,
+}
+
+impl Default for PatternSearch {
+    fn default() -> Self {
+        Self {
+            initial_step: 0.25,
+            min_step: 1e-3,
+            max_evaluations: 500,
+        }
+    }
+}
+
+impl PatternSearch {
+    /// Minimize `cost` starting from `start` (clamped to `[0, 1]`).
+    ///
+    /// With zero parameters the cost is evaluated once at the empty point.
+    pub fn minimize<F>(&self, start: &[f32], mut cost: F) -> SearchResult
+    where
+        F: FnMut(&[f32]) -> f32,
+    {
+        let mut base: Vec<f32> = start.iter().map(|x| x.clamp(0.0, 1.0)).collect();
+        let mut base_cost = cost(&base);
+        let mut evaluations = 1;
+        let mut step = self.initial_step;
+
+        while step >= self.min_step && evaluations < self.max_evaluations && !base.is_empty() {
+            let (point, point_cost) =
+                self.explore(&base, base_cost, step, &mut cost, &mut evaluations);
+
+            if point_cost < base_cost {
+                // Pattern move: jump along the improving direction, then
+                // explore around the jump and keep it only if it helps.
+                let jump: Vec<f32> = point
+                    .iter()
+                    .zip(&base)
+                    .map(|(p, b)| (2.0 * p - b).clamp(0.0, 1.0))
+                    .collect();
+                base = point;
+                base_cost = point_cost;
+
+                if evaluations < self.max_evaluations {
+                    let jump_cost = cost(&jump);
+                    evaluations += 1;
+                    let (point, point_cost) =
+                        self.explore(&jump, jump_cost, step, &mut cost, &mut evaluations);
+                    if point_cost < base_cost {
+                        base = point;
+                        base_cost = point_cost;
+                    }
+                }
+            } else {
+                step *= 0.5;
+            }
+        }
+
+        SearchResult {
+            params: base,
+            cost: base_cost,
+            evaluations,
+        }
+    }
+
+    /// Try ±`step` along each axis from `point`, keeping improvements.
+    fn explore<F>(
+        &self,
+        point: &[f32],
+        point_cost: f32,
+        step: f32,
+        cost: &mut F,
+        evaluations: &mut usize,
+    ) -> (Vec<f32>, f32)
+    where
+        F: FnMut(&[f32]) -> f32,
+    {
+        let mut best = point.to_vec();
+        let mut best_cost = point_cost;
+
+        for axis in 0..best.len() {
+            for direction in [1.0, -1.0] {
+                if *evaluations >= self.max_evaluations {
+                    return (best, best_cost);
+                }
+                let original = best[axis];
+                let moved = (original + direction * step).clamp(0.0, 1.0);
+                if moved == original {
+                    continue;
+                }
+                best[axis] = moved;
+                let c = cost(&best);
+                *evaluations += 1;
+                if c < best_cost {
+                    best_cost = c;
+                    break;
+                }
+                best[axis] = original;
+            }
+        }
+
+        (best, best_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_quadratic_minimum() {
+        let target = [0.13, 0.62, 0.91];
+        let result = PatternSearch::default().minimize(&[0.5; 3], |x| {
+            x.iter().zip(&target).map(|(a, b)| (a - b).powi(2)).sum()
+        });
+        for (p, t) in result.params.iter().zip(&target) {
+            assert!((p - t).abs() < 0.01, "{p} vs {t}");
+        }
+    }
+
+    #[test]
+    fn respects_bounds() {
+        // Minimum lies outside the cube; the search must stop at the edge.
+        let result = PatternSearch::default().minimize(&[0.5], |x| (x[0] - 1.5).powi(2));
+        assert_eq!(result.params, vec![1.0]);
+    }
+
+    #[test]
+    fn respects_evaluation_budget() {
+        let search = PatternSearch {
+            max_evaluations: 20,
+            ..PatternSearch::default()
+        };
+        let mut calls = 0;
+        let result = search.minimize(&[0.0; 4], |x| {
+            calls += 1;
+            x.iter().map(|v| (v - 0.3).abs()).sum()
+        });
+        assert_eq!(calls, result.evaluations);
+        assert!(result.evaluations <= 20);
+    }
+
+    #[test]
+    fn empty_parameter_set_evaluates_once() {
+        let result = PatternSearch::default().minimize(&[], |_| 3.0);
+        assert_eq!(result.evaluations, 1);
+        assert_eq!(result.cost, 3.0);
+    }
+}
@@ -0,0 +1,389 @@
+//! Spectral tilt: tonal balance against a pink-noise reference.
+//!
+//! Pink noise carries equal power in every octave, which is why it is the
+//! usual yardstick for tonal balance: a well-balanced mix has a long-term
+//! average spectrum (LTAS) close to pink, tilted slightly darker. A
+//! [`TiltAnalyzer`] accumulates the LTAS of a stereo signal in octave bands
+//! and compares it with a reference slope:
+//!
+//! - **Slope** — least-squares fit of the band levels against octave number,
+//!   in dB per octave relative to pink. `0` is pink, negative is darker
+//!   (white noise reads +3, brown noise −3).
+//! - **Deviation** — each band's level minus the reference line through the
+//!   band levels' average, so positive bands stick out and negative bands are
+//!   missing.
+//!
+//! The reference can itself be tilted (`reference_tilt`), e.g. −1.5 dB/octave
+//! for a typical commercial mix; `0` is pink.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use sonido_analysis::tilt::analyze_tilt;
+//!
+//! // White noise rises 3 dB per octave against pink.
+//! let mut seed = 1_u32;
+//! let noise: Vec<f32> = (0..96000)
+//!     .map(|_| {
+//!         seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+//!         (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+//!     })
+//!     .collect();
+//! let report = analyze_tilt(&noise, &noise, 48000.0, 0.0);
+//! assert!((report.slope_db_per_octave - 3.0).abs() < 0.3);
+//! ```
+
+use crate::fft::{Fft, Window};
+
+/// Octave band centres (ISO 266), 31.5 Hz to 16 kHz.
+pub const OCTAVE_CENTRES_HZ: [f32; 10] = [
+    31.5, 63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0,
+];
+
+/// Deviation in dB beyond which a band is called out as out of balance.
+pub const DEVIATION_THRESHOLD_DB: f32 = 3.0;
+
+/// FFT length for the LTAS (~85 ms at 48 kHz, 11.7 Hz bins).
+const FFT_SIZE: usize = 4096;
+
+/// Bands quieter than this relative to the loudest band are left out of the
+/// fit: a band-limited signal is not "missing" the bands it never had.
+const BAND_FLOOR_DB: f32 = -60.0;
+
+/// Long-term level of one octave band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OctaveLevel {
+    /// Band centre in Hz.
+    pub centre_hz: f32,
+    /// Lower band edge in Hz.
+    pub low_hz: f32,
+    /// Upper band edge in Hz.
+    pub high_hz: f32,
+    /// Average power in the band in dB (a full-scale sine reads -3 dB).
+    pub level_db: f32,
+    /// Level of the reference line at this band in dB.
+    pub reference_db: f32,
+    /// `level_db - reference_db`.
+    pub deviation_db: f32,
+}
+
+/// Result of a spectral tilt analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiltReport {
+    /// Bands with signal, low to high.
+    pub bands: Vec<OctaveLevel>,
+    /// Fitted slope of the band levels in dB per octave; `0` is pink.
+    pub slope_db_per_octave: f32,
+    /// Slope of the reference line in dB per octave; `0` is pink.
+    pub reference_tilt: f32,
+}
+
+impl TiltReport {
+    /// How much brighter (positive) or darker than the reference the signal
+    /// is, in dB per octave.
+    pub fn tilt_vs_reference(&self) -> f32 {
+        self.slope_db_per_octave - self.reference_tilt
+    }
+
+    /// Bands deviating from the reference by more than `threshold_db` either way.
+    pub fn outliers(&self, threshold_db: f32) -> impl Iterator<Item = &OctaveLevel> {
+        self.bands
+            .iter()
+            .filter(move |b| b.deviation_db.abs() > threshold_db)
+    }
+}
+
+/// Streaming long-term average spectrum in octave bands.
+///
+/// Feed stereo audio with [`push_samples`](Self::push_samples); the LTAS
+/// averages the two channels' power, so stereo width does not change it.
+/// Band powers come from Hann-windowed FFT frames with 50% overlap; a
+/// partial trailing frame is not analyzed.
+pub struct TiltAnalyzer {
+    sample_rate: f32,
+    fft: Fft,
+    window: Vec<f32>,
+    /// Unanalyzed input, at most one frame.
+    pending_left: Vec<f32>,
+    pending_right: Vec<f32>,
+    /// FFT bin range of each band below Nyquist.
+    band_bins: Vec<(usize, usize)>,
+    /// Σ over frames of Σ over band bins of (|L|² + |R|²) / 2.
+    band_power: Vec<f64>,
+    frames: usize,
+}
+
+impl TiltAnalyzer {
+    /// Create an analyzer for audio at `sample_rate`.
+    pub fn new(sample_rate: f32) -> Self {
+        let bin_hz = sample_rate / FFT_SIZE as f32;
+        let nyquist_bin = FFT_SIZE / 2;
+        let band_bins: Vec<(usize, usize)> = OCTAVE_CENTRES_HZ
+            .iter()
+            .map(|&centre| {
+                let (low, high) = octave_edges(centre);
+                let lo = ((low / bin_hz).ceil() as usize).max(1);
+                let hi = ((high / bin_hz).ceil() as usize).min(nyquist_bin);
+                (lo, hi.max(lo + 1))
+            })
+            .take_while(|&(lo, _)| lo < nyquist_bin)
+            .collect();
+        Self {
+            sample_rate,
+            fft: Fft::new(FFT_SIZE),
+            window: Window::Hann.coefficients(FFT_SIZE),
+            pending_left: Vec::with_capacity(FFT_SIZE),
+            pending_right: Vec::with_capacity(FFT_SIZE),
+            band_power: vec![0.0; band_bins.len()],
+            band_bins,
+            frames: 0,
+        }
+    }
+
+    /// Sample rate this analyzer was created with, in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Feed stereo samples. `right` must be as long as `left`; pass the same
+    /// slice twice for mono.
+    pub fn push_samples(&mut self, left: &[f32], right: &[f32]) {
+        debug_assert_eq!(left.len(), right.len());
+        let mut offset = 0;
+        while offset < left.len() {
+            let take = (FFT_SIZE - self.pending_left.len()).min(left.len() - offset);
+            self.pending_left
+                .extend_from_slice(&left[offset..offset + take]);
+            self.pending_right
+                .extend_from_slice(&right[offset..offset + take]);
+            offset += take;
+            if self.pending_left.len() == FFT_SIZE {
+                self.analyze_frame();
+                // 50% overlap
+                self.pending_left.drain(..FFT_SIZE / 2);
+                self.pending_right.drain(..FFT_SIZE / 2);
+            }
+        }
+    }
+
+    fn analyze_frame(&mut self) {
+        let windowed = |samples: &[f32]| -> Vec<f32> {
+            samples
+                .iter()
+                .zip(&self.window)
+                .map(|(s, w)| s * w)
+                .collect()
+        };
+        let left = self.fft.forward(&windowed(&self.pending_left));
+        let right = self.fft.forward(&windowed(&self.pending_right));
+        for (power, &(lo, hi)) in self.band_power.iter_mut().zip(&self.band_bins) {
+            for bin in lo..hi {
+                *power += (left[bin].norm_sqr() + right[bin].norm_sqr()) as f64 * 0.5;
+            }
+        }
+        self.frames += 1;
+    }
+
+    /// Whether at least one full frame has been analyzed.
+    pub fn has_data(&self) -> bool {
+        self.frames > 0
+    }
+
+    /// Compare everything pushed so far with a reference line of
+    /// `reference_tilt` dB per octave (`0` = pink).
+    pub fn report(&self, reference_tilt: f32) -> TiltReport {
+        // Parseval for a Hann window: one-sided Σ|X|² ≈ N · Σw² / 2 · mean square,
+        // with Σw² = 3N/8.
+        let n = FFT_SIZE as f64;
+        let scale = 2.0 / (n * n * 3.0 / 8.0) / self.frames.max(1) as f64;
+        let levels: Vec<(f32, f32)> = OCTAVE_CENTRES_HZ
+            .iter()
+            .zip(&self.band_power)
+            .map(|(&centre, &power)| {
+                let ms = power * scale;
+                let db = if ms > 0.0 {
+                    (10.0 * ms.log10()) as f32
+                } else {
+                    f32::NEG_INFINITY
+                };
+                (centre, db)
+            })
+            .collect();
+        let loudest = levels
+            .iter()
+            .map(|&(_, db)| db)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let used: Vec<(f32, f32)> = levels
+            .into_iter()
+            .filter(|&(_, db)| db.is_finite() && db > loudest + BAND_FLOOR_DB)
+            .collect();
+
+        // Least-squares fit of level against octave number (log2 f / 1 kHz)
+        let octave = |hz: f32| (hz / 1000.0).log2();
+        let count = used.len() as f32;
+        let slope = if used.len() >= 2 {
+            let mean_x = used.iter().map(|&(f, _)| octave(f)).sum::<f32>() / count;
+            let mean_y = used.iter().map(|&(_, db)| db).sum::<f32>() / count;
+            let (mut sxy, mut sxx) = (0.0, 0.0);
+            for &(f, db) in &used {
+                let dx = octave(f) - mean_x;
+                sxy += dx * (db - mean_y);
+                sxx += dx * dx;
+            }
+            sxy / sxx
+        } else {
+            0.0
+        };
+
+        // Reference line through the average of the band levels
+        let offset = used
+            .iter()
+            .map(|&(f, db)| db - reference_tilt * octave(f))
+            .sum::<f32>()
+            / count.max(1.0);
+        let bands = used
+            .iter()
+            .map(|&(centre, level_db)| {
+                let (low_hz, high_hz) = octave_edges(centre);
+                let reference_db = offset + reference_tilt * octave(centre);
+                OctaveLevel {
+                    centre_hz: centre,
+                    low_hz,
+                    high_hz,
+                    level_db,
+                    reference_db,
+                    deviation_db: level_db - reference_db,
+                }
+            })
+            .collect();
+
+        TiltReport {
+            bands,
+            slope_db_per_octave: slope,
+            reference_tilt,
+        }
+    }
+
+    /// Clear all accumulated state.
+    pub fn reset(&mut self) {
+        self.pending_left.clear();
+        self.pending_right.clear();
+        self.band_power.fill(0.0);
+        self.frames = 0;
+    }
+}
+
+/// Octave band edges around `centre_hz`.
+fn octave_edges(centre_hz: f32) -> (f32, f32) {
+    let half = core::f32::consts::SQRT_2;
+    (centre_hz / half, centre_hz * half)
+}
+
+/// Analyze a complete stereo signal against a reference of `reference_tilt`
+/// dB per octave (`0` = pink).
+pub fn analyze_tilt(
+    left: &[f32],
+    right: &[f32],
+    sample_rate: f32,
+    reference_tilt: f32,
+) -> TiltReport {
+    let mut analyzer = TiltAnalyzer::new(sample_rate);
+    analyzer.push_samples(left, right);
+    analyzer.report(reference_tilt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn white(len: usize) -> Vec<f32> {
+        let mut seed = 7_u32;
+        (0..len)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect()
+    }
+
+    /// One-pole integrator: white noise becomes brown (-6 dB/octave).
+    fn brown(len: usize) -> Vec<f32> {
+        let mut state = 0.0;
+        white(len)
+            .into_iter()
+            .map(|s| {
+                state = state * 0.999 + s * 0.05;
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn white_noise_is_three_db_brighter_than_pink() {
+        let noise = white(96000);
+        let report = analyze_tilt(&noise, &noise, 48000.0, 0.0);
+        assert_eq!(report.bands.len(), OCTAVE_CENTRES_HZ.len());
+        assert!((report.slope_db_per_octave - 3.0).abs() < 0.3);
+        // A white spectrum is a straight line, so the 3 dB/octave reference
+        // leaves nothing standing out.
+        let report = analyze_tilt(&noise, &noise, 48000.0, 3.0);
+        assert!(report.tilt_vs_reference().abs() < 0.3);
+        assert_eq!(report.outliers(DEVIATION_THRESHOLD_DB).count(), 0);
+    }
+
+    #[test]
+    fn brown_noise_is_darker_than_pink() {
+        let noise = brown(96000);
+        let report = analyze_tilt(&noise, &noise, 48000.0, 0.0);
+        // -3 dB/octave above the integrator corner (~8 Hz)
+        assert!(
+            (report.slope_db_per_octave + 3.0).abs() < 0.5,
+            "slope = {}",
+            report.slope_db_per_octave
+        );
+        assert!(report.tilt_vs_reference() < 0.0);
+    }
+
+    #[test]
+    fn full_scale_sine_reads_minus_three_db() {
+        let sine: Vec<f32> = (0..48000)
+            .map(|i| (std::f32::consts::TAU * 1000.0 * i as f32 / 48000.0).sin())
+            .collect();
+        let report = analyze_tilt(&sine, &sine, 48000.0, 0.0);
+        // Only the 1 kHz band has signal above the floor.
+        assert_eq!(report.bands.len(), 1);
+        let band = report.bands[0];
+        assert_eq!(band.centre_hz, 1000.0);
+        assert!(
+            (band.level_db + 3.0).abs() < 0.2,
+            "level = {}",
+            band.level_db
+        );
+    }
+
+    #[test]
+    fn prominent_band_is_an_outlier() {
+        // White noise with a 1 kHz tone on top: the 1 kHz band sticks out.
+        let noise = white(96000);
+        let mixed: Vec<f32> = noise
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s * 0.05 + (std::f32::consts::TAU * 1000.0 * i as f32 / 48000.0).sin())
+            .collect();
+        let report = analyze_tilt(&mixed, &mixed, 48000.0, 3.0);
+        let outliers: Vec<_> = report.outliers(DEVIATION_THRESHOLD_DB).collect();
+        assert!(
+            outliers
+                .iter()
+                .any(|b| b.centre_hz == 1000.0 && b.deviation_db > 10.0)
+        );
+    }
+
+    #[test]
+    fn silence_reports_no_bands() {
+        let silence = vec![0.0; 48000];
+        let report = analyze_tilt(&silence, &silence, 48000.0, 0.0);
+        assert!(report.bands.is_empty());
+        assert_eq!(report.slope_db_per_octave, 0.0);
+    }
+}
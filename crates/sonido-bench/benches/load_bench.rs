@@ -0,0 +1,101 @@
+//! Criterion benchmarks for every registered effect, the default chain, and
+//! graph compilation.
+//!
+//! - **effect/<id>** — each registered effect at 48 kHz across block sizes
+//! - **default_chain/<rate>** — the default chain as a linear graph across
+//!   sample rates and block sizes (DSP plus scheduler overhead)
+//! - **schedule** — compiling the default chain graph
+//!
+//! Run with: `cargo bench -p sonido-bench`, or filter, e.g.
+//! `cargo bench -p sonido-bench -- effect/reverb`
+#![allow(missing_docs)]
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use sonido_bench::{BLOCK_SIZES, Processor, SAMPLE_RATES, Target, test_signal};
+use sonido_core::graph::ProcessingGraph;
+use sonido_registry::EffectRegistry;
+
+const SAMPLE_RATE: f32 = 48000.0;
+
+fn bench_target(c: &mut Criterion, group_name: &str, target: &Target, sample_rate: f32) {
+    let registry = EffectRegistry::new();
+    let mut group = c.benchmark_group(group_name);
+
+    for &block_size in BLOCK_SIZES {
+        let mut processor = Processor::new(&registry, target, sample_rate, block_size)
+            .expect("registered target builds");
+        let (left, right) = test_signal(sample_rate, block_size);
+        let mut left_out = vec![0.0; block_size];
+        let mut right_out = vec![0.0; block_size];
+
+        group.throughput(Throughput::Elements(block_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(block_size),
+            &block_size,
+            |b, _| {
+                b.iter(|| {
+                    processor.process_block(
+                        black_box(&left),
+                        black_box(&right),
+                        &mut left_out,
+                        &mut right_out,
+                    );
+                    black_box(left_out[0])
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_effects(c: &mut Criterion) {
+    let registry = EffectRegistry::new();
+    for target in Target::all(&registry) {
+        if let Target::Effect(id) = target {
+            bench_target(c, &format!("effect/{id}"), &target, SAMPLE_RATE);
+        }
+    }
+}
+
+fn bench_default_chain(c: &mut Criterion) {
+    for &sample_rate in SAMPLE_RATES {
+        bench_target(
+            c,
+            &format!("default_chain/{}", sample_rate as u32),
+            &Target::DefaultChain,
+            sample_rate,
+        );
+    }
+}
+
+fn bench_schedule(c: &mut Criterion) {
+    let registry = EffectRegistry::new();
+    let ids = registry.default_chain_ids();
+
+    c.bench_function("schedule/default_chain_compile", |b| {
+        b.iter_batched(
+            || {
+                let effects = ids
+                    .iter()
+                    .map(|id| registry.create(id, SAMPLE_RATE).expect("registered"))
+                    .collect::<Vec<_>>();
+                let mut graph = ProcessingGraph::new(SAMPLE_RATE, 256);
+                let mut prev = graph.add_input();
+                for effect in effects {
+                    let node = graph.add_effect(effect);
+                    graph.connect(prev, node).expect("connect");
+                    prev = node;
+                }
+                let output = graph.add_output();
+                graph.connect(prev, output).expect("connect");
+                graph
+            },
+            |mut graph| black_box(graph.compile().expect("compile")),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_effects, bench_default_chain, bench_schedule);
+criterion_main!(benches);
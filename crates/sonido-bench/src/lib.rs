@@ -0,0 +1,327 @@
+//! DSP load measurement for Sonido.
+//!
+//! Measures how fast registered effects and the default effect chain process
+//! audio, expressed as a **realtime factor** (RTF): seconds of audio processed
+//! per second of wall-clock time on one core. An RTF of 50× means the target
+//! uses about 2% of a core when running live. `sonido bench` prints these
+//! numbers so users can size a rig; the criterion benches in `benches/` cover
+//! the same targets plus graph compilation for regression tracking.
+//!
+//! # Targets
+//!
+//! - [`Target::Effect`] — one registered effect, processed directly
+//! - [`Target::DefaultChain`] — the registry's default chain as a linear
+//!   [`ProcessingGraph`], so scheduler overhead is included
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_bench::{Target, measure};
+//! use sonido_registry::EffectRegistry;
+//!
+//! let registry = EffectRegistry::new();
+//! let m = measure(&registry, &Target::Effect("reverb"), 48000.0, 256, 0.05).unwrap();
+//! println!("reverb: {:.0}x realtime", m.realtime_factor());
+//! ```
+
+use std::f32::consts::TAU;
+use std::time::{Duration, Instant};
+
+use sonido_core::EffectWithParams;
+use sonido_core::graph::{GraphError, ProcessingGraph};
+use sonido_registry::EffectRegistry;
+
+/// Sample rates measured by default.
+pub const SAMPLE_RATES: &[f32] = &[44_100.0, 48_000.0, 96_000.0];
+
+/// Block sizes measured by default.
+pub const BLOCK_SIZES: &[usize] = &[64, 256, 1024];
+
+/// Seconds of audio processed per measurement by default.
+pub const DEFAULT_DURATION_SECS: f64 = 2.0;
+
+/// Audio processed before timing starts, so buffers are allocated, caches
+/// are warm, and smoothed parameters have settled.
+const WARMUP_SECS: f64 = 0.1;
+
+/// Display name of [`Target::DefaultChain`].
+pub const DEFAULT_CHAIN_NAME: &str = "default-chain";
+
+/// Errors from building a measurement target.
+#[derive(Debug, thiserror::Error)]
+pub enum BenchError {
+    /// No registered effect has this ID.
+    #[error("unknown effect: {0}")]
+    UnknownEffect(String),
+    /// The default chain graph failed to build.
+    #[error("graph error: {0}")]
+    Graph(#[from] GraphError),
+}
+
+/// What to measure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// A single registered effect, by registry ID.
+    Effect(&'static str),
+    /// The registry's default chain as a linear processing graph.
+    DefaultChain,
+}
+
+impl Target {
+    /// Every registered effect in registry order, then the default chain.
+    pub fn all(registry: &EffectRegistry) -> Vec<Self> {
+        registry
+            .all_effects()
+            .into_iter()
+            .map(|d| Self::Effect(d.id))
+            .chain(std::iter::once(Self::DefaultChain))
+            .collect()
+    }
+
+    /// Resolve a name: an effect ID, or [`DEFAULT_CHAIN_NAME`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BenchError::UnknownEffect`] if `name` matches neither.
+    pub fn from_name(registry: &EffectRegistry, name: &str) -> Result<Self, BenchError> {
+        if name == DEFAULT_CHAIN_NAME {
+            return Ok(Self::DefaultChain);
+        }
+        registry
+            .get(name)
+            .map(|d| Self::Effect(d.id))
+            .ok_or_else(|| BenchError::UnknownEffect(name.to_owned()))
+    }
+
+    /// Effect ID, or [`DEFAULT_CHAIN_NAME`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Effect(id) => id,
+            Self::DefaultChain => DEFAULT_CHAIN_NAME,
+        }
+    }
+}
+
+/// A ready-to-run measurement target.
+pub enum Processor {
+    /// A single effect.
+    Effect(Box<dyn EffectWithParams + Send>),
+    /// A compiled graph.
+    Graph(Box<ProcessingGraph>),
+}
+
+impl Processor {
+    /// Build `target` at `sample_rate`, sized for `block_size` blocks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BenchError`] if the effect is unknown or the graph fails to
+    /// compile.
+    pub fn new(
+        registry: &EffectRegistry,
+        target: &Target,
+        sample_rate: f32,
+        block_size: usize,
+    ) -> Result<Self, BenchError> {
+        let create = |id: &str| {
+            registry
+                .create(id, sample_rate)
+                .ok_or_else(|| BenchError::UnknownEffect(id.to_owned()))
+        };
+        match target {
+            Target::Effect(id) => Ok(Self::Effect(create(id)?)),
+            Target::DefaultChain => {
+                let effects = registry
+                    .default_chain_ids()
+                    .iter()
+                    .map(|id| create(id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Self::Graph(Box::new(ProcessingGraph::linear(
+                    effects,
+                    sample_rate,
+                    block_size,
+                )?)))
+            }
+        }
+    }
+
+    /// Process one stereo block.
+    pub fn process_block(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        match self {
+            Self::Effect(effect) => {
+                effect.process_block_stereo(left_in, right_in, left_out, right_out);
+            }
+            Self::Graph(graph) => graph.process_block(left_in, right_in, left_out, right_out),
+        }
+    }
+}
+
+/// Stereo test signal: a decaying, slightly detuned harmonic tone that
+/// retriggers every half second, so dynamics and gates stay active.
+pub fn test_signal(sample_rate: f32, len: usize) -> (Vec<f32>, Vec<f32>) {
+    let period = (sample_rate * 0.5) as usize;
+    let tone = |i: usize, freq: f32| {
+        let t = (i % period.max(1)) as f32 / sample_rate;
+        let envelope = (-4.0 * t).exp();
+        let phase = TAU * freq * t;
+        envelope * 0.5 * (phase.sin() + 0.5 * (2.0 * phase).sin() + 0.25 * (3.0 * phase).sin())
+    };
+    let left = (0..len).map(|i| tone(i, 110.0)).collect();
+    let right = (0..len).map(|i| tone(i, 110.5)).collect();
+    (left, right)
+}
+
+/// One timing result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// Effect ID or [`DEFAULT_CHAIN_NAME`].
+    pub target: &'static str,
+    /// Sample rate in Hz.
+    pub sample_rate: f32,
+    /// Block size in samples.
+    pub block_size: usize,
+    /// Seconds of audio processed while timing.
+    pub audio_secs: f64,
+    /// Wall-clock time spent processing it.
+    pub elapsed: Duration,
+}
+
+impl Measurement {
+    /// Seconds of audio processed per second of processing time.
+    pub fn realtime_factor(&self) -> f64 {
+        self.audio_secs / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    /// Share of one core needed to run live, in percent.
+    pub fn load_percent(&self) -> f64 {
+        100.0 / self.realtime_factor()
+    }
+
+    /// Processing time per stereo sample frame, in nanoseconds.
+    pub fn ns_per_frame(&self) -> f64 {
+        self.elapsed.as_secs_f64() * 1e9 / (self.audio_secs * f64::from(self.sample_rate))
+    }
+}
+
+/// Time `target` processing `audio_secs` of the [`test_signal`] in
+/// `block_size` blocks, after a short warm-up.
+///
+/// # Errors
+///
+/// Returns [`BenchError`] if the target cannot be built.
+pub fn measure(
+    registry: &EffectRegistry,
+    target: &Target,
+    sample_rate: f32,
+    block_size: usize,
+    audio_secs: f64,
+) -> Result<Measurement, BenchError> {
+    let mut processor = Processor::new(registry, target, sample_rate, block_size)?;
+    let block_size = block_size.max(1);
+    let blocks_for = |secs: f64| ((secs * f64::from(sample_rate)) as usize).div_ceil(block_size);
+
+    // One second of signal, looped
+    let (left, right) = test_signal(sample_rate, (sample_rate as usize).max(block_size));
+    let input_blocks = left.len() / block_size;
+    let mut left_out = vec![0.0; block_size];
+    let mut right_out = vec![0.0; block_size];
+    let mut run = |blocks: usize, processor: &mut Processor| {
+        for b in 0..blocks {
+            let start = (b % input_blocks) * block_size;
+            let range = start..start + block_size;
+            processor.process_block(
+                &left[range.clone()],
+                &right[range],
+                &mut left_out,
+                &mut right_out,
+            );
+        }
+        std::hint::black_box((left_out[0], right_out[0]));
+    };
+
+    run(blocks_for(WARMUP_SECS), &mut processor);
+    let blocks = blocks_for(audio_secs).max(1);
+    let start = Instant::now();
+    run(blocks, &mut processor);
+    let elapsed = start.elapsed();
+
+    Ok(Measurement {
+        target: target.name(),
+        sample_rate,
+        block_size,
+        audio_secs: (blocks * block_size) as f64 / f64::from(sample_rate),
+        elapsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_registered_effect_measures() {
+        let registry = EffectRegistry::new();
+        let targets = Target::all(&registry);
+        assert_eq!(targets.len(), registry.len() + 1);
+
+        for target in &targets {
+            let m = measure(&registry, target, 48000.0, 64, 0.01).unwrap();
+            assert_eq!(m.target, target.name());
+            assert!(
+                m.realtime_factor().is_finite() && m.realtime_factor() > 0.0,
+                "{}: rtf {}",
+                m.target,
+                m.realtime_factor()
+            );
+            assert!(m.audio_secs >= 0.01);
+        }
+    }
+
+    #[test]
+    fn target_names_round_trip() {
+        let registry = EffectRegistry::new();
+        for target in Target::all(&registry) {
+            assert_eq!(Target::from_name(&registry, target.name()).unwrap(), target);
+        }
+        assert!(matches!(
+            Target::from_name(&registry, "no-such-effect"),
+            Err(BenchError::UnknownEffect(_))
+        ));
+    }
+
+    #[test]
+    fn measurement_math() {
+        let m = Measurement {
+            target: "gain",
+            sample_rate: 48000.0,
+            block_size: 256,
+            audio_secs: 2.0,
+            elapsed: Duration::from_millis(20),
+        };
+        assert!((m.realtime_factor() - 100.0).abs() < 1e-9);
+        assert!((m.load_percent() - 1.0).abs() < 1e-9);
+        assert!((m.ns_per_frame() - 20e6 / 96000.0).abs() < 1e-6);
+    }
+
+    /// DSP load regression check: the whole default chain must leave most of
+    /// a core free at a typical live setting. Timing is only meaningful in
+    /// optimized builds.
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "run with `cargo test --release`")]
+    fn default_chain_runs_well_under_realtime() {
+        let registry = EffectRegistry::new();
+        let m = measure(&registry, &Target::DefaultChain, 48000.0, 256, 1.0).unwrap();
+        assert!(
+            m.realtime_factor() > 2.0,
+            "default chain at {:.1}x realtime ({:.0}% load)",
+            m.realtime_factor(),
+            m.load_percent()
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Blind ABX listening test for `sonido compare --abx`.
+//!
+//! Each trial picks a random segment of the two (loudness-matched) files and
+//! secretly assigns X to A or B. The segment loops through the output device
+//! while the listener switches sources by typing a command letter followed
+//! by Enter:
+//!
+//! | Command | Action |
+//! |---------|--------|
+//! | `a` / `b` / `x` | Loop that source over the current segment |
+//! | `s` | Stop playback |
+//! | `1` | Answer "X is A" |
+//! | `2` | Answer "X is B" |
+//! | `q` | End the test early |
+//!
+//! Switching sources restarts the segment so the listener compares the same
+//! material. The score is reported with the one-sided binomial probability of
+//! getting at least that many answers right by guessing.
+
+use sonido_core::graph::StereoSamples;
+use sonido_io::{AudioStream, StreamConfig};
+use std::io::BufRead;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Fade length at the segment edges, in seconds.
+const FADE_SECONDS: f32 = 0.01;
+
+/// ABX test settings.
+pub struct AbxConfig {
+    /// Number of trials.
+    pub trials: usize,
+    /// Segment length in seconds.
+    pub segment_seconds: f32,
+    /// Output device (index, exact name, or partial name).
+    pub device: Option<String>,
+    /// Sample rate shared by both files, in Hz.
+    pub sample_rate: u32,
+}
+
+/// One of the two files under test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    A,
+    B,
+}
+
+/// A parsed listener command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Play A or B.
+    Play(Source),
+    /// Play the hidden X.
+    PlayX,
+    Stop,
+    /// Answer which source X is.
+    Answer(Source),
+    Quit,
+}
+
+/// Parse one line of listener input.
+fn parse_command(line: &str) -> Option<Command> {
+    match line.trim().to_ascii_lowercase().as_str() {
+        "a" => Some(Command::Play(Source::A)),
+        "b" => Some(Command::Play(Source::B)),
+        "x" => Some(Command::PlayX),
+        "s" => Some(Command::Stop),
+        "1" => Some(Command::Answer(Source::A)),
+        "2" => Some(Command::Answer(Source::B)),
+        "q" => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+/// XorShift64 generator for trial randomization.
+struct Rng(u64);
+
+impl Rng {
+    /// Seed from the system clock.
+    fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self(nanos | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform value in `0..n` (`n` > 0).
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Random segment start so `start + segment_len <= len`.
+fn pick_segment(rng: &mut Rng, len: usize, segment_len: usize) -> usize {
+    rng.below(len.saturating_sub(segment_len) + 1)
+}
+
+/// One-sided binomial p-value: probability of at least `correct` right
+/// answers out of `trials` by guessing.
+fn guess_probability(correct: usize, trials: usize) -> f64 {
+    if trials == 0 {
+        return 1.0;
+    }
+    let mut binomial = 1.0f64; // C(trials, 0)
+    let mut tail = 0.0f64;
+    for k in 0..=trials {
+        if k > 0 {
+            binomial *= (trials - k + 1) as f64 / k as f64;
+        }
+        if k >= correct {
+            tail += binomial;
+        }
+    }
+    tail / 2f64.powi(trials as i32)
+}
+
+/// Segment playback state shared with the audio callback.
+struct Playback {
+    /// 0 = silent, 1 = A, 2 = B.
+    source: AtomicUsize,
+    /// Segment start in frames.
+    start: AtomicUsize,
+    /// Playhead offset within the segment.
+    offset: AtomicUsize,
+}
+
+impl Playback {
+    fn play(&self, source: Option<Source>) {
+        self.offset.store(0, Ordering::Relaxed);
+        let id = match source {
+            None => 0,
+            Some(Source::A) => 1,
+            Some(Source::B) => 2,
+        };
+        self.source.store(id, Ordering::Release);
+    }
+}
+
+/// Run the interactive ABX test. Blocks until all trials are answered or
+/// the listener quits.
+pub fn run(a: StereoSamples, b: StereoSamples, config: AbxConfig) -> anyhow::Result<()> {
+    let len = a.len().min(b.len());
+    let segment_len = ((config.segment_seconds * config.sample_rate as f32) as usize).min(len);
+    if segment_len == 0 || config.trials == 0 {
+        anyhow::bail!("ABX needs at least one trial and a non-empty segment");
+    }
+    let fade_len =
+        ((FADE_SECONDS * config.sample_rate as f32) as usize).clamp(1, segment_len / 2 + 1);
+
+    let playback = Arc::new(Playback {
+        source: AtomicUsize::new(0),
+        start: AtomicUsize::new(0),
+        offset: AtomicUsize::new(0),
+    });
+
+    let mut stream = AudioStream::new(StreamConfig {
+        sample_rate: config.sample_rate,
+        buffer_size: 1024,
+        input_device: None,
+        output_device: config.device,
+    })?;
+    let channels = stream.output_channels() as usize;
+    let running = stream.running_handle();
+
+    println!(
+        "ABX test: {} trials, {:.1}s segments",
+        config.trials, config.segment_seconds
+    );
+    println!("  a / b / x  play source     s  stop");
+    println!("  1  X is A    2  X is B     q  quit");
+    println!("(type a command and press Enter)\n");
+
+    let listener_playback = Arc::clone(&playback);
+    let trials = config.trials;
+    let listener = std::thread::spawn(move || {
+        let result = listen(&listener_playback, trials, len, segment_len);
+        running.store(false, Ordering::SeqCst);
+        result
+    });
+
+    let cb_playback = Arc::clone(&playback);
+    stream.run_output(move |data: &mut [f32]| {
+        let source = cb_playback.source.load(Ordering::Acquire);
+        let samples = match source {
+            1 => &a,
+            2 => &b,
+            _ => {
+                data.fill(0.0);
+                return;
+            }
+        };
+        let start = cb_playback.start.load(Ordering::Relaxed);
+        let mut offset = cb_playback.offset.load(Ordering::Relaxed);
+
+        for frame in data.chunks_mut(channels) {
+            // Fade in/out at the segment edges so the loop point is click-free
+            let edge = offset.min(segment_len - 1 - offset);
+            let gain = (edge as f32 / fade_len as f32).min(1.0);
+            let l = samples.left[start + offset] * gain;
+            let r = samples.right[start + offset] * gain;
+            if let [left, right, rest @ ..] = frame {
+                *left = l;
+                *right = r;
+                rest.fill(0.0);
+            } else {
+                frame[0] = (l + r) * 0.5;
+            }
+            offset = (offset + 1) % segment_len;
+        }
+
+        cb_playback.offset.store(offset, Ordering::Relaxed);
+    })?;
+
+    let (correct, answered) = listener
+        .join()
+        .map_err(|_| anyhow::anyhow!("ABX input thread panicked"))?;
+
+    println!("\nABX Result");
+    println!("----------");
+    println!("  Correct: {correct}/{answered}");
+    if answered > 0 {
+        let p = guess_probability(correct, answered);
+        println!("  Probability of guessing: {:.1}%", p * 100.0);
+        println!(
+            "  {}",
+            if p < 0.05 {
+                "The difference is audible (p < 0.05)."
+            } else {
+                "No reliable audible difference (p >= 0.05)."
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Read listener commands from stdin and run the trials.
+///
+/// Returns `(correct, answered)`.
+fn listen(playback: &Playback, trials: usize, len: usize, segment_len: usize) -> (usize, usize) {
+    let mut rng = Rng::from_time();
+    let mut lines = std::io::stdin().lock().lines();
+    let mut correct = 0;
+
+    for trial in 0..trials {
+        let x = if rng.below(2) == 0 {
+            Source::A
+        } else {
+            Source::B
+        };
+        playback.play(None);
+        playback
+            .start
+            .store(pick_segment(&mut rng, len, segment_len), Ordering::Relaxed);
+        println!("Trial {}/{}", trial + 1, trials);
+
+        loop {
+            let Some(Ok(line)) = lines.next() else {
+                playback.play(None);
+                return (correct, trial);
+            };
+            match parse_command(&line) {
+                Some(Command::Play(source)) => playback.play(Some(source)),
+                Some(Command::PlayX) => playback.play(Some(x)),
+                Some(Command::Stop) => playback.play(None),
+                Some(Command::Answer(answer)) => {
+                    if answer == x {
+                        correct += 1;
+                    }
+                    break;
+                }
+                Some(Command::Quit) => {
+                    playback.play(None);
+                    return (correct, trial);
+                }
+                None => println!("  commands: a b x s 1 2 q"),
+            }
+        }
+    }
+
+    playback.play(None);
+    (correct, trials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse_command(" A\n"), Some(Command::Play(Source::A)));
+        assert_eq!(parse_command("x"), Some(Command::PlayX));
+        assert_eq!(parse_command("2"), Some(Command::Answer(Source::B)));
+        assert_eq!(parse_command("q"), Some(Command::Quit));
+        assert_eq!(parse_command("ab"), None);
+    }
+
+    #[test]
+    fn guess_probability_matches_binomial() {
+        assert_eq!(guess_probability(0, 10), 1.0);
+        // 10/10 by chance: 1/1024
+        assert!((guess_probability(10, 10) - 1.0 / 1024.0).abs() < 1e-12);
+        // >= 9/10: 11/1024
+        assert!((guess_probability(9, 10) - 11.0 / 1024.0).abs() < 1e-12);
+        assert_eq!(guess_probability(0, 0), 1.0);
+    }
+
+    #[test]
+    fn segments_stay_in_bounds() {
+        let mut rng = Rng(0x1234_5678);
+        for _ in 0..1000 {
+            let start = pick_segment(&mut rng, 1000, 300);
+            assert!(start + 300 <= 1000);
+        }
+        assert_eq!(pick_segment(&mut rng, 300, 300), 0);
+    }
+}
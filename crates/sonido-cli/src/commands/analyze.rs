@@ -2,13 +2,19 @@
 
 use clap::{Args, Subcommand};
 use rustfft::num_complex::Complex;
-use sonido_analysis::export::{export_distortion_json, export_frd, export_spectrogram_csv};
-use sonido_analysis::{Chromagram, ConstantQTransform, CqtSpectrogram, ImdAnalyzer};
+use sonido_analysis::export::{
+    WaterfallStyle, export_csd_csv, export_csd_png, export_distortion_json, export_frd,
+    export_spectrogram_csv,
+};
+use sonido_analysis::monocheck::{MONO_LOSS_THRESHOLD_DB, MonoReport, analyze_mono, probe_effect};
+use sonido_analysis::tilt::{DEVIATION_THRESHOLD_DB, analyze_tilt};
+use sonido_analysis::{Chromagram, ConstantQTransform, CqtSpectrogram, CsdAnalyzer, ImdAnalyzer};
 use sonido_analysis::{
     Comodulogram, FilterBank, FrequencyBand, HilbertTransform, PacAnalyzer, PacMethod,
 };
 use sonido_analysis::{Fft, StftAnalyzer, ThdAnalyzer, TransferFunction, Window, welch_psd};
-use sonido_io::{WavSpec, read_wav, write_wav};
+use sonido_graph_dsl::{create_effect_with_params, parse_effect_spec};
+use sonido_io::{WavSpec, read_wav, read_wav_stereo, write_wav};
 use std::path::PathBuf;
 
 #[derive(Args)]
@@ -160,6 +166,57 @@ enum AnalyzeCommand {
         output: PathBuf,
     },
 
+    /// Cumulative spectral decay (waterfall) of an impulse response
+    Csd {
+        /// Impulse response WAV file
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+
+        /// Output file (.png for a waterfall plot, .csv for the raw data)
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Analysis span after the IR peak in milliseconds
+        #[arg(long, default_value = "50")]
+        window_ms: f32,
+
+        /// Number of slices
+        #[arg(long, default_value = "30")]
+        slices: usize,
+
+        /// Time between slices in milliseconds
+        #[arg(long, default_value = "1.0")]
+        step_ms: f32,
+
+        /// Rise window at the start of each slice in milliseconds
+        #[arg(long, default_value = "0.5")]
+        rise_ms: f32,
+
+        /// FFT size (defaults to the next power of two of the window, min 4096)
+        #[arg(long)]
+        fft_size: Option<usize>,
+
+        /// Displayed dynamic range in dB (PNG only)
+        #[arg(long, default_value = "40")]
+        range: f32,
+
+        /// Lowest displayed frequency in Hz (PNG only)
+        #[arg(long, default_value = "20")]
+        min_freq: f32,
+
+        /// Highest displayed frequency in Hz (PNG only)
+        #[arg(long, default_value = "20000")]
+        max_freq: f32,
+
+        /// Image width in pixels (PNG only)
+        #[arg(long, default_value = "1200")]
+        width: usize,
+
+        /// Image height in pixels (PNG only)
+        #[arg(long, default_value = "700")]
+        height: usize,
+    },
+
     /// Analyze dynamics (RMS, crest factor, dynamic range)
     Dynamics {
         /// Input WAV file
@@ -167,6 +224,47 @@ enum AnalyzeCommand {
         input: PathBuf,
     },
 
+    /// Measure ITU-R BS.1770 loudness (integrated/momentary/short-term LUFS, true peak)
+    Loudness {
+        /// Input WAV file
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+
+        /// Target loudness in LUFS; prints the gain needed to reach it
+        #[arg(long, allow_hyphen_values = true)]
+        target: Option<f32>,
+    },
+
+    /// Check mono compatibility: correlation over time, bands that cancel
+    /// when summed to mono, and effects that cause it
+    Monocheck {
+        /// Input WAV file (stereo)
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+
+        /// Effect chain to probe one effect at a time on mono noise, e.g.
+        /// "chorus|stereo_widener:haas=12"
+        #[arg(long, value_name = "CHAIN")]
+        chain: Option<String>,
+
+        /// Band loss in dB above which summing to mono counts as cancelling
+        #[arg(long, default_value_t = MONO_LOSS_THRESHOLD_DB)]
+        threshold: f32,
+    },
+
+    /// Compare the long-term average spectrum with a pink-noise reference,
+    /// per octave band
+    Tilt {
+        /// Input WAV file
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+
+        /// Reference slope in dB per octave relative to pink (0 = pink noise;
+        /// about -1.5 for a typical mix)
+        #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+        reference: f32,
+    },
+
     /// Analyze Phase-Amplitude Coupling (PAC) between frequency bands
     Pac {
         /// Input WAV file
@@ -331,6 +429,9 @@ enum AnalyzeCommand {
 
     /// Compare two audio files (alias for top-level compare command)
     Compare(super::compare::CompareArgs),
+
+    /// Continuously analyze a live input (level, spectrum, THD, loudness)
+    Live(super::live::LiveArgs),
 }
 
 pub fn run(args: AnalyzeArgs) -> anyhow::Result<()> {
@@ -788,6 +889,85 @@ pub fn run(args: AnalyzeArgs) -> anyhow::Result<()> {
             println!("\nWrote spectrogram to {}", output.display());
         }
 
+        AnalyzeCommand::Csd {
+            input,
+            output,
+            window_ms,
+            slices,
+            step_ms,
+            rise_ms,
+            fft_size,
+            range,
+            min_freq,
+            max_freq,
+            width,
+            height,
+        } => {
+            println!(
+                "Computing cumulative spectral decay of {}...",
+                input.display()
+            );
+
+            let (ir, spec) = read_wav(&input)?;
+            let sample_rate = spec.sample_rate as f32;
+
+            println!(
+                "  {} samples, {} Hz, {:.2}s",
+                ir.len(),
+                spec.sample_rate,
+                ir.len() as f32 / sample_rate
+            );
+
+            let mut analyzer =
+                CsdAnalyzer::new(sample_rate, window_ms / 1000.0, slices, step_ms / 1000.0)
+                    .with_rise_time(rise_ms / 1000.0);
+            if let Some(size) = fft_size {
+                analyzer = analyzer.with_fft_size(size);
+            }
+            let csd = analyzer.analyze(&ir);
+            let Some(&last_time) = csd.times.last() else {
+                anyhow::bail!("Impulse response is empty");
+            };
+
+            println!(
+                "\n  CSD: {} slices over {:.1} ms",
+                csd.times.len(),
+                last_time * 1000.0
+            );
+            if csd.times.len() < slices {
+                println!("  (IR ends before the requested {} slices)", slices);
+            }
+            println!(
+                "  Frequency resolution: {:.1} Hz",
+                csd.frequencies.get(1).copied().unwrap_or(0.0)
+            );
+
+            let ringing = csd.ringing_frequencies(last_time, min_freq, max_freq, 5);
+            if !ringing.is_empty() {
+                println!("\n  Still ringing at {:.1} ms:", last_time * 1000.0);
+                for (freq, level) in ringing {
+                    println!("    {:>8.1} Hz: {:6.1} dB", freq, level);
+                }
+            }
+
+            let is_png = output
+                .extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case("png"));
+            if is_png {
+                let style = WaterfallStyle {
+                    width,
+                    height,
+                    db_range: range,
+                    min_freq,
+                    max_freq,
+                };
+                export_csd_png(&csd, &output, &style)?;
+            } else {
+                export_csd_csv(&csd, &output)?;
+            }
+            println!("\nWrote CSD to {}", output.display());
+        }
+
         AnalyzeCommand::Dynamics { input } => {
             println!("Analyzing dynamics of {}...", input.display());
 
@@ -819,6 +999,154 @@ pub fn run(args: AnalyzeArgs) -> anyhow::Result<()> {
             println!("  Headroom:       {:.1} dB", -peak);
         }
 
+        AnalyzeCommand::Loudness { input, target } => {
+            println!("Measuring loudness of {}...", input.display());
+
+            let (samples, spec) = read_wav_stereo(&input)?;
+            let sample_rate = spec.sample_rate as f32;
+
+            println!(
+                "  {} samples, {} Hz, {} channel(s), {:.2}s",
+                samples.len(),
+                spec.sample_rate,
+                spec.channels,
+                samples.len() as f32 / sample_rate
+            );
+
+            let right = (spec.channels > 1).then_some(samples.right.as_slice());
+            let loudness = sonido_analysis::measure_loudness(&samples.left, right, sample_rate);
+
+            println!("\nLoudness (ITU-R BS.1770):");
+            println!(
+                "  Integrated:       {} LUFS",
+                format_lufs(loudness.integrated)
+            );
+            println!(
+                "  Max momentary:    {} LUFS",
+                format_lufs(loudness.max_momentary)
+            );
+            println!(
+                "  Max short-term:   {} LUFS",
+                format_lufs(loudness.max_short_term)
+            );
+            println!("  True peak:        {:.1} dBTP", loudness.true_peak_dbtp);
+
+            if let Some(target) = target {
+                if loudness.integrated.is_finite() {
+                    let gain = target - loudness.integrated;
+                    println!(
+                        "  Gain to {:.1} LUFS: {:+.1} dB (true peak after gain {:.1} dBTP)",
+                        target,
+                        gain,
+                        loudness.true_peak_dbtp + gain
+                    );
+                } else {
+                    println!("  Gain to {target:.1} LUFS: n/a (program is silent or too short)");
+                }
+            }
+        }
+
+        AnalyzeCommand::Monocheck {
+            input,
+            chain,
+            threshold,
+        } => {
+            println!("Checking mono compatibility of {}...", input.display());
+
+            let (samples, spec) = read_wav_stereo(&input)?;
+            let sample_rate = spec.sample_rate as f32;
+            if spec.channels < 2 {
+                println!("  Mono file: nothing can cancel when summed.");
+            } else {
+                let report = analyze_mono(&samples.left, &samples.right, sample_rate);
+                print_mono_report(&report, threshold);
+            }
+
+            if let Some(chain) = chain {
+                println!("\nEffects (each probed alone on mono noise):");
+                for spec in chain.split('|').map(str::trim).filter(|s| !s.is_empty()) {
+                    let (name, params) = parse_effect_spec(spec)?;
+                    let (mut effect, id) = create_effect_with_params(&name, sample_rate, &params)?;
+                    let probe = probe_effect(effect.as_mut(), sample_rate);
+                    let worst = probe
+                        .worst_band()
+                        .map(|b| format!("worst {:.1} dB at {}", b.loss_db, format_hz(b.centre_hz)))
+                        .unwrap_or_else(|| "silent".to_string());
+                    println!(
+                        "  {:<20} correlation {:+.2}, {}{}",
+                        id,
+                        probe.correlation,
+                        worst,
+                        if probe.is_problematic() {
+                            "  <- cancels in mono"
+                        } else {
+                            ""
+                        }
+                    );
+                }
+            }
+        }
+
+        AnalyzeCommand::Tilt { input, reference } => {
+            println!("Analyzing spectral tilt of {}...", input.display());
+
+            let (samples, spec) = read_wav_stereo(&input)?;
+            let sample_rate = spec.sample_rate as f32;
+            let right = if spec.channels > 1 {
+                &samples.right
+            } else {
+                &samples.left
+            };
+            let report = analyze_tilt(&samples.left, right, sample_rate, reference);
+            if report.bands.is_empty() {
+                println!("  No signal to analyze.");
+                return Ok(());
+            }
+
+            let reference_name = if reference == 0.0 {
+                "pink noise".to_string()
+            } else {
+                format!("pink {reference:+.1} dB/oct")
+            };
+            println!("\nTilt against {reference_name}:");
+            println!(
+                "  Slope:            {:+.1} dB/oct vs pink",
+                report.slope_db_per_octave
+            );
+            let tilt = report.tilt_vs_reference();
+            let verdict = if tilt > 0.5 {
+                "brighter than the reference"
+            } else if tilt < -0.5 {
+                "darker than the reference"
+            } else {
+                "balanced"
+            };
+            println!("  Against reference: {tilt:+.1} dB/oct ({verdict})");
+
+            println!("\n  {:>9}  {:>8}  {:>9}", "Band", "Level", "Deviation");
+            for band in &report.bands {
+                // One '#' per dB, left of the bar for deficits, right for excess
+                let width = band.deviation_db.abs().round().min(12.0) as usize;
+                let bar = if band.deviation_db < 0.0 {
+                    format!("{:>12}|", "#".repeat(width))
+                } else {
+                    format!("{:>12}|{}", "", "#".repeat(width))
+                };
+                println!(
+                    "  {:>9}  {:>5.1} dB  {:>+6.1} dB  {}{}",
+                    format_hz(band.centre_hz),
+                    band.level_db,
+                    band.deviation_db,
+                    bar,
+                    if band.deviation_db.abs() > DEVIATION_THRESHOLD_DB {
+                        "  <-"
+                    } else {
+                        ""
+                    }
+                );
+            }
+        }
+
         AnalyzeCommand::Pac {
             input,
             phase_low,
@@ -1475,6 +1803,10 @@ pub fn run(args: AnalyzeArgs) -> anyhow::Result<()> {
         AnalyzeCommand::Compare(args) => {
             super::compare::run(args)?;
         }
+
+        AnalyzeCommand::Live(args) => {
+            super::live::run(args)?;
+        }
     }
 
     Ok(())
@@ -1514,3 +1846,60 @@ fn rand_simple() -> f64 {
         (SEED as f64) / (u64::MAX as f64)
     }
 }
+
+/// Print the correlation summary and the bands losing more than `threshold` dB.
+fn print_mono_report(report: &MonoReport, threshold: f32) {
+    println!("\nCorrelation:");
+    println!("  Overall:          {:+.2}", report.correlation);
+    println!("  Lowest (100 ms):  {:+.2}", report.min_correlation);
+    let negative = report
+        .timeline
+        .iter()
+        .filter(|p| p.correlation < 0.0)
+        .count();
+    if negative > 0 {
+        let first = report.timeline.iter().find(|p| p.correlation < 0.0);
+        println!(
+            "  Negative in {} of {} segments (first at {:.1}s)",
+            negative,
+            report.timeline.len(),
+            first.map_or(0.0, |p| p.time_secs)
+        );
+    }
+
+    println!("\nMono sum:");
+    println!("  Broadband loss:   {:.1} dB", report.loss_db);
+    let problems: Vec<_> = report.problem_bands(threshold).collect();
+    if problems.is_empty() {
+        println!("  No band loses more than {threshold:.1} dB: mono compatible");
+    } else {
+        println!("  Bands losing more than {threshold:.1} dB:");
+        for band in problems {
+            println!(
+                "    {:>8} ({:.0}-{:.0} Hz)  -{:.1} dB",
+                format_hz(band.centre_hz),
+                band.low_hz,
+                band.high_hz,
+                band.loss_db
+            );
+        }
+    }
+}
+
+/// Format a frequency as `Hz` or `kHz`.
+fn format_hz(hz: f32) -> String {
+    if hz >= 1000.0 {
+        format!("{:.1} kHz", hz / 1000.0)
+    } else {
+        format!("{hz:.0} Hz")
+    }
+}
+
+/// Format a LUFS reading, showing `-inf` for unmeasured/gated programs.
+fn format_lufs(lufs: f32) -> String {
+    if lufs.is_finite() {
+        format!("{lufs:.1}")
+    } else {
+        "-inf".to_string()
+    }
+}
@@ -0,0 +1,116 @@
+//! DSP load benchmark command.
+//!
+//! Times every registered effect and the default chain at a few sample rates
+//! and block sizes, and prints realtime factors so users can size their rigs.
+
+use clap::Args;
+use sonido_bench::{
+    BLOCK_SIZES, DEFAULT_CHAIN_NAME, DEFAULT_DURATION_SECS, Measurement, SAMPLE_RATES, Target,
+    measure,
+};
+use sonido_registry::EffectRegistry;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Effect IDs to measure, or "default-chain" (default: every effect and
+    /// the default chain)
+    #[arg(value_name = "TARGET")]
+    targets: Vec<String>,
+
+    /// Sample rates in Hz, comma-separated
+    #[arg(long, value_delimiter = ',', default_values_t = SAMPLE_RATES.iter().map(|&r| r as u32))]
+    sample_rate: Vec<u32>,
+
+    /// Block sizes in samples, comma-separated
+    #[arg(long, value_delimiter = ',', default_values_t = BLOCK_SIZES.iter().copied())]
+    block_size: Vec<usize>,
+
+    /// Seconds of audio to process per measurement
+    #[arg(long, default_value_t = DEFAULT_DURATION_SECS)]
+    duration: f64,
+
+    /// Fail if any measurement runs slower than this realtime factor
+    #[arg(long, value_name = "RTF")]
+    min_rtf: Option<f64>,
+}
+
+pub fn run(args: BenchArgs) -> anyhow::Result<()> {
+    if args.duration <= 0.0 {
+        anyhow::bail!("--duration must be positive");
+    }
+    if args.sample_rate.contains(&0) || args.block_size.contains(&0) {
+        anyhow::bail!("sample rates and block sizes must be non-zero");
+    }
+
+    let registry = EffectRegistry::new();
+    let targets = if args.targets.is_empty() {
+        Target::all(&registry)
+    } else {
+        args.targets
+            .iter()
+            .map(|name| Target::from_name(&registry, name))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    if cfg!(debug_assertions) {
+        eprintln!("Note: debug build; use a release build for representative numbers.\n");
+    }
+
+    println!(
+        "{:<16} {:>7} {:>6} {:>10} {:>8} {:>10}",
+        "Target", "Rate", "Block", "Realtime", "Load", "ns/frame"
+    );
+    println!("{}", "-".repeat(62));
+
+    let mut slow = Vec::new();
+    for target in &targets {
+        for &rate in &args.sample_rate {
+            for &block_size in &args.block_size {
+                let m = measure(&registry, target, rate as f32, block_size, args.duration)?;
+                print_row(&m);
+                if args.min_rtf.is_some_and(|min| m.realtime_factor() < min) {
+                    slow.push(m);
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("Realtime: seconds of audio processed per second on one core.");
+    println!("Load: share of one core needed to run live.");
+    if targets.contains(&Target::DefaultChain) {
+        println!(
+            "{DEFAULT_CHAIN_NAME}: {} as a linear graph.",
+            registry.default_chain_ids().join(" → ")
+        );
+    }
+
+    if let Some(min) = args.min_rtf
+        && !slow.is_empty()
+    {
+        println!();
+        for m in &slow {
+            println!(
+                "Below {min}x: {} at {} Hz / {} samples ({:.1}x)",
+                m.target,
+                m.sample_rate,
+                m.block_size,
+                m.realtime_factor()
+            );
+        }
+        anyhow::bail!("{} measurement(s) below --min-rtf {min}", slow.len());
+    }
+    Ok(())
+}
+
+fn print_row(m: &Measurement) {
+    println!(
+        "{:<16} {:>7} {:>6} {:>9.1}x {:>7.2}% {:>10.1}",
+        m.target,
+        m.sample_rate,
+        m.block_size,
+        m.realtime_factor(),
+        m.load_percent(),
+        m.ns_per_frame()
+    );
+}
@@ -1,6 +1,10 @@
 //! Shared CLI helpers used across multiple commands.
 
+use crate::graph_dsl::{
+    ManifestEntry, build_graph, build_graph_from_json, parse_graph_dsl, validate_spec,
+};
 use sonido_config::{Preset, find_preset as config_find_preset, get_factory_preset};
+use sonido_core::graph::ProcessingGraph;
 use std::path::PathBuf;
 
 /// Parse a `key=value` string for clap's `value_parser`.
@@ -45,6 +49,35 @@ pub fn load_preset(name: &str) -> anyhow::Result<Preset> {
     )
 }
 
+/// File behind a `--preset` argument, resolved in the same order as
+/// [`load_preset`]. `None` for factory presets, which have no file.
+pub fn preset_file(name: &str) -> Option<PathBuf> {
+    if get_factory_preset(name).is_some() {
+        return None;
+    }
+    config_find_preset(name).or_else(|| {
+        let path = PathBuf::from(name);
+        path.exists().then_some(path)
+    })
+}
+
+/// Build a graph from a `--graph` argument: DSL text, or a path to a `.json`
+/// snapshot written by `sonido process --export`.
+pub fn load_graph(
+    spec: &str,
+    sample_rate: f32,
+    block_size: usize,
+) -> anyhow::Result<(ProcessingGraph, Vec<ManifestEntry>)> {
+    if spec.ends_with(".json") {
+        let json = std::fs::read_to_string(spec)?;
+        Ok(build_graph_from_json(&json, sample_rate, block_size)?)
+    } else {
+        let spec = parse_graph_dsl(spec)?;
+        validate_spec(&spec)?;
+        Ok(build_graph(&spec, sample_rate, block_size)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,12 +1,23 @@
 //! A/B comparison command for reverse engineering.
-
+//!
+//! The implementation is loudness-matched to the reference (BS.1770
+//! integrated loudness) before any metric is computed, so level differences
+//! do not mask or exaggerate tonal ones. `--null` time-aligns the files and
+//! reports what is left after subtracting them; `--diff-spectrogram` writes
+//! where in time and frequency the aligned files diverge; `--abx` runs a
+//! blind listening test instead (see [`super::abx`]).
+
+use super::abx::{self, AbxConfig};
 use clap::Args;
 use sonido_analysis::compare::{mse, rmse, snr_db};
-use sonido_analysis::dynamics;
-use sonido_analysis::{Fft, Window, spectral_correlation, spectral_difference};
-use sonido_core::linear_to_db;
-use sonido_io::read_wav;
-use std::path::PathBuf;
+use sonido_analysis::export::{HeatmapStyle, export_difference_csv, export_difference_png};
+use sonido_analysis::{
+    Alignment, Fft, LoudnessSummary, Window, align, difference_spectrogram, dynamics,
+    measure_loudness, null_difference, spectral_correlation, spectral_difference,
+};
+use sonido_core::{db_to_linear, linear_to_db};
+use sonido_io::{WavSpec, read_wav, read_wav_stereo, write_wav};
+use std::path::{Path, PathBuf};
 
 #[derive(Args)]
 pub struct CompareArgs {
@@ -29,18 +40,262 @@ pub struct CompareArgs {
     /// Show detailed frequency band analysis
     #[arg(long)]
     detailed: bool,
+
+    /// Compare at the files' own levels instead of matching loudness
+    #[arg(long)]
+    no_loudness_match: bool,
+
+    /// Null test: time-align via cross-correlation, subtract, and report the residual
+    #[arg(long, conflicts_with = "abx")]
+    null: bool,
+
+    /// Write the null-test difference signal to this WAV file
+    #[arg(long, value_name = "FILE", requires = "null")]
+    null_output: Option<PathBuf>,
+
+    /// Maximum alignment offset searched by the null test and difference
+    /// spectrogram, in milliseconds
+    #[arg(long, default_value = "250")]
+    max_lag_ms: f32,
+
+    /// Write a time-aligned difference spectrogram (.csv or .png)
+    #[arg(long, value_name = "FILE", conflicts_with = "abx")]
+    diff_spectrogram: Option<PathBuf>,
+
+    /// Run a blind ABX listening test through the output device
+    #[arg(long)]
+    abx: bool,
+
+    /// Number of ABX trials
+    #[arg(long, default_value = "10", requires = "abx")]
+    trials: usize,
+
+    /// ABX segment length in seconds
+    #[arg(long, default_value = "4.0", requires = "abx")]
+    segment: f32,
+
+    /// Output device for ABX playback (index, exact name, or partial name)
+    #[arg(long, requires = "abx")]
+    device: Option<String>,
 }
 
-pub fn run(args: CompareArgs) -> anyhow::Result<()> {
-    println!("A/B Comparison");
-    println!("==============");
+/// Gain in dB that brings `implementation` to the loudness of `reference`.
+///
+/// `None` when either program is silent or too short to measure.
+fn loudness_match_db(reference: &LoudnessSummary, implementation: &LoudnessSummary) -> Option<f32> {
+    (reference.integrated.is_finite() && implementation.integrated.is_finite())
+        .then_some(reference.integrated - implementation.integrated)
+}
+
+/// Audio analyzed by the null test, in samples (first 10 s at 48 kHz).
+const ALIGN_WINDOW: usize = 480_000;
+
+/// Time-align the implementation to the reference within `--max-lag-ms`.
+fn align_pair(pair: &MatchedPair, max_lag_ms: f32) -> Alignment {
+    let max_lag = (max_lag_ms.max(0.0) * 0.001 * pair.sample_rate as f32) as usize;
+    align(&pair.reference, &pair.implementation, max_lag, ALIGN_WINDOW)
+}
+
+/// Mono reference/implementation pair, loudness-matched unless disabled.
+struct MatchedPair {
+    reference: Vec<f32>,
+    implementation: Vec<f32>,
+    sample_rate: u32,
+    ref_loudness: LoudnessSummary,
+    impl_loudness: LoudnessSummary,
+    /// Gain applied to the implementation, if matched.
+    gain_db: Option<f32>,
+}
+
+/// Load both files as mono and loudness-match the implementation.
+fn load_matched(args: &CompareArgs) -> anyhow::Result<MatchedPair> {
+    let (reference, ref_spec) = read_wav(&args.reference)?;
+    let (mut implementation, impl_spec) = read_wav(&args.implementation)?;
+
+    if ref_spec.sample_rate != impl_spec.sample_rate {
+        anyhow::bail!(
+            "Sample rate mismatch: {} vs {}",
+            ref_spec.sample_rate,
+            impl_spec.sample_rate
+        );
+    }
+
+    let sample_rate = ref_spec.sample_rate as f32;
+    let ref_loudness = measure_loudness(&reference, None, sample_rate);
+    let impl_loudness = measure_loudness(&implementation, None, sample_rate);
+    let gain_db = if args.no_loudness_match {
+        None
+    } else {
+        loudness_match_db(&ref_loudness, &impl_loudness)
+    };
+    if let Some(gain_db) = gain_db {
+        let gain = db_to_linear(gain_db);
+        for sample in &mut implementation {
+            *sample *= gain;
+        }
+    }
+
+    Ok(MatchedPair {
+        reference,
+        implementation,
+        sample_rate: ref_spec.sample_rate,
+        ref_loudness,
+        impl_loudness,
+        gain_db,
+    })
+}
+
+/// Print the loudness section of the report.
+fn print_loudness(pair: &MatchedPair, no_loudness_match: bool) {
+    println!("Loudness");
+    println!("--------");
+    println!("  Reference:      {:.1} LUFS", pair.ref_loudness.integrated);
+    println!(
+        "  Implementation: {:.1} LUFS",
+        pair.impl_loudness.integrated
+    );
+    match pair.gain_db {
+        Some(gain_db) => println!("  Matched: implementation {gain_db:+.1} dB"),
+        None if no_loudness_match => println!("  Not matched (--no-loudness-match)"),
+        None => println!("  Not matched (a file is silent or too short to measure)"),
+    }
+    println!();
+}
+
+/// Align, subtract, and report the residual; optionally write it to a WAV.
+fn run_null(args: CompareArgs) -> anyhow::Result<()> {
+    println!("Null Test");
+    println!("=========");
     println!("  Reference:      {}", args.reference.display());
     println!("  Implementation: {}", args.implementation.display());
     println!();
 
-    // Load files
-    let (ref_samples, ref_spec) = read_wav(&args.reference)?;
-    let (impl_samples, impl_spec) = read_wav(&args.implementation)?;
+    let pair = load_matched(&args)?;
+    print_loudness(&pair, args.no_loudness_match);
+
+    let sample_rate = pair.sample_rate as f32;
+    let alignment = align_pair(&pair, args.max_lag_ms);
+    let difference = null_difference(&pair.reference, &pair.implementation, alignment);
+    if difference.is_empty() {
+        anyhow::bail!("The files do not overlap after alignment");
+    }
+
+    let ref_rms = dynamics::rms(&pair.reference);
+    let residual_rms = dynamics::rms(&difference);
+    let residual_peak = dynamics::peak(&difference);
+
+    println!("Alignment");
+    println!("---------");
+    println!(
+        "  Offset:   {} samples ({:+.2} ms, {})",
+        alignment.lag,
+        alignment.lag as f32 * 1000.0 / sample_rate,
+        if alignment.lag >= 0 {
+            "implementation lags"
+        } else {
+            "implementation leads"
+        }
+    );
+    println!(
+        "  Polarity: {}",
+        if alignment.inverted {
+            "inverted (corrected)"
+        } else {
+            "normal"
+        }
+    );
+    println!();
+
+    println!("Residual");
+    println!("--------");
+    println!("  RMS:        {:.1} dBFS", linear_to_db(residual_rms));
+    println!("  Peak:       {:.1} dBFS", linear_to_db(residual_peak));
+    println!(
+        "  Null depth: {:.1} dB below reference RMS",
+        linear_to_db(ref_rms) - linear_to_db(residual_rms)
+    );
+
+    if let Some(path) = &args.null_output {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: pair.sample_rate,
+            bits_per_sample: 32,
+        };
+        write_wav(path, &difference, spec)?;
+        println!("\nWrote difference signal to {}", path.display());
+    }
+
+    if let Some(path) = &args.diff_spectrogram {
+        println!();
+        write_difference_spectrogram(path, &pair, alignment, args.fft_size)?;
+    }
+
+    Ok(())
+}
+
+/// Write the difference spectrogram of the aligned pair and summarize it.
+///
+/// The format follows the extension: `.png` for a heatmap, CSV otherwise.
+fn write_difference_spectrogram(
+    path: &Path,
+    pair: &MatchedPair,
+    alignment: Alignment,
+    fft_size: usize,
+) -> anyhow::Result<()> {
+    let diff = difference_spectrogram(
+        &pair.reference,
+        &pair.implementation,
+        alignment,
+        pair.sample_rate as f32,
+        fft_size,
+    );
+    if diff.times.is_empty() {
+        anyhow::bail!("The aligned overlap is shorter than one {fft_size}-sample frame");
+    }
+
+    println!("Difference Spectrogram");
+    println!("----------------------");
+    println!(
+        "  {} frames x {} bins (implementation minus reference)",
+        diff.times.len(),
+        diff.frequencies.len()
+    );
+    println!(
+        "  Aligned:     {} samples{}",
+        alignment.lag,
+        if alignment.inverted {
+            ", polarity inverted"
+        } else {
+            ""
+        }
+    );
+    println!("  Mean |diff|: {:.2} dB", diff.mean_abs_db());
+    if let Some((time, freq, db)) = diff.peak() {
+        println!("  Largest:     {db:+.1} dB at {time:.3} s, {freq:.0} Hz");
+    }
+
+    let is_png = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+    if is_png {
+        let style = HeatmapStyle {
+            max_freq: HeatmapStyle::default()
+                .max_freq
+                .min(pair.sample_rate as f32 / 2.0),
+            ..HeatmapStyle::default()
+        };
+        export_difference_png(&diff, path, &style)?;
+    } else {
+        export_difference_csv(&diff, path)?;
+    }
+    println!("\nWrote difference spectrogram to {}", path.display());
+    Ok(())
+}
+
+/// Run the ABX test on the stereo files, loudness-matched unless disabled.
+fn run_abx(args: CompareArgs) -> anyhow::Result<()> {
+    let (reference, ref_spec) = read_wav_stereo(&args.reference)?;
+    let (mut implementation, impl_spec) = read_wav_stereo(&args.implementation)?;
 
     if ref_spec.sample_rate != impl_spec.sample_rate {
         anyhow::bail!(
@@ -49,22 +304,79 @@ pub fn run(args: CompareArgs) -> anyhow::Result<()> {
             impl_spec.sample_rate
         );
     }
-
     let sample_rate = ref_spec.sample_rate as f32;
 
+    if !args.no_loudness_match {
+        let ref_loudness = measure_loudness(&reference.left, Some(&reference.right), sample_rate);
+        let impl_loudness = measure_loudness(
+            &implementation.left,
+            Some(&implementation.right),
+            sample_rate,
+        );
+        match loudness_match_db(&ref_loudness, &impl_loudness) {
+            Some(gain_db) => {
+                println!("Loudness match: B {gain_db:+.1} dB");
+                let gain = db_to_linear(gain_db);
+                for sample in implementation
+                    .left
+                    .iter_mut()
+                    .chain(implementation.right.iter_mut())
+                {
+                    *sample *= gain;
+                }
+            }
+            None => println!("Loudness match skipped (a file is silent or too short)"),
+        }
+    }
+
+    println!("  A: {}", args.reference.display());
+    println!("  B: {}", args.implementation.display());
+
+    abx::run(
+        reference,
+        implementation,
+        AbxConfig {
+            trials: args.trials,
+            segment_seconds: args.segment,
+            device: args.device,
+            sample_rate: ref_spec.sample_rate,
+        },
+    )
+}
+
+pub fn run(args: CompareArgs) -> anyhow::Result<()> {
+    if args.abx {
+        return run_abx(args);
+    }
+    if args.null {
+        return run_null(args);
+    }
+
+    println!("A/B Comparison");
+    println!("==============");
+    println!("  Reference:      {}", args.reference.display());
+    println!("  Implementation: {}", args.implementation.display());
+    println!();
+
+    // Load files, loudness-matching the implementation to the reference
+    let pair = load_matched(&args)?;
+    let sample_rate = pair.sample_rate as f32;
+
     // Use the shorter length
-    let len = ref_samples.len().min(impl_samples.len());
-    let ref_samples = &ref_samples[..len];
-    let impl_samples = &impl_samples[..len];
+    let len = pair.reference.len().min(pair.implementation.len());
+    let ref_samples = &pair.reference[..len];
+    let impl_samples = &pair.implementation[..len];
 
     println!(
         "Comparing {} samples ({:.2}s at {} Hz)",
         len,
         len as f32 / sample_rate,
-        ref_spec.sample_rate
+        pair.sample_rate
     );
     println!();
 
+    print_loudness(&pair, args.no_loudness_match);
+
     // Time-domain metrics
     let mse_val = mse(ref_samples, impl_samples);
     let rmse_val = rmse(ref_samples, impl_samples);
@@ -193,6 +505,12 @@ pub fn run(args: CompareArgs) -> anyhow::Result<()> {
         }
     );
 
+    if let Some(path) = &args.diff_spectrogram {
+        println!();
+        let alignment = align_pair(&pair, args.max_lag_ms);
+        write_difference_spectrogram(path, &pair, alignment, fft_size)?;
+    }
+
     // Write JSON report if requested
     if let Some(output_path) = args.output {
         let report = serde_json::json!({
@@ -201,6 +519,11 @@ pub fn run(args: CompareArgs) -> anyhow::Result<()> {
             "sample_rate": sample_rate,
             "length_samples": len,
             "duration_seconds": len as f32 / sample_rate,
+            "loudness": {
+                "reference_lufs": pair.ref_loudness.integrated,
+                "implementation_lufs": pair.impl_loudness.integrated,
+                "match_gain_db": pair.gain_db,
+            },
             "time_domain": {
                 "mse": mse_val,
                 "rmse": rmse_val,
@@ -271,3 +594,29 @@ fn compute_average_spectrum(samples: &[f32], fft_size: usize) -> Vec<f32> {
 
     avg_spectrum
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(integrated: f32) -> LoudnessSummary {
+        LoudnessSummary {
+            integrated,
+            max_momentary: integrated,
+            max_short_term: integrated,
+            true_peak_dbtp: 0.0,
+        }
+    }
+
+    #[test]
+    fn loudness_match_gain() {
+        assert_eq!(
+            loudness_match_db(&summary(-14.0), &summary(-20.0)),
+            Some(6.0)
+        );
+        assert_eq!(
+            loudness_match_db(&summary(-14.0), &summary(f32::NEG_INFINITY)),
+            None
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Shell completion scripts and dynamic effect/parameter suggestions.
+//!
+//! `sonido completions <shell>` prints a small registration script. On every
+//! tab press the shell calls back into `sonido` with `COMPLETE=<shell>` set,
+//! so effect IDs, parameter names, and enum labels always come from the
+//! registry of the installed binary rather than a snapshot baked into the
+//! script.
+
+use clap::{Args, ValueEnum};
+use clap_complete::engine::CompletionCandidate;
+use clap_complete::env::{Bash, Elvish, EnvCompleter, Fish, Powershell, Zsh};
+use sonido_core::ParamFlags;
+use sonido_graph_dsl::{parse_param_value, resolve_effect_name, resolve_param_index};
+use sonido_registry::EffectRegistry;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+
+/// Environment variable that switches `sonido` into completion mode.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Print a shell completion script.
+#[derive(Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate the script for
+    #[arg(value_enum)]
+    shell: CompletionShell,
+}
+
+/// Shells with completion support.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CompletionShell {
+    /// Bourne Again shell
+    Bash,
+    /// Z shell
+    Zsh,
+    /// Friendly interactive shell
+    Fish,
+    /// Elvish
+    Elvish,
+    /// PowerShell
+    Powershell,
+}
+
+/// Run the completions command.
+pub fn run(args: CompletionsArgs) -> anyhow::Result<()> {
+    let shell: &dyn EnvCompleter = match args.shell {
+        CompletionShell::Bash => &Bash,
+        CompletionShell::Zsh => &Zsh,
+        CompletionShell::Fish => &Fish,
+        CompletionShell::Elvish => &Elvish,
+        CompletionShell::Powershell => &Powershell,
+    };
+    let completer = completer_program();
+    shell.write_registration(
+        COMPLETE_VAR,
+        "sonido",
+        "sonido",
+        &completer,
+        &mut std::io::stdout().lock(),
+    )?;
+    Ok(())
+}
+
+/// Program the registration script calls back into: `argv[0]` as invoked,
+/// made absolute when it is a relative path such as `./target/release/sonido`.
+fn completer_program() -> String {
+    let argv0 = PathBuf::from(
+        std::env::args_os()
+            .next()
+            .unwrap_or_else(|| "sonido".into()),
+    );
+    let path = if argv0.components().count() > 1 && argv0.is_relative() {
+        std::env::current_dir()
+            .map(|dir| dir.join(&argv0))
+            .unwrap_or(argv0)
+    } else {
+        argv0
+    };
+    path.to_string_lossy().into_owned()
+}
+
+/// Complete a registry effect ID (`sonido effects <EFFECT>`, `--effect`).
+pub fn complete_effect_id(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    effect_candidates(&EffectRegistry::new(), "", current)
+}
+
+/// Complete an effect spec or chain: `effect:param=value,...|effect...`.
+///
+/// Suggests effect IDs before the `:`, parameter names after it, and step
+/// labels (`shape=soft`) after an `=` on enumerated parameters.
+pub fn complete_effect_spec(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    spec_candidates(&EffectRegistry::new(), current)
+}
+
+fn spec_candidates(registry: &EffectRegistry, current: &str) -> Vec<CompletionCandidate> {
+    let split = current.rfind('|').map_or(0, |i| i + 1);
+    let (chain, segment) = current.split_at(split);
+
+    let Some((name, params)) = segment.split_once(':') else {
+        return effect_candidates(registry, chain, segment.trim_start());
+    };
+    let effect_id = resolve_effect_name(name.trim());
+    let Some(descriptors) = registry.param_descriptors(effect_id) else {
+        return Vec::new();
+    };
+
+    let split = params.rfind(',').map_or(0, |i| i + 1);
+    let (done, partial) = params.split_at(split);
+    let head = format!("{chain}{name}:{done}");
+
+    if let Some((key, value)) = partial.split_once('=') {
+        let Some(desc) = resolve_param_index(registry, effect_id, key.trim())
+            .and_then(|idx| descriptors.get(idx))
+        else {
+            return Vec::new();
+        };
+        // Offer labels in shell-friendly form ("Soft Clip" -> "softclip"),
+        // keeping only spellings the value parser accepts.
+        let value = value.to_lowercase();
+        return desc
+            .step_labels
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, label)| {
+                let word: String = label.split_whitespace().collect::<String>().to_lowercase();
+                let step = desc.min + i as f32 * desc.step.max(1.0);
+                (word.starts_with(&value) && parse_param_value(desc, &word) == Some(step))
+                    .then(|| CompletionCandidate::new(format!("{head}{key}={word}")))
+            })
+            .collect();
+    }
+
+    let partial = partial.trim_start().to_lowercase();
+    descriptors
+        .iter()
+        .filter(|d| {
+            !d.flags.contains(ParamFlags::HIDDEN) && !d.flags.contains(ParamFlags::READ_ONLY)
+        })
+        .map(|d| (d.short_name.to_lowercase(), d))
+        .filter(|(key, _)| key.starts_with(&partial))
+        .map(|(key, d)| {
+            let help = format!(
+                "{} ({} to {})",
+                d.name,
+                d.format_value(d.min),
+                d.format_value(d.max)
+            );
+            CompletionCandidate::new(format!("{head}{key}=")).help(Some(help.into()))
+        })
+        .collect()
+}
+
+fn effect_candidates(
+    registry: &EffectRegistry,
+    head: &str,
+    partial: &str,
+) -> Vec<CompletionCandidate> {
+    let partial = partial.to_lowercase();
+    registry
+        .all_effects()
+        .into_iter()
+        .filter(|d| d.id.starts_with(&partial))
+        .map(|d| {
+            CompletionCandidate::new(format!("{head}{}", d.id)).help(Some(d.description.into()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(current: &str) -> Vec<String> {
+        spec_candidates(&EffectRegistry::new(), current)
+            .iter()
+            .map(|c| c.get_value().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn completes_effect_ids() {
+        let all = values("");
+        assert_eq!(all.len(), EffectRegistry::new().len());
+        assert!(values("dist").contains(&"distortion".to_string()));
+        assert!(values("preamp:gain=6|dist").contains(&"preamp:gain=6|distortion".to_string()));
+    }
+
+    #[test]
+    fn completes_param_names_and_labels() {
+        let params = values("distortion:dr");
+        assert_eq!(params, ["distortion:drive="]);
+        assert!(values("distortion:drive=12,").contains(&"distortion:drive=12,tone=".to_string()));
+        assert!(values("lowpass:").iter().all(|v| v.starts_with("lowpass:")));
+
+        let shapes = values("distortion:shape=");
+        assert!(
+            shapes.contains(&"distortion:shape=softclip".to_string()),
+            "{shapes:?}"
+        );
+        assert!(values("distortion:drive=1").is_empty());
+        assert!(values("nosuch:").is_empty());
+    }
+}
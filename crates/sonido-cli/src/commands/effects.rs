@@ -2,21 +2,44 @@
 
 #![allow(clippy::print_literal)] // Table headers use literal strings intentionally
 
+use super::completions::complete_effect_id;
 use crate::effects::available_effects;
-use clap::Args;
+use clap::{Args, ValueEnum};
+use clap_complete::engine::ArgValueCompleter;
+use serde_json::{Value, json};
+use sonido_core::{ParamDescriptor, ParamFlags, ParamScale, ParamUnit};
+use sonido_registry::{EffectDescriptor, EffectRegistry};
 
 #[derive(Args)]
 pub struct EffectsArgs {
     /// Show details for a specific effect
-    #[arg(value_name = "EFFECT")]
+    #[arg(value_name = "EFFECT", add = ArgValueCompleter::new(complete_effect_id))]
     effect: Option<String>,
 
     /// Show example commands
     #[arg(long)]
     examples: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    format: EffectsFormat,
+}
+
+/// Output format for `sonido effects`.
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum EffectsFormat {
+    /// Human-readable tables
+    #[default]
+    Text,
+    /// Complete registry descriptors as JSON
+    Json,
 }
 
 pub fn run(args: EffectsArgs) -> anyhow::Result<()> {
+    if matches!(args.format, EffectsFormat::Json) {
+        return print_json(args.effect.as_deref());
+    }
+
     let effects = available_effects();
 
     if let Some(effect_name) = &args.effect {
@@ -130,3 +153,147 @@ pub fn run(args: EffectsArgs) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Prints registry descriptors as JSON: one object for `effect`, or an
+/// array of every registered effect.
+fn print_json(effect: Option<&str>) -> anyhow::Result<()> {
+    let registry = EffectRegistry::new();
+    let value = match effect {
+        Some(name) => {
+            let desc = registry
+                .all_effects()
+                .into_iter()
+                .find(|d| d.id.eq_ignore_ascii_case(name))
+                .ok_or_else(|| anyhow::anyhow!("Unknown effect: {}", name))?;
+            effect_json(&registry, desc)
+        }
+        None => Value::Array(
+            registry
+                .all_effects()
+                .into_iter()
+                .map(|desc| effect_json(&registry, desc))
+                .collect(),
+        ),
+    };
+    println!("{}", serde_json::to_string_pretty(&value)?);
+    Ok(())
+}
+
+/// Complete JSON descriptor for one registry effect.
+fn effect_json(registry: &EffectRegistry, desc: &EffectDescriptor) -> Value {
+    let params: Vec<Value> = registry
+        .param_descriptors(desc.id)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(index, d)| param_json(index, d))
+        .collect();
+
+    json!({
+        "id": desc.id,
+        "name": desc.name,
+        "short_name": desc.short_name,
+        "description": desc.description,
+        "category": desc.category.name(),
+        "params": params,
+    })
+}
+
+/// JSON descriptor for one parameter. `key` is the name accepted by
+/// `--param` and chain syntax.
+fn param_json(index: usize, d: &ParamDescriptor) -> Value {
+    let (scale, exponent) = match d.scale {
+        ParamScale::Linear => ("linear", None),
+        ParamScale::Logarithmic => ("logarithmic", None),
+        ParamScale::Power(e) => ("power", Some(e)),
+        _ => ("other", None),
+    };
+    let flags: Vec<&str> = [
+        (ParamFlags::AUTOMATABLE, "automatable"),
+        (ParamFlags::STEPPED, "stepped"),
+        (ParamFlags::HIDDEN, "hidden"),
+        (ParamFlags::READ_ONLY, "read_only"),
+        (ParamFlags::MODULATABLE, "modulatable"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| d.flags.contains(*flag))
+    .map(|(_, name)| name)
+    .collect();
+
+    json!({
+        "index": index,
+        "key": d.short_name.to_lowercase(),
+        "id": d.id.0,
+        "string_id": d.string_id,
+        "name": d.name,
+        "short_name": d.short_name,
+        "description": d.description,
+        "group": d.group,
+        "unit": unit_name(d.unit),
+        "unit_suffix": d.unit.suffix().trim(),
+        "min": d.min,
+        "max": d.max,
+        "default": d.default,
+        "default_text": d.format_value(d.default),
+        "step": d.step,
+        "scale": scale,
+        "scale_exponent": exponent,
+        "flags": flags,
+        "step_labels": d.step_labels,
+        "modulation_id": d.modulation_id,
+        "smoothing_ms": d.smoothing_time_ms(),
+    })
+}
+
+/// Stable machine name for a parameter unit.
+fn unit_name(unit: ParamUnit) -> &'static str {
+    match unit {
+        ParamUnit::Decibels => "decibels",
+        ParamUnit::Hertz => "hertz",
+        ParamUnit::Milliseconds => "milliseconds",
+        ParamUnit::Seconds => "seconds",
+        ParamUnit::Percent => "percent",
+        ParamUnit::Ratio => "ratio",
+        ParamUnit::Semitones => "semitones",
+        ParamUnit::Cents => "cents",
+        ParamUnit::None => "none",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_describes_every_effect() {
+        let registry = EffectRegistry::new();
+        for desc in registry.all_effects() {
+            let value = effect_json(&registry, desc);
+            let params = value["params"].as_array().unwrap();
+            assert_eq!(params.len(), desc.param_count, "{}", desc.id);
+            for p in params {
+                assert!(p["min"].as_f64().unwrap() <= p["default"].as_f64().unwrap());
+                assert!(p["default"].as_f64().unwrap() <= p["max"].as_f64().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn json_includes_step_labels_and_units() {
+        let registry = EffectRegistry::new();
+        let value = effect_json(&registry, registry.get("distortion").unwrap());
+        assert_eq!(value["category"], "Distortion");
+
+        let params = value["params"].as_array().unwrap();
+        let drive = params.iter().find(|p| p["key"] == "drive").unwrap();
+        assert_eq!(drive["unit"], "decibels");
+        assert_eq!(drive["unit_suffix"], "dB");
+
+        let stepped = params
+            .iter()
+            .find(|p| p["flags"].as_array().unwrap().contains(&json!("stepped")))
+            .unwrap();
+        assert!(!stepped["step_labels"].as_array().unwrap().is_empty());
+    }
+}
@@ -1,10 +1,12 @@
 //! Test signal generation command.
 
+use crate::effects::create_effect_with_params;
 use clap::{Args, Subcommand, ValueEnum};
 use sonido_analysis::SineSweep;
 use sonido_io::{WavSpec, write_wav};
 use sonido_synth::voice::midi_to_freq;
 use sonido_synth::{AdsrEnvelope, Oscillator, OscillatorWaveform, PolyphonicSynth};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Waveform types for CLI
@@ -30,6 +32,14 @@ impl From<CliWaveform> for OscillatorWaveform {
     }
 }
 
+/// Noise colors for CLI
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum CliNoiseColor {
+    #[default]
+    White,
+    Pink,
+}
+
 #[derive(Args)]
 pub struct GenerateArgs {
     #[command(subcommand)]
@@ -84,12 +94,16 @@ enum GenerateCommand {
         amplitude: f32,
     },
 
-    /// Generate white noise
+    /// Generate white or pink noise
     Noise {
         /// Output WAV file
         #[arg(value_name = "OUTPUT")]
         output: PathBuf,
 
+        /// Noise color
+        #[arg(long, value_enum, default_value = "white")]
+        color: CliNoiseColor,
+
         /// Duration in seconds
         #[arg(long, default_value = "1.0")]
         duration: f32,
@@ -302,17 +316,21 @@ pub fn run(args: GenerateArgs) -> anyhow::Result<()> {
 
         GenerateCommand::Noise {
             output,
+            color,
             duration,
             sample_rate,
             amplitude,
         } => {
-            println!("Generating white noise...");
+            let color_name = format!("{:?}", color).to_lowercase();
+            println!("Generating {} noise...", color_name);
             println!("  {:.2}s at {} Hz", duration, sample_rate);
 
-            let num_samples = (duration * sample_rate as f32) as usize;
-            let samples: Vec<f32> = (0..num_samples)
-                .map(|_| (rand_f32() * 2.0 - 1.0) * amplitude)
-                .collect();
+            let samples = render_test_signal(
+                &[("signal", color_name.as_str())],
+                duration,
+                sample_rate,
+                amplitude,
+            )?;
 
             let spec = WavSpec {
                 channels: 1,
@@ -334,13 +352,13 @@ pub fn run(args: GenerateArgs) -> anyhow::Result<()> {
             println!("Generating sine tone...");
             println!("  {} Hz for {:.2}s", freq, duration);
 
-            let num_samples = (duration * sample_rate as f32) as usize;
-            let samples: Vec<f32> = (0..num_samples)
-                .map(|i| {
-                    let t = i as f32 / sample_rate as f32;
-                    (2.0 * std::f32::consts::PI * freq * t).sin() * amplitude
-                })
-                .collect();
+            let freq = freq.to_string();
+            let samples = render_test_signal(
+                &[("signal", "sine"), ("freq", freq.as_str())],
+                duration,
+                sample_rate,
+                amplitude,
+            )?;
 
             let spec = WavSpec {
                 channels: 1,
@@ -549,19 +567,53 @@ pub fn run(args: GenerateArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Simple PRNG for noise generation (xorshift32)
-fn rand_f32() -> f32 {
-    use std::cell::Cell;
-    thread_local! {
-        static STATE: Cell<u32> = const { Cell::new(0x12345678) };
+/// Render `duration` seconds of the `test_signal` effect at full scale, scaled
+/// by `amplitude`.
+///
+/// Shares the generator used as a graph node, so a file written here matches
+/// what the node plays live. `params` use the same names as
+/// `--effect test_signal --param ...`.
+fn render_test_signal(
+    params: &[(&str, &str)],
+    duration: f32,
+    sample_rate: u32,
+    amplitude: f32,
+) -> anyhow::Result<Vec<f32>> {
+    let mut params: HashMap<String, String> = params
+        .iter()
+        .map(|&(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    params.insert("level".to_string(), "0".to_string());
+
+    let mut generator = create_effect_with_params("test_signal", sample_rate as f32, &params)?;
+    // Snap smoothed params to their targets so the file starts at full level.
+    generator.reset();
+
+    let num_samples = (duration * sample_rate as f32) as usize;
+    Ok((0..num_samples)
+        .map(|_| generator.process(0.0) * amplitude)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_starts_at_full_amplitude() {
+        let samples =
+            render_test_signal(&[("signal", "sine"), ("freq", "1000")], 0.01, 48000, 0.5).unwrap();
+        assert_eq!(samples.len(), 480);
+        // 48 samples per cycle: the first peak lands at sample 12, unsmoothed.
+        assert!((samples[12] - 0.5).abs() < 1e-3, "{}", samples[12]);
     }
 
-    STATE.with(|state| {
-        let mut x = state.get();
-        x ^= x << 13;
-        x ^= x >> 17;
-        x ^= x << 5;
-        state.set(x);
-        (x as f32) / (u32::MAX as f32)
-    })
+    #[test]
+    fn noise_colors_render() {
+        for color in ["white", "pink"] {
+            let samples = render_test_signal(&[("signal", color)], 0.1, 48000, 1.0).unwrap();
+            let peak = samples.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+            assert!(peak > 0.1 && peak <= 1.5, "{color}: peak {peak}");
+        }
+    }
 }
@@ -0,0 +1,84 @@
+//! Graph inspection commands.
+//!
+//! `sonido graph dot` renders a graph's topology and compiled schedule as
+//! GraphViz DOT for troubleshooting complex routings.
+
+use super::common::load_graph;
+use clap::{Args, Subcommand};
+use sonido_core::graph::GraphEngine;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GraphArgs {
+    #[command(subcommand)]
+    command: GraphCommand,
+}
+
+#[derive(Subcommand)]
+enum GraphCommand {
+    /// Render the topology, buffer assignments, and compiled schedule as GraphViz DOT
+    Dot {
+        /// Graph DSL specification, or a JSON snapshot written by `process --export`
+        spec: String,
+
+        /// Write DOT to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Sample rate to build the graph at
+        #[arg(long, default_value = "48000")]
+        sample_rate: u32,
+
+        /// Block size to compile the schedule for
+        #[arg(long, default_value = "512")]
+        block_size: usize,
+    },
+}
+
+pub fn run(args: GraphArgs) -> anyhow::Result<()> {
+    match args.command {
+        GraphCommand::Dot {
+            spec,
+            output,
+            sample_rate,
+            block_size,
+        } => {
+            let dot = render_dot(&spec, sample_rate as f32, block_size)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, dot)?;
+                    println!("Wrote {}", path.display());
+                    println!("Render with: dot -Tsvg {} -o graph.svg", path.display());
+                }
+                None => print!("{dot}"),
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Builds the graph described by `spec` and renders it as DOT.
+fn render_dot(spec: &str, sample_rate: f32, block_size: usize) -> anyhow::Result<String> {
+    let (graph, manifest) = load_graph(spec, sample_rate, block_size)?;
+    Ok(GraphEngine::new_dag(graph, manifest).to_dot())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_names_effects_and_lists_schedule() {
+        let dot = render_dot("split(distortion; -) | reverb", 48000.0, 256).unwrap();
+        assert!(dot.starts_with("digraph sonido {"));
+        assert!(dot.contains("distortion #"));
+        assert!(dot.contains("reverb #"));
+        assert!(dot.contains("block 256"));
+        assert!(dot.contains("ProcessEffect"));
+    }
+
+    #[test]
+    fn dot_rejects_bad_spec() {
+        assert!(render_dot("split(", 48000.0, 256).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 //! Display WAV file metadata.
 
 use clap::Args;
-use sonido_io::{WavFormat, read_wav_info};
+use sonido_io::{LoopKind, WavFormat, WavMetadata, read_wav_info, read_wav_metadata};
 
 /// Display WAV file information.
 #[derive(Args)]
@@ -31,9 +31,112 @@ pub fn run(args: InfoArgs) -> anyhow::Result<()> {
     let file_size = std::fs::metadata(&args.file)?.len();
     println!("File Size:   {}", format_bytes(file_size));
 
+    let metadata = read_wav_metadata(&args.file)?;
+    print_metadata(&metadata, info.sample_rate);
+
     Ok(())
 }
 
+/// Print broadcast WAV fields, cue points, and loops, if present.
+fn print_metadata(metadata: &WavMetadata, sample_rate: u32) {
+    let secs = |frame: u32| f64::from(frame) / f64::from(sample_rate);
+
+    if let Some(bext) = &metadata.bext {
+        println!("\nBroadcast WAV (v{}):", bext.version);
+        let fields = [
+            ("Description", bext.description.as_str()),
+            ("Originator", bext.originator.as_str()),
+            ("Reference", bext.originator_reference.as_str()),
+        ];
+        for (name, value) in fields {
+            if !value.is_empty() {
+                println!("  {:<12} {}", format!("{name}:"), value);
+            }
+        }
+        if !bext.origination_date.is_empty() {
+            println!(
+                "  Created:     {} {}",
+                bext.origination_date, bext.origination_time
+            );
+        }
+        println!(
+            "  Timecode:    {} ({} samples)",
+            format_timecode(bext.time_reference, sample_rate),
+            bext.time_reference
+        );
+        if let Some(lufs) = bext.loudness_value {
+            print!("  Loudness:    {lufs:.1} LUFS");
+            if let Some(lra) = bext.loudness_range {
+                print!(", LRA {lra:.1} LU");
+            }
+            if let Some(tp) = bext.max_true_peak {
+                print!(", true peak {tp:.1} dBTP");
+            }
+            println!();
+        }
+        for line in bext.coding_history.lines().filter(|l| !l.is_empty()) {
+            println!("  History:     {line}");
+        }
+    }
+
+    if !metadata.cues.is_empty() {
+        println!("\nCue Points ({}):", metadata.cues.len());
+        for cue in &metadata.cues {
+            println!(
+                "  #{:<3} {:>10.3}s  (frame {}){}",
+                cue.id,
+                secs(cue.frame),
+                cue.frame,
+                cue.label
+                    .as_deref()
+                    .map(|l| format!("  {l}"))
+                    .unwrap_or_default()
+            );
+        }
+    }
+
+    if !metadata.loops.is_empty() {
+        println!("\nLoops ({}):", metadata.loops.len());
+        for l in &metadata.loops {
+            let kind = match l.kind {
+                LoopKind::Forward => "forward",
+                LoopKind::PingPong => "ping-pong",
+                LoopKind::Backward => "backward",
+            };
+            let count = if l.play_count == 0 {
+                "infinite".to_string()
+            } else {
+                format!("{}x", l.play_count)
+            };
+            println!(
+                "  {:<9} {:.3}s - {:.3}s  (frames {}..{}), {}",
+                kind,
+                secs(l.start),
+                secs(l.end),
+                l.start,
+                l.end,
+                count
+            );
+        }
+    }
+
+    if let Some(note) = metadata.root_note {
+        println!("\nRoot Note:   {note} (MIDI)");
+    }
+}
+
+/// Format a sample count since midnight as `hh:mm:ss.mmm`.
+fn format_timecode(samples: u64, sample_rate: u32) -> String {
+    let millis = samples * 1000 / u64::from(sample_rate.max(1));
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
 fn format_bytes(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{bytes} B")
@@ -48,6 +151,15 @@ fn format_bytes(bytes: u64) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_timecode_hours() {
+        assert_eq!(format_timecode(0, 48000), "00:00:00.000");
+        assert_eq!(
+            format_timecode(48000 * (3600 * 10 + 61) + 24000, 48000),
+            "10:01:01.500"
+        );
+    }
+
     #[test]
     fn format_bytes_zero() {
         assert_eq!(format_bytes(0), "0 B");
@@ -0,0 +1,464 @@
+//! Live input analysis.
+//!
+//! `sonido analyze live` opens the input device and prints a running
+//! readout of level, peak frequency, spectral centroid, THD, and BS.1770
+//! loudness, for checking hardware without recording a file first.
+//!
+//! The audio callback only copies input into an [`InputTap`] ring; a
+//! reporter thread pulls the newest samples every interval and runs the
+//! analysis in a [`LiveMeter`]. The output device is fed silence.
+
+use clap::{Args, ValueEnum};
+use sonido_analysis::loudness::LufsMeter;
+use sonido_analysis::{Fft, ThdAnalyzer, Window, spectral_centroid};
+use sonido_io::{AudioStream, StreamConfig};
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Fraction of the spectrum's power that must sit around the peak before
+/// the signal counts as a tone and THD is reported.
+const TONAL_POWER_FRACTION: f32 = 0.5;
+
+/// Signals below this RMS level (dBFS) are treated as silence.
+const SILENCE_DB: f32 = -80.0;
+
+/// Which input channel to analyze.
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Channel {
+    /// Left input (channel 1)
+    #[default]
+    Left,
+    /// Right input (channel 2)
+    Right,
+    /// Average of left and right
+    Mix,
+}
+
+#[derive(Args)]
+pub struct LiveArgs {
+    /// Input device (index, exact name, or partial name)
+    #[arg(long)]
+    input_device: Option<String>,
+
+    /// Output device (index, exact name, or partial name); receives silence
+    #[arg(long)]
+    output_device: Option<String>,
+
+    /// Input channel to analyze
+    #[arg(long, value_enum, default_value_t)]
+    channel: Channel,
+
+    /// Sample rate in Hz
+    #[arg(long, default_value = "48000")]
+    sample_rate: u32,
+
+    /// Buffer size
+    #[arg(long, default_value = "256")]
+    buffer_size: u32,
+
+    /// Time between readouts in milliseconds
+    #[arg(long, default_value = "250")]
+    interval_ms: u64,
+
+    /// FFT size for the spectrum and THD
+    #[arg(long, default_value = "8192")]
+    fft_size: usize,
+
+    /// Stop after this many seconds (default: run until Ctrl+C)
+    #[arg(long)]
+    duration: Option<f32>,
+
+    /// Print one line per readout instead of updating a single line
+    #[arg(long)]
+    lines: bool,
+}
+
+pub fn run(args: LiveArgs) -> anyhow::Result<()> {
+    if !args.fft_size.is_power_of_two() || args.fft_size > InputTap::CAPACITY {
+        anyhow::bail!(
+            "--fft-size must be a power of two no larger than {}",
+            InputTap::CAPACITY
+        );
+    }
+    let sample_rate = args.sample_rate as f32;
+    let interval = Duration::from_millis(args.interval_ms.max(20));
+    // The ring must hold everything that arrives between readouts so the
+    // loudness meter sees continuous audio.
+    if interval.as_secs_f32() * sample_rate >= InputTap::CAPACITY as f32 {
+        anyhow::bail!("--interval-ms is too long for {} Hz", args.sample_rate);
+    }
+
+    let mut stream = AudioStream::new(StreamConfig {
+        sample_rate: args.sample_rate,
+        buffer_size: args.buffer_size,
+        input_device: args.input_device.clone(),
+        output_device: args.output_device.clone(),
+    })?;
+    let running = stream.running_handle();
+
+    let r = Arc::clone(&running);
+    ctrlc::set_handler(move || {
+        r.store(false, Ordering::SeqCst);
+    })?;
+
+    println!(
+        "Live analysis at {} Hz ({} channel), Ctrl+C to stop",
+        args.sample_rate,
+        match args.channel {
+            Channel::Left => "left",
+            Channel::Right => "right",
+            Channel::Mix => "mixed",
+        }
+    );
+    println!("  RMS/Peak dBFS | Peak Hz | Centroid Hz | THD % | Momentary/Short-term LUFS");
+
+    let tap = Arc::new(InputTap::new());
+    // The stream only raises `running` once it starts, so the reporter
+    // waits on its own flag, raised after the stream returns.
+    let done = Arc::new(AtomicBool::new(false));
+    let reporter = {
+        let tap = Arc::clone(&tap);
+        let running = Arc::clone(&running);
+        let done = Arc::clone(&done);
+        let in_place = !args.lines && std::io::stdout().is_terminal();
+        let duration = args.duration.map(Duration::from_secs_f32);
+        let fft_size = args.fft_size;
+        std::thread::spawn(move || {
+            let mut meter = LiveMeter::new(sample_rate, fft_size);
+            let mut fresh = vec![0.0; InputTap::CAPACITY];
+            let mut read = tap.written();
+            let started = Instant::now();
+            while !done.load(Ordering::SeqCst) {
+                std::thread::sleep(interval);
+                let written = tap.written();
+                let count = written.wrapping_sub(read).min(InputTap::CAPACITY);
+                read = written;
+                tap.copy_latest(&mut fresh[..count]);
+                meter.push(&fresh[..count]);
+
+                let line = meter.reading().to_string();
+                if in_place {
+                    print!("\r  {line}  ");
+                    let _ = std::io::stdout().flush();
+                } else {
+                    println!("  {line}");
+                }
+                if duration.is_some_and(|d| started.elapsed() >= d) {
+                    running.store(false, Ordering::SeqCst);
+                    break;
+                }
+            }
+            if in_place {
+                println!();
+            }
+            meter
+        })
+    };
+
+    let channel = args.channel;
+    let mut mixed = vec![0.0; args.buffer_size as usize];
+    let result = stream.run_stereo(move |left_in, right_in, left_out, right_out| {
+        match channel {
+            Channel::Left => tap.push(left_in),
+            Channel::Right => tap.push(right_in),
+            Channel::Mix => {
+                let frames = left_in.len().min(right_in.len());
+                if mixed.len() < frames {
+                    mixed.resize(frames, 0.0);
+                }
+                for ((m, &l), &r) in mixed.iter_mut().zip(left_in).zip(right_in) {
+                    *m = 0.5 * (l + r);
+                }
+                tap.push(&mixed[..frames]);
+            }
+        }
+        left_out.fill(0.0);
+        right_out.fill(0.0);
+    });
+
+    done.store(true, Ordering::SeqCst);
+    let meter = reporter
+        .join()
+        .map_err(|_| anyhow::anyhow!("Analysis thread panicked"))?;
+    result?;
+    println!("\nSummary");
+    println!("-------");
+    println!(
+        "  Integrated: {}",
+        format_lufs(meter.loudness.integrated(), "LUFS")
+    );
+    println!(
+        "  True peak:  {}",
+        format_lufs(meter.loudness.true_peak_dbtp(), "dBTP")
+    );
+    Ok(())
+}
+
+/// Lock-free ring of the newest input samples.
+///
+/// The audio thread pushes blocks; the reporter thread copies out the
+/// samples written since its last read.
+struct InputTap {
+    samples: Box<[AtomicU32]>,
+    /// Total samples written; the next write goes to `written % CAPACITY`.
+    written: AtomicUsize,
+}
+
+impl InputTap {
+    /// Samples kept in the ring (power of two, ~1.4 s at 48 kHz).
+    const CAPACITY: usize = 65536;
+
+    fn new() -> Self {
+        Self {
+            samples: (0..Self::CAPACITY).map(|_| AtomicU32::new(0)).collect(),
+            written: AtomicUsize::new(0),
+        }
+    }
+
+    /// Append a block of samples (audio thread only).
+    fn push(&self, block: &[f32]) {
+        let start = self.written.load(Ordering::Relaxed);
+        for (i, &sample) in block.iter().enumerate() {
+            self.samples[(start + i) & (Self::CAPACITY - 1)]
+                .store(sample.to_bits(), Ordering::Relaxed);
+        }
+        self.written
+            .store(start.wrapping_add(block.len()), Ordering::Release);
+    }
+
+    /// Total samples written so far (wraps on overflow).
+    fn written(&self) -> usize {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Copy the newest `out.len()` samples into `out`, oldest first.
+    fn copy_latest(&self, out: &mut [f32]) {
+        let len = out.len().min(Self::CAPACITY);
+        let start = self.written().wrapping_sub(len);
+        for (i, sample) in out[..len].iter_mut().enumerate() {
+            let bits =
+                self.samples[start.wrapping_add(i) & (Self::CAPACITY - 1)].load(Ordering::Relaxed);
+            *sample = f32::from_bits(bits);
+        }
+    }
+}
+
+/// One readout of [`LiveMeter`].
+#[derive(Debug, Clone, Copy)]
+struct LiveReading {
+    /// RMS level since the previous readout in dBFS.
+    rms_db: f32,
+    /// Sample peak since the previous readout in dBFS.
+    peak_db: f32,
+    /// Frequency of the strongest spectral bin in Hz.
+    peak_freq: f32,
+    /// Spectral centroid in Hz.
+    centroid: f32,
+    /// THD ratio, when the signal is a clear tone.
+    thd: Option<f32>,
+    /// Momentary loudness (400 ms) in LUFS.
+    momentary: f32,
+    /// Short-term loudness (3 s) in LUFS.
+    short_term: f32,
+}
+
+impl std::fmt::Display for LiveReading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.rms_db < SILENCE_DB {
+            write!(f, "  --.- /  --.-  | (silence)")?;
+        } else {
+            write!(
+                f,
+                "{:6.1} / {:5.1} | {:7.1} | {:7.0} | ",
+                self.rms_db, self.peak_db, self.peak_freq, self.centroid
+            )?;
+            match self.thd {
+                Some(thd) => write!(f, "{:6.3}", thd * 100.0)?,
+                None => write!(f, "   -- ")?,
+            }
+        }
+        write!(
+            f,
+            " | {} / {}",
+            format_lufs(self.momentary, "LUFS"),
+            format_lufs(self.short_term, "LUFS")
+        )
+    }
+}
+
+/// Running analysis of a mono signal.
+struct LiveMeter {
+    sample_rate: f32,
+    fft: Fft,
+    window: Vec<f32>,
+    /// The newest `fft_size` samples, oldest first.
+    frame: Vec<f32>,
+    thd: ThdAnalyzer,
+    loudness: LufsMeter,
+    sum_sq: f64,
+    count: usize,
+    peak: f32,
+}
+
+impl LiveMeter {
+    fn new(sample_rate: f32, fft_size: usize) -> Self {
+        Self {
+            sample_rate,
+            fft: Fft::new(fft_size),
+            window: Window::Hann.coefficients(fft_size),
+            frame: vec![0.0; fft_size],
+            thd: ThdAnalyzer::new(sample_rate, fft_size),
+            loudness: LufsMeter::new(sample_rate),
+            sum_sq: 0.0,
+            count: 0,
+            peak: 0.0,
+        }
+    }
+
+    /// Feed new samples.
+    fn push(&mut self, samples: &[f32]) {
+        // Dual mono, as the GUI analyzer meters its mono output
+        self.loudness.push_samples(samples, samples);
+        for &s in samples {
+            self.sum_sq += (s as f64) * (s as f64);
+            self.peak = self.peak.max(s.abs());
+        }
+        self.count += samples.len();
+
+        let n = self.frame.len();
+        if samples.len() >= n {
+            self.frame.copy_from_slice(&samples[samples.len() - n..]);
+        } else {
+            self.frame.rotate_left(samples.len());
+            self.frame[n - samples.len()..].copy_from_slice(samples);
+        }
+    }
+
+    /// Analyze the current frame and reset the level accumulators.
+    fn reading(&mut self) -> LiveReading {
+        let rms = if self.count > 0 {
+            (self.sum_sq / self.count as f64).sqrt() as f32
+        } else {
+            0.0
+        };
+        let rms_db = 20.0 * rms.max(1e-10).log10();
+        let peak_db = 20.0 * self.peak.max(1e-10).log10();
+        self.sum_sq = 0.0;
+        self.count = 0;
+        self.peak = 0.0;
+
+        let windowed: Vec<f32> = self
+            .frame
+            .iter()
+            .zip(&self.window)
+            .map(|(s, w)| s * w)
+            .collect();
+        let magnitudes: Vec<f32> = self
+            .fft
+            .forward(&windowed)
+            .iter()
+            .take(self.frame.len() / 2 + 1)
+            .map(|c| c.norm())
+            .collect();
+        let bin_width = self.sample_rate / self.frame.len() as f32;
+        // Skip DC when looking for the peak
+        let peak_bin = magnitudes
+            .iter()
+            .enumerate()
+            .skip(1)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(i, _)| i);
+
+        let total: f32 = magnitudes.iter().skip(1).map(|m| m * m).sum();
+        let around_peak: f32 = magnitudes
+            [peak_bin.saturating_sub(3).max(1)..(peak_bin + 4).min(magnitudes.len())]
+            .iter()
+            .map(|m| m * m)
+            .sum();
+        let tonal = total > 0.0 && around_peak / total >= TONAL_POWER_FRACTION;
+        let thd = (tonal && rms_db >= SILENCE_DB).then(|| {
+            self.thd
+                .analyze(&self.frame, peak_bin as f32 * bin_width)
+                .thd_ratio
+        });
+
+        LiveReading {
+            rms_db,
+            peak_db,
+            peak_freq: peak_bin as f32 * bin_width,
+            centroid: spectral_centroid(&magnitudes, self.sample_rate),
+            thd,
+            momentary: self.loudness.momentary(),
+            short_term: self.loudness.short_term(),
+        }
+    }
+}
+
+/// Format a loudness value, showing `--` before the meter has a reading.
+fn format_lufs(value: f32, unit: &str) -> String {
+    if value.is_finite() {
+        format!("{value:6.1} {unit}")
+    } else {
+        format!("   -- {unit}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn tone(sample_rate: f32, freq: f32, amplitude: f32, second: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|n| {
+                let phase = 2.0 * PI * freq * n as f32 / sample_rate;
+                amplitude * (phase.sin() + second * (2.0 * phase).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_level_frequency_and_thd_of_a_tone() {
+        let mut meter = LiveMeter::new(48000.0, 8192);
+        meter.push(&tone(48000.0, 1000.0, 0.5, 0.01, 48000));
+        let reading = meter.reading();
+
+        assert!((reading.rms_db - (-9.03)).abs() < 0.1, "{reading:?}");
+        assert!((reading.peak_db - (-6.0)).abs() < 0.1, "{reading:?}");
+        assert!((reading.peak_freq - 1000.0).abs() < 10.0, "{reading:?}");
+        let thd = reading.thd.expect("tone should be detected");
+        assert!((thd - 0.01).abs() < 0.002, "{reading:?}");
+        assert!(reading.momentary.is_finite());
+    }
+
+    #[test]
+    fn noise_has_no_thd_and_levels_reset() {
+        let mut meter = LiveMeter::new(48000.0, 4096);
+        let mut seed = 1u32;
+        let noise: Vec<f32> = (0..8192)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 8) as f32 / (1u32 << 24) as f32 - 0.5
+            })
+            .collect();
+        meter.push(&noise);
+        let reading = meter.reading();
+        assert!(reading.thd.is_none());
+        assert!(reading.centroid > 5000.0, "{reading:?}");
+
+        assert!(meter.reading().rms_db < SILENCE_DB);
+    }
+
+    #[test]
+    fn tap_returns_newest_samples_in_order() {
+        let tap = InputTap::new();
+        let block: Vec<f32> = (0..InputTap::CAPACITY + 10).map(|i| i as f32).collect();
+        tap.push(&block);
+        let mut out = [0.0; 4];
+        tap.copy_latest(&mut out);
+        let last = (InputTap::CAPACITY + 9) as f32;
+        assert_eq!(out, [last - 3.0, last - 2.0, last - 1.0, last]);
+    }
+}
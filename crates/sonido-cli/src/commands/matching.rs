@@ -0,0 +1,338 @@
+//! Fit an effect's parameters to a reference recording.
+//!
+//! `sonido match` takes a dry recording and the same material processed by
+//! the target (a hardware pedal, a plugin, a mix), then searches the chosen
+//! effect's normalized parameters with [`PatternSearch`] to minimize the
+//! distance between the rendered and target spectra. The cost is the mean
+//! 1/3-octave band level difference ([`band_distance_db`]), optionally plus
+//! a weighted THD difference for distortion fitting on test-tone material.
+//!
+//! The result is printed as a chain spec and can be saved as a preset.
+
+use super::common::parse_key_val;
+use crate::effects::create_effect_with_params;
+use clap::Args;
+use sonido_analysis::{PatternSearch, ThdAnalyzer, band_distance_db};
+use sonido_core::EffectWithParams;
+use sonido_core::graph::{GraphSnapshot, SnapshotEntry};
+use sonido_core::param_info::{ParamDescriptor, ParamFlags};
+use sonido_graph_dsl::{
+    resolve_effect_name, resolve_param_index, snapshot_to_dsl, snapshot_to_preset,
+};
+use sonido_io::read_wav;
+use sonido_registry::EffectRegistry;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Block size used when rendering candidates.
+const BLOCK_SIZE: usize = 512;
+
+#[derive(Args)]
+pub struct MatchArgs {
+    /// Dry (unprocessed) recording
+    #[arg(value_name = "DRY")]
+    dry: PathBuf,
+
+    /// The same material processed by the tone to match
+    #[arg(value_name = "TARGET")]
+    target: PathBuf,
+
+    /// Effect whose parameters are searched (e.g., eq, distortion)
+    #[arg(short, long)]
+    effect: String,
+
+    /// Parameters to search, comma-separated (default: all continuous parameters)
+    #[arg(long, value_delimiter = ',')]
+    params: Vec<String>,
+
+    /// Parameters held at a fixed value during the search (e.g., "shape=soft")
+    #[arg(long, value_parser = parse_key_val, number_of_values = 1)]
+    fixed: Vec<(String, String)>,
+
+    /// Maximum number of candidate renders
+    #[arg(long, default_value = "300")]
+    iterations: usize,
+
+    /// Length of audio analyzed from the start of both files, in seconds
+    #[arg(long, default_value = "5")]
+    seconds: f32,
+
+    /// FFT size for spectral analysis
+    #[arg(long, default_value = "4096")]
+    fft_size: usize,
+
+    /// Weight of the THD difference (dB) in the cost; 0 disables it.
+    /// Most useful when the recordings are a steady test tone.
+    #[arg(long, default_value = "0")]
+    thd_weight: f32,
+
+    /// Save the fitted parameters as a preset file (.toml)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Preset name (defaults to "<effect>-match")
+    #[arg(long)]
+    name: Option<String>,
+}
+
+pub fn run(args: MatchArgs) -> anyhow::Result<()> {
+    let (dry, dry_spec) = read_wav(&args.dry)?;
+    let (target, target_spec) = read_wav(&args.target)?;
+    if dry_spec.sample_rate != target_spec.sample_rate {
+        anyhow::bail!(
+            "Sample rates differ: {} Hz vs {} Hz",
+            dry_spec.sample_rate,
+            target_spec.sample_rate
+        );
+    }
+    let sample_rate = dry_spec.sample_rate as f32;
+
+    let len = dry
+        .len()
+        .min(target.len())
+        .min((args.seconds * sample_rate) as usize);
+    if len < args.fft_size {
+        anyhow::bail!(
+            "Need at least {} samples of overlapping audio, got {}",
+            args.fft_size,
+            len
+        );
+    }
+    let dry = &dry[..len];
+    let target = &target[..len];
+
+    let fixed: HashMap<String, String> = args.fixed.into_iter().collect();
+    let template = create_effect_with_params(&args.effect, sample_rate, &fixed)?;
+    let effect_id = resolve_effect_name(&args.effect);
+    let registry = EffectRegistry::new();
+    let searched = select_params(
+        &registry,
+        effect_id,
+        template.as_ref(),
+        &args.params,
+        &fixed,
+    )?;
+    if searched.is_empty() {
+        anyhow::bail!("No parameters to search");
+    }
+
+    let thd = (args.thd_weight > 0.0).then(|| ThdAnalyzer::new(sample_rate, args.fft_size));
+    let target_thd_db = thd.as_ref().map(|a| a.analyze_auto(target).thd_db);
+
+    let render = |x: &[f32]| -> anyhow::Result<Box<dyn EffectWithParams + Send>> {
+        let mut effect = create_effect_with_params(&args.effect, sample_rate, &fixed)?;
+        for (&index, &value) in searched.iter().zip(x) {
+            if let Some(desc) = effect.effect_param_info(index) {
+                effect.effect_set_param(index, desc.denormalize(value));
+            }
+        }
+        Ok(effect)
+    };
+    let cost = |x: &[f32]| -> f32 {
+        let Ok(mut effect) = render(x) else {
+            return f32::INFINITY;
+        };
+        let output = process_mono(effect.as_mut(), dry);
+        let mut cost = band_distance_db(&output, target, sample_rate, args.fft_size);
+        if let (Some(analyzer), Some(target_db)) = (&thd, target_thd_db) {
+            cost += args.thd_weight * (analyzer.analyze_auto(&output).thd_db - target_db).abs();
+        }
+        cost
+    };
+
+    let start: Vec<f32> = searched
+        .iter()
+        .filter_map(|&i| {
+            template
+                .effect_param_info(i)
+                .map(|desc| desc.normalize(template.effect_get_param(i)))
+        })
+        .collect();
+    let names: Vec<&str> = searched
+        .iter()
+        .filter_map(|&i| template.effect_param_info(i).map(|d| d.name))
+        .collect();
+
+    println!(
+        "Matching {} ({} Hz, {:.2}s)",
+        effect_id,
+        dry_spec.sample_rate,
+        len as f32 / sample_rate
+    );
+    println!("  Searching: {}", names.join(", "));
+
+    let initial_cost = cost(&start);
+    let search = PatternSearch {
+        max_evaluations: args.iterations.max(1),
+        ..PatternSearch::default()
+    };
+    let result = search.minimize(&start, cost);
+
+    let fitted = render(&result.params)?;
+    println!("\nMatch Result");
+    println!("------------");
+    println!("  Renders:       {}", result.evaluations);
+    println!("  Initial cost:  {:.2} dB", initial_cost);
+    println!("  Final cost:    {:.2} dB", result.cost);
+    println!();
+    for &index in &searched {
+        if let Some(desc) = fitted.effect_param_info(index) {
+            println!(
+                "  {:<20} {}",
+                desc.name,
+                desc.format_value(fitted.effect_get_param(index))
+            );
+        }
+    }
+
+    let snapshot = GraphSnapshot {
+        entries: vec![SnapshotEntry {
+            effect_id: effect_id.to_string(),
+            params: (0..fitted.effect_param_count())
+                .map(|i| fitted.effect_get_param(i))
+                .collect(),
+            bypassed: false,
+        }],
+        topology: None,
+    };
+    println!("\nChain: {}", snapshot_to_dsl(&snapshot, &registry));
+
+    if let Some(path) = &args.output {
+        let name = args
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("{effect_id}-match"));
+        let preset = snapshot_to_preset(&snapshot, &name, &registry).with_description(format!(
+            "Fitted to {} by sonido match",
+            args.target.display()
+        ));
+        preset.save(path)?;
+        println!("Saved preset '{}' to {}", name, path.display());
+    }
+
+    Ok(())
+}
+
+/// Resolve the parameters to search.
+///
+/// With no explicit list, every continuous parameter that is not held by
+/// `--fixed` is searched. Stepped (enum) parameters cannot be searched and
+/// must be set with `--fixed` instead.
+fn select_params(
+    registry: &EffectRegistry,
+    effect_id: &str,
+    effect: &dyn EffectWithParams,
+    requested: &[String],
+    fixed: &HashMap<String, String>,
+) -> anyhow::Result<Vec<usize>> {
+    if requested.is_empty() {
+        let fixed_indices: Vec<usize> = fixed
+            .keys()
+            .filter_map(|k| resolve_param_index(registry, effect_id, k))
+            .collect();
+        return Ok((0..effect.effect_param_count())
+            .filter(|i| !fixed_indices.contains(i))
+            .filter(|&i| {
+                effect
+                    .effect_param_info(i)
+                    .is_some_and(|d| is_searchable(&d))
+            })
+            .collect());
+    }
+
+    requested
+        .iter()
+        .map(|name| {
+            let index = resolve_param_index(registry, effect_id, name.trim()).ok_or_else(|| {
+                anyhow::anyhow!("Unknown parameter '{}' for effect '{}'", name, effect_id)
+            })?;
+            match effect.effect_param_info(index) {
+                Some(desc) if is_searchable(&desc) => Ok(index),
+                _ => anyhow::bail!(
+                    "Parameter '{}' is not continuous; set it with --fixed instead",
+                    name
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Continuous, user-facing parameters are the only ones worth searching.
+fn is_searchable(desc: &ParamDescriptor) -> bool {
+    !desc.flags.contains(ParamFlags::STEPPED)
+        && !desc.flags.contains(ParamFlags::HIDDEN)
+        && !desc.flags.contains(ParamFlags::READ_ONLY)
+}
+
+/// Render `input` through `effect` in fixed-size blocks.
+fn process_mono(effect: &mut dyn EffectWithParams, input: &[f32]) -> Vec<f32> {
+    let mut output = vec![0.0; input.len()];
+    for (inp, out) in input.chunks(BLOCK_SIZE).zip(output.chunks_mut(BLOCK_SIZE)) {
+        effect.process_block(inp, out);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distortion() -> Box<dyn EffectWithParams + Send> {
+        create_effect_with_params("distortion", 48000.0, &HashMap::new()).unwrap()
+    }
+
+    #[test]
+    fn default_selection_skips_stepped_and_fixed() {
+        let registry = EffectRegistry::new();
+        let effect = distortion();
+        let fixed: HashMap<String, String> = [("drive".to_string(), "20".to_string())].into();
+        let drive = resolve_param_index(&registry, "distortion", "drive").unwrap();
+
+        let selected =
+            select_params(&registry, "distortion", effect.as_ref(), &[], &fixed).unwrap();
+        assert!(!selected.is_empty());
+        assert!(!selected.contains(&drive));
+        for i in selected {
+            assert!(is_searchable(&effect.effect_param_info(i).unwrap()));
+        }
+    }
+
+    #[test]
+    fn explicit_selection_rejects_unknown_and_stepped() {
+        let registry = EffectRegistry::new();
+        let effect = distortion();
+        let none = HashMap::new();
+
+        let drive = resolve_param_index(&registry, "distortion", "drive").unwrap();
+        let selected = select_params(
+            &registry,
+            "distortion",
+            effect.as_ref(),
+            &["drive".into()],
+            &none,
+        )
+        .unwrap();
+        assert_eq!(selected, vec![drive]);
+
+        assert!(
+            select_params(
+                &registry,
+                "distortion",
+                effect.as_ref(),
+                &["nope".into()],
+                &none
+            )
+            .is_err()
+        );
+        assert!(
+            select_params(
+                &registry,
+                "distortion",
+                effect.as_ref(),
+                &["shape".into()],
+                &none
+            )
+            .is_err()
+        );
+    }
+}
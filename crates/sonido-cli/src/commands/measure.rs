@@ -0,0 +1,354 @@
+//! One-step hardware measurement.
+//!
+//! `sonido measure` plays a [`MeasurementPlan`] stimulus (log sweep followed
+//! by stepped-level test tones) out of the audio interface, records the
+//! device's return, and writes a measurement report. It replaces the manual
+//! `generate sweep` → record → `analyze ir` / `transfer` / `distortion`
+//! workflow.
+//!
+//! The stimulus can also be exported (`--export-stimulus`) and played with
+//! another tool, with the recording analyzed afterwards (`--recording`).
+
+use clap::Args;
+use sonido_analysis::export::export_frd;
+use sonido_analysis::ir::SweepConfig;
+use sonido_analysis::{MeasurementPlan, MeasurementReport};
+use sonido_io::{AudioStream, StreamConfig, WavSpec, read_wav, write_wav};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+
+#[derive(Args)]
+pub struct MeasureArgs {
+    /// Directory for the report files (created if missing)
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        required_unless_present = "export_stimulus"
+    )]
+    output: Option<PathBuf>,
+
+    /// Analyze an existing recording of the stimulus instead of playing it
+    #[arg(long, value_name = "FILE", conflicts_with = "export_stimulus")]
+    recording: Option<PathBuf>,
+
+    /// Write the stimulus to a WAV file and exit
+    #[arg(long, value_name = "FILE")]
+    export_stimulus: Option<PathBuf>,
+
+    /// Input device (index, exact name, or partial name)
+    #[arg(long)]
+    input_device: Option<String>,
+
+    /// Output device (index, exact name, or partial name)
+    #[arg(long)]
+    output_device: Option<String>,
+
+    /// Input channel carrying the device's return (1 = left, 2 = right)
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u8).range(1..=2))]
+    return_channel: u8,
+
+    /// Sample rate in Hz
+    #[arg(long, default_value = "48000")]
+    sample_rate: u32,
+
+    /// Sweep start frequency in Hz
+    #[arg(long, default_value = "20")]
+    start_freq: f32,
+
+    /// Sweep end frequency in Hz
+    #[arg(long, default_value = "20000")]
+    end_freq: f32,
+
+    /// Sweep duration in seconds
+    #[arg(long, default_value = "5")]
+    sweep_seconds: f32,
+
+    /// Sweep peak level in dBFS
+    #[arg(long, default_value = "-12", allow_hyphen_values = true)]
+    sweep_level: f32,
+
+    /// Test tone frequency for the level steps in Hz
+    #[arg(long, default_value = "1000")]
+    tone_freq: f32,
+
+    /// Quietest tone step in dBFS
+    #[arg(long, default_value = "-42", allow_hyphen_values = true)]
+    level_min: f32,
+
+    /// Loudest tone step in dBFS
+    #[arg(long, default_value = "0", allow_hyphen_values = true)]
+    level_max: f32,
+
+    /// Spacing between tone steps in dB
+    #[arg(long, default_value = "6")]
+    level_step: f32,
+
+    /// Silence after the sweep and each tone in seconds (must exceed the
+    /// round-trip latency plus the device's decay)
+    #[arg(long, default_value = "1")]
+    gap: f32,
+}
+
+pub fn run(args: MeasureArgs) -> anyhow::Result<()> {
+    let plan = build_plan(&args)?;
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: args.sample_rate,
+        bits_per_sample: 32,
+    };
+
+    if let Some(path) = &args.export_stimulus {
+        write_wav(path, &plan.stimulus(), spec)?;
+        println!(
+            "Wrote {:.1}s stimulus to {}",
+            plan.len() as f32 / plan.sample_rate(),
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let Some(dir) = &args.output else {
+        anyhow::bail!("--output is required");
+    };
+    std::fs::create_dir_all(dir)?;
+
+    let recorded = match &args.recording {
+        Some(path) => {
+            let (samples, rec_spec) = read_wav(path)?;
+            if rec_spec.sample_rate != args.sample_rate {
+                anyhow::bail!(
+                    "Recording is {} Hz but the plan is {} Hz (use --sample-rate)",
+                    rec_spec.sample_rate,
+                    args.sample_rate
+                );
+            }
+            samples
+        }
+        None => {
+            let samples = play_and_record(&plan, &args)?;
+            let path = dir.join("recording.wav");
+            write_wav(&path, &samples, spec)?;
+            println!("  Wrote raw recording to {}", path.display());
+            samples
+        }
+    };
+
+    let report = plan.analyze(&recorded);
+    print_report(&report, &plan);
+    write_report(&report, &plan, dir, spec)?;
+    Ok(())
+}
+
+/// Build the stimulus layout from the command-line options.
+fn build_plan(args: &MeasureArgs) -> anyhow::Result<MeasurementPlan> {
+    if args.level_step <= 0.0 || args.level_min > args.level_max {
+        anyhow::bail!("Need --level-min <= --level-max and a positive --level-step");
+    }
+    if args.level_max > 0.0 || args.sweep_level > 0.0 {
+        anyhow::bail!("Levels are in dBFS and cannot exceed 0");
+    }
+    let sample_rate = args.sample_rate as f32;
+
+    Ok(MeasurementPlan {
+        sweep: SweepConfig {
+            start_freq: args.start_freq,
+            end_freq: args.end_freq.min(sample_rate * 0.5),
+            duration_secs: args.sweep_seconds,
+            sample_rate,
+        },
+        sweep_level_db: args.sweep_level,
+        tone_freq: args.tone_freq,
+        tone_levels_db: tone_levels(args.level_min, args.level_max, args.level_step),
+        gap_secs: args.gap,
+        ..MeasurementPlan::default()
+    })
+}
+
+/// Levels from `min` up to `max` (inclusive when it falls on a step).
+fn tone_levels(min: f32, max: f32, step: f32) -> Vec<f32> {
+    let steps = ((max - min) / step + 1e-3).floor() as usize;
+    (0..=steps).map(|i| min + i as f32 * step).collect()
+}
+
+/// Play the stimulus on both output channels and record the return channel.
+fn play_and_record(plan: &MeasurementPlan, args: &MeasureArgs) -> anyhow::Result<Vec<f32>> {
+    let stimulus = plan.stimulus();
+    let total = stimulus.len();
+
+    let mut stream = AudioStream::new(StreamConfig {
+        sample_rate: args.sample_rate,
+        buffer_size: 512,
+        input_device: args.input_device.clone(),
+        output_device: args.output_device.clone(),
+    })?;
+    let running = stream.running_handle();
+
+    println!(
+        "Measuring: {:.1}s stimulus at {} Hz, recording input channel {}",
+        total as f32 / plan.sample_rate(),
+        args.sample_rate,
+        args.return_channel
+    );
+    println!("  Keep the device connected and avoid touching the controls...");
+
+    let result = Arc::new(Mutex::new(Vec::new()));
+    let cb_result = Arc::clone(&result);
+    let use_right = args.return_channel == 2;
+    let mut recorded = Vec::with_capacity(total);
+    let mut position = 0;
+
+    stream.run_stereo(move |left_in, right_in, left_out, right_out| {
+        let input = if use_right { right_in } else { left_in };
+        for ((l, r), &x) in left_out.iter_mut().zip(right_out.iter_mut()).zip(input) {
+            let s = stimulus.get(position).copied().unwrap_or(0.0);
+            *l = s;
+            *r = s;
+            if position < total {
+                recorded.push(x);
+            }
+            position += 1;
+        }
+        if position >= total && !recorded.is_empty() {
+            if let Ok(mut out) = cb_result.lock() {
+                *out = std::mem::take(&mut recorded);
+            }
+            running.store(false, Ordering::SeqCst);
+        }
+    })?;
+
+    let recorded = std::mem::take(
+        &mut *result
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Recording buffer poisoned"))?,
+    );
+    if recorded.len() < total {
+        anyhow::bail!(
+            "Measurement stopped early ({} of {} samples)",
+            recorded.len(),
+            total
+        );
+    }
+    Ok(recorded)
+}
+
+fn print_report(report: &MeasurementReport, plan: &MeasurementPlan) {
+    let sample_rate = plan.sample_rate();
+    println!("\nMeasurement Report");
+    println!("------------------");
+    println!(
+        "  Latency:   {} samples ({:.2} ms)",
+        report.latency_samples,
+        report.latency_samples as f32 * 1000.0 / sample_rate
+    );
+    println!(
+        "  Polarity:  {}",
+        if report.inverted {
+            "inverted"
+        } else {
+            "normal"
+        }
+    );
+    if let Some(gain) = report.small_signal_gain_db() {
+        println!("  Small-signal gain: {:+.1} dB", gain);
+    }
+    match report.input_level_at_thd(0.01) {
+        Some(level) => println!("  1% THD reached at: {:.1} dBFS input", level),
+        None => println!("  1% THD reached at: not reached"),
+    }
+
+    let response = &report.response;
+    if !response.magnitude_db.is_empty() {
+        let reference = response.magnitude_at(1000.0);
+        println!("\n  Frequency response (relative to 1 kHz):");
+        for freq in [50.0, 100.0, 200.0, 500.0, 2000.0, 5000.0, 10000.0] {
+            if (plan.sweep.start_freq..=plan.sweep.end_freq).contains(&freq) {
+                println!(
+                    "    {:>6.0} Hz: {:+6.1} dB",
+                    freq,
+                    response.magnitude_at(freq) - reference
+                );
+            }
+        }
+    }
+
+    println!("\n  Level steps ({:.0} Hz tone):", plan.tone_freq);
+    println!("    Input dBFS  Output dBFS  Gain dB    THD %");
+    for p in &report.levels {
+        println!(
+            "    {:>10.1}  {:>11.1}  {:>7.1}  {:>7.3}",
+            p.input_db,
+            p.output_rms_db,
+            p.gain_db,
+            p.thd_ratio * 100.0
+        );
+    }
+}
+
+/// Write `response.frd`, `ir.wav`, and `report.json` into `dir`.
+fn write_report(
+    report: &MeasurementReport,
+    plan: &MeasurementPlan,
+    dir: &Path,
+    spec: WavSpec,
+) -> anyhow::Result<()> {
+    let frd = dir.join("response.frd");
+    export_frd(&report.response, &frd)?;
+    let ir = dir.join("ir.wav");
+    write_wav(&ir, &report.impulse_response, spec)?;
+
+    let levels: Vec<_> = report
+        .levels
+        .iter()
+        .map(|p| {
+            serde_json::json!({
+                "input_dbfs": p.input_db,
+                "output_rms_dbfs": p.output_rms_db,
+                "gain_db": p.gain_db,
+                "thd_percent": p.thd_ratio * 100.0,
+            })
+        })
+        .collect();
+    let json = serde_json::json!({
+        "sample_rate": plan.sample_rate(),
+        "latency_samples": report.latency_samples,
+        "latency_ms": report.latency_samples as f32 * 1000.0 / plan.sample_rate(),
+        "polarity_inverted": report.inverted,
+        "small_signal_gain_db": report.small_signal_gain_db(),
+        "thd_1_percent_input_dbfs": report.input_level_at_thd(0.01),
+        "sweep": {
+            "start_hz": plan.sweep.start_freq,
+            "end_hz": plan.sweep.end_freq,
+            "seconds": plan.sweep.duration_secs,
+            "level_dbfs": plan.sweep_level_db,
+        },
+        "tone_hz": plan.tone_freq,
+        "levels": levels,
+    });
+    let path = dir.join("report.json");
+    std::fs::write(&path, serde_json::to_string_pretty(&json)?)?;
+
+    println!(
+        "\nWrote {}, {}, {}",
+        frd.display(),
+        ir.display(),
+        path.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_levels_include_both_ends() {
+        assert_eq!(
+            tone_levels(-42.0, 0.0, 6.0),
+            vec![-42.0, -36.0, -30.0, -24.0, -18.0, -12.0, -6.0, 0.0]
+        );
+        assert_eq!(tone_levels(-20.0, -3.0, 6.0), vec![-20.0, -14.0, -8.0]);
+        assert_eq!(tone_levels(-6.0, -6.0, 3.0), vec![-6.0]);
+    }
+}
@@ -1,14 +1,23 @@
 //! CLI command implementations.
 
+pub mod abx;
 pub mod analyze;
+pub mod bench;
 pub mod common;
 pub mod compare;
+pub mod completions;
 pub mod daisy;
 pub mod devices;
 pub mod effects;
 pub mod generate;
+pub mod graph;
 pub mod info;
+pub mod live;
+pub mod matching;
+pub mod measure;
 pub mod play;
 pub mod presets;
 pub mod process;
 pub mod realtime;
+#[cfg(feature = "script")]
+pub mod script;
@@ -1,8 +1,10 @@
 //! Audio file playback command with optional effect processing.
 
 use super::common::{load_preset, parse_key_val};
+use super::completions::{complete_effect_id, complete_effect_spec};
 use crate::effects::{create_effect_with_params, parse_chain};
 use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
 use sonido_io::{AudioStream, GraphEngine, StreamConfig, read_wav_stereo};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -16,11 +18,11 @@ pub struct PlayArgs {
     file: PathBuf,
 
     /// Single effect to apply during playback
-    #[arg(short, long)]
+    #[arg(short, long, add = ArgValueCompleter::new(complete_effect_id))]
     effect: Option<String>,
 
     /// Effect chain specification (e.g., "preamp:gain=6|distortion:drive=15")
-    #[arg(short, long)]
+    #[arg(short, long, add = ArgValueCompleter::new(complete_effect_spec))]
     chain: Option<String>,
 
     /// Preset name or path
@@ -47,6 +47,10 @@ enum PresetsCommand {
         #[arg(short, long)]
         description: Option<String>,
 
+        /// Global dry/wet mix in percent (0 = dry, 100 = fully processed)
+        #[arg(long, value_name = "PERCENT", default_value_t = 100.0)]
+        mix: f32,
+
         /// Overwrite if preset already exists
         #[arg(long)]
         force: bool,
@@ -94,8 +98,9 @@ pub fn run(args: PresetsArgs) -> anyhow::Result<()> {
             name,
             chain,
             description,
+            mix,
             force,
-        } => save_preset(&name, &chain, description.as_deref(), force),
+        } => save_preset(&name, &chain, description.as_deref(), mix, force),
         PresetsCommand::Delete { name, force } => delete_preset(&name, force),
         PresetsCommand::Copy { source, name } => copy_preset(&source, name.as_deref()),
         PresetsCommand::ExportFactory { output_dir, force } => export_factory(&output_dir, force),
@@ -164,6 +169,9 @@ fn show_preset(name: &str) -> anyhow::Result<()> {
     }
 
     println!("Sample Rate: {} Hz", preset.sample_rate);
+    if preset.mix < 100.0 {
+        println!("Mix: {}% processed", preset.mix);
+    }
     println!();
 
     println!("Effects ({}):", preset.effects.len());
@@ -176,6 +184,21 @@ fn show_preset(name: &str) -> anyhow::Result<()> {
                 println!("      {} = {}", key, value);
             }
         }
+        for send in &effect.sends {
+            let tap = if send.pre_fader { "pre" } else { "post" };
+            println!(
+                "      → {} at {:.1} dB ({tap}-fader)",
+                send.aux, send.level_db
+            );
+        }
+    }
+
+    for aux in &preset.aux {
+        println!();
+        println!("Aux bus '{}' return ({}):", aux.name, aux.effects.len());
+        for (i, effect) in aux.effects.iter().enumerate() {
+            println!("  {}. {}", i + 1, effect.display_type());
+        }
     }
 
     println!();
@@ -192,8 +215,13 @@ fn save_preset(
     name: &str,
     chain: &str,
     description: Option<&str>,
+    mix: f32,
     force: bool,
 ) -> anyhow::Result<()> {
+    if !(0.0..=100.0).contains(&mix) {
+        anyhow::bail!("--mix must be between 0 and 100, got {mix}");
+    }
+
     // Ensure user presets directory exists
     ensure_user_presets_dir()?;
 
@@ -214,7 +242,7 @@ fn save_preset(
     if let Some(desc) = description {
         preset = preset.with_description(desc);
     }
-    preset = preset.with_effects(effects);
+    preset = preset.with_mix(mix).with_effects(effects);
 
     // Save the preset
     preset.save(&preset_path)?;
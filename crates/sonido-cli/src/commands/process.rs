@@ -1,38 +1,60 @@
 //! File-based effect processing command.
 
-use super::common::{load_preset, parse_key_val};
-use crate::effects::{create_effect_with_params, parse_chain};
-use crate::graph_dsl::{build_graph, build_graph_slug, parse_graph_dsl, validate_spec};
+use super::common::{load_graph, load_preset, parse_key_val};
+use super::completions::complete_effect_spec;
+use crate::effects::{
+    check_effect_params, create_effect_with_params, parse_chain, parse_effect_spec,
+};
+use crate::graph_dsl::{
+    GraphNode, build_graph_from_preset, build_graph_slug, parse_graph_dsl, validate_spec,
+};
 use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
 use indicatif::{ProgressBar, ProgressStyle};
-use sonido_analysis::dynamics;
-use sonido_core::linear_to_db;
-use sonido_io::{GraphEngine, WavSpec, read_wav_stereo, write_wav, write_wav_stereo};
+use sonido_analysis::LufsMeter;
+use sonido_config::Preset;
+use sonido_core::safety_limiter::{DEFAULT_CEILING_DB, MIN_CEILING_DB};
+use sonido_core::{TailLength, db_to_linear, linear_to_db};
+use sonido_io::{
+    GraphEngine, WavBlockReader, WavBlockWriter, WavSpec, process_wav_stream, read_wav_info,
+    read_wav_metadata, write_wav_metadata,
+};
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+mod watch;
 
 #[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ProcessArgs {
     /// Input WAV file
-    #[arg(value_name = "INPUT")]
-    input: PathBuf,
+    #[arg(value_name = "INPUT", required_unless_present = "check")]
+    input: Option<PathBuf>,
 
     /// Output WAV file (auto-generated if omitted)
     #[arg(value_name = "OUTPUT")]
     output: Option<PathBuf>,
 
-    /// Single effect to apply
-    #[arg(short, long)]
+    /// Single effect to apply, with optional inline parameters (e.g., "distortion:drive=15")
+    #[arg(short, long, add = ArgValueCompleter::new(complete_effect_spec))]
     effect: Option<String>,
 
     /// Effect chain specification (e.g., "preamp:gain=6|distortion:drive=15")
-    #[arg(short, long, conflicts_with = "graph")]
+    #[arg(short, long, conflicts_with = "graph", add = ArgValueCompleter::new(complete_effect_spec))]
     chain: Option<String>,
 
-    /// Graph topology specification with split/merge (e.g., "split(distortion; -)|limiter")
+    /// Graph topology specification with split/merge (e.g., "split(distortion; -)|limiter"),
+    /// or a JSON graph snapshot file written by --export
     #[arg(short, long, conflicts_with_all = ["effect", "chain", "preset"])]
     graph: Option<String>,
 
+    /// Write the built graph (topology, parameters, bypass states) as a JSON snapshot
+    #[arg(long, value_name = "FILE", requires = "graph")]
+    export: Option<PathBuf>,
+
     /// Preset name or path (supports factory presets, user presets, and file paths)
     #[arg(short, long)]
     preset: Option<String>,
@@ -41,6 +63,12 @@ pub struct ProcessArgs {
     #[arg(long, value_parser = parse_key_val, number_of_values = 1)]
     param: Vec<(String, String)>,
 
+    /// Global dry/wet mix in percent (0 = dry, 100 = fully processed). The
+    /// dry signal is delayed by the chain's latency so the blend stays in
+    /// phase. Overrides the preset's `mix`.
+    #[arg(long, value_name = "PERCENT")]
+    mix: Option<f32>,
+
     /// Processing block size
     #[arg(long, default_value = "512")]
     block_size: usize,
@@ -52,52 +80,443 @@ pub struct ProcessArgs {
     /// Force mono output (mix stereo to mono)
     #[arg(long)]
     mono: bool,
+
+    /// Normalize the output to this integrated loudness in LUFS (e.g. -14)
+    #[arg(long, value_name = "LUFS", allow_hyphen_values = true)]
+    normalize_lufs: Option<f32>,
+
+    /// Apply the output safety limiter (a brickwall after the whole chain).
+    /// Unlike `realtime`, where it is on unless --no-limiter, offline renders
+    /// leave it off so peaks above 0 dBFS survive in float output and
+    /// --normalize-lufs. Panic and safe mode are live-only controls.
+    #[arg(long)]
+    limiter: bool,
+
+    /// Output safety limiter ceiling in dBFS (-24 to 0)
+    #[arg(
+        long,
+        value_name = "DB",
+        default_value_t = DEFAULT_CEILING_DB,
+        allow_hyphen_values = true,
+        requires = "limiter"
+    )]
+    limiter_ceiling: f32,
+
+    /// Keep rendering after the input ends so reverb and delay tails ring
+    /// out: "auto" (until the output falls below --tail-threshold) or a
+    /// length in seconds
+    #[arg(long, value_name = "auto|SECONDS", value_parser = parse_tail)]
+    tail: Option<Tail>,
+
+    /// Level in dBFS below which `--tail auto` treats the output as silent
+    #[arg(
+        long,
+        value_name = "DB",
+        default_value = "-80",
+        allow_hyphen_values = true
+    )]
+    tail_threshold: f32,
+
+    /// Also write each effect stage's output and the dry input as separate
+    /// files next to the output (`<output>_dry.wav`, `<output>_01_<effect>.wav`, ...)
+    #[arg(long)]
+    stems: bool,
+
+    /// Validate the preset, chain, or graph (effects, parameter values,
+    /// schedule) and exit without processing audio. INPUT is optional.
+    #[arg(long)]
+    check: bool,
+
+    /// Re-render whenever the preset file (or a `.json` graph snapshot)
+    /// changes on disk, until Ctrl+C
+    #[arg(long, conflicts_with = "check")]
+    watch: bool,
+
+    /// With --watch, also re-render when the input file changes
+    #[arg(long, requires = "watch")]
+    watch_input: bool,
+
+    /// With --watch, loop the latest render on the default output device
+    #[arg(long, requires = "watch")]
+    play: bool,
+}
+
+/// `--tail` value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tail {
+    /// Until the output decays below the threshold.
+    Auto,
+    /// A fixed length in seconds.
+    Seconds(f32),
+}
+
+/// Quiet time that ends an automatic tail. Longer than the longest delay
+/// time (2 s), so the render doesn't stop between repeats.
+const TAIL_HOLD_SECS: f32 = 2.5;
+
+/// Longest automatic tail, for feedback that never decays.
+const TAIL_MAX_SECS: f32 = 60.0;
+
+/// Parse a `--tail` value: `auto`, or seconds with an optional `s` suffix.
+fn parse_tail(s: &str) -> Result<Tail, String> {
+    if s.eq_ignore_ascii_case("auto") {
+        return Ok(Tail::Auto);
+    }
+    match s.trim_end_matches('s').parse::<f32>() {
+        Ok(secs) if secs >= 0.0 && secs.is_finite() => Ok(Tail::Seconds(secs)),
+        _ => Err(format!(
+            "expected \"auto\" or a length in seconds, got \"{s}\""
+        )),
+    }
+}
+
+impl Tail {
+    /// Engine tail length at `sample_rate`.
+    fn length(self, threshold_db: f32, sample_rate: f32) -> TailLength {
+        match self {
+            Tail::Auto => TailLength::Auto {
+                threshold: db_to_linear(threshold_db),
+                hold: (TAIL_HOLD_SECS * sample_rate) as usize,
+                max: (TAIL_MAX_SECS * sample_rate) as usize,
+            },
+            Tail::Seconds(secs) => TailLength::Fixed((secs * sample_rate) as usize),
+        }
+    }
 }
 
 pub fn run(args: ProcessArgs) -> anyhow::Result<()> {
-    // Read input file as stereo (mono files are duplicated to both channels)
-    println!("Reading {}...", args.input.display());
-    let (samples, spec) = read_wav_stereo(&args.input)?;
+    if let Some(mix) = args.mix
+        && !(0.0..=100.0).contains(&mix)
+    {
+        anyhow::bail!("--mix must be between 0 and 100, got {mix}");
+    }
+    if !(MIN_CEILING_DB..=0.0).contains(&args.limiter_ceiling) {
+        anyhow::bail!(
+            "--limiter-ceiling must be between {MIN_CEILING_DB} and 0 dBFS, got {}",
+            args.limiter_ceiling
+        );
+    }
+    if args.check {
+        return check(&args);
+    }
+    let Some(input) = &args.input else {
+        anyhow::bail!("No input file given");
+    };
+
+    // Ctrl+C stops after the current block and keeps what is rendered.
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&interrupted);
+    ctrlc::set_handler(move || flag.store(true, Ordering::SeqCst))?;
+
+    if args.watch {
+        return watch::run(&args, input, &interrupted);
+    }
+    render(&args, input, &interrupted)?;
+    Ok(())
+}
+
+/// Stream `input` through the effects into the output file and return its
+/// path. Stops early, keeping the partial render, once `interrupted` is set.
+fn render(args: &ProcessArgs, input: &Path, interrupted: &AtomicBool) -> anyhow::Result<PathBuf> {
+    // Input is streamed block by block (mono files are duplicated to both
+    // channels), so memory use doesn't grow with the file length.
+    println!("Reading {}...", input.display());
+    let mut reader = WavBlockReader::open(input)?;
+    let spec = reader.spec();
+    // Broadcast WAV fields, cues, and loops carry over: output starts at the
+    // same sample and keeps the input's sample rate, so positions stay valid.
+    let metadata = read_wav_metadata(input)?;
     let sample_rate = spec.sample_rate as f32;
 
     println!(
         "  {} samples, {} Hz, {} channel(s), {:.2}s",
-        samples.len(),
+        reader.len(),
         spec.sample_rate,
         spec.channels,
-        samples.len() as f32 / sample_rate
+        reader.len() as f32 / sample_rate
+    );
+
+    let output_path = output_path(args, input);
+
+    let block_size = args.block_size;
+    let preset = args.preset.as_deref().map(load_preset).transpose()?;
+    let BuiltEngine {
+        mut engine,
+        stage_names,
+        is_graph_mode,
+    } = build_engine(args, preset.as_ref(), sample_rate, block_size)?;
+    let mix = args.mix.or(preset.as_ref().map(|p| p.mix)).unwrap_or(100.0);
+    engine.set_global_mix_immediate(mix / 100.0);
+    engine.set_output_limiter(args.limiter);
+    engine.set_limiter_ceiling_db(args.limiter_ceiling);
+
+    // Determine output mode
+    let output_stereo = !args.mono;
+    if is_graph_mode {
+        // node_count includes Input + Output; subtract 2 for "processing" nodes
+        let processing_nodes = engine.graph().node_count().saturating_sub(2);
+        println!(
+            "Processing graph topology ({} nodes, {} output)...",
+            processing_nodes,
+            if output_stereo { "stereo" } else { "mono" }
+        );
+    } else {
+        println!(
+            "Processing with {} effect(s) ({} output)...",
+            engine.effect_count(),
+            if output_stereo { "stereo" } else { "mono" }
+        );
+    }
+
+    let out_spec = WavSpec {
+        channels: if output_stereo { 2 } else { 1 },
+        sample_rate: spec.sample_rate,
+        bits_per_sample: args.bit_depth,
+    };
+
+    // Normalization needs the loudness of the whole output, so the first
+    // pass renders to a float file that the gain pass then streams through.
+    // Writing over the input would truncate it mid-read, so that also goes
+    // through a render file that replaces the input at the end.
+    let overwrites_input = same_file(input, &output_path);
+    let render_path = if args.normalize_lufs.is_some() || overwrites_input {
+        output_path.with_extension("render.wav")
+    } else {
+        output_path.clone()
+    };
+    let render_spec = if args.normalize_lufs.is_some() {
+        WavSpec {
+            bits_per_sample: 32,
+            ..out_spec
+        }
+    } else {
+        out_spec
+    };
+    let mut writer = WavBlockWriter::create(&render_path, render_spec)?;
+
+    // Stems are written as they render, before loudness normalization, so
+    // they sum back to the unnormalized mix.
+    let mut stems = if args.stems {
+        engine.capture_stems(true);
+        Some(StemWriters::create(
+            &engine,
+            &stage_names,
+            &output_path,
+            out_spec,
+        )?)
+    } else {
+        None
+    };
+
+    let mut input_level = Level::default();
+    let mut output_level = Level::default();
+    let mut meter = args.normalize_lufs.map(|_| LufsMeter::new(sample_rate));
+    let mut stem_error = None;
+
+    let pb = ProgressBar::new(reader.len() as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("[{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+            .unwrap()
+            .progress_chars("##-"),
+    );
+
+    let processed = process_wav_stream(
+        &mut engine,
+        &mut reader,
+        &mut writer,
+        block_size,
+        |engine, block| {
+            input_level.push(block.input_left, block.input_right);
+            output_level.push(block.output_left, block.output_right);
+            if let Some(meter) = &mut meter {
+                push_loudness(meter, block.output_left, block.output_right, output_stereo);
+            }
+            if let Some(stems) = &mut stems
+                && let Err(err) = stems.write(engine, block.input_left, block.input_right)
+            {
+                stem_error = Some(err);
+                return ControlFlow::Break(());
+            }
+            pb.set_position((block.position + block.input_left.len()) as u64);
+            if interrupted.load(Ordering::SeqCst) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    )?;
+    if let Some(err) = stem_error {
+        return Err(err.into());
+    }
+    // Release the input before a render file replaces it.
+    drop(reader);
+
+    let stopped_early = interrupted.load(Ordering::SeqCst);
+    if stopped_early {
+        pb.abandon_with_message("interrupted");
+        println!(
+            "\nInterrupted: keeping the first {:.2}s ({} samples)",
+            processed as f32 / sample_rate,
+            processed
+        );
+    } else {
+        pb.finish_with_message("done");
+    }
+
+    if let Some(tail) = args.tail.filter(|_| !stopped_early) {
+        // The tail is written as it renders, like the input was.
+        let mut write_error = None;
+        let mut silence = Vec::new();
+        let rendered = engine.render_tail_blocks(
+            tail.length(args.tail_threshold, sample_rate),
+            block_size,
+            |engine, left, right| {
+                let written = writer.write_stereo(left, right).and_then(|()| {
+                    let Some(stems) = &mut stems else {
+                        return Ok(());
+                    };
+                    silence.resize(left.len(), 0.0);
+                    stems.write(engine, &silence, &silence)
+                });
+                if let Err(err) = written {
+                    write_error = Some(err);
+                    return ControlFlow::Break(());
+                }
+                output_level.push(left, right);
+                if let Some(meter) = &mut meter {
+                    push_loudness(meter, left, right, output_stereo);
+                }
+                if interrupted.load(Ordering::SeqCst) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        );
+        if let Some(err) = write_error {
+            return Err(err.into());
+        }
+        println!(
+            "  Rendered {:.2}s tail ({} samples){}",
+            rendered as f32 / sample_rate,
+            rendered,
+            if interrupted.load(Ordering::SeqCst) {
+                ", interrupted"
+            } else {
+                ""
+            }
+        );
+    }
+    writer.finalize()?;
+    if let Some(stems) = stems {
+        for path in stems.finalize()? {
+            println!("  Wrote stem {}", path.display());
+        }
+    }
+
+    let output_gain = if let (Some(target), Some(meter)) = (args.normalize_lufs, &meter) {
+        let gain = normalization_gain(meter, target).unwrap_or(1.0);
+        apply_gain(&render_path, &output_path, out_spec, gain, block_size)?;
+        std::fs::remove_file(&render_path)?;
+        gain
+    } else {
+        if render_path != output_path {
+            std::fs::rename(&render_path, &output_path)?;
+        }
+        1.0
+    };
+
+    println!("\nStats:");
+    println!(
+        "  Input:  RMS {:.1} dB, Peak {:.1} dB",
+        linear_to_db(input_level.rms()),
+        linear_to_db(input_level.peak)
     );
+    println!(
+        "  Output: RMS {:.1} dB, Peak {:.1} dB",
+        linear_to_db(output_level.rms() * output_gain),
+        linear_to_db(output_level.peak * output_gain)
+    );
+
+    println!("\nWrote {}", output_path.display());
+    if !metadata.is_empty() {
+        write_wav_metadata(&output_path, &metadata)?;
+        println!(
+            "  Kept metadata: {} cue point(s), {} loop(s){}",
+            metadata.cues.len(),
+            metadata.loops.len(),
+            if metadata.bext.is_some() {
+                ", broadcast WAV"
+            } else {
+                ""
+            }
+        );
+    }
+
+    println!("Done!");
 
-    // Resolve output path before params are consumed
-    let output_path = match args.output {
-        Some(path) => path,
+    Ok(output_path)
+}
+
+/// `OUTPUT`, or a name generated from the input and effect arguments.
+fn output_path(args: &ProcessArgs, input: &Path) -> PathBuf {
+    match &args.output {
+        Some(path) => path.clone(),
         None => generate_output_path(
-            &args.input,
+            input,
             args.effect.as_deref(),
             args.chain.as_deref(),
             args.graph.as_deref(),
             args.preset.as_deref(),
             &args.param,
         ),
-    };
+    }
+}
 
-    // Build effect chain or graph topology
-    let block_size = args.block_size;
-    let is_graph_mode = args.graph.is_some();
-
-    let mut engine = if let Some(graph_spec) = &args.graph {
-        // Graph topology mode: parse DSL → build ProcessingGraph
-        let spec = parse_graph_dsl(graph_spec)?;
-        validate_spec(&spec)?;
-        let graph = build_graph(&spec, sample_rate, block_size)?;
-        GraphEngine::new(graph)
+/// Engine built from the effect arguments.
+struct BuiltEngine {
+    engine: GraphEngine,
+    /// Stem names for linear chains, whose slots carry no registry IDs
+    stage_names: Vec<String>,
+    /// `--graph`, or a preset whose aux buses need send/return routing
+    is_graph_mode: bool,
+}
+
+/// Build the effect chain or graph topology from `--effect`, `--chain`,
+/// `--graph`, or the loaded `--preset`.
+fn build_engine(
+    args: &ProcessArgs,
+    preset: Option<&Preset>,
+    sample_rate: f32,
+    block_size: usize,
+) -> anyhow::Result<BuiltEngine> {
+    // Presets with aux buses need send/return routing, so they build a graph too.
+    let aux_preset = preset.filter(|p| !p.aux.is_empty());
+    let is_graph_mode = args.graph.is_some() || aux_preset.is_some();
+    let mut stage_names: Vec<String> = Vec::new();
+
+    let engine = if let Some(graph_spec) = &args.graph {
+        // Graph topology mode: parse DSL (or load a JSON snapshot) → build ProcessingGraph
+        let (graph, manifest) = load_graph(graph_spec, sample_rate, block_size)?;
+        let engine = GraphEngine::new_dag(graph, manifest);
+        if let Some(path) = &args.export {
+            std::fs::write(path, engine.to_snapshot_json()?)?;
+            println!("Exported graph to {}", path.display());
+        }
+        engine
+    } else if let Some(preset) = aux_preset {
+        println!(
+            "Loading preset: {} ({} aux bus(es))",
+            preset.name,
+            preset.aux.len()
+        );
+        let (graph, manifest) = build_graph_from_preset(preset, sample_rate, block_size)?;
+        GraphEngine::new_dag(graph, manifest)
     } else {
         let mut engine = GraphEngine::new_linear(sample_rate, block_size);
 
-        if let Some(preset_name) = &args.preset {
-            // Load preset by name or path using sonido-config
-            let preset = load_preset(preset_name)?;
-
+        if let Some(preset) = preset {
             println!("Loading preset: {}", preset.name);
             for effect_cfg in &preset.effects {
                 if effect_cfg.bypassed {
@@ -109,6 +528,7 @@ pub fn run(args: ProcessArgs) -> anyhow::Result<()> {
                     &effect_cfg.params,
                 )?;
                 engine.add_effect(effect);
+                stage_names.push(effect_cfg.effect_type.clone());
             }
         } else if let Some(chain_spec) = &args.chain {
             // Parse chain specification
@@ -116,11 +536,13 @@ pub fn run(args: ProcessArgs) -> anyhow::Result<()> {
             for effect in effects {
                 engine.add_effect(effect);
             }
-        } else if let Some(effect_name) = &args.effect {
+            stage_names = chain_effect_names(chain_spec);
+        } else if let Some(effect_spec) = &args.effect {
             // Single effect with optional parameters
-            let params: HashMap<String, String> = args.param.into_iter().collect();
-            let effect = create_effect_with_params(effect_name, sample_rate, &params)?;
+            let (name, params) = single_effect(effect_spec, &args.param)?;
+            let effect = create_effect_with_params(&name, sample_rate, &params)?;
             engine.add_effect(effect);
+            stage_names.push(name);
         } else {
             anyhow::bail!("No effect specified. Use --effect, --chain, --graph, or --preset");
         }
@@ -132,83 +554,347 @@ pub fn run(args: ProcessArgs) -> anyhow::Result<()> {
         engine
     };
 
-    // Determine output mode
-    let output_stereo = !args.mono;
-    if is_graph_mode {
-        // node_count includes Input + Output; subtract 2 for "processing" nodes
-        let processing_nodes = engine.graph().node_count().saturating_sub(2);
-        println!(
-            "Processing graph topology ({} nodes, {} output)...",
-            processing_nodes,
-            if output_stereo { "stereo" } else { "mono" }
-        );
-    } else {
-        println!(
-            "Processing with {} effect(s) ({} output)...",
-            engine.effect_count(),
-            if output_stereo { "stereo" } else { "mono" }
-        );
+    Ok(BuiltEngine {
+        engine,
+        stage_names,
+        is_graph_mode,
+    })
+}
+
+/// `--check`: validate every effect and parameter, build the graph, and
+/// print its compiled schedule without reading or writing audio.
+///
+/// Parameters are checked as written, before effects clamp them, and
+/// bypassed preset effects are checked too. Fails with the number of
+/// problems found.
+fn check(args: &ProcessArgs) -> anyhow::Result<()> {
+    // The input's header sets the sample rate when one is given.
+    let sample_rate = match &args.input {
+        Some(path) => read_wav_info(path)?.sample_rate as f32,
+        None => 48000.0,
+    };
+    let preset = args.preset.as_deref().map(load_preset).transpose()?;
+
+    let effects = effect_params(args, preset.as_ref())?;
+    let mut problems = 0;
+    for effect in &effects {
+        let issues = check_effect_params(&effect.name, &effect.params);
+        if issues.is_empty() {
+            println!("  ok    {}", effect.label);
+        }
+        for issue in &issues {
+            println!("  FAIL  {}: {issue}", effect.label);
+        }
+        problems += issues.len();
+    }
+    if problems > 0 {
+        anyhow::bail!("{problems} problem(s) found");
     }
 
-    // Process with progress bar using stereo processing
-    let pb = ProgressBar::new(samples.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("[{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
-            .unwrap()
-            .progress_chars("##-"),
+    let BuiltEngine { mut engine, .. } =
+        build_engine(args, preset.as_ref(), sample_rate, args.block_size)?;
+    let schedule = match engine.graph().compiled() {
+        Some(schedule) => Arc::clone(schedule),
+        None => engine.graph_mut().compile()?,
+    };
+    let latency = schedule.total_latency();
+    println!(
+        "\nGraph: {} effect(s), {} node(s), {} edge(s) at {} Hz, block size {}",
+        engine.effect_count(),
+        engine.graph().node_count(),
+        engine.graph().edge_count(),
+        sample_rate,
+        args.block_size
     );
+    println!(
+        "  Schedule: {} step(s), {} buffer(s), {} compensation delay(s), {} feedback delay(s)",
+        schedule.step_count(),
+        schedule.buffer_count(),
+        schedule.delay_line_count(),
+        schedule.feedback_delay_count()
+    );
+    println!(
+        "  Latency:  {latency} samples ({:.2} ms)",
+        latency as f32 * 1000.0 / sample_rate
+    );
+    println!("OK");
+    Ok(())
+}
+
+/// An effect to validate with `--check`.
+struct EffectToCheck {
+    /// Where it appears, for the report
+    label: String,
+    name: String,
+    params: HashMap<String, String>,
+}
+
+impl EffectToCheck {
+    fn new(label: String, name: &str, params: HashMap<String, String>) -> Self {
+        Self {
+            label,
+            name: name.to_string(),
+            params,
+        }
+    }
+}
+
+/// Every effect named by the arguments, including bypassed preset effects
+/// and aux returns.
+///
+/// JSON graph snapshots hold resolved parameter values, so they are only
+/// checked by building them.
+fn effect_params(
+    args: &ProcessArgs,
+    preset: Option<&Preset>,
+) -> anyhow::Result<Vec<EffectToCheck>> {
+    let mut effects = Vec::new();
+    if let Some(graph_spec) = &args.graph {
+        if !graph_spec.ends_with(".json") {
+            let spec = parse_graph_dsl(graph_spec)?;
+            validate_spec(&spec)?;
+            collect_graph_effects(&spec, &mut effects);
+        }
+    } else if let Some(preset) = preset {
+        println!("Checking preset: {}", preset.name);
+        for cfg in &preset.effects {
+            let label = if cfg.bypassed {
+                format!("{} (bypassed)", cfg.effect_type)
+            } else {
+                cfg.effect_type.clone()
+            };
+            effects.push(EffectToCheck::new(
+                label,
+                &cfg.effect_type,
+                cfg.params.clone(),
+            ));
+        }
+        for aux in &preset.aux {
+            for cfg in &aux.effects {
+                let label = format!("aux {}: {}", aux.name, cfg.effect_type);
+                effects.push(EffectToCheck::new(
+                    label,
+                    &cfg.effect_type,
+                    cfg.params.clone(),
+                ));
+            }
+        }
+    } else if let Some(chain_spec) = &args.chain {
+        for effect_spec in chain_spec.split('|').map(str::trim) {
+            if !effect_spec.is_empty() {
+                let (name, params) = parse_effect_spec(effect_spec)?;
+                effects.push(EffectToCheck::new(name.clone(), &name, params));
+            }
+        }
+    } else if let Some(effect_spec) = &args.effect {
+        let (name, params) = single_effect(effect_spec, &args.param)?;
+        effects.push(EffectToCheck::new(name.clone(), &name, params));
+    }
+    Ok(effects)
+}
 
-    let output = engine.process_file_stereo(&samples, block_size);
+/// Append the effects of a parsed graph DSL, depth first.
+fn collect_graph_effects(nodes: &[GraphNode], effects: &mut Vec<EffectToCheck>) {
+    for node in nodes {
+        match node {
+            GraphNode::Effect { name, params } => {
+                effects.push(EffectToCheck::new(name.clone(), name, params.clone()));
+            }
+            GraphNode::Dry => {}
+            GraphNode::Split { paths } => {
+                for path in paths {
+                    collect_graph_effects(path, effects);
+                }
+            }
+        }
+    }
+}
 
-    // Update progress (process_file_stereo handles blocks internally)
-    pb.set_position(samples.len() as u64);
-    pb.finish_with_message("done");
+/// RMS and peak of the mono mix, accumulated block by block.
+#[derive(Default)]
+struct Level {
+    sum_squares: f64,
+    peak: f32,
+    len: usize,
+}
 
-    // Calculate stats (using left channel for simplicity, or mono mix)
-    let input_mono = samples.to_mono();
-    let output_mono = output.to_mono();
+impl Level {
+    fn push(&mut self, left: &[f32], right: &[f32]) {
+        for (&l, &r) in left.iter().zip(right) {
+            let mono = (l + r) * 0.5;
+            self.sum_squares += f64::from(mono * mono);
+            self.peak = self.peak.max(mono.abs());
+        }
+        self.len += left.len().min(right.len());
+    }
 
-    let input_rms = dynamics::rms(&input_mono);
-    let output_rms = dynamics::rms(&output_mono);
-    let input_peak = dynamics::peak(&input_mono);
-    let output_peak = dynamics::peak(&output_mono);
+    fn rms(&self) -> f32 {
+        if self.len == 0 {
+            0.0
+        } else {
+            (self.sum_squares / self.len as f64).sqrt() as f32
+        }
+    }
+}
 
-    println!("\nStats:");
-    println!(
-        "  Input:  RMS {:.1} dB, Peak {:.1} dB",
-        linear_to_db(input_rms),
-        linear_to_db(input_peak)
-    );
-    println!(
-        "  Output: RMS {:.1} dB, Peak {:.1} dB",
-        linear_to_db(output_rms),
-        linear_to_db(output_peak)
-    );
+/// `--stems` outputs: the dry input plus one file per effect slot.
+struct StemWriters {
+    dry: (PathBuf, WavBlockWriter),
+    slots: Vec<(PathBuf, WavBlockWriter)>,
+}
 
-    // Write output file
-    println!("\nWriting {}...", output_path.display());
+impl StemWriters {
+    fn create(
+        engine: &GraphEngine,
+        stage_names: &[String],
+        output: &Path,
+        spec: WavSpec,
+    ) -> sonido_io::Result<Self> {
+        let ids = engine.effect_ids();
+        let dry_path = stem_path(output, "dry");
+        let dry = WavBlockWriter::create(&dry_path, spec)?;
+        let slots = (0..engine.effect_count())
+            .map(|slot| {
+                let name = match ids.get(slot) {
+                    Some(&id) if !id.is_empty() => id,
+                    _ => stage_names.get(slot).map_or("", String::as_str),
+                };
+                let path = stem_path(output, &stage_label(slot, name));
+                WavBlockWriter::create(&path, spec).map(|writer| (path, writer))
+            })
+            .collect::<sonido_io::Result<_>>()?;
+        Ok(Self {
+            dry: (dry_path, dry),
+            slots,
+        })
+    }
 
-    if output_stereo {
-        let out_spec = WavSpec {
-            channels: 2,
-            sample_rate: spec.sample_rate,
-            bits_per_sample: args.bit_depth,
-        };
-        write_wav_stereo(&output_path, &output, out_spec)?;
+    /// Writes the dry block and the stems the engine collected for it.
+    fn write(
+        &mut self,
+        engine: &mut GraphEngine,
+        dry_left: &[f32],
+        dry_right: &[f32],
+    ) -> sonido_io::Result<()> {
+        self.dry.1.write_stereo(dry_left, dry_right)?;
+        for ((_, writer), stem) in self.slots.iter_mut().zip(engine.take_stems()) {
+            writer.write_stereo(&stem.left, &stem.right)?;
+        }
+        Ok(())
+    }
+
+    /// Finalizes every file and returns their paths.
+    fn finalize(self) -> sonido_io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::with_capacity(self.slots.len() + 1);
+        for (path, writer) in std::iter::once(self.dry).chain(self.slots) {
+            writer.finalize()?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Stem file path: `<output stem>_<label>.wav` next to the output.
+fn stem_path(output: &Path, label: &str) -> PathBuf {
+    let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+    output.with_file_name(format!("{stem}_{label}.wav"))
+}
+
+/// Stem label for a slot: 1-based index plus effect name, e.g. `02_reverb`.
+fn stage_label(slot: usize, name: &str) -> String {
+    if name.is_empty() {
+        format!("{:02}_slot", slot + 1)
     } else {
-        let out_spec = WavSpec {
-            channels: 1,
-            sample_rate: spec.sample_rate,
-            bits_per_sample: args.bit_depth,
-        };
-        let mono_output = output.to_mono();
-        write_wav(&output_path, &mono_output, out_spec)?;
+        format!("{:02}_{name}", slot + 1)
     }
+}
 
-    println!("Done!");
+/// `--effect` spec plus `--param` overrides. The spec may carry inline
+/// parameters (`distortion:drive=15`); `--param` wins on conflicts.
+fn single_effect(
+    spec: &str,
+    overrides: &[(String, String)],
+) -> anyhow::Result<(String, HashMap<String, String>)> {
+    let (name, mut params) = parse_effect_spec(spec)?;
+    params.extend(overrides.iter().cloned());
+    Ok((name, params))
+}
 
-    Ok(())
+/// Effect names in a chain specification, in order.
+fn chain_effect_names(chain_spec: &str) -> Vec<String> {
+    chain_spec
+        .split('|')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.split(':').next().unwrap_or(s).trim().to_string())
+        .collect()
+}
+
+/// Whether `a` and `b` name the same existing file.
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Feed an output block to the normalization meter. Mono output is
+/// measured as a single channel, matching how it is written.
+fn push_loudness(meter: &mut LufsMeter, left: &[f32], right: &[f32], stereo: bool) {
+    if stereo {
+        meter.push_samples(left, right);
+    } else {
+        let mono: Vec<f32> = left.iter().zip(right).map(|(l, r)| (l + r) * 0.5).collect();
+        meter.push_samples(&mono, &vec![0.0; mono.len()]);
+    }
+}
+
+/// Linear gain that brings the metered output to `target` LUFS integrated.
+///
+/// Returns `None` (with a warning) when the output is too short or too
+/// quiet to measure, and warns when the gain pushes the true peak above
+/// 0 dBTP.
+fn normalization_gain(meter: &LufsMeter, target: f32) -> Option<f32> {
+    let integrated = meter.integrated();
+    if !integrated.is_finite() {
+        println!(
+            "\nWarning: output is silent or shorter than 400 ms; skipping loudness normalization"
+        );
+        return None;
+    }
+
+    let gain_db = target - integrated;
+    println!("\nNormalizing {integrated:.1} LUFS -> {target:.1} LUFS ({gain_db:+.1} dB)");
+    let peak_after = meter.true_peak_dbtp() + gain_db;
+    if peak_after > 0.0 {
+        println!("  Warning: true peak after gain is {peak_after:+.1} dBTP; the output may clip");
+    }
+    Some(db_to_linear(gain_db))
+}
+
+/// Stream `input` to `output` in `spec`, scaled by `gain`.
+fn apply_gain(
+    input: &Path,
+    output: &Path,
+    spec: WavSpec,
+    gain: f32,
+    block_size: usize,
+) -> sonido_io::Result<()> {
+    let mut reader = WavBlockReader::open(input)?;
+    let mut writer = WavBlockWriter::create(output, spec)?;
+    let mut left = vec![0.0; block_size.max(1)];
+    let mut right = vec![0.0; block_size.max(1)];
+    loop {
+        let n = reader.read_stereo(&mut left, &mut right)?;
+        if n == 0 {
+            break;
+        }
+        for sample in left[..n].iter_mut().chain(right[..n].iter_mut()) {
+            *sample *= gain;
+        }
+        writer.write_stereo(&left[..n], &right[..n])?;
+    }
+    writer.finalize()
 }
 
 /// Generate an output file path from input path and effect specification.
@@ -234,7 +920,7 @@ fn generate_output_path(
     } else if let Some(chain_spec) = chain {
         build_chain_slug(chain_spec)
     } else if let Some(effect_name) = effect {
-        let mut slug = effect_name.to_string();
+        let mut slug = build_chain_slug(effect_name);
         for (k, v) in params {
             slug.push('_');
             slug.push_str(k);
@@ -282,6 +968,70 @@ fn build_chain_slug(chain_spec: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sonido_analysis::measure_loudness;
+    use sonido_io::read_wav_stereo;
+
+    #[test]
+    fn tail_values() {
+        assert_eq!(parse_tail("auto"), Ok(Tail::Auto));
+        assert_eq!(parse_tail("AUTO"), Ok(Tail::Auto));
+        assert_eq!(parse_tail("4.5"), Ok(Tail::Seconds(4.5)));
+        assert_eq!(parse_tail("3s"), Ok(Tail::Seconds(3.0)));
+        assert!(parse_tail("-1").is_err());
+        assert!(parse_tail("long").is_err());
+
+        assert_eq!(
+            Tail::Seconds(0.5).length(-80.0, 48000.0),
+            TailLength::Fixed(24000)
+        );
+        let TailLength::Auto {
+            threshold,
+            hold,
+            max,
+        } = Tail::Auto.length(-60.0, 48000.0)
+        else {
+            panic!("auto tail should be adaptive");
+        };
+        assert!((threshold - 0.001).abs() < 1e-6);
+        assert_eq!((hold, max), (120_000, 2_880_000));
+    }
+
+    #[test]
+    fn stem_paths() {
+        let output = Path::new("mixes/take_reverb.wav");
+        assert_eq!(
+            stem_path(output, "dry"),
+            PathBuf::from("mixes/take_reverb_dry.wav")
+        );
+        assert_eq!(
+            stem_path(output, &stage_label(1, "reverb")),
+            PathBuf::from("mixes/take_reverb_02_reverb.wav")
+        );
+        assert_eq!(stage_label(0, ""), "01_slot");
+        assert_eq!(
+            chain_effect_names("preamp:gain=6 | delay:time=300|"),
+            ["preamp", "delay"]
+        );
+    }
+
+    #[test]
+    fn single_effect_merges_inline_params() {
+        let overrides = [("tone".to_string(), "-2".to_string())];
+        let (name, params) = single_effect("distortion:drive=15,tone=3", &overrides).unwrap();
+        assert_eq!(name, "distortion");
+        assert_eq!(params["drive"], "15");
+        assert_eq!(params["tone"], "-2");
+
+        let path = generate_output_path(
+            Path::new("input.wav"),
+            Some("distortion:drive=15"),
+            None,
+            None,
+            None,
+            &[],
+        );
+        assert_eq!(path, PathBuf::from("input_distortion_drive=15.wav"));
+    }
 
     #[test]
     fn output_path_preset_slug() {
@@ -359,6 +1109,63 @@ mod tests {
         assert!(filename.ends_with(".wav"));
     }
 
+    #[test]
+    fn normalization_gain_hits_target() {
+        let sample_rate = 48000.0;
+        let tone: Vec<f32> = (0..(sample_rate as usize * 4))
+            .map(|i| 0.1 * (std::f32::consts::TAU * 1000.0 * i as f32 / sample_rate).sin())
+            .collect();
+        let mut meter = LufsMeter::new(sample_rate);
+        push_loudness(&mut meter, &tone, &tone, true);
+        let gain = normalization_gain(&meter, -14.0).unwrap();
+
+        let scaled: Vec<f32> = tone.iter().map(|s| s * gain).collect();
+        let loudness = measure_loudness(&scaled, Some(&scaled), sample_rate);
+        assert!(
+            (loudness.integrated + 14.0).abs() < 0.1,
+            "integrated = {}",
+            loudness.integrated
+        );
+    }
+
+    #[test]
+    fn normalization_gain_skips_silence() {
+        let mut meter = LufsMeter::new(48000.0);
+        let silence = vec![0.0; 48000];
+        push_loudness(&mut meter, &silence, &silence, true);
+        assert_eq!(normalization_gain(&meter, -14.0), None);
+    }
+
+    #[test]
+    fn apply_gain_streams_scaled_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let render = dir.path().join("render.wav");
+        let output = dir.path().join("out.wav");
+        let float = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+        };
+        let mut writer = WavBlockWriter::create(&render, float).unwrap();
+        writer.write_stereo(&[0.25; 1000], &[-0.5; 1000]).unwrap();
+        writer.finalize().unwrap();
+
+        apply_gain(&render, &output, float, 2.0, 256).unwrap();
+        let (scaled, _) = read_wav_stereo(&output).unwrap();
+        assert_eq!(scaled.left, [0.5; 1000]);
+        assert_eq!(scaled.right, [-1.0; 1000]);
+    }
+
+    #[test]
+    fn level_tracks_mono_mix() {
+        let mut level = Level::default();
+        level.push(&[0.5, -1.0], &[0.5, 0.0]);
+        level.push(&[0.0], &[0.0]);
+        assert_eq!(level.peak, 0.5);
+        assert!((level.rms() - (0.5f32 / 3.0).sqrt()).abs() < 1e-6);
+        assert_eq!(Level::default().rms(), 0.0);
+    }
+
     #[test]
     fn chain_slug_single_effect() {
         assert_eq!(build_chain_slug("distortion"), "distortion");
@@ -0,0 +1,252 @@
+//! `process --watch`: re-render when the preset or input changes on disk.
+//!
+//! Files are polled for modification time and size rather than watched
+//! through OS notifications, which keeps this dependency-free and copes with
+//! editors that save by writing a temporary file and renaming it over the
+//! original. A change must hold still for one poll before it triggers a
+//! render, so a save caught half-written waits for the rest.
+
+use super::{ProcessArgs, output_path, render, same_file};
+use crate::commands::common::{load_preset, preset_file};
+use sonido_io::{AudioStream, StereoSamples, StreamConfig, read_wav_stereo};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc};
+use std::time::{Duration, SystemTime};
+
+/// How often watched files are checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Render, then re-render after every change to the watched files until
+/// `interrupted` is set. Render errors are reported and watching goes on,
+/// so a typo in the preset can be fixed with another save.
+pub(super) fn run(
+    args: &ProcessArgs,
+    input: &Path,
+    interrupted: &AtomicBool,
+) -> anyhow::Result<()> {
+    let watched = watched_files(args, input)?;
+    if args.watch_input && same_file(input, &output_path(args, input)) {
+        anyhow::bail!("--watch-input would re-render its own output; choose a different OUTPUT");
+    }
+    let player = if args.play {
+        Some(LoopPlayer::start(args.block_size)?)
+    } else {
+        None
+    };
+
+    let mut stamps: Vec<Option<Stamp>> = watched.iter().map(|p| stamp(p)).collect();
+    loop {
+        match render(args, input, interrupted) {
+            Ok(output) => {
+                if let Some(player) = &player {
+                    match read_wav_stereo(&output) {
+                        Ok((samples, _)) => player.play(samples),
+                        Err(err) => eprintln!("Cannot play {}: {err}", output.display()),
+                    }
+                }
+            }
+            Err(err) => eprintln!("\nRender failed: {err:#}"),
+        }
+        if interrupted.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let names: Vec<String> = watched.iter().map(|p| p.display().to_string()).collect();
+        println!(
+            "\nWatching {}{} (Ctrl+C to stop)...",
+            names.join(", "),
+            if player.is_some() {
+                ", looping playback"
+            } else {
+                ""
+            }
+        );
+        let Some(changed) = wait_for_change(&watched, &mut stamps, interrupted) else {
+            break;
+        };
+        println!("\n{} changed, re-rendering...\n", changed.display());
+    }
+    Ok(())
+}
+
+/// Files whose changes trigger a render: the preset file or `.json` graph
+/// snapshot, plus the input with `--watch-input`.
+fn watched_files(args: &ProcessArgs, input: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if let Some(name) = &args.preset {
+        match preset_file(name) {
+            Some(path) => files.push(path),
+            None if args.watch_input => {}
+            None => {
+                // Reports "not found" for names that aren't factory presets either.
+                load_preset(name)?;
+                anyhow::bail!(
+                    "'{name}' is a factory preset with no file to watch; copy it with \
+                     'sonido presets copy \"{name}\" --name <NEW>' and watch the copy"
+                );
+            }
+        }
+    } else if let Some(graph) = args.graph.as_deref().filter(|g| g.ends_with(".json")) {
+        files.push(PathBuf::from(graph));
+    }
+    if args.watch_input {
+        files.push(input.to_path_buf());
+    }
+    if files.is_empty() {
+        anyhow::bail!(
+            "--watch follows a --preset file or a .json --graph snapshot; \
+             add --watch-input to re-render on input changes"
+        );
+    }
+    Ok(files)
+}
+
+/// Modification time and length of a file.
+type Stamp = (SystemTime, u64);
+
+/// Current stamp of `path`, or `None` while it is missing (mid-rename).
+fn stamp(path: &Path) -> Option<Stamp> {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// Block until a watched file settles into a new stamp, returning its path,
+/// or `None` once `interrupted` is set.
+fn wait_for_change(
+    files: &[PathBuf],
+    stamps: &mut [Option<Stamp>],
+    interrupted: &AtomicBool,
+) -> Option<PathBuf> {
+    let mut pending: Option<(usize, Stamp)> = None;
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        if interrupted.load(Ordering::SeqCst) {
+            return None;
+        }
+        if let Some((i, seen)) = pending
+            && stamp(&files[i]) == Some(seen)
+        {
+            // Same stamp as last poll: the write has finished.
+            stamps[i] = Some(seen);
+            return Some(files[i].clone());
+        }
+        pending = files
+            .iter()
+            .zip(stamps.iter())
+            .enumerate()
+            .find_map(|(i, (path, old))| {
+                let new = stamp(path)?;
+                (Some(new) != *old).then_some((i, new))
+            });
+    }
+}
+
+/// Loops the latest render on the default output device.
+///
+/// The audio callback picks up a new render between buffers and restarts
+/// it from the top. cpal streams can't move between threads, so the stream
+/// lives on its own thread for the whole watch session.
+struct LoopPlayer {
+    renders: mpsc::Sender<StereoSamples>,
+    running: Arc<AtomicBool>,
+}
+
+impl LoopPlayer {
+    fn start(buffer_size: usize) -> anyhow::Result<Self> {
+        let (renders, next_render) = mpsc::channel::<StereoSamples>();
+        let (ready, started) = mpsc::channel();
+        std::thread::spawn(move || {
+            let config = StreamConfig {
+                buffer_size: buffer_size as u32,
+                ..StreamConfig::default()
+            };
+            let mut stream = match AudioStream::new(config) {
+                Ok(stream) => stream,
+                Err(err) => {
+                    let _ = ready.send(Err(err));
+                    return;
+                }
+            };
+            let _ = ready.send(Ok(stream.running_handle()));
+
+            let channels = usize::from(stream.output_channels()).max(1);
+            let mut clip = StereoSamples::new(Vec::new(), Vec::new());
+            let mut pos = 0;
+            let result = stream.run_output(move |data: &mut [f32]| {
+                if let Ok(next) = next_render.try_recv() {
+                    clip = next;
+                    pos = 0;
+                }
+                for frame in data.chunks_mut(channels) {
+                    let (l, r) = if clip.left.is_empty() {
+                        (0.0, 0.0)
+                    } else {
+                        let sample = (clip.left[pos], clip.right[pos]);
+                        pos = (pos + 1) % clip.left.len();
+                        sample
+                    };
+                    match frame {
+                        [mono] => *mono = (l + r) * 0.5,
+                        [left, right, rest @ ..] => {
+                            *left = l;
+                            *right = r;
+                            rest.fill(0.0);
+                        }
+                        [] => {}
+                    }
+                }
+            });
+            if let Err(err) = result {
+                eprintln!("Playback stopped: {err}");
+            }
+        });
+        let running = started
+            .recv()
+            .map_err(|_| anyhow::anyhow!("playback thread exited"))??;
+        Ok(Self { renders, running })
+    }
+
+    /// Replace the looping render.
+    fn play(&self, samples: StereoSamples) {
+        let _ = self.renders.send(samples);
+    }
+}
+
+impl Drop for LoopPlayer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_for_a_settled_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("preset.toml");
+        std::fs::write(&path, "name = \"a\"").unwrap();
+        let files = vec![path.clone()];
+        let mut stamps = vec![stamp(&path)];
+
+        let writer = std::thread::spawn({
+            let path = path.clone();
+            move || {
+                std::thread::sleep(POLL_INTERVAL);
+                std::fs::write(&path, "name = \"longer\"").unwrap();
+            }
+        });
+        let interrupted = AtomicBool::new(false);
+        assert_eq!(
+            wait_for_change(&files, &mut stamps, &interrupted),
+            Some(path.clone())
+        );
+        assert_eq!(stamps[0], stamp(&path));
+        writer.join().unwrap();
+
+        interrupted.store(true, Ordering::SeqCst);
+        assert_eq!(wait_for_change(&files, &mut stamps, &interrupted), None);
+    }
+}
@@ -1,20 +1,86 @@
 //! Real-time audio processing command.
 
 use super::common::{load_preset, parse_key_val};
-use crate::effects::{create_effect_with_params, parse_chain};
-use clap::Args;
+use super::completions::{complete_effect_id, complete_effect_spec};
+#[cfg(any(feature = "tui", feature = "pi"))]
+use crate::control::{EngineCommand, PeakMeters};
+#[cfg(feature = "pi")]
+use crate::pedalboard::PedalboardSession;
+#[cfg(feature = "tui")]
+use crate::tui::TuiSession;
+use clap::{Args, ValueEnum};
+use clap_complete::engine::ArgValueCompleter;
+#[cfg(feature = "pi")]
+use sonido_config::PedalboardConfig;
+use sonido_core::EffectWithParams;
+use sonido_core::db_to_linear;
+use sonido_core::gain::{AUTO_TRIM_TARGET_DB, AUTO_TRIM_WINDOW_SECS, AutoTrim, InputRouting};
+use sonido_core::graph::{Rig, RigOutput};
+use sonido_core::safety_limiter::{DEFAULT_CEILING_DB, MIN_CEILING_DB};
+use sonido_graph_dsl::{create_effect_with_params, parse_effect_spec};
 use sonido_io::{AudioStream, GraphEngine, StreamConfig, default_device};
 use std::collections::HashMap;
-use std::sync::atomic::Ordering;
+use std::io::BufRead;
+#[cfg(feature = "pi")]
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
+
+/// Which input channels feed the stereo chain.
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Routing {
+    /// Left and right pass through unchanged
+    #[default]
+    Stereo,
+    /// Left input on both sides (guitar on input 1)
+    Left,
+    /// Right input on both sides (guitar on input 2)
+    Right,
+    /// Left and right summed to mono, -3 dB
+    Mono,
+}
+
+impl From<Routing> for InputRouting {
+    fn from(routing: Routing) -> Self {
+        match routing {
+            Routing::Stereo => Self::Stereo,
+            Routing::Left => Self::Left,
+            Routing::Right => Self::Right,
+            Routing::Mono => Self::SumMono,
+        }
+    }
+}
+
+/// How `--rig-b` combines the two rigs.
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum RigMode {
+    /// Equal-power morph between rig A and rig B (see --rig-morph)
+    #[default]
+    Blend,
+    /// Rig A on the left output, rig B on the right
+    Split,
+}
+
+impl From<RigMode> for RigOutput {
+    fn from(mode: RigMode) -> Self {
+        match mode {
+            RigMode::Blend => Self::Blend,
+            RigMode::Split => Self::Split,
+        }
+    }
+}
+
+/// An effect and its registry ID, ready to add to an engine.
+type ChainEffect = (Box<dyn EffectWithParams + Send>, &'static str);
 
 #[derive(Args)]
 pub struct RealtimeArgs {
     /// Single effect to apply
-    #[arg(short, long)]
+    #[arg(short, long, add = ArgValueCompleter::new(complete_effect_id))]
     effect: Option<String>,
 
     /// Effect chain specification
-    #[arg(short, long)]
+    #[arg(short, long, add = ArgValueCompleter::new(complete_effect_spec))]
     chain: Option<String>,
 
     /// Preset name or path (supports factory presets, user presets, and file paths)
@@ -44,13 +110,247 @@ pub struct RealtimeArgs {
     /// Force mono processing (ignore stereo input/output)
     #[arg(long)]
     mono: bool,
+
+    /// Second effect chain (rig B), run in parallel on the same input as the
+    /// main chain (rig A); toggle between them with r + Enter
+    #[arg(long, value_name = "CHAIN")]
+    rig_b: Option<String>,
+
+    /// How the two rigs reach the output
+    #[arg(long, value_enum, default_value_t, requires = "rig_b")]
+    rig_output: RigMode,
+
+    /// Starting A/B morph for --rig-output blend: 0 = rig A, 1 = rig B
+    #[arg(long, default_value_t = 0.0, requires = "rig_b")]
+    rig_morph: f32,
+
+    /// Input channel routing; use left or right when the interface delivers
+    /// the instrument on only one channel
+    #[arg(long, value_enum, default_value_t, conflicts_with = "mono")]
+    input_routing: Routing,
+
+    /// Measure input peaks for the first 3 seconds, then trim the input so
+    /// peaks hit the trim target
+    #[arg(long)]
+    auto_trim: bool,
+
+    /// Peak target for --auto-trim in dBFS
+    #[arg(long, default_value_t = AUTO_TRIM_TARGET_DB, allow_hyphen_values = true)]
+    trim_target: f32,
+
+    /// Disable the output safety limiter (a brickwall after the whole chain)
+    #[arg(long)]
+    no_limiter: bool,
+
+    /// Output safety limiter ceiling in dBFS (-24 to 0)
+    #[arg(
+        long,
+        value_name = "DB",
+        default_value_t = DEFAULT_CEILING_DB,
+        allow_hyphen_values = true,
+        conflicts_with = "no_limiter"
+    )]
+    limiter_ceiling: f32,
+
+    /// Interactive terminal UI: select slots and adjust parameters with the
+    /// arrow keys, with level meters
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+
+    /// Hardware pedalboard mapping file: encoders, footswitches, knobs, and
+    /// LEDs on Raspberry Pi GPIO/I2C bound to chain parameters
+    #[cfg(feature = "pi")]
+    #[arg(long, value_name = "FILE")]
+    pedalboard: Option<PathBuf>,
+}
+
+/// Input stage for `--input-routing`.
+///
+/// Applies an [`InputRouting`] to each stereo input block, ahead of auto-trim
+/// and the effect chain.
+struct InputRoute {
+    routing: InputRouting,
+    left: Vec<f32>,
+    right: Vec<f32>,
+}
+
+impl InputRoute {
+    fn new(routing: InputRouting, buffer_size: usize) -> Self {
+        Self {
+            routing,
+            left: vec![0.0; buffer_size],
+            right: vec![0.0; buffer_size],
+        }
+    }
+
+    /// Route one input block.
+    fn process(&mut self, left: &[f32], right: &[f32]) -> (&[f32], &[f32]) {
+        let frames = left.len().min(right.len());
+        if self.left.len() < frames {
+            self.left.resize(frames, 0.0);
+            self.right.resize(frames, 0.0);
+        }
+        self.left[..frames].copy_from_slice(&left[..frames]);
+        self.right[..frames].copy_from_slice(&right[..frames]);
+        self.routing
+            .route_block(&mut self.left[..frames], &mut self.right[..frames]);
+        (&self.left[..frames], &self.right[..frames])
+    }
+}
+
+/// Auto-trim is still measuring.
+const TRIM_LISTENING: u8 = 0;
+/// Auto-trim finished and applied a gain.
+const TRIM_APPLIED: u8 = 1;
+/// Auto-trim finished on silence; gain left at unity.
+const TRIM_SILENT: u8 = 2;
+
+/// Input stage for `--auto-trim`.
+///
+/// Measures the raw input with [`AutoTrim`] during the listening window,
+/// then scales all subsequent input by the suggested gain. Progress is
+/// published through atomics so the main thread can report the result.
+struct InputTrim {
+    trim: AutoTrim,
+    gain: f32,
+    left: Vec<f32>,
+    right: Vec<f32>,
+    state: Arc<AtomicU8>,
+    gain_db: Arc<AtomicU32>,
+}
+
+impl InputTrim {
+    fn new(sample_rate: f32, target_db: f32, buffer_size: usize) -> Self {
+        let mut trim = AutoTrim::new(sample_rate).with_target_db(target_db);
+        trim.start();
+        Self {
+            trim,
+            gain: 1.0,
+            left: vec![0.0; buffer_size],
+            right: vec![0.0; buffer_size],
+            state: Arc::new(AtomicU8::new(TRIM_LISTENING)),
+            gain_db: Arc::new(AtomicU32::new(0.0_f32.to_bits())),
+        }
+    }
+
+    /// Measure (while listening) and apply the trim gain to one input block.
+    fn process(&mut self, left: &[f32], right: &[f32]) -> (&[f32], &[f32]) {
+        if self.trim.is_active() {
+            if let Some(db) = self.trim.process_block(left, right) {
+                self.gain = db_to_linear(db);
+                self.gain_db.store(db.to_bits(), Ordering::Release);
+                self.state.store(TRIM_APPLIED, Ordering::Release);
+            } else if !self.trim.is_active() {
+                self.state.store(TRIM_SILENT, Ordering::Release);
+            }
+        }
+
+        let frames = left.len().min(right.len());
+        if self.left.len() < frames {
+            self.left.resize(frames, 0.0);
+            self.right.resize(frames, 0.0);
+        }
+        for i in 0..frames {
+            self.left[i] = left[i] * self.gain;
+            self.right[i] = right[i] * self.gain;
+        }
+        (&self.left[..frames], &self.right[..frames])
+    }
+
+    /// Print the auto-trim result from a background thread once it is known.
+    fn spawn_reporter(&self) {
+        let state = Arc::clone(&self.state);
+        let gain_db = Arc::clone(&self.gain_db);
+        std::thread::spawn(move || {
+            loop {
+                match state.load(Ordering::Acquire) {
+                    TRIM_APPLIED => {
+                        let db = f32::from_bits(gain_db.load(Ordering::Acquire));
+                        println!("Auto-trim: input gain set to {db:+.1} dB");
+                        return;
+                    }
+                    TRIM_SILENT => {
+                        println!("Auto-trim: no input signal detected, input gain left at 0 dB");
+                        return;
+                    }
+                    _ => std::thread::sleep(std::time::Duration::from_millis(100)),
+                }
+            }
+        });
+    }
+
+    /// Describe the auto-trim progress for the TUI status line.
+    #[cfg(feature = "tui")]
+    fn status(&self) -> impl Fn() -> String + Send + 'static {
+        let state = Arc::clone(&self.state);
+        let gain_db = Arc::clone(&self.gain_db);
+        move || match state.load(Ordering::Acquire) {
+            TRIM_APPLIED => {
+                let db = f32::from_bits(gain_db.load(Ordering::Acquire));
+                format!("Auto-trim: input gain {db:+.1} dB")
+            }
+            TRIM_SILENT => "Auto-trim: no input signal, input gain 0 dB".to_string(),
+            _ => "Auto-trim: listening (play your loudest part)".to_string(),
+        }
+    }
+}
+
+/// Keys typed on stdin, raised by [`spawn_key_listener()`] and taken by the
+/// audio callback.
+struct KeyFlags {
+    /// `p` + Enter: [`GraphEngine::panic()`], which fades out, clears every
+    /// effect's state, and fades back in.
+    panic: Arc<AtomicBool>,
+    /// `r` + Enter: [`GraphEngine::toggle_rig()`] (dual rigs only).
+    toggle_rig: Arc<AtomicBool>,
+}
+
+/// Watch stdin for the panic key and, with `--rig-b`, the rig toggle key.
+fn spawn_key_listener(mut rig: Option<Rig>) -> KeyFlags {
+    let flags = KeyFlags {
+        panic: Arc::new(AtomicBool::new(false)),
+        toggle_rig: Arc::new(AtomicBool::new(false)),
+    };
+    let panic = Arc::clone(&flags.panic);
+    let toggle_rig = Arc::clone(&flags.toggle_rig);
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { return };
+            let key = line.trim();
+            if key.eq_ignore_ascii_case("p") {
+                panic.store(true, Ordering::Release);
+                println!("Panic: resetting all effects");
+            } else if key.eq_ignore_ascii_case("r")
+                && let Some(active) = rig.as_mut()
+            {
+                *active = active.other();
+                toggle_rig.store(true, Ordering::Release);
+                println!("Switching to {}", active.label());
+            }
+        }
+    });
+    flags
+}
+
+/// Parse a `|`-separated chain specification into effects.
+fn parse_chain(chain_spec: &str, sample_rate: f32) -> anyhow::Result<Vec<ChainEffect>> {
+    chain_spec
+        .split('|')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|spec| {
+            let (name, params) = parse_effect_spec(spec)?;
+            Ok(create_effect_with_params(&name, sample_rate, &params)?)
+        })
+        .collect()
 }
 
 pub fn run(args: RealtimeArgs) -> anyhow::Result<()> {
     let sample_rate = args.sample_rate as f32;
 
     // Build effect chain
-    let mut engine = GraphEngine::new_linear(sample_rate, args.buffer_size as usize);
+    let mut rig_a: Vec<ChainEffect> = Vec::new();
 
     if let Some(preset_name) = &args.preset {
         // Load preset by name or path using sonido-config
@@ -61,29 +361,55 @@ pub fn run(args: RealtimeArgs) -> anyhow::Result<()> {
             if effect_cfg.bypassed {
                 continue; // Skip bypassed effects
             }
-            let effect = create_effect_with_params(
+            rig_a.push(create_effect_with_params(
                 &effect_cfg.effect_type,
                 sample_rate,
                 &effect_cfg.params,
-            )?;
-            engine.add_effect(effect);
+            )?);
         }
     } else if let Some(chain_spec) = &args.chain {
-        let effects = parse_chain(chain_spec, sample_rate)?;
-        for effect in effects {
-            engine.add_effect(effect);
-        }
+        rig_a = parse_chain(chain_spec, sample_rate)?;
     } else if let Some(effect_name) = &args.effect {
         let params: HashMap<String, String> = args.param.into_iter().collect();
-        let effect = create_effect_with_params(effect_name, sample_rate, &params)?;
-        engine.add_effect(effect);
+        rig_a.push(create_effect_with_params(
+            effect_name,
+            sample_rate,
+            &params,
+        )?);
     } else {
         anyhow::bail!("No effect specified. Use --effect, --chain, or --preset");
     }
 
+    let mut engine = match &args.rig_b {
+        Some(chain_spec) => {
+            let rig_b = parse_chain(chain_spec, sample_rate)?;
+            let mut engine =
+                GraphEngine::dual_from_chains(rig_a, rig_b, sample_rate, args.buffer_size as usize)
+                    .map_err(|e| anyhow::anyhow!("Failed to build rigs: {e}"))?;
+            engine.set_rig_output(args.rig_output.into());
+            engine.set_rig_morph_immediate(args.rig_morph);
+            engine
+        }
+        None => {
+            let mut engine = GraphEngine::new_linear(sample_rate, args.buffer_size as usize);
+            for (effect, id) in rig_a {
+                engine.add_effect_named(effect, id);
+            }
+            engine
+        }
+    };
+
     if engine.is_empty() {
         anyhow::bail!("No effects to process");
     }
+    if !(MIN_CEILING_DB..=0.0).contains(&args.limiter_ceiling) {
+        anyhow::bail!(
+            "--limiter-ceiling must be between {MIN_CEILING_DB} and 0 dBFS, got {}",
+            args.limiter_ceiling
+        );
+    }
+    engine.set_output_limiter(!args.no_limiter);
+    engine.set_limiter_ceiling_db(args.limiter_ceiling);
 
     // Resolve device names from index or partial match
     let (default_input, default_output) = default_device()?;
@@ -141,7 +467,88 @@ pub fn run(args: RealtimeArgs) -> anyhow::Result<()> {
     println!("  Output: {}", output_name);
     println!("  Sample rate: {} Hz", args.sample_rate);
     println!("  Buffer size: {} samples", args.buffer_size);
-    println!("\nPress Ctrl+C to stop...\n");
+    if engine.is_dual() {
+        println!(
+            "  Rigs: A {} effect(s), B {} effect(s), {} output",
+            engine.rig_slots(Rig::A).len(),
+            engine.rig_slots(Rig::B).len(),
+            engine.rig_output().label().to_lowercase()
+        );
+    }
+    let routing = InputRouting::from(args.input_routing);
+    if routing != InputRouting::Stereo {
+        println!("  Input routing: {}", routing.label());
+    }
+    if args.no_limiter {
+        println!("  Output limiter: off");
+    } else {
+        println!("  Output limiter: {:.1} dBFS ceiling", args.limiter_ceiling);
+    }
+    if args.auto_trim {
+        println!(
+            "  Auto-trim: listening for {AUTO_TRIM_WINDOW_SECS:.0} s, target {:.1} dBFS \
+             (play your loudest part)",
+            args.trim_target
+        );
+    }
+
+    // Edits from the TUI and pedalboard threads, applied by the audio callback,
+    // and the levels it records for them.
+    #[cfg(any(feature = "tui", feature = "pi"))]
+    let (commands, command_queue) = std::sync::mpsc::channel::<EngineCommand>();
+    #[cfg(any(feature = "tui", feature = "pi"))]
+    let mut meters: Vec<Arc<PeakMeters>> = Vec::new();
+
+    #[cfg(feature = "pi")]
+    let pedalboard = match &args.pedalboard {
+        Some(path) => {
+            let config = PedalboardConfig::load(path)?;
+            println!(
+                "  Pedalboard: {} ({} controls, {} bindings)",
+                if config.name.is_empty() {
+                    path.display().to_string()
+                } else {
+                    config.name.clone()
+                },
+                config.controls.len(),
+                config.bindings.len()
+            );
+            Some(PedalboardSession::new(&config, &engine, commands.clone())?)
+        }
+        None => None,
+    };
+
+    #[cfg(feature = "tui")]
+    let use_tui = args.tui;
+    #[cfg(not(feature = "tui"))]
+    let use_tui = false;
+
+    let mut trim = args
+        .auto_trim
+        .then(|| InputTrim::new(sample_rate, args.trim_target, args.buffer_size as usize));
+    let mut route = (routing != InputRouting::Stereo)
+        .then(|| InputRoute::new(routing, args.buffer_size as usize));
+    // The TUI owns stdin and the screen: it handles panic and the rig toggle
+    // itself and shows the auto-trim result in its status line.
+    let keys = if use_tui {
+        KeyFlags {
+            panic: Arc::new(AtomicBool::new(false)),
+            toggle_rig: Arc::new(AtomicBool::new(false)),
+        }
+    } else {
+        if engine.is_dual() {
+            println!(
+                "\nType p + Enter to panic (reset all effect state), r + Enter to switch \
+                 rigs, Ctrl+C to stop...\n"
+            );
+        } else {
+            println!("\nType p + Enter to panic (reset all effect state), Ctrl+C to stop...\n");
+        }
+        if let Some(trim) = &trim {
+            trim.spawn_reporter();
+        }
+        spawn_key_listener(engine.is_dual().then(|| engine.active_rig()))
+    };
 
     // Create audio stream
     let config = StreamConfig {
@@ -160,17 +567,103 @@ pub fn run(args: RealtimeArgs) -> anyhow::Result<()> {
         r.store(false, Ordering::SeqCst);
     })?;
 
+    #[cfg(feature = "tui")]
+    let tui_ui = if use_tui {
+        let tui_meters = Arc::new(PeakMeters::new(engine.slot_count()));
+        meters.push(Arc::clone(&tui_meters));
+        let mut session = TuiSession::new(
+            &engine,
+            commands.clone(),
+            tui_meters,
+            stream.running_handle(),
+            format!(
+                "sonido realtime | {input_name} -> {output_name} | {} Hz, {} samples",
+                args.sample_rate, args.buffer_size
+            ),
+        );
+        if let Some(trim) = &trim {
+            session = session.with_status(trim.status());
+        }
+        let close = session.close_handle();
+        Some((close, std::thread::spawn(move || session.run())))
+    } else {
+        None
+    };
+
+    #[cfg(feature = "pi")]
+    let pedalboard = pedalboard.map(|session| {
+        meters.extend(session.meters());
+        let close = session.close_handle();
+        (close, std::thread::spawn(move || session.run()))
+    });
+    #[cfg(any(feature = "tui", feature = "pi"))]
+    drop(commands);
+
     // Run the audio stream on the main thread
     // Use stereo or mono processing based on flag
-    if args.mono {
+    let result = if args.mono {
         stream.run(move |input, output| {
-            engine.process_block(input, output);
-        })?;
+            if keys.panic.swap(false, Ordering::AcqRel) {
+                engine.panic();
+            }
+            if keys.toggle_rig.swap(false, Ordering::AcqRel) {
+                engine.toggle_rig();
+            }
+            #[cfg(any(feature = "tui", feature = "pi"))]
+            EngineCommand::apply_pending(&command_queue, &mut engine);
+            match trim.as_mut() {
+                Some(trim) => engine.process_block(trim.process(input, input).0, output),
+                None => engine.process_block(input, output),
+            }
+            #[cfg(any(feature = "tui", feature = "pi"))]
+            for meters in &meters {
+                meters.record(&engine, (input, input), (&*output, &*output));
+            }
+        })
     } else {
         stream.run_stereo(move |left_in, right_in, left_out, right_out| {
-            engine.process_block_stereo(left_in, right_in, left_out, right_out);
-        })?;
+            if keys.panic.swap(false, Ordering::AcqRel) {
+                engine.panic();
+            }
+            if keys.toggle_rig.swap(false, Ordering::AcqRel) {
+                engine.toggle_rig();
+            }
+            #[cfg(any(feature = "tui", feature = "pi"))]
+            EngineCommand::apply_pending(&command_queue, &mut engine);
+            let (left_in, right_in) = match route.as_mut() {
+                Some(route) => route.process(left_in, right_in),
+                None => (left_in, right_in),
+            };
+            match trim.as_mut() {
+                Some(trim) => {
+                    let (left_in, right_in) = trim.process(left_in, right_in);
+                    engine.process_block_stereo(left_in, right_in, left_out, right_out);
+                }
+                None => engine.process_block_stereo(left_in, right_in, left_out, right_out),
+            }
+            #[cfg(any(feature = "tui", feature = "pi"))]
+            for meters in &meters {
+                meters.record(&engine, (left_in, right_in), (&*left_out, &*right_out));
+            }
+        })
+    };
+
+    #[cfg(feature = "pi")]
+    if let Some((close, handle)) = pedalboard {
+        close.store(true, Ordering::SeqCst);
+        handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("pedalboard thread panicked"))?;
+    }
+    // Stop the TUI (restoring the terminal) before reporting a stream error.
+    #[cfg(feature = "tui")]
+    if let Some((close, handle)) = tui_ui {
+        close.store(true, Ordering::SeqCst);
+        handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("TUI thread panicked"))??;
     }
+    result?;
 
     println!("Done!");
     Ok(())
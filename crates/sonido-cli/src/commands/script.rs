@@ -0,0 +1,64 @@
+//! Script command: run rhai scripts that build chains and render files.
+//!
+//! See `sonido_script` for the script API. Scripts start from an empty chain.
+//! Transport commands (`play()`, `seek()`, ...) need the GUI console and fail
+//! here; use `render()` for batch work.
+
+use clap::{Args, Subcommand};
+use sonido_io::{WavSpec, read_wav_stereo, write_wav_stereo};
+use sonido_script::{ScriptChain, ScriptError, ScriptHost, ScriptRunner};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct ScriptArgs {
+    #[command(subcommand)]
+    command: ScriptCommand,
+}
+
+#[derive(Subcommand)]
+enum ScriptCommand {
+    /// Run a script file
+    Run {
+        /// Script file (e.g., patch.rhai)
+        file: PathBuf,
+    },
+}
+
+pub fn run(args: ScriptArgs) -> anyhow::Result<()> {
+    match args.command {
+        ScriptCommand::Run { file } => {
+            let mut runner = ScriptRunner::new(ScriptChain::new(), CliHost);
+            runner.run_file(&file)?;
+            Ok(())
+        }
+    }
+}
+
+/// Prints to stdout and renders WAV files (32-bit float, stereo).
+struct CliHost;
+
+impl ScriptHost for CliHost {
+    fn print(&mut self, text: &str) {
+        println!("{text}");
+    }
+
+    fn render(
+        &mut self,
+        chain: &ScriptChain,
+        input: &Path,
+        output: &Path,
+    ) -> Result<(), ScriptError> {
+        let (samples, spec) = read_wav_stereo(input)
+            .map_err(|e| ScriptError::Host(format!("{}: {e}", input.display())))?;
+        let rendered = chain.render(&samples, spec.sample_rate as f32);
+        let out_spec = WavSpec {
+            channels: 2,
+            sample_rate: spec.sample_rate,
+            bits_per_sample: 32,
+        };
+        write_wav_stereo(output, &rendered, out_spec)
+            .map_err(|e| ScriptError::Host(format!("{}: {e}", output.display())))?;
+        println!("Rendered {} -> {}", input.display(), output.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+//! Edits sent to the realtime audio callback from control threads.
+//!
+//! The callback owns the [`GraphEngine`]; the terminal UI and the hardware
+//! pedalboard send [`EngineCommand`]s over a channel, and the callback
+//! applies whatever is pending before each block. Levels travel the other
+//! way through [`PeakMeters`], one set per reader.
+
+use sonido_core::graph::GraphEngine;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::Receiver;
+
+/// An edit to the running engine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EngineCommand {
+    /// Set a parameter (plain units).
+    SetParam {
+        /// Chain slot.
+        slot: usize,
+        /// Parameter index within the slot's effect.
+        param: usize,
+        /// New value.
+        value: f32,
+    },
+    /// Bypass or enable a slot.
+    SetBypass {
+        /// Chain slot.
+        slot: usize,
+        /// New bypass state.
+        bypassed: bool,
+    },
+    /// Monitor one slot's output alone, or `None` for the full chain.
+    SetSolo {
+        /// Chain slot to solo.
+        slot: Option<usize>,
+    },
+    /// Reset all effect state ([`GraphEngine::panic`]).
+    Panic,
+    /// Crossfade to the other rig of a dual-rig engine
+    /// ([`GraphEngine::toggle_rig`]).
+    ToggleRig,
+}
+
+impl EngineCommand {
+    /// Apply the command to the engine (audio thread).
+    pub fn apply(self, engine: &mut GraphEngine) {
+        match self {
+            Self::SetParam { slot, param, value } => {
+                engine.set_param_at(slot, param, value);
+            }
+            Self::SetBypass { slot, bypassed } => engine.set_bypass_at(slot, bypassed),
+            Self::SetSolo { slot } => {
+                engine.set_solo_at(slot);
+            }
+            Self::Panic => engine.panic(),
+            Self::ToggleRig => {
+                engine.toggle_rig();
+            }
+        }
+    }
+
+    /// Apply every pending command (never blocks).
+    pub fn apply_pending(commands: &Receiver<Self>, engine: &mut GraphEngine) {
+        while let Ok(command) = commands.try_recv() {
+            command.apply(engine);
+        }
+    }
+}
+
+/// Peak levels published by the audio callback.
+///
+/// Each meter holds the largest absolute sample since its reader last took it.
+/// Peaks are non-negative, so their `f32` bit patterns order the same way
+/// as the values and `fetch_max` on the bits is a float max.
+pub struct PeakMeters {
+    input: [AtomicU32; 2],
+    output: [AtomicU32; 2],
+    slots: Vec<AtomicU32>,
+}
+
+impl PeakMeters {
+    /// Create meters for a chain of `slots` effects.
+    pub fn new(slots: usize) -> Self {
+        Self {
+            input: [AtomicU32::new(0), AtomicU32::new(0)],
+            output: [AtomicU32::new(0), AtomicU32::new(0)],
+            slots: (0..slots).map(|_| AtomicU32::new(0)).collect(),
+        }
+    }
+
+    /// Record one processed block (audio thread).
+    pub fn record(&self, engine: &GraphEngine, input: (&[f32], &[f32]), output: (&[f32], &[f32])) {
+        for (meter, block) in self.input.iter().zip([input.0, input.1]) {
+            meter.fetch_max(block_peak(block).to_bits(), Ordering::Relaxed);
+        }
+        for (meter, block) in self.output.iter().zip([output.0, output.1]) {
+            meter.fetch_max(block_peak(block).to_bits(), Ordering::Relaxed);
+        }
+        for (meter, &id) in self.slots.iter().zip(engine.chain_order()) {
+            if let Some((l, r)) = engine.node_peak(id) {
+                meter.fetch_max(l.abs().max(r.abs()).to_bits(), Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Read and clear the input meters (left, right).
+    #[cfg(feature = "tui")]
+    pub fn take_input(&self) -> [f32; 2] {
+        self.input.each_ref().map(take)
+    }
+
+    /// Read and clear the output meters (left, right).
+    #[cfg(feature = "tui")]
+    pub fn take_output(&self) -> [f32; 2] {
+        self.output.each_ref().map(take)
+    }
+
+    /// Read and clear one slot's output meter.
+    pub fn take_slot(&self, slot: usize) -> f32 {
+        self.slots.get(slot).map_or(0.0, take)
+    }
+}
+
+/// Read and clear a meter.
+fn take(meter: &AtomicU32) -> f32 {
+    f32::from_bits(meter.swap(0, Ordering::Relaxed))
+}
+
+fn block_peak(block: &[f32]) -> f32 {
+    block.iter().fold(0.0f32, |peak, s| peak.max(s.abs()))
+}
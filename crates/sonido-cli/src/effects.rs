@@ -4,7 +4,7 @@
 //! display types for the `sonido effects` command.
 
 // Re-export shared types from sonido-graph-dsl
-pub use sonido_graph_dsl::{EffectError, parse_chain};
+pub use sonido_graph_dsl::{EffectError, check_effect_params, parse_chain, parse_effect_spec};
 
 use sonido_core::EffectWithParams;
 use sonido_registry::EffectRegistry;
@@ -103,7 +103,7 @@ mod tests {
 
     #[test]
     fn available_effects_count() {
-        assert_eq!(available_effects().len(), 35);
+        assert_eq!(available_effects().len(), 42);
     }
 
     #[test]
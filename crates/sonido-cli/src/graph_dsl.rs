@@ -4,22 +4,10 @@
 //! crate so that both CLI and GUI can use the same DSL.
 
 pub use sonido_graph_dsl::{
-    DslError, GraphSpec, build_graph_only, build_graph_slug, parse_graph_dsl, validate_spec,
+    DslError, GraphNode, GraphSpec, ManifestEntry, build_graph, build_graph_from_json,
+    build_graph_from_preset, build_graph_slug, parse_graph_dsl, validate_spec,
 };
 
-/// Build a [`ProcessingGraph`](sonido_core::graph::ProcessingGraph) from a DSL spec.
-///
-/// Thin wrapper that discards the manifest. CLI's `--graph` flag uses this
-/// because it wraps the graph in `GraphEngine::new()` (legacy path).
-/// Use [`sonido_graph_dsl::build_graph`] for the manifest-aware version.
-pub fn build_graph(
-    spec: &GraphSpec,
-    sample_rate: f32,
-    block_size: usize,
-) -> Result<sonido_core::graph::ProcessingGraph, DslError> {
-    build_graph_only(spec, sample_rate, block_size)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,8 +22,9 @@ mod tests {
     fn reexport_build_works() {
         let spec = parse_graph_dsl("reverb:mix=0.3").unwrap();
         validate_spec(&spec).unwrap();
-        let graph = build_graph(&spec, 48000.0, 256).unwrap();
+        let (graph, manifest) = build_graph(&spec, 48000.0, 256).unwrap();
         assert_eq!(graph.node_count(), 3);
+        assert_eq!(manifest[0].1, "reverb");
     }
 
     #[test]
@@ -1,15 +1,27 @@
 //! Sonido CLI - Command-line interface for the Sonido DSP framework.
 
 mod commands;
+#[cfg(any(feature = "tui", feature = "pi"))]
+mod control;
 mod effects;
 mod graph_dsl;
+#[cfg(feature = "pi")]
+mod pedalboard;
+#[cfg(feature = "tui")]
+mod tui;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "sonido")]
 #[command(author, version, about = "Sonido DSP Framework CLI", long_about = None)]
 struct Cli {
+    /// Also write log messages to this file (appended, without colors).
+    ///
+    /// Verbosity follows `RUST_LOG` (default: warn).
+    #[arg(long, global = true, value_name = "PATH")]
+    log_file: Option<std::path::PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -31,6 +43,15 @@ enum Commands {
     /// Compare two audio files (A/B comparison)
     Compare(commands::compare::CompareArgs),
 
+    /// Fit effect parameters to a reference recording
+    Match(commands::matching::MatchArgs),
+
+    /// Measure a hardware device: sweep response, latency, and THD vs level
+    Measure(commands::measure::MeasureArgs),
+
+    /// Benchmark DSP load of effects and the default chain (realtime factor)
+    Bench(commands::bench::BenchArgs),
+
     /// List and manage audio devices
     Devices(commands::devices::DevicesArgs),
 
@@ -40,6 +61,9 @@ enum Commands {
     /// Display WAV file information
     Info(commands::info::InfoArgs),
 
+    /// Inspect graph topologies (DOT export)
+    Graph(commands::graph::GraphArgs),
+
     /// Play an audio file through effects
     Play(commands::play::PlayArgs),
 
@@ -48,17 +72,49 @@ enum Commands {
 
     /// Daisy Seed preset management
     Daisy(commands::daisy::DaisyArgs),
+
+    /// Print a shell completion script (bash, zsh, fish, elvish, powershell)
+    Completions(commands::completions::CompletionsArgs),
+
+    /// Run rhai scripts that build effect chains and render files
+    #[cfg(feature = "script")]
+    Script(commands::script::ScriptArgs),
 }
 
 fn main() -> anyhow::Result<()> {
-    use tracing_subscriber::EnvFilter;
+    use anyhow::Context;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::{EnvFilter, fmt};
 
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()))
-        .init();
+    // Answer completion requests from the script printed by `sonido completions`.
+    clap_complete::CompleteEnv::with_factory(Cli::command)
+        .var(commands::completions::COMPLETE_VAR)
+        .complete();
 
     let cli = Cli::parse();
 
+    let log_file = cli
+        .log_file
+        .as_ref()
+        .map(|path| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("cannot open log file {}", path.display()))
+        })
+        .transpose()?;
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()))
+        .with(fmt::layer())
+        .with(log_file.map(|file| {
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(std::sync::Mutex::new(file))
+        }))
+        .init();
+
     tracing::debug!(command = ?std::mem::discriminant(&cli.command), "dispatching command");
 
     match cli.command {
@@ -67,11 +123,18 @@ fn main() -> anyhow::Result<()> {
         Commands::Generate(args) => commands::generate::run(args),
         Commands::Analyze(args) => commands::analyze::run(args),
         Commands::Compare(args) => commands::compare::run(args),
+        Commands::Match(args) => commands::matching::run(args),
+        Commands::Measure(args) => commands::measure::run(args),
+        Commands::Bench(args) => commands::bench::run(args),
         Commands::Devices(args) => commands::devices::run(args),
         Commands::Effects(args) => commands::effects::run(args),
         Commands::Info(args) => commands::info::run(args),
+        Commands::Graph(args) => commands::graph::run(args),
         Commands::Play(args) => commands::play::run(args),
         Commands::Presets(args) => commands::presets::run(args),
         Commands::Daisy(args) => commands::daisy::run(args),
+        Commands::Completions(args) => commands::completions::run(args),
+        #[cfg(feature = "script")]
+        Commands::Script(args) => commands::script::run(args),
     }
 }
@@ -0,0 +1,511 @@
+//! Raspberry Pi pedalboard for `sonido realtime --pedalboard`.
+//!
+//! Reads rotary encoders and footswitches on the GPIO header and
+//! potentiometers on an ADS1115 ADC over I2C (through rppal), maps them to
+//! chain parameters with a [`PedalboardConfig`], and drives status LEDs.
+//!
+//! Like the TUI, the pedalboard runs on its own thread and never touches the
+//! [`GraphEngine`]: control changes reach the audio callback as
+//! [`EngineCommand`]s, and clip LEDs read slot levels from [`PeakMeters`].
+//! The thread polls every [`POLL_INTERVAL`], fast enough to decode encoders
+//! in software. Each change is also written back to the other controls on
+//! the same target and shown on the LEDs through [`LedFeedback`].
+
+use crate::control::{EngineCommand, PeakMeters};
+use rppal::gpio::{Gpio, InputPin, Level, OutputPin};
+use rppal::i2c::I2c;
+use sonido_config::{
+    AdcConfig, CurveConfig, HardwareControl, LedMode, PedalboardConfig, PickupConfig,
+};
+use sonido_core::graph::GraphEngine;
+use sonido_core::param_info::{ParamDescriptor, ParamUnit};
+use sonido_graph_dsl::resolve_param_index;
+use sonido_platform::{
+    Breakpoints, ControlMapper, ControlResponse, ControlType, FootswitchMode, LedFeedback,
+    LedPattern, Pedalboard, PedalboardIo, PickupMode, PlatformController, ResponseCurve,
+};
+use sonido_registry::EffectRegistry;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+/// Control poll interval.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Maximum number of control → parameter bindings.
+const MAX_BINDINGS: usize = 128;
+
+/// Maximum number of LEDs.
+const MAX_LEDS: usize = 32;
+
+/// How long a clip LED stays lit after the slot's output clips.
+const CLIP_HOLD_SECS: f32 = 0.25;
+
+/// ADS1115 conversion register.
+const ADS1115_CONVERSION: u8 = 0x00;
+
+/// ADS1115 config register.
+const ADS1115_CONFIG: u8 = 0x01;
+
+/// Single-shot conversion against ground, ±4.096 V, 860 SPS, comparator
+/// off; OR in the channel at bits 12–13.
+const ADS1115_SINGLE_SHOT: u16 = 0xC3E3;
+
+/// Input voltage at ADS1115 full scale with the ±4.096 V range.
+const ADS1115_FULL_SCALE_V: f32 = 4.096;
+
+/// Time for one conversion at 860 SPS, with margin.
+const ADS1115_CONVERSION_TIME: Duration = Duration::from_micros(1500);
+
+/// What a binding drives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    /// A parameter, by chain slot and parameter index.
+    Param { slot: usize, param: usize },
+    /// A slot's bypass: high engages the effect.
+    Bypass { slot: usize },
+}
+
+/// What an LED shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Indicator {
+    /// A target's normalized position (1.0 for an engaged slot).
+    State(Target),
+    /// A parameter's period in seconds, for tempo blinking.
+    Period { slot: usize, param: usize },
+    /// A slot's output peak.
+    Clip { slot: usize },
+}
+
+/// ADS1115 read round-robin: one channel converts while the others return
+/// their last reading, so knob reads never block the encoder polling.
+struct Ads1115 {
+    i2c: I2c,
+    /// Volts-per-count over the pot supply voltage.
+    scale: f32,
+    channels: Vec<u8>,
+    next: usize,
+    pending: Option<(u8, Instant)>,
+    values: [Option<f32>; 4],
+}
+
+impl Ads1115 {
+    fn new(config: &AdcConfig, channels: Vec<u8>) -> anyhow::Result<Self> {
+        let mut i2c = I2c::with_bus(config.bus)?;
+        i2c.set_slave_address(config.address)?;
+        let mut adc = Self {
+            i2c,
+            scale: ADS1115_FULL_SCALE_V / (f32::from(i16::MAX) * config.vref),
+            channels,
+            next: 0,
+            pending: None,
+            values: [None; 4],
+        };
+        // Read every knob once so the controls start where they are.
+        for channel in adc.channels.clone() {
+            adc.start(channel)?;
+            std::thread::sleep(ADS1115_CONVERSION_TIME);
+            adc.values[usize::from(channel)] = Some(adc.fetch()?);
+        }
+        Ok(adc)
+    }
+
+    /// Latest reading of `channel`, advancing the round-robin.
+    fn read(&mut self, channel: u8) -> Option<f32> {
+        self.service();
+        self.values.get(usize::from(channel)).copied().flatten()
+    }
+
+    fn service(&mut self) {
+        if let Some((channel, started)) = self.pending {
+            if started.elapsed() < ADS1115_CONVERSION_TIME {
+                return;
+            }
+            self.values[usize::from(channel)] = self.fetch().ok();
+            self.pending = None;
+        }
+        let channel = self.channels[self.next];
+        self.next = (self.next + 1) % self.channels.len();
+        if self.start(channel).is_ok() {
+            self.pending = Some((channel, Instant::now()));
+        }
+    }
+
+    fn start(&mut self, channel: u8) -> rppal::i2c::Result<()> {
+        let [high, low] = (ADS1115_SINGLE_SHOT | u16::from(channel) << 12).to_be_bytes();
+        self.i2c.write(&[ADS1115_CONFIG, high, low]).map(|_| ())
+    }
+
+    fn fetch(&mut self) -> rppal::i2c::Result<f32> {
+        let mut raw = [0; 2];
+        self.i2c.write_read(&[ADS1115_CONVERSION], &mut raw)?;
+        Ok((f32::from(i16::from_be_bytes(raw)) * self.scale).clamp(0.0, 1.0))
+    }
+}
+
+/// GPIO and I2C access through rppal.
+///
+/// Inputs use the internal pull-ups and are active when pulled to ground;
+/// outputs start low.
+struct PiIo {
+    inputs: HashMap<u8, InputPin>,
+    outputs: HashMap<u8, OutputPin>,
+    adc: Option<Ads1115>,
+}
+
+impl PiIo {
+    fn new(config: &PedalboardConfig) -> anyhow::Result<Self> {
+        let gpio = Gpio::new()?;
+        let mut inputs = HashMap::new();
+        let mut outputs = HashMap::new();
+        let mut channels = Vec::new();
+        for control in &config.controls {
+            match *control {
+                HardwareControl::Encoder { pins, .. } => {
+                    for pin in pins {
+                        inputs.insert(pin, gpio.get(pin)?.into_input_pullup());
+                    }
+                }
+                HardwareControl::Footswitch { pin, .. } => {
+                    inputs.insert(pin, gpio.get(pin)?.into_input_pullup());
+                }
+                HardwareControl::Led { pin } => {
+                    outputs.insert(pin, gpio.get(pin)?.into_output_low());
+                }
+                HardwareControl::Knob { channel, .. } => channels.push(channel),
+            }
+        }
+        let adc = match &config.adc {
+            Some(adc) if !channels.is_empty() => Some(Ads1115::new(adc, channels)?),
+            _ => None,
+        };
+        Ok(Self {
+            inputs,
+            outputs,
+            adc,
+        })
+    }
+}
+
+impl PedalboardIo for PiIo {
+    fn read_pin(&mut self, pin: u8) -> bool {
+        self.inputs.get(&pin).is_some_and(InputPin::is_low)
+    }
+
+    fn write_pin(&mut self, pin: u8, high: bool) {
+        if let Some(output) = self.outputs.get_mut(&pin) {
+            output.write(if high { Level::High } else { Level::Low });
+        }
+    }
+
+    fn read_adc(&mut self, channel: u8) -> Option<f32> {
+        self.adc.as_mut()?.read(channel)
+    }
+}
+
+/// Response curve from its stored form.
+fn curve_from_config(config: &CurveConfig) -> anyhow::Result<ResponseCurve> {
+    Ok(match config {
+        CurveConfig::Linear => ResponseCurve::Linear,
+        CurveConfig::Log => ResponseCurve::Log,
+        CurveConfig::Custom(points) => {
+            let points: Vec<_> = points.iter().map(|&[x, y]| (x, y)).collect();
+            ResponseCurve::Custom(
+                Breakpoints::from_points(&points)
+                    .ok_or_else(|| anyhow::anyhow!("too many curve points"))?,
+            )
+        }
+    })
+}
+
+/// Blink period in seconds for a parameter value: a time in milliseconds
+/// or seconds, or a rate in hertz. `None` for parameters in other units.
+fn period_secs(desc: &ParamDescriptor, value: f32) -> Option<f32> {
+    match desc.unit {
+        ParamUnit::Milliseconds => Some(value / 1000.0),
+        ParamUnit::Seconds => Some(value),
+        ParamUnit::Hertz if value > 0.0 => Some(value.recip()),
+        ParamUnit::Hertz => Some(0.0),
+        _ => None,
+    }
+}
+
+/// Normalized position of a target in the engine.
+fn current_position(engine: &GraphEngine, target: Target) -> f32 {
+    match target {
+        Target::Param { slot, param } => engine
+            .param_descriptor_at(slot, param)
+            .zip(engine.get_param_at(slot, param))
+            .map_or(0.0, |(desc, value)| desc.normalize(value)),
+        Target::Bypass { slot } => {
+            if engine.is_bypassed_at(slot) {
+                0.0
+            } else {
+                1.0
+            }
+        }
+    }
+}
+
+/// The pedalboard thread: polls the hardware and sends the bound edits.
+pub struct PedalboardSession {
+    board: Pedalboard<PiIo>,
+    mapper: ControlMapper<MAX_BINDINGS, Target>,
+    descriptors: HashMap<(usize, usize), ParamDescriptor>,
+    leds: LedFeedback<MAX_LEDS, Indicator>,
+    /// Slots with clip LEDs.
+    clip_slots: Vec<usize>,
+    /// Slot levels recorded by the audio callback, if there are clip LEDs.
+    meters: Option<Arc<PeakMeters>>,
+    commands: Sender<EngineCommand>,
+    closed: Arc<AtomicBool>,
+}
+
+impl PedalboardSession {
+    /// Open the hardware described by `config` and resolve its bindings
+    /// against the engine's chain.
+    ///
+    /// Encoders and toggle footswitches start at the current value of the
+    /// first thing they drive, so the first turn or press doesn't jump, and
+    /// LEDs start out showing the chain as loaded. Knobs take over the
+    /// loaded values in their configured pickup mode.
+    pub fn new(
+        config: &PedalboardConfig,
+        engine: &GraphEngine,
+        commands: Sender<EngineCommand>,
+    ) -> anyhow::Result<Self> {
+        let registry = EffectRegistry::new();
+        let mut board = Pedalboard::new(PiIo::new(config)?);
+        let ids = config
+            .controls
+            .iter()
+            .map(|control| {
+                match *control {
+                    HardwareControl::Encoder { pins: [a, b], step } => {
+                        board.add_encoder(a, b, step)
+                    }
+                    HardwareControl::Knob { channel, .. } => board.add_knob(channel),
+                    HardwareControl::Footswitch { pin, momentary } => board.add_footswitch(
+                        pin,
+                        if momentary {
+                            FootswitchMode::Momentary
+                        } else {
+                            FootswitchMode::Toggle
+                        },
+                    ),
+                    HardwareControl::Led { pin } => board.add_led(pin),
+                }
+                .ok_or_else(|| anyhow::anyhow!("too many pedalboard controls"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut session = Self {
+            board,
+            mapper: ControlMapper::new(),
+            descriptors: HashMap::new(),
+            leds: LedFeedback::new(),
+            clip_slots: Vec::new(),
+            meters: None,
+            commands,
+            closed: Arc::new(AtomicBool::new(false)),
+        };
+
+        for binding in &config.bindings {
+            let id = ids[binding.control];
+            let slot = binding.slot;
+            let Some(effect_id) = engine.effect_id_at(slot) else {
+                anyhow::bail!(
+                    "control {} is bound to slot {slot}, but the chain has {} slot(s)",
+                    binding.control,
+                    engine.slot_count()
+                );
+            };
+            let target = match &binding.param {
+                Some(key) => {
+                    let param = resolve_param_index(&registry, effect_id, key)
+                        .and_then(|param| {
+                            let desc = engine.param_descriptor_at(slot, param)?;
+                            session.descriptors.insert((slot, param), desc);
+                            Some(param)
+                        })
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("slot {slot} ({effect_id}) has no parameter '{key}'")
+                        })?;
+                    Target::Param { slot, param }
+                }
+                None => Target::Bypass { slot },
+            };
+
+            if session.board.control_type(id) == Some(ControlType::Led) {
+                let (indicator, pattern) = match (binding.led, target) {
+                    (LedMode::Tempo, Target::Param { slot, param }) => {
+                        (Indicator::Period { slot, param }, LedPattern::Tempo)
+                    }
+                    (LedMode::Clip, _) => {
+                        if !session.clip_slots.contains(&slot) {
+                            session.clip_slots.push(slot);
+                        }
+                        (
+                            Indicator::Clip { slot },
+                            LedPattern::Peak {
+                                hold: CLIP_HOLD_SECS,
+                            },
+                        )
+                    }
+                    _ => (Indicator::State(target), LedPattern::Switch),
+                };
+                if !session.leds.bind(id, indicator, pattern) {
+                    anyhow::bail!("too many pedalboard LEDs (max {MAX_LEDS})");
+                }
+                if let Indicator::Period { slot, param } = indicator
+                    && period_secs(&session.descriptors[&(slot, param)], 0.0).is_none()
+                {
+                    anyhow::bail!(
+                        "control {}: '{}' on slot {slot} is not a time or rate, \
+                         so it has no tempo to blink at",
+                        binding.control,
+                        binding.param.as_deref().unwrap_or_default()
+                    );
+                }
+                continue;
+            }
+            let response =
+                ControlResponse::new(curve_from_config(&binding.curve)?, binding.min, binding.max)
+                    .inverted(binding.invert);
+            let first = session.mapper.targets(id).next().is_none();
+            if !session.mapper.add_target(id, target, response) {
+                anyhow::bail!("too many pedalboard bindings (max {MAX_BINDINGS})");
+            }
+            if first {
+                let current = current_position(engine, target);
+                session
+                    .board
+                    .write_control(id, response.position_for(current));
+            }
+        }
+
+        for (control, &id) in config.controls.iter().zip(&ids) {
+            if let HardwareControl::Knob { pickup, .. } = *control {
+                session.mapper.set_pickup_mode(
+                    id,
+                    match pickup {
+                        PickupConfig::Jump => PickupMode::Jump,
+                        PickupConfig::Pickup => PickupMode::Pickup,
+                        PickupConfig::Relative => PickupMode::Relative,
+                    },
+                );
+            }
+        }
+        let driven: Vec<_> = session
+            .mapper
+            .entries()
+            .map(|(_, target, _)| target)
+            .collect();
+        for target in driven {
+            session
+                .mapper
+                .sync(target, current_position(engine, target));
+        }
+
+        let shown: Vec<_> = session
+            .leds
+            .entries()
+            .filter_map(|(_, indicator, _)| match indicator {
+                Indicator::State(target) => Some(target),
+                Indicator::Period { slot, param } => Some(Target::Param { slot, param }),
+                Indicator::Clip { .. } => None,
+            })
+            .collect();
+        for target in shown {
+            session.show(target, current_position(engine, target));
+        }
+        if !session.clip_slots.is_empty() {
+            session.meters = Some(Arc::new(PeakMeters::new(engine.slot_count())));
+        }
+        Ok(session)
+    }
+
+    /// Flag that stops [`run`](Self::run).
+    pub fn close_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.closed)
+    }
+
+    /// Slot levels for the clip LEDs, if any, to be recorded by the audio
+    /// callback.
+    pub fn meters(&self) -> Option<Arc<PeakMeters>> {
+        self.meters.clone()
+    }
+
+    /// Poll the hardware until closed, then turn the LEDs off.
+    pub fn run(mut self) {
+        let mut last = Instant::now();
+        while !self.closed.load(Ordering::Acquire) {
+            self.board.poll();
+            self.dispatch();
+            if let Some(meters) = &self.meters {
+                for &slot in &self.clip_slots {
+                    self.leds
+                        .update(Indicator::Clip { slot }, meters.take_slot(slot));
+                }
+            }
+            let now = Instant::now();
+            self.leds
+                .flush(&mut self.board, now.duration_since(last).as_secs_f32());
+            last = now;
+            std::thread::sleep(POLL_INTERVAL);
+        }
+        for (led, _, _) in self.leds.entries() {
+            self.board.write_control(led, 0.0);
+        }
+        self.board.flush();
+    }
+
+    /// Show a target's new position on its LEDs.
+    fn show(&mut self, target: Target, position: f32) {
+        self.leds.update(Indicator::State(target), position);
+        if let Target::Param { slot, param } = target
+            && let Some(desc) = self.descriptors.get(&(slot, param))
+            && let Some(period) = period_secs(desc, desc.denormalize(position))
+        {
+            self.leds.update(Indicator::Period { slot, param }, period);
+        }
+    }
+
+    /// Send the edits for every control that changed this poll, and move
+    /// the LEDs and other controls on the same targets to match (knobs on
+    /// them fall back to their pickup mode).
+    fn dispatch(&mut self) {
+        for index in 0..self.board.control_count() {
+            let Some(id) = self.board.control_id(index) else {
+                continue;
+            };
+            let Some(state) = self.board.read_control(id).filter(|state| state.changed) else {
+                continue;
+            };
+            let moved: Vec<_> = self.mapper.move_control(id, state.value).collect();
+            for (target, position) in moved {
+                let command = match target {
+                    Target::Param { slot, param } => EngineCommand::SetParam {
+                        slot,
+                        param,
+                        value: self.descriptors[&(slot, param)].denormalize(position),
+                    },
+                    Target::Bypass { slot } => EngineCommand::SetBypass {
+                        slot,
+                        bypassed: position < 0.5,
+                    },
+                };
+                let _ = self.commands.send(command);
+                self.show(target, position);
+                self.mapper.sync(target, position);
+                for (other, value) in self.mapper.writeback(target, position) {
+                    if other != id {
+                        self.board.write_control(other, value);
+                    }
+                }
+            }
+        }
+    }
+}
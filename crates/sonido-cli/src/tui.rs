@@ -0,0 +1,625 @@
+//! Terminal UI for `sonido realtime --tui`.
+//!
+//! Shows the chain's slots with per-slot output meters, the selected slot's
+//! parameters, and input/output meters, and lets the user pick and adjust
+//! parameters from the keyboard. Meant for headless rigs (a Raspberry Pi
+//! pedalboard over SSH or on a small console) where the GUI cannot run.
+//!
+//! The UI runs on its own thread and never touches the [`GraphEngine`]:
+//! edits reach the audio callback as [`EngineCommand`]s over a channel, and the
+//! callback publishes peak levels through [`PeakMeters`].
+
+use crate::control::{EngineCommand, PeakMeters};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Gauge, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use sonido_core::graph::{GraphEngine, Rig};
+use sonido_core::param_info::{ParamDescriptor, ParamFlags};
+use sonido_registry::EffectRegistry;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+/// Arrow-key step as a fraction of the normalized range.
+const KEY_STEP: f32 = 0.01;
+
+/// Shift+arrow step as a fraction of the normalized range.
+const KEY_STEP_FINE: f32 = 0.001;
+
+/// Redraw interval (the meters fall back to silence between peaks).
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Bottom of the meter scale in dBFS.
+const METER_FLOOR_DB: f32 = -60.0;
+
+/// One parameter as shown in the UI.
+#[derive(Debug, Clone)]
+struct ParamView {
+    index: usize,
+    desc: ParamDescriptor,
+    value: f32,
+}
+
+/// One chain slot as shown in the UI.
+#[derive(Debug, Clone)]
+struct SlotView {
+    name: String,
+    bypassed: bool,
+    params: Vec<ParamView>,
+}
+
+/// A key press, after mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    NextSlot,
+    PrevSlot,
+    NextParam,
+    PrevParam,
+    Increase { fine: bool },
+    Decrease { fine: bool },
+    ToggleBypass,
+    ToggleSolo,
+    Panic,
+    ToggleRig,
+    Quit,
+}
+
+impl Action {
+    fn from_key(key: KeyEvent) -> Option<Self> {
+        let fine = key.modifiers.contains(KeyModifiers::SHIFT);
+        Some(match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Self::Quit,
+            KeyCode::Char('q') | KeyCode::Esc => Self::Quit,
+            KeyCode::Tab | KeyCode::Char(']') => Self::NextSlot,
+            KeyCode::BackTab | KeyCode::Char('[') => Self::PrevSlot,
+            KeyCode::Down | KeyCode::Char('j') => Self::NextParam,
+            KeyCode::Up | KeyCode::Char('k') => Self::PrevParam,
+            KeyCode::Right | KeyCode::Char('l') => Self::Increase { fine },
+            KeyCode::Left | KeyCode::Char('h') => Self::Decrease { fine },
+            KeyCode::Char('b' | ' ') => Self::ToggleBypass,
+            KeyCode::Char('s') => Self::ToggleSolo,
+            KeyCode::Char('p') => Self::Panic,
+            KeyCode::Char('r') => Self::ToggleRig,
+            _ => return None,
+        })
+    }
+}
+
+/// UI state: the chain's slots and the current selection.
+struct TuiState {
+    slots: Vec<SlotView>,
+    slot: usize,
+    param: usize,
+    /// Slot whose output is monitored alone, if any.
+    solo: Option<usize>,
+    /// Whether the engine runs two rigs (slot names carry their rig).
+    dual: bool,
+}
+
+impl TuiState {
+    /// Capture the chain before the engine moves to the audio thread.
+    fn from_engine(engine: &GraphEngine, registry: &EffectRegistry) -> Self {
+        let dual = engine.is_dual();
+        let slots = (0..engine.slot_count())
+            .map(|slot| {
+                let id = engine.effect_id_at(slot).unwrap_or("?");
+                let name = registry
+                    .get(id)
+                    .map_or_else(|| id.to_string(), |d| d.name.to_string());
+                let params = (0..engine.param_count_at(slot))
+                    .filter_map(|index| {
+                        let desc = engine.param_descriptor_at(slot, index)?;
+                        if desc.flags.contains(ParamFlags::HIDDEN) {
+                            return None;
+                        }
+                        let value = engine.get_param_at(slot, index)?;
+                        Some(ParamView { index, desc, value })
+                    })
+                    .collect();
+                SlotView {
+                    name: if dual {
+                        let rig = if engine.rig_slots(Rig::B).contains(&slot) {
+                            Rig::B
+                        } else {
+                            Rig::A
+                        };
+                        format!("{}: {name}", rig.label())
+                    } else {
+                        name
+                    },
+                    bypassed: engine.is_bypassed_at(slot),
+                    params,
+                }
+            })
+            .collect();
+        Self {
+            slots,
+            slot: 0,
+            param: 0,
+            solo: engine.solo_slot(),
+            dual,
+        }
+    }
+
+    /// Update the state for `action` and return the edit to send, if any.
+    fn handle(&mut self, action: Action) -> Option<EngineCommand> {
+        let count = self.slots.len();
+        match action {
+            Action::NextSlot if count > 0 => {
+                self.slot = (self.slot + 1) % count;
+                self.param = 0;
+            }
+            Action::PrevSlot if count > 0 => {
+                self.slot = (self.slot + count - 1) % count;
+                self.param = 0;
+            }
+            Action::NextParam => {
+                let params = self.slots.get(self.slot)?.params.len();
+                self.param = (self.param + 1).min(params.saturating_sub(1));
+            }
+            Action::PrevParam => self.param = self.param.saturating_sub(1),
+            Action::Increase { fine } => return self.nudge(1.0, fine),
+            Action::Decrease { fine } => return self.nudge(-1.0, fine),
+            Action::ToggleBypass => {
+                let slot = self.slots.get_mut(self.slot)?;
+                slot.bypassed = !slot.bypassed;
+                return Some(EngineCommand::SetBypass {
+                    slot: self.slot,
+                    bypassed: slot.bypassed,
+                });
+            }
+            Action::ToggleSolo if count > 0 => {
+                self.solo = (self.solo != Some(self.slot)).then_some(self.slot);
+                return Some(EngineCommand::SetSolo { slot: self.solo });
+            }
+            Action::Panic => return Some(EngineCommand::Panic),
+            Action::ToggleRig if self.dual => return Some(EngineCommand::ToggleRig),
+            Action::NextSlot
+            | Action::PrevSlot
+            | Action::ToggleSolo
+            | Action::ToggleRig
+            | Action::Quit => {}
+        }
+        None
+    }
+
+    /// Step the selected parameter up (`direction` 1) or down (-1).
+    ///
+    /// Stepped parameters move by one step; continuous ones by
+    /// [`KEY_STEP`] (or [`KEY_STEP_FINE`]) of the normalized range, so
+    /// logarithmic parameters step evenly across their scale.
+    fn nudge(&mut self, direction: f32, fine: bool) -> Option<EngineCommand> {
+        let slot = self.slot;
+        let param = self.slots.get_mut(slot)?.params.get_mut(self.param)?;
+        let desc = &param.desc;
+        if desc.flags.contains(ParamFlags::READ_ONLY) {
+            return None;
+        }
+        let value = if desc.flags.contains(ParamFlags::STEPPED) {
+            param.value + direction * desc.step.max(1.0)
+        } else {
+            let step = if fine { KEY_STEP_FINE } else { KEY_STEP };
+            let normalized = (desc.normalize(param.value) + direction * step).clamp(0.0, 1.0);
+            desc.denormalize(normalized)
+        };
+        let value = desc.clamp(value);
+        if value == param.value {
+            return None;
+        }
+        param.value = value;
+        Some(EngineCommand::SetParam {
+            slot,
+            param: param.index,
+            value,
+        })
+    }
+}
+
+/// Everything the UI thread needs.
+pub struct TuiSession {
+    state: TuiState,
+    commands: Sender<EngineCommand>,
+    meters: Arc<PeakMeters>,
+    /// The stream's running flag, cleared when the user quits.
+    running: Arc<AtomicBool>,
+    /// Set by the audio side once the stream has stopped.
+    closed: Arc<AtomicBool>,
+    title: String,
+    /// Extra status (e.g. auto-trim), refreshed every frame.
+    status: Option<Box<dyn Fn() -> String + Send>>,
+}
+
+impl TuiSession {
+    /// Capture the engine's chain for display.
+    ///
+    /// Call before the engine moves into the audio callback. Quitting the
+    /// UI clears `running` to stop the stream; setting the
+    /// [`close_handle`](Self::close_handle) flag closes the UI.
+    pub fn new(
+        engine: &GraphEngine,
+        commands: Sender<EngineCommand>,
+        meters: Arc<PeakMeters>,
+        running: Arc<AtomicBool>,
+        title: String,
+    ) -> Self {
+        Self {
+            state: TuiState::from_engine(engine, &EffectRegistry::new()),
+            commands,
+            meters,
+            running,
+            closed: Arc::new(AtomicBool::new(false)),
+            title,
+            status: None,
+        }
+    }
+
+    /// Flag that closes the UI when set (e.g. after the stream fails).
+    pub fn close_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.closed)
+    }
+
+    /// Show an extra status line under the title.
+    pub fn with_status(mut self, status: impl Fn() -> String + Send + 'static) -> Self {
+        self.status = Some(Box::new(status));
+        self
+    }
+
+    /// Take over the terminal and run until the user quits or the UI is closed.
+    pub fn run(mut self) -> std::io::Result<()> {
+        let mut terminal = ratatui::init();
+        let result = self.event_loop(&mut terminal);
+        ratatui::restore();
+        self.running.store(false, Ordering::SeqCst);
+        result
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        let mut levels = Levels::new(self.state.slots.len());
+        while !self.closed.load(Ordering::SeqCst) {
+            levels.update(&self.meters);
+            let status = self.status.as_ref().map(|s| s());
+            terminal.draw(|frame| self.draw(frame, &levels, status.as_deref()))?;
+
+            if !event::poll(FRAME_INTERVAL)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match Action::from_key(key) {
+                Some(Action::Quit) => break,
+                Some(action) => {
+                    if let Some(command) = self.state.handle(action) {
+                        // The receiver only goes away when the stream stops.
+                        let _ = self.commands.send(command);
+                    }
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn draw(&self, frame: &mut Frame, levels: &Levels, status: Option<&str>) {
+        let [header, body, meters, help] = Layout::vertical([
+            Constraint::Length(if status.is_some() { 2 } else { 1 }),
+            Constraint::Min(4),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let mut header_lines = vec![Line::from(Span::styled(
+            self.title.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))];
+        if let Some(status) = status {
+            header_lines.push(Line::from(status));
+        }
+        frame.render_widget(Paragraph::new(header_lines), header);
+
+        let [chain, params] =
+            Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)])
+                .areas(body);
+        self.draw_chain(frame, chain, levels);
+        self.draw_params(frame, params);
+
+        let [input, output] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(meters);
+        draw_stereo_meter(frame, input, "In", levels.input);
+        draw_stereo_meter(frame, output, "Out", levels.output);
+
+        frame.render_widget(
+            Paragraph::new(if self.state.dual {
+                "Tab/Shift+Tab slot  ↑↓ param  ←→ adjust (Shift fine)  b bypass  s solo  r rig  p panic  q quit"
+            } else {
+                "Tab/Shift+Tab slot  ↑↓ param  ←→ adjust (Shift fine)  b bypass  s solo  p panic  q quit"
+            })
+            .style(Style::default().fg(Color::DarkGray)),
+            help,
+        );
+    }
+
+    fn draw_chain(&self, frame: &mut Frame, area: Rect, levels: &Levels) {
+        // Room left for the meter after "> name  "
+        let bar_width = usize::from(area.width.saturating_sub(4)).saturating_sub(16);
+        let items: Vec<ListItem> = self
+            .state
+            .slots
+            .iter()
+            .zip(&levels.slots)
+            .enumerate()
+            .map(|(index, (slot, &peak))| {
+                let style = if slot.bypassed {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+                let name = if self.state.solo == Some(index) {
+                    format!("{} (solo)", slot.name)
+                } else if slot.bypassed {
+                    format!("{} (off)", slot.name)
+                } else {
+                    slot.name.clone()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{name:<14.14} "), style),
+                    Span::styled(meter_bar(peak, bar_width), meter_style(peak)),
+                ]))
+            })
+            .collect();
+        let mut list_state = ListState::default().with_selected(Some(self.state.slot));
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::bordered().title(" Chain "))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .highlight_symbol("> "),
+            area,
+            &mut list_state,
+        );
+    }
+
+    fn draw_params(&self, frame: &mut Frame, area: Rect) {
+        let Some(slot) = self.state.slots.get(self.state.slot) else {
+            frame.render_widget(Block::bordered().title(" Parameters "), area);
+            return;
+        };
+        let bar_width = usize::from(area.width.saturating_sub(4)).saturating_sub(34);
+        let items: Vec<ListItem> = slot
+            .params
+            .iter()
+            .map(|p| {
+                let filled =
+                    (p.desc.normalize(p.value).clamp(0.0, 1.0) * bar_width as f32).round() as usize;
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{:<18.18} ", p.desc.name)),
+                    Span::styled(
+                        format!("{:<width$}", "█".repeat(filled), width = bar_width),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(format!(" {:>12.12}", p.desc.format_value(p.value))),
+                ]))
+            })
+            .collect();
+        let mut list_state = ListState::default().with_selected(Some(self.state.param));
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::bordered().title(format!(" {} ", slot.name)))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            area,
+            &mut list_state,
+        );
+    }
+}
+
+/// Meter readings with a simple peak fall-off between frames.
+struct Levels {
+    input: [f32; 2],
+    output: [f32; 2],
+    slots: Vec<f32>,
+}
+
+impl Levels {
+    /// Linear decay per frame (about 20 dB/s at 20 frames/s near full scale).
+    const FALL: f32 = 0.9;
+
+    fn new(slots: usize) -> Self {
+        Self {
+            input: [0.0; 2],
+            output: [0.0; 2],
+            slots: vec![0.0; slots],
+        }
+    }
+
+    fn update(&mut self, meters: &PeakMeters) {
+        let fall = |shown: &mut f32, peak: f32| *shown = peak.max(*shown * Self::FALL);
+        for (shown, peak) in self.input.iter_mut().zip(meters.take_input()) {
+            fall(shown, peak);
+        }
+        for (shown, peak) in self.output.iter_mut().zip(meters.take_output()) {
+            fall(shown, peak);
+        }
+        for (slot, shown) in self.slots.iter_mut().enumerate() {
+            fall(shown, meters.take_slot(slot));
+        }
+    }
+}
+
+/// Position of `peak` on the meter scale, 0–1.
+fn meter_fraction(peak: f32) -> f32 {
+    let db = 20.0 * peak.max(1e-6).log10();
+    ((db - METER_FLOOR_DB) / -METER_FLOOR_DB).clamp(0.0, 1.0)
+}
+
+fn meter_style(peak: f32) -> Style {
+    let color = if peak >= 1.0 {
+        Color::Red
+    } else if peak >= 0.5 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    Style::default().fg(color)
+}
+
+fn meter_bar(peak: f32, width: usize) -> String {
+    let filled = (meter_fraction(peak) * width as f32).round() as usize;
+    format!("{:<width$}", "▮".repeat(filled), width = width)
+}
+
+fn draw_stereo_meter(frame: &mut Frame, area: Rect, label: &str, peaks: [f32; 2]) {
+    let rows = Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).split(area);
+    for ((row, peak), side) in rows.iter().zip(peaks).zip(["L", "R"]) {
+        let db = 20.0 * peak.max(1e-6).log10();
+        let label = if db <= METER_FLOOR_DB {
+            format!("{label} {side}   -inf")
+        } else {
+            format!("{label} {side} {db:6.1}")
+        };
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(meter_style(peak))
+                .ratio(f64::from(meter_fraction(peak)))
+                .label(label),
+            *row,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> TuiState {
+        let registry = EffectRegistry::new();
+        let mut engine = GraphEngine::new_linear(48000.0, 256);
+        for id in ["distortion", "reverb"] {
+            engine.add_effect_named(registry.create(id, 48000.0).unwrap(), id);
+        }
+        TuiState::from_engine(&engine, &registry)
+    }
+
+    #[test]
+    fn selection_wraps_slots_and_clamps_params() {
+        let mut state = state();
+        assert_eq!(state.slots.len(), 2);
+        state.handle(Action::PrevSlot);
+        assert_eq!(state.slot, 1);
+        state.handle(Action::NextSlot);
+        assert_eq!(state.slot, 0);
+
+        state.handle(Action::PrevParam);
+        assert_eq!(state.param, 0);
+        for _ in 0..100 {
+            state.handle(Action::NextParam);
+        }
+        assert_eq!(state.param, state.slots[0].params.len() - 1);
+    }
+
+    #[test]
+    fn continuous_params_step_in_normalized_units() {
+        let mut state = state();
+        let desc = state.slots[0].params[0].desc;
+        assert!(!desc.flags.contains(ParamFlags::STEPPED));
+        let before = desc.normalize(state.slots[0].params[0].value);
+
+        let Some(EngineCommand::SetParam { slot, param, value }) =
+            state.handle(Action::Increase { fine: false })
+        else {
+            panic!("expected a parameter edit");
+        };
+        assert_eq!((slot, param), (0, state.slots[0].params[0].index));
+        assert!((desc.normalize(value) - (before + KEY_STEP)).abs() < 1e-4);
+
+        state.handle(Action::Decrease { fine: true });
+        let after = desc.normalize(state.slots[0].params[0].value);
+        assert!((after - (before + KEY_STEP - KEY_STEP_FINE)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn stepped_params_move_one_step_and_clamp() {
+        let mut state = state();
+        let index = state.slots[0]
+            .params
+            .iter()
+            .position(|p| p.desc.flags.contains(ParamFlags::STEPPED))
+            .expect("distortion has a stepped parameter");
+        state.param = index;
+        let desc = state.slots[0].params[index].desc;
+
+        for _ in 0..100 {
+            state.handle(Action::Decrease { fine: false });
+        }
+        assert_eq!(state.slots[0].params[index].value, desc.min);
+        assert_eq!(state.handle(Action::Decrease { fine: false }), None);
+        state.handle(Action::Increase { fine: true });
+        assert_eq!(
+            state.slots[0].params[index].value,
+            desc.min + desc.step.max(1.0)
+        );
+    }
+
+    #[test]
+    fn bypass_toggles_and_reports() {
+        let mut state = state();
+        state.handle(Action::NextSlot);
+        assert_eq!(
+            state.handle(Action::ToggleBypass),
+            Some(EngineCommand::SetBypass {
+                slot: 1,
+                bypassed: true
+            })
+        );
+        assert!(state.slots[1].bypassed);
+    }
+
+    #[test]
+    fn solo_toggles_on_selected_slot() {
+        let mut state = state();
+        assert_eq!(
+            state.handle(Action::ToggleSolo),
+            Some(EngineCommand::SetSolo { slot: Some(0) })
+        );
+        state.handle(Action::NextSlot);
+        assert_eq!(
+            state.handle(Action::ToggleSolo),
+            Some(EngineCommand::SetSolo { slot: Some(1) })
+        );
+        assert_eq!(
+            state.handle(Action::ToggleSolo),
+            Some(EngineCommand::SetSolo { slot: None })
+        );
+    }
+
+    #[test]
+    fn rig_toggle_only_on_dual_engines() {
+        let mut state = state();
+        assert_eq!(state.handle(Action::ToggleRig), None);
+
+        let registry = EffectRegistry::new();
+        let rig = |id| vec![(registry.create(id, 48000.0).unwrap(), id)];
+        let engine =
+            GraphEngine::dual_from_chains(rig("distortion"), rig("reverb"), 48000.0, 256).unwrap();
+        let mut state = TuiState::from_engine(&engine, &registry);
+        assert!(state.slots[1].name.starts_with("Rig B: "));
+        assert_eq!(
+            state.handle(Action::ToggleRig),
+            Some(EngineCommand::ToggleRig)
+        );
+    }
+
+    #[test]
+    fn meter_scale_spans_floor_to_full_scale() {
+        assert_eq!(meter_fraction(0.0), 0.0);
+        assert!((meter_fraction(1.0) - 1.0).abs() < 1e-6);
+        assert!((meter_fraction(0.031_622_78) - 0.5).abs() < 1e-3);
+    }
+}
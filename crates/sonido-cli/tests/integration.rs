@@ -354,6 +354,40 @@ fn cli_generate_tone() {
     assert_eq!(spec.sample_rate, 48000); // default sample rate
 }
 
+#[test]
+fn cli_generate_pink_noise() {
+    use tempfile::TempDir;
+
+    let dir = TempDir::new().unwrap();
+    let output_path = dir.path().join("pink.wav");
+
+    let output = sonido_bin()
+        .args([
+            "generate",
+            "noise",
+            output_path.to_str().unwrap(),
+            "--color",
+            "pink",
+            "--duration",
+            "0.5",
+            "--amplitude",
+            "0.5",
+        ])
+        .output()
+        .expect("failed to run sonido generate noise");
+
+    assert!(
+        output.status.success(),
+        "sonido generate noise failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let (loaded, _) = sonido_io::read_wav(&output_path).unwrap();
+    assert_eq!(loaded.len(), 24000);
+    let peak = loaded.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+    assert!(peak > 0.1 && peak < 0.75, "pink noise peak {peak}");
+}
+
 // ---------------------------------------------------------------------------
 // Registry-based effect creation tests (library-level, not binary)
 // ---------------------------------------------------------------------------
@@ -364,8 +398,8 @@ fn registry_creates_all_19_effects() {
 
     let registry = EffectRegistry::new();
 
-    // The registry should have 35 effects
-    assert_eq!(registry.len(), 35, "registry should have 35 effects");
+    // The registry should have 42 effects
+    assert_eq!(registry.len(), 42, "registry should have 42 effects");
 
     // Verify we can create each one
     let effect_names = [
@@ -0,0 +1,102 @@
+//! Crash-safe file writes.
+//!
+//! [`write_atomic`] writes to a temporary file beside the target, flushes it
+//! to disk, then renames it over the target. A crash or power loss mid-write
+//! leaves either the old file or the new one in place, never a truncated mix.
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::ConfigError;
+
+/// Write `contents` to `path` atomically, creating parent directories as
+/// needed.
+///
+/// The data goes to a hidden `.<name>.tmp` file in the same directory (so the
+/// rename never crosses filesystems), is synced to disk, and then replaces
+/// `path` in a single rename. On failure the temporary file is removed and
+/// any existing file at `path` is left untouched.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::CreateDir`] if the parent directory cannot be
+/// created, or [`ConfigError::WriteFile`] if writing, syncing, or renaming
+/// fails.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// sonido_config::write_atomic("session.json", b"{}").unwrap();
+/// ```
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<(), ConfigError> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+        && !parent.exists()
+    {
+        fs::create_dir_all(parent).map_err(|e| ConfigError::create_dir(parent, e))?;
+    }
+
+    let tmp = temp_path(path);
+    let result = write_synced(&tmp, contents.as_ref()).and_then(|()| fs::rename(&tmp, path));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp);
+        return Err(ConfigError::write_file(path, e));
+    }
+    Ok(())
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// `dir/name` → `dir/.name.tmp`.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn creates_parent_dirs_and_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested/deeper/state.json");
+        write_atomic(&path, "first").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+    }
+
+    #[test]
+    fn replaces_existing_file_without_leftovers() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1, "temporary file left behind");
+    }
+
+    #[test]
+    fn failed_write_keeps_target_and_cleans_up() {
+        let dir = TempDir::new().unwrap();
+        // A directory cannot be replaced by a file rename.
+        let path = dir.path().join("occupied");
+        fs::create_dir(&path).unwrap();
+        fs::write(path.join("inner"), "keep").unwrap();
+
+        let err = write_atomic(&path, "data").unwrap_err();
+        assert!(matches!(err, ConfigError::WriteFile { .. }));
+        assert_eq!(fs::read_to_string(path.join("inner")).unwrap(), "keep");
+        assert!(!temp_path(&path).exists());
+    }
+}
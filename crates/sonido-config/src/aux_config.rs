@@ -0,0 +1,88 @@
+//! Send/return aux bus configuration types.
+
+use serde::{Deserialize, Serialize};
+
+use crate::effect_config::EffectConfig;
+
+/// A send from an effect's output into a named aux bus.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [[effects.sends]]
+/// aux = "verb"
+/// level_db = -12.0
+/// pre_fader = false
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SendConfig {
+    /// Name of the [`AuxConfig`] bus this send feeds.
+    pub aux: String,
+
+    /// Send level in dB.
+    #[serde(default)]
+    pub level_db: f32,
+
+    /// Tap the signal before the effect's fader instead of after it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pre_fader: bool,
+}
+
+impl SendConfig {
+    /// Create a post-fader send into `aux` at `level_db`.
+    pub fn new(aux: impl Into<String>, level_db: f32) -> Self {
+        Self {
+            aux: aux.into(),
+            level_db,
+            pre_fader: false,
+        }
+    }
+
+    /// Tap the send before the fader.
+    pub fn pre_fader(mut self) -> Self {
+        self.pre_fader = true;
+        self
+    }
+}
+
+/// A named aux bus and the return chain it feeds.
+///
+/// Sends on the main chain sum into the bus; the bus runs through
+/// `effects` in order, and the return is mixed back in after the main chain.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [[aux]]
+/// name = "verb"
+///
+/// [[aux.effects]]
+/// type = "reverb"
+/// [aux.effects.params]
+/// mix = "100%"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuxConfig {
+    /// Bus name referenced by [`SendConfig::aux`].
+    pub name: String,
+
+    /// Return chain, in processing order.
+    #[serde(default)]
+    pub effects: Vec<EffectConfig>,
+}
+
+impl AuxConfig {
+    /// Create an aux bus with an empty return chain.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            effects: Vec::new(),
+        }
+    }
+
+    /// Add an effect to the return chain.
+    pub fn with_effect(mut self, effect: EffectConfig) -> Self {
+        self.effects.push(effect);
+        self
+    }
+}
@@ -3,6 +3,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::aux_config::SendConfig;
+use crate::slot_color::SlotColor;
+
 /// Configuration for a single effect in a preset.
 ///
 /// Each effect has a type identifier and optional parameters. Effects can be
@@ -35,6 +38,19 @@ pub struct EffectConfig {
     /// Values are strings to support various formats (numbers, percentages, etc.)
     #[serde(default)]
     pub params: HashMap<String, String>,
+
+    /// Sends from this effect's output into aux buses.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sends: Vec<SendConfig>,
+
+    /// User name for the slot (`"Lead Boost"`), shown instead of the
+    /// effect's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Color label for the slot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<SlotColor>,
 }
 
 impl EffectConfig {
@@ -53,6 +69,9 @@ impl EffectConfig {
             effect_type,
             bypassed,
             params: HashMap::new(),
+            sends: Vec::new(),
+            label: None,
+            color: None,
         }
     }
 
@@ -62,6 +81,9 @@ impl EffectConfig {
             effect_type: effect_type.into(),
             bypassed: true,
             params: HashMap::new(),
+            sends: Vec::new(),
+            label: None,
+            color: None,
         }
     }
 
@@ -71,6 +93,25 @@ impl EffectConfig {
         self
     }
 
+    /// Add a send from this effect into an aux bus.
+    pub fn with_send(mut self, send: SendConfig) -> Self {
+        self.sends.push(send);
+        self
+    }
+
+    /// Name the slot. Empty or blank labels are dropped.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        let label = label.into();
+        self.label = (!label.trim().is_empty()).then_some(label);
+        self
+    }
+
+    /// Assign a color label to the slot.
+    pub fn with_color(mut self, color: SlotColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
     /// Set whether the effect is bypassed.
     pub fn with_bypass(mut self, bypassed: bool) -> Self {
         self.bypassed = bypassed;
@@ -301,5 +342,20 @@ mod tests {
         assert_eq!(parsed.effect_type, "distortion");
         assert!(parsed.bypassed);
         assert_eq!(parsed.get_param("drive"), Some("0.7"));
+        assert!(!toml_str.contains("label"), "unset label is omitted");
+    }
+
+    #[test]
+    fn test_label_and_color() {
+        let config = EffectConfig::new("distortion")
+            .with_label("Lead Boost")
+            .with_color(SlotColor::Red);
+        let toml_str = toml::to_string(&config).unwrap();
+        assert!(toml_str.contains("color = \"red\""));
+        let parsed: EffectConfig = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.label.as_deref(), Some("Lead Boost"));
+        assert_eq!(parsed.color, Some(SlotColor::Red));
+
+        assert_eq!(EffectConfig::new("distortion").with_label("  ").label, None);
     }
 }
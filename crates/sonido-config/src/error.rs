@@ -34,6 +34,14 @@ pub enum ConfigError {
     #[error("failed to serialize TOML: {0}")]
     TomlSerialize(#[from] toml::ser::Error),
 
+    /// Failed to parse or serialize JSON
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Malformed plugin state
+    #[error("invalid plugin state: {0}")]
+    InvalidState(String),
+
     /// Preset not found
     #[error("preset not found: {0}")]
     PresetNotFound(String),
@@ -57,6 +65,10 @@ pub enum ConfigError {
     #[error("validation failed: {0}")]
     Validation(#[from] crate::validation::ValidationError),
 
+    /// Invalid pedalboard mapping file
+    #[error("invalid pedalboard mapping: {0}")]
+    InvalidPedalboard(String),
+
     /// Failed to create directory
     #[error("failed to create directory '{path}': {source}")]
     CreateDir {
@@ -49,7 +49,7 @@ type = "distortion"
 bypassed = true
 [effects.params]
 drive = "15"
-tone = "4000"
+tone = "0"
 level = "-6"
 
 [[effects]]
@@ -104,7 +104,7 @@ gain = "3"
 type = "distortion"
 [effects.params]
 drive = "12"
-tone = "5000"
+tone = "-2"
 level = "-3"
 
 [[effects]]
@@ -133,7 +133,7 @@ gain = "6"
 type = "distortion"
 [effects.params]
 drive = "30"
-tone = "4500"
+tone = "-3"
 level = "-6"
 
 [[effects]]
@@ -215,7 +215,7 @@ gain = "2"
 [[effects]]
 type = "tape"
 [effects.params]
-drive = "60"
+drive = "14"
 saturation = "70"
 
 [[effects]]
@@ -329,7 +329,7 @@ gain = "3"
 type = "distortion"
 [effects.params]
 drive = "8"
-tone = "6000"
+tone = "0"
 level = "-2"
 
 [[effects]]
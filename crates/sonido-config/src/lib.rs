@@ -7,8 +7,13 @@
 //!
 //! - **Preset System**: Load and save effect presets from TOML files
 //! - **Effect Chains**: Build chains of effects with parameter configuration
+//! - **Aux Buses**: Effects can send into named aux buses with their own return chains
+//! - **Macros**: Presets can carry macro knobs that each drive several parameters
+//! - **Pedalboards**: Mapping files binding GPIO/I2C hardware controls to parameters
 //! - **Validation**: Validate effect types and parameter ranges
 //! - **Paths**: Platform-specific preset and config directories
+//! - **Plugin State**: JSON state blob saved by the CLAP adapter
+//! - **Atomic Writes**: Crash-safe write-temp-then-rename file saves
 //! - **Factory Presets**: Built-in presets for common use cases
 //!
 //! # Example
@@ -35,10 +40,16 @@
 //! preset.save(&path).unwrap();
 //! ```
 
+mod atomic_write;
+mod aux_config;
 mod chain;
 mod effect_config;
 mod error;
+mod macro_config;
+mod pedalboard_config;
+mod plugin_state;
 mod preset;
+mod slot_color;
 
 /// Platform-specific paths for presets and configuration.
 pub mod paths;
@@ -49,6 +60,8 @@ pub mod validation;
 /// Factory presets bundled with the library.
 pub mod factory_presets;
 
+pub use atomic_write::write_atomic;
+pub use aux_config::{AuxConfig, SendConfig};
 pub use chain::EffectChain;
 pub use effect_config::{EffectConfig, parse_param_value};
 pub use error::ConfigError;
@@ -56,12 +69,21 @@ pub use factory_presets::{
     FACTORY_PRESET_NAMES, factory_preset_names, factory_presets, get_factory_preset,
     is_factory_preset,
 };
+pub use macro_config::{CurveConfig, MacroConfig, MacroTarget};
 pub use paths::{
     ensure_user_config_dir, ensure_user_presets_dir, find_preset, list_all_presets,
-    list_system_presets, list_user_presets, preset_name_from_path, system_presets_dir,
-    user_config_dir, user_presets_dir,
+    list_system_presets, list_user_effect_presets, list_user_presets, preset_name_from_path,
+    system_presets_dir, user_config_dir, user_effect_presets_dir, user_presets_dir,
+};
+pub use pedalboard_config::{
+    AdcConfig, ControlBinding, HardwareControl, LedMode, MAX_PEDALBOARD_CONTROLS, PedalboardConfig,
+    PickupConfig,
+};
+pub use plugin_state::{
+    BYPASS_KEY_PREFIX, COLOR_KEY_PREFIX, LABEL_KEY_PREFIX, PluginState, UI_ZOOM_KEY,
 };
 pub use preset::{PRESET_VERSION, Preset, migrate_state, topology_byte};
+pub use slot_color::SlotColor;
 pub use validation::{
     EffectValidator, ParamValidationInfo, ValidationError, ValidationResult, validate_effect,
     validate_effect_config, validate_effect_param, validate_preset,
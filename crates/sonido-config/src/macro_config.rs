@@ -0,0 +1,119 @@
+//! Macro control configuration types.
+
+use serde::{Deserialize, Serialize};
+
+/// A macro control stored in a preset: one knob driving several parameters.
+///
+/// Targets refer to effects by position in the preset's effect list and to
+/// parameters by their preset key, so a macro travels with the chain it was
+/// saved with.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [[macros]]
+/// name = "Intensity"
+/// value = 0.4
+///
+/// [[macros.targets]]
+/// slot = 0
+/// param = "drive"
+/// min = 0.2
+/// max = 0.9
+/// curve = "log"
+///
+/// [[macros.targets]]
+/// slot = 1
+/// param = "mix"
+/// invert = true
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MacroConfig {
+    /// Knob label.
+    pub name: String,
+
+    /// Knob position (0.0–1.0).
+    #[serde(default)]
+    pub value: f32,
+
+    /// Parameters the knob drives.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<MacroTarget>,
+}
+
+impl MacroConfig {
+    /// Create a macro with no targets at position 0.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: 0.0,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Add a target to the macro.
+    pub fn with_target(mut self, target: MacroTarget) -> Self {
+        self.targets.push(target);
+        self
+    }
+}
+
+/// One parameter driven by a macro.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MacroTarget {
+    /// Index of the effect in the preset's effect list.
+    pub slot: usize,
+
+    /// Parameter key, as in the effect's `params` table.
+    pub param: String,
+
+    /// Normalized parameter position with the knob at 0.
+    #[serde(default)]
+    pub min: f32,
+
+    /// Normalized parameter position with the knob at 1.
+    #[serde(default = "default_max")]
+    pub max: f32,
+
+    /// Response curve of the knob's travel.
+    #[serde(default)]
+    pub curve: CurveConfig,
+
+    /// Run the knob's travel backwards for this target.
+    #[serde(default)]
+    pub invert: bool,
+}
+
+impl MacroTarget {
+    /// Create a target sweeping the whole range of `param` linearly.
+    pub fn new(slot: usize, param: impl Into<String>) -> Self {
+        Self {
+            slot,
+            param: param.into(),
+            min: 0.0,
+            max: 1.0,
+            curve: CurveConfig::Linear,
+            invert: false,
+        }
+    }
+}
+
+/// Stored form of a control response curve.
+///
+/// Serialized as `"linear"`, `"log"`, or `{ custom = [[x, y], ...] }` with
+/// breakpoints in 0.0–1.0.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveConfig {
+    /// Output follows input.
+    #[default]
+    Linear,
+    /// Audio (logarithmic) taper.
+    Log,
+    /// Piecewise-linear curve through `[input, output]` points.
+    Custom(Vec<[f32; 2]>),
+}
+
+fn default_max() -> f32 {
+    1.0
+}
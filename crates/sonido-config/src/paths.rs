@@ -6,6 +6,7 @@
 //! # Directory Structure
 //!
 //! - **User presets**: `~/.config/sonido/presets/` (Linux), `~/Library/Application Support/sonido/presets/` (macOS), `%APPDATA%\sonido\presets\` (Windows)
+//! - **Single-effect presets**: `<user presets>/effects/<effect type>/`
 //! - **User config**: `~/.config/sonido/` (Linux), `~/Library/Application Support/sonido/` (macOS), `%APPDATA%\sonido\` (Windows)
 //! - **System presets**: `/usr/share/sonido/presets/` (Linux), `/Library/Application Support/sonido/presets/` (macOS)
 //!
@@ -32,6 +33,9 @@ const APP_NAME: &str = "sonido";
 /// Subdirectory name for presets.
 const PRESETS_SUBDIR: &str = "presets";
 
+/// Subdirectory of the user presets directory for single-effect presets.
+const EFFECT_PRESETS_SUBDIR: &str = "effects";
+
 /// Returns the user-specific presets directory.
 ///
 /// # Platform Paths
@@ -48,6 +52,18 @@ pub fn user_presets_dir() -> PathBuf {
         .join(PRESETS_SUBDIR)
 }
 
+/// Returns the directory for single-effect presets of one effect type.
+///
+/// A single-effect preset stores the parameters of one effect slot (e.g. a
+/// favorite compressor setting). Each effect type has its own namespace,
+/// `<user presets>/effects/<effect_type>/`, so these files never appear
+/// among whole-chain presets.
+pub fn user_effect_presets_dir(effect_type: &str) -> PathBuf {
+    user_presets_dir()
+        .join(EFFECT_PRESETS_SUBDIR)
+        .join(effect_type)
+}
+
 /// Returns the user-specific configuration directory.
 ///
 /// # Platform Paths
@@ -199,6 +215,13 @@ pub fn list_user_presets() -> Vec<PathBuf> {
     list_presets_in_dir(&user_presets_dir())
 }
 
+/// List the single-effect presets saved for `effect_type`.
+///
+/// Returns an empty vector if the directory doesn't exist or can't be read.
+pub fn list_user_effect_presets(effect_type: &str) -> Vec<PathBuf> {
+    list_presets_in_dir(&user_effect_presets_dir(effect_type))
+}
+
 /// List all preset files in the system presets directory.
 ///
 /// Returns an empty vector if the directory doesn't exist or can't be read.
@@ -265,6 +288,13 @@ mod tests {
         assert!(dir_str.contains("sonido") || dir_str.contains("presets"));
     }
 
+    #[test]
+    fn test_user_effect_presets_dir_is_namespaced() {
+        let dir = user_effect_presets_dir("compressor");
+        assert!(dir.starts_with(user_presets_dir()));
+        assert!(dir.ends_with("effects/compressor"));
+    }
+
     #[test]
     fn test_user_config_dir() {
         let dir = user_config_dir();
@@ -0,0 +1,504 @@
+//! Hardware pedalboard mapping files.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::error::ConfigError;
+use crate::macro_config::CurveConfig;
+
+/// Maximum number of hardware controls (control IDs are 8-bit).
+pub const MAX_PEDALBOARD_CONTROLS: usize = 256;
+
+/// Number of inputs on the ADS1115 ADC.
+const ADC_CHANNELS: u8 = 4;
+
+/// A hardware pedalboard: the controls wired to the board and the
+/// parameters they drive.
+///
+/// Controls are numbered by their position in `controls`, starting at 0;
+/// bindings refer to them by that index and to effects by their position in
+/// the running chain. A binding without `param` targets the slot's bypass:
+/// a footswitch engages the effect while its value is high, and an LED
+/// lights while the effect is engaged. An LED bound to a parameter lights
+/// above the parameter's midpoint, or with `led = "tempo"` blinks at the
+/// parameter's time or rate (tap tempo on a delay or LFO); `led = "clip"`
+/// on a slot binding lights it briefly whenever the slot's output clips.
+///
+/// # TOML Format
+///
+/// ```toml
+/// name = "Pi pedal"
+///
+/// # ADS1115 on /dev/i2c-1 for the knobs
+/// [adc]
+/// bus = 1
+/// address = 0x48
+/// vref = 3.3
+///
+/// [[controls]]          # control 0
+/// type = "encoder"
+/// pins = [17, 27]
+/// step = 0.02
+///
+/// [[controls]]          # control 1
+/// type = "knob"
+/// channel = 0
+/// pickup = "pickup"     # after a preset change, wait for the knob to pass
+///
+/// [[controls]]          # control 2
+/// type = "footswitch"
+/// pin = 22
+///
+/// [[controls]]          # control 3
+/// type = "led"
+/// pin = 23
+///
+/// [[bindings]]
+/// control = 0
+/// slot = 0
+/// param = "drive"
+///
+/// [[bindings]]
+/// control = 1
+/// slot = 1
+/// param = "mix"
+/// max = 0.6
+/// curve = "log"
+///
+/// [[bindings]]          # footswitch bypasses slot 0...
+/// control = 2
+/// slot = 0
+///
+/// [[bindings]]          # ...and the LED shows it
+/// control = 3
+/// slot = 0
+///
+/// [[controls]]          # control 4
+/// type = "led"
+/// pin = 24
+///
+/// [[bindings]]          # blinks at slot 1's delay time
+/// control = 4
+/// slot = 1
+/// param = "time"
+/// led = "tempo"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PedalboardConfig {
+    /// Board label.
+    #[serde(default)]
+    pub name: String,
+
+    /// I2C ADC for potentiometers; required if there are knobs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adc: Option<AdcConfig>,
+
+    /// Hardware controls, numbered by position.
+    #[serde(default)]
+    pub controls: Vec<HardwareControl>,
+
+    /// Control → parameter bindings.
+    #[serde(default)]
+    pub bindings: Vec<ControlBinding>,
+}
+
+impl PedalboardConfig {
+    /// Load and validate a mapping file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| ConfigError::read_file(path, e))?;
+        Self::from_toml(&content)
+    }
+
+    /// Parse and validate a mapping from a TOML string.
+    pub fn from_toml(toml_str: &str) -> Result<Self, ConfigError> {
+        let config: Self = toml::from_str(toml_str)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Serialize to a TOML string.
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Check control counts, ADC channels, encoder steps, binding indices,
+    /// and LED modes.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |reason: String| Err(ConfigError::InvalidPedalboard(reason));
+        if self.controls.len() > MAX_PEDALBOARD_CONTROLS {
+            return invalid(format!(
+                "{} controls, at most {MAX_PEDALBOARD_CONTROLS} supported",
+                self.controls.len()
+            ));
+        }
+        for (index, control) in self.controls.iter().enumerate() {
+            match *control {
+                HardwareControl::Knob { channel, .. } => {
+                    if self.adc.is_none() {
+                        return invalid(format!("control {index} is a knob but no [adc] is set"));
+                    }
+                    if channel >= ADC_CHANNELS {
+                        return invalid(format!(
+                            "control {index}: ADC channel {channel} out of range 0-{}",
+                            ADC_CHANNELS - 1
+                        ));
+                    }
+                }
+                HardwareControl::Encoder { step, .. } if !(step > 0.0 && step <= 1.0) => {
+                    return invalid(format!("control {index}: encoder step {step} outside 0-1"));
+                }
+                _ => {}
+            }
+        }
+        for binding in &self.bindings {
+            let Some(control) = self.controls.get(binding.control) else {
+                return invalid(format!(
+                    "binding to control {}, but only {} controls are defined",
+                    binding.control,
+                    self.controls.len()
+                ));
+            };
+            let index = binding.control;
+            match binding.led {
+                LedMode::State => {}
+                _ if !matches!(control, HardwareControl::Led { .. }) => {
+                    return invalid(format!("control {index} has an LED mode but is not an LED"));
+                }
+                LedMode::Tempo if binding.param.is_none() => {
+                    return invalid(format!("control {index}: tempo LED needs a param"));
+                }
+                LedMode::Clip if binding.param.is_some() => {
+                    return invalid(format!("control {index}: clip LED takes no param"));
+                }
+                LedMode::Tempo | LedMode::Clip => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// ADS1115 analog-to-digital converter on an I2C bus.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AdcConfig {
+    /// I2C bus number (`/dev/i2c-<bus>`).
+    #[serde(default = "default_bus")]
+    pub bus: u8,
+
+    /// 7-bit device address.
+    #[serde(default = "default_address")]
+    pub address: u16,
+
+    /// Voltage across the potentiometers; a knob at full travel reads this.
+    #[serde(default = "default_vref")]
+    pub vref: f32,
+}
+
+impl Default for AdcConfig {
+    fn default() -> Self {
+        Self {
+            bus: default_bus(),
+            address: default_address(),
+            vref: default_vref(),
+        }
+    }
+}
+
+/// One physical control on the board.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HardwareControl {
+    /// Detented rotary encoder on two GPIO pins (BCM numbering).
+    Encoder {
+        /// A and B pins; A leads B when turning clockwise.
+        pins: [u8; 2],
+        /// Normalized movement per detent.
+        #[serde(default = "default_step")]
+        step: f32,
+    },
+    /// Potentiometer on an ADC input (0–3).
+    Knob {
+        /// ADC channel.
+        channel: u8,
+        /// How the knob takes over a parameter set by something else.
+        #[serde(default)]
+        pickup: PickupConfig,
+    },
+    /// Footswitch on a GPIO pin, closing to ground.
+    Footswitch {
+        /// GPIO pin (BCM numbering).
+        pin: u8,
+        /// Follow the switch instead of toggling on each press.
+        #[serde(default)]
+        momentary: bool,
+    },
+    /// LED on a GPIO pin, lit when high.
+    Led {
+        /// GPIO pin (BCM numbering).
+        pin: u8,
+    },
+}
+
+/// How a knob takes over a parameter that changed elsewhere (the loaded
+/// preset, another control, the TUI).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PickupConfig {
+    /// The parameter jumps to the knob on the next move.
+    #[default]
+    Jump,
+    /// Nothing changes until the knob passes the parameter's value.
+    Pickup,
+    /// The knob moves the parameter by as much as it turns.
+    Relative,
+}
+
+/// A control driving one parameter (or a slot's bypass).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ControlBinding {
+    /// Index into [`PedalboardConfig::controls`].
+    pub control: usize,
+
+    /// Position of the effect in the running chain.
+    pub slot: usize,
+
+    /// Parameter key, as in a preset's `params` table; `None` binds the
+    /// slot's bypass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+
+    /// Normalized parameter position with the control at 0.
+    #[serde(default)]
+    pub min: f32,
+
+    /// Normalized parameter position with the control at 1.
+    #[serde(default = "default_max")]
+    pub max: f32,
+
+    /// Response curve of the control's travel.
+    #[serde(default)]
+    pub curve: CurveConfig,
+
+    /// Run the control's travel backwards.
+    #[serde(default)]
+    pub invert: bool,
+
+    /// What an LED shows; other controls must leave this at `state`.
+    #[serde(default)]
+    pub led: LedMode,
+}
+
+/// What an LED binding shows.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LedMode {
+    /// The slot's bypass (lit when engaged), or the parameter (lit above
+    /// its midpoint).
+    #[default]
+    State,
+    /// Blink at the parameter's period: a time in milliseconds or a rate
+    /// in hertz.
+    Tempo,
+    /// Light briefly whenever the slot's output reaches full scale.
+    Clip,
+}
+
+impl ControlBinding {
+    /// Bind `control` to the whole range of `param` on `slot`.
+    pub fn param(control: usize, slot: usize, param: impl Into<String>) -> Self {
+        Self {
+            param: Some(param.into()),
+            ..Self::bypass(control, slot)
+        }
+    }
+
+    /// Bind `control` to the bypass of `slot`.
+    pub fn bypass(control: usize, slot: usize) -> Self {
+        Self {
+            control,
+            slot,
+            param: None,
+            min: 0.0,
+            max: 1.0,
+            curve: CurveConfig::Linear,
+            invert: false,
+            led: LedMode::State,
+        }
+    }
+}
+
+fn default_bus() -> u8 {
+    1
+}
+
+fn default_address() -> u16 {
+    0x48
+}
+
+fn default_vref() -> f32 {
+    3.3
+}
+
+fn default_step() -> f32 {
+    0.02
+}
+
+fn default_max() -> f32 {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+        name = "Pi pedal"
+
+        [adc]
+        address = 0x49
+
+        [[controls]]
+        type = "encoder"
+        pins = [17, 27]
+
+        [[controls]]
+        type = "knob"
+        channel = 2
+        pickup = "relative"
+
+        [[controls]]
+        type = "footswitch"
+        pin = 22
+        momentary = true
+
+        [[controls]]
+        type = "led"
+        pin = 23
+
+        [[bindings]]
+        control = 1
+        slot = 1
+        param = "mix"
+        max = 0.6
+        curve = "log"
+
+        [[bindings]]
+        control = 2
+        slot = 0
+
+        [[bindings]]
+        control = 3
+        slot = 0
+        param = "time"
+        led = "tempo"
+    "#;
+
+    #[test]
+    fn parses_controls_and_bindings() {
+        let config = PedalboardConfig::from_toml(EXAMPLE).unwrap();
+        assert_eq!(config.name, "Pi pedal");
+        let adc = config.adc.as_ref().unwrap();
+        assert_eq!((adc.bus, adc.address, adc.vref), (1, 0x49, 3.3));
+        assert_eq!(
+            config.controls,
+            [
+                HardwareControl::Encoder {
+                    pins: [17, 27],
+                    step: 0.02
+                },
+                HardwareControl::Knob {
+                    channel: 2,
+                    pickup: PickupConfig::Relative
+                },
+                HardwareControl::Footswitch {
+                    pin: 22,
+                    momentary: true
+                },
+                HardwareControl::Led { pin: 23 },
+            ]
+        );
+        assert_eq!(
+            config.bindings[0],
+            ControlBinding {
+                max: 0.6,
+                curve: CurveConfig::Log,
+                ..ControlBinding::param(1, 1, "mix")
+            }
+        );
+        assert_eq!(config.bindings[1], ControlBinding::bypass(2, 0));
+        assert_eq!(
+            config.bindings[2],
+            ControlBinding {
+                led: LedMode::Tempo,
+                ..ControlBinding::param(3, 0, "time")
+            }
+        );
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let config = PedalboardConfig::from_toml(EXAMPLE).unwrap();
+        let text = config.to_toml().unwrap();
+        assert_eq!(PedalboardConfig::from_toml(&text).unwrap(), config);
+    }
+
+    #[test]
+    fn rejects_invalid_mappings() {
+        let knob_without_adc = r#"
+            [[controls]]
+            type = "knob"
+            channel = 0
+        "#;
+        let bad_channel = r#"
+            [adc]
+            [[controls]]
+            type = "knob"
+            channel = 4
+        "#;
+        let bad_binding = r#"
+            [[controls]]
+            type = "led"
+            pin = 4
+            [[bindings]]
+            control = 1
+            slot = 0
+        "#;
+        let bad_step = r#"
+            [[controls]]
+            type = "encoder"
+            pins = [5, 6]
+            step = 0.0
+        "#;
+        let tempo_without_param = r#"
+            [[controls]]
+            type = "led"
+            pin = 4
+            [[bindings]]
+            control = 0
+            slot = 0
+            led = "tempo"
+        "#;
+        let clip_on_footswitch = r#"
+            [[controls]]
+            type = "footswitch"
+            pin = 4
+            [[bindings]]
+            control = 0
+            slot = 0
+            led = "clip"
+        "#;
+        for toml in [
+            knob_without_adc,
+            bad_channel,
+            bad_binding,
+            bad_step,
+            tempo_without_param,
+            clip_on_footswitch,
+        ] {
+            assert!(matches!(
+                PedalboardConfig::from_toml(toml),
+                Err(ConfigError::InvalidPedalboard(_))
+            ));
+        }
+    }
+}
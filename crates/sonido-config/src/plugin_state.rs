@@ -0,0 +1,167 @@
+//! Plugin state blob saved by the CLAP adapter.
+//!
+//! A JSON object mapping stable `ParamId`s to values, plus onboard slot
+//! bypass flags, names and colors, and the editor zoom:
+//!
+//! ```json
+//! {"200": 12.0, "201": 0.5, "bypass_1": false, "label_1": "Lead Boost",
+//!  "color_1": "teal", "ui_zoom": 1.25}
+//! ```
+//!
+//! Keys are stable IDs rather than indices, so state survives parameter
+//! reordering across plugin versions. Unknown keys and values of the wrong
+//! type are skipped, so state from older or newer versions still loads; only
+//! input that is not a JSON object is rejected.
+
+use crate::error::ConfigError;
+use crate::slot_color::SlotColor;
+
+/// Key prefix for onboard effect slot bypass flags (`"bypass_<slot>"`).
+pub const BYPASS_KEY_PREFIX: &str = "bypass_";
+
+/// Key prefix for onboard effect slot names (`"label_<slot>"`).
+pub const LABEL_KEY_PREFIX: &str = "label_";
+
+/// Key prefix for onboard effect slot colors (`"color_<slot>"`).
+pub const COLOR_KEY_PREFIX: &str = "color_";
+
+/// Key for the editor zoom factor.
+pub const UI_ZOOM_KEY: &str = "ui_zoom";
+
+/// Decoded plugin state.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginState {
+    /// Parameter values by stable `ParamId`.
+    pub params: Vec<(u32, f32)>,
+    /// Bypass flags by onboard effect slot.
+    pub bypassed: Vec<(usize, bool)>,
+    /// User names by onboard effect slot.
+    pub labels: Vec<(usize, String)>,
+    /// Color labels by onboard effect slot.
+    pub colors: Vec<(usize, SlotColor)>,
+    /// Editor zoom factor, if saved.
+    pub ui_zoom: Option<f32>,
+}
+
+impl PluginState {
+    /// Encode as a JSON object.
+    ///
+    /// Non-finite values have no JSON representation and are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Json`] if serialization fails.
+    pub fn to_json(&self) -> Result<Vec<u8>, ConfigError> {
+        let number = |v: f32| serde_json::Number::from_f64(f64::from(v)).map(Into::into);
+        let mut obj = serde_json::Map::new();
+        for &(id, value) in &self.params {
+            if let Some(value) = number(value) {
+                obj.insert(id.to_string(), value);
+            }
+        }
+        for &(slot, bypassed) in &self.bypassed {
+            obj.insert(format!("{BYPASS_KEY_PREFIX}{slot}"), bypassed.into());
+        }
+        for (slot, label) in &self.labels {
+            obj.insert(format!("{LABEL_KEY_PREFIX}{slot}"), label.as_str().into());
+        }
+        for &(slot, color) in &self.colors {
+            obj.insert(format!("{COLOR_KEY_PREFIX}{slot}"), color.name().into());
+        }
+        if let Some(zoom) = self.ui_zoom.and_then(number) {
+            obj.insert(UI_ZOOM_KEY.to_owned(), zoom);
+        }
+        Ok(serde_json::to_vec(&serde_json::Value::Object(obj))?)
+    }
+
+    /// Decode a JSON state blob.
+    ///
+    /// Values that are not finite as `f32` are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Json`] for malformed JSON and
+    /// [`ConfigError::InvalidState`] if the top level is not an object.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, ConfigError> {
+        let value: serde_json::Value = serde_json::from_slice(bytes)?;
+        let serde_json::Value::Object(obj) = value else {
+            return Err(ConfigError::InvalidState(
+                "expected a JSON object".to_owned(),
+            ));
+        };
+        let finite = |v: &serde_json::Value| v.as_f64().map(|v| v as f32).filter(|v| v.is_finite());
+
+        let mut state = Self::default();
+        for (key, value) in &obj {
+            if key == UI_ZOOM_KEY {
+                state.ui_zoom = finite(value);
+            } else if let Some(slot) = key.strip_prefix(BYPASS_KEY_PREFIX) {
+                if let (Ok(slot), Some(bypassed)) = (slot.parse(), value.as_bool()) {
+                    state.bypassed.push((slot, bypassed));
+                }
+            } else if let Some(slot) = key.strip_prefix(LABEL_KEY_PREFIX) {
+                if let (Ok(slot), Some(label)) = (slot.parse(), value.as_str()) {
+                    state.labels.push((slot, label.to_owned()));
+                }
+            } else if let Some(slot) = key.strip_prefix(COLOR_KEY_PREFIX) {
+                let color = value.as_str().and_then(SlotColor::from_name);
+                if let (Ok(slot), Some(color)) = (slot.parse(), color) {
+                    state.colors.push((slot, color));
+                }
+            } else if let (Ok(id), Some(value)) = (key.parse(), finite(value)) {
+                state.params.push((id, value));
+            }
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let state = PluginState {
+            params: vec![(200, 12.0), (201, 0.5)],
+            bypassed: vec![(1, true), (2, false)],
+            labels: vec![(1, "Lead Boost".to_owned())],
+            colors: vec![(2, SlotColor::Teal)],
+            ui_zoom: Some(1.25),
+        };
+        let decoded = PluginState::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(decoded, state);
+    }
+
+    #[test]
+    fn skips_unknown_and_mistyped_entries() {
+        let json = br#"{"200": 1.5, "201": "loud", "bypass_x": true, "bypass_1": 3,
+                        "label_1": 4, "color_1": "mauve",
+                        "future_key": [1, 2], "ui_zoom": null}"#;
+        let state = PluginState::from_json(json).unwrap();
+        assert_eq!(state.params, vec![(200, 1.5)]);
+        assert!(state.bypassed.is_empty());
+        assert!(state.labels.is_empty());
+        assert!(state.colors.is_empty());
+        assert_eq!(state.ui_zoom, None);
+    }
+
+    #[test]
+    fn drops_values_that_overflow_f32() {
+        let state = PluginState::from_json(br#"{"200": 1e300, "ui_zoom": -1e39}"#).unwrap();
+        assert_eq!(state, PluginState::default());
+    }
+
+    #[test]
+    fn malformed_input_is_an_error() {
+        assert!(matches!(
+            PluginState::from_json(b"{\"200\": "),
+            Err(ConfigError::Json(_))
+        ));
+        assert!(matches!(
+            PluginState::from_json(b"[1, 2]"),
+            Err(ConfigError::InvalidState(_))
+        ));
+        assert!(PluginState::from_json(&[0xff, 0xfe]).is_err());
+    }
+}
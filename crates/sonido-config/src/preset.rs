@@ -9,8 +9,10 @@
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::aux_config::AuxConfig;
 use crate::effect_config::EffectConfig;
 use crate::error::ConfigError;
+use crate::macro_config::MacroConfig;
 
 /// Current preset format version.
 pub const PRESET_VERSION: &str = "1.0";
@@ -28,6 +30,7 @@ pub const PRESET_VERSION: &str = "1.0";
 /// description = "A warm, vintage tone"
 /// version = "1.0"
 /// sample_rate = 48000
+/// mix = 80.0
 ///
 /// [[effects]]
 /// type = "distortion"
@@ -41,7 +44,16 @@ pub const PRESET_VERSION: &str = "1.0";
 /// [effects.params]
 /// room_size = "0.8"
 /// damping = "0.3"
+///
+/// [[macros]]
+/// name = "Space"
+/// [[macros.targets]]
+/// slot = 1
+/// param = "room_size"
 /// ```
+///
+/// Effects may carry `[[effects.sends]]` into named `[[aux]]` buses, each with
+/// its own return chain; see [`AuxConfig`].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Preset {
     /// Name of the preset.
@@ -67,9 +79,23 @@ pub struct Preset {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub topology: Option<String>,
 
+    /// Global dry/wet mix in percent: `100` (the default) is fully
+    /// processed, `0` dry. The dry path is latency-compensated (see
+    /// `GraphEngine::set_global_mix`).
+    #[serde(default = "default_mix", skip_serializing_if = "is_full_mix")]
+    pub mix: f32,
+
     /// List of effects in the chain.
     #[serde(default)]
     pub effects: Vec<EffectConfig>,
+
+    /// Macro controls, in knob order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macros: Vec<MacroConfig>,
+
+    /// Aux buses fed by effect sends, each with a return chain.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aux: Vec<AuxConfig>,
 }
 
 fn default_version() -> String {
@@ -80,6 +106,15 @@ fn default_sample_rate() -> u32 {
     48000
 }
 
+fn default_mix() -> f32 {
+    100.0
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde's skip_serializing_if signature
+fn is_full_mix(mix: &f32) -> bool {
+    *mix == 100.0
+}
+
 impl Preset {
     /// Create a new empty preset at the current format version.
     pub fn new(name: impl Into<String>) -> Self {
@@ -89,7 +124,10 @@ impl Preset {
             version: PRESET_VERSION.to_string(),
             sample_rate: 48000,
             topology: None,
+            mix: 100.0,
             effects: Vec::new(),
+            macros: Vec::new(),
+            aux: Vec::new(),
         }
     }
 
@@ -111,6 +149,12 @@ impl Preset {
         self
     }
 
+    /// Set the global dry/wet mix in percent.
+    pub fn with_mix(mut self, mix: f32) -> Self {
+        self.mix = mix;
+        self
+    }
+
     /// Add an effect to the preset.
     pub fn with_effect(mut self, effect: EffectConfig) -> Self {
         self.effects.push(effect);
@@ -123,6 +167,18 @@ impl Preset {
         self
     }
 
+    /// Add a macro control to the preset.
+    pub fn with_macro(mut self, macro_config: MacroConfig) -> Self {
+        self.macros.push(macro_config);
+        self
+    }
+
+    /// Add an aux bus to the preset.
+    pub fn with_aux(mut self, aux: AuxConfig) -> Self {
+        self.aux.push(aux);
+        self
+    }
+
     /// Load a preset from a TOML file.
     pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
         let path = path.as_ref();
@@ -137,19 +193,13 @@ impl Preset {
     }
 
     /// Save the preset to a TOML file.
+    ///
+    /// Creates the parent directory if needed. The write is atomic (see
+    /// [`write_atomic`](crate::write_atomic)), so a crash mid-save keeps the
+    /// previous file intact.
     pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
-        let path = path.as_ref();
-
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent()
-            && !parent.exists()
-        {
-            std::fs::create_dir_all(parent).map_err(|e| ConfigError::create_dir(parent, e))?;
-        }
-
         let content = toml::to_string_pretty(self)?;
-        std::fs::write(path, content).map_err(|e| ConfigError::write_file(path, e))?;
-        Ok(())
+        crate::write_atomic(path, content)
     }
 
     /// Convert the preset to a TOML string.
@@ -308,6 +358,86 @@ mod tests {
         assert_eq!(preset.len(), 2);
     }
 
+    #[test]
+    fn test_preset_macros_roundtrip() {
+        use crate::macro_config::{CurveConfig, MacroConfig, MacroTarget};
+
+        let mut drive = MacroTarget::new(0, "drive");
+        drive.min = 0.2;
+        drive.curve = CurveConfig::Log;
+        let mut mix = MacroTarget::new(1, "mix");
+        mix.invert = true;
+        mix.curve = CurveConfig::Custom(vec![[0.0, 0.0], [0.5, 0.8], [1.0, 1.0]]);
+        let mut intensity = MacroConfig::new("Intensity")
+            .with_target(drive)
+            .with_target(mix);
+        intensity.value = 0.4;
+        let preset = Preset::new("Macros")
+            .with_effect(EffectConfig::new("distortion"))
+            .with_effect(EffectConfig::new("reverb"))
+            .with_macro(intensity);
+
+        let restored = Preset::from_toml(&preset.to_toml().unwrap()).unwrap();
+        assert_eq!(restored, preset);
+
+        // Presets without macros don't write the key, and omitted target
+        // fields take their defaults
+        assert!(!Preset::new("Plain").to_toml().unwrap().contains("macros"));
+        let parsed = Preset::from_toml(
+            r#"
+name = "Short"
+[[macros]]
+name = "Space"
+[[macros.targets]]
+slot = 0
+param = "room_size"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.macros,
+            vec![MacroConfig::new("Space").with_target(MacroTarget::new(0, "room_size"))]
+        );
+    }
+
+    #[test]
+    fn test_preset_aux_roundtrip() {
+        use crate::aux_config::{AuxConfig, SendConfig};
+
+        let preset = Preset::new("Wet")
+            .with_effect(
+                EffectConfig::new("distortion")
+                    .with_send(SendConfig::new("verb", -12.0))
+                    .with_send(SendConfig::new("echo", -6.0).pre_fader()),
+            )
+            .with_aux(AuxConfig::new("verb").with_effect(EffectConfig::new("reverb")))
+            .with_aux(AuxConfig::new("echo").with_effect(EffectConfig::new("delay")));
+
+        let toml = preset.to_toml().unwrap();
+        assert!(toml.contains("[[effects.sends]]"));
+        assert!(toml.contains("[[aux]]"));
+        assert_eq!(Preset::from_toml(&toml).unwrap(), preset);
+
+        let plain = Preset::new("Dry").with_effect(EffectConfig::new("reverb"));
+        let toml = plain.to_toml().unwrap();
+        assert!(!toml.contains("sends") && !toml.contains("aux"));
+    }
+
+    #[test]
+    fn test_preset_mix_roundtrip() {
+        let preset = Preset::new("Blend")
+            .with_mix(40.0)
+            .with_effect(EffectConfig::new("reverb"));
+        let toml = preset.to_toml().unwrap();
+        assert!(toml.contains("mix = 40.0"));
+        assert_eq!(Preset::from_toml(&toml).unwrap(), preset);
+
+        // Fully processed is the default and is left out of the file
+        let full = Preset::new("Full").with_effect(EffectConfig::new("reverb"));
+        assert!(!full.to_toml().unwrap().contains("mix"));
+        assert_eq!(Preset::from_toml("name = \"Old\"").unwrap().mix, 100.0);
+    }
+
     #[test]
     fn test_preset_from_toml() {
         let toml = r#"
@@ -0,0 +1,98 @@
+//! Color labels for effect slots.
+//!
+//! A small fixed palette, like the track colors in a DAW: presets and plugin
+//! state store the name (`"teal"`), and each GUI maps it to its own shade
+//! through [`SlotColor::rgb`].
+
+use serde::{Deserialize, Serialize};
+
+/// Color label assigned to an effect slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotColor {
+    /// Red.
+    Red,
+    /// Orange.
+    Orange,
+    /// Yellow.
+    Yellow,
+    /// Green.
+    Green,
+    /// Teal.
+    Teal,
+    /// Blue.
+    Blue,
+    /// Purple.
+    Purple,
+    /// Pink.
+    Pink,
+}
+
+impl SlotColor {
+    /// Every color, in palette order.
+    pub const ALL: [Self; 8] = [
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Green,
+        Self::Teal,
+        Self::Blue,
+        Self::Purple,
+        Self::Pink,
+    ];
+
+    /// Lowercase name used in preset and state files.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Green => "green",
+            Self::Teal => "teal",
+            Self::Blue => "blue",
+            Self::Purple => "purple",
+            Self::Pink => "pink",
+        }
+    }
+
+    /// Parse a name written by [`name`](Self::name), ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Display color as sRGB, chosen to read on a dark background.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Self::Red => [0xE0, 0x4F, 0x4F],
+            Self::Orange => [0xE8, 0x8A, 0x3C],
+            Self::Yellow => [0xE3, 0xC5, 0x3F],
+            Self::Green => [0x5C, 0xBF, 0x60],
+            Self::Teal => [0x3F, 0xB8, 0xAF],
+            Self::Blue => [0x4F, 0x8F, 0xE6],
+            Self::Purple => [0x9B, 0x6B, 0xE0],
+            Self::Pink => [0xE0, 0x6B, 0xB0],
+        }
+    }
+
+    /// Position in [`ALL`](Self::ALL).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        for (i, color) in SlotColor::ALL.into_iter().enumerate() {
+            assert_eq!(color.index(), i);
+            assert_eq!(SlotColor::from_name(color.name()), Some(color));
+        }
+        assert_eq!(SlotColor::from_name(" Teal "), Some(SlotColor::Teal));
+        assert_eq!(SlotColor::from_name("mauve"), None);
+    }
+}
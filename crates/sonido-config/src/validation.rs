@@ -59,6 +59,10 @@ pub enum ValidationError {
         reason: String,
     },
 
+    /// A send names an aux bus the preset does not define.
+    #[error("send targets unknown aux bus '{0}'")]
+    UnknownAuxBus(String),
+
     /// Multiple validation errors.
     #[error("multiple validation errors: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
     Multiple(Vec<ValidationError>),
@@ -350,7 +354,23 @@ pub fn validate_preset(preset: &crate::Preset) -> ValidationResult<()> {
     let mut validator = EffectValidator::new();
     let mut errors = Vec::new();
 
-    for effect_config in &preset.effects {
+    if !(0.0..=100.0).contains(&preset.mix) {
+        errors.push(ValidationError::OutOfRange {
+            param: "mix".to_string(),
+            value: preset.mix,
+            min: 0.0,
+            max: 100.0,
+        });
+    }
+
+    for send in preset.effects.iter().flat_map(|e| &e.sends) {
+        if !preset.aux.iter().any(|aux| aux.name == send.aux) {
+            errors.push(ValidationError::UnknownAuxBus(send.aux.clone()));
+        }
+    }
+
+    let returns = preset.aux.iter().flat_map(|aux| &aux.effects);
+    for effect_config in preset.effects.iter().chain(returns) {
         // Validate effect type
         if let Err(e) = validator.validate_effect(&effect_config.effect_type) {
             errors.push(e);
@@ -562,7 +582,7 @@ mod tests {
         assert!(ids.contains(&"distortion"));
         assert!(ids.contains(&"reverb"));
         assert!(ids.contains(&"compressor"));
-        assert_eq!(ids.len(), 35); // 35 effects registered
+        assert_eq!(ids.len(), 42); // 42 effects registered
     }
 
     #[test]
@@ -576,7 +596,7 @@ mod tests {
         assert_eq!(params[0].max, 40.0);
 
         let params = validator.effect_params("reverb").unwrap();
-        assert_eq!(params.len(), 8);
+        assert_eq!(params.len(), 11);
     }
 
     #[test]
@@ -759,4 +779,42 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_validate_preset_sends() {
+        use crate::{AuxConfig, EffectConfig, Preset, SendConfig};
+
+        let preset = Preset::new("Wet")
+            .with_effect(EffectConfig::new("distortion").with_send(SendConfig::new("verb", -6.0)))
+            .with_aux(AuxConfig::new("verb").with_effect(EffectConfig::new("reverb")));
+        assert!(validate_preset(&preset).is_ok());
+
+        let mut dangling = preset.clone();
+        dangling.aux.clear();
+        assert!(matches!(
+            validate_preset(&dangling),
+            Err(ValidationError::UnknownAuxBus(name)) if name == "verb"
+        ));
+
+        let mut bad_return = preset;
+        bad_return.aux[0]
+            .effects
+            .push(EffectConfig::new("nonexistent"));
+        assert!(matches!(
+            validate_preset(&bad_return),
+            Err(ValidationError::UnknownEffect(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_preset_mix() {
+        use crate::{EffectConfig, Preset};
+
+        let preset = Preset::new("Blend").with_effect(EffectConfig::new("reverb"));
+        assert!(validate_preset(&preset.clone().with_mix(0.0)).is_ok());
+        assert!(matches!(
+            validate_preset(&preset.with_mix(150.0)),
+            Err(ValidationError::OutOfRange { param, .. }) if param == "mix"
+        ));
+    }
 }
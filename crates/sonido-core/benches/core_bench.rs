@@ -5,8 +5,9 @@
 
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 use sonido_core::{
-    AllpassFilter, Biquad, CombFilter, DcBlocker, Effect, EnvelopeFollower, InterpolatedDelay, Lfo,
-    LfoWaveform, OnePole, SmoothedParam, StateVariableFilter, lowpass_coefficients,
+    AllpassFilter, Biquad, Biquad4, CombFilter, DcBlocker, Effect, EnvelopeFollower, F32x4,
+    InterpolatedDelay, Lfo, LfoWaveform, OnePole, SmoothedParam, StateVariableFilter, Svf4,
+    SvfOutput, lowpass_coefficients, peaking_eq_coefficients,
 };
 
 const SAMPLE_RATE: f32 = 48000.0;
@@ -58,6 +59,59 @@ fn bench_biquad(c: &mut Criterion) {
     group.finish();
 }
 
+/// Four peaking biquads: four scalar filters vs one `Biquad4`.
+///
+/// Build with `--features simd` to compare the SSE2/NEON backend against the
+/// portable one.
+fn bench_biquad4(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Biquad4");
+    let coeffs: [_; 4] = core::array::from_fn(|i| {
+        peaking_eq_coefficients(200.0 * (i + 1) as f32, 1.0, 6.0, SAMPLE_RATE)
+    });
+
+    for &block_size in BLOCK_SIZES {
+        let input = generate_test_signal(block_size);
+
+        group.bench_with_input(
+            BenchmarkId::new("scalar_x4", block_size),
+            &block_size,
+            |b, _| {
+                let mut filters: [Biquad; 4] = core::array::from_fn(|i| {
+                    let (b0, b1, b2, a0, a1, a2) = coeffs[i];
+                    let mut biquad = Biquad::new();
+                    biquad.set_coefficients(b0, b1, b2, a0, a1, a2);
+                    biquad
+                });
+                b.iter(|| {
+                    for &sample in &input {
+                        for filter in &mut filters {
+                            black_box(filter.process(black_box(sample)));
+                        }
+                    }
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("lanes", block_size),
+            &block_size,
+            |b, _| {
+                let mut bank = Biquad4::new();
+                for (lane, &(b0, b1, b2, a0, a1, a2)) in coeffs.iter().enumerate() {
+                    bank.set_coefficients(lane, b0, b1, b2, a0, a1, a2);
+                }
+                b.iter(|| {
+                    for &sample in &input {
+                        black_box(bank.process(black_box(F32x4::splat(sample))));
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_svf(c: &mut Criterion) {
     let mut group = c.benchmark_group("SVF");
 
@@ -80,6 +134,52 @@ fn bench_svf(c: &mut Criterion) {
         );
     }
 
+    // Four filters: four scalar SVFs vs one `Svf4` block
+    for &block_size in BLOCK_SIZES {
+        let input = generate_test_signal(block_size);
+        let cutoffs = [250.0, 1000.0, 4000.0, 8000.0];
+
+        group.bench_with_input(
+            BenchmarkId::new("scalar_x4", block_size),
+            &block_size,
+            |b, _| {
+                let mut filters: [StateVariableFilter; 4] = core::array::from_fn(|i| {
+                    let mut svf = StateVariableFilter::new(SAMPLE_RATE);
+                    svf.set_cutoff(cutoffs[i]);
+                    svf.set_output_type(SvfOutput::Bandpass);
+                    svf
+                });
+                b.iter(|| {
+                    for &sample in &input {
+                        for svf in &mut filters {
+                            black_box(svf.process(black_box(sample)));
+                        }
+                    }
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("svf4_block", block_size),
+            &block_size,
+            |b, _| {
+                let mut bank = Svf4::new(SAMPLE_RATE);
+                for (lane, &hz) in cutoffs.iter().enumerate() {
+                    bank.set_cutoff(lane, hz);
+                }
+                bank.set_output_type(SvfOutput::Bandpass);
+                let mut frames = vec![F32x4::ZERO; block_size];
+                b.iter(|| {
+                    for (frame, &sample) in frames.iter_mut().zip(&input) {
+                        *frame = F32x4::splat(sample);
+                    }
+                    bank.process_block(black_box(&mut frames));
+                    black_box(frames[0])
+                });
+            },
+        );
+    }
+
     // set_cutoff recalculation cost
     group.bench_function("set_cutoff_recalc", |b| {
         let mut svf = StateVariableFilter::new(SAMPLE_RATE);
@@ -310,6 +410,7 @@ fn bench_envelope_follower(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_biquad,
+    bench_biquad4,
     bench_svf,
     bench_comb,
     bench_allpass,
@@ -1,11 +1,12 @@
 //! Biquad (bi-quadratic) filter structure.
 //!
 //! Provides a generic second-order IIR filter that can be configured
-//! for various filter types (low-pass, high-pass, band-pass, notch).
+//! for various filter types (low-pass, high-pass, band-pass, notch, all-pass).
 //!
 //! Coefficient calculation uses the RBJ Audio EQ Cookbook formulas.
 
 use crate::flush_denormal;
+use crate::simd::F32x4;
 use core::f32::consts::PI;
 use libm::{cosf, sinf};
 
@@ -107,6 +108,111 @@ impl Default for Biquad {
     }
 }
 
+/// Four independent biquads processed in parallel.
+///
+/// Each lane of an [`F32x4`] runs its own TDF-II filter with its own
+/// coefficients, so one call to [`process`](Self::process) does the work of
+/// four [`Biquad::process`] calls. Use it for filters that run side by side
+/// on different signals: the left/right channels of an EQ band, the lines of
+/// a feedback delay network, or a bank of band-pass resonators.
+///
+/// Each lane is bit-identical to a scalar [`Biquad`] with the same
+/// coefficients, on every [`simd`](crate::simd) backend.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::{Biquad4, F32x4, lowpass_coefficients, highpass_coefficients};
+///
+/// let mut bank = Biquad4::new();
+/// let (b0, b1, b2, a0, a1, a2) = lowpass_coefficients(800.0, 0.707, 48000.0);
+/// bank.set_coefficients(0, b0, b1, b2, a0, a1, a2);
+/// let (b0, b1, b2, a0, a1, a2) = highpass_coefficients(800.0, 0.707, 48000.0);
+/// bank.set_coefficients(1, b0, b1, b2, a0, a1, a2);
+///
+/// // Lanes 2 and 3 keep the passthrough coefficients.
+/// let out = bank.process(F32x4::splat(0.5));
+/// assert_eq!(out.lane(2), 0.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Biquad4 {
+    b0: F32x4,
+    b1: F32x4,
+    b2: F32x4,
+    a1: F32x4,
+    a2: F32x4,
+    s1: F32x4,
+    s2: F32x4,
+}
+
+impl Biquad4 {
+    /// Creates four biquads with passthrough coefficients.
+    pub fn new() -> Self {
+        Self {
+            b0: F32x4::splat(1.0),
+            b1: F32x4::ZERO,
+            b2: F32x4::ZERO,
+            a1: F32x4::ZERO,
+            a2: F32x4::ZERO,
+            s1: F32x4::ZERO,
+            s2: F32x4::ZERO,
+        }
+    }
+
+    /// Sets the coefficients of one lane, normalizing by `a0` as
+    /// [`Biquad::set_coefficients`] does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= 4`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_coefficients(
+        &mut self,
+        lane: usize,
+        b0: f32,
+        b1: f32,
+        b2: f32,
+        a0: f32,
+        a1: f32,
+        a2: f32,
+    ) {
+        let a0_inv = 1.0 / a0;
+        self.b0.set_lane(lane, b0 * a0_inv);
+        self.b1.set_lane(lane, b1 * a0_inv);
+        self.b2.set_lane(lane, b2 * a0_inv);
+        self.a1.set_lane(lane, a1 * a0_inv);
+        self.a2.set_lane(lane, a2 * a0_inv);
+    }
+
+    /// Processes one sample in each lane.
+    #[inline]
+    pub fn process(&mut self, input: F32x4) -> F32x4 {
+        let output = self.b0 * input + self.s1;
+        self.s1 = (self.b1 * input - self.a1 * output + self.s2).flush_denormal();
+        self.s2 = (self.b2 * input - self.a2 * output).flush_denormal();
+        output
+    }
+
+    /// Processes a block in place, one [`F32x4`] frame per sample.
+    pub fn process_block(&mut self, frames: &mut [F32x4]) {
+        for frame in frames {
+            *frame = self.process(*frame);
+        }
+    }
+
+    /// Clears the state of all four lanes, keeping the coefficients.
+    pub fn clear(&mut self) {
+        self.s1 = F32x4::ZERO;
+        self.s2 = F32x4::ZERO;
+    }
+}
+
+impl Default for Biquad4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Calculates low-pass filter coefficients using the RBJ cookbook formula.
 ///
 /// # Arguments
@@ -233,6 +339,41 @@ pub fn notch_coefficients(
     (b0, b1, b2, a0, a1, a2)
 }
 
+/// Calculates all-pass filter coefficients using the RBJ cookbook formula.
+///
+/// Unity magnitude at all frequencies; the phase falls from 0° at DC through
+/// −180° at `frequency` to −360° at Nyquist. Higher Q makes the phase turn
+/// more steeply around `frequency`.
+///
+/// # Arguments
+///
+/// * `frequency` - Center frequency in Hz (−180° point)
+/// * `q` - Q factor (steepness of the phase transition)
+/// * `sample_rate` - Sample rate in Hz
+///
+/// # Returns
+///
+/// (b0, b1, b2, a0, a1, a2) coefficients
+pub fn allpass_coefficients(
+    frequency: f32,
+    q: f32,
+    sample_rate: f32,
+) -> (f32, f32, f32, f32, f32, f32) {
+    let omega = 2.0 * PI * frequency / sample_rate;
+    let cos_omega = cosf(omega);
+    let sin_omega = sinf(omega);
+    let alpha = sin_omega / (2.0 * q);
+
+    let b0 = 1.0 - alpha;
+    let b1 = -2.0 * cos_omega;
+    let b2 = 1.0 + alpha;
+    let a0 = 1.0 + alpha;
+    let a1 = -2.0 * cos_omega;
+    let a2 = 1.0 - alpha;
+
+    (b0, b1, b2, a0, a1, a2)
+}
+
 /// Calculates peaking EQ filter coefficients using the RBJ cookbook formula.
 ///
 /// A peaking EQ boosts or cuts around a center frequency with a specified bandwidth.
@@ -276,6 +417,36 @@ pub fn peaking_eq_coefficients(
 mod tests {
     use super::*;
 
+    #[test]
+    fn biquad4_lanes_match_scalar() {
+        let coeffs = [
+            lowpass_coefficients(500.0, 0.707, 48000.0),
+            highpass_coefficients(2000.0, 2.0, 48000.0),
+            peaking_eq_coefficients(1000.0, 1.5, 9.0, 48000.0),
+            notch_coefficients(60.0, 10.0, 48000.0),
+        ];
+        let mut scalar: [Biquad; 4] = core::array::from_fn(|_| Biquad::new());
+        let mut bank = Biquad4::new();
+        for (lane, &(b0, b1, b2, a0, a1, a2)) in coeffs.iter().enumerate() {
+            scalar[lane].set_coefficients(b0, b1, b2, a0, a1, a2);
+            bank.set_coefficients(lane, b0, b1, b2, a0, a1, a2);
+        }
+
+        for n in 0..2000 {
+            let x = libm::sinf(n as f32 * 0.37) * if n < 1000 { 1.0 } else { 1e-22 };
+            let input = F32x4::new(x, -x, 0.5 * x, x * x);
+            let out = bank.process(input);
+            for lane in 0..4 {
+                let want = scalar[lane].process(input.lane(lane));
+                assert_eq!(
+                    out.lane(lane).to_bits(),
+                    want.to_bits(),
+                    "lane {lane} n {n}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_biquad_passthrough() {
         let mut biquad = Biquad::new();
@@ -376,6 +547,29 @@ mod tests {
         assert!(a2.is_finite());
     }
 
+    #[test]
+    fn test_allpass_unity_magnitude() {
+        let sr = 48000.0;
+        let mut filter = Biquad::new();
+        let (b0, b1, b2, a0, a1, a2) = allpass_coefficients(1000.0, 0.707, sr);
+        filter.set_coefficients(b0, b1, b2, a0, a1, a2);
+
+        for freq in [100.0, 1000.0, 8000.0] {
+            filter.clear();
+            let (mut energy_in, mut energy_out) = (0.0_f32, 0.0_f32);
+            for i in 0..9600 {
+                let x = sinf(2.0 * PI * freq * i as f32 / sr);
+                let y = filter.process(x);
+                if i >= 4800 {
+                    energy_in += x * x;
+                    energy_out += y * y;
+                }
+            }
+            let gain = libm::sqrtf(energy_out / energy_in);
+            assert!((gain - 1.0).abs() < 0.01, "{freq} Hz: gain {gain}");
+        }
+    }
+
     #[test]
     fn test_peaking_eq_coefficients() {
         // Test boost
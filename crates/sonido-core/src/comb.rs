@@ -317,6 +317,19 @@ impl ModulatedComb {
     /// a one-pole lowpass before being summed with the input.
     #[inline]
     pub fn process(&mut self, input: f32) -> f32 {
+        let output = self.read();
+        self.write(input, output);
+        output
+    }
+
+    /// Read the modulated delay output and advance the LFO, without writing.
+    ///
+    /// [`read`](Self::read) and [`write`](Self::write) split
+    /// [`process`](Self::process) in two, so a feedback delay network can
+    /// mix the outputs of all its lines before feeding them back. Call
+    /// `write` exactly once after each `read`.
+    #[inline]
+    pub fn read(&mut self) -> f32 {
         let modulated_delay =
             self.base_delay + self.mod_depth_samples * fast_sin_turns(self.mod_phase);
         let output = self.delay.read(modulated_delay);
@@ -327,12 +340,50 @@ impl ModulatedComb {
             self.mod_phase -= 1.0;
         }
 
+        output
+    }
+
+    /// Write `input` plus the damped, scaled `feedback_signal` into the line.
+    ///
+    /// In [`process`](Self::process) the feedback signal is the line's own
+    /// output; in an FDN it is that line's row of the mixing matrix.
+    #[inline]
+    pub fn write(&mut self, input: f32, feedback_signal: f32) {
         // Damping in feedback path
-        let damped = self.damping.process(output);
+        let damped = self.damping.process(feedback_signal);
         self.delay
             .write(flush_denormal(input + damped * self.feedback));
+    }
 
-        output
+    /// Set the base delay length in samples.
+    ///
+    /// Clamped so the modulated read position stays within the capacity
+    /// allocated at construction; lengthen a line by constructing it at its
+    /// longest delay and shortening it from there.
+    #[inline]
+    pub fn set_delay(&mut self, delay_samples: f32) {
+        let max = (self.delay.capacity() - 4) as f32 - self.mod_depth_samples;
+        self.base_delay = delay_samples.clamp(self.mod_depth_samples + 1.0, max.max(1.0));
+    }
+
+    /// Get the base delay length in samples.
+    #[inline]
+    pub fn delay(&self) -> f32 {
+        self.base_delay
+    }
+
+    /// Set the modulation depth in samples.
+    ///
+    /// Clamped to the headroom between the base delay and the capacity
+    /// allocated at construction, and to the base delay itself so the read
+    /// position never goes negative.
+    #[inline]
+    pub fn set_mod_depth_samples(&mut self, depth_samples: f32) {
+        let headroom = (self.delay.capacity() - 4) as f32 - self.base_delay;
+        self.mod_depth_samples = depth_samples
+            .min(headroom)
+            .min(self.base_delay - 1.0)
+            .max(0.0);
     }
 
     /// Set the feedback coefficient.
@@ -435,6 +486,40 @@ mod modulated_comb_tests {
         );
     }
 
+    #[test]
+    fn test_modulated_comb_split_matches_process() {
+        let mut whole = ModulatedComb::new(60.0, 0.8, 6000.0, 0.7, 0.3, 48000.0);
+        let mut split = whole.clone();
+
+        for i in 0..1000 {
+            let input = libm::sinf(i as f32 * 0.31);
+            let expected = whole.process(input);
+            let out = split.read();
+            split.write(input, out);
+            assert_eq!(out.to_bits(), expected.to_bits(), "sample {i}");
+        }
+    }
+
+    #[test]
+    fn test_modulated_comb_set_delay_clamps_to_capacity() {
+        let mut comb = ModulatedComb::new(100.0, 0.7, 6000.0, 0.5, 0.3, 48000.0);
+        comb.set_delay(50.0);
+        assert_eq!(comb.delay(), 50.0);
+
+        comb.set_delay(10_000.0);
+        assert!(
+            comb.delay() <= 100.0,
+            "delay grew past capacity: {}",
+            comb.delay()
+        );
+
+        comb.set_mod_depth_samples(1000.0);
+        comb.set_delay(0.0);
+        for _ in 0..1000 {
+            assert!(comb.process(1.0).is_finite());
+        }
+    }
+
     #[test]
     fn test_modulated_comb_reset() {
         let mut comb = ModulatedComb::new(50.0, 0.8, 6000.0, 0.5, 0.3, 48000.0);
@@ -0,0 +1,555 @@
+//! Linkwitz-Riley crossover filters and an N-band splitter.
+//!
+//! A Linkwitz-Riley crossover of order 2n squares an nth-order Butterworth
+//! response: the lowpass and highpass outputs are each −6 dB at the crossover
+//! frequency and, summed, give an allpass with flat magnitude. Three slopes
+//! are provided:
+//!
+//! | Slope | dB/oct | Sections per path | Summed response |
+//! |-------|--------|-------------------|-----------------|
+//! | LR2 | 12 | 1 (Q = 0.5) | 1st-order allpass (highpass inverted) |
+//! | LR4 | 24 | 2 (Q = 0.707) | 2nd-order allpass, Q = 0.707 |
+//! | LR8 | 48 | 4 (Q = 0.541, 1.307, twice) | two 2nd-order allpasses |
+//!
+//! [`LinkwitzRiley`] is a two-way split. [`LinkwitzRileyAllpass`] reproduces
+//! the phase of its summed output without splitting, which is what a band
+//! needs when it bypasses a crossover that other bands pass through.
+//! [`BandSplitter`] chains `N − 1` crossovers and inserts those allpasses so
+//! every band carries the same phase, and the bands sum to the input through
+//! a pure allpass (magnitude reconstruction is exact).
+//!
+//! All sections use the bilinear transform prewarped at the crossover
+//! frequency, so the analog identities above also hold for the digital
+//! filters, not just approximately.
+//!
+//! Filters are mono; use one instance per channel.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use sonido_core::{BandSplitter, CrossoverSlope};
+//!
+//! let mut split = BandSplitter::<3>::new(48000.0, &[250.0, 3000.0], CrossoverSlope::Lr4);
+//! let [low, mid, high] = split.process(0.5);
+//! let sum = low + mid + high; // allpass of the input
+//! # let _ = sum;
+//! ```
+//!
+//! # Reference
+//!
+//! S. Linkwitz, "Active Crossover Networks for Noncoincident Drivers",
+//! JAES vol. 24, no. 1, 1976. D. Bohn, "Linkwitz-Riley Crossovers: A Primer",
+//! Rane Note 160, 2005.
+
+use core::f32::consts::PI;
+use libm::tanf;
+
+use crate::Biquad;
+
+/// Most biquad sections in one crossover path (LR8).
+const MAX_SECTIONS: usize = 4;
+
+/// Butterworth Q values for the 4th-order prototype used by LR8.
+const BUTTERWORTH4_Q: [f32; 2] = [0.541_196_1, 1.306_563];
+
+/// Crossover slope.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CrossoverSlope {
+    /// 12 dB/octave.
+    Lr2,
+    /// 24 dB/octave.
+    #[default]
+    Lr4,
+    /// 48 dB/octave.
+    Lr8,
+}
+
+impl CrossoverSlope {
+    /// Rolloff of each output in dB per octave.
+    pub const fn db_per_octave(self) -> u32 {
+        match self {
+            Self::Lr2 => 12,
+            Self::Lr4 => 24,
+            Self::Lr8 => 48,
+        }
+    }
+
+    /// Biquad Q for each section of one output path.
+    fn section_qs(self) -> &'static [f32] {
+        const SQRT1_2: f32 = core::f32::consts::FRAC_1_SQRT_2;
+        match self {
+            Self::Lr2 => &[0.5],
+            Self::Lr4 => &[SQRT1_2, SQRT1_2],
+            Self::Lr8 => &[
+                BUTTERWORTH4_Q[0],
+                BUTTERWORTH4_Q[1],
+                BUTTERWORTH4_Q[0],
+                BUTTERWORTH4_Q[1],
+            ],
+        }
+    }
+}
+
+/// Prewarped bilinear constant `tan(π·f/fs)` with `f` kept below Nyquist.
+fn warp(freq_hz: f32, sample_rate: f32) -> f32 {
+    let f = freq_hz.clamp(1.0, sample_rate * 0.49);
+    tanf(PI * f / sample_rate)
+}
+
+/// Set `biquad` to a 2nd-order lowpass (`highpass = false`) or highpass.
+fn set_section(biquad: &mut Biquad, k: f32, q: f32, highpass: bool) {
+    let k2 = k * k;
+    let norm = 1.0 + k / q + k2;
+    let a1 = 2.0 * (k2 - 1.0);
+    let a2 = 1.0 - k / q + k2;
+    if highpass {
+        biquad.set_coefficients(1.0, -2.0, 1.0, norm, a1, a2);
+    } else {
+        biquad.set_coefficients(k2, 2.0 * k2, k2, norm, a1, a2);
+    }
+}
+
+/// Two-way Linkwitz-Riley crossover.
+///
+/// ## Parameters
+/// - `freq_hz`: Crossover frequency in Hz, clamped below Nyquist.
+/// - `slope`: [`CrossoverSlope`] (default LR4).
+///
+/// [`process`](Self::process) returns `(low, high)`. For LR2 the high
+/// output is inverted so the sum is flat; LR4 and LR8 outputs are in phase.
+#[derive(Debug, Clone)]
+pub struct LinkwitzRiley {
+    lowpass: [Biquad; MAX_SECTIONS],
+    highpass: [Biquad; MAX_SECTIONS],
+    sections: usize,
+    slope: CrossoverSlope,
+    freq_hz: f32,
+    sample_rate: f32,
+}
+
+impl LinkwitzRiley {
+    /// Create a crossover at `freq_hz`.
+    pub fn new(sample_rate: f32, freq_hz: f32, slope: CrossoverSlope) -> Self {
+        let mut xover = Self {
+            lowpass: Default::default(),
+            highpass: Default::default(),
+            sections: 0,
+            slope,
+            freq_hz,
+            sample_rate,
+        };
+        xover.update();
+        xover
+    }
+
+    /// Crossover frequency in Hz.
+    pub fn frequency(&self) -> f32 {
+        self.freq_hz
+    }
+
+    /// Current slope.
+    pub fn slope(&self) -> CrossoverSlope {
+        self.slope
+    }
+
+    /// Move the crossover frequency. Filter state is kept.
+    pub fn set_frequency(&mut self, freq_hz: f32) {
+        self.freq_hz = freq_hz;
+        self.update();
+    }
+
+    /// Change the slope. Clears filter state when the slope changes.
+    pub fn set_slope(&mut self, slope: CrossoverSlope) {
+        if slope != self.slope {
+            self.slope = slope;
+            self.clear();
+            self.update();
+        }
+    }
+
+    /// Change the sample rate, recomputing coefficients.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.update();
+    }
+
+    /// Split one sample into `(low, high)`.
+    #[inline]
+    pub fn process(&mut self, input: f32) -> (f32, f32) {
+        let mut low = input;
+        let mut high = input;
+        for i in 0..self.sections {
+            low = self.lowpass[i].process(low);
+            high = self.highpass[i].process(high);
+        }
+        if self.slope == CrossoverSlope::Lr2 {
+            high = -high;
+        }
+        (low, high)
+    }
+
+    /// Clear filter state.
+    pub fn clear(&mut self) {
+        self.lowpass.iter_mut().for_each(Biquad::clear);
+        self.highpass.iter_mut().for_each(Biquad::clear);
+    }
+
+    fn update(&mut self) {
+        let k = warp(self.freq_hz, self.sample_rate);
+        let qs = self.slope.section_qs();
+        self.sections = qs.len();
+        for (i, &q) in qs.iter().enumerate() {
+            set_section(&mut self.lowpass[i], k, q, false);
+            set_section(&mut self.highpass[i], k, q, true);
+        }
+    }
+}
+
+/// Allpass with the same response as a [`LinkwitzRiley`] crossover's
+/// summed `low + high` output.
+///
+/// Used to phase-align a signal path that does not pass through a
+/// crossover with one that does.
+#[derive(Debug, Clone)]
+pub struct LinkwitzRileyAllpass {
+    sections: [Biquad; 2],
+    count: usize,
+    slope: CrossoverSlope,
+    freq_hz: f32,
+    sample_rate: f32,
+}
+
+impl LinkwitzRileyAllpass {
+    /// Create an allpass matching a crossover at `freq_hz`.
+    pub fn new(sample_rate: f32, freq_hz: f32, slope: CrossoverSlope) -> Self {
+        let mut ap = Self {
+            sections: Default::default(),
+            count: 0,
+            slope,
+            freq_hz,
+            sample_rate,
+        };
+        ap.update();
+        ap
+    }
+
+    /// Move the matched crossover frequency. Filter state is kept.
+    pub fn set_frequency(&mut self, freq_hz: f32) {
+        self.freq_hz = freq_hz;
+        self.update();
+    }
+
+    /// Change the slope. Clears filter state when the slope changes.
+    pub fn set_slope(&mut self, slope: CrossoverSlope) {
+        if slope != self.slope {
+            self.slope = slope;
+            self.clear();
+            self.update();
+        }
+    }
+
+    /// Change the sample rate, recomputing coefficients.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.update();
+    }
+
+    /// Process one sample.
+    #[inline]
+    pub fn process(&mut self, input: f32) -> f32 {
+        let mut out = input;
+        for section in &mut self.sections[..self.count] {
+            out = section.process(out);
+        }
+        out
+    }
+
+    /// Clear filter state.
+    pub fn clear(&mut self) {
+        self.sections.iter_mut().for_each(Biquad::clear);
+    }
+
+    fn update(&mut self) {
+        let k = warp(self.freq_hz, self.sample_rate);
+        match self.slope {
+            CrossoverSlope::Lr2 => {
+                // (1 − s/ωc) / (1 + s/ωc): LP − HP of the Q = 0.5 pair.
+                let c = (k - 1.0) / (k + 1.0);
+                self.sections[0].set_coefficients(c, 1.0, 0.0, 1.0, c, 0.0);
+                self.count = 1;
+            }
+            CrossoverSlope::Lr4 => {
+                self.set_allpass(0, k, core::f32::consts::FRAC_1_SQRT_2);
+                self.count = 1;
+            }
+            CrossoverSlope::Lr8 => {
+                self.set_allpass(0, k, BUTTERWORTH4_Q[0]);
+                self.set_allpass(1, k, BUTTERWORTH4_Q[1]);
+                self.count = 2;
+            }
+        }
+    }
+
+    /// 2nd-order allpass `(s² − s/Q + 1) / (s² + s/Q + 1)`.
+    fn set_allpass(&mut self, index: usize, k: f32, q: f32) {
+        let k2 = k * k;
+        let norm = 1.0 + k / q + k2;
+        let a1 = 2.0 * (k2 - 1.0);
+        let a2 = 1.0 - k / q + k2;
+        self.sections[index].set_coefficients(a2, a1, norm, norm, a1, a2);
+    }
+}
+
+/// Splits a signal into `N` phase-aligned bands.
+///
+/// Band 0 is lowest. Crossover `i` sits between bands `i` and `i + 1`.
+/// Every band below the top passes through allpasses for the crossovers it
+/// skips, so `bands.iter().sum()` equals the input through the cascade of
+/// all crossover allpasses — flat magnitude at any crossover settings,
+/// including unsorted frequencies.
+///
+/// Storage is fixed-size (`N` crossovers and `N × N` allpass slots, of which
+/// `N − 1` and `(N − 1)(N − 2) / 2` are used), so the splitter never
+/// allocates.
+///
+/// ## Parameters
+/// - `N`: Number of bands (at least 1).
+/// - `freqs_hz`: `N − 1` crossover frequencies, lowest band first.
+/// - `slope`: [`CrossoverSlope`] shared by all crossovers.
+#[derive(Debug, Clone)]
+pub struct BandSplitter<const N: usize> {
+    crossovers: [LinkwitzRiley; N],
+    /// `allpasses[band][j]` aligns `band` with crossover `j`; only
+    /// `j > band` is used.
+    allpasses: [[LinkwitzRileyAllpass; N]; N],
+}
+
+impl<const N: usize> BandSplitter<N> {
+    /// Create a splitter with the given crossover frequencies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0 or `freqs_hz.len() != N - 1`.
+    pub fn new(sample_rate: f32, freqs_hz: &[f32], slope: CrossoverSlope) -> Self {
+        assert!(N >= 1, "BandSplitter needs at least one band");
+        assert_eq!(
+            freqs_hz.len(),
+            N - 1,
+            "BandSplitter<{N}> takes {} frequencies",
+            N - 1
+        );
+        let freq = |i: usize| freqs_hz.get(i).copied().unwrap_or(1000.0);
+        Self {
+            crossovers: core::array::from_fn(|i| LinkwitzRiley::new(sample_rate, freq(i), slope)),
+            allpasses: core::array::from_fn(|_| {
+                core::array::from_fn(|j| LinkwitzRileyAllpass::new(sample_rate, freq(j), slope))
+            }),
+        }
+    }
+
+    /// Number of crossovers (`N − 1`).
+    pub const fn crossover_count(&self) -> usize {
+        N - 1
+    }
+
+    /// Frequency of crossover `index` in Hz.
+    pub fn frequency(&self, index: usize) -> f32 {
+        self.crossovers[index].frequency()
+    }
+
+    /// Move crossover `index`. Out-of-range indices are ignored.
+    pub fn set_frequency(&mut self, index: usize, freq_hz: f32) {
+        if index + 1 >= N {
+            return;
+        }
+        self.crossovers[index].set_frequency(freq_hz);
+        for band in &mut self.allpasses {
+            band[index].set_frequency(freq_hz);
+        }
+    }
+
+    /// Change the slope of every crossover.
+    pub fn set_slope(&mut self, slope: CrossoverSlope) {
+        self.crossovers.iter_mut().for_each(|x| x.set_slope(slope));
+        for band in &mut self.allpasses {
+            for ap in band.iter_mut() {
+                ap.set_slope(slope);
+            }
+        }
+    }
+
+    /// Change the sample rate, recomputing coefficients.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.crossovers
+            .iter_mut()
+            .for_each(|x| x.set_sample_rate(sample_rate));
+        for band in &mut self.allpasses {
+            for ap in band.iter_mut() {
+                ap.set_sample_rate(sample_rate);
+            }
+        }
+    }
+
+    /// Split one sample into `N` bands, lowest first.
+    #[inline]
+    pub fn process(&mut self, input: f32) -> [f32; N] {
+        let mut bands = [0.0; N];
+        let mut rest = input;
+        for band in 0..N - 1 {
+            let (low, high) = self.crossovers[band].process(rest);
+            // Align with the crossovers this band skips.
+            let mut aligned = low;
+            for j in band + 1..N - 1 {
+                aligned = self.allpasses[band][j].process(aligned);
+            }
+            bands[band] = aligned;
+            rest = high;
+        }
+        bands[N - 1] = rest;
+        bands
+    }
+
+    /// Clear all filter state.
+    pub fn clear(&mut self) {
+        self.crossovers.iter_mut().for_each(LinkwitzRiley::clear);
+        for band in &mut self.allpasses {
+            band.iter_mut().for_each(LinkwitzRileyAllpass::clear);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    const SR: f32 = 48000.0;
+    const SLOPES: [CrossoverSlope; 3] = [
+        CrossoverSlope::Lr2,
+        CrossoverSlope::Lr4,
+        CrossoverSlope::Lr8,
+    ];
+
+    fn impulse(len: usize) -> Vec<f32> {
+        let mut x = vec![0.0; len];
+        x[0] = 1.0;
+        x
+    }
+
+    /// Steady-state RMS of a sine at `freq` through `f`.
+    fn sine_gain(freq: f32, mut f: impl FnMut(f32) -> f32) -> f32 {
+        let n = 48000;
+        let mut sum = 0.0;
+        for i in 0..n {
+            let y = f(libm::sinf(2.0 * PI * freq * i as f32 / SR));
+            if i >= n / 2 {
+                sum += y * y;
+            }
+        }
+        libm::sqrtf(sum / (n / 2) as f32) * core::f32::consts::SQRT_2
+    }
+
+    #[test]
+    fn outputs_are_minus_6db_at_crossover() {
+        for slope in SLOPES {
+            let mut lp = LinkwitzRiley::new(SR, 1000.0, slope);
+            let mut hp = lp.clone();
+            let low = sine_gain(1000.0, |x| lp.process(x).0);
+            let high = sine_gain(1000.0, |x| hp.process(x).1);
+            assert!((low - 0.5).abs() < 0.01, "{slope:?} low {low}");
+            assert!((high - 0.5).abs() < 0.01, "{slope:?} high {high}");
+        }
+    }
+
+    #[test]
+    fn slopes_attenuate_by_order() {
+        // One octave up, |H| = 1 / (1 + r^(2n)) for a squared nth-order Butterworth.
+        for slope in SLOPES {
+            let mut xover = LinkwitzRiley::new(SR, 1000.0, slope);
+            let low = sine_gain(2000.0, |x| xover.process(x).0);
+            let n = (slope.db_per_octave() / 12) as f32;
+            let expected = 1.0 / (1.0 + libm::powf(2.0, 2.0 * n));
+            let error_db = 20.0 * libm::log10f(low / expected);
+            assert!(error_db.abs() < 0.5, "{slope:?}: {error_db} dB off");
+        }
+    }
+
+    #[test]
+    fn sum_matches_allpass_exactly() {
+        for slope in SLOPES {
+            let mut xover = LinkwitzRiley::new(SR, 1200.0, slope);
+            let mut ap = LinkwitzRileyAllpass::new(SR, 1200.0, slope);
+            for x in impulse(2048) {
+                let (low, high) = xover.process(x);
+                let reference = ap.process(x);
+                assert!((low + high - reference).abs() < 1e-5, "{slope:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn band_splitter_reconstructs_through_allpass_cascade() {
+        for slope in SLOPES {
+            let freqs = [120.0, 800.0, 4000.0];
+            let mut split = BandSplitter::<4>::new(SR, &freqs, slope);
+            let mut cascade: Vec<_> = freqs
+                .iter()
+                .map(|&f| LinkwitzRileyAllpass::new(SR, f, slope))
+                .collect();
+            for x in impulse(4096) {
+                let sum: f32 = split.process(x).iter().sum();
+                let reference = cascade.iter_mut().fold(x, |y, ap| ap.process(y));
+                assert!((sum - reference).abs() < 1e-4, "{slope:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn band_splitter_sum_is_flat() {
+        let mut split = BandSplitter::<3>::new(SR, &[250.0, 3000.0], CrossoverSlope::Lr4);
+        for freq in [60.0, 250.0, 1000.0, 3000.0, 12000.0] {
+            split.clear();
+            let gain = sine_gain(freq, |x| split.process(x).iter().sum());
+            assert!((gain - 1.0).abs() < 0.01, "{freq} Hz: {gain}");
+        }
+    }
+
+    #[test]
+    fn bands_isolate_their_range() {
+        let mut split = BandSplitter::<3>::new(SR, &[250.0, 3000.0], CrossoverSlope::Lr8);
+        let mid = sine_gain(900.0, |x| split.process(x)[1]);
+        split.clear();
+        let low = sine_gain(900.0, |x| split.process(x)[0]);
+        split.clear();
+        let high = sine_gain(900.0, |x| split.process(x)[2]);
+        assert!(mid > 0.9, "mid {mid}");
+        assert!(low < 0.01 && high < 0.01, "low {low} high {high}");
+    }
+
+    #[test]
+    fn single_band_passes_through() {
+        let mut split = BandSplitter::<1>::new(SR, &[], CrossoverSlope::Lr4);
+        assert_eq!(split.crossover_count(), 0);
+        assert_eq!(split.process(0.25), [0.25]);
+    }
+
+    #[test]
+    fn retuning_and_slope_change_keep_reconstruction() {
+        let mut split = BandSplitter::<3>::new(SR, &[250.0, 3000.0], CrossoverSlope::Lr4);
+        split.set_frequency(0, 400.0);
+        split.set_frequency(5, 1.0); // ignored
+        split.set_slope(CrossoverSlope::Lr2);
+        assert_eq!(split.frequency(0), 400.0);
+        split.clear();
+
+        let mut a = LinkwitzRileyAllpass::new(SR, 400.0, CrossoverSlope::Lr2);
+        let mut b = LinkwitzRileyAllpass::new(SR, 3000.0, CrossoverSlope::Lr2);
+        for x in impulse(1024) {
+            let sum: f32 = split.process(x).iter().sum();
+            assert!((sum - b.process(a.process(x))).abs() < 1e-5);
+        }
+    }
+}
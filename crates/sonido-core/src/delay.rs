@@ -120,19 +120,24 @@ impl InterpolatedDelay {
     /// * `delay_samples` - Delay time in samples (can be fractional)
     ///
     /// Returns the interpolated sample from the delay line.
-    #[inline]
+    ///
+    /// Always inlined: the cubic arm makes the body large enough that the
+    /// compiler otherwise emits a call per read, which doubled the cost of
+    /// each tap in the reverb.
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
     pub fn read(&self, delay_samples: f32) -> f32 {
         debug_assert!(delay_samples >= 0.0);
 
         let len = self.buffer.len();
         let delay_clamped = delay_samples.min((len - 1) as f32);
 
-        let delay_int = delay_clamped as usize;
+        // Through u32: a plain truncating convert, where f32 → usize needs a
+        // saturating 64-bit sequence. Buffers never approach 2^32 samples.
+        let delay_int = delay_clamped as u32 as usize;
         let frac = delay_clamped - delay_int as f32;
 
-        // read_pos = (write_pos + len - delay_int - 1) % len
-        // This points to the sample `delay_int` samples before the last written.
-        let read_pos = (self.write_pos + len - delay_int - 1) % len;
+        let read_pos = self.read_index(delay_int);
 
         match self.interpolation {
             Interpolation::None => self.buffer[read_pos],
@@ -186,8 +191,19 @@ impl InterpolatedDelay {
     pub fn read_integer(&self, delay_samples: usize) -> f32 {
         let len = self.buffer.len();
         let delay_clamped = delay_samples.min(len - 1);
-        let read_pos = (self.write_pos + len - delay_clamped - 1) % len;
-        self.buffer[read_pos]
+        self.buffer[self.read_index(delay_clamped)]
+    }
+
+    /// Buffer index of the sample written `delay` samples before the last one.
+    ///
+    /// Equivalent to `(write_pos + len - delay - 1) % len` for `delay < len`,
+    /// with a compare instead of the integer division — every delay read in
+    /// a reverb goes through here several times per sample.
+    #[inline]
+    fn read_index(&self, delay: usize) -> usize {
+        let len = self.buffer.len();
+        let pos = self.write_pos + len - delay - 1;
+        if pos >= len { pos - len } else { pos }
     }
 
     /// Writes a sample to the delay line and advances the write position.
@@ -0,0 +1,195 @@
+//! Sidechain key filter for dynamics detection paths.
+//!
+//! [`KeyFilter`] shapes the signal a compressor or gate listens to: a
+//! Butterworth highpass removes low end that would otherwise dominate the
+//! detector, then a first-order tilt around 1 kHz leans the detector toward
+//! lows or highs without a second filter to tune.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! key → HPF (12 dB/oct) ─┬─ one-pole LP (1 kHz) ── × low_gain ──┐
+//!                        └─ (x − LP) ────────────── × high_gain ─┴─► out
+//! ```
+//!
+//! With `tilt_db = +6`, content above the pivot is raised by 6 dB and content
+//! below it lowered by 6 dB. At 0 dB the tilt stage is bypassed and the output
+//! is exactly the highpassed key.
+
+use crate::biquad::{Biquad, highpass_coefficients};
+use crate::cached::Cached;
+use crate::fast_math::fast_db_to_linear;
+use crate::one_pole::OnePole;
+
+/// Butterworth Q for the key highpass (1/√2).
+const HPF_Q: f32 = 0.707;
+
+/// Pivot frequency of the tilt stage in Hz.
+const TILT_PIVOT_HZ: f32 = 1000.0;
+
+/// Tilt below this magnitude (dB) bypasses the tilt stage.
+const TILT_BYPASS_DB: f32 = 0.01;
+
+/// Highpass + tilt filter for a sidechain key signal.
+///
+/// Coefficient recomputation is guarded by [`Cached`], so calling
+/// [`set_controls`](Self::set_controls) every sample is cheap while the
+/// controls are steady.
+///
+/// # Invariants
+///
+/// - `sample_rate` must be > 0.0
+/// - Highpass frequency is clamped to 20–500 Hz, tilt to ±12 dB
+pub struct KeyFilter {
+    /// Sample rate in Hz — required for coefficient calculation.
+    sample_rate: f32,
+    /// Key highpass.
+    hpf: Biquad,
+    /// Lowpass splitting the key at the tilt pivot.
+    tilt_lp: OnePole,
+    /// Cached highpass coefficients, keyed on frequency.
+    hpf_cache: Cached<[f32; 6]>,
+    /// Cached `[low_gain, high_gain]`, keyed on tilt dB.
+    tilt_cache: Cached<[f32; 2]>,
+    /// Whether the tilt stage is applied.
+    tilt_active: bool,
+}
+
+impl KeyFilter {
+    /// Create a key filter with the highpass at `hpf_hz` and no tilt.
+    pub fn new(sample_rate: f32, hpf_hz: f32) -> Self {
+        let coeffs = Self::hpf_coefficients(hpf_hz, sample_rate);
+        let mut hpf = Biquad::new();
+        hpf.set_coefficients(
+            coeffs[0], coeffs[1], coeffs[2], coeffs[3], coeffs[4], coeffs[5],
+        );
+        let mut hpf_cache = Cached::new(coeffs, 1);
+        hpf_cache.update(&[hpf_hz], 0.5, |_| coeffs);
+        let mut tilt_cache = Cached::new([1.0, 1.0], 1);
+        tilt_cache.update(&[0.0], 1e-3, |_| [1.0, 1.0]);
+
+        Self {
+            sample_rate,
+            hpf,
+            tilt_lp: OnePole::new(sample_rate, TILT_PIVOT_HZ),
+            hpf_cache,
+            tilt_cache,
+            tilt_active: false,
+        }
+    }
+
+    /// Set the highpass frequency (Hz) and tilt (dB, positive = brighter).
+    pub fn set_controls(&mut self, hpf_hz: f32, tilt_db: f32) {
+        let sr = self.sample_rate;
+        let c = *self.hpf_cache.update(&[hpf_hz], 0.5, |inputs| {
+            Self::hpf_coefficients(inputs[0], sr)
+        });
+        self.hpf
+            .set_coefficients(c[0], c[1], c[2], c[3], c[4], c[5]);
+
+        let tilt_db = tilt_db.clamp(-12.0, 12.0);
+        self.tilt_active = libm::fabsf(tilt_db) >= TILT_BYPASS_DB;
+        if self.tilt_active {
+            self.tilt_cache.update(&[tilt_db], 1e-3, |inputs| {
+                [fast_db_to_linear(-inputs[0]), fast_db_to_linear(inputs[0])]
+            });
+        }
+    }
+
+    /// Filter one key sample.
+    #[inline]
+    pub fn process(&mut self, input: f32) -> f32 {
+        let filtered = self.hpf.process(input);
+        // Keep the split running while bypassed so enabling tilt is click-free
+        let low = self.tilt_lp.process(filtered);
+        if self.tilt_active {
+            let [low_gain, high_gain] = *self.tilt_cache.get();
+            low * low_gain + (filtered - low) * high_gain
+        } else {
+            filtered
+        }
+    }
+
+    /// Clear filter state and invalidate coefficient caches.
+    pub fn reset(&mut self) {
+        self.hpf.clear();
+        self.tilt_lp.reset();
+        self.hpf_cache.invalidate();
+        self.tilt_cache.invalidate();
+    }
+
+    /// Update sample rate and invalidate coefficient caches.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.tilt_lp.set_sample_rate(sample_rate);
+        self.hpf_cache.invalidate();
+    }
+
+    fn hpf_coefficients(freq: f32, sample_rate: f32) -> [f32; 6] {
+        let (b0, b1, b2, a0, a1, a2) =
+            highpass_coefficients(freq.clamp(20.0, 500.0), HPF_Q, sample_rate);
+        [b0, b1, b2, a0, a1, a2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Peak output level of a settled sine through `filter`.
+    fn sine_peak(filter: &mut KeyFilter, freq: f32) -> f32 {
+        let sr = 48000.0;
+        let mut peak = 0.0_f32;
+        for i in 0..9600 {
+            let x = libm::sinf(core::f32::consts::TAU * freq * i as f32 / sr);
+            let y = filter.process(x);
+            if i >= 4800 {
+                peak = peak.max(y.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn highpass_rejects_lows() {
+        let mut filter = KeyFilter::new(48000.0, 200.0);
+        filter.set_controls(200.0, 0.0);
+        assert!(sine_peak(&mut filter, 40.0) < 0.1);
+        let mut filter = KeyFilter::new(48000.0, 200.0);
+        filter.set_controls(200.0, 0.0);
+        assert!(sine_peak(&mut filter, 2000.0) > 0.95);
+    }
+
+    #[test]
+    fn tilt_leans_toward_highs() {
+        let mut flat = KeyFilter::new(48000.0, 20.0);
+        flat.set_controls(20.0, 0.0);
+        let flat_low = sine_peak(&mut flat, 100.0);
+
+        let mut bright = KeyFilter::new(48000.0, 20.0);
+        bright.set_controls(20.0, 6.0);
+        let bright_low = sine_peak(&mut bright, 100.0);
+        let mut bright = KeyFilter::new(48000.0, 20.0);
+        bright.set_controls(20.0, 6.0);
+        let bright_high = sine_peak(&mut bright, 10000.0);
+
+        assert!(
+            bright_low < flat_low * 0.6,
+            "lows should drop: {bright_low}"
+        );
+        assert!(bright_high > 1.8, "highs should rise: {bright_high}");
+    }
+
+    #[test]
+    fn zero_tilt_is_exact_highpass() {
+        let mut key = KeyFilter::new(48000.0, 80.0);
+        let mut reference = Biquad::new();
+        let c = KeyFilter::hpf_coefficients(80.0, 48000.0);
+        reference.set_coefficients(c[0], c[1], c[2], c[3], c[4], c[5]);
+        for i in 0..512 {
+            let x = libm::sinf(i as f32 * 0.05);
+            key.set_controls(80.0, 0.0);
+            assert_eq!(key.process(x), reference.process(x));
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //! - [`GainStage`] — Nonlinear gain stage with ADAA anti-aliasing, parameterized
 //!   by a waveshaper function and its antiderivative.
 //! - [`ToneStack`] — 3-band interactive tone control (bass/mid/treble peaking EQ).
+//! - [`KeyFilter`] — Sidechain highpass + tilt for compressor/gate detection.
 //!
 //! # Usage
 //!
@@ -30,7 +31,9 @@
 //! ```
 
 pub mod gain_stage;
+pub mod key_filter;
 pub mod tone_stack;
 
 pub use gain_stage::GainStage;
+pub use key_filter::KeyFilter;
 pub use tone_stack::ToneStack;
@@ -6,9 +6,34 @@
 //! Supports both peak and RMS detection modes. Peak detection follows
 //! instantaneous amplitude; RMS detection follows signal power
 //! (root-mean-square) for a smoother, more averaged response.
+//!
+//! # Detector Options
+//!
+//! - **RMS window** ([`EnvelopeFollower::set_rms_window_ms`]): in RMS mode,
+//!   averages `x²` over a sliding rectangular window before the attack/release
+//!   stage, giving a true windowed RMS instead of the exponential estimate.
+//! - **Log domain** ([`EnvelopeFollower::set_log_domain`]): runs attack and
+//!   release on the level in dB, so release falls at a constant dB rate
+//!   regardless of level — the smoothing used by most compressor designs.
+//! - **Hold** ([`EnvelopeFollower::set_hold_ms`]): keeps the envelope at its
+//!   last peak for a fixed time before release starts, which stops gates
+//!   chattering on decaying notes.
+//! - **Stereo link** ([`EnvelopeFollower::process_stereo`]): one detector fed
+//!   from both channels, so linked dynamics react identically on each side.
+//!
+//! The RMS window buffer is allocated by `set_rms_window_ms` (and
+//! `set_sample_rate` when a window is set); processing never allocates.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
 
 use libm::{expf, sqrtf};
 
+use crate::math::{db_to_linear, linear_to_db};
+
+/// Envelope floor in the log domain, in dB.
+const LOG_FLOOR_DB: f32 = -120.0;
+
 /// Detection mode for envelope following.
 ///
 /// Determines how the input signal is measured before smoothing.
@@ -40,6 +65,14 @@ pub enum DetectionMode {
 /// In **RMS** mode, it smooths `x[n]²` then takes `sqrt()` on output,
 /// giving a power-based measurement that better represents perceived loudness.
 ///
+/// ## Parameters
+/// - `attack_ms`: Rise time constant (min 0.1 ms, default 10.0)
+/// - `release_ms`: Fall time constant (min 1.0 ms, default 100.0)
+/// - `detection_mode`: Peak or RMS (default Peak)
+/// - `rms_window_ms`: Sliding RMS window, 0 = exponential RMS (default 0.0)
+/// - `log_domain`: Smooth in dB rather than linear (default false)
+/// - `hold_ms`: Hold before release (default 0.0)
+///
 /// # Example
 ///
 /// ```rust
@@ -54,7 +87,8 @@ pub enum DetectionMode {
 /// ```
 #[derive(Debug, Clone)]
 pub struct EnvelopeFollower {
-    /// Current envelope level (linear amplitude in Peak mode, squared power in RMS mode)
+    /// Current envelope level (linear amplitude in Peak mode, squared power in
+    /// RMS mode, amplitude in dB in the log domain)
     envelope: f32,
     /// Attack coefficient
     attack_coeff: f32,
@@ -68,6 +102,22 @@ pub struct EnvelopeFollower {
     release_ms: f32,
     /// Detection mode (Peak or RMS)
     detection_mode: DetectionMode,
+    /// Sliding window of `x²` for windowed RMS (empty when off)
+    window: Vec<f32>,
+    /// Next write position in `window`
+    window_pos: usize,
+    /// Running sum of `window`
+    window_sum: f32,
+    /// RMS window length in ms (0 = off)
+    window_ms: f32,
+    /// Smooth in dB rather than linear
+    log_domain: bool,
+    /// Hold time in ms
+    hold_ms: f32,
+    /// Hold time in samples
+    hold_samples: u32,
+    /// Samples of hold remaining
+    hold_counter: u32,
 }
 
 impl EnvelopeFollower {
@@ -85,6 +135,14 @@ impl EnvelopeFollower {
             attack_ms: 10.0,
             release_ms: 100.0,
             detection_mode: DetectionMode::Peak,
+            window: Vec::new(),
+            window_pos: 0,
+            window_sum: 0.0,
+            window_ms: 0.0,
+            log_domain: false,
+            hold_ms: 0.0,
+            hold_samples: 0,
+            hold_counter: 0,
         };
         follower.recalculate_coefficients();
         follower
@@ -132,9 +190,12 @@ impl EnvelopeFollower {
     }
 
     /// Update sample rate and recalculate coefficients.
+    ///
+    /// Reallocates the RMS window if one is set.
     pub fn set_sample_rate(&mut self, sample_rate: f32) {
         self.sample_rate = sample_rate;
         self.recalculate_coefficients();
+        self.set_rms_window_ms(self.window_ms);
     }
 
     /// Set the detection mode (Peak or RMS).
@@ -144,10 +205,64 @@ impl EnvelopeFollower {
     pub fn set_detection_mode(&mut self, mode: DetectionMode) {
         if self.detection_mode != mode {
             self.detection_mode = mode;
-            self.envelope = 0.0;
+            self.reset();
         }
     }
 
+    /// Set the sliding RMS window length in milliseconds.
+    ///
+    /// Only used in RMS mode. With a window, `x²` is averaged over the last
+    /// `window_ms` of input before attack/release smoothing; 0 turns the
+    /// window off (exponential RMS). Allocates the window buffer, so call
+    /// it outside the audio thread. Clears the window contents.
+    pub fn set_rms_window_ms(&mut self, window_ms: f32) {
+        self.window_ms = window_ms.max(0.0);
+        let len = (self.window_ms * self.sample_rate / 1000.0 + 0.5) as usize;
+        self.window = if self.window_ms > 0.0 {
+            vec![0.0; len.max(1)]
+        } else {
+            Vec::new()
+        };
+        self.window_pos = 0;
+        self.window_sum = 0.0;
+    }
+
+    /// Get the RMS window length in milliseconds (0 = off).
+    pub fn rms_window_ms(&self) -> f32 {
+        self.window_ms
+    }
+
+    /// Smooth the envelope in dB (`true`) or linear amplitude (`false`).
+    ///
+    /// In the log domain, attack and release are exponential in dB, so a
+    /// release falls at the same dB rate whatever the level. Switching
+    /// resets the envelope.
+    pub fn set_log_domain(&mut self, enabled: bool) {
+        if self.log_domain != enabled {
+            self.log_domain = enabled;
+            self.reset();
+        }
+    }
+
+    /// Whether smoothing runs in the log domain.
+    pub fn log_domain(&self) -> bool {
+        self.log_domain
+    }
+
+    /// Set the hold time in milliseconds.
+    ///
+    /// After the envelope stops rising it stays put for `hold_ms` before
+    /// release begins.
+    pub fn set_hold_ms(&mut self, hold_ms: f32) {
+        self.hold_ms = hold_ms.max(0.0);
+        self.recalculate_coefficients();
+    }
+
+    /// Get the hold time in milliseconds.
+    pub fn hold_ms(&self) -> f32 {
+        self.hold_ms
+    }
+
     /// Get the current detection mode.
     pub fn detection_mode(&self) -> DetectionMode {
         self.detection_mode
@@ -166,10 +281,57 @@ impl EnvelopeFollower {
             DetectionMode::Peak => input.abs(),
             DetectionMode::Rms => input * input,
         };
+        self.follow(detection)
+    }
+
+    /// Process a stereo pair through one linked detector.
+    ///
+    /// Peak mode follows `max(|left|, |right|)`; RMS mode follows the mean
+    /// power `(left² + right²) / 2`. Feeding both channels of a linked
+    /// compressor or gate through one follower keeps the image stable.
+    #[inline]
+    pub fn process_stereo(&mut self, left: f32, right: f32) -> f32 {
+        let detection = match self.detection_mode {
+            DetectionMode::Peak => left.abs().max(right.abs()),
+            DetectionMode::Rms => 0.5 * (left * left + right * right),
+        };
+        self.follow(detection)
+    }
+
+    /// Run the window, hold, and attack/release stages on a detection value
+    /// (`|x|` in Peak mode, `x²` in RMS mode).
+    #[inline]
+    fn follow(&mut self, detection: f32) -> f32 {
+        let mut detection = detection;
+        if self.detection_mode == DetectionMode::Rms && !self.window.is_empty() {
+            let len = self.window.len();
+            self.window_sum += detection - self.window[self.window_pos];
+            self.window[self.window_pos] = detection;
+            self.window_pos += 1;
+            if self.window_pos == len {
+                // Re-sum once per window so rounding errors cannot build up.
+                self.window_pos = 0;
+                self.window_sum = self.window.iter().sum();
+            }
+            detection = self.window_sum.max(0.0) / len as f32;
+        }
+
+        if self.log_domain {
+            // Amplitude in dB for both modes (10·log10(x²) = 20·log10|x|).
+            detection = match self.detection_mode {
+                DetectionMode::Peak => linear_to_db(detection),
+                DetectionMode::Rms => 0.5 * linear_to_db(detection),
+            }
+            .max(LOG_FLOOR_DB);
+        }
 
         // Choose attack or release based on whether signal is rising or falling
         let coeff = if detection > self.envelope {
+            self.hold_counter = self.hold_samples;
             self.attack_coeff
+        } else if self.hold_counter > 0 {
+            self.hold_counter -= 1;
+            1.0
         } else {
             self.release_coeff
         };
@@ -177,26 +339,35 @@ impl EnvelopeFollower {
         // Exponential smoothing: y[n] = coeff * y[n-1] + (1 - coeff) * x[n]
         self.envelope = coeff * self.envelope + (1.0 - coeff) * detection;
 
-        match self.detection_mode {
-            DetectionMode::Peak => self.envelope,
-            DetectionMode::Rms => sqrtf(self.envelope),
-        }
+        self.level()
     }
 
     /// Get current envelope level without processing new input.
     ///
     /// In RMS mode, returns `sqrt(internal_state)` to convert from
-    /// squared power domain to amplitude domain.
+    /// squared power domain to amplitude domain. In the log domain, converts
+    /// from dB, returning 0.0 at the floor.
     pub fn level(&self) -> f32 {
+        if self.log_domain {
+            return if self.envelope <= LOG_FLOOR_DB {
+                0.0
+            } else {
+                db_to_linear(self.envelope)
+            };
+        }
         match self.detection_mode {
             DetectionMode::Peak => self.envelope,
             DetectionMode::Rms => sqrtf(self.envelope),
         }
     }
 
-    /// Reset the envelope to zero.
+    /// Reset the envelope to zero and clear the RMS window and hold.
     pub fn reset(&mut self) {
-        self.envelope = 0.0;
+        self.envelope = if self.log_domain { LOG_FLOOR_DB } else { 0.0 };
+        self.window.iter_mut().for_each(|x| *x = 0.0);
+        self.window_pos = 0;
+        self.window_sum = 0.0;
+        self.hold_counter = 0;
     }
 
     fn recalculate_coefficients(&mut self) {
@@ -204,6 +375,7 @@ impl EnvelopeFollower {
         // coeff = exp(-1 / (time_ms * sample_rate / 1000))
         self.attack_coeff = expf(-1.0 / (self.attack_ms * self.sample_rate / 1000.0));
         self.release_coeff = expf(-1.0 / (self.release_ms * self.sample_rate / 1000.0));
+        self.hold_samples = (self.hold_ms * self.sample_rate / 1000.0) as u32;
     }
 }
 
@@ -358,4 +530,122 @@ mod tests {
         env.set_detection_mode(DetectionMode::Rms);
         assert_eq!(env.level(), 0.0, "Switching mode should reset envelope");
     }
+
+    #[test]
+    fn test_rms_window_is_exact() {
+        use super::DetectionMode;
+
+        let sr = 48000.0;
+        let mut env = EnvelopeFollower::new(sr);
+        env.set_detection_mode(DetectionMode::Rms);
+        env.set_attack_ms(0.1);
+        env.set_release_ms(1.0);
+        // 10 ms window holds exactly two cycles of a 200 Hz sine.
+        env.set_rms_window_ms(10.0);
+        assert_eq!(env.rms_window_ms(), 10.0);
+
+        let mut level = 0.0;
+        for i in 0..4800 {
+            level = env.process(libm::sinf(
+                i as f32 * 2.0 * core::f32::consts::PI * 200.0 / sr,
+            ));
+        }
+        assert!(
+            (level - core::f32::consts::FRAC_1_SQRT_2).abs() < 0.01,
+            "windowed RMS of a unit sine should be 0.707, got {level}"
+        );
+
+        // A window forgets what left it: silence for one window reads zero.
+        for _ in 0..480 + 960 {
+            level = env.process(0.0);
+        }
+        assert!(level < 1e-3, "window should empty, got {level}");
+
+        // Peak mode ignores the window.
+        env.set_detection_mode(DetectionMode::Peak);
+        assert!(env.process(-0.5) > 0.0);
+    }
+
+    #[test]
+    fn test_log_domain_release_is_linear_in_db() {
+        let sr = 48000.0;
+        let mut env = EnvelopeFollower::with_times(sr, 0.1, 50.0);
+        env.set_log_domain(true);
+        assert!(env.log_domain());
+        assert_eq!(env.level(), 0.0);
+
+        for _ in 0..2000 {
+            env.process(0.5);
+        }
+        assert!((env.level() - 0.5).abs() < 1e-3, "settles on input level");
+
+        // Release toward the −120 dB floor: each step covers the same
+        // fraction of the remaining distance in dB.
+        let db = |env: &EnvelopeFollower| linear_to_db(env.level());
+        let start = db(&env);
+        for _ in 0..2400 {
+            env.process(0.0);
+        }
+        let mid = db(&env);
+        for _ in 0..2400 {
+            env.process(0.0);
+        }
+        let end = db(&env);
+        let ratio = (end - LOG_FLOOR_DB) / (mid - LOG_FLOOR_DB);
+        let expected = (mid - LOG_FLOOR_DB) / (start - LOG_FLOOR_DB);
+        assert!((ratio - expected).abs() < 1e-3, "{start} -> {mid} -> {end}");
+    }
+
+    #[test]
+    fn test_hold_delays_release() {
+        let sr = 48000.0;
+        let mut held = EnvelopeFollower::with_times(sr, 0.1, 5.0);
+        held.set_hold_ms(20.0);
+        assert_eq!(held.hold_ms(), 20.0);
+        let mut free = EnvelopeFollower::with_times(sr, 0.1, 5.0);
+
+        for _ in 0..500 {
+            held.process(1.0);
+            free.process(1.0);
+        }
+        let peak = held.level();
+        // 10 ms of silence: inside the hold.
+        for _ in 0..480 {
+            held.process(0.0);
+            free.process(0.0);
+        }
+        assert_eq!(held.level(), peak, "held level should not move");
+        assert!(free.level() < 0.2 * peak);
+
+        // Past the hold, release proceeds.
+        for _ in 0..960 {
+            held.process(0.0);
+        }
+        assert!(held.level() < 0.5 * peak);
+    }
+
+    #[test]
+    fn test_stereo_linked_detection() {
+        use super::DetectionMode;
+
+        let mut peak = EnvelopeFollower::with_times(48000.0, 0.1, 100.0);
+        for _ in 0..1000 {
+            peak.process_stereo(0.2, -0.8);
+        }
+        assert!(
+            (peak.level() - 0.8).abs() < 1e-3,
+            "peak links on the louder side"
+        );
+
+        let mut rms = EnvelopeFollower::with_times(48000.0, 0.1, 100.0);
+        rms.set_detection_mode(DetectionMode::Rms);
+        for _ in 0..1000 {
+            rms.process_stereo(1.0, 0.0);
+        }
+        assert!(
+            (rms.level() - core::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3,
+            "RMS links on mean power, got {}",
+            rms.level()
+        );
+    }
 }
@@ -14,6 +14,7 @@
 //! | [`fast_linear_to_db`] | [`linear_to_db`](crate::linear_to_db) | Gain, level metering | < 0.05 dB |
 //! | [`fast_sin_turns`] | `libm::sinf` | LFO modulation | < 0.001 |
 //! | [`fast_cos_turns`] | `libm::cosf` | LFO modulation (phase-shifted) | < 0.001 |
+//! | [`fast_sin_turns4`] | `libm::sinf` ×4 | LFO banks (phases in `[0, 1)`) | < 0.001 |
 //! | [`fast_tan`] | `libm::tanf` | Filter coefficients | < 0.1% (f < sr/4) |
 //! | [`fast_tanh`] | `libm::tanhf` | Saturation, soft clip | < 0.024 abs (\|x\| < 3) |
 //!
@@ -39,6 +40,7 @@
 //!
 //! Reference: ARM Cortex-M7 Technical Reference Manual, FPU instruction timings.
 
+use crate::simd::F32x4;
 use libm::{floorf, tanf};
 
 /// Fast base-2 logarithm via IEEE 754 float decomposition.
@@ -230,6 +232,34 @@ pub fn fast_cos_turns(turns: f32) -> f32 {
     fast_sin_turns(turns + 0.25)
 }
 
+/// [`fast_sin_turns`] on the four lanes of an [`F32x4`].
+///
+/// Every lane must already be wrapped to `[0, 1)` — LFO phase accumulators
+/// are. Centring the phase on the half-cycle, `x = 2p − 1 ∈ [−1, 1)`, turns
+/// the sign split into an absolute value, so the whole approximation is
+/// branch-free: `sin(2πp) = −sin(πx) ≈ −(y + 0.225·y·(|y| − 1))` with
+/// `y = 4x(1 − |x|)`.
+///
+/// Lanes agree with [`fast_sin_turns`] to within float rounding.
+///
+/// # Examples
+///
+/// ```
+/// use sonido_core::F32x4;
+/// use sonido_core::fast_math::fast_sin_turns4;
+///
+/// let s = fast_sin_turns4(F32x4::new(0.0, 0.25, 0.5, 0.75)).to_array();
+/// assert!(s[0].abs() < 0.002 && (s[1] - 1.0).abs() < 0.002);
+/// assert!(s[2].abs() < 0.002 && (s[3] + 1.0).abs() < 0.002);
+/// ```
+#[inline]
+pub fn fast_sin_turns4(turns: F32x4) -> F32x4 {
+    let one = F32x4::splat(1.0);
+    let x = turns * F32x4::splat(2.0) - one;
+    let y = F32x4::splat(4.0) * x * (one - x.abs());
+    F32x4::ZERO - (F32x4::splat(0.225) * y * (y.abs() - one) + y)
+}
+
 /// Fast tangent for small positive angles.
 ///
 /// Uses a Padé \[2/1\] rational approximation:
@@ -536,6 +566,21 @@ mod tests {
         }
     }
 
+    // ---- fast_sin_turns4 ----
+
+    #[test]
+    fn sin4_matches_scalar() {
+        for i in 0..250 {
+            let base = i as f32 / 1000.0;
+            let turns = F32x4::new(base, base + 0.25, base + 0.5, base + 0.75);
+            let lanes = fast_sin_turns4(turns).to_array();
+            for (got, t) in lanes.into_iter().zip(turns.to_array()) {
+                let want = fast_sin_turns(t);
+                assert!((got - want).abs() < 1e-6, "turns={t}: {got} vs {want}");
+            }
+        }
+    }
+
     // ---- fast_tan ----
 
     #[test]
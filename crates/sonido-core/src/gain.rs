@@ -21,6 +21,20 @@
 //! The output level operates in linear gain internally (for multiplication in
 //! the audio path) but exposes a dB interface for parameter get/set. The range
 //! is [`OUTPUT_MIN_DB`] to [`OUTPUT_MAX_DB`] (−6 to +6 dB), clamped on set.
+//!
+//! # Input auto-trim
+//!
+//! [`AutoTrim`] handles the other end of the chain: it listens to the raw
+//! input for a few seconds and suggests an input gain that puts the observed
+//! peaks at a target level (default [`AUTO_TRIM_TARGET_DB`]). It also reports
+//! the window's peak and RMS levels, so a calibration UI can show the user what
+//! it heard before applying the suggestion.
+//!
+//! # Input routing
+//!
+//! [`InputRouting`] sits in front of both: it picks which interface channels
+//! feed the stereo chain, for interfaces that deliver a guitar on only one
+//! side.
 
 use crate::{ParamDescriptor, SmoothedParam, db_to_linear, linear_to_db};
 
@@ -57,6 +71,7 @@ pub fn output_level_db(param: &SmoothedParam) -> f32 {
 /// Use as the **last** `ParameterInfo` index for every effect.
 pub fn output_param_descriptor() -> ParamDescriptor {
     ParamDescriptor::gain_db("Output", "Out", OUTPUT_MIN_DB, OUTPUT_MAX_DB, 0.0)
+        .with_description("Output level after processing.")
 }
 
 /// Exact wet-signal compensation for feedback comb resonance.
@@ -85,6 +100,292 @@ pub fn feedback_wet_compensation(feedback: f32) -> f32 {
     (1.0 - feedback.clamp(0.0, 0.99)).max(0.01)
 }
 
+/// How the two input channels of an interface feed the stereo chain.
+///
+/// Guitar interfaces often put the instrument on one channel and leave the
+/// other silent (or carry a mic on it). Routing copies the chosen channel to
+/// both sides, or folds both into mono, before any gain staging. Each mode
+/// has a fixed [`compensation()`](Self::compensation) gain:
+///
+/// - [`Stereo`](InputRouting::Stereo) — channels pass through unchanged (the default).
+/// - [`Left`](InputRouting::Left) / [`Right`](InputRouting::Right) — one
+///   channel is copied to both sides at unity, so a one-sided input reaches
+///   the chain at its original level.
+/// - [`SumMono`](InputRouting::SumMono) — `(L + R)` scaled by −3 dB. A signal
+///   present on only one side loses 3 dB and a fully correlated pair gains
+///   3 dB, instead of the 6 dB swing a plain sum or average would give.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::gain::InputRouting;
+///
+/// let mut left = [0.5_f32; 4];
+/// let mut right = [0.0_f32; 4];
+/// InputRouting::Left.route_block(&mut left, &mut right);
+/// assert_eq!(right, [0.5; 4]);
+/// assert_eq!(InputRouting::from_label("mono"), Some(InputRouting::SumMono));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum InputRouting {
+    /// Left and right pass through unchanged.
+    #[default]
+    Stereo,
+    /// The left input feeds both sides.
+    Left,
+    /// The right input feeds both sides.
+    Right,
+    /// Both inputs are summed to mono and fed to both sides.
+    SumMono,
+}
+
+impl InputRouting {
+    /// All routing modes in menu order.
+    pub const ALL: [Self; 4] = [Self::Stereo, Self::Left, Self::Right, Self::SumMono];
+
+    /// Returns a short display label (`"Stereo"`, `"Left"`, `"Right"`, `"Mono"`).
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Stereo => "Stereo",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::SumMono => "Mono",
+        }
+    }
+
+    /// Parses a label produced by [`label()`](Self::label) (case-insensitive).
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|r| r.label().eq_ignore_ascii_case(label))
+    }
+
+    /// Position in [`ALL`](Self::ALL), for storing the mode in an atomic.
+    pub const fn index(self) -> u8 {
+        match self {
+            Self::Stereo => 0,
+            Self::Left => 1,
+            Self::Right => 2,
+            Self::SumMono => 3,
+        }
+    }
+
+    /// Inverse of [`index()`](Self::index); out-of-range values map to
+    /// [`Stereo`](Self::Stereo).
+    pub const fn from_index(index: u8) -> Self {
+        match index {
+            1 => Self::Left,
+            2 => Self::Right,
+            3 => Self::SumMono,
+            _ => Self::Stereo,
+        }
+    }
+
+    /// Linear gain applied to the routed signal.
+    pub const fn compensation(self) -> f32 {
+        match self {
+            Self::SumMono => core::f32::consts::FRAC_1_SQRT_2,
+            Self::Stereo | Self::Left | Self::Right => 1.0,
+        }
+    }
+
+    /// Route one stereo frame.
+    #[inline]
+    pub fn route(self, left: f32, right: f32) -> (f32, f32) {
+        match self {
+            Self::Stereo => (left, right),
+            Self::Left => (left, left),
+            Self::Right => (right, right),
+            Self::SumMono => {
+                let mono = (left + right) * self.compensation();
+                (mono, mono)
+            }
+        }
+    }
+
+    /// Route a stereo block in place, over the shorter buffer's length.
+    pub fn route_block(self, left: &mut [f32], right: &mut [f32]) {
+        if self == Self::Stereo {
+            return;
+        }
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            (*l, *r) = self.route(*l, *r);
+        }
+    }
+}
+
+/// Default peak target for [`AutoTrim`] in dBFS.
+///
+/// Leaves 12 dB of headroom for drive stages and transients the listening
+/// window did not catch.
+pub const AUTO_TRIM_TARGET_DB: f32 = -12.0;
+
+/// Default listening window for [`AutoTrim`] in seconds.
+pub const AUTO_TRIM_WINDOW_SECS: f32 = 3.0;
+
+/// Peaks below this level (dBFS) are treated as silence, not signal.
+const AUTO_TRIM_SILENCE_DB: f32 = -60.0;
+
+/// Input auto-trim: measures peaks over a window and suggests an input gain.
+///
+/// Feed the *pre-gain* input with [`process_block`](Self::process_block)
+/// after calling [`start`](Self::start). When the window has elapsed, the
+/// measurement stops and `process_block` returns the gain in dB that maps the
+/// observed peak to the target, clamped to the configured gain range. If the
+/// input stayed below −60 dBFS the measurement stops without a result, so an
+/// idle input never gets boosted to the maximum gain. The window's levels
+/// stay readable through [`peak_db`](Self::peak_db) and
+/// [`rms_db`](Self::rms_db) until the next [`start`](Self::start).
+///
+/// Allocation-free and safe to run on the audio thread.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::gain::AutoTrim;
+///
+/// let mut trim = AutoTrim::new(1000.0).with_window_secs(0.1);
+/// trim.start();
+///
+/// // Peaks at 0.5 (≈ −6 dBFS) need ≈ −6 dB to reach −12 dBFS.
+/// let block = [0.5_f32; 100];
+/// let gain_db = trim.process_block(&block, &block).unwrap();
+/// assert!((gain_db - (-6.0)).abs() < 0.1);
+/// assert!(!trim.is_active());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AutoTrim {
+    sample_rate: f32,
+    target_db: f32,
+    window_secs: f32,
+    min_gain_db: f32,
+    max_gain_db: f32,
+    /// Samples left in the current listening window (0 = idle).
+    remaining: usize,
+    /// Highest absolute sample seen in the current window (linear).
+    peak: f32,
+    /// Sum of squared samples (both channels) in the current window.
+    sum_squares: f64,
+    /// Frames measured in the current window.
+    frames: usize,
+}
+
+impl AutoTrim {
+    /// Create an idle auto-trim with the default target, window, and a
+    /// −20 to +20 dB gain range.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            target_db: AUTO_TRIM_TARGET_DB,
+            window_secs: AUTO_TRIM_WINDOW_SECS,
+            min_gain_db: -20.0,
+            max_gain_db: 20.0,
+            remaining: 0,
+            peak: 0.0,
+            sum_squares: 0.0,
+            frames: 0,
+        }
+    }
+
+    /// Set the peak target in dBFS.
+    pub fn with_target_db(mut self, target_db: f32) -> Self {
+        self.target_db = target_db;
+        self
+    }
+
+    /// Set the listening window length in seconds.
+    pub fn with_window_secs(mut self, secs: f32) -> Self {
+        self.window_secs = secs.max(0.0);
+        self
+    }
+
+    /// Set the range the suggested gain is clamped to, in dB.
+    pub fn with_gain_range(mut self, min_db: f32, max_db: f32) -> Self {
+        self.min_gain_db = min_db;
+        self.max_gain_db = max_db.max(min_db);
+        self
+    }
+
+    /// Peak target in dBFS.
+    pub fn target_db(&self) -> f32 {
+        self.target_db
+    }
+
+    /// Begin (or restart) a listening window.
+    pub fn start(&mut self) {
+        self.remaining = ((self.window_secs * self.sample_rate) as usize).max(1);
+        self.peak = 0.0;
+        self.sum_squares = 0.0;
+        self.frames = 0;
+    }
+
+    /// Abort the current listening window without producing a result.
+    pub fn cancel(&mut self) {
+        self.remaining = 0;
+    }
+
+    /// Whether a listening window is in progress.
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Fraction of the listening window elapsed, in `[0, 1]`.
+    ///
+    /// Returns 0.0 when idle.
+    pub fn progress(&self) -> f32 {
+        let total = ((self.window_secs * self.sample_rate) as usize).max(1);
+        if self.remaining == 0 {
+            0.0
+        } else {
+            1.0 - self.remaining as f32 / total as f32
+        }
+    }
+
+    /// Peak level of the current or most recent window in dBFS, over both
+    /// channels.
+    pub fn peak_db(&self) -> f32 {
+        linear_to_db(self.peak)
+    }
+
+    /// RMS level of the current or most recent window in dBFS, over both
+    /// channels.
+    pub fn rms_db(&self) -> f32 {
+        if self.frames == 0 {
+            return linear_to_db(0.0);
+        }
+        let mean_square = self.sum_squares / (2 * self.frames) as f64;
+        linear_to_db(libm::sqrt(mean_square) as f32)
+    }
+
+    /// Measure one block of pre-gain stereo input.
+    ///
+    /// Returns `Some(gain_db)` exactly once, on the block that completes the
+    /// window with signal present. Returns `None` while idle, while
+    /// listening, and when the window completes on silence.
+    pub fn process_block(&mut self, left: &[f32], right: &[f32]) -> Option<f32> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let frames = left.len().min(right.len()).min(self.remaining);
+        for (&l, &r) in left[..frames].iter().zip(&right[..frames]) {
+            self.peak = self.peak.max(l.abs()).max(r.abs());
+            self.sum_squares += f64::from(l * l + r * r);
+        }
+        self.frames += frames;
+        self.remaining -= frames;
+
+        if self.remaining > 0 {
+            return None;
+        }
+
+        let peak_db = linear_to_db(self.peak);
+        (peak_db > AUTO_TRIM_SILENCE_DB)
+            .then(|| (self.target_db - peak_db).clamp(self.min_gain_db, self.max_gain_db))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +459,124 @@ mod tests {
             assert!(feedback_wet_compensation(i as f32 / 100.0) > 0.0);
         }
     }
+
+    #[test]
+    fn input_routing_label_roundtrip() {
+        for routing in InputRouting::ALL {
+            assert_eq!(InputRouting::from_label(routing.label()), Some(routing));
+            assert_eq!(InputRouting::from_index(routing.index()), routing);
+        }
+        assert_eq!(InputRouting::from_label("RIGHT"), Some(InputRouting::Right));
+        assert_eq!(InputRouting::from_label("sides"), None);
+        assert_eq!(InputRouting::from_index(200), InputRouting::Stereo);
+    }
+
+    #[test]
+    fn input_routing_modes() {
+        assert_eq!(InputRouting::Stereo.route(0.25, -0.5), (0.25, -0.5));
+        assert_eq!(InputRouting::Left.route(0.25, -0.5), (0.25, 0.25));
+        assert_eq!(InputRouting::Right.route(0.25, -0.5), (-0.5, -0.5));
+
+        // A one-sided signal summed to mono drops by 3 dB on both sides.
+        let (l, r) = InputRouting::SumMono.route(1.0, 0.0);
+        assert_eq!(l, r);
+        assert!((linear_to_db(l) - (-3.01)).abs() < 0.01, "got {l}");
+    }
+
+    #[test]
+    fn input_routing_block_in_place() {
+        let mut left = [0.0_f32, 0.0, 0.0];
+        let mut right = [0.1_f32, 0.2, 0.3];
+        InputRouting::Right.route_block(&mut left, &mut right);
+        assert_eq!(left, right);
+        assert_eq!(left, [0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn auto_trim_idle_returns_none() {
+        let mut trim = AutoTrim::new(48000.0);
+        assert!(!trim.is_active());
+        assert_eq!(trim.process_block(&[1.0; 64], &[1.0; 64]), None);
+    }
+
+    #[test]
+    fn auto_trim_hits_target() {
+        let mut trim = AutoTrim::new(1000.0)
+            .with_window_secs(1.0)
+            .with_target_db(-12.0);
+        trim.start();
+
+        // Quiet signal with one louder peak on the right channel.
+        let left = [0.05_f32; 500];
+        let mut right = [0.05_f32; 500];
+        right[123] = -0.1;
+        assert_eq!(trim.process_block(&left, &right), None);
+        assert!(trim.is_active());
+        assert!((trim.progress() - 0.5).abs() < 1e-3);
+
+        let gain = trim.process_block(&left, &left).expect("window complete");
+        let expected = -12.0 - linear_to_db(0.1);
+        assert!(
+            (gain - expected).abs() < 0.01,
+            "got {gain}, want {expected}"
+        );
+        assert!(!trim.is_active());
+        assert_eq!(trim.process_block(&left, &left), None);
+    }
+
+    #[test]
+    fn auto_trim_reports_window_levels() {
+        let mut trim = AutoTrim::new(100.0).with_window_secs(1.0);
+        trim.start();
+        // Full-scale square wave on the left, silence on the right:
+        // peak 0 dBFS, mean square 0.5 → RMS ≈ -3 dBFS.
+        let left: [f32; 100] = core::array::from_fn(|i| if i % 2 == 0 { 1.0 } else { -1.0 });
+        let right = [0.0_f32; 100];
+        assert!(trim.process_block(&left, &right).is_some());
+        assert!(trim.peak_db().abs() < 0.01);
+        assert!((trim.rms_db() - linear_to_db(0.5_f32.sqrt())).abs() < 0.01);
+
+        // Levels survive until the next start.
+        assert!(trim.peak_db().abs() < 0.01);
+        trim.start();
+        assert!(trim.peak_db() < -150.0);
+        assert!(trim.rms_db() < -150.0);
+    }
+
+    #[test]
+    fn auto_trim_clamps_to_gain_range() {
+        let mut trim = AutoTrim::new(100.0)
+            .with_window_secs(1.0)
+            .with_gain_range(-6.0, 6.0);
+        trim.start();
+        let quiet = [0.01_f32; 100]; // -40 dBFS wants +28 dB
+        assert_eq!(trim.process_block(&quiet, &quiet), Some(6.0));
+
+        trim.start();
+        let hot = [1.0_f32; 100]; // 0 dBFS wants -12 dB
+        assert_eq!(trim.process_block(&hot, &hot), Some(-6.0));
+    }
+
+    #[test]
+    fn auto_trim_silence_yields_no_result() {
+        let mut trim = AutoTrim::new(100.0).with_window_secs(1.0);
+        trim.start();
+        let silent = [1e-5_f32; 100];
+        assert_eq!(trim.process_block(&silent, &silent), None);
+        assert!(!trim.is_active());
+    }
+
+    #[test]
+    fn auto_trim_cancel_and_restart() {
+        let mut trim = AutoTrim::new(100.0).with_window_secs(1.0);
+        trim.start();
+        trim.process_block(&[1.0; 50], &[1.0; 50]);
+        trim.cancel();
+        assert!(!trim.is_active());
+
+        // Restart clears the previous peak.
+        trim.start();
+        let gain = trim.process_block(&[0.25; 100], &[0.25; 100]).unwrap();
+        assert!((gain - (-12.0 - linear_to_db(0.25))).abs() < 0.01);
+    }
 }
@@ -232,6 +232,31 @@ impl CompensationDelay {
         self.right.fill(0.0);
         self.write_pos = 0;
     }
+
+    /// Changes the delay length, keeping the most recent input.
+    ///
+    /// The newest `min(old, new)` samples stay queued in order, so a longer
+    /// delay inserts silence and a shorter one drops the oldest samples, rather
+    /// than replaying stale data. Allocates; call from the control thread.
+    pub fn set_delay_samples(&mut self, delay_samples: usize) {
+        if delay_samples == self.delay_samples {
+            return;
+        }
+        let len = delay_samples.max(1);
+        let mut left = vec![0.0; len];
+        let mut right = vec![0.0; len];
+        let old = self.delay_samples;
+        // The newest sample is read last, from the end of the new line.
+        for k in 0..old.min(delay_samples) {
+            let src = (self.write_pos + old - 1 - k) % old;
+            left[delay_samples - 1 - k] = self.left[src];
+            right[delay_samples - 1 - k] = self.right[src];
+        }
+        self.left = left;
+        self.right = right;
+        self.write_pos = 0;
+        self.delay_samples = delay_samples;
+    }
 }
 
 #[cfg(test)]
@@ -471,4 +496,29 @@ mod tests {
         assert_eq!(left[4], 2.0);
         assert_eq!(left[5], 3.0);
     }
+
+    #[test]
+    fn compensation_delay_resize_keeps_newest_samples() {
+        let mut delay = CompensationDelay::new(3);
+        let mut left = [1.0f32, 2.0, 3.0, 4.0];
+        let mut right = left;
+        delay.process_block_inplace(&mut left, &mut right);
+        // Queued: 2, 3, 4.
+
+        delay.set_delay_samples(5);
+        let mut left = [0.0f32; 5];
+        let mut right = left;
+        delay.process_block_inplace(&mut left, &mut right);
+        assert_eq!(left, [0.0, 0.0, 2.0, 3.0, 4.0]);
+
+        let mut left = [7.0f32, 8.0, 9.0, 10.0, 11.0];
+        let mut right = left;
+        delay.process_block_inplace(&mut left, &mut right);
+        delay.set_delay_samples(2);
+        let mut left = [0.0f32; 2];
+        let mut right = left;
+        delay.process_block_inplace(&mut left, &mut right);
+        assert_eq!(left, [10.0, 11.0]);
+        assert_eq!(delay.delay_samples(), 2);
+    }
 }
@@ -27,11 +27,39 @@
 use alloc::{boxed::Box, string::String, vec, vec::Vec};
 
 use crate::EffectWithParams;
+use crate::panic::PanicFade;
 use crate::param_info::ParamDescriptor;
+use crate::safe_mode::SafeMode;
+use crate::safety_limiter::SafetyLimiter;
 use crate::tempo::TempoContext;
 
+use core::ops::{ControlFlow, Range};
+
+use super::rig::{Rig, RigMix, RigOutput};
 use super::stereo_samples::StereoSamples;
-use super::{GraphError, NodeId, ProcessingGraph};
+use super::transition::{Outgoing, Transition};
+use super::{BypassMode, GraphError, LatencyUpdate, NodeId, PdcMode, ProcessingGraph};
+
+/// Length of the ring-out rendered by [`GraphEngine::render_tail`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TailLength {
+    /// Exactly this many samples.
+    Fixed(usize),
+    /// Until the output stays at or below `threshold` (linear peak, either
+    /// channel) for `hold` samples, rendering at most `max` samples. The
+    /// quiet run at the end is trimmed.
+    ///
+    /// `hold` must exceed the longest gap between echoes (the longest delay
+    /// time in the chain), or rendering stops between repeats.
+    Auto {
+        /// Peak level that counts as silence.
+        threshold: f32,
+        /// Quiet samples that end the tail.
+        hold: usize,
+        /// Upper bound on the rendered tail.
+        max: usize,
+    },
+}
 
 /// Graph-based processing engine for DAG audio routing.
 ///
@@ -52,6 +80,25 @@ pub struct GraphEngine {
     scratch_left: Vec<f32>,
     /// Pre-allocated scratch buffer (right channel) for in-place processing.
     scratch_right: Vec<f32>,
+    /// Fade/reset state for [`panic()`](Self::panic).
+    panic: PanicFade,
+    /// Latency-compensated dry path for [`set_safe_mode()`](Self::set_safe_mode)
+    /// and [`set_global_mix()`](Self::set_global_mix).
+    safe: SafeMode,
+    /// Output protection after everything else (see
+    /// [`set_output_limiter()`](Self::set_output_limiter)).
+    limiter: SafetyLimiter,
+    /// Dual-rig output stage; `None` for a single graph.
+    rigs: Option<RigMix>,
+    /// Engine being crossfaded out after [`crossfade_from()`](Self::crossfade_from).
+    outgoing: Option<Outgoing>,
+    /// Per-slot outputs collected by file rendering while
+    /// [`capture_stems()`](Self::capture_stems) is on.
+    stems: Option<Vec<StereoSamples>>,
+    /// Trailing stem samples [`take_stems()`](Self::take_stems) leaves in
+    /// place while [`render_tail_blocks()`](Self::render_tail_blocks) holds
+    /// back the matching output.
+    stems_held: usize,
 }
 
 impl GraphEngine {
@@ -73,6 +120,13 @@ impl GraphEngine {
             output_node,
             scratch_left: vec![0.0; block_size],
             scratch_right: vec![0.0; block_size],
+            panic: PanicFade::new(sample_rate),
+            safe: SafeMode::new(sample_rate),
+            limiter: SafetyLimiter::new(sample_rate),
+            rigs: None,
+            outgoing: None,
+            stems: None,
+            stems_held: 0,
         }
     }
 
@@ -85,6 +139,7 @@ impl GraphEngine {
     /// [`from_chain()`](Self::from_chain) for linear chains.
     pub fn new(graph: ProcessingGraph) -> Self {
         let block_size = graph.block_size();
+        let sample_rate = graph.sample_rate();
         Self {
             graph,
             chain_order: Vec::new(),
@@ -93,6 +148,13 @@ impl GraphEngine {
             output_node: NodeId::sentinel(),
             scratch_left: vec![0.0; block_size],
             scratch_right: vec![0.0; block_size],
+            panic: PanicFade::new(sample_rate),
+            safe: SafeMode::new(sample_rate),
+            limiter: SafetyLimiter::new(sample_rate),
+            rigs: None,
+            outgoing: None,
+            stems: None,
+            stems_held: 0,
         }
     }
 
@@ -113,6 +175,7 @@ impl GraphEngine {
     ///   The manifest order becomes the slot index order for parameter access.
     pub fn new_dag(graph: ProcessingGraph, manifest: Vec<(NodeId, &'static str)>) -> Self {
         let block_size = graph.block_size();
+        let sample_rate = graph.sample_rate();
         let chain_order: Vec<NodeId> = manifest.iter().map(|(id, _)| *id).collect();
         let effect_ids: Vec<&'static str> = manifest.iter().map(|(_, name)| *name).collect();
         Self {
@@ -123,6 +186,13 @@ impl GraphEngine {
             output_node: NodeId::sentinel(),
             scratch_left: vec![0.0; block_size],
             scratch_right: vec![0.0; block_size],
+            panic: PanicFade::new(sample_rate),
+            safe: SafeMode::new(sample_rate),
+            limiter: SafetyLimiter::new(sample_rate),
+            rigs: None,
+            outgoing: None,
+            stems: None,
+            stems_held: 0,
         }
     }
 
@@ -161,9 +231,87 @@ impl GraphEngine {
             output_node,
             scratch_left: vec![0.0; block_size],
             scratch_right: vec![0.0; block_size],
+            panic: PanicFade::new(sample_rate),
+            safe: SafeMode::new(sample_rate),
+            limiter: SafetyLimiter::new(sample_rate),
+            rigs: None,
+            outgoing: None,
+            stems: None,
+            stems_held: 0,
         })
     }
 
+    /// Creates a dual-rig engine from a pre-compiled graph with two rigs.
+    ///
+    /// Input and output bus 0 carry rig A, bus 1 rig B (see the
+    /// [`rig`](super::rig) module). Both input buses receive the same audio;
+    /// the two output buses are combined by morph or split, see
+    /// [`set_rig_morph()`](Self::set_rig_morph) and
+    /// [`set_rig_output()`](Self::set_rig_output).
+    ///
+    /// `manifest` lists rig A's effect nodes first, then rig B's, starting at
+    /// slot `rig_b_start`. Otherwise behaves like [`new_dag()`](Self::new_dag).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::InvalidConnection`] if the graph has fewer than
+    /// two input or output buses.
+    pub fn new_dual(
+        graph: ProcessingGraph,
+        manifest: Vec<(NodeId, &'static str)>,
+        rig_b_start: usize,
+    ) -> Result<Self, GraphError> {
+        if graph.input_buses().len() < 2 || graph.output_buses().len() < 2 {
+            return Err(GraphError::InvalidConnection(String::from(
+                "a dual rig needs two input and two output buses",
+            )));
+        }
+        let rig_b_start = rig_b_start.min(manifest.len());
+        let mix = RigMix::new(rig_b_start, graph.sample_rate(), graph.block_size());
+        let mut engine = Self::new_dag(graph, manifest);
+        engine.rigs = Some(mix);
+        Ok(engine)
+    }
+
+    /// Creates a dual-rig engine from two linear chains fed from the same input.
+    ///
+    /// Each chain runs Input → E1 → ... → En → Output on its own named bus
+    /// pair (`"rig_a"`, `"rig_b"`). Slots are numbered through rig A, then rig B.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError`] if graph construction or compilation fails.
+    pub fn dual_from_chains(
+        rig_a: Vec<(Box<dyn EffectWithParams + Send>, &'static str)>,
+        rig_b: Vec<(Box<dyn EffectWithParams + Send>, &'static str)>,
+        sample_rate: f32,
+        block_size: usize,
+    ) -> Result<Self, GraphError> {
+        let mut graph = ProcessingGraph::new(sample_rate, block_size);
+        let inputs = [
+            graph.add_input_named("rig_a"),
+            graph.add_input_named("rig_b"),
+        ];
+        let outputs = [
+            graph.add_output_named("rig_a"),
+            graph.add_output_named("rig_b"),
+        ];
+        let rig_b_start = rig_a.len();
+        let mut manifest = Vec::with_capacity(rig_a.len() + rig_b.len());
+        for ((chain, input), output) in [rig_a, rig_b].into_iter().zip(inputs).zip(outputs) {
+            let mut prev = input;
+            for (effect, id) in chain {
+                let node = graph.add_effect(effect);
+                graph.connect(prev, node)?;
+                manifest.push((node, id));
+                prev = node;
+            }
+            graph.connect(prev, output)?;
+        }
+        graph.compile()?;
+        Self::new_dual(graph, manifest, rig_b_start)
+    }
+
     // --- Chain management ---
 
     /// Appends an effect to the end of the linear chain.
@@ -438,6 +586,52 @@ impl GraphEngine {
         self.graph.effect_with_params_mut(node_id)
     }
 
+    /// Swaps the effect at a slot for `effect`, letting the previous instance
+    /// ring out as a spillover tail (see
+    /// [`ProcessingGraph::replace_effect`]).
+    ///
+    /// `effect` should be the same type as the slot's current effect: the
+    /// manifest ID is unchanged and the graph is not recompiled. Returns
+    /// `false` if `slot` is out of bounds.
+    pub fn replace_at(&mut self, slot: usize, effect: Box<dyn EffectWithParams + Send>) -> bool {
+        let Some(&node_id) = self.chain_order.get(slot) else {
+            return false;
+        };
+        self.graph.replace_effect(node_id, effect).is_ok()
+    }
+
+    /// Moves running effect instances from `old` into this engine.
+    ///
+    /// Each `(old_slot, new_slot)` pair whose effect IDs match moves `old`'s
+    /// instance, with its internal state (delay lines, reverb tails, envelope
+    /// followers), into `new_slot` in place of the fresh instance this engine
+    /// was built with; that instance is dropped and the old slot becomes a
+    /// passthrough. Pairs that are out of range or name different effects are
+    /// skipped. Both engines must run at the same sample rate.
+    ///
+    /// Used when a rebuilt topology replaces a running engine, so nodes that
+    /// survived the edit keep sounding. Returns the number of instances moved.
+    pub fn adopt_effects(&mut self, old: &mut GraphEngine, carry: &[(usize, usize)]) -> usize {
+        let mut adopted = 0;
+        for &(from, to) in carry {
+            let (Some(&old_id), Some(&new_id)) =
+                (old.chain_order.get(from), self.chain_order.get(to))
+            else {
+                continue;
+            };
+            if old.effect_ids.get(from) != self.effect_ids.get(to) {
+                continue;
+            }
+            let Some(running) = old.graph.take_effect(old_id) else {
+                continue;
+            };
+            if self.graph.swap_effect(new_id, running).is_ok() {
+                adopted += 1;
+            }
+        }
+        adopted
+    }
+
     /// Sets the bypass state for an effect at a slot.
     pub fn set_bypass_at(&mut self, slot: usize, bypassed: bool) {
         if let Some(&node_id) = self.chain_order.get(slot) {
@@ -454,6 +648,44 @@ impl GraphEngine {
             .is_some_and(|&id| self.graph.is_bypassed(id))
     }
 
+    /// Sets the [`BypassMode`] for an effect at a slot.
+    pub fn set_bypass_mode_at(&mut self, slot: usize, mode: BypassMode) {
+        if let Some(&node_id) = self.chain_order.get(slot) {
+            self.graph.set_bypass_mode(node_id, mode);
+        }
+    }
+
+    /// Returns the [`BypassMode`] of the effect at a slot.
+    ///
+    /// Returns [`BypassMode::Dry`] if the slot is out of bounds.
+    pub fn bypass_mode_at(&self, slot: usize) -> BypassMode {
+        self.chain_order
+            .get(slot)
+            .map_or(BypassMode::Dry, |&id| self.graph.bypass_mode(id))
+    }
+
+    /// Solos the effect at a slot, replacing the output with that point of the
+    /// chain. `None` returns to the normal output.
+    ///
+    /// See [`ProcessingGraph::set_solo`]. Returns `false` if `slot` is out of
+    /// bounds.
+    pub fn set_solo_at(&mut self, slot: Option<usize>) -> bool {
+        let node_id = match slot {
+            Some(slot) => match self.chain_order.get(slot) {
+                Some(&id) => Some(id),
+                None => return false,
+            },
+            None => None,
+        };
+        self.graph.set_solo(node_id).is_ok()
+    }
+
+    /// Returns the soloed slot, if any.
+    pub fn solo_slot(&self) -> Option<usize> {
+        let solo = self.graph.solo()?;
+        self.chain_order.iter().position(|&id| id == solo)
+    }
+
     /// Captures the current chain state as a [`GraphSnapshot`].
     ///
     /// Each entry contains the effect ID, all parameter values, and bypass state.
@@ -488,6 +720,41 @@ impl GraphEngine {
         }
     }
 
+    /// Serializes the full graph to JSON, using the slot manifest for effect IDs.
+    ///
+    /// Unlike [`snapshot()`](Self::snapshot), this captures the DAG itself
+    /// (splits, merges, feedback and sidechain routing) and bypass modes.
+    /// Load with [`ProcessingGraph::from_snapshot_json()`] and pass the returned
+    /// effect list to [`new_dag()`](Self::new_dag).
+    ///
+    /// # Errors
+    ///
+    /// See [`ProcessingGraph::to_snapshot_json()`]; effect nodes missing from
+    /// the manifest (e.g. an engine built with [`new()`](Self::new)) yield
+    /// [`GraphJsonError::MissingEffectId`](super::GraphJsonError::MissingEffectId).
+    #[cfg(feature = "json")]
+    pub fn to_snapshot_json(&self) -> Result<String, super::GraphJsonError> {
+        self.graph.to_snapshot_json(|id| {
+            self.chain_order
+                .iter()
+                .position(|&n| n == id)
+                .map(|slot| self.effect_ids[slot])
+        })
+    }
+
+    /// Renders the graph and its compiled schedule as GraphViz DOT, naming
+    /// effect nodes by their registry IDs from the slot manifest.
+    ///
+    /// See [`ProcessingGraph::to_dot()`].
+    pub fn to_dot(&self) -> String {
+        self.graph.to_dot(|id| {
+            self.chain_order
+                .iter()
+                .position(|&n| n == id)
+                .map(|slot| self.effect_ids[slot])
+        })
+    }
+
     // --- Parameter / effect access (NodeId-based) ---
 
     /// Returns a mutable reference to an effect's [`EffectWithParams`] interface.
@@ -549,11 +816,130 @@ impl GraphEngine {
     /// Sets the sample rate for all effect nodes.
     pub fn set_sample_rate(&mut self, sample_rate: f32) {
         self.graph.set_sample_rate(sample_rate);
+        self.panic.set_sample_rate(sample_rate);
+        self.safe.set_sample_rate(sample_rate);
+        self.limiter.set_sample_rate(sample_rate);
+        if let Some(mix) = &mut self.rigs {
+            mix.set_sample_rate(sample_rate);
+        }
     }
 
-    /// Resets all effect nodes and clears delay lines.
+    /// Resets all effect nodes and clears delay lines. Drops an engine being
+    /// crossfaded out.
     pub fn reset(&mut self) {
         self.graph.reset();
+        self.outgoing = None;
+    }
+
+    /// Panic: fades the output to silence, [`reset()`](Self::reset)s every
+    /// effect, then fades back in.
+    ///
+    /// Real-time safe — call from the audio thread. The fade and reset run
+    /// over the following real-time `process_block*` calls (not the
+    /// `process_file*` methods), each ramp lasting
+    /// [`PANIC_FADE_MS`](crate::panic::PANIC_FADE_MS).
+    pub fn panic(&mut self) {
+        self.panic.trigger();
+    }
+
+    /// Whether a [`panic()`](Self::panic) fade is in progress.
+    pub fn is_panicking(&self) -> bool {
+        self.panic.is_active()
+    }
+
+    /// Safe mode: crossfades the output to the dry input, delayed by the
+    /// graph latency, and stops processing the graph until released.
+    ///
+    /// Once the output is fully dry, every effect with a tail (delays,
+    /// reverbs, loopers: anything that can feed back) is reset and an engine
+    /// being crossfaded out is dropped, so releasing safe mode fades the
+    /// graph back in from silence rather than from the runaway. Real-time
+    /// safe; fades run over the following `process_block*` calls (or file
+    /// rendering), each lasting
+    /// [`SAFE_MODE_FADE_MS`](crate::safe_mode::SAFE_MODE_FADE_MS).
+    pub fn set_safe_mode(&mut self, engaged: bool) {
+        self.safe.set_engaged(engaged);
+    }
+
+    /// Whether safe mode is engaged.
+    pub fn is_safe_mode(&self) -> bool {
+        self.safe.is_engaged()
+    }
+
+    /// Sets the global dry/wet mix, from `0.0` (dry) to `1.0` (processed, the
+    /// default).
+    ///
+    /// The dry signal is the safe-mode dry path, delayed by the graph
+    /// latency, so the blend stays phase-coherent with lookahead or
+    /// oversampled effects in the graph. Changes are smoothed over
+    /// [`MIX_SMOOTHING_MS`](crate::safe_mode::MIX_SMOOTHING_MS). Real-time safe.
+    pub fn set_global_mix(&mut self, mix: f32) {
+        self.safe.set_mix(mix);
+    }
+
+    /// Sets the global dry/wet mix without smoothing, e.g. before rendering
+    /// a file.
+    pub fn set_global_mix_immediate(&mut self, mix: f32) {
+        self.safe.set_mix_immediate(mix);
+    }
+
+    /// The global dry/wet mix.
+    pub fn global_mix(&self) -> f32 {
+        self.safe.mix()
+    }
+
+    /// Enables the output protection limiter: a zero-latency brickwall at
+    /// the [ceiling](Self::set_limiter_ceiling_db), applied after the graph,
+    /// an outgoing engine, and safe mode. It is a system stage, not a graph
+    /// node, so no topology or preset can remove it. Off by default; runs in
+    /// the real-time `process_block*` calls and in file rendering alike.
+    pub fn set_output_limiter(&mut self, enabled: bool) {
+        self.limiter.set_enabled(enabled);
+    }
+
+    /// Whether the output protection limiter is enabled.
+    pub fn output_limiter(&self) -> bool {
+        self.limiter.is_enabled()
+    }
+
+    /// Sets the output limiter ceiling in dBFS (see [`SafetyLimiter::set_ceiling_db`]).
+    pub fn set_limiter_ceiling_db(&mut self, ceiling_db: f32) {
+        self.limiter.set_ceiling_db(ceiling_db);
+    }
+
+    /// The output limiter ceiling in dBFS.
+    pub fn limiter_ceiling_db(&self) -> f32 {
+        self.limiter.ceiling_db()
+    }
+
+    /// Peak output limiter gain reduction in dB since the last call, for a
+    /// limiting indicator.
+    pub fn take_limiter_reduction_db(&mut self) -> f32 {
+        self.limiter.take_reduction_db()
+    }
+
+    /// Performs the pending panic reset or safe-mode mute, and keeps the
+    /// safe-mode dry path aligned with the graph latency.
+    fn begin_block(&mut self) {
+        if self.panic.take_reset() {
+            self.reset();
+        }
+        if self.safe.take_mute() {
+            self.mute_feedback_effects();
+        }
+        self.safe.set_latency(self.graph.latency_samples());
+    }
+
+    /// Resets every effect with a tail and drops an outgoing engine.
+    fn mute_feedback_effects(&mut self) {
+        for slot in 0..self.chain_order.len() {
+            if let Some(effect) = self.effect_at_mut(slot)
+                && effect.tail_samples() > 0
+            {
+                effect.reset();
+            }
+        }
+        self.outgoing = None;
     }
 
     /// Returns the total graph latency in samples.
@@ -561,11 +947,139 @@ impl GraphEngine {
         self.graph.latency_samples()
     }
 
+    /// Sets the latency compensation mode. Wraps [`ProcessingGraph::set_pdc_mode`].
+    pub fn set_pdc_mode(&mut self, mode: PdcMode) {
+        self.graph.set_pdc_mode(mode);
+    }
+
+    /// Returns the latency compensation mode.
+    pub fn pdc_mode(&self) -> PdcMode {
+        self.graph.pdc_mode()
+    }
+
+    /// Brings latency compensation up to date after a latency-affecting
+    /// parameter change. Wraps [`ProcessingGraph::update_latency`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError`] if the graph fails to recompile.
+    pub fn update_latency(&mut self) -> Result<LatencyUpdate, GraphError> {
+        self.graph.update_latency()
+    }
+
     /// Broadcasts a tempo context to all effect nodes.
     pub fn set_tempo_context(&mut self, ctx: &TempoContext) {
         self.graph.set_tempo_context(ctx);
     }
 
+    // --- Dual rigs ---
+
+    /// Whether this engine runs two rigs (see [`new_dual()`](Self::new_dual)).
+    pub fn is_dual(&self) -> bool {
+        self.rigs.is_some()
+    }
+
+    /// Slot range belonging to `rig`.
+    ///
+    /// A single-graph engine is all rig A; rig B's range is empty.
+    pub fn rig_slots(&self, rig: Rig) -> Range<usize> {
+        let split = self
+            .rigs
+            .as_ref()
+            .map_or(self.chain_order.len(), |mix| mix.b_start);
+        match rig {
+            Rig::A => 0..split,
+            Rig::B => split..self.chain_order.len(),
+        }
+    }
+
+    /// Sets the A↔B morph (`0.0` = rig A, `1.0` = rig B), smoothed over
+    /// [`RIG_MORPH_MS`](super::rig::RIG_MORPH_MS). Ignored for a single graph.
+    pub fn set_rig_morph(&mut self, morph: f32) {
+        if let Some(mix) = &mut self.rigs {
+            mix.set_morph(morph);
+        }
+    }
+
+    /// Sets the A↔B morph without smoothing, e.g. when a rebuilt engine takes
+    /// over from a running one. Ignored for a single graph.
+    pub fn set_rig_morph_immediate(&mut self, morph: f32) {
+        if let Some(mix) = &mut self.rigs {
+            mix.set_morph_immediate(morph);
+        }
+    }
+
+    /// Returns the morph target (`0.0` for a single graph).
+    pub fn rig_morph(&self) -> f32 {
+        self.rigs.as_ref().map_or(0.0, RigMix::morph)
+    }
+
+    /// The rig currently dominating the output.
+    pub fn active_rig(&self) -> Rig {
+        Rig::from_morph(self.rig_morph())
+    }
+
+    /// Footswitch toggle: crossfades to the other rig and returns it.
+    ///
+    /// From a part-way morph this goes to whichever end is farther away.
+    pub fn toggle_rig(&mut self) -> Rig {
+        let next = self.active_rig().other();
+        self.set_rig_morph(next.morph());
+        self.active_rig()
+    }
+
+    /// Sets how the two rigs are combined. Ignored for a single graph.
+    pub fn set_rig_output(&mut self, output: RigOutput) {
+        if let Some(mix) = &mut self.rigs {
+            mix.set_output(output);
+        }
+    }
+
+    /// Returns how the two rigs are combined.
+    pub fn rig_output(&self) -> RigOutput {
+        self.rigs.as_ref().map_or(RigOutput::Blend, RigMix::output)
+    }
+
+    // --- Preset transitions ---
+
+    /// Takes over from `old`, the engine this one replaces, with a crossfade.
+    ///
+    /// `old` keeps running on the same input for `transition.time_ms` while
+    /// this engine fades in; with [`TailMode::RingOut`](super::TailMode::RingOut)
+    /// it then keeps ringing out until its longest effect tail has passed.
+    /// Crossfades run in the real-time `process_block*` calls and in file
+    /// rendering alike; only the [panic](Self::panic) fade is live-only. If
+    /// `old` was itself mid-transition, its own outgoing engine finishes
+    /// inside it.
+    ///
+    /// Allocates the crossfade scratch buffers (one block per channel).
+    pub fn crossfade_from(&mut self, old: Box<GraphEngine>, transition: Transition) {
+        let block_size = self.graph.block_size();
+        self.outgoing = Some(Outgoing::new(old, transition, block_size));
+    }
+
+    /// Whether an outgoing engine from [`crossfade_from()`](Self::crossfade_from)
+    /// is still sounding.
+    pub fn is_transitioning(&self) -> bool {
+        self.outgoing.is_some()
+    }
+
+    /// Mixes the outgoing engine (if any) into a rendered block, dropping it
+    /// once it falls silent.
+    fn mix_outgoing(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        if let Some(old) = &mut self.outgoing
+            && !old.mix_into(left_in, right_in, left_out, right_out)
+        {
+            self.outgoing = None;
+        }
+    }
+
     // --- Processing ---
 
     /// Processes a block of stereo audio through the graph.
@@ -578,8 +1092,13 @@ impl GraphEngine {
         left_out: &mut [f32],
         right_out: &mut [f32],
     ) {
-        self.graph
-            .process_block(left_in, right_in, left_out, right_out);
+        let len = left_in.len();
+        self.process_live(
+            left_in,
+            right_in,
+            &mut left_out[..len],
+            &mut right_out[..len],
+        );
     }
 
     /// Processes a block of mono audio through the graph.
@@ -588,42 +1107,147 @@ impl GraphEngine {
     pub fn process_block(&mut self, input: &[f32], output: &mut [f32]) {
         let len = input.len();
         debug_assert!(output.len() >= len);
-        self.scratch_right.resize(len, 0.0);
-        self.graph.process_block(
-            input,
-            input,
-            &mut output[..len],
-            &mut self.scratch_right[..len],
-        );
+        let mut right = core::mem::take(&mut self.scratch_right);
+        right.resize(len, 0.0);
+        self.process_live(input, input, &mut output[..len], &mut right[..len]);
+        self.scratch_right = right;
     }
 
     /// Processes a block of mono audio in-place.
     pub fn process_block_inplace(&mut self, buffer: &mut [f32]) {
         let len = buffer.len();
-        self.scratch_left.resize(len, 0.0);
-        self.scratch_right.resize(len, 0.0);
-        self.scratch_left[..len].copy_from_slice(&buffer[..len]);
-        self.graph.process_block(
-            &self.scratch_left[..len],
-            &self.scratch_left[..len],
-            buffer,
-            &mut self.scratch_right[..len],
-        );
+        let mut input = core::mem::take(&mut self.scratch_left);
+        let mut right = core::mem::take(&mut self.scratch_right);
+        input.resize(len, 0.0);
+        right.resize(len, 0.0);
+        input[..len].copy_from_slice(buffer);
+        self.process_live(&input[..len], &input[..len], buffer, &mut right[..len]);
+        self.scratch_left = input;
+        self.scratch_right = right;
     }
 
     /// Processes a block of stereo audio in-place.
     pub fn process_block_stereo_inplace(&mut self, left: &mut [f32], right: &mut [f32]) {
         let len = left.len();
-        self.scratch_left.resize(len, 0.0);
-        self.scratch_right.resize(len, 0.0);
-        self.scratch_left[..len].copy_from_slice(&left[..len]);
-        self.scratch_right[..len].copy_from_slice(&right[..len]);
-        self.graph.process_block(
-            &self.scratch_left[..len],
-            &self.scratch_right[..len],
+        let mut left_in = core::mem::take(&mut self.scratch_left);
+        let mut right_in = core::mem::take(&mut self.scratch_right);
+        left_in.resize(len, 0.0);
+        right_in.resize(len, 0.0);
+        left_in[..len].copy_from_slice(&left[..len]);
+        right_in[..len].copy_from_slice(&right[..len]);
+        self.process_live(
+            &left_in[..len],
+            &right_in[..len],
             &mut left[..len],
             &mut right[..len],
         );
+        self.scratch_left = left_in;
+        self.scratch_right = right_in;
+    }
+
+    /// One real-time block: every [file rendering](Self::process_stages)
+    /// stage, then the panic fade.
+    fn process_live(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        self.process_stages(left_in, right_in, left_out, right_out);
+        self.panic.apply(left_out, right_out);
+    }
+
+    /// One block through the graph (skipped while safe mode is fully dry),
+    /// an outgoing engine, the safe-mode dry path and global mix, and the
+    /// output limiter.
+    /// File rendering runs these stages alone.
+    fn process_stages(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        self.begin_block();
+        if !self.safe.is_dry() {
+            render(
+                &mut self.graph,
+                &mut self.rigs,
+                left_in,
+                right_in,
+                left_out,
+                right_out,
+            );
+            self.mix_outgoing(left_in, right_in, left_out, right_out);
+        }
+        self.safe.apply(left_in, right_in, left_out, right_out);
+        self.limiter.process(left_out, right_out);
+    }
+
+    /// Starts or stops collecting every slot's output during file rendering.
+    ///
+    /// While on, [`process_file_stereo()`](Self::process_file_stereo) and
+    /// [`render_tail()`](Self::render_tail) append each effect slot's output
+    /// (its [tap](ProcessingGraph::set_tap), right after the effect) to a
+    /// stem collected with [`take_stems()`](Self::take_stems). In a linear
+    /// chain, slot `n`'s stem is what slot `n + 1` hears; in a DAG, the last
+    /// slot of a parallel branch is that branch's contribution to the merge.
+    /// Only the primary graph is tapped, not dual-rig graphs.
+    pub fn capture_stems(&mut self, enabled: bool) {
+        let solo = self.graph.solo();
+        for &node in &self.chain_order {
+            if enabled || solo != Some(node) {
+                self.graph.set_tap(node, enabled);
+            }
+        }
+        self.stems = enabled.then(Vec::new);
+    }
+
+    /// Returns the stems collected since capture started or the last call,
+    /// one per slot in slot order. Empty when capture is off.
+    pub fn take_stems(&mut self) -> Vec<StereoSamples> {
+        let held = self.stems_held;
+        let Some(stems) = &mut self.stems else {
+            return Vec::new();
+        };
+        if held == 0 {
+            return core::mem::take(stems);
+        }
+        stems
+            .iter_mut()
+            .map(|stem| {
+                let len = stem.len().saturating_sub(held);
+                StereoSamples::new(
+                    stem.left.drain(..len).collect(),
+                    stem.right.drain(..len).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Appends the first `len` samples of each slot's tap to the stems.
+    fn collect_stems(&mut self, len: usize) {
+        let Some(stems) = &mut self.stems else {
+            return;
+        };
+        // Slots added since the last block start at the current position.
+        let start = stems.first().map_or(0, StereoSamples::len);
+        stems.resize_with(self.chain_order.len(), || {
+            StereoSamples::new(vec![0.0; start], vec![0.0; start])
+        });
+        for (stem, &node) in stems.iter_mut().zip(&self.chain_order) {
+            match self.graph.read_tap(node) {
+                Some((left, right)) if left.len() >= len => {
+                    stem.left.extend_from_slice(&left[..len]);
+                    stem.right.extend_from_slice(&right[..len]);
+                }
+                _ => {
+                    stem.left.resize(stem.left.len() + len, 0.0);
+                    stem.right.resize(stem.right.len() + len, 0.0);
+                }
+            }
+        }
     }
 
     /// Processes an entire mono file through the graph.
@@ -637,7 +1261,7 @@ impl GraphEngine {
         for i in (0..len).step_by(block_size) {
             let chunk_len = block_size.min(len - i);
             let end = i + chunk_len;
-            self.graph.process_block(
+            self.process_stages(
                 &input[i..end],
                 &input[i..end],
                 &mut output[i..end],
@@ -657,20 +1281,147 @@ impl GraphEngine {
         let len = input.len();
         let mut left_out = vec![0.0; len];
         let mut right_out = vec![0.0; len];
+        self.process_file_chunk(
+            &input.left,
+            &input.right,
+            &mut left_out,
+            &mut right_out,
+            block_size,
+        );
+        StereoSamples::new(left_out, right_out)
+    }
 
+    /// Processes the next stretch of a file that is streamed through the
+    /// graph, in blocks of at most `block_size` samples.
+    ///
+    /// Consecutive calls render the same as one
+    /// [`process_file_stereo()`](Self::process_file_stereo) over the whole
+    /// file, so callers can read, process, and write a file a chunk at a
+    /// time. Stems are collected as usual. Output buffers must be at least as
+    /// large as input buffers.
+    pub fn process_file_chunk(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+        block_size: usize,
+    ) {
+        let block_size = block_size.max(1);
+        let len = left_in.len();
         for i in (0..len).step_by(block_size) {
             let chunk_len = block_size.min(len - i);
             let end = i + chunk_len;
 
-            self.graph.process_block(
-                &input.left[i..end],
-                &input.right[i..end],
+            self.process_stages(
+                &left_in[i..end],
+                &right_in[i..end],
                 &mut left_out[i..end],
                 &mut right_out[i..end],
             );
+            self.collect_stems(chunk_len);
         }
+    }
 
-        StereoSamples::new(left_out, right_out)
+    /// Renders the ring-out after a file: feeds silence and returns what
+    /// reverbs and delays still produce.
+    ///
+    /// Call after [`process_file_stereo()`](Self::process_file_stereo) and
+    /// append the result so the rendered file includes the full decay. Holds
+    /// the whole tail in memory; [`render_tail_blocks()`](Self::render_tail_blocks)
+    /// streams it instead.
+    pub fn render_tail(&mut self, length: TailLength, block_size: usize) -> StereoSamples {
+        let mut tail = StereoSamples::new(Vec::new(), Vec::new());
+        self.render_tail_blocks(length, block_size, |_, left, right| {
+            tail.left.extend_from_slice(left);
+            tail.right.extend_from_slice(right);
+            ControlFlow::Continue(())
+        });
+        tail
+    }
+
+    /// Renders the ring-out after a file a block at a time, passing each
+    /// block to `on_block` as it is ready, and returns the number of tail
+    /// samples passed on. `on_block` can stop rendering early by returning
+    /// [`ControlFlow::Break`].
+    ///
+    /// With [`TailLength::Auto`], samples after the last audible one are held
+    /// back until something audible follows, so the trimmed quiet run is
+    /// never passed on and memory stays bounded by `hold` plus one block.
+    /// Stems are held back to match: inside `on_block`,
+    /// [`take_stems()`](Self::take_stems) returns exactly the stems for the
+    /// blocks passed on so far.
+    pub fn render_tail_blocks<F>(
+        &mut self,
+        length: TailLength,
+        block_size: usize,
+        mut on_block: F,
+    ) -> usize
+    where
+        F: FnMut(&mut GraphEngine, &[f32], &[f32]) -> ControlFlow<()>,
+    {
+        let block_size = block_size.max(1);
+        let silence = vec![0.0; block_size];
+        let (limit, auto) = match length {
+            TailLength::Fixed(samples) => (samples, None),
+            TailLength::Auto {
+                threshold,
+                hold,
+                max,
+            } => (max, Some((threshold, hold))),
+        };
+
+        // Rendered samples not passed on yet: the quiet run after the last
+        // audible sample, then the latest block.
+        let mut left = Vec::with_capacity(block_size);
+        let mut right = Vec::with_capacity(block_size);
+        let mut rendered = 0;
+        let mut passed = 0;
+        while rendered < limit {
+            let start = left.len();
+            let chunk_len = block_size.min(limit - rendered);
+            left.resize(start + chunk_len, 0.0);
+            right.resize(start + chunk_len, 0.0);
+            self.process_stages(
+                &silence[..chunk_len],
+                &silence[..chunk_len],
+                &mut left[start..],
+                &mut right[start..],
+            );
+            self.collect_stems(chunk_len);
+            rendered += chunk_len;
+
+            // Samples up to and including the last one above the threshold
+            let ready = match auto {
+                None => left.len(),
+                Some((threshold, _)) => (start..left.len())
+                    .rev()
+                    .find(|&i| left[i].abs() > threshold || right[i].abs() > threshold)
+                    .map_or(0, |last| last + 1),
+            };
+            if ready > 0 {
+                self.stems_held = left.len() - ready;
+                let flow = on_block(self, &left[..ready], &right[..ready]);
+                self.stems_held = 0;
+                passed += ready;
+                left.drain(..ready);
+                right.drain(..ready);
+                if flow.is_break() {
+                    break;
+                }
+            }
+            if auto.is_some_and(|(_, hold)| left.len() >= hold) {
+                break;
+            }
+        }
+
+        // Drop the stems of the trimmed quiet run.
+        for stem in self.stems.iter_mut().flatten() {
+            let len = stem.len().saturating_sub(left.len());
+            stem.left.truncate(len);
+            stem.right.truncate(len);
+        }
+        passed
     }
 
     // --- Internal helpers ---
@@ -683,6 +1434,33 @@ impl GraphEngine {
     }
 }
 
+/// Runs one block through the graph, mixing both rigs for a dual-rig engine.
+///
+/// A free function so callers can pass slices borrowed from other engine fields.
+fn render(
+    graph: &mut ProcessingGraph,
+    rigs: &mut Option<RigMix>,
+    left_in: &[f32],
+    right_in: &[f32],
+    left_out: &mut [f32],
+    right_out: &mut [f32],
+) {
+    let Some(mix) = rigs else {
+        graph.process_block(left_in, right_in, left_out, right_out);
+        return;
+    };
+    let len = left_in.len();
+    mix.ensure_len(len);
+    graph.process_block_buses(
+        &[(left_in, right_in), (left_in, right_in)],
+        &mut [
+            (&mut mix.a_left[..len], &mut mix.a_right[..len]),
+            (&mut mix.b_left[..len], &mut mix.b_right[..len]),
+        ],
+    );
+    mix.mix(&mut left_out[..len], &mut right_out[..len]);
+}
+
 /// Snapshot of the entire chain state for save/restore workflows.
 ///
 /// Captures effect IDs, parameter values, bypass states, and optional topology.
@@ -735,6 +1513,7 @@ mod tests {
 
     use super::*;
     use crate::Effect;
+    use crate::graph::{Rig, RigOutput};
     use crate::param_info::{ParamDescriptor, ParameterInfo};
 
     /// Simple test effect that multiplies by a constant.
@@ -777,6 +1556,148 @@ mod tests {
         Box::new(Gain { factor })
     }
 
+    /// Holds its last input, reporting a 20 ms tail.
+    struct Sustain {
+        state: f32,
+    }
+
+    impl Effect for Sustain {
+        fn process(&mut self, input: f32) -> f32 {
+            if input != 0.0 {
+                self.state = input;
+            }
+            self.state
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {
+            self.state = 0.0;
+        }
+        fn tail_samples(&self) -> usize {
+            960
+        }
+    }
+
+    impl ParameterInfo for Sustain {
+        fn param_count(&self) -> usize {
+            0
+        }
+        fn param_info(&self, _index: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _index: usize) -> f32 {
+            0.0
+        }
+        fn set_param(&mut self, _index: usize, _value: f32) {}
+    }
+
+    /// Feedback echo: repeats the input every `delay` samples at half level.
+    struct Echo {
+        left: Vec<f32>,
+        right: Vec<f32>,
+        index: usize,
+    }
+
+    impl Echo {
+        fn new(delay: usize) -> Self {
+            Self {
+                left: vec![0.0; delay],
+                right: vec![0.0; delay],
+                index: 0,
+            }
+        }
+    }
+
+    impl Effect for Echo {
+        fn process(&mut self, input: f32) -> f32 {
+            self.process_stereo(input, input).0
+        }
+        fn process_stereo(&mut self, left: f32, right: f32) -> (f32, f32) {
+            let i = self.index;
+            let (dl, dr) = (self.left[i], self.right[i]);
+            self.left[i] = left + 0.5 * dl;
+            self.right[i] = right + 0.5 * dr;
+            self.index = (i + 1) % self.left.len();
+            (left + dl, right + dr)
+        }
+        fn is_true_stereo(&self) -> bool {
+            true
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {
+            self.left.fill(0.0);
+            self.right.fill(0.0);
+        }
+    }
+
+    impl ParameterInfo for Echo {
+        fn param_count(&self) -> usize {
+            0
+        }
+        fn param_info(&self, _index: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _index: usize) -> f32 {
+            0.0
+        }
+        fn set_param(&mut self, _index: usize, _value: f32) {}
+    }
+
+    /// Delays its input by `latency` samples and reports it, like a
+    /// lookahead limiter.
+    struct Lookahead {
+        left: Vec<f32>,
+        right: Vec<f32>,
+        index: usize,
+    }
+
+    impl Lookahead {
+        fn new(latency: usize) -> Self {
+            Self {
+                left: vec![0.0; latency],
+                right: vec![0.0; latency],
+                index: 0,
+            }
+        }
+    }
+
+    impl Effect for Lookahead {
+        fn process(&mut self, input: f32) -> f32 {
+            self.process_stereo(input, input).0
+        }
+        fn process_stereo(&mut self, left: f32, right: f32) -> (f32, f32) {
+            let i = self.index;
+            let out = (self.left[i], self.right[i]);
+            self.left[i] = left;
+            self.right[i] = right;
+            self.index = (i + 1) % self.left.len();
+            out
+        }
+        fn is_true_stereo(&self) -> bool {
+            true
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {
+            self.left.fill(0.0);
+            self.right.fill(0.0);
+        }
+        fn latency_samples(&self) -> usize {
+            self.left.len()
+        }
+    }
+
+    impl ParameterInfo for Lookahead {
+        fn param_count(&self) -> usize {
+            0
+        }
+        fn param_info(&self, _index: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _index: usize) -> f32 {
+            0.0
+        }
+        fn set_param(&mut self, _index: usize, _value: f32) {}
+    }
+
     /// Process enough blocks to let the crossfade settle.
     fn settle_crossfade(engine: &mut GraphEngine) {
         let bs = engine.graph().block_size();
@@ -804,6 +1725,141 @@ mod tests {
         assert_eq!(right_out, [0.5, 1.0, 1.5, 2.0]);
     }
 
+    /// Engine with a 1000-sample echo that has rendered a 100-sample file
+    /// starting with a unit impulse.
+    fn echo_after_impulse() -> GraphEngine {
+        let mut engine =
+            GraphEngine::from_chain(vec![Box::new(Echo::new(1000))], 48000.0, 64).unwrap();
+        let mut impulse = vec![0.0; 100];
+        impulse[0] = 1.0;
+        let input = StereoSamples::new(impulse.clone(), impulse);
+        assert_eq!(engine.process_file_stereo(&input, 64).len(), 100);
+        engine
+    }
+
+    #[test]
+    fn render_tail_fixed_length() {
+        let tail = echo_after_impulse().render_tail(TailLength::Fixed(1000), 64);
+        assert_eq!(tail.len(), 1000);
+        // First echo lands 1000 samples after the impulse, at tail index 900
+        assert_eq!(tail.left[900], 1.0);
+        assert_eq!(tail.right[900], 1.0);
+    }
+
+    #[test]
+    fn render_tail_auto_stops_after_last_audible_echo() {
+        // Echo k (k = 0, 1, ...) has level 2^-k; the last above -60 dB is
+        // k = 9, 10000 samples after the impulse.
+        let auto = |hold| TailLength::Auto {
+            threshold: 1e-3,
+            hold,
+            max: 48000,
+        };
+        let tail = echo_after_impulse().render_tail(auto(1500), 64);
+        assert_eq!(tail.len(), 9901);
+        assert!((tail.left[9900] - 0.5f32.powi(9)).abs() < 1e-9);
+
+        // A hold shorter than the gap before the first repeat renders nothing.
+        let tail = echo_after_impulse().render_tail(auto(500), 64);
+        assert!(tail.is_empty());
+
+        let capped = TailLength::Auto {
+            threshold: 1e-3,
+            hold: 1500,
+            max: 5000,
+        };
+        assert_eq!(echo_after_impulse().render_tail(capped, 64).len(), 4901);
+    }
+
+    #[test]
+    fn render_tail_blocks_streams_the_trimmed_tail_with_stems() {
+        let auto = TailLength::Auto {
+            threshold: 1e-3,
+            hold: 1500,
+            max: 48000,
+        };
+        let whole = echo_after_impulse().render_tail(auto, 64);
+
+        let mut engine = echo_after_impulse();
+        engine.capture_stems(true);
+        let mut streamed = StereoSamples::new(Vec::new(), Vec::new());
+        let mut longest = 0;
+        let passed = engine.render_tail_blocks(auto, 64, |engine, left, right| {
+            let stems = engine.take_stems();
+            assert_eq!(stems[0].left, left, "stems match the block");
+            longest = longest.max(left.len());
+            streamed.left.extend_from_slice(left);
+            streamed.right.extend_from_slice(right);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(passed, 9901);
+        assert_eq!(streamed.left, whole.left);
+        assert_eq!(streamed.right, whole.right);
+        assert!(longest <= 1000 + 64, "blocks end at each echo: {longest}");
+        assert!(engine.take_stems()[0].is_empty(), "trimmed run dropped");
+
+        // Breaking stops rendering after the first block passed on
+        let mut engine = echo_after_impulse();
+        let passed =
+            engine.render_tail_blocks(
+                TailLength::Fixed(1000),
+                64,
+                |_, _, _| ControlFlow::Break(()),
+            );
+        assert_eq!(passed, 64);
+    }
+
+    #[test]
+    fn stems_capture_each_stage_including_tail() {
+        let mut engine = GraphEngine::from_chain(vec![gain(0.5), gain(4.0)], 48000.0, 64).unwrap();
+        engine.capture_stems(true);
+
+        let input = StereoSamples::new(vec![1.0; 100], vec![-1.0; 100]);
+        let output = engine.process_file_stereo(&input, 64);
+        engine.render_tail(TailLength::Fixed(30), 64);
+        let stems = engine.take_stems();
+
+        assert_eq!(stems.len(), 2);
+        assert!(stems.iter().all(|s| s.len() == 130));
+        assert_eq!(stems[0].left[..100], [0.5; 100]);
+        assert_eq!(stems[0].right[..100], [-0.5; 100]);
+        assert_eq!(stems[1].left[..100], output.left[..]);
+        assert_eq!(stems[1].left[100..], [0.0; 30]);
+
+        // Taken stems restart empty; capture off collects nothing.
+        assert!(engine.take_stems().iter().all(StereoSamples::is_empty));
+        engine.capture_stems(false);
+        engine.process_file_stereo(&input, 64);
+        assert!(engine.take_stems().is_empty());
+    }
+
+    #[test]
+    fn file_chunks_render_like_whole_file() {
+        let mut impulse = vec![0.0; 3000];
+        impulse[0] = 1.0;
+        let input = StereoSamples::new(impulse.clone(), impulse);
+        let echo = || GraphEngine::from_chain(vec![Box::new(Echo::new(1000))], 48000.0, 64);
+        let whole = echo().unwrap().process_file_stereo(&input, 64);
+
+        // Chunk lengths that don't line up with the block size
+        let mut engine = echo().unwrap();
+        let mut left = vec![0.0; 3000];
+        let mut right = vec![0.0; 3000];
+        for start in (0..3000).step_by(700) {
+            let end = (start + 700).min(3000);
+            engine.process_file_chunk(
+                &input.left[start..end],
+                &input.right[start..end],
+                &mut left[start..end],
+                &mut right[start..end],
+                64,
+            );
+        }
+        assert_eq!(left, whole.left);
+        assert_eq!(right, whole.right);
+        assert_eq!(left[2000], 0.5);
+    }
+
     #[test]
     fn test_process_file_stereo() {
         let mut engine = GraphEngine::from_chain(vec![gain(0.5)], 48000.0, 64).unwrap();
@@ -1080,6 +2136,26 @@ mod tests {
         assert_eq!(engine.effect_ids(), &[""]);
     }
 
+    #[test]
+    fn test_adopt_effects_moves_matching_instances() {
+        let mut old = GraphEngine::new_linear(48000.0, 64);
+        old.add_effect_named(gain(2.0), "a");
+        old.add_effect_named(gain(3.0), "b");
+
+        let mut new = GraphEngine::new_linear(48000.0, 64);
+        new.add_effect_named(gain(1.0), "b");
+        new.add_effect_named(gain(1.0), "a");
+        new.add_effect_named(gain(1.0), "c");
+
+        // (0, 2) names different effects and is skipped.
+        let adopted = new.adopt_effects(&mut old, &[(0, 2), (0, 1), (1, 0), (5, 0)]);
+        assert_eq!(adopted, 2);
+        assert_eq!(new.get_param_at(0, 0), Some(3.0));
+        assert_eq!(new.get_param_at(1, 0), Some(2.0));
+        assert_eq!(new.get_param_at(2, 0), Some(1.0));
+        assert!(old.effect_at(0).is_none(), "old slot left as passthrough");
+    }
+
     #[test]
     fn test_remove_at() {
         let mut engine = GraphEngine::new_linear(48000.0, 256);
@@ -1185,6 +2261,45 @@ mod tests {
         assert!(!engine.is_bypassed_at(99));
     }
 
+    #[test]
+    fn test_bypass_mode_at() {
+        let mut engine = GraphEngine::new_linear(48000.0, 256);
+        engine.add_effect_named(gain(2.0), "g");
+
+        assert_eq!(engine.bypass_mode_at(0), BypassMode::Dry);
+        engine.set_bypass_mode_at(0, BypassMode::Tails);
+        assert_eq!(engine.bypass_mode_at(0), BypassMode::Tails);
+
+        // Out of bounds is safe.
+        engine.set_bypass_mode_at(99, BypassMode::Mute);
+        assert_eq!(engine.bypass_mode_at(99), BypassMode::Dry);
+    }
+
+    #[test]
+    fn test_solo_at() {
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        engine.add_effect_named(gain(2.0), "a");
+        engine.add_effect_named(gain(3.0), "b");
+        settle_crossfade(&mut engine);
+
+        assert_eq!(engine.solo_slot(), None);
+        assert!(engine.set_solo_at(Some(0)));
+        assert_eq!(engine.solo_slot(), Some(0));
+
+        // After the fade, the output is the first stage alone (×2, not ×6).
+        let input = vec![0.5; 64];
+        let mut left = vec![0.0; 64];
+        let mut right = vec![0.0; 64];
+        for _ in 0..50 {
+            engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        }
+        assert!((left[63] - 1.0).abs() < 1e-4, "{}", left[63]);
+
+        assert!(!engine.set_solo_at(Some(99)));
+        assert!(engine.set_solo_at(None));
+        assert_eq!(engine.solo_slot(), None);
+    }
+
     #[test]
     fn test_snapshot() {
         let mut engine = GraphEngine::new_linear(48000.0, 256);
@@ -1525,4 +2640,307 @@ mod tests {
             "cloned snapshot must be independent"
         );
     }
+
+    #[test]
+    fn test_panic_fades_and_resets_state() {
+        /// Integrator standing in for a runaway feedback loop: holds its
+        /// output until reset.
+        struct Hold {
+            state: f32,
+        }
+
+        impl Effect for Hold {
+            fn process(&mut self, input: f32) -> f32 {
+                self.state += input;
+                self.state
+            }
+            fn set_sample_rate(&mut self, _sample_rate: f32) {}
+            fn reset(&mut self) {
+                self.state = 0.0;
+            }
+        }
+
+        impl ParameterInfo for Hold {
+            fn param_count(&self) -> usize {
+                0
+            }
+            fn param_info(&self, _index: usize) -> Option<ParamDescriptor> {
+                None
+            }
+            fn get_param(&self, _index: usize) -> f32 {
+                0.0
+            }
+            fn set_param(&mut self, _index: usize, _value: f32) {}
+        }
+
+        let mut engine = GraphEngine::new_linear(48000.0, 256);
+        engine.add_effect(Box::new(Hold { state: 0.0 }));
+        settle_crossfade(&mut engine);
+
+        let mut left = [0.0; 256];
+        let mut right = [0.0; 256];
+        let mut impulse = [0.0; 256];
+        impulse[0] = 1.0;
+        let silence = [0.0; 256];
+        // Mono state: drive one channel only.
+        engine.process_block_stereo(&impulse, &silence, &mut left, &mut right);
+        assert!((left[255] - 1.0).abs() < 1e-3, "state held");
+
+        engine.panic();
+        assert!(engine.is_panicking());
+        engine.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        assert!(left.windows(2).all(|w| w[1] <= w[0]), "fade-out");
+        assert_eq!(left[255], 0.0);
+
+        for _ in 0..2 {
+            engine.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        }
+        assert!(!engine.is_panicking());
+        assert!(left.iter().all(|&s| s == 0.0), "state cleared by panic");
+
+        engine.process_block_stereo(&impulse, &silence, &mut left, &mut right);
+        assert!((left[255] - 1.0).abs() < 1e-3, "unity gain after panic");
+    }
+
+    // --- Preset transition tests ---
+
+    #[test]
+    fn test_crossfade_from_fades_between_engines() {
+        use crate::graph::{TailMode, Transition};
+
+        let mut old = GraphEngine::new_linear(48000.0, 64);
+        old.add_effect(gain(2.0));
+        settle_crossfade(&mut old);
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        engine.add_effect(gain(4.0));
+        settle_crossfade(&mut engine);
+
+        // 10 ms at 48 kHz = 7.5 blocks of 64
+        engine.crossfade_from(Box::new(old), Transition::new(10.0, TailMode::Fade));
+        assert!(engine.is_transitioning());
+        let input = [0.5; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        let mut last = 1.0;
+        for _ in 0..8 {
+            engine.process_block_stereo(&input, &input, &mut left, &mut right);
+            assert!(left.iter().all(|&s| s >= last - 1e-5 && s <= 2.0 + 1e-5));
+            last = left[63];
+        }
+        assert!(!engine.is_transitioning());
+        assert!(
+            (left[63] - 2.0).abs() < 1e-5,
+            "new engine only: {}",
+            left[63]
+        );
+    }
+
+    #[test]
+    fn test_crossfade_ring_out_keeps_old_tail() {
+        use crate::graph::{TailMode, Transition};
+
+        let old_engine = |tails| {
+            let mut old = GraphEngine::new_linear(48000.0, 64);
+            old.add_effect(Box::new(Sustain { state: 0.0 }));
+            settle_crossfade(&mut old);
+            let input = [1.0; 64];
+            let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+            old.process_block_stereo(&input, &input, &mut left, &mut right);
+            let mut engine = GraphEngine::new_linear(48000.0, 64);
+            engine.crossfade_from(Box::new(old), Transition::new(0.0, tails));
+            engine
+        };
+        let silence = [0.0; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+
+        let mut faded = old_engine(TailMode::Fade);
+        faded.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        assert!(left.iter().all(|&s| s == 0.0), "tail cut with the fade");
+        assert!(!faded.is_transitioning());
+
+        let mut ringing = old_engine(TailMode::RingOut);
+        ringing.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        assert!(left[63] > 0.9, "tail rings out: {}", left[63]);
+        for _ in 0..15 {
+            ringing.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        }
+        assert!(!ringing.is_transitioning(), "dropped after its tail");
+        assert!(left[63].abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_panic_drops_outgoing_engine() {
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        engine.crossfade_from(
+            Box::new(GraphEngine::new_linear(48000.0, 64)),
+            crate::graph::Transition::default(),
+        );
+        engine.panic();
+        let silence = [0.0; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        for _ in 0..10 {
+            engine.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        }
+        assert!(!engine.is_transitioning());
+    }
+
+    // --- Safe mode tests ---
+
+    #[test]
+    fn test_safe_mode_passes_dry_and_mutes_tails() {
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        engine.add_effect(gain(2.0));
+        engine.add_effect(Box::new(Sustain { state: 0.0 }));
+        settle_crossfade(&mut engine);
+
+        let input = [0.5; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        assert!((left[63] - 1.0).abs() < 1e-5);
+
+        engine.set_safe_mode(true);
+        assert!(engine.is_safe_mode());
+        for _ in 0..10 {
+            engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        }
+        assert!(left.iter().all(|&s| s == 0.5), "dry path");
+
+        // The held state was muted while dry: silence in, silence out
+        engine.set_safe_mode(false);
+        let silence = [0.0; 64];
+        for _ in 0..10 {
+            engine.process_block_stereo(&silence, &silence, &mut left, &mut right);
+        }
+        assert!(!engine.is_safe_mode());
+        assert!(left.iter().all(|&s| s == 0.0), "feedback state cleared");
+        engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        assert!((left[63] - 1.0).abs() < 1e-5, "chain back in");
+    }
+
+    #[test]
+    fn test_global_mix_keeps_dry_aligned_with_latent_graph() {
+        let mut engine =
+            GraphEngine::from_chain(vec![Box::new(Lookahead::new(100)), gain(0.5)], 48000.0, 64)
+                .unwrap();
+        assert_eq!(engine.latency_samples(), 100);
+        engine.set_global_mix_immediate(0.5);
+        assert_eq!(engine.global_mix(), 0.5);
+
+        // One impulse: wet (0.5 × 0.5) and dry (0.5) land on the same sample
+        let mut impulse = vec![0.0; 1000];
+        impulse[10] = 1.0;
+        let input = StereoSamples::new(impulse.clone(), impulse);
+        let output = engine.process_file_stereo(&input, 64);
+        for (i, (&l, &r)) in output.left.iter().zip(&output.right).enumerate() {
+            let expected = if i == 110 { 0.75 } else { 0.0 };
+            assert!((l - expected).abs() < 1e-6, "left {l} at {i}");
+            assert!((r - expected).abs() < 1e-6, "right {r} at {i}");
+        }
+    }
+
+    #[test]
+    fn test_output_limiter_caps_any_graph() {
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        engine.add_effect(gain(10.0));
+        settle_crossfade(&mut engine);
+        engine.set_output_limiter(true);
+        engine.set_limiter_ceiling_db(-6.0);
+        assert!(engine.output_limiter());
+
+        let input = [0.5; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        let ceiling = crate::math::db_to_linear(-6.0);
+        assert!(left.iter().all(|&s| s <= ceiling + 1e-6));
+        assert!(engine.take_limiter_reduction_db() > 13.0);
+    }
+
+    #[test]
+    fn test_output_limiter_covers_file_rendering() {
+        let mut engine = echo_after_impulse();
+        engine.add_effect(gain(10.0));
+        settle_crossfade(&mut engine);
+        engine.set_output_limiter(true);
+        engine.set_limiter_ceiling_db(-6.0);
+        let ceiling = crate::math::db_to_linear(-6.0);
+
+        let input = StereoSamples::new(vec![0.5; 1000], vec![-0.5; 1000]);
+        let output = engine.process_file_stereo(&input, 64);
+        assert!(output.left.iter().all(|&s| s <= ceiling + 1e-6));
+        assert!(output.right.iter().all(|&s| s >= -ceiling - 1e-6));
+
+        let tail = engine.render_tail(TailLength::Fixed(2000), 64);
+        assert!(tail.left.iter().all(|&s| s.abs() <= ceiling + 1e-6));
+        assert!(engine.take_limiter_reduction_db() > 13.0);
+    }
+
+    // --- Dual rig tests ---
+
+    fn dual(sample_rate: f32) -> GraphEngine {
+        let mut engine = GraphEngine::dual_from_chains(
+            vec![(gain(2.0), "gain_a")],
+            vec![(gain(3.0), "gain_b"), (gain(1.0), "trim")],
+            sample_rate,
+            64,
+        )
+        .unwrap();
+        settle_crossfade(&mut engine);
+        engine
+    }
+
+    #[test]
+    fn test_dual_slots_span_both_rigs() {
+        let engine = dual(48000.0);
+        assert!(engine.is_dual());
+        assert_eq!(engine.effect_ids(), &["gain_a", "gain_b", "trim"]);
+        assert_eq!(engine.rig_slots(Rig::A), 0..1);
+        assert_eq!(engine.rig_slots(Rig::B), 1..3);
+
+        let single = GraphEngine::new_linear(48000.0, 64);
+        assert!(!single.is_dual());
+        assert!(single.rig_slots(Rig::B).is_empty());
+    }
+
+    #[test]
+    fn test_dual_morph_and_toggle() {
+        let mut engine = dual(48000.0);
+        let input = [0.5; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+
+        engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        assert!((left[63] - 1.0).abs() < 1e-4, "rig A only: {}", left[63]);
+
+        assert_eq!(engine.toggle_rig(), Rig::B);
+        for _ in 0..250 {
+            engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        }
+        assert!((left[63] - 1.5).abs() < 1e-4, "rig B only: {}", left[63]);
+
+        engine.set_rig_morph(0.5);
+        for _ in 0..250 {
+            engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        }
+        let expected = (1.0 + 1.5) * core::f32::consts::FRAC_1_SQRT_2;
+        assert!((left[63] - expected).abs() < 1e-3, "blend: {}", left[63]);
+    }
+
+    #[test]
+    fn test_dual_split_output() {
+        let mut engine = dual(48000.0);
+        engine.set_rig_output(RigOutput::Split);
+        let input = [0.5; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        engine.process_block_stereo(&input, &input, &mut left, &mut right);
+        assert!((left[63] - 1.0).abs() < 1e-4);
+        assert!((right[63] - 1.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_new_dual_requires_two_buses() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let output = graph.add_output();
+        graph.connect(input, output).unwrap();
+        graph.compile().unwrap();
+        assert!(GraphEngine::new_dual(graph, Vec::new(), 0).is_err());
+    }
 }
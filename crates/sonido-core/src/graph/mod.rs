@@ -31,12 +31,39 @@
 //! and inserts [`CompensationDelay`] steps for shorter
 //! parallel paths.
 //!
+//! # Mixed Sample Rates
+//!
+//! The graph runs at one rate. Sources at another rate (a file player with
+//! 44.1 kHz material in a 48 kHz graph) enter through an [`SrcNode`], a
+//! streaming polyphase converter fed from an [`SrcFeed`] handle; its filter
+//! delay is reported to latency compensation like any other node.
+//!
+//! # Multiple Buses
+//!
+//! Each Input and Output node is a bus, numbered in creation order. A graph can
+//! feed a main output, a headphone cue, and an FX send from one schedule;
+//! [`ProcessingGraph::process_block_buses()`] takes a buffer pair per bus.
+//!
+//! # Dual Rigs
+//!
+//! Two parallel graphs fed from the same input (stereo A/B rigs) share one
+//! graph on separate bus pairs; [`GraphEngine::new_dual()`] blends them with
+//! an equal-power morph or splits them across the stereo outputs (see
+//! [`rig`]).
+//!
 //! # Click-free Schedule Swap
 //!
 //! When a new schedule replaces the old one, both run simultaneously during a ~5ms
 //! crossfade window (via [`SmoothedParam`](crate::SmoothedParam)). This eliminates
 //! audible clicks from topology changes.
 //!
+//! # Preset Transitions
+//!
+//! Replacing a whole engine (a preset change) can crossfade over a configurable
+//! time instead: [`GraphEngine::crossfade_from()`] keeps the old engine running
+//! until the fade ends, or until its delay and reverb tails have rung out (see
+//! [`transition`]).
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -69,15 +96,25 @@ pub mod edge;
 pub mod engine;
 pub mod node;
 mod processing;
+pub mod rig;
 pub mod schedule;
 #[cfg(feature = "spectral")]
 pub mod spectral_node;
+pub mod src_node;
 pub mod stereo_samples;
+pub mod transition;
 
 pub use buffer::{BufferPool, CompensationDelay, StereoBuffer};
 pub use edge::EdgeId;
-pub use engine::{GraphEngine, GraphSnapshot, SnapshotEntry, SnapshotTopology, TopoNode};
-pub use node::{NodeId, NodeKind, NodeRate};
-pub use processing::{GraphError, ProcessingGraph};
-pub use schedule::{CompiledSchedule, MAX_SPLIT_TARGETS, ProcessStep};
+pub use engine::{
+    GraphEngine, GraphSnapshot, SnapshotEntry, SnapshotTopology, TailLength, TopoNode,
+};
+pub use node::{AuxSend, BypassMode, NodeId, NodeKind, NodeRate, SEND_MAX_DB, SendTap};
+#[cfg(feature = "json")]
+pub use processing::GraphJsonError;
+pub use processing::{BusInput, BusOutput, GraphError, LatencyUpdate, PdcMode, ProcessingGraph};
+pub use rig::{Rig, RigOutput};
+pub use schedule::{CompiledSchedule, ProcessStep, SPLIT_COPY_WIDTH};
+pub use src_node::{SrcFeed, SrcNode};
 pub use stereo_samples::StereoSamples;
+pub use transition::{TailMode, Transition};
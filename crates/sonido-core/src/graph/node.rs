@@ -8,12 +8,15 @@
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
 use crate::effect_with_params::EffectWithParams;
+use crate::math::db_to_linear;
 use crate::param::SmoothedParam;
 
-use super::buffer::StereoBuffer;
+use super::buffer::{CompensationDelay, StereoBuffer};
 
 /// Unique identifier for a node in the processing graph.
 ///
@@ -70,12 +73,182 @@ pub enum NodeRate {
     Control(f32),
 }
 
+/// How a bypassed effect node treats its signal.
+///
+/// Selected per node with
+/// [`ProcessingGraph::set_bypass_mode()`](super::ProcessingGraph::set_bypass_mode).
+/// All modes share the same click-free bypass crossfade; they differ only in
+/// what the crossfade lands on:
+///
+/// - [`Dry`](BypassMode::Dry) — input passes through unchanged (the default).
+/// - [`Mute`](BypassMode::Mute) — output fades to silence.
+/// - [`Tails`](BypassMode::Tails) — the effect input is gated to silence while
+///   the dry signal passes through, so delay and reverb tails ring out naturally.
+///   The effect keeps processing for [`tail_samples()`](crate::Effect::tail_samples)
+///   after the fade, then drops to the zero-cost dry path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum BypassMode {
+    /// Pass the input through unchanged.
+    #[default]
+    Dry,
+    /// Output silence.
+    Mute,
+    /// Pass the input through while the effect's existing tail rings out.
+    Tails,
+}
+
+impl BypassMode {
+    /// All bypass modes in menu order.
+    pub const ALL: [Self; 3] = [Self::Dry, Self::Mute, Self::Tails];
+
+    /// Returns a short display label (`"Dry"`, `"Mute"`, `"Tails"`).
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Dry => "Dry",
+            Self::Mute => "Mute",
+            Self::Tails => "Tails",
+        }
+    }
+
+    /// Parses a label produced by [`label()`](Self::label) (case-insensitive).
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.label().eq_ignore_ascii_case(label))
+    }
+}
+
+/// Where a [`NodeKind::Send`] node taps its signal relative to its fader.
+///
+/// - [`PreFader`](SendTap::PreFader) — the send level ignores the fader, so a
+///   cue or monitor mix is unaffected by the main mix balance.
+/// - [`PostFader`](SendTap::PostFader) — the send follows the fader, so an
+///   effect return stays proportional to the dry signal (the default).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SendTap {
+    /// Tap the signal before the fader.
+    PreFader,
+    /// Tap the signal after the fader.
+    #[default]
+    PostFader,
+}
+
+impl SendTap {
+    /// All taps in menu order.
+    pub const ALL: [Self; 2] = [Self::PreFader, Self::PostFader];
+
+    /// Returns a short display label (`"Pre"`, `"Post"`).
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::PreFader => "Pre",
+            Self::PostFader => "Post",
+        }
+    }
+
+    /// Parses a label produced by [`label()`](Self::label) (case-insensitive).
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.label().eq_ignore_ascii_case(label))
+    }
+}
+
+/// Highest send or fader gain accepted, in dB.
+pub const SEND_MAX_DB: f32 = 12.0;
+
+/// State of a [`NodeKind::Send`] node: its target aux bus, fader, send level,
+/// and tap point.
+///
+/// Levels are in dB; `f32::NEG_INFINITY` silences the path. Every change
+/// is smoothed, including a switch between pre- and post-fader.
+pub struct AuxSend {
+    /// The [`NodeKind::AuxBus`] node the send feeds.
+    pub(crate) aux: NodeId,
+    level_db: f32,
+    fader_db: f32,
+    tap: SendTap,
+    /// Linear send gain.
+    pub(crate) level: SmoothedParam,
+    /// Linear fader gain on the through path.
+    pub(crate) fader: SmoothedParam,
+    /// Blend from pre-fader (0.0) to post-fader (1.0) tapping.
+    pub(crate) post: SmoothedParam,
+}
+
+impl AuxSend {
+    /// Creates a send into `aux` at `level_db`, with the fader at unity.
+    pub(crate) fn new(aux: NodeId, level_db: f32, tap: SendTap, sample_rate: f32) -> Self {
+        let level_db = level_db.min(SEND_MAX_DB);
+        let post = if tap == SendTap::PostFader { 1.0 } else { 0.0 };
+        Self {
+            aux,
+            level_db,
+            fader_db: 0.0,
+            tap,
+            level: SmoothedParam::fast(db_to_linear(level_db), sample_rate),
+            fader: SmoothedParam::fast(1.0, sample_rate),
+            post: SmoothedParam::fast(post, sample_rate),
+        }
+    }
+
+    /// The aux bus this send feeds.
+    pub fn aux(&self) -> NodeId {
+        self.aux
+    }
+
+    /// Send level in dB.
+    pub fn level_db(&self) -> f32 {
+        self.level_db
+    }
+
+    /// Fader level on the through path in dB.
+    pub fn fader_db(&self) -> f32 {
+        self.fader_db
+    }
+
+    /// Where the send taps the signal.
+    pub fn tap(&self) -> SendTap {
+        self.tap
+    }
+
+    pub(crate) fn set_level_db(&mut self, db: f32) {
+        self.level_db = db.min(SEND_MAX_DB);
+        self.level.set_target(db_to_linear(self.level_db));
+    }
+
+    pub(crate) fn set_fader_db(&mut self, db: f32) {
+        self.fader_db = db.min(SEND_MAX_DB);
+        self.fader.set_target(db_to_linear(self.fader_db));
+    }
+
+    pub(crate) fn set_tap(&mut self, tap: SendTap) {
+        self.tap = tap;
+        self.post
+            .set_target(if tap == SendTap::PostFader { 1.0 } else { 0.0 });
+    }
+
+    pub(crate) fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.level.set_sample_rate(sample_rate);
+        self.fader.set_sample_rate(sample_rate);
+        self.post.set_sample_rate(sample_rate);
+    }
+
+    /// Jumps all smoothed gains to their targets.
+    pub(crate) fn snap(&mut self) {
+        self.level.snap_to_target();
+        self.fader.snap_to_target();
+        self.post.snap_to_target();
+    }
+}
+
 /// The role of a node in the processing graph.
 #[non_exhaustive]
 pub enum NodeKind {
-    /// Receives external audio input. Exactly one per graph.
+    /// Receives external audio input. Each Input node is one input bus.
     Input,
-    /// Produces final audio output. Exactly one per graph.
+    /// Produces final audio output. Each Output node is one output bus.
     Output,
     /// Wraps a DSP effect implementing [`EffectWithParams`].
     Effect(Box<dyn EffectWithParams + Send>),
@@ -83,6 +256,16 @@ pub enum NodeKind {
     Split,
     /// Fan-in: sums N inputs into one output.
     Merge,
+    /// Inline send: passes its input through a fader to its one outgoing
+    /// edge and taps a copy at the send level into an [`AuxBus`](Self::AuxBus).
+    ///
+    /// Created with [`add_send()`](super::ProcessingGraph::add_send).
+    Send(AuxSend),
+    /// Shared aux bus: like [`Merge`](Self::Merge) but sums its inputs at
+    /// unity instead of averaging them, as a mixer bus does.
+    ///
+    /// Sends feed it; its single output drives a return chain.
+    AuxBus,
     /// A nested sub-graph processed as a single opaque block.
     ///
     /// The inner [`super::ProcessingGraph`] is compiled independently.  During outer
@@ -115,6 +298,16 @@ pub(crate) struct NodeData {
     /// Used during bypass crossfade so the dry signal is available even when
     /// `input_buf == output_buf` (in-place processing).
     pub bypass_buf: StereoBuffer,
+    /// Delays the dry bypass signal by the node's compensated latency, so
+    /// bypassing a latent effect does not shift its path against the others.
+    pub bypass_delay: CompensationDelay,
+    /// What the bypass crossfade lands on (dry, silence, or dry plus tail).
+    pub bypass_mode: BypassMode,
+    /// Samples of effect tail still to render in [`BypassMode::Tails`].
+    ///
+    /// Armed from `tail_samples()` when the node is bypassed; counts down
+    /// once the crossfade settles.
+    pub tail_remaining: usize,
     /// Per-block peak input level (left, right). Updated during `run_schedule`.
     pub peak_in: (f32, f32),
     /// Per-block peak output level (left, right). Updated during `run_schedule`.
@@ -146,6 +339,8 @@ pub(crate) struct NodeData {
     /// Always `0` on non-ARM platforms (desktop, WASM). Used for per-effect
     /// CPU profiling on the Daisy Seed.
     pub last_cycles: u32,
+    /// Bus name for Input and Output nodes (e.g. `"cue"`), if one was given.
+    pub bus_name: Option<String>,
 }
 
 impl NodeData {
@@ -161,6 +356,9 @@ impl NodeData {
             bypassed: false,
             bypass_fade,
             bypass_buf: StereoBuffer::new(0),
+            bypass_delay: CompensationDelay::new(0),
+            bypass_mode: BypassMode::Dry,
+            tail_remaining: 0,
             peak_in: (0.0, 0.0),
             peak_out: (0.0, 0.0),
             tapped: false,
@@ -169,6 +367,7 @@ impl NodeData {
             node_rate: NodeRate::Audio,
             control_output: (0.0, 0.0),
             last_cycles: 0,
+            bus_name: None,
         }
     }
 }
@@ -230,6 +429,22 @@ mod tests {
         assert_eq!(NodeRate::Audio, NodeRate::Audio);
     }
 
+    // ── BypassMode ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn bypass_mode_default_is_dry() {
+        assert_eq!(BypassMode::default(), BypassMode::Dry);
+    }
+
+    #[test]
+    fn bypass_mode_label_roundtrip() {
+        for mode in BypassMode::ALL {
+            assert_eq!(BypassMode::from_label(mode.label()), Some(mode));
+        }
+        assert_eq!(BypassMode::from_label("tails"), Some(BypassMode::Tails));
+        assert_eq!(BypassMode::from_label("wet"), None);
+    }
+
     // ── NodeData ──────────────────────────────────────────────────────────────
 
     #[test]
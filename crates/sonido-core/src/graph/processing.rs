@@ -20,14 +20,23 @@ use crate::math::wet_dry_mix;
 use crate::param::SmoothedParam;
 use crate::tempo::TempoContext;
 
-use super::buffer::{BufferPool, CompensationDelay};
+use super::buffer::{BufferPool, CompensationDelay, StereoBuffer};
 use super::edge::{Edge, EdgeId};
-use super::node::{NodeData, NodeId, NodeKind, NodeRate};
-use super::schedule::{CompiledSchedule, MAX_SPLIT_TARGETS, ProcessStep};
+use super::node::{BypassMode, NodeData, NodeId, NodeKind, NodeRate};
+use super::schedule::{CompiledSchedule, ProcessStep, SPLIT_COPY_WIDTH};
 
 #[cfg(all(debug_assertions, feature = "debug-alloc"))]
 use assert_no_alloc::assert_no_alloc;
 
+mod dot;
+#[cfg(feature = "json")]
+mod json;
+mod pdc;
+mod sends;
+#[cfg(feature = "json")]
+pub use json::GraphJsonError;
+pub use pdc::{LatencyUpdate, PdcMode};
+
 /// An effect removed from the graph that's still producing decaying output.
 ///
 /// Fed silence each block; output mixed into the final output with exponential
@@ -45,12 +54,17 @@ struct SpilloverTail {
     right_buf: Vec<f32>,
 }
 
+/// One input bus for [`ProcessingGraph::process_block_buses()`]: `(left, right)`.
+pub type BusInput<'a> = (&'a [f32], &'a [f32]);
+
+/// One output bus for [`ProcessingGraph::process_block_buses()`]: `(left, right)`.
+pub type BusOutput<'a> = (&'a mut [f32], &'a mut [f32]);
+
 /// Formats a compiled `ProcessStep` into a human-readable description.
-#[cfg(feature = "tracing")]
 fn format_step(step: &ProcessStep) -> String {
     match step {
-        ProcessStep::WriteInput { buffer_idx } => {
-            format!("WriteInput → buf[{buffer_idx}]")
+        ProcessStep::WriteInput { buffer_idx, bus } => {
+            format!("WriteInput bus {bus} → buf[{buffer_idx}]")
         }
         ProcessStep::ProcessEffect {
             node_idx,
@@ -94,8 +108,8 @@ fn format_step(step: &ProcessStep) -> String {
         } => {
             format!("DelayCompensate buf[{buffer_idx}] delay_line[{delay_line_idx}]")
         }
-        ProcessStep::ReadOutput { buffer_idx } => {
-            format!("ReadOutput ← buf[{buffer_idx}]")
+        ProcessStep::ReadOutput { buffer_idx, bus } => {
+            format!("ReadOutput bus {bus} ← buf[{buffer_idx}]")
         }
         ProcessStep::FeedbackDelay {
             buffer_idx,
@@ -116,6 +130,21 @@ fn format_step(step: &ProcessStep) -> String {
         } => {
             format!("ProcessSubGraph node[{node_idx}] buf[{input_buf}] → buf[{output_buf}]")
         }
+        ProcessStep::Send {
+            node_idx,
+            source_buf,
+            through_buf,
+            send_buf,
+        } => {
+            let mut line = format!("Send node[{node_idx}] buf[{source_buf}]");
+            if let Some(through) = through_buf {
+                line.push_str(&format!(" → buf[{through}]"));
+            }
+            if let Some(send) = send_buf {
+                line.push_str(&format!(" aux → buf[{send}]"));
+            }
+            line
+        }
     }
 }
 
@@ -129,9 +158,9 @@ pub enum GraphError {
     EdgeNotFound(EdgeId),
     /// Adding this edge would create a cycle.
     CycleDetected,
-    /// The graph must have exactly one Input node.
+    /// The graph must have at least one Input node.
     InvalidInputCount(usize),
-    /// The graph must have exactly one Output node.
+    /// The graph must have at least one Output node.
     InvalidOutputCount(usize),
     /// A node has an invalid connection (e.g., Input with incoming edges).
     InvalidConnection(String),
@@ -148,8 +177,10 @@ impl std::fmt::Display for GraphError {
             Self::NodeNotFound(id) => write!(f, "node {id:?} not found"),
             Self::EdgeNotFound(id) => write!(f, "edge {id:?} not found"),
             Self::CycleDetected => write!(f, "adding this edge would create a cycle"),
-            Self::InvalidInputCount(n) => write!(f, "expected 1 Input node, found {n}"),
-            Self::InvalidOutputCount(n) => write!(f, "expected 1 Output node, found {n}"),
+            Self::InvalidInputCount(n) => write!(f, "expected at least 1 Input node, found {n}"),
+            Self::InvalidOutputCount(n) => {
+                write!(f, "expected at least 1 Output node, found {n}")
+            }
             Self::InvalidConnection(msg) => write!(f, "invalid connection: {msg}"),
             Self::EmptyGraph => write!(f, "graph has no processable nodes"),
             Self::DuplicateEdge(a, b) => write!(f, "edge from {a:?} to {b:?} already exists"),
@@ -199,10 +230,10 @@ pub struct ProcessingGraph {
     swap_fade: SmoothedParam,
     /// Previous schedule, kept alive during crossfade.
     prev_compiled: Option<Arc<CompiledSchedule>>,
-    /// Pre-allocated crossfade buffers for RT-safe schedule transitions.
-    /// Cache the last output before a schedule swap; blended toward new output.
-    crossfade_left: Vec<f32>,
-    crossfade_right: Vec<f32>,
+    /// Pre-allocated crossfade buffers for RT-safe schedule transitions, one
+    /// per output bus. Cache the last output before a schedule swap; blended
+    /// toward new output.
+    crossfade: Vec<StereoBuffer>,
     /// Pre-allocated sidechain copy buffers (RT-safe, zero allocation per block).
     ///
     /// When a `ProcessEffect` step has a `sidechain_buf`, the executor copies the
@@ -221,6 +252,31 @@ pub struct ProcessingGraph {
     spillover_enabled: bool,
     /// Pending dropped effects (to avoid deallocation inside audio thread).
     pub dead_effects: Vec<Box<dyn EffectWithParams + Send>>,
+    /// Node whose output replaces the graph output, if any.
+    solo: Option<NodeId>,
+    /// Previous solo source, faded out while [`solo`](Self::solo) fades in.
+    /// `None` means the normal graph output.
+    solo_prev: Option<NodeId>,
+    /// Crossfade envelope from `solo_prev` (0.0) to `solo` (1.0).
+    solo_fade: SmoothedParam,
+    /// Nodes whose tap was enabled by [`set_solo()`](Self::set_solo) rather
+    /// than by the caller; released once no longer needed.
+    solo_taps: Vec<NodeId>,
+    /// Plugin delay compensation mode.
+    pdc: PdcMode,
+    /// Per-node own latency (by node slot) the current schedule compensates for.
+    compiled_latencies: Vec<usize>,
+    /// Per-node own latency captured by [`PdcMode::Frozen`].
+    frozen_latencies: Vec<usize>,
+}
+
+/// Everything [`ProcessingGraph::compile()`] derives from the topology before
+/// touching any runtime state.
+struct SchedulePlan {
+    schedule: CompiledSchedule,
+    /// Own latency per node slot that the schedule compensates for.
+    own_latencies: Vec<usize>,
+    output_count: usize,
 }
 
 impl ProcessingGraph {
@@ -233,6 +289,7 @@ impl ProcessingGraph {
     pub fn new(sample_rate: f32, block_size: usize) -> Self {
         let mut swap_fade = SmoothedParam::fast(1.0, sample_rate);
         swap_fade.snap_to_target();
+        let solo_fade = swap_fade.clone();
         Self {
             nodes: Vec::new(),
             edges: Vec::new(),
@@ -243,8 +300,7 @@ impl ProcessingGraph {
             next_edge_slot: 0,
             swap_fade,
             prev_compiled: None,
-            crossfade_left: vec![0.0; block_size],
-            crossfade_right: vec![0.0; block_size],
+            crossfade: vec![StereoBuffer::new(block_size)],
             sc_tmp_left: vec![0.0; block_size],
             sc_tmp_right: vec![0.0; block_size],
             audio_pool: BufferPool::new(0, block_size),
@@ -253,6 +309,13 @@ impl ProcessingGraph {
             spillover_tails: Vec::new(),
             spillover_enabled: true,
             dead_effects: Vec::new(),
+            solo: None,
+            solo_prev: None,
+            solo_fade,
+            solo_taps: Vec::new(),
+            pdc: PdcMode::On,
+            compiled_latencies: Vec::new(),
+            frozen_latencies: Vec::new(),
         }
     }
 
@@ -260,18 +323,42 @@ impl ProcessingGraph {
 
     /// Adds an audio input node. Returns the new node's ID.
     ///
-    /// A graph must have exactly one Input node to compile successfully.
+    /// A graph needs at least one Input node to compile. Each Input node is a
+    /// separate input bus, numbered in creation order; the first is the main
+    /// bus fed by [`process_block()`](Self::process_block).
     pub fn add_input(&mut self) -> NodeId {
         self.add_node(NodeKind::Input)
     }
 
     /// Adds an audio output node. Returns the new node's ID.
     ///
-    /// A graph must have exactly one Output node to compile successfully.
+    /// A graph needs at least one Output node to compile. Each Output node is
+    /// a separate output bus, numbered in creation order; the first is the
+    /// main bus written by [`process_block()`](Self::process_block).
     pub fn add_output(&mut self) -> NodeId {
         self.add_node(NodeKind::Output)
     }
 
+    /// Adds a named input bus (e.g. `"sidechain"` or `"return"`).
+    ///
+    /// Behaves like [`add_input()`](Self::add_input); the name can be resolved
+    /// back to a bus number with [`input_bus()`](Self::input_bus).
+    pub fn add_input_named(&mut self, name: &str) -> NodeId {
+        let id = self.add_node(NodeKind::Input);
+        self.set_bus_name(id, name);
+        id
+    }
+
+    /// Adds a named output bus (e.g. `"cue"` or `"fx_send"`).
+    ///
+    /// Behaves like [`add_output()`](Self::add_output); the name can be
+    /// resolved back to a bus number with [`output_bus()`](Self::output_bus).
+    pub fn add_output_named(&mut self, name: &str) -> NodeId {
+        let id = self.add_node(NodeKind::Output);
+        self.set_bus_name(id, name);
+        id
+    }
+
     /// Adds an effect processing node wrapping the given [`EffectWithParams`].
     ///
     /// The effect's sample rate is set to the graph's sample rate.
@@ -330,26 +417,22 @@ impl ProcessingGraph {
             self.disconnect_internal(edge_id);
         }
 
+        // A removed node can no longer be monitored.
+        if self.solo == Some(id) {
+            self.solo = None;
+        }
+        if self.solo_prev == Some(id) {
+            self.solo_prev = None;
+        }
+        self.solo_taps.retain(|&t| t != id);
+
         // Take the node out of the slot.
         if let Some(node_data) = self.nodes[idx].take() {
             // If this is an Effect node with a non-zero tail and spillover is enabled,
             // preserve the effect so it can ring out instead of cutting abruptly.
             if let NodeKind::Effect(effect) = node_data.kind {
-                let tail = effect.tail_samples();
-                if tail > 0 && self.spillover_enabled {
-                    let fade_ms = (tail as f32 / self.sample_rate * 1000.0).min(10000.0);
-                    let mut fade = SmoothedParam::with_config(1.0, self.sample_rate, fade_ms);
-                    fade.set_target(0.0);
-                    let buf_size = self.block_size.max(1);
-                    self.spillover_tails.push(SpilloverTail {
-                        effect,
-                        fade,
-                        remaining: tail,
-                        left_buf: vec![0.0; buf_size],
-                        right_buf: vec![0.0; buf_size],
-                    });
-                }
                 // If no tail or spillover disabled, effect is dropped here.
+                drop(self.spill(effect));
             }
             // Non-effect nodes (Input, Output, Split, Merge) are dropped normally.
         }
@@ -358,7 +441,8 @@ impl ProcessingGraph {
         Ok(())
     }
 
-    /// Returns the `NodeId` of the Input node, or `None` if no Input exists.
+    /// Returns the `NodeId` of the main (bus 0) Input node, or `None` if no
+    /// Input exists.
     pub fn input_id(&self) -> Option<NodeId> {
         self.nodes.iter().flatten().find_map(|n| {
             if matches!(n.kind, NodeKind::Input) {
@@ -369,7 +453,8 @@ impl ProcessingGraph {
         })
     }
 
-    /// Returns the `NodeId` of the Output node, or `None` if no Output exists.
+    /// Returns the `NodeId` of the main (bus 0) Output node, or `None` if no
+    /// Output exists.
     pub fn output_id(&self) -> Option<NodeId> {
         self.nodes.iter().flatten().find_map(|n| {
             if matches!(n.kind, NodeKind::Output) {
@@ -380,6 +465,46 @@ impl ProcessingGraph {
         })
     }
 
+    /// Returns the Input nodes in bus order (index = input bus number).
+    pub fn input_buses(&self) -> Vec<NodeId> {
+        self.io_nodes(true)
+    }
+
+    /// Returns the Output nodes in bus order (index = output bus number).
+    pub fn output_buses(&self) -> Vec<NodeId> {
+        self.io_nodes(false)
+    }
+
+    /// Resolves a named input bus to its bus number.
+    pub fn input_bus(&self, name: &str) -> Option<usize> {
+        self.io_nodes(true)
+            .iter()
+            .position(|&id| self.bus_name(id) == Some(name))
+    }
+
+    /// Resolves a named output bus to its bus number.
+    pub fn output_bus(&self, name: &str) -> Option<usize> {
+        self.io_nodes(false)
+            .iter()
+            .position(|&id| self.bus_name(id) == Some(name))
+    }
+
+    /// Returns the bus name of an Input or Output node, if it has one.
+    pub fn bus_name(&self, id: NodeId) -> Option<&str> {
+        self.get_node(id).ok()?.bus_name.as_deref()
+    }
+
+    /// Names (or with `""`, unnames) an Input or Output node's bus.
+    ///
+    /// Ignored for other node kinds.
+    pub fn set_bus_name(&mut self, id: NodeId, name: &str) {
+        if let Some(Some(node)) = self.nodes.get_mut(id.0 as usize)
+            && matches!(node.kind, NodeKind::Input | NodeKind::Output)
+        {
+            node.bus_name = (!name.is_empty()).then(|| String::from(name));
+        }
+    }
+
     /// Clears orphaned/dead effects from the garbage list.
     /// MUST be called from a non-audio thread to avoid interrupt latency spikes!
     pub fn clear_garbage(&mut self) {
@@ -715,20 +840,131 @@ impl ProcessingGraph {
         }
     }
 
+    /// Swaps the effect in an Effect node for `effect`.
+    ///
+    /// The node keeps its edges, bypass state, and schedule, so no recompile
+    /// is needed as long as `effect` has the same latency as the one it
+    /// replaces (e.g. a fresh instance of the same type). The previous effect
+    /// rings out as a spillover tail, exactly as if its node had been removed.
+    ///
+    /// Returns an error if the node doesn't exist or isn't an Effect node.
+    pub fn replace_effect(
+        &mut self,
+        id: NodeId,
+        mut effect: Box<dyn EffectWithParams + Send>,
+    ) -> Result<(), GraphError> {
+        effect.set_sample_rate(self.sample_rate);
+        let node = self
+            .nodes
+            .get_mut(id.0 as usize)
+            .and_then(|n| n.as_mut())
+            .ok_or(GraphError::NodeNotFound(id))?;
+        let NodeKind::Effect(current) = &mut node.kind else {
+            return Err(GraphError::NodeNotFound(id));
+        };
+        let old = core::mem::replace(current, effect);
+        if let Some(old) = self.spill(old) {
+            self.dead_effects.push(old);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!("graph_replace: effect node {id}");
+        Ok(())
+    }
+
+    /// Swaps the effect in an Effect node for `effect` and returns the
+    /// previous instance, with no spillover.
+    ///
+    /// Unlike [`replace_effect()`](Self::replace_effect), the incoming
+    /// instance is used as-is: it keeps its internal state and is not
+    /// re-initialized for this graph's sample rate, so it must already run at
+    /// that rate. This is how a running instance moves into a rebuilt graph
+    /// with its delay lines and tails intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::NodeNotFound`] (dropping `effect`) if the node
+    /// doesn't exist or isn't an Effect node.
+    pub fn swap_effect(
+        &mut self,
+        id: NodeId,
+        effect: Box<dyn EffectWithParams + Send>,
+    ) -> Result<Box<dyn EffectWithParams + Send>, GraphError> {
+        match self.nodes.get_mut(id.0 as usize).and_then(|n| n.as_mut()) {
+            Some(NodeData {
+                kind: NodeKind::Effect(current),
+                ..
+            }) => Ok(core::mem::replace(current, effect)),
+            _ => Err(GraphError::NodeNotFound(id)),
+        }
+    }
+
+    /// Moves `effect` into the spillover tails if it has a tail and spillover
+    /// is enabled; otherwise hands it back.
+    fn spill(
+        &mut self,
+        effect: Box<dyn EffectWithParams + Send>,
+    ) -> Option<Box<dyn EffectWithParams + Send>> {
+        let tail = effect.tail_samples();
+        if tail == 0 || !self.spillover_enabled {
+            return Some(effect);
+        }
+        let fade_ms = (tail as f32 / self.sample_rate * 1000.0).min(10000.0);
+        let mut fade = SmoothedParam::with_config(1.0, self.sample_rate, fade_ms);
+        fade.set_target(0.0);
+        let buf_size = self.block_size.max(1);
+        self.spillover_tails.push(SpilloverTail {
+            effect,
+            fade,
+            remaining: tail,
+            left_buf: vec![0.0; buf_size],
+            right_buf: vec![0.0; buf_size],
+        });
+        None
+    }
+
     /// Sets the bypass state of an effect node.
     ///
-    /// When bypassed, audio passes through unchanged with a click-free crossfade.
+    /// When bypassed, the node crossfades click-free to whatever its
+    /// [`BypassMode`] selects (dry input by default).
     /// Has no effect on non-Effect nodes.
     pub fn set_bypass(&mut self, id: NodeId, bypassed: bool) {
         if let Some(Some(node)) = self.nodes.get_mut(id.0 as usize)
-            && matches!(node.kind, NodeKind::Effect(_))
+            && let NodeKind::Effect(ref effect) = node.kind
         {
+            if bypassed && !node.bypassed {
+                node.tail_remaining = effect.tail_samples();
+            }
             node.bypassed = bypassed;
             node.bypass_fade
                 .set_target(if bypassed { 0.0 } else { 1.0 });
         }
     }
 
+    /// Sets what a bypassed effect node outputs.
+    ///
+    /// Takes effect immediately, including for a node that is already
+    /// bypassed. No recompilation is needed. Has no effect on non-Effect nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` — target node
+    /// * `mode` — [`BypassMode::Dry`], [`BypassMode::Mute`], or [`BypassMode::Tails`]
+    pub fn set_bypass_mode(&mut self, id: NodeId, mode: BypassMode) {
+        if let Some(Some(node)) = self.nodes.get_mut(id.0 as usize)
+            && matches!(node.kind, NodeKind::Effect(_))
+        {
+            node.bypass_mode = mode;
+        }
+    }
+
+    /// Returns the bypass mode of a node ([`BypassMode::Dry`] if not found).
+    pub fn bypass_mode(&self, id: NodeId) -> BypassMode {
+        self.nodes
+            .get(id.0 as usize)
+            .and_then(|n| n.as_ref())
+            .map_or(BypassMode::Dry, |n| n.bypass_mode)
+    }
+
     /// Returns whether the node is bypassed.
     pub fn is_bypassed(&self, id: NodeId) -> bool {
         self.nodes
@@ -775,6 +1011,68 @@ impl ProcessingGraph {
         Some((&node.tap_buf.left, &node.tap_buf.right))
     }
 
+    /// Solos an effect node: its output replaces the graph output.
+    ///
+    /// Lets a user hear what one point of the graph contributes — a single
+    /// branch of a parallel split, or the signal halfway down a chain — without
+    /// rewiring. The graph keeps processing as usual; only the final output is
+    /// swapped for the node's [tap](Self::set_tap), with a ~5 ms crossfade on
+    /// every change. `None` returns to the normal output. No recompilation is
+    /// needed.
+    ///
+    /// The node's tap is enabled if it is not already, and released again when
+    /// the solo moves elsewhere. Removing the soloed node clears the solo.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::NodeNotFound`] if the node does not exist, or
+    /// [`GraphError::InvalidConnection`] if it is not an Effect node.
+    pub fn set_solo(&mut self, id: Option<NodeId>) -> Result<(), GraphError> {
+        if let Some(id) = id
+            && !matches!(self.get_node(id)?.kind, NodeKind::Effect(_))
+        {
+            return Err(GraphError::InvalidConnection(format!(
+                "{id} is not an effect node and cannot be soloed"
+            )));
+        }
+        if id == self.solo {
+            return Ok(());
+        }
+
+        // The outgoing source keeps its tap for the fade; older ones go.
+        let keep = self.solo;
+        let released: Vec<NodeId> = self
+            .solo_taps
+            .iter()
+            .copied()
+            .filter(|&t| Some(t) != keep && Some(t) != id)
+            .collect();
+        for t in released {
+            self.set_tap(t, false);
+        }
+        self.solo_taps.retain(|&t| Some(t) == keep || Some(t) == id);
+
+        if let Some(id) = id
+            && !self.nodes[id.0 as usize].as_ref().is_some_and(|n| n.tapped)
+        {
+            self.set_tap(id, true);
+            self.solo_taps.push(id);
+        }
+
+        self.solo_prev = self.solo;
+        self.solo = id;
+        self.solo_fade = SmoothedParam::fast(0.0, self.sample_rate);
+        self.solo_fade.set_target(1.0);
+        #[cfg(feature = "tracing")]
+        tracing::debug!("graph_solo: {id:?}");
+        Ok(())
+    }
+
+    /// Returns the soloed node, if any.
+    pub fn solo(&self) -> Option<NodeId> {
+        self.solo
+    }
+
     /// Returns the per-block peak output level `(left, right)` for an effect node.
     ///
     /// Peak is the maximum absolute sample value observed in the output buffer
@@ -864,16 +1162,63 @@ impl ProcessingGraph {
     /// # Errors
     ///
     /// Returns [`GraphError`] if:
-    /// - The graph doesn't have exactly 1 Input and 1 Output node
+    /// - The graph has no Input or no Output node
     /// - The graph contains a cycle (defensive — primary check is at `connect()` time)
     /// - The graph is empty
     pub fn compile(&mut self) -> Result<Arc<CompiledSchedule>, GraphError> {
-        // Validate: exactly 1 Input, 1 Output.
+        let plan = self.plan_schedule()?;
+        let schedule = Arc::new(plan.schedule);
+
+        // Pre-allocate audio pool for RT-safe execution.
+        if self.audio_pool.count() < schedule.buffer_count {
+            self.audio_pool = BufferPool::new(schedule.buffer_count, self.block_size);
+        } else {
+            self.audio_pool.resize_all(self.block_size);
+        }
+
+        // Build persistent compensation delay lines (state persists across blocks).
+        self.audio_delay_lines = schedule
+            .delay_sample_counts
+            .iter()
+            .map(|&samples| CompensationDelay::new(samples))
+            .collect();
+
+        // Build persistent feedback delay lines (1 block each, state persists).
+        self.feedback_delay_lines = (0..schedule.feedback_delay_count)
+            .map(|_| CompensationDelay::new(self.block_size))
+            .collect();
+
+        // One crossfade cache per output bus, sized to the current block.
+        self.crossfade
+            .resize_with(plan.output_count, || StereoBuffer::new(self.block_size));
+        for cache in &mut self.crossfade {
+            cache.resize(self.block_size);
+        }
+
+        self.apply_bypass_delays(&plan.own_latencies);
+        self.compiled_latencies = plan.own_latencies;
+
+        // Click-free swap: keep old schedule for crossfade.
+        if self.compiled.is_some() {
+            self.prev_compiled = self.compiled.take();
+            self.swap_fade = SmoothedParam::fast(0.0, self.sample_rate);
+            self.swap_fade.set_target(1.0);
+            #[cfg(feature = "tracing")]
+            tracing::debug!("graph_swap: crossfade from previous schedule");
+        }
+
+        self.compiled = Some(Arc::clone(&schedule));
+        Ok(schedule)
+    }
+
+    /// Sorts the graph and builds a schedule without touching runtime state.
+    fn plan_schedule(&self) -> Result<SchedulePlan, GraphError> {
+        // Validate: at least 1 Input and 1 Output bus.
         let (input_count, output_count) = self.count_io_nodes();
-        if input_count != 1 {
+        if input_count == 0 {
             return Err(GraphError::InvalidInputCount(input_count));
         }
-        if output_count != 1 {
+        if output_count == 0 {
             return Err(GraphError::InvalidOutputCount(output_count));
         }
 
@@ -882,12 +1227,9 @@ impl ProcessingGraph {
         #[cfg(feature = "tracing")]
         tracing::debug!("graph_sort: {} nodes in topo order", sorted.len());
 
-        // Find input and output node indices.
-        let input_node_idx = self.find_node_by_kind_is_input();
-        let output_node_idx = self.find_node_by_kind_is_output();
-
         // Compute per-node cumulative latency (longest path from input).
-        let node_latency = self.compute_node_latencies(&sorted);
+        let own_latencies = self.compensated_latencies();
+        let node_latency = self.compute_node_latencies(&sorted, &own_latencies);
 
         // Emit raw schedule steps (with inline delay compensation).
         let (
@@ -896,23 +1238,26 @@ impl ProcessingGraph {
             edge_last_read,
             delay_sample_counts,
             _emit_feedback_count,
-        ) = self.emit_raw_schedule(&sorted, input_node_idx, output_node_idx, &node_latency);
+        ) = self.emit_raw_schedule(&sorted, &node_latency);
 
         // Buffer liveness analysis: assign buffer slots.
         let (final_steps, buffer_count) =
             Self::assign_buffers(raw_steps, &edge_first_write, &edge_last_read);
 
-        // Total latency is the max latency at the Output node.
-        let total_latency = sorted
+        // Each output bus reports its own path latency; the total is the
+        // worst of them. Without compensation, paths are not aligned, so
+        // report the longest live path.
+        let reported_latency = if self.pdc == PdcMode::Off {
+            self.compute_node_latencies(&sorted, &self.live_latencies())
+        } else {
+            node_latency
+        };
+        let output_latencies: Vec<usize> = self
+            .io_nodes(false)
             .iter()
-            .filter(|&&idx| {
-                self.nodes[idx]
-                    .as_ref()
-                    .is_some_and(|n| matches!(n.kind, NodeKind::Output))
-            })
-            .map(|&idx| node_latency[idx])
-            .max()
-            .unwrap_or(0);
+            .map(|id| reported_latency[id.0 as usize])
+            .collect();
+        let total_latency = output_latencies.iter().copied().max().unwrap_or(0);
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -940,48 +1285,21 @@ impl ProcessingGraph {
             .count();
 
         // Build the compiled schedule (lightweight — no pool or delay lines).
-        let schedule = Arc::new(CompiledSchedule {
+        let schedule = CompiledSchedule {
             steps: final_steps,
             buffer_count,
-            delay_sample_counts: delay_sample_counts.clone(),
+            delay_sample_counts,
             total_latency,
+            input_bus_count: input_count,
+            output_latencies,
             block_size: self.block_size,
             feedback_delay_count,
-        });
-
-        // Pre-allocate audio pool for RT-safe execution.
-        if self.audio_pool.count() < buffer_count {
-            self.audio_pool = BufferPool::new(buffer_count, self.block_size);
-        } else {
-            self.audio_pool.resize_all(self.block_size);
-        }
-
-        // Build persistent compensation delay lines (state persists across blocks).
-        self.audio_delay_lines = delay_sample_counts
-            .into_iter()
-            .map(CompensationDelay::new)
-            .collect();
-
-        // Build persistent feedback delay lines (1 block each, state persists).
-        self.feedback_delay_lines = (0..feedback_delay_count)
-            .map(|_| CompensationDelay::new(self.block_size))
-            .collect();
-
-        // Ensure crossfade buffers match current block size.
-        self.crossfade_left.resize(self.block_size, 0.0);
-        self.crossfade_right.resize(self.block_size, 0.0);
-
-        // Click-free swap: keep old schedule for crossfade.
-        if self.compiled.is_some() {
-            self.prev_compiled = self.compiled.take();
-            self.swap_fade = SmoothedParam::fast(0.0, self.sample_rate);
-            self.swap_fade.set_target(1.0);
-            #[cfg(feature = "tracing")]
-            tracing::debug!("graph_swap: crossfade from previous schedule");
-        }
-
-        self.compiled = Some(Arc::clone(&schedule));
-        Ok(schedule)
+        };
+        Ok(SchedulePlan {
+            schedule,
+            own_latencies,
+            output_count,
+        })
     }
 
     // --- Kahn's topological sort ---
@@ -1060,8 +1378,6 @@ impl ProcessingGraph {
     fn emit_raw_schedule(
         &self,
         sorted: &[usize],
-        _input_node_idx: usize,
-        _output_node_idx: usize,
         node_latency: &[usize],
     ) -> (
         Vec<RawStep>,
@@ -1095,6 +1411,15 @@ impl ProcessingGraph {
         // Track each node's primary output vbuf for sidechain consumers to look up.
         let mut node_output_vbuf: Vec<Option<usize>> = vec![None; self.nodes.len()];
 
+        // Bus number of each Input/Output node, by node index.
+        let mut bus_of = vec![0usize; self.nodes.len()];
+        for (bus, id) in self.io_nodes(true).into_iter().enumerate() {
+            bus_of[id.0 as usize] = bus;
+        }
+        for (bus, id) in self.io_nodes(false).into_iter().enumerate() {
+            bus_of[id.0 as usize] = bus;
+        }
+
         for &node_idx in sorted {
             let node = self.nodes[node_idx].as_ref().unwrap();
             let _step_idx = steps.len();
@@ -1104,7 +1429,10 @@ impl ProcessingGraph {
                     // Write external input into each outgoing edge's buffer.
                     for edge_id in &node.outgoing {
                         if let Some(vbuf) = edge_to_vbuf[edge_id.0 as usize] {
-                            steps.push(RawStep::WriteInput { vbuf });
+                            steps.push(RawStep::WriteInput {
+                                vbuf,
+                                bus: bus_of[node_idx],
+                            });
                             if vbuf_first_write[vbuf] == usize::MAX {
                                 vbuf_first_write[vbuf] = steps.len() - 1;
                             }
@@ -1121,7 +1449,10 @@ impl ProcessingGraph {
                     if let Some(edge_id) = node.incoming.first()
                         && let Some(vbuf) = edge_to_vbuf[edge_id.0 as usize]
                     {
-                        steps.push(RawStep::ReadOutput { vbuf });
+                        steps.push(RawStep::ReadOutput {
+                            vbuf,
+                            bus: bus_of[node_idx],
+                        });
                         vbuf_last_read[vbuf] = vbuf_last_read[vbuf].max(steps.len() - 1);
                     }
                 }
@@ -1206,7 +1537,8 @@ impl ProcessingGraph {
                 }
 
                 NodeKind::Split => {
-                    // Copy from the single input to each output.
+                    // Copy from the single input to each output, SPLIT_COPY_WIDTH
+                    // destinations per step.
                     let in_vbuf = node
                         .incoming
                         .first()
@@ -1220,27 +1552,23 @@ impl ProcessingGraph {
                     if let Some(iv) = in_vbuf
                         && !out_vbufs.is_empty()
                     {
-                        debug_assert!(
-                            out_vbufs.len() <= MAX_SPLIT_TARGETS,
-                            "split fan-out {} exceeds MAX_SPLIT_TARGETS {}",
-                            out_vbufs.len(),
-                            MAX_SPLIT_TARGETS
-                        );
-                        steps.push(RawStep::SplitCopy {
-                            source_vbuf: iv,
-                            dest_vbufs: out_vbufs.clone(),
-                        });
-                        let s = steps.len() - 1;
-                        vbuf_last_read[iv] = vbuf_last_read[iv].max(s);
-                        for &ov in &out_vbufs {
-                            if vbuf_first_write[ov] == usize::MAX {
-                                vbuf_first_write[ov] = s;
+                        for chunk in out_vbufs.chunks(SPLIT_COPY_WIDTH) {
+                            steps.push(RawStep::SplitCopy {
+                                source_vbuf: iv,
+                                dest_vbufs: chunk.to_vec(),
+                            });
+                            let s = steps.len() - 1;
+                            vbuf_last_read[iv] = vbuf_last_read[iv].max(s);
+                            for &ov in chunk {
+                                if vbuf_first_write[ov] == usize::MAX {
+                                    vbuf_first_write[ov] = s;
+                                }
                             }
                         }
                     }
                 }
 
-                NodeKind::Merge => {
+                NodeKind::Merge | NodeKind::AuxBus => {
                     // Sum all incoming into the single output with latency compensation.
                     let out_vbuf = node
                         .outgoing
@@ -1290,8 +1618,13 @@ impl ProcessingGraph {
                             .max()
                             .unwrap_or(0);
 
+                        // Merges average their paths; aux buses sum them at unity.
                         let path_count = incoming_with_nodes.len();
-                        let gain = 1.0 / path_count as f32;
+                        let gain = if matches!(node.kind, NodeKind::AuxBus) {
+                            1.0
+                        } else {
+                            1.0 / path_count as f32
+                        };
                         #[cfg(feature = "tracing")]
                         tracing::debug!("  merge_gain: {path_count} paths, gain={gain:.3}");
 
@@ -1327,6 +1660,46 @@ impl ProcessingGraph {
                     }
                 }
 
+                NodeKind::Send(send) => {
+                    // One input; the edge to the aux bus carries the send, any
+                    // other outgoing edge carries the faded through signal.
+                    let in_vbuf = node
+                        .incoming
+                        .first()
+                        .and_then(|eid| edge_to_vbuf[eid.0 as usize]);
+                    let mut through_vbuf = None;
+                    let mut send_vbuf = None;
+                    for eid in &node.outgoing {
+                        let Some(edge) = self.edges[eid.0 as usize].as_ref() else {
+                            continue;
+                        };
+                        if edge.to == send.aux {
+                            send_vbuf = edge_to_vbuf[eid.0 as usize];
+                        } else {
+                            through_vbuf = edge_to_vbuf[eid.0 as usize];
+                        }
+                    }
+
+                    if let Some(iv) = in_vbuf
+                        && (through_vbuf.is_some() || send_vbuf.is_some())
+                    {
+                        steps.push(RawStep::Send {
+                            node_idx,
+                            source_vbuf: iv,
+                            through_vbuf,
+                            send_vbuf,
+                        });
+                        let s = steps.len() - 1;
+                        vbuf_last_read[iv] = vbuf_last_read[iv].max(s);
+                        for ov in through_vbuf.into_iter().chain(send_vbuf) {
+                            if vbuf_first_write[ov] == usize::MAX {
+                                vbuf_first_write[ov] = s;
+                            }
+                        }
+                        node_output_vbuf[node_idx] = through_vbuf;
+                    }
+                }
+
                 NodeKind::SubGraph(_) => {
                     // Sub-graph: route like a single Effect node — one input vbuf, one output vbuf.
                     let in_vbuf = node
@@ -1436,8 +1809,9 @@ impl ProcessingGraph {
         let steps = raw_steps
             .into_iter()
             .map(|raw| match raw {
-                RawStep::WriteInput { vbuf } => ProcessStep::WriteInput {
+                RawStep::WriteInput { vbuf, bus } => ProcessStep::WriteInput {
                     buffer_idx: vbuf_to_phys[vbuf].unwrap_or(0),
+                    bus,
                 },
                 RawStep::ProcessEffect {
                     node_idx,
@@ -1457,10 +1831,11 @@ impl ProcessingGraph {
                     source_vbuf,
                     dest_vbufs,
                 } => {
-                    let mut dest_arr = [0usize; MAX_SPLIT_TARGETS];
-                    let count = dest_vbufs.len().min(MAX_SPLIT_TARGETS);
-                    for (i, v) in dest_vbufs.into_iter().enumerate().take(count) {
-                        dest_arr[i] = vbuf_to_phys[v].unwrap_or(0);
+                    let mut dest_arr = [0usize; SPLIT_COPY_WIDTH];
+                    let count = dest_vbufs.len();
+                    debug_assert!(count <= SPLIT_COPY_WIDTH, "split chunk of {count}");
+                    for (slot, v) in dest_arr.iter_mut().zip(dest_vbufs) {
+                        *slot = vbuf_to_phys[v].unwrap_or(0);
                     }
                     ProcessStep::SplitCopy {
                         source_buf: vbuf_to_phys[source_vbuf].unwrap_or(0),
@@ -1480,8 +1855,9 @@ impl ProcessingGraph {
                     dest_buf: vbuf_to_phys[dest_vbuf].unwrap_or(0),
                     gain,
                 },
-                RawStep::ReadOutput { vbuf } => ProcessStep::ReadOutput {
+                RawStep::ReadOutput { vbuf, bus } => ProcessStep::ReadOutput {
                     buffer_idx: vbuf_to_phys[vbuf].unwrap_or(0),
+                    bus,
                 },
                 RawStep::DelayCompensate {
                     vbuf,
@@ -1510,6 +1886,17 @@ impl ProcessingGraph {
                     buffer_idx: vbuf_to_phys[vbuf].unwrap_or(0),
                     block_len,
                 },
+                RawStep::Send {
+                    node_idx,
+                    source_vbuf,
+                    through_vbuf,
+                    send_vbuf,
+                } => ProcessStep::Send {
+                    node_idx,
+                    source_buf: vbuf_to_phys[source_vbuf].unwrap_or(0),
+                    through_buf: through_vbuf.and_then(|v| vbuf_to_phys[v]),
+                    send_buf: send_vbuf.and_then(|v| vbuf_to_phys[v]),
+                },
             })
             .collect();
 
@@ -1519,18 +1906,14 @@ impl ProcessingGraph {
     /// Computes cumulative latency to each node (longest path from Input).
     ///
     /// For each node in topological order, its latency is the maximum incoming
-    /// latency plus its own latency contribution.
-    fn compute_node_latencies(&self, sorted: &[usize]) -> Vec<usize> {
+    /// latency plus its own latency contribution, taken from `own` by node slot.
+    fn compute_node_latencies(&self, sorted: &[usize], own: &[usize]) -> Vec<usize> {
         let n = self.nodes.len();
         let mut node_latency = vec![0usize; n];
 
         for &node_idx in sorted {
             let node = self.nodes[node_idx].as_ref().unwrap();
-
-            let own_latency = match &node.kind {
-                NodeKind::Effect(effect) => effect.latency_samples(),
-                _ => 0,
-            };
+            let own_latency = own.get(node_idx).copied().unwrap_or(0);
 
             let max_incoming = node
                 .incoming
@@ -1553,6 +1936,10 @@ impl ProcessingGraph {
 
     /// Processes one block of stereo audio through the compiled graph.
     ///
+    /// Feeds the main input bus and writes the main output bus. Other input
+    /// buses receive silence and other output buses are discarded; use
+    /// [`process_block_buses()`](Self::process_block_buses) to drive them.
+    ///
     /// If the graph was recently recompiled, crossfades from cached previous
     /// output toward the new schedule's output over ~5ms. Only the new schedule
     /// executes — no double-processing of effects.
@@ -1568,10 +1955,34 @@ impl ProcessingGraph {
         left_out: &mut [f32],
         right_out: &mut [f32],
     ) {
-        let len = left_in.len();
         debug_assert_eq!(left_in.len(), right_in.len());
-        debug_assert!(left_out.len() >= len);
-        debug_assert!(right_out.len() >= len);
+        debug_assert!(left_out.len() >= left_in.len());
+        debug_assert!(right_out.len() >= left_in.len());
+        self.process_block_buses(&[(left_in, right_in)], &mut [(left_out, right_out)]);
+    }
+
+    /// Processes one block of stereo audio with a buffer pair per bus.
+    ///
+    /// `inputs[n]` feeds input bus `n` and `outputs[n]` receives output bus
+    /// `n` (see [`input_buses()`](Self::input_buses) and
+    /// [`output_buses()`](Self::output_buses)). Buses without a buffer read
+    /// silence or are discarded; output buses with no connected source are
+    /// filled with silence. The block length is taken from the first input
+    /// bus, or from the first output bus when no inputs are given.
+    ///
+    /// Schedule-swap crossfades cover every output bus. Solo monitoring and
+    /// spillover tails of removed effects only affect the main bus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `compile()` has not been called, or if buffer lengths don't
+    /// match the graph's block size.
+    pub fn process_block_buses(&mut self, inputs: &[BusInput<'_>], outputs: &mut [BusOutput<'_>]) {
+        let len = inputs
+            .first()
+            .map(|(l, _)| l.len())
+            .or_else(|| outputs.first().map(|(l, _)| l.len()))
+            .unwrap_or(0);
 
         let schedule = self
             .compiled
@@ -1589,20 +2000,37 @@ impl ProcessingGraph {
             &mut self.feedback_delay_lines,
             &mut self.sc_tmp_left,
             &mut self.sc_tmp_right,
-            left_in,
-            right_in,
-            left_out,
-            right_out,
+            len,
+            inputs,
+            outputs,
         );
 
+        // Solo: swap the main output for a node's tap, crossfading on change.
+        if let Some((left_out, right_out)) = outputs.first_mut()
+            && (self.solo.is_some() || !self.solo_fade.is_settled())
+        {
+            let from = Self::solo_source(&self.nodes, self.solo_prev, len);
+            let to = Self::solo_source(&self.nodes, self.solo, len);
+            for i in 0..len {
+                let fade = self.solo_fade.advance();
+                let (from_l, from_r) =
+                    from.map_or((left_out[i], right_out[i]), |(l, r)| (l[i], r[i]));
+                let (to_l, to_r) = to.map_or((left_out[i], right_out[i]), |(l, r)| (l[i], r[i]));
+                left_out[i] = from_l * (1.0 - fade) + to_l * fade;
+                right_out[i] = from_r * (1.0 - fade) + to_r * fade;
+            }
+        }
+
         if is_crossfading {
-            // Blend from cached previous output toward new output.
+            // Blend from cached previous output toward new output, on every bus.
             for i in 0..len {
                 let fade = self.swap_fade.advance();
-                left_out[i] = self.crossfade_left.get(i).copied().unwrap_or(0.0) * (1.0 - fade)
-                    + left_out[i] * fade;
-                right_out[i] = self.crossfade_right.get(i).copied().unwrap_or(0.0) * (1.0 - fade)
-                    + right_out[i] * fade;
+                for ((left_out, right_out), cache) in outputs.iter_mut().zip(&self.crossfade) {
+                    left_out[i] = cache.left.get(i).copied().unwrap_or(0.0) * (1.0 - fade)
+                        + left_out[i] * fade;
+                    right_out[i] = cache.right.get(i).copied().unwrap_or(0.0) * (1.0 - fade)
+                        + right_out[i] * fade;
+                }
             }
 
             // If crossfade is done, drop the old schedule reference.
@@ -1611,7 +2039,7 @@ impl ProcessingGraph {
             }
         }
 
-        // Process spillover tails and mix into output.
+        // Process spillover tails and mix into the main output.
         let mut i = 0;
         while i < self.spillover_tails.len() {
             if self.spillover_tails[i].remaining == 0 {
@@ -1631,13 +2059,15 @@ impl ProcessingGraph {
                 &mut tail.right_buf[..buf_len],
             );
             // Mix faded tail into the final output.
-            for j in 0..buf_len {
-                let fade = tail.fade.advance();
-                left_out[j] += tail.left_buf[j] * fade;
-                right_out[j] += tail.right_buf[j] * fade;
+            if let Some((left_out, right_out)) = outputs.first_mut() {
+                for j in 0..buf_len {
+                    let fade = tail.fade.advance();
+                    left_out[j] += tail.left_buf[j] * fade;
+                    right_out[j] += tail.right_buf[j] * fade;
+                }
             }
             tail.remaining = tail.remaining.saturating_sub(buf_len);
-            
+
             if tail.remaining == 0 && tail.fade.is_settled() {
                 let tail = self.spillover_tails.swap_remove(i);
                 self.dead_effects.push(tail.effect);
@@ -1647,9 +2077,25 @@ impl ProcessingGraph {
         }
 
         // Cache output for potential future crossfade.
-        let cache_len = len.min(self.crossfade_left.len());
-        self.crossfade_left[..cache_len].copy_from_slice(&left_out[..cache_len]);
-        self.crossfade_right[..cache_len].copy_from_slice(&right_out[..cache_len]);
+        for ((left_out, right_out), cache) in outputs.iter().zip(&mut self.crossfade) {
+            let cache_len = len.min(cache.len());
+            cache.left[..cache_len].copy_from_slice(&left_out[..cache_len]);
+            cache.right[..cache_len].copy_from_slice(&right_out[..cache_len]);
+        }
+    }
+
+    /// Returns the tap slices feeding the solo output for `id`, or `None` for
+    /// the normal graph output (also used if the tap has gone away).
+    fn solo_source(
+        nodes: &[Option<NodeData>],
+        id: Option<NodeId>,
+        len: usize,
+    ) -> Option<(&[f32], &[f32])> {
+        let node = nodes.get(id?.0 as usize)?.as_ref()?;
+        if !node.tapped || node.tap_buf.len() < len {
+            return None;
+        }
+        Some((&node.tap_buf.left, &node.tap_buf.right))
     }
 
     /// Executes a compiled schedule against the given node state.
@@ -1670,13 +2116,16 @@ impl ProcessingGraph {
         feedback_delay_lines: &mut [CompensationDelay],
         sc_tmp_left: &mut [f32],
         sc_tmp_right: &mut [f32],
-        left_in: &[f32],
-        right_in: &[f32],
-        left_out: &mut [f32],
-        right_out: &mut [f32],
+        len: usize,
+        inputs: &[BusInput<'_>],
+        outputs: &mut [BusOutput<'_>],
     ) {
-        let len = left_in.len();
         pool.clear_all();
+        // Output buses with no connected source stay silent.
+        for (left_out, right_out) in outputs.iter_mut() {
+            left_out[..len].fill(0.0);
+            right_out[..len].fill(0.0);
+        }
 
         let mut execute =
             |nodes: &mut [Option<NodeData>],
@@ -1685,10 +2134,15 @@ impl ProcessingGraph {
              feedback_delay_lines: &mut [CompensationDelay]| {
                 for step in &schedule.steps {
                     match step {
-                        ProcessStep::WriteInput { buffer_idx } => {
+                        ProcessStep::WriteInput { buffer_idx, bus } => {
                             let buf = pool.get_mut(*buffer_idx);
-                            buf.left[..len].copy_from_slice(&left_in[..len]);
-                            buf.right[..len].copy_from_slice(&right_in[..len]);
+                            if let Some((left_in, right_in)) = inputs.get(*bus) {
+                                buf.left[..len].copy_from_slice(&left_in[..len]);
+                                buf.right[..len].copy_from_slice(&right_in[..len]);
+                            } else {
+                                buf.left[..len].fill(0.0);
+                                buf.right[..len].fill(0.0);
+                            }
                         }
 
                         ProcessStep::ProcessEffect {
@@ -1714,23 +2168,47 @@ impl ProcessingGraph {
                                     node.peak_in = (peak_l, peak_r);
                                 }
 
+                                let mode = node.bypass_mode;
                                 let bypass_settled = node.bypassed && node.bypass_fade.is_settled();
                                 let bypass_fading = !bypass_settled
                                     && (node.bypassed || !node.bypass_fade.is_settled());
-
-                                if bypass_settled {
-                                    // Fully bypassed and fade complete — copy input to output,
-                                    // skip effect processing entirely (massive CPU savings).
-                                    if *input_buf != *output_buf {
-                                        let (inp, out) =
-                                            pool.get_ref_and_mut(*input_buf, *output_buf);
-                                        out.left[..len].copy_from_slice(&inp.left[..len]);
-                                        out.right[..len].copy_from_slice(&inp.right[..len]);
+                                // Tails bypass keeps running the effect on silence
+                                // until its reported tail has rung out.
+                                let tail_ringing = bypass_settled
+                                    && mode == BypassMode::Tails
+                                    && node.tail_remaining > 0;
+
+                                if bypass_settled && !tail_ringing {
+                                    // Fully bypassed and fade complete — skip effect
+                                    // processing entirely (massive CPU savings).
+                                    if mode == BypassMode::Mute {
+                                        let out = pool.get_mut(*output_buf);
+                                        out.left[..len].fill(0.0);
+                                        out.right[..len].fill(0.0);
+                                    } else {
+                                        // If input_buf == output_buf, data is already in place.
+                                        if *input_buf != *output_buf {
+                                            let (inp, out) =
+                                                pool.get_ref_and_mut(*input_buf, *output_buf);
+                                            out.left[..len].copy_from_slice(&inp.left[..len]);
+                                            out.right[..len].copy_from_slice(&inp.right[..len]);
+                                        }
+                                        // Keep the node's latency so parallel paths stay aligned.
+                                        let out = pool.get_mut(*output_buf);
+                                        node.bypass_delay.process_block_inplace(
+                                            &mut out.left[..len],
+                                            &mut out.right[..len],
+                                        );
                                     }
-                                    // If input_buf == output_buf, data is already in place.
                                 } else {
                                     // Phase 1: Save dry signal before effect processing.
-                                    if bypass_fading {
+                                    // A latent node feeds its dry delay every block so the
+                                    // delayed dry signal is ready the moment bypass engages.
+                                    let delay_dry = node.bypass_delay.delay_samples() > 0;
+                                    let keep_dry = (bypass_fading && mode != BypassMode::Mute)
+                                        || tail_ringing
+                                        || delay_dry;
+                                    if keep_dry {
                                         let src = pool.get(*input_buf);
                                         node.bypass_buf.left[..len]
                                             .copy_from_slice(&src.left[..len]);
@@ -1738,6 +2216,28 @@ impl ProcessingGraph {
                                             .copy_from_slice(&src.right[..len]);
                                     }
 
+                                    // Tails: the effect sees the dry signal gated by the
+                                    // bypass envelope, staged in the output buffer so the
+                                    // (possibly shared) input buffer stays untouched.
+                                    let effect_in = if keep_dry && mode == BypassMode::Tails {
+                                        let mut gate = node.bypass_fade.clone();
+                                        let out = pool.get_mut(*output_buf);
+                                        for i in 0..len {
+                                            let g = gate.advance();
+                                            out.left[i] = node.bypass_buf.left[i] * g;
+                                            out.right[i] = node.bypass_buf.right[i] * g;
+                                        }
+                                        *output_buf
+                                    } else {
+                                        *input_buf
+                                    };
+                                    if delay_dry {
+                                        node.bypass_delay.process_block_inplace(
+                                            &mut node.bypass_buf.left[..len],
+                                            &mut node.bypass_buf.right[..len],
+                                        );
+                                    }
+
                                     // Phase 2: Process through effect.
                                     let cycles_start = Self::read_cycles();
                                     if let NodeKind::Effect(ref mut effect) = node.kind {
@@ -1754,8 +2254,8 @@ impl ProcessingGraph {
                                             let sc_l = &sc_tmp_left[..proc_len];
                                             let sc_r = &sc_tmp_right[..proc_len];
 
-                                            if *input_buf == *output_buf {
-                                                let buf = pool.get_mut(*input_buf);
+                                            if effect_in == *output_buf {
+                                                let buf = pool.get_mut(*output_buf);
                                                 effect.process_block_stereo_inplace_with_sidechain(
                                                     &mut buf.left[..proc_len],
                                                     &mut buf.right[..proc_len],
@@ -1764,7 +2264,7 @@ impl ProcessingGraph {
                                                 );
                                             } else {
                                                 let (inp, out) =
-                                                    pool.get_ref_and_mut(*input_buf, *output_buf);
+                                                    pool.get_ref_and_mut(effect_in, *output_buf);
                                                 effect.process_block_stereo_with_sidechain(
                                                     &inp.left[..proc_len],
                                                     &inp.right[..proc_len],
@@ -1774,15 +2274,15 @@ impl ProcessingGraph {
                                                     sc_r,
                                                 );
                                             }
-                                        } else if *input_buf == *output_buf {
-                                            let buf = pool.get_mut(*input_buf);
+                                        } else if effect_in == *output_buf {
+                                            let buf = pool.get_mut(*output_buf);
                                             effect.process_block_stereo_inplace(
                                                 &mut buf.left[..proc_len],
                                                 &mut buf.right[..proc_len],
                                             );
                                         } else {
                                             let (inp, out) =
-                                                pool.get_ref_and_mut(*input_buf, *output_buf);
+                                                pool.get_ref_and_mut(effect_in, *output_buf);
                                             effect.process_block_stereo(
                                                 &inp.left[..proc_len],
                                                 &inp.right[..proc_len],
@@ -1794,22 +2294,40 @@ impl ProcessingGraph {
                                     node.last_cycles =
                                         Self::read_cycles().wrapping_sub(cycles_start);
 
-                                    // Phase 3: Crossfade between dry and wet during fade.
+                                    // Phase 3: Crossfade toward the bypass target during fade.
                                     if bypass_fading {
                                         let out = pool.get_mut(*output_buf);
                                         for i in 0..len {
                                             let fade = node.bypass_fade.advance();
-                                            out.left[i] = wet_dry_mix(
-                                                node.bypass_buf.left[i],
-                                                out.left[i],
-                                                fade,
-                                            );
-                                            out.right[i] = wet_dry_mix(
-                                                node.bypass_buf.right[i],
-                                                out.right[i],
-                                                fade,
-                                            );
+                                            let (dry_l, dry_r) =
+                                                (node.bypass_buf.left[i], node.bypass_buf.right[i]);
+                                            match mode {
+                                                BypassMode::Mute => {
+                                                    out.left[i] *= fade;
+                                                    out.right[i] *= fade;
+                                                }
+                                                BypassMode::Tails => {
+                                                    // Effect input is already gated, so its
+                                                    // output is the fading wet signal plus tail.
+                                                    out.left[i] += dry_l * (1.0 - fade);
+                                                    out.right[i] += dry_r * (1.0 - fade);
+                                                }
+                                                _ => {
+                                                    out.left[i] =
+                                                        wet_dry_mix(dry_l, out.left[i], fade);
+                                                    out.right[i] =
+                                                        wet_dry_mix(dry_r, out.right[i], fade);
+                                                }
+                                            }
+                                        }
+                                    } else if tail_ringing {
+                                        let out = pool.get_mut(*output_buf);
+                                        for i in 0..len {
+                                            out.left[i] += node.bypass_buf.left[i];
+                                            out.right[i] += node.bypass_buf.right[i];
                                         }
+                                        node.tail_remaining =
+                                            node.tail_remaining.saturating_sub(len);
                                     }
                                 }
 
@@ -1884,10 +2402,12 @@ impl ProcessingGraph {
                             }
                         }
 
-                        ProcessStep::ReadOutput { buffer_idx } => {
-                            let buf = pool.get(*buffer_idx);
-                            left_out[..len].copy_from_slice(&buf.left[..len]);
-                            right_out[..len].copy_from_slice(&buf.right[..len]);
+                        ProcessStep::ReadOutput { buffer_idx, bus } => {
+                            if let Some((left_out, right_out)) = outputs.get_mut(*bus) {
+                                let buf = pool.get(*buffer_idx);
+                                left_out[..len].copy_from_slice(&buf.left[..len]);
+                                right_out[..len].copy_from_slice(&buf.right[..len]);
+                            }
                         }
 
                         ProcessStep::FeedbackDelay {
@@ -1921,6 +2441,47 @@ impl ProcessingGraph {
                             buf.right[1..len].fill(r0);
                         }
 
+                        ProcessStep::Send {
+                            node_idx,
+                            source_buf,
+                            through_buf,
+                            send_buf,
+                        } => {
+                            // The source buffer is still live during this step, so
+                            // neither output aliases it (see `assign_buffers`).
+                            if let Some(Some(node)) = nodes.get_mut(*node_idx)
+                                && let NodeKind::Send(ref mut send) = node.kind
+                            {
+                                // Through path: source × fader.
+                                if let Some(through) = *through_buf {
+                                    let (src, dst) = pool.get_ref_and_mut(*source_buf, through);
+                                    for i in 0..len {
+                                        let fader = send.fader.advance();
+                                        dst.left[i] = src.left[i] * fader;
+                                        dst.right[i] = src.right[i] * fader;
+                                    }
+                                }
+                                // Send path: source × level, scaled by the fader as
+                                // far as the pre→post blend says.
+                                if let Some(aux) = *send_buf {
+                                    let fader_live = through_buf.is_none();
+                                    let (src, dst) = pool.get_ref_and_mut(*source_buf, aux);
+                                    for i in 0..len {
+                                        let fader = if fader_live {
+                                            send.fader.advance()
+                                        } else {
+                                            send.fader.get()
+                                        };
+                                        let post = send.post.advance();
+                                        let gain =
+                                            send.level.advance() * (1.0 - post + post * fader);
+                                        dst.left[i] = src.left[i] * gain;
+                                        dst.right[i] = src.right[i] * gain;
+                                    }
+                                }
+                            }
+                        }
+
                         ProcessStep::ProcessSubGraph {
                             node_idx,
                             input_buf,
@@ -1963,9 +2524,12 @@ impl ProcessingGraph {
     pub fn set_sample_rate(&mut self, sample_rate: f32) {
         self.sample_rate = sample_rate;
         self.swap_fade.set_sample_rate(sample_rate);
+        self.solo_fade.set_sample_rate(sample_rate);
         for node in self.nodes.iter_mut().flatten() {
-            if let NodeKind::Effect(ref mut effect) = node.kind {
-                effect.set_sample_rate(sample_rate);
+            match node.kind {
+                NodeKind::Effect(ref mut effect) => effect.set_sample_rate(sample_rate),
+                NodeKind::Send(ref mut send) => send.set_sample_rate(sample_rate),
+                _ => {}
             }
             node.bypass_fade.set_sample_rate(sample_rate);
         }
@@ -1980,11 +2544,15 @@ impl ProcessingGraph {
     /// Also resizes pre-allocated buffers to match the new block size.
     pub fn set_block_size(&mut self, block_size: usize) {
         self.block_size = block_size;
-        self.crossfade_left.resize(block_size, 0.0);
-        self.crossfade_right.resize(block_size, 0.0);
+        for cache in &mut self.crossfade {
+            cache.resize(block_size);
+        }
         self.audio_pool.resize_all(block_size);
         for node in self.nodes.iter_mut().flatten() {
             node.bypass_buf.resize(block_size);
+            if node.tapped {
+                node.tap_buf.resize(block_size);
+            }
         }
     }
 
@@ -1993,12 +2561,16 @@ impl ProcessingGraph {
     /// Also clears all active spillover tails.
     pub fn reset(&mut self) {
         for node in self.nodes.iter_mut().flatten() {
-            if let NodeKind::Effect(ref mut effect) = node.kind {
-                effect.reset();
+            match node.kind {
+                NodeKind::Effect(ref mut effect) => effect.reset(),
+                NodeKind::Send(ref mut send) => send.snap(),
+                _ => {}
             }
             node.bypass_fade.snap_to_target();
+            node.bypass_delay.clear();
         }
         self.swap_fade.snap_to_target();
+        self.solo_fade.snap_to_target();
         self.prev_compiled = None;
         for dl in &mut self.audio_delay_lines {
             dl.clear();
@@ -2174,13 +2746,34 @@ impl ProcessingGraph {
             )));
         }
 
-        // Merge: exactly 1 outgoing.
-        if matches!(from_node.kind, NodeKind::Merge) && !from_node.outgoing.is_empty() {
+        // Merge and aux bus: exactly 1 outgoing.
+        if matches!(from_node.kind, NodeKind::Merge | NodeKind::AuxBus)
+            && !from_node.outgoing.is_empty()
+        {
             return Err(GraphError::InvalidConnection(format!(
                 "Merge node {from} already has an outgoing edge"
             )));
         }
 
+        // Send: 1 incoming, and 1 outgoing besides the edge to its aux bus.
+        if matches!(to_node.kind, NodeKind::Send(_)) && !to_node.incoming.is_empty() {
+            return Err(GraphError::InvalidConnection(format!(
+                "Send node {to} already has an incoming edge"
+            )));
+        }
+        if let NodeKind::Send(ref send) = from_node.kind
+            && to != send.aux
+            && from_node.outgoing.iter().any(|eid| {
+                self.edges[eid.0 as usize]
+                    .as_ref()
+                    .is_some_and(|e| e.to != send.aux)
+            })
+        {
+            return Err(GraphError::InvalidConnection(format!(
+                "Send node {from} already has an outgoing edge"
+            )));
+        }
+
         Ok(())
     }
 
@@ -2213,26 +2806,20 @@ impl ProcessingGraph {
         (inputs, outputs)
     }
 
-    /// Finds the index of the Input node.
-    fn find_node_by_kind_is_input(&self) -> usize {
-        self.nodes
-            .iter()
-            .position(|n| {
-                n.as_ref()
-                    .is_some_and(|nd| matches!(nd.kind, NodeKind::Input))
-            })
-            .expect("no Input node found (should be validated before calling)")
-    }
-
-    /// Finds the index of the Output node.
-    fn find_node_by_kind_is_output(&self) -> usize {
+    /// Returns the Input (`inputs == true`) or Output nodes in bus order.
+    ///
+    /// Buses are numbered by node slot, i.e. creation order.
+    fn io_nodes(&self, inputs: bool) -> Vec<NodeId> {
         self.nodes
             .iter()
-            .position(|n| {
-                n.as_ref()
-                    .is_some_and(|nd| matches!(nd.kind, NodeKind::Output))
+            .flatten()
+            .filter(|n| match n.kind {
+                NodeKind::Input => inputs,
+                NodeKind::Output => !inputs,
+                _ => false,
             })
-            .expect("no Output node found (should be validated before calling)")
+            .map(|n| n.id)
+            .collect()
     }
 }
 
@@ -2241,6 +2828,7 @@ impl ProcessingGraph {
 enum RawStep {
     WriteInput {
         vbuf: usize,
+        bus: usize,
     },
     ProcessEffect {
         node_idx: usize,
@@ -2265,6 +2853,7 @@ enum RawStep {
     },
     ReadOutput {
         vbuf: usize,
+        bus: usize,
     },
     DelayCompensate {
         vbuf: usize,
@@ -2283,6 +2872,12 @@ enum RawStep {
         input_vbuf: usize,
         output_vbuf: usize,
     },
+    Send {
+        node_idx: usize,
+        source_vbuf: usize,
+        through_vbuf: Option<usize>,
+        send_vbuf: Option<usize>,
+    },
 }
 
 #[cfg(test)]
@@ -2575,6 +3170,116 @@ mod tests {
         assert!(matches!(result, Err(GraphError::InvalidOutputCount(0))));
     }
 
+    #[test]
+    fn test_multiple_output_buses() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let main_fx = graph.add_effect(Box::new(Gain { factor: 2.0 }));
+        let cue_fx = graph.add_effect(Box::new(Gain { factor: 3.0 }));
+        let main = graph.add_output();
+        let cue = graph.add_output_named("cue");
+        graph.connect(input, split).unwrap();
+        graph.connect(split, main_fx).unwrap();
+        graph.connect(split, cue_fx).unwrap();
+        graph.connect(main_fx, main).unwrap();
+        graph.connect(cue_fx, cue).unwrap();
+
+        let schedule = graph.compile().unwrap();
+        assert_eq!(schedule.input_bus_count(), 1);
+        assert_eq!(schedule.output_bus_count(), 2);
+        assert_eq!(graph.output_buses(), vec![main, cue]);
+        assert_eq!(graph.output_bus("cue"), Some(1));
+        assert_eq!(graph.output_id(), Some(main));
+
+        let input_buf = [1.0f32; 64];
+        let (mut main_l, mut main_r) = ([0.0f32; 64], [0.0f32; 64]);
+        let (mut cue_l, mut cue_r) = ([0.0f32; 64], [0.0f32; 64]);
+        graph.process_block_buses(
+            &[(&input_buf, &input_buf)],
+            &mut [(&mut main_l, &mut main_r), (&mut cue_l, &mut cue_r)],
+        );
+        assert!((main_l[63] - 2.0).abs() < 1e-6);
+        assert!((main_r[63] - 2.0).abs() < 1e-6);
+        assert!((cue_l[63] - 3.0).abs() < 1e-6);
+        assert!((cue_r[63] - 3.0).abs() < 1e-6);
+
+        // The classic entry point drives the main bus only.
+        graph.process_block(&input_buf, &input_buf, &mut main_l, &mut main_r);
+        assert!((main_l[63] - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_multiple_input_buses() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let main = graph.add_input();
+        let ret = graph.add_input_named("return");
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(main, merge).unwrap();
+        graph.connect(ret, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.compile().unwrap();
+        assert_eq!(graph.input_bus("return"), Some(1));
+        assert_eq!(graph.bus_name(ret), Some("return"));
+
+        let (one, half) = ([1.0f32; 64], [0.5f32; 64]);
+        let (mut left, mut right) = ([0.0f32; 64], [0.0f32; 64]);
+        graph.process_block_buses(
+            &[(&one, &one), (&half, &half)],
+            &mut [(&mut left, &mut right)],
+        );
+        // Merge normalizes by path count: (1.0 + 0.5) / 2.
+        assert!((left[10] - 0.75).abs() < 1e-6);
+
+        // Buses without a buffer read silence.
+        graph.process_block(&one, &one, &mut left, &mut right);
+        assert!((left[10] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_output_buses_report_own_latency() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let latent = graph.add_effect(Box::new(LatentGain {
+            factor: 1.0,
+            latency: 100,
+        }));
+        let main = graph.add_output();
+        let cue = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, latent).unwrap();
+        graph.connect(latent, main).unwrap();
+        graph.connect(split, cue).unwrap();
+
+        let schedule = graph.compile().unwrap();
+        assert_eq!(schedule.output_latency(0), Some(100));
+        assert_eq!(schedule.output_latency(1), Some(0));
+        assert_eq!(schedule.output_latency(2), None);
+        assert_eq!(schedule.total_latency(), 100);
+    }
+
+    #[test]
+    fn test_unconnected_output_bus_is_silent() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let main = graph.add_output();
+        graph.add_output_named("spare");
+        graph.connect(input, main).unwrap();
+        graph.compile().unwrap();
+
+        let one = [1.0f32; 64];
+        let (mut main_l, mut main_r) = ([0.0f32; 64], [0.0f32; 64]);
+        let (mut spare_l, mut spare_r) = ([9.0f32; 64], [9.0f32; 64]);
+        graph.process_block_buses(
+            &[(&one, &one)],
+            &mut [(&mut main_l, &mut main_r), (&mut spare_l, &mut spare_r)],
+        );
+        assert_eq!(main_l[0], 1.0);
+        assert!(spare_l.iter().chain(&spare_r).all(|&s| s == 0.0));
+    }
+
     #[test]
     fn test_compile_direct_passthrough() {
         let mut graph = ProcessingGraph::new(48000.0, 256);
@@ -3017,7 +3722,7 @@ mod tests {
 
     #[test]
     fn test_split_max_fanout() {
-        // Split to MAX_SPLIT_TARGETS destinations, verify all receive signal.
+        // Split to SPLIT_COPY_WIDTH destinations, verify all receive signal.
         let mut graph = ProcessingGraph::new(48000.0, 4);
         let input = graph.add_input();
         let split = graph.add_split();
@@ -3026,7 +3731,7 @@ mod tests {
         let merge = graph.add_merge();
         let output = graph.add_output();
 
-        for _ in 0..MAX_SPLIT_TARGETS {
+        for _ in 0..SPLIT_COPY_WIDTH {
             let effect = graph.add_effect(Box::new(Gain { factor: 1.0 }));
             graph.connect(split, effect).unwrap();
             graph.connect(effect, merge).unwrap();
@@ -3050,6 +3755,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_wide_fanout_chains_copy_steps() {
+        // 40 branches, each scaling by its own gain, so a dropped or
+        // misrouted copy shows up in the merged sum.
+        const BRANCHES: usize = 40;
+        let mut graph = ProcessingGraph::new(48000.0, 4);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        for i in 0..BRANCHES {
+            let effect = graph.add_effect(Box::new(Gain {
+                factor: (i + 1) as f32,
+            }));
+            graph.connect(split, effect).unwrap();
+            graph.connect(effect, merge).unwrap();
+        }
+        graph.connect(merge, output).unwrap();
+        let schedule = graph.compile().unwrap();
+
+        let copies: usize = schedule
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                ProcessStep::SplitCopy { dest_count, .. } => Some(*dest_count),
+                _ => None,
+            })
+            .sum();
+        assert_eq!(copies, BRANCHES);
+        assert_eq!(
+            schedule
+                .steps
+                .iter()
+                .filter(|s| matches!(s, ProcessStep::SplitCopy { .. }))
+                .count(),
+            BRANCHES.div_ceil(SPLIT_COPY_WIDTH)
+        );
+
+        let input_block = [1.0; 4];
+        let (mut left, mut right) = ([0.0; 4], [0.0; 4]);
+        graph.process_block(&input_block, &input_block, &mut left, &mut right);
+        // Mean of gains 1..=40 is 20.5.
+        let expected = (BRANCHES + 1) as f32 / 2.0;
+        for &s in left.iter().chain(&right) {
+            assert!((s - expected).abs() < 1e-4, "expected {expected}, got {s}");
+        }
+    }
+
     // --- Latency compensation regression tests ---
 
     /// Two sequential merges: Input→Split→[A(lat=10), B(lat=0)]→Merge→Split→[C(lat=5), D(lat=0)]→Merge→Output.
@@ -3239,6 +3993,111 @@ mod tests {
         assert!(graph.read_tap(effect_id).is_none());
     }
 
+    /// Input → Split → {Gain(2), Gain(3)} → Merge → Output, with the two
+    /// branch node IDs. The merge averages, so the mix of a 1.0 input is 2.5.
+    fn solo_diamond(block_size: usize) -> (ProcessingGraph, NodeId, NodeId) {
+        let mut graph = ProcessingGraph::new(48000.0, block_size);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let a = graph.add_effect(Box::new(Gain { factor: 2.0 }));
+        let b = graph.add_effect(Box::new(Gain { factor: 3.0 }));
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, a).unwrap();
+        graph.connect(split, b).unwrap();
+        graph.connect(a, merge).unwrap();
+        graph.connect(b, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.compile().unwrap();
+        (graph, a, b)
+    }
+
+    /// Runs `blocks` blocks of constant 1.0 input, returning the last left block.
+    fn run_constant(graph: &mut ProcessingGraph, blocks: usize) -> Vec<f32> {
+        let n = graph.block_size();
+        let input = vec![1.0; n];
+        let mut left = vec![0.0; n];
+        let mut right = vec![0.0; n];
+        for _ in 0..blocks {
+            graph.process_block(&input, &input, &mut left, &mut right);
+        }
+        left
+    }
+
+    #[test]
+    fn solo_routes_branch_to_output() {
+        let (mut graph, a, b) = solo_diamond(64);
+        assert!((run_constant(&mut graph, 1)[0] - 2.5).abs() < 1e-5);
+
+        graph.set_solo(Some(b)).unwrap();
+        assert_eq!(graph.solo(), Some(b));
+        let out = run_constant(&mut graph, 50);
+        assert!((out[63] - 3.0).abs() < 1e-4, "solo b: {}", out[63]);
+
+        graph.set_solo(Some(a)).unwrap();
+        let out = run_constant(&mut graph, 50);
+        assert!((out[63] - 2.0).abs() < 1e-4, "solo a: {}", out[63]);
+
+        graph.set_solo(None).unwrap();
+        let out = run_constant(&mut graph, 50);
+        assert!((out[63] - 2.5).abs() < 1e-4, "unsolo: {}", out[63]);
+    }
+
+    /// Entering solo ramps from the mix to the branch, no step.
+    #[test]
+    fn solo_switch_is_click_free() {
+        let (mut graph, _, b) = solo_diamond(64);
+        run_constant(&mut graph, 1);
+        graph.set_solo(Some(b)).unwrap();
+
+        let input = vec![1.0; 64];
+        let mut left = vec![0.0; 64];
+        let mut right = vec![0.0; 64];
+        let mut prev = 2.5f32;
+        for _ in 0..50 {
+            graph.process_block(&input, &input, &mut left, &mut right);
+            for &s in &left {
+                assert!((s - prev).abs() < 0.1, "jump {prev} -> {s}");
+                prev = s;
+            }
+        }
+    }
+
+    #[test]
+    fn solo_tap_is_released_and_user_tap_kept() {
+        let (mut graph, a, b) = solo_diamond(64);
+        graph.set_tap(b, true);
+
+        graph.set_solo(Some(a)).unwrap();
+        assert!(graph.read_tap(a).is_some());
+        graph.set_solo(Some(b)).unwrap();
+        // `a` fades out, so its tap stays until the solo moves again.
+        assert!(graph.read_tap(a).is_some());
+        graph.set_solo(None).unwrap();
+        assert!(graph.read_tap(a).is_none());
+        // The caller enabled `b`'s tap; solo leaves it alone.
+        assert!(graph.read_tap(b).is_some());
+    }
+
+    #[test]
+    fn solo_rejects_non_effect_and_clears_on_remove() {
+        let (mut graph, _, b) = solo_diamond(64);
+        let input = graph.input_id().unwrap();
+        assert!(matches!(
+            graph.set_solo(Some(input)),
+            Err(GraphError::InvalidConnection(_))
+        ));
+        assert!(matches!(
+            graph.set_solo(Some(NodeId(99))),
+            Err(GraphError::NodeNotFound(_))
+        ));
+
+        graph.set_solo(Some(b)).unwrap();
+        graph.remove_node(b).unwrap();
+        assert_eq!(graph.solo(), None);
+    }
+
     #[test]
     fn peak_tracking_reports_correct_levels() {
         // Gain(1.0) with a known DC signal — peak_out should equal that value.
@@ -3310,6 +4169,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bypass_mode_mute_outputs_silence() {
+        let effects: Vec<Box<dyn EffectWithParams + Send>> = vec![Box::new(Gain { factor: 2.0 })];
+        let mut graph = ProcessingGraph::linear(effects, 48000.0, 64).unwrap();
+        let effect_id = NodeId(1);
+
+        graph.set_bypass_mode(effect_id, BypassMode::Mute);
+        assert_eq!(graph.bypass_mode(effect_id), BypassMode::Mute);
+        graph.set_bypass(effect_id, true);
+
+        let left_in = vec![0.5; 64];
+        let right_in = vec![0.25; 64];
+        let mut left_out = vec![0.0; 64];
+        let mut right_out = vec![0.0; 64];
+
+        // During the fade the output only ever shrinks toward zero.
+        graph.process_block(&left_in, &right_in, &mut left_out, &mut right_out);
+        for &s in &left_out {
+            assert!(
+                (0.0..=1.0 + 1e-6).contains(&s),
+                "mute fade out of range: {s}"
+            );
+        }
+
+        if let Some(Some(node)) = graph.nodes.get_mut(1) {
+            node.bypass_fade.snap_to_target();
+        }
+        graph.process_block(&left_in, &right_in, &mut left_out, &mut right_out);
+        assert!(left_out.iter().chain(&right_out).all(|&s| s == 0.0));
+    }
+
+    #[test]
+    fn test_bypass_mode_tails_rings_out_then_goes_dry() {
+        let block_size = 64usize;
+        let tail_len = 512usize;
+        let mut graph = ProcessingGraph::new(48000.0, block_size);
+        let input = graph.add_input();
+        let effect_id = graph.add_effect(Box::new(TailTestEffect {
+            tail: tail_len,
+            buffer: [0.0; 256],
+            write_pos: 0,
+        }));
+        let output = graph.add_output();
+        graph.connect(input, effect_id).unwrap();
+        graph.connect(effect_id, output).unwrap();
+        graph.compile().unwrap();
+
+        let ones = vec![1.0; block_size];
+        let dry = vec![0.25; block_size];
+        let mut left_out = vec![0.0; block_size];
+        let mut right_out = vec![0.0; block_size];
+
+        // Fill the delay line.
+        for _ in 0..8 {
+            graph.process_block(&ones, &ones, &mut left_out, &mut right_out);
+        }
+
+        graph.set_bypass_mode(effect_id, BypassMode::Tails);
+        graph.set_bypass(effect_id, true);
+        if let Some(Some(node)) = graph.nodes.get_mut(effect_id.0 as usize) {
+            node.bypass_fade.snap_to_target();
+        }
+
+        // Dry passes through and the delayed tail is added on top.
+        graph.process_block(&dry, &dry, &mut left_out, &mut right_out);
+        assert!(
+            left_out.iter().all(|&s| s > 0.25 + 1e-3),
+            "tails bypass should sum dry and ringing tail"
+        );
+
+        // Once the reported tail has elapsed, output is exactly dry.
+        for _ in 0..(tail_len / block_size) {
+            graph.process_block(&dry, &dry, &mut left_out, &mut right_out);
+        }
+        graph.process_block(&dry, &dry, &mut left_out, &mut right_out);
+        for (i, &s) in left_out.iter().enumerate() {
+            assert!(
+                (s - 0.25).abs() < 1e-6,
+                "tails bypass after ring-out left[{i}]: expected 0.25, got {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bypass_mode_dry_drops_tail() {
+        // Same setup as the tails test, but the default mode cuts the tail.
+        let block_size = 64usize;
+        let mut graph = ProcessingGraph::new(48000.0, block_size);
+        let input = graph.add_input();
+        let effect_id = graph.add_effect(Box::new(TailTestEffect {
+            tail: 512,
+            buffer: [0.0; 256],
+            write_pos: 0,
+        }));
+        let output = graph.add_output();
+        graph.connect(input, effect_id).unwrap();
+        graph.connect(effect_id, output).unwrap();
+        graph.compile().unwrap();
+
+        let ones = vec![1.0; block_size];
+        let dry = vec![0.25; block_size];
+        let mut left_out = vec![0.0; block_size];
+        let mut right_out = vec![0.0; block_size];
+        for _ in 0..8 {
+            graph.process_block(&ones, &ones, &mut left_out, &mut right_out);
+        }
+
+        graph.set_bypass(effect_id, true);
+        if let Some(Some(node)) = graph.nodes.get_mut(effect_id.0 as usize) {
+            node.bypass_fade.snap_to_target();
+        }
+        graph.process_block(&dry, &dry, &mut left_out, &mut right_out);
+        assert!(left_out.iter().all(|&s| (s - 0.25).abs() < 1e-6));
+    }
+
     // --- Spillover tail tests ---
 
     /// Test effect with a delay-line feedback loop so it actually has a tail.
@@ -3405,6 +4379,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replace_effect_spills_previous_instance() {
+        let block_size = 64usize;
+        let mut graph = ProcessingGraph::new(48000.0, block_size);
+        let input = graph.add_input();
+        let effect_id = graph.add_effect(Box::new(TailTestEffect {
+            tail: 512,
+            buffer: [0.0; 256],
+            write_pos: 0,
+        }));
+        let output = graph.add_output();
+        graph.connect(input, effect_id).unwrap();
+        graph.connect(effect_id, output).unwrap();
+        graph.compile().unwrap();
+
+        let signal = vec![0.5_f32; block_size];
+        let mut left_out = vec![0.0_f32; block_size];
+        let mut right_out = vec![0.0_f32; block_size];
+        for _ in 0..4 {
+            graph.process_block(&signal, &signal, &mut left_out, &mut right_out);
+        }
+
+        let fresh = Box::new(TailTestEffect {
+            tail: 512,
+            buffer: [0.0; 256],
+            write_pos: 0,
+        });
+        graph.replace_effect(effect_id, fresh).unwrap();
+        assert_eq!(graph.spillover_count(), 1);
+
+        // The fresh instance is silent on silence; the old one still rings.
+        let silence = vec![0.0_f32; block_size];
+        graph.process_block(&silence, &silence, &mut left_out, &mut right_out);
+        let sum: f32 = left_out.iter().map(|s| s.abs()).sum();
+        assert!(sum > 0.0, "replaced effect should ring out, got sum={sum}");
+
+        assert!(matches!(
+            graph.replace_effect(input, Box::new(Gain { factor: 1.0 })),
+            Err(GraphError::NodeNotFound(_))
+        ));
+    }
+
     #[test]
     fn no_spillover_for_zero_tail_effect() {
         // Gain (tail = 0) should not create a spillover tail.
@@ -0,0 +1,317 @@
+//! GraphViz DOT export of the topology and compiled schedule, for debugging.
+//!
+//! [`ProcessingGraph::to_dot()`] renders two clusters:
+//!
+//! - **topology** — one box per node with its label (bus number and name for
+//!   Input/Output nodes), own latency, cumulative
+//!   path latency, bypass state, and the buffer slots its compiled step reads
+//!   and writes. Feedback edges are dashed; sidechain routes are dotted.
+//! - **schedule** — the compiled steps in execution order, with compensation
+//!   delay lengths resolved to samples.
+//!
+//! Render with `dot -Tsvg graph.dot -o graph.svg`.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use super::{ProcessingGraph, format_step};
+use crate::graph::node::{NodeId, NodeKind, NodeRate};
+use crate::graph::schedule::ProcessStep;
+
+impl ProcessingGraph {
+    /// Renders the graph topology and compiled schedule as a GraphViz DOT document.
+    ///
+    /// `label` names effect nodes (usually the registry ID); unnamed effects
+    /// fall back to `effect`. Buffer assignments and the schedule cluster are
+    /// only shown once the graph has been compiled.
+    pub fn to_dot<'a>(&self, label: impl Fn(NodeId) -> Option<&'a str>) -> String {
+        let schedule = self.compiled.as_deref();
+        let latencies = self
+            .kahn_sort()
+            .ok()
+            .map(|sorted| self.compute_node_latencies(&sorted, &self.compensated_latencies()));
+
+        let inputs = self.input_buses();
+        let outputs = self.output_buses();
+        let bus_label = |kind: &str, buses: &[NodeId], id: NodeId| {
+            let bus = buses.iter().position(|&b| b == id).unwrap_or(0);
+            match self.bus_name(id) {
+                Some(name) => format!("{kind} bus {bus} ({name})"),
+                None => format!("{kind} bus {bus}"),
+            }
+        };
+
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph sonido {{");
+        let _ = writeln!(out, "  rankdir=LR;");
+        let _ = writeln!(out, "  node [shape=box, fontname=\"Helvetica\"];");
+        let summary = match schedule {
+            Some(s) => format!(
+                "{} Hz, block {}, {} buffers, latency {} samples",
+                self.sample_rate,
+                self.block_size,
+                s.buffer_count(),
+                s.total_latency()
+            ),
+            None => format!(
+                "{} Hz, block {}, not compiled",
+                self.sample_rate, self.block_size
+            ),
+        };
+        let _ = writeln!(out, "  label=\"{}\";", escape(&summary));
+
+        let _ = writeln!(out, "  subgraph cluster_topology {{");
+        let _ = writeln!(out, "    label=\"topology\";");
+        for (idx, node) in self.nodes.iter().enumerate() {
+            let Some(node) = node else { continue };
+            let (name, shape) = match &node.kind {
+                NodeKind::Input => (bus_label("Input", &inputs, node.id), "invhouse"),
+                NodeKind::Output => (bus_label("Output", &outputs, node.id), "house"),
+                NodeKind::Split => (String::from("Split"), "triangle"),
+                NodeKind::Merge => (String::from("Merge"), "invtriangle"),
+                NodeKind::AuxBus => (String::from("Aux Bus"), "invtrapezium"),
+                NodeKind::Send(send) => (
+                    format!(
+                        "Send {:.1} dB ({})\nfader {:.1} dB",
+                        send.level_db(),
+                        send.tap().label(),
+                        send.fader_db()
+                    ),
+                    "cds",
+                ),
+                NodeKind::Effect(_) => (String::from(label(node.id).unwrap_or("effect")), "box"),
+                NodeKind::SubGraph(_) => (String::from("SubGraph"), "box3d"),
+            };
+            let mut text = format!("{name} #{idx}");
+            if let NodeKind::Effect(effect) = &node.kind {
+                let own = effect.latency_samples();
+                let path = latencies.as_ref().map_or(own, |l| l[idx]);
+                let _ = write!(text, "\nlatency {own} (path {path})");
+                if let NodeRate::Control(hz) = node.node_rate {
+                    let _ = write!(text, "\ncontrol {hz} Hz");
+                }
+                if node.bypassed {
+                    let _ = write!(text, "\nbypassed ({})", node.bypass_mode.label());
+                }
+            }
+            if let Some(bufs) = schedule.and_then(|s| node_buffers(&s.steps, idx)) {
+                let _ = write!(text, "\n{bufs}");
+            }
+            let style = if node.bypassed { ", style=dashed" } else { "" };
+            let _ = writeln!(
+                out,
+                "    n{idx} [label=\"{}\", shape={shape}{style}];",
+                escape(&text)
+            );
+        }
+        for edge in self.edges.iter().flatten() {
+            let attrs = if edge.is_feedback {
+                " [style=dashed, label=\"feedback\"]"
+            } else if self.aux_send(edge.from).is_some_and(|s| s.aux() == edge.to) {
+                " [style=bold, label=\"send\"]"
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "    n{} -> n{}{attrs};", edge.from.0, edge.to.0);
+        }
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if let Some(source) = node.as_ref().and_then(|n| n.sidechain_source) {
+                let _ = writeln!(
+                    out,
+                    "    n{} -> n{idx} [style=dotted, label=\"sidechain\"];",
+                    source.0
+                );
+            }
+        }
+        let _ = writeln!(out, "  }}");
+
+        if let Some(schedule) = schedule {
+            let mut steps = String::new();
+            for (i, step) in schedule.steps.iter().enumerate() {
+                let mut line = format_step(step);
+                if let ProcessStep::DelayCompensate { delay_line_idx, .. } = step
+                    && let Some(samples) = schedule.delay_sample_counts.get(*delay_line_idx)
+                {
+                    let _ = write!(line, " ({samples} samples)");
+                }
+                let _ = write!(steps, "{i}: {}\\l", escape(&line));
+            }
+            let _ = writeln!(out, "  subgraph cluster_schedule {{");
+            let _ = writeln!(
+                out,
+                "    label=\"schedule ({} steps)\";",
+                schedule.step_count()
+            );
+            let _ = writeln!(
+                out,
+                "    schedule [shape=note, fontname=\"Courier\", label=\"{steps}\"];"
+            );
+            let _ = writeln!(out, "  }}");
+        }
+
+        let _ = writeln!(out, "}}");
+        out
+    }
+}
+
+/// Describes the buffer slots the compiled step for node `idx` reads and writes.
+fn node_buffers(steps: &[ProcessStep], idx: usize) -> Option<String> {
+    steps.iter().find_map(|step| match *step {
+        ProcessStep::ProcessEffect {
+            node_idx,
+            input_buf,
+            output_buf,
+            sidechain_buf,
+            ..
+        } if node_idx == idx => Some(match sidechain_buf {
+            Some(sc) => format!("buf[{input_buf}] → buf[{output_buf}] SC=buf[{sc}]"),
+            None => format!("buf[{input_buf}] → buf[{output_buf}]"),
+        }),
+        ProcessStep::ProcessSubGraph {
+            node_idx,
+            input_buf,
+            output_buf,
+        } if node_idx == idx => Some(format!("buf[{input_buf}] → buf[{output_buf}]")),
+        ProcessStep::Send {
+            node_idx,
+            source_buf,
+            through_buf,
+            send_buf,
+        } if node_idx == idx => {
+            let mut text = format!("buf[{source_buf}]");
+            if let Some(through) = through_buf {
+                let _ = write!(text, " → buf[{through}]");
+            }
+            if let Some(send) = send_buf {
+                let _ = write!(text, " aux=buf[{send}]");
+            }
+            Some(text)
+        }
+        _ => None,
+    })
+}
+
+/// Escapes a string for use inside a double-quoted DOT label.
+///
+/// Newlines become centred line breaks (`\n`); `\l` sequences written by the
+/// caller are left alone because they are added after escaping.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::Effect;
+    use crate::effect_with_params::EffectWithParams;
+    use crate::param_info::{ParamDescriptor, ParameterInfo};
+
+    /// Passthrough reporting a fixed latency.
+    struct Latent(usize);
+
+    impl Effect for Latent {
+        fn process(&mut self, input: f32) -> f32 {
+            input
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {}
+        fn latency_samples(&self) -> usize {
+            self.0
+        }
+    }
+
+    impl ParameterInfo for Latent {
+        fn param_count(&self) -> usize {
+            0
+        }
+        fn param_info(&self, _: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _: usize) -> f32 {
+            0.0
+        }
+        fn set_param(&mut self, _: usize, _: f32) {}
+    }
+
+    fn latent(samples: usize) -> Box<dyn EffectWithParams + Send> {
+        Box::new(Latent(samples))
+    }
+
+    #[test]
+    fn dot_shows_topology_buffers_and_compensation() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let slow = graph.add_effect(latent(128));
+        let fast = graph.add_effect(latent(0));
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, slow).unwrap();
+        graph.connect(split, fast).unwrap();
+        graph.connect(slow, merge).unwrap();
+        graph.connect(fast, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.set_bypass(fast, true);
+        graph.compile().unwrap();
+
+        let dot = graph.to_dot(|id| (id == slow).then_some("lookahead"));
+        assert!(dot.starts_with("digraph sonido {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("latency 128 samples"));
+        assert!(dot.contains("lookahead #2\\nlatency 128 (path 128)"));
+        assert!(dot.contains("effect #3"));
+        assert!(dot.contains("bypassed (Dry)"));
+        assert!(dot.contains("DelayCompensate"));
+        assert!(dot.contains("(128 samples)"));
+        assert!(dot.contains("n0 -> n1;"));
+        assert!(dot.contains("Input bus 0 #0"));
+        assert!(dot.contains("cluster_schedule"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+    }
+
+    #[test]
+    fn dot_marks_feedback_sidechain_and_uncompiled() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let fx = graph.add_effect(latent(0));
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, merge).unwrap();
+        graph.connect(merge, fx).unwrap();
+        graph.connect(fx, output).unwrap();
+        graph.connect_feedback(fx, merge).unwrap();
+        graph.connect_sidechain(input, fx).unwrap();
+
+        let dot = graph.to_dot(|_| None);
+        assert!(dot.contains("not compiled"));
+        assert!(!dot.contains("cluster_schedule"));
+        assert!(dot.contains("[style=dashed, label=\"feedback\"]"));
+        assert!(dot.contains("n0 -> n2 [style=dotted, label=\"sidechain\"]"));
+
+        graph.compile().unwrap();
+        let dot = graph.to_dot(|_| None);
+        assert!(dot.contains("FeedbackDelay"));
+        assert!(dot.contains("SC=buf["));
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        assert_eq!(escape("a \"b\"\\c\nd"), "a \\\"b\\\"\\\\c\\nd");
+    }
+}
@@ -0,0 +1,649 @@
+//! JSON snapshots of a [`ProcessingGraph`] for session files and external tooling.
+//!
+//! A snapshot captures every node (with registry effect ID, parameter values,
+//! bypass state, bypass mode, control rate, and sidechain source for effect
+//! nodes) and every edge, including feedback edges:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "nodes": [
+//!     { "id": 0, "kind": "input" },
+//!     { "id": 1, "kind": "output" },
+//!     { "id": 3, "kind": "output", "name": "cue" },
+//!     { "id": 2, "kind": "effect", "effect_id": "reverb", "params": [2.5, 30.0],
+//!       "bypassed": true, "bypass_mode": "Tails" }
+//!   ],
+//!   "edges": [
+//!     { "from": 0, "to": 2, "feedback": false },
+//!     { "from": 2, "to": 1, "feedback": false }
+//!   ]
+//! }
+//! ```
+//!
+//! Aux buses are `"aux_bus"` nodes; sends are `"send"` nodes naming their
+//! aux bus, with `level_db` (`null` when silenced), `fader_db`, and `tap`
+//! (`"Pre"` or `"Post"`).
+//!
+//! `bypass_mode`, `control_rate_hz`, `sidechain`, and bus `name`s are omitted
+//! when they hold their defaults. Input and Output nodes are saved in bus
+//! order, so bus numbers survive a round trip. Node `id`s are document-local: they link edges to nodes but are not
+//! preserved as [`NodeId`]s on load. The graph does not know registry IDs, so
+//! both directions take a callback — a lookup on save and an effect factory on
+//! load (typically `EffectRegistry::create`).
+//!
+//! Sub-graph nodes are not supported.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{GraphError, ProcessingGraph};
+use crate::effect_with_params::EffectWithParams;
+use crate::graph::node::{BypassMode, NodeId, NodeKind, NodeRate, SendTap};
+
+/// Current snapshot schema version.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// Lowest fader level written; JSON has no `-inf`.
+const FADER_FLOOR_DB: f32 = -144.0;
+
+/// Errors from [`ProcessingGraph::to_snapshot_json()`] and
+/// [`ProcessingGraph::from_snapshot_json()`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GraphJsonError {
+    /// The text is not valid snapshot JSON.
+    Json(serde_json::Error),
+    /// Rebuilding the graph failed (bad connection, cycle, missing I/O).
+    Graph(GraphError),
+    /// The effect factory did not recognise this effect ID.
+    UnknownEffect(String),
+    /// The ID lookup returned nothing for this effect node.
+    MissingEffectId(NodeId),
+    /// The graph contains a node kind snapshots cannot describe (sub-graphs).
+    Unsupported(NodeId),
+    /// The document is well-formed JSON but not a valid snapshot.
+    InvalidDocument(String),
+}
+
+impl std::fmt::Display for GraphJsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "invalid graph JSON: {e}"),
+            Self::Graph(e) => write!(f, "cannot rebuild graph: {e}"),
+            Self::UnknownEffect(id) => write!(f, "unknown effect '{id}'"),
+            Self::MissingEffectId(id) => write!(f, "no effect ID for {id}"),
+            Self::Unsupported(id) => write!(f, "{id} cannot be serialized (sub-graph)"),
+            Self::InvalidDocument(msg) => write!(f, "invalid graph snapshot: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GraphJsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            Self::Graph(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for GraphJsonError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<GraphError> for GraphJsonError {
+    fn from(e: GraphError) -> Self {
+        Self::Graph(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct GraphDoc {
+    version: u32,
+    nodes: Vec<NodeDoc>,
+    edges: Vec<EdgeDoc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NodeDoc {
+    id: u32,
+    #[serde(flatten)]
+    kind: NodeDocKind,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum NodeDocKind {
+    Input {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    Output {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    Split,
+    Merge,
+    AuxBus,
+    Send {
+        aux: u32,
+        level_db: Option<f32>,
+        #[serde(default)]
+        fader_db: f32,
+        tap: String,
+    },
+    Effect {
+        effect_id: String,
+        #[serde(default)]
+        params: Vec<f32>,
+        #[serde(default)]
+        bypassed: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bypass_mode: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        control_rate_hz: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sidechain: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct EdgeDoc {
+    from: u32,
+    to: u32,
+    #[serde(default)]
+    feedback: bool,
+}
+
+impl ProcessingGraph {
+    /// Serializes the graph topology and effect state to pretty-printed JSON.
+    ///
+    /// `effect_id` maps each effect node to its registry ID (for a
+    /// [`GraphEngine`](crate::graph::GraphEngine), use
+    /// [`GraphEngine::to_snapshot_json()`](crate::graph::GraphEngine::to_snapshot_json),
+    /// which supplies its manifest). Parameter values are read back from the
+    /// effects, so they reflect the current targets.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphJsonError::MissingEffectId`] if the lookup returns `None` for an
+    /// effect node, [`GraphJsonError::Unsupported`] for sub-graph nodes.
+    pub fn to_snapshot_json<'a>(
+        &self,
+        effect_id: impl Fn(NodeId) -> Option<&'a str>,
+    ) -> Result<String, GraphJsonError> {
+        let mut nodes = Vec::new();
+        for node in self.nodes.iter().flatten() {
+            let kind = match &node.kind {
+                NodeKind::Input => NodeDocKind::Input {
+                    name: node.bus_name.clone(),
+                },
+                NodeKind::Output => NodeDocKind::Output {
+                    name: node.bus_name.clone(),
+                },
+                NodeKind::Split => NodeDocKind::Split,
+                NodeKind::Merge => NodeDocKind::Merge,
+                NodeKind::AuxBus => NodeDocKind::AuxBus,
+                NodeKind::Send(send) => NodeDocKind::Send {
+                    aux: send.aux().index(),
+                    level_db: send.level_db().is_finite().then(|| send.level_db()),
+                    fader_db: send.fader_db().max(FADER_FLOOR_DB),
+                    tap: send.tap().label().to_string(),
+                },
+                NodeKind::Effect(effect) => NodeDocKind::Effect {
+                    effect_id: effect_id(node.id)
+                        .ok_or(GraphJsonError::MissingEffectId(node.id))?
+                        .to_string(),
+                    params: (0..effect.effect_param_count())
+                        .map(|i| effect.effect_get_param(i))
+                        .collect(),
+                    bypassed: node.bypassed,
+                    bypass_mode: (node.bypass_mode != BypassMode::Dry)
+                        .then(|| node.bypass_mode.label().to_string()),
+                    control_rate_hz: match node.node_rate {
+                        NodeRate::Control(hz) => Some(hz),
+                        _ => None,
+                    },
+                    sidechain: node.sidechain_source.map(NodeId::index),
+                },
+                NodeKind::SubGraph(_) => return Err(GraphJsonError::Unsupported(node.id)),
+            };
+            nodes.push(NodeDoc {
+                id: node.id.index(),
+                kind,
+            });
+        }
+
+        let edges = self
+            .edges
+            .iter()
+            .flatten()
+            .map(|e| EdgeDoc {
+                from: e.from.index(),
+                to: e.to.index(),
+                feedback: e.is_feedback,
+            })
+            .collect();
+
+        let doc = GraphDoc {
+            version: SNAPSHOT_VERSION,
+            nodes,
+            edges,
+        };
+        Ok(serde_json::to_string_pretty(&doc)?)
+    }
+
+    /// Rebuilds and compiles a graph from JSON written by
+    /// [`to_snapshot_json()`](Self::to_snapshot_json).
+    ///
+    /// `create` builds a fresh effect for a registry ID; saved parameter
+    /// values are applied on top and the effect is reset so they take hold
+    /// without smoothing. Bypassed nodes start fully bypassed (no fade).
+    ///
+    /// Returns the graph plus its effect nodes and registry IDs in document
+    /// order — the shape [`GraphEngine::new_dag()`](crate::graph::GraphEngine::new_dag)
+    /// expects for its manifest. Surplus saved parameters are ignored; missing
+    /// ones keep the effect's defaults.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphJsonError::Json`] for malformed input,
+    /// [`GraphJsonError::UnknownEffect`] when `create` returns `None`,
+    /// [`GraphJsonError::InvalidDocument`] for unsupported versions, duplicate
+    /// node IDs, or edges naming missing nodes, and [`GraphJsonError::Graph`]
+    /// if the topology fails to connect or compile.
+    pub fn from_snapshot_json(
+        json: &str,
+        sample_rate: f32,
+        block_size: usize,
+        mut create: impl FnMut(&str) -> Option<Box<dyn EffectWithParams + Send>>,
+    ) -> Result<(Self, Vec<(NodeId, String)>), GraphJsonError> {
+        let doc: GraphDoc = serde_json::from_str(json)?;
+        if doc.version != SNAPSHOT_VERSION {
+            return Err(GraphJsonError::InvalidDocument(format!(
+                "unsupported version {}",
+                doc.version
+            )));
+        }
+
+        let mut graph = Self::new(sample_rate, block_size);
+        let mut ids: HashMap<u32, NodeId> = HashMap::with_capacity(doc.nodes.len());
+        let mut effects = Vec::new();
+        let mut sidechains = Vec::new();
+
+        for node in doc.nodes {
+            let id = match node.kind {
+                NodeDocKind::Input { name } => {
+                    graph.add_input_named(name.as_deref().unwrap_or_default())
+                }
+                NodeDocKind::Output { name } => {
+                    graph.add_output_named(name.as_deref().unwrap_or_default())
+                }
+                NodeDocKind::Split => graph.add_split(),
+                NodeDocKind::Merge => graph.add_merge(),
+                NodeDocKind::AuxBus => graph.add_aux_bus(),
+                NodeDocKind::Send {
+                    aux,
+                    level_db,
+                    fader_db,
+                    tap,
+                } => {
+                    let aux = ids.get(&aux).copied().ok_or_else(|| {
+                        GraphJsonError::InvalidDocument(format!(
+                            "send names aux bus {aux}, which must precede it"
+                        ))
+                    })?;
+                    let tap = SendTap::from_label(&tap).ok_or_else(|| {
+                        GraphJsonError::InvalidDocument(format!("unknown send tap '{tap}'"))
+                    })?;
+                    let id = graph.add_send(aux, level_db.unwrap_or(f32::NEG_INFINITY), tap)?;
+                    if let Some(send) = graph.aux_send_mut(id) {
+                        send.set_fader_db(fader_db);
+                        send.snap();
+                    }
+                    id
+                }
+                NodeDocKind::Effect {
+                    effect_id,
+                    params,
+                    bypassed,
+                    bypass_mode,
+                    control_rate_hz,
+                    sidechain,
+                } => {
+                    let mut effect = create(&effect_id)
+                        .ok_or_else(|| GraphJsonError::UnknownEffect(effect_id.clone()))?;
+                    for (i, &value) in params.iter().take(effect.effect_param_count()).enumerate() {
+                        effect.effect_set_param(i, value);
+                    }
+                    effect.reset();
+
+                    let id = graph.add_effect(effect);
+                    if let Some(label) = bypass_mode {
+                        let mode = BypassMode::from_label(&label).ok_or_else(|| {
+                            GraphJsonError::InvalidDocument(format!(
+                                "unknown bypass mode '{label}'"
+                            ))
+                        })?;
+                        graph.set_bypass_mode(id, mode);
+                    }
+                    if bypassed {
+                        let data = graph.nodes[id.0 as usize].as_mut().unwrap();
+                        data.bypassed = true;
+                        data.bypass_fade.set_target(0.0);
+                        data.bypass_fade.snap_to_target();
+                    }
+                    if let Some(hz) = control_rate_hz {
+                        graph.set_node_rate(id, NodeRate::Control(hz))?;
+                    }
+                    if let Some(source) = sidechain {
+                        sidechains.push((source, id));
+                    }
+                    effects.push((id, effect_id));
+                    id
+                }
+            };
+            if ids.insert(node.id, id).is_some() {
+                return Err(GraphJsonError::InvalidDocument(format!(
+                    "duplicate node id {}",
+                    node.id
+                )));
+            }
+        }
+
+        let lookup = |doc_id: u32| {
+            ids.get(&doc_id).copied().ok_or_else(|| {
+                GraphJsonError::InvalidDocument(format!("reference to missing node {doc_id}"))
+            })
+        };
+        for edge in &doc.edges {
+            let (from, to) = (lookup(edge.from)?, lookup(edge.to)?);
+            // `add_send()` already wired each send to its aux bus.
+            if graph.aux_send(from).is_some_and(|send| send.aux() == to) {
+                continue;
+            }
+            if edge.feedback {
+                graph.connect_feedback(from, to)?;
+            } else {
+                graph.connect(from, to)?;
+            }
+        }
+        for (source, target) in sidechains {
+            graph.connect_sidechain(lookup(source)?, target)?;
+        }
+
+        graph.compile()?;
+        Ok((graph, effects))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Effect;
+    use crate::graph::GraphEngine;
+    use crate::param_info::{ParamDescriptor, ParameterInfo};
+
+    /// Two-parameter gain so saved parameter values are observable.
+    struct Gain {
+        factor: f32,
+        offset: f32,
+    }
+
+    impl Effect for Gain {
+        fn process(&mut self, input: f32) -> f32 {
+            input * self.factor + self.offset
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {}
+    }
+
+    impl ParameterInfo for Gain {
+        fn param_count(&self) -> usize {
+            2
+        }
+        fn param_info(&self, index: usize) -> Option<ParamDescriptor> {
+            match index {
+                0 => Some(ParamDescriptor::custom("Factor", "Factor", 0.0, 10.0, 1.0)),
+                1 => Some(ParamDescriptor::custom("Offset", "Offset", -1.0, 1.0, 0.0)),
+                _ => None,
+            }
+        }
+        fn get_param(&self, index: usize) -> f32 {
+            match index {
+                0 => self.factor,
+                1 => self.offset,
+                _ => 0.0,
+            }
+        }
+        fn set_param(&mut self, index: usize, value: f32) {
+            match index {
+                0 => self.factor = value,
+                1 => self.offset = value,
+                _ => {}
+            }
+        }
+    }
+
+    fn create(id: &str) -> Option<Box<dyn EffectWithParams + Send>> {
+        (id == "gain").then(|| {
+            Box::new(Gain {
+                factor: 1.0,
+                offset: 0.0,
+            }) as Box<dyn EffectWithParams + Send>
+        })
+    }
+
+    fn render(graph: &mut ProcessingGraph) -> f32 {
+        let input = [1.0; 64];
+        let (mut left, mut right) = ([0.0; 64], [0.0; 64]);
+        for _ in 0..50 {
+            graph.process_block(&input, &input, &mut left, &mut right);
+        }
+        left[63]
+    }
+
+    /// Input → Split → {gain ×2, gain ×3 (bypassed, Mute)} → Merge → Output.
+    fn diamond() -> (ProcessingGraph, Vec<(NodeId, &'static str)>) {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let a = graph.add_effect(create("gain").unwrap());
+        let b = graph.add_effect(create("gain").unwrap());
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph
+            .effect_with_params_mut(a)
+            .unwrap()
+            .effect_set_param(0, 2.0);
+        graph
+            .effect_with_params_mut(b)
+            .unwrap()
+            .effect_set_param(0, 3.0);
+        graph
+            .effect_with_params_mut(b)
+            .unwrap()
+            .effect_set_param(1, 0.5);
+        graph.set_bypass_mode(b, BypassMode::Mute);
+        graph.set_bypass(b, true);
+        graph.connect(input, split).unwrap();
+        graph.connect(split, a).unwrap();
+        graph.connect(split, b).unwrap();
+        graph.connect(a, merge).unwrap();
+        graph.connect(b, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.compile().unwrap();
+        (graph, vec![(a, "gain"), (b, "gain")])
+    }
+
+    #[test]
+    fn round_trip_preserves_topology_params_and_bypass() {
+        let (mut original, manifest) = diamond();
+        let lookup = |id: NodeId| manifest.iter().find(|(n, _)| *n == id).map(|(_, s)| *s);
+        let json = original.to_snapshot_json(lookup).unwrap();
+
+        let (mut restored, effects) =
+            ProcessingGraph::from_snapshot_json(&json, 48000.0, 64, create).unwrap();
+        assert_eq!(restored.node_count(), original.node_count());
+        assert_eq!(restored.edge_count(), original.edge_count());
+        assert_eq!(effects.len(), 2);
+        assert!(effects.iter().all(|(_, id)| id == "gain"));
+
+        let (a, b) = (effects[0].0, effects[1].0);
+        assert_eq!(
+            restored
+                .effect_with_params_ref(a)
+                .unwrap()
+                .effect_get_param(0),
+            2.0
+        );
+        assert_eq!(
+            restored
+                .effect_with_params_ref(b)
+                .unwrap()
+                .effect_get_param(1),
+            0.5
+        );
+        assert!(!restored.is_bypassed(a));
+        assert!(restored.is_bypassed(b));
+        assert_eq!(restored.bypass_mode(b), BypassMode::Mute);
+
+        assert!((render(&mut restored) - render(&mut original)).abs() < 1e-4);
+
+        let again = restored
+            .to_snapshot_json(|id| {
+                effects
+                    .iter()
+                    .find(|(n, _)| *n == id)
+                    .map(|(_, s)| s.as_str())
+            })
+            .unwrap();
+        assert_eq!(again, json);
+    }
+
+    #[test]
+    fn feedback_sidechain_and_control_rate_survive() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let fx = graph.add_effect(create("gain").unwrap());
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, merge).unwrap();
+        graph.connect(merge, fx).unwrap();
+        graph.connect(fx, output).unwrap();
+        graph.connect_feedback(fx, merge).unwrap();
+        graph.connect_sidechain(split, fx).unwrap();
+        graph.set_node_rate(fx, NodeRate::Control(100.0)).unwrap();
+        graph.compile().unwrap();
+
+        let json = graph.to_snapshot_json(|_| Some("gain")).unwrap();
+        assert!(json.contains("\"feedback\": true"));
+        assert!(json.contains("\"control_rate_hz\": 100.0"));
+
+        let (restored, effects) =
+            ProcessingGraph::from_snapshot_json(&json, 48000.0, 64, create).unwrap();
+        let fx = effects[0].0;
+        let data = restored.nodes[fx.0 as usize].as_ref().unwrap();
+        assert_eq!(data.node_rate, NodeRate::Control(100.0));
+        assert!(data.sidechain_source.is_some());
+        assert_eq!(
+            restored
+                .edges
+                .iter()
+                .flatten()
+                .filter(|e| e.is_feedback)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn engine_snapshot_json_uses_manifest() {
+        let (graph, manifest) = diamond();
+        let engine = GraphEngine::new_dag(graph, manifest);
+        let json = engine.to_snapshot_json().unwrap();
+        assert_eq!(json.matches("\"effect_id\": \"gain\"").count(), 2);
+        assert!(json.contains("\"bypass_mode\": \"Mute\""));
+    }
+
+    #[test]
+    fn bus_names_and_order_survive() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let main = graph.add_output();
+        let cue = graph.add_output_named("cue");
+        let split = graph.add_split();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, main).unwrap();
+        graph.connect(split, cue).unwrap();
+        let json = graph.to_snapshot_json(|_| None).unwrap();
+        assert!(json.contains("\"name\": \"cue\""));
+
+        let (restored, _) =
+            ProcessingGraph::from_snapshot_json(&json, 48000.0, 64, create).unwrap();
+        assert_eq!(restored.output_buses().len(), 2);
+        assert_eq!(restored.output_bus("cue"), Some(1));
+        assert_eq!(restored.bus_name(restored.output_buses()[0]), None);
+    }
+
+    #[test]
+    fn sends_survive_round_trip() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let fx = graph.add_aux_bus();
+        let send = graph.add_send(fx, -9.0, SendTap::PreFader).unwrap();
+        let master = graph.add_aux_bus();
+        let output = graph.add_output();
+        graph.set_send_fader(send, -3.0);
+        graph.connect(input, send).unwrap();
+        graph.connect(send, master).unwrap();
+        graph.connect(fx, master).unwrap();
+        graph.connect(master, output).unwrap();
+        let json = graph.to_snapshot_json(|_| None).unwrap();
+        assert!(json.contains("\"kind\": \"send\""));
+
+        let (mut restored, _) =
+            ProcessingGraph::from_snapshot_json(&json, 48000.0, 64, create).unwrap();
+        let restored_send = restored.aux_send(send).unwrap();
+        assert_eq!(restored_send.aux(), fx);
+        assert_eq!(restored_send.level_db(), -9.0);
+        assert_eq!(restored_send.fader_db(), -3.0);
+        assert_eq!(restored_send.tap(), SendTap::PreFader);
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        restored.compile().unwrap();
+    }
+
+    #[test]
+    fn errors_are_reported() {
+        let (graph, _) = diamond();
+        assert!(matches!(
+            graph.to_snapshot_json(|_| None),
+            Err(GraphJsonError::MissingEffectId(_))
+        ));
+
+        let json = graph.to_snapshot_json(|_| Some("fuzz")).unwrap();
+        assert!(matches!(
+            ProcessingGraph::from_snapshot_json(&json, 48000.0, 64, create),
+            Err(GraphJsonError::UnknownEffect(id)) if id == "fuzz"
+        ));
+
+        let dangling =
+            r#"{"version":1,"nodes":[{"id":0,"kind":"input"}],"edges":[{"from":0,"to":7}]}"#;
+        assert!(matches!(
+            ProcessingGraph::from_snapshot_json(dangling, 48000.0, 64, create),
+            Err(GraphJsonError::InvalidDocument(_))
+        ));
+
+        assert!(matches!(
+            ProcessingGraph::from_snapshot_json("{", 48000.0, 64, create),
+            Err(GraphJsonError::Json(_))
+        ));
+    }
+}
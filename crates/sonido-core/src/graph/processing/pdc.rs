@@ -0,0 +1,397 @@
+//! Plugin delay compensation (PDC) control.
+//!
+//! The compiler aligns parallel paths by delaying the shorter ones at each
+//! merge point, using each effect's [`latency_samples()`](crate::Effect::latency_samples).
+//! [`PdcMode`] turns that off or pins it to a snapshot, and
+//! [`update_latency()`](ProcessingGraph::update_latency) picks up latency
+//! changes from parameters (lookahead, oversampling factor) without a
+//! schedule swap when only the delay lengths move.
+//!
+//! A bypassed effect keeps its compensated latency: its dry signal runs
+//! through a delay of the same length, so bypassing a lookahead limiter on
+//! one branch does not pull that branch ahead of the others.
+
+#[cfg(not(feature = "std"))]
+use alloc::{sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use super::{GraphError, ProcessingGraph};
+use crate::graph::node::NodeKind;
+
+/// How a [`ProcessingGraph`] compensates for effect latency.
+///
+/// Selected per graph with [`ProcessingGraph::set_pdc_mode()`].
+///
+/// - [`On`](PdcMode::On) — parallel paths are aligned to the effects'
+///   current latencies (the default).
+/// - [`Frozen`](PdcMode::Frozen) — alignment keeps the latencies in effect
+///   when the mode was selected; later changes are ignored, so the reported
+///   latency stays put while a parameter is tweaked. Effects added after
+///   freezing use their live latency.
+/// - [`Off`](PdcMode::Off) — no compensation delays. Lowest latency, but
+///   paths through latent effects arrive early or late at a merge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum PdcMode {
+    /// Compensate for the effects' current latencies.
+    #[default]
+    On,
+    /// Compensate for the latencies captured when the mode was selected.
+    Frozen,
+    /// Do not compensate.
+    Off,
+}
+
+impl PdcMode {
+    /// All PDC modes in menu order.
+    pub const ALL: [Self; 3] = [Self::On, Self::Frozen, Self::Off];
+
+    /// Returns a short display label (`"On"`, `"Frozen"`, `"Off"`).
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::On => "On",
+            Self::Frozen => "Frozen",
+            Self::Off => "Off",
+        }
+    }
+
+    /// Parses a label produced by [`label()`](Self::label) (case-insensitive).
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.label().eq_ignore_ascii_case(label))
+    }
+}
+
+/// What [`ProcessingGraph::update_latency()`] had to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LatencyUpdate {
+    /// The compensated latencies already match the schedule.
+    Unchanged,
+    /// Only delay lengths changed; they were resized in place with no
+    /// schedule swap.
+    Patched,
+    /// The schedule changed shape (a compensation delay appeared or
+    /// vanished, or the topology was edited) and was recompiled with the
+    /// usual crossfade.
+    Recompiled,
+}
+
+impl ProcessingGraph {
+    /// Sets the latency compensation mode.
+    ///
+    /// Selecting [`PdcMode::Frozen`] captures the latencies the current
+    /// schedule compensates for (or the live ones, if there is none yet).
+    /// The mode is applied by the next [`compile()`](Self::compile) or
+    /// [`update_latency()`](Self::update_latency).
+    pub fn set_pdc_mode(&mut self, mode: PdcMode) {
+        if mode == PdcMode::Frozen && self.pdc != PdcMode::Frozen {
+            self.frozen_latencies = if self.compiled.is_some() && self.pdc == PdcMode::On {
+                self.compiled_latencies.clone()
+            } else {
+                self.live_latencies()
+            };
+        }
+        self.pdc = mode;
+    }
+
+    /// Returns the latency compensation mode.
+    pub fn pdc_mode(&self) -> PdcMode {
+        self.pdc
+    }
+
+    /// Whether the compensated latencies differ from the compiled schedule.
+    ///
+    /// Cheap enough to poll from the control thread after parameter changes.
+    /// Always `false` before the first compile.
+    pub fn latency_changed(&self) -> bool {
+        self.compiled.is_some()
+            && !same_latencies(&self.compensated_latencies(), &self.compiled_latencies)
+    }
+
+    /// Brings latency compensation up to date with the effects' reported
+    /// latencies and the [`PdcMode`].
+    ///
+    /// Call after changing a parameter that affects latency. When only
+    /// compensation delay lengths change, the delay lines are resized in place
+    /// and the running schedule is patched without a crossfade. Otherwise the
+    /// graph is recompiled, as is any topology edit pending since the last
+    /// compile. Allocates — call from the control thread, not per block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError`] under the same conditions as
+    /// [`compile()`](Self::compile).
+    pub fn update_latency(&mut self) -> Result<LatencyUpdate, GraphError> {
+        let Some(current) = self.compiled.clone() else {
+            self.compile()?;
+            return Ok(LatencyUpdate::Recompiled);
+        };
+        if !self.latency_changed() {
+            return Ok(LatencyUpdate::Unchanged);
+        }
+
+        let plan = self.plan_schedule()?;
+        let schedule = plan.schedule;
+        if schedule.steps != current.steps || schedule.buffer_count != current.buffer_count {
+            self.compile()?;
+            return Ok(LatencyUpdate::Recompiled);
+        }
+
+        for (line, &samples) in self
+            .audio_delay_lines
+            .iter_mut()
+            .zip(&schedule.delay_sample_counts)
+        {
+            line.set_delay_samples(samples);
+        }
+        self.apply_bypass_delays(&plan.own_latencies);
+        self.compiled_latencies = plan.own_latencies;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "graph_latency: patched to {} samples without recompiling",
+            schedule.total_latency
+        );
+        self.compiled = Some(Arc::new(schedule));
+        Ok(LatencyUpdate::Patched)
+    }
+
+    /// Each node's reported latency, by node slot.
+    pub(super) fn live_latencies(&self) -> Vec<usize> {
+        self.nodes
+            .iter()
+            .map(|node| match node.as_ref().map(|n| &n.kind) {
+                Some(NodeKind::Effect(effect)) => effect.latency_samples(),
+                _ => 0,
+            })
+            .collect()
+    }
+
+    /// Each node's latency as the compiler should compensate for it, by node
+    /// slot, following the [`PdcMode`].
+    pub(super) fn compensated_latencies(&self) -> Vec<usize> {
+        let mut latencies = self.live_latencies();
+        match self.pdc {
+            PdcMode::On => {}
+            PdcMode::Frozen => {
+                for (live, &frozen) in latencies.iter_mut().zip(&self.frozen_latencies) {
+                    *live = frozen;
+                }
+            }
+            PdcMode::Off => latencies.fill(0),
+        }
+        latencies
+    }
+
+    /// Sizes each effect's bypass dry delay to its compensated latency.
+    pub(super) fn apply_bypass_delays(&mut self, own_latencies: &[usize]) {
+        for (node, &latency) in self.nodes.iter_mut().zip(own_latencies) {
+            if let Some(node) = node
+                && matches!(node.kind, NodeKind::Effect(_))
+            {
+                node.bypass_delay.set_delay_samples(latency);
+            }
+        }
+    }
+}
+
+/// Compares per-slot latencies, treating missing trailing slots as zero.
+fn same_latencies(a: &[usize], b: &[usize]) -> bool {
+    let len = a.len().max(b.len());
+    (0..len).all(|i| a.get(i).copied().unwrap_or(0) == b.get(i).copied().unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{boxed::Box, vec, vec::Vec};
+
+    use super::*;
+    use crate::Effect;
+    use crate::graph::node::NodeId;
+    use crate::graph::schedule::ProcessStep;
+    use crate::param_info::{ParamDescriptor, ParameterInfo};
+
+    const BLOCK: usize = 16;
+
+    /// Pure stereo delay whose length (param 0) is its reported latency.
+    struct Lookahead {
+        line: Vec<(f32, f32)>,
+        pos: usize,
+        latency: usize,
+    }
+
+    impl Lookahead {
+        fn boxed(latency: usize) -> Box<Self> {
+            Box::new(Self {
+                line: vec![(0.0, 0.0); 32],
+                pos: 0,
+                latency,
+            })
+        }
+    }
+
+    impl Effect for Lookahead {
+        fn process(&mut self, input: f32) -> f32 {
+            self.process_stereo(input, input).0
+        }
+        fn process_stereo(&mut self, left: f32, right: f32) -> (f32, f32) {
+            let len = self.line.len();
+            self.line[self.pos] = (left, right);
+            let out = self.line[(self.pos + len - self.latency) % len];
+            self.pos = (self.pos + 1) % len;
+            out
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {
+            self.line.fill((0.0, 0.0));
+        }
+        fn latency_samples(&self) -> usize {
+            self.latency
+        }
+    }
+
+    impl ParameterInfo for Lookahead {
+        fn param_count(&self) -> usize {
+            1
+        }
+        fn param_info(&self, _: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _: usize) -> f32 {
+            self.latency as f32
+        }
+        fn set_param(&mut self, _: usize, value: f32) {
+            self.latency = value as usize;
+        }
+    }
+
+    /// Input → Split → {Lookahead, direct} → Merge → Output.
+    fn diamond(latency: usize) -> (ProcessingGraph, NodeId) {
+        let mut graph = ProcessingGraph::new(48000.0, BLOCK);
+        let input = graph.add_input();
+        let split = graph.add_split();
+        let latent = graph.add_effect(Lookahead::boxed(latency));
+        let merge = graph.add_merge();
+        let output = graph.add_output();
+        graph.connect(input, split).unwrap();
+        graph.connect(split, latent).unwrap();
+        graph.connect(split, merge).unwrap();
+        graph.connect(latent, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.compile().unwrap();
+        (graph, latent)
+    }
+
+    /// Lets fades settle on silence, then returns the response to an impulse.
+    fn impulse_response(graph: &mut ProcessingGraph) -> [f32; BLOCK] {
+        let silence = [0.0f32; BLOCK];
+        let (mut left, mut right) = ([0.0f32; BLOCK], [0.0f32; BLOCK]);
+        for _ in 0..200 {
+            graph.process_block(&silence, &silence, &mut left, &mut right);
+        }
+        let mut impulse = [0.0f32; BLOCK];
+        impulse[0] = 1.0;
+        graph.process_block(&impulse, &impulse, &mut left, &mut right);
+        left
+    }
+
+    fn set_latency(graph: &mut ProcessingGraph, id: NodeId, latency: usize) {
+        graph
+            .effect_with_params_mut(id)
+            .unwrap()
+            .effect_set_param(0, latency as f32);
+    }
+
+    fn delay_steps(graph: &ProcessingGraph) -> usize {
+        graph
+            .compiled()
+            .unwrap()
+            .steps
+            .iter()
+            .filter(|s| matches!(s, ProcessStep::DelayCompensate { .. }))
+            .count()
+    }
+
+    #[test]
+    fn compensated_diamond_is_aligned() {
+        let (mut graph, _) = diamond(4);
+        let out = impulse_response(&mut graph);
+        assert!((out[4] - 1.0).abs() < 1e-4, "{out:?}");
+        assert_eq!(out.iter().filter(|s| s.abs() > 1e-4).count(), 1);
+    }
+
+    #[test]
+    fn bypass_keeps_compensated_latency() {
+        let (mut graph, latent) = diamond(4);
+        graph.set_bypass(latent, true);
+        let out = impulse_response(&mut graph);
+        assert!((out[4] - 1.0).abs() < 1e-4, "{out:?}");
+        assert_eq!(out.iter().filter(|s| s.abs() > 1e-4).count(), 1);
+        assert_eq!(graph.latency_samples(), 4);
+    }
+
+    #[test]
+    fn off_mode_skips_compensation() {
+        let (mut graph, latent) = diamond(4);
+        graph.set_pdc_mode(PdcMode::Off);
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Recompiled);
+        assert_eq!(delay_steps(&graph), 0);
+        // Paths are no longer aligned; the longest one is still reported.
+        assert_eq!(graph.latency_samples(), 4);
+        let out = impulse_response(&mut graph);
+        assert!(
+            (out[0] - 0.5).abs() < 1e-4 && (out[4] - 0.5).abs() < 1e-4,
+            "{out:?}"
+        );
+
+        // Bypass is not delayed either.
+        graph.set_bypass(latent, true);
+        let out = impulse_response(&mut graph);
+        assert!((out[0] - 1.0).abs() < 1e-4, "{out:?}");
+    }
+
+    #[test]
+    fn latency_change_patches_delay_in_place() {
+        let (mut graph, latent) = diamond(4);
+        assert!(!graph.latency_changed());
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Unchanged);
+
+        set_latency(&mut graph, latent, 9);
+        assert!(graph.latency_changed());
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Patched);
+        assert!(graph.prev_compiled.is_none(), "patch must not crossfade");
+        assert_eq!(graph.latency_samples(), 9);
+        let out = impulse_response(&mut graph);
+        assert!((out[9] - 1.0).abs() < 1e-4, "{out:?}");
+
+        // Dropping to zero removes the compensation step entirely.
+        set_latency(&mut graph, latent, 0);
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Recompiled);
+        assert_eq!(delay_steps(&graph), 0);
+    }
+
+    #[test]
+    fn frozen_mode_holds_latency_until_released() {
+        let (mut graph, latent) = diamond(4);
+        graph.set_pdc_mode(PdcMode::Frozen);
+        set_latency(&mut graph, latent, 9);
+        assert!(!graph.latency_changed());
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Unchanged);
+        assert_eq!(graph.latency_samples(), 4);
+
+        graph.set_pdc_mode(PdcMode::On);
+        assert_eq!(graph.update_latency().unwrap(), LatencyUpdate::Patched);
+        assert_eq!(graph.latency_samples(), 9);
+    }
+
+    #[test]
+    fn pdc_mode_labels_round_trip() {
+        for mode in PdcMode::ALL {
+            assert_eq!(PdcMode::from_label(mode.label()), Some(mode));
+        }
+        assert_eq!(PdcMode::from_label("frozen"), Some(PdcMode::Frozen));
+        assert_eq!(PdcMode::from_label("auto"), None);
+    }
+}
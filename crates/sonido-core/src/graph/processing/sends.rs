@@ -0,0 +1,223 @@
+//! Mixer-style send/return routing over the DAG.
+//!
+//! An aux bus ([`NodeKind::AuxBus`]) is a summing node that adds its inputs at
+//! unity. A send ([`NodeKind::Send`]) sits inline on a path: it passes its
+//! input through a fader to its one outgoing edge, and taps a copy, pre- or
+//! post-fader, into an aux bus at the send level. The aux bus output feeds a
+//! return chain (typically a reverb or delay), which rejoins the main mix at a
+//! second aux bus:
+//!
+//! ```text
+//! Input → drums → Send ─────────────→ master (AuxBus) → Output
+//!                   └→ fx (AuxBus) → reverb ─┘
+//! ```
+//!
+//! Level, fader, and tap changes are smoothed and take effect without a
+//! recompile. Adding or removing sends is a topology change like any other.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use super::{GraphError, ProcessingGraph};
+use crate::graph::node::{AuxSend, NodeId, NodeKind, SendTap};
+
+impl ProcessingGraph {
+    /// Adds an aux bus: a summing node that adds all of its inputs at unity.
+    ///
+    /// Feed it with [`add_send()`](Self::add_send) (or plain edges) and
+    /// connect its single output to a return chain. Also serves as the
+    /// master bus where returns rejoin the dry signal.
+    pub fn add_aux_bus(&mut self) -> NodeId {
+        let id = self.add_node(NodeKind::AuxBus);
+        #[cfg(feature = "tracing")]
+        tracing::debug!("graph_add: aux bus node {id}");
+        id
+    }
+
+    /// Adds an inline send into `aux` at `level_db`, tapped at `tap`.
+    ///
+    /// The send is connected to the aux bus immediately; wire it into a path
+    /// with [`connect()`](Self::connect) like an effect (one edge in, one
+    /// edge out). Its fader starts at 0 dB.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::NodeNotFound`] if `aux` does not exist, or
+    /// [`GraphError::InvalidConnection`] if it is not an aux bus.
+    pub fn add_send(
+        &mut self,
+        aux: NodeId,
+        level_db: f32,
+        tap: SendTap,
+    ) -> Result<NodeId, GraphError> {
+        if !matches!(self.get_node(aux)?.kind, NodeKind::AuxBus) {
+            return Err(GraphError::InvalidConnection(format!(
+                "send target {aux} is not an aux bus"
+            )));
+        }
+        let send = AuxSend::new(aux, level_db, tap, self.sample_rate);
+        let id = self.add_node(NodeKind::Send(send));
+        if let Err(e) = self.connect(id, aux) {
+            let _ = self.remove_node(id);
+            return Err(e);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!("graph_add: send node {id} → aux {aux}");
+        Ok(id)
+    }
+
+    /// Returns the send state of a [`NodeKind::Send`] node.
+    pub fn aux_send(&self, id: NodeId) -> Option<&AuxSend> {
+        match &self.get_node(id).ok()?.kind {
+            NodeKind::Send(send) => Some(send),
+            _ => None,
+        }
+    }
+
+    /// Sets a send's level in dB (clamped to
+    /// [`SEND_MAX_DB`](crate::graph::node::SEND_MAX_DB)). Ignored for other
+    /// node kinds.
+    pub fn set_send_level(&mut self, id: NodeId, level_db: f32) {
+        if let Some(send) = self.aux_send_mut(id) {
+            send.set_level_db(level_db);
+        }
+    }
+
+    /// Sets a send's through-path fader in dB. Ignored for other node kinds.
+    ///
+    /// A post-fader send follows the fader; a pre-fader send does not.
+    pub fn set_send_fader(&mut self, id: NodeId, fader_db: f32) {
+        if let Some(send) = self.aux_send_mut(id) {
+            send.set_fader_db(fader_db);
+        }
+    }
+
+    /// Moves a send's tap point before or after its fader, crossfading
+    /// between the two. Ignored for other node kinds.
+    pub fn set_send_tap(&mut self, id: NodeId, tap: SendTap) {
+        if let Some(send) = self.aux_send_mut(id) {
+            send.set_tap(tap);
+        }
+    }
+
+    pub(super) fn aux_send_mut(&mut self, id: NodeId) -> Option<&mut AuxSend> {
+        match &mut self.nodes.get_mut(id.0 as usize)?.as_mut()?.kind {
+            NodeKind::Send(send) => Some(send),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::Effect;
+    use crate::effect_with_params::EffectWithParams;
+    use crate::math::db_to_linear;
+    use crate::param_info::{ParamDescriptor, ParameterInfo};
+
+    /// Fixed-gain stand-in for a return effect.
+    struct Scale(f32);
+
+    impl Effect for Scale {
+        fn process(&mut self, input: f32) -> f32 {
+            input * self.0
+        }
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+        fn reset(&mut self) {}
+    }
+
+    impl ParameterInfo for Scale {
+        fn param_count(&self) -> usize {
+            0
+        }
+        fn param_info(&self, _: usize) -> Option<ParamDescriptor> {
+            None
+        }
+        fn get_param(&self, _: usize) -> f32 {
+            0.0
+        }
+        fn set_param(&mut self, _: usize, _: f32) {}
+    }
+
+    fn scale(gain: f32) -> Box<dyn EffectWithParams + Send> {
+        Box::new(Scale(gain))
+    }
+
+    /// Input → Send → master → Output, with the send feeding fx → return → master.
+    fn send_return(tap: SendTap) -> (ProcessingGraph, NodeId) {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let input = graph.add_input();
+        let fx = graph.add_aux_bus();
+        let send = graph.add_send(fx, -6.0, tap).unwrap();
+        let ret = graph.add_effect(scale(0.5));
+        let master = graph.add_aux_bus();
+        let output = graph.add_output();
+        graph.connect(input, send).unwrap();
+        graph.connect(send, master).unwrap();
+        graph.connect(fx, ret).unwrap();
+        graph.connect(ret, master).unwrap();
+        graph.connect(master, output).unwrap();
+        graph.compile().unwrap();
+        (graph, send)
+    }
+
+    /// Runs enough blocks of DC for smoothing to settle; returns the last sample.
+    fn settle(graph: &mut ProcessingGraph) -> f32 {
+        let input = [1.0f32; 64];
+        let (mut left, mut right) = ([0.0f32; 64], [0.0f32; 64]);
+        for _ in 0..50 {
+            graph.process_block(&input, &input, &mut left, &mut right);
+        }
+        left[63]
+    }
+
+    #[test]
+    fn return_sums_with_dry_at_unity() {
+        let (mut graph, _) = send_return(SendTap::PostFader);
+        let expected = 1.0 + db_to_linear(-6.0) * 0.5;
+        assert!((settle(&mut graph) - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn post_fader_send_follows_fader_and_pre_does_not() {
+        let (mut graph, send) = send_return(SendTap::PostFader);
+        graph.set_send_fader(send, -6.0);
+        let fader = db_to_linear(-6.0);
+        let wet = db_to_linear(-6.0) * 0.5;
+        assert!((settle(&mut graph) - (fader + wet * fader)).abs() < 1e-4);
+
+        graph.set_send_tap(send, SendTap::PreFader);
+        assert!((settle(&mut graph) - (fader + wet)).abs() < 1e-4);
+        assert_eq!(graph.aux_send(send).unwrap().tap(), SendTap::PreFader);
+    }
+
+    #[test]
+    fn muted_send_leaves_dry_path() {
+        let (mut graph, send) = send_return(SendTap::PostFader);
+        graph.set_send_level(send, f32::NEG_INFINITY);
+        assert!((settle(&mut graph) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn send_requires_aux_bus_and_one_through_edge() {
+        let mut graph = ProcessingGraph::new(48000.0, 64);
+        let merge = graph.add_merge();
+        assert!(matches!(
+            graph.add_send(merge, 0.0, SendTap::PostFader),
+            Err(GraphError::InvalidConnection(_))
+        ));
+        assert_eq!(graph.node_count(), 1);
+
+        let aux = graph.add_aux_bus();
+        let send = graph.add_send(aux, 0.0, SendTap::PostFader).unwrap();
+        assert_eq!(graph.aux_send(send).unwrap().aux(), aux);
+        let a = graph.add_merge();
+        let b = graph.add_merge();
+        graph.connect(send, a).unwrap();
+        assert!(graph.connect(send, b).is_err());
+    }
+}
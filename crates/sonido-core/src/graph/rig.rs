@@ -0,0 +1,268 @@
+//! Dual A/B rigs: two parallel effect graphs fed from the same input.
+//!
+//! A dual-rig [`GraphEngine`](super::GraphEngine) holds both rigs in one
+//! [`ProcessingGraph`](super::ProcessingGraph) using multiple buses: input and
+//! output bus 0 belong to rig A, bus 1 to rig B. Each block the same input
+//! audio feeds both input buses, both rigs run, and the engine combines the two
+//! output buses according to the [`RigOutput`] mode:
+//!
+//! - [`RigOutput::Blend`] — equal-power morph between the rigs. Morph `0.0` is
+//!   rig A only, `1.0` rig B only, `0.5` both at -3 dB. Morph changes are
+//!   smoothed, so a footswitch toggle (snapping morph to the other end) is a
+//!   short crossfade rather than a click.
+//! - [`RigOutput::Split`] — rig A summed to mono on the left output, rig B on
+//!   the right, for feeding two amps or a stereo pair of cabinets.
+//!
+//! Both rigs always run, so reverb and delay tails of the inactive rig keep
+//! decaying naturally and a switch back never starts from stale state.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use core::f32::consts::FRAC_PI_2;
+
+use crate::SmoothedParam;
+
+/// Morph smoothing time constant in milliseconds (sets the A↔B toggle crossfade).
+pub const RIG_MORPH_MS: f32 = 20.0;
+
+/// One of the two rigs of a dual-rig engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Rig {
+    /// The first rig (bus 0).
+    #[default]
+    A,
+    /// The second rig (bus 1).
+    B,
+}
+
+impl Rig {
+    /// Both rigs, in bus order.
+    pub const ALL: [Rig; 2] = [Rig::A, Rig::B];
+
+    /// Display label (`"Rig A"` / `"Rig B"`).
+    pub fn label(self) -> &'static str {
+        match self {
+            Rig::A => "Rig A",
+            Rig::B => "Rig B",
+        }
+    }
+
+    /// The other rig.
+    pub fn other(self) -> Rig {
+        match self {
+            Rig::A => Rig::B,
+            Rig::B => Rig::A,
+        }
+    }
+
+    /// Morph position at which this rig is heard alone (`0.0` for A, `1.0` for B).
+    pub fn morph(self) -> f32 {
+        match self {
+            Rig::A => 0.0,
+            Rig::B => 1.0,
+        }
+    }
+
+    /// The rig that dominates at a morph position (B above `0.5`).
+    pub fn from_morph(morph: f32) -> Rig {
+        if morph > 0.5 { Rig::B } else { Rig::A }
+    }
+}
+
+/// How a dual-rig engine combines its two rigs into the stereo output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RigOutput {
+    /// Equal-power morph between rig A and rig B (default).
+    #[default]
+    Blend,
+    /// Rig A (mono sum) on the left output, rig B on the right. Morph is ignored.
+    Split,
+}
+
+impl RigOutput {
+    /// All modes, in menu order.
+    pub const ALL: [RigOutput; 2] = [RigOutput::Blend, RigOutput::Split];
+
+    /// Display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            RigOutput::Blend => "Blend",
+            RigOutput::Split => "Split",
+        }
+    }
+
+    /// Parses a label (case-insensitive) back into a mode.
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.label().eq_ignore_ascii_case(label))
+    }
+
+    /// Position in [`ALL`](Self::ALL), for storing the mode in an atomic.
+    pub const fn index(self) -> u8 {
+        match self {
+            Self::Blend => 0,
+            Self::Split => 1,
+        }
+    }
+
+    /// Inverse of [`index()`](Self::index); out-of-range values map to
+    /// [`Blend`](Self::Blend).
+    pub const fn from_index(index: u8) -> Self {
+        match index {
+            1 => Self::Split,
+            _ => Self::Blend,
+        }
+    }
+}
+
+/// Equal-power `(gain_a, gain_b)` for a morph position in `[0, 1]`.
+pub fn morph_gains(morph: f32) -> (f32, f32) {
+    let angle = morph.clamp(0.0, 1.0) * FRAC_PI_2;
+    (libm::cosf(angle), libm::sinf(angle))
+}
+
+/// Output stage of a dual-rig engine: per-rig scratch buffers and the mixer.
+pub(crate) struct RigMix {
+    /// First slot index belonging to rig B.
+    pub(crate) b_start: usize,
+    output: RigOutput,
+    morph: SmoothedParam,
+    pub(crate) a_left: Vec<f32>,
+    pub(crate) a_right: Vec<f32>,
+    pub(crate) b_left: Vec<f32>,
+    pub(crate) b_right: Vec<f32>,
+}
+
+impl RigMix {
+    pub(crate) fn new(b_start: usize, sample_rate: f32, block_size: usize) -> Self {
+        Self {
+            b_start,
+            output: RigOutput::Blend,
+            morph: SmoothedParam::with_config(0.0, sample_rate, RIG_MORPH_MS),
+            a_left: vec![0.0; block_size],
+            a_right: vec![0.0; block_size],
+            b_left: vec![0.0; block_size],
+            b_right: vec![0.0; block_size],
+        }
+    }
+
+    pub(crate) fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.morph.set_sample_rate(sample_rate);
+    }
+
+    pub(crate) fn output(&self) -> RigOutput {
+        self.output
+    }
+
+    pub(crate) fn set_output(&mut self, output: RigOutput) {
+        self.output = output;
+    }
+
+    pub(crate) fn morph(&self) -> f32 {
+        self.morph.target()
+    }
+
+    pub(crate) fn set_morph(&mut self, morph: f32) {
+        self.morph.set_target(morph.clamp(0.0, 1.0));
+    }
+
+    pub(crate) fn set_morph_immediate(&mut self, morph: f32) {
+        self.morph.set_immediate(morph.clamp(0.0, 1.0));
+    }
+
+    /// Grows the scratch buffers to hold `len` frames (no-op once sized).
+    pub(crate) fn ensure_len(&mut self, len: usize) {
+        for buf in [
+            &mut self.a_left,
+            &mut self.a_right,
+            &mut self.b_left,
+            &mut self.b_right,
+        ] {
+            if buf.len() < len {
+                buf.resize(len, 0.0);
+            }
+        }
+    }
+
+    /// Mixes the first `left.len()` frames of both rigs into `left`/`right`.
+    pub(crate) fn mix(&mut self, left: &mut [f32], right: &mut [f32]) {
+        let len = left.len().min(right.len());
+        match self.output {
+            RigOutput::Split => {
+                for i in 0..len {
+                    left[i] = 0.5 * (self.a_left[i] + self.a_right[i]);
+                    right[i] = 0.5 * (self.b_left[i] + self.b_right[i]);
+                }
+                // Morph is ignored here; settle it so switching to Blend starts clean.
+                if !self.morph.is_settled() {
+                    self.morph.snap_to_target();
+                }
+            }
+            RigOutput::Blend => {
+                if self.morph.is_settled() {
+                    let (ga, gb) = morph_gains(self.morph.get());
+                    for i in 0..len {
+                        left[i] = ga * self.a_left[i] + gb * self.b_left[i];
+                        right[i] = ga * self.a_right[i] + gb * self.b_right[i];
+                    }
+                } else {
+                    for i in 0..len {
+                        let (ga, gb) = morph_gains(self.morph.advance());
+                        left[i] = ga * self.a_left[i] + gb * self.b_left[i];
+                        right[i] = ga * self.a_right[i] + gb * self.b_right[i];
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rig_output_label_roundtrip() {
+        for mode in RigOutput::ALL {
+            assert_eq!(RigOutput::from_label(mode.label()), Some(mode));
+        }
+        assert_eq!(RigOutput::from_label("split"), Some(RigOutput::Split));
+        assert_eq!(RigOutput::from_label("both"), None);
+        for mode in RigOutput::ALL {
+            assert_eq!(RigOutput::from_index(mode.index()), mode);
+        }
+    }
+
+    #[test]
+    fn rig_other_and_morph() {
+        assert_eq!(Rig::A.other(), Rig::B);
+        assert_eq!(Rig::from_morph(Rig::B.morph()), Rig::B);
+        assert_eq!(Rig::from_morph(0.5), Rig::A);
+    }
+
+    #[test]
+    fn morph_gains_equal_power() {
+        assert_eq!(morph_gains(0.0), (1.0, 0.0));
+        let (a, b) = morph_gains(1.0);
+        assert!(a.abs() < 1e-6 && (b - 1.0).abs() < 1e-6);
+        let (a, b) = morph_gains(0.5);
+        assert!((a * a + b * b - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn split_puts_rigs_on_separate_sides() {
+        let mut mix = RigMix::new(0, 48000.0, 4);
+        mix.set_output(RigOutput::Split);
+        mix.a_left.fill(1.0);
+        mix.a_right.fill(1.0);
+        mix.b_left.fill(-0.5);
+        mix.b_right.fill(-0.5);
+        let (mut l, mut r) = ([0.0; 4], [0.0; 4]);
+        mix.mix(&mut l, &mut r);
+        assert_eq!(l, [1.0; 4]);
+        assert_eq!(r, [-0.5; 4]);
+    }
+}
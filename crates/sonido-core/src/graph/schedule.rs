@@ -11,11 +11,13 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-/// Maximum number of fan-out targets for a single Split node.
+/// Number of destinations one [`ProcessStep::SplitCopy`] step carries.
 ///
 /// Fixed-size array eliminates the `Vec<usize>` heap allocation from `SplitCopy`,
-/// making the `ProcessStep` enum entirely stack-allocated.
-pub const MAX_SPLIT_TARGETS: usize = 8;
+/// making the `ProcessStep` enum entirely stack-allocated. This does not limit
+/// fan-out: a wider Split compiles to consecutive `SplitCopy` steps from the
+/// same source.
+pub const SPLIT_COPY_WIDTH: usize = 8;
 
 /// A single instruction in the compiled processing schedule.
 ///
@@ -25,12 +27,14 @@ pub const MAX_SPLIT_TARGETS: usize = 8;
 /// and read final output.
 ///
 /// All variants are stack-allocated (no heap pointers) for RT-safety.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ProcessStep {
     /// Write external audio input into a buffer slot.
     WriteInput {
         /// Buffer slot to write the external input into.
         buffer_idx: usize,
+        /// Input bus the audio is taken from (0 = main).
+        bus: usize,
     },
 
     /// Process audio through an effect node.
@@ -78,12 +82,13 @@ pub enum ProcessStep {
     /// Copy a source buffer to one or more destination buffers (fan-out from Split node).
     ///
     /// Uses a fixed-size array to avoid heap allocation. `dest_count` indicates
-    /// how many entries in `dest_bufs` are valid.
+    /// how many entries in `dest_bufs` are valid. A Split with more than
+    /// [`SPLIT_COPY_WIDTH`] outputs emits one step per chunk of destinations.
     SplitCopy {
         /// Buffer slot to copy from.
         source_buf: usize,
         /// Buffer slots to copy into (first `dest_count` entries are valid).
-        dest_bufs: [usize; MAX_SPLIT_TARGETS],
+        dest_bufs: [usize; SPLIT_COPY_WIDTH],
         /// Number of valid entries in `dest_bufs`.
         dest_count: usize,
     },
@@ -148,10 +153,27 @@ pub enum ProcessStep {
         block_len: usize,
     },
 
+    /// Run an inline send: write the faded input to `through_buf` and the
+    /// send-level copy to `send_buf`, which feeds an aux bus.
+    ///
+    /// Either output may be absent if the send is not wired on that side.
+    Send {
+        /// Index into the graph's node array (for fader and level state).
+        node_idx: usize,
+        /// Buffer slot holding the send's input.
+        source_buf: usize,
+        /// Buffer slot for the through (post-fader) signal.
+        through_buf: Option<usize>,
+        /// Buffer slot for the signal sent to the aux bus.
+        send_buf: Option<usize>,
+    },
+
     /// Read the final output from a buffer slot.
     ReadOutput {
         /// Buffer slot containing the final mixed output.
         buffer_idx: usize,
+        /// Output bus the audio is written to (0 = main).
+        bus: usize,
     },
 }
 
@@ -173,6 +195,10 @@ pub struct CompiledSchedule {
     pub(crate) delay_sample_counts: Vec<usize>,
     /// Total graph latency in samples (longest path from input to output).
     pub(crate) total_latency: usize,
+    /// Number of input buses (one per Input node).
+    pub(crate) input_bus_count: usize,
+    /// Latency in samples of each output bus, indexed by bus number.
+    pub(crate) output_latencies: Vec<usize>,
     /// Block size used for `SampleAndHold` and `FeedbackDelay` steps.
     pub(crate) block_size: usize,
     /// Number of feedback delay lines needed.
@@ -196,6 +222,25 @@ impl CompiledSchedule {
         self.total_latency
     }
 
+    /// Returns the number of input buses the schedule reads from.
+    pub fn input_bus_count(&self) -> usize {
+        self.input_bus_count
+    }
+
+    /// Returns the number of output buses the schedule writes to.
+    pub fn output_bus_count(&self) -> usize {
+        self.output_latencies.len()
+    }
+
+    /// Returns the latency in samples of output bus `bus`, or `None` if the
+    /// bus does not exist.
+    ///
+    /// Buses are compensated independently: a cue bus that skips a lookahead
+    /// limiter on the main bus reports less latency than the main bus.
+    pub fn output_latency(&self, bus: usize) -> Option<usize> {
+        self.output_latencies.get(bus).copied()
+    }
+
     /// Returns the number of compensation delay lines.
     pub fn delay_line_count(&self) -> usize {
         self.delay_sample_counts.len()
@@ -486,10 +531,10 @@ mod tests {
         assert!(sched.buffer_count() <= 4, "linear chain needs few buffers");
     }
 
-    // ── MAX_SPLIT_TARGETS constant ─────────────────────────────────────────
+    // ── SPLIT_COPY_WIDTH constant ──────────────────────────────────────────
 
     #[test]
-    fn max_split_targets_is_at_least_two() {
-        const { assert!(MAX_SPLIT_TARGETS >= 2) };
+    fn split_copy_width_is_at_least_two() {
+        const { assert!(SPLIT_COPY_WIDTH >= 2) };
     }
 }
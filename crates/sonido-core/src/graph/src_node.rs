@@ -0,0 +1,609 @@
+//! Streaming sample-rate conversion as a graph primitive.
+//!
+//! [`SrcNode`] lets a source running at its own rate — a file player with
+//! 44.1 kHz material, a network stream — feed a graph running at a different
+//! rate without converting the material offline. The source pushes frames at
+//! its native rate into the [`SrcFeed`] handle; the node renders them at the
+//! graph rate. The node's graph input is ignored, so it acts as a source: wire
+//! it from the graph input (or any node) for scheduling, and merge its output
+//! with the live path.
+//!
+//! ```text
+//!   source thread / callback            audio thread (graph rate)
+//!   ────────────────────────            ─────────────────────────
+//!   SrcFeed::push(l, r) ──► SPSC ring ──► SrcNode ──► Merge ──► Output
+//!                                                       ▲
+//!                                 Input ────────────────┘ (live path)
+//! ```
+//!
+//! # Polyphase resampling
+//!
+//! The conversion ratio is reduced to `P/Q` (graph rate / source rate, e.g.
+//! 160/147 for 44.1 → 48 kHz). Rates that don't reduce to `P ≤`
+//! [`SRC_MAX_PHASES`] use the closest fraction within that bound, a pitch
+//! error of a few ppm at most. A windowed-sinc prototype (Blackman window,
+//! cutoff `0.9 / max(P, Q)`, the same design as `sonido_analysis::resample`)
+//! is split into `P` polyphase branches of [`SRC_TAPS_PER_PHASE`] taps or
+//! more. Each output sample evaluates one branch against the most recent
+//! source frames, so per-sample cost is fixed whatever the ratio.
+//!
+//! Reference: P. P. Vaidyanathan, *Multirate Systems and Filter Banks*,
+//! Prentice Hall, 1993, Section 4.3.
+//!
+//! # Latency
+//!
+//! [`latency_samples()`](Effect::latency_samples) reports the prototype's
+//! group delay converted to graph-rate samples, so latency compensation
+//! delays the parallel live path to match.
+//!
+//! # Underruns
+//!
+//! If the ring runs dry the node renders silence for the missing frames and
+//! keeps its timing; [`SrcFeed::underruns()`] counts them. Producers keep the
+//! ring topped up with [`SrcFeed::demand()`] before each graph block.
+
+#[cfg(not(feature = "std"))]
+use alloc::{sync::Arc, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use core::f32::consts::PI;
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use crate::effect::Effect;
+use crate::param_info::{ParamDescriptor, ParameterInfo};
+
+/// Minimum taps per polyphase branch.
+///
+/// Downsampling ratios (`Q > P`) get proportionally more taps per branch so
+/// the anti-aliasing cutoff keeps the same transition width.
+pub const SRC_TAPS_PER_PHASE: usize = 32;
+
+/// Upper bound on the number of polyphase branches (`P`).
+pub const SRC_MAX_PHASES: usize = 1024;
+
+/// Feed ring capacity in frames: several blocks of source material even at
+/// 4× downsampling with 1024-sample graph blocks.
+const MIN_FEED_CAPACITY: usize = 8192;
+
+/// Lock-free single-producer/single-consumer stereo frame ring.
+struct FeedRing {
+    /// Left samples as f32 bit-cast to u32.
+    left: Vec<AtomicU32>,
+    /// Right samples as f32 bit-cast to u32.
+    right: Vec<AtomicU32>,
+    /// `capacity - 1`; capacity is a power of two.
+    mask: usize,
+    /// Total frames written (producer-owned).
+    head: AtomicUsize,
+    /// Total frames read (consumer-owned).
+    tail: AtomicUsize,
+    /// Current ratio numerator (graph rate), for [`SrcFeed::demand()`].
+    up: AtomicUsize,
+    /// Current ratio denominator (source rate).
+    down: AtomicUsize,
+    /// Frames rendered as silence because the ring was empty.
+    underruns: AtomicUsize,
+}
+
+impl FeedRing {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(MIN_FEED_CAPACITY).next_power_of_two();
+        Self {
+            left: (0..capacity).map(|_| AtomicU32::new(0)).collect(),
+            right: (0..capacity).map(|_| AtomicU32::new(0)).collect(),
+            mask: capacity - 1,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            up: AtomicUsize::new(1),
+            down: AtomicUsize::new(1),
+            underruns: AtomicUsize::new(0),
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.head
+            .load(Ordering::Acquire)
+            .wrapping_sub(self.tail.load(Ordering::Acquire))
+    }
+
+    fn capacity(&self) -> usize {
+        self.mask + 1
+    }
+
+    /// Pop one frame (consumer side).
+    fn pop(&self) -> Option<(f32, f32)> {
+        let tail = self.tail.load(Ordering::Relaxed);
+        if self.head.load(Ordering::Acquire) == tail {
+            return None;
+        }
+        let i = tail & self.mask;
+        let frame = (
+            f32::from_bits(self.left[i].load(Ordering::Relaxed)),
+            f32::from_bits(self.right[i].load(Ordering::Relaxed)),
+        );
+        self.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Some(frame)
+    }
+}
+
+/// Producer handle for an [`SrcNode`]: push source-rate frames from any one
+/// thread.
+///
+/// Returned alongside the node by [`SrcNode::new()`]. Cloning is not
+/// supported — the ring has exactly one producer.
+pub struct SrcFeed {
+    ring: Arc<FeedRing>,
+}
+
+impl SrcFeed {
+    /// Push stereo frames at the source rate. Returns how many frames were
+    /// accepted (fewer than `left.len()` when the ring is full).
+    pub fn push(&self, left: &[f32], right: &[f32]) -> usize {
+        let ring = &*self.ring;
+        let head = ring.head.load(Ordering::Relaxed);
+        let space = ring.capacity() - ring.available();
+        let n = left.len().min(right.len()).min(space);
+        for (k, (&l, &r)) in left.iter().zip(right).take(n).enumerate() {
+            let i = head.wrapping_add(k) & ring.mask;
+            ring.left[i].store(l.to_bits(), Ordering::Relaxed);
+            ring.right[i].store(r.to_bits(), Ordering::Relaxed);
+        }
+        ring.head.store(head.wrapping_add(n), Ordering::Release);
+        n
+    }
+
+    /// Frames queued and not yet consumed by the node.
+    pub fn available(&self) -> usize {
+        self.ring.available()
+    }
+
+    /// Ring capacity in frames.
+    pub fn capacity(&self) -> usize {
+        self.ring.capacity()
+    }
+
+    /// Source frames to push so the node can render `output_frames` at the
+    /// graph rate without underrunning.
+    ///
+    /// Pushing exactly this amount before each block keeps the ring bounded
+    /// at about one block of source frames.
+    pub fn demand(&self, output_frames: usize) -> usize {
+        let up = self.ring.up.load(Ordering::Acquire);
+        let down = self.ring.down.load(Ordering::Acquire);
+        let needed = (output_frames * down).div_ceil(up) + 1;
+        needed.saturating_sub(self.available())
+    }
+
+    /// Total frames the node rendered as silence because the ring was empty.
+    pub fn underruns(&self) -> usize {
+        self.ring.underruns.load(Ordering::Relaxed)
+    }
+}
+
+/// Streaming polyphase sample-rate converter node.
+///
+/// Renders frames pushed through its [`SrcFeed`] (at `source_rate`) at the
+/// graph's sample rate. Implements [`Effect`] and [`ParameterInfo`] (no
+/// parameters), so it is added with
+/// [`ProcessingGraph::add_effect()`](super::ProcessingGraph::add_effect),
+/// which also sets its output rate.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::graph::{ProcessingGraph, SrcNode};
+///
+/// let (src, feed) = SrcNode::new(44100.0, 48000.0);
+/// let mut graph = ProcessingGraph::new(48000.0, 256);
+/// let input = graph.add_input();
+/// let output = graph.add_output();
+/// let node = graph.add_effect(Box::new(src));
+/// graph.connect(input, node).unwrap();
+/// graph.connect(node, output).unwrap();
+/// graph.compile().unwrap();
+///
+/// // Before each block, top up the feed with 44.1 kHz material.
+/// let material = vec![0.0_f32; 1024];
+/// let n = feed.demand(256);
+/// feed.push(&material[..n], &material[..n]);
+///
+/// let silence = [0.0_f32; 256];
+/// let (mut left, mut right) = ([0.0_f32; 256], [0.0_f32; 256]);
+/// graph.process_block(&silence, &silence, &mut left, &mut right);
+/// ```
+pub struct SrcNode {
+    ring: Arc<FeedRing>,
+    source_rate: f32,
+    /// Ratio numerator: output (graph) rate after reduction.
+    up: usize,
+    /// Ratio denominator: source rate after reduction.
+    down: usize,
+    /// Taps per polyphase branch.
+    taps: usize,
+    /// Branch coefficients, `up × taps`, each branch ordered oldest → newest
+    /// frame and pre-scaled by `up` (zero-insertion gain).
+    coeffs: Vec<f32>,
+    /// Source frame history, doubled so the newest `taps` frames are always
+    /// one contiguous slice.
+    history_l: Vec<f32>,
+    history_r: Vec<f32>,
+    /// Write position in the history (`0..taps`).
+    history_pos: usize,
+    /// Current polyphase branch (`0..up`).
+    phase: usize,
+    /// Whether the first source frame has been loaded.
+    primed: bool,
+    /// Prototype group delay in graph-rate samples.
+    latency: usize,
+}
+
+impl SrcNode {
+    /// Create a converter from `source_rate` to `graph_rate` and its feed.
+    ///
+    /// `graph_rate` is replaced by the graph's rate when the node is added to
+    /// a graph. Non-positive rates are treated as equal rates.
+    pub fn new(source_rate: f32, graph_rate: f32) -> (Self, SrcFeed) {
+        let ring = Arc::new(FeedRing::new(0));
+        let mut node = Self {
+            ring: Arc::clone(&ring),
+            source_rate,
+            up: 1,
+            down: 1,
+            taps: SRC_TAPS_PER_PHASE,
+            coeffs: Vec::new(),
+            history_l: Vec::new(),
+            history_r: Vec::new(),
+            history_pos: 0,
+            phase: 0,
+            primed: false,
+            latency: 0,
+        };
+        node.configure(graph_rate);
+        (node, SrcFeed { ring })
+    }
+
+    /// Source sample rate in Hz.
+    pub fn source_rate(&self) -> f32 {
+        self.source_rate
+    }
+
+    /// Reduced conversion ratio `(P, Q)`: `P` graph-rate samples per `Q`
+    /// source frames.
+    pub fn ratio(&self) -> (usize, usize) {
+        (self.up, self.down)
+    }
+
+    /// Rebuild the polyphase filter for a new graph rate.
+    fn configure(&mut self, graph_rate: f32) {
+        let (up, down) = reduce_ratio(graph_rate, self.source_rate);
+        let num_taps = SRC_TAPS_PER_PHASE * up.max(down) + 1;
+        let taps = num_taps.div_ceil(up);
+        let prototype = design_lowpass(num_taps, 0.9 / up.max(down) as f32);
+
+        // Branch `p` holds prototype taps p, p+P, p+2P, …; tap k multiplies
+        // the frame k steps before the newest, so store it at `taps-1-k`.
+        let mut coeffs = vec![0.0; up * taps];
+        for (n, &h) in prototype.iter().enumerate() {
+            let (p, k) = (n % up, n / up);
+            coeffs[p * taps + (taps - 1 - k)] = h * up as f32;
+        }
+
+        self.up = up;
+        self.down = down;
+        self.taps = taps;
+        self.coeffs = coeffs;
+        self.history_l = vec![0.0; 2 * taps];
+        self.history_r = vec![0.0; 2 * taps];
+        self.latency = libm::roundf((num_taps - 1) as f32 / (2.0 * down as f32)) as usize;
+        self.ring.up.store(up, Ordering::Release);
+        self.ring.down.store(down, Ordering::Release);
+        self.reset_state();
+    }
+
+    fn reset_state(&mut self) {
+        self.history_l.fill(0.0);
+        self.history_r.fill(0.0);
+        self.history_pos = 0;
+        self.phase = 0;
+        self.primed = false;
+    }
+
+    /// Load the next source frame into the history (silence on underrun).
+    fn advance_source(&mut self) {
+        let (l, r) = self.ring.pop().unwrap_or_else(|| {
+            self.ring.underruns.fetch_add(1, Ordering::Relaxed);
+            (0.0, 0.0)
+        });
+        let pos = self.history_pos;
+        self.history_l[pos] = l;
+        self.history_l[pos + self.taps] = l;
+        self.history_r[pos] = r;
+        self.history_r[pos + self.taps] = r;
+        self.history_pos = (pos + 1) % self.taps;
+    }
+
+    /// Render one graph-rate frame.
+    fn render(&mut self) -> (f32, f32) {
+        if !self.primed {
+            self.advance_source();
+            self.primed = true;
+        }
+
+        // After the last write at `history_pos - 1`, the newest `taps`
+        // frames are the contiguous window starting at `history_pos`.
+        let start = self.history_pos;
+        let branch = &self.coeffs[self.phase * self.taps..(self.phase + 1) * self.taps];
+        let window_l = &self.history_l[start..start + self.taps];
+        let window_r = &self.history_r[start..start + self.taps];
+        let mut out_l = 0.0;
+        let mut out_r = 0.0;
+        for ((&h, &l), &r) in branch.iter().zip(window_l).zip(window_r) {
+            out_l += h * l;
+            out_r += h * r;
+        }
+
+        self.phase += self.down;
+        while self.phase >= self.up {
+            self.phase -= self.up;
+            self.advance_source();
+        }
+        (out_l, out_r)
+    }
+}
+
+impl Effect for SrcNode {
+    fn process(&mut self, _input: f32) -> f32 {
+        self.render().0
+    }
+
+    fn process_stereo(&mut self, _left: f32, _right: f32) -> (f32, f32) {
+        self.render()
+    }
+
+    fn process_block_stereo(
+        &mut self,
+        _left_in: &[f32],
+        _right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        for (l, r) in left_out.iter_mut().zip(right_out.iter_mut()) {
+            (*l, *r) = self.render();
+        }
+    }
+
+    fn is_true_stereo(&self) -> bool {
+        true
+    }
+
+    /// Rebuilds the filter when the new graph rate changes the conversion
+    /// ratio. Allocates — call from the mutation thread, as
+    /// [`ProcessingGraph`](super::ProcessingGraph) does.
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        if reduce_ratio(sample_rate, self.source_rate) != (self.up, self.down) {
+            self.configure(sample_rate);
+        }
+    }
+
+    /// Clears the filter history and drops queued source frames.
+    fn reset(&mut self) {
+        self.reset_state();
+        while self.ring.pop().is_some() {}
+    }
+
+    fn latency_samples(&self) -> usize {
+        self.latency
+    }
+}
+
+impl ParameterInfo for SrcNode {
+    fn param_count(&self) -> usize {
+        0
+    }
+
+    fn param_info(&self, _index: usize) -> Option<ParamDescriptor> {
+        None
+    }
+
+    fn get_param(&self, _index: usize) -> f32 {
+        0.0
+    }
+
+    fn set_param(&mut self, _index: usize, _value: f32) {}
+}
+
+/// Reduce `to / from` to a fraction `P/Q` with `P ≤ SRC_MAX_PHASES`.
+///
+/// Integer rates reduce exactly by their GCD; anything else (or a reduced
+/// `P` over the bound) falls back to the best continued-fraction
+/// approximation within the bound.
+fn reduce_ratio(to: f32, from: f32) -> (usize, usize) {
+    if !(to > 0.0 && from > 0.0) {
+        return (1, 1);
+    }
+    let (to_hz, from_hz) = (libm::roundf(to), libm::roundf(from));
+    if to_hz == to && from_hz == from {
+        let (to_hz, from_hz) = (to_hz as usize, from_hz as usize);
+        let g = gcd(to_hz, from_hz);
+        let (p, q) = (to_hz / g, from_hz / g);
+        if p <= SRC_MAX_PHASES && q <= SRC_MAX_PHASES * 16 {
+            return (p, q);
+        }
+    }
+    best_fraction(f64::from(to) / f64::from(from), SRC_MAX_PHASES)
+}
+
+/// Closest fraction `p/q` to `x` with `p ≤ max_num`, by continued fractions.
+fn best_fraction(x: f64, max_num: usize) -> (usize, usize) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0usize, 1usize, 1usize, 0usize);
+    let mut value = x;
+    loop {
+        let a = libm::floor(value) as usize;
+        let p2 = a * p1 + p0;
+        let q2 = a * q1 + q0;
+        if p2 > max_num || q2 == 0 && p1 == 0 {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        let frac = value - a as f64;
+        if frac < 1e-12 {
+            break;
+        }
+        value = 1.0 / frac;
+    }
+    if p1 == 0 || q1 == 0 { (1, 1) } else { (p1, q1) }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Windowed-sinc lowpass (Blackman window), normalized to unity DC gain.
+///
+/// `cutoff` is normalized to Nyquist (1.0 = fs/2).
+fn design_lowpass(num_taps: usize, cutoff: f32) -> Vec<f32> {
+    let m = (num_taps - 1) as f32;
+    let mut coeffs: Vec<f32> = (0..num_taps)
+        .map(|n| {
+            let x = n as f32 - m / 2.0;
+            let sinc = if x.abs() < 1e-7 {
+                cutoff
+            } else {
+                libm::sinf(PI * cutoff * x) / (PI * x)
+            };
+            let t = 2.0 * PI * n as f32 / m;
+            let window = 0.42 - 0.5 * libm::cosf(t) + 0.08 * libm::cosf(2.0 * t);
+            sinc * window
+        })
+        .collect();
+    let sum: f32 = coeffs.iter().sum();
+    if sum.abs() > 1e-12 {
+        for c in &mut coeffs {
+            *c /= sum;
+        }
+    }
+    coeffs
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn standard_rates_reduce_exactly() {
+        assert_eq!(reduce_ratio(48000.0, 44100.0), (160, 147));
+        assert_eq!(reduce_ratio(44100.0, 48000.0), (147, 160));
+        assert_eq!(reduce_ratio(96000.0, 48000.0), (2, 1));
+        assert_eq!(reduce_ratio(48000.0, 48000.0), (1, 1));
+    }
+
+    #[test]
+    fn odd_rates_use_bounded_approximation() {
+        let (p, q) = reduce_ratio(48000.0, 44099.5);
+        assert!(p <= SRC_MAX_PHASES);
+        let ratio = p as f64 / q as f64;
+        assert!((ratio - 48000.0 / 44099.5).abs() < 1e-5, "{p}/{q}");
+    }
+
+    /// Run `frames` graph-rate samples of a sine at `freq` (source rate
+    /// `from`), feeding on demand in blocks of 256.
+    fn render_sine(from: f32, to: f32, freq: f32, frames: usize) -> (Vec<f32>, SrcFeed, usize) {
+        let (mut node, feed) = SrcNode::new(from, to);
+        let latency = node.latency_samples();
+        let mut phase = 0usize;
+        let mut out = Vec::with_capacity(frames);
+        let (mut l, mut r) = ([0.0_f32; 256], [0.0_f32; 256]);
+        while out.len() < frames {
+            let n = feed.demand(256);
+            let src: Vec<f32> = (phase..phase + n)
+                .map(|i| libm::sinf(2.0 * PI * freq * i as f32 / from))
+                .collect();
+            phase += n;
+            assert_eq!(feed.push(&src, &src), n);
+            node.process_block_stereo(&[0.0; 256], &[0.0; 256], &mut l, &mut r);
+            out.extend_from_slice(&l);
+        }
+        (out, feed, latency)
+    }
+
+    #[test]
+    fn converts_frequency_and_level() {
+        let (out, feed, latency) = render_sine(44100.0, 48000.0, 1000.0, 48000);
+        assert_eq!(feed.underruns(), 0);
+
+        // Skip the filter delay, then count rising zero crossings over 0.5 s.
+        let settled = &out[latency + 1000..latency + 1000 + 24000];
+        let crossings = settled
+            .windows(2)
+            .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+            .count();
+        assert!((499..=501).contains(&crossings), "crossings = {crossings}");
+
+        let peak = settled.iter().fold(0.0_f32, |m, &s| m.max(s.abs()));
+        assert!((peak - 1.0).abs() < 0.02, "peak = {peak}");
+    }
+
+    #[test]
+    fn downsampling_keeps_level() {
+        let (out, feed, latency) = render_sine(48000.0, 44100.0, 1000.0, 44100);
+        assert_eq!(feed.underruns(), 0);
+        let settled = &out[latency + 1000..];
+        let peak = settled.iter().fold(0.0_f32, |m, &s| m.max(s.abs()));
+        assert!((peak - 1.0).abs() < 0.02, "peak = {peak}");
+    }
+
+    #[test]
+    fn demand_keeps_ring_bounded() {
+        let (_, feed, _) = render_sine(44100.0, 48000.0, 440.0, 48000);
+        // About one block of source frames stays queued.
+        assert!(feed.available() <= 256, "available = {}", feed.available());
+    }
+
+    #[test]
+    fn underrun_renders_silence() {
+        let (mut node, feed) = SrcNode::new(44100.0, 48000.0);
+        let (l, r) = node.process_stereo(0.3, 0.3);
+        assert_eq!((l, r), (0.0, 0.0));
+        assert!(feed.underruns() > 0);
+    }
+
+    #[test]
+    fn push_stops_when_full() {
+        let (_node, feed) = SrcNode::new(44100.0, 48000.0);
+        let block = vec![0.5_f32; feed.capacity() + 10];
+        assert_eq!(feed.push(&block, &block), feed.capacity());
+        assert_eq!(feed.push(&block, &block), 0);
+    }
+
+    #[test]
+    fn graph_compensates_src_latency() {
+        use crate::graph::ProcessingGraph;
+
+        let (src, _feed) = SrcNode::new(44100.0, 48000.0);
+        let latency = src.latency_samples();
+        assert!(latency > 0);
+
+        let mut graph = ProcessingGraph::new(48000.0, 256);
+        let input = graph.add_input();
+        let output = graph.add_output();
+        let split = graph.add_split();
+        let merge = graph.add_merge();
+        let node = graph.add_effect(Box::new(src));
+        graph.connect(input, split).unwrap();
+        graph.connect(split, node).unwrap();
+        graph.connect(split, merge).unwrap();
+        graph.connect(node, merge).unwrap();
+        graph.connect(merge, output).unwrap();
+        graph.compile().unwrap();
+
+        assert_eq!(graph.latency_samples(), latency);
+    }
+}
@@ -0,0 +1,202 @@
+//! Engine-to-engine crossfades for preset changes.
+//!
+//! A schedule swap inside one graph always crossfades over ~5 ms. Switching
+//! presets live benefits from a longer, configurable fade between two whole
+//! engines: [`GraphEngine::crossfade_from()`](super::GraphEngine::crossfade_from)
+//! keeps the outgoing engine running beside the incoming one for the
+//! [`Transition`] time. The [`TailMode`] decides what happens to the outgoing
+//! engine's delays and reverbs:
+//!
+//! - [`TailMode::Fade`] — the outgoing engine's output fades out; its tails are
+//!   cut off with it.
+//! - [`TailMode::RingOut`] — the outgoing engine's *input* fades out while its
+//!   output stays in the mix, so the dry signal hands over smoothly and the old
+//!   delay repeats and reverb tail decay naturally under the new preset. The
+//!   outgoing engine is dropped once its longest effect tail has passed.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::LinearSmoothedParam;
+
+use super::GraphEngine;
+
+/// Default preset crossfade time in milliseconds.
+pub const DEFAULT_TRANSITION_MS: f32 = 50.0;
+
+/// Longest preset crossfade time in milliseconds.
+pub const MAX_TRANSITION_MS: f32 = 2000.0;
+
+/// Longest ring-out of an outgoing engine in [`TailMode::RingOut`], in seconds.
+pub const MAX_RING_OUT_SECS: f32 = 10.0;
+
+/// What happens to the outgoing engine's effect tails during a [`Transition`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TailMode {
+    /// Fade the outgoing engine's output, tails included (default).
+    #[default]
+    Fade,
+    /// Fade the outgoing engine's input and let its tails ring out.
+    RingOut,
+}
+
+impl TailMode {
+    /// All modes, in menu order.
+    pub const ALL: [TailMode; 2] = [TailMode::Fade, TailMode::RingOut];
+
+    /// Display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            TailMode::Fade => "Fade",
+            TailMode::RingOut => "Ring out",
+        }
+    }
+
+    /// Parses a label (case-insensitive) back into a mode.
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.label().eq_ignore_ascii_case(label))
+    }
+}
+
+/// Crossfade settings for replacing a running engine.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// Crossfade length in milliseconds, clamped to [`MAX_TRANSITION_MS`].
+    pub time_ms: f32,
+    /// Treatment of the outgoing engine's tails.
+    pub tails: TailMode,
+}
+
+impl Transition {
+    /// A crossfade of `time_ms` with the given tail treatment.
+    pub fn new(time_ms: f32, tails: TailMode) -> Self {
+        Self {
+            time_ms: time_ms.clamp(0.0, MAX_TRANSITION_MS),
+            tails,
+        }
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRANSITION_MS, TailMode::Fade)
+    }
+}
+
+/// An engine being crossfaded out, owned by the engine replacing it.
+pub(crate) struct Outgoing {
+    engine: Box<GraphEngine>,
+    /// Crossfade position: `1.0` all outgoing, `0.0` all incoming.
+    fade: LinearSmoothedParam,
+    /// Outgoing output gain during a ring-out, spanning crossfade and tail.
+    tail: Option<LinearSmoothedParam>,
+    left: Vec<f32>,
+    right: Vec<f32>,
+}
+
+impl Outgoing {
+    pub(crate) fn new(engine: Box<GraphEngine>, transition: Transition, block_size: usize) -> Self {
+        let sample_rate = engine.sample_rate();
+        let mut fade = LinearSmoothedParam::with_config(1.0, sample_rate, transition.time_ms);
+        fade.set_target(0.0);
+        let tail = match transition.tails {
+            TailMode::Fade => None,
+            TailMode::RingOut => {
+                let tail_samples = (0..engine.slot_count())
+                    .filter_map(|slot| engine.effect_at(slot))
+                    .map(|effect| effect.tail_samples())
+                    .max()
+                    .unwrap_or(0);
+                let ring_ms =
+                    (tail_samples as f32 / sample_rate * 1000.0).min(MAX_RING_OUT_SECS * 1000.0);
+                let mut tail = LinearSmoothedParam::with_config(
+                    1.0,
+                    sample_rate,
+                    transition.time_ms + ring_ms,
+                );
+                tail.set_target(0.0);
+                Some(tail)
+            }
+        };
+        Self {
+            engine,
+            fade,
+            tail,
+            left: vec![0.0; block_size],
+            right: vec![0.0; block_size],
+        }
+    }
+
+    /// Fades the incoming engine's block in `left_out`/`right_out` and mixes
+    /// the outgoing engine's rendering of the same input on top. Returns
+    /// `false` once the outgoing engine is silent and can be dropped.
+    pub(crate) fn mix_into(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) -> bool {
+        let len = left_in.len();
+        if self.left.len() < len {
+            self.left.resize(len, 0.0);
+            self.right.resize(len, 0.0);
+        }
+        let (left, right) = (&mut self.left[..len], &mut self.right[..len]);
+
+        // Outgoing input: faded for a ring-out, untouched otherwise
+        if self.tail.is_some() {
+            let mut fade = self.fade.clone();
+            for i in 0..len {
+                let gain = fade.advance();
+                left[i] = left_in[i] * gain;
+                right[i] = right_in[i] * gain;
+            }
+        } else {
+            left.copy_from_slice(&left_in[..len]);
+            right.copy_from_slice(&right_in[..len]);
+        }
+        self.engine.process_block_stereo_inplace(left, right);
+
+        for i in 0..len {
+            let fade = self.fade.advance();
+            let out_gain = match &mut self.tail {
+                Some(tail) => tail.advance(),
+                None => fade,
+            };
+            left_out[i] = left_out[i] * (1.0 - fade) + left[i] * out_gain;
+            right_out[i] = right_out[i] * (1.0 - fade) + right[i] * out_gain;
+        }
+
+        match &self.tail {
+            Some(tail) => !tail.is_settled(),
+            None => !self.fade.is_settled(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tail_mode_label_roundtrip() {
+        for mode in TailMode::ALL {
+            assert_eq!(TailMode::from_label(mode.label()), Some(mode));
+        }
+        assert_eq!(TailMode::from_label("ring OUT"), Some(TailMode::RingOut));
+        assert_eq!(TailMode::from_label("cut"), None);
+    }
+
+    #[test]
+    fn transition_time_is_clamped() {
+        assert_eq!(Transition::new(-5.0, TailMode::Fade).time_ms, 0.0);
+        assert_eq!(
+            Transition::new(1e6, TailMode::Fade).time_ms,
+            MAX_TRANSITION_MS
+        );
+    }
+}
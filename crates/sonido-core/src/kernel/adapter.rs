@@ -19,6 +19,12 @@
 //! 3. Pass snapshot to `kernel.process_stereo()`
 //! 4. Track input/output peaks for observability
 //!
+//! Once every smoother has settled, `process_block*` skips the per-sample
+//! loop and hands the whole block to the kernel's own
+//! [`process_block_stereo`](DspKernel::process_block_stereo) with the
+//! now-constant snapshot, so kernels with a vectorised block path use it
+//! whenever no parameter is moving.
+//!
 //! # Thread Safety
 //!
 //! `Adapter<K, S>` implements `Send` because both `DspKernel: Send` and
@@ -420,6 +426,72 @@ impl<K: DspKernel, S: SmoothingPolicy> Adapter<K, S> {
     fn advance_params(&mut self) {
         self.policy.advance_all::<K::Params>(&mut self.snapshot);
     }
+
+    /// Fold a block of input and output samples into the peak accumulators.
+    #[inline]
+    fn track_block_peaks(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &[f32],
+        right_out: &[f32],
+    ) {
+        let peak = |acc: f32, buf: &[f32]| buf.iter().fold(acc, |p, x| p.max(x.abs()));
+        self.peak_in = (
+            peak(self.peak_in.0, left_in),
+            peak(self.peak_in.1, right_in),
+        );
+        self.peak_out = (
+            peak(self.peak_out.0, left_out),
+            peak(self.peak_out.1, right_out),
+        );
+    }
+
+    /// Process one stereo frame through
+    /// [`DspKernel::process_stereo_with_sidechain`], tracking peaks like
+    /// [`Effect::process_stereo`].
+    #[inline]
+    fn process_stereo_sidechain(
+        &mut self,
+        left: f32,
+        right: f32,
+        sc_left: f32,
+        sc_right: f32,
+    ) -> (f32, f32) {
+        if S::tracks_peaks() {
+            let abs_l = left.abs();
+            let abs_r = right.abs();
+            if abs_l > self.peak_in.0 {
+                self.peak_in.0 = abs_l;
+            }
+            if abs_r > self.peak_in.1 {
+                self.peak_in.1 = abs_r;
+            }
+        }
+
+        self.advance_params();
+        let (l, r) = self.kernel.process_stereo_with_sidechain(
+            left,
+            right,
+            sc_left,
+            sc_right,
+            &self.snapshot,
+        );
+        self.kernel.update_diagnostics(&mut self.snapshot);
+
+        if S::tracks_peaks() {
+            let abs_l_out = l.abs();
+            let abs_r_out = r.abs();
+            if abs_l_out > self.peak_out.0 {
+                self.peak_out.0 = abs_l_out;
+            }
+            if abs_r_out > self.peak_out.1 {
+                self.peak_out.1 = abs_r_out;
+            }
+        }
+
+        (l, r)
+    }
 }
 
 // ── Effect impl ──────────────────────────────────────────────────────────────
@@ -489,6 +561,14 @@ impl<K: DspKernel, S: SmoothingPolicy> Effect for Adapter<K, S> {
 
     fn process_block(&mut self, input: &[f32], output: &mut [f32]) {
         debug_assert_eq!(input.len(), output.len());
+        if self.policy.is_settled() {
+            self.kernel.process_block(input, output, &self.snapshot);
+            self.kernel.update_diagnostics(&mut self.snapshot);
+            if S::tracks_peaks() {
+                self.track_block_peaks(input, input, output, output);
+            }
+            return;
+        }
         for (inp, out) in input.iter().zip(output.iter_mut()) {
             if S::tracks_peaks() {
                 let abs_in = inp.abs();
@@ -527,6 +607,22 @@ impl<K: DspKernel, S: SmoothingPolicy> Effect for Adapter<K, S> {
         debug_assert_eq!(left_in.len(), left_out.len());
         debug_assert_eq!(left_out.len(), right_out.len());
 
+        // Nothing is smoothing, so the snapshot is constant for the block.
+        if self.policy.is_settled() {
+            self.kernel.process_block_stereo(
+                left_in,
+                right_in,
+                left_out,
+                right_out,
+                &self.snapshot,
+            );
+            self.kernel.update_diagnostics(&mut self.snapshot);
+            if S::tracks_peaks() {
+                self.track_block_peaks(left_in, right_in, left_out, right_out);
+            }
+            return;
+        }
+
         for i in 0..left_in.len() {
             if S::tracks_peaks() {
                 let abs_l = left_in[i].abs();
@@ -560,7 +656,59 @@ impl<K: DspKernel, S: SmoothingPolicy> Effect for Adapter<K, S> {
         }
     }
 
+    /// Routes the sidechain to [`DspKernel::process_stereo_with_sidechain`];
+    /// kernels without sidechain support fall back to `process_stereo`.
+    fn process_block_stereo_with_sidechain(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+        sc_left: &[f32],
+        sc_right: &[f32],
+    ) {
+        debug_assert_eq!(left_in.len(), right_in.len());
+        debug_assert_eq!(left_in.len(), left_out.len());
+        debug_assert_eq!(left_out.len(), right_out.len());
+        debug_assert_eq!(left_in.len(), sc_left.len());
+        debug_assert_eq!(sc_left.len(), sc_right.len());
+
+        for i in 0..left_in.len() {
+            let (l, r) =
+                self.process_stereo_sidechain(left_in[i], right_in[i], sc_left[i], sc_right[i]);
+            left_out[i] = l;
+            right_out[i] = r;
+        }
+    }
+
+    fn process_block_stereo_inplace_with_sidechain(
+        &mut self,
+        left: &mut [f32],
+        right: &mut [f32],
+        sc_left: &[f32],
+        sc_right: &[f32],
+    ) {
+        debug_assert_eq!(left.len(), right.len());
+        debug_assert_eq!(left.len(), sc_left.len());
+        debug_assert_eq!(sc_left.len(), sc_right.len());
+
+        for i in 0..left.len() {
+            let (l, r) = self.process_stereo_sidechain(left[i], right[i], sc_left[i], sc_right[i]);
+            left[i] = l;
+            right[i] = r;
+        }
+    }
+
+    /// Forwards a sample rate change to the kernel and smoothers.
+    ///
+    /// Repeating the current rate is a no-op. Kernels rebuild delay lines,
+    /// clear state, and invalidate coefficient caches in
+    /// [`DspKernel::set_sample_rate`], so hosts that re-send the rate every
+    /// block (or on every transport start) must not reach it.
     fn set_sample_rate(&mut self, sample_rate: f32) {
+        if sample_rate == self.sample_rate {
+            return;
+        }
         self.sample_rate = sample_rate;
         self.kernel.set_sample_rate(sample_rate);
         self.policy.set_sample_rate::<K::Params>(sample_rate);
@@ -615,7 +763,7 @@ impl<K: DspKernel, S: SmoothingPolicy> ParameterInfo for Adapter<K, S> {
     }
 
     fn param_info(&self, index: usize) -> Option<ParamDescriptor> {
-        K::Params::descriptor(index)
+        K::Params::descriptor(index).map(|d| d.with_smoothing(K::Params::smoothing(index)))
     }
 
     fn get_param(&self, index: usize) -> f32 {
@@ -761,6 +909,7 @@ mod tests {
         assert_eq!(desc.name, "Gain");
         assert_eq!(desc.min, -60.0);
         assert_eq!(desc.max, 12.0);
+        assert_eq!(desc.smoothing, TestGainParams::smoothing(0));
     }
 
     #[test]
@@ -846,6 +995,37 @@ mod tests {
         assert!(!output.is_nan());
     }
 
+    #[test]
+    fn adapter_skips_unchanged_sample_rate() {
+        struct RateKernel {
+            changes: usize,
+        }
+
+        impl DspKernel for RateKernel {
+            type Params = TestGainParams;
+
+            fn process(&mut self, input: f32, _params: &TestGainParams) -> f32 {
+                input
+            }
+
+            fn reset(&mut self) {}
+            fn set_sample_rate(&mut self, _: f32) {
+                self.changes += 1;
+            }
+        }
+
+        let mut adapter = Adapter::new(RateKernel { changes: 0 }, 48000.0);
+        for _ in 0..1000 {
+            adapter.set_sample_rate(48000.0);
+        }
+        assert_eq!(adapter.kernel().changes, 0);
+
+        adapter.set_sample_rate(96000.0);
+        adapter.set_sample_rate(96000.0);
+        adapter.set_sample_rate(48000.0);
+        assert_eq!(adapter.kernel().changes, 2);
+    }
+
     #[test]
     fn adapter_load_snapshot_sets_targets() {
         let mut adapter = Adapter::<TestGainKernel, SmoothedPolicy>::new(TestGainKernel, 48000.0);
@@ -393,7 +393,8 @@ pub trait DspKernel: Send {
     /// Process a block of mono samples with a single parameter snapshot.
     ///
     /// Default: calls `process()` per sample. Override for vectorized
-    /// processing on embedded targets.
+    /// processing; like [`process_block_stereo`](Self::process_block_stereo),
+    /// [`Adapter`](super::Adapter) uses it once its smoothers have settled.
     fn process_block(&mut self, input: &[f32], output: &mut [f32], params: &Self::Params) {
         debug_assert_eq!(input.len(), output.len());
         for i in 0..input.len() {
@@ -406,10 +407,10 @@ pub trait DspKernel: Send {
     /// Default: calls `process_stereo()` per sample. Override for vectorized
     /// processing or per-block coefficient updates on embedded targets.
     ///
-    /// The parameter snapshot is constant for the entire block. On desktop,
-    /// [`Adapter`](super::Adapter) calls per-sample methods instead (advancing
-    /// smoothers each sample). This block method is primarily for embedded use
-    /// where params are stable across a block.
+    /// The parameter snapshot is constant for the entire block.
+    /// [`Adapter`](super::Adapter) calls this whenever its smoothers have
+    /// settled, and falls back to per-sample `process_stereo()` while a
+    /// parameter is still moving.
     fn process_block_stereo(
         &mut self,
         left_in: &[f32],
@@ -435,7 +436,11 @@ pub trait DspKernel: Send {
 
     /// Update internal state for a new sample rate.
     ///
-    /// Recalculate filter coefficients, delay line sizes, etc.
+    /// Recalculate filter coefficients, delay line sizes, etc. The
+    /// [`Adapter`](super::Adapter) only calls this when the rate actually
+    /// changes, so it may allocate and clear state; coefficients that depend
+    /// on parameters as well should be invalidated here and recomputed lazily
+    /// through [`Cached`](crate::Cached).
     fn set_sample_rate(&mut self, sample_rate: f32);
 
     /// Report processing latency in samples.
@@ -2,10 +2,17 @@
 //!
 //! Provides smooth, periodic modulation signals used in chorus, flanger,
 //! tremolo, vibrato, and other time-based effects.
+//!
+//! Beyond free-running rates, an [`Lfo`] can follow the host tempo: with a
+//! [`NoteDivision`] set via [`Lfo::set_tempo_sync`], each
+//! [`Lfo::set_tempo_context`] call sets the rate from the BPM and, while the
+//! transport plays, locks the phase to the beat position so the cycle lands
+//! on the grid. A start phase, retrigger on transport start, and
+//! unipolar/bipolar output round out the controls a mod matrix needs.
 
 use crate::fast_math::fast_sin_turns;
 
-use crate::tempo::NoteDivision;
+use crate::tempo::{NoteDivision, TempoContext};
 
 /// LFO waveform type
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -22,6 +29,20 @@ pub enum LfoWaveform {
     Square,
     /// Sample-and-hold — random stepped values at the LFO rate.
     SampleAndHold,
+    /// Smoothed random — a new random target each cycle, reached along a
+    /// smoothstep curve so the output wanders without steps.
+    SmoothRandom,
+}
+
+/// Output range of an [`Lfo`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LfoPolarity {
+    /// Output in \[-1.0, 1.0\].
+    #[default]
+    Bipolar,
+    /// Output in \[0.0, 1.0\].
+    Unipolar,
 }
 
 /// Low Frequency Oscillator for generating modulation signals.
@@ -36,6 +57,7 @@ pub enum LfoWaveform {
 /// - **Saw**: Rising ramp, abrupt reset
 /// - **Square**: Binary on/off modulation
 /// - **SampleAndHold**: Random stepped values
+/// - **SmoothRandom**: Random values joined by smooth curves
 ///
 /// # Example
 ///
@@ -64,6 +86,18 @@ pub struct Lfo {
     prev_phase: f32,
     /// LCG pseudo-random state for Sample & Hold waveform.
     rng_state: u32,
+    /// SmoothRandom: value the current cycle starts from (`sh_value` is the target).
+    smooth_from: f32,
+    /// Phase that `reset()` and `retrigger()` return to.
+    start_phase: f32,
+    /// Output range.
+    polarity: LfoPolarity,
+    /// Note division the rate follows, if tempo-synced.
+    sync: Option<NoteDivision>,
+    /// Restart at `start_phase` when the transport starts.
+    retrigger_on_play: bool,
+    /// Transport state seen by the last `set_tempo_context()`.
+    was_playing: bool,
 }
 
 impl Default for Lfo {
@@ -83,6 +117,12 @@ impl Lfo {
             sh_value: 0.0,
             prev_phase: 0.0,
             rng_state: 0x1234_5678,
+            smooth_from: 0.0,
+            start_phase: 0.0,
+            polarity: LfoPolarity::Bipolar,
+            sync: None,
+            retrigger_on_play: false,
+            was_playing: false,
         }
     }
 
@@ -106,11 +146,91 @@ impl Lfo {
         self.waveform
     }
 
-    /// Reset phase to 0
+    /// Reset phase to the start phase and restart the random sequence.
     pub fn reset(&mut self) {
-        self.phase = 0.0;
-        self.prev_phase = 0.0;
+        self.phase = self.start_phase;
+        self.prev_phase = self.start_phase;
         self.rng_state = 0x1234_5678;
+        self.sh_value = 0.0;
+        self.smooth_from = 0.0;
+    }
+
+    /// Jump to the start phase, keeping the random sequence running.
+    pub fn retrigger(&mut self) {
+        self.phase = self.start_phase;
+        self.prev_phase = self.start_phase;
+    }
+
+    /// Set the phase `reset()` and `retrigger()` return to (0.0 - 1.0).
+    ///
+    /// Also offsets the beat-locked phase when tempo-synced.
+    pub fn set_start_phase(&mut self, phase: f32) {
+        self.start_phase = phase.clamp(0.0, 1.0) % 1.0;
+    }
+
+    /// Get the start phase (0.0 - 1.0)
+    pub fn start_phase(&self) -> f32 {
+        self.start_phase
+    }
+
+    /// Set output polarity.
+    pub fn set_polarity(&mut self, polarity: LfoPolarity) {
+        self.polarity = polarity;
+    }
+
+    /// Get output polarity.
+    pub fn polarity(&self) -> LfoPolarity {
+        self.polarity
+    }
+
+    /// Follow the host tempo at `division`, or run free with `None`.
+    ///
+    /// Takes effect at the next [`set_tempo_context`](Self::set_tempo_context).
+    pub fn set_tempo_sync(&mut self, division: Option<NoteDivision>) {
+        self.sync = division;
+    }
+
+    /// Get the synced note division, if any.
+    pub fn tempo_sync(&self) -> Option<NoteDivision> {
+        self.sync
+    }
+
+    /// Restart at the start phase whenever the transport starts playing.
+    pub fn set_retrigger_on_play(&mut self, enabled: bool) {
+        self.retrigger_on_play = enabled;
+    }
+
+    /// Whether the LFO restarts when the transport starts.
+    pub fn retrigger_on_play(&self) -> bool {
+        self.retrigger_on_play
+    }
+
+    /// Apply a host tempo snapshot, typically once per block.
+    ///
+    /// - Retriggers on the stopped → playing edge if enabled.
+    /// - When tempo-synced, sets the rate from `ctx.bpm` and, while playing,
+    ///   sets the phase to `beat_position / division.beats() + start_phase`.
+    pub fn set_tempo_context(&mut self, ctx: &TempoContext) {
+        if ctx.is_playing && !self.was_playing && self.retrigger_on_play {
+            self.retrigger();
+        }
+        self.was_playing = ctx.is_playing;
+
+        if let Some(division) = self.sync {
+            self.sync_to_tempo(ctx.bpm, division);
+            if ctx.is_playing {
+                let cycles = ctx.beat_position / division.beats() + self.start_phase;
+                let locked = cycles - libm::floorf(cycles);
+                // Let the random waveforms see a real wrap, but not a small
+                // backward drift correction.
+                self.prev_phase = if locked < self.phase - 0.5 {
+                    self.phase
+                } else {
+                    locked
+                };
+                self.phase = locked;
+            }
+        }
     }
 
     /// Sync phase to a specific value (0.0 - 1.0)
@@ -129,13 +249,24 @@ impl Lfo {
 
     /// Compute the waveform value at the current phase without advancing.
     ///
-    /// Returns a bipolar value in \[-1.0, 1.0\] based on the current phase
-    /// and waveform. For `SampleAndHold`, returns the currently held value.
+    /// Returns a value in the range set by the polarity (bipolar
+    /// \[-1.0, 1.0\] by default) based on the current phase and waveform.
+    /// For `SampleAndHold`, returns the currently held value.
     ///
     /// This is useful for reading the LFO state without side effects
     /// (e.g., for `ModulationSource::mod_value()`).
     #[inline]
     pub fn value_at_phase(&self) -> f32 {
+        let value = self.bipolar_at_phase();
+        match self.polarity {
+            LfoPolarity::Bipolar => value,
+            LfoPolarity::Unipolar => (value + 1.0) * 0.5,
+        }
+    }
+
+    /// Waveform value in \[-1.0, 1.0\] at the current phase.
+    #[inline]
+    fn bipolar_at_phase(&self) -> f32 {
         match self.waveform {
             LfoWaveform::Sine => fast_sin_turns(self.phase),
 
@@ -158,23 +289,44 @@ impl Lfo {
             }
 
             LfoWaveform::SampleAndHold => self.sh_value,
+
+            LfoWaveform::SmoothRandom => {
+                let t = self.phase;
+                let eased = t * t * (3.0 - 2.0 * t);
+                self.smooth_from + (self.sh_value - self.smooth_from) * eased
+            }
         }
     }
 
-    /// Get next LFO value (-1.0 to 1.0)
+    /// Get next LFO value (-1.0 to 1.0, or 0.0 to 1.0 when unipolar)
     #[inline]
     pub fn advance(&mut self) -> f32 {
-        // S&H: generate new random value on phase wrap (before reading)
-        if self.waveform == LfoWaveform::SampleAndHold && self.phase < self.prev_phase {
+        let output = self.step();
+        match self.polarity {
+            LfoPolarity::Bipolar => output,
+            LfoPolarity::Unipolar => (output + 1.0) * 0.5,
+        }
+    }
+
+    /// Advance one sample, returning the bipolar value.
+    #[inline]
+    fn step(&mut self) -> f32 {
+        // Random waveforms: pick a new value on phase wrap (before reading)
+        if matches!(
+            self.waveform,
+            LfoWaveform::SampleAndHold | LfoWaveform::SmoothRandom
+        ) && self.phase < self.prev_phase
+        {
             // LCG PRNG (Numerical Recipes constants) — ~3 cycles vs ~100 for sinf
             self.rng_state = self
                 .rng_state
                 .wrapping_mul(1_664_525)
                 .wrapping_add(1_013_904_223);
+            self.smooth_from = self.sh_value;
             self.sh_value = (self.rng_state as f32) / (u32::MAX as f32) * 2.0 - 1.0;
         }
 
-        let output = self.value_at_phase();
+        let output = self.bipolar_at_phase();
 
         self.prev_phase = self.phase;
         self.phase += self.phase_inc;
@@ -186,8 +338,10 @@ impl Lfo {
     }
 
     /// Get next value scaled to range (0.0 to 1.0 for unipolar)
+    ///
+    /// Always unipolar, whatever the polarity setting.
     pub fn advance_unipolar(&mut self) -> f32 {
-        (self.advance() + 1.0) * 0.5
+        (self.step() + 1.0) * 0.5
     }
 
     /// Set sample rate
@@ -334,4 +488,124 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_start_phase_and_retrigger() {
+        let mut lfo = Lfo::new(48000.0, 2.0);
+        lfo.set_start_phase(0.25);
+        lfo.reset();
+        assert_eq!(lfo.phase(), 0.25);
+        // Sine at 90° is the peak.
+        assert!((lfo.advance() - 1.0).abs() < 1e-3);
+
+        for _ in 0..1000 {
+            lfo.advance();
+        }
+        lfo.retrigger();
+        assert_eq!(lfo.phase(), 0.25);
+
+        lfo.set_start_phase(1.0);
+        assert_eq!(lfo.start_phase(), 0.0);
+    }
+
+    #[test]
+    fn test_polarity() {
+        use crate::ModulationSource;
+
+        let mut lfo = Lfo::new(48000.0, 50.0);
+        assert!(lfo.is_bipolar());
+        lfo.set_polarity(LfoPolarity::Unipolar);
+        assert!(!lfo.is_bipolar());
+
+        let (mut lo, mut hi) = (f32::MAX, f32::MIN);
+        for _ in 0..2000 {
+            let value = lfo.advance();
+            lo = lo.min(value);
+            hi = hi.max(value);
+        }
+        assert!(lo >= 0.0 && lo < 0.01, "min {lo}");
+        assert!(hi <= 1.0 && hi > 0.99, "max {hi}");
+
+        // advance_unipolar() does not map twice.
+        lfo.reset();
+        let mut reference = Lfo::new(48000.0, 50.0);
+        for _ in 0..100 {
+            assert!((lfo.advance_unipolar() - reference.advance_unipolar()).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_tempo_sync_locks_to_beat() {
+        let mut lfo = Lfo::new(48000.0, 1.0);
+        lfo.set_tempo_sync(Some(NoteDivision::Half));
+        let mut ctx = TempoContext {
+            bpm: 120.0,
+            beat_position: 3.0,
+            ..TempoContext::default()
+        };
+
+        // Stopped: rate follows the tempo, phase runs free.
+        lfo.set_tempo_context(&ctx);
+        assert!((lfo.frequency() - 1.0).abs() < 1e-4);
+        assert_eq!(lfo.phase(), 0.0);
+
+        // Playing: a half note is two beats, so beat 3 is halfway through a cycle.
+        ctx.is_playing = true;
+        lfo.set_tempo_context(&ctx);
+        assert!((lfo.phase() - 0.5).abs() < 1e-6);
+
+        lfo.set_start_phase(0.25);
+        lfo.set_tempo_context(&ctx);
+        assert!((lfo.phase() - 0.75).abs() < 1e-6);
+
+        lfo.set_tempo_sync(None);
+        ctx.bpm = 60.0;
+        lfo.set_tempo_context(&ctx);
+        assert!(
+            (lfo.frequency() - 1.0).abs() < 1e-4,
+            "free-running rate kept"
+        );
+    }
+
+    #[test]
+    fn test_retrigger_on_transport_start() {
+        let mut lfo = Lfo::new(48000.0, 3.0);
+        lfo.set_start_phase(0.5);
+        lfo.set_retrigger_on_play(true);
+        for _ in 0..777 {
+            lfo.advance();
+        }
+
+        let mut ctx = TempoContext::default();
+        lfo.set_tempo_context(&ctx);
+        assert_ne!(lfo.phase(), 0.5, "no retrigger while stopped");
+
+        ctx.is_playing = true;
+        lfo.set_tempo_context(&ctx);
+        assert_eq!(lfo.phase(), 0.5, "retrigger on play");
+
+        lfo.advance();
+        lfo.set_tempo_context(&ctx);
+        assert_ne!(lfo.phase(), 0.5, "only on the rising edge");
+    }
+
+    #[test]
+    fn test_smooth_random_is_continuous() {
+        let mut lfo = Lfo::new(48000.0, 10.0);
+        lfo.set_waveform(LfoWaveform::SmoothRandom);
+
+        let mut prev = lfo.advance();
+        let (mut lo, mut hi) = (prev, prev);
+        for _ in 0..48000 {
+            let value = lfo.advance();
+            // Largest slope of a smoothstep across a ±1 jump is 1.5 per
+            // cycle, i.e. 3 × 10 Hz / 48 kHz per sample.
+            assert!((value - prev).abs() < 1e-3, "step {prev} -> {value}");
+            assert!((-1.0..=1.0).contains(&value));
+            lo = lo.min(value);
+            hi = hi.max(value);
+            prev = value;
+        }
+        assert!(hi - lo > 0.5, "random walk should move: {lo}..{hi}");
+    }
 }
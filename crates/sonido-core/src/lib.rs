@@ -26,6 +26,10 @@
 //! - [`ModulatedComb`] - Comb filter with LFO-modulated delay for FDN reverbs
 //! - [`AllpassFilter`] - Schroeder allpass for diffusion
 //! - [`ModulatedAllpass`] - Allpass filter with LFO-modulated delay for FDN reverbs
+//! - [`LinkwitzRiley`] - LR2/LR4/LR8 two-way crossover with matching [`LinkwitzRileyAllpass`]
+//! - [`BandSplitter`] - N-band phase-aligned crossover with flat summed magnitude
+//! - [`Biquad4`] / [`Svf4`] / [`OnePole4`] - Four filters in the lanes of an [`F32x4`]
+//!   (explicit SSE2/NEON with the `simd` feature)
 //!
 //! ## Delay Lines
 //!
@@ -34,7 +38,7 @@
 //!
 //! ## Modulation & Dynamics
 //!
-//! - [`Lfo`] - Low-frequency oscillator (5 waveforms)
+//! - [`Lfo`] - Low-frequency oscillator (6 waveforms, tempo sync, unipolar/bipolar)
 //! - [`EnvelopeFollower`] - Amplitude envelope detection
 //!
 //! ## Anti-Aliasing
@@ -104,6 +108,7 @@ pub mod biquad;
 pub mod cached;
 pub mod comb;
 pub mod compose;
+pub mod crossover;
 pub mod dc_blocker;
 pub mod delay;
 pub mod dsp;
@@ -121,9 +126,14 @@ pub mod math;
 pub mod modulation;
 pub mod one_pole;
 pub mod oversample;
+pub mod panic;
 pub mod param;
 pub mod param_info;
+pub mod param_map;
 pub mod plugin_host;
+pub mod safe_mode;
+pub mod safety_limiter;
+pub mod simd;
 pub mod svf;
 pub mod tempo;
 
@@ -131,15 +141,16 @@ pub mod tempo;
 pub use adaa::Adaa1;
 pub use allpass::{AllpassFilter, ModulatedAllpass};
 pub use biquad::{
-    Biquad, bandpass_coefficients, highpass_coefficients, lowpass_coefficients, notch_coefficients,
-    peaking_eq_coefficients,
+    Biquad, Biquad4, allpass_coefficients, bandpass_coefficients, highpass_coefficients,
+    lowpass_coefficients, notch_coefficients, peaking_eq_coefficients,
 };
 pub use cached::Cached;
 pub use comb::{CombFilter, ModulatedComb};
 pub use compose::{EffectNode, GraphBuilder, feedback, par, seq};
+pub use crossover::{BandSplitter, CrossoverSlope, LinkwitzRiley, LinkwitzRileyAllpass};
 pub use dc_blocker::DcBlocker;
 pub use delay::{FixedDelayLine, InterpolatedDelay, Interpolation};
-pub use dsp::{GainStage, ToneStack};
+pub use dsp::{GainStage, KeyFilter, ToneStack};
 pub use effect::{Chain, Effect, EffectExt, TailReporting};
 pub use effect_with_params::EffectWithParams;
 pub use envelope::{DetectionMode, EnvelopeFollower};
@@ -149,15 +160,16 @@ pub use fast_math::{
 #[cfg(feature = "spectral")]
 pub use graph::spectral_node::{SpectralConfig, SpectralEffect, SpectralNode, WindowType};
 pub use graph::{
-    BufferPool, CompensationDelay, CompiledSchedule, EdgeId, GraphEngine, GraphError,
-    GraphSnapshot, NodeId, NodeKind, NodeRate, ProcessStep, ProcessingGraph, SnapshotEntry,
-    SnapshotTopology, StereoBuffer, StereoSamples, TopoNode,
+    AuxSend, BufferPool, BypassMode, CompensationDelay, CompiledSchedule, EdgeId, GraphEngine,
+    GraphError, GraphSnapshot, LatencyUpdate, NodeId, NodeKind, NodeRate, PdcMode, ProcessStep,
+    ProcessingGraph, SendTap, SnapshotEntry, SnapshotTopology, SrcFeed, SrcNode, StereoBuffer,
+    StereoSamples, TailLength, TopoNode,
 };
 pub use kernel::{
     Adapter, DirectPolicy, DspKernel, KernelParams, MorphCurve, MorphSpace, SmoothedPolicy,
     SmoothingPolicy, SmoothingStyle,
 };
-pub use lfo::{Lfo, LfoWaveform};
+pub use lfo::{Lfo, LfoPolarity, LfoWaveform};
 pub use loop_buffer::LoopBuffer;
 pub use macro_map::{MacroMap, MacroMapping};
 pub use math::{
@@ -166,11 +178,15 @@ pub use math::{
     tape_sat_ad, tape_sat_neg_ad, tape_sat_pos_ad, wet_dry_mix, wet_dry_mix_stereo,
 };
 pub use modulation::{ModulationAmount, ModulationSource};
-pub use one_pole::OnePole;
+pub use one_pole::{OnePole, OnePole4};
 pub use oversample::{MAX_OVERSAMPLE_FACTOR, Oversampled};
+pub use panic::{PANIC_FADE_MS, PanicFade};
 pub use param::{LinearSmoothedParam, SmoothedParam};
 pub use param_info::{ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit, ParameterInfo};
-pub use svf::{FourPoleSvf, StateVariableFilter, SvfOutput};
+pub use safe_mode::{SAFE_MODE_FADE_MS, SafeMode};
+pub use safety_limiter::SafetyLimiter;
+pub use simd::F32x4;
+pub use svf::{FourPoleSvf, StateVariableFilter, Svf4, SvfOutput, SvfSlope};
 pub use tempo::{
     DIVISION_LABELS, NoteDivision, TempoContext, TempoManager, TransportState, division_to_index,
     index_to_division,
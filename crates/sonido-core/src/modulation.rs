@@ -8,7 +8,7 @@
 //! `EnvelopeFollower` requires input via `process()` and does not
 //! implement this trait.
 
-use crate::Lfo;
+use crate::{Lfo, LfoPolarity};
 
 /// Trait for anything that can generate modulation signals.
 ///
@@ -83,7 +83,7 @@ impl ModulationSource for Lfo {
     }
 
     fn is_bipolar(&self) -> bool {
-        true
+        self.polarity() == LfoPolarity::Bipolar
     }
 
     fn mod_reset(&mut self) {
@@ -29,6 +29,7 @@
 //! Section: One-Pole Filter.
 
 use crate::flush_denormal;
+use crate::simd::F32x4;
 use libm::expf;
 
 /// One-pole (6 dB/oct) lowpass filter.
@@ -107,6 +108,85 @@ impl OnePole {
     }
 }
 
+/// Four [`OnePole`] lowpass filters processed in parallel.
+///
+/// Each lane of an [`F32x4`] has its own cutoff and state; the sample rate is
+/// shared. Use it where several signals are damped side by side, such as the
+/// lines of a feedback delay network.
+///
+/// Each lane is bit-identical to a scalar [`OnePole`] at the same cutoff, on
+/// every [`simd`](crate::simd) backend.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::{F32x4, OnePole4};
+///
+/// let mut bank = OnePole4::new(48000.0, 4000.0);
+/// bank.set_frequency(3, 500.0);
+/// let out = bank.process(F32x4::splat(1.0));
+/// assert!(out.lane(3) < out.lane(0)); // lower cutoff, slower rise
+/// ```
+#[derive(Debug, Clone)]
+pub struct OnePole4 {
+    state: F32x4,
+    coeff: F32x4,
+    sample_rate: f32,
+    freq: [f32; 4],
+}
+
+impl OnePole4 {
+    /// Create four one-pole lowpass filters, all at `freq_hz`.
+    pub fn new(sample_rate: f32, freq_hz: f32) -> Self {
+        let mut bank = Self {
+            state: F32x4::ZERO,
+            coeff: F32x4::ZERO,
+            sample_rate,
+            freq: [freq_hz; 4],
+        };
+        for lane in 0..4 {
+            bank.recalculate_coeff(lane);
+        }
+        bank
+    }
+
+    /// Set the cutoff frequency of one lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= 4`.
+    pub fn set_frequency(&mut self, lane: usize, freq_hz: f32) {
+        self.freq[lane] = freq_hz;
+        self.recalculate_coeff(lane);
+    }
+
+    /// Process one sample in each lane.
+    #[inline]
+    pub fn process(&mut self, input: F32x4) -> F32x4 {
+        self.state = (input + self.coeff * (self.state - input)).flush_denormal();
+        self.state
+    }
+
+    /// Reset the state of all four lanes to zero.
+    pub fn reset(&mut self) {
+        self.state = F32x4::ZERO;
+    }
+
+    /// Update the sample rate and recalculate every lane's coefficient.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        for lane in 0..4 {
+            self.recalculate_coeff(lane);
+        }
+    }
+
+    /// Same formula as [`OnePole`], so lanes match it exactly.
+    fn recalculate_coeff(&mut self, lane: usize) {
+        let coeff = expf(-core::f32::consts::TAU * self.freq[lane] / self.sample_rate);
+        self.coeff.set_lane(lane, coeff);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +231,27 @@ mod tests {
         let out = lp.process(0.0);
         assert_eq!(out, 0.0);
     }
+
+    #[test]
+    fn one_pole4_lanes_match_scalar() {
+        let freqs = [100.0, 1000.0, 4000.0, 12000.0];
+        let mut bank = OnePole4::new(44100.0, 1000.0);
+        let mut scalars = freqs.map(|hz| OnePole::new(44100.0, hz));
+        for (lane, hz) in freqs.into_iter().enumerate() {
+            bank.set_frequency(lane, hz);
+        }
+
+        for i in 0..500 {
+            let x = libm::sinf(i as f32 * 0.3) + if i % 7 == 0 { 0.5 } else { -0.1 };
+            let out = bank.process(F32x4::new(x, -x, 0.5 * x, x * x)).to_array();
+            let want = [x, -x, 0.5 * x, x * x];
+            for lane in 0..4 {
+                assert_eq!(
+                    out[lane],
+                    scalars[lane].process(want[lane]),
+                    "lane {lane}, sample {i}"
+                );
+            }
+        }
+    }
 }
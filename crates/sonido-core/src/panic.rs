@@ -0,0 +1,187 @@
+//! Click-free "panic" reset for runaway feedback and self-oscillation.
+//!
+//! A panic fades the output to silence, resets every effect's state (delay
+//! lines, reverb tanks, filter memories, LFO phases) while silent, then fades
+//! back in. [`PanicFade`] is the fade/reset state machine; the owner of the
+//! effects drives it once per block:
+//!
+//! ```rust
+//! use sonido_core::panic::PanicFade;
+//!
+//! let mut panic = PanicFade::new(48000.0);
+//! let (mut left, mut right) = ([0.5_f32; 256], [0.5_f32; 256]);
+//!
+//! panic.trigger();
+//! for _ in 0..4 {
+//!     if panic.take_reset() {
+//!         // Reset effect state here, before processing the block.
+//!     }
+//!     // ... process the block into left/right ...
+//!     panic.apply(&mut left, &mut right);
+//! }
+//! ```
+//!
+//! The fade-out completes within [`PANIC_FADE_MS`]; the reset happens at the
+//! start of the next block, which is rendered from silence.
+
+/// Length of each panic fade ramp (out, then back in) in milliseconds.
+pub const PANIC_FADE_MS: f32 = 5.0;
+
+/// Panic progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    /// Not panicking; [`PanicFade::apply`] is a no-op.
+    Idle,
+    /// Ramping the output down to silence.
+    FadingOut,
+    /// Silent; effects are reset at the next block boundary.
+    ResetPending,
+    /// Ramping back up after the reset.
+    FadingIn,
+}
+
+/// Fade-out → reset → fade-in state machine for a panic reset.
+///
+/// Real-time safe: no allocation, a linear gain ramp per sample while active.
+#[derive(Debug, Clone)]
+pub struct PanicFade {
+    stage: Stage,
+    gain: f32,
+    step: f32,
+}
+
+impl PanicFade {
+    /// Create an idle panic fade for `sample_rate`.
+    pub fn new(sample_rate: f32) -> Self {
+        let mut fade = Self {
+            stage: Stage::Idle,
+            gain: 1.0,
+            step: 1.0,
+        };
+        fade.set_sample_rate(sample_rate);
+        fade
+    }
+
+    /// Update the ramp length for a new sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        let samples = (sample_rate * PANIC_FADE_MS / 1000.0).max(1.0);
+        self.step = 1.0 / samples;
+    }
+
+    /// Start a panic. Re-triggering mid-panic fades out again from the
+    /// current gain, so repeated presses never click.
+    pub fn trigger(&mut self) {
+        if self.stage != Stage::ResetPending {
+            self.stage = Stage::FadingOut;
+        }
+    }
+
+    /// Whether a panic is in progress.
+    pub fn is_active(&self) -> bool {
+        self.stage != Stage::Idle
+    }
+
+    /// Call at the start of each block. Returns `true` exactly once per
+    /// panic, when the output is silent and effect state should be reset.
+    pub fn take_reset(&mut self) -> bool {
+        if self.stage == Stage::ResetPending {
+            self.stage = Stage::FadingIn;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Apply the panic gain to a processed stereo block.
+    pub fn apply(&mut self, left: &mut [f32], right: &mut [f32]) {
+        if self.stage == Stage::Idle {
+            return;
+        }
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            match self.stage {
+                Stage::FadingOut => {
+                    self.gain -= self.step;
+                    if self.gain <= 0.0 {
+                        self.gain = 0.0;
+                        self.stage = Stage::ResetPending;
+                    }
+                }
+                Stage::FadingIn => {
+                    self.gain += self.step;
+                    if self.gain >= 1.0 {
+                        self.gain = 1.0;
+                        self.stage = Stage::Idle;
+                    }
+                }
+                Stage::ResetPending | Stage::Idle => {}
+            }
+            *l *= self.gain;
+            *r *= self.gain;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one block of constant 1.0 through the fade; returns (reset, block).
+    fn run_block(fade: &mut PanicFade) -> (bool, [f32; 256]) {
+        let reset = fade.take_reset();
+        let (mut left, mut right) = ([1.0_f32; 256], [1.0_f32; 256]);
+        fade.apply(&mut left, &mut right);
+        assert_eq!(left, right);
+        (reset, left)
+    }
+
+    #[test]
+    fn idle_is_passthrough() {
+        let mut fade = PanicFade::new(48000.0);
+        let (reset, block) = run_block(&mut fade);
+        assert!(!reset);
+        assert!(block.iter().all(|&s| s == 1.0));
+        assert!(!fade.is_active());
+    }
+
+    #[test]
+    fn fades_out_resets_once_and_fades_in() {
+        // 5 ms at 48 kHz = 240 samples, inside one 256-sample block.
+        let mut fade = PanicFade::new(48000.0);
+        fade.trigger();
+
+        let (reset, block) = run_block(&mut fade);
+        assert!(!reset);
+        assert!(block.windows(2).all(|w| w[1] <= w[0]), "monotonic fade-out");
+        assert_eq!(block[255], 0.0);
+
+        let (reset, block) = run_block(&mut fade);
+        assert!(reset, "reset requested after fade-out");
+        assert!(block.windows(2).all(|w| w[1] >= w[0]), "monotonic fade-in");
+        assert_eq!(block[255], 1.0);
+        assert!(!fade.is_active());
+
+        let (reset, _) = run_block(&mut fade);
+        assert!(!reset, "reset requested only once");
+    }
+
+    #[test]
+    fn retrigger_during_fade_in_fades_from_current_gain() {
+        let mut fade = PanicFade::new(48000.0);
+        fade.trigger();
+        run_block(&mut fade);
+        fade.take_reset();
+
+        let (mut left, mut right) = ([1.0_f32; 64], [1.0_f32; 64]);
+        fade.apply(&mut left, &mut right);
+        let gain = left[63];
+        assert!(gain > 0.0 && gain < 1.0);
+
+        fade.trigger();
+        let (mut left, mut right) = ([1.0_f32; 4], [1.0_f32; 4]);
+        fade.apply(&mut left, &mut right);
+        assert!(
+            left[0] < gain && left[0] > gain - 0.01,
+            "no jump on retrigger"
+        );
+    }
+}
@@ -169,10 +169,18 @@ impl SmoothedParam {
         }
         // One-pole lowpass: y[n] = y[n-1] + coeff * (target - y[n-1])
         // Equivalent to: y[n] = (1-coeff) * y[n-1] + coeff * target
-        self.current = self.current + self.coeff * (self.target - self.current);
-        if (self.current - self.target).abs() < 1e-6 {
+        let next = self.current + self.coeff * (self.target - self.current);
+        if (next - self.target).abs() < 1e-6 {
             self.current = self.target;
             self.settled = true;
+        } else if next == self.current {
+            // The step has dropped below half an f32 ulp of `current`, so the
+            // filter can never get closer. For targets of order 1 and up this
+            // happens outside the 1e-6 window; settle where it stands rather
+            // than jump the remaining gap.
+            self.settled = true;
+        } else {
+            self.current = next;
         }
         self.current
     }
@@ -405,6 +413,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn smoothed_param_settles_at_float_resolution() {
+        // 4 kHz is spaced ~0.0005 apart in f32, far wider than the 1e-6
+        // window, so the smoother has to settle once its step stops moving it.
+        let mut param = SmoothedParam::with_config(100.0, 48000.0, 20.0);
+        param.set_target(4000.0);
+        for _ in 0..48000 {
+            param.advance();
+        }
+
+        assert!(param.is_settled(), "stuck at {}", param.get());
+        assert!((param.get() - 4000.0).abs() < 0.5, "got {}", param.get());
+    }
+
     #[test]
     fn smoothed_param_gradual_approach() {
         let mut param = SmoothedParam::with_config(0.0, 48000.0, 10.0);
@@ -19,6 +19,8 @@
 //! - [`ParamFlags`] — capability flags for plugin hosts (automatable, stepped, etc.)
 //! - `string_id` — human-readable stable ID for debugging and serialization
 //! - `group` — parameter grouping for host tree display
+//! - `description` — long-form help text for tooltips
+//! - `smoothing` — how quickly platform layers ramp to new values
 //!
 //! # Example
 //!
@@ -168,6 +170,8 @@ macro_rules! impl_params {
     };
 }
 
+use crate::kernel::SmoothingStyle;
+
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String};
 
@@ -548,12 +552,19 @@ pub struct ParamDescriptor {
     /// Default: [`ParamFlags::AUTOMATABLE`].
     pub flags: ParamFlags,
 
-    /// Parameter group for host tree display (e.g., `"filter"`, `"modulation"`).
+    /// Parameter group for host tree display (e.g., `"Sidechain"`, `"Tempo Sync"`).
     ///
     /// Empty string means top-level (ungrouped). Used by CLAP hosts to
-    /// organize parameters hierarchically.
+    /// organize parameters hierarchically, and by the generic GUI panel to
+    /// render collapsible sections.
     pub group: &'static str,
 
+    /// Long description for tooltips and help text.
+    ///
+    /// One or two sentences on what the parameter does. Empty string means
+    /// no description; the name alone is shown.
+    pub description: &'static str,
+
     /// Optional modulation routing ID for CLAP hosts.
     ///
     /// When `Some`, the host can apply non-destructive modulation to this parameter.
@@ -567,6 +578,19 @@ pub struct ParamDescriptor {
     /// Used by [`format_value()`](Self::format_value) for CLAP `value_to_text()`
     /// and VST3 parameter display.
     pub step_labels: Option<&'static [&'static str]>,
+
+    /// How quickly platform layers should ramp to a new value.
+    ///
+    /// Kernel effects report their [`KernelParams::smoothing()`] choice here,
+    /// so plugin wrappers and GUIs can smooth parameters of effects they only
+    /// see as trait objects. Outside ramps snap
+    /// [`STEPPED`](ParamFlags::STEPPED) parameters; see
+    /// [`smoothing_time_ms()`](Self::smoothing_time_ms).
+    ///
+    /// Default: [`SmoothingStyle::Standard`].
+    ///
+    /// [`KernelParams::smoothing()`]: crate::kernel::KernelParams::smoothing
+    pub smoothing: SmoothingStyle,
 }
 
 impl ParamDescriptor {
@@ -587,8 +611,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -609,8 +635,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -632,8 +660,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -666,8 +696,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -700,8 +732,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -728,8 +762,10 @@ impl ParamDescriptor {
             scale: ParamScale::Logarithmic,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -761,8 +797,10 @@ impl ParamDescriptor {
             scale: ParamScale::Linear,
             flags: ParamFlags::AUTOMATABLE,
             group: "",
+            description: "",
             modulation_id: None,
             step_labels: None,
+            smoothing: SmoothingStyle::Standard,
         }
     }
 
@@ -825,6 +863,14 @@ impl ParamDescriptor {
         self
     }
 
+    /// Sets the long description shown in tooltips.
+    ///
+    /// Builder pattern — call after a factory method or struct literal.
+    pub const fn with_description(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+
     /// Sets step labels for discrete/enum parameters.
     ///
     /// Builder pattern — call after a factory method or struct literal.
@@ -872,6 +918,82 @@ impl ParamDescriptor {
         self
     }
 
+    /// Sets the smoothing style used when the value changes.
+    ///
+    /// Builder pattern — call after a factory method or struct literal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sonido_core::{ParamDescriptor, ParamScale, ParamUnit, SmoothingStyle};
+    ///
+    /// let cutoff = ParamDescriptor::custom("Cutoff", "Cutoff", 20.0, 20000.0, 1000.0)
+    ///     .with_unit(ParamUnit::Hertz)
+    ///     .with_scale(ParamScale::Logarithmic)
+    ///     .with_smoothing(SmoothingStyle::Slow);
+    /// assert_eq!(cutoff.smoothing_time_ms(), 20.0);
+    /// ```
+    pub const fn with_smoothing(mut self, smoothing: SmoothingStyle) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Ramp time in milliseconds for a change to this parameter.
+    ///
+    /// This is the ramp a platform layer applies outside the effect. It is
+    /// `0.0` (snap) for [`STEPPED`](ParamFlags::STEPPED) and
+    /// [`READ_ONLY`](ParamFlags::READ_ONLY) parameters whatever their
+    /// [`smoothing`](Self::smoothing) says, since ramping through
+    /// intermediate enum values would switch modes along the way. A kernel
+    /// may still glide a stepped parameter internally, as the bitcrusher
+    /// does with bit depth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sonido_core::{ParamDescriptor, ParamFlags};
+    ///
+    /// assert_eq!(ParamDescriptor::mix().smoothing_time_ms(), 10.0);
+    /// let mode = ParamDescriptor::custom("Mode", "Mode", 0.0, 2.0, 0.0)
+    ///     .with_step(1.0)
+    ///     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED));
+    /// assert_eq!(mode.smoothing_time_ms(), 0.0);
+    /// ```
+    pub fn smoothing_time_ms(&self) -> f32 {
+        if self.flags.contains(ParamFlags::STEPPED) || self.flags.contains(ParamFlags::READ_ONLY) {
+            0.0
+        } else {
+            self.smoothing.time_ms()
+        }
+    }
+
+    /// Clamps a value to range and, for [`STEPPED`](ParamFlags::STEPPED)
+    /// parameters, rounds it to the nearest step.
+    ///
+    /// Use this where values arrive from outside (host automation, MIDI)
+    /// and may fall between the discrete positions of an enum parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sonido_core::{ParamDescriptor, ParamFlags};
+    ///
+    /// let mode = ParamDescriptor::custom("Mode", "Mode", 0.0, 3.0, 0.0)
+    ///     .with_step(1.0)
+    ///     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED));
+    /// assert_eq!(mode.snap(1.4), 1.0);
+    /// assert_eq!(mode.snap(2.6), 3.0);
+    /// assert_eq!(mode.snap(9.0), 3.0);
+    /// assert_eq!(ParamDescriptor::mix().snap(33.3), 33.3);
+    /// ```
+    pub fn snap(&self, value: f32) -> f32 {
+        if !self.flags.contains(ParamFlags::STEPPED) {
+            return self.clamp(value);
+        }
+        let step = if self.step > 0.0 { self.step } else { 1.0 };
+        self.clamp(self.min + libm::roundf((value - self.min) / step) * step)
+    }
+
     /// Clamps a value to this parameter's valid range.
     ///
     /// # Example
@@ -1054,8 +1176,10 @@ impl ParamDescriptor {
     /// Formats a parameter value as human-readable text for DAW display.
     ///
     /// Stepped parameters with [`step_labels`](Self::step_labels) return the label
-    /// string. Continuous parameters format by [`ParamUnit`] with appropriate
-    /// precision and unit suffixes. Large values auto-scale (Hz → kHz, ms → s).
+    /// of the nearest step. Other [`STEPPED`](ParamFlags::STEPPED) parameters with
+    /// whole-number steps print as integers ("6", "12 st"). Continuous parameters
+    /// format by [`ParamUnit`] with appropriate precision and unit suffixes. Large
+    /// values auto-scale (Hz → kHz, ms → s).
     ///
     /// Maps directly to CLAP `value_to_text()` and VST3 parameter display.
     ///
@@ -1072,17 +1196,25 @@ impl ParamDescriptor {
     /// ```
     pub fn format_value(&self, value: f32) -> String {
         if let Some(labels) = self.step_labels {
-            let idx = ((value - self.min) / self.step.max(1.0)) as usize;
+            let idx = libm::roundf((value - self.min) / self.step.max(1.0)) as usize;
             return String::from(*labels.get(idx).unwrap_or(&"?"));
         }
+        if self.is_integer_stepped() {
+            return format!("{}{}", libm::roundf(value) as i32, self.unit.suffix());
+        }
         match self.unit {
             ParamUnit::Decibels => format!("{:.1} dB", value),
             ParamUnit::Hertz if value >= 1000.0 => format!("{:.1} kHz", value / 1000.0),
-            ParamUnit::Hertz => format!("{:.0} Hz", value),
+            ParamUnit::Hertz if value >= 100.0 => format!("{:.0} Hz", value),
+            ParamUnit::Hertz if value >= 10.0 => format!("{:.1} Hz", value),
+            ParamUnit::Hertz => format!("{:.2} Hz", value),
             ParamUnit::Milliseconds if value >= 1000.0 => format!("{:.2} s", value / 1000.0),
             ParamUnit::Milliseconds => format!("{:.0} ms", value),
+            ParamUnit::Seconds => format!("{:.2} s", value),
             ParamUnit::Percent => format!("{:.0}%", value),
             ParamUnit::Ratio => format!("{:.1}:1", value),
+            ParamUnit::Semitones => format!("{:.1} st", value),
+            ParamUnit::Cents => format!("{:.0} ct", value),
             ParamUnit::None => format!("{:.2}", value),
         }
     }
@@ -1090,7 +1222,10 @@ impl ParamDescriptor {
     /// Parses human-readable text back to a parameter value.
     ///
     /// Handles step labels (case-insensitive match) and numeric values with
-    /// unit suffixes. Auto-converts scaled units (kHz → Hz, s → ms).
+    /// unit suffixes, with or without a separating space ("-6 dB", "1.2kHz").
+    /// Auto-converts scaled units (kHz → Hz, s ↔ ms). Values for
+    /// [`STEPPED`](ParamFlags::STEPPED) parameters snap to the nearest step, so
+    /// text produced by [`format_value`](Self::format_value) parses back exactly.
     ///
     /// Maps directly to CLAP `text_to_value()` and VST3 string-to-parameter.
     ///
@@ -1113,27 +1248,57 @@ impl ParamDescriptor {
         {
             return Some(self.min + i as f32 * self.step.max(1.0));
         }
-        // Strip known suffixes and parse
-        let cleaned = text
-            .trim()
-            .trim_end_matches(" dB")
-            .trim_end_matches(" Hz")
-            .trim_end_matches(" kHz")
-            .trim_end_matches(" ms")
-            .trim_end_matches(" s")
-            .trim_end_matches('%')
-            .trim_end_matches(":1")
-            .trim();
-        let val: f32 = cleaned.parse().ok()?;
-        // Handle kHz → Hz, s → ms conversions
-        if text.contains("kHz") || (text.ends_with(" s") && !text.ends_with(" ms")) {
-            Some(val * 1000.0)
+        // Strip a known suffix (with or without a space, any case) and parse.
+        // Time suffixes scale to milliseconds; seconds params divide back out.
+        let base = if self.unit == ParamUnit::Seconds {
+            1000.0
         } else {
-            Some(val)
+            1.0
+        };
+        let trimmed = text.trim();
+        let (number, scale) = UNIT_SUFFIXES
+            .iter()
+            .find_map(|&(suffix, scale)| {
+                let split = trimmed.len().checked_sub(suffix.len())?;
+                let tail = trimmed.get(split..)?;
+                tail.eq_ignore_ascii_case(suffix)
+                    .then(|| (&trimmed[..split], scale))
+            })
+            .unwrap_or((trimmed, base));
+        let val: f32 = number.trim().parse().ok()?;
+        let val = val * scale / base;
+        if self.flags.contains(ParamFlags::STEPPED) && self.step > 0.0 {
+            let steps = libm::roundf((val - self.min) / self.step);
+            return Some(self.min + steps * self.step);
         }
+        Some(val)
+    }
+
+    /// Whether this is a [`STEPPED`](ParamFlags::STEPPED) parameter whose steps
+    /// are whole numbers, so values display without a fractional part.
+    fn is_integer_stepped(&self) -> bool {
+        self.flags.contains(ParamFlags::STEPPED)
+            && self.step >= 1.0
+            && libm::truncf(self.step) == self.step
+            && libm::truncf(self.min) == self.min
     }
 }
 
+/// Suffixes accepted by [`ParamDescriptor::parse_value`], with the factor that
+/// converts them to the base unit. Longer suffixes sharing an ending come first
+/// ("kHz" before "Hz", "ms" and "st" before "s"). Times are in milliseconds.
+const UNIT_SUFFIXES: [(&str, f32); 9] = [
+    ("kHz", 1000.0),
+    ("Hz", 1.0),
+    ("dB", 1.0),
+    ("ms", 1.0),
+    ("st", 1.0),
+    ("s", 1000.0),
+    ("ct", 1.0),
+    ("%", 1.0),
+    (":1", 1.0),
+];
+
 /// Unit type for parameter display and formatting.
 ///
 /// This enum helps GUI applications and hardware displays format parameter
@@ -1168,12 +1333,21 @@ pub enum ParamUnit {
     /// Milliseconds (ms) - for time parameters like delay, attack, release.
     Milliseconds,
 
+    /// Seconds (s) - for long times like reverb decay or sweep duration.
+    Seconds,
+
     /// Percentage (%) - for mix, blend, and normalized parameters.
     Percent,
 
     /// Ratio (n:1) - for compressor ratios and similar.
     Ratio,
 
+    /// Semitones (st) - for pitch offsets in musical intervals.
+    Semitones,
+
+    /// Cents (ct) - for fine pitch offsets, 1/100 of a semitone.
+    Cents,
+
     /// No unit - for dimensionless or custom parameters.
     None,
 }
@@ -1195,8 +1369,11 @@ impl ParamUnit {
             ParamUnit::Decibels => " dB",
             ParamUnit::Hertz => " Hz",
             ParamUnit::Milliseconds => " ms",
+            ParamUnit::Seconds => " s",
             ParamUnit::Percent => "%",
             ParamUnit::Ratio => ":1",
+            ParamUnit::Semitones => " st",
+            ParamUnit::Cents => " ct",
             ParamUnit::None => "",
         }
     }
@@ -1438,6 +1615,9 @@ mod tests {
         assert_eq!(ParamUnit::Milliseconds.suffix(), " ms");
         assert_eq!(ParamUnit::Percent.suffix(), "%");
         assert_eq!(ParamUnit::Ratio.suffix(), ":1");
+        assert_eq!(ParamUnit::Seconds.suffix(), " s");
+        assert_eq!(ParamUnit::Semitones.suffix(), " st");
+        assert_eq!(ParamUnit::Cents.suffix(), " ct");
         assert_eq!(ParamUnit::None.suffix(), "");
     }
 
@@ -1521,6 +1701,16 @@ mod tests {
         assert!(desc.flags.contains(ParamFlags::STEPPED));
     }
 
+    #[test]
+    fn test_with_group_and_description_builder() {
+        let desc = ParamDescriptor::mix()
+            .with_group("Sidechain")
+            .with_description("Blend of wet and dry signal.");
+        assert_eq!(desc.group, "Sidechain");
+        assert_eq!(desc.description, "Blend of wet and dry signal.");
+        assert_eq!(desc.name, "Mix"); // unchanged
+    }
+
     #[test]
     fn test_defaults() {
         let desc = ParamDescriptor::mix();
@@ -1529,6 +1719,7 @@ mod tests {
         assert_eq!(desc.scale, ParamScale::Linear);
         assert_eq!(desc.flags, ParamFlags::AUTOMATABLE);
         assert_eq!(desc.group, "");
+        assert_eq!(desc.description, "");
         assert_eq!(desc.modulation_id, None);
         assert_eq!(desc.step_labels, None);
     }
@@ -1579,6 +1770,9 @@ mod tests {
     fn test_format_value_hertz() {
         let desc = ParamDescriptor::rate_hz(20.0, 20000.0, 1000.0);
         assert_eq!(desc.format_value(440.0), "440 Hz");
+        // Sub-audio rates (LFOs) keep enough precision to round-trip.
+        assert_eq!(desc.format_value(0.25), "0.25 Hz");
+        assert_eq!(desc.format_value(12.5), "12.5 Hz");
         assert_eq!(desc.format_value(1500.0), "1.5 kHz");
         assert_eq!(desc.format_value(20000.0), "20.0 kHz");
     }
@@ -1591,6 +1785,19 @@ mod tests {
         assert_eq!(desc.format_value(5.0), "5 ms");
     }
 
+    #[test]
+    fn test_format_value_musical_units() {
+        let secs =
+            ParamDescriptor::custom("Decay", "Decay", 0.1, 10.0, 2.0).with_unit(ParamUnit::Seconds);
+        assert_eq!(secs.format_value(2.5), "2.50 s");
+        let st = ParamDescriptor::custom("Pitch", "Pitch", -24.0, 24.0, 0.0)
+            .with_unit(ParamUnit::Semitones);
+        assert_eq!(st.format_value(-7.0), "-7.0 st");
+        let ct =
+            ParamDescriptor::custom("Fine", "Fine", -50.0, 50.0, 0.0).with_unit(ParamUnit::Cents);
+        assert_eq!(ct.format_value(12.0), "12 ct");
+    }
+
     #[test]
     fn test_format_value_percent() {
         let desc = ParamDescriptor::mix();
@@ -1640,12 +1847,43 @@ mod tests {
         assert_eq!(desc.format_value(4.0), "?");
     }
 
+    #[test]
+    fn test_format_value_step_labels_rounds_to_nearest() {
+        let desc = ParamDescriptor::custom("Wave", "Wave", 0.0, 2.0, 0.0)
+            .with_step_labels(&["Sine", "Triangle", "Square"]);
+        // Host round-trips through normalized values can land just below a step.
+        assert_eq!(desc.format_value(0.9999), "Triangle");
+        assert_eq!(desc.format_value(1.4), "Triangle");
+        assert_eq!(desc.format_value(1.6), "Square");
+    }
+
+    #[test]
+    fn test_format_value_integer_stepped() {
+        let desc = ParamDescriptor::custom("Stages", "Stages", 2.0, 12.0, 6.0)
+            .with_step(2.0)
+            .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED));
+        assert_eq!(desc.format_value(6.0), "6");
+        assert_eq!(desc.parse_value("6"), Some(6.0));
+        // Off-grid text snaps to the nearest step.
+        assert_eq!(desc.parse_value("6.9"), Some(6.0));
+        assert_eq!(desc.parse_value("7.1"), Some(8.0));
+
+        let desc = ParamDescriptor::custom("Pitch", "Pitch", -12.0, 12.0, 0.0)
+            .with_unit(ParamUnit::Semitones)
+            .with_step(1.0)
+            .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED));
+        assert_eq!(desc.format_value(-7.0), "-7 st");
+        assert_eq!(desc.parse_value("-7 st"), Some(-7.0));
+    }
+
     #[test]
     fn test_parse_value_decibels() {
         let desc = ParamDescriptor::gain_db("Gain", "Gain", -60.0, 12.0, 0.0);
         assert_eq!(desc.parse_value("-6.0 dB"), Some(-6.0));
         assert_eq!(desc.parse_value("0.0 dB"), Some(0.0));
         assert_eq!(desc.parse_value("-6.0"), Some(-6.0));
+        assert_eq!(desc.parse_value("-6dB"), Some(-6.0));
+        assert_eq!(desc.parse_value(" -6 db "), Some(-6.0));
     }
 
     #[test]
@@ -1653,6 +1891,8 @@ mod tests {
         let desc = ParamDescriptor::rate_hz(20.0, 20000.0, 1000.0);
         assert_eq!(desc.parse_value("440 Hz"), Some(440.0));
         assert_eq!(desc.parse_value("1.5 kHz"), Some(1500.0));
+        assert_eq!(desc.parse_value("1.2kHz"), Some(1200.0));
+        assert_eq!(desc.parse_value("350hz"), Some(350.0));
     }
 
     #[test]
@@ -1660,6 +1900,26 @@ mod tests {
         let desc = ParamDescriptor::time_ms("Time", "Time", 1.0, 2000.0, 250.0);
         assert_eq!(desc.parse_value("250 ms"), Some(250.0));
         assert_eq!(desc.parse_value("1.5 s"), Some(1500.0));
+        assert_eq!(desc.parse_value("350ms"), Some(350.0));
+    }
+
+    #[test]
+    fn test_parse_value_seconds() {
+        let desc =
+            ParamDescriptor::custom("Decay", "Decay", 0.1, 10.0, 2.0).with_unit(ParamUnit::Seconds);
+        assert_eq!(desc.parse_value("2.5 s"), Some(2.5));
+        assert_eq!(desc.parse_value("2.5"), Some(2.5));
+        assert_eq!(desc.parse_value("500 ms"), Some(0.5));
+    }
+
+    #[test]
+    fn test_parse_value_pitch_units() {
+        let st = ParamDescriptor::custom("Pitch", "Pitch", -24.0, 24.0, 0.0)
+            .with_unit(ParamUnit::Semitones);
+        assert_eq!(st.parse_value("-7 st"), Some(-7.0));
+        let ct =
+            ParamDescriptor::custom("Fine", "Fine", -50.0, 50.0, 0.0).with_unit(ParamUnit::Cents);
+        assert_eq!(ct.parse_value("12ct"), Some(12.0));
     }
 
     #[test]
@@ -1712,6 +1972,7 @@ mod tests {
         assert_eq!(desc.scale, ParamScale::Linear);
         assert_eq!(desc.flags, ParamFlags::AUTOMATABLE);
         assert_eq!(desc.group, "");
+        assert_eq!(desc.description, "");
         assert_eq!(desc.modulation_id, None);
         assert_eq!(desc.step_labels, None);
     }
@@ -0,0 +1,229 @@
+//! Parameter carry-over between different effect types.
+//!
+//! When a chain slot swaps one effect for another (Distortion → Tape, Chorus →
+//! Flanger), [`map_params`] decides which of the outgoing effect's settings
+//! make sense on the incoming one, so the user keeps their drive, mix, and
+//! output levels instead of starting from defaults.
+//!
+//! # Matching rules
+//!
+//! A target parameter takes a source value only when both share the same
+//! [`ParamUnit`] and the same [`group`](ParamDescriptor::group), and either:
+//!
+//! 1. their names match (case-insensitive), e.g. "Rate" ↔ "Rate", or
+//! 2. both names belong to the same semantic role — drive ("Drive", "Gain",
+//!    "Input"), tone ("Tone", "Color"), mix ("Mix", "Wet", "Blend"), or
+//!    output ("Output", "Level", "Volume").
+//!
+//! Exact name matches win over role matches, and each source is used at most
+//! once. Stepped selectors only carry over between identical label sets (a
+//! waveform index means nothing to a different list), and read-only or
+//! hidden targets are never written. Carried values are clamped to the
+//! target's range.
+//!
+//! ```text
+//!  Distortion              Tape
+//!  Drive   20 dB  ───────► Drive   20 dB   (same name, same unit)
+//!  Tone     3 dB     ✗     —               (no dB "Tone" on tape)
+//!  Output  -2 dB  ───────► Output  -2 dB
+//!  Waveshape         ✗     —               (stepped, different labels)
+//! ```
+//!
+//! # no_std
+//!
+//! Compatible with no_std + alloc.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::param_info::{ParamDescriptor, ParamFlags};
+
+/// Name families treated as the same control across effects.
+const ROLES: [&[&str]; 4] = [
+    &["drive", "gain", "input", "pre gain"],
+    &["tone", "color", "colour"],
+    &["mix", "wet", "blend", "dry/wet"],
+    &["output", "level", "volume", "out"],
+];
+
+/// Maps parameter values from one effect's descriptors onto another's.
+///
+/// Returns one entry per `to` descriptor: `Some(value)` for parameters that
+/// take over a compatible setting from `from_values`, `None` for those that
+/// should keep their default. `from_values` is parallel to `from`; missing
+/// trailing values are treated as unmatched.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::{ParamDescriptor, param_map::map_params};
+///
+/// let from = [
+///     ParamDescriptor::gain_db("Drive", "Drive", 0.0, 40.0, 8.0),
+///     ParamDescriptor::mix(),
+/// ];
+/// let to = [
+///     ParamDescriptor::gain_db("Gain", "Gain", 0.0, 24.0, 6.0),
+///     ParamDescriptor::rate_hz(0.1, 10.0, 1.0),
+/// ];
+///
+/// // "Drive" → "Gain" by role, clamped to the 24 dB maximum. Rate has no source.
+/// assert_eq!(map_params(&from, &[30.0, 50.0], &to), vec![Some(24.0), None]);
+/// ```
+pub fn map_params(
+    from: &[ParamDescriptor],
+    from_values: &[f32],
+    to: &[ParamDescriptor],
+) -> Vec<Option<f32>> {
+    let mut used = Vec::with_capacity(from.len());
+    used.resize(from.len(), false);
+    let mut mapped = Vec::with_capacity(to.len());
+
+    for target in to {
+        let writable = !target.flags.contains(ParamFlags::READ_ONLY)
+            && !target.flags.contains(ParamFlags::HIDDEN);
+        let source = writable
+            .then(|| {
+                find_source(from, from_values, &used, target, |a, b| {
+                    a.eq_ignore_ascii_case(b)
+                })
+                .or_else(|| find_source(from, from_values, &used, target, same_role))
+            })
+            .flatten();
+        mapped.push(source.map(|i| {
+            used[i] = true;
+            from_values[i].clamp(target.min, target.max)
+        }));
+    }
+    mapped
+}
+
+/// First unused source compatible with `target` whose name satisfies `names_match`.
+fn find_source(
+    from: &[ParamDescriptor],
+    from_values: &[f32],
+    used: &[bool],
+    target: &ParamDescriptor,
+    names_match: impl Fn(&str, &str) -> bool,
+) -> Option<usize> {
+    from.iter()
+        .zip(from_values)
+        .enumerate()
+        .position(|(i, (source, value))| {
+            !used[i]
+                && value.is_finite()
+                && compatible(source, target)
+                && names_match(source.name, target.name)
+        })
+}
+
+/// Same unit and group, and stepped parameters share their label set.
+fn compatible(source: &ParamDescriptor, target: &ParamDescriptor) -> bool {
+    let stepped =
+        |d: &ParamDescriptor| d.flags.contains(ParamFlags::STEPPED) || d.step_labels.is_some();
+    source.unit == target.unit
+        && source.group == target.group
+        && !source.flags.contains(ParamFlags::HIDDEN)
+        && (!stepped(source) && !stepped(target)
+            || source.step_labels.is_some() && source.step_labels == target.step_labels)
+}
+
+/// Whether two names fall in the same [`ROLES`] family.
+fn same_role(a: &str, b: &str) -> bool {
+    ROLES.iter().any(|role| {
+        role.iter().any(|r| r.eq_ignore_ascii_case(a))
+            && role.iter().any(|r| r.eq_ignore_ascii_case(b))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::param_info::ParamUnit;
+
+    #[test]
+    fn exact_names_carry_over_within_range() {
+        let from = [
+            ParamDescriptor::gain_db("Drive", "Drive", 0.0, 40.0, 8.0),
+            ParamDescriptor::mix(),
+            ParamDescriptor::gain_db("Output", "Output", -6.0, 6.0, 0.0),
+        ];
+        let to = [
+            ParamDescriptor::gain_db("Output", "Output", -12.0, 12.0, -6.0),
+            ParamDescriptor::gain_db("Drive", "Drive", 0.0, 24.0, 6.0),
+        ];
+        let mapped = map_params(&from, &[12.0, 70.0, -2.0], &to);
+        assert_eq!(mapped, vec![Some(-2.0), Some(12.0)]);
+    }
+
+    #[test]
+    fn roles_match_when_no_exact_name() {
+        let from = [
+            ParamDescriptor {
+                name: "Wet",
+                short_name: "Wet",
+                ..ParamDescriptor::mix()
+            },
+            ParamDescriptor::gain_db("Level", "Level", -20.0, 6.0, 0.0),
+        ];
+        let to = [
+            ParamDescriptor::mix(),
+            ParamDescriptor::gain_db("Output", "Output", -12.0, 12.0, 0.0),
+        ];
+        assert_eq!(
+            map_params(&from, &[40.0, -18.0], &to),
+            vec![Some(40.0), Some(-12.0)]
+        );
+    }
+
+    #[test]
+    fn exact_match_beats_role_and_sources_are_used_once() {
+        let from = [
+            ParamDescriptor::gain_db("Gain", "Gain", 0.0, 24.0, 0.0),
+            ParamDescriptor::gain_db("Drive", "Drive", 0.0, 24.0, 0.0),
+        ];
+        let to = [
+            ParamDescriptor::gain_db("Drive", "Drive", 0.0, 24.0, 0.0),
+            ParamDescriptor::gain_db("Input", "Input", 0.0, 24.0, 0.0),
+        ];
+        // "Drive" takes its namesake; "Input" falls back to the remaining drive-role "Gain".
+        assert_eq!(
+            map_params(&from, &[3.0, 9.0], &to),
+            vec![Some(9.0), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn unit_or_group_mismatch_is_not_carried() {
+        let from = [
+            ParamDescriptor::gain_db("Tone", "Tone", -12.0, 12.0, 0.0),
+            ParamDescriptor::gain_db("Gain", "Gain", -12.0, 12.0, 0.0).with_group("Low"),
+        ];
+        let to = [
+            ParamDescriptor::custom("Tone", "Tone", 0.0, 1.0, 0.5).with_unit(ParamUnit::None),
+            ParamDescriptor::gain_db("Gain", "Gain", -12.0, 12.0, 0.0).with_group("High"),
+        ];
+        assert_eq!(map_params(&from, &[6.0, 6.0], &to), vec![None, None]);
+    }
+
+    #[test]
+    fn stepped_params_need_identical_labels() {
+        let shapes = ParamDescriptor::custom("Waveform", "Wave", 0.0, 2.0, 0.0)
+            .with_step_labels(&["Sine", "Triangle", "Square"]);
+        let other = ParamDescriptor::custom("Waveform", "Wave", 0.0, 1.0, 0.0)
+            .with_step_labels(&["Sine", "Saw"]);
+        assert_eq!(map_params(&[shapes], &[2.0], &[shapes]), vec![Some(2.0)]);
+        assert_eq!(map_params(&[shapes], &[2.0], &[other]), vec![None]);
+    }
+
+    #[test]
+    fn read_only_targets_and_missing_values_are_skipped() {
+        let from = [ParamDescriptor::mix(), ParamDescriptor::mix()];
+        let to = [
+            ParamDescriptor::mix().with_flags(ParamFlags::READ_ONLY),
+            ParamDescriptor::mix(),
+        ];
+        assert_eq!(map_params(&from, &[], &to), vec![None, None]);
+        assert_eq!(map_params(&from, &[25.0], &to), vec![None, Some(25.0)]);
+    }
+}
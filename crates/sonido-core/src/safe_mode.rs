@@ -0,0 +1,281 @@
+//! Safe mode: hard bypass of a whole chain for when a patch runs away.
+//!
+//! Where [`panic`](crate::panic) resets effect state and carries on, safe mode
+//! takes the chain out of the signal path until it is released. [`SafeMode`]
+//! crossfades the output to a dry path delayed by the chain's latency, so the
+//! switch is click-free and the dry signal lines up with what was playing.
+//! Once fully dry it asks its owner, once, to mute the feedback-capable
+//! effects (anything with a tail: delays, reverbs, loopers), and the owner
+//! stops processing the chain. Releasing safe mode fades the chain back in
+//! from that clean state.
+//!
+//! The same dry path carries the chain's global dry/wet
+//! [mix](SafeMode::set_mix), so a parallel blend stays phase-coherent with
+//! lookahead or oversampled effects in the chain.
+//!
+//! ```rust
+//! use sonido_core::safe_mode::SafeMode;
+//!
+//! let mut safe = SafeMode::new(48000.0);
+//! let input = [0.5_f32; 256];
+//! let (mut left, mut right) = ([0.0_f32; 256], [0.0_f32; 256]);
+//!
+//! safe.set_engaged(true);
+//! for _ in 0..4 {
+//!     if safe.take_mute() {
+//!         // Reset the feedback-capable effects here.
+//!     }
+//!     if !safe.is_dry() {
+//!         // ... process the chain into left/right ...
+//!     }
+//!     safe.apply(&input, &input, &mut left, &mut right);
+//! }
+//! assert!(safe.is_dry());
+//! assert_eq!(left[255], 0.5);
+//! ```
+
+use crate::LinearSmoothedParam;
+use crate::graph::CompensationDelay;
+
+/// Length of the crossfade into and out of safe mode in milliseconds.
+pub const SAFE_MODE_FADE_MS: f32 = 10.0;
+
+/// Smoothing time for global mix changes in milliseconds.
+pub const MIX_SMOOTHING_MS: f32 = 10.0;
+
+/// Safe mode progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    /// Released; [`SafeMode::apply`] only feeds the dry delay.
+    Idle,
+    /// Crossfading from the chain to the dry path.
+    FadingOut,
+    /// Dry path only; the chain is not processed.
+    Dry,
+    /// Crossfading from the dry path back to the chain.
+    FadingIn,
+}
+
+/// Dry-path crossfade and mute request for a chain's safe mode, plus the
+/// chain's global dry/wet mix.
+///
+/// Real-time safe except [`set_latency`](Self::set_latency), which resizes
+/// the dry delay when the chain's latency changes.
+pub struct SafeMode {
+    stage: Stage,
+    /// Chain level: `1.0` processed, `0.0` dry.
+    wet: LinearSmoothedParam,
+    /// Global dry/wet mix: `1.0` fully processed, `0.0` dry.
+    mix: LinearSmoothedParam,
+    /// Dry path, delayed to line up with the chain's output.
+    dry: CompensationDelay,
+    /// Set on reaching [`Stage::Dry`]; cleared by [`take_mute`](Self::take_mute).
+    mute_pending: bool,
+}
+
+impl SafeMode {
+    /// Create a released safe mode for `sample_rate` with no dry delay.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            stage: Stage::Idle,
+            wet: LinearSmoothedParam::with_config(1.0, sample_rate, SAFE_MODE_FADE_MS),
+            mix: LinearSmoothedParam::with_config(1.0, sample_rate, MIX_SMOOTHING_MS),
+            dry: CompensationDelay::new(0),
+            mute_pending: false,
+        }
+    }
+
+    /// Update the crossfade length for a new sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.wet.set_sample_rate(sample_rate);
+        self.mix.set_sample_rate(sample_rate);
+    }
+
+    /// Set the global dry/wet mix, `0.0` (dry) to `1.0` (processed),
+    /// smoothed over [`MIX_SMOOTHING_MS`]. Safe mode fades from and back to
+    /// this blend.
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix.set_target(mix.clamp(0.0, 1.0));
+    }
+
+    /// Set the global dry/wet mix without smoothing, for setting up before
+    /// processing starts.
+    pub fn set_mix_immediate(&mut self, mix: f32) {
+        self.mix.set_immediate(mix.clamp(0.0, 1.0));
+    }
+
+    /// The global dry/wet mix target.
+    pub fn mix(&self) -> f32 {
+        self.mix.target()
+    }
+
+    /// Delay the dry path by the chain's latency in samples. Allocates when
+    /// the latency changes.
+    pub fn set_latency(&mut self, samples: usize) {
+        self.dry.set_delay_samples(samples);
+    }
+
+    /// Engage (`true`) or release (`false`) safe mode. Reversing mid-fade
+    /// turns around from the current level, so toggling never clicks.
+    pub fn set_engaged(&mut self, engaged: bool) {
+        if engaged == self.is_engaged() {
+            return;
+        }
+        if engaged {
+            self.stage = Stage::FadingOut;
+            self.wet.set_target(0.0);
+        } else {
+            self.stage = Stage::FadingIn;
+            self.mute_pending = false;
+            self.wet.set_target(1.0);
+        }
+    }
+
+    /// Whether safe mode is engaged (fading to or holding the dry path).
+    pub fn is_engaged(&self) -> bool {
+        matches!(self.stage, Stage::FadingOut | Stage::Dry)
+    }
+
+    /// Whether the output is fully dry; the chain need not be processed.
+    pub fn is_dry(&self) -> bool {
+        self.stage == Stage::Dry
+    }
+
+    /// Call at the start of each block. Returns `true` exactly once per
+    /// engagement, when the output has gone fully dry and feedback-capable
+    /// effects should be muted.
+    pub fn take_mute(&mut self) -> bool {
+        core::mem::take(&mut self.mute_pending)
+    }
+
+    /// Mix the delayed dry input into a processed block in
+    /// `left_out`/`right_out` (whose contents are ignored while
+    /// [`is_dry`](Self::is_dry)).
+    ///
+    /// Call every block, engaged or not, so the dry delay stays primed.
+    pub fn apply(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+    ) {
+        let len = left_out.len().min(right_out.len()).min(left_in.len());
+        for i in 0..len {
+            let (dry_l, dry_r) = self.dry.process(left_in[i], right_in[i]);
+            let mix = self.mix.advance();
+            let wet = match self.stage {
+                Stage::Idle => mix,
+                Stage::Dry => {
+                    left_out[i] = dry_l;
+                    right_out[i] = dry_r;
+                    continue;
+                }
+                Stage::FadingOut | Stage::FadingIn => self.wet.advance() * mix,
+            };
+            if wet < 1.0 {
+                left_out[i] = left_out[i] * wet + dry_l * (1.0 - wet);
+                right_out[i] = right_out[i] * wet + dry_r * (1.0 - wet);
+            }
+        }
+        if self.wet.is_settled() {
+            match self.stage {
+                Stage::FadingOut => {
+                    self.stage = Stage::Dry;
+                    self.mute_pending = true;
+                }
+                Stage::FadingIn => self.stage = Stage::Idle,
+                Stage::Idle | Stage::Dry => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one block of a ramp input over a wet signal of 1.0.
+    fn run_block(safe: &mut SafeMode, start: f32) -> [f32; 64] {
+        let mut input = [0.0_f32; 64];
+        for (i, s) in input.iter_mut().enumerate() {
+            *s = start + i as f32;
+        }
+        let (mut left, mut right) = ([1.0_f32; 64], [1.0_f32; 64]);
+        safe.apply(&input, &input, &mut left, &mut right);
+        assert_eq!(left, right);
+        left
+    }
+
+    #[test]
+    fn released_is_passthrough() {
+        let mut safe = SafeMode::new(48000.0);
+        let out = run_block(&mut safe, 0.0);
+        assert_eq!(out, [1.0; 64]);
+        assert!(!safe.take_mute());
+    }
+
+    #[test]
+    fn engage_fades_to_delayed_dry_and_mutes_once() {
+        let mut safe = SafeMode::new(48000.0);
+        safe.set_latency(16);
+        safe.set_engaged(true);
+        assert!(safe.is_engaged() && !safe.is_dry());
+
+        // 10 ms at 48 kHz = 7.5 blocks of 64
+        let mut mutes = 0;
+        for block in 0..10 {
+            mutes += usize::from(safe.take_mute());
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        assert!(safe.is_dry());
+        assert_eq!(mutes + usize::from(safe.take_mute()), 1);
+        assert!(!safe.take_mute(), "mute requested once");
+
+        // Dry output is the input delayed by the latency
+        let out = run_block(&mut safe, 640.0);
+        assert_eq!(out[0], 640.0 - 16.0);
+        assert_eq!(out[63], 703.0 - 16.0);
+    }
+
+    #[test]
+    fn release_fades_back_to_the_chain() {
+        let mut safe = SafeMode::new(48000.0);
+        safe.set_engaged(true);
+        for block in 0..10 {
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        safe.set_engaged(false);
+        assert!(!safe.is_engaged() && !safe.is_dry());
+        for block in 10..20 {
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        let out = run_block(&mut safe, 0.0);
+        assert_eq!(out, [1.0; 64]);
+    }
+
+    #[test]
+    fn mix_blends_delayed_dry_and_survives_safe_mode() {
+        let mut safe = SafeMode::new(48000.0);
+        safe.set_latency(16);
+        safe.set_mix(0.25);
+        for block in 0..10 {
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        let out = run_block(&mut safe, 640.0);
+        assert_eq!(out[0], 0.25 + 0.75 * (640.0 - 16.0));
+
+        // Releasing safe mode returns to the blend, not fully processed
+        safe.set_engaged(true);
+        for block in 11..21 {
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        safe.set_engaged(false);
+        for block in 21..31 {
+            run_block(&mut safe, (block * 64) as f32);
+        }
+        let out = run_block(&mut safe, 1984.0);
+        assert_eq!(out[63], 0.25 + 0.75 * (2047.0 - 16.0));
+        assert_eq!(safe.mix(), 0.25);
+    }
+}
@@ -0,0 +1,204 @@
+//! Output protection limiter: the last stage before the speakers.
+//!
+//! [`SafetyLimiter`] is a zero-latency brickwall limiter meant to sit after
+//! every user effect, outside the user graph, so no preset or gain staging can
+//! push the output past the ceiling. Gain reduction follows peaks instantly
+//! (linked across channels) and releases exponentially; a final clamp at the
+//! ceiling catches anything the envelope lets through. It has no lookahead, so
+//! adds no latency, at the cost of some distortion on hard transients — it is
+//! protection, not a mastering limiter.
+//!
+//! Disabling it lets any active gain reduction release before the stage goes
+//! transparent, so toggling never jumps in level.
+//!
+//! ```rust
+//! use sonido_core::safety_limiter::SafetyLimiter;
+//!
+//! let mut limiter = SafetyLimiter::new(48000.0);
+//! limiter.set_enabled(true);
+//! limiter.set_ceiling_db(-6.0);
+//! let (mut left, mut right) = ([1.5_f32; 64], [-1.5_f32; 64]);
+//! limiter.process(&mut left, &mut right);
+//! assert!(left.iter().all(|&s| s <= 0.502));
+//! assert!(limiter.take_reduction_db() > 9.0);
+//! ```
+
+use crate::math::{db_to_linear, linear_to_db};
+
+/// Default output ceiling in dBFS.
+pub const DEFAULT_CEILING_DB: f32 = -1.0;
+
+/// Lowest settable ceiling in dBFS.
+pub const MIN_CEILING_DB: f32 = -24.0;
+
+/// Release time constant in milliseconds.
+pub const SAFETY_RELEASE_MS: f32 = 100.0;
+
+/// Zero-latency brickwall limiter for output protection.
+///
+/// Real-time safe: no allocation, one gain computation per frame while
+/// enabled or releasing.
+#[derive(Debug, Clone)]
+pub struct SafetyLimiter {
+    enabled: bool,
+    ceiling_db: f32,
+    ceiling: f32,
+    /// Current gain (1.0 = no reduction).
+    gain: f32,
+    release_coeff: f32,
+    /// Lowest gain since the last [`take_reduction_db`](Self::take_reduction_db).
+    min_gain: f32,
+}
+
+impl SafetyLimiter {
+    /// Create a disabled limiter at [`DEFAULT_CEILING_DB`].
+    pub fn new(sample_rate: f32) -> Self {
+        let mut limiter = Self {
+            enabled: false,
+            ceiling_db: DEFAULT_CEILING_DB,
+            ceiling: db_to_linear(DEFAULT_CEILING_DB),
+            gain: 1.0,
+            release_coeff: 0.0,
+            min_gain: 1.0,
+        };
+        limiter.set_sample_rate(sample_rate);
+        limiter
+    }
+
+    /// Update the release coefficient for a new sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        let samples = (SAFETY_RELEASE_MS / 1000.0 * sample_rate).max(1.0);
+        self.release_coeff = libm::expf(-1.0 / samples);
+    }
+
+    /// Enable or disable the limiter.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Whether the limiter is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Set the ceiling in dBFS, clamped to [`MIN_CEILING_DB`]`..=0`.
+    pub fn set_ceiling_db(&mut self, ceiling_db: f32) {
+        let ceiling_db = ceiling_db.clamp(MIN_CEILING_DB, 0.0);
+        if ceiling_db != self.ceiling_db {
+            self.ceiling_db = ceiling_db;
+            self.ceiling = db_to_linear(ceiling_db);
+        }
+    }
+
+    /// The ceiling in dBFS.
+    pub fn ceiling_db(&self) -> f32 {
+        self.ceiling_db
+    }
+
+    /// Peak gain reduction in dB (positive) since the last call, for a
+    /// limiting indicator. Resets the peak.
+    pub fn take_reduction_db(&mut self) -> f32 {
+        let reduction = -linear_to_db(self.min_gain);
+        self.min_gain = self.gain;
+        reduction.max(0.0)
+    }
+
+    /// Limit a stereo block in place.
+    pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+        if !self.enabled && self.gain >= 1.0 {
+            return;
+        }
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            let target = if self.enabled {
+                let peak = libm::fabsf(*l).max(libm::fabsf(*r));
+                if peak > self.ceiling {
+                    self.ceiling / peak
+                } else {
+                    1.0
+                }
+            } else {
+                1.0
+            };
+            self.gain = if target < self.gain {
+                target
+            } else {
+                // Release toward the target; snap once inaudible (~0.001 dB)
+                // or once f32 rounding stalls the approach.
+                let gain = target + (self.gain - target) * self.release_coeff;
+                if target - gain < 1e-4 || gain == self.gain {
+                    target
+                } else {
+                    gain
+                }
+            };
+            self.min_gain = self.min_gain.min(self.gain);
+            *l *= self.gain;
+            *r *= self.gain;
+            if self.enabled {
+                *l = l.clamp(-self.ceiling, self.ceiling);
+                *r = r.clamp(-self.ceiling, self.ceiling);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_is_transparent() {
+        let mut limiter = SafetyLimiter::new(48000.0);
+        let (mut left, mut right) = ([4.0_f32; 64], [-4.0_f32; 64]);
+        limiter.process(&mut left, &mut right);
+        assert_eq!(left, [4.0; 64]);
+        assert_eq!(right, [-4.0; 64]);
+        assert_eq!(limiter.take_reduction_db(), 0.0);
+    }
+
+    #[test]
+    fn output_never_exceeds_ceiling() {
+        let mut limiter = SafetyLimiter::new(48000.0);
+        limiter.set_enabled(true);
+        let ceiling = db_to_linear(DEFAULT_CEILING_DB);
+        let (mut left, mut right) = ([0.0_f32; 256], [0.0_f32; 256]);
+        for (i, (l, r)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
+            *l = 8.0 * libm::sinf(i as f32 * 0.1);
+            *r = 0.1;
+        }
+        limiter.process(&mut left, &mut right);
+        assert!(left.iter().chain(&right).all(|s| s.abs() <= ceiling + 1e-6));
+        assert!(limiter.take_reduction_db() > 17.0);
+    }
+
+    #[test]
+    fn quiet_signal_passes_untouched() {
+        let mut limiter = SafetyLimiter::new(48000.0);
+        limiter.set_enabled(true);
+        let (mut left, mut right) = ([0.5_f32; 64], [-0.5_f32; 64]);
+        limiter.process(&mut left, &mut right);
+        assert_eq!(left, [0.5; 64]);
+        assert_eq!(limiter.take_reduction_db(), 0.0);
+    }
+
+    #[test]
+    fn disabling_releases_smoothly() {
+        let mut limiter = SafetyLimiter::new(48000.0);
+        limiter.set_enabled(true);
+        let (mut left, mut right) = ([2.0_f32; 64], [2.0_f32; 64]);
+        limiter.process(&mut left, &mut right);
+
+        limiter.set_enabled(false);
+        let (mut left, mut right) = ([0.5_f32; 64], [0.5_f32; 64]);
+        limiter.process(&mut left, &mut right);
+        assert!(left[0] < 0.5, "still releasing");
+        assert!(left.windows(2).all(|w| w[1] >= w[0]));
+        for _ in 0..1000 {
+            let (mut left, mut right) = ([0.5_f32; 64], [0.5_f32; 64]);
+            limiter.process(&mut left, &mut right);
+        }
+        let (mut left, mut right) = ([0.5_f32; 64], [0.5_f32; 64]);
+        limiter.process(&mut left, &mut right);
+        assert_eq!(left, [0.5; 64], "transparent after release");
+    }
+}
@@ -20,11 +20,32 @@
 //! output), preserving the filter's frequency response at low levels while
 //! adding harmonic richness at high drive.
 //!
+//! # Resonance Drive
+//!
+//! A second nonlinearity, [`set_resonance_drive`](StateVariableFilter::set_resonance_drive),
+//! raises the damping term `k` with the bandpass level, so resonance
+//! compresses as it gets louder: quiet signals keep the full peak, loud ones
+//! squelch instead of ringing out — the acid bassline behaviour of diode and
+//! transistor ladders. The level comes from the integrator state, so the
+//! update stays a single zero-delay solve per sample.
+//!
+//! # Output Morphing
+//!
+//! [`SvfOutput::Morph`] crossfades between outputs with one continuous
+//! control, [`set_morph`](StateVariableFilter::set_morph): 0 = lowpass,
+//! 1 = bandpass, 2 = highpass, 3 = notch, blending linearly in between.
+//!
 //! # Four-Pole Mode
 //!
 //! [`FourPoleSvf`] cascades two SVF stages for 24 dB/oct slopes. The second
 //! stage tracks the first stage's cutoff. Resonance is split equally between
-//! stages to prevent excessive ringing.
+//! stages to prevent excessive ringing. [`SvfSlope::Db12`] runs the first
+//! stage alone with the full resonance.
+//!
+//! # Four-Lane Banks
+//!
+//! [`Svf4`] runs four linear SVFs in the lanes of an [`F32x4`], each with
+//! its own cutoff and resonance, for filter banks and polyphonic voices.
 //!
 //! # Performance
 //!
@@ -44,9 +65,11 @@ use libm::tanf;
 use crate::Effect;
 use crate::fast_math::{fast_tan, fast_tanh};
 use crate::flush_denormal;
+use crate::simd::F32x4;
 
 /// State Variable Filter output type.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SvfOutput {
     /// Low-pass filter output — passes frequencies below the cutoff.
     #[default]
@@ -57,6 +80,34 @@ pub enum SvfOutput {
     Bandpass,
     /// Notch (band-reject) filter output — rejects frequencies near the cutoff.
     Notch,
+    /// Continuous blend of the other outputs, set by the morph position.
+    Morph,
+}
+
+/// Rolloff of a [`FourPoleSvf`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SvfSlope {
+    /// 12 dB/oct — first stage only.
+    Db12,
+    /// 24 dB/oct — both stages.
+    #[default]
+    Db24,
+}
+
+/// Blend `(lp, hp, bp, notch)` for a morph position in \[0, 3\].
+///
+/// 0 = lowpass, 1 = bandpass, 2 = highpass, 3 = notch.
+#[inline]
+fn morph_outputs(morph: f32, (lp, hp, bp, notch): (f32, f32, f32, f32)) -> f32 {
+    let m = morph.clamp(0.0, 3.0);
+    if m < 1.0 {
+        lp + (bp - lp) * m
+    } else if m < 2.0 {
+        bp + (hp - bp) * (m - 1.0)
+    } else {
+        hp + (notch - hp) * (m - 2.0)
+    }
 }
 
 /// State Variable Filter (2-pole, 12 dB/oct).
@@ -70,6 +121,8 @@ pub enum SvfOutput {
 /// - `cutoff`: Filter cutoff frequency in Hz (20.0 to sr×0.49, default 1000.0)
 /// - `resonance`: Q factor (0.5 to 20.0, default 0.707)
 /// - `drive`: Nonlinear saturation amount (0.0 to 1.0, default 0.0)
+/// - `resonance_drive`: Level-dependent resonance compression (0.0 to 1.0, default 0.0)
+/// - `morph`: Output blend for [`SvfOutput::Morph`] (0.0 to 3.0, default 0.0)
 /// - `output_type`: Which filter output to use (default `Lowpass`)
 ///
 /// # Example
@@ -101,6 +154,8 @@ pub struct StateVariableFilter {
     resonance: f32,
     output_type: SvfOutput,
     drive: f32,
+    resonance_drive: f32,
+    morph: f32,
 }
 
 impl Default for StateVariableFilter {
@@ -125,6 +180,8 @@ impl StateVariableFilter {
             resonance: 0.707,
             output_type: SvfOutput::Lowpass,
             drive: 0.0,
+            resonance_drive: 0.0,
+            morph: 0.0,
         };
         svf.update_g();
         svf.update_k();
@@ -191,6 +248,35 @@ impl StateVariableFilter {
         self.drive
     }
 
+    /// Set resonance drive amount.
+    ///
+    /// Range: 0.0 (linear resonance) to 1.0. Adds up to
+    /// `2 × resonance_drive × tanh(level)` to the damping term, where `level`
+    /// is the normalised bandpass level held in the integrator state. Small
+    /// signals see the full resonant peak; loud ones are held back, giving
+    /// the squelchy, self-limiting resonance of acid basslines.
+    pub fn set_resonance_drive(&mut self, amount: f32) {
+        self.resonance_drive = amount.clamp(0.0, 1.0);
+    }
+
+    /// Get current resonance drive amount (0.0–1.0).
+    pub fn resonance_drive(&self) -> f32 {
+        self.resonance_drive
+    }
+
+    /// Set the morph position used by [`SvfOutput::Morph`].
+    ///
+    /// Range: 0.0 to 3.0. Values are clamped. 0 = lowpass, 1 = bandpass,
+    /// 2 = highpass, 3 = notch; positions in between crossfade linearly.
+    pub fn set_morph(&mut self, morph: f32) {
+        self.morph = morph.clamp(0.0, 3.0);
+    }
+
+    /// Get current morph position (0.0–3.0).
+    pub fn morph(&self) -> f32 {
+        self.morph
+    }
+
     /// Recompute the `g` coefficient from the current cutoff and sample rate.
     ///
     /// Uses `fast_tan` for cutoff < 10 kHz (< 0.1% error in that range),
@@ -218,8 +304,17 @@ impl StateVariableFilter {
     /// integrator state. The saturation affects only the state update, not the
     /// direct output, preserving the filter's frequency response at low levels.
     pub fn process_all(&mut self, input: f32) -> (f32, f32, f32, f32) {
+        // Resonance drive: damping grows with the bandpass level in the
+        // integrator state (ic1eq ≈ 2·bp, scaled by k to unit peak gain).
+        let k = if self.resonance_drive > 0.0 {
+            let level = (0.5 * self.k * self.ic1eq).abs();
+            self.k + 2.0 * self.resonance_drive * fast_tanh(level)
+        } else {
+            self.k
+        };
+
         let v3 = input - self.ic2eq;
-        let v1 = (self.g * v3 + self.ic1eq) / (1.0 + self.g * (self.g + self.k));
+        let v1 = (self.g * v3 + self.ic1eq) / (1.0 + self.g * (self.g + k));
         let v2 = self.ic2eq + self.g * v1;
 
         // Nonlinear drive: saturate the bandpass integrator state.
@@ -239,7 +334,7 @@ impl StateVariableFilter {
 
         let lp = v2;
         let bp = v1;
-        let hp = input - self.k * v1 - v2;
+        let hp = input - k * v1 - v2;
         let notch = lp + hp;
 
         (lp, hp, bp, notch)
@@ -255,6 +350,7 @@ impl Effect for StateVariableFilter {
             SvfOutput::Highpass => hp,
             SvfOutput::Bandpass => bp,
             SvfOutput::Notch => notch,
+            SvfOutput::Morph => morph_outputs(self.morph, (lp, hp, bp, notch)),
         }
     }
 
@@ -269,6 +365,219 @@ impl Effect for StateVariableFilter {
     }
 }
 
+/// Four linear [`StateVariableFilter`]s processed in parallel.
+///
+/// Each lane of an [`F32x4`] has its own cutoff, resonance and integrator
+/// state; the sample rate, output type and morph position are shared. Use it
+/// for filter banks and for per-voice filters in a polyphonic engine: one
+/// [`process`](Self::process) call advances all four.
+///
+/// Drive and resonance drive are not supported. The solve multiplies by a
+/// precomputed reciprocal instead of dividing each sample, so lanes match a
+/// scalar [`StateVariableFilter`] to within float rounding rather than bit
+/// for bit.
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_core::{F32x4, Svf4, SvfOutput};
+///
+/// let mut bank = Svf4::new(48000.0);
+/// for (lane, hz) in [250.0, 1000.0, 4000.0, 8000.0].into_iter().enumerate() {
+///     bank.set_cutoff(lane, hz);
+///     bank.set_resonance(lane, 4.0);
+/// }
+/// bank.set_output_type(SvfOutput::Bandpass);
+///
+/// let mut block = [F32x4::splat(0.25); 64];
+/// bank.process_block(&mut block);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Svf4 {
+    ic1eq: F32x4,
+    ic2eq: F32x4,
+    k: F32x4,
+    /// `1 / (1 + g·(g + k))`.
+    a1: F32x4,
+    /// `g·a1`.
+    a2: F32x4,
+    /// `g·a2`.
+    a3: F32x4,
+    sample_rate: f32,
+    cutoff: [f32; 4],
+    resonance: [f32; 4],
+    output_type: SvfOutput,
+    morph: f32,
+}
+
+impl Default for Svf4 {
+    fn default() -> Self {
+        Self::new(48000.0)
+    }
+}
+
+impl Svf4 {
+    /// Create four SVFs at the given sample rate, each with the
+    /// [`StateVariableFilter::new`] defaults.
+    pub fn new(sample_rate: f32) -> Self {
+        let mut svf = Self {
+            ic1eq: F32x4::ZERO,
+            ic2eq: F32x4::ZERO,
+            k: F32x4::ZERO,
+            a1: F32x4::ZERO,
+            a2: F32x4::ZERO,
+            a3: F32x4::ZERO,
+            sample_rate,
+            cutoff: [1000.0; 4],
+            resonance: [0.707; 4],
+            output_type: SvfOutput::Lowpass,
+            morph: 0.0,
+        };
+        for lane in 0..4 {
+            svf.update_lane(lane);
+        }
+        svf
+    }
+
+    /// Set the cutoff of one lane in Hz, clamped as in
+    /// [`StateVariableFilter::set_cutoff`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= 4`.
+    pub fn set_cutoff(&mut self, lane: usize, freq: f32) {
+        self.cutoff[lane] = freq.clamp(20.0, self.sample_rate * 0.49);
+        self.update_lane(lane);
+    }
+
+    /// Cutoff of one lane in Hz.
+    pub fn cutoff(&self, lane: usize) -> f32 {
+        self.cutoff[lane]
+    }
+
+    /// Set the resonance (Q) of one lane, clamped to 0.5–20.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= 4`.
+    pub fn set_resonance(&mut self, lane: usize, q: f32) {
+        self.resonance[lane] = q.clamp(0.5, 20.0);
+        self.update_lane(lane);
+    }
+
+    /// Resonance (Q) of one lane.
+    pub fn resonance(&self, lane: usize) -> f32 {
+        self.resonance[lane]
+    }
+
+    /// Set the output returned by [`process`](Self::process), for all lanes.
+    pub fn set_output_type(&mut self, output_type: SvfOutput) {
+        self.output_type = output_type;
+    }
+
+    /// Current output type.
+    pub fn output_type(&self) -> SvfOutput {
+        self.output_type
+    }
+
+    /// Set the morph position used by [`SvfOutput::Morph`], for all lanes.
+    pub fn set_morph(&mut self, morph: f32) {
+        self.morph = morph.clamp(0.0, 3.0);
+    }
+
+    /// Current morph position (0.0–3.0).
+    pub fn morph(&self) -> f32 {
+        self.morph
+    }
+
+    /// Update the sample rate and recompute every lane's coefficients.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        for lane in 0..4 {
+            self.update_lane(lane);
+        }
+    }
+
+    /// Clear the integrator state of all lanes.
+    pub fn reset(&mut self) {
+        self.ic1eq = F32x4::ZERO;
+        self.ic2eq = F32x4::ZERO;
+    }
+
+    /// Recompute the coefficients of one lane, using the same
+    /// `fast_tan`/`tanf` split as [`StateVariableFilter`].
+    fn update_lane(&mut self, lane: usize) {
+        let cutoff = self.cutoff[lane];
+        let arg = PI * cutoff / self.sample_rate;
+        let g = if cutoff < 10_000.0 {
+            fast_tan(arg)
+        } else {
+            tanf(arg)
+        };
+        let k = 1.0 / self.resonance[lane];
+        let a1 = 1.0 / (1.0 + g * (g + k));
+        self.k.set_lane(lane, k);
+        self.a1.set_lane(lane, a1);
+        self.a2.set_lane(lane, g * a1);
+        self.a3.set_lane(lane, g * g * a1);
+    }
+
+    /// Process one sample per lane and return all outputs
+    /// (lowpass, highpass, bandpass, notch).
+    ///
+    /// Uses the precomputed-reciprocal form of the TPT solve, so `v1` and
+    /// `v2` come out of independent multiply-adds with no per-sample divide.
+    #[inline]
+    pub fn process_all(&mut self, input: F32x4) -> (F32x4, F32x4, F32x4, F32x4) {
+        let two = F32x4::splat(2.0);
+        let v3 = input - self.ic2eq;
+        let v1 = self.a1 * self.ic1eq + self.a2 * v3;
+        let v2 = self.ic2eq + self.a2 * self.ic1eq + self.a3 * v3;
+
+        self.ic1eq = (two * v1 - self.ic1eq).flush_denormal();
+        self.ic2eq = (two * v2 - self.ic2eq).flush_denormal();
+
+        let lp = v2;
+        let bp = v1;
+        let hp = input - self.k * v1 - v2;
+        let notch = lp + hp;
+
+        (lp, hp, bp, notch)
+    }
+
+    /// Process one sample per lane and return the selected output.
+    #[inline]
+    pub fn process(&mut self, input: F32x4) -> F32x4 {
+        let (lp, hp, bp, notch) = self.process_all(input);
+        match self.output_type {
+            SvfOutput::Lowpass => lp,
+            SvfOutput::Highpass => hp,
+            SvfOutput::Bandpass => bp,
+            SvfOutput::Notch => notch,
+            SvfOutput::Morph => F32x4::from_array(core::array::from_fn(|i| {
+                morph_outputs(
+                    self.morph,
+                    (lp.lane(i), hp.lane(i), bp.lane(i), notch.lane(i)),
+                )
+            })),
+        }
+    }
+
+    /// Process a block in place, one [`F32x4`] frame per sample.
+    ///
+    /// The output selection is hoisted out of the loop, so the lowpass,
+    /// highpass, bandpass and notch paths run branch-free per sample.
+    pub fn process_block(&mut self, frames: &mut [F32x4]) {
+        match self.output_type {
+            SvfOutput::Lowpass => frames.iter_mut().for_each(|f| *f = self.process_all(*f).0),
+            SvfOutput::Highpass => frames.iter_mut().for_each(|f| *f = self.process_all(*f).1),
+            SvfOutput::Bandpass => frames.iter_mut().for_each(|f| *f = self.process_all(*f).2),
+            SvfOutput::Notch => frames.iter_mut().for_each(|f| *f = self.process_all(*f).3),
+            SvfOutput::Morph => frames.iter_mut().for_each(|f| *f = self.process(*f)),
+        }
+    }
+}
+
 /// Four-pole (24 dB/oct) State Variable Filter.
 ///
 /// Cascades two [`StateVariableFilter`] stages for steeper rolloff slopes.
@@ -276,15 +585,18 @@ impl Effect for StateVariableFilter {
 /// equally between stages (`Q_stage = Q_total × 0.5`) to prevent excessive
 /// ringing at high resonance values.
 ///
-/// Both stages inherit the nonlinear drive setting, applied independently
-/// in each stage's integrator feedback path.
+/// Both stages inherit the nonlinear drive, resonance drive, and morph
+/// settings, applied independently in each stage.
 ///
 /// ## Parameters
 ///
 /// - `cutoff`: Filter cutoff frequency in Hz (20.0 to sr×0.49, default 1000.0)
 /// - `resonance`: Combined Q factor (0.5 to 20.0, default 0.707) — each stage
-///   receives half
+///   receives half at 24 dB/oct, the single stage all of it at 12 dB/oct
 /// - `drive`: Nonlinear saturation amount (0.0 to 1.0, default 0.0)
+/// - `resonance_drive`: Level-dependent resonance compression (0.0 to 1.0, default 0.0)
+/// - `morph`: Output blend for [`SvfOutput::Morph`] (0.0 to 3.0, default 0.0)
+/// - `slope`: [`SvfSlope`] (default 24 dB/oct)
 /// - `output_type`: Which filter output to use (default `Lowpass`)
 ///
 /// # Example
@@ -313,6 +625,8 @@ pub struct FourPoleSvf {
     output_type: SvfOutput,
     /// Combined resonance before splitting.
     resonance: f32,
+    /// 12 dB/oct bypasses `stage2`.
+    slope: SvfSlope,
 }
 
 impl Default for FourPoleSvf {
@@ -331,6 +645,7 @@ impl FourPoleSvf {
             stage2: StateVariableFilter::new(sample_rate),
             output_type: SvfOutput::Lowpass,
             resonance: 0.707,
+            slope: SvfSlope::Db24,
         };
         svf.update_resonance();
         svf
@@ -351,8 +666,9 @@ impl FourPoleSvf {
 
     /// Set combined resonance (Q factor).
     ///
-    /// Range: 0.5 to 20.0. Each stage receives `Q × 0.5`, clamped to the
-    /// per-stage minimum of 0.5.
+    /// Range: 0.5 to 20.0. At 24 dB/oct each stage receives `Q × 0.5`,
+    /// clamped to the per-stage minimum of 0.5; at 12 dB/oct the single
+    /// stage receives `Q`.
     pub fn set_resonance(&mut self, q: f32) {
         self.resonance = q.clamp(0.5, 20.0);
         self.update_resonance();
@@ -389,18 +705,71 @@ impl FourPoleSvf {
         self.stage1.drive()
     }
 
-    /// Distribute combined resonance equally to both stages.
+    /// Set resonance drive for both stages.
+    ///
+    /// Range: 0.0 to 1.0. See [`StateVariableFilter::set_resonance_drive`].
+    pub fn set_resonance_drive(&mut self, amount: f32) {
+        self.stage1.set_resonance_drive(amount);
+        self.stage2.set_resonance_drive(amount);
+    }
+
+    /// Get current resonance drive amount (0.0–1.0).
+    pub fn resonance_drive(&self) -> f32 {
+        self.stage1.resonance_drive()
+    }
+
+    /// Set the morph position for both stages.
+    ///
+    /// Range: 0.0 to 3.0. Used when the output type is [`SvfOutput::Morph`];
+    /// both stages blend the same way, so the slope doubles along the whole
+    /// morph range.
+    pub fn set_morph(&mut self, morph: f32) {
+        self.stage1.set_morph(morph);
+        self.stage2.set_morph(morph);
+    }
+
+    /// Get current morph position (0.0–3.0).
+    pub fn morph(&self) -> f32 {
+        self.stage1.morph()
+    }
+
+    /// Select 12 or 24 dB/oct.
+    ///
+    /// Switching to 24 dB/oct clears the second stage, which is idle at
+    /// 12 dB/oct.
+    pub fn set_slope(&mut self, slope: SvfSlope) {
+        if slope != self.slope {
+            self.slope = slope;
+            self.stage2.reset();
+            self.update_resonance();
+        }
+    }
+
+    /// Get current slope.
+    pub fn slope(&self) -> SvfSlope {
+        self.slope
+    }
+
+    /// Distribute combined resonance to the active stages.
     fn update_resonance(&mut self) {
-        let q_stage = self.resonance * 0.5;
-        self.stage1.set_resonance(q_stage);
-        self.stage2.set_resonance(q_stage);
+        match self.slope {
+            SvfSlope::Db12 => self.stage1.set_resonance(self.resonance),
+            SvfSlope::Db24 => {
+                let q_stage = self.resonance * 0.5;
+                self.stage1.set_resonance(q_stage);
+                self.stage2.set_resonance(q_stage);
+            }
+        }
     }
 }
 
 impl Effect for FourPoleSvf {
     fn process(&mut self, input: f32) -> f32 {
         let mid = self.stage1.process(input);
-        self.stage2.process(mid)
+        match self.slope {
+            SvfSlope::Db12 => mid,
+            SvfSlope::Db24 => self.stage2.process(mid),
+        }
     }
 
     fn reset(&mut self) {
@@ -420,6 +789,66 @@ mod tests {
 
     // ---- StateVariableFilter (2-pole) ----
 
+    #[test]
+    fn svf4_lanes_match_scalar() {
+        let settings = [
+            (200.0, 0.707),
+            (1500.0, 4.0),
+            (6000.0, 1.0),
+            (12_000.0, 8.0),
+        ];
+        let mut scalar: [StateVariableFilter; 4] = core::array::from_fn(|i| {
+            let mut svf = StateVariableFilter::new(48000.0);
+            svf.set_cutoff(settings[i].0);
+            svf.set_resonance(settings[i].1);
+            svf
+        });
+        let mut bank = Svf4::new(48000.0);
+        for (lane, &(hz, q)) in settings.iter().enumerate() {
+            bank.set_cutoff(lane, hz);
+            bank.set_resonance(lane, q);
+        }
+
+        for n in 0..1000 {
+            let x = libm::sinf(n as f32 * 0.11) + if n % 50 == 0 { 0.8 } else { 0.0 };
+            let (lp, hp, bp, notch) = bank.process_all(F32x4::splat(x));
+            for (lane, svf) in scalar.iter_mut().enumerate() {
+                let want = svf.process_all(x);
+                let got = (
+                    lp.lane(lane),
+                    hp.lane(lane),
+                    bp.lane(lane),
+                    notch.lane(lane),
+                );
+                for (g, w) in [
+                    (got.0, want.0),
+                    (got.1, want.1),
+                    (got.2, want.2),
+                    (got.3, want.3),
+                ] {
+                    assert!(
+                        (g - w).abs() < 1e-4,
+                        "lane {lane} n {n}: {got:?} vs {want:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn svf4_block_matches_per_sample() {
+        let mut a = Svf4::new(48000.0);
+        a.set_output_type(SvfOutput::Highpass);
+        a.set_cutoff(2, 3000.0);
+        let mut b = a.clone();
+
+        let mut block: [F32x4; 256] =
+            core::array::from_fn(|n| F32x4::splat(libm::sinf(n as f32 * 0.3)));
+        let expected = block.map(|x| b.process(x));
+        a.process_block(&mut block);
+        assert_eq!(block, expected);
+    }
+
     #[test]
     fn test_svf_lowpass_dc() {
         let mut svf = StateVariableFilter::new(48000.0);
@@ -735,4 +1164,120 @@ mod tests {
         assert_eq!(svf4.output_type(), SvfOutput::Lowpass);
         assert_eq!(svf4.drive(), 0.0);
     }
+
+    // ---- Morph / slope / resonance drive ----
+
+    /// Steady-state peak of a sine at `freq` through `f`.
+    fn sine_peak(freq: f32, amplitude: f32, mut f: impl FnMut(f32) -> f32) -> f32 {
+        let omega = core::f32::consts::TAU * freq / 48000.0;
+        let mut peak: f32 = 0.0;
+        for i in 0..24000 {
+            let out = f(amplitude * libm::sinf(i as f32 * omega));
+            if i >= 12000 {
+                peak = peak.max(out.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_morph_endpoints_match_outputs() {
+        let mut morph = StateVariableFilter::new(48000.0);
+        morph.set_output_type(SvfOutput::Morph);
+        let mut reference = StateVariableFilter::new(48000.0);
+
+        for (position, expected) in [(0.0, 0usize), (1.0, 2), (2.0, 1), (3.0, 3)] {
+            morph.set_morph(position);
+            morph.reset();
+            reference.reset();
+            for i in 0..200 {
+                let input = libm::sinf(i as f32 * 0.3);
+                let outputs = reference.process_all(input);
+                let want = [outputs.0, outputs.1, outputs.2, outputs.3][expected];
+                assert!(
+                    (morph.process(input) - want).abs() < 1e-6,
+                    "morph {position}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_morph_blends_between_outputs() {
+        let mut morph = StateVariableFilter::new(48000.0);
+        morph.set_output_type(SvfOutput::Morph);
+        morph.set_morph(1.5);
+        let mut reference = StateVariableFilter::new(48000.0);
+        for i in 0..200 {
+            let input = libm::sinf(i as f32 * 0.3);
+            let (_, hp, bp, _) = reference.process_all(input);
+            assert!((morph.process(input) - 0.5 * (bp + hp)).abs() < 1e-6);
+        }
+
+        morph.set_morph(7.0);
+        assert_eq!(morph.morph(), 3.0);
+    }
+
+    #[test]
+    fn test_four_pole_slope_selection() {
+        let mut svf = FourPoleSvf::new(48000.0);
+        svf.set_cutoff(500.0);
+        svf.set_resonance(2.0);
+        assert_eq!(svf.slope(), SvfSlope::Db24);
+
+        let steep = sine_peak(4000.0, 1.0, |x| svf.process(x));
+        svf.set_slope(SvfSlope::Db12);
+        // The single stage takes the full resonance.
+        assert!((svf.stage1.resonance() - 2.0).abs() < 1e-6);
+        svf.reset();
+        let gentle = sine_peak(4000.0, 1.0, |x| svf.process(x));
+
+        // Three octaves up: about 36 dB more attenuation at 24 dB/oct.
+        assert!(steep < gentle * 0.05, "12 dB {gentle}, 24 dB {steep}");
+
+        // A 12 dB/oct FourPoleSvf matches a single StateVariableFilter.
+        let mut single = StateVariableFilter::new(48000.0);
+        single.set_cutoff(500.0);
+        single.set_resonance(2.0);
+        svf.reset();
+        for i in 0..200 {
+            let input = libm::sinf(i as f32 * 0.1);
+            assert!((svf.process(input) - single.process(input)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_resonance_drive_compresses_loud_resonance() {
+        let make = |amount: f32| {
+            let mut svf = StateVariableFilter::new(48000.0);
+            svf.set_cutoff(1000.0);
+            svf.set_resonance(15.0);
+            svf.set_resonance_drive(amount);
+            svf
+        };
+
+        // Quiet input: resonant peak is essentially untouched.
+        let (mut clean, mut driven) = (make(0.0), make(1.0));
+        let quiet_clean = sine_peak(1000.0, 0.001, |x| clean.process(x));
+        let quiet_driven = sine_peak(1000.0, 0.001, |x| driven.process(x));
+        assert!(
+            quiet_driven > quiet_clean * 0.9,
+            "{quiet_clean} vs {quiet_driven}"
+        );
+
+        // Loud input: the peak is held back.
+        let (mut clean, mut driven) = (make(0.0), make(1.0));
+        let loud_clean = sine_peak(1000.0, 0.5, |x| clean.process(x));
+        let loud_driven = sine_peak(1000.0, 0.5, |x| driven.process(x));
+        assert!(
+            loud_driven < loud_clean * 0.5,
+            "{loud_clean} vs {loud_driven}"
+        );
+        assert!(loud_driven.is_finite());
+
+        let mut svf = make(3.0);
+        assert_eq!(svf.resonance_drive(), 1.0);
+        svf.set_resonance_drive(-1.0);
+        assert_eq!(svf.resonance_drive(), 0.0);
+    }
 }
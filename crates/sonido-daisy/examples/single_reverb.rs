@@ -12,7 +12,7 @@
 //! | KNOB_3       | PA7          | Damping (0–100%)                              |
 //! | KNOB_4       | PA6          | Mix / dry-wet (0–100%)                        |
 //! | KNOB_5       | PC1          | Output level (−20 to +6 dB)                   |
-//! | KNOB_6       | PC4          | Predelay (0–100 ms)                           |
+//! | KNOB_6       | PC4          | Predelay (0–200 ms)                           |
 //! | TOGGLE_1 up  | PB4          | Width: Up=Wide (100%)                         |
 //! | TOGGLE_1 mid | (neither)    | Width: Mid=Normal (50%)                       |
 //! | TOGGLE_1 dn  | PB5          | Width: Down=Mono (0%)                         |
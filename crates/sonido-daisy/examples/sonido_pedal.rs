@@ -11,6 +11,10 @@
 //! | **T2** | A mode (edit)  | B mode (edit)  | Morph (FS1/FS2 ramp)        |
 //! | **T3** | Linear 1→2→3   | Parallel split | Fan 1→split→[2,3]→merge     |
 //!
+//! In A/B modes, FS2 long-press on a focused Gate starts its learn: play
+//! nothing for a few seconds and the threshold is set just above the noise.
+//! LED 2 flashes while it listens.
+//!
 //! # Build & Flash
 //!
 //! ```bash
@@ -44,7 +48,7 @@ use sonido_daisy::{
     BLOCK_SIZE, ClockProfile, SAMPLE_RATE, f32_to_u24, heartbeat, led::UserLed, u24_to_f32,
 };
 use sonido_effects::{
-    BitcrusherKernel, ChorusKernel, DelayKernel, DistortionKernel, FilterKernel,
+    BitcrusherKernel, ChorusKernel, DelayKernel, DistortionKernel, FilterKernel, GateKernel,
     PhaserKernel, ReverbKernel, RingModKernel,
 };
 use sonido_platform::knob_mapping::{self, NULL_KNOB};
@@ -130,6 +134,8 @@ struct CallbackState {
     fs1_was_pressed: bool,
     fs2_was_pressed: bool,
     led_envelope: f32,
+    /// Slot whose gate is learning its threshold (FS2 long-press), if any.
+    learn_node: Option<usize>,
 }
 
 /// SAFETY: Only accessed from the audio callback (single-threaded, no ISR).
@@ -265,7 +271,7 @@ fn toggle_to_topology(val: u8) -> Topology {
 // ── Effect factory ──────────────────────────────────────────────────────────
 
 /// Create an effect by its scroll index.
-/// Index 0 = null/bypass (returns None), 1–8 = EFFECT_IDS[0..8].
+/// Index 0 = null/bypass (returns None), 1–9 = EFFECT_IDS[0..9].
 fn create_effect(idx: usize, sr: f32) -> Option<Box<dyn EffectWithParams + Send>> {
     match idx {
         0 => None, // null / bypass
@@ -277,6 +283,7 @@ fn create_effect(idx: usize, sr: f32) -> Option<Box<dyn EffectWithParams + Send>
         6 => Some(Box::new(Adapter::new_direct(ReverbKernel::new(sr), sr))),
         7 => Some(Box::new(Adapter::new_direct(RingModKernel::new(sr), sr))),
         8 => Some(Box::new(Adapter::new_direct(FilterKernel::new(sr), sr))),
+        9 => Some(Box::new(Adapter::new_direct(GateKernel::new(sr), sr))),
         _ => None,
     }
 }
@@ -650,6 +657,7 @@ fn init_statics(focused: usize, ab: AbMode, topo: Topology) {
             fs1_was_pressed: false,
             fs2_was_pressed: false,
             led_envelope: 0.0,
+            learn_node: None,
         });
     }
 }
@@ -866,7 +874,11 @@ async fn main(spawner: embassy_executor::Spawner) {
                             NEEDS_REBUILD.store(true, Ordering::Release);
                             cb.pickup_locked = [true; 6];
                         }
+                        if cb.fs2_was_pressed && !fs2_pressed && cb.fs2_held >= TAP_LIMIT {
+                            start_gate_learn(cb);
+                        }
                     }
+                    poll_gate_learn(cb);
 
                     if !fs1_pressed { cb.fs1_held = 0; }
                     if !fs2_pressed { cb.fs2_held = 0; }
@@ -944,6 +956,10 @@ async fn main(spawner: embassy_executor::Spawner) {
                     if BYPASSED.load(Ordering::Relaxed) {
                         CONTROLS.write_led(0, 0.0);
                         CONTROLS.write_led(1, 0.0);
+                    } else if cb.learn_node.is_some() {
+                        // ~5 Hz flash: toggle every 10 control polls.
+                        let phase = cb.poll_counter / (effect_slot::CONTROL_POLL_EVERY * 10);
+                        CONTROLS.write_led(1, if phase % 2 == 0 { 1.0 } else { 0.0 });
                     } else if cb.led_blink_remaining > 0 {
                         cb.led_blink_timer += 1;
                         if cb.led_blink_timer >= 10 { cb.led_blink_timer = 0; cb.led_blink_remaining -= 1; }
@@ -1001,6 +1017,72 @@ fn handle_both_release_toggle(cb: &mut CallbackState, fs1: bool, fs2: bool) {
     }
 }
 
+// ── Gate learn ──────────────────────────────────────────────────────────────
+
+/// `GateParams` indices used by the learn gesture.
+const GATE_THRESHOLD: usize = 0;
+const GATE_LEARN: usize = 12;
+const GATE_LEARN_PROGRESS: usize = 13;
+const GATE_LEARNED_THRESHOLD: usize = 14;
+
+/// The node in `slot`, if that slot holds a gate.
+fn gate_node(nodes: &[NodeState; NUM_SLOTS], slot: usize) -> Option<NodeId> {
+    let eff_idx = nodes[slot].effect_index.filter(|&i| i > 0)?;
+    if EFFECT_IDS[eff_idx - 1] != "gate" {
+        return None;
+    }
+    unsafe { NODE_IDS_STORAGE[slot] }
+}
+
+/// Start learning on the focused slot's gate (no-op for other effects).
+fn start_gate_learn(cb: &mut CallbackState) {
+    let nodes = unsafe { NODES_STORAGE.as_ref().unwrap() };
+    let graph = unsafe { GRAPH_STORAGE.as_mut().unwrap() };
+    if let Some(nid) = gate_node(nodes, cb.focused_node)
+        && let Some(effect) = graph.effect_with_params_mut(nid)
+    {
+        effect.effect_set_param(GATE_LEARN, 1.0);
+        cb.learn_node = Some(cb.focused_node);
+    }
+}
+
+/// Finish a gate learn: once the gate reports a result, write the learned
+/// threshold into the effect and the current A/B snapshot, switch learn off,
+/// and blink LED 2. Drops the learn if the slot no longer holds a learning
+/// gate (effect scrolled away, snapshot applied over it).
+fn poll_gate_learn(cb: &mut CallbackState) {
+    let Some(slot) = cb.learn_node else {
+        return;
+    };
+    let nodes = unsafe { NODES_STORAGE.as_mut().unwrap() };
+    let graph = unsafe { GRAPH_STORAGE.as_mut().unwrap() };
+    let Some(effect) = gate_node(nodes, slot).and_then(|nid| graph.effect_with_params_mut(nid))
+    else {
+        cb.learn_node = None;
+        return;
+    };
+    if effect.effect_get_param(GATE_LEARN) < 0.5 {
+        cb.learn_node = None;
+        return;
+    }
+    if effect.effect_get_param(GATE_LEARN_PROGRESS) < 1.0 {
+        return;
+    }
+
+    let threshold = effect.effect_get_param(GATE_LEARNED_THRESHOLD);
+    effect.effect_set_param(GATE_THRESHOLD, threshold);
+    effect.effect_set_param(GATE_LEARN, 0.0);
+    let mut learned = [(NULL_KNOB, 0.0); 6];
+    learned[0] = (GATE_THRESHOLD as u8, threshold);
+    nodes[slot].update_snapshot(&cb.ab_mode, &learned);
+
+    cb.learn_node = None;
+    // The threshold knob no longer matches; make it pick up the new value.
+    cb.pickup_locked = [true; 6];
+    cb.led_blink_remaining = 4;
+    cb.led_blink_timer = 0;
+}
+
 /// Write the DFU-timeout magic into STM32H7 Backup SRAM and reset, which causes the
 /// Daisy Seed bootloader to remain in DFU mode indefinitely on the next boot.
 /// See RM0433 §8.11 (AHB4ENR) / §7.4 (PWR CR1) and the libDaisy bootloader source.
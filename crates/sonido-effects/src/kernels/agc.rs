@@ -0,0 +1,530 @@
+//! AGC kernel — slow automatic gain control toward a target RMS or LUFS level.
+//!
+//! `AgcKernel` owns DSP state (K-weighting filters, windowed RMS detector,
+//! current gain). Parameters are received via `&AgcParams` each sample.
+//! Deployed via [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin,
+//! or called directly on embedded targets.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! Input ──┬──────────────────────────────────────────── × gain ── × output
+//!         │                                                ▲
+//!         └─► [K-weighting] ─► 400 ms RMS (linked) ─► gain rider
+//! ```
+//!
+//! # Gain Rider
+//!
+//! The detector measures the level over a 400 ms sliding window — the
+//! EBU R128 momentary window — either as plain RMS or K-weighted (LUFS). The
+//! gain needed to reach the target is
+//!
+//! ```text
+//! wanted = clamp(target − level, −MAX_CUT_DB, max_gain)
+//! ```
+//!
+//! and the applied gain moves toward it at no more than `speed` dB per second.
+//! The rate limit is what makes the AGC elastic rather than a compressor:
+//! phrasing and accents survive, while the overall level drifts to the target
+//! over seconds. Below the gate threshold the gain is frozen, so pauses and
+//! noise floor are not pulled up.
+//!
+//! Both channels share one detector and one gain, so the stereo image is kept.
+//!
+//! # LUFS Detection
+//!
+//! K-weighting follows ITU-R BS.1770: a +4 dB high shelf at 1.5 kHz and a
+//! 38 Hz highpass, then mean power across channels with the −0.691 dB offset.
+//! The shelf is the RBJ S = 1 design, within a fraction of a dB of the
+//! standard's prefilter — close enough to ride gain, not a certified meter.
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (adapter handles smoothing)
+//! let adapter = Adapter::new(AgcKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing)
+//! let mut kernel = AgcKernel::new(48000.0);
+//! let params = AgcParams::from_knobs(target, speed, max_gain, gate, detector, output);
+//! let (l, r) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use super::shelving_eq::high_shelf_coefficients;
+use sonido_core::biquad::highpass_coefficients;
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    Biquad, DetectionMode, EnvelopeFollower, ParamDescriptor, ParamFlags, ParamId, ParamScale,
+    fast_db_to_linear, math::linear_to_db,
+};
+
+// ── Constants ─────────────────────────────────────────────────────────────────
+
+/// Detector window in milliseconds (EBU R128 momentary loudness).
+const WINDOW_MS: f32 = 400.0;
+
+/// Largest cut the AGC will apply, in dB.
+const MAX_CUT_DB: f32 = 30.0;
+
+/// BS.1770 loudness offset for K-weighted mean power, in dB.
+const LUFS_OFFSET_DB: f32 = -0.691;
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`AgcKernel`].
+///
+/// All values are in **user-facing units** — the same units shown in GUIs and
+/// stored in presets. The kernel converts internally as needed.
+///
+/// ## Parameter Table
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `target_db` | dB | −40–−6 | −18.0 |
+/// | 1 | `speed_db_per_s` | dB/s | 0.5–20 | 3.0 |
+/// | 2 | `max_gain_db` | dB | 0–40 | 12.0 |
+/// | 3 | `gate_db` | dB | −80–−20 | −50.0 |
+/// | 4 | `detector` | index | 0–1 | 0 (RMS) |
+/// | 5 | `output_db` | dB | −60–6 | 0.0 |
+/// | 6 | `gain_db` | dB | −30–40 | 0.0 (READ_ONLY) |
+#[derive(Debug, Clone, Copy)]
+pub struct AgcParams {
+    /// Target level in dBFS RMS (or LUFS with the K-weighted detector).
+    ///
+    /// Range: −40.0 to −6.0 dB. Default −18.0.
+    pub target_db: f32,
+
+    /// Fastest rate the gain may change, in dB per second.
+    ///
+    /// Range: 0.5 to 20.0 (logarithmic). Default 3.0.
+    pub speed_db_per_s: f32,
+
+    /// Largest boost the AGC will apply, in dB.
+    ///
+    /// Range: 0.0 to 40.0 dB. Default 12.0.
+    pub max_gain_db: f32,
+
+    /// Gate threshold in dB. Below it the gain is held.
+    ///
+    /// Range: −80.0 to −20.0 dB. Default −50.0.
+    pub gate_db: f32,
+
+    /// Detector: 0.0 = RMS, 1.0 = LUFS (K-weighted). Stepped.
+    pub detector: f32,
+
+    /// Output level in decibels.
+    ///
+    /// Range: −60.0 to +6.0 dB, default 0.0.
+    pub output_db: f32,
+
+    /// READ_ONLY diagnostic: gain currently applied, in dB.
+    ///
+    /// Written by the kernel through `update_diagnostics`; not user-settable.
+    pub gain_db: f32,
+}
+
+impl Default for AgcParams {
+    fn default() -> Self {
+        Self {
+            target_db: -18.0,
+            speed_db_per_s: 3.0,
+            max_gain_db: 12.0,
+            gate_db: -50.0,
+            detector: 0.0,
+            output_db: 0.0,
+            gain_db: 0.0,
+        }
+    }
+}
+
+impl AgcParams {
+    /// Creates parameters from normalized 0–1 knob readings.
+    ///
+    /// | Argument | Index | Parameter | Range |
+    /// |----------|-------|-----------|-------|
+    /// | `target` | 0 | `target_db` | −40–−6 dB |
+    /// | `speed` | 1 | `speed_db_per_s` | 0.5–20 dB/s (log) |
+    /// | `max_gain` | 2 | `max_gain_db` | 0–40 dB |
+    /// | `gate` | 3 | `gate_db` | −80–−20 dB |
+    /// | `detector` | 4 | `detector` | 0–1 (stepped) |
+    /// | `output` | 5 | `output_db` | −60–+6 dB |
+    pub fn from_knobs(
+        target: f32,
+        speed: f32,
+        max_gain: f32,
+        gate: f32,
+        detector: f32,
+        output: f32,
+    ) -> Self {
+        Self::from_normalized(&[target, speed, max_gain, gate, detector, output])
+    }
+}
+
+impl KernelParams for AgcParams {
+    const COUNT: usize = 7;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::gain_db("Target", "Tgt", -40.0, -6.0, -18.0)
+                    .with_id(ParamId(3200), "agc_target")
+                    .with_description("Level the AGC steers toward."),
+            ),
+            1 => Some(
+                ParamDescriptor::custom("Speed", "Spd", 0.5, 20.0, 3.0)
+                    .with_step(0.1)
+                    .with_id(ParamId(3201), "agc_speed")
+                    .with_description("Fastest the gain may change, in dB per second.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            2 => Some(
+                ParamDescriptor::gain_db("Max Gain", "MaxG", 0.0, 40.0, 12.0)
+                    .with_id(ParamId(3202), "agc_max_gain")
+                    .with_description("Largest boost the AGC will apply."),
+            ),
+            3 => Some(
+                ParamDescriptor::gain_db("Gate", "Gate", -80.0, -20.0, -50.0)
+                    .with_id(ParamId(3203), "agc_gate")
+                    .with_description("Below this level the gain is held rather than boosted."),
+            ),
+            4 => Some(
+                ParamDescriptor::custom("Detector", "Det", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(3204), "agc_detector")
+                    .with_description("Level measurement: plain RMS, or K-weighted LUFS.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["RMS", "LUFS"]),
+            ),
+            5 => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(3205), "agc_output"),
+            ),
+            6 => Some(
+                ParamDescriptor::gain_db("Gain", "Gain", -MAX_CUT_DB, 40.0, 0.0)
+                    .with_id(ParamId(3206), "agc_gain")
+                    .with_description("Gain currently applied (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 => SmoothingStyle::Standard, // target_db — already rate-limited by the rider
+            1 => SmoothingStyle::Standard, // speed_db_per_s
+            2 => SmoothingStyle::Standard, // max_gain_db
+            3 => SmoothingStyle::Standard, // gate_db
+            4 => SmoothingStyle::None,     // detector — stepped, snap immediately
+            5 => SmoothingStyle::Fast,     // output_db — 5 ms
+            6 => SmoothingStyle::None,     // gain_db — READ_ONLY diagnostic
+            _ => SmoothingStyle::Standard,
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.target_db,
+            1 => self.speed_db_per_s,
+            2 => self.max_gain_db,
+            3 => self.gate_db,
+            4 => self.detector,
+            5 => self.output_db,
+            6 => self.gain_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.target_db = value,
+            1 => self.speed_db_per_s = value,
+            2 => self.max_gain_db = value,
+            3 => self.gate_db = value,
+            4 => self.detector = value,
+            5 => self.output_db = value,
+            6 => self.gain_db = value, // READ_ONLY: kernel writes this
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP automatic gain control kernel.
+///
+/// Contains ONLY the mutable state required for audio processing:
+///
+/// - K-weighting biquads: `k_shelf[channel]` and `k_highpass[channel]`
+/// - Linked RMS [`EnvelopeFollower`] with a 400 ms sliding window
+/// - The gain currently applied, in dB
+///
+/// No `SmoothedParam`, no atomics, no platform awareness.
+pub struct AgcKernel {
+    /// Current sample rate in Hz.
+    sample_rate: f32,
+
+    /// BS.1770 stage 1: +4 dB high shelf per channel.
+    k_shelf: [Biquad; 2],
+
+    /// BS.1770 stage 2: 38 Hz highpass per channel.
+    k_highpass: [Biquad; 2],
+
+    /// Windowed RMS detector fed from both channels.
+    detector: EnvelopeFollower,
+
+    /// Gain currently applied, in dB.
+    gain_db: f32,
+}
+
+impl AgcKernel {
+    /// Create a new AGC kernel at the given sample rate.
+    ///
+    /// Allocates the detector window (400 ms of samples).
+    pub fn new(sample_rate: f32) -> Self {
+        let mut detector = EnvelopeFollower::with_times(sample_rate, 0.1, 1.0);
+        detector.set_detection_mode(DetectionMode::Rms);
+        detector.set_rms_window_ms(WINDOW_MS);
+
+        let mut kernel = Self {
+            sample_rate,
+            k_shelf: [Biquad::new(), Biquad::new()],
+            k_highpass: [Biquad::new(), Biquad::new()],
+            detector,
+            gain_db: 0.0,
+        };
+        kernel.update_k_weighting();
+        kernel
+    }
+
+    /// Gain currently applied, in dB.
+    pub fn gain_db(&self) -> f32 {
+        self.gain_db
+    }
+
+    /// Recompute K-weighting coefficients for the current sample rate.
+    fn update_k_weighting(&mut self) {
+        let sr = self.sample_rate;
+        let (sb0, sb1, sb2, sa0, sa1, sa2) = high_shelf_coefficients(1500.0, 4.0, sr);
+        let (hb0, hb1, hb2, ha0, ha1, ha2) = highpass_coefficients(38.0, 0.5, sr);
+        for ch in 0..2 {
+            self.k_shelf[ch].set_coefficients(sb0, sb1, sb2, sa0, sa1, sa2);
+            self.k_highpass[ch].set_coefficients(hb0, hb1, hb2, ha0, ha1, ha2);
+        }
+    }
+}
+
+impl DspKernel for AgcKernel {
+    type Params = AgcParams;
+
+    fn process_stereo(&mut self, left: f32, right: f32, params: &AgcParams) -> (f32, f32) {
+        // ── 1. Detection ──────────────────────────────────────────────────────
+        let lufs = params.detector >= 0.5;
+        let level = if lufs {
+            let kl = self.k_highpass[0].process(self.k_shelf[0].process(left));
+            let kr = self.k_highpass[1].process(self.k_shelf[1].process(right));
+            self.detector.process_stereo(kl, kr)
+        } else {
+            self.detector.process_stereo(left, right)
+        };
+        let level_db = linear_to_db(level) + if lufs { LUFS_OFFSET_DB } else { 0.0 };
+
+        // ── 2. Gain rider (held below the gate) ───────────────────────────────
+        if level_db >= params.gate_db {
+            let max_gain = params.max_gain_db.clamp(0.0, 40.0);
+            let wanted = (params.target_db - level_db).clamp(-MAX_CUT_DB, max_gain);
+            let max_step = params.speed_db_per_s.max(0.0) / self.sample_rate;
+            self.gain_db += (wanted - self.gain_db).clamp(-max_step, max_step);
+        }
+
+        // ── 3. Apply gain and output level ────────────────────────────────────
+        let gain = fast_db_to_linear(self.gain_db + params.output_db);
+        (left * gain, right * gain)
+    }
+
+    fn reset(&mut self) {
+        for ch in 0..2 {
+            self.k_shelf[ch].clear();
+            self.k_highpass[ch].clear();
+        }
+        self.detector.reset();
+        self.gain_db = 0.0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.detector.set_sample_rate(sample_rate);
+        self.update_k_weighting();
+    }
+
+    /// Linked-stereo detection: one gain for both channels.
+    fn is_true_stereo(&self) -> bool {
+        true
+    }
+
+    fn update_diagnostics(&self, params: &mut AgcParams) {
+        params.gain_db = self.gain_db;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::Effect;
+    use sonido_core::kernel::Adapter;
+
+    const SR: f32 = 48000.0;
+
+    /// Run `seconds` of a 1 kHz sine at `amplitude` and return the output RMS
+    /// in dB over the final 100 ms.
+    fn run_sine(kernel: &mut AgcKernel, params: &AgcParams, amplitude: f32, seconds: f32) -> f32 {
+        let total = (seconds * SR) as usize;
+        let tail = (0.1 * SR) as usize;
+        let mut energy = 0.0_f32;
+        for i in 0..total {
+            let x = amplitude * libm::sinf(2.0 * core::f32::consts::PI * 1000.0 * i as f32 / SR);
+            let (l, _) = kernel.process_stereo(x, x, params);
+            if i >= total - tail {
+                energy += l * l;
+            }
+        }
+        linear_to_db(libm::sqrtf(energy / tail as f32))
+    }
+
+    #[test]
+    fn silence_in_silence_out() {
+        let mut kernel = AgcKernel::new(SR);
+        let params = AgcParams::default();
+        for _ in 0..1000 {
+            let (l, r) = kernel.process_stereo(0.0, 0.0, &params);
+            assert_eq!((l, r), (0.0, 0.0));
+        }
+        assert_eq!(kernel.gain_db(), 0.0, "gate should hold gain on silence");
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(AgcParams::COUNT, 7);
+
+        let d0 = AgcParams::descriptor(0).expect("index 0 must exist");
+        assert_eq!(d0.name, "Target");
+        assert_eq!(d0.id, ParamId(3200));
+        assert_eq!(d0.string_id, "agc_target");
+
+        let d5 = AgcParams::descriptor(5).expect("index 5 must exist");
+        assert_eq!(d5.name, "Output");
+        assert_eq!(d5.id, ParamId(3205));
+
+        let d6 = AgcParams::descriptor(6).expect("index 6 must exist");
+        assert!(d6.flags.contains(ParamFlags::READ_ONLY));
+        assert_eq!(d6.id, ParamId(3206));
+
+        assert!(AgcParams::descriptor(7).is_none());
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let kernel = AgcKernel::new(SR);
+        let mut adapter = Adapter::new(kernel, SR);
+        adapter.reset();
+        let output = adapter.process(0.3);
+        assert!(output.is_finite(), "adapter.process() returned {output}");
+    }
+
+    /// Quiet input is raised to the target, loud input is lowered to it.
+    #[test]
+    fn rides_to_target() {
+        let params = AgcParams {
+            speed_db_per_s: 20.0,
+            max_gain_db: 40.0,
+            ..Default::default()
+        };
+        for amplitude_db in [-36.0, -6.0] {
+            let mut kernel = AgcKernel::new(SR);
+            let amplitude = sonido_core::math::db_to_linear(amplitude_db);
+            let out_db = run_sine(&mut kernel, &params, amplitude, 4.0);
+            assert!(
+                (out_db - params.target_db).abs() < 0.5,
+                "input sine at {amplitude_db} dBFS: output {out_db} dB RMS"
+            );
+        }
+    }
+
+    /// Gain never changes faster than `speed` dB/s.
+    #[test]
+    fn speed_limits_gain_rate() {
+        let mut kernel = AgcKernel::new(SR);
+        let params = AgcParams {
+            speed_db_per_s: 2.0,
+            max_gain_db: 40.0,
+            ..Default::default()
+        };
+        // Let the detector window fill before measuring the slope.
+        run_sine(&mut kernel, &params, 0.01, 0.5);
+        let before = kernel.gain_db();
+        run_sine(&mut kernel, &params, 0.01, 1.0);
+        let rise = kernel.gain_db() - before;
+        assert!((rise - 2.0).abs() < 1e-2, "1 s at 2 dB/s, rose {rise} dB");
+    }
+
+    /// Boost stops at Max Gain.
+    #[test]
+    fn max_gain_caps_boost() {
+        let mut kernel = AgcKernel::new(SR);
+        let params = AgcParams {
+            speed_db_per_s: 20.0,
+            max_gain_db: 6.0,
+            ..Default::default()
+        };
+        run_sine(&mut kernel, &params, 0.01, 2.0);
+        assert!((kernel.gain_db() - 6.0).abs() < 1e-3);
+    }
+
+    /// Input below the gate does not move the gain.
+    #[test]
+    fn gate_freezes_gain() {
+        let mut kernel = AgcKernel::new(SR);
+        let params = AgcParams {
+            gate_db: -40.0,
+            speed_db_per_s: 20.0,
+            ..Default::default()
+        };
+        // −50 dBFS sine: RMS ≈ −53 dB, below the gate.
+        run_sine(&mut kernel, &params, 0.003, 1.0);
+        assert_eq!(kernel.gain_db(), 0.0);
+    }
+
+    /// K-weighting lifts highs: a 4 kHz tone reads louder in LUFS mode than
+    /// in RMS mode, so the AGC applies less gain.
+    #[test]
+    fn lufs_detector_weights_highs() {
+        let gain_after = |detector: f32| {
+            let mut kernel = AgcKernel::new(SR);
+            let params = AgcParams {
+                detector,
+                speed_db_per_s: 20.0,
+                max_gain_db: 40.0,
+                ..Default::default()
+            };
+            for i in 0..(3.0 * SR) as usize {
+                let x = 0.05 * libm::sinf(2.0 * core::f32::consts::PI * 4000.0 * i as f32 / SR);
+                kernel.process_stereo(x, x, &params);
+            }
+            kernel.gain_db()
+        };
+        let rms_gain = gain_after(0.0);
+        let lufs_gain = gain_after(1.0);
+        assert!(
+            rms_gain - lufs_gain > 2.5,
+            "RMS gain {rms_gain} dB, LUFS gain {lufs_gain} dB"
+        );
+    }
+}
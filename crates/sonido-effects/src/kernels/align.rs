@@ -0,0 +1,326 @@
+//! Align kernel — sample-accurate delay and polarity for time-aligning signals.
+//!
+//! `AlignKernel` owns DSP state (one delay line per channel). Parameters are
+//! received via `&AlignParams` each sample. Deployed via
+//! [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin, or called
+//! directly on embedded targets.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! Input L/R
+//!   → Delay     (0–2000 samples, fractional, cubic interpolation)
+//!   → Polarity  (Normal / Inverted, immediate)
+//!   → Output level
+//!   → Output L/R
+//! ```
+//!
+//! # Use
+//!
+//! A DI and a mic'd cabinet recorded together arrive at different times: the
+//! mic is roughly 3 samples late per centimetre at 48 kHz (sound travels
+//! ~343 m/s). Placed on the earlier of two parallel graph branches, `Align`
+//! delays it by that offset so the two sum without comb filtering. Polarity
+//! covers a mic or cable wired out of phase. Residual phase differences that
+//! a delay cannot fix are the job of
+//! [`PhaseRotatorKernel`](super::PhaseRotatorKernel).
+//!
+//! Delay is counted in samples rather than milliseconds because alignment is
+//! measured and nudged sample by sample; fractional values are interpolated.
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(AlignKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing, ADCs are hardware-filtered)
+//! let mut kernel = AlignKernel::new(48000.0);
+//! let params = AlignParams::from_knobs(adc_delay, adc_polarity, adc_output);
+//! let (left, right) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    InterpolatedDelay, Interpolation, ParamDescriptor, ParamFlags, ParamId, fast_db_to_linear,
+};
+
+// ── Constants ─────────────────────────────────────────────────────────────────
+
+/// Maximum alignment delay in samples (~42 ms at 48 kHz, ~14 m of mic distance).
+const MAX_DELAY_SAMPLES: f32 = 2000.0;
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`AlignKernel`].
+///
+/// All values are in **user-facing units** — the same units shown in GUIs and
+/// stored in presets. The kernel converts internally as needed.
+///
+/// ## Parameter Table
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `delay_samples` | samples | 0–2000 | 0.0 |
+/// | 1 | `polarity` | index | 0–1 | 0 (Normal) |
+/// | 2 | `output_db` | dB | −60–6 | 0.0 |
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlignParams {
+    /// Delay applied to both channels, in samples.
+    ///
+    /// Range: 0.0 to 2000.0. Default 0.0. Fractional values are interpolated.
+    pub delay_samples: f32,
+
+    /// Polarity.
+    ///
+    /// 0.0 = Normal, 1.0 = Inverted. Stepped.
+    pub polarity: f32,
+
+    /// Output level in decibels.
+    ///
+    /// Range: −60.0 to +6.0 dB, default 0.0.
+    pub output_db: f32,
+}
+
+impl AlignParams {
+    /// Creates parameters from normalized 0–1 knob readings.
+    ///
+    /// | Argument | Index | Parameter | Range |
+    /// |----------|-------|-----------|-------|
+    /// | `delay` | 0 | `delay_samples` | 0–2000 samples |
+    /// | `polarity` | 1 | `polarity` | 0–1 (stepped) |
+    /// | `output` | 2 | `output_db` | −60–+6 dB |
+    pub fn from_knobs(delay: f32, polarity: f32, output: f32) -> Self {
+        Self::from_normalized(&[delay, polarity, output])
+    }
+}
+
+impl KernelParams for AlignParams {
+    const COUNT: usize = 3;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::custom("Delay", "Dly", 0.0, MAX_DELAY_SAMPLES, 0.0)
+                    .with_step(0.1)
+                    .with_id(ParamId(3900), "align_delay")
+                    .with_description("Delay in samples, to line up with a parallel path."),
+            ),
+            1 => Some(
+                ParamDescriptor::custom("Polarity", "Pol", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(3901), "align_polarity")
+                    .with_description("Invert the signal polarity.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Normal", "Invert"]),
+            ),
+            2 => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(3902), "align_output"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 => SmoothingStyle::Interpolated, // delay_samples — 50 ms, glide without clicks
+            1 => SmoothingStyle::None,         // polarity — stepped, snap immediately
+            2 => SmoothingStyle::Fast,         // output_db — 5 ms
+            _ => SmoothingStyle::Standard,
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.delay_samples,
+            1 => self.polarity,
+            2 => self.output_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.delay_samples = value,
+            1 => self.polarity = value,
+            2 => self.output_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP alignment kernel.
+///
+/// Contains ONLY the mutable state required for audio processing:
+///
+/// - One [`InterpolatedDelay`] per channel, sized for [`MAX_DELAY_SAMPLES`]
+///
+/// No `SmoothedParam`, no atomics, no platform awareness. The delay is in
+/// samples, so the buffers do not depend on the sample rate.
+pub struct AlignKernel {
+    /// Delay lines — `[left, right]`.
+    delay: [InterpolatedDelay; 2],
+}
+
+impl AlignKernel {
+    /// Create a new alignment kernel.
+    ///
+    /// The sample rate is accepted for API symmetry with other kernels; the
+    /// delay is specified in samples and does not depend on it.
+    pub fn new(_sample_rate: f32) -> Self {
+        let delay = core::array::from_fn(|_| {
+            // +4: room for the cubic interpolator's look-behind at full delay.
+            InterpolatedDelay::new(MAX_DELAY_SAMPLES as usize + 4)
+        });
+        Self { delay }
+    }
+}
+
+impl DspKernel for AlignKernel {
+    type Params = AlignParams;
+
+    fn process_stereo(&mut self, left: f32, right: f32, params: &AlignParams) -> (f32, f32) {
+        // ── 1. Delay ──────────────────────────────────────────────────────────
+        // Write first so a delay of 0 reads the current input.
+        let delay = params.delay_samples.clamp(0.0, MAX_DELAY_SAMPLES);
+        // Cubic interpolation needs one sample newer than the read point,
+        // which does not exist below a one-sample delay.
+        let interpolation = if delay >= 1.0 {
+            Interpolation::Cubic
+        } else {
+            Interpolation::Linear
+        };
+        self.delay[0].write(left);
+        self.delay[1].write(right);
+        self.delay[0].set_interpolation(interpolation);
+        self.delay[1].set_interpolation(interpolation);
+        let mut l = self.delay[0].read(delay);
+        let mut r = self.delay[1].read(delay);
+
+        // ── 2. Polarity ───────────────────────────────────────────────────────
+        if params.polarity >= 0.5 {
+            l = -l;
+            r = -r;
+        }
+
+        // ── 3. Output level ───────────────────────────────────────────────────
+        let output_gain = fast_db_to_linear(params.output_db);
+        (l * output_gain, r * output_gain)
+    }
+
+    fn reset(&mut self) {
+        for line in &mut self.delay {
+            line.clear();
+        }
+    }
+
+    fn set_sample_rate(&mut self, _sample_rate: f32) {}
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::Effect;
+    use sonido_core::kernel::Adapter;
+
+    #[test]
+    fn defaults_pass_through() {
+        let mut kernel = AlignKernel::new(48000.0);
+        let params = AlignParams::default();
+        let (l, r) = kernel.process_stereo(0.4, -0.2, &params);
+        assert!((l - 0.4).abs() < 1e-6);
+        assert!((r + 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(AlignParams::COUNT, 3);
+
+        let d0 = AlignParams::descriptor(0).expect("index 0 must exist");
+        assert_eq!(d0.name, "Delay");
+        assert_eq!(d0.id, ParamId(3900));
+        assert_eq!(d0.string_id, "align_delay");
+
+        let d1 = AlignParams::descriptor(1).expect("index 1 must exist");
+        assert_eq!(d1.name, "Polarity");
+        assert_eq!(d1.id, ParamId(3901));
+
+        let d2 = AlignParams::descriptor(2).expect("index 2 must exist");
+        assert_eq!(d2.name, "Output");
+        assert_eq!(d2.id, ParamId(3902));
+
+        assert!(AlignParams::descriptor(3).is_none());
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let kernel = AlignKernel::new(48000.0);
+        let mut adapter = Adapter::new(kernel, 48000.0);
+        adapter.reset();
+        let output = adapter.process(0.3);
+        assert!(output.is_finite(), "adapter.process() returned {output}");
+    }
+
+    /// An impulse comes out exactly `delay_samples` later.
+    #[test]
+    fn integer_delay_is_exact() {
+        for delay in [0usize, 1, 37, 2000] {
+            let mut kernel = AlignKernel::new(48000.0);
+            let params = AlignParams {
+                delay_samples: delay as f32,
+                ..Default::default()
+            };
+            for n in 0..=delay + 2 {
+                let x = if n == 0 { 1.0 } else { 0.0 };
+                let (l, r) = kernel.process_stereo(x, x, &params);
+                let expected = if n == delay { 1.0 } else { 0.0 };
+                assert!(
+                    (l - expected).abs() < 1e-6 && (r - expected).abs() < 1e-6,
+                    "delay {delay}, sample {n}: got ({l}, {r})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn polarity_inverts() {
+        let mut kernel = AlignKernel::new(48000.0);
+        let params = AlignParams {
+            polarity: 1.0,
+            ..Default::default()
+        };
+        let (l, r) = kernel.process_stereo(0.5, -0.25, &params);
+        assert!((l + 0.5).abs() < 1e-6);
+        assert!((r - 0.25).abs() < 1e-6);
+    }
+
+    /// A half-sample delay lands an impulse between two output samples.
+    #[test]
+    fn fractional_delay_interpolates() {
+        let mut kernel = AlignKernel::new(48000.0);
+        let params = AlignParams {
+            delay_samples: 10.5,
+            ..Default::default()
+        };
+        let out: Vec<f32> = (0..16)
+            .map(|n| {
+                kernel
+                    .process_stereo(if n == 0 { 1.0 } else { 0.0 }, 0.0, &params)
+                    .0
+            })
+            .collect();
+        assert!((out[10] - out[11]).abs() < 1e-3, "{out:?}");
+        assert!(out[10] > 0.4, "{out:?}");
+    }
+}
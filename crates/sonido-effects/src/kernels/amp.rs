@@ -272,48 +272,63 @@ impl KernelParams for AmpParams {
                 ParamDescriptor::custom("Gain", "Gain", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2100), "amp_gain"),
+                    .with_id(ParamId(2100), "amp_gain")
+                    .with_description("Preamp drive, from clean to heavily overdriven."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Bass", "Bass", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2101), "amp_bass"),
+                    .with_id(ParamId(2101), "amp_bass")
+                    .with_group("Tone Stack")
+                    .with_description("Low-frequency tone control; 50% is flat."),
             ),
             2 => Some(
                 ParamDescriptor::custom("Mid", "Mid", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2102), "amp_mid"),
+                    .with_id(ParamId(2102), "amp_mid")
+                    .with_group("Tone Stack")
+                    .with_description("Midrange tone control."),
             ),
             3 => Some(
                 ParamDescriptor::custom("Treble", "Treble", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2103), "amp_treble"),
+                    .with_id(ParamId(2103), "amp_treble")
+                    .with_group("Tone Stack")
+                    .with_description("High-frequency tone control."),
             ),
             4 => Some(
                 ParamDescriptor::custom("Presence", "Pres", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2104), "amp_presence"),
+                    .with_id(ParamId(2104), "amp_presence")
+                    .with_group("Tone Stack")
+                    .with_description("High shelf at 4 kHz; 50% is flat."),
             ),
             5 => Some(
                 ParamDescriptor::custom("Sag", "Sag", 0.0, 100.0, 30.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(2105), "amp_sag"),
+                    .with_id(ParamId(2105), "amp_sag")
+                    .with_description(
+                        "Power-supply sag; higher values compress and soften hard playing.",
+                    ),
             ),
             6 => Some(
                 ParamDescriptor::custom("Bright", "Bright", 0.0, 1.0, 0.0)
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step(1.0)
                     .with_step_labels(&["Off", "On"])
-                    .with_id(ParamId(2106), "amp_bright"),
+                    .with_id(ParamId(2106), "amp_bright")
+                    .with_group("Tone Stack")
+                    .with_description("Bright switch: +3 dB high shelf at 2 kHz."),
             ),
             7 => Some(
                 ParamDescriptor::gain_db("Master", "Master", -60.0, 0.0, -6.0)
-                    .with_id(ParamId(2107), "amp_master"),
+                    .with_id(ParamId(2107), "amp_master")
+                    .with_description("Master volume after the tone stack."),
             ),
             8 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(2108), "amp_output"),
@@ -111,25 +111,33 @@ impl KernelParams for BitcrusherParams {
                 ParamDescriptor::custom("Bit Depth", "Bits", 2.0, 16.0, 8.0)
                     .with_step(1.0)
                     .with_id(ParamId(1700), "crush_bits")
+                    .with_description("Quantization bit depth; fewer bits, more grit.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
             ),
             1 => Some(
                 ParamDescriptor::custom("Downsample", "Down", 1.0, 64.0, 1.0)
                     .with_step(1.0)
                     .with_id(ParamId(1701), "crush_down")
+                    .with_description(
+                        "Sample-and-hold factor; higher values lower the effective sample rate.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
             ),
             2 => Some(
                 ParamDescriptor::custom("Jitter", "Jitter", 0.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
-                    .with_id(ParamId(1702), "crush_jitter"),
+                    .with_id(ParamId(1702), "crush_jitter")
+                    .with_description(
+                        "Random variation in the hold time for a less regular, noisier sound.",
+                    ),
             ),
             3 => Some(
                 ParamDescriptor {
                     default: 100.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1703), "crush_mix"),
+                .with_id(ParamId(1703), "crush_mix")
+                .with_description("Blend of dry and crushed signal."),
             ),
             4 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1704), "crush_output"),
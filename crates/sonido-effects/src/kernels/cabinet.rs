@@ -100,15 +100,21 @@ impl KernelParams for CabinetParams {
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2200), "cab_ir")
+                    .with_description("Speaker cabinet impulse response.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(IR_LABELS),
             ),
-            1 => Some(ParamDescriptor::mix().with_id(ParamId(2201), "cab_mix")),
+            1 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2201), "cab_mix")
+                    .with_description("Blend of dry and cabinet-filtered signal."),
+            ),
             2 => Some(
                 ParamDescriptor::custom("Low Cut", "Low Cut", 20.0, 500.0, 80.0)
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic)
-                    .with_id(ParamId(2202), "cab_low_cut"),
+                    .with_id(ParamId(2202), "cab_low_cut")
+                    .with_description("Highpass before the cabinet to tighten the low end."),
             ),
             3 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(2203), "cab_output"),
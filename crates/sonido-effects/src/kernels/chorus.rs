@@ -12,7 +12,7 @@
 //! alignment and produce a rich, evolving ensemble texture:
 //!
 //! - Voice 1: sine LFO at base rate, 0° phase
-//! - Voice 2: sine LFO at base rate, 90° phase offset
+//! - Voice 2: sine LFO at base rate, phase offset set by `spread_pct` (90° default)
 //! - Voice 3: sine LFO at rate × 0.73 (irrational ratio)
 //! - Voice 4: triangle LFO at rate × 1.17 (irrational ratio)
 //!
@@ -28,6 +28,11 @@
 //! (Wet L, Wet R) ─→ Wet/Dry Mix ─→ Soft Limit ─→ Output Level ─→ Out
 //! ```
 //!
+//! Voices 1 and 2 are the left and right channels of the chorus. Voice 2's
+//! LFO is locked to voice 1's at a phase lead of 0°–180° (`spread_pct`
+//! 0–100 %), so the stereo image runs from a centred, mono-compatible sweep
+//! at 0 % to channels moving in opposite directions at 100 %.
+//!
 //! Stereo panning matrix per voice count:
 //! - 2 voices: V1 80% L / 20% R, V2 20% L / 80% R
 //! - 3 voices: V1 left, V2 right, V3 center
@@ -101,6 +106,7 @@ const VOICE4_RATE_RATIO: f32 = 1.17;
 /// | 7 | `division` | index (0–11) | 0–11 | 3 |
 /// | 8 | `output_db` | dB | −20–+6 | 0.0 |
 /// | 9 | `lfo_phase` | 0–1 | READ_ONLY diagnostic | 0.0 |
+/// | 10 | `spread_pct` | % | 0–100 | 50.0 |
 #[derive(Debug, Clone, Copy)]
 pub struct ChorusParams {
     /// LFO rate in Hz. Voices 1–2 use this directly; voice 3 uses rate × 0.73,
@@ -155,6 +161,12 @@ pub struct ChorusParams {
     /// Current LFO phase (0.0–1.0). READ_ONLY diagnostic — written by the
     /// kernel each sample; never read back for DSP.
     pub lfo_phase: f32,
+
+    /// Stereo LFO spread as a percentage (0–100%).
+    ///
+    /// Maps to a 0°–180° phase lead of the voice 2 (right) LFO over voice 1
+    /// (left). Default 50% (90°).
+    pub spread_pct: f32,
 }
 
 impl Default for ChorusParams {
@@ -170,6 +182,7 @@ impl Default for ChorusParams {
             division: 3.0,
             output_db: 0.0,
             lfo_phase: 0.0,
+            spread_pct: 50.0,
         }
     }
 }
@@ -189,7 +202,8 @@ impl ChorusParams {
     /// | `feedback` | 4 | `feedback_pct` | 0–70 % |
     /// | `output` | 8 | `output_db` | −20–+6 dB |
     ///
-    /// Fixed: voices=2, base_delay=15 ms, sync=Off, division=Quarter.
+    /// Fixed: voices=2, base_delay=15 ms, sync=Off, division=Quarter,
+    /// spread=50 %.
     pub fn from_knobs(rate: f32, depth: f32, mix: f32, feedback: f32, output: f32) -> Self {
         // Fixed normalized values for embedded defaults:
         //   voices=2 in [2,4]      → norm = 0.0
@@ -211,17 +225,30 @@ impl ChorusParams {
 }
 
 impl KernelParams for ChorusParams {
-    const COUNT: usize = 10;
+    const COUNT: usize = 11;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
-            0 => Some(ParamDescriptor::rate_hz(0.1, 10.0, 1.0).with_id(ParamId(700), "chor_rate")),
-            1 => Some(ParamDescriptor::depth().with_id(ParamId(701), "chor_depth")),
-            2 => Some(ParamDescriptor::mix().with_id(ParamId(702), "chor_mix")),
+            0 => Some(
+                ParamDescriptor::rate_hz(0.1, 10.0, 1.0)
+                    .with_id(ParamId(700), "chor_rate")
+                    .with_description("LFO speed of the delay modulation."),
+            ),
+            1 => Some(
+                ParamDescriptor::depth()
+                    .with_id(ParamId(701), "chor_depth")
+                    .with_description("Amount of delay modulation; more depth, more pitch wobble."),
+            ),
+            2 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(702), "chor_mix")
+                    .with_description("Blend of dry and chorused signal."),
+            ),
             3 => Some(
                 ParamDescriptor::custom("Voices", "Voices", 2.0, 4.0, 2.0)
                     .with_step(1.0)
                     .with_id(ParamId(704), "chor_voices")
+                    .with_description("Number of modulated delay voices.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["2", "3", "4"]),
             ),
@@ -229,18 +256,22 @@ impl KernelParams for ChorusParams {
                 ParamDescriptor::custom("Feedback", "Fdbk", 0.0, 70.0, 0.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(705), "chor_feedback"),
+                    .with_id(ParamId(705), "chor_feedback")
+                    .with_description("Amount of output fed back into the delay lines."),
             ),
             5 => Some(
                 ParamDescriptor::custom("Base Delay", "BDly", 5.0, 25.0, 15.0)
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(0.5)
-                    .with_id(ParamId(706), "chor_base_delay"),
+                    .with_id(ParamId(706), "chor_base_delay")
+                    .with_description("Center delay time the voices modulate around."),
             ),
             6 => Some(
                 ParamDescriptor::custom("Sync", "Sync", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(707), "chor_sync")
+                    .with_group("Tempo Sync")
+                    .with_description("Lock the LFO rate to the host tempo using Division.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -248,6 +279,8 @@ impl KernelParams for ChorusParams {
                 ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 3.0)
                     .with_step(1.0)
                     .with_id(ParamId(708), "chor_division")
+                    .with_group("Tempo Sync")
+                    .with_description("Note length of one LFO cycle when Sync is on.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(DIVISION_LABELS),
             ),
@@ -257,8 +290,17 @@ impl KernelParams for ChorusParams {
             9 => Some(
                 ParamDescriptor::custom("LFO Phase", "Phase", 0.0, 1.0, 0.0)
                     .with_id(ParamId(709), "chor_lfo_phase")
+                    .with_description("Current LFO position in its cycle (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
+            10 => Some(
+                ParamDescriptor::custom("Spread", "Sprd", 0.0, 100.0, 50.0)
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(ParamId(710), "chor_spread")
+                    .with_description("Phase offset between the left and right voices, up to 180°.")
+                    .with_group("Stereo"),
+            ),
             _ => None,
         }
     }
@@ -275,6 +317,7 @@ impl KernelParams for ChorusParams {
             7 => SmoothingStyle::None,     // division — discrete
             8 => SmoothingStyle::Standard, // output_db — 10ms
             9 => SmoothingStyle::None,     // lfo_phase — READ_ONLY diagnostic, no smoothing
+            10 => SmoothingStyle::Standard, // spread_pct — 10ms, phase offset glides
             _ => SmoothingStyle::Standard,
         }
     }
@@ -291,6 +334,7 @@ impl KernelParams for ChorusParams {
             7 => self.division,
             8 => self.output_db,
             9 => self.lfo_phase,
+            10 => self.spread_pct,
             _ => 0.0,
         }
     }
@@ -307,6 +351,7 @@ impl KernelParams for ChorusParams {
             7 => self.division = value,
             8 => self.output_db = value,
             9 => self.lfo_phase = value,
+            10 => self.spread_pct = value,
             _ => {}
         }
     }
@@ -342,7 +387,8 @@ pub struct ChorusKernel {
 
     /// Voice 1 LFO: sine at base rate, 0° initial phase.
     lfo1: Lfo,
-    /// Voice 2 LFO: sine at base rate, 90° initial phase offset.
+    /// Voice 2 LFO: sine at base rate, re-phased each sample to lead voice 1
+    /// by the spread.
     lfo2: Lfo,
     /// Voice 3 LFO: sine at rate × [`VOICE3_RATE_RATIO`] (0.73).
     lfo3: Lfo,
@@ -392,9 +438,8 @@ impl ChorusKernel {
         // Voice 1: sine at 1 Hz, 0° phase
         let lfo1 = Lfo::new(sample_rate, 1.0);
 
-        // Voice 2: sine at 1 Hz, 90° (0.25 cycle) phase offset — quadrature pair
-        let mut lfo2 = Lfo::new(sample_rate, 1.0);
-        lfo2.set_phase(0.25);
+        // Voice 2: sine at 1 Hz, locked to voice 1 at the spread offset
+        let lfo2 = Lfo::new(sample_rate, 1.0);
 
         // Voice 3: sine at rate × 0.73 — irrational ratio prevents locking
         let lfo3 = Lfo::new(sample_rate, VOICE3_RATE_RATIO);
@@ -425,7 +470,8 @@ impl ChorusKernel {
     ///
     /// Voice routing:
     /// - Voice 1 is fed by the left input channel
-    /// - Voice 2 is fed by the right input channel
+    /// - Voice 2 is fed by the right input channel, its LFO leading voice 1's
+    ///   by `spread` cycles
     /// - Voices 3–4 are fed by the mid (average L+R) signal
     ///
     /// Returns `(wet_left, wet_right)` before wet/dry mixing.
@@ -436,8 +482,16 @@ impl ChorusKernel {
         right: f32,
         depth: f32,
         feedback: f32,
+        spread: f32,
         voices: u8,
     ) -> (f32, f32) {
+        // Lock voice 2's LFO to voice 1's before either advances
+        let mut phase2 = self.lfo1.phase() + spread;
+        if phase2 >= 1.0 {
+            phase2 -= 1.0;
+        }
+        self.lfo2.set_phase(phase2);
+
         // Voice 1 — fed by left channel
         let dt1 = self.base_delay_samples + self.lfo1.advance() * depth * self.max_mod_samples;
         let wet1 = self.delay1.read(dt1);
@@ -495,13 +549,14 @@ impl DspKernel for ChorusKernel {
     /// 2. Update base delay from `params.base_delay_ms` (in samples)
     /// 3. Apply tempo sync if enabled: derive LFO rate from `tempo.division_to_hz()`
     /// 4. Set all four LFO frequencies (voices 3/4 use irrational rate ratios)
-    /// 5. Route through stereo voice matrix with feedback and panning
+    /// 5. Route through stereo voice matrix with feedback, spread, and panning
     /// 6. Wet/dry mix → soft limit → output level
     fn process_stereo(&mut self, left: f32, right: f32, params: &ChorusParams) -> (f32, f32) {
         // ── Unit conversion ──
         let depth = params.depth_pct / 100.0; // 0–100 % → 0–1
         let mix = params.mix_pct / 100.0; // 0–100 % → 0–1
         let feedback = params.feedback_pct / 100.0; // 0–70 % → 0–0.7
+        let spread = params.spread_pct / 100.0 * 0.5; // 0–100 % → 0–0.5 cycle
         let output = fast_db_to_linear(params.output_db);
 
         // ── Base delay in samples ──
@@ -524,7 +579,8 @@ impl DspKernel for ChorusKernel {
         let voices = (libm::roundf(params.voices) as u8).clamp(2, 4);
 
         // ── Voice processing with feedback ──
-        let (wet_l, wet_r) = self.process_voices_stereo(left, right, depth, feedback, voices);
+        let (wet_l, wet_r) =
+            self.process_voices_stereo(left, right, depth, feedback, spread, voices);
 
         // ── LFO phase diagnostic (after advance) ──
         self.lfo_phase_diagnostic = self.lfo1.phase();
@@ -546,8 +602,6 @@ impl DspKernel for ChorusKernel {
         self.lfo2.reset();
         self.lfo3.reset();
         self.lfo4.reset();
-        // Restore voice 2 LFO to its initial 90° phase offset after reset.
-        self.lfo2.set_phase(0.25);
         self.fb_state = [0.0; 4];
     }
 
@@ -564,8 +618,8 @@ impl DspKernel for ChorusKernel {
     }
 
     fn is_true_stereo(&self) -> bool {
-        // Different voice panning routes left and right outputs differently —
-        // this is genuine cross-channel processing.
+        // Left and right voices sweep at the spread offset and the pan matrix
+        // blends them across channels — genuine cross-channel processing.
         true
     }
 
@@ -630,7 +684,7 @@ mod tests {
     /// Descriptor count must match `COUNT` and all indices must be populated.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(ChorusParams::COUNT, 10, "Expected 10 parameters");
+        assert_eq!(ChorusParams::COUNT, 11, "Expected 11 parameters");
 
         for i in 0..ChorusParams::COUNT {
             assert!(
@@ -791,6 +845,7 @@ mod tests {
         assert_eq!(adapter.param_info(7).unwrap().id, ParamId(708)); // division
         assert_eq!(adapter.param_info(8).unwrap().id, ParamId(703)); // output
         assert_eq!(adapter.param_info(9).unwrap().id, ParamId(709)); // lfo_phase diagnostic
+        assert_eq!(adapter.param_info(10).unwrap().id, ParamId(710)); // spread
     }
 
     // ── Morphing / preset ────────────────────────────────────────────────────
@@ -927,4 +982,55 @@ mod tests {
         let adapter = Adapter::new(ChorusKernel::new(48000.0), 48000.0);
         assert!(adapter.is_true_stereo());
     }
+
+    // ── Stereo spread ────────────────────────────────────────────────────────
+
+    /// At 0% spread the left and right voices sweep together, so a mono input
+    /// stays mono; raising the spread decorrelates the channels.
+    #[test]
+    fn spread_controls_channel_divergence() {
+        let run = |spread_pct: f32| {
+            let mut kernel = ChorusKernel::new(48000.0);
+            let params = ChorusParams {
+                rate: 2.0,
+                depth_pct: 100.0,
+                mix_pct: 100.0,
+                spread_pct,
+                ..Default::default()
+            };
+            let mut max_diff = 0.0f32;
+            for i in 0..24000 {
+                let x = libm::sinf(i as f32 * 0.05) * 0.5;
+                let (l, r) = kernel.process_stereo(x, x, &params);
+                max_diff = max_diff.max((l - r).abs());
+            }
+            max_diff
+        };
+
+        assert!(run(0.0) < 1e-6, "0% spread should keep L and R identical");
+        assert!(
+            run(100.0) > 0.05,
+            "full spread should decorrelate the channels"
+        );
+    }
+
+    /// Voice 2's LFO leads voice 1's by the spread after every sample.
+    #[test]
+    fn spread_sets_lfo_phase_offset() {
+        for (spread_pct, offset) in [(0.0, 0.0), (50.0, 0.25), (100.0, 0.5)] {
+            let mut kernel = ChorusKernel::new(48000.0);
+            let params = ChorusParams {
+                spread_pct,
+                ..Default::default()
+            };
+            for _ in 0..1000 {
+                kernel.process_stereo(0.0, 0.0, &params);
+                let diff = (kernel.lfo2.phase() - kernel.lfo1.phase()).rem_euclid(1.0);
+                assert!(
+                    (diff - offset).abs() < 1e-4,
+                    "spread {spread_pct}%: phase difference {diff}, expected {offset}"
+                );
+            }
+        }
+    }
 }
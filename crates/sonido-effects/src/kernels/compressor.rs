@@ -1,7 +1,7 @@
-//! Compressor kernel — dynamics processing with soft-knee, sidechain HPF, and lookahead.
+//! Compressor kernel — dynamics processing with soft-knee, sidechain key filter, and lookahead.
 //!
-//! `CompressorKernel` owns DSP state (envelope followers, sidechain HPF,
-//! coefficient caches, gain reduction memory). Parameters are received via
+//! `CompressorKernel` owns DSP state (envelope followers, sidechain key filter,
+//! gain reduction memory). Parameters are received via
 //! `&CompressorParams` each sample. Deployed via
 //! [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin, or called
 //! directly on embedded targets.
@@ -9,13 +9,22 @@
 //! # Signal Flow
 //!
 //! ```text
-//! Input → SC HPF (detect only) → Dual Envelope → Gain Computer → Gain Reduction
-//!           (mid signal L+R/2)     (fast + slow)       ↓
-//!                                               Makeup (auto/manual)
-//!                                                       ↓
-//!                                               Wet/Dry Mix → Soft Limit → Output
+//! Key → SC HPF + Tilt (detect only) → Dual Envelope → Gain Computer → Gain Reduction
+//!  (mid signal L+R/2)                    (fast + slow)       ↓
+//!                                                     Makeup (auto/manual)
+//!                                                             ↓
+//!                                                     Wet/Dry Mix → Soft Limit → Output
 //! ```
 //!
+//! # Key Routing
+//!
+//! The key is the main input's mid signal unless `key_source` is External and
+//! an external sidechain is supplied (graph sidechain edge or the plugin's
+//! sidechain input port), in which case the external pair's mid is used. Both
+//! keys pass through the same highpass and tilt. With `key_listen` on, the
+//! filtered key replaces the output so the detector can be auditioned while
+//! tuning the filter.
+//!
 //! # Program-Dependent Release
 //!
 //! Two parallel envelope followers run on the sidechain mid signal:
@@ -57,9 +66,8 @@
 
 use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
 use sonido_core::{
-    Biquad, Cached, DetectionMode, EnvelopeFollower, ParamDescriptor, ParamFlags, ParamId,
-    ParamScale, ParamUnit, fast_db_to_linear, fast_linear_to_db, highpass_coefficients,
-    math::soft_limit, wet_dry_mix_stereo,
+    DetectionMode, EnvelopeFollower, KeyFilter, ParamDescriptor, ParamFlags, ParamId, ParamScale,
+    ParamUnit, fast_db_to_linear, fast_linear_to_db, math::soft_limit, wet_dry_mix_stereo,
 };
 
 // ── Constants ────────────────────────────────────────────────────────────────
@@ -67,9 +75,6 @@ use sonido_core::{
 /// Fixed fast-release time for the program-dependent dual-envelope design (ms).
 const FAST_RELEASE_MS: f32 = 50.0;
 
-/// Butterworth Q for the sidechain HPF (1/√2 ≈ 0.707).
-const SC_HPF_Q: f32 = 0.707;
-
 // ═══════════════════════════════════════════════════════════════════════════
 //  Parameters
 // ═══════════════════════════════════════════════════════════════════════════
@@ -95,14 +100,18 @@ const SC_HPF_Q: f32 = 0.707;
 /// | 8 | `auto_makeup` | index | 0–1 | 0 (Off) |
 /// | 9 | `output_db` | dB | −6–6 | 0.0 |
 /// | 10 | `mix_pct` | % | 0–100 | 100.0 |
+/// | 11 | `gain_reduction_db` | dB | −60–0 | read-only |
+/// | 12 | `sidechain_tilt_db` | dB | −6–6 | 0.0 |
+/// | 13 | `key_source` | index | 0–1 | 0 (Internal) |
+/// | 14 | `key_listen` | index | 0–1 | 0 (Off) |
 ///
 /// ## Smoothing Notes
 ///
 /// - `threshold_db`, `ratio`, `knee_db`: Standard (10 ms) — avoid zipper on automation.
 /// - `attack_ms`, `release_ms`: Standard (10 ms) — timing params, smooth transitions.
 /// - `makeup_db`, `output_db`: Standard (10 ms) — level faders.
-/// - `sidechain_freq_hz`: Slow (20 ms) — filter coefficient recalc, avoid zipper.
-/// - `detection`, `auto_makeup`: None — stepped/discrete, snap immediately.
+/// - `sidechain_freq_hz`, `sidechain_tilt_db`: Slow (20 ms) — filter coefficient recalc, avoid zipper.
+/// - `detection`, `auto_makeup`, `key_source`, `key_listen`: None — stepped/discrete, snap immediately.
 /// - `mix_pct`: Standard (10 ms) — wet/dry blend.
 #[derive(Debug, Clone, Copy)]
 pub struct CompressorParams {
@@ -184,6 +193,25 @@ pub struct CompressorParams {
     /// `KernelParams` index 11 with `ParamFlags::READ_ONLY | ParamFlags::HIDDEN`.
     /// Actual value is updated by `CompressorKernel::gain_reduction_db` field.
     pub gain_reduction_db: f32,
+
+    /// Sidechain tilt in decibels.
+    ///
+    /// Range: −6.0–6.0 dB (default 0.0). Tilts the detection path around
+    /// 1 kHz after the highpass: positive values make the compressor react
+    /// more to highs (cymbals, sibilance), negative values more to lows.
+    pub sidechain_tilt_db: f32,
+
+    /// Key source: 0.0 = Internal, 1.0 = External.
+    ///
+    /// External keys the detector from the sidechain input when one is
+    /// supplied; Internal always detects from the main input. Stepped.
+    pub key_source: f32,
+
+    /// Key listen: 0.0 = Off, 1.0 = On.
+    ///
+    /// When on, the output is the filtered key signal instead of the
+    /// compressed audio. Stepped.
+    pub key_listen: f32,
 }
 
 impl Default for CompressorParams {
@@ -201,6 +229,9 @@ impl Default for CompressorParams {
             output_db: 0.0,
             mix_pct: 100.0,
             gain_reduction_db: 0.0,
+            sidechain_tilt_db: 0.0,
+            key_source: 0.0,
+            key_listen: 0.0,
         }
     }
 }
@@ -245,7 +276,7 @@ impl CompressorParams {
 }
 
 impl KernelParams for CompressorParams {
-    const COUNT: usize = 12;
+    const COUNT: usize = 15;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
@@ -253,7 +284,8 @@ impl KernelParams for CompressorParams {
             // ParamId(300), "comp_thresh" — matches classic compressor.rs [0]
             0 => Some(
                 ParamDescriptor::gain_db("Threshold", "Thresh", -60.0, 0.0, -18.0)
-                    .with_id(ParamId(300), "comp_thresh"),
+                    .with_id(ParamId(300), "comp_thresh")
+                    .with_description("Level above which gain reduction starts."),
             ),
             // ── [1] Ratio ────────────────────────────────────────────────────
             // ParamId(301), "comp_ratio" — matches classic compressor.rs [1]
@@ -261,7 +293,8 @@ impl KernelParams for CompressorParams {
                 ParamDescriptor::custom("Ratio", "Ratio", 1.0, 20.0, 4.0)
                     .with_unit(ParamUnit::Ratio)
                     .with_step(0.1)
-                    .with_id(ParamId(301), "comp_ratio"),
+                    .with_id(ParamId(301), "comp_ratio")
+                    .with_description("How strongly signal above the threshold is reduced."),
             ),
             // ── [2] Attack ───────────────────────────────────────────────────
             // ParamId(302), "comp_attack" — matches classic compressor.rs [2]
@@ -270,6 +303,9 @@ impl KernelParams for CompressorParams {
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(0.1)
                     .with_id(ParamId(302), "comp_attack")
+                    .with_description(
+                        "How fast gain reduction engages once the signal crosses the threshold.",
+                    )
                     .with_scale(ParamScale::Power(2.0)),
             ),
             // ── [3] Release ──────────────────────────────────────────────────
@@ -277,19 +313,24 @@ impl KernelParams for CompressorParams {
             3 => Some(
                 ParamDescriptor::time_ms("Release", "Release", 10.0, 1000.0, 100.0)
                     .with_id(ParamId(303), "comp_release")
+                    .with_description(
+                        "How fast gain recovers after the signal falls back below the threshold.",
+                    )
                     .with_scale(ParamScale::Power(2.0)),
             ),
             // ── [4] Makeup Gain ──────────────────────────────────────────────
             // ParamId(304), "comp_makeup" — matches classic compressor.rs [4]
             4 => Some(
                 ParamDescriptor::gain_db("Makeup Gain", "Makeup", 0.0, 24.0, 0.0)
-                    .with_id(ParamId(304), "comp_makeup"),
+                    .with_id(ParamId(304), "comp_makeup")
+                    .with_description("Gain added after compression to restore level."),
             ),
             // ── [5] Knee ─────────────────────────────────────────────────────
             // ParamId(305), "comp_knee" — matches classic compressor.rs [5]
             5 => Some(
                 ParamDescriptor::gain_db("Knee", "Knee", 0.0, 12.0, 6.0)
-                    .with_id(ParamId(305), "comp_knee"),
+                    .with_id(ParamId(305), "comp_knee")
+                    .with_description("Width of the soft transition around the threshold."),
             ),
             // ── [6] Detection mode ───────────────────────────────────────────
             // ParamId(306), "comp_detect" — matches classic compressor.rs [6]
@@ -297,6 +338,10 @@ impl KernelParams for CompressorParams {
                 ParamDescriptor::custom("Detection", "Detect", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(306), "comp_detect")
+                    .with_group("Sidechain")
+                    .with_description(
+                        "Detector response: Peak follows transients, RMS follows average loudness.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Peak", "RMS"]),
             ),
@@ -307,6 +352,10 @@ impl KernelParams for CompressorParams {
                     .with_unit(ParamUnit::Hertz)
                     .with_step(1.0)
                     .with_id(ParamId(307), "comp_sc_freq")
+                    .with_group("Sidechain")
+                    .with_description(
+                        "Highpass on the detector so low end doesn't pump the compressor.",
+                    )
                     .with_scale(ParamScale::Logarithmic),
             ),
             // ── [8] Auto makeup ──────────────────────────────────────────────
@@ -315,6 +364,7 @@ impl KernelParams for CompressorParams {
                 ParamDescriptor::custom("Auto Makeup", "AutoMU", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(308), "comp_auto_makeup")
+                    .with_description("Estimate makeup gain from threshold and ratio.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -329,7 +379,10 @@ impl KernelParams for CompressorParams {
                 ParamDescriptor::custom("Mix", "Mix", 0.0, 100.0, 100.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(310), "comp_mix"),
+                    .with_id(ParamId(310), "comp_mix")
+                    .with_description(
+                        "Blend of dry and compressed signal for parallel compression.",
+                    ),
             ),
             // ── [11] Gain Reduction (READ_ONLY diagnostic) ───────────────────
             // ParamId(311), "comp_gain_reduction" — current GR in dB (always ≤ 0).
@@ -337,8 +390,45 @@ impl KernelParams for CompressorParams {
             11 => Some(
                 ParamDescriptor::gain_db("Gain Reduction", "GR", -60.0, 0.0, 0.0)
                     .with_id(ParamId(311), "comp_gain_reduction")
+                    .with_description("Gain reduction currently applied (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
+            // ── [12] Sidechain tilt ──────────────────────────────────────────
+            // ParamId(312), "comp_sc_tilt"
+            12 => Some(
+                ParamDescriptor::gain_db("SC Tilt", "SC Tilt", -6.0, 6.0, 0.0)
+                    .with_id(ParamId(312), "comp_sc_tilt")
+                    .with_group("Sidechain")
+                    .with_description(
+                        "Tilts the detector toward highs (positive) or lows (negative).",
+                    ),
+            ),
+            // ── [13] Key source ──────────────────────────────────────────────
+            // ParamId(313), "comp_key"
+            13 => Some(
+                ParamDescriptor::custom("Key", "Key", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(313), "comp_key")
+                    .with_group("Sidechain")
+                    .with_description(
+                        "Detector source: the main input, or the external sidechain input.",
+                    )
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Internal", "External"]),
+            ),
+            // ── [14] Key listen ──────────────────────────────────────────────
+            // ParamId(314), "comp_key_listen"
+            14 => Some(
+                ParamDescriptor::custom("Key Listen", "Listen", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(314), "comp_key_listen")
+                    .with_group("Sidechain")
+                    .with_description(
+                        "Output the filtered detector signal instead of the compressed audio.",
+                    )
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Off", "On"]),
+            ),
             _ => None,
         }
     }
@@ -357,6 +447,9 @@ impl KernelParams for CompressorParams {
             9 => SmoothingStyle::Standard, // output level — level fader
             10 => SmoothingStyle::Standard, // mix — wet/dry blend
             11 => SmoothingStyle::None,    // gain_reduction_db — READ_ONLY diagnostic
+            12 => SmoothingStyle::Slow,    // sidechain tilt — filter gains, avoid zipper
+            13 => SmoothingStyle::None,    // key source — stepped/discrete, snap immediately
+            14 => SmoothingStyle::None,    // key listen — stepped/discrete, snap immediately
             _ => SmoothingStyle::Standard,
         }
     }
@@ -375,6 +468,9 @@ impl KernelParams for CompressorParams {
             9 => self.output_db,
             10 => self.mix_pct,
             11 => self.gain_reduction_db,
+            12 => self.sidechain_tilt_db,
+            13 => self.key_source,
+            14 => self.key_listen,
             _ => 0.0,
         }
     }
@@ -393,6 +489,9 @@ impl KernelParams for CompressorParams {
             9 => self.output_db = value,
             10 => self.mix_pct = value,
             11 => self.gain_reduction_db = value, // READ_ONLY: kernel writes this
+            12 => self.sidechain_tilt_db = value,
+            13 => self.key_source = value,
+            14 => self.key_listen = value,
             _ => {}
         }
     }
@@ -411,9 +510,8 @@ impl KernelParams for CompressorParams {
 ///
 /// - `envelope_follower` — slow envelope (user-set release time).
 /// - `fast_envelope` — fast envelope (fixed 50 ms release for program-dependent release).
-/// - `sidechain_hpf` — biquad HPF on the detection path.
-/// - `last_gain_reduction_db` — last computed gain reduction (always ≤ 0.0 dB).
-/// - Coefficient caches (`last_*`) — avoid re-running `expf` / HPF recalc every sample.
+/// - `key_filter` — highpass + tilt on the detection path.
+/// - `gain_reduction_db` — last computed gain reduction (always ≤ 0.0 dB).
 ///
 /// # Program-Dependent Release
 ///
@@ -425,48 +523,33 @@ impl KernelParams for CompressorParams {
 /// Reference: Giannoulis, Massberg & Reiss, "Digital Dynamic Range Compressor
 /// Design — A Tutorial and Analysis", JAES 2012.
 pub struct CompressorKernel {
-    /// Sample rate — needed for HPF and envelope coefficient recalculation.
-    sample_rate: f32,
-
     /// Slow envelope follower (user-configured release time).
     envelope_follower: EnvelopeFollower,
 
     /// Fast envelope follower (fixed 50 ms release for program-dependent release).
     fast_envelope: EnvelopeFollower,
 
-    /// Sidechain highpass filter (detection path only).
+    /// Sidechain key filter (detection path only).
     ///
-    /// Applied to the linked-stereo mid signal `(L + R) / 2` before both
-    /// envelope followers. Coefficients recomputed when `sidechain_freq_hz` changes.
-    sidechain_hpf: Biquad,
+    /// Applied to the mono key — the main input's mid signal `(L + R) / 2`,
+    /// or the external sidechain's — before both envelope followers.
+    key_filter: KeyFilter,
 
     /// Last computed gain reduction in dB (always ≤ 0.0).
     ///
     /// Exposed as READ_ONLY param at index 11 via `CompressorParams::gain_reduction_db`.
     gain_reduction_db: f32,
-
-    /// Change-detector for sidechain HPF coefficients.
-    ///
-    /// Avoids re-running `highpass_coefficients()` (involves `sinf`/`cosf`)
-    /// every sample when `sidechain_freq_hz` is stable.
-    sc_hpf_cache: Cached<[f32; 6]>,
 }
 
 impl CompressorKernel {
     /// Create a new compressor kernel initialized with default parameters.
     ///
-    /// The sidechain HPF is set to 80 Hz (default). Both envelope followers are
-    /// configured for peak detection with 10 ms attack. The fast follower uses a
-    /// fixed 50 ms release; the slow follower uses 100 ms (default).
+    /// The sidechain HPF is set to 80 Hz (default) with no tilt. Both envelope
+    /// followers are configured for peak detection with 10 ms attack. The fast
+    /// follower uses a fixed 50 ms release; the slow follower uses 100 ms (default).
     pub fn new(sample_rate: f32) -> Self {
         let defaults = CompressorParams::default();
 
-        // Initialize sidechain HPF at default 80 Hz
-        let mut sidechain_hpf = Biquad::new();
-        let (b0, b1, b2, a0, a1, a2) =
-            highpass_coefficients(defaults.sidechain_freq_hz, SC_HPF_Q, sample_rate);
-        sidechain_hpf.set_coefficients(b0, b1, b2, a0, a1, a2);
-
         // Slow envelope: user-controlled release
         let mut envelope_follower = EnvelopeFollower::new(sample_rate);
         envelope_follower.set_attack_ms(defaults.attack_ms);
@@ -477,53 +560,24 @@ impl CompressorKernel {
         fast_envelope.set_attack_ms(defaults.attack_ms);
         fast_envelope.set_release_ms(FAST_RELEASE_MS);
 
-        let initial_sc_hpf_coeffs = {
-            let (b0, b1, b2, a0, a1, a2) =
-                highpass_coefficients(defaults.sidechain_freq_hz, SC_HPF_Q, sample_rate);
-            [b0, b1, b2, a0, a1, a2]
-        };
-        let mut sc_hpf_cache = Cached::new(initial_sc_hpf_coeffs, 1);
-        sc_hpf_cache.update(&[defaults.sidechain_freq_hz], 0.5, |inputs| {
-            let (b0, b1, b2, a0, a1, a2) =
-                highpass_coefficients(inputs[0].clamp(20.0, 500.0), SC_HPF_Q, sample_rate);
-            [b0, b1, b2, a0, a1, a2]
-        });
-
         Self {
-            sample_rate,
             envelope_follower,
             fast_envelope,
-            sidechain_hpf,
+            key_filter: KeyFilter::new(sample_rate, defaults.sidechain_freq_hz),
             gain_reduction_db: 0.0,
-            sc_hpf_cache,
         }
     }
 
     /// Update cached state when relevant parameters change.
     ///
-    /// Comparisons use small epsilon thresholds to avoid redundant recalculation
-    /// while smoothed parameters are advancing. Only recomputes what actually changed:
-    /// - HPF coefficients when `sidechain_freq_hz` changes.
-    /// - Attack coefficients for both followers when `attack_ms` changes.
-    /// - Slow release coefficient when `release_ms` changes.
-    /// - Detection mode on both followers when `detection` changes.
+    /// - Key filter highpass/tilt (coefficients cached inside [`KeyFilter`]).
+    /// - Attack coefficients for both followers.
+    /// - Slow release coefficient.
+    /// - Detection mode on both followers.
     #[inline]
     fn update_caches(&mut self, params: &CompressorParams) {
-        let sc_coeffs = *self
-            .sc_hpf_cache
-            .update(&[params.sidechain_freq_hz], 0.5, |inputs| {
-                let (b0, b1, b2, a0, a1, a2) =
-                    highpass_coefficients(inputs[0].clamp(20.0, 500.0), SC_HPF_Q, self.sample_rate);
-                [b0, b1, b2, a0, a1, a2]
-            });
-        self.sidechain_hpf.set_coefficients(
-            sc_coeffs[0],
-            sc_coeffs[1],
-            sc_coeffs[2],
-            sc_coeffs[3],
-            sc_coeffs[4],
-            sc_coeffs[5],
-        );
+        self.key_filter
+            .set_controls(params.sidechain_freq_hz, params.sidechain_tilt_db);
 
         // attack_ms / release_ms / detection: EnvelopeFollower::set_*() is cheap
         // (stores a float + recomputes a single expf). Always update — no Cached needed.
@@ -600,32 +654,20 @@ impl CompressorKernel {
         let auto_db = -threshold_db * (1.0 - 1.0 / ratio) * 0.5;
         fast_db_to_linear(auto_db)
     }
-}
 
-impl DspKernel for CompressorKernel {
-    type Params = CompressorParams;
-
-    /// Process a stereo sample pair with linked-stereo compression.
+    /// Filter `key`, run detection and the gain computer, and apply the
+    /// result to `left`/`right`.
     ///
-    /// The detection signal is the mid signal `(L + R) / 2`, so both channels
-    /// receive identical gain reduction. This preserves the stereo image — if
-    /// L and R were compressed independently, image shift would occur whenever
-    /// only one channel exceeds the threshold.
-    ///
-    /// Signal path:
-    /// 1. Mid signal → sidechain HPF → dual envelope → gain computer
-    /// 2. Gain reduction (dB) → linear → multiply makeup gain
-    /// 3. Apply `comp_gain` to both L and R
-    /// 4. Wet/dry blend (`mix_pct`)
-    /// 5. Soft limit (ceiling 1.0) → output level
-    fn process_stereo(&mut self, left: f32, right: f32, params: &CompressorParams) -> (f32, f32) {
-        // ── Update coefficient caches ──────────────────────────────────────
-        self.update_caches(params);
-
-        // ── Linked-stereo sidechain detection ─────────────────────────────
-        // Mid signal avoids image shift from independent L/R compression
-        let mid = (left + right) * 0.5;
-        let detection = self.sidechain_hpf.process(mid);
+    /// With `key_listen` on, returns the filtered key on both channels instead.
+    #[inline]
+    fn compress(
+        &mut self,
+        left: f32,
+        right: f32,
+        key: f32,
+        params: &CompressorParams,
+    ) -> (f32, f32) {
+        let detection = self.key_filter.process(key);
         let envelope = self.dual_envelope(detection);
 
         // ── Gain computer ─────────────────────────────────────────────────
@@ -637,6 +679,11 @@ impl DspKernel for CompressorKernel {
             params.knee_db,
         );
         self.gain_reduction_db = gain_reduction_db;
+
+        if params.key_listen >= 0.5 {
+            return (detection, detection);
+        }
+
         let gain_linear = fast_db_to_linear(gain_reduction_db);
 
         // ── Makeup gain (auto or manual) ──────────────────────────────────
@@ -662,17 +709,39 @@ impl DspKernel for CompressorKernel {
             soft_limit(mixed_r, 1.0) * output,
         )
     }
+}
 
-    /// Process a stereo sample with an external sidechain signal.
+impl DspKernel for CompressorKernel {
+    type Params = CompressorParams;
+
+    /// Process a stereo sample pair with linked-stereo compression.
     ///
-    /// Bypasses the internal sidechain HPF and the derived mid signal: the
-    /// external `sc_left`/`sc_right` average is used directly as the detection
-    /// signal. This is the standard "key input" / "sidechain input" workflow:
-    /// e.g., feed a kick drum to compress a bass line.
+    /// The detection signal is the mid signal `(L + R) / 2`, so both channels
+    /// receive identical gain reduction. This preserves the stereo image — if
+    /// L and R were compressed independently, image shift would occur whenever
+    /// only one channel exceeds the threshold.
     ///
-    /// The compressor's internal HPF (`sidechain_freq_hz`) is skipped when an
-    /// external sidechain is connected. If pre-filtering the external signal is
-    /// needed, insert a filter node before the sidechain source in the graph.
+    /// Signal path:
+    /// 1. Mid signal → key filter (HPF + tilt) → dual envelope → gain computer
+    /// 2. Gain reduction (dB) → linear → multiply makeup gain
+    /// 3. Apply `comp_gain` to both L and R
+    /// 4. Wet/dry blend (`mix_pct`)
+    /// 5. Soft limit (ceiling 1.0) → output level
+    fn process_stereo(&mut self, left: f32, right: f32, params: &CompressorParams) -> (f32, f32) {
+        self.update_caches(params);
+
+        // Mid signal avoids image shift from independent L/R compression
+        let mid = (left + right) * 0.5;
+        self.compress(left, right, mid, params)
+    }
+
+    /// Process a stereo sample with an external sidechain signal.
+    ///
+    /// With `key_source` set to External, the `sc_left`/`sc_right` average
+    /// replaces the main mid signal as the key — the standard "key input"
+    /// workflow, e.g. feeding a kick drum to duck a bass line. The external
+    /// key passes through the same highpass and tilt as the internal one.
+    /// With `key_source` Internal the sidechain is ignored.
     fn process_stereo_with_sidechain(
         &mut self,
         left: f32,
@@ -681,62 +750,27 @@ impl DspKernel for CompressorKernel {
         sc_right: f32,
         params: &CompressorParams,
     ) -> (f32, f32) {
-        // Update caches (attack/release/detection mode) — all apply.
         self.update_caches(params);
 
-        // External sidechain: use mono average of SC channels directly.
-        // Skip the internal HPF — the external source is pre-routed by the user.
-        let sc_mid = (sc_left + sc_right) * 0.5;
-        let envelope = self.dual_envelope(sc_mid);
-
-        // ── Gain computer ─────────────────────────────────────────────────
-        let envelope_db = fast_linear_to_db(envelope);
-        let gain_reduction_db = Self::compute_gain_db(
-            envelope_db,
-            params.threshold_db,
-            params.ratio,
-            params.knee_db,
-        );
-        self.gain_reduction_db = gain_reduction_db;
-        let gain_linear = fast_db_to_linear(gain_reduction_db);
-
-        // ── Makeup gain (auto or manual) ──────────────────────────────────
-        let makeup = if params.auto_makeup >= 0.5 {
-            Self::auto_makeup_linear(params.threshold_db, params.ratio)
+        let key = if params.key_source >= 0.5 {
+            (sc_left + sc_right) * 0.5
         } else {
-            fast_db_to_linear(params.makeup_db)
+            (left + right) * 0.5
         };
-
-        // ── Apply compression gain ────────────────────────────────────────
-        let comp_gain = gain_linear * makeup;
-        let comp_l = left * comp_gain;
-        let comp_r = right * comp_gain;
-
-        // ── Wet/dry blend ─────────────────────────────────────────────────
-        let mix = params.mix_pct / 100.0;
-        let (mixed_l, mixed_r) = wet_dry_mix_stereo(left, right, comp_l, comp_r, mix);
-
-        // ── Soft limit → output level ─────────────────────────────────────
-        let output = fast_db_to_linear(params.output_db);
-        (
-            soft_limit(mixed_l, 1.0) * output,
-            soft_limit(mixed_r, 1.0) * output,
-        )
+        self.compress(left, right, key, params)
     }
 
     fn reset(&mut self) {
         self.envelope_follower.reset();
         self.fast_envelope.reset();
-        self.sidechain_hpf.clear();
+        self.key_filter.reset();
         self.gain_reduction_db = 0.0;
-        self.sc_hpf_cache.invalidate();
     }
 
     fn set_sample_rate(&mut self, sample_rate: f32) {
-        self.sample_rate = sample_rate;
         self.envelope_follower.set_sample_rate(sample_rate);
         self.fast_envelope.set_sample_rate(sample_rate);
-        self.sc_hpf_cache.invalidate();
+        self.key_filter.set_sample_rate(sample_rate);
     }
 
     /// Compressor uses linked-stereo detection (cross-channel mid signal).
@@ -790,7 +824,7 @@ mod tests {
     /// Descriptor count must equal `CompressorParams::COUNT` and all must be present.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(CompressorParams::COUNT, 12);
+        assert_eq!(CompressorParams::COUNT, 15);
         for i in 0..CompressorParams::COUNT {
             assert!(
                 CompressorParams::descriptor(i).is_some(),
@@ -798,8 +832,8 @@ mod tests {
             );
         }
         assert!(
-            CompressorParams::descriptor(12).is_none(),
-            "Index 12 should return None"
+            CompressorParams::descriptor(15).is_none(),
+            "Index 15 should return None"
         );
     }
 
@@ -1037,17 +1071,17 @@ mod tests {
         let kernel = CompressorKernel::new(48000.0);
         let adapter = Adapter::new(kernel, 48000.0);
 
-        // Count (12 = 11 user params + 1 READ_ONLY diagnostic)
-        assert_eq!(adapter.param_count(), 12);
+        // Count (15 = 14 user params + 1 READ_ONLY diagnostic)
+        assert_eq!(adapter.param_count(), 15);
 
         // All present, none past end
-        for i in 0..12 {
+        for i in 0..15 {
             assert!(
                 adapter.param_info(i).is_some(),
                 "Missing param_info for index {i}"
             );
         }
-        assert!(adapter.param_info(12).is_none());
+        assert!(adapter.param_info(15).is_none());
 
         // Names match classic compressor.rs
         let expected_names = [
@@ -1085,6 +1119,9 @@ mod tests {
         assert_eq!(adapter.param_info(8).unwrap().id, ParamId(308)); // Auto Makeup
         assert_eq!(adapter.param_info(9).unwrap().id, ParamId(309)); // Output
         assert_eq!(adapter.param_info(10).unwrap().id, ParamId(310)); // Mix
+        assert_eq!(adapter.param_info(12).unwrap().id, ParamId(312)); // SC Tilt
+        assert_eq!(adapter.param_info(13).unwrap().id, ParamId(313)); // Key
+        assert_eq!(adapter.param_info(14).unwrap().id, ParamId(314)); // Key Listen
 
         // String IDs — used by CLAP host preset recall
         assert_eq!(adapter.param_info(0).unwrap().string_id, "comp_thresh");
@@ -1224,6 +1261,7 @@ mod tests {
             auto_makeup: 0.0,
             mix_pct: 100.0,
             output_db: 0.0,
+            key_source: 1.0,
             ..CompressorParams::default()
         };
 
@@ -1291,4 +1329,94 @@ mod tests {
             );
         }
     }
+
+    /// With `key_source` Internal, a supplied sidechain must not drive detection.
+    #[test]
+    fn internal_key_ignores_sidechain() {
+        let sr = 48000.0_f32;
+        let mut kernel = CompressorKernel::new(sr);
+        let params = CompressorParams {
+            threshold_db: -20.0,
+            ratio: 8.0,
+            attack_ms: 1.0,
+            ..CompressorParams::default()
+        };
+        let main_level = 0.001_f32;
+        let mut out = 0.0;
+        for i in 0..2000 {
+            let sc = 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 440.0 / sr);
+            out = kernel
+                .process_stereo_with_sidechain(main_level, main_level, sc, sc, &params)
+                .0;
+        }
+        assert!(
+            (out - main_level).abs() < 1e-5,
+            "Internal key should leave quiet main untouched, got {out}"
+        );
+    }
+
+    /// Key listen outputs the filtered key and still reports gain reduction.
+    #[test]
+    fn key_listen_outputs_filtered_key() {
+        let sr = 48000.0_f32;
+        let mut kernel = CompressorKernel::new(sr);
+        let params = CompressorParams {
+            threshold_db: -20.0,
+            sidechain_freq_hz: 500.0,
+            key_source: 1.0,
+            key_listen: 1.0,
+            ..CompressorParams::default()
+        };
+
+        // 40 Hz key is mostly removed by the 500 Hz highpass
+        let mut peak = 0.0_f32;
+        for i in 0..9600 {
+            let sc = 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 40.0 / sr);
+            let (l, r) = kernel.process_stereo_with_sidechain(0.3, 0.3, sc, sc, &params);
+            assert_eq!(l, r);
+            if i >= 4800 {
+                peak = peak.max(l.abs());
+            }
+        }
+        assert!(
+            peak < 0.05,
+            "Listen should hear the filtered key, peak={peak}"
+        );
+
+        // A 4 kHz key passes and drives gain reduction
+        for i in 0..4800 {
+            let sc = 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 4000.0 / sr);
+            kernel.process_stereo_with_sidechain(0.3, 0.3, sc, sc, &params);
+        }
+        assert!(kernel.gain_reduction_db < -3.0);
+    }
+
+    /// The adapter's block sidechain path reaches the kernel's external key.
+    #[test]
+    fn adapter_routes_sidechain() {
+        let sr = 48000.0_f32;
+        let mut adapter = Adapter::new(CompressorKernel::new(sr), sr);
+        adapter.set_param(0, -20.0); // threshold
+        adapter.set_param(1, 8.0); // ratio
+        adapter.set_param(13, 1.0); // key: External
+        adapter.reset();
+
+        let main = [0.001_f32; 1024];
+        let key: Vec<f32> = (0..1024)
+            .map(|i| 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 440.0 / sr))
+            .collect();
+        let mut out_l = [0.0_f32; 1024];
+        let mut out_r = [0.0_f32; 1024];
+        for _ in 0..4 {
+            adapter.process_block_stereo_with_sidechain(
+                &main, &main, &mut out_l, &mut out_r, &key, &key,
+            );
+        }
+        assert!(
+            out_l[1023] < 0.001,
+            "Key should compress main: {}",
+            out_l[1023]
+        );
+        assert!(adapter.get_param(11) < -3.0, "GR should be reported");
+    }
 }
@@ -132,24 +132,30 @@ impl KernelParams for DeesserParams {
                     .with_unit(ParamUnit::Hertz)
                     .with_step(10.0)
                     .with_id(ParamId(3000), "ds_freq")
+                    .with_description(
+                        "Detector highpass; sibilance above this frequency triggers reduction.",
+                    )
                     .with_scale(ParamScale::Logarithmic),
             ),
             // ── [1] Threshold ─────────────────────────────────────────────────
             1 => Some(
                 ParamDescriptor::gain_db("Threshold", "Thresh", -60.0, 0.0, -20.0)
-                    .with_id(ParamId(3001), "ds_thresh"),
+                    .with_id(ParamId(3001), "ds_thresh")
+                    .with_description("Level above which sibilance is reduced."),
             ),
             // ── [2] Ratio ─────────────────────────────────────────────────────
             2 => Some(
                 ParamDescriptor::custom("Ratio", "Ratio", 1.0, 20.0, 8.0)
                     .with_unit(ParamUnit::Ratio)
                     .with_step(0.1)
-                    .with_id(ParamId(3002), "ds_ratio"),
+                    .with_id(ParamId(3002), "ds_ratio")
+                    .with_description("How strongly sibilance above the threshold is reduced."),
             ),
             // ── [3] Range ─────────────────────────────────────────────────────
             3 => Some(
                 ParamDescriptor::gain_db("Range", "Range", 0.0, 24.0, 12.0)
-                    .with_id(ParamId(3003), "ds_range"),
+                    .with_id(ParamId(3003), "ds_range")
+                    .with_description("Maximum gain reduction."),
             ),
             // ── [4] Output level ──────────────────────────────────────────────
             4 => Some(
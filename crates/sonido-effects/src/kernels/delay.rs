@@ -24,6 +24,20 @@
 //! Diffusion is a two-stage Schroeder allpass cascade (13 ms and 7 ms prime
 //! delays) applied inside the feedback path for smeared, tape-like echoes.
 //!
+//! Wow and flutter modulate the read position of both delay lines with a
+//! slow sine LFO (0.6 Hz, up to ±2 ms) and a faster triangle LFO (6.5 Hz, up
+//! to ±0.15 ms), the slow drift and fast wobble of a worn tape transport or a
+//! BBD clock. Saturation soft-clips the feedback signal after the filters,
+//! so loud repeats compress and thicken instead of building up linearly:
+//!
+//! ```text
+//! sat(x) = tanh(k·x) / k,   k = 1 + 4 × saturation
+//! ```
+//!
+//! Dividing by `k` keeps small-signal loop gain at unity, so the feedback
+//! knob still sets the repeat level for quiet material. All three default to
+//! 0, where the delay is bit-identical to the unmodulated, linear loop.
+//!
 //! Tempo sync overrides the manual delay time with a musical note division
 //! at the host BPM — recomputed each time [`DspKernel::set_tempo_context()`]
 //! is called.
@@ -33,6 +47,7 @@
 //! - Feedback filtering: standard analog delay modelling (Zölzer, "DAFX" Ch. 6)
 //! - Diffusion: Schroeder allpass cascade (prime delay lengths 13 ms, 7 ms)
 //! - Tempo sync: musical note divisions via [`NoteDivision`]
+//! - Wow/flutter: Valimaki & Smith, tape transport flutter modelling
 //!
 //! # Deployment
 //!
@@ -50,10 +65,10 @@
 use libm::{ceilf, logf};
 use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
 use sonido_core::{
-    AllpassFilter, Biquad, DIVISION_LABELS, InterpolatedDelay, NoteDivision, OnePole,
-    ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit, TempoContext, TempoManager,
-    fast_db_to_linear, flush_denormal, highpass_coefficients, index_to_division, wet_dry_mix,
-    wet_dry_mix_stereo,
+    AllpassFilter, Biquad, DIVISION_LABELS, InterpolatedDelay, Lfo, LfoWaveform, NoteDivision,
+    OnePole, ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit, TempoContext,
+    TempoManager, fast_db_to_linear, flush_denormal, highpass_coefficients, index_to_division,
+    soft_clip, wet_dry_mix, wet_dry_mix_stereo,
 };
 
 // ── Constants ─────────────────────────────────────────────────────────────────
@@ -79,6 +94,27 @@ const DIFFUSION_AP1_S: f32 = 0.013;
 /// Second diffusion allpass delay in seconds (7 ms prime).
 const DIFFUSION_AP2_S: f32 = 0.007;
 
+/// Wow LFO rate in Hz — slow transport drift.
+const WOW_RATE_HZ: f32 = 0.6;
+
+/// Delay-time swing at 100 % wow, in seconds (±2 ms).
+///
+/// At 0.6 Hz this peaks at roughly ±13 cents of pitch deviation.
+const WOW_DEPTH_S: f32 = 0.002;
+
+/// Flutter LFO rate in Hz — fast capstan/clock wobble.
+const FLUTTER_RATE_HZ: f32 = 6.5;
+
+/// Delay-time swing at 100 % flutter, in seconds (±0.15 ms).
+///
+/// At 6.5 Hz this peaks at roughly ±7 cents of pitch deviation.
+const FLUTTER_DEPTH_S: f32 = 0.000_15;
+
+/// Drive added to the feedback saturator at 100 % saturation.
+///
+/// The saturator computes `tanh(k·x) / k` with `k = 1 + SATURATION_DRIVE × s`.
+const SATURATION_DRIVE: f32 = 4.0;
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Parameters
 // ═══════════════════════════════════════════════════════════════════════════
@@ -100,6 +136,9 @@ const DIFFUSION_AP2_S: f32 = 0.007;
 /// | 7 | `sync` | index | 0–1 | 0 (Off) |
 /// | 8 | `division` | index | 0–11 | 2 (Quarter) |
 /// | 9 | `output_db` | dB | −20–6 | 0.0 |
+/// | 10 | `wow_pct` | % | 0–100 | 0.0 |
+/// | 11 | `flutter_pct` | % | 0–100 | 0.0 |
+/// | 12 | `saturation_pct` | % | 0–100 | 0.0 |
 #[derive(Debug, Clone, Copy)]
 pub struct DelayParams {
     /// Delay time in milliseconds.
@@ -161,6 +200,25 @@ pub struct DelayParams {
     ///
     /// Range: −20.0 to +20.0 dB. Applied after the wet/dry mix.
     pub output_db: f32,
+
+    /// Wow depth in percent.
+    ///
+    /// Range: 0.0 to 100.0 %. Slow (0.6 Hz) sine modulation of the delay
+    /// time, up to ±2 ms at 100 %. Repeats drift gently in pitch.
+    pub wow_pct: f32,
+
+    /// Flutter depth in percent.
+    ///
+    /// Range: 0.0 to 100.0 %. Fast (6.5 Hz) triangle modulation of the delay
+    /// time, up to ±0.15 ms at 100 %. Adds a subtle warble to repeats.
+    pub flutter_pct: f32,
+
+    /// Feedback saturation amount in percent.
+    ///
+    /// Range: 0.0 to 100.0 %. At 0 % the feedback loop is linear. Higher
+    /// values soft-clip each repeat, compressing loud echoes and keeping
+    /// high-feedback settings from running away.
+    pub saturation_pct: f32,
 }
 
 impl Default for DelayParams {
@@ -177,6 +235,9 @@ impl Default for DelayParams {
             sync: 0.0,
             division: 2.0,
             output_db: 0.0,
+            wow_pct: 0.0,
+            flutter_pct: 0.0,
+            saturation_pct: 0.0,
         }
     }
 }
@@ -199,6 +260,9 @@ impl DelayParams {
     /// - `sync`: Sync toggle → 0.0 = Off, 1.0 = On (stepped)
     /// - `division`: Division selector → index 0–11 (stepped)
     /// - `output`: Output knob → −20–+6 dB
+    ///
+    /// Wow, flutter and saturation have no dedicated knob and stay at their
+    /// defaults (0 %).
     #[allow(clippy::too_many_arguments)]
     pub fn from_knobs(
         time: f32,
@@ -219,13 +283,14 @@ impl DelayParams {
 }
 
 impl KernelParams for DelayParams {
-    const COUNT: usize = 10;
+    const COUNT: usize = 13;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
             0 => Some(
                 ParamDescriptor::time_ms("Delay Time", "Time", 1.0, 2000.0, 300.0)
                     .with_id(ParamId(1100), "dly_time")
+                    .with_description("Time between repeats when Sync is off.")
                     .with_scale(ParamScale::Logarithmic),
             ),
             1 => Some(
@@ -239,9 +304,16 @@ impl KernelParams for DelayParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1101), "dly_feedback"),
+                .with_id(ParamId(1101), "dly_feedback")
+                .with_description(
+                    "Level of each repeat relative to the last; sets how long echoes last.",
+                ),
+            ),
+            2 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(1102), "dly_mix")
+                    .with_description("Blend of dry and delayed signal."),
             ),
-            2 => Some(ParamDescriptor::mix().with_id(ParamId(1102), "dly_mix")),
             3 => Some(
                 ParamDescriptor {
                     name: "Ping Pong",
@@ -254,18 +326,27 @@ impl KernelParams for DelayParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1103), "dly_ping_pong")
+                .with_description("Bounce repeats between left and right.")
                 .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                 .with_step_labels(&["Off", "On"]),
             ),
             4 => Some(
                 ParamDescriptor::custom("Feedback LP", "Fb LP", 200.0, 20000.0, 20000.0)
                     .with_id(ParamId(1105), "dly_fb_lp")
+                    .with_group("Feedback Filter")
+                    .with_description(
+                        "Lowpass in the feedback path; lower values darken each repeat.",
+                    )
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic),
             ),
             5 => Some(
                 ParamDescriptor::custom("Feedback HP", "Fb HP", 20.0, 2000.0, 20.0)
                     .with_id(ParamId(1106), "dly_fb_hp")
+                    .with_group("Feedback Filter")
+                    .with_description(
+                        "Highpass in the feedback path; higher values thin out each repeat.",
+                    )
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic),
             ),
@@ -280,7 +361,10 @@ impl KernelParams for DelayParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1107), "dly_diffusion"),
+                .with_id(ParamId(1107), "dly_diffusion")
+                .with_description(
+                    "Smears repeats through allpass diffusion for a softer, reverb-like tail.",
+                ),
             ),
             7 => Some(
                 ParamDescriptor {
@@ -294,6 +378,8 @@ impl KernelParams for DelayParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1108), "dly_sync")
+                .with_group("Tempo Sync")
+                .with_description("Lock the delay time to the host tempo using Division.")
                 .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                 .with_step_labels(&["Off", "On"]),
             ),
@@ -309,28 +395,80 @@ impl KernelParams for DelayParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1109), "dly_division")
+                .with_group("Tempo Sync")
+                .with_description("Note length of the delay time when Sync is on.")
                 .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                 .with_step_labels(DIVISION_LABELS),
             ),
             9 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1104), "dly_output"),
             ),
+            10 => Some(
+                ParamDescriptor {
+                    name: "Wow",
+                    short_name: "Wow",
+                    unit: ParamUnit::Percent,
+                    min: 0.0,
+                    max: 100.0,
+                    default: 0.0,
+                    step: 1.0,
+                    ..ParamDescriptor::mix()
+                }
+                .with_id(ParamId(1110), "dly_wow")
+                .with_group("Character")
+                .with_description("Slow pitch drift of the repeats, like a tape transport."),
+            ),
+            11 => Some(
+                ParamDescriptor {
+                    name: "Flutter",
+                    short_name: "Flut",
+                    unit: ParamUnit::Percent,
+                    min: 0.0,
+                    max: 100.0,
+                    default: 0.0,
+                    step: 1.0,
+                    ..ParamDescriptor::mix()
+                }
+                .with_id(ParamId(1111), "dly_flutter")
+                .with_group("Character")
+                .with_description("Fast pitch warble of the repeats."),
+            ),
+            12 => Some(
+                ParamDescriptor {
+                    name: "Saturation",
+                    short_name: "Sat",
+                    unit: ParamUnit::Percent,
+                    min: 0.0,
+                    max: 100.0,
+                    default: 0.0,
+                    step: 1.0,
+                    ..ParamDescriptor::mix()
+                }
+                .with_id(ParamId(1112), "dly_saturation")
+                .with_group("Character")
+                .with_description(
+                    "Soft clipping in the feedback path; loud repeats compress and thicken.",
+                ),
+            ),
             _ => None,
         }
     }
 
     fn smoothing(index: usize) -> SmoothingStyle {
         match index {
-            0 => SmoothingStyle::Slow,         // time_ms — 20 ms, prevents zipper/pitch clicks
-            1 => SmoothingStyle::Standard,     // feedback_pct — 10 ms
-            2 => SmoothingStyle::Standard,     // mix_pct — 10 ms
-            3 => SmoothingStyle::None,         // ping_pong — stepped toggle, snap
-            4 => SmoothingStyle::Slow,         // fb_lp_hz — 20 ms, filter coefficients
-            5 => SmoothingStyle::Slow,         // fb_hp_hz — 20 ms, filter coefficients
-            6 => SmoothingStyle::Standard,     // diffusion_pct — 10 ms
-            7 => SmoothingStyle::None,         // sync — stepped toggle, snap
-            8 => SmoothingStyle::None,         // division — stepped enum, snap
-            9 => SmoothingStyle::Standard,     // output_db — 10 ms
+            0 => SmoothingStyle::Slow, // time_ms — 20 ms, prevents zipper/pitch clicks
+            1 => SmoothingStyle::Standard, // feedback_pct — 10 ms
+            2 => SmoothingStyle::Standard, // mix_pct — 10 ms
+            3 => SmoothingStyle::None, // ping_pong — stepped toggle, snap
+            4 => SmoothingStyle::Slow, // fb_lp_hz — 20 ms, filter coefficients
+            5 => SmoothingStyle::Slow, // fb_hp_hz — 20 ms, filter coefficients
+            6 => SmoothingStyle::Standard, // diffusion_pct — 10 ms
+            7 => SmoothingStyle::None, // sync — stepped toggle, snap
+            8 => SmoothingStyle::None, // division — stepped enum, snap
+            9 => SmoothingStyle::Standard, // output_db — 10 ms
+            10 => SmoothingStyle::Slow, // wow_pct — 20 ms, modulates delay time
+            11 => SmoothingStyle::Slow, // flutter_pct — 20 ms, modulates delay time
+            12 => SmoothingStyle::Standard, // saturation_pct — 10 ms
             _ => SmoothingStyle::Standard,
         }
     }
@@ -347,6 +485,9 @@ impl KernelParams for DelayParams {
             7 => self.sync,
             8 => self.division,
             9 => self.output_db,
+            10 => self.wow_pct,
+            11 => self.flutter_pct,
+            12 => self.saturation_pct,
             _ => 0.0,
         }
     }
@@ -363,6 +504,9 @@ impl KernelParams for DelayParams {
             7 => self.sync = value,
             8 => self.division = value,
             9 => self.output_db = value,
+            10 => self.wow_pct = value,
+            11 => self.flutter_pct = value,
+            12 => self.saturation_pct = value,
             _ => {}
         }
     }
@@ -380,6 +524,7 @@ impl KernelParams for DelayParams {
 /// - One-pole lowpass filters in the feedback path (L/R)
 /// - Biquad highpass filters in the feedback path (L/R)
 /// - Schroeder allpass diffusers — two per channel (L/R × 2)
+/// - Wow (sine) and flutter (triangle) LFOs shared by both delay lines
 /// - A [`TempoManager`] for tempo-synced delay times
 /// - Cached filter frequencies to avoid unnecessary coefficient recalculations
 /// - Cached `ping_pong` flag for `is_true_stereo()`
@@ -414,6 +559,10 @@ pub struct DelayKernel {
     diffusion_ap1_r: AllpassFilter,
     /// Second Schroeder allpass diffuser — right channel (7 ms prime delay).
     diffusion_ap2_r: AllpassFilter,
+    /// Sine LFO for wow (slow delay-time drift, 0.6 Hz).
+    wow_lfo: Lfo,
+    /// Triangle LFO for flutter (fast delay-time wobble, 6.5 Hz).
+    flutter_lfo: Lfo,
     /// Tempo manager for BPM-synced delay times.
     tempo: TempoManager,
     /// Current sample rate in Hz.
@@ -466,6 +615,11 @@ impl DelayKernel {
         feedback_hp_l.set_coefficients(b0, b1, b2, a0, a1, a2);
         feedback_hp_r.set_coefficients(b0, b1, b2, a0, a1, a2);
 
+        let mut wow_lfo = Lfo::new(sample_rate, WOW_RATE_HZ);
+        wow_lfo.set_waveform(LfoWaveform::Sine);
+        let mut flutter_lfo = Lfo::new(sample_rate, FLUTTER_RATE_HZ);
+        flutter_lfo.set_waveform(LfoWaveform::Triangle);
+
         Self {
             delay_line_l: InterpolatedDelay::new(max_delay_samples),
             delay_line_r: InterpolatedDelay::new(max_delay_samples),
@@ -477,6 +631,8 @@ impl DelayKernel {
             diffusion_ap2_l: AllpassFilter::new(ap2_samples.max(1)),
             diffusion_ap1_r: AllpassFilter::new(ap1_samples.max(1)),
             diffusion_ap2_r: AllpassFilter::new(ap2_samples.max(1)),
+            wow_lfo,
+            flutter_lfo,
             tempo: TempoManager::new(sample_rate, 120.0),
             sample_rate,
             max_delay_samples: max_delay_samples as f32,
@@ -543,6 +699,32 @@ impl DelayKernel {
         }
     }
 
+    /// Advance the wow/flutter LFOs and return the modulated read position.
+    ///
+    /// The LFOs run continuously so that raising wow or flutter mid-stream
+    /// picks up the transport's current phase. At 0 % depth the offset is
+    /// exactly zero and `delay_samples` passes through unchanged.
+    #[inline]
+    fn modulated_delay_samples(&mut self, delay_samples: f32, params: &DelayParams) -> f32 {
+        let wow = self.wow_lfo.advance() * (params.wow_pct / 100.0) * WOW_DEPTH_S;
+        let flutter = self.flutter_lfo.advance() * (params.flutter_pct / 100.0) * FLUTTER_DEPTH_S;
+        let offset = (wow + flutter) * self.sample_rate;
+        (delay_samples + offset).clamp(1.0, self.max_delay_samples - 1.0)
+    }
+
+    /// Soft-clip the filtered feedback signal: `tanh(k·x) / k`.
+    ///
+    /// Bypassed at 0 % so the linear loop stays bit-exact.
+    #[inline]
+    fn saturate_feedback(signal: f32, saturation: f32) -> f32 {
+        if saturation > 0.0 {
+            let k = 1.0 + SATURATION_DRIVE * saturation;
+            soft_clip(k * signal) / k
+        } else {
+            signal
+        }
+    }
+
     /// Process the feedback signal through LP → HP → (optional) diffusion — left channel.
     #[inline]
     fn filter_feedback_l(&mut self, signal: f32) -> f32 {
@@ -578,9 +760,11 @@ impl DspKernel for DelayKernel {
     /// ## Per-sample steps
     ///
     /// 1. Update LP/HP/diffusion coefficients if parameters have changed.
-    /// 2. Resolve effective delay time (manual or tempo-synced).
+    /// 2. Resolve effective delay time (manual or tempo-synced) and apply
+    ///    wow/flutter modulation.
     /// 3. Read the delayed signal from both delay lines.
-    /// 4. Build the feedback signal: `delayed × feedback → LP → HP → diffusion`.
+    /// 4. Build the feedback signal:
+    ///    `delayed × feedback → LP → HP → diffusion → saturation`.
     ///    In ping-pong mode, feedback crosses channels (L delay reads from R's
     ///    delayed signal and vice versa).
     /// 5. Write `input + filtered_feedback` into each delay line (denormal-flushed).
@@ -595,6 +779,7 @@ impl DspKernel for DelayKernel {
         let feedback = params.feedback_pct / 100.0; // 0–0.95
         let mix = params.mix_pct / 100.0; // 0–1
         let output_gain = fast_db_to_linear(params.output_db);
+        let saturation = params.saturation_pct / 100.0; // 0–1
         let delay_samples = self.effective_delay_samples(params);
 
         // Track ping-pong for is_true_stereo(), and delay/feedback for tail_samples()
@@ -602,21 +787,26 @@ impl DspKernel for DelayKernel {
         self.cached_delay_samples = delay_samples;
         self.cached_feedback = feedback;
 
-        // ── Read delayed signals ──
-        let delayed_l = self.delay_line_l.read(delay_samples);
-        let delayed_r = self.delay_line_r.read(delay_samples);
+        // ── Read delayed signals (wow/flutter-modulated) ──
+        let read_samples = self.modulated_delay_samples(delay_samples, params);
+        let delayed_l = self.delay_line_l.read(read_samples);
+        let delayed_r = self.delay_line_r.read(read_samples);
 
         // ── Feedback path ──
         if self.cached_ping_pong {
             // Ping-pong: L delay receives R's delayed signal, R receives L's.
-            let filtered_l = self.filter_feedback_l(delayed_r * feedback);
-            let filtered_r = self.filter_feedback_r(delayed_l * feedback);
+            let filtered_l =
+                Self::saturate_feedback(self.filter_feedback_l(delayed_r * feedback), saturation);
+            let filtered_r =
+                Self::saturate_feedback(self.filter_feedback_r(delayed_l * feedback), saturation);
             self.delay_line_l.write(flush_denormal(left + filtered_l));
             self.delay_line_r.write(flush_denormal(right + filtered_r));
         } else {
             // Standard: each channel feeds back into itself.
-            let filtered_l = self.filter_feedback_l(delayed_l * feedback);
-            let filtered_r = self.filter_feedback_r(delayed_r * feedback);
+            let filtered_l =
+                Self::saturate_feedback(self.filter_feedback_l(delayed_l * feedback), saturation);
+            let filtered_r =
+                Self::saturate_feedback(self.filter_feedback_r(delayed_r * feedback), saturation);
             self.delay_line_l.write(flush_denormal(left + filtered_l));
             self.delay_line_r.write(flush_denormal(right + filtered_r));
         }
@@ -642,10 +832,13 @@ impl DspKernel for DelayKernel {
         let feedback = params.feedback_pct / 100.0;
         let mix = params.mix_pct / 100.0;
         let output_gain = fast_db_to_linear(params.output_db);
+        let saturation = params.saturation_pct / 100.0;
         let delay_samples = self.effective_delay_samples(params);
 
-        let delayed = self.delay_line_l.read(delay_samples);
-        let filtered = self.filter_feedback_l(delayed * feedback);
+        let read_samples = self.modulated_delay_samples(delay_samples, params);
+        let delayed = self.delay_line_l.read(read_samples);
+        let filtered =
+            Self::saturate_feedback(self.filter_feedback_l(delayed * feedback), saturation);
         self.delay_line_l.write(flush_denormal(input + filtered));
 
         let comp = sonido_core::gain::feedback_wet_compensation(feedback);
@@ -663,6 +856,8 @@ impl DspKernel for DelayKernel {
         self.diffusion_ap2_l.clear();
         self.diffusion_ap1_r.clear();
         self.diffusion_ap2_r.clear();
+        self.wow_lfo.reset();
+        self.flutter_lfo.reset();
     }
 
     fn set_sample_rate(&mut self, sample_rate: f32) {
@@ -694,6 +889,8 @@ impl DspKernel for DelayKernel {
         self.feedback_hp_l.set_coefficients(b0, b1, b2, a0, a1, a2);
         self.feedback_hp_r.set_coefficients(b0, b1, b2, a0, a1, a2);
 
+        self.wow_lfo.set_sample_rate(sample_rate);
+        self.flutter_lfo.set_sample_rate(sample_rate);
         self.tempo.set_sample_rate(sample_rate);
     }
 
@@ -805,11 +1002,11 @@ mod tests {
 
     // ── Parameter count ────────────────────────────────────────────────────
 
-    /// `DelayParams::COUNT` must equal 10 and every descriptor index must be
+    /// `DelayParams::COUNT` must equal 13 and every descriptor index must be
     /// `Some`, while the index beyond `COUNT` returns `None`.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(DelayParams::COUNT, 10, "Expected exactly 10 parameters");
+        assert_eq!(DelayParams::COUNT, 13, "Expected exactly 13 parameters");
 
         for i in 0..DelayParams::COUNT {
             assert!(
@@ -875,12 +1072,16 @@ mod tests {
             ParamId(1104),
             "output must be ParamId(1104) — non-sequential by design"
         );
+        assert_eq!(p(10).id, ParamId(1110), "wow must be ParamId(1110)");
+        assert_eq!(p(11).id, ParamId(1111), "flutter must be ParamId(1111)");
+        assert_eq!(p(12).id, ParamId(1112), "saturation must be ParamId(1112)");
 
         // Verify string IDs
         assert_eq!(p(0).string_id, "dly_time");
         assert_eq!(p(3).string_id, "dly_ping_pong");
         assert_eq!(p(4).string_id, "dly_fb_lp");
         assert_eq!(p(9).string_id, "dly_output");
+        assert_eq!(p(12).string_id, "dly_saturation");
 
         // Verify division labels are present
         assert_eq!(
@@ -912,6 +1113,9 @@ mod tests {
             sync: 0.0,
             division: 5.0,
             output_db: -6.0,
+            wow_pct: 60.0,
+            flutter_pct: 40.0,
+            saturation_pct: 80.0,
         };
 
         for i in 0..=10 {
@@ -1100,4 +1304,107 @@ mod tests {
              lp={energy_lp:.4}, clean={energy_clean:.4}"
         );
     }
+
+    // ── Wow / flutter ──────────────────────────────────────────────────────
+
+    /// Wow must move the read position: a steady sine through a fully wet
+    /// delay comes out different once the delay time is modulated.
+    #[test]
+    fn wow_modulates_repeats() {
+        let sr = 48000.0_f32;
+        let run = |wow_pct: f32, flutter_pct: f32| {
+            let mut kernel = DelayKernel::new(sr);
+            let params = DelayParams {
+                time_ms: 100.0,
+                feedback_pct: 0.0,
+                mix_pct: 100.0,
+                wow_pct,
+                flutter_pct,
+                ..DelayParams::default()
+            };
+            (0..48000)
+                .map(|i| {
+                    let x = libm::sinf(core::f32::consts::TAU * 1000.0 * i as f32 / sr) * 0.5;
+                    kernel.process_stereo(x, x, &params).0
+                })
+                .collect::<Vec<f32>>()
+        };
+
+        let dry = run(0.0, 0.0);
+        for (wow, flutter) in [(100.0, 0.0), (0.0, 100.0)] {
+            let wet = run(wow, flutter);
+            let max_diff = dry
+                .iter()
+                .zip(&wet)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0_f32, f32::max);
+            assert!(
+                max_diff > 0.05,
+                "wow={wow} flutter={flutter} should alter the repeats, max diff {max_diff}"
+            );
+            assert!(wet.iter().all(|x| x.is_finite()));
+        }
+    }
+
+    // ── Feedback saturation ────────────────────────────────────────────────
+
+    /// Saturation must hold a hot, high-feedback loop well below the level
+    /// the linear loop builds up to.
+    #[test]
+    fn saturation_bounds_feedback_buildup() {
+        let sr = 48000.0_f32;
+        let peak = |saturation_pct: f32| {
+            let mut kernel = DelayKernel::new(sr);
+            let params = DelayParams {
+                time_ms: 20.0,
+                feedback_pct: 95.0,
+                mix_pct: 100.0,
+                saturation_pct,
+                ..DelayParams::default()
+            };
+            let mut peak = 0.0_f32;
+            for i in 0..48000 {
+                let x = libm::sinf(core::f32::consts::TAU * 200.0 * i as f32 / sr);
+                let (l, _) = kernel.process_stereo(x, x, &params);
+                assert!(l.is_finite(), "saturation={saturation_pct}: NaN/Inf at {i}");
+                peak = peak.max(l.abs());
+            }
+            peak
+        };
+
+        let linear = peak(0.0);
+        let saturated = peak(100.0);
+        assert!(
+            saturated < linear * 0.5,
+            "saturated peak {saturated} should sit well below linear peak {linear}"
+        );
+    }
+
+    /// Quiet repeats pass through the saturator nearly unchanged, so the
+    /// feedback knob keeps its meaning at low levels.
+    #[test]
+    fn saturation_transparent_at_low_level() {
+        let sr = 48000.0_f32;
+        let tail = |saturation_pct: f32| {
+            let mut kernel = DelayKernel::new(sr);
+            let params = DelayParams {
+                time_ms: 10.0,
+                feedback_pct: 50.0,
+                mix_pct: 100.0,
+                saturation_pct,
+                ..DelayParams::default()
+            };
+            kernel.process_stereo(0.01, 0.01, &params);
+            (0..2400)
+                .map(|_| kernel.process_stereo(0.0, 0.0, &params).0.abs())
+                .sum::<f32>()
+        };
+
+        let linear = tail(0.0);
+        let saturated = tail(100.0);
+        assert!(
+            (saturated - linear).abs() < linear * 0.01,
+            "low-level tail should be unchanged: linear={linear}, saturated={saturated}"
+        );
+    }
 }
@@ -137,13 +137,15 @@ impl KernelParams for DistortionParams {
         match index {
             0 => Some(
                 ParamDescriptor::gain_db("Drive", "Drive", 0.0, 40.0, 8.0)
-                    .with_id(ParamId(200), "dist_drive"),
+                    .with_id(ParamId(200), "dist_drive")
+                    .with_description("Gain into the waveshaper; more drive, more saturation."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Tone", "Tone", -12.0, 12.0, 0.0)
                     .with_unit(ParamUnit::Decibels)
                     .with_step(0.5)
-                    .with_id(ParamId(201), "dist_tone"),
+                    .with_id(ParamId(201), "dist_tone")
+                    .with_description("Boost or cut around 1 kHz after clipping."),
             ),
             2 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(202), "dist_output"),
@@ -152,6 +154,7 @@ impl KernelParams for DistortionParams {
                 ParamDescriptor::custom("Waveshape", "Shape", 0.0, 3.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(203), "dist_shape")
+                    .with_description("Clipping curve used by the waveshaper.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Soft Clip", "Hard Clip", "Foldback", "Asymmetric"]),
             ),
@@ -159,13 +162,17 @@ impl KernelParams for DistortionParams {
                 ParamDescriptor::custom("Mix", "Mix", 0.0, 100.0, 100.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(204), "dist_mix"),
+                    .with_id(ParamId(204), "dist_mix")
+                    .with_description("Blend of dry and distorted signal."),
             ),
             5 => Some(
                 ParamDescriptor::custom("Dynamics", "Dyn", 0.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(205), "dist_dynamics"),
+                    .with_id(ParamId(205), "dist_dynamics")
+                    .with_description(
+                        "Lets drive follow playing dynamics, so quiet passages clean up.",
+                    ),
             ),
             _ => None,
         }
@@ -99,7 +99,8 @@ impl KernelParams for DroneParams {
                     default: 30.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3300), "drone_root"),
+                .with_id(ParamId(3300), "drone_root")
+                .with_description("Level of the resonance at the input pitch."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -108,7 +109,8 @@ impl KernelParams for DroneParams {
                     default: 20.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3301), "drone_fifth"),
+                .with_id(ParamId(3301), "drone_fifth")
+                .with_description("Level of the resonance a fifth above."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -117,22 +119,25 @@ impl KernelParams for DroneParams {
                     default: 25.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3302), "drone_octave"),
+                .with_id(ParamId(3302), "drone_octave")
+                .with_description("Level of the resonance an octave above."),
             ),
             3 => Some(
                 ParamDescriptor::custom("Detune", "Detune", 0.0, 50.0, 5.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3303), "drone_detune"),
+                    .with_unit(ParamUnit::Cents)
+                    .with_id(ParamId(3303), "drone_detune")
+                    .with_description("Depth of slow pitch wobble per voice."),
             ),
             4 => Some(
-                // Decay stored internally in seconds; display as plain value (no Seconds unit)
                 ParamDescriptor::custom("Decay", "Decay", 0.1, 10.0, 3.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3304), "drone_decay"),
+                    .with_unit(ParamUnit::Seconds)
+                    .with_id(ParamId(3304), "drone_decay")
+                    .with_description("How long the drone sustains after the input stops."),
             ),
             5 => Some(
                 ParamDescriptor::gain_db("Output", "Out", -60.0, 6.0, 0.0)
-                    .with_id(ParamId(3305), "drone_output"),
+                    .with_id(ParamId(3305), "drone_output")
+                    .with_description("Output level after processing."),
             ),
             _ => None,
         }
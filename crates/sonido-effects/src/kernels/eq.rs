@@ -1,6 +1,6 @@
 //! 3-band parametric EQ kernel — low shelf, peaking mid, and high shelf with output gain.
 //!
-//! `EqKernel` owns DSP state (six biquad filters, sample rate, coefficient
+//! `EqKernel` owns DSP state (three two-lane biquad banks, sample rate, coefficient
 //! caches, decimation counter). Parameters are received via `&EqParams` each
 //! sample. Deployed via [`Adapter`](sonido_core::kernel::Adapter) for
 //! desktop/plugin, or called directly on embedded targets.
@@ -66,7 +66,7 @@
 use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
 use sonido_core::math::soft_limit;
 use sonido_core::{
-    Biquad, Cached, ParamDescriptor, ParamId, ParamScale, ParamUnit, fast_db_to_linear,
+    Biquad4, Cached, F32x4, ParamDescriptor, ParamId, ParamScale, ParamUnit, fast_db_to_linear,
     peaking_eq_coefficients,
 };
 
@@ -233,11 +233,15 @@ impl KernelParams for EqParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(500), "eq_low_freq")
+                .with_group("Low")
+                .with_description("Center frequency of the low band.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             1 => Some(
                 ParamDescriptor::gain_db("Low Gain", "LowGain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(501), "eq_low_gain"),
+                    .with_id(ParamId(501), "eq_low_gain")
+                    .with_group("Low")
+                    .with_description("Boost or cut of the low band."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -250,7 +254,9 @@ impl KernelParams for EqParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(502), "eq_low_q"),
+                .with_id(ParamId(502), "eq_low_q")
+                .with_group("Low")
+                .with_description("Bandwidth of the low band; higher values are narrower."),
             ),
             // Mid band
             3 => Some(
@@ -265,11 +271,15 @@ impl KernelParams for EqParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(503), "eq_mid_freq")
+                .with_group("Mid")
+                .with_description("Center frequency of the mid band.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             4 => Some(
                 ParamDescriptor::gain_db("Mid Gain", "MidGain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(504), "eq_mid_gain"),
+                    .with_id(ParamId(504), "eq_mid_gain")
+                    .with_group("Mid")
+                    .with_description("Boost or cut of the mid band."),
             ),
             5 => Some(
                 ParamDescriptor {
@@ -282,7 +292,9 @@ impl KernelParams for EqParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(505), "eq_mid_q"),
+                .with_id(ParamId(505), "eq_mid_q")
+                .with_group("Mid")
+                .with_description("Bandwidth of the mid band; higher values are narrower."),
             ),
             // High band
             6 => Some(
@@ -297,11 +309,15 @@ impl KernelParams for EqParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(506), "eq_high_freq")
+                .with_group("High")
+                .with_description("Center frequency of the high band.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             7 => Some(
                 ParamDescriptor::gain_db("High Gain", "HighGain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(507), "eq_high_gain"),
+                    .with_id(ParamId(507), "eq_high_gain")
+                    .with_group("High")
+                    .with_description("Boost or cut of the high band."),
             ),
             8 => Some(
                 ParamDescriptor {
@@ -314,7 +330,9 @@ impl KernelParams for EqParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(508), "eq_high_q"),
+                .with_id(ParamId(508), "eq_high_q")
+                .with_group("High")
+                .with_description("Bandwidth of the high band; higher values are narrower."),
             ),
             // Output
             9 => Some(
@@ -380,7 +398,7 @@ impl KernelParams for EqParams {
 /// Pure DSP 3-band parametric equalizer kernel.
 ///
 /// Contains ONLY the mutable state required for audio processing:
-/// - Six biquad filters (low/mid/high × L/R)
+/// - Three [`Biquad4`] banks (low/mid/high), left and right in lanes 0 and 1
 /// - Sample rate (for Nyquist clamping and coefficient recalculation)
 /// - Per-band coefficient caches (nine cached values — recompute only on change)
 /// - Coefficient decimation counter (recalculate at most every 32 samples)
@@ -389,27 +407,19 @@ impl KernelParams for EqParams {
 /// are recomputed at most every `COEFF_UPDATE_INTERVAL` samples when any
 /// band's frequency, gain, or Q has changed beyond `CHANGE_EPSILON`.
 ///
-/// The dual L/R biquad pairs implement a dual-mono topology: the same
+/// The L/R lanes implement a dual-mono topology: the same
 /// coefficients are applied to both channels (not true stereo decorrelation),
 /// so [`DspKernel::process_stereo`] returns `false` for `is_true_stereo`.
 pub struct EqKernel {
     /// Sample rate in Hz, used for Nyquist clamping and coefficient computation.
     sample_rate: f32,
 
-    /// Low band biquad filter — left channel.
-    low_l: Biquad,
-    /// Low band biquad filter — right channel.
-    low_r: Biquad,
-
-    /// Mid band biquad filter — left channel.
-    mid_l: Biquad,
-    /// Mid band biquad filter — right channel.
-    mid_r: Biquad,
-
-    /// High band biquad filter — left channel.
-    high_l: Biquad,
-    /// High band biquad filter — right channel.
-    high_r: Biquad,
+    /// Low band biquads — lane 0 left, lane 1 right.
+    low: Biquad4,
+    /// Mid band biquads — lane 0 left, lane 1 right.
+    mid: Biquad4,
+    /// High band biquads — lane 0 left, lane 1 right.
+    high: Biquad4,
 
     /// Change-detector for low band biquad coefficients.
     ///
@@ -433,7 +443,7 @@ pub struct EqKernel {
 impl EqKernel {
     /// Create a new parametric EQ kernel at the given sample rate.
     ///
-    /// All six biquad filters are initialised with default parameters
+    /// All band filters are initialised with default parameters
     /// (flat response: 100/1000/5000 Hz, 0 dB gain, Q=1.0).
     pub fn new(sample_rate: f32) -> Self {
         let defaults = EqParams::default();
@@ -464,62 +474,12 @@ impl EqKernel {
             sample_rate,
         );
 
-        let mut low_l = Biquad::new();
-        let mut low_r = Biquad::new();
-        low_l.set_coefficients(
-            initial_low[0],
-            initial_low[1],
-            initial_low[2],
-            initial_low[3],
-            initial_low[4],
-            initial_low[5],
-        );
-        low_r.set_coefficients(
-            initial_low[0],
-            initial_low[1],
-            initial_low[2],
-            initial_low[3],
-            initial_low[4],
-            initial_low[5],
-        );
-
-        let mut mid_l = Biquad::new();
-        let mut mid_r = Biquad::new();
-        mid_l.set_coefficients(
-            initial_mid[0],
-            initial_mid[1],
-            initial_mid[2],
-            initial_mid[3],
-            initial_mid[4],
-            initial_mid[5],
-        );
-        mid_r.set_coefficients(
-            initial_mid[0],
-            initial_mid[1],
-            initial_mid[2],
-            initial_mid[3],
-            initial_mid[4],
-            initial_mid[5],
-        );
-
-        let mut high_l = Biquad::new();
-        let mut high_r = Biquad::new();
-        high_l.set_coefficients(
-            initial_high[0],
-            initial_high[1],
-            initial_high[2],
-            initial_high[3],
-            initial_high[4],
-            initial_high[5],
-        );
-        high_r.set_coefficients(
-            initial_high[0],
-            initial_high[1],
-            initial_high[2],
-            initial_high[3],
-            initial_high[4],
-            initial_high[5],
-        );
+        let mut low = Biquad4::new();
+        Self::apply_coefficients(&mut low, initial_low);
+        let mut mid = Biquad4::new();
+        Self::apply_coefficients(&mut mid, initial_mid);
+        let mut high = Biquad4::new();
+        Self::apply_coefficients(&mut high, initial_high);
 
         let mut low_cache = Cached::new(initial_low, 3);
         low_cache.update(
@@ -548,12 +508,9 @@ impl EqKernel {
 
         Self {
             sample_rate,
-            low_l,
-            low_r,
-            mid_l,
-            mid_r,
-            high_l,
-            high_r,
+            low,
+            mid,
+            high,
             low_cache,
             mid_cache,
             high_cache,
@@ -561,11 +518,83 @@ impl EqKernel {
         }
     }
 
-    /// Apply coefficient array to a pair of biquad filters.
+    /// Apply coefficient array to the left and right lanes of a band.
     #[inline]
-    fn apply_coefficients(filter_l: &mut Biquad, filter_r: &mut Biquad, c: [f32; 6]) {
-        filter_l.set_coefficients(c[0], c[1], c[2], c[3], c[4], c[5]);
-        filter_r.set_coefficients(c[0], c[1], c[2], c[3], c[4], c[5]);
+    fn apply_coefficients(band: &mut Biquad4, c: [f32; 6]) {
+        band.set_coefficients(0, c[0], c[1], c[2], c[3], c[4], c[5]);
+        band.set_coefficients(1, c[0], c[1], c[2], c[3], c[4], c[5]);
+    }
+
+    /// Recompute the coefficients of every band whose frequency, gain, or Q
+    /// moved beyond `CHANGE_EPSILON`.
+    fn update_coefficients(&mut self, params: &EqParams) {
+        let sr = self.sample_rate;
+        let compute = |inputs: &[f32]| {
+            let freq = inputs[0].clamp(20.0, sr * 0.475);
+            let (b0, b1, b2, a0, a1, a2) = peaking_eq_coefficients(freq, inputs[2], inputs[1], sr);
+            [b0, b1, b2, a0, a1, a2]
+        };
+
+        let low_c = *self.low_cache.update(
+            &[params.low_freq_hz, params.low_gain_db, params.low_q],
+            CHANGE_EPSILON,
+            compute,
+        );
+        Self::apply_coefficients(&mut self.low, low_c);
+
+        let mid_c = *self.mid_cache.update(
+            &[params.mid_freq_hz, params.mid_gain_db, params.mid_q],
+            CHANGE_EPSILON,
+            compute,
+        );
+        Self::apply_coefficients(&mut self.mid, mid_c);
+
+        let high_c = *self.high_cache.update(
+            &[params.high_freq_hz, params.high_gain_db, params.high_q],
+            CHANGE_EPSILON,
+            compute,
+        );
+        Self::apply_coefficients(&mut self.high, high_c);
+    }
+
+    /// Run one frame (left and right in lanes 0 and 1) through Low → Mid → High.
+    #[inline]
+    fn filter(&mut self, frame: F32x4) -> F32x4 {
+        self.high.process(self.mid.process(self.low.process(frame)))
+    }
+
+    /// Run `len` frames through the bands with one parameter snapshot.
+    ///
+    /// Coefficients refresh at the same sample the per-sample path would
+    /// refresh them (params are constant, so only the first refresh in the
+    /// block can change anything), which keeps block output identical to
+    /// calling [`process_stereo`](DspKernel::process_stereo) per sample.
+    #[inline]
+    fn process_frames(
+        &mut self,
+        params: &EqParams,
+        len: usize,
+        input: impl Fn(usize) -> F32x4,
+        mut output: impl FnMut(usize, F32x4),
+    ) {
+        let due = (self.coeff_update_counter - 1) as usize;
+        if due >= len {
+            self.coeff_update_counter -= len as u32;
+        } else {
+            // Index of the last sample in the block, counted from the refresh.
+            let last = (len - 1 - due) as u32;
+            self.coeff_update_counter = COEFF_UPDATE_INTERVAL - last % COEFF_UPDATE_INTERVAL;
+        }
+
+        for i in 0..due.min(len) {
+            output(i, self.filter(input(i)));
+        }
+        if due < len {
+            self.update_coefficients(params);
+            for i in due..len {
+                output(i, self.filter(input(i)));
+            }
+        }
     }
 }
 
@@ -580,69 +609,63 @@ impl DspKernel for EqKernel {
         self.coeff_update_counter = self.coeff_update_counter.wrapping_sub(1);
         if self.coeff_update_counter == 0 {
             self.coeff_update_counter = COEFF_UPDATE_INTERVAL;
-
-            let sr = self.sample_rate;
-            let low_c = *self.low_cache.update(
-                &[params.low_freq_hz, params.low_gain_db, params.low_q],
-                CHANGE_EPSILON,
-                |inputs| {
-                    let freq = inputs[0].clamp(20.0, sr * 0.475);
-                    let (b0, b1, b2, a0, a1, a2) =
-                        peaking_eq_coefficients(freq, inputs[2], inputs[1], sr);
-                    [b0, b1, b2, a0, a1, a2]
-                },
-            );
-            Self::apply_coefficients(&mut self.low_l, &mut self.low_r, low_c);
-
-            let mid_c = *self.mid_cache.update(
-                &[params.mid_freq_hz, params.mid_gain_db, params.mid_q],
-                CHANGE_EPSILON,
-                |inputs| {
-                    let freq = inputs[0].clamp(20.0, sr * 0.475);
-                    let (b0, b1, b2, a0, a1, a2) =
-                        peaking_eq_coefficients(freq, inputs[2], inputs[1], sr);
-                    [b0, b1, b2, a0, a1, a2]
-                },
-            );
-            Self::apply_coefficients(&mut self.mid_l, &mut self.mid_r, mid_c);
-
-            let high_c = *self.high_cache.update(
-                &[params.high_freq_hz, params.high_gain_db, params.high_q],
-                CHANGE_EPSILON,
-                |inputs| {
-                    let freq = inputs[0].clamp(20.0, sr * 0.475);
-                    let (b0, b1, b2, a0, a1, a2) =
-                        peaking_eq_coefficients(freq, inputs[2], inputs[1], sr);
-                    [b0, b1, b2, a0, a1, a2]
-                },
-            );
-            Self::apply_coefficients(&mut self.high_l, &mut self.high_r, high_c);
+            self.update_coefficients(params);
         }
 
         // ── Unit conversion ───────────────────────────────────────────────────
         let output_gain = fast_db_to_linear(params.output_db);
 
         // ── Signal path: Low → Mid → High → Soft Limit → Output Level ────────
-        // Left channel through L filters
-        let left_low = self.low_l.process(left);
-        let left_mid = self.mid_l.process(left_low);
-        let left_out = soft_limit(self.high_l.process(left_mid), 1.0) * output_gain;
-
-        // Right channel through R filters
-        let right_low = self.low_r.process(right);
-        let right_mid = self.mid_r.process(right_low);
-        let right_out = soft_limit(self.high_r.process(right_mid), 1.0) * output_gain;
+        // Both channels run side by side in the lanes of each band.
+        let banded = self.filter(F32x4::new(left, right, 0.0, 0.0));
+        let left_out = soft_limit(banded.lane(0), 1.0) * output_gain;
+        let right_out = soft_limit(banded.lane(1), 1.0) * output_gain;
 
         (left_out, right_out)
     }
 
+    /// Mono block: the input feeds both lanes, as the per-sample
+    /// [`process`](DspKernel::process) default does, and lane 0 is returned.
+    fn process_block(&mut self, input: &[f32], output: &mut [f32], params: &EqParams) {
+        debug_assert_eq!(input.len(), output.len());
+        let output_gain = fast_db_to_linear(params.output_db);
+        self.process_frames(
+            params,
+            input.len(),
+            |i| F32x4::new(input[i], input[i], 0.0, 0.0),
+            |i, banded| output[i] = soft_limit(banded.lane(0), 1.0) * output_gain,
+        );
+    }
+
+    /// Stereo block: output gain is converted once, and coefficients are
+    /// refreshed between runs of frames rather than checked every sample.
+    fn process_block_stereo(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+        params: &EqParams,
+    ) {
+        debug_assert_eq!(left_in.len(), right_in.len());
+        debug_assert_eq!(left_in.len(), left_out.len());
+        debug_assert_eq!(left_out.len(), right_out.len());
+        let output_gain = fast_db_to_linear(params.output_db);
+        self.process_frames(
+            params,
+            left_in.len(),
+            |i| F32x4::new(left_in[i], right_in[i], 0.0, 0.0),
+            |i, banded| {
+                left_out[i] = soft_limit(banded.lane(0), 1.0) * output_gain;
+                right_out[i] = soft_limit(banded.lane(1), 1.0) * output_gain;
+            },
+        );
+    }
+
     fn reset(&mut self) {
-        self.low_l.clear();
-        self.low_r.clear();
-        self.mid_l.clear();
-        self.mid_r.clear();
-        self.high_l.clear();
-        self.high_r.clear();
+        self.low.clear();
+        self.mid.clear();
+        self.high.clear();
 
         // Invalidate all caches — forces coefficient recomputation on the next
         // processing call so filters are correctly re-initialised after reset.
@@ -1007,4 +1030,58 @@ mod tests {
              flat={energy_flat:.4}, boosted={energy_boosted:.4}"
         );
     }
+    #[test]
+    fn block_matches_per_sample() {
+        // Uneven block sizes land the coefficient refresh at different
+        // offsets; the boost switches on mid-stream to make the refresh count.
+        let flat = EqParams::default();
+        let boosted = EqParams {
+            low_gain_db: 6.0,
+            mid_gain_db: -4.0,
+            high_freq_hz: 3000.0,
+            output_db: -3.0,
+            ..EqParams::default()
+        };
+        let input: [f32; 300] = core::array::from_fn(|i| {
+            libm::sinf(i as f32 * 0.07) * 0.8 + libm::sinf(i as f32 * 0.9) * 0.3
+        });
+
+        let mut reference = EqKernel::new(48000.0);
+        let mut stereo = EqKernel::new(48000.0);
+        let mut start = 0;
+        for (n, len) in [7usize, 45, 1, 100, 33, 64, 50].into_iter().enumerate() {
+            let params = if n < 2 { &flat } else { &boosted };
+            let block = &input[start..start + len];
+            let right: [f32; 100] = core::array::from_fn(|i| -block.get(i).copied().unwrap_or(0.0));
+            let right = &right[..len];
+
+            let mut left_out = [0.0f32; 100];
+            let mut right_out = [0.0f32; 100];
+            stereo.process_block_stereo(
+                block,
+                right,
+                &mut left_out[..len],
+                &mut right_out[..len],
+                params,
+            );
+
+            for i in 0..len {
+                let (l, r) = reference.process_stereo(block[i], right[i], params);
+                assert_eq!(left_out[i], l, "left differs at {}", start + i);
+                assert_eq!(right_out[i], r, "right differs at {}", start + i);
+            }
+            start += len;
+        }
+
+        // The mono path feeds both lanes with the same input, like `process`.
+        let mut reference = EqKernel::new(48000.0);
+        let mut mono = EqKernel::new(48000.0);
+        let mut out = [0.0f32; 300];
+        mono.process_block(&input[..150], &mut out[..150], &flat);
+        mono.process_block(&input[150..], &mut out[150..], &boosted);
+        for (i, &x) in input.iter().enumerate() {
+            let params = if i < 150 { &flat } else { &boosted };
+            assert_eq!(out[i], reference.process(x, params), "mono differs at {i}");
+        }
+    }
 }
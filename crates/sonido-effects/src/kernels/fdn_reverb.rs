@@ -0,0 +1,827 @@
+//! FDN reverb kernel — 8-line modulated feedback delay network with a Householder matrix.
+//!
+//! `FdnReverbKernel` is a dense, smooth alternative to the Freeverb-derived
+//! [`ReverbKernel`](super::ReverbKernel). Each line is a
+//! [`ModulatedComb`] with its own LFO rate and one-pole damping; the lines'
+//! outputs are mixed through an 8×8 Householder reflection before being fed
+//! back, so energy spreads evenly across all lines on every pass without
+//! coloring the tail. Chains of [`ModulatedAllpass`] diffuse the input first,
+//! which gives the attack its density.
+//!
+//! # Architecture
+//!
+//! ```text
+//! L → predelay → [4 modulated allpasses] ─→ even lines ─┐
+//! R → predelay → [4 modulated allpasses] ─→ odd lines  ─┤
+//!                                                        ↓
+//!              ┌──────── [8 modulated combs: read] ←─────┘
+//!              │                  ↓
+//!              │        Householder  H = I − (2/8)·11ᵀ
+//!              │                  ↓
+//!              └─→ [damp → × g_i → write]
+//!
+//! even line outputs → wet L, odd line outputs → wet R
+//!              → stereo width (M/S) → wet/dry → output
+//! ```
+//!
+//! # Decay
+//!
+//! Every line gets its own feedback gain `g_i = 10^(−3·L_i / (T60·fs))`
+//! for its length `L_i` in samples, so each line loses 60 dB over the same
+//! time and the decay is set in seconds independently of size. The
+//! Householder matrix is orthogonal, the damping filters have unity gain at
+//! DC, and every `g_i < 1`, so the loop is stable at all settings.
+//! [`ModulatedComb`] caps feedback at 0.99, which shortens only the longest
+//! decays in the smallest sizes.
+//!
+//! # References
+//!
+//! - Jot & Chaigne, "Digital Delay Networks for Designing Artificial
+//!   Reverberators", AES Convention Paper 3030, 1991.
+//! - Julius O. Smith, "Physical Audio Signal Processing", section
+//!   "Householder Feedback Matrix", CCRMA, 2010.
+//! - Jon Dattorro, "Effect Design, Part 1: Reverberator and Other Filters",
+//!   J. Audio Eng. Soc., Vol. 45, No. 9, 1997.
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(FdnReverbKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing, ADCs are hardware-filtered)
+//! let mut kernel = FdnReverbKernel::new(48000.0);
+//! let params = FdnReverbParams::from_knobs(adc_decay, adc_size, adc_diff, adc_damp,
+//!                                          adc_mod, adc_predelay, adc_width, adc_mix, adc_out);
+//! let (left, right) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use libm::{ceilf, powf, sqrtf};
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    InterpolatedDelay, ModulatedAllpass, ModulatedComb, ParamDescriptor, ParamId, ParamUnit,
+    fast_db_to_linear, wet_dry_mix_stereo,
+};
+
+// ── Constants ───────────────────────────────────────────────────────────────
+
+/// Reference sample rate for delay tuning constants (44.1 kHz).
+const REF_RATE: f32 = 44100.0;
+
+/// Number of FDN lines.
+const LINES: usize = 8;
+
+/// FDN line lengths at 44.1 kHz and 100% size (33–62 ms, all prime).
+const LINE_TUNINGS_44K: [usize; LINES] = [1447, 1553, 1693, 1867, 2053, 2251, 2477, 2713];
+
+/// Per-line LFO rates in Hz, spread so no two lines move together.
+const LINE_MOD_RATES: [f32; LINES] = [0.31, 0.43, 0.53, 0.67, 0.79, 0.89, 1.03, 1.13];
+
+/// Line delay scale at 0% size; 100% size is 1.0.
+const MIN_SIZE_SCALE: f32 = 0.3;
+
+/// Line modulation depth at 100% modulation, in milliseconds.
+const MAX_MOD_DEPTH_MS: f32 = 1.0;
+
+/// Input diffusion allpass lengths at 44.1 kHz, left and right (prime,
+/// offset between channels for decorrelation).
+const DIFFUSER_TUNINGS_44K: [[usize; 4]; 2] = [[151, 109, 383, 281], [157, 113, 389, 293]];
+
+/// LFO rate and depth of the input diffusers.
+const DIFFUSER_MOD_RATE: f32 = 0.5;
+const DIFFUSER_MOD_DEPTH_MS: f32 = 0.1;
+
+/// Maximum pre-delay in milliseconds.
+const MAX_PREDELAY_MS: f32 = 200.0;
+
+/// Gain of the diffused input into each line.
+const INPUT_GAIN: f32 = 0.5;
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Scale a 44.1 kHz delay length to the current sample rate.
+#[inline]
+fn scale_to_rate(samples: usize, sr: f32) -> f32 {
+    (samples as f32 * sr / REF_RATE).max(1.0)
+}
+
+/// Map size (0–100%) to the line delay scale.
+#[inline]
+fn size_scale(size_pct: f32) -> f32 {
+    MIN_SIZE_SCALE + (1.0 - MIN_SIZE_SCALE) * (size_pct * 0.01).clamp(0.0, 1.0)
+}
+
+/// Map damping (0% = bright, 100% = dark) to the in-loop lowpass cutoff.
+///
+/// Logarithmic from 20 kHz down to 500 Hz.
+#[inline]
+fn damping_to_hz(damping_pct: f32) -> f32 {
+    500.0 * powf(40.0, 1.0 - (damping_pct * 0.01).clamp(0.0, 1.0))
+}
+
+/// Map diffusion (0–100%) to the input allpass coefficient (0.1–0.7).
+#[inline]
+fn diffusion_to_coeff(diffusion_pct: f32) -> f32 {
+    0.1 + 0.6 * (diffusion_pct * 0.01).clamp(0.0, 1.0)
+}
+
+/// Feedback gain that decays a line of `len` samples by 60 dB in `decay_s`.
+#[inline]
+fn line_gain(len: f32, decay_s: f32, sr: f32) -> f32 {
+    powf(10.0, -3.0 * len / (decay_s * sr))
+}
+
+/// In-place 8-point Householder reflection `x ← x − (2/8)·Σx`.
+///
+/// Orthogonal (energy-preserving) and maximally diffusing: every output
+/// takes an equal share of every input, at the cost of one sum and eight
+/// subtractions.
+#[inline]
+fn householder8(x: &mut [f32; LINES]) {
+    let s = x.iter().sum::<f32>() * (2.0 / LINES as f32);
+    for v in x.iter_mut() {
+        *v -= s;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`FdnReverbKernel`].
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `decay_s` | s | 0.2–10 | 2.5 |
+/// | 1 | `size_pct` | % | 0–100 | 60.0 |
+/// | 2 | `diffusion_pct` | % | 0–100 | 70.0 |
+/// | 3 | `damping_pct` | % | 0–100 | 40.0 |
+/// | 4 | `modulation_pct` | % | 0–100 | 30.0 |
+/// | 5 | `predelay_ms` | ms | 0–200 | 10.0 |
+/// | 6 | `width_pct` | % | 0–100 | 100.0 |
+/// | 7 | `mix_pct` | % | 0–100 | 30.0 |
+/// | 8 | `output_db` | dB | −6–+6 | 0.0 |
+#[derive(Debug, Clone, Copy)]
+pub struct FdnReverbParams {
+    /// Decay time (RT60) in seconds (0.2–10 s).
+    ///
+    /// Each line's feedback gain is derived from its length so the whole
+    /// network loses 60 dB in this time, whatever the size.
+    pub decay_s: f32,
+
+    /// Size of the space as a percentage (0–100%).
+    ///
+    /// Scales the line lengths from 30% to 100% of their tunings
+    /// (about 10–62 ms), spacing the echo density further apart.
+    pub size_pct: f32,
+
+    /// Input diffusion amount (0–100%).
+    ///
+    /// Coefficient of the input allpass chains. Higher values smear the
+    /// attack into a smooth swell; lower values keep discrete echoes.
+    pub diffusion_pct: f32,
+
+    /// High-frequency damping (0% = bright, 100% = dark).
+    ///
+    /// Cutoff of the one-pole lowpass inside each line's feedback path.
+    pub damping_pct: f32,
+
+    /// Line modulation depth (0–100%, up to 1 ms).
+    ///
+    /// Slow per-line delay modulation breaks up metallic ringing; high
+    /// values add an audible chorus to the tail.
+    pub modulation_pct: f32,
+
+    /// Pre-delay time in milliseconds (0–200 ms).
+    pub predelay_ms: f32,
+
+    /// Stereo width as a percentage (0% = mono, 100% = full stereo).
+    pub width_pct: f32,
+
+    /// Wet/dry mix as a percentage (0% = dry, 100% = wet).
+    pub mix_pct: f32,
+
+    /// Output level in decibels (−6 to +6 dB).
+    pub output_db: f32,
+}
+
+impl Default for FdnReverbParams {
+    fn default() -> Self {
+        Self {
+            decay_s: 2.5,
+            size_pct: 60.0,
+            diffusion_pct: 70.0,
+            damping_pct: 40.0,
+            modulation_pct: 30.0,
+            predelay_ms: 10.0,
+            width_pct: 100.0,
+            mix_pct: 30.0,
+            output_db: 0.0,
+        }
+    }
+}
+
+impl FdnReverbParams {
+    /// Create parameters from normalized 0–1 hardware knob readings.
+    ///
+    /// Curves are derived from [`ParamDescriptor`] — same mapping as GUI and
+    /// plugin hosts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_knobs(
+        decay: f32,
+        size: f32,
+        diffusion: f32,
+        damping: f32,
+        modulation: f32,
+        predelay: f32,
+        width: f32,
+        mix: f32,
+        output: f32,
+    ) -> Self {
+        Self::from_normalized(&[
+            decay, size, diffusion, damping, modulation, predelay, width, mix, output,
+        ])
+    }
+}
+
+/// Percentage descriptor with the given name, default and identity.
+fn percent(
+    name: &'static str,
+    short_name: &'static str,
+    default: f32,
+    id: u32,
+    key: &'static str,
+) -> ParamDescriptor {
+    ParamDescriptor {
+        name,
+        short_name,
+        unit: ParamUnit::Percent,
+        min: 0.0,
+        max: 100.0,
+        default,
+        step: 1.0,
+        ..ParamDescriptor::mix()
+    }
+    .with_id(ParamId(id), key)
+}
+
+impl KernelParams for FdnReverbParams {
+    const COUNT: usize = 9;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::custom("Decay", "Decay", 0.2, 10.0, 2.5)
+                    .with_unit(ParamUnit::Seconds)
+                    .with_id(ParamId(4100), "fdn_decay")
+                    .with_description("Time for the tail to fall by 60 dB."),
+            ),
+            1 => Some(
+                percent("Size", "Size", 60.0, 4101, "fdn_size")
+                    .with_description("Size of the space; larger sizes thin out the echo density."),
+            ),
+            2 => Some(
+                percent("Diffusion", "Diff", 70.0, 4102, "fdn_diffusion")
+                    .with_description("Input diffusion; higher values give a smoother attack."),
+            ),
+            3 => Some(
+                percent("Damping", "Damp", 40.0, 4103, "fdn_damping").with_description(
+                    "High-frequency absorption; higher values give a darker tail.",
+                ),
+            ),
+            4 => Some(
+                percent("Modulation", "Mod", 30.0, 4104, "fdn_modulation").with_description(
+                    "Slow movement of the delay lines; breaks up metallic ringing.",
+                ),
+            ),
+            5 => Some(
+                ParamDescriptor::custom("Pre-Delay", "PreDly", 0.0, MAX_PREDELAY_MS, 10.0)
+                    .with_unit(ParamUnit::Milliseconds)
+                    .with_step(1.0)
+                    .with_id(ParamId(4105), "fdn_predelay")
+                    .with_description(
+                        "Gap before the reverb starts, separating it from the dry signal.",
+                    ),
+            ),
+            6 => Some(
+                percent("Stereo Width", "Width", 100.0, 4106, "fdn_width")
+                    .with_description("Stereo width of the reverb, from mono (0%) to full stereo."),
+            ),
+            7 => Some(
+                ParamDescriptor {
+                    default: 30.0,
+                    ..ParamDescriptor::mix()
+                }
+                .with_id(ParamId(4107), "fdn_mix")
+                .with_description("Blend of dry and reverberated signal."),
+            ),
+            8 => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(4108), "fdn_output"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 => SmoothingStyle::Slow,         // decay_s — line feedback gains
+            1 => SmoothingStyle::Slow,         // size_pct — line lengths glide
+            2 => SmoothingStyle::Standard,     // diffusion_pct — allpass coeffs
+            3 => SmoothingStyle::Slow,         // damping_pct — one-pole cutoffs
+            4 => SmoothingStyle::Slow,         // modulation_pct — LFO depth
+            5 => SmoothingStyle::Interpolated, // predelay_ms — prevent pitch artifacts
+            6 => SmoothingStyle::Standard,     // width_pct
+            7 => SmoothingStyle::Standard,     // mix_pct
+            8 => SmoothingStyle::Fast,         // output_db
+            _ => SmoothingStyle::Standard,
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.decay_s,
+            1 => self.size_pct,
+            2 => self.diffusion_pct,
+            3 => self.damping_pct,
+            4 => self.modulation_pct,
+            5 => self.predelay_ms,
+            6 => self.width_pct,
+            7 => self.mix_pct,
+            8 => self.output_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.decay_s = value,
+            1 => self.size_pct = value,
+            2 => self.diffusion_pct = value,
+            3 => self.damping_pct = value,
+            4 => self.modulation_pct = value,
+            5 => self.predelay_ms = value,
+            6 => self.width_pct = value,
+            7 => self.mix_pct = value,
+            8 => self.output_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP FDN reverb kernel — eight modulated comb lines around a Householder matrix.
+///
+/// Contains only mutable DSP state. No `SmoothedParam`, no atomics.
+///
+/// ## DSP State
+///
+/// - **Pre-delay** (`InterpolatedDelay` × 2): up to 200 ms per channel.
+/// - **Input diffusers** (`ModulatedAllpass` × 4 per channel): smear the
+///   input before it enters the network.
+/// - **FDN lines** (`ModulatedComb` × 8): allocated at 100% size and full
+///   modulation depth; size shortens them in place.
+///
+/// ## Coefficient Caching
+///
+/// Line lengths, feedback gains, damping cutoffs, diffusion coefficients
+/// and modulation depth are recomputed only when their parameters change
+/// beyond epsilon, avoiding per-sample `powf`.
+pub struct FdnReverbKernel {
+    predelay: [InterpolatedDelay; 2],
+    diffusers: [[ModulatedAllpass; 4]; 2],
+    lines: [ModulatedComb; LINES],
+
+    sample_rate: f32,
+
+    // Coefficient caches (NaN sentinel = force update on first sample)
+    cached_decay: f32,
+    cached_size: f32,
+    cached_diffusion: f32,
+    cached_damping: f32,
+    cached_modulation: f32,
+    /// Wet gain compensating the network's resonance, `sqrt(1 − mean g)`.
+    compensation: f32,
+}
+
+impl FdnReverbKernel {
+    /// Create a new FDN reverb kernel at the given sample rate.
+    pub fn new(sample_rate: f32) -> Self {
+        let defaults = FdnReverbParams::default();
+        let mut kernel = Self {
+            predelay: core::array::from_fn(|_| Self::new_predelay(sample_rate)),
+            diffusers: Self::new_diffusers(sample_rate),
+            lines: Self::new_lines(sample_rate),
+            sample_rate,
+            cached_decay: f32::NAN,
+            cached_size: f32::NAN,
+            cached_diffusion: f32::NAN,
+            cached_damping: f32::NAN,
+            cached_modulation: f32::NAN,
+            compensation: 1.0,
+        };
+        kernel.update_derived(&defaults);
+        kernel
+    }
+
+    fn new_predelay(sample_rate: f32) -> InterpolatedDelay {
+        InterpolatedDelay::new((ceilf(MAX_PREDELAY_MS * 0.001 * sample_rate) as usize).max(1))
+    }
+
+    fn new_diffusers(sample_rate: f32) -> [[ModulatedAllpass; 4]; 2] {
+        core::array::from_fn(|ch| {
+            core::array::from_fn(|i| {
+                ModulatedAllpass::new(
+                    scale_to_rate(DIFFUSER_TUNINGS_44K[ch][i], sample_rate),
+                    diffusion_to_coeff(70.0),
+                    DIFFUSER_MOD_RATE,
+                    DIFFUSER_MOD_DEPTH_MS,
+                    sample_rate,
+                )
+            })
+        })
+    }
+
+    /// FDN lines at full size and full modulation depth, so that every
+    /// size and depth fits in the allocated capacity.
+    fn new_lines(sample_rate: f32) -> [ModulatedComb; LINES] {
+        core::array::from_fn(|i| {
+            ModulatedComb::new(
+                scale_to_rate(LINE_TUNINGS_44K[i], sample_rate),
+                0.0,
+                damping_to_hz(40.0),
+                LINE_MOD_RATES[i],
+                MAX_MOD_DEPTH_MS,
+                sample_rate,
+            )
+        })
+    }
+
+    /// Recompute cached coefficients when parameters change by more than epsilon.
+    #[inline]
+    fn update_derived(&mut self, params: &FdnReverbParams) {
+        // A NaN cache forces an update.
+        let changed = |value: f32, cached: f32| cached.is_nan() || (value - cached).abs() > 0.001;
+        let decay = params.decay_s.clamp(0.2, 10.0);
+
+        if changed(params.size_pct, self.cached_size) {
+            self.cached_size = params.size_pct;
+            let scale = size_scale(params.size_pct);
+            for (i, line) in self.lines.iter_mut().enumerate() {
+                line.set_delay(scale_to_rate(LINE_TUNINGS_44K[i], self.sample_rate) * scale);
+            }
+            // Line gains depend on line lengths.
+            self.cached_decay = f32::NAN;
+        }
+        if changed(decay, self.cached_decay) {
+            self.cached_decay = decay;
+            let mut gain_sum = 0.0;
+            for line in &mut self.lines {
+                // One extra sample: the line is read before it is written.
+                let g = line_gain(line.delay() + 1.0, decay, self.sample_rate);
+                line.set_feedback(g);
+                gain_sum += line.feedback();
+            }
+            let mean = gain_sum / LINES as f32;
+            self.compensation = sqrtf((1.0 - mean).max(0.01));
+        }
+        if changed(params.damping_pct, self.cached_damping) {
+            self.cached_damping = params.damping_pct;
+            let hz = damping_to_hz(params.damping_pct);
+            for line in &mut self.lines {
+                line.set_damping(hz);
+            }
+        }
+        if changed(params.diffusion_pct, self.cached_diffusion) {
+            self.cached_diffusion = params.diffusion_pct;
+            let coeff = diffusion_to_coeff(params.diffusion_pct);
+            for ap in self.diffusers.iter_mut().flatten() {
+                ap.set_feedback(coeff);
+            }
+        }
+        if changed(params.modulation_pct, self.cached_modulation) {
+            self.cached_modulation = params.modulation_pct;
+            let depth = (params.modulation_pct * 0.01).clamp(0.0, 1.0)
+                * MAX_MOD_DEPTH_MS
+                * 0.001
+                * self.sample_rate;
+            for line in &mut self.lines {
+                line.set_mod_depth_samples(depth);
+            }
+        }
+    }
+}
+
+impl DspKernel for FdnReverbKernel {
+    type Params = FdnReverbParams;
+
+    /// Process a stereo sample pair through the reverb.
+    ///
+    /// ## Signal Flow
+    ///
+    /// 1. Update cached coefficients when parameters change.
+    /// 2. Stereo pre-delay and input diffusion.
+    /// 3. Read all eight lines, mix through the Householder matrix.
+    /// 4. Write back: L input into even lines, R into odd lines, plus the
+    ///    damped, decay-scaled matrix output.
+    /// 5. Even lines → wet L, odd lines → wet R.
+    /// 6. Stereo width (M/S) → wet/dry mix → output gain.
+    #[inline]
+    fn process_stereo(&mut self, left: f32, right: f32, params: &FdnReverbParams) -> (f32, f32) {
+        self.update_derived(params);
+
+        // ── Pre-delay + input diffusion ──
+        let predelay_samples = (params.predelay_ms * 0.001 * self.sample_rate)
+            .clamp(0.0, (self.predelay[0].capacity() - 1) as f32);
+        let mut input = [left, right];
+        for (ch, x) in input.iter_mut().enumerate() {
+            self.predelay[ch].write(*x);
+            let mut diffused = self.predelay[ch].read(predelay_samples);
+            for ap in &mut self.diffusers[ch] {
+                diffused = ap.process(diffused);
+            }
+            *x = diffused * INPUT_GAIN;
+        }
+
+        // ── FDN: read, mix, write ──
+        let mut raw = [0.0f32; LINES];
+        for (out, line) in raw.iter_mut().zip(&mut self.lines) {
+            *out = line.read();
+        }
+        let mut mixed = raw;
+        householder8(&mut mixed);
+        for (i, line) in self.lines.iter_mut().enumerate() {
+            line.write(input[i % 2], mixed[i]);
+        }
+
+        // ── Stereo output: L = even lines, R = odd lines ──
+        let wet_l = (raw[0] + raw[2] + raw[4] + raw[6]) * 0.25 * self.compensation;
+        let wet_r = (raw[1] + raw[3] + raw[5] + raw[7]) * 0.25 * self.compensation;
+
+        // ── Stereo width: M/S encode-scale-decode ──
+        let width = params.width_pct * 0.01;
+        let mid = (wet_l + wet_r) * 0.5;
+        let side = (wet_l - wet_r) * 0.5;
+
+        // ── Wet/dry mix → output gain ──
+        let (out_l, out_r) = wet_dry_mix_stereo(
+            left,
+            right,
+            mid + side * width,
+            mid - side * width,
+            params.mix_pct * 0.01,
+        );
+        let output = fast_db_to_linear(params.output_db);
+        (out_l * output, out_r * output)
+    }
+
+    fn reset(&mut self) {
+        for d in &mut self.predelay {
+            d.clear();
+        }
+        for ap in self.diffusers.iter_mut().flatten() {
+            ap.reset();
+        }
+        for line in &mut self.lines {
+            line.reset();
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.predelay = core::array::from_fn(|_| Self::new_predelay(sample_rate));
+        self.diffusers = Self::new_diffusers(sample_rate);
+        self.lines = Self::new_lines(sample_rate);
+
+        // Invalidate caches so the new lines pick up the current settings.
+        self.cached_decay = f32::NAN;
+        self.cached_size = f32::NAN;
+        self.cached_diffusion = f32::NAN;
+        self.cached_damping = f32::NAN;
+        self.cached_modulation = f32::NAN;
+    }
+
+    /// Returns `true` — left and right feed and read different lines.
+    fn is_true_stereo(&self) -> bool {
+        true
+    }
+
+    /// Reverb tail in samples: the RT60 plus the longest pre-delay.
+    fn tail_samples(&self) -> usize {
+        let decay = if self.cached_decay.is_nan() {
+            FdnReverbParams::default().decay_s
+        } else {
+            self.cached_decay
+        };
+        ((decay + MAX_PREDELAY_MS * 0.001) * self.sample_rate) as usize
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::kernel::Adapter;
+    use sonido_core::{Effect, ParameterInfo};
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    /// Wet-only impulse response of both channels.
+    fn impulse_response(params: &FdnReverbParams, len: usize) -> Vec<(f32, f32)> {
+        let mut kernel = FdnReverbKernel::new(48000.0);
+        (0..len)
+            .map(|i| {
+                let x = if i == 0 { 1.0 } else { 0.0 };
+                kernel.process_stereo(x, x, params)
+            })
+            .collect()
+    }
+
+    fn wet() -> FdnReverbParams {
+        FdnReverbParams {
+            mix_pct: 100.0,
+            predelay_ms: 0.0,
+            ..FdnReverbParams::default()
+        }
+    }
+
+    fn energy(block: &[(f32, f32)]) -> f32 {
+        block.iter().map(|(l, r)| l * l + r * r).sum()
+    }
+
+    #[test]
+    fn householder_is_orthogonal() {
+        let mut x = [0.3, -1.2, 0.7, 0.0, 2.5, -0.4, 1.1, -0.9];
+        let before: f32 = x.iter().map(|v| v * v).sum();
+        householder8(&mut x);
+        let after: f32 = x.iter().map(|v| v * v).sum();
+        assert!((before - after).abs() < 1e-4, "{before} vs {after}");
+
+        // Involutory: applying it twice returns the input.
+        householder8(&mut x);
+        assert!((x[4] - 2.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn silence_in_silence_out() {
+        let mut kernel = FdnReverbKernel::new(48000.0);
+        let params = FdnReverbParams::default();
+        for _ in 0..4800 {
+            let (l, r) = kernel.process_stereo(0.0, 0.0, &params);
+            assert_eq!((l, r), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn tail_follows_decay_time() {
+        // Energy 1 s after the impulse, relative to the first 100 ms.
+        let late_ratio = |decay_s: f32| {
+            let params = FdnReverbParams { decay_s, ..wet() };
+            let ir = impulse_response(&params, 52800);
+            energy(&ir[48000..]) / energy(&ir[..4800])
+        };
+        let short = late_ratio(0.5);
+        let long = late_ratio(4.0);
+        assert!(
+            long > short * 1000.0,
+            "4 s decay should ring far longer than 0.5 s: short {short}, long {long}"
+        );
+        // At T60 = 1 s the tail is ~60 dB down after one second.
+        let one = late_ratio(1.0);
+        assert!(
+            one < 1e-4,
+            "1 s decay left {one} of the early energy after 1 s"
+        );
+    }
+
+    #[test]
+    fn stable_at_extremes() {
+        for size in [0.0, 100.0] {
+            for modulation in [0.0, 100.0] {
+                let params = FdnReverbParams {
+                    decay_s: 10.0,
+                    size_pct: size,
+                    diffusion_pct: 100.0,
+                    damping_pct: 0.0,
+                    modulation_pct: modulation,
+                    ..wet()
+                };
+                let ir = impulse_response(&params, 96000);
+                let peak = ir
+                    .iter()
+                    .fold(0.0f32, |m, (l, r)| m.max(l.abs()).max(r.abs()));
+                assert!(
+                    peak.is_finite() && peak < 1.0,
+                    "size {size} mod {modulation}: peak {peak}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn damping_darkens_tail() {
+        // Sample-to-sample difference energy tracks high-frequency content.
+        let brightness = |damping_pct: f32| {
+            let params = FdnReverbParams {
+                damping_pct,
+                ..wet()
+            };
+            let ir = impulse_response(&params, 24000);
+            let tail = &ir[12000..];
+            let diff: f32 = tail.windows(2).map(|w| (w[1].0 - w[0].0).powi(2)).sum();
+            diff / energy(tail)
+        };
+        let bright = brightness(0.0);
+        let dark = brightness(90.0);
+        assert!(dark < bright * 0.5, "bright {bright}, dark {dark}");
+    }
+
+    #[test]
+    fn size_delays_first_echo() {
+        let onset = |size_pct: f32| {
+            let params = FdnReverbParams {
+                size_pct,
+                diffusion_pct: 0.0,
+                ..wet()
+            };
+            // First sample after the diffusers where the network output appears.
+            impulse_response(&params, 9600)
+                .iter()
+                .position(|(l, _)| l.abs() > 1e-3)
+                .unwrap()
+        };
+        assert!(onset(100.0) > onset(0.0) + 1000);
+    }
+
+    #[test]
+    fn channels_are_decorrelated() {
+        let ir = impulse_response(&wet(), 24000);
+        let (mut ll, mut rr, mut lr) = (0.0f32, 0.0f32, 0.0f32);
+        for (l, r) in &ir[4800..] {
+            ll += l * l;
+            rr += r * r;
+            lr += l * r;
+        }
+        let corr = lr / sqrtf(ll * rr);
+        assert!(corr.abs() < 0.5, "L/R correlation {corr}");
+
+        let mono = FdnReverbParams {
+            width_pct: 0.0,
+            ..wet()
+        };
+        for (l, r) in impulse_response(&mono, 4800) {
+            assert!((l - r).abs() < 1e-6, "width 0 should be mono");
+        }
+    }
+
+    #[test]
+    fn reset_clears_state() {
+        let mut kernel = FdnReverbKernel::new(48000.0);
+        let params = wet();
+        for _ in 0..4800 {
+            kernel.process_stereo(0.5, -0.5, &params);
+        }
+        kernel.reset();
+        let (l, r) = kernel.process_stereo(0.0, 0.0, &params);
+        assert!(l.abs() < 1e-9 && r.abs() < 1e-9, "l={l}, r={r}");
+    }
+
+    #[test]
+    fn adapter_param_info_matches() {
+        let adapter = Adapter::new(FdnReverbKernel::new(48000.0), 48000.0);
+        assert_eq!(adapter.param_count(), FdnReverbParams::COUNT);
+        for i in 0..FdnReverbParams::COUNT {
+            assert_eq!(adapter.param_info(i).unwrap().id, ParamId(4100 + i as u32));
+        }
+        assert!(adapter.param_info(FdnReverbParams::COUNT).is_none());
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let mut adapter = Adapter::new(FdnReverbKernel::new(48000.0), 48000.0);
+        adapter.reset();
+        for _ in 0..1000 {
+            let (l, r) = adapter.process_stereo(0.3, -0.3);
+            assert!(l.is_finite() && r.is_finite());
+        }
+    }
+
+    #[test]
+    fn from_knobs_maps_ranges() {
+        let max = FdnReverbParams::from_knobs(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+        assert!((max.decay_s - 10.0).abs() < 0.01, "decay {}", max.decay_s);
+        assert!((max.predelay_ms - 200.0).abs() < 0.01);
+        assert!((max.mix_pct - 100.0).abs() < 0.01);
+
+        let min = FdnReverbParams::from_knobs(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert!((min.decay_s - 0.2).abs() < 0.01, "decay {}", min.decay_s);
+        assert!(min.size_pct.abs() < 0.01);
+    }
+}
@@ -130,20 +130,22 @@ impl KernelParams for FilterParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1200), "flt_cutoff")
+                .with_description("Corner or center frequency of the filter.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             1 => Some(
                 ParamDescriptor {
                     name: "Resonance",
                     short_name: "Reso",
-                    unit: ParamUnit::Ratio,
+                    unit: ParamUnit::None,
                     min: 0.1,
                     max: 20.0,
                     default: 0.707,
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1201), "flt_resonance"),
+                .with_id(ParamId(1201), "flt_resonance")
+                .with_description("Filter Q; higher values emphasize the cutoff frequency."),
             ),
             2 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1202), "flt_output"),
@@ -152,6 +154,7 @@ impl KernelParams for FilterParams {
                 ParamDescriptor::custom("Type", "Type", 0.0, 3.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1203), "flt_type")
+                    .with_description("Filter response: lowpass, highpass, bandpass, or notch.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["LPF", "HPF", "BPF", "Notch"]),
             ),
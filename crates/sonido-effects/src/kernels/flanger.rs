@@ -1,4 +1,4 @@
-//! Flanger kernel — through-zero flanging with feedback and stereo LFO offset.
+//! Flanger kernel — through-zero flanging with feedback and stereo LFO spread.
 //!
 //! `FlangerKernel` owns DSP state (delay lines, LFOs, feedback samples).
 //! Parameters are received via `&FlangerParams` each sample. Deployed via
@@ -19,6 +19,17 @@
 //! crossing the paths are time-aligned, producing the characteristic "jet whoosh"
 //! null of tape-flanger circuits.
 //!
+//! ## Stereo Spread and Cross-Feedback
+//!
+//! Each channel has its own delay line and LFO. The right LFO is locked to the
+//! left one at a phase offset set by `spread_pct`: 0 % sweeps both channels
+//! together, 50 % (the default) is 90° apart, and 100 % sweeps them in
+//! opposite directions. `cross_fb_pct` routes part of each channel's feedback
+//! into the other channel's delay line, so at 100 % the regeneration ping-pongs
+//! between the sides. The feedback going into each line is a blend of the two
+//! channels with weights that sum to 1, so cross-feedback never raises the loop
+//! gain above `|feedback|`.
+//!
 //! ## Bipolar Feedback
 //!
 //! Feedback ranges from −95 % to +95 %. Positive feedback reinforces odd harmonics
@@ -68,6 +79,8 @@ use sonido_core::{
 /// | 6 | `division` | index | 0–11 | 3 (Quarter) |
 /// | 7 | `output_db` | dB | −20–+6 | 0.0 |
 /// | 8 | `lfo_phase` | 0–1 | READ_ONLY diagnostic | 0.0 |
+/// | 9 | `spread_pct` | % | 0–100 | 50.0 |
+/// | 10 | `cross_fb_pct` | % | 0–100 | 0.0 |
 #[derive(Debug, Clone, Copy)]
 pub struct FlangerParams {
     /// LFO rate in Hz.
@@ -120,6 +133,18 @@ pub struct FlangerParams {
     /// Current LFO phase (0.0–1.0). READ_ONLY diagnostic — written by the
     /// kernel each sample; never read back for DSP.
     pub lfo_phase: f32,
+
+    /// Stereo LFO spread in percent.
+    ///
+    /// Range: 0.0 to 100.0 %, mapped to a 0°–180° phase lead of the right
+    /// LFO over the left. Default 50.0 % (90°).
+    pub spread_pct: f32,
+
+    /// Cross-feedback in percent.
+    ///
+    /// Range: 0.0 to 100.0 %. The share of each channel's feedback that is
+    /// sent to the opposite channel's delay line. Default 0.0 %.
+    pub cross_fb_pct: f32,
 }
 
 impl Default for FlangerParams {
@@ -134,6 +159,8 @@ impl Default for FlangerParams {
             division: 3.0,
             output_db: 0.0,
             lfo_phase: 0.0,
+            spread_pct: 50.0,
+            cross_fb_pct: 0.0,
         }
     }
 }
@@ -143,7 +170,8 @@ impl FlangerParams {
     ///
     /// Curves are derived from [`ParamDescriptor`] — same mapping as GUI and
     /// plugin hosts. `tzf` and `sync` snap at 0.5 (stepped); `division` maps
-    /// to the nearest integer index 0–11.
+    /// to the nearest integer index 0–11. Spread and cross-feedback stay at
+    /// their defaults (50 % and 0 %).
     ///
     /// | Argument | Index | Parameter | Range |
     /// |----------|-------|-----------|-------|
@@ -171,29 +199,34 @@ impl FlangerParams {
 }
 
 impl KernelParams for FlangerParams {
-    const COUNT: usize = 9;
+    const COUNT: usize = 11;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
-            0 => Some(ParamDescriptor::rate_hz(0.05, 5.0, 0.5).with_id(ParamId(800), "flgr_rate")),
+            0 => Some(ParamDescriptor::rate_hz(0.05, 5.0, 0.5).with_id(ParamId(800), "flgr_rate")
+            .with_description("LFO speed of the delay sweep.")),
             1 => Some(
                 ParamDescriptor {
                     default: 35.0,
                     ..ParamDescriptor::depth()
                 }
-                .with_id(ParamId(801), "flgr_depth"),
+                .with_id(ParamId(801), "flgr_depth")
+                .with_description("Range of the delay sweep."),
             ),
             2 => Some(
                 ParamDescriptor::custom("Feedback", "Fdbk", -95.0, 95.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(802), "flgr_fdbk"),
+                    .with_id(ParamId(802), "flgr_fdbk")
+                    .with_description("Feedback around the delay; negative values invert it for a hollower sound."),
             ),
-            3 => Some(ParamDescriptor::mix().with_id(ParamId(803), "flgr_mix")),
+            3 => Some(ParamDescriptor::mix().with_id(ParamId(803), "flgr_mix")
+            .with_description("Blend of dry and flanged signal.")),
             4 => Some(
                 ParamDescriptor::custom("TZF", "TZF", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(805), "flgr_tzf")
+                    .with_description("Through-zero mode: delays the dry path so the sweep cancels fully at its midpoint. Adds latency.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -201,6 +234,8 @@ impl KernelParams for FlangerParams {
                 ParamDescriptor::custom("Sync", "Sync", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(806), "flgr_sync")
+                    .with_group("Tempo Sync")
+                    .with_description("Lock the LFO rate to the host tempo using Division.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -208,6 +243,8 @@ impl KernelParams for FlangerParams {
                 ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 3.0)
                     .with_step(1.0)
                     .with_id(ParamId(807), "flgr_division")
+                    .with_group("Tempo Sync")
+                    .with_description("Note length of one LFO cycle when Sync is on.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(DIVISION_LABELS),
             ),
@@ -217,8 +254,25 @@ impl KernelParams for FlangerParams {
             8 => Some(
                 ParamDescriptor::custom("LFO Phase", "Phase", 0.0, 1.0, 0.0)
                     .with_id(ParamId(809), "flgr_lfo_phase")
+                    .with_description("Current LFO position in its cycle (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
+            9 => Some(
+                ParamDescriptor::custom("Spread", "Sprd", 0.0, 100.0, 50.0)
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(ParamId(810), "flgr_spread")
+                    .with_group("Stereo")
+                    .with_description("Phase offset between the left and right sweeps, up to 180°."),
+            ),
+            10 => Some(
+                ParamDescriptor::custom("Cross Feedback", "X-Fb", 0.0, 100.0, 0.0)
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(ParamId(811), "flgr_cross_fb")
+                    .with_group("Stereo")
+                    .with_description("Share of the feedback sent to the opposite channel."),
+            ),
             _ => None,
         }
     }
@@ -234,6 +288,8 @@ impl KernelParams for FlangerParams {
             6 => SmoothingStyle::None,     // division — stepped enum, snap
             7 => SmoothingStyle::Standard, // output level
             8 => SmoothingStyle::None,     // lfo_phase — READ_ONLY diagnostic, no smoothing
+            9 => SmoothingStyle::Standard, // spread — phase offset glides
+            10 => SmoothingStyle::Standard, // cross feedback
             _ => SmoothingStyle::Standard,
         }
     }
@@ -249,6 +305,8 @@ impl KernelParams for FlangerParams {
             6 => self.division,
             7 => self.output_db,
             8 => self.lfo_phase,
+            9 => self.spread_pct,
+            10 => self.cross_fb_pct,
             _ => 0.0,
         }
     }
@@ -264,6 +322,8 @@ impl KernelParams for FlangerParams {
             6 => self.division = value,
             7 => self.output_db = value,
             8 => self.lfo_phase = value,
+            9 => self.spread_pct = value,
+            10 => self.cross_fb_pct = value,
             _ => {}
         }
     }
@@ -278,7 +338,7 @@ impl KernelParams for FlangerParams {
 /// Contains ONLY the mutable state required for audio processing:
 /// - Wet-path modulated delay lines (L/R)
 /// - TZF fixed dry-path delay lines (L/R)
-/// - LFOs (L/R — right channel offset 90°)
+/// - LFOs (L/R — right channel locked to left at the spread offset)
 /// - Feedback sample registers (L/R)
 /// - Derived geometry constants (base delay, max modulation)
 /// - Tempo manager for tempo-sync
@@ -295,7 +355,7 @@ pub struct FlangerKernel {
     dry_delay_r: InterpolatedDelay,
     /// LFO for left channel.
     lfo: Lfo,
-    /// LFO for right channel (initialised 90° ahead of left).
+    /// LFO for right channel, re-phased each sample to lead the left by the spread.
     lfo_r: Lfo,
     /// Last wet output stored for feedback on the left channel.
     feedback_sample: f32,
@@ -305,8 +365,6 @@ pub struct FlangerKernel {
     base_delay_samples: f32,
     /// Maximum modulation excursion in samples — 5 ms at the current sample rate.
     max_mod_samples: f32,
-    /// Fixed stereo phase offset between the two LFOs (0.25 = 90°).
-    stereo_spread: f32,
     /// Tempo manager for tempo-synced LFO rates.
     tempo: TempoManager,
     /// Current sample rate (Hz).
@@ -340,21 +398,17 @@ impl FlangerKernel {
         // TZF dry delay is fixed at base_delay_samples (the sweep midpoint).
         let dry_delay_size = ceilf(base_delay_samples) as usize + 1;
 
-        let mut lfo_r = Lfo::new(sample_rate, 0.5);
-        lfo_r.set_phase(0.25); // 90° offset for stereo spread
-
         Self {
             delay: InterpolatedDelay::new(max_delay_samples),
             delay_r: InterpolatedDelay::new(max_delay_samples),
             dry_delay_l: InterpolatedDelay::new(dry_delay_size),
             dry_delay_r: InterpolatedDelay::new(dry_delay_size),
             lfo: Lfo::new(sample_rate, 0.5),
-            lfo_r,
+            lfo_r: Lfo::new(sample_rate, 0.5),
             feedback_sample: 0.0,
             feedback_sample_r: 0.0,
             base_delay_samples,
             max_mod_samples,
-            stereo_spread: 0.25,
             tempo: TempoManager::new(sample_rate, 120.0),
             sample_rate,
             lfo_phase_diagnostic: 0.0,
@@ -385,12 +439,14 @@ impl DspKernel for FlangerKernel {
     /// ## Signal path
     ///
     /// 1. Compute effective LFO rate (manual or tempo-synced).
-    /// 2. Advance both LFOs to obtain unipolar [0, 1] modulation values.
+    /// 2. Lock the right LFO to the left at the spread offset, then advance
+    ///    both to obtain unipolar [0, 1] modulation values.
     /// 3. Derive per-channel delay times:
     ///    `delay = base + (lfo * 2 − 1) × depth × max_mod`
     ///    clamped to ≥ `MIN_DELAY_MS` samples (non-TZF) or ≥ 0 (TZF).
     /// 4. Read from the modulated delay line at the computed delay time.
-    /// 5. Write `input + feedback_sample × feedback_fraction` into the delay.
+    /// 5. Write `input + blended feedback × feedback_fraction` into each delay,
+    ///    where the blend moves `cross_fb` of each channel's feedback across.
     /// 6. In TZF mode, route the dry signal through a fixed delay at
     ///    `base_delay_samples`; otherwise pass dry directly.
     /// 7. Apply `feedback_wet_compensation` to the wet signal, mix, and apply
@@ -403,12 +459,22 @@ impl DspKernel for FlangerKernel {
         let mix = params.mix_pct / 100.0; // 0–1 fraction
         let output_gain = fast_db_to_linear(params.output_db);
         let tzf = params.tzf > 0.5;
+        let spread = params.spread_pct / 100.0 * 0.5; // 0–100 % → 0–0.5 cycle
+        let cross = params.cross_fb_pct / 100.0; // 0–1 fraction
 
         // ── LFO advancement ──
         let rate = self.effective_rate(params);
         self.lfo.set_frequency(rate);
         self.lfo_r.set_frequency(rate);
 
+        // Right LFO leads the left by the spread. Re-phasing every sample
+        // lets the offset glide with the smoothed parameter.
+        let mut phase_r = self.lfo.phase() + spread;
+        if phase_r >= 1.0 {
+            phase_r -= 1.0;
+        }
+        self.lfo_r.set_phase(phase_r);
+
         let lfo_l = self.lfo.advance_unipolar(); // [0, 1]
         let lfo_r = self.lfo_r.advance_unipolar(); // [0, 1]
         self.lfo_phase_diagnostic = self.lfo.phase();
@@ -431,8 +497,12 @@ impl DspKernel for FlangerKernel {
         let delayed_r = self.delay_r.read(delay_r);
 
         // ── Write input + feedback into delay lines ──
-        let input_l = left + self.feedback_sample * feedback;
-        let input_r = right + self.feedback_sample_r * feedback;
+        // Cross-feedback blends the two channels' feedback with weights
+        // summing to 1, so the loop gain stays at |feedback|.
+        let fb_l = self.feedback_sample * (1.0 - cross) + self.feedback_sample_r * cross;
+        let fb_r = self.feedback_sample_r * (1.0 - cross) + self.feedback_sample * cross;
+        let input_l = left + fb_l * feedback;
+        let input_r = right + fb_r * feedback;
         self.delay.write(input_l);
         self.delay_r.write(input_r);
 
@@ -465,8 +535,8 @@ impl DspKernel for FlangerKernel {
     }
 
     fn is_true_stereo(&self) -> bool {
-        // L and R channels use LFOs with a 90° phase offset — their outputs
-        // are decorrelated, qualifying this as a true stereo effect.
+        // L and R sweep at the spread offset and cross-feedback couples the
+        // channels — their outputs differ, qualifying this as true stereo.
         true
     }
 
@@ -525,8 +595,6 @@ impl DspKernel for FlangerKernel {
         self.dry_delay_r.clear();
         self.lfo.reset();
         self.lfo_r.reset();
-        // Restore the right-channel LFO phase offset after reset clears it.
-        self.lfo_r.set_phase(self.stereo_spread);
         self.feedback_sample = 0.0;
         self.feedback_sample_r = 0.0;
     }
@@ -579,10 +647,10 @@ mod tests {
 
     // ── Descriptor count ──
 
-    /// `FlangerParams::COUNT` is 8 and every index 0..COUNT has a descriptor.
+    /// `FlangerParams::COUNT` is 11 and every index 0..COUNT has a descriptor.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(FlangerParams::COUNT, 9, "Expected 9 parameters");
+        assert_eq!(FlangerParams::COUNT, 11, "Expected 11 parameters");
 
         for i in 0..FlangerParams::COUNT {
             assert!(
@@ -788,6 +856,16 @@ mod tests {
             ParamId(809),
             "lfo_phase diagnostic ParamId"
         );
+        assert_eq!(
+            adapter.param_info(9).unwrap().id,
+            ParamId(810),
+            "spread ParamId"
+        );
+        assert_eq!(
+            adapter.param_info(10).unwrap().id,
+            ParamId(811),
+            "cross feedback ParamId"
+        );
 
         // Range sanity checks.
         let feedback_desc = adapter.param_info(2).unwrap();
@@ -869,6 +947,15 @@ mod tests {
 
         params.set(7, -6.0);
         assert!((params.get(7) - (-6.0)).abs() < 1e-6, "output roundtrip");
+
+        params.set(9, 80.0);
+        assert!((params.get(9) - 80.0).abs() < 1e-6, "spread roundtrip");
+
+        params.set(10, 40.0);
+        assert!(
+            (params.get(10) - 40.0).abs() < 1e-6,
+            "cross feedback roundtrip"
+        );
     }
 
     // ── from_knobs mapping ──
@@ -956,8 +1043,8 @@ mod tests {
 
     // ── Right-channel LFO offset restored after reset ──
 
-    /// After `reset()`, the right LFO phase offset (90°) is restored,
-    /// ensuring stereo decorrelation is maintained.
+    /// After `reset()`, the right LFO still leads the left by the spread
+    /// (90° at the default), ensuring stereo decorrelation is maintained.
     ///
     /// The test primes the kernel with steady input until the delay buffers
     /// have audio in them (so the wet signal contributes to the output), then
@@ -999,4 +1086,98 @@ mod tests {
             "L and R outputs should differ after reset (stereo decorrelation via 90° LFO offset)",
         );
     }
+
+    // ── Stereo spread ──
+
+    /// At 0 % spread both channels sweep together, so identical inputs give
+    /// identical outputs; at 100 % they diverge.
+    #[test]
+    fn spread_controls_channel_divergence() {
+        let run = |spread_pct: f32| {
+            let mut kernel = FlangerKernel::new(48000.0);
+            let params = FlangerParams {
+                rate: 2.0,
+                depth_pct: 100.0,
+                mix_pct: 100.0,
+                spread_pct,
+                ..FlangerParams::default()
+            };
+            let mut max_diff = 0.0f32;
+            for i in 0..24000 {
+                let x = libm::sinf(i as f32 * 0.05) * 0.5;
+                let (l, r) = kernel.process_stereo(x, x, &params);
+                max_diff = max_diff.max((l - r).abs());
+            }
+            max_diff
+        };
+
+        assert!(run(0.0) < 1e-6, "0 % spread should keep L and R identical");
+        assert!(
+            run(100.0) > 0.05,
+            "full spread should decorrelate the channels"
+        );
+    }
+
+    /// At 100 % spread the right LFO sits half a cycle from the left.
+    #[test]
+    fn full_spread_is_antiphase() {
+        let mut kernel = FlangerKernel::new(48000.0);
+        let params = FlangerParams {
+            spread_pct: 100.0,
+            ..FlangerParams::default()
+        };
+        for _ in 0..1000 {
+            kernel.process_stereo(0.0, 0.0, &params);
+            let diff = (kernel.lfo_r.phase() - kernel.lfo.phase()).rem_euclid(1.0);
+            assert!((diff - 0.5).abs() < 1e-4, "phase difference {diff}");
+        }
+    }
+
+    // ── Cross-feedback ──
+
+    /// With cross-feedback, regeneration from a left-only input reaches the
+    /// right channel; without it the right channel stays silent.
+    #[test]
+    fn cross_feedback_couples_channels() {
+        let run = |cross_fb_pct: f32| {
+            let mut kernel = FlangerKernel::new(48000.0);
+            let params = FlangerParams {
+                feedback_pct: 90.0,
+                mix_pct: 100.0,
+                cross_fb_pct,
+                ..FlangerParams::default()
+            };
+            let mut energy_r = 0.0f32;
+            for i in 0..4800 {
+                let x = if i == 0 { 1.0 } else { 0.0 };
+                let (_, r) = kernel.process_stereo(x, 0.0, &params);
+                energy_r += r * r;
+            }
+            energy_r
+        };
+
+        assert!(run(0.0) < 1e-12, "no cross-feedback should leave R silent");
+        assert!(run(100.0) > 1e-3, "cross-feedback should reach R");
+    }
+
+    /// Maximum feedback with full cross-feedback stays bounded.
+    #[test]
+    fn cross_feedback_stable() {
+        let mut kernel = FlangerKernel::new(48000.0);
+        let params = FlangerParams {
+            feedback_pct: 95.0,
+            depth_pct: 100.0,
+            mix_pct: 100.0,
+            cross_fb_pct: 100.0,
+            ..FlangerParams::default()
+        };
+        for i in 0..48000 {
+            let x = if i % 2 == 0 { 0.5 } else { -0.5 };
+            let (l, r) = kernel.process_stereo(x, -x, &params);
+            assert!(
+                l.is_finite() && r.is_finite() && l.abs() < 20.0 && r.abs() < 20.0,
+                "unbounded at sample {i}: l={l}, r={r}"
+            );
+        }
+    }
 }
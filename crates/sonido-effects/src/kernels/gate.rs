@@ -1,6 +1,6 @@
-//! Gate kernel — noise gate with hysteresis, hold time, and sidechain HPF.
+//! Gate kernel — noise gate with hysteresis, hold time, and sidechain key filter.
 //!
-//! `GateKernel` owns DSP state (envelope follower, sidechain biquad, gate
+//! `GateKernel` owns DSP state (envelope follower, sidechain key filters, gate
 //! state machine, exponential coefficients, linear caches). Parameters are
 //! received via `&GateParams` each sample. Deployed via
 //! [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin, or
@@ -9,13 +9,28 @@
 //! # Signal Flow
 //!
 //! ```text
-//! Input ──┬──────────────────────────────────────────── × gain ── × output
-//!         │                                                  ▲
-//!         └─► SC HPF (Biquad) ─► Envelope ─► GateStateMachine
+//! Input ──┬──────────────────────────────────────────────── × gain ── × output
+//!         │                                                      ▲
+//! Key ────┴─► SC HPF + Tilt (per channel) ─► Envelope ─► GateStateMachine
 //! ```
 //!
-//! Linked-stereo processing: the sidechain uses the average of `|left|` and
-//! `|right|`, so both channels are gated by the same gain factor.
+//! Linked-stereo processing: each key channel is filtered, then the detector
+//! uses the average of the filtered `|left|` and `|right|`, so both channels
+//! are gated by the same gain factor.
+//!
+//! The key is the main input unless `key_source` is External and an external
+//! sidechain is supplied. With `key_listen` on, the filtered key replaces the
+//! output.
+//!
+//! # Learn
+//!
+//! Switching `learn` on starts a [`LEARN_WINDOW_SECS`] measurement of the
+//! detector envelope while only noise is playing. When it finishes,
+//! `learn_progress` reads 1.0 and the READ_ONLY `learned_*` params hold a
+//! threshold just above the noise peak (plus hysteresis and
+//! [`LEARN_MARGIN_DB`]) and suggested hold and release times. The kernel never
+//! changes its own settings: the host (GUI panel, pedal firmware) applies the
+//! result and switches `learn` off again.
 //!
 //! # Gate State Machine
 //!
@@ -51,10 +66,20 @@
 
 use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
 use sonido_core::{
-    Biquad, Cached, EnvelopeFollower, ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit,
-    fast_db_to_linear, highpass_coefficients, math::db_to_linear,
+    Cached, EnvelopeFollower, KeyFilter, ParamDescriptor, ParamFlags, ParamId, ParamScale,
+    ParamUnit, fast_db_to_linear,
+    math::{db_to_linear, linear_to_db},
 };
 
+/// Length of a learn measurement in seconds.
+pub const LEARN_WINDOW_SECS: f32 = 3.0;
+
+/// Headroom above the noise peak (on top of hysteresis) for a learned threshold, in dB.
+///
+/// The gate closes at `threshold − hysteresis`, so the learned threshold puts
+/// the close point this far above the loudest noise seen.
+pub const LEARN_MARGIN_DB: f32 = 3.0;
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Gate state machine
 // ═══════════════════════════════════════════════════════════════════════════
@@ -97,6 +122,15 @@ enum GateState {
 /// | 5 | `hysteresis_db` | dB | 0–12 | 3.0 |
 /// | 6 | `sidechain_freq_hz` | Hz | 20–500 | 80.0 |
 /// | 7 | `output_db` | dB | −20–+6 | 0.0 |
+/// | 8 | `gate_open` | — | 0–1 | read-only |
+/// | 9 | `sidechain_tilt_db` | dB | −6–6 | 0.0 |
+/// | 10 | `key_source` | index | 0–1 | 0 (Internal) |
+/// | 11 | `key_listen` | index | 0–1 | 0 (Off) |
+/// | 12 | `learn` | index | 0–1 | 0 (Off) |
+/// | 13 | `learn_progress` | — | 0–1 | read-only |
+/// | 14 | `learned_threshold_db` | dB | −80–0 | read-only |
+/// | 15 | `learned_hold_ms` | ms | 0–500 | read-only |
+/// | 16 | `learned_release_ms` | ms | 10–1000 | read-only |
 ///
 /// # Notes on ParamId assignment
 ///
@@ -165,6 +199,47 @@ pub struct GateParams {
     /// `KernelParams` index 8 with `ParamFlags::READ_ONLY | ParamFlags::HIDDEN`.
     /// Actual value is provided by `GateKernel::gate_open_diagnostic` field.
     pub gate_open: f32,
+
+    /// Sidechain tilt in decibels.
+    ///
+    /// Range: −6.0–6.0 dB (default 0.0). Tilts the detection path around
+    /// 1 kHz after the highpass, so e.g. a snare's crack rather than its
+    /// body opens the gate.
+    pub sidechain_tilt_db: f32,
+
+    /// Key source: 0.0 = Internal, 1.0 = External.
+    ///
+    /// External opens the gate from the sidechain input when one is
+    /// supplied; Internal always detects from the main input. Stepped.
+    pub key_source: f32,
+
+    /// Key listen: 0.0 = Off, 1.0 = On.
+    ///
+    /// When on, the output is the filtered key signal instead of the gated
+    /// audio. Stepped.
+    pub key_listen: f32,
+
+    /// Learn trigger: 0.0 = Off, 1.0 = Learn.
+    ///
+    /// Switching on starts a noise-floor measurement; switching off cancels
+    /// it or acknowledges the result. Stepped and hidden — a host control,
+    /// not a preset setting.
+    pub learn: f32,
+
+    /// READ_ONLY diagnostic: learn progress, 0.0–1.0.
+    ///
+    /// 1.0 means the `learned_*` values hold a fresh result. Reads 0.0
+    /// whenever `learn` is off.
+    pub learn_progress: f32,
+
+    /// READ_ONLY diagnostic: threshold from the last learn, in dB.
+    pub learned_threshold_db: f32,
+
+    /// READ_ONLY diagnostic: suggested hold time from the last learn, in ms.
+    pub learned_hold_ms: f32,
+
+    /// READ_ONLY diagnostic: suggested release time from the last learn, in ms.
+    pub learned_release_ms: f32,
 }
 
 impl Default for GateParams {
@@ -179,6 +254,14 @@ impl Default for GateParams {
             sidechain_freq_hz: 80.0,
             output_db: 0.0,
             gate_open: 0.0,
+            sidechain_tilt_db: 0.0,
+            key_source: 0.0,
+            key_listen: 0.0,
+            learn: 0.0,
+            learn_progress: 0.0,
+            learned_threshold_db: -40.0,
+            learned_hold_ms: 50.0,
+            learned_release_ms: 100.0,
         }
     }
 }
@@ -215,7 +298,7 @@ impl GateParams {
 }
 
 impl KernelParams for GateParams {
-    const COUNT: usize = 9;
+    const COUNT: usize = 17;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
@@ -232,7 +315,8 @@ impl KernelParams for GateParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(400), "gate_thresh"),
+                .with_id(ParamId(400), "gate_thresh")
+                .with_description("Level above which the gate opens."),
             ),
             // ── [1] Attack ──────────────────────────────────────────────────
             // ParamId(401), "gate_attack" — matches classic gate.rs [1]
@@ -247,19 +331,22 @@ impl KernelParams for GateParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(401), "gate_attack"),
+                .with_id(ParamId(401), "gate_attack")
+                .with_description("How fast the gate opens."),
             ),
             // ── [2] Release ─────────────────────────────────────────────────
             // ParamId(402), "gate_release" — matches classic gate.rs [2]
             2 => Some(
                 ParamDescriptor::time_ms("Release", "Rel", 10.0, 1000.0, 100.0)
-                    .with_id(ParamId(402), "gate_release"),
+                    .with_id(ParamId(402), "gate_release")
+                    .with_description("How fast the gate closes after the hold time."),
             ),
             // ── [3] Hold ────────────────────────────────────────────────────
             // ParamId(403), "gate_hold" — matches classic gate.rs [3]
             3 => Some(
                 ParamDescriptor::time_ms("Hold", "Hold", 0.0, 500.0, 50.0)
-                    .with_id(ParamId(403), "gate_hold"),
+                    .with_id(ParamId(403), "gate_hold")
+                    .with_description("Minimum time the gate stays open after the signal falls below the threshold."),
             ),
             // ── [4] Range (floor) ───────────────────────────────────────────
             // ParamId(405), "gate_range" — NOTE: 405 not 404; matches classic gate.rs [4]
@@ -274,7 +361,8 @@ impl KernelParams for GateParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(405), "gate_range"),
+                .with_id(ParamId(405), "gate_range")
+                .with_description("Attenuation when closed; raise it for a gentler gate."),
             ),
             // ── [5] Hysteresis ──────────────────────────────────────────────
             // ParamId(406), "gate_hysteresis" — matches classic gate.rs [5]
@@ -289,7 +377,8 @@ impl KernelParams for GateParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(406), "gate_hysteresis"),
+                .with_id(ParamId(406), "gate_hysteresis")
+                .with_description("How far below the threshold the signal must fall before the gate closes."),
             ),
             // ── [6] SC HPF Freq ─────────────────────────────────────────────
             // ParamId(407), "gate_sc_hpf", Logarithmic — matches classic gate.rs [6]
@@ -305,6 +394,8 @@ impl KernelParams for GateParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(407), "gate_sc_hpf")
+                .with_group("Sidechain")
+                .with_description("Highpass on the detector so low rumble doesn't open the gate.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             // ── [7] Output ──────────────────────────────────────────────────
@@ -318,6 +409,79 @@ impl KernelParams for GateParams {
             8 => Some(
                 ParamDescriptor::custom("Gate Open", "Open", 0.0, 1.0, 0.0)
                     .with_id(ParamId(408), "gate_open")
+                    .with_description("Whether the gate is currently open (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            // ── [9] SC Tilt ─────────────────────────────────────────────────
+            // ParamId(409), "gate_sc_tilt"
+            9 => Some(
+                ParamDescriptor::gain_db("SC Tilt", "SC Tilt", -6.0, 6.0, 0.0)
+                    .with_id(ParamId(409), "gate_sc_tilt")
+                    .with_group("Sidechain")
+                    .with_description("Tilts the detector toward highs (positive) or lows (negative)."),
+            ),
+            // ── [10] Key source ─────────────────────────────────────────────
+            // ParamId(410), "gate_key"
+            10 => Some(
+                ParamDescriptor::custom("Key", "Key", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(410), "gate_key")
+                    .with_group("Sidechain")
+                    .with_description("Detector source: the main input, or the external sidechain input.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Internal", "External"]),
+            ),
+            // ── [11] Key listen ─────────────────────────────────────────────
+            // ParamId(411), "gate_key_listen"
+            11 => Some(
+                ParamDescriptor::custom("Key Listen", "Listen", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(411), "gate_key_listen")
+                    .with_group("Sidechain")
+                    .with_description("Output the filtered detector signal instead of the gated audio.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Off", "On"]),
+            ),
+            // ── [12] Learn ──────────────────────────────────────────────────
+            // ParamId(412), "gate_learn" — trigger, hidden from generic UIs
+            12 => Some(
+                ParamDescriptor::custom("Learn", "Learn", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(412), "gate_learn")
+                    .with_description("Listen to noise only for a few seconds and suggest a threshold.")
+                    .with_flags(ParamFlags::STEPPED.union(ParamFlags::HIDDEN))
+                    .with_step_labels(&["Off", "Learn"]),
+            ),
+            // ── [13] Learn progress (READ_ONLY diagnostic) ──────────────────
+            // ParamId(413), "gate_learn_progress" — 1.0 = result ready
+            13 => Some(
+                ParamDescriptor::custom("Learn Progress", "Learn", 0.0, 1.0, 0.0)
+                    .with_id(ParamId(413), "gate_learn_progress")
+                    .with_description("Progress of the current learn (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            // ── [14] Learned threshold (READ_ONLY diagnostic) ───────────────
+            // ParamId(414), "gate_learned_thresh"
+            14 => Some(
+                ParamDescriptor::gain_db("Learned Threshold", "Lrn Thr", -80.0, 0.0, -40.0)
+                    .with_id(ParamId(414), "gate_learned_thresh")
+                    .with_description("Threshold measured by the last learn (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            // ── [15] Learned hold (READ_ONLY diagnostic) ────────────────────
+            // ParamId(415), "gate_learned_hold"
+            15 => Some(
+                ParamDescriptor::time_ms("Learned Hold", "Lrn Hold", 0.0, 500.0, 50.0)
+                    .with_id(ParamId(415), "gate_learned_hold")
+                    .with_description("Hold time suggested by the last learn (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            // ── [16] Learned release (READ_ONLY diagnostic) ─────────────────
+            // ParamId(416), "gate_learned_release"
+            16 => Some(
+                ParamDescriptor::time_ms("Learned Release", "Lrn Rel", 10.0, 1000.0, 100.0)
+                    .with_id(ParamId(416), "gate_learned_release")
+                    .with_description("Release time suggested by the last learn (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
             _ => None,
@@ -335,6 +499,11 @@ impl KernelParams for GateParams {
             6 => SmoothingStyle::Slow,     // sidechain HPF — filter coefficient, avoid zipper
             7 => SmoothingStyle::Standard, // output level
             8 => SmoothingStyle::None,     // gate_open — READ_ONLY diagnostic, no smoothing
+            9 => SmoothingStyle::Slow,     // sidechain tilt — filter gains, avoid zipper
+            10 => SmoothingStyle::None,    // key source — stepped, snap immediately
+            11 => SmoothingStyle::None,    // key listen — stepped, snap immediately
+            12 => SmoothingStyle::None,    // learn — trigger, snap immediately
+            13..=16 => SmoothingStyle::None, // learn results — READ_ONLY diagnostics
             _ => SmoothingStyle::Standard,
         }
     }
@@ -352,6 +521,14 @@ impl KernelParams for GateParams {
             // Index 8 (gate_open): actual value stored on GateKernel, not GateParams.
             // Returns 0.0 placeholder here; consumers read gate_open_diagnostic directly.
             8 => self.gate_open,
+            9 => self.sidechain_tilt_db,
+            10 => self.key_source,
+            11 => self.key_listen,
+            12 => self.learn,
+            13 => self.learn_progress,
+            14 => self.learned_threshold_db,
+            15 => self.learned_hold_ms,
+            16 => self.learned_release_ms,
             _ => 0.0,
         }
     }
@@ -367,11 +544,116 @@ impl KernelParams for GateParams {
             6 => self.sidechain_freq_hz = value,
             7 => self.output_db = value,
             8 => self.gate_open = value, // READ_ONLY: kernel writes this, not user
+            9 => self.sidechain_tilt_db = value,
+            10 => self.key_source = value,
+            11 => self.key_listen = value,
+            12 => self.learn = value,
+            // READ_ONLY: kernel writes these, not user
+            13 => self.learn_progress = value,
+            14 => self.learned_threshold_db = value,
+            15 => self.learned_hold_ms = value,
+            16 => self.learned_release_ms = value,
             _ => {}
         }
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+//  Learn
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Noise-floor measurement behind the `learn` parameter.
+///
+/// Tracks the peak and RMS of the detector envelope over
+/// [`LEARN_WINDOW_SECS`], then derives:
+///
+/// - **Threshold**: `peak + hysteresis + LEARN_MARGIN_DB`, so the gate closes
+///   above the loudest noise.
+/// - **Hold**: 10 ms per dB of envelope crest (`peak − rms`), 10–200 ms.
+///   Spiky noise (crackle, hum bursts) gets a longer hold so the gate rides
+///   through it instead of chattering; steady hiss gets a short one.
+/// - **Release**: three times the hold, 50–500 ms, so the tail fades under
+///   the noise rather than cutting off.
+#[derive(Debug, Clone, Copy)]
+struct GateLearn {
+    /// `learn` was on at the previous sample (rising-edge detection).
+    armed: bool,
+    /// Envelope samples measured so far.
+    samples: u32,
+    /// Measurement length in samples, fixed when the measurement starts.
+    window: u32,
+    /// Envelope peak over the window (linear).
+    peak: f32,
+    /// Sum of squared envelope values over the window.
+    sum_squares: f64,
+    /// Progress 0–1; 1.0 once the result below is fresh.
+    progress: f32,
+    /// Learned threshold in dB.
+    threshold_db: f32,
+    /// Suggested hold in ms.
+    hold_ms: f32,
+    /// Suggested release in ms.
+    release_ms: f32,
+}
+
+impl GateLearn {
+    fn new() -> Self {
+        let defaults = GateParams::default();
+        Self {
+            armed: false,
+            samples: 0,
+            window: 0,
+            peak: 0.0,
+            sum_squares: 0.0,
+            progress: 0.0,
+            threshold_db: defaults.learned_threshold_db,
+            hold_ms: defaults.learned_hold_ms,
+            release_ms: defaults.learned_release_ms,
+        }
+    }
+
+    /// Advance by one envelope sample while `learn` is `on`.
+    #[inline]
+    fn update(&mut self, on: bool, envelope: f32, sample_rate: f32, hysteresis_db: f32) {
+        if !on {
+            self.armed = false;
+            self.progress = 0.0;
+            return;
+        }
+        if !self.armed {
+            self.armed = true;
+            self.samples = 0;
+            self.window = ((LEARN_WINDOW_SECS * sample_rate) as u32).max(1);
+            self.peak = 0.0;
+            self.sum_squares = 0.0;
+            self.progress = 0.0;
+        }
+        if self.samples >= self.window {
+            return;
+        }
+
+        self.peak = self.peak.max(envelope);
+        self.sum_squares += f64::from(envelope) * f64::from(envelope);
+        self.samples += 1;
+        self.progress = self.samples as f32 / self.window as f32;
+        if self.samples == self.window {
+            self.finish(hysteresis_db);
+        }
+    }
+
+    /// Turn the window statistics into the learned settings.
+    fn finish(&mut self, hysteresis_db: f32) {
+        let rms = libm::sqrt(self.sum_squares / f64::from(self.samples)) as f32;
+        let peak_db = linear_to_db(self.peak);
+        let crest_db = (peak_db - linear_to_db(rms)).max(0.0);
+
+        self.threshold_db = (peak_db + hysteresis_db + LEARN_MARGIN_DB).clamp(-80.0, 0.0);
+        self.hold_ms = (crest_db * 10.0).clamp(10.0, 200.0);
+        self.release_ms = (self.hold_ms * 3.0).clamp(50.0, 500.0);
+        self.progress = 1.0;
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Kernel
 // ═══════════════════════════════════════════════════════════════════════════
@@ -385,13 +667,14 @@ impl KernelParams for GateParams {
 ///
 /// - `envelope_follower` — fast peak detector (0.1 ms attack / 20 ms release)
 ///   on the sidechain path.
-/// - `sidechain_hpf` — biquad HPF that rejects low-frequency content from
-///   the detection path before the envelope follower.
+/// - `key_filter_l` / `key_filter_r` — highpass + tilt on each key channel
+///   before the envelope follower.
 /// - `state` / `gain` / `hold_counter` — gate state machine.
 /// - `attack_coeff` / `release_coeff` — exponential one-pole coefficients
 ///   cached from `params.attack_ms` / `params.release_ms`.
 /// - `cached_threshold_linear` / `cached_floor_linear` — linearized dB values
 ///   recomputed only when the corresponding params change.
+/// - `learn` — noise-floor measurement started by `params.learn`.
 ///
 /// # Gate Algorithm
 ///
@@ -413,11 +696,11 @@ pub struct GateKernel {
     /// attack/release parameters control the gain ramp, not this follower.
     envelope_follower: EnvelopeFollower,
 
-    /// Sidechain highpass biquad filter.
-    ///
-    /// Rejects low-frequency content from the detection path.
-    /// Coefficients are recomputed when `sidechain_freq_hz` changes.
-    sidechain_hpf: Biquad,
+    /// Left (and mono) key filter — highpass + tilt on the detection path.
+    key_filter_l: KeyFilter,
+
+    /// Right key filter.
+    key_filter_r: KeyFilter,
 
     /// Current gate state (Closed, Opening, Open, Holding, Closing).
     state: GateState,
@@ -461,14 +744,15 @@ pub struct GateKernel {
     /// Change-detector for release_ms → release_coeff.
     release_cache: Cached<f32>,
 
-    /// Change-detector for sidechain HPF coefficients.
-    sc_hpf_cache: Cached<[f32; 6]>,
-
     /// Diagnostic: 1.0 when gate is open/opening, 0.0 when closed/closing.
     ///
     /// Written each sample in `process_stereo`. Exposed at `GateParams` index 8
     /// with `ParamFlags::READ_ONLY | ParamFlags::HIDDEN`.
     pub gate_open_diagnostic: f32,
+
+    /// Noise-floor measurement driven by `params.learn`; its result is
+    /// exposed at `GateParams` indices 13–16.
+    learn: GateLearn,
 }
 
 impl GateKernel {
@@ -485,11 +769,6 @@ impl GateKernel {
         envelope_follower.set_attack_ms(0.1);
         envelope_follower.set_release_ms(20.0);
 
-        // Initialize sidechain HPF at default 80 Hz
-        let mut sidechain_hpf = Biquad::new();
-        let (b0, b1, b2, a0, a1, a2) = highpass_coefficients(80.0, 0.707, sample_rate);
-        sidechain_hpf.set_coefficients(b0, b1, b2, a0, a1, a2);
-
         // Compute initial attack/release coefficients
         let attack_coeff = Self::compute_coeff(defaults.attack_ms, sample_rate);
         let release_coeff = Self::compute_coeff(defaults.release_ms, sample_rate);
@@ -498,10 +777,6 @@ impl GateKernel {
         let cached_threshold_linear = fast_db_to_linear(defaults.threshold_db);
         let cached_floor_linear = db_to_linear(defaults.range_db);
 
-        let sc_hpf_coeffs = {
-            let (b0, b1, b2, a0, a1, a2) = highpass_coefficients(80.0, 0.707, sample_rate);
-            [b0, b1, b2, a0, a1, a2]
-        };
         let mut threshold_cache = Cached::new(cached_threshold_linear, 1);
         threshold_cache.update(&[defaults.threshold_db], 1e-3, |inputs| {
             fast_db_to_linear(inputs[0])
@@ -518,17 +793,12 @@ impl GateKernel {
         release_cache.update(&[defaults.release_ms], 1e-2, |inputs| {
             Self::compute_coeff(inputs[0], sample_rate)
         });
-        let mut sc_hpf_cache = Cached::new(sc_hpf_coeffs, 1);
-        sc_hpf_cache.update(&[defaults.sidechain_freq_hz], 0.5, |inputs| {
-            let freq = inputs[0].clamp(20.0, 500.0);
-            let (b0, b1, b2, a0, a1, a2) = highpass_coefficients(freq, 0.707, sample_rate);
-            [b0, b1, b2, a0, a1, a2]
-        });
 
         Self {
             sample_rate,
             envelope_follower,
-            sidechain_hpf,
+            key_filter_l: KeyFilter::new(sample_rate, defaults.sidechain_freq_hz),
+            key_filter_r: KeyFilter::new(sample_rate, defaults.sidechain_freq_hz),
             state: GateState::Closed,
             gain: cached_floor_linear,
             hold_counter: 0,
@@ -543,8 +813,8 @@ impl GateKernel {
             hysteresis_cache,
             attack_cache,
             release_cache,
-            sc_hpf_cache,
             gate_open_diagnostic: 0.0,
+            learn: GateLearn::new(),
         }
     }
 
@@ -595,21 +865,10 @@ impl GateKernel {
             .update(&[params.release_ms], 1e-2, |inputs| {
                 Self::compute_coeff(inputs[0], self.sample_rate)
             });
-        let sc_coeffs = *self
-            .sc_hpf_cache
-            .update(&[params.sidechain_freq_hz], 0.5, |inputs| {
-                let freq = inputs[0].clamp(20.0, 500.0);
-                let (b0, b1, b2, a0, a1, a2) = highpass_coefficients(freq, 0.707, self.sample_rate);
-                [b0, b1, b2, a0, a1, a2]
-            });
-        self.sidechain_hpf.set_coefficients(
-            sc_coeffs[0],
-            sc_coeffs[1],
-            sc_coeffs[2],
-            sc_coeffs[3],
-            sc_coeffs[4],
-            sc_coeffs[5],
-        );
+        self.key_filter_l
+            .set_controls(params.sidechain_freq_hz, params.sidechain_tilt_db);
+        self.key_filter_r
+            .set_controls(params.sidechain_freq_hz, params.sidechain_tilt_db);
     }
 
     /// Advance the gate state machine for one sample.
@@ -693,6 +952,55 @@ impl GateKernel {
             }
         }
     }
+    /// Run the detector on a filtered key level and advance the gate.
+    ///
+    /// `level` is the filtered key; the envelope follower rectifies it.
+    #[inline]
+    fn detect(&mut self, level: f32, params: &GateParams) {
+        let floor = self.cached_floor_linear;
+        let envelope = self.envelope_follower.process(level);
+
+        let hold_samples = ((params.hold_ms / 1000.0) * self.sample_rate) as u32;
+        self.advance_gate_state(envelope, hold_samples, floor);
+        self.learn.update(
+            params.learn >= 0.5,
+            envelope,
+            self.sample_rate,
+            params.hysteresis_db,
+        );
+
+        self.gate_open_diagnostic = match self.state {
+            GateState::Open | GateState::Opening | GateState::Holding => 1.0,
+            GateState::Closed | GateState::Closing => 0.0,
+        };
+    }
+
+    /// Gate `left`/`right` from a stereo key.
+    ///
+    /// Each key channel is filtered, then `(|key_l| + |key_r|) × 0.5` drives
+    /// the detector. With `key_listen` on, returns the filtered key instead.
+    #[inline]
+    fn gate_stereo(
+        &mut self,
+        left: f32,
+        right: f32,
+        key_l: f32,
+        key_r: f32,
+        params: &GateParams,
+    ) -> (f32, f32) {
+        let key_l = self.key_filter_l.process(key_l);
+        let key_r = self.key_filter_r.process(key_r);
+        self.detect((libm::fabsf(key_l) + libm::fabsf(key_r)) * 0.5, params);
+        if params.key_listen >= 0.5 {
+            return (key_l, key_r);
+        }
+
+        let output_gain = fast_db_to_linear(params.output_db);
+        (
+            left * self.gain * output_gain,
+            right * self.gain * output_gain,
+        )
+    }
 }
 
 impl DspKernel for GateKernel {
@@ -700,24 +1008,16 @@ impl DspKernel for GateKernel {
 
     /// Process a single mono sample through the noise gate.
     ///
-    /// Sidechain: input → HPF → envelope follower → gate state machine.
+    /// Sidechain: input → HPF + tilt → envelope follower → gate state machine.
     /// Output: `input × gate_gain × output_level`.
     fn process(&mut self, input: f32, params: &GateParams) -> f32 {
         self.update_caches(params);
 
-        let floor = self.cached_floor_linear;
-
-        // Sidechain path: HPF → envelope follower
-        let sc_filtered = self.sidechain_hpf.process(input);
-        let envelope = self.envelope_follower.process(sc_filtered);
-
-        let hold_samples = ((params.hold_ms / 1000.0) * self.sample_rate) as u32;
-        self.advance_gate_state(envelope, hold_samples, floor);
-
-        self.gate_open_diagnostic = match self.state {
-            GateState::Open | GateState::Opening | GateState::Holding => 1.0,
-            GateState::Closed | GateState::Closing => 0.0,
-        };
+        let key = self.key_filter_l.process(input);
+        self.detect(key, params);
+        if params.key_listen >= 0.5 {
+            return key;
+        }
 
         let output_gain = fast_db_to_linear(params.output_db);
         input * self.gain * output_gain
@@ -725,39 +1025,18 @@ impl DspKernel for GateKernel {
 
     /// Process a stereo sample pair through the noise gate.
     ///
-    /// Linked-stereo sidechain: `(|left| + |right|) × 0.5` drives the gate
-    /// so both channels are controlled by the same gain factor.
+    /// Linked-stereo sidechain: the average of the filtered `|left|` and
+    /// `|right|` drives the gate so both channels share one gain factor.
     fn process_stereo(&mut self, left: f32, right: f32, params: &GateParams) -> (f32, f32) {
         self.update_caches(params);
-
-        let floor = self.cached_floor_linear;
-
-        // Linked-stereo sidechain: average channel amplitudes, then HPF + envelope
-        let sum = (libm::fabsf(left) + libm::fabsf(right)) * 0.5;
-        let sc_filtered = self.sidechain_hpf.process(sum);
-        let envelope = self.envelope_follower.process(sc_filtered);
-
-        let hold_samples = ((params.hold_ms / 1000.0) * self.sample_rate) as u32;
-        self.advance_gate_state(envelope, hold_samples, floor);
-
-        self.gate_open_diagnostic = match self.state {
-            GateState::Open | GateState::Opening | GateState::Holding => 1.0,
-            GateState::Closed | GateState::Closing => 0.0,
-        };
-
-        let output_gain = fast_db_to_linear(params.output_db);
-        (
-            left * self.gain * output_gain,
-            right * self.gain * output_gain,
-        )
+        self.gate_stereo(left, right, left, right, params)
     }
 
     /// Process a stereo sample with an external sidechain signal.
     ///
-    /// Uses the external `sc_left`/`sc_right` average as the detection signal
-    /// instead of the main input. The internal sidechain HPF is bypassed when
-    /// an external sidechain is connected — the external source is already
-    /// pre-routed by the caller.
+    /// With `key_source` External, `sc_left`/`sc_right` replace the main
+    /// input as the key; they pass through the same highpass and tilt. With
+    /// `key_source` Internal the sidechain is ignored.
     fn process_stereo_with_sidechain(
         &mut self,
         left: f32,
@@ -767,31 +1046,17 @@ impl DspKernel for GateKernel {
         params: &GateParams,
     ) -> (f32, f32) {
         self.update_caches(params);
-
-        let floor = self.cached_floor_linear;
-
-        // External sidechain: use average amplitude, bypass the internal HPF.
-        let sc_mid = (libm::fabsf(sc_left) + libm::fabsf(sc_right)) * 0.5;
-        let envelope = self.envelope_follower.process(sc_mid);
-
-        let hold_samples = ((params.hold_ms / 1000.0) * self.sample_rate) as u32;
-        self.advance_gate_state(envelope, hold_samples, floor);
-
-        self.gate_open_diagnostic = match self.state {
-            GateState::Open | GateState::Opening | GateState::Holding => 1.0,
-            GateState::Closed | GateState::Closing => 0.0,
-        };
-
-        let output_gain = fast_db_to_linear(params.output_db);
-        (
-            left * self.gain * output_gain,
-            right * self.gain * output_gain,
-        )
+        if params.key_source >= 0.5 {
+            self.gate_stereo(left, right, sc_left, sc_right, params)
+        } else {
+            self.gate_stereo(left, right, left, right, params)
+        }
     }
 
     fn reset(&mut self) {
         self.envelope_follower.reset();
-        self.sidechain_hpf.clear();
+        self.key_filter_l.reset();
+        self.key_filter_r.reset();
         self.state = GateState::Closed;
         self.gain = self.cached_floor_linear;
         self.hold_counter = 0;
@@ -801,21 +1066,28 @@ impl DspKernel for GateKernel {
         self.hysteresis_cache.invalidate();
         self.attack_cache.invalidate();
         self.release_cache.invalidate();
-        self.sc_hpf_cache.invalidate();
         self.gate_open_diagnostic = 0.0;
+        // Abandon a measurement in progress; a held `learn` restarts it.
+        self.learn.armed = false;
+        self.learn.progress = 0.0;
     }
 
     fn set_sample_rate(&mut self, sample_rate: f32) {
         self.sample_rate = sample_rate;
         self.envelope_follower.set_sample_rate(sample_rate);
+        self.key_filter_l.set_sample_rate(sample_rate);
+        self.key_filter_r.set_sample_rate(sample_rate);
         // Invalidate time-dependent caches so they recompute at next process()
         self.attack_cache.invalidate();
         self.release_cache.invalidate();
-        self.sc_hpf_cache.invalidate();
     }
 
     fn update_diagnostics(&self, params: &mut GateParams) {
         params.gate_open = self.gate_open_diagnostic;
+        params.learn_progress = self.learn.progress;
+        params.learned_threshold_db = self.learn.threshold_db;
+        params.learned_hold_ms = self.learn.hold_ms;
+        params.learned_release_ms = self.learn.release_ms;
     }
 }
 
@@ -866,7 +1138,7 @@ mod tests {
     /// Descriptor count must equal `GateParams::COUNT` and all descriptors must be present.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(GateParams::COUNT, 9);
+        assert_eq!(GateParams::COUNT, 17);
         for i in 0..GateParams::COUNT {
             assert!(
                 GateParams::descriptor(i).is_some(),
@@ -874,8 +1146,8 @@ mod tests {
             );
         }
         assert!(
-            GateParams::descriptor(9).is_none(),
-            "Index 9 should be None"
+            GateParams::descriptor(17).is_none(),
+            "Index 17 should be None"
         );
     }
 
@@ -899,6 +1171,7 @@ mod tests {
             range_db: -80.0,
             sidechain_freq_hz: 20.0, // HPF at 20 Hz — barely filters anything
             gate_open: 0.0,
+            ..GateParams::default()
         };
 
         // Warm up with loud 500 Hz sine (well above -60 dB threshold)
@@ -985,17 +1258,17 @@ mod tests {
         let kernel = GateKernel::new(48000.0);
         let adapter = Adapter::new(kernel, 48000.0);
 
-        // Count (9 = 8 user params + 1 READ_ONLY diagnostic)
-        assert_eq!(adapter.param_count(), 9);
+        // Count (17 = 12 user params + 5 READ_ONLY diagnostics)
+        assert_eq!(adapter.param_count(), 17);
 
         // All present, none past end
-        for i in 0..9 {
+        for i in 0..17 {
             assert!(
                 adapter.param_info(i).is_some(),
                 "Missing param_info for index {i}"
             );
         }
-        assert!(adapter.param_info(9).is_none());
+        assert!(adapter.param_info(17).is_none());
 
         // Names match classic gate.rs
         let expected = [
@@ -1027,6 +1300,11 @@ mod tests {
         assert_eq!(adapter.param_info(5).unwrap().id, ParamId(406)); // Hysteresis
         assert_eq!(adapter.param_info(6).unwrap().id, ParamId(407)); // SC HPF Freq
         assert_eq!(adapter.param_info(7).unwrap().id, ParamId(404)); // Output (NOTE: 404, not 408)
+        assert_eq!(adapter.param_info(9).unwrap().id, ParamId(409)); // SC Tilt
+        assert_eq!(adapter.param_info(10).unwrap().id, ParamId(410)); // Key
+        assert_eq!(adapter.param_info(11).unwrap().id, ParamId(411)); // Key Listen
+        assert_eq!(adapter.param_info(12).unwrap().id, ParamId(412)); // Learn
+        assert_eq!(adapter.param_info(16).unwrap().id, ParamId(416)); // Learned Release
 
         // String IDs — used by CLAP host preset recall
         assert_eq!(adapter.param_info(0).unwrap().string_id, "gate_thresh");
@@ -1074,4 +1352,181 @@ mod tests {
             }
         }
     }
+
+    /// An external key opens the gate on a quiet main signal only when
+    /// `key_source` is External.
+    #[test]
+    fn external_key_opens_gate() {
+        let sr = 48000.0_f32;
+        let run = |key_source: f32| {
+            let mut kernel = GateKernel::new(sr);
+            let params = GateParams {
+                threshold_db: -30.0,
+                key_source,
+                ..GateParams::default()
+            };
+            let main = 0.001_f32;
+            let mut out = 0.0;
+            for i in 0..4800 {
+                let sc = 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 1000.0 / sr);
+                out = kernel
+                    .process_stereo_with_sidechain(main, main, sc, sc, &params)
+                    .0;
+            }
+            out
+        };
+        assert!(
+            (run(1.0) - 0.001).abs() < 1e-5,
+            "External key should open the gate"
+        );
+        assert!(run(0.0) < 1e-5, "Internal key should keep the gate closed");
+    }
+
+    /// Key listen outputs the filtered key on each channel.
+    #[test]
+    fn key_listen_outputs_filtered_key() {
+        let sr = 48000.0_f32;
+        let mut kernel = GateKernel::new(sr);
+        let params = GateParams {
+            sidechain_freq_hz: 500.0,
+            key_listen: 1.0,
+            ..GateParams::default()
+        };
+        let mut low_peak = 0.0_f32;
+        let mut high_peak = 0.0_f32;
+        for i in 0..9600 {
+            let t = i as f32 / sr;
+            let low = 0.5 * libm::sinf(2.0 * core::f32::consts::PI * 40.0 * t);
+            let high = 0.5 * libm::sinf(2.0 * core::f32::consts::PI * 4000.0 * t);
+            let (l, r) = kernel.process_stereo(low, high, &params);
+            if i >= 4800 {
+                low_peak = low_peak.max(l.abs());
+                high_peak = high_peak.max(r.abs());
+            }
+        }
+        assert!(
+            low_peak < 0.05,
+            "40 Hz should be filtered out, peak={low_peak}"
+        );
+        assert!(high_peak > 0.45, "4 kHz should pass, peak={high_peak}");
+    }
+
+    /// Deterministic noise at roughly `amplitude` peak.
+    fn noise(amplitude: f32) -> impl FnMut() -> f32 {
+        let mut state = 0x1234_5678_u32;
+        move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            amplitude * ((state >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0)
+        }
+    }
+
+    /// Learning on noise yields a threshold that keeps that noise gated
+    /// while a louder signal still opens the gate.
+    #[test]
+    fn learn_sets_threshold_above_noise() {
+        let sr = 48000.0_f32;
+        let window = (LEARN_WINDOW_SECS * sr) as usize;
+        let mut kernel = GateKernel::new(sr);
+        let mut hiss = noise(0.01);
+        let mut params = GateParams {
+            learn: 1.0,
+            ..GateParams::default()
+        };
+        let mut diag = params;
+
+        for i in 0..window {
+            let x = hiss();
+            kernel.process_stereo(x, x, &params);
+            if i == window / 2 {
+                kernel.update_diagnostics(&mut diag);
+                assert!((diag.learn_progress - 0.5).abs() < 0.01);
+            }
+        }
+        kernel.update_diagnostics(&mut diag);
+        assert_eq!(diag.learn_progress, 1.0);
+        assert!(
+            (-45.0..-20.0).contains(&diag.learned_threshold_db),
+            "threshold {} dB should sit just above -40 dBFS noise",
+            diag.learned_threshold_db
+        );
+        assert!((10.0..=200.0).contains(&diag.learned_hold_ms));
+        assert!((50.0..=500.0).contains(&diag.learned_release_ms));
+
+        // Apply the result and acknowledge: progress clears, result stays.
+        params.threshold_db = diag.learned_threshold_db;
+        params.learn = 0.0;
+        // The gate may still be open from the learn pass; let it close first.
+        let mut opened = false;
+        for i in 0..window {
+            let x = hiss();
+            kernel.process_stereo(x, x, &params);
+            opened |= i >= window / 4 && kernel.gate_open_diagnostic > 0.0;
+        }
+        assert!(!opened, "learned threshold should keep the noise gated");
+        kernel.update_diagnostics(&mut diag);
+        assert_eq!(diag.learn_progress, 0.0);
+        assert_eq!(diag.learned_threshold_db, params.threshold_db);
+
+        for i in 0..4800 {
+            let x = 0.5 * libm::sinf(i as f32 * 2.0 * core::f32::consts::PI * 500.0 / sr);
+            kernel.process_stereo(x, x, &params);
+        }
+        assert_eq!(
+            kernel.gate_open_diagnostic, 1.0,
+            "a real signal should open the gate"
+        );
+    }
+
+    /// Switching learn off mid-window cancels; switching on again restarts.
+    #[test]
+    fn learn_off_cancels_and_restarts() {
+        let mut kernel = GateKernel::new(48000.0);
+        let mut params = GateParams {
+            learn: 1.0,
+            ..GateParams::default()
+        };
+        let mut diag = params;
+        for _ in 0..1000 {
+            kernel.process(0.01, &params);
+        }
+        params.learn = 0.0;
+        kernel.process(0.01, &params);
+        kernel.update_diagnostics(&mut diag);
+        assert_eq!(diag.learn_progress, 0.0);
+        assert_eq!(
+            diag.learned_threshold_db, -40.0,
+            "no result from a cancelled learn"
+        );
+
+        params.learn = 1.0;
+        kernel.process(0.01, &params);
+        kernel.update_diagnostics(&mut diag);
+        assert!(diag.learn_progress > 0.0 && diag.learn_progress < 0.001);
+    }
+
+    /// Through the adapter, learn results read back as READ_ONLY params.
+    #[test]
+    fn learn_results_read_through_adapter() {
+        let sr = 48000.0_f32;
+        let mut adapter = Adapter::new(GateKernel::new(sr), sr);
+        adapter.set_param(12, 1.0);
+        let mut hiss = noise(0.01);
+        let frames = (LEARN_WINDOW_SECS * sr) as usize + 1;
+        let left: Vec<f32> = (0..frames).map(|_| hiss()).collect();
+        let right = left.clone();
+        let mut out_l = vec![0.0; frames];
+        let mut out_r = vec![0.0; frames];
+        adapter.process_block_stereo(&left, &right, &mut out_l, &mut out_r);
+
+        assert_eq!(adapter.get_param(13), 1.0);
+        let threshold = adapter.get_param(14);
+        assert!((-45.0..-20.0).contains(&threshold), "threshold={threshold}");
+        assert!(
+            adapter
+                .param_info(12)
+                .unwrap()
+                .flags
+                .contains(ParamFlags::HIDDEN)
+        );
+    }
 }
@@ -116,6 +116,7 @@ impl KernelParams for GlitchParams {
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(3500), "gl_mode")
+                    .with_description("Glitch type: stutter, tape stop, reverse, or shuffle.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(MODE_LABELS),
             ),
@@ -123,7 +124,8 @@ impl KernelParams for GlitchParams {
                 ParamDescriptor::custom("Rate", "Rate", 1.0, 32.0, 4.0)
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic)
-                    .with_id(ParamId(3501), "gl_rate"),
+                    .with_id(ParamId(3501), "gl_rate")
+                    .with_description("How often a glitch may trigger."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -131,7 +133,8 @@ impl KernelParams for GlitchParams {
                     short_name: "Depth",
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3502), "gl_depth"),
+                .with_id(ParamId(3502), "gl_depth")
+                .with_description("Intensity of each glitch."),
             ),
             3 => Some(
                 ParamDescriptor {
@@ -139,12 +142,18 @@ impl KernelParams for GlitchParams {
                     short_name: "Prob",
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3503), "gl_probability"),
+                .with_id(ParamId(3503), "gl_probability")
+                .with_description("Chance that each trigger produces a glitch."),
+            ),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(3504), "gl_mix")
+                    .with_description("Blend of dry and glitched signal."),
             ),
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(3504), "gl_mix")),
             5 => Some(
                 ParamDescriptor::gain_db("Output", "Out", -60.0, 6.0, 0.0)
-                    .with_id(ParamId(3505), "gl_output"),
+                    .with_id(ParamId(3505), "gl_output")
+                    .with_description("Output level after processing."),
             ),
             _ => None,
         }
@@ -190,20 +190,26 @@ impl KernelParams for LimiterParams {
         match index {
             0 => Some(
                 ParamDescriptor::gain_db("Threshold", "Thresh", -30.0, 0.0, -6.0)
-                    .with_id(ParamId(1600), "lim_thresh"),
+                    .with_id(ParamId(1600), "lim_thresh")
+                    .with_description("Level above which limiting starts."),
             ),
             1 => Some(
                 ParamDescriptor::gain_db("Ceiling", "Ceil", -30.0, 0.0, -0.3)
-                    .with_id(ParamId(1601), "lim_ceil"),
+                    .with_id(ParamId(1601), "lim_ceil")
+                    .with_description("Absolute peak level the output never exceeds."),
             ),
             2 => Some(
                 ParamDescriptor::time_ms("Release", "Rel", 10.0, 500.0, 100.0)
                     .with_id(ParamId(1602), "lim_release")
+                    .with_description("How fast gain recovers after a peak.")
                     .with_scale(ParamScale::Power(2.0)),
             ),
             3 => Some(
                 ParamDescriptor::time_ms("Lookahead", "Look", 0.0, 10.0, 5.0)
-                    .with_id(ParamId(1603), "lim_look"),
+                    .with_id(ParamId(1603), "lim_look")
+                    .with_description(
+                        "How far ahead peaks are detected; adds the same amount of latency.",
+                    ),
             ),
             4 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1604), "lim_output"),
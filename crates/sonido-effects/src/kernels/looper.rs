@@ -202,6 +202,9 @@ impl KernelParams for LooperParams {
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2000), "looper_mode")
+                    .with_description(
+                        "Transport: stop, record a new loop, play it back, or overdub onto it.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(MODE_LABELS),
             ),
@@ -216,13 +219,15 @@ impl KernelParams for LooperParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2001), "looper_feedback"),
+                .with_id(ParamId(2001), "looper_feedback")
+                .with_description("How much of the existing loop survives each overdub pass."),
             ),
             2 => Some(
                 ParamDescriptor::custom("Half Speed", "Half Spd", 0.0, 1.0, 0.0)
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2002), "looper_half_speed")
+                    .with_description("Half-speed playback. Reserved; not yet implemented.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -231,10 +236,15 @@ impl KernelParams for LooperParams {
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2003), "looper_reverse")
+                    .with_description("Play the loop backwards.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(2004), "looper_mix")),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2004), "looper_mix")
+                    .with_description("Blend of dry input and loop playback."),
+            ),
             5 => Some(
                 sonido_core::gain::output_param_descriptor()
                     .with_id(ParamId(2005), "looper_output"),
@@ -10,6 +10,8 @@
 //! Kernels are deployed via [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin
 //! use, or called directly on embedded targets.
 
+pub mod agc;
+pub mod align;
 pub mod amp;
 pub mod bitcrusher;
 pub mod cabinet;
@@ -20,6 +22,7 @@ pub mod delay;
 pub mod distortion;
 pub mod drone;
 pub mod eq;
+pub mod fdn_reverb;
 pub mod filter;
 pub mod flanger;
 pub mod gate;
@@ -27,6 +30,8 @@ pub mod glitch;
 pub mod limiter;
 pub mod looper;
 pub mod multiband_comp;
+pub mod multitap_delay;
+pub mod phase_rotator;
 pub mod phaser;
 pub mod pitch_shift;
 pub mod plate_reverb;
@@ -34,10 +39,12 @@ pub mod preamp;
 pub mod reverb;
 pub mod ringmod;
 pub mod shelving_eq;
+pub mod slicer;
 pub mod spring_reverb;
 pub mod stage;
 pub mod stereo_widener;
 pub mod tape;
+pub mod test_signal;
 pub mod texture;
 pub mod time_stretch;
 pub mod transient_shaper;
@@ -46,6 +53,8 @@ pub mod tuner;
 pub mod vibrato;
 pub mod wah;
 
+pub use agc::{AgcKernel, AgcParams};
+pub use align::{AlignKernel, AlignParams};
 pub use amp::{AmpKernel, AmpParams};
 pub use bitcrusher::{BitcrusherKernel, BitcrusherParams};
 pub use cabinet::{CabinetKernel, CabinetParams};
@@ -56,6 +65,7 @@ pub use delay::{DelayKernel, DelayParams};
 pub use distortion::{DistortionKernel, DistortionParams};
 pub use drone::{DroneKernel, DroneParams};
 pub use eq::{EqKernel, EqParams};
+pub use fdn_reverb::{FdnReverbKernel, FdnReverbParams};
 pub use filter::{FilterKernel, FilterParams};
 pub use flanger::{FlangerKernel, FlangerParams};
 pub use gate::{GateKernel, GateParams};
@@ -63,6 +73,8 @@ pub use glitch::{GlitchKernel, GlitchParams};
 pub use limiter::{LimiterKernel, LimiterParams};
 pub use looper::{LooperKernel, LooperParams};
 pub use multiband_comp::{MultibandCompKernel, MultibandCompParams};
+pub use multitap_delay::{MultiTapDelayKernel, MultiTapDelayParams};
+pub use phase_rotator::{PhaseRotatorKernel, PhaseRotatorParams};
 pub use phaser::{PhaserKernel, PhaserParams};
 pub use pitch_shift::{PitchShiftKernel, PitchShiftParams};
 pub use plate_reverb::{PlateReverbKernel, PlateReverbParams};
@@ -70,10 +82,12 @@ pub use preamp::{PreampKernel, PreampParams};
 pub use reverb::{ReverbKernel, ReverbParams};
 pub use ringmod::{RingModKernel, RingModParams};
 pub use shelving_eq::{ShelvingEqKernel, ShelvingEqParams};
+pub use slicer::{SlicerKernel, SlicerParams};
 pub use spring_reverb::{SpringReverbKernel, SpringReverbParams};
 pub use stage::{StageKernel, StageParams};
 pub use stereo_widener::{StereoWidenerKernel, StereoWidenerParams};
 pub use tape::{TapeKernel, TapeParams};
+pub use test_signal::{TestSignalKernel, TestSignalParams};
 pub use texture::{TextureKernel, TextureParams};
 pub use time_stretch::{TimeStretchKernel, TimeStretchParams};
 pub use transient_shaper::{TransientShaperKernel, TransientShaperParams};
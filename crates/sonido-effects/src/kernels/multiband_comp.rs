@@ -194,6 +194,8 @@ impl KernelParams for MultibandCompParams {
                     .with_unit(ParamUnit::Hertz)
                     .with_step(1.0)
                     .with_id(ParamId(2600), "mbc_xover_low")
+                    .with_group("Crossover")
+                    .with_description("Split frequency between the low and mid bands.")
                     .with_scale(ParamScale::Logarithmic),
             ),
             // ── [1] Mid/high crossover ────────────────────────────────────────
@@ -202,58 +204,78 @@ impl KernelParams for MultibandCompParams {
                     .with_unit(ParamUnit::Hertz)
                     .with_step(1.0)
                     .with_id(ParamId(2601), "mbc_xover_high")
+                    .with_group("Crossover")
+                    .with_description("Split frequency between the mid and high bands.")
                     .with_scale(ParamScale::Logarithmic),
             ),
             // ── [2] Low threshold ─────────────────────────────────────────────
             2 => Some(
                 ParamDescriptor::gain_db("Low Thresh", "Lo Thr", -60.0, 0.0, -20.0)
-                    .with_id(ParamId(2602), "mbc_low_thresh"),
+                    .with_id(ParamId(2602), "mbc_low_thresh")
+                    .with_group("Low")
+                    .with_description("Compression threshold for the low band."),
             ),
             // ── [3] Low ratio ─────────────────────────────────────────────────
             3 => Some(
                 ParamDescriptor::custom("Low Ratio", "Lo Rat", 1.0, 20.0, 4.0)
                     .with_unit(ParamUnit::Ratio)
                     .with_step(0.1)
-                    .with_id(ParamId(2603), "mbc_low_ratio"),
+                    .with_id(ParamId(2603), "mbc_low_ratio")
+                    .with_group("Low")
+                    .with_description("Compression ratio for the low band."),
             ),
             // ── [4] Mid threshold ─────────────────────────────────────────────
             4 => Some(
                 ParamDescriptor::gain_db("Mid Thresh", "Md Thr", -60.0, 0.0, -20.0)
-                    .with_id(ParamId(2604), "mbc_mid_thresh"),
+                    .with_id(ParamId(2604), "mbc_mid_thresh")
+                    .with_group("Mid")
+                    .with_description("Compression threshold for the mid band."),
             ),
             // ── [5] Mid ratio ─────────────────────────────────────────────────
             5 => Some(
                 ParamDescriptor::custom("Mid Ratio", "Md Rat", 1.0, 20.0, 4.0)
                     .with_unit(ParamUnit::Ratio)
                     .with_step(0.1)
-                    .with_id(ParamId(2605), "mbc_mid_ratio"),
+                    .with_id(ParamId(2605), "mbc_mid_ratio")
+                    .with_group("Mid")
+                    .with_description("Compression ratio for the mid band."),
             ),
             // ── [6] High threshold ────────────────────────────────────────────
             6 => Some(
                 ParamDescriptor::gain_db("High Thresh", "Hi Thr", -60.0, 0.0, -20.0)
-                    .with_id(ParamId(2606), "mbc_high_thresh"),
+                    .with_id(ParamId(2606), "mbc_high_thresh")
+                    .with_group("High")
+                    .with_description("Compression threshold for the high band."),
             ),
             // ── [7] High ratio ────────────────────────────────────────────────
             7 => Some(
                 ParamDescriptor::custom("High Ratio", "Hi Rat", 1.0, 20.0, 4.0)
                     .with_unit(ParamUnit::Ratio)
                     .with_step(0.1)
-                    .with_id(ParamId(2607), "mbc_high_ratio"),
+                    .with_id(ParamId(2607), "mbc_high_ratio")
+                    .with_group("High")
+                    .with_description("Compression ratio for the high band."),
             ),
             // ── [8] Low makeup gain ───────────────────────────────────────────
             8 => Some(
                 ParamDescriptor::gain_db("Low Gain", "Lo Gain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(2608), "mbc_low_gain"),
+                    .with_id(ParamId(2608), "mbc_low_gain")
+                    .with_group("Low")
+                    .with_description("Makeup gain for the low band."),
             ),
             // ── [9] Mid makeup gain ───────────────────────────────────────────
             9 => Some(
                 ParamDescriptor::gain_db("Mid Gain", "Md Gain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(2609), "mbc_mid_gain"),
+                    .with_id(ParamId(2609), "mbc_mid_gain")
+                    .with_group("Mid")
+                    .with_description("Makeup gain for the mid band."),
             ),
             // ── [10] High makeup gain ─────────────────────────────────────────
             10 => Some(
                 ParamDescriptor::gain_db("High Gain", "Hi Gain", -12.0, 12.0, 0.0)
-                    .with_id(ParamId(2610), "mbc_high_gain"),
+                    .with_id(ParamId(2610), "mbc_high_gain")
+                    .with_group("High")
+                    .with_description("Makeup gain for the high band."),
             ),
             // ── [11] Output level ─────────────────────────────────────────────
             11 => Some(
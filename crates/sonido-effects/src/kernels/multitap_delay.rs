@@ -0,0 +1,984 @@
+//! Multi-tap delay kernel — four panned taps on one delay line with rhythmic patterns.
+//!
+//! `MultiTapDelayKernel` reads a single delay line at four positions. Each tap
+//! has its own level, stereo pan, and feedback send, so one echo can spread
+//! into a rhythmic figure across the stereo field. A pattern selector places
+//! the taps at fixed fractions of a master `Length` (which can follow the host
+//! tempo); in Manual mode every tap has its own time or note division.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//!                ┌──────────── Σ feedback_i × tap_i ◄─────────────┐
+//!                ▼                                                │
+//! (L+R)/2 ──►(+)──► [delay line] ──► tap 1 … tap 4 ───────────────┤
+//!                                      │
+//!                                      └─► × level_i, pan_i ─► Σ ─► wet L/R
+//!
+//! wet L/R ─► wet/dry mix (dry stays stereo) ─► output gain
+//! ```
+//!
+//! # Tap Patterns
+//!
+//! | Index | Pattern | Tap times (× Length) |
+//! |-------|---------|----------------------|
+//! | 0 | Manual | per-tap `Time` (or `Division` when synced) |
+//! | 1 | Even | 1/4, 2/4, 3/4, 1 |
+//! | 2 | Dotted | 3/16, 6/16, 9/16, 12/16 |
+//! | 3 | Triplet | 1/6, 2/6, 3/6, 4/6 |
+//! | 4 | Accel | 0.4, 0.7, 0.9, 1 (taps speed up) |
+//! | 5 | Ritard | 0.1, 0.3, 0.6, 1 (taps slow down) |
+//!
+//! With a Length of one bar, Even gives quarter notes, Dotted gives dotted
+//! eighths, and Triplet gives quarter-note triplets.
+//!
+//! # Stability
+//!
+//! The feedback sends are summed into the line input. When their total
+//! exceeds [`MAX_TOTAL_FEEDBACK`] they are scaled down together, so the loop
+//! gain at any frequency stays below 1 and the delay cannot run away.
+//!
+//! # Panning
+//!
+//! Pan uses the same balance law as the stage effect: the far channel is
+//! attenuated by `min(1, 1 ± pan)` and the near channel stays at unity, so a
+//! centred tap plays at full level on both sides.
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(MultiTapDelayKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing, ADCs are hardware-filtered)
+//! let mut kernel = MultiTapDelayKernel::new(48000.0);
+//! let params = MultiTapDelayParams::from_knobs(0.2, 0.5, 0.0, 0.2, 0.5, 0.5);
+//! let (left, right) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use libm::{ceilf, logf};
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    DIVISION_LABELS, InterpolatedDelay, ParamDescriptor, ParamFlags, ParamId, ParamScale,
+    ParamUnit, TempoContext, TempoManager, fast_db_to_linear, flush_denormal, index_to_division,
+    wet_dry_mix_stereo,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Constants
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Number of delay taps.
+pub const TAPS: usize = 4;
+
+/// Maximum delay time in seconds (2 seconds), shared by every tap.
+const MAX_DELAY_S: f32 = 2.0;
+
+/// Shortest tap time in milliseconds.
+const MIN_TAP_MS: f32 = 1.0;
+
+/// Upper bound on the summed feedback sends.
+///
+/// Sends whose total exceeds this are scaled down proportionally.
+pub const MAX_TOTAL_FEEDBACK: f32 = 0.95;
+
+/// Pattern index for per-tap (manual) times.
+pub const PATTERN_MANUAL: usize = 0;
+
+/// Labels for the tap pattern selector, indexed by value.
+pub const PATTERN_LABELS: &[&str] = &["Manual", "Even", "Dotted", "Triplet", "Accel", "Ritard"];
+
+/// Tap positions as fractions of the master length, for patterns 1–5.
+const PATTERN_RATIOS: [[f32; TAPS]; 5] = [
+    [0.25, 0.5, 0.75, 1.0],                       // Even
+    [0.1875, 0.375, 0.5625, 0.75],                // Dotted
+    [1.0 / 6.0, 2.0 / 6.0, 3.0 / 6.0, 4.0 / 6.0], // Triplet
+    [0.4, 0.7, 0.9, 1.0],                         // Accel
+    [0.1, 0.3, 0.6, 1.0],                         // Ritard
+];
+
+/// Default manual tap times in ms (the Even pattern at the default length).
+const DEFAULT_TIME_MS: [f32; TAPS] = [125.0, 250.0, 375.0, 500.0];
+
+/// Default manual tap divisions: Sixteenth, Eighth, Dot 8th, Quarter.
+const DEFAULT_DIVISION: [f32; TAPS] = [4.0, 3.0, 8.0, 2.0];
+
+/// Default tap levels in percent — each echo a little quieter.
+const DEFAULT_LEVEL_PCT: [f32; TAPS] = [100.0, 80.0, 60.0, 40.0];
+
+/// Default tap pans in percent — alternating, widening outward.
+const DEFAULT_PAN_PCT: [f32; TAPS] = [-50.0, 50.0, -100.0, 100.0];
+
+/// Default feedback sends in percent — only the last tap regenerates.
+const DEFAULT_FEEDBACK_PCT: [f32; TAPS] = [0.0, 0.0, 0.0, 30.0];
+
+/// Per-tap parameter slots, in order, starting at index 4.
+const TAP_FIELDS: usize = 5;
+
+/// Index of the first per-tap parameter.
+const TAP_BASE: usize = 4;
+
+/// Index of the mix parameter.
+const MIX_INDEX: usize = TAP_BASE + TAPS * TAP_FIELDS;
+
+/// Index of the first read-only effective tap time.
+const ACTUAL_BASE: usize = MIX_INDEX + 1;
+
+/// Index of the output parameter.
+const OUTPUT_INDEX: usize = ACTUAL_BASE + TAPS;
+
+const TIME_NAMES: [&str; TAPS] = ["Tap 1 Time", "Tap 2 Time", "Tap 3 Time", "Tap 4 Time"];
+const TIME_SHORT: [&str; TAPS] = ["T1 Time", "T2 Time", "T3 Time", "T4 Time"];
+const TIME_IDS: [&str; TAPS] = [
+    "mtd_tap1_time",
+    "mtd_tap2_time",
+    "mtd_tap3_time",
+    "mtd_tap4_time",
+];
+
+const DIVISION_NAMES: [&str; TAPS] = ["Tap 1 Div", "Tap 2 Div", "Tap 3 Div", "Tap 4 Div"];
+const DIVISION_SHORT: [&str; TAPS] = ["T1 Div", "T2 Div", "T3 Div", "T4 Div"];
+const DIVISION_IDS: [&str; TAPS] = [
+    "mtd_tap1_division",
+    "mtd_tap2_division",
+    "mtd_tap3_division",
+    "mtd_tap4_division",
+];
+
+const LEVEL_NAMES: [&str; TAPS] = ["Tap 1 Level", "Tap 2 Level", "Tap 3 Level", "Tap 4 Level"];
+const LEVEL_SHORT: [&str; TAPS] = ["T1 Lvl", "T2 Lvl", "T3 Lvl", "T4 Lvl"];
+const LEVEL_IDS: [&str; TAPS] = [
+    "mtd_tap1_level",
+    "mtd_tap2_level",
+    "mtd_tap3_level",
+    "mtd_tap4_level",
+];
+
+const PAN_NAMES: [&str; TAPS] = ["Tap 1 Pan", "Tap 2 Pan", "Tap 3 Pan", "Tap 4 Pan"];
+const PAN_SHORT: [&str; TAPS] = ["T1 Pan", "T2 Pan", "T3 Pan", "T4 Pan"];
+const PAN_IDS: [&str; TAPS] = [
+    "mtd_tap1_pan",
+    "mtd_tap2_pan",
+    "mtd_tap3_pan",
+    "mtd_tap4_pan",
+];
+
+const FEEDBACK_NAMES: [&str; TAPS] = [
+    "Tap 1 Feedback",
+    "Tap 2 Feedback",
+    "Tap 3 Feedback",
+    "Tap 4 Feedback",
+];
+const FEEDBACK_SHORT: [&str; TAPS] = ["T1 Fb", "T2 Fb", "T3 Fb", "T4 Fb"];
+const FEEDBACK_IDS: [&str; TAPS] = [
+    "mtd_tap1_feedback",
+    "mtd_tap2_feedback",
+    "mtd_tap3_feedback",
+    "mtd_tap4_feedback",
+];
+
+const ACTUAL_NAMES: [&str; TAPS] = [
+    "Tap 1 Actual",
+    "Tap 2 Actual",
+    "Tap 3 Actual",
+    "Tap 4 Actual",
+];
+const ACTUAL_SHORT: [&str; TAPS] = ["T1 Act", "T2 Act", "T3 Act", "T4 Act"];
+const ACTUAL_IDS: [&str; TAPS] = [
+    "mtd_tap1_actual",
+    "mtd_tap2_actual",
+    "mtd_tap3_actual",
+    "mtd_tap4_actual",
+];
+
+const TAP_GROUPS: [&str; TAPS] = ["Tap 1", "Tap 2", "Tap 3", "Tap 4"];
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`MultiTapDelayKernel`].
+///
+/// Per-tap parameters occupy five consecutive slots per tap, starting at
+/// index 4: tap `t` (0-based) uses indices `4 + 5t` … `8 + 5t`.
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `pattern` | index | 0–5 (STEPPED) | 1 (Even) |
+/// | 1 | `length_ms` | ms | 10–2000 | 500.0 |
+/// | 2 | `sync` | index | 0–1 (STEPPED) | 0 (Off) |
+/// | 3 | `division` | index | 0–11 (STEPPED) | 0 (Whole) |
+/// | 4 + 5t | `tap_time_ms[t]` | ms | 1–2000 | 125/250/375/500 |
+/// | 5 + 5t | `tap_division[t]` | index | 0–11 (STEPPED) | 16th/8th/Dot 8th/Qtr |
+/// | 6 + 5t | `tap_level_pct[t]` | % | 0–100 | 100/80/60/40 |
+/// | 7 + 5t | `tap_pan_pct[t]` | % | −100–100 | −50/50/−100/100 |
+/// | 8 + 5t | `tap_feedback_pct[t]` | % | 0–95 | 0/0/0/30 |
+/// | 24 | `mix_pct` | % | 0–100 | 50.0 |
+/// | 25–28 | `tap_actual_ms[t]` | ms | 0–2000 (READ_ONLY) | — |
+/// | 29 | `output_db` | dB | −20–+6 | 0.0 |
+#[derive(Debug, Clone, Copy)]
+pub struct MultiTapDelayParams {
+    /// Tap pattern index (STEPPED). See [`PATTERN_LABELS`].
+    ///
+    /// 0 = Manual uses the per-tap times; other patterns place the taps at
+    /// fixed fractions of the master length.
+    pub pattern: f32,
+    /// Master length in milliseconds. Range: 10.0 to 2000.0 ms.
+    ///
+    /// The span the tap patterns divide. Ignored when `sync > 0.5` and in
+    /// Manual mode.
+    pub length_ms: f32,
+    /// Tempo sync enable: 0.0 = Off, 1.0 = On.
+    ///
+    /// When on, the master length follows `division` and Manual taps follow
+    /// their own `tap_division` at the host BPM.
+    pub sync: f32,
+    /// Master length as a note division index (0–11) when synced.
+    pub division: f32,
+    /// Manual tap times in milliseconds. Range: 1.0 to 2000.0 ms.
+    pub tap_time_ms: [f32; TAPS],
+    /// Manual tap note division indices (0–11), used when synced.
+    pub tap_division: [f32; TAPS],
+    /// Tap levels in percent. Range: 0.0 to 100.0 %.
+    pub tap_level_pct: [f32; TAPS],
+    /// Tap stereo positions in percent. Range: −100.0 (left) to 100.0 (right).
+    pub tap_pan_pct: [f32; TAPS],
+    /// Tap feedback sends in percent. Range: 0.0 to 95.0 %.
+    ///
+    /// How much of each tap is fed back into the delay line. The sends are
+    /// scaled down together when their sum exceeds 95 %.
+    pub tap_feedback_pct: [f32; TAPS],
+    /// Wet/dry mix in percent. Range: 0.0 to 100.0 %.
+    pub mix_pct: f32,
+    /// Effective tap times in milliseconds (READ_ONLY, written by the kernel).
+    ///
+    /// Resolves pattern, sync, and tempo, for tap-time displays.
+    pub tap_actual_ms: [f32; TAPS],
+    /// Output level in decibels. Range: −20.0 to +6.0 dB.
+    pub output_db: f32,
+}
+
+impl Default for MultiTapDelayParams {
+    fn default() -> Self {
+        Self {
+            pattern: 1.0,
+            length_ms: 500.0,
+            sync: 0.0,
+            division: 0.0,
+            tap_time_ms: DEFAULT_TIME_MS,
+            tap_division: DEFAULT_DIVISION,
+            tap_level_pct: DEFAULT_LEVEL_PCT,
+            tap_pan_pct: DEFAULT_PAN_PCT,
+            tap_feedback_pct: DEFAULT_FEEDBACK_PCT,
+            mix_pct: 50.0,
+            tap_actual_ms: DEFAULT_TIME_MS,
+            output_db: 0.0,
+        }
+    }
+}
+
+impl MultiTapDelayParams {
+    /// Creates parameters from normalized 0–1 knob readings.
+    ///
+    /// Curves are derived from [`ParamDescriptor`] — same mapping as GUI and
+    /// plugin hosts. Per-tap controls keep their defaults; the pattern
+    /// selector places the taps.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: Pattern selector → index 0–5 (stepped)
+    /// - `length`: Length knob → 10–2000 ms (logarithmic)
+    /// - `sync`: Sync toggle → 0.0 = Off, 1.0 = On (stepped)
+    /// - `division`: Division selector → index 0–11 (stepped)
+    /// - `mix`: Mix knob → 0–100 %
+    /// - `output`: Output knob → −20–+6 dB
+    pub fn from_knobs(
+        pattern: f32,
+        length: f32,
+        sync: f32,
+        division: f32,
+        mix: f32,
+        output: f32,
+    ) -> Self {
+        let mut params = Self::from_normalized(&[pattern, length, sync, division]);
+        params.set(MIX_INDEX, Self::denormalize(MIX_INDEX, mix));
+        params.set(OUTPUT_INDEX, Self::denormalize(OUTPUT_INDEX, output));
+        params
+    }
+
+    /// Map a normalized value through the descriptor at `index`.
+    fn denormalize(index: usize, normalized: f32) -> f32 {
+        Self::descriptor(index).map_or(0.0, |d| d.denormalize(normalized))
+    }
+}
+
+impl KernelParams for MultiTapDelayParams {
+    const COUNT: usize = OUTPUT_INDEX + 1;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::custom("Pattern", "Pattern", 0.0, 5.0, 1.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(4200), "mtd_pattern")
+                    .with_description(
+                        "Tap rhythm: Manual uses each tap's own time; the others divide Length.",
+                    )
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(PATTERN_LABELS),
+            ),
+            1 => Some(
+                ParamDescriptor::time_ms("Length", "Length", 10.0, 2000.0, 500.0)
+                    .with_scale(ParamScale::Logarithmic)
+                    .with_id(ParamId(4201), "mtd_length")
+                    .with_description("Span the tap pattern is spread over when Sync is off."),
+            ),
+            2 => Some(
+                ParamDescriptor::custom("Sync", "Sync", 0.0, 1.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(4202), "mtd_sync")
+                    .with_group("Tempo Sync")
+                    .with_description("Lock Length and manual tap times to the host tempo.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Off", "On"]),
+            ),
+            3 => Some(
+                ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(4203), "mtd_division")
+                    .with_group("Tempo Sync")
+                    .with_description("Note length of the pattern span when Sync is on.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(DIVISION_LABELS),
+            ),
+            i if (TAP_BASE..MIX_INDEX).contains(&i) => {
+                let tap = (i - TAP_BASE) / TAP_FIELDS;
+                let id = ParamId(4200 + i as u32);
+                let group = TAP_GROUPS[tap];
+                Some(match (i - TAP_BASE) % TAP_FIELDS {
+                    0 => ParamDescriptor::time_ms(
+                        TIME_NAMES[tap],
+                        TIME_SHORT[tap],
+                        MIN_TAP_MS,
+                        2000.0,
+                        DEFAULT_TIME_MS[tap],
+                    )
+                    .with_scale(ParamScale::Logarithmic)
+                    .with_id(id, TIME_IDS[tap])
+                    .with_group(group)
+                    .with_description("Tap delay time in Manual pattern when Sync is off."),
+                    1 => ParamDescriptor::custom(
+                        DIVISION_NAMES[tap],
+                        DIVISION_SHORT[tap],
+                        0.0,
+                        11.0,
+                        DEFAULT_DIVISION[tap],
+                    )
+                    .with_step(1.0)
+                    .with_id(id, DIVISION_IDS[tap])
+                    .with_group(group)
+                    .with_description("Tap note length in Manual pattern when Sync is on.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(DIVISION_LABELS),
+                    2 => ParamDescriptor::custom(
+                        LEVEL_NAMES[tap],
+                        LEVEL_SHORT[tap],
+                        0.0,
+                        100.0,
+                        DEFAULT_LEVEL_PCT[tap],
+                    )
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(id, LEVEL_IDS[tap])
+                    .with_group(group)
+                    .with_description("Volume of this tap's echo."),
+                    3 => ParamDescriptor::custom(
+                        PAN_NAMES[tap],
+                        PAN_SHORT[tap],
+                        -100.0,
+                        100.0,
+                        DEFAULT_PAN_PCT[tap],
+                    )
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(id, PAN_IDS[tap])
+                    .with_group(group)
+                    .with_description("Stereo position of this tap's echo."),
+                    _ => ParamDescriptor::custom(
+                        FEEDBACK_NAMES[tap],
+                        FEEDBACK_SHORT[tap],
+                        0.0,
+                        95.0,
+                        DEFAULT_FEEDBACK_PCT[tap],
+                    )
+                    .with_unit(ParamUnit::Percent)
+                    .with_step(1.0)
+                    .with_id(id, FEEDBACK_IDS[tap])
+                    .with_group(group)
+                    .with_description("How much of this tap is fed back into the delay line."),
+                })
+            }
+            MIX_INDEX => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(4224), "mtd_mix")
+                    .with_description("Blend of dry and delayed signal."),
+            ),
+            i if (ACTUAL_BASE..OUTPUT_INDEX).contains(&i) => {
+                let tap = i - ACTUAL_BASE;
+                Some(
+                    ParamDescriptor::time_ms(
+                        ACTUAL_NAMES[tap],
+                        ACTUAL_SHORT[tap],
+                        0.0,
+                        2000.0,
+                        DEFAULT_TIME_MS[tap],
+                    )
+                    .with_id(ParamId(4200 + i as u32), ACTUAL_IDS[tap])
+                    .with_group(TAP_GROUPS[tap])
+                    .with_description("Tap time after pattern and tempo sync (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+                )
+            }
+            OUTPUT_INDEX => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(4229), "mtd_output"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 | 2 | 3 => SmoothingStyle::None, // pattern, sync, division — stepped
+            1 => SmoothingStyle::Slow,         // length — 20 ms, prevents pitch clicks
+            i if (TAP_BASE..MIX_INDEX).contains(&i) => match (i - TAP_BASE) % TAP_FIELDS {
+                0 => SmoothingStyle::Slow,     // tap time — 20 ms
+                1 => SmoothingStyle::None,     // tap division — stepped
+                _ => SmoothingStyle::Standard, // level, pan, feedback — 10 ms
+            },
+            MIX_INDEX => SmoothingStyle::Standard, // mix — 10 ms
+            OUTPUT_INDEX => SmoothingStyle::Standard, // output — 10 ms
+            _ => SmoothingStyle::None,             // effective tap times — diagnostic
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.pattern,
+            1 => self.length_ms,
+            2 => self.sync,
+            3 => self.division,
+            i if (TAP_BASE..MIX_INDEX).contains(&i) => {
+                let tap = (i - TAP_BASE) / TAP_FIELDS;
+                match (i - TAP_BASE) % TAP_FIELDS {
+                    0 => self.tap_time_ms[tap],
+                    1 => self.tap_division[tap],
+                    2 => self.tap_level_pct[tap],
+                    3 => self.tap_pan_pct[tap],
+                    _ => self.tap_feedback_pct[tap],
+                }
+            }
+            MIX_INDEX => self.mix_pct,
+            i if (ACTUAL_BASE..OUTPUT_INDEX).contains(&i) => self.tap_actual_ms[i - ACTUAL_BASE],
+            OUTPUT_INDEX => self.output_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.pattern = value,
+            1 => self.length_ms = value,
+            2 => self.sync = value,
+            3 => self.division = value,
+            i if (TAP_BASE..MIX_INDEX).contains(&i) => {
+                let tap = (i - TAP_BASE) / TAP_FIELDS;
+                match (i - TAP_BASE) % TAP_FIELDS {
+                    0 => self.tap_time_ms[tap] = value,
+                    1 => self.tap_division[tap] = value,
+                    2 => self.tap_level_pct[tap] = value,
+                    3 => self.tap_pan_pct[tap] = value,
+                    _ => self.tap_feedback_pct[tap] = value,
+                }
+            }
+            MIX_INDEX => self.mix_pct = value,
+            i if (ACTUAL_BASE..OUTPUT_INDEX).contains(&i) => {
+                self.tap_actual_ms[i - ACTUAL_BASE] = value;
+            }
+            OUTPUT_INDEX => self.output_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP multi-tap delay kernel.
+///
+/// Contains ONLY the mutable state required for audio processing:
+///
+/// - One [`InterpolatedDelay`] line with a 2-second maximum, fed by the
+///   mid (L+R)/2 of the input
+/// - A [`TempoManager`] for synced lengths and tap divisions
+/// - The most recent effective tap times, for diagnostics and `tail_samples()`
+pub struct MultiTapDelayKernel {
+    /// Shared delay line all four taps read from.
+    line: InterpolatedDelay,
+    /// Tempo manager for BPM-synced tap times.
+    tempo: TempoManager,
+    /// Current sample rate in Hz.
+    sample_rate: f32,
+    /// Maximum number of delay samples (= 2 s × sample_rate).
+    max_delay_samples: f32,
+    /// Effective tap times in ms from the last processed sample.
+    tap_ms: [f32; TAPS],
+    /// Summed (scaled) feedback from the last processed sample.
+    ///
+    /// Used by `tail_samples()` to estimate the number of audible repeats.
+    total_feedback: f32,
+}
+
+impl MultiTapDelayKernel {
+    /// Create a new multi-tap delay kernel at the given sample rate.
+    ///
+    /// Allocates one 2-second delay line. Tempo starts at 120 BPM until a
+    /// tempo context arrives.
+    pub fn new(sample_rate: f32) -> Self {
+        let max_delay_samples = ceilf(MAX_DELAY_S * sample_rate) as usize;
+        Self {
+            line: InterpolatedDelay::new(max_delay_samples),
+            tempo: TempoManager::new(sample_rate, 120.0),
+            sample_rate,
+            max_delay_samples: max_delay_samples as f32,
+            tap_ms: DEFAULT_TIME_MS,
+            total_feedback: DEFAULT_FEEDBACK_PCT[TAPS - 1] / 100.0,
+        }
+    }
+
+    /// Effective tap times in milliseconds from the last processed sample.
+    pub fn tap_times_ms(&self) -> [f32; TAPS] {
+        self.tap_ms
+    }
+
+    /// Resolve the four tap times in milliseconds from pattern, sync, and tempo.
+    ///
+    /// Times are clamped to `[1 ms, 2 s)`.
+    #[inline]
+    fn resolve_tap_ms(&self, params: &MultiTapDelayParams) -> [f32; TAPS] {
+        let synced = params.sync > 0.5;
+        let pattern = (params.pattern + 0.5) as usize;
+        let max_ms = (self.max_delay_samples - 1.0) / self.sample_rate * 1000.0;
+
+        let times = if pattern == PATTERN_MANUAL || pattern > PATTERN_RATIOS.len() {
+            core::array::from_fn(|t| {
+                if synced {
+                    let div = index_to_division(params.tap_division[t] as u8);
+                    self.tempo.division_to_ms(div)
+                } else {
+                    params.tap_time_ms[t]
+                }
+            })
+        } else {
+            let length = if synced {
+                self.tempo
+                    .division_to_ms(index_to_division(params.division as u8))
+            } else {
+                params.length_ms
+            };
+            PATTERN_RATIOS[pattern - 1].map(|ratio| ratio * length)
+        };
+        times.map(|ms| ms.clamp(MIN_TAP_MS, max_ms))
+    }
+
+    /// Balance-law pan gains `(left, right)` for a pan in percent.
+    #[inline]
+    fn pan_gains(pan_pct: f32) -> (f32, f32) {
+        let pan = (pan_pct / 100.0).clamp(-1.0, 1.0);
+        ((1.0 - pan).min(1.0), (1.0 + pan).min(1.0))
+    }
+}
+
+impl DspKernel for MultiTapDelayKernel {
+    type Params = MultiTapDelayParams;
+
+    /// Process one stereo sample pair.
+    ///
+    /// ## Per-sample steps
+    ///
+    /// 1. Resolve the effective tap times (pattern, sync, tempo).
+    /// 2. Read all four taps from the delay line.
+    /// 3. Sum `tap × feedback send` (scaled so the total stays ≤ 95 %) and
+    ///    write `(L+R)/2 + feedback` into the line.
+    /// 4. Pan and sum `tap × level` into the wet pair, then wet/dry mix and
+    ///    output gain.
+    #[inline]
+    fn process_stereo(
+        &mut self,
+        left: f32,
+        right: f32,
+        params: &MultiTapDelayParams,
+    ) -> (f32, f32) {
+        self.tap_ms = self.resolve_tap_ms(params);
+
+        let sends = params.tap_feedback_pct.map(|pct| pct.max(0.0) / 100.0);
+        let send_total: f32 = sends.iter().sum();
+        let send_scale = if send_total > MAX_TOTAL_FEEDBACK {
+            MAX_TOTAL_FEEDBACK / send_total
+        } else {
+            1.0
+        };
+        self.total_feedback = send_total * send_scale;
+
+        let mut feedback = 0.0;
+        let mut wet_l = 0.0;
+        let mut wet_r = 0.0;
+        for t in 0..TAPS {
+            let samples = self.tap_ms[t] * 0.001 * self.sample_rate;
+            let tap = self.line.read(samples);
+            feedback += tap * sends[t] * send_scale;
+
+            let level = params.tap_level_pct[t] / 100.0;
+            let (gain_l, gain_r) = Self::pan_gains(params.tap_pan_pct[t]);
+            wet_l += tap * level * gain_l;
+            wet_r += tap * level * gain_r;
+        }
+
+        let mid = (left + right) * 0.5;
+        self.line.write(flush_denormal(mid + feedback));
+
+        let mix = params.mix_pct / 100.0;
+        let output_gain = fast_db_to_linear(params.output_db);
+        let (out_l, out_r) = wet_dry_mix_stereo(left, right, wet_l, wet_r, mix);
+        (out_l * output_gain, out_r * output_gain)
+    }
+
+    fn reset(&mut self) {
+        self.line.clear();
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        let max_samples = ceilf(MAX_DELAY_S * sample_rate) as usize;
+        self.max_delay_samples = max_samples as f32;
+        self.line = InterpolatedDelay::new(max_samples);
+        self.tempo.set_sample_rate(sample_rate);
+    }
+
+    /// Always `true`: taps are panned independently, so one input channel
+    /// reaches both outputs.
+    fn is_true_stereo(&self) -> bool {
+        true
+    }
+
+    /// Receive tempo context and store the new BPM.
+    fn set_tempo_context(&mut self, ctx: &TempoContext) {
+        self.tempo.set_bpm(ctx.bpm);
+    }
+
+    /// Estimated tail in samples: the longest tap, plus enough regenerations
+    /// for the summed feedback to fall below −60 dB. Capped at 10 seconds.
+    fn tail_samples(&self) -> usize {
+        let longest_ms = self.tap_ms.iter().copied().fold(0.0_f32, f32::max);
+        let longest = (longest_ms * 0.001 * self.sample_rate) as usize;
+        let repeats = if self.total_feedback < 0.001 {
+            1
+        } else {
+            1 + ceilf(logf(0.001) / logf(self.total_feedback)) as usize
+        };
+        (longest * repeats).min((10.0 * self.sample_rate) as usize)
+    }
+
+    fn update_diagnostics(&self, params: &mut MultiTapDelayParams) {
+        params.tap_actual_ms = self.tap_ms;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::kernel::Adapter;
+    use sonido_core::{Effect, ParameterInfo};
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    /// Wet-only impulse response of both channels.
+    fn impulse_response(params: &MultiTapDelayParams, len: usize) -> Vec<(f32, f32)> {
+        let mut kernel = MultiTapDelayKernel::new(48000.0);
+        let params = MultiTapDelayParams {
+            mix_pct: 100.0,
+            ..*params
+        };
+        (0..len)
+            .map(|i| {
+                let x = if i == 0 { 1.0 } else { 0.0 };
+                kernel.process_stereo(x, x, &params)
+            })
+            .collect()
+    }
+
+    /// Tap delays (in samples) of the echoes louder than `threshold`.
+    ///
+    /// Taps are read before the input is written, so an echo of `d` samples
+    /// arrives at output index `d + 1`.
+    fn echo_positions(ir: &[(f32, f32)], threshold: f32) -> Vec<usize> {
+        ir.iter()
+            .enumerate()
+            .filter(|(_, (l, r))| l.abs() > threshold || r.abs() > threshold)
+            .map(|(i, _)| i - 1)
+            .collect()
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(MultiTapDelayParams::COUNT, 30);
+        for i in 0..MultiTapDelayParams::COUNT {
+            assert!(
+                MultiTapDelayParams::descriptor(i).is_some(),
+                "Missing descriptor at index {i}"
+            );
+        }
+        assert!(MultiTapDelayParams::descriptor(MultiTapDelayParams::COUNT).is_none());
+    }
+
+    #[test]
+    fn param_ids_are_sequential() {
+        for i in 0..MultiTapDelayParams::COUNT {
+            let desc = MultiTapDelayParams::descriptor(i).unwrap();
+            assert_eq!(desc.id, ParamId(4200 + i as u32), "index {i}");
+        }
+        let p = |i| MultiTapDelayParams::descriptor(i).unwrap();
+        assert_eq!(p(4).string_id, "mtd_tap1_time");
+        assert_eq!(p(23).string_id, "mtd_tap4_feedback");
+        assert_eq!(p(MIX_INDEX).string_id, "mtd_mix");
+        assert_eq!(p(OUTPUT_INDEX).string_id, "mtd_output");
+        assert!(p(ACTUAL_BASE).flags.contains(ParamFlags::READ_ONLY));
+    }
+
+    #[test]
+    fn get_set_round_trip() {
+        let mut params = MultiTapDelayParams::default();
+        for i in 0..MultiTapDelayParams::COUNT {
+            params.set(i, i as f32 + 0.5);
+        }
+        for i in 0..MultiTapDelayParams::COUNT {
+            assert_eq!(params.get(i), i as f32 + 0.5, "index {i}");
+        }
+    }
+
+    #[test]
+    fn silence_in_silence_out() {
+        let mut kernel = MultiTapDelayKernel::new(48000.0);
+        let params = MultiTapDelayParams::default();
+        for _ in 0..1000 {
+            let (l, r) = kernel.process_stereo(0.0, 0.0, &params);
+            assert_eq!((l, r), (0.0, 0.0));
+        }
+    }
+
+    /// The Even pattern places four echoes at quarters of the length, with
+    /// the default levels and pans.
+    #[test]
+    fn even_pattern_places_four_taps() {
+        let params = MultiTapDelayParams {
+            tap_feedback_pct: [0.0; TAPS],
+            length_ms: 400.0,
+            ..MultiTapDelayParams::default()
+        };
+        let ir = impulse_response(&params, 20000);
+        assert_eq!(echo_positions(&ir, 0.1), [4800, 9600, 14400, 19200]);
+
+        // Tap 1: level 100 %, pan −50 → L 1.0, R 0.5.
+        let (l, r) = ir[4801];
+        assert!((l - 1.0).abs() < 1e-3 && (r - 0.5).abs() < 1e-3, "{l}, {r}");
+        // Tap 4: level 40 %, hard right.
+        let (l, r) = ir[19201];
+        assert!(l.abs() < 1e-6 && (r - 0.4).abs() < 1e-3, "{l}, {r}");
+    }
+
+    #[test]
+    fn patterns_move_taps() {
+        let base = MultiTapDelayParams {
+            tap_feedback_pct: [0.0; TAPS],
+            length_ms: 600.0,
+            ..MultiTapDelayParams::default()
+        };
+        for (pattern, ratios) in PATTERN_RATIOS.iter().enumerate() {
+            let params = MultiTapDelayParams {
+                pattern: (pattern + 1) as f32,
+                ..base
+            };
+            let ir = impulse_response(&params, 30000);
+            let expected: Vec<usize> = ratios
+                .iter()
+                .map(|r| (r * 600.0 * 48.0 + 0.5) as usize)
+                .collect();
+            let found = echo_positions(&ir, 0.1);
+            assert_eq!(found.len(), TAPS, "pattern {pattern}: {found:?}");
+            for (f, e) in found.iter().zip(&expected) {
+                assert!(
+                    f.abs_diff(*e) <= 1,
+                    "pattern {pattern}: {found:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn manual_pattern_uses_tap_times() {
+        let params = MultiTapDelayParams {
+            pattern: PATTERN_MANUAL as f32,
+            tap_time_ms: [10.0, 70.0, 30.0, 200.0],
+            tap_feedback_pct: [0.0; TAPS],
+            ..MultiTapDelayParams::default()
+        };
+        let ir = impulse_response(&params, 12000);
+        assert_eq!(echo_positions(&ir, 0.1), [480, 1440, 3360, 9600]);
+    }
+
+    #[test]
+    fn sync_follows_tempo() {
+        let mut kernel = MultiTapDelayKernel::new(48000.0);
+        kernel.set_tempo_context(&TempoContext {
+            bpm: 100.0,
+            ..TempoContext::default()
+        });
+
+        // Even over a half note at 100 BPM (1200 ms) → 300 ms spacing.
+        let params = MultiTapDelayParams {
+            sync: 1.0,
+            division: 1.0,
+            ..MultiTapDelayParams::default()
+        };
+        kernel.process_stereo(0.0, 0.0, &params);
+        let times = kernel.tap_times_ms();
+        for (t, want) in [300.0, 600.0, 900.0, 1200.0].iter().enumerate() {
+            assert!((times[t] - want).abs() < 0.01, "{times:?}");
+        }
+
+        // Manual taps follow their own divisions (defaults: 16th, 8th, dot 8th, qtr).
+        let params = MultiTapDelayParams {
+            pattern: PATTERN_MANUAL as f32,
+            ..params
+        };
+        kernel.process_stereo(0.0, 0.0, &params);
+        let times = kernel.tap_times_ms();
+        for (t, want) in [150.0, 300.0, 450.0, 600.0].iter().enumerate() {
+            assert!((times[t] - want).abs() < 0.01, "{times:?}");
+        }
+    }
+
+    #[test]
+    fn long_synced_lengths_clamp_to_buffer() {
+        let mut kernel = MultiTapDelayKernel::new(48000.0);
+        kernel.set_tempo_context(&TempoContext {
+            bpm: 60.0,
+            ..TempoContext::default()
+        });
+        let params = MultiTapDelayParams {
+            sync: 1.0,
+            division: 0.0, // whole note at 60 BPM = 4 s
+            ..MultiTapDelayParams::default()
+        };
+        let (l, r) = kernel.process_stereo(1.0, 1.0, &params);
+        assert!(l.is_finite() && r.is_finite());
+        assert!(kernel.tap_times_ms().iter().all(|&ms| ms < 2000.0));
+    }
+
+    /// Even with every send at its maximum, the scaled loop decays.
+    #[test]
+    fn feedback_sum_is_bounded() {
+        let params = MultiTapDelayParams {
+            tap_feedback_pct: [95.0; TAPS],
+            length_ms: 40.0,
+            ..MultiTapDelayParams::default()
+        };
+        let ir = impulse_response(&params, 96000);
+        let peak = |range: core::ops::Range<usize>| {
+            ir[range]
+                .iter()
+                .map(|(l, r)| l.abs().max(r.abs()))
+                .fold(0.0_f32, f32::max)
+        };
+        let early = peak(0..9600);
+        let late = peak(86400..96000);
+        assert!(late < early * 0.5, "early {early}, late {late}");
+        assert!(ir.iter().all(|(l, r)| l.is_finite() && r.is_finite()));
+    }
+
+    #[test]
+    fn feedback_send_regenerates_taps() {
+        let dry = MultiTapDelayParams {
+            tap_feedback_pct: [0.0; TAPS],
+            length_ms: 100.0,
+            ..MultiTapDelayParams::default()
+        };
+        let wet = MultiTapDelayParams {
+            tap_feedback_pct: [0.0, 0.0, 0.0, 50.0],
+            ..dry
+        };
+        let energy = |p: &MultiTapDelayParams| {
+            impulse_response(p, 48000)[6000..]
+                .iter()
+                .map(|(l, r)| l * l + r * r)
+                .sum::<f32>()
+        };
+        assert!(energy(&dry) < 1e-9);
+        assert!(energy(&wet) > 0.01);
+    }
+
+    #[test]
+    fn diagnostics_report_effective_times() {
+        let mut adapter = Adapter::new(MultiTapDelayKernel::new(48000.0), 48000.0);
+        adapter.set_param(1, 800.0); // length
+        for _ in 0..24000 {
+            adapter.process_stereo(0.0, 0.0);
+        }
+        for t in 0..TAPS {
+            let want = PATTERN_RATIOS[0][t] * 800.0;
+            let got = adapter.get_param(ACTUAL_BASE + t);
+            assert!((got - want).abs() < 1.0, "tap {t}: {got} vs {want}");
+        }
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let mut adapter = Adapter::new(MultiTapDelayKernel::new(48000.0), 48000.0);
+        assert_eq!(adapter.param_count(), MultiTapDelayParams::COUNT);
+        for _ in 0..1000 {
+            let (l, r) = adapter.process_stereo(0.3, -0.2);
+            assert!(l.is_finite() && r.is_finite());
+        }
+        assert!(adapter.is_true_stereo());
+    }
+
+    #[test]
+    fn from_knobs_maps_globals() {
+        let params = MultiTapDelayParams::from_knobs(1.0, 1.0, 0.0, 0.0, 1.0, 0.5);
+        assert_eq!(params.pattern, 5.0);
+        assert!((params.length_ms - 2000.0).abs() < 0.5);
+        assert!((params.mix_pct - 100.0).abs() < 0.1);
+        assert!(params.output_db.abs() < 0.1);
+        assert_eq!(params.tap_level_pct, DEFAULT_LEVEL_PCT);
+    }
+
+    #[test]
+    fn tail_covers_longest_tap_and_feedback() {
+        let mut kernel = MultiTapDelayKernel::new(48000.0);
+        let params = MultiTapDelayParams {
+            tap_feedback_pct: [0.0; TAPS],
+            ..MultiTapDelayParams::default()
+        };
+        kernel.process_stereo(0.0, 0.0, &params);
+        assert_eq!(kernel.tail_samples(), 24000);
+
+        kernel.process_stereo(0.0, 0.0, &MultiTapDelayParams::default());
+        assert!(kernel.tail_samples() > 24000 * 5);
+    }
+}
@@ -0,0 +1,440 @@
+//! Phase Rotator kernel — cascaded second-order allpasses with adjustable frequency.
+//!
+//! `PhaseRotatorKernel` owns DSP state (allpass biquads, coefficient cache).
+//! Parameters are received via `&PhaseRotatorParams` each sample. Deployed via
+//! [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin, or called
+//! directly on embedded targets.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! Input L/R
+//!   → Allpass cascade (0–8 RBJ second-order allpasses at freq_hz, Q)
+//!   → Output level
+//!   → Output L/R
+//! ```
+//!
+//! # Phase Rotation
+//!
+//! Each second-order allpass has unity magnitude at every frequency and a
+//! phase that falls from 0° at DC through −180° at `freq_hz` to −360° at
+//! Nyquist. Cascading N stages gives −N × 180° at `freq_hz`. Q sets how
+//! quickly the phase turns around the centre frequency.
+//!
+//! Because the magnitude response is flat, the rotator changes nothing on
+//! its own. It matters when the output is summed with another signal — for
+//! example a DI and a mic'd amp on parallel graph branches. Rotating one
+//! branch around the frequency where they cancel brings them back into
+//! phase without the comb filtering a plain delay would cause. Pair it with
+//! [`AlignKernel`](super::AlignKernel) for the coarse time offset and
+//! polarity.
+//!
+//! Reference: Bristow-Johnson, "Cookbook formulae for audio EQ biquad filter
+//! coefficients" (allpass section).
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(PhaseRotatorKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing, ADCs are hardware-filtered)
+//! let mut kernel = PhaseRotatorKernel::new(48000.0);
+//! let params = PhaseRotatorParams::from_knobs(adc_freq, adc_stages, adc_q, adc_output);
+//! let (left, right) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    Biquad, Cached, ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit,
+    allpass_coefficients, fast_db_to_linear,
+};
+
+// ── Constants ─────────────────────────────────────────────────────────────────
+
+/// Maximum number of second-order allpass stages (up to 1440° at the centre).
+const MAX_STAGES: usize = 8;
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`PhaseRotatorKernel`].
+///
+/// All values are in **user-facing units** — the same units shown in GUIs and
+/// stored in presets. The kernel converts internally as needed.
+///
+/// ## Parameter Table
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `freq_hz` | Hz | 20–20000 | 200.0 |
+/// | 1 | `stages` | index | 0–8 | 1 |
+/// | 2 | `q` | — | 0.1–10 | 0.707 |
+/// | 3 | `output_db` | dB | −60–6 | 0.0 |
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseRotatorParams {
+    /// Centre frequency of the allpass stages in Hz.
+    ///
+    /// Range: 20.0 to 20000.0 Hz (logarithmic). Default 200.0. Each stage
+    /// contributes −180° here.
+    pub freq_hz: f32,
+
+    /// Number of second-order allpass stages.
+    ///
+    /// Range: 0 to 8 (stepped). Default 1. 0 bypasses the cascade.
+    pub stages: f32,
+
+    /// Allpass Q — steepness of the phase transition around `freq_hz`.
+    ///
+    /// Range: 0.1 to 10.0 (logarithmic). Default 0.707.
+    pub q: f32,
+
+    /// Output level in decibels.
+    ///
+    /// Range: −60.0 to +6.0 dB, default 0.0.
+    pub output_db: f32,
+}
+
+impl Default for PhaseRotatorParams {
+    fn default() -> Self {
+        Self {
+            freq_hz: 200.0,
+            stages: 1.0,
+            q: core::f32::consts::FRAC_1_SQRT_2,
+            output_db: 0.0,
+        }
+    }
+}
+
+impl PhaseRotatorParams {
+    /// Creates parameters from normalized 0–1 knob readings.
+    ///
+    /// | Argument | Index | Parameter | Range |
+    /// |----------|-------|-----------|-------|
+    /// | `freq` | 0 | `freq_hz` | 20–20000 Hz (log) |
+    /// | `stages` | 1 | `stages` | 0–8 (stepped) |
+    /// | `q` | 2 | `q` | 0.1–10 (log) |
+    /// | `output` | 3 | `output_db` | −60–+6 dB |
+    pub fn from_knobs(freq: f32, stages: f32, q: f32, output: f32) -> Self {
+        Self::from_normalized(&[freq, stages, q, output])
+    }
+}
+
+impl KernelParams for PhaseRotatorParams {
+    const COUNT: usize = 4;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::custom("Frequency", "Freq", 20.0, 20000.0, 200.0)
+                    .with_unit(ParamUnit::Hertz)
+                    .with_step(1.0)
+                    .with_id(ParamId(3800), "pr_freq")
+                    .with_description("Frequency the phase rotates around.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            1 => Some(
+                ParamDescriptor::custom("Stages", "Stgs", 0.0, MAX_STAGES as f32, 1.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(3801), "pr_stages")
+                    .with_description("Number of allpass stages, 180° each; 0 bypasses.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
+            ),
+            2 => Some(
+                ParamDescriptor::custom("Q", "Q", 0.1, 10.0, core::f32::consts::FRAC_1_SQRT_2)
+                    .with_step(0.01)
+                    .with_id(ParamId(3802), "pr_q")
+                    .with_description("Steepness of the phase transition around the frequency.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            3 => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(3803), "pr_output"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 => SmoothingStyle::Slow, // freq_hz — filter coefficient, 20 ms
+            1 => SmoothingStyle::None, // stages — stepped, snap immediately
+            2 => SmoothingStyle::Slow, // q — filter coefficient, 20 ms
+            3 => SmoothingStyle::Fast, // output_db — 5 ms
+            _ => SmoothingStyle::Standard,
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.freq_hz,
+            1 => self.stages,
+            2 => self.q,
+            3 => self.output_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.freq_hz = value,
+            1 => self.stages = value,
+            2 => self.q = value,
+            3 => self.output_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP phase rotator kernel.
+///
+/// Contains ONLY the mutable state required for audio processing:
+///
+/// - Allpass biquads: `allpass[channel][stage]` (2 × [`MAX_STAGES`])
+/// - Sample rate and coefficient cache keyed on `(freq_hz, q)`
+///
+/// No `SmoothedParam`, no atomics, no platform awareness.
+///
+/// # Invariants
+///
+/// All stages share one set of coefficients. Inactive stages (index ≥
+/// `stages`) are not processed, so their state is stale; they are cleared
+/// when the stage count changes so re-enabled stages start from silence.
+pub struct PhaseRotatorKernel {
+    /// Current sample rate in Hz.
+    sample_rate: f32,
+
+    /// Allpass cascade — `[channel][stage]`.
+    allpass: [[Biquad; MAX_STAGES]; 2],
+
+    /// Stage count used for the previous sample.
+    active_stages: usize,
+
+    /// Coefficient cache keyed on `[freq_hz, q]`.
+    coeff_cache: Cached<[f32; 6]>,
+}
+
+impl PhaseRotatorKernel {
+    /// Create a new phase rotator kernel at the given sample rate.
+    pub fn new(sample_rate: f32) -> Self {
+        let defaults = PhaseRotatorParams::default();
+        let coeffs = Self::compute_coefficients(defaults.freq_hz, defaults.q, sample_rate);
+        let mut coeff_cache = Cached::new(coeffs, 2);
+        coeff_cache.update(&[defaults.freq_hz, defaults.q], 0.001, |inputs| {
+            Self::compute_coefficients(inputs[0], inputs[1], sample_rate)
+        });
+
+        let mut allpass = core::array::from_fn(|_| core::array::from_fn(|_| Biquad::new()));
+        Self::apply_coefficients(&mut allpass, coeffs);
+
+        Self {
+            sample_rate,
+            allpass,
+            active_stages: defaults.stages as usize,
+            coeff_cache,
+        }
+    }
+
+    /// RBJ allpass coefficients with frequency clamped below Nyquist.
+    fn compute_coefficients(freq_hz: f32, q: f32, sample_rate: f32) -> [f32; 6] {
+        let freq = freq_hz.clamp(20.0, sample_rate * 0.45);
+        let (b0, b1, b2, a0, a1, a2) = allpass_coefficients(freq, q.max(0.1), sample_rate);
+        [b0, b1, b2, a0, a1, a2]
+    }
+
+    /// Apply one coefficient set to every stage on both channels.
+    fn apply_coefficients(allpass: &mut [[Biquad; MAX_STAGES]; 2], c: [f32; 6]) {
+        for channel in allpass {
+            for stage in channel.iter_mut() {
+                stage.set_coefficients(c[0], c[1], c[2], c[3], c[4], c[5]);
+            }
+        }
+    }
+}
+
+impl DspKernel for PhaseRotatorKernel {
+    type Params = PhaseRotatorParams;
+
+    fn process_stereo(&mut self, left: f32, right: f32, params: &PhaseRotatorParams) -> (f32, f32) {
+        // ── 1. Coefficients (only pushed to the biquads when they change) ─────
+        let sr = self.sample_rate;
+        let allpass = &mut self.allpass;
+        self.coeff_cache
+            .update(&[params.freq_hz, params.q], 0.001, |inputs| {
+                let coeffs = Self::compute_coefficients(inputs[0], inputs[1], sr);
+                Self::apply_coefficients(allpass, coeffs);
+                coeffs
+            });
+
+        // ── 2. Stage count ────────────────────────────────────────────────────
+        let stages = (params.stages.clamp(0.0, MAX_STAGES as f32) + 0.5) as usize;
+        if stages != self.active_stages {
+            for channel in &mut self.allpass {
+                channel[self.active_stages.min(stages)..]
+                    .iter_mut()
+                    .for_each(Biquad::clear);
+            }
+            self.active_stages = stages;
+        }
+
+        // ── 3. Allpass cascade ────────────────────────────────────────────────
+        let mut l = left;
+        let mut r = right;
+        for stage in 0..stages {
+            l = self.allpass[0][stage].process(l);
+            r = self.allpass[1][stage].process(r);
+        }
+
+        // ── 4. Output level ───────────────────────────────────────────────────
+        let output_gain = fast_db_to_linear(params.output_db);
+        (l * output_gain, r * output_gain)
+    }
+
+    fn reset(&mut self) {
+        for channel in &mut self.allpass {
+            channel.iter_mut().for_each(Biquad::clear);
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.coeff_cache.invalidate();
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::Effect;
+    use sonido_core::kernel::Adapter;
+
+    /// Run a sine through the kernel and return the settled RMS gain.
+    fn sine_response(
+        kernel: &mut PhaseRotatorKernel,
+        params: &PhaseRotatorParams,
+        freq: f32,
+    ) -> f32 {
+        let sr = 48000.0;
+        let (mut energy_in, mut energy_out) = (0.0_f32, 0.0_f32);
+        for i in 0..19200 {
+            let x = libm::sinf(2.0 * core::f32::consts::PI * freq * i as f32 / sr);
+            let (l, _) = kernel.process_stereo(x, x, params);
+            if i >= 9600 {
+                energy_in += x * x;
+                energy_out += l * l;
+            }
+        }
+        libm::sqrtf(energy_out / energy_in)
+    }
+
+    #[test]
+    fn silence_in_silence_out() {
+        let mut kernel = PhaseRotatorKernel::new(48000.0);
+        let params = PhaseRotatorParams::default();
+        let (l, r) = kernel.process_stereo(0.0, 0.0, &params);
+        assert!(l.abs() < 1e-6, "Expected silence on left, got {l}");
+        assert!(r.abs() < 1e-6, "Expected silence on right, got {r}");
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(PhaseRotatorParams::COUNT, 4);
+
+        let d0 = PhaseRotatorParams::descriptor(0).expect("index 0 must exist");
+        assert_eq!(d0.name, "Frequency");
+        assert_eq!(d0.id, ParamId(3800));
+        assert_eq!(d0.string_id, "pr_freq");
+
+        let d1 = PhaseRotatorParams::descriptor(1).expect("index 1 must exist");
+        assert_eq!(d1.name, "Stages");
+        assert_eq!(d1.id, ParamId(3801));
+
+        let d2 = PhaseRotatorParams::descriptor(2).expect("index 2 must exist");
+        assert_eq!(d2.id, ParamId(3802));
+
+        let d3 = PhaseRotatorParams::descriptor(3).expect("index 3 must exist");
+        assert_eq!(d3.name, "Output");
+        assert_eq!(d3.id, ParamId(3803));
+
+        assert!(PhaseRotatorParams::descriptor(4).is_none());
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let kernel = PhaseRotatorKernel::new(48000.0);
+        let mut adapter = Adapter::new(kernel, 48000.0);
+        adapter.reset();
+        let output = adapter.process(0.3);
+        assert!(output.is_finite(), "adapter.process() returned {output}");
+    }
+
+    /// The cascade is allpass: magnitude stays at unity for every stage count.
+    #[test]
+    fn magnitude_is_flat() {
+        for stages in [1.0, 4.0, 8.0] {
+            let params = PhaseRotatorParams {
+                freq_hz: 500.0,
+                stages,
+                q: 2.0,
+                ..Default::default()
+            };
+            for freq in [100.0, 500.0, 5000.0] {
+                let mut kernel = PhaseRotatorKernel::new(48000.0);
+                let gain = sine_response(&mut kernel, &params, freq);
+                assert!(
+                    (gain - 1.0).abs() < 0.01,
+                    "{stages} stages at {freq} Hz: gain {gain}"
+                );
+            }
+        }
+    }
+
+    /// One stage is −180° at the centre frequency: summing with the dry
+    /// signal cancels there.
+    #[test]
+    fn one_stage_inverts_at_centre() {
+        let sr = 48000.0;
+        let freq = 1000.0;
+        let params = PhaseRotatorParams {
+            freq_hz: freq,
+            ..Default::default()
+        };
+        let mut kernel = PhaseRotatorKernel::new(sr);
+        let mut peak_sum = 0.0_f32;
+        for i in 0..19200 {
+            let x = libm::sinf(2.0 * core::f32::consts::PI * freq * i as f32 / sr);
+            let (l, _) = kernel.process_stereo(x, x, &params);
+            if i >= 9600 {
+                peak_sum = peak_sum.max((x + l).abs());
+            }
+        }
+        assert!(
+            peak_sum < 0.02,
+            "dry + rotated should cancel, got {peak_sum}"
+        );
+    }
+
+    /// Zero stages passes the signal through unchanged.
+    #[test]
+    fn zero_stages_is_bypass() {
+        let mut kernel = PhaseRotatorKernel::new(48000.0);
+        let params = PhaseRotatorParams {
+            stages: 0.0,
+            ..Default::default()
+        };
+        for i in 0..100 {
+            let x = libm::sinf(i as f32 * 0.1);
+            let (l, r) = kernel.process_stereo(x, -x, &params);
+            assert!((l - x).abs() < 1e-6 && (r + x).abs() < 1e-6);
+        }
+    }
+}
@@ -267,9 +267,17 @@ impl KernelParams for PhaserParams {
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
             // Index 0: Rate — must match classic Phaser impl_params! ParamId(900)
-            0 => Some(ParamDescriptor::rate_hz(0.05, 5.0, 0.3).with_id(ParamId(900), "phsr_rate")),
+            0 => Some(
+                ParamDescriptor::rate_hz(0.05, 5.0, 0.3)
+                    .with_id(ParamId(900), "phsr_rate")
+                    .with_description("LFO speed of the notch sweep."),
+            ),
             // Index 1: Depth — ParamId(901)
-            1 => Some(ParamDescriptor::depth().with_id(ParamId(901), "phsr_depth")),
+            1 => Some(
+                ParamDescriptor::depth()
+                    .with_id(ParamId(901), "phsr_depth")
+                    .with_description("How far the notches sweep between Min and Max Freq."),
+            ),
             // Index 2: Stages — ParamId(902), STEPPED, step=2
             2 => Some(
                 ParamDescriptor {
@@ -283,16 +291,26 @@ impl KernelParams for PhaserParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(902), "phsr_stages")
+                .with_description("Number of allpass stages; more stages, more notches.")
                 .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
             ),
             // Index 3: Feedback — ParamId(903)
-            3 => Some(ParamDescriptor::feedback().with_id(ParamId(903), "phsr_feedback")),
+            3 => Some(
+                ParamDescriptor::feedback()
+                    .with_id(ParamId(903), "phsr_feedback")
+                    .with_description("Resonance at the notches."),
+            ),
             // Index 4: Mix — ParamId(904)
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(904), "phsr_mix")),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(904), "phsr_mix")
+                    .with_description("Blend of dry and phased signal."),
+            ),
             // Index 5: Min Freq — ParamId(906) (note: output is 905, min/max come before it)
             5 => Some(
                 ParamDescriptor::custom("Min Freq", "MinF", 20.0, 2000.0, 200.0)
                     .with_id(ParamId(906), "phsr_min_freq")
+                    .with_description("Lowest frequency of the sweep.")
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic),
             ),
@@ -300,6 +318,7 @@ impl KernelParams for PhaserParams {
             6 => Some(
                 ParamDescriptor::custom("Max Freq", "MaxF", 200.0, 20000.0, 4000.0)
                     .with_id(ParamId(907), "phsr_max_freq")
+                    .with_description("Highest frequency of the sweep.")
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic),
             ),
@@ -308,6 +327,8 @@ impl KernelParams for PhaserParams {
                 ParamDescriptor::custom("Sync", "Sync", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(908), "phsr_sync")
+                    .with_group("Tempo Sync")
+                    .with_description("Lock the LFO rate to the host tempo using Division.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -316,6 +337,8 @@ impl KernelParams for PhaserParams {
                 ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 3.0)
                     .with_step(1.0)
                     .with_id(ParamId(909), "phsr_division")
+                    .with_group("Tempo Sync")
+                    .with_description("Note length of one LFO cycle when Sync is on.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(DIVISION_LABELS),
             ),
@@ -327,6 +350,7 @@ impl KernelParams for PhaserParams {
             10 => Some(
                 ParamDescriptor::custom("LFO Phase", "Phase", 0.0, 1.0, 0.0)
                     .with_id(ParamId(910), "phsr_lfo_phase")
+                    .with_description("Current LFO position in its cycle (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
             _ => None,
@@ -118,26 +118,38 @@ impl KernelParams for PitchShiftParams {
         match index {
             0 => Some(
                 ParamDescriptor::custom("Semitones", "Semi", -24.0, 24.0, 0.0)
-                    .with_unit(ParamUnit::None)
+                    .with_unit(ParamUnit::Semitones)
                     .with_step(1.0)
-                    .with_id(ParamId(2400), "ps_semitones"),
+                    .with_id(ParamId(2400), "ps_semitones")
+                    .with_description("Coarse pitch shift."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Cents", "Cents", -50.0, 50.0, 0.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(2401), "ps_cents"),
+                    .with_unit(ParamUnit::Cents)
+                    .with_id(ParamId(2401), "ps_cents")
+                    .with_description("Fine pitch shift."),
             ),
             2 => Some(
                 ParamDescriptor::custom("Grain Size", "Grain", 10.0, 50.0, 20.0)
                     .with_unit(ParamUnit::Milliseconds)
-                    .with_id(ParamId(2402), "ps_grain_size"),
+                    .with_id(ParamId(2402), "ps_grain_size")
+                    .with_description(
+                        "Length of each grain; shorter is tighter, longer is smoother.",
+                    ),
+            ),
+            3 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2403), "ps_mix")
+                    .with_description("Blend of dry and shifted signal."),
             ),
-            3 => Some(ParamDescriptor::mix().with_id(ParamId(2403), "ps_mix")),
             4 => Some(
                 ParamDescriptor::custom("Quality", "Quality", 0.0, 1.0, 0.0)
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2404), "ps_quality")
+                    .with_description(
+                        "High overlaps more grains for a smoother result at higher CPU cost.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Standard", "High"]),
             ),
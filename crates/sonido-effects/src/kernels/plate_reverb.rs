@@ -226,8 +226,9 @@ impl KernelParams for PlateReverbParams {
         match index {
             0 => Some(
                 ParamDescriptor::custom("Decay", "Decay", 0.1, 10.0, 2.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(2700), "plate_decay"),
+                    .with_unit(ParamUnit::Seconds)
+                    .with_id(ParamId(2700), "plate_decay")
+                    .with_description("Length of the reverb tail."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -240,13 +241,19 @@ impl KernelParams for PlateReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2701), "plate_damping"),
+                .with_id(ParamId(2701), "plate_damping")
+                .with_description(
+                    "High-frequency absorption in the tank; higher values darken the tail.",
+                ),
             ),
             2 => Some(
                 ParamDescriptor::custom("Pre-Delay", "PreDly", 0.0, 100.0, 20.0)
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(1.0)
-                    .with_id(ParamId(2702), "plate_predelay"),
+                    .with_id(ParamId(2702), "plate_predelay")
+                    .with_description(
+                        "Gap before the reverb starts, separating it from the dry signal.",
+                    ),
             ),
             3 => Some(
                 ParamDescriptor {
@@ -259,7 +266,10 @@ impl KernelParams for PlateReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2703), "plate_bandwidth"),
+                .with_id(ParamId(2703), "plate_bandwidth")
+                .with_description(
+                    "Lowpass on the input; lower values darken what enters the plate.",
+                ),
             ),
             4 => Some(
                 ParamDescriptor {
@@ -272,7 +282,8 @@ impl KernelParams for PlateReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2704), "plate_diffusion"),
+                .with_id(ParamId(2704), "plate_diffusion")
+                .with_description("Input diffusion; higher values give a denser attack."),
             ),
             5 => Some(
                 ParamDescriptor {
@@ -285,9 +296,16 @@ impl KernelParams for PlateReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2705), "plate_size"),
+                .with_id(ParamId(2705), "plate_size")
+                .with_description(
+                    "Size of the plate; larger sizes space reflections further apart.",
+                ),
+            ),
+            6 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2706), "plate_mix")
+                    .with_description("Blend of dry and reverberated signal."),
             ),
-            6 => Some(ParamDescriptor::mix().with_id(ParamId(2706), "plate_mix")),
             7 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(2707), "plate_output"),
             ),
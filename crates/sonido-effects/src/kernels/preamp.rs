@@ -145,13 +145,15 @@ impl KernelParams for PreampParams {
         match index {
             0 => Some(
                 ParamDescriptor::gain_db("Gain", "Gain", 0.0, 40.0, 0.0)
-                    .with_id(ParamId(100), "pre_gain"),
+                    .with_id(ParamId(100), "pre_gain")
+                    .with_description("Gain applied before the tone stage."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Tone", "Tone", -12.0, 12.0, 0.0)
                     .with_unit(ParamUnit::Decibels)
                     .with_step(0.5)
-                    .with_id(ParamId(101), "pre_tone"),
+                    .with_id(ParamId(101), "pre_tone")
+                    .with_description("Tilts the tone darker (negative) or brighter (positive)."),
             ),
             2 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(102), "pre_output"),
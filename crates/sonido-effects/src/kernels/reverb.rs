@@ -18,15 +18,18 @@
 //! The FDN uses an 8×8 Hadamard matrix (implemented via fast Walsh–Hadamard
 //! butterfly) to mix energy between delay lines in the feedback path. Each delay
 //! line has sinusoidal LFO modulation (breaking metallic resonances) and a
-//! one-pole lowpass for high-frequency damping. A tapped delay provides early
-//! reflections with room-size-dependent timing.
+//! one-pole lowpass for high-frequency damping, followed by optional low and
+//! high shelves that set how long the bass and treble decay relative to the
+//! mids (`low_mult`, `high_mult`). A tapped delay provides early reflections
+//! in one of four shapes (Room, Chamber, Hall, Cathedral), stretched by room
+//! size. Pre-delay runs up to 200 ms.
 //!
 //! # Signal Flow (per sample)
 //!
 //! 1. Stereo predelay
 //! 2. Mono sum → ER tapped delay write
 //! 3. ER stereo read (even/odd taps → L/R)
-//! 4. 8-line Hadamard FDN (modulated read → butterfly → damp → write)
+//! 4. 8-line Hadamard FDN (modulated read → butterfly → damp → band shelves → write)
 //! 5. Allpass diffusion (separate L/R chains)
 //! 6. ER + late reverb combine
 //! 7. Stereo width via M/S
@@ -54,10 +57,10 @@
 //! ```
 
 use libm::{ceilf, powf, roundf, sqrtf};
-use sonido_core::fast_math::fast_sin_turns;
+use sonido_core::fast_math::fast_sin_turns4;
 use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
 use sonido_core::{
-    InterpolatedDelay, Interpolation, ModulatedAllpass, OnePole, ParamDescriptor, ParamId,
+    F32x4, InterpolatedDelay, Interpolation, OnePole4, ParamDescriptor, ParamFlags, ParamId,
     ParamUnit, fast_db_to_linear, flush_denormal, wet_dry_mix_stereo,
 };
 
@@ -80,7 +83,7 @@ const ALLPASS_TUNINGS_44K_R: [usize; 4] = [579, 464, 364, 248];
 
 /// Early reflection tap positions at 44.1 kHz reference (primes, ~25 ms window).
 ///
-/// Room-size scaling extends these up to ~80 ms for large rooms.
+/// The Room pattern in [`ER_PATTERNS`]. Room-size scaling stretches it up to 2×.
 const ER_TAP_POSITIONS_44K: [usize; 14] = [
     131, 197, 263, 337, 401, 463, 541, 613, 677, 751, 811, 877, 941, 1009,
 ];
@@ -92,10 +95,18 @@ const ER_TAP_COUNT: usize = 14;
 const REFERENCE_RATE: f32 = 44100.0;
 
 /// Maximum pre-delay in milliseconds.
-const MAX_PREDELAY_MS: f32 = 100.0;
+const MAX_PREDELAY_MS: f32 = 200.0;
 
 /// Maximum early reflection delay in milliseconds.
-const MAX_ER_MS: f32 = 80.0;
+///
+/// Covers the last Cathedral tap (~90 ms) at the largest room scale (2×).
+const MAX_ER_MS: f32 = 185.0;
+
+/// Crossover below which `low_mult` scales the decay time (Hz).
+const LOW_XOVER_HZ: f32 = 250.0;
+
+/// Crossover above which `high_mult` scales the decay time (Hz).
+const HIGH_XOVER_HZ: f32 = 4000.0;
 
 /// Hadamard scale factor: 1/sqrt(8) for energy preservation.
 ///
@@ -115,6 +126,9 @@ const ALLPASS_FEEDBACK: f32 = 0.6;
 /// Allpass modulation depth (ms). Subtle — enough to decorrelate.
 const ALLPASS_MOD_DEPTH_MS: f32 = 0.15;
 
+/// Allpass LFO rates in Hz (left, right).
+const ALLPASS_MOD_RATES: [f32; 2] = [0.7, 0.8];
+
 // ── Helper functions ─────────────────────────────────────────────────────────
 
 /// Scale delay samples from 44.1 kHz reference to target rate.
@@ -146,6 +160,75 @@ const ER_TAP_GAINS: [f32; ER_TAP_COUNT] = [
     0.080_178, // i=13: 0.3 / sqrt(14)
 ];
 
+/// Chamber ER gains: `0.229 / (i+1)^0.3`. Slower falloff than the room —
+/// hard, reflective walls. Even taps sum to 1, like [`ER_TAP_GAINS`].
+const ER_CHAMBER_GAINS: [f32; ER_TAP_COUNT] = [
+    0.229_272, 0.186_226, 0.164_897, 0.151_263, 0.141_468, 0.133_938, 0.127_885, 0.122_864,
+    0.118_598, 0.114_908, 0.111_669, 0.108_792, 0.106_210, 0.103_875,
+];
+
+/// Cathedral ER gains: `0.213 / (i+1)^0.25`. Late reflections stay nearly
+/// as loud as the first. Even taps sum to 1, like [`ER_TAP_GAINS`].
+const ER_CATHEDRAL_GAINS: [f32; ER_TAP_COUNT] = [
+    0.212_927, 0.179_049, 0.161_789, 0.150_562, 0.142_393, 0.136_048, 0.130_905, 0.126_607,
+    0.122_933, 0.119_737, 0.116_918, 0.114_402, 0.112_136, 0.110_077,
+];
+
+/// Early reflection pattern for one room shape.
+///
+/// Even-indexed taps feed the left channel, odd-indexed taps the right.
+struct ErPattern {
+    /// Tap positions in samples at the 44.1 kHz reference rate.
+    taps_44k: [usize; ER_TAP_COUNT],
+    /// Tap gains.
+    gains: [f32; ER_TAP_COUNT],
+}
+
+/// Number of early reflection shapes.
+const ER_SHAPE_COUNT: usize = 4;
+
+/// Early reflection patterns, indexed by the `er_shape` parameter.
+///
+/// All tap positions are primes, scaled by room size at run time.
+const ER_PATTERNS: [ErPattern; ER_SHAPE_COUNT] = [
+    // Room: dense taps over ~3–23 ms.
+    ErPattern {
+        taps_44k: ER_TAP_POSITIONS_44K,
+        gains: ER_TAP_GAINS,
+    },
+    // Chamber: tight, strong taps over ~2–15 ms.
+    ErPattern {
+        taps_44k: [
+            89, 127, 167, 211, 251, 293, 337, 379, 421, 467, 509, 557, 601, 647,
+        ],
+        gains: ER_CHAMBER_GAINS,
+    },
+    // Hall: sparser taps over ~10–56 ms.
+    ErPattern {
+        taps_44k: [
+            443, 619, 773, 941, 1103, 1259, 1409, 1571, 1721, 1873, 2027, 2179, 2333, 2477,
+        ],
+        gains: ER_TAP_GAINS,
+    },
+    // Cathedral: widely spaced taps over ~15–90 ms.
+    ErPattern {
+        taps_44k: [
+            661, 937, 1193, 1447, 1699, 1949, 2203, 2459, 2707, 2957, 3209, 3463, 3709, 3967,
+        ],
+        gains: ER_CATHEDRAL_GAINS,
+    },
+];
+
+/// Step labels for the `er_shape` parameter, in [`ER_PATTERNS`] order.
+const ER_SHAPE_LABELS: &[&str] = &["Room", "Chamber", "Hall", "Cathedral"];
+
+/// Base ER tap positions of every shape, scaled to `sample_rate`.
+fn er_taps_for_rate(sample_rate: f32) -> [[f32; ER_TAP_COUNT]; ER_SHAPE_COUNT] {
+    core::array::from_fn(|shape| {
+        core::array::from_fn(|i| scale_to_rate(ER_PATTERNS[shape].taps_44k[i], sample_rate) as f32)
+    })
+}
+
 /// Convert damping parameter (0 = bright, 1 = dark) to lowpass cutoff Hz.
 ///
 /// Uses a logarithmic mapping: 200 Hz at damping = 1.0, 20 kHz at damping = 0.0.
@@ -155,6 +238,18 @@ fn damping_to_hz(damping: f32) -> f32 {
     200.0 * powf(100.0, 1.0 - damping)
 }
 
+/// Shelf amount that makes a band decay `mult` times as long as the mids.
+///
+/// Returns exactly 0 at `mult = 1`, which lets the FDN skip the band split.
+#[inline]
+fn band_shelf(feedback: f32, mult: f32) -> f32 {
+    if (mult - 1.0).abs() < 1e-6 || feedback <= 0.0 {
+        0.0
+    } else {
+        powf(feedback, 1.0 / mult - 1.0) - 1.0
+    }
+}
+
 // ── Hadamard fast Walsh–Hadamard transform ───────────────────────────────────
 
 /// In-place butterfly on array elements at indices `i` and `j`.
@@ -198,6 +293,39 @@ fn hadamard8(buf: &mut [f32; 8]) {
     }
 }
 
+/// FDN lines 0–3 and 4–7 as two four-lane vectors.
+#[inline]
+fn lanes8(values: [f32; 8]) -> [F32x4; 2] {
+    [
+        F32x4::new(values[0], values[1], values[2], values[3]),
+        F32x4::new(values[4], values[5], values[6], values[7]),
+    ]
+}
+
+/// Advance LFO phases by one step, wrapping each lane back into `[0, 1)`.
+#[inline]
+fn advance_phases(phases: F32x4, incs: F32x4) -> F32x4 {
+    F32x4::from_array(
+        (phases + incs)
+            .to_array()
+            .map(|p| if p >= 1.0 { p - 1.0 } else { p }),
+    )
+}
+
+/// Allpass delay lines and base read positions for both channels.
+fn allpass_lines(sample_rate: f32, mod_depth: f32) -> ([[InterpolatedDelay; 4]; 2], [F32x4; 2]) {
+    let bases = [ALLPASS_TUNINGS_44K, ALLPASS_TUNINGS_44K_R]
+        .map(|tunings| tunings.map(|t| scale_to_rate(t, sample_rate) as f32));
+    let lines = bases.map(|channel| {
+        channel.map(|base| {
+            let mut delay = InterpolatedDelay::new((base + mod_depth) as usize + 4);
+            delay.set_interpolation(Interpolation::Linear);
+            delay
+        })
+    });
+    (lines, bases.map(F32x4::from_array))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Parameters
 // ═══════════════════════════════════════════════════════════════════════════
@@ -212,11 +340,14 @@ fn hadamard8(buf: &mut [f32; 8]) {
 /// | 0 | `room_size_pct` | % | 0–100 | 50.0 |
 /// | 1 | `decay_pct` | % | 0–100 | 50.0 |
 /// | 2 | `damping_pct` | % | 0–100 | 50.0 |
-/// | 3 | `predelay_ms` | ms | 0–100 | 10.0 |
+/// | 3 | `predelay_ms` | ms | 0–200 | 10.0 |
 /// | 4 | `mix_pct` | % | 0–100 | 50.0 |
 /// | 5 | `width_pct` | % | 0–100 | 100.0 |
 /// | 6 | `er_level_pct` | % | 0–100 | 50.0 |
 /// | 7 | `output_db` | dB | −6–+6 | 0.0 |
+/// | 8 | `er_shape` | enum | 0–3 (STEPPED) | 0.0 (Room) |
+/// | 9 | `low_mult` | × | 0.5–2.0 | 1.0 |
+/// | 10 | `high_mult` | × | 0.25–1.0 | 1.0 |
 #[derive(Debug, Clone, Copy)]
 pub struct ReverbParams {
     /// Room size as a percentage (0–100%).
@@ -237,7 +368,7 @@ pub struct ReverbParams {
     /// - 100% = dark (heavy HF absorption)
     pub damping_pct: f32,
 
-    /// Pre-delay time in milliseconds (0–100 ms).
+    /// Pre-delay time in milliseconds (0–200 ms).
     ///
     /// Introduces a gap between the dry signal and the onset of reverb,
     /// which helps preserve transient clarity in dense mixes.
@@ -260,6 +391,24 @@ pub struct ReverbParams {
 
     /// Output level in decibels (−6 to +6 dB).
     pub output_db: f32,
+
+    /// Early reflection pattern: 0 = Room, 1 = Chamber, 2 = Hall, 3 = Cathedral.
+    ///
+    /// Selects the tap timing and gain falloff; `room_size_pct` still
+    /// stretches the chosen pattern.
+    pub er_shape: f32,
+
+    /// Decay time below 250 Hz, as a multiple of the mid-band decay (0.5–2.0×).
+    ///
+    /// Above 1 the low end rings longer, like a large hall; below 1 it
+    /// tightens.
+    pub low_mult: f32,
+
+    /// Decay time above 4 kHz, as a multiple of the mid-band decay (0.25–1.0×).
+    ///
+    /// Models air absorption, on top of `damping_pct`. Capped at 1 so no band
+    /// can outlast the mid band and push the loop gain above unity.
+    pub high_mult: f32,
 }
 
 impl Default for ReverbParams {
@@ -273,6 +422,9 @@ impl Default for ReverbParams {
             width_pct: 100.0,
             er_level_pct: 50.0,
             output_db: 0.0,
+            er_shape: 0.0,
+            low_mult: 1.0,
+            high_mult: 1.0,
         }
     }
 }
@@ -282,6 +434,7 @@ impl ReverbParams {
     ///
     /// Curves (logarithmic for frequency/time, linear for percentage) are
     /// derived from [`ParamDescriptor`] — same mapping as GUI and plugin hosts.
+    /// The ER shape and decay multipliers keep their defaults.
     #[allow(clippy::too_many_arguments)]
     pub fn from_knobs(
         room: f32,
@@ -298,7 +451,7 @@ impl ReverbParams {
 }
 
 impl KernelParams for ReverbParams {
-    const COUNT: usize = 8;
+    const COUNT: usize = 11;
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
@@ -313,7 +466,8 @@ impl KernelParams for ReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1500), "rev_room_size"),
+                .with_id(ParamId(1500), "rev_room_size")
+                .with_description("Size of the simulated space."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -326,7 +480,8 @@ impl KernelParams for ReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1501), "rev_decay"),
+                .with_id(ParamId(1501), "rev_decay")
+                .with_description("Length of the reverb tail."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -339,15 +494,23 @@ impl KernelParams for ReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1502), "rev_damping"),
+                .with_id(ParamId(1502), "rev_damping")
+                .with_description("High-frequency absorption; higher values give a darker tail."),
             ),
             3 => Some(
-                ParamDescriptor::custom("Pre-Delay", "PreDly", 0.0, 100.0, 10.0)
+                ParamDescriptor::custom("Pre-Delay", "PreDly", 0.0, MAX_PREDELAY_MS, 10.0)
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(1.0)
-                    .with_id(ParamId(1503), "rev_predelay"),
+                    .with_id(ParamId(1503), "rev_predelay")
+                    .with_description(
+                        "Gap before the reverb starts, separating it from the dry signal.",
+                    ),
+            ),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(1504), "rev_mix")
+                    .with_description("Blend of dry and reverberated signal."),
             ),
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(1504), "rev_mix")),
             5 => Some(
                 ParamDescriptor {
                     name: "Stereo Width",
@@ -359,7 +522,8 @@ impl KernelParams for ReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1505), "rev_width"),
+                .with_id(ParamId(1505), "rev_width")
+                .with_description("Stereo width of the reverb, from mono (0%) to full stereo."),
             ),
             6 => Some(
                 ParamDescriptor {
@@ -372,11 +536,40 @@ impl KernelParams for ReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1508), "rev_er_level"),
+                .with_id(ParamId(1508), "rev_er_level")
+                .with_description("Level of the early reflections relative to the tail."),
             ),
             7 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1507), "rev_output"),
             ),
+            8 => Some(
+                ParamDescriptor::custom("ER Shape", "Shape", 0.0, 3.0, 0.0)
+                    .with_unit(ParamUnit::None)
+                    .with_step(1.0)
+                    .with_id(ParamId(1509), "rev_er_shape")
+                    .with_description(
+                        "Pattern of the early reflections, from a small room to a cathedral.",
+                    )
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(ER_SHAPE_LABELS)
+                    .with_group("Early Reflections"),
+            ),
+            9 => Some(
+                ParamDescriptor::custom("Low Decay", "Lo Mult", 0.5, 2.0, 1.0)
+                    .with_unit(ParamUnit::None)
+                    .with_step(0.05)
+                    .with_id(ParamId(1510), "rev_low_mult")
+                    .with_description("Decay time below 250 Hz relative to the mids.")
+                    .with_group("Band Decay"),
+            ),
+            10 => Some(
+                ParamDescriptor::custom("High Decay", "Hi Mult", 0.25, 1.0, 1.0)
+                    .with_unit(ParamUnit::None)
+                    .with_step(0.05)
+                    .with_id(ParamId(1511), "rev_high_mult")
+                    .with_description("Decay time above 4 kHz relative to the mids.")
+                    .with_group("Band Decay"),
+            ),
             _ => None,
         }
     }
@@ -391,6 +584,9 @@ impl KernelParams for ReverbParams {
             5 => SmoothingStyle::Standard, // width_pct — 10ms
             6 => SmoothingStyle::Standard, // er_level_pct — 10ms
             7 => SmoothingStyle::Standard, // output_db — 10ms
+            8 => SmoothingStyle::None, // er_shape — stepped, snap immediately
+            9 => SmoothingStyle::Slow, // low_mult — affects FDN band gains
+            10 => SmoothingStyle::Slow, // high_mult — affects FDN band gains
             _ => SmoothingStyle::Standard,
         }
     }
@@ -405,6 +601,9 @@ impl KernelParams for ReverbParams {
             5 => self.width_pct,
             6 => self.er_level_pct,
             7 => self.output_db,
+            8 => self.er_shape,
+            9 => self.low_mult,
+            10 => self.high_mult,
             _ => 0.0,
         }
     }
@@ -419,6 +618,9 @@ impl KernelParams for ReverbParams {
             5 => self.width_pct = value,
             6 => self.er_level_pct = value,
             7 => self.output_db = value,
+            8 => self.er_shape = value,
+            9 => self.low_mult = value,
+            10 => self.high_mult = value,
             _ => {}
         }
     }
@@ -441,46 +643,67 @@ impl KernelParams for ReverbParams {
 ///   metallic resonances.
 /// - **8 one-pole damping filters**: one per FDN line. Applied after Hadamard
 ///   mixing to simulate frequency-dependent air absorption.
+/// - **Band decay shelves** (two one-pole splits per FDN line, at 250 Hz and
+///   4 kHz): scale the loop gain below and above the crossovers so the low
+///   and high bands decay `low_mult` / `high_mult` times as long as the mids.
+///   Skipped while both multipliers are 1.
 /// - **ER tapped delay** (`InterpolatedDelay`): writes mono input; 14 taps read
 ///   back at room-size-scaled positions, distributed to L (even) and R (odd).
+///   The `er_shape` parameter picks one of four tap patterns.
 /// - **4 modulated allpass diffusers per channel** (L/R): smooth the late reverb
 ///   texture after the FDN by scattering energy across delay.
 /// - **Stereo predelay** (two `InterpolatedDelay` lines, L + R): delays the input
 ///   before entering both ER and FDN.
 ///
+/// Per-line state (LFO phases, damping and shelf filters) lives in [`F32x4`]
+/// lanes — FDN lines 0–3 and 4–7, and the four allpass stages of each
+/// channel — so the LFOs, filters and feedback gains run four lines at a
+/// time. Only the delay reads and writes, the Hadamard butterflies, and the
+/// serial allpass stages stay scalar.
+///
 /// ## Coefficient Caching
 ///
-/// `feedback`, `fdn_compensation`, and `er_room_scale` are recomputed only when
-/// `room_size_pct`, `decay_pct`, or `damping_pct` change beyond a small epsilon.
+/// `feedback`, `fdn_compensation`, `er_room_scale`, and the band shelf gains
+/// are recomputed only when `room_size_pct`, `decay_pct`, `damping_pct`, or a
+/// decay multiplier change beyond a small epsilon.
 /// This avoids redundant `powf` / `sqrtf` calls on every sample during normal play.
 pub struct ReverbKernel {
     // ── FDN: 8 delay lines with LFO modulation + feedback damping ────────
     /// Eight delay lines forming the feedback delay network.
     fdn_delays: [InterpolatedDelay; 8],
     /// One-pole lowpass filters applied per FDN line for HF damping.
-    fdn_damping: [OnePole; 8],
+    fdn_damping: [OnePole4; 2],
     /// Base read positions in samples (scaled from 44.1 kHz reference).
-    fdn_base_delays: [f32; 8],
+    fdn_base_delays: [F32x4; 2],
     /// Peak modulation depth in samples = `FDN_MOD_DEPTH_MS * 0.001 * sample_rate`.
     fdn_mod_depth: f32,
     /// Current LFO phase per FDN line (turns, 0.0–1.0).
-    fdn_phases: [f32; 8],
+    fdn_phases: [F32x4; 2],
     /// LFO phase increment per sample per FDN line (rate / sample_rate).
-    fdn_phase_incs: [f32; 8],
-    /// Hadamard-mixed feedback from the previous sample (one value per FDN line).
-    fdn_fb: [f32; 8],
+    fdn_phase_incs: [F32x4; 2],
+    /// Low-band splits for the `low_mult` shelf (one per FDN line).
+    fdn_low_split: [OnePole4; 2],
+    /// High-band splits for the `high_mult` shelf (one per FDN line).
+    fdn_high_split: [OnePole4; 2],
 
     // ── Early reflections ─────────────────────────────────────────────────
     /// Tapped delay line for early reflections (up to `MAX_ER_MS`).
     er_delay: InterpolatedDelay,
-    /// Base tap positions in samples (scaled to current sample rate).
-    er_base_taps: [f32; ER_TAP_COUNT],
+    /// Base tap positions in samples for every ER shape (scaled to current sample rate).
+    er_base_taps: [[f32; ER_TAP_COUNT]; ER_SHAPE_COUNT],
 
     // ── Allpass diffusion (stereo, modulated) ─────────────────────────────
-    /// Four modulated allpass sections for the left channel.
-    allpasses_l: [ModulatedAllpass; 4],
-    /// Four modulated allpass sections for the right channel (different delay times).
-    allpasses_r: [ModulatedAllpass; 4],
+    /// Four modulated allpass sections per channel (`[0]` left, `[1]` right,
+    /// with different delay times).
+    ap_delays: [[InterpolatedDelay; 4]; 2],
+    /// Base read position of each allpass section in samples.
+    ap_base_delays: [F32x4; 2],
+    /// Peak allpass modulation depth in samples.
+    ap_mod_depth: f32,
+    /// Current LFO phase of each allpass section (turns, 0.0–1.0).
+    ap_phases: [F32x4; 2],
+    /// LFO phase increment per sample, one rate per channel.
+    ap_phase_incs: [F32x4; 2],
 
     // ── Predelay (stereo) ─────────────────────────────────────────────────
     /// Left-channel predelay line (up to `MAX_PREDELAY_MS`).
@@ -504,6 +727,30 @@ pub struct ReverbKernel {
     fdn_compensation: f32,
     /// ER tap scaling: `0.5 + room × 1.5` (range 0.5 → 2.0).
     er_room_scale: f32,
+    /// Last low-band decay multiplier used to compute the shelf gains.
+    cached_low_mult: f32,
+    /// Last high-band decay multiplier used to compute the shelf gains.
+    cached_high_mult: f32,
+    /// Low shelf amount: `feedback^(1/low_mult − 1) − 1` (0 = flat).
+    low_shelf: f32,
+    /// High shelf amount: `feedback^(1/high_mult − 1) − 1` (0 = flat).
+    high_shelf: f32,
+}
+
+/// [`ReverbParams`] converted to the units the per-sample path uses.
+struct Frame {
+    /// Predelay in samples, clamped to the line capacity.
+    predelay: f32,
+    /// Wet/dry mix (0–1).
+    mix: f32,
+    /// Early-reflection level (0–1).
+    er_lvl: f32,
+    /// Stereo width (0–1).
+    width: f32,
+    /// Linear output gain.
+    output: f32,
+    /// Index into [`ER_PATTERNS`].
+    shape: usize,
 }
 
 impl ReverbKernel {
@@ -526,42 +773,24 @@ impl ReverbKernel {
         });
 
         let damping_hz = damping_to_hz(0.5); // default damping = 50%
-        let fdn_damping: [OnePole; 8] =
-            core::array::from_fn(|_| OnePole::new(sample_rate, damping_hz));
+        let fdn_damping = core::array::from_fn(|_| OnePole4::new(sample_rate, damping_hz));
 
-        let fdn_base_delays: [f32; 8] =
-            core::array::from_fn(|i| scale_to_rate(FDN_TUNINGS_44K[i], sample_rate) as f32);
+        let fdn_base_delays = lanes8(core::array::from_fn(|i| {
+            scale_to_rate(FDN_TUNINGS_44K[i], sample_rate) as f32
+        }));
 
-        let fdn_phases = [0.0f32; 8];
-        let fdn_phase_incs: [f32; 8] = core::array::from_fn(|i| FDN_MOD_RATES[i] / sample_rate);
+        let fdn_phases = [F32x4::ZERO; 2];
+        let fdn_phase_incs = lanes8(core::array::from_fn(|i| FDN_MOD_RATES[i] / sample_rate));
 
-        // Early reflections tapped delay (~80 ms max)
+        // Early reflections tapped delay (up to MAX_ER_MS)
         let er_max = (ceilf(MAX_ER_MS * 0.001 * sample_rate) as usize).max(1);
         let er_delay = InterpolatedDelay::new(er_max);
-        let er_base_taps: [f32; ER_TAP_COUNT] =
-            core::array::from_fn(|i| scale_to_rate(ER_TAP_POSITIONS_44K[i], sample_rate) as f32);
+        let er_base_taps = er_taps_for_rate(sample_rate);
 
         // Allpass diffusion (modulated, stereo)
-        let allpasses_l: [ModulatedAllpass; 4] = core::array::from_fn(|i| {
-            let base = scale_to_rate(ALLPASS_TUNINGS_44K[i], sample_rate) as f32;
-            ModulatedAllpass::new(
-                base,
-                ALLPASS_FEEDBACK,
-                0.7,
-                ALLPASS_MOD_DEPTH_MS,
-                sample_rate,
-            )
-        });
-        let allpasses_r: [ModulatedAllpass; 4] = core::array::from_fn(|i| {
-            let base = scale_to_rate(ALLPASS_TUNINGS_44K_R[i], sample_rate) as f32;
-            ModulatedAllpass::new(
-                base,
-                ALLPASS_FEEDBACK,
-                0.8,
-                ALLPASS_MOD_DEPTH_MS,
-                sample_rate,
-            )
-        });
+        let ap_mod_depth = ALLPASS_MOD_DEPTH_MS * 0.001 * sample_rate;
+        let (ap_delays, ap_base_delays) = allpass_lines(sample_rate, ap_mod_depth);
+        let ap_phase_incs = ALLPASS_MOD_RATES.map(|rate| F32x4::splat(rate / sample_rate));
 
         // Predelay (stereo, up to 100 ms)
         let max_predelay = (ceilf(MAX_PREDELAY_MS * 0.001 * sample_rate) as usize).max(1);
@@ -575,11 +804,15 @@ impl ReverbKernel {
             fdn_mod_depth: mod_depth,
             fdn_phases,
             fdn_phase_incs,
-            fdn_fb: [0.0; 8],
+            fdn_low_split: core::array::from_fn(|_| OnePole4::new(sample_rate, LOW_XOVER_HZ)),
+            fdn_high_split: core::array::from_fn(|_| OnePole4::new(sample_rate, HIGH_XOVER_HZ)),
             er_delay,
             er_base_taps,
-            allpasses_l,
-            allpasses_r,
+            ap_delays,
+            ap_base_delays,
+            ap_mod_depth,
+            ap_phases: [F32x4::ZERO; 2],
+            ap_phase_incs,
             predelay_l,
             predelay_r,
             sample_rate,
@@ -589,16 +822,22 @@ impl ReverbKernel {
             feedback: 0.0,
             fdn_compensation: 1.0,
             er_room_scale: 1.0,
+            cached_low_mult: 1.0,
+            cached_high_mult: 1.0,
+            low_shelf: 0.0,
+            high_shelf: 0.0,
         };
-        // Prime the coefficient cache with the default param values (room=0.5, decay=0.5, damp=0.5).
-        kernel.update_derived(0.5, 0.5, 0.5);
+        // Prime the coefficient cache with the default param values
+        // (room=0.5, decay=0.5, damp=0.5, flat band decay).
+        kernel.update_derived(0.5, 0.5, 0.5, 1.0, 1.0);
         kernel
     }
 
     // ── Internal processing helpers ───────────────────────────────────────
 
-    /// Recompute `feedback`, `fdn_compensation`, `er_room_scale`, and damping
-    /// cutoff when room/decay/damping parameters change by more than 0.001.
+    /// Recompute `feedback`, `fdn_compensation`, `er_room_scale`, damping
+    /// cutoff, and band shelf gains when room/decay/damping or a decay
+    /// multiplier changes by more than 0.001.
     ///
     /// Uses the Freeverb feedback formula:
     /// - `scaled_room = 0.28 + room × 0.7`  (range 0.28..0.98)
@@ -606,17 +845,26 @@ impl ReverbKernel {
     ///
     /// `fdn_compensation = sqrt(1 − feedback)` preserves wet-signal level.
     /// `er_room_scale = 0.5 + room × 1.5` scales ER tap positions (0.5 → 2.0).
+    ///
+    /// Decay time is proportional to `−1 / ln(gain)` per loop pass, so a band
+    /// that should ring `m` times as long needs gain `feedback^(1/m)`. The
+    /// shelves apply the ratio to the mid-band `feedback`:
+    /// `shelf = feedback^(1/m − 1) − 1`.
     #[inline]
-    fn update_derived(&mut self, room: f32, decay: f32, damp: f32) {
+    fn update_derived(&mut self, room: f32, decay: f32, damp: f32, low_mult: f32, high_mult: f32) {
         if (room - self.cached_room).abs() < 0.001
             && (decay - self.cached_decay).abs() < 0.001
             && (damp - self.cached_damp).abs() < 0.001
+            && (low_mult - self.cached_low_mult).abs() < 0.001
+            && (high_mult - self.cached_high_mult).abs() < 0.001
         {
             return;
         }
         self.cached_room = room;
         self.cached_decay = decay;
         self.cached_damp = damp;
+        self.cached_low_mult = low_mult;
+        self.cached_high_mult = high_mult;
 
         let scaled_room = 0.28 + room * 0.7;
         self.feedback = (scaled_room + decay * (0.98 - scaled_room)).clamp(0.0, 0.99);
@@ -625,8 +873,13 @@ impl ReverbKernel {
 
         let freq = damping_to_hz(damp);
         for filter in &mut self.fdn_damping {
-            filter.set_frequency(freq);
+            for lane in 0..4 {
+                filter.set_frequency(lane, freq);
+            }
         }
+
+        self.low_shelf = band_shelf(self.feedback, low_mult);
+        self.high_shelf = band_shelf(self.feedback, high_mult);
     }
 
     /// Process stereo predelay for one channel.
@@ -652,31 +905,46 @@ impl ReverbKernel {
     /// 3. Damp the mixed signals and write them back with the new input.
     /// 4. Advance each LFO phase.
     ///
+    /// The LFOs, filters and feedback gain run on lines 0–3 and 4–7 as two
+    /// [`F32x4`] halves.
+    ///
     /// Returns raw L (even delays averaged) and R (odd delays averaged) before
     /// `fdn_compensation` scaling.
     #[inline]
     fn process_fdn(&mut self, input: f32) -> (f32, f32) {
         let mut raw = [0.0f32; 8];
 
-        // 1. Read modulated outputs
-        for i in 0..8 {
-            let modulated =
-                self.fdn_base_delays[i] + self.fdn_mod_depth * fast_sin_turns(self.fdn_phases[i]);
-            raw[i] = self.fdn_delays[i].read(modulated);
+        // 1. Read modulated outputs, advance LFO phases
+        let depth = F32x4::splat(self.fdn_mod_depth);
+        for half in 0..2 {
+            let lfo = fast_sin_turns4(self.fdn_phases[half]);
+            let positions = (self.fdn_base_delays[half] + depth * lfo).to_array();
+            for (lane, position) in positions.into_iter().enumerate() {
+                raw[half * 4 + lane] = self.fdn_delays[half * 4 + lane].read(position);
+            }
+            self.fdn_phases[half] =
+                advance_phases(self.fdn_phases[half], self.fdn_phase_incs[half]);
         }
 
         // 2. Hadamard-mix raw outputs for the feedback path
         let mut mixed = raw;
         hadamard8(&mut mixed);
 
-        // 3. Damp, write back with feedback, advance LFO phases
-        for i in 0..8 {
-            let damped = self.fdn_damping[i].process(mixed[i]);
-            self.fdn_delays[i].write(flush_denormal(input + damped * self.feedback));
-
-            self.fdn_phases[i] += self.fdn_phase_incs[i];
-            if self.fdn_phases[i] >= 1.0 {
-                self.fdn_phases[i] -= 1.0;
+        // 3. Damp, shape band decay, write back with feedback
+        let band_decay = self.low_shelf != 0.0 || self.high_shelf != 0.0;
+        let input = F32x4::splat(input);
+        let feedback = F32x4::splat(self.feedback);
+        for (half, mixed) in lanes8(mixed).into_iter().enumerate() {
+            let mut damped = self.fdn_damping[half].process(mixed);
+            if band_decay {
+                let low = self.fdn_low_split[half].process(damped);
+                damped = damped + F32x4::splat(self.low_shelf) * low;
+                let high = damped - self.fdn_high_split[half].process(damped);
+                damped = damped + F32x4::splat(self.high_shelf) * high;
+            }
+            let writes = (input + damped * feedback).flush_denormal().to_array();
+            for (lane, value) in writes.into_iter().enumerate() {
+                self.fdn_delays[half * 4 + lane].write(value);
             }
         }
 
@@ -687,20 +955,106 @@ impl ReverbKernel {
         (fdn_l * self.fdn_compensation, fdn_r * self.fdn_compensation)
     }
 
+    /// Convert `params` to internal units and refresh the derived
+    /// coefficient caches. Called once per sample by `process_stereo` and
+    /// once per block by the block paths.
+    #[inline]
+    fn frame(&mut self, params: &ReverbParams) -> Frame {
+        let room = params.room_size_pct / 100.0;
+        let decay = params.decay_pct / 100.0;
+        let damp = params.damping_pct / 100.0;
+        self.update_derived(
+            room,
+            decay,
+            damp,
+            params.low_mult.clamp(0.5, 2.0),
+            params.high_mult.clamp(0.25, 1.0),
+        );
+        Frame {
+            predelay: (params.predelay_ms * 0.001 * self.sample_rate)
+                .clamp(0.0, MAX_PREDELAY_MS * 0.001 * self.sample_rate),
+            mix: params.mix_pct / 100.0,
+            er_lvl: params.er_level_pct / 100.0,
+            width: params.width_pct / 100.0,
+            output: fast_db_to_linear(params.output_db),
+            shape: ((params.er_shape + 0.5) as usize).min(ER_SHAPE_COUNT - 1),
+        }
+    }
+
+    /// Process one stereo sample with already-converted parameters.
+    #[inline]
+    fn process_frame(&mut self, left: f32, right: f32, frame: &Frame) -> (f32, f32) {
+        // ── Stereo predelay ──
+        let pre_l = Self::apply_predelay(&mut self.predelay_l, left, frame.predelay);
+        let pre_r = Self::apply_predelay(&mut self.predelay_r, right, frame.predelay);
+        let mono = (pre_l + pre_r) * 0.5;
+
+        // ── Early reflections ──
+        self.er_delay.write(mono);
+        let (er_l, er_r) = self.compute_er_stereo(frame.shape);
+
+        // ── FDN (mono in → stereo out) ──
+        let (fdn_l, fdn_r) = self.process_fdn(mono);
+
+        // ── Allpass diffusion (separate L/R chains) ──
+        let diff_l = self.process_allpass(0, fdn_l);
+        let diff_r = self.process_allpass(1, fdn_r);
+
+        // ── Combine ER + late reverb ──
+        let wet_l = diff_l + er_l * frame.er_lvl;
+        let wet_r = diff_r + er_r * frame.er_lvl;
+
+        // ── Stereo width: M/S encode-scale-decode ──
+        let mid = (wet_l + wet_r) * 0.5;
+        let side = (wet_l - wet_r) * 0.5;
+        let final_l = mid + side * frame.width;
+        let final_r = mid - side * frame.width;
+
+        // ── Wet/dry mix → output gain ──
+        let (out_l, out_r) = wet_dry_mix_stereo(left, right, final_l, final_r, frame.mix);
+        (out_l * frame.output, out_r * frame.output)
+    }
+
+    /// Run one channel (`0` = L, `1` = R) through its four modulated
+    /// Schroeder allpass sections: `output = −input + delayed`,
+    /// `write = input + g·delayed`.
+    ///
+    /// The four LFOs are evaluated together; the sections themselves run in
+    /// series.
+    #[inline]
+    fn process_allpass(&mut self, channel: usize, input: f32) -> f32 {
+        let lfo = fast_sin_turns4(self.ap_phases[channel]);
+        let positions =
+            (self.ap_base_delays[channel] + F32x4::splat(self.ap_mod_depth) * lfo).to_array();
+        self.ap_phases[channel] =
+            advance_phases(self.ap_phases[channel], self.ap_phase_incs[channel]);
+
+        let mut signal = input;
+        for (line, position) in self.ap_delays[channel].iter_mut().zip(positions) {
+            let delayed = line.read(position);
+            line.write(flush_denormal(signal + delayed * ALLPASS_FEEDBACK));
+            signal = delayed - signal;
+        }
+        signal
+    }
+
     /// Compute stereo early reflections from the ER tapped delay.
     ///
-    /// Even-indexed taps → L, odd-indexed taps → R. Tap positions are
-    /// scaled by `er_room_scale` so larger rooms produce wider ER spacing.
+    /// Even-indexed taps → L, odd-indexed taps → R. `shape` selects the
+    /// pattern in [`ER_PATTERNS`]; tap positions are scaled by
+    /// `er_room_scale` so larger rooms produce wider ER spacing.
     ///
     /// Returns `(er_left, er_right)`.
     #[inline]
-    fn compute_er_stereo(&self) -> (f32, f32) {
+    fn compute_er_stereo(&self, shape: usize) -> (f32, f32) {
+        let taps = &self.er_base_taps[shape];
+        let gains = &ER_PATTERNS[shape].gains;
         let mut er_l = 0.0f32;
         let mut er_r = 0.0f32;
         for i in 0..ER_TAP_COUNT {
-            let tap_pos = self.er_base_taps[i] * self.er_room_scale;
+            let tap_pos = taps[i] * self.er_room_scale;
             let sample = self.er_delay.read(tap_pos);
-            let gain = ER_TAP_GAINS[i];
+            let gain = gains[i];
             if i % 2 == 0 {
                 er_l += sample * gain;
             } else {
@@ -730,61 +1084,43 @@ impl DspKernel for ReverbKernel {
     /// 9. Wet/dry mix → output gain.
     #[inline]
     fn process_stereo(&mut self, left: f32, right: f32, params: &ReverbParams) -> (f32, f32) {
-        // ── Unit conversion ──
-        let room = params.room_size_pct / 100.0;
-        let decay = params.decay_pct / 100.0;
-        let damp = params.damping_pct / 100.0;
-        let predelay_samples = (params.predelay_ms * 0.001 * self.sample_rate)
-            .clamp(0.0, MAX_PREDELAY_MS * 0.001 * self.sample_rate);
-        let mix = params.mix_pct / 100.0;
-        let er_lvl = params.er_level_pct / 100.0;
-        let width = params.width_pct / 100.0;
-        let output = fast_db_to_linear(params.output_db);
-
-        // ── Update coefficient caches ──
-        self.update_derived(room, decay, damp);
-
-        // ── Stereo predelay ──
-        let pre_l = Self::apply_predelay(&mut self.predelay_l, left, predelay_samples);
-        let pre_r = Self::apply_predelay(&mut self.predelay_r, right, predelay_samples);
-        let mono = (pre_l + pre_r) * 0.5;
-
-        // ── Early reflections ──
-        self.er_delay.write(mono);
-        let (er_l, er_r) = self.compute_er_stereo();
-
-        // ── FDN (mono in → stereo out) ──
-        let (fdn_l, fdn_r) = self.process_fdn(mono);
+        let frame = self.frame(params);
+        self.process_frame(left, right, &frame)
+    }
 
-        // ── Allpass diffusion (separate L/R chains) ──
-        let mut diff_l = fdn_l;
-        for ap in &mut self.allpasses_l {
-            diff_l = ap.process(diff_l);
+    /// Mono block: steps 1–2 run once for the block.
+    fn process_block(&mut self, input: &[f32], output: &mut [f32], params: &ReverbParams) {
+        debug_assert_eq!(input.len(), output.len());
+        let frame = self.frame(params);
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = self.process_frame(*x, *x, &frame).0;
         }
-        let mut diff_r = fdn_r;
-        for ap in &mut self.allpasses_r {
-            diff_r = ap.process(diff_r);
-        }
-
-        // ── Combine ER + late reverb ──
-        let wet_l = diff_l + er_l * er_lvl;
-        let wet_r = diff_r + er_r * er_lvl;
-
-        // ── Stereo width: M/S encode-scale-decode ──
-        let mid = (wet_l + wet_r) * 0.5;
-        let side = (wet_l - wet_r) * 0.5;
-        let final_l = mid + side * width;
-        let final_r = mid - side * width;
+    }
 
-        // ── Wet/dry mix → output gain ──
-        let (out_l, out_r) = wet_dry_mix_stereo(left, right, final_l, final_r, mix);
-        (out_l * output, out_r * output)
+    /// Stereo block: steps 1–2 run once for the block.
+    fn process_block_stereo(
+        &mut self,
+        left_in: &[f32],
+        right_in: &[f32],
+        left_out: &mut [f32],
+        right_out: &mut [f32],
+        params: &ReverbParams,
+    ) {
+        debug_assert_eq!(left_in.len(), right_in.len());
+        debug_assert_eq!(left_in.len(), left_out.len());
+        debug_assert_eq!(left_out.len(), right_out.len());
+        let frame = self.frame(params);
+        for i in 0..left_in.len() {
+            let (l, r) = self.process_frame(left_in[i], right_in[i], &frame);
+            left_out[i] = l;
+            right_out[i] = r;
+        }
     }
 
     /// Reset all DSP state to silence.
     ///
-    /// Clears all delay lines, resets one-pole filter histories, zeroes LFO
-    /// phases and the FDN feedback buffer, and resets all allpass diffusers.
+    /// Clears all delay lines (FDN, early reflections, allpass diffusers and
+    /// predelay), resets one-pole filter histories, and zeroes LFO phases.
     /// The coefficient cache is invalidated so it will be recomputed on the
     /// next call to `process_stereo`.
     fn reset(&mut self) {
@@ -794,17 +1130,21 @@ impl DspKernel for ReverbKernel {
         for filter in &mut self.fdn_damping {
             filter.reset();
         }
-        self.fdn_phases = [0.0; 8];
-        self.fdn_fb = [0.0; 8];
+        for filter in self
+            .fdn_low_split
+            .iter_mut()
+            .chain(&mut self.fdn_high_split)
+        {
+            filter.reset();
+        }
+        self.fdn_phases = [F32x4::ZERO; 2];
 
         self.er_delay.clear();
 
-        for ap in &mut self.allpasses_l {
-            ap.reset();
-        }
-        for ap in &mut self.allpasses_r {
-            ap.reset();
+        for line in self.ap_delays.iter_mut().flatten() {
+            line.clear();
         }
+        self.ap_phases = [F32x4::ZERO; 2];
 
         self.predelay_l.clear();
         self.predelay_r.clear();
@@ -831,42 +1171,32 @@ impl DspKernel for ReverbKernel {
             delay.set_interpolation(Interpolation::Linear);
             delay
         });
-        self.fdn_base_delays =
-            core::array::from_fn(|i| scale_to_rate(FDN_TUNINGS_44K[i], sample_rate) as f32);
-        self.fdn_phase_incs = core::array::from_fn(|i| FDN_MOD_RATES[i] / sample_rate);
+        self.fdn_base_delays = lanes8(core::array::from_fn(|i| {
+            scale_to_rate(FDN_TUNINGS_44K[i], sample_rate) as f32
+        }));
+        self.fdn_phase_incs = lanes8(core::array::from_fn(|i| FDN_MOD_RATES[i] / sample_rate));
         for filter in &mut self.fdn_damping {
             filter.set_sample_rate(sample_rate);
         }
-        self.fdn_phases = [0.0; 8];
-        self.fdn_fb = [0.0; 8];
+        for filter in self
+            .fdn_low_split
+            .iter_mut()
+            .chain(&mut self.fdn_high_split)
+        {
+            filter.set_sample_rate(sample_rate);
+        }
+        self.fdn_phases = [F32x4::ZERO; 2];
 
         // Recreate ER delay
         let er_max = (ceilf(MAX_ER_MS * 0.001 * sample_rate) as usize).max(1);
         self.er_delay = InterpolatedDelay::new(er_max);
-        self.er_base_taps =
-            core::array::from_fn(|i| scale_to_rate(ER_TAP_POSITIONS_44K[i], sample_rate) as f32);
+        self.er_base_taps = er_taps_for_rate(sample_rate);
 
         // Recreate allpass diffusion
-        self.allpasses_l = core::array::from_fn(|i| {
-            let base = scale_to_rate(ALLPASS_TUNINGS_44K[i], sample_rate) as f32;
-            ModulatedAllpass::new(
-                base,
-                ALLPASS_FEEDBACK,
-                0.7,
-                ALLPASS_MOD_DEPTH_MS,
-                sample_rate,
-            )
-        });
-        self.allpasses_r = core::array::from_fn(|i| {
-            let base = scale_to_rate(ALLPASS_TUNINGS_44K_R[i], sample_rate) as f32;
-            ModulatedAllpass::new(
-                base,
-                ALLPASS_FEEDBACK,
-                0.8,
-                ALLPASS_MOD_DEPTH_MS,
-                sample_rate,
-            )
-        });
+        self.ap_mod_depth = ALLPASS_MOD_DEPTH_MS * 0.001 * sample_rate;
+        (self.ap_delays, self.ap_base_delays) = allpass_lines(sample_rate, self.ap_mod_depth);
+        self.ap_phase_incs = ALLPASS_MOD_RATES.map(|rate| F32x4::splat(rate / sample_rate));
+        self.ap_phases = [F32x4::ZERO; 2];
 
         // Recreate predelay
         let max_predelay = (ceilf(MAX_PREDELAY_MS * 0.001 * sample_rate) as usize).max(1);
@@ -910,7 +1240,7 @@ impl DspKernel for ReverbKernel {
 mod tests {
     use super::*;
     use sonido_core::kernel::Adapter;
-    use sonido_core::{Effect, ParameterInfo};
+    use sonido_core::{Effect, OnePole, ParameterInfo};
 
     // ── Basic correctness ────────────────────────────────────────────────────
 
@@ -941,6 +1271,7 @@ mod tests {
             width_pct: 100.0,
             er_level_pct: 80.0,
             output_db: 0.0,
+            ..ReverbParams::default()
         };
 
         for i in 0..2000 {
@@ -952,10 +1283,10 @@ mod tests {
         }
     }
 
-    /// `ReverbParams::COUNT` must equal 8, and all indices must have descriptors.
+    /// `ReverbParams::COUNT` must equal 11, and all indices must have descriptors.
     #[test]
     fn params_descriptor_count() {
-        assert_eq!(ReverbParams::COUNT, 8, "Expected 8 parameters");
+        assert_eq!(ReverbParams::COUNT, 11, "Expected 11 parameters");
 
         for i in 0..ReverbParams::COUNT {
             assert!(
@@ -1000,6 +1331,9 @@ mod tests {
         assert_eq!(adapter.param_info(5).unwrap().id, ParamId(1505)); // width
         assert_eq!(adapter.param_info(6).unwrap().id, ParamId(1508)); // er_level (non-sequential!)
         assert_eq!(adapter.param_info(7).unwrap().id, ParamId(1507)); // output (non-sequential!)
+        assert_eq!(adapter.param_info(8).unwrap().id, ParamId(1509)); // er_shape
+        assert_eq!(adapter.param_info(9).unwrap().id, ParamId(1510)); // low_mult
+        assert_eq!(adapter.param_info(10).unwrap().id, ParamId(1511)); // high_mult
     }
 
     /// Morphing between two param states must always produce finite output.
@@ -1016,6 +1350,9 @@ mod tests {
             width_pct: 100.0,
             er_level_pct: 100.0,
             output_db: -6.0,
+            er_shape: 3.0,
+            low_mult: 2.0,
+            high_mult: 0.25,
         };
 
         for i in 0..=10 {
@@ -1052,8 +1389,8 @@ mod tests {
             max.damping_pct
         );
         assert!(
-            (max.predelay_ms - 100.0).abs() < 0.01,
-            "Predelay at 1.0 should be 100 ms, got {}",
+            (max.predelay_ms - 200.0).abs() < 0.01,
+            "Predelay at 1.0 should be 200 ms, got {}",
             max.predelay_ms
         );
         assert!(
@@ -1123,10 +1460,12 @@ mod tests {
             mid.room_size_pct
         );
         assert!(
-            (mid.predelay_ms - 50.0).abs() < 0.5,
-            "Predelay at 0.5 should be 50 ms, got {}",
+            (mid.predelay_ms - 100.0).abs() < 0.5,
+            "Predelay at 0.5 should be 100 ms, got {}",
             mid.predelay_ms
         );
+        assert_eq!(mid.er_shape, 0.0, "from_knobs leaves the ER shape at Room");
+        assert_eq!(mid.low_mult, 1.0, "from_knobs leaves low_mult at 1.0");
         assert!(
             mid.output_db.abs() < 0.1,
             "Output at 0.5 should be 0 dB, got {}",
@@ -1150,6 +1489,7 @@ mod tests {
             width_pct: 100.0,
             er_level_pct: 50.0,
             output_db: 0.0,
+            ..ReverbParams::default()
         };
 
         // Impulse
@@ -1172,6 +1512,135 @@ mod tests {
         );
     }
 
+    /// Render the wet impulse response of one channel.
+    fn impulse_response(params: &ReverbParams, len: usize) -> Vec<f32> {
+        let mut kernel = ReverbKernel::new(48000.0);
+        (0..len)
+            .map(|i| {
+                let x = if i == 0 { 1.0 } else { 0.0 };
+                kernel.process_stereo(x, x, params).0
+            })
+            .collect()
+    }
+
+    /// Each ER shape must place its first reflection at a different time.
+    #[test]
+    fn er_shapes_move_first_reflection() {
+        let onset = |shape: f32| {
+            let params = ReverbParams {
+                predelay_ms: 0.0,
+                mix_pct: 100.0,
+                er_shape: shape,
+                ..ReverbParams::default()
+            };
+            impulse_response(&params, 4800)
+                .iter()
+                .position(|x| x.abs() > 1e-4)
+                .expect("ER should produce output")
+        };
+        let onsets: Vec<usize> = (0..ER_SHAPE_COUNT).map(|s| onset(s as f32)).collect();
+        // Chamber < Room < Hall < Cathedral
+        assert!(
+            onsets[1] < onsets[0] && onsets[0] < onsets[2] && onsets[2] < onsets[3],
+            "unexpected first reflections per shape: {onsets:?}"
+        );
+    }
+
+    /// Pre-delay must hold the wet signal back for up to 200 ms.
+    #[test]
+    fn predelay_reaches_200_ms() {
+        let params = ReverbParams {
+            predelay_ms: 200.0,
+            mix_pct: 100.0,
+            ..ReverbParams::default()
+        };
+        let ir = impulse_response(&params, 14400);
+        let onset = ir.iter().position(|x| x.abs() > 1e-4).unwrap();
+        assert!(
+            onset >= 9600,
+            "wet onset at {onset} samples, expected ≥ 200 ms (9600)"
+        );
+    }
+
+    /// Energy of `signal` after a one-pole split at `hz`: low band if `low`.
+    fn band_energy(signal: &[f32], hz: f32, low: bool) -> f32 {
+        let mut lp = OnePole::new(48000.0, hz);
+        signal
+            .iter()
+            .map(|&x| {
+                let l = lp.process(x);
+                let band = if low { l } else { x - l };
+                band * band
+            })
+            .sum()
+    }
+
+    /// `low_mult` above 1 must leave more bass in the late tail.
+    #[test]
+    fn low_mult_lengthens_bass_decay() {
+        let late_bass = |low_mult: f32| {
+            let params = ReverbParams {
+                predelay_ms: 0.0,
+                mix_pct: 100.0,
+                er_level_pct: 0.0,
+                damping_pct: 0.0,
+                low_mult,
+                ..ReverbParams::default()
+            };
+            let ir = impulse_response(&params, 48000);
+            band_energy(&ir[24000..], 150.0, true)
+        };
+        let flat = late_bass(1.0);
+        let long = late_bass(2.0);
+        assert!(
+            long > flat * 2.0,
+            "low_mult 2.0 should boost late bass energy: flat {flat}, long {long}"
+        );
+    }
+
+    /// `high_mult` below 1 must take treble out of the late tail.
+    #[test]
+    fn high_mult_shortens_treble_decay() {
+        let late_treble = |high_mult: f32| {
+            let params = ReverbParams {
+                predelay_ms: 0.0,
+                mix_pct: 100.0,
+                er_level_pct: 0.0,
+                damping_pct: 0.0,
+                high_mult,
+                ..ReverbParams::default()
+            };
+            let ir = impulse_response(&params, 24000);
+            band_energy(&ir[12000..], 6000.0, false)
+        };
+        let flat = late_treble(1.0);
+        let short = late_treble(0.25);
+        assert!(
+            short < flat * 0.5,
+            "high_mult 0.25 should cut late treble energy: flat {flat}, short {short}"
+        );
+    }
+
+    /// Extreme band multipliers at maximum decay must stay bounded.
+    #[test]
+    fn band_decay_stays_stable() {
+        let params = ReverbParams {
+            room_size_pct: 100.0,
+            decay_pct: 100.0,
+            damping_pct: 0.0,
+            mix_pct: 100.0,
+            low_mult: 2.0,
+            high_mult: 1.0,
+            ..ReverbParams::default()
+        };
+        let ir = impulse_response(&params, 96000);
+        let peak_late = ir[48000..].iter().fold(0.0f32, |m, x| m.max(x.abs()));
+        assert!(
+            peak_late.is_finite() && peak_late < 1.0,
+            "late peak {peak_late}"
+        );
+    }
+
     /// Applying the Hadamard transform twice must return the original vector (involutory).
     ///
     /// The normalized 8×8 Hadamard satisfies (H₈/√8)² = H₈²/8 = 8I/8 = I, meaning
@@ -135,21 +135,29 @@ impl KernelParams for RingModParams {
                 ParamDescriptor::custom("Frequency", "Freq", 20.0, 2000.0, 220.0)
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic)
-                    .with_id(ParamId(1800), "ring_freq"),
+                    .with_id(ParamId(1800), "ring_freq")
+                    .with_description("Carrier frequency multiplied with the input."),
             ),
             1 => Some(
                 // Matches classic: ParamDescriptor::depth() with id 1801.
                 // depth() factory: custom "Depth"/"Depth", 0–100 %, default 100.0
-                ParamDescriptor::depth().with_id(ParamId(1801), "ring_depth"),
+                ParamDescriptor::depth()
+                    .with_id(ParamId(1801), "ring_depth")
+                    .with_description("Amount of ring modulation; 0% leaves the input unchanged."),
             ),
             2 => Some(
                 ParamDescriptor::custom("Waveform", "Wave", 0.0, 2.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1802), "ring_wave")
+                    .with_description("Carrier waveform.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Sine", "Triangle", "Square"]),
             ),
-            3 => Some(ParamDescriptor::mix().with_id(ParamId(1803), "ring_mix")),
+            3 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(1803), "ring_mix")
+                    .with_description("Blend of dry and modulated signal."),
+            ),
             4 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1804), "ring_output"),
             ),
@@ -123,7 +123,7 @@ fn low_shelf_coefficients(
 /// arrangement.
 ///
 /// Reference: Bristow-Johnson, "Audio EQ Cookbook", 1994.
-fn high_shelf_coefficients(
+pub(crate) fn high_shelf_coefficients(
     frequency: f32,
     gain_db: f32,
     sample_rate: f32,
@@ -239,11 +239,13 @@ impl KernelParams for ShelvingEqParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(2500), "shelf_low_freq")
+                .with_description("Corner frequency of the low shelf.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             1 => Some(
                 ParamDescriptor::gain_db("Low Gain", "LowGn", -15.0, 15.0, 0.0)
-                    .with_id(ParamId(2501), "shelf_low_gain"),
+                    .with_id(ParamId(2501), "shelf_low_gain")
+                    .with_description("Boost or cut below the low corner."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -257,11 +259,13 @@ impl KernelParams for ShelvingEqParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(2502), "shelf_high_freq")
+                .with_description("Corner frequency of the high shelf.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             3 => Some(
                 ParamDescriptor::gain_db("High Gain", "HiGn", -15.0, 15.0, 0.0)
-                    .with_id(ParamId(2503), "shelf_high_gain"),
+                    .with_id(ParamId(2503), "shelf_high_gain")
+                    .with_description("Boost or cut above the high corner."),
             ),
             4 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(2504), "shelf_output"),
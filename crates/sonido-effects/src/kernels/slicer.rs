@@ -0,0 +1,703 @@
+//! Slicer kernel — tempo-synced rhythmic gate driven by a 16-step pattern.
+//!
+//! `SlicerKernel` chops the input with a step sequence of gate levels. Each
+//! step is Off, On, or Accent; the step clock runs at the selected note
+//! division of the host tempo. A one-pole envelope with separate attack and
+//! release times shapes every transition, so slices can be anything from
+//! hard-edged stutters to soft pumping swells.
+//!
+//! # Step Levels
+//!
+//! | Value | Name | Gain |
+//! |-------|------|------|
+//! | 0 | Off | `1 − depth` (silence at 100 % depth) |
+//! | 1 | On | unity |
+//! | 2 | Accent | unity + `accent` dB |
+//!
+//! # Timing
+//!
+//! The pattern plays `steps` steps (1–16) and then wraps. Each step lasts one
+//! `division` at the current BPM. While the host transport is playing the
+//! step position follows the host's beat position, so the pattern lines up
+//! with bars; when stopped it free-runs at the last known tempo.
+//!
+//! `shuffle` delays every second step by up to half a step (at 100 %), the
+//! classic swing feel. The delayed step is shortened by the same amount so
+//! each pair of steps keeps its total length.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! input → × gate envelope (attack/release one-pole) → wet/dry mix → output gain
+//!                 ↑
+//!   step clock (BPM × division, shuffle) → pattern lookup → target level
+//! ```
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(SlicerKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing)
+//! let mut kernel = SlicerKernel::new(48000.0);
+//! let params = SlicerParams::default();
+//! let (left, right) = kernel.process_stereo(input_l, input_r, &params);
+//! ```
+
+use libm::{expf, fabs, fmod};
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    DIVISION_LABELS, ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit, TempoContext,
+    fast_db_to_linear, index_to_division, wet_dry_mix,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Constants
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Number of steps in the pattern.
+pub const SLICER_STEPS: usize = 16;
+
+/// Step level: gate closed.
+pub const STEP_OFF: f32 = 0.0;
+/// Step level: gate open at unity.
+pub const STEP_ON: f32 = 1.0;
+/// Step level: gate open with the accent boost.
+pub const STEP_ACCENT: f32 = 2.0;
+
+/// Labels for the three step levels, indexed by value.
+pub const STEP_LABELS: &[&str] = &["Off", "On", "Accent"];
+
+/// Host beat positions closer than this (in steps) to the internal clock
+/// are treated as already in sync, so block-rate updates don't jitter.
+const SYNC_TOLERANCE: f64 = 0.01;
+
+/// Default pattern: accent on each beat, On on the off-beat eighth.
+const DEFAULT_PATTERN: [f32; SLICER_STEPS] = [
+    STEP_ACCENT,
+    STEP_OFF,
+    STEP_ON,
+    STEP_OFF,
+    STEP_ACCENT,
+    STEP_OFF,
+    STEP_ON,
+    STEP_OFF,
+    STEP_ACCENT,
+    STEP_OFF,
+    STEP_ON,
+    STEP_OFF,
+    STEP_ACCENT,
+    STEP_OFF,
+    STEP_ON,
+    STEP_OFF,
+];
+
+const STEP_NAMES: [&str; SLICER_STEPS] = [
+    "Step 1", "Step 2", "Step 3", "Step 4", "Step 5", "Step 6", "Step 7", "Step 8", "Step 9",
+    "Step 10", "Step 11", "Step 12", "Step 13", "Step 14", "Step 15", "Step 16",
+];
+
+const STEP_SHORT_NAMES: [&str; SLICER_STEPS] = [
+    "S1", "S2", "S3", "S4", "S5", "S6", "S7", "S8", "S9", "S10", "S11", "S12", "S13", "S14", "S15",
+    "S16",
+];
+
+const STEP_STRING_IDS: [&str; SLICER_STEPS] = [
+    "slc_step_1",
+    "slc_step_2",
+    "slc_step_3",
+    "slc_step_4",
+    "slc_step_5",
+    "slc_step_6",
+    "slc_step_7",
+    "slc_step_8",
+    "slc_step_9",
+    "slc_step_10",
+    "slc_step_11",
+    "slc_step_12",
+    "slc_step_13",
+    "slc_step_14",
+    "slc_step_15",
+    "slc_step_16",
+];
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`SlicerKernel`].
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0–15 | `steps[i]` | index | 0–2 (Off/On/Accent, STEPPED) | A-0-1-0 × 4 |
+/// | 16 | `length` | steps | 1–16 (STEPPED) | 16 |
+/// | 17 | `division` | index | 0–11 (STEPPED) | 4 (Sixteenth) |
+/// | 18 | `shuffle_pct` | % | 0–100 | 0.0 |
+/// | 19 | `attack_ms` | ms | 0.1–50 | 2.0 |
+/// | 20 | `release_ms` | ms | 1–500 | 20.0 |
+/// | 21 | `depth_pct` | % | 0–100 | 100.0 |
+/// | 22 | `accent_db` | dB | 0–12 | 3.0 |
+/// | 23 | `mix_pct` | % | 0–100 | 100.0 |
+/// | 24 | `position` | step | 0–15 (READ_ONLY) | 0.0 |
+/// | 25 | `output_db` | dB | −20–+6 | 0.0 |
+#[derive(Debug, Clone, Copy)]
+pub struct SlicerParams {
+    /// Step levels (STEPPED). 0=Off, 1=On, 2=Accent.
+    pub steps: [f32; SLICER_STEPS],
+    /// Number of steps before the pattern wraps. Range: 1 to 16.
+    pub length: f32,
+    /// Step length as a note division index (0–11, see `DIVISION_LABELS`).
+    pub division: f32,
+    /// Swing amount in percent. Range: 0.0 to 100.0 %.
+    ///
+    /// Delays every second step by up to half a step.
+    pub shuffle_pct: f32,
+    /// Gate opening time in milliseconds. Range: 0.1 to 50.0 ms.
+    pub attack_ms: f32,
+    /// Gate closing time in milliseconds. Range: 1.0 to 500.0 ms.
+    pub release_ms: f32,
+    /// How far Off steps close the gate, in percent. Range: 0.0 to 100.0 %.
+    pub depth_pct: f32,
+    /// Extra gain on Accent steps in decibels. Range: 0.0 to 12.0 dB.
+    pub accent_db: f32,
+    /// Wet/dry mix in percent. Range: 0.0 to 100.0 %.
+    pub mix_pct: f32,
+    /// Current step index (READ_ONLY diagnostic, written by the kernel).
+    pub position: f32,
+    /// Output level in decibels. Range: −20.0 to +6.0 dB.
+    pub output_db: f32,
+}
+
+impl Default for SlicerParams {
+    fn default() -> Self {
+        Self {
+            steps: DEFAULT_PATTERN,
+            length: SLICER_STEPS as f32,
+            division: 4.0,
+            shuffle_pct: 0.0,
+            attack_ms: 2.0,
+            release_ms: 20.0,
+            depth_pct: 100.0,
+            accent_db: 3.0,
+            mix_pct: 100.0,
+            position: 0.0,
+            output_db: 0.0,
+        }
+    }
+}
+
+impl KernelParams for SlicerParams {
+    const COUNT: usize = SLICER_STEPS + 10;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            i if i < SLICER_STEPS => Some(
+                ParamDescriptor::custom(
+                    STEP_NAMES[i],
+                    STEP_SHORT_NAMES[i],
+                    0.0,
+                    2.0,
+                    DEFAULT_PATTERN[i],
+                )
+                .with_unit(ParamUnit::None)
+                .with_step(1.0)
+                .with_id(ParamId(3700 + i as u32), STEP_STRING_IDS[i])
+                .with_group("Pattern")
+                .with_description("Step level: Off closes the gate, On opens it, Accent opens it with extra gain.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(STEP_LABELS),
+            ),
+            16 => Some(
+                ParamDescriptor::custom("Steps", "Steps", 1.0, 16.0, 16.0)
+                    .with_unit(ParamUnit::None)
+                    .with_step(1.0)
+                    .with_id(ParamId(3716), "slc_length")
+                    .with_group("Pattern")
+                    .with_description("Number of steps before the pattern repeats.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
+            ),
+            17 => Some(
+                ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 4.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(3717), "slc_division")
+                    .with_description("Note length of one step.")
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(DIVISION_LABELS),
+            ),
+            18 => Some(
+                ParamDescriptor {
+                    name: "Shuffle",
+                    short_name: "Shufl",
+                    default: 0.0,
+                    ..ParamDescriptor::mix()
+                }
+                .with_id(ParamId(3718), "slc_shuffle")
+                .with_description("Swing: delays every second step by up to half a step."),
+            ),
+            19 => Some(
+                ParamDescriptor::custom("Attack", "Attack", 0.1, 50.0, 2.0)
+                    .with_unit(ParamUnit::Milliseconds)
+                    .with_step(0.1)
+                    .with_scale(ParamScale::Power(2.0))
+                    .with_id(ParamId(3719), "slc_attack")
+                    .with_group("Gate Shape")
+                    .with_description("How fast the gate opens at each step."),
+            ),
+            20 => Some(
+                ParamDescriptor::time_ms("Release", "Release", 1.0, 500.0, 20.0)
+                    .with_scale(ParamScale::Power(2.0))
+                    .with_id(ParamId(3720), "slc_release")
+                    .with_group("Gate Shape")
+                    .with_description("How fast the gate closes at each step."),
+            ),
+            21 => Some(
+                ParamDescriptor {
+                    default: 100.0,
+                    ..ParamDescriptor::depth()
+                }
+                .with_id(ParamId(3721), "slc_depth")
+                .with_group("Gate Shape")
+                .with_description("How far Off steps close the gate."),
+            ),
+            22 => Some(
+                ParamDescriptor::gain_db("Accent", "Accent", 0.0, 12.0, 3.0)
+                    .with_id(ParamId(3722), "slc_accent")
+                    .with_group("Gate Shape")
+                    .with_description("Extra gain on Accent steps."),
+            ),
+            23 => Some(ParamDescriptor::mix().with_id(ParamId(3723), "slc_mix")
+            .with_description("Blend of dry and sliced signal.")),
+            24 => Some(
+                ParamDescriptor::custom("Position", "Pos", 0.0, 15.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(3724), "slc_position")
+                    .with_description("Step currently playing (read-only).")
+                    .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
+            ),
+            25 => Some(
+                sonido_core::gain::output_param_descriptor().with_id(ParamId(3725), "slc_output"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            i if i < SLICER_STEPS => SmoothingStyle::None, // steps — stepped
+            16 => SmoothingStyle::None,                    // length — stepped
+            17 => SmoothingStyle::None,                    // division — stepped
+            18 => SmoothingStyle::Standard,                // shuffle — 10 ms
+            19 => SmoothingStyle::Standard,                // attack — 10 ms
+            20 => SmoothingStyle::Standard,                // release — 10 ms
+            21 => SmoothingStyle::Standard,                // depth — 10 ms
+            22 => SmoothingStyle::Standard,                // accent — 10 ms
+            23 => SmoothingStyle::Standard,                // mix — 10 ms
+            25 => SmoothingStyle::Fast,                    // output — 5 ms
+            _ => SmoothingStyle::None,                     // position — diagnostic
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            i if i < SLICER_STEPS => self.steps[i],
+            16 => self.length,
+            17 => self.division,
+            18 => self.shuffle_pct,
+            19 => self.attack_ms,
+            20 => self.release_ms,
+            21 => self.depth_pct,
+            22 => self.accent_db,
+            23 => self.mix_pct,
+            24 => self.position,
+            25 => self.output_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            i if i < SLICER_STEPS => self.steps[i] = value,
+            16 => self.length = value,
+            17 => self.division = value,
+            18 => self.shuffle_pct = value,
+            19 => self.attack_ms = value,
+            20 => self.release_ms = value,
+            21 => self.depth_pct = value,
+            22 => self.accent_db = value,
+            23 => self.mix_pct = value,
+            24 => self.position = value,
+            25 => self.output_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP rhythmic gate kernel.
+///
+/// # Invariants
+///
+/// - `step_pos` is always in `[0, length)` for the most recent `length`.
+/// - `env` stays within `[0, accent gain]`.
+pub struct SlicerKernel {
+    /// Position in the pattern, in steps (fractional).
+    step_pos: f64,
+    /// Host beat position to align to on the next sample, if playing.
+    pending_beat: Option<f64>,
+    /// Current tempo in BPM.
+    bpm: f32,
+    /// Gate envelope level.
+    env: f32,
+    /// Step index at the last processed sample (for diagnostics).
+    current_step: usize,
+    /// Attack time the cached coefficient was computed for.
+    attack_ms: f32,
+    /// Release time the cached coefficient was computed for.
+    release_ms: f32,
+    /// One-pole coefficient for rising envelope.
+    attack_coeff: f32,
+    /// One-pole coefficient for falling envelope.
+    release_coeff: f32,
+    /// Audio sample rate in Hz.
+    sample_rate: f32,
+}
+
+impl SlicerKernel {
+    /// Create a new slicer kernel at the given sample rate.
+    ///
+    /// The step clock starts at 120 BPM until a tempo context arrives.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            step_pos: 0.0,
+            pending_beat: None,
+            bpm: 120.0,
+            env: 0.0,
+            current_step: 0,
+            attack_ms: 0.0,
+            release_ms: 0.0,
+            attack_coeff: 0.0,
+            release_coeff: 0.0,
+            sample_rate,
+        }
+    }
+
+    /// Current step index (0-based).
+    pub fn current_step(&self) -> usize {
+        self.current_step
+    }
+
+    /// One-pole coefficient for a time constant in milliseconds.
+    #[inline]
+    fn coeff(ms: f32, sample_rate: f32) -> f32 {
+        expf(-1.0 / (ms.max(0.01) * 0.001 * sample_rate))
+    }
+
+    /// Wrap a step position into `[0, length)`.
+    #[inline]
+    fn wrap(pos: f64, length: usize) -> f64 {
+        let wrapped = fmod(pos, length as f64);
+        if wrapped < 0.0 {
+            wrapped + length as f64
+        } else {
+            wrapped
+        }
+    }
+
+    /// Step index for a fractional pattern position with shuffle applied.
+    ///
+    /// Steps come in pairs; the second step of each pair starts
+    /// `shuffle × 0.5` steps late.
+    #[inline]
+    fn step_at(pos: f64, shuffle: f32, length: usize) -> usize {
+        let pair = (pos * 0.5) as usize;
+        let within = pos - (pair * 2) as f64;
+        let swing_point = 1.0 + f64::from(shuffle.clamp(0.0, 1.0)) * 0.5;
+        let step = pair * 2 + usize::from(within >= swing_point);
+        step.min(length - 1)
+    }
+}
+
+impl DspKernel for SlicerKernel {
+    type Params = SlicerParams;
+
+    fn process_stereo(&mut self, left: f32, right: f32, params: &SlicerParams) -> (f32, f32) {
+        let length = (params.length + 0.5).clamp(1.0, SLICER_STEPS as f32) as usize;
+        let beats_per_step = f64::from(index_to_division(params.division as u8).beats());
+
+        // ── Step clock ────────────────────────────────────────────────────
+        if let Some(beat) = self.pending_beat.take() {
+            let target = Self::wrap(beat / beats_per_step, length);
+            let mut diff = fabs(target - self.step_pos);
+            diff = diff.min(length as f64 - diff);
+            if diff > SYNC_TOLERANCE {
+                self.step_pos = target;
+            }
+        }
+        if self.step_pos >= length as f64 {
+            self.step_pos = Self::wrap(self.step_pos, length);
+        }
+
+        let step = Self::step_at(self.step_pos, params.shuffle_pct / 100.0, length);
+        self.current_step = step;
+
+        let increment = f64::from(self.bpm) / 60.0 / beats_per_step / f64::from(self.sample_rate);
+        self.step_pos += increment;
+        if self.step_pos >= length as f64 {
+            self.step_pos -= length as f64;
+        }
+
+        // ── Gate envelope ─────────────────────────────────────────────────
+        let target = match (params.steps[step] + 0.5) as u8 {
+            0 => 1.0 - params.depth_pct / 100.0,
+            1 => 1.0,
+            _ => fast_db_to_linear(params.accent_db),
+        };
+
+        if params.attack_ms != self.attack_ms {
+            self.attack_ms = params.attack_ms;
+            self.attack_coeff = Self::coeff(params.attack_ms, self.sample_rate);
+        }
+        if params.release_ms != self.release_ms {
+            self.release_ms = params.release_ms;
+            self.release_coeff = Self::coeff(params.release_ms, self.sample_rate);
+        }
+        let coeff = if target > self.env {
+            self.attack_coeff
+        } else {
+            self.release_coeff
+        };
+        self.env = target + coeff * (self.env - target);
+
+        // ── Wet/dry mix and output gain ───────────────────────────────────
+        let mix = params.mix_pct / 100.0;
+        let output_gain = fast_db_to_linear(params.output_db);
+
+        let out_l = wet_dry_mix(left, left * self.env, mix) * output_gain;
+        let out_r = wet_dry_mix(right, right * self.env, mix) * output_gain;
+
+        (out_l, out_r)
+    }
+
+    fn set_tempo_context(&mut self, ctx: &TempoContext) {
+        self.bpm = ctx.bpm.max(1.0);
+        self.pending_beat = ctx.is_playing.then_some(f64::from(ctx.beat_position));
+    }
+
+    fn reset(&mut self) {
+        self.step_pos = 0.0;
+        self.pending_beat = None;
+        self.env = 0.0;
+        self.current_step = 0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        // Force coefficient recompute at the new rate
+        self.attack_ms = 0.0;
+        self.release_ms = 0.0;
+    }
+
+    fn update_diagnostics(&self, params: &mut SlicerParams) {
+        params.position = self.current_step as f32;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::kernel::Adapter;
+    use sonido_core::{Effect, ParameterInfo};
+
+    /// Samples per sixteenth note at 120 BPM, 48 kHz.
+    const SIXTEENTH: usize = 6000;
+
+    /// Params with hard edges so step levels are easy to read.
+    fn hard_params(steps: [f32; SLICER_STEPS]) -> SlicerParams {
+        SlicerParams {
+            steps,
+            attack_ms: 0.1,
+            release_ms: 1.0,
+            accent_db: 6.0,
+            ..SlicerParams::default()
+        }
+    }
+
+    /// Gain at the end of each of the first `count` steps for a DC input.
+    fn step_gains(kernel: &mut SlicerKernel, params: &SlicerParams, count: usize) -> Vec<f32> {
+        (0..count)
+            .map(|_| {
+                let mut out = 0.0;
+                for _ in 0..SIXTEENTH {
+                    out = kernel.process_stereo(1.0, 1.0, params).0;
+                }
+                out
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pattern_gates_signal() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let mut steps = [STEP_OFF; SLICER_STEPS];
+        steps[0] = STEP_ON;
+        steps[2] = STEP_ACCENT;
+        let params = hard_params(steps);
+
+        let gains = step_gains(&mut kernel, &params, 4);
+        assert!((gains[0] - 1.0).abs() < 1e-3, "On step: {}", gains[0]);
+        assert!(gains[1].abs() < 1e-3, "Off step: {}", gains[1]);
+        let accent = fast_db_to_linear(6.0);
+        assert!(
+            (gains[2] - accent).abs() < 1e-3,
+            "Accent step: {}",
+            gains[2]
+        );
+        assert!(gains[3].abs() < 1e-3, "Off step: {}", gains[3]);
+    }
+
+    #[test]
+    fn depth_sets_off_level() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let params = SlicerParams {
+            depth_pct: 50.0,
+            ..hard_params([STEP_OFF; SLICER_STEPS])
+        };
+        let gains = step_gains(&mut kernel, &params, 1);
+        assert!((gains[0] - 0.5).abs() < 1e-3, "Half depth: {}", gains[0]);
+    }
+
+    #[test]
+    fn length_wraps_pattern() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let mut steps = [STEP_OFF; SLICER_STEPS];
+        steps[0] = STEP_ON;
+        let params = SlicerParams {
+            length: 3.0,
+            ..hard_params(steps)
+        };
+        let gains = step_gains(&mut kernel, &params, 7);
+        let open: Vec<bool> = gains.iter().map(|g| *g > 0.5).collect();
+        assert_eq!(open, [true, false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn shuffle_delays_odd_steps() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let params = SlicerParams {
+            shuffle_pct: 100.0,
+            ..SlicerParams::default()
+        };
+        // At full shuffle step 1 starts 1.5 steps into the pair
+        for _ in 0..(SIXTEENTH * 3 / 2 - 10) {
+            kernel.process_stereo(0.0, 0.0, &params);
+        }
+        assert_eq!(kernel.current_step(), 0);
+        for _ in 0..20 {
+            kernel.process_stereo(0.0, 0.0, &params);
+        }
+        assert_eq!(kernel.current_step(), 1);
+        for _ in 0..(SIXTEENTH / 2) {
+            kernel.process_stereo(0.0, 0.0, &params);
+        }
+        assert_eq!(kernel.current_step(), 2);
+    }
+
+    #[test]
+    fn follows_host_beat_position() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let params = SlicerParams::default();
+        kernel.set_tempo_context(&TempoContext {
+            bpm: 120.0,
+            is_playing: true,
+            beat_position: 2.5,
+            sample_rate: 48000.0,
+        });
+        kernel.process_stereo(0.0, 0.0, &params);
+        // 2.5 beats of sixteenths = step 10
+        assert_eq!(kernel.current_step(), 10);
+
+        // Stopped transport keeps free-running from where it was
+        kernel.set_tempo_context(&TempoContext {
+            bpm: 120.0,
+            is_playing: false,
+            beat_position: 0.0,
+            sample_rate: 48000.0,
+        });
+        kernel.process_stereo(0.0, 0.0, &params);
+        assert_eq!(kernel.current_step(), 10);
+    }
+
+    #[test]
+    fn attack_softens_transitions() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let params = SlicerParams {
+            attack_ms: 20.0,
+            ..hard_params([STEP_ON; SLICER_STEPS])
+        };
+        // 1 ms into a 20 ms attack the gate is still mostly closed
+        let mut out = 0.0;
+        for _ in 0..48 {
+            out = kernel.process_stereo(1.0, 1.0, &params).0;
+        }
+        assert!(out > 0.0 && out < 0.1, "Attack should ramp, got {out}");
+    }
+
+    #[test]
+    fn finite_output_extreme_params() {
+        let mut kernel = SlicerKernel::new(48000.0);
+        let params = SlicerParams {
+            steps: [STEP_ACCENT; SLICER_STEPS],
+            length: 1.0,
+            division: 11.0,
+            shuffle_pct: 100.0,
+            attack_ms: 0.1,
+            release_ms: 1.0,
+            accent_db: 12.0,
+            ..SlicerParams::default()
+        };
+        for i in 0..4800_u32 {
+            let s = libm::sinf(i as f32 * 0.05);
+            let (l, r) = kernel.process_stereo(s, -s, &params);
+            assert!(l.is_finite() && r.is_finite(), "Non-finite at {i}");
+        }
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(SlicerParams::COUNT, 26);
+        for i in 0..SlicerParams::COUNT {
+            assert!(
+                SlicerParams::descriptor(i).is_some(),
+                "Missing descriptor at {i}"
+            );
+        }
+        assert!(SlicerParams::descriptor(SlicerParams::COUNT).is_none());
+        assert_eq!(
+            SlicerParams::descriptor(24).unwrap().string_id,
+            "slc_position"
+        );
+    }
+
+    #[test]
+    fn adapter_reports_position() {
+        let mut adapter = Adapter::new(SlicerKernel::new(48000.0), 48000.0);
+        let input = vec![0.5_f32; SIXTEENTH + 10];
+        let mut out = vec![0.0_f32; SIXTEENTH + 10];
+        adapter.process_block(&input, &mut out);
+        assert!(out.iter().all(|s| s.is_finite()));
+        assert_eq!(adapter.get_param(24), 1.0);
+
+        let step = adapter.param_info(0).unwrap();
+        assert!(step.flags.contains(ParamFlags::STEPPED));
+        assert_eq!(step.id, ParamId(3700));
+    }
+}
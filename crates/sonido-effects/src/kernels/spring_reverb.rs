@@ -236,8 +236,9 @@ impl KernelParams for SpringReverbParams {
         match index {
             0 => Some(
                 ParamDescriptor::custom("Decay", "Decay", 0.5, 5.0, 2.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(2800), "spring_decay"),
+                    .with_unit(ParamUnit::Seconds)
+                    .with_id(ParamId(2800), "spring_decay")
+                    .with_description("How long the spring rings after the input stops."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -250,7 +251,8 @@ impl KernelParams for SpringReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2801), "spring_tension"),
+                .with_id(ParamId(2801), "spring_tension")
+                .with_description("Loose springs bounce more; tight springs are brighter."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -263,7 +265,10 @@ impl KernelParams for SpringReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2802), "spring_drip"),
+                .with_id(ParamId(2802), "spring_drip")
+                .with_description(
+                    "Input overdrive that brings out the spring's splash on transients.",
+                ),
             ),
             3 => Some(
                 ParamDescriptor {
@@ -276,9 +281,14 @@ impl KernelParams for SpringReverbParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(2803), "spring_damping"),
+                .with_id(ParamId(2803), "spring_damping")
+                .with_description("High-frequency absorption; higher values give a darker tail."),
+            ),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2804), "spring_mix")
+                    .with_description("Blend of dry and reverberated signal."),
             ),
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(2804), "spring_mix")),
             5 => Some(
                 sonido_core::gain::output_param_descriptor()
                     .with_id(ParamId(2805), "spring_output"),
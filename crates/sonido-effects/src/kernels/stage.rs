@@ -254,24 +254,33 @@ impl KernelParams for StageParams {
         match index {
             0 => Some(
                 ParamDescriptor::gain_db("Gain", "Gain", -40.0, 12.0, 0.0)
-                    .with_id(ParamId(1900), "stage_gain"),
+                    .with_id(ParamId(1900), "stage_gain")
+                    .with_description("Input gain trim."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Width", "Width", 0.0, 200.0, 100.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(1901), "stage_width"),
+                    .with_id(ParamId(1901), "stage_width")
+                    .with_group("Stereo")
+                    .with_description(
+                        "Stereo width, from mono (0%) through normal (100%) to exaggerated (200%).",
+                    ),
             ),
             2 => Some(
                 ParamDescriptor::custom("Balance", "Bal", -100.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(1902), "stage_balance"),
+                    .with_id(ParamId(1902), "stage_balance")
+                    .with_group("Stereo")
+                    .with_description("Left/right balance."),
             ),
             3 => Some(
                 ParamDescriptor::custom("Phase L", "PhL", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1903), "stage_phase_l")
+                    .with_group("Phase")
+                    .with_description("Invert the polarity of the left channel.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -279,6 +288,8 @@ impl KernelParams for StageParams {
                 ParamDescriptor::custom("Phase R", "PhR", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1904), "stage_phase_r")
+                    .with_group("Phase")
+                    .with_description("Invert the polarity of the right channel.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -286,6 +297,10 @@ impl KernelParams for StageParams {
                 ParamDescriptor::custom("Channel", "Chan", 0.0, 3.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1905), "stage_channel")
+                    .with_group("Stereo")
+                    .with_description(
+                        "Channel routing: normal, swapped, or either side copied to both.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Normal", "Swap", "Mono L", "Mono R"]),
             ),
@@ -293,6 +308,7 @@ impl KernelParams for StageParams {
                 ParamDescriptor::custom("DC Block", "DC", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1906), "stage_dc_block")
+                    .with_description("Remove DC offset with a subsonic highpass.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -300,6 +316,8 @@ impl KernelParams for StageParams {
                 ParamDescriptor::custom("Bass Mono", "BMon", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1907), "stage_bass_mono")
+                    .with_group("Bass Mono")
+                    .with_description("Sum low frequencies to mono.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -308,18 +326,26 @@ impl KernelParams for StageParams {
                     .with_unit(ParamUnit::Hertz)
                     .with_step(1.0)
                     .with_id(ParamId(1908), "stage_bass_freq")
+                    .with_group("Bass Mono")
+                    .with_description("Crossover below which bass is summed to mono.")
                     .with_scale(ParamScale::Logarithmic),
             ),
             9 => Some(
                 ParamDescriptor::custom("Haas", "Haas", 0.0, 30.0, 0.0)
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(0.1)
-                    .with_id(ParamId(1909), "stage_haas"),
+                    .with_id(ParamId(1909), "stage_haas")
+                    .with_group("Haas")
+                    .with_description(
+                        "Short delay on one channel to widen the image; 0 disables it.",
+                    ),
             ),
             10 => Some(
                 ParamDescriptor::custom("Haas Side", "HSde", 0.0, 1.0, 1.0)
                     .with_step(1.0)
                     .with_id(ParamId(1910), "stage_haas_side")
+                    .with_group("Haas")
+                    .with_description("Channel that receives the Haas delay.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["L", "R"]),
             ),
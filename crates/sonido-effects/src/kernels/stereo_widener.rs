@@ -155,13 +155,15 @@ impl KernelParams for StereoWidenerParams {
                 ParamDescriptor::custom("Width", "Width", 0.0, 200.0, 100.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(1.0)
-                    .with_id(ParamId(3100), "sw_width"),
+                    .with_id(ParamId(3100), "sw_width")
+                    .with_description("Stereo width, from mono (0%) through original (100%) to doubled side (200%)."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Haas Delay", "Haas", 0.0, 30.0, 0.0)
                     .with_unit(ParamUnit::Milliseconds)
                     .with_step(0.1)
-                    .with_id(ParamId(3101), "sw_haas_delay"),
+                    .with_id(ParamId(3101), "sw_haas_delay")
+                    .with_description("Short inter-channel delay to widen the image; 0 disables it."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -174,7 +176,8 @@ impl KernelParams for StereoWidenerParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3102), "sw_bass_mono"),
+                .with_id(ParamId(3102), "sw_bass_mono")
+                .with_description("Sum bass below this frequency to mono; 0 disables it."),
             ),
             3 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(3103), "sw_output"),
@@ -284,7 +284,8 @@ impl KernelParams for TapeParams {
                     step: 0.5,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1400), "tape_drive"),
+                .with_id(ParamId(1400), "tape_drive")
+                .with_description("Input level into the tape saturation stage."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -297,7 +298,10 @@ impl KernelParams for TapeParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1401), "tape_saturation"),
+                .with_id(ParamId(1401), "tape_saturation")
+                .with_description(
+                    "Blend from hard clipping (0%) to smooth tape saturation (100%).",
+                ),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -311,6 +315,7 @@ impl KernelParams for TapeParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1403), "tape_hf_rolloff")
+                .with_description("Cutoff of the high-frequency loss from the tape head.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             3 => Some(
@@ -324,7 +329,10 @@ impl KernelParams for TapeParams {
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1404), "tape_bias"),
+                .with_id(ParamId(1404), "tape_bias")
+                .with_description(
+                    "Offset into the saturation curve; non-zero values add even harmonics.",
+                ),
             ),
             4 => Some(
                 ParamDescriptor {
@@ -337,7 +345,9 @@ impl KernelParams for TapeParams {
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1405), "tape_wow"),
+                .with_id(ParamId(1405), "tape_wow")
+                .with_group("Transport")
+                .with_description("Slow pitch drift from transport speed variation."),
             ),
             5 => Some(
                 ParamDescriptor {
@@ -350,7 +360,9 @@ impl KernelParams for TapeParams {
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1406), "tape_flutter"),
+                .with_id(ParamId(1406), "tape_flutter")
+                .with_group("Transport")
+                .with_description("Fast pitch wobble from transport speed variation."),
             ),
             6 => Some(
                 ParamDescriptor {
@@ -363,7 +375,10 @@ impl KernelParams for TapeParams {
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1407), "tape_hysteresis"),
+                .with_id(ParamId(1407), "tape_hysteresis")
+                .with_description(
+                    "Magnetic lag fed back into the saturation stage; thickens the tone.",
+                ),
             ),
             7 => Some(
                 ParamDescriptor {
@@ -376,7 +391,9 @@ impl KernelParams for TapeParams {
                     step: 0.01,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1408), "tape_head_bump"),
+                .with_id(ParamId(1408), "tape_head_bump")
+                .with_group("Head Bump")
+                .with_description("Low-frequency resonance from the playback head, up to +6 dB."),
             ),
             8 => Some(
                 ParamDescriptor {
@@ -390,11 +407,14 @@ impl KernelParams for TapeParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(1409), "tape_bump_freq")
+                .with_group("Head Bump")
+                .with_description("Center frequency of the head bump resonance.")
                 .with_scale(ParamScale::Logarithmic),
             ),
             9 => Some(
                 ParamDescriptor::gain_db("Output", "Out", -12.0, 12.0, -6.0)
-                    .with_id(ParamId(1402), "tape_output"),
+                    .with_id(ParamId(1402), "tape_output")
+                    .with_description("Output level after processing."),
             ),
             _ => None,
         }
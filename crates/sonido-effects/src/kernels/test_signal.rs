@@ -0,0 +1,551 @@
+//! Test signal kernel — sine, white/pink noise, and sweep generator.
+//!
+//! `TestSignalKernel` owns the generator state (oscillator phase, sweep
+//! position, noise PRNG and pinking filter). Parameters are received via
+//! `&TestSignalParams` each sample. Deployed via
+//! [`Adapter`](sonido_core::kernel::Adapter) for desktop/plugin, or called
+//! directly on embedded targets.
+//!
+//! # Signal Flow
+//!
+//! ```text
+//! Input (ignored)
+//!
+//! Signal ─┬─ Sine   (Frequency)
+//!         ├─ White  (xorshift32, uniform)
+//!         ├─ Pink   (white → Kellet pinking filter)
+//!         └─ Sweep  (Sweep Start → Sweep End, log, looping over Sweep Time)
+//!   → × Level
+//!   → Output L = R
+//! ```
+//!
+//! # Use
+//!
+//! Inserted at the head of a graph, the node replaces the input with a known
+//! signal so a chain can be calibrated or a downstream effect measured without
+//! an external source. The CLI `generate tone` and `generate noise` commands
+//! render through this kernel as well.
+//!
+//! **Level** is the peak level for sine, sweep, and white noise. Pink noise
+//! uses Paul Kellet's refined filter scaled so its peaks land near the same
+//! level; its higher crest factor puts the RMS roughly 14 dB below the peak.
+//!
+//! Both channels carry the same signal.
+//!
+//! # Deployment
+//!
+//! ```rust,ignore
+//! // Desktop / Plugin (via adapter — handles smoothing automatically)
+//! let adapter = Adapter::new(TestSignalKernel::new(48000.0), 48000.0);
+//! let mut effect: Box<dyn Effect> = Box::new(adapter);
+//!
+//! // Embedded / Daisy Seed (direct — no smoothing)
+//! let mut kernel = TestSignalKernel::new(48000.0);
+//! let params = TestSignalParams::default();
+//! let (left, right) = kernel.process_stereo(0.0, 0.0, &params);
+//! ```
+
+use sonido_core::kernel::{DspKernel, KernelParams, SmoothingStyle};
+use sonido_core::{
+    ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit, db_to_linear, math::flush_denormal,
+};
+
+// ── Constants ─────────────────────────────────────────────────────────────────
+
+/// PRNG seed, restored on reset so output is reproducible.
+const RNG_SEED: u32 = 0x1234_5678;
+
+/// Output scale for the Kellet pinking filter, keeping peaks near ±1.
+const PINK_SCALE: f32 = 0.11;
+
+/// Signal selector values.
+const SIGNAL_SINE: u8 = 0;
+const SIGNAL_WHITE: u8 = 1;
+const SIGNAL_PINK: u8 = 2;
+// Anything else is the sweep.
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameter values for [`TestSignalKernel`].
+///
+/// All values are in **user-facing units** — the same units shown in GUIs and
+/// stored in presets. The kernel converts internally as needed.
+///
+/// ## Parameter Table
+///
+/// | Index | Field | Unit | Range | Default |
+/// |-------|-------|------|-------|---------|
+/// | 0 | `signal` | index | 0–3 | 0 (Sine) |
+/// | 1 | `freq_hz` | Hz | 20–20000 | 1000.0 |
+/// | 2 | `sweep_start_hz` | Hz | 20–20000 | 20.0 |
+/// | 3 | `sweep_end_hz` | Hz | 20–20000 | 20000.0 |
+/// | 4 | `sweep_time_s` | s | 0.1–30 | 5.0 |
+/// | 5 | `level_db` | dB | −60–0 | −18.0 |
+#[derive(Debug, Clone, Copy)]
+pub struct TestSignalParams {
+    /// Signal type.
+    ///
+    /// 0 = Sine, 1 = White noise, 2 = Pink noise, 3 = Sweep. Stepped.
+    pub signal: f32,
+
+    /// Sine frequency in Hz.
+    ///
+    /// Range: 20.0 to 20000.0 Hz (logarithmic). Default 1000.0.
+    pub freq_hz: f32,
+
+    /// Sweep start frequency in Hz.
+    ///
+    /// Range: 20.0 to 20000.0 Hz (logarithmic). Default 20.0.
+    pub sweep_start_hz: f32,
+
+    /// Sweep end frequency in Hz. May be below the start for a downward sweep.
+    ///
+    /// Range: 20.0 to 20000.0 Hz (logarithmic). Default 20000.0.
+    pub sweep_end_hz: f32,
+
+    /// Duration of one sweep in seconds; the sweep then restarts.
+    ///
+    /// Range: 0.1 to 30.0 s. Default 5.0.
+    pub sweep_time_s: f32,
+
+    /// Peak output level in dBFS.
+    ///
+    /// Range: −60.0 to 0.0 dB. Default −18.0.
+    pub level_db: f32,
+}
+
+impl Default for TestSignalParams {
+    fn default() -> Self {
+        Self {
+            signal: 0.0,
+            freq_hz: 1000.0,
+            sweep_start_hz: 20.0,
+            sweep_end_hz: 20000.0,
+            sweep_time_s: 5.0,
+            level_db: -18.0,
+        }
+    }
+}
+
+impl TestSignalParams {
+    /// Creates parameters from normalized 0–1 knob readings.
+    ///
+    /// | Argument | Index | Parameter | Range |
+    /// |----------|-------|-----------|-------|
+    /// | `signal` | 0 | `signal` | 0–3 (stepped) |
+    /// | `freq` | 1 | `freq_hz` | 20–20000 Hz |
+    /// | `sweep_start` | 2 | `sweep_start_hz` | 20–20000 Hz |
+    /// | `sweep_end` | 3 | `sweep_end_hz` | 20–20000 Hz |
+    /// | `sweep_time` | 4 | `sweep_time_s` | 0.1–30 s |
+    /// | `level` | 5 | `level_db` | −60–0 dB |
+    pub fn from_knobs(
+        signal: f32,
+        freq: f32,
+        sweep_start: f32,
+        sweep_end: f32,
+        sweep_time: f32,
+        level: f32,
+    ) -> Self {
+        Self::from_normalized(&[signal, freq, sweep_start, sweep_end, sweep_time, level])
+    }
+}
+
+impl KernelParams for TestSignalParams {
+    const COUNT: usize = 6;
+
+    fn descriptor(index: usize) -> Option<ParamDescriptor> {
+        match index {
+            0 => Some(
+                ParamDescriptor::custom("Signal", "Sig", 0.0, 3.0, 0.0)
+                    .with_step(1.0)
+                    .with_id(ParamId(4000), "ts_signal")
+                    .with_description(
+                        "Generated signal: sine, white noise, pink noise, or a sine sweep.",
+                    )
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                    .with_step_labels(&["Sine", "White", "Pink", "Sweep"]),
+            ),
+            1 => Some(
+                ParamDescriptor::custom("Frequency", "Freq", 20.0, 20000.0, 1000.0)
+                    .with_unit(ParamUnit::Hertz)
+                    .with_step(1.0)
+                    .with_id(ParamId(4001), "ts_freq")
+                    .with_description("Frequency of the sine.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            2 => Some(
+                ParamDescriptor::custom("Sweep Start", "Start", 20.0, 20000.0, 20.0)
+                    .with_unit(ParamUnit::Hertz)
+                    .with_step(1.0)
+                    .with_id(ParamId(4002), "ts_sweep_start")
+                    .with_group("Sweep")
+                    .with_description("Frequency the sweep starts at.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            3 => Some(
+                ParamDescriptor::custom("Sweep End", "End", 20.0, 20000.0, 20000.0)
+                    .with_unit(ParamUnit::Hertz)
+                    .with_step(1.0)
+                    .with_id(ParamId(4003), "ts_sweep_end")
+                    .with_group("Sweep")
+                    .with_description("Frequency the sweep ends at.")
+                    .with_scale(ParamScale::Logarithmic),
+            ),
+            4 => Some(
+                ParamDescriptor::custom("Sweep Time", "Time", 0.1, 30.0, 5.0)
+                    .with_unit(ParamUnit::Seconds)
+                    .with_step(0.1)
+                    .with_id(ParamId(4004), "ts_sweep_time")
+                    .with_group("Sweep")
+                    .with_description("Duration of one sweep."),
+            ),
+            5 => Some(
+                ParamDescriptor::gain_db("Level", "Level", -60.0, 0.0, -18.0)
+                    .with_id(ParamId(4005), "ts_level")
+                    .with_description("Level of the generated signal."),
+            ),
+            _ => None,
+        }
+    }
+
+    fn smoothing(index: usize) -> SmoothingStyle {
+        match index {
+            0 => SmoothingStyle::None,     // signal — stepped, switch immediately
+            1 => SmoothingStyle::Standard, // freq_hz — 10 ms glide
+            2..=4 => SmoothingStyle::None, // sweep shape — read once per sample
+            5 => SmoothingStyle::Fast,     // level_db — 5 ms
+            _ => SmoothingStyle::Standard,
+        }
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match index {
+            0 => self.signal,
+            1 => self.freq_hz,
+            2 => self.sweep_start_hz,
+            3 => self.sweep_end_hz,
+            4 => self.sweep_time_s,
+            5 => self.level_db,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.signal = value,
+            1 => self.freq_hz = value,
+            2 => self.sweep_start_hz = value,
+            3 => self.sweep_end_hz = value,
+            4 => self.sweep_time_s = value,
+            5 => self.level_db = value,
+            _ => {}
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Kernel
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Pure DSP test signal generator.
+///
+/// Contains ONLY the mutable state required for generation:
+///
+/// - Normalized oscillator phase, shared by the sine and the sweep
+/// - Sweep position in samples
+/// - xorshift32 PRNG state and the seven pinking filter states
+///
+/// The input is ignored. Reset restores the initial phase and PRNG seed, so
+/// the same parameters always render the same samples.
+pub struct TestSignalKernel {
+    /// Oscillator phase in cycles, `[0, 1)`.
+    phase: f32,
+    /// Samples elapsed in the current sweep.
+    sweep_pos: f32,
+    /// xorshift32 PRNG state (never 0).
+    rng: u32,
+    /// Kellet pinking filter states `b0..b6`.
+    pink: [f32; 7],
+    /// Audio sample rate in Hz.
+    sample_rate: f32,
+}
+
+impl TestSignalKernel {
+    /// Create a new test signal kernel at the given sample rate.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            phase: 0.0,
+            sweep_pos: 0.0,
+            rng: RNG_SEED,
+            pink: [0.0; 7],
+            sample_rate,
+        }
+    }
+
+    /// xorshift32 PRNG — returns a value in `[-1.0, 1.0]`.
+    #[inline]
+    fn white(&mut self) -> f32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+
+    /// Paul Kellet's refined pinking filter (−3 dB/octave within ±0.05 dB
+    /// above 9.2 Hz at 44.1 kHz).
+    #[inline]
+    fn pink(&mut self) -> f32 {
+        let w = self.white();
+        let b = &mut self.pink;
+        b[0] = flush_denormal(0.99886 * b[0] + w * 0.055_517_9);
+        b[1] = flush_denormal(0.99332 * b[1] + w * 0.075_075_9);
+        b[2] = flush_denormal(0.969 * b[2] + w * 0.153_852);
+        b[3] = flush_denormal(0.8665 * b[3] + w * 0.310_485_6);
+        b[4] = flush_denormal(0.55 * b[4] + w * 0.532_952_2);
+        b[5] = flush_denormal(-0.7616 * b[5] - w * 0.016_898);
+        let out = b[0] + b[1] + b[2] + b[3] + b[4] + b[5] + b[6] + w * 0.5362;
+        b[6] = w * 0.115_926;
+        out * PINK_SCALE
+    }
+
+    /// Sine at `freq_hz`, advancing the shared phase.
+    #[inline]
+    fn sine(&mut self, freq_hz: f32) -> f32 {
+        let out = libm::sinf(core::f32::consts::TAU * self.phase);
+        self.phase += freq_hz / self.sample_rate;
+        self.phase -= libm::floorf(self.phase);
+        out
+    }
+
+    /// Logarithmic sweep: `f(t) = start · (end / start)^(t / T)`, restarting
+    /// after `T` seconds. Phase stays continuous across the restart.
+    #[inline]
+    fn sweep(&mut self, params: &TestSignalParams) -> f32 {
+        let length = (params.sweep_time_s * self.sample_rate).max(1.0);
+        if self.sweep_pos >= length {
+            self.sweep_pos = 0.0;
+        }
+        let start = params.sweep_start_hz.max(1.0);
+        let end = params.sweep_end_hz.max(1.0);
+        let freq = start * libm::powf(end / start, self.sweep_pos / length);
+        self.sweep_pos += 1.0;
+        self.sine(freq)
+    }
+
+    /// Generate one sample at the configured level.
+    #[inline]
+    fn generate(&mut self, params: &TestSignalParams) -> f32 {
+        let raw = match params.signal as u8 {
+            SIGNAL_SINE => self.sine(params.freq_hz),
+            SIGNAL_WHITE => self.white(),
+            SIGNAL_PINK => self.pink(),
+            _ => self.sweep(params),
+        };
+        raw * db_to_linear(params.level_db)
+    }
+}
+
+impl DspKernel for TestSignalKernel {
+    type Params = TestSignalParams;
+
+    fn process_stereo(&mut self, _left: f32, _right: f32, params: &TestSignalParams) -> (f32, f32) {
+        let out = self.generate(params);
+        (out, out)
+    }
+
+    fn reset(&mut self) {
+        self.phase = 0.0;
+        self.sweep_pos = 0.0;
+        self.rng = RNG_SEED;
+        self.pink = [0.0; 7];
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+    }
+
+    fn is_true_stereo(&self) -> bool {
+        // One generator feeds both channels; per-channel processing would
+        // advance it twice per frame.
+        true
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::Effect;
+    use sonido_core::kernel::Adapter;
+
+    fn render(params: &TestSignalParams, len: usize) -> Vec<f32> {
+        let mut kernel = TestSignalKernel::new(48000.0);
+        (0..len)
+            .map(|_| kernel.process_stereo(0.0, 0.0, params).0)
+            .collect()
+    }
+
+    fn rms(x: &[f32]) -> f32 {
+        (x.iter().map(|s| s * s).sum::<f32>() / x.len() as f32).sqrt()
+    }
+
+    fn peak(x: &[f32]) -> f32 {
+        x.iter().fold(0.0f32, |m, s| m.max(s.abs()))
+    }
+
+    #[test]
+    fn params_descriptor_count() {
+        assert_eq!(TestSignalParams::COUNT, 6);
+
+        let d0 = TestSignalParams::descriptor(0).expect("index 0 must exist");
+        assert_eq!(d0.name, "Signal");
+        assert_eq!(d0.id, ParamId(4000));
+        assert_eq!(d0.string_id, "ts_signal");
+
+        let d5 = TestSignalParams::descriptor(5).expect("index 5 must exist");
+        assert_eq!(d5.name, "Level");
+        assert_eq!(d5.id, ParamId(4005));
+        assert_eq!(d5.unit, ParamUnit::Decibels);
+
+        assert!(TestSignalParams::descriptor(6).is_none());
+    }
+
+    #[test]
+    fn adapter_wraps_as_effect() {
+        let kernel = TestSignalKernel::new(48000.0);
+        let mut adapter = Adapter::new(kernel, 48000.0);
+        adapter.reset();
+        let output = adapter.process(0.3);
+        assert!(output.is_finite(), "adapter.process() returned {output}");
+    }
+
+    /// The input never reaches the output.
+    #[test]
+    fn input_is_ignored() {
+        let params = TestSignalParams::default();
+        let mut a = TestSignalKernel::new(48000.0);
+        let mut b = TestSignalKernel::new(48000.0);
+        for n in 0..256 {
+            let x = if n % 2 == 0 { 1.0 } else { -1.0 };
+            assert_eq!(
+                a.process_stereo(x, -x, &params),
+                b.process_stereo(0.0, 0.0, &params)
+            );
+        }
+    }
+
+    #[test]
+    fn sine_matches_level_and_frequency() {
+        let params = TestSignalParams {
+            freq_hz: 1000.0,
+            level_db: -6.0,
+            ..Default::default()
+        };
+        let out = render(&params, 48000);
+        let expected = db_to_linear(-6.0);
+        assert!((peak(&out) - expected).abs() < 1e-3, "peak {}", peak(&out));
+
+        // 1 kHz over one second: 1000 rising zero crossings.
+        let crossings = out.windows(2).filter(|w| w[0] < 0.0 && w[1] >= 0.0).count();
+        assert!((999..=1001).contains(&crossings), "crossings {crossings}");
+    }
+
+    #[test]
+    fn white_noise_is_bounded_and_full_band() {
+        let params = TestSignalParams {
+            signal: 1.0,
+            level_db: 0.0,
+            ..Default::default()
+        };
+        let out = render(&params, 48000);
+        assert!(peak(&out) <= 1.0);
+        // Uniform in [-1, 1]: RMS 1/√3.
+        let r = rms(&out);
+        assert!((r - 1.0 / 3.0f32.sqrt()).abs() < 0.01, "rms {r}");
+    }
+
+    /// Pink noise carries more energy in the low octave than white noise does.
+    #[test]
+    fn pink_noise_tilts_down() {
+        fn low_high_ratio(x: &[f32]) -> f32 {
+            // Crude split: 64-sample moving average as the lowpass.
+            let mut low = Vec::with_capacity(x.len());
+            let mut acc = 0.0;
+            for (i, &s) in x.iter().enumerate() {
+                acc += s;
+                if i >= 64 {
+                    acc -= x[i - 64];
+                }
+                low.push(acc / 64.0);
+            }
+            let high: Vec<f32> = x.iter().zip(&low).map(|(a, b)| a - b).collect();
+            rms(&low) / rms(&high)
+        }
+        let white = render(
+            &TestSignalParams {
+                signal: 1.0,
+                ..Default::default()
+            },
+            96000,
+        );
+        let pink = render(
+            &TestSignalParams {
+                signal: 2.0,
+                ..Default::default()
+            },
+            96000,
+        );
+        assert!(
+            low_high_ratio(&pink) > 4.0 * low_high_ratio(&white),
+            "pink {} white {}",
+            low_high_ratio(&pink),
+            low_high_ratio(&white)
+        );
+        assert!(peak(&pink) < db_to_linear(-18.0) * 1.5);
+    }
+
+    /// A rising sweep crosses zero faster at the end than at the start, then
+    /// restarts after `sweep_time_s`.
+    #[test]
+    fn sweep_rises_and_loops() {
+        let params = TestSignalParams {
+            signal: 3.0,
+            sweep_start_hz: 100.0,
+            sweep_end_hz: 10000.0,
+            sweep_time_s: 1.0,
+            ..Default::default()
+        };
+        let out = render(&params, 96000);
+        let crossings =
+            |x: &[f32]| x.windows(2).filter(|w| w[0] < 0.0 && w[1] >= 0.0).count() as f32;
+        let head = crossings(&out[..4800]);
+        let tail = crossings(&out[43200..48000]);
+        assert!(tail > 20.0 * head, "head {head} tail {tail}");
+        let next_head = crossings(&out[48000..52800]);
+        assert!((next_head - head).abs() <= 1.0, "{head} vs {next_head}");
+    }
+
+    #[test]
+    fn reset_reproduces_output() {
+        let params = TestSignalParams {
+            signal: 2.0,
+            ..Default::default()
+        };
+        let mut kernel = TestSignalKernel::new(48000.0);
+        let first: Vec<f32> = (0..512)
+            .map(|_| kernel.process_stereo(0.0, 0.0, &params).0)
+            .collect();
+        kernel.reset();
+        let second: Vec<f32> = (0..512)
+            .map(|_| kernel.process_stereo(0.0, 0.0, &params).0)
+            .collect();
+        assert_eq!(first, second);
+    }
+}
@@ -109,11 +109,13 @@ impl KernelParams for TextureParams {
             0 => Some(
                 ParamDescriptor::custom("Density", "Density", 1.0, 20.0, 8.0)
                     .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3400), "tex_density"),
+                    .with_id(ParamId(3400), "tex_density")
+                    .with_description("Grains started per second."),
             ),
             1 => Some(
                 ParamDescriptor::time_ms("Size", "Size", 50.0, 500.0, 200.0)
-                    .with_id(ParamId(3401), "tex_size"),
+                    .with_id(ParamId(3401), "tex_size")
+                    .with_description("Length of each grain."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -121,17 +123,26 @@ impl KernelParams for TextureParams {
                     short_name: "Scatter",
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3402), "tex_scatter"),
+                .with_id(ParamId(3402), "tex_scatter")
+                .with_description(
+                    "How widely grain start positions spread through the captured audio.",
+                ),
             ),
             3 => Some(
                 ParamDescriptor::custom("Pitch Var", "Pitch", 0.0, 24.0, 2.0)
-                    .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3403), "tex_pitch_var"),
+                    .with_unit(ParamUnit::Semitones)
+                    .with_id(ParamId(3403), "tex_pitch_var")
+                    .with_description("Maximum random pitch offset per grain."),
+            ),
+            4 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(3404), "tex_mix")
+                    .with_description("Blend of dry signal and grain texture."),
             ),
-            4 => Some(ParamDescriptor::mix().with_id(ParamId(3404), "tex_mix")),
             5 => Some(
                 ParamDescriptor::gain_db("Output", "Out", -60.0, 6.0, 0.0)
-                    .with_id(ParamId(3405), "tex_output"),
+                    .with_id(ParamId(3405), "tex_output")
+                    .with_description("Output level after processing."),
             ),
             _ => None,
         }
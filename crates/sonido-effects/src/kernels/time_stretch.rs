@@ -132,16 +132,22 @@ impl KernelParams for TimeStretchParams {
             0 => Some(
                 ParamDescriptor::custom("Time Ratio", "Time", 0.25, 4.0, 1.0)
                     .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3600), "ts_time_ratio"),
+                    .with_id(ParamId(3600), "ts_time_ratio")
+                    .with_description("Playback duration: below 1 is faster, above 1 is slower."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Pitch Ratio", "Pitch", 0.25, 4.0, 1.0)
                     .with_unit(ParamUnit::None)
-                    .with_id(ParamId(3601), "ts_pitch_ratio"),
+                    .with_id(ParamId(3601), "ts_pitch_ratio")
+                    .with_description(
+                        "Pitch change independent of time: below 1 is lower, above 1 is higher.",
+                    ),
             ),
             2 => Some(
                 ParamDescriptor::time_ms("Grain Size", "Grain", 10.0, 100.0, 50.0)
-                    .with_id(ParamId(3602), "ts_grain_size"),
+                    .with_id(ParamId(3602), "ts_grain_size")
+                    .with_group("Grains")
+                    .with_description("Length of each grain."),
             ),
             3 => Some(
                 ParamDescriptor {
@@ -149,7 +155,9 @@ impl KernelParams for TimeStretchParams {
                     short_name: "Density",
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3603), "ts_density"),
+                .with_id(ParamId(3603), "ts_density")
+                .with_group("Grains")
+                .with_description("Grain overlap; higher values are smoother."),
             ),
             4 => Some(
                 ParamDescriptor {
@@ -158,13 +166,18 @@ impl KernelParams for TimeStretchParams {
                     default: 20.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(3604), "ts_randomize"),
+                .with_id(ParamId(3604), "ts_randomize")
+                .with_group("Grains")
+                .with_description("Random scatter of grain positions for a more diffuse sound."),
             ),
             5 => Some(
                 ParamDescriptor::custom("Freeze", "Freeze", 0.0, 1.0, 0.0)
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(3605), "ts_freeze")
+                    .with_description(
+                        "Stop recording and keep playing grains from the frozen buffer.",
+                    )
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -173,12 +186,14 @@ impl KernelParams for TimeStretchParams {
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(3606), "ts_sync")
+                    .with_description("Tempo sync. Reserved; not yet implemented.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
             7 => Some(
                 ParamDescriptor::gain_db("Output", "Out", -60.0, 6.0, 0.0)
-                    .with_id(ParamId(3607), "ts_output"),
+                    .with_id(ParamId(3607), "ts_output")
+                    .with_description("Output level after processing."),
             ),
             _ => None,
         }
@@ -162,7 +162,8 @@ impl KernelParams for TransientShaperParams {
                 ParamDescriptor::custom("Attack", "Attack", -100.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(0.1)
-                    .with_id(ParamId(2900), "ts_attack"),
+                    .with_id(ParamId(2900), "ts_attack")
+                    .with_description("Boost or cut transients; -100% removes them."),
             ),
             // ── [1] Sustain ──────────────────────────────────────────────────
             // ParamId(2901), "ts_sustain" — sustained body boost/cut
@@ -170,7 +171,8 @@ impl KernelParams for TransientShaperParams {
                 ParamDescriptor::custom("Sustain", "Sustain", -100.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(0.1)
-                    .with_id(ParamId(2901), "ts_sustain"),
+                    .with_id(ParamId(2901), "ts_sustain")
+                    .with_description("Boost or cut the sustained body; -100% removes it."),
             ),
             // ── [2] Sensitivity ──────────────────────────────────────────────
             // ParamId(2902), "ts_sensitivity" — detection pre-gain
@@ -178,11 +180,16 @@ impl KernelParams for TransientShaperParams {
                 ParamDescriptor::custom("Sensitivity", "Sens", 0.0, 100.0, 50.0)
                     .with_unit(ParamUnit::Percent)
                     .with_step(0.1)
-                    .with_id(ParamId(2902), "ts_sensitivity"),
+                    .with_id(ParamId(2902), "ts_sensitivity")
+                    .with_description("Detector pre-gain; raise it to catch quieter transients."),
             ),
             // ── [3] Mix ──────────────────────────────────────────────────────
             // ParamId(2903), "ts_mix" — wet/dry blend
-            3 => Some(ParamDescriptor::mix().with_id(ParamId(2903), "ts_mix")),
+            3 => Some(
+                ParamDescriptor::mix()
+                    .with_id(ParamId(2903), "ts_mix")
+                    .with_description("Blend of dry and shaped signal."),
+            ),
             // ── [4] Output ───────────────────────────────────────────────────
             // ParamId(2904), "ts_output" — final output trim
             4 => Some(output_param_descriptor().with_id(ParamId(2904), "ts_output")),
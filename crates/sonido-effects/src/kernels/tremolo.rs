@@ -145,25 +145,39 @@ impl KernelParams for TremoloParams {
 
     fn descriptor(index: usize) -> Option<ParamDescriptor> {
         match index {
-            0 => Some(ParamDescriptor::rate_hz(0.5, 20.0, 5.0).with_id(ParamId(1000), "trem_rate")),
-            1 => Some(ParamDescriptor::depth().with_id(ParamId(1001), "trem_depth")),
+            0 => Some(
+                ParamDescriptor::rate_hz(0.5, 20.0, 5.0)
+                    .with_id(ParamId(1000), "trem_rate")
+                    .with_description("LFO speed of the volume modulation."),
+            ),
+            1 => Some(
+                ParamDescriptor::depth()
+                    .with_id(ParamId(1001), "trem_depth")
+                    .with_description("How far the volume dips on each cycle."),
+            ),
             2 => Some(
                 ParamDescriptor::custom("Waveform", "Wave", 0.0, 3.0, 0.0)
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(1002), "trem_waveform")
+                    .with_description("Shape of the volume modulation.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Sine", "Triangle", "Square", "SampleHold"]),
             ),
             3 => Some(
                 ParamDescriptor::custom("Stereo Spread", "Spread", 0.0, 100.0, 0.0)
                     .with_unit(ParamUnit::Percent)
-                    .with_id(ParamId(1004), "trem_stereo_spread"),
+                    .with_id(ParamId(1004), "trem_stereo_spread")
+                    .with_description(
+                        "LFO phase offset between channels; 100% gives full auto-pan.",
+                    ),
             ),
             4 => Some(
                 ParamDescriptor::custom("Sync", "Sync", 0.0, 1.0, 0.0)
                     .with_step(1.0)
                     .with_id(ParamId(1005), "trem_sync")
+                    .with_group("Tempo Sync")
+                    .with_description("Lock the LFO rate to the host tempo using Division.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -171,6 +185,8 @@ impl KernelParams for TremoloParams {
                 ParamDescriptor::custom("Division", "Div", 0.0, 11.0, 3.0)
                     .with_step(1.0)
                     .with_id(ParamId(1006), "trem_division")
+                    .with_group("Tempo Sync")
+                    .with_description("Note length of one LFO cycle when Sync is on.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(DIVISION_LABELS),
             ),
@@ -180,6 +196,7 @@ impl KernelParams for TremoloParams {
             7 => Some(
                 ParamDescriptor::custom("LFO Phase", "Phase", 0.0, 1.0, 0.0)
                     .with_id(ParamId(1007), "trem_lfo_phase")
+                    .with_description("Current LFO position in its cycle (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
             _ => None,
@@ -118,13 +118,15 @@ impl KernelParams for TunerParams {
                 ParamDescriptor::custom("Reference", "Ref", 415.0, 465.0, 440.0)
                     .with_unit(ParamUnit::Hertz)
                     .with_scale(ParamScale::Logarithmic)
-                    .with_id(ParamId(2300), "tuner_reference"),
+                    .with_id(ParamId(2300), "tuner_reference")
+                    .with_description("Reference pitch for A4."),
             ),
             1 => Some(
                 ParamDescriptor::custom("Mute", "Mute", 0.0, 1.0, 0.0)
                     .with_unit(ParamUnit::None)
                     .with_step(1.0)
                     .with_id(ParamId(2301), "tuner_mute")
+                    .with_description("Silence the output while tuning.")
                     .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                     .with_step_labels(&["Off", "On"]),
             ),
@@ -135,12 +137,16 @@ impl KernelParams for TunerParams {
                 ParamDescriptor::custom("Detected Hz", "Det Hz", 0.0, 5000.0, 0.0)
                     .with_unit(ParamUnit::Hertz)
                     .with_id(ParamId(2303), "tuner_detected_hz")
+                    .with_description("Detected fundamental frequency (read-only).")
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
             4 => Some(
                 ParamDescriptor::custom("Cents", "Cents", -50.0, 50.0, 0.0)
-                    .with_unit(ParamUnit::None)
+                    .with_unit(ParamUnit::Cents)
                     .with_id(ParamId(2304), "tuner_cents")
+                    .with_description(
+                        "Deviation from the nearest note; positive is sharp (read-only).",
+                    )
                     .with_flags(ParamFlags::READ_ONLY.union(ParamFlags::HIDDEN)),
             ),
             _ => None,
@@ -228,7 +228,8 @@ impl KernelParams for VibratoParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1300), "vib_depth"),
+                .with_id(ParamId(1300), "vib_depth")
+                .with_description("Scales the depth of all six wow and flutter units; 100% is their native depth."),
             ),
             1 => Some(
                 ParamDescriptor {
@@ -241,7 +242,8 @@ impl KernelParams for VibratoParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(1301), "vib_mix"),
+                .with_id(ParamId(1301), "vib_mix")
+                .with_description("Blend of dry and modulated signal."),
             ),
             2 => Some(
                 sonido_core::gain::output_param_descriptor().with_id(ParamId(1302), "vib_output"),
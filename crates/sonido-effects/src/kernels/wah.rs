@@ -187,6 +187,9 @@ impl KernelParams for WahParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(600), "wah_freq")
+                .with_description(
+                    "Center frequency of the wah filter; the pedal position in Manual mode.",
+                )
                 .with_scale(sonido_core::ParamScale::Logarithmic),
             ),
             1 => Some(
@@ -200,7 +203,8 @@ impl KernelParams for WahParams {
                     step: 0.1,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(601), "wah_reso"),
+                .with_id(ParamId(601), "wah_reso")
+                .with_description("Filter Q; higher values give a sharper, vocal peak."),
             ),
             2 => Some(
                 ParamDescriptor {
@@ -213,7 +217,8 @@ impl KernelParams for WahParams {
                     step: 1.0,
                     ..ParamDescriptor::mix()
                 }
-                .with_id(ParamId(602), "wah_sens"),
+                .with_id(ParamId(602), "wah_sens")
+                .with_description("How far the envelope sweeps the filter in Auto mode."),
             ),
             3 => Some(
                 ParamDescriptor {
@@ -227,6 +232,9 @@ impl KernelParams for WahParams {
                     ..ParamDescriptor::mix()
                 }
                 .with_id(ParamId(603), "wah_mode")
+                .with_description(
+                    "Auto follows the input envelope; Manual holds the set frequency.",
+                )
                 .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
                 .with_step_labels(&["Auto", "Manual"]),
             ),
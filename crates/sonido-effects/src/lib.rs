@@ -35,7 +35,9 @@
 //! ## Time-Based
 //!
 //! - [`DelayKernel`] - Tape-style feedback delay with interpolation
+//! - [`MultiTapDelayKernel`] - Four panned taps on one line with rhythmic tap patterns
 //! - [`ReverbKernel`] - Freeverb-style algorithmic reverb (8 combs + 4 allpasses)
+//! - [`FdnReverbKernel`] - Modulated 8-line FDN reverb with a Householder feedback matrix
 //!
 //! ## Filters
 //!
@@ -50,6 +52,10 @@
 //! ## Utility
 //!
 //! - [`StageKernel`] - Signal conditioning: gain, phase, width, balance, bass mono, Haas delay
+//! - [`AgcKernel`] - Slow automatic gain control toward a target RMS or LUFS level
+//! - [`AlignKernel`] - Sample-accurate delay and polarity for aligning parallel signals
+//! - [`PhaseRotatorKernel`] - Cascaded allpasses that rotate phase around a chosen frequency
+//! - [`TestSignalKernel`] - Sine, white/pink noise, and sweep generator for calibrating chains
 //!
 //! # Usage
 //!
@@ -116,17 +122,19 @@ pub mod test_utils;
 
 // Re-export all kernel types at crate root
 pub use kernels::{
-    AmpKernel, AmpParams, BitcrusherKernel, BitcrusherParams, CabinetKernel, CabinetParams,
-    ChorusKernel, ChorusParams, CompressorKernel, CompressorParams, DeesserKernel, DeesserParams,
-    DelayKernel, DelayParams, DistortionKernel, DistortionParams, DroneKernel, DroneParams,
-    EqKernel, EqParams, FilterKernel, FilterParams, FlangerKernel, FlangerParams, GateKernel,
+    AgcKernel, AgcParams, AlignKernel, AlignParams, AmpKernel, AmpParams, BitcrusherKernel,
+    BitcrusherParams, CabinetKernel, CabinetParams, ChorusKernel, ChorusParams, CompressorKernel,
+    CompressorParams, DeesserKernel, DeesserParams, DelayKernel, DelayParams, DistortionKernel,
+    DistortionParams, DroneKernel, DroneParams, EqKernel, EqParams, FdnReverbKernel,
+    FdnReverbParams, FilterKernel, FilterParams, FlangerKernel, FlangerParams, GateKernel,
     GateParams, GlitchKernel, GlitchParams, LimiterKernel, LimiterParams, LooperKernel,
-    LooperParams, MultibandCompKernel, MultibandCompParams, PhaserKernel, PhaserParams,
+    LooperParams, MultiTapDelayKernel, MultiTapDelayParams, MultibandCompKernel,
+    MultibandCompParams, PhaseRotatorKernel, PhaseRotatorParams, PhaserKernel, PhaserParams,
     PitchShiftKernel, PitchShiftParams, PlateReverbKernel, PlateReverbParams, PreampKernel,
     PreampParams, ReverbKernel, ReverbParams, RingModKernel, RingModParams, ShelvingEqKernel,
-    ShelvingEqParams, SpringReverbKernel, SpringReverbParams, StageKernel, StageParams,
-    StereoWidenerKernel, StereoWidenerParams, TapeKernel, TapeParams, TextureKernel, TextureParams,
-    TimeStretchKernel, TimeStretchParams, TransientShaperKernel, TransientShaperParams,
-    TremoloKernel, TremoloParams, TunerKernel, TunerParams, VibratoKernel, VibratoParams,
-    WahKernel, WahParams,
+    ShelvingEqParams, SlicerKernel, SlicerParams, SpringReverbKernel, SpringReverbParams,
+    StageKernel, StageParams, StereoWidenerKernel, StereoWidenerParams, TapeKernel, TapeParams,
+    TestSignalKernel, TestSignalParams, TextureKernel, TextureParams, TimeStretchKernel,
+    TimeStretchParams, TransientShaperKernel, TransientShaperParams, TremoloKernel, TremoloParams,
+    TunerKernel, TunerParams, VibratoKernel, VibratoParams, WahKernel, WahParams,
 };
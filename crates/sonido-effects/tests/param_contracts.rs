@@ -1,10 +1,12 @@
-//! Parameter contract tests: range access, unity gain, curve conventions.
+//! Parameter contract tests: range access, unity gain, curve conventions, display text.
 //!
 //! These tests enforce the hardware tuning contracts:
 //! 1. **Full Range Accessible** — knob 0→1 covers descriptor min→max
 //! 2. **Unity Gain at Defaults** — processing with defaults produces near-unity output
-//! 3. **Scale Conventions** — Hz params use Logarithmic, STEPPED has valid steps, etc.
+//! 3. **Scale Conventions** — Hz params use Logarithmic, STEPPED has valid steps,
+//!    every param has a description, etc.
 //! 4. **Monotonic Curves** — sweeping 0→1 produces monotonically increasing values
+//! 5. **Display Text Round-Trip** — `format_value` text parses back to the same value
 
 mod helpers;
 
@@ -94,7 +96,9 @@ fn defaults_produce_near_unity_gain() {
         "flanger",
         "phaser",
         "delay",
+        "multitap_delay",
         "reverb",
+        "fdn_reverb",
         "plate_reverb",
         "spring_reverb", // reverbs — mix at defaults → partial cancellation
         "distortion",
@@ -112,6 +116,7 @@ fn defaults_produce_near_unity_gain() {
         "time_stretch", // grain-based — low density at defaults
         "amp",          // guitar amp model — gain character varies with model
         "plate_reverb", // dense diffusion + tank gain at default mix
+        "test_signal",  // generator — replaces the input
     ];
 
     for id in all_ids() {
@@ -157,6 +162,7 @@ fn defaults_produce_near_unity_gain() {
 /// - default ∈ [min, max]
 /// - min < max
 /// - ParamIds are unique within each effect
+/// - Every parameter has a description (GUI tooltip)
 #[test]
 fn param_scale_conventions() {
     let registry = EffectRegistry::new();
@@ -204,6 +210,10 @@ fn param_scale_conventions() {
                     ));
                 }
 
+                if desc.description.is_empty() {
+                    violations.push(format!("  {id}[{idx}] \"{}\": no description", desc.name));
+                }
+
                 if !seen_ids.insert(desc.id) {
                     violations.push(format!(
                         "  {id}[{idx}] \"{}\": duplicate ParamId {:?}",
@@ -295,3 +305,62 @@ fn adc_to_param_curve_monotonic() {
         violations,
     );
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Test 5: Display Text Round-Trip
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Text shown to hosts must parse back to the value it describes:
+/// `parse_value(format_value(v))` lands within 1% of range at min, default, and
+/// max. STEPPED params (including labeled selectors) must round-trip exactly.
+///
+/// This is what CLAP `value_to_text` / `text_to_value` rely on, so automation
+/// lanes and typed-in values agree with the knob.
+#[test]
+fn display_text_round_trip() {
+    let registry = EffectRegistry::new();
+    let mut violations = Vec::new();
+
+    for id in all_ids() {
+        let effect = registry.create(&id, SAMPLE_RATE).unwrap();
+        let count = effect.effect_param_count();
+
+        for idx in 0..count {
+            if let Some(desc) = effect.effect_param_info(idx) {
+                if desc.flags.contains(ParamFlags::READ_ONLY) {
+                    continue;
+                }
+
+                let stepped =
+                    desc.flags.contains(ParamFlags::STEPPED) || desc.step_labels.is_some();
+                let range = (desc.max - desc.min).abs();
+                let tolerance = if stepped || range == 0.0 {
+                    1e-4
+                } else {
+                    range * 0.01
+                };
+
+                for value in [desc.min, desc.default, desc.max] {
+                    let text = desc.format_value(value);
+                    match desc.parse_value(&text) {
+                        Some(parsed) if (parsed - value).abs() <= tolerance => {}
+                        Some(parsed) => violations.push(format!(
+                            "  {id}[{idx}] \"{}\": {value} → \"{text}\" → {parsed}",
+                            desc.name
+                        )),
+                        None => violations.push(format!(
+                            "  {id}[{idx}] \"{}\": {value} → \"{text}\" does not parse",
+                            desc.name
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    assert_no_violations(
+        "display_text_round_trip",
+        "format_value text must parse back to the value it shows",
+        violations,
+    );
+}
@@ -143,9 +143,12 @@ const RULE3_NON_LAST_EXCEPTIONS: &[&str] = &[
     "chorus",
     "flanger",
     "phaser",
+    "delay",
+    "reverb",
     "tremolo",
     "gate",
     "tuner", // Output at [2], then READ_ONLY Detected Hz + Cents
+    "agc",   // Output at [5], then READ_ONLY applied Gain
 ];
 
 #[test]
@@ -197,6 +200,7 @@ fn rule3_output_parameter() {
 
 const RULE4_TESTED: &[&str] = &[
     "delay",
+    "multitap_delay",
     "flanger",
     "phaser",
     "reverb",
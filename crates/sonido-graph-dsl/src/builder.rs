@@ -2,12 +2,16 @@
 //!
 //! Returns a manifest of `(NodeId, effect_id)` pairs alongside the graph,
 //! enabling `GraphEngine::new_dag()` to populate its slot-indexed access tables.
+//! Presets with send/return aux buses are built by [`build_graph_from_preset`].
 
 use crate::DslError;
 use crate::effects::create_effect_with_params;
 use crate::parser::{GraphNode, GraphSpec};
 
-use sonido_core::graph::{NodeId, ProcessingGraph};
+use sonido_config::{EffectConfig, Preset};
+use sonido_core::graph::{NodeId, ProcessingGraph, SendTap};
+use sonido_registry::EffectRegistry;
+use std::collections::HashMap;
 
 /// An effect manifest entry: node ID + registry effect ID.
 pub type ManifestEntry = (NodeId, &'static str);
@@ -63,6 +67,123 @@ pub fn build_graph_only(
     build_graph(spec, sample_rate, block_size).map(|(graph, _)| graph)
 }
 
+/// Rebuild a `ProcessingGraph` and effect manifest from a JSON graph snapshot.
+///
+/// Accepts the output of `ProcessingGraph::to_snapshot_json()` or
+/// `GraphEngine::to_snapshot_json()`. Effects are created through the registry
+/// by canonical ID (no alias resolution), so the manifest carries the
+/// registry's `&'static str` IDs ready for `GraphEngine::new_dag()`.
+pub fn build_graph_from_json(
+    json: &str,
+    sample_rate: f32,
+    block_size: usize,
+) -> Result<(ProcessingGraph, Vec<ManifestEntry>), DslError> {
+    let registry = EffectRegistry::new();
+    let (graph, effects) =
+        ProcessingGraph::from_snapshot_json(json, sample_rate, block_size, |id| {
+            registry.create(id, sample_rate)
+        })?;
+    let manifest = effects
+        .into_iter()
+        .filter_map(|(node, id)| registry.get(&id).map(|d| (node, d.id)))
+        .collect();
+    Ok((graph, manifest))
+}
+
+/// Build a `ProcessingGraph` and effect manifest from a preset, including its
+/// send/return aux buses.
+///
+/// The main chain runs in series from Input; each effect's sends follow it as
+/// inline send nodes. With aux buses present, the chain and every return
+/// chain sum into a master aux bus feeding Output:
+///
+/// ```text
+/// Input → fx1 → Send → fx2 → master → Output
+///                 └→ verb → reverb ┘
+/// ```
+///
+/// Bypassed effects are built and bypassed. The manifest lists main-chain
+/// effects first, then each aux bus's return chain in preset order.
+///
+/// # Errors
+///
+/// Returns [`DslError::UnknownAuxBus`] if a send names a bus the preset does
+/// not define, or another [`DslError`] if an effect or parameter is invalid.
+pub fn build_graph_from_preset(
+    preset: &Preset,
+    sample_rate: f32,
+    block_size: usize,
+) -> Result<(ProcessingGraph, Vec<ManifestEntry>), DslError> {
+    tracing::debug!(
+        "dsl_build: preset '{}' with {} aux bus(es)",
+        preset.name,
+        preset.aux.len()
+    );
+    let mut graph = ProcessingGraph::new(sample_rate, block_size);
+    let mut manifest = Vec::new();
+
+    let input = graph.add_input();
+    let output = graph.add_output();
+    let buses: HashMap<&str, NodeId> = preset
+        .aux
+        .iter()
+        .map(|aux| (aux.name.as_str(), graph.add_aux_bus()))
+        .collect();
+
+    let mut tail = input;
+    for config in &preset.effects {
+        tail = add_preset_effect(&mut graph, &mut manifest, config, tail, sample_rate)?;
+        for send in &config.sends {
+            let aux = *buses
+                .get(send.aux.as_str())
+                .ok_or_else(|| DslError::UnknownAuxBus(send.aux.clone()))?;
+            let tap = if send.pre_fader {
+                SendTap::PreFader
+            } else {
+                SendTap::PostFader
+            };
+            let node = graph.add_send(aux, send.level_db, tap)?;
+            graph.connect(tail, node)?;
+            tail = node;
+        }
+    }
+
+    if preset.aux.is_empty() {
+        graph.connect(tail, output)?;
+    } else {
+        let master = graph.add_aux_bus();
+        graph.connect(tail, master)?;
+        for aux in &preset.aux {
+            let mut ret = buses[aux.name.as_str()];
+            for config in &aux.effects {
+                ret = add_preset_effect(&mut graph, &mut manifest, config, ret, sample_rate)?;
+            }
+            graph.connect(ret, master)?;
+        }
+        graph.connect(master, output)?;
+    }
+
+    graph.compile()?;
+    Ok((graph, manifest))
+}
+
+/// Append one preset effect after `tail`, returning its node ID.
+fn add_preset_effect(
+    graph: &mut ProcessingGraph,
+    manifest: &mut Vec<ManifestEntry>,
+    config: &EffectConfig,
+    tail: NodeId,
+    sample_rate: f32,
+) -> Result<NodeId, DslError> {
+    let (effect, resolved_id) =
+        create_effect_with_params(config.canonical_type(), sample_rate, &config.params)?;
+    let id = graph.add_effect(effect);
+    graph.set_bypass(id, config.bypassed);
+    graph.connect(tail, id)?;
+    manifest.push((id, resolved_id));
+    Ok(id)
+}
+
 /// Build a serial path, returning `(entry, exit)` node IDs.
 ///
 /// Dry nodes in mixed paths (e.g., `- | reverb`) are skipped — the dry is
@@ -233,6 +354,59 @@ mod tests {
         assert!(energy > 0.0, "output should contain signal");
     }
 
+    #[test]
+    fn json_snapshot_round_trip() {
+        use sonido_core::graph::GraphEngine;
+
+        let spec = parse_graph_dsl("split(distortion:drive=20; -) | reverb:mix=30").unwrap();
+        let (graph, manifest) = build_graph(&spec, 48000.0, 256).unwrap();
+        let mut engine = GraphEngine::new_dag(graph, manifest);
+        engine.set_bypass_at(1, true);
+        let json = engine.to_snapshot_json().unwrap();
+
+        let (graph, manifest) = build_graph_from_json(&json, 48000.0, 256).unwrap();
+        assert_eq!(graph.node_count(), 6);
+        let ids: Vec<&str> = manifest.iter().map(|m| m.1).collect();
+        assert_eq!(ids, ["distortion", "reverb"]);
+        let restored = GraphEngine::new_dag(graph, manifest);
+        assert_eq!(restored.get_param_at(0, 0), engine.get_param_at(0, 0));
+        assert!(restored.is_bypassed_at(1));
+        assert_eq!(restored.to_snapshot_json().unwrap(), json);
+    }
+
+    #[test]
+    fn preset_sends_feed_return_chains() {
+        use sonido_config::{AuxConfig, SendConfig};
+
+        let preset = Preset::new("Wet")
+            .with_effect(
+                EffectConfig::new("distortion")
+                    .with_param("drive", "10")
+                    .with_send(SendConfig::new("verb", -12.0).pre_fader()),
+            )
+            .with_effect(EffectConfig::new("!chorus"))
+            .with_aux(AuxConfig::new("verb").with_effect(EffectConfig::new("reverb")));
+        let (mut graph, manifest) = build_graph_from_preset(&preset, 48000.0, 256).unwrap();
+
+        // Input, Output, verb bus, distortion, send, chorus, master, reverb
+        assert_eq!(graph.node_count(), 8);
+        let ids: Vec<&str> = manifest.iter().map(|m| m.1).collect();
+        assert_eq!(ids, ["distortion", "chorus", "reverb"]);
+        assert!(graph.is_bypassed(manifest[1].0));
+
+        let input = vec![0.1_f32; 256];
+        let (mut left, mut right) = (vec![0.0_f32; 256], vec![0.0_f32; 256]);
+        graph.process_block(&input, &input, &mut left, &mut right);
+        assert!(left.iter().any(|s| *s != 0.0));
+
+        let mut dangling = preset;
+        dangling.aux.clear();
+        assert!(matches!(
+            build_graph_from_preset(&dangling, 48000.0, 256),
+            Err(DslError::UnknownAuxBus(name)) if name == "verb"
+        ));
+    }
+
     #[test]
     fn build_graph_only_compat() {
         let spec = parse_graph_dsl("reverb").unwrap();
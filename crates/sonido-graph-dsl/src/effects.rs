@@ -5,7 +5,7 @@
 //! CLI and GUI aliases for effect names, parameter names, and enum values are
 //! normalized here before being passed to the registry.
 
-use sonido_core::EffectWithParams;
+use sonido_core::{EffectWithParams, ParamDescriptor};
 use sonido_registry::EffectRegistry;
 use std::collections::HashMap;
 
@@ -34,6 +34,19 @@ pub enum EffectError {
         message: String,
     },
 
+    /// Parameter value outside its descriptor's range (effects clamp it).
+    #[error("Value {value} for '{param}' is outside {min}..={max}")]
+    OutOfRange {
+        /// Parameter name.
+        param: String,
+        /// Parsed value.
+        value: f32,
+        /// Descriptor minimum.
+        min: f32,
+        /// Descriptor maximum.
+        max: f32,
+    },
+
     /// Generic parse error.
     #[error("Parse error: {0}")]
     ParseError(String),
@@ -220,6 +233,21 @@ fn normalize_enum_value(param_name: &str, value: &str) -> String {
     }
 }
 
+/// Resolve a user-facing parameter name (or alias) to its index.
+///
+/// `effect_id` must be a canonical registry ID (see [`resolve_effect_name`]).
+/// Accepts the same aliases as chain specs, e.g. `time` for the delay's
+/// "Delay Time" or `lf` for the EQ's "Low Frequency".
+pub fn resolve_param_index(registry: &EffectRegistry, effect_id: &str, key: &str) -> Option<usize> {
+    registry.param_index_by_name(effect_id, &normalize_param_name(effect_id, key))
+}
+
+/// Parse a user-facing parameter value, accepting enum shorthands
+/// (`soft`, `on`, `lp`, …) as well as unit-suffixed numbers (`-6 dB`, `1.2 kHz`).
+pub fn parse_param_value(desc: &ParamDescriptor, value: &str) -> Option<f32> {
+    desc.parse_value(&normalize_enum_value(desc.name, value))
+}
+
 /// Create an effect with custom parameters using the registry.
 ///
 /// Effect names and parameter names are resolved through alias tables,
@@ -246,25 +274,20 @@ pub fn create_effect_with_params(
         .ok_or_else(|| EffectError::UnknownEffect(name.to_string()))?;
 
     for (key, value) in params {
-        let norm_key = normalize_param_name(effect_id, key);
-
-        let idx = registry
-            .param_index_by_name(effect_id, &norm_key)
-            .ok_or_else(|| EffectError::UnknownParameter {
+        let idx = resolve_param_index(&registry, effect_id, key).ok_or_else(|| {
+            EffectError::UnknownParameter {
                 effect: name.to_string(),
                 param: key.clone(),
-            })?;
+            }
+        })?;
 
         let desc = effect.effect_param_info(idx).unwrap();
 
         // Try enum normalization first, then parse_value
-        let norm_value = normalize_enum_value(&norm_key, value);
-        let parsed = desc
-            .parse_value(&norm_value)
-            .ok_or_else(|| EffectError::InvalidValue {
-                param: key.clone(),
-                message: format!("'{}' is not a valid value for '{}'", value, desc.name),
-            })?;
+        let parsed = parse_param_value(&desc, value).ok_or_else(|| EffectError::InvalidValue {
+            param: key.clone(),
+            message: format!("'{}' is not a valid value for '{}'", value, desc.name),
+        })?;
 
         effect.effect_set_param(idx, parsed);
     }
@@ -272,6 +295,55 @@ pub fn create_effect_with_params(
     Ok((effect, resolved_static_id))
 }
 
+/// Check an effect's parameters against their descriptors without keeping
+/// the effect.
+///
+/// Names and values resolve the same way as [`create_effect_with_params`],
+/// which stops at the first error and silently clamps out-of-range values.
+/// This reports every problem instead, ordered by parameter name: an
+/// unknown effect, unknown parameters, unparseable values, and values
+/// outside the descriptor range. Empty when the effect would be created
+/// exactly as written.
+pub fn check_effect_params(name: &str, params: &HashMap<String, String>) -> Vec<EffectError> {
+    let registry = EffectRegistry::new();
+    let effect_id = resolve_effect_name(name);
+    let Some(effect) = registry.create(effect_id, 48000.0) else {
+        return vec![EffectError::UnknownEffect(name.to_string())];
+    };
+
+    let mut keys: Vec<&String> = params.keys().collect();
+    keys.sort();
+    let mut problems = Vec::new();
+    for key in keys {
+        let Some(desc) = resolve_param_index(&registry, effect_id, key)
+            .and_then(|idx| effect.effect_param_info(idx))
+        else {
+            problems.push(EffectError::UnknownParameter {
+                effect: name.to_string(),
+                param: key.clone(),
+            });
+            continue;
+        };
+        let value = &params[key];
+        match parse_param_value(&desc, value) {
+            None => problems.push(EffectError::InvalidValue {
+                param: key.clone(),
+                message: format!("'{}' is not a valid value for '{}'", value, desc.name),
+            }),
+            Some(v) if !(desc.min..=desc.max).contains(&v) => {
+                problems.push(EffectError::OutOfRange {
+                    param: key.clone(),
+                    value: v,
+                    min: desc.min,
+                    max: desc.max,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    problems
+}
+
 /// Parse an effect chain specification.
 ///
 /// Format: `"effect1:param1=value1,param2=value2|effect2:param=value"`
@@ -343,6 +415,56 @@ fn parse_params(params_str: &str) -> Result<HashMap<String, String>, EffectError
 mod tests {
     use super::*;
 
+    #[test]
+    fn check_effect_params_reports_every_problem() {
+        let params = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        // Aliases and enum shorthands resolve like create_effect_with_params
+        assert!(check_effect_params("delay", &params(&[("time", "300")])).is_empty());
+        assert!(check_effect_params("distortion", &params(&[("shape", "soft")])).is_empty());
+
+        let problems = check_effect_params(
+            "delay",
+            &params(&[("time", "99999"), ("bogus", "1"), ("feedback", "lots")]),
+        );
+        assert_eq!(problems.len(), 3);
+        assert!(
+            matches!(&problems[0], EffectError::UnknownParameter { param, .. } if param == "bogus")
+        );
+        assert!(
+            matches!(&problems[1], EffectError::InvalidValue { param, .. } if param == "feedback")
+        );
+        assert!(
+            matches!(&problems[2], EffectError::OutOfRange { param, value, .. } if param == "time" && *value == 99999.0)
+        );
+
+        assert!(matches!(
+            check_effect_params("nope", &HashMap::new())[..],
+            [EffectError::UnknownEffect(_)]
+        ));
+    }
+
+    #[test]
+    fn factory_presets_pass_param_check() {
+        for preset in sonido_config::factory_presets() {
+            let returns = preset.aux.iter().flat_map(|aux| &aux.effects);
+            for cfg in preset.effects.iter().chain(returns) {
+                let problems = check_effect_params(&cfg.effect_type, &cfg.params);
+                assert!(
+                    problems.is_empty(),
+                    "{} / {}: {problems:?}",
+                    preset.name,
+                    cfg.effect_type
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_create_effect_with_params() {
         let params = HashMap::new();
@@ -354,6 +476,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_resolve_param_index_and_value() {
+        let registry = EffectRegistry::new();
+        let time = resolve_param_index(&registry, "delay", "time").unwrap();
+        assert_eq!(
+            Some(time),
+            registry.param_index_by_name("delay", "delay time")
+        );
+        assert!(resolve_param_index(&registry, "delay", "nonexistent").is_none());
+
+        let shape = resolve_param_index(&registry, "distortion", "shape").unwrap();
+        let desc = &registry.param_descriptors("distortion").unwrap()[shape];
+        assert_eq!(
+            parse_param_value(desc, "hard"),
+            desc.parse_value("Hard Clip")
+        );
+    }
+
     #[test]
     fn test_parse_params() {
         let params = parse_params("drive=15,tone=4000").unwrap();
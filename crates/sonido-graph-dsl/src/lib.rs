@@ -8,7 +8,9 @@
 //! Three-layer design:
 //! - **Parser** ([`parser`]): pure text → IR transformation, no audio dependencies
 //! - **Builder** ([`builder`]): IR → `ProcessingGraph`
-//!   with effect manifest for `GraphEngine::new_dag()`
+//!   with effect manifest for `GraphEngine::new_dag()`; also rebuilds graphs
+//!   from JSON snapshots ([`build_graph_from_json`]) and presets with aux
+//!   buses ([`build_graph_from_preset`])
 //! - **Effects** ([`effects`]): effect factory with name/parameter alias resolution
 //! - **Serialize** ([`serialize`]): `GraphSpec` and `GraphSnapshot` ↔ DSL text
 //!
@@ -30,13 +32,16 @@ pub mod parser;
 pub mod serialize;
 
 // Re-export primary API
-pub use builder::{ManifestEntry, build_graph, build_graph_only};
+pub use builder::{
+    ManifestEntry, build_graph, build_graph_from_json, build_graph_from_preset, build_graph_only,
+};
 pub use effects::{
-    EffectError, create_effect_with_params, parse_chain, parse_effect_spec, resolve_effect_name,
+    EffectError, check_effect_params, create_effect_with_params, parse_chain, parse_effect_spec,
+    parse_param_value, resolve_effect_name, resolve_param_index,
 };
 pub use parser::{
-    DslParseError, GraphNode, GraphSpec, build_graph_slug, count_nodes, parse_graph_dsl,
-    validate_spec,
+    DslParseError, GraphNode, GraphSpec, MAX_SPLIT_DEPTH, build_graph_slug, count_nodes,
+    parse_graph_dsl, validate_spec,
 };
 pub use serialize::{
     graph_to_dsl, snapshot_from_dsl, snapshot_to_dsl, snapshot_to_preset, topology_from_spec,
@@ -56,4 +61,12 @@ pub enum DslError {
     /// Graph construction error (cycle, invalid connection, etc.).
     #[error(transparent)]
     Graph(#[from] sonido_core::graph::GraphError),
+
+    /// JSON graph snapshot error (malformed document, unknown effect, etc.).
+    #[error(transparent)]
+    Json(#[from] sonido_core::graph::GraphJsonError),
+
+    /// A preset send names an aux bus the preset does not define.
+    #[error("send targets unknown aux bus '{0}'")]
+    UnknownAuxBus(String),
 }
@@ -27,9 +27,14 @@
 //! Two-phase design: parse → [`GraphSpec`], then build → `ProcessingGraph`.
 //! The parse phase is pure (no audio dependencies, fully testable).
 
-use sonido_core::graph::MAX_SPLIT_TARGETS;
 use std::collections::HashMap;
 
+/// Deepest `split(...)` nesting the parser accepts.
+///
+/// Real topologies nest two or three levels; the limit keeps hostile input
+/// from overflowing the stack of the recursive-descent parser.
+pub const MAX_SPLIT_DEPTH: usize = 32;
+
 // ---------------------------------------------------------------------------
 // IR types
 // ---------------------------------------------------------------------------
@@ -89,13 +94,11 @@ pub enum DslParseError {
         /// Position of the empty path.
         pos: usize,
     },
-    /// Too many paths in a single split.
-    #[error("split exceeds maximum {max} paths (found {count})")]
-    SplitTooManyPaths {
-        /// Number of paths found.
-        count: usize,
-        /// Maximum allowed.
-        max: usize,
+    /// `split(...)` nested deeper than [`MAX_SPLIT_DEPTH`].
+    #[error("splits nested deeper than {MAX_SPLIT_DEPTH} at position {pos}")]
+    TooDeep {
+        /// Position of the `split(` that exceeded the limit.
+        pos: usize,
     },
     /// Dry passthrough `-` used outside a split context.
     #[error("dry passthrough '-' is only valid inside a split")]
@@ -116,20 +119,38 @@ pub enum DslParseError {
 
 /// Recursive descent parser for the graph topology DSL.
 ///
-/// LL(1), single byte lookahead. All input is ASCII.
+/// LL(1), single byte lookahead. Structural characters are ASCII; names and
+/// values may hold any UTF-8, which is sliced only at ASCII boundaries.
 struct Parser<'a> {
+    source: &'a str,
     input: &'a [u8],
     pos: usize,
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
     fn new(input: &'a str) -> Self {
         Self {
+            source: input,
             input: input.as_bytes(),
             pos: 0,
+            depth: 0,
         }
     }
 
+    /// Input text between two byte positions, trimmed.
+    ///
+    /// Positions only ever stop at ASCII bytes or the end of input, which are
+    /// always char boundaries; `get` keeps a violation from panicking.
+    fn text(&self, start: usize, end: usize) -> &'a str {
+        self.source.get(start..end).unwrap_or_default().trim()
+    }
+
+    /// The full character at the current position, for error messages.
+    fn current_char(&self) -> Option<char> {
+        self.source.get(self.pos..)?.chars().next()
+    }
+
     fn peek(&self) -> Option<char> {
         self.input.get(self.pos).map(|&b| b as char)
     }
@@ -152,7 +173,7 @@ impl<'a> Parser<'a> {
     fn parse_graph(&mut self) -> Result<GraphSpec, DslParseError> {
         let spec = self.parse_path()?;
         self.skip_ws();
-        if let Some(ch) = self.peek() {
+        if let Some(ch) = self.current_char() {
             return Err(DslParseError::UnexpectedChar { pos: self.pos, ch });
         }
         Ok(spec)
@@ -197,6 +218,10 @@ impl<'a> Parser<'a> {
     /// `split_expr ::= 'split(' path ( ';' path )+ ')'`
     fn parse_split(&mut self) -> Result<GraphNode, DslParseError> {
         let open_pos = self.pos;
+        if self.depth == MAX_SPLIT_DEPTH {
+            return Err(DslParseError::TooDeep { pos: open_pos });
+        }
+        self.depth += 1;
         self.pos += 6; // consume "split("
 
         let mut paths = vec![self.parse_path()?];
@@ -221,16 +246,11 @@ impl<'a> Parser<'a> {
             return Err(DslParseError::UnclosedSplit { pos: open_pos });
         }
         self.advance(); // consume ')'
+        self.depth -= 1;
 
         if paths.len() < 2 {
             return Err(DslParseError::SplitTooFewPaths { count: paths.len() });
         }
-        if paths.len() > MAX_SPLIT_TARGETS {
-            return Err(DslParseError::SplitTooManyPaths {
-                count: paths.len(),
-                max: MAX_SPLIT_TARGETS,
-            });
-        }
 
         Ok(GraphNode::Split { paths })
     }
@@ -248,10 +268,7 @@ impl<'a> Parser<'a> {
             self.advance();
         }
 
-        let name = std::str::from_utf8(&self.input[start..self.pos])
-            .expect("DSL input should be valid UTF-8")
-            .trim()
-            .to_string();
+        let name = self.text(start, self.pos).to_string();
 
         if name.is_empty() {
             return Err(DslParseError::EmptySplitPath { pos: start });
@@ -283,7 +300,7 @@ impl<'a> Parser<'a> {
                         pos: key_start,
                         message: format!(
                             "expected '=' in parameter near '{}'",
-                            std::str::from_utf8(&self.input[key_start..self.pos]).unwrap_or("?")
+                            self.text(key_start, self.pos)
                         ),
                     });
                 }
@@ -297,10 +314,7 @@ impl<'a> Parser<'a> {
                 });
             }
 
-            let key = std::str::from_utf8(&self.input[key_start..self.pos])
-                .expect("UTF-8")
-                .trim()
-                .to_string();
+            let key = self.text(key_start, self.pos).to_string();
             self.advance(); // consume '='
 
             // Value: up to ',', '|', ';', ')', or end
@@ -312,10 +326,7 @@ impl<'a> Parser<'a> {
                 self.advance();
             }
 
-            let value = std::str::from_utf8(&self.input[val_start..self.pos])
-                .expect("UTF-8")
-                .trim()
-                .to_string();
+            let value = self.text(val_start, self.pos).to_string();
 
             params.insert(key, value);
 
@@ -617,6 +628,13 @@ mod tests {
         assert!(matches!(err, DslParseError::SplitTooFewPaths { count: 1 }));
     }
 
+    #[test]
+    fn parse_wide_split() {
+        let dsl = format!("split({})", vec!["reverb"; 32].join("; "));
+        let spec = parse_graph_dsl(&dsl).unwrap();
+        assert!(matches!(&spec[0], GraphNode::Split { paths } if paths.len() == 32));
+    }
+
     #[test]
     fn error_empty_path_in_split() {
         let err = parse_graph_dsl("split(distortion; ; reverb)").unwrap_err();
@@ -629,6 +647,29 @@ mod tests {
         assert!(matches!(err, DslParseError::ParamError { .. }));
     }
 
+    #[test]
+    fn error_split_nesting_too_deep() {
+        let nest =
+            |depth: usize| format!("{}reverb{}", "split(".repeat(depth), "; -)".repeat(depth));
+        assert!(parse_graph_dsl(&nest(MAX_SPLIT_DEPTH)).is_ok());
+        let err = parse_graph_dsl(&nest(MAX_SPLIT_DEPTH + 1)).unwrap_err();
+        assert!(matches!(err, DslParseError::TooDeep { .. }), "{err}");
+        // Far past the limit must still be an error, not a stack overflow
+        assert!(parse_graph_dsl(&"split(".repeat(1_000_000)).is_err());
+    }
+
+    #[test]
+    fn non_ascii_input_does_not_panic() {
+        let spec = parse_graph_dsl("réverb:mïx=½ | délai").unwrap();
+        assert!(matches!(&spec[0], GraphNode::Effect { name, params }
+            if name == "réverb" && params["mïx"] == "½"));
+        let err = parse_graph_dsl("split(a; b)é").unwrap_err();
+        assert!(
+            matches!(err, DslParseError::UnexpectedChar { ch: 'é', .. }),
+            "{err}"
+        );
+    }
+
     // --- Slug tests ---
 
     #[test]
@@ -6,8 +6,9 @@
 //! without coupling to numeric values.
 //!
 //! A high-contrast theme option is available via [`AccessibilityContext`] — when
-//! `high_contrast` is `true`, [`SonidoTheme`](crate::theme::SonidoTheme) replaces
-//! the normal amber/green palette with WCAG-AA-compliant colours.
+//! `high_contrast` is `true`, hosts select
+//! [`ColorScheme::HighContrast`](crate::theme::ColorScheme::HighContrast), which
+//! replaces the normal amber/green palette with WCAG-AA-compliant colours.
 //!
 //! # Integration Pattern
 //!
@@ -38,8 +39,9 @@ pub struct AccessibilityContext {
 
     /// Whether the high-contrast theme variant should be applied.
     ///
-    /// Overrides the normal SonidoTheme palette with colours meeting WCAG AA
-    /// contrast ratios (≥ 4.5 : 1 for normal text, ≥ 3 : 1 for large text).
+    /// Selects [`ColorScheme::HighContrast`](crate::theme::ColorScheme::HighContrast),
+    /// whose colours meet WCAG AA contrast ratios (≥ 4.5 : 1 for normal text,
+    /// ≥ 3 : 1 for large text).
     pub high_contrast: bool,
 }
 
@@ -0,0 +1,315 @@
+//! Copy/paste of one effect's parameters as plain text.
+//!
+//! [`EffectSettings`] captures every parameter of a slot and renders it as a
+//! short TOML-style document, so settings can travel through the system
+//! clipboard between slots, between the standalone app and plugin editors,
+//! or into a text file:
+//!
+//! ```text
+//! # sonido effect settings
+//! effect = "distortion"
+//! dist_drive = 25
+//! dist_tone = 4000
+//! ```
+//!
+//! Parameters are keyed by [`ParamDescriptor::string_id`] (falling back to
+//! the snake_case name when a parameter has none). Pasting checks that the
+//! target slot holds the same effect type; unknown keys are ignored and
+//! values are clamped by the bridge.
+//!
+//! [`copy_paste_buttons`] draws the Copy / Paste pair. Copy goes through
+//! [`egui::Context::copy_text`]; egui has no clipboard read, so the caller
+//! supplies the paste text.
+//!
+//! [`ParamDescriptor::string_id`]: sonido_core::ParamDescriptor::string_id
+
+use core::fmt;
+
+use sonido_core::ParamDescriptor;
+
+use crate::param_bridge::{ParamBridge, ParamIndex, SlotIndex, set_grouped};
+
+/// First line of every settings document.
+pub const HEADER: &str = "# sonido effect settings";
+
+/// Why pasted text could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasteError {
+    /// The text is not a settings document (missing header or effect line).
+    NotSettings,
+    /// A line is not `key = value` with a numeric value.
+    Malformed {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// The settings belong to a different effect type.
+    WrongEffect {
+        /// Effect in the target slot.
+        expected: String,
+        /// Effect named in the settings.
+        found: String,
+    },
+}
+
+impl fmt::Display for PasteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSettings => write!(f, "clipboard does not hold sonido effect settings"),
+            Self::Malformed { line } => write!(f, "malformed settings at line {line}"),
+            Self::WrongEffect { expected, found } => {
+                write!(f, "settings are for {found}, not {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PasteError {}
+
+/// One effect's parameter values, keyed by stable parameter ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectSettings {
+    /// Effect type (registry ID).
+    pub effect_id: String,
+    /// `(key, value)` per parameter, in parameter order.
+    pub params: Vec<(String, f32)>,
+}
+
+impl EffectSettings {
+    /// Capture the parameters of `slot`.
+    pub fn capture(bridge: &dyn ParamBridge, slot: SlotIndex) -> Self {
+        let params = (0..bridge.param_count(slot))
+            .filter_map(|p| {
+                let desc = bridge.param_descriptor(slot, ParamIndex(p))?;
+                Some((param_key(&desc), bridge.get(slot, ParamIndex(p))))
+            })
+            .collect();
+        Self {
+            effect_id: bridge.effect_id(slot).to_string(),
+            params,
+        }
+    }
+
+    /// Render as a settings document.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{HEADER}\neffect = \"{}\"\n", self.effect_id);
+        for (key, value) in &self.params {
+            text.push_str(&format!("{key} = {value}\n"));
+        }
+        text
+    }
+
+    /// Parse a settings document.
+    ///
+    /// Blank lines and `#` comments are skipped.
+    pub fn parse(text: &str) -> Result<Self, PasteError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        if lines.next().map(|(_, line)| line) != Some(HEADER) {
+            return Err(PasteError::NotSettings);
+        }
+
+        let mut effect_id = None;
+        let mut params = Vec::new();
+        for (number, line) in lines {
+            if line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .ok_or(PasteError::Malformed { line: number })?;
+            if key == "effect" {
+                let id = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .ok_or(PasteError::Malformed { line: number })?;
+                effect_id = Some(id.to_string());
+                continue;
+            }
+            let value = value
+                .parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or(PasteError::Malformed { line: number })?;
+            params.push((key.to_string(), value));
+        }
+
+        Ok(Self {
+            effect_id: effect_id.ok_or(PasteError::NotSettings)?,
+            params,
+        })
+    }
+
+    /// Apply to `slot`, which must hold the same effect type.
+    ///
+    /// The parameters are set as one grouped gesture so plugin hosts record
+    /// the paste as a single undo step. Returns the number of parameters set.
+    pub fn apply(&self, bridge: &dyn ParamBridge, slot: SlotIndex) -> Result<usize, PasteError> {
+        let expected = bridge.effect_id(slot);
+        if expected != self.effect_id {
+            return Err(PasteError::WrongEffect {
+                expected: expected.to_string(),
+                found: self.effect_id.clone(),
+            });
+        }
+
+        let mut changes = Vec::new();
+        for p in (0..bridge.param_count(slot)).map(ParamIndex) {
+            let Some(desc) = bridge.param_descriptor(slot, p) else {
+                continue;
+            };
+            let key = param_key(&desc);
+            if let Some(&(_, value)) = self.params.iter().find(|(k, _)| *k == key) {
+                changes.push((slot, p, value));
+            }
+        }
+        set_grouped(bridge, &changes);
+        Ok(changes.len())
+    }
+}
+
+/// Stable text key for a parameter: its `string_id`, or the snake_case name.
+fn param_key(desc: &ParamDescriptor) -> String {
+    if desc.string_id.is_empty() {
+        desc.name.to_lowercase().replace([' ', '-'], "_")
+    } else {
+        desc.string_id.to_string()
+    }
+}
+
+/// Copy and Paste buttons for one slot.
+///
+/// `read_clipboard` is called only when Paste is clicked and returns the
+/// clipboard text, if any. Returns the paste outcome when Paste was clicked.
+pub fn copy_paste_buttons(
+    ui: &mut egui::Ui,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    read_clipboard: impl FnOnce() -> Option<String>,
+) -> Option<Result<usize, PasteError>> {
+    if ui
+        .small_button("Copy")
+        .on_hover_text("Copy this effect's settings")
+        .clicked()
+    {
+        ui.ctx()
+            .copy_text(EffectSettings::capture(bridge, slot).to_text());
+    }
+    ui.small_button("Paste")
+        .on_hover_text("Paste settings copied from the same effect type")
+        .clicked()
+        .then(|| {
+            let text = read_clipboard().ok_or(PasteError::NotSettings)?;
+            EffectSettings::parse(&text)?.apply(bridge, slot)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::ParamId;
+    use std::sync::Mutex;
+
+    struct Bridge {
+        effect_id: &'static str,
+        descriptors: Vec<ParamDescriptor>,
+        values: Mutex<Vec<f32>>,
+    }
+
+    impl Bridge {
+        fn new(effect_id: &'static str) -> Self {
+            let descriptors = vec![
+                ParamDescriptor::gain_db("Drive", "Drive", 0.0, 40.0, 12.0)
+                    .with_id(ParamId(1), "dist_drive"),
+                ParamDescriptor::custom("Tone Shape", "Tone", 0.0, 1.0, 0.5),
+            ];
+            let values = Mutex::new(descriptors.iter().map(|d| d.default).collect());
+            Self {
+                effect_id,
+                descriptors,
+                values,
+            }
+        }
+    }
+
+    impl ParamBridge for Bridge {
+        fn slot_count(&self) -> usize {
+            1
+        }
+        fn effect_id(&self, _slot: SlotIndex) -> &str {
+            self.effect_id
+        }
+        fn param_count(&self, _slot: SlotIndex) -> usize {
+            self.descriptors.len()
+        }
+        fn param_descriptor(&self, _slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+            self.descriptors.get(param.0).copied()
+        }
+        fn get(&self, _slot: SlotIndex, param: ParamIndex) -> f32 {
+            self.values.lock().unwrap()[param.0]
+        }
+        fn set(&self, _slot: SlotIndex, param: ParamIndex, value: f32) {
+            let desc = &self.descriptors[param.0];
+            self.values.lock().unwrap()[param.0] = value.clamp(desc.min, desc.max);
+        }
+        fn is_bypassed(&self, _slot: SlotIndex) -> bool {
+            false
+        }
+        fn set_bypassed(&self, _slot: SlotIndex, _bypassed: bool) {}
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let source = Bridge::new("distortion");
+        source.set(SlotIndex(0), ParamIndex(0), 27.5);
+        source.set(SlotIndex(0), ParamIndex(1), 0.125);
+
+        let text = EffectSettings::capture(&source, SlotIndex(0)).to_text();
+        assert!(text.starts_with(HEADER));
+        assert!(text.contains("dist_drive = 27.5"));
+        assert!(text.contains("tone_shape = 0.125"));
+
+        let target = Bridge::new("distortion");
+        let settings = EffectSettings::parse(&text).unwrap();
+        assert_eq!(settings.apply(&target, SlotIndex(0)), Ok(2));
+        assert_eq!(target.get(SlotIndex(0), ParamIndex(0)), 27.5);
+        assert_eq!(target.get(SlotIndex(0), ParamIndex(1)), 0.125);
+    }
+
+    #[test]
+    fn paste_validates_effect_and_text() {
+        let reverb = Bridge::new("reverb");
+        let settings = EffectSettings::parse(&format!(
+            "{HEADER}\neffect = \"distortion\"\n# note\ndist_drive = 99\nunknown = 1\n"
+        ))
+        .unwrap();
+        assert_eq!(
+            settings.apply(&reverb, SlotIndex(0)),
+            Err(PasteError::WrongEffect {
+                expected: "reverb".into(),
+                found: "distortion".into(),
+            })
+        );
+
+        // Unknown keys are skipped; values are clamped by the bridge.
+        let dist = Bridge::new("distortion");
+        assert_eq!(settings.apply(&dist, SlotIndex(0)), Ok(1));
+        assert_eq!(dist.get(SlotIndex(0), ParamIndex(0)), 40.0);
+
+        assert_eq!(
+            EffectSettings::parse("drive = 1"),
+            Err(PasteError::NotSettings)
+        );
+        assert_eq!(
+            EffectSettings::parse(&format!("{HEADER}\ndist_drive = 1\n")),
+            Err(PasteError::NotSettings)
+        );
+        assert_eq!(
+            EffectSettings::parse(&format!("{HEADER}\neffect = \"delay\"\ntime = soon\n")),
+            Err(PasteError::Malformed { line: 3 })
+        );
+    }
+}
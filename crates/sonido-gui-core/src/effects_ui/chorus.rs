@@ -22,10 +22,11 @@ impl ChorusPanel {
     ///
     /// Param indices: 0 = rate (Hz), 1 = depth (%), 2 = mix (%),
     /// 3 = voices, 4 = feedback (%), 5 = base delay (ms),
-    /// 6 = sync (on/off), 7 = division (note value), 8 = output (dB).
+    /// 6 = sync (on/off), 7 = division (note value), 8 = output (dB),
+    /// 9 = LFO phase (read-only), 10 = spread (%).
     pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
         let theme = SonidoTheme::get(ui.ctx());
-        let fader_indices: &[usize] = &[0, 1, 2, 3, 4, 5, 8];
+        let fader_indices: &[usize] = &[0, 1, 2, 3, 4, 5, 10, 8];
         let param_count = fader_indices.len();
         let avail_w = ui.available_width();
         let fader_w = theme.layout.fader_width(avail_w, param_count);
@@ -26,10 +26,10 @@ impl DelayPanel {
     /// Param indices: 0 = time (ms), 1 = feedback (%), 2 = mix (%),
     /// 3 = ping pong (on/off), 4 = feedback LP (Hz), 5 = feedback HP (Hz),
     /// 6 = diffusion (%), 7 = sync (on/off), 8 = division (note value),
-    /// 9 = output (dB).
+    /// 9 = output (dB), 10 = wow (%), 11 = flutter (%), 12 = saturation (%).
     pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
         let theme = SonidoTheme::get(ui.ctx());
-        let fader_indices: &[usize] = &[0, 1, 2, 4, 5, 6, 9];
+        let fader_indices: &[usize] = &[0, 1, 2, 4, 5, 6, 10, 11, 12, 9];
         let param_count = fader_indices.len();
         let avail_w = ui.available_width();
         let fader_w = theme.layout.fader_width(avail_w, param_count);
@@ -1,11 +1,36 @@
 //! Parametric EQ effect UI panel.
 
 use crate::theme::SonidoTheme;
-use crate::widgets::bridged_fader;
+use crate::widgets::{EqBandParams, bridged_eq_curve, bridged_fader};
 use crate::{ParamBridge, ParamIndex, SlotIndex};
 use egui::Ui;
 
+/// Parameter indices for the three bands, low to high.
+const BANDS: [EqBandParams; 3] = [
+    EqBandParams {
+        freq: ParamIndex(0),
+        gain: ParamIndex(1),
+        q: ParamIndex(2),
+    },
+    EqBandParams {
+        freq: ParamIndex(3),
+        gain: ParamIndex(4),
+        q: ParamIndex(5),
+    },
+    EqBandParams {
+        freq: ParamIndex(6),
+        gain: ParamIndex(7),
+        q: ParamIndex(8),
+    },
+];
+
+/// Height of the response curve in points.
+const CURVE_HEIGHT: f32 = 110.0;
+
 /// UI panel for the 3-band parametric EQ effect.
+///
+/// A frequency-response curve sits above the per-band faders; its handles
+/// set each band's frequency and gain by dragging and its Q by scrolling.
 pub struct ParametricEqPanel;
 
 impl ParametricEqPanel {
@@ -28,6 +53,8 @@ impl ParametricEqPanel {
         let fader_h = theme.layout.fader_height(ui.available_height().min(200.0));
 
         ui.vertical(|ui| {
+            bridged_eq_curve(ui, bridge, slot, &BANDS, avail_w, CURVE_HEIGHT);
+            ui.add_space(4.0);
             // Low band (params 0, 1, 2)
             Self::render_band(ui, bridge, slot, "LOW", 0, fader_w, fader_h, &theme);
             ui.add_space(4.0);
@@ -22,10 +22,11 @@ impl FlangerPanel {
     ///
     /// Param indices: 0 = rate (Hz), 1 = depth (%), 2 = feedback (%),
     /// 3 = mix (%), 4 = TZF (on/off),
-    /// 5 = sync (on/off), 6 = division (note value), 7 = output (dB).
+    /// 5 = sync (on/off), 6 = division (note value), 7 = output (dB),
+    /// 8 = LFO phase (read-only), 9 = spread (%), 10 = cross feedback (%).
     pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
         let theme = SonidoTheme::get(ui.ctx());
-        let fader_indices: &[usize] = &[0, 1, 2, 3, 7];
+        let fader_indices: &[usize] = &[0, 1, 2, 9, 10, 3, 7];
         let param_count = fader_indices.len();
         let avail_w = ui.available_width();
         let fader_w = theme.layout.fader_width(avail_w, param_count);
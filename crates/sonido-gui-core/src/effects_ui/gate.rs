@@ -5,18 +5,31 @@ use crate::widgets::bridged_fader;
 use crate::{ParamBridge, ParamIndex, SlotIndex};
 use egui::Ui;
 
+const THRESHOLD: ParamIndex = ParamIndex(0);
+const RELEASE: ParamIndex = ParamIndex(2);
+const HOLD: ParamIndex = ParamIndex(3);
+const LEARN: ParamIndex = ParamIndex(12);
+const LEARN_PROGRESS: ParamIndex = ParamIndex(13);
+const LEARNED_THRESHOLD: ParamIndex = ParamIndex(14);
+const LEARNED_HOLD: ParamIndex = ParamIndex(15);
+const LEARNED_RELEASE: ParamIndex = ParamIndex(16);
+
 /// UI panel for the noise gate effect.
-pub struct GatePanel;
+pub struct GatePanel {
+    /// Hold and release (ms) suggested by the last learn, until applied or dismissed.
+    suggestion: Option<(f32, f32)>,
+}
 
 impl GatePanel {
     /// Create a new gate panel.
     pub fn new() -> Self {
-        Self
+        Self { suggestion: None }
     }
 
     /// Render the noise gate controls.
     ///
     /// Param indices: 0 = threshold (dB), 1 = attack (ms), 2 = release (ms), 3 = hold (ms).
+    /// The Learn row drives 12 = learn and reads the results at 13–16.
     pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
         let theme = SonidoTheme::get(ui.ctx());
         let param_count = 4;
@@ -25,6 +38,10 @@ impl GatePanel {
         let fader_h = theme.layout.fader_height(ui.available_height().min(200.0));
 
         ui.vertical(|ui| {
+            ui.horizontal(|ui| self.learn_ui(ui, bridge, slot));
+
+            ui.add_space(8.0);
+
             ui.horizontal_wrapped(|ui| {
                 for i in 0..param_count {
                     bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
@@ -32,6 +49,68 @@ impl GatePanel {
             });
         });
     }
+
+    /// Learn button, progress while the gate listens, and the hold/release
+    /// suggestion once it finishes.
+    ///
+    /// A finished learn sets the threshold straight away and switches learn
+    /// off; hold and release are only applied on request.
+    fn learn_ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
+        if bridge.get(slot, LEARN) >= 0.5 {
+            let progress = bridge.get(slot, LEARN_PROGRESS);
+            if progress < 1.0 {
+                ui.add(
+                    egui::ProgressBar::new(progress)
+                        .desired_width(140.0)
+                        .text("Listening…"),
+                );
+                if ui.small_button("Cancel").clicked() {
+                    bridge.set(slot, LEARN, 0.0);
+                }
+                ui.ctx().request_repaint();
+                return;
+            }
+            set_with_gesture(bridge, slot, THRESHOLD, bridge.get(slot, LEARNED_THRESHOLD));
+            bridge.set(slot, LEARN, 0.0);
+            self.suggestion = Some((
+                bridge.get(slot, LEARNED_HOLD),
+                bridge.get(slot, LEARNED_RELEASE),
+            ));
+        }
+
+        if ui
+            .button("Learn")
+            .on_hover_text(
+                "Play nothing (just the noise) for a few seconds; \
+                 the threshold is set just above it.",
+            )
+            .clicked()
+        {
+            self.suggestion = None;
+            bridge.set(slot, LEARN, 1.0);
+        }
+
+        if let Some((hold, release)) = self.suggestion {
+            ui.label(format!(
+                "Suggested: hold {hold:.0} ms, release {release:.0} ms"
+            ));
+            if ui.small_button("Apply").clicked() {
+                set_with_gesture(bridge, slot, HOLD, hold);
+                set_with_gesture(bridge, slot, RELEASE, release);
+                self.suggestion = None;
+            }
+            if ui.small_button("Dismiss").clicked() {
+                self.suggestion = None;
+            }
+        }
+    }
+}
+
+/// Set one parameter as a single host gesture.
+fn set_with_gesture(bridge: &dyn ParamBridge, slot: SlotIndex, param: ParamIndex, value: f32) {
+    bridge.begin_set(slot, param);
+    bridge.set(slot, param, value);
+    bridge.end_set(slot, param);
 }
 
 impl Default for GatePanel {
@@ -3,7 +3,9 @@
 //! [`GenericPanel`] renders any registered effect's parameters using
 //! [`bridged_fader`] for continuous parameters and [`bridged_combo`] for
 //! stepped/enum parameters. It discovers parameter metadata at render time
-//! via the [`ParamBridge`], so it works for any effect ID.
+//! via the [`ParamBridge`], so it works for any effect ID. Parameters with a
+//! [`group`](sonido_core::ParamDescriptor::group) are collected into
+//! collapsible sections below the ungrouped ones.
 //!
 //! This is the catch-all panel returned by [`create_panel`](super::create_panel)
 //! when no dedicated panel exists for the given effect ID.
@@ -22,8 +24,9 @@ const FADERS_PER_ROW: usize = 6;
 ///
 /// Renders all visible parameters in rows of 6, using
 /// [`bridged_combo`] for stepped (enum) parameters and [`bridged_fader`]
-/// for continuous parameters. Parameters flagged `READ_ONLY` or `HIDDEN`
-/// are skipped.
+/// for continuous parameters. Grouped parameters go in one collapsible
+/// section per group, in order of first appearance. Parameters flagged
+/// `READ_ONLY` or `HIDDEN` are skipped.
 ///
 /// The display name and short name are derived from the effect ID at
 /// construction time (capitalized ID, first 4 characters short).
@@ -83,6 +86,7 @@ impl GenericPanel {
     ///
     /// Parameters are rendered in rows of 6. Stepped
     /// (enum) parameters use a combo box; continuous parameters use a fader.
+    /// Grouped parameters follow in collapsible sections, open by default.
     /// `READ_ONLY` and `HIDDEN` parameters are skipped.
     pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
         let theme = SonidoTheme::get(ui.ctx());
@@ -97,72 +101,97 @@ impl GenericPanel {
             return;
         }
 
-        // Collect visible param indices, split into stepped vs continuous.
-        let mut stepped: Vec<usize> = Vec::new();
-        let mut continuous: Vec<usize> = Vec::new();
-
+        // Collect visible param indices by group; "" (ungrouped) comes first.
+        let mut sections: Vec<(&'static str, Vec<usize>)> = vec![("", Vec::new())];
         for i in 0..param_count {
-            let desc = bridge.param_descriptor(slot, ParamIndex(i));
-            if let Some(ref d) = desc {
-                if d.flags.contains(ParamFlags::HIDDEN) || d.flags.contains(ParamFlags::READ_ONLY) {
+            let group = match bridge.param_descriptor(slot, ParamIndex(i)) {
+                Some(d)
+                    if d.flags.contains(ParamFlags::HIDDEN)
+                        || d.flags.contains(ParamFlags::READ_ONLY) =>
+                {
                     continue;
                 }
-                if d.flags.contains(ParamFlags::STEPPED) {
-                    stepped.push(i);
+                Some(d) => d.group,
+                None => "",
+            };
+            match sections.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, params)) => params.push(i),
+                None => sections.push((group, vec![i])),
+            }
+        }
+
+        ui.vertical(|ui| {
+            for (group, params) in &sections {
+                if group.is_empty() {
+                    self.params_ui(ui, bridge, slot, params);
                 } else {
-                    continuous.push(i);
+                    egui::CollapsingHeader::new(
+                        egui::RichText::new(*group)
+                            .font(egui::FontId::monospace(10.0))
+                            .color(theme.colors.text_secondary),
+                    )
+                    .id_salt((&self.effect_id, *group, slot.0))
+                    .default_open(true)
+                    .show(ui, |ui| self.params_ui(ui, bridge, slot, params));
                 }
-            } else {
-                continuous.push(i);
             }
-        }
+        });
+    }
+
+    /// Render one section: stepped params as a row of combos, then
+    /// continuous params as faders in rows of [`FADERS_PER_ROW`].
+    fn params_ui(&self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex, params: &[usize]) {
+        let theme = SonidoTheme::get(ui.ctx());
+
+        let (stepped, continuous): (Vec<usize>, Vec<usize>) = params.iter().partition(|&&i| {
+            bridge
+                .param_descriptor(slot, ParamIndex(i))
+                .is_some_and(|d| d.flags.contains(ParamFlags::STEPPED))
+        });
 
         let avail_w = ui.available_width();
         let row_count = continuous.len().clamp(1, FADERS_PER_ROW);
         let fader_w = theme.layout.fader_width(avail_w, row_count);
         let fader_h = theme.layout.fader_height(ui.available_height().min(200.0));
 
-        ui.vertical(|ui| {
-            // Stepped (combo) params in a horizontal row
-            if !stepped.is_empty() {
-                ui.horizontal(|ui| {
-                    for &i in &stepped {
-                        let desc = bridge.param_descriptor(slot, ParamIndex(i));
-                        let label_str = desc.as_ref().map_or("Param", |d| d.short_name);
-                        ui.label(
-                            egui::RichText::new(format!("{label_str}:"))
-                                .font(egui::FontId::monospace(10.0))
-                                .color(theme.colors.text_secondary),
-                        );
-
-                        let id_salt = format!("{}_{}", self.effect_id, i);
-                        if let Some(ref d) = desc {
-                            if let Some(labels) = d.step_labels {
-                                bridged_combo(ui, bridge, slot, ParamIndex(i), &id_salt, labels);
-                            } else {
-                                let count = (d.max - d.min).round() as usize + 1;
-                                let generated: Vec<String> =
-                                    (0..count).map(|n| n.to_string()).collect();
-                                let refs: Vec<&str> =
-                                    generated.iter().map(String::as_str).collect();
-                                bridged_combo(ui, bridge, slot, ParamIndex(i), &id_salt, &refs);
-                            }
+        // Stepped (combo) params in a horizontal row
+        if !stepped.is_empty() {
+            ui.horizontal(|ui| {
+                for &i in &stepped {
+                    let desc = bridge.param_descriptor(slot, ParamIndex(i));
+                    let label_str = desc.as_ref().map_or("Param", |d| d.short_name);
+                    ui.label(
+                        egui::RichText::new(format!("{label_str}:"))
+                            .font(egui::FontId::monospace(10.0))
+                            .color(theme.colors.text_secondary),
+                    );
+
+                    let id_salt = format!("{}_{}", self.effect_id, i);
+                    if let Some(ref d) = desc {
+                        if let Some(labels) = d.step_labels {
+                            bridged_combo(ui, bridge, slot, ParamIndex(i), &id_salt, labels);
+                        } else {
+                            let count = (d.max - d.min).round() as usize + 1;
+                            let generated: Vec<String> =
+                                (0..count).map(|n| n.to_string()).collect();
+                            let refs: Vec<&str> = generated.iter().map(String::as_str).collect();
+                            bridged_combo(ui, bridge, slot, ParamIndex(i), &id_salt, &refs);
                         }
-                        ui.add_space(8.0);
                     }
-                });
-                ui.add_space(8.0);
-            }
+                    ui.add_space(8.0);
+                }
+            });
+            ui.add_space(8.0);
+        }
 
-            // Continuous params in rows of FADERS_PER_ROW
-            if !continuous.is_empty() {
-                ui.horizontal_wrapped(|ui| {
-                    for &i in &continuous {
-                        bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
-                    }
-                });
-            }
-        });
+        // Continuous params in rows of FADERS_PER_ROW
+        if !continuous.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                for &i in &continuous {
+                    bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
+                }
+            });
+        }
     }
 }
 
@@ -17,11 +17,14 @@ mod gate;
 pub mod generic;
 mod limiter;
 pub mod looper;
+mod multitap_delay;
 mod phaser;
 mod preamp;
 mod reverb;
 mod ringmod;
+mod slicer;
 mod stage;
+mod synth;
 mod tape;
 mod tremolo;
 mod vibrato;
@@ -39,11 +42,14 @@ pub use gate::GatePanel;
 pub use generic::GenericPanel;
 pub use limiter::LimiterPanel;
 pub use looper::LooperPanel;
+pub use multitap_delay::MultiTapDelayPanel;
 pub use phaser::PhaserPanel;
 pub use preamp::PreampPanel;
 pub use reverb::ReverbPanel;
 pub use ringmod::RingModPanel;
+pub use slicer::SlicerPanel;
 pub use stage::StagePanel;
+pub use synth::SynthPanel;
 pub use tape::TapePanel;
 pub use tremolo::TremoloPanel;
 pub use vibrato::VibratoPanel;
@@ -100,12 +106,15 @@ impl_effect_panel!(FlangerPanel, "Flanger", "Flgr");
 impl_effect_panel!(PhaserPanel, "Phaser", "Phsr");
 impl_effect_panel!(TremoloPanel, "Tremolo", "Trem");
 impl_effect_panel!(DelayPanel, "Delay", "Dly");
+impl_effect_panel!(MultiTapDelayPanel, "Multi-Tap Delay", "MTap");
 impl_effect_panel!(FilterPanel, "Filter", "Flt");
 impl_effect_panel!(VibratoPanel, "Vibrato", "Vib");
 impl_effect_panel!(TapePanel, "Tape", "Tape");
 impl_effect_panel!(ReverbPanel, "Reverb", "Rev");
 impl_effect_panel!(StagePanel, "Stage", "Stge");
 impl_effect_panel!(LooperPanel, "Looper", "Loop");
+impl_effect_panel!(SlicerPanel, "Slicer", "Slcr");
+impl_effect_panel!(SynthPanel, "Synth", "Syn");
 
 /// Create an effect panel for the given registry effect ID.
 ///
@@ -125,6 +134,7 @@ pub fn create_panel(effect_id: &str) -> Option<Box<dyn EffectPanel + Send + Sync
         "phaser" => Some(Box::new(PhaserPanel::new())),
         "tremolo" => Some(Box::new(TremoloPanel::new())),
         "delay" => Some(Box::new(DelayPanel::new())),
+        "multitap_delay" => Some(Box::new(MultiTapDelayPanel::new())),
         "filter" => Some(Box::new(FilterPanel::new())),
         "vibrato" => Some(Box::new(VibratoPanel::new())),
         "tape" => Some(Box::new(TapePanel::new())),
@@ -134,6 +144,8 @@ pub fn create_panel(effect_id: &str) -> Option<Box<dyn EffectPanel + Send + Sync
         "ringmod" => Some(Box::new(RingModPanel::new())),
         "stage" => Some(Box::new(StagePanel::new())),
         "looper" => Some(Box::new(LooperPanel::new())),
+        "slicer" => Some(Box::new(SlicerPanel::new())),
+        "synth" => Some(Box::new(SynthPanel::new())),
         other => GenericPanel::try_new(other).map(|p| Box::new(p) as _),
     }
 }
@@ -189,6 +201,28 @@ mod tests {
         assert_eq!(p.short_name(), "Loop");
     }
 
+    #[test]
+    fn create_panel_slicer() {
+        let panel = create_panel("slicer").expect("create_panel(\"slicer\") returned None");
+        assert_eq!(panel.name(), "Slicer");
+        assert_eq!(panel.short_name(), "Slcr");
+    }
+
+    #[test]
+    fn create_panel_multitap_delay() {
+        let panel =
+            create_panel("multitap_delay").expect("create_panel(\"multitap_delay\") returned None");
+        assert_eq!(panel.name(), "Multi-Tap Delay");
+        assert_eq!(panel.short_name(), "MTap");
+    }
+
+    #[test]
+    fn create_panel_synth() {
+        let panel = create_panel("synth").expect("create_panel(\"synth\") returned None");
+        assert_eq!(panel.name(), "Synth");
+        assert_eq!(panel.short_name(), "Syn");
+    }
+
     #[test]
     fn create_panel_generic_fallback() {
         // Known registry effect without a dedicated panel returns a GenericPanel
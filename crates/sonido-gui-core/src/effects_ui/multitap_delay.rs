@@ -0,0 +1,160 @@
+//! Multi-tap delay effect UI panel.
+
+use crate::theme::SonidoTheme;
+use crate::widgets::{TapParams, bridged_combo, bridged_fader, bridged_tap_timeline};
+use crate::{ParamBridge, ParamIndex, SlotIndex};
+use egui::Ui;
+use sonido_core::DIVISION_LABELS;
+
+/// Tap pattern labels.
+const PATTERN_LABELS: &[&str] = &["Manual", "Even", "Dotted", "Triplet", "Accel", "Ritard"];
+
+/// Sync toggle labels.
+const SYNC_LABELS: &[&str] = &["Off", "On"];
+
+/// Number of taps.
+const TAPS: usize = 4;
+
+/// First per-tap parameter.
+const TAP_BASE: usize = 4;
+
+/// Parameters per tap: time, division, level, pan, feedback.
+const TAP_FIELDS: usize = 5;
+
+/// First read-only effective tap time.
+const ACTUAL_BASE: usize = 25;
+
+/// Combo id salts for the per-tap division selectors.
+const TAP_DIVISION_IDS: [&str; TAPS] = [
+    "mtd_tap1_division",
+    "mtd_tap2_division",
+    "mtd_tap3_division",
+    "mtd_tap4_division",
+];
+
+/// UI panel for the multi-tap delay effect.
+pub struct MultiTapDelayPanel;
+
+impl MultiTapDelayPanel {
+    /// Create a new multi-tap delay panel.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render the multi-tap delay effect controls.
+    ///
+    /// Param indices: 0 = pattern, 1 = length (ms), 2 = sync (on/off),
+    /// 3 = division (note value), 4–23 = taps 1–4 as (time (ms), division,
+    /// level (%), pan (%), feedback (%)), 24 = mix (%), 25–28 = effective
+    /// tap times (read-only), 29 = output (dB).
+    pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
+        let theme = SonidoTheme::get(ui.ctx());
+        let avail_w = ui.available_width();
+        // Widest row: four faders per tap across two taps.
+        let fader_w = theme.layout.fader_width(avail_w, 2 * (TAP_FIELDS - 1));
+        let fader_h = theme.layout.fader_height(ui.available_height().min(160.0));
+
+        let timeline: Vec<TapParams> = (0..TAPS)
+            .map(|t| {
+                let base = TAP_BASE + t * TAP_FIELDS;
+                TapParams {
+                    time: ParamIndex(ACTUAL_BASE + t),
+                    level: ParamIndex(base + 2),
+                    pan: ParamIndex(base + 3),
+                }
+            })
+            .collect();
+        let length_ms = bridge.get(slot, ParamIndex(1));
+
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Pattern:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(0),
+                    "mtd_pattern",
+                    PATTERN_LABELS,
+                );
+
+                ui.add_space(12.0);
+
+                ui.label("Sync:");
+                bridged_combo(ui, bridge, slot, ParamIndex(2), "mtd_sync", SYNC_LABELS);
+
+                ui.add_space(8.0);
+
+                ui.label("Div:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(3),
+                    "mtd_division",
+                    DIVISION_LABELS,
+                );
+            });
+
+            ui.add_space(8.0);
+
+            bridged_tap_timeline(
+                ui,
+                bridge,
+                slot,
+                &timeline,
+                length_ms,
+                avail_w.min(480.0),
+                72.0,
+            );
+
+            ui.add_space(8.0);
+
+            ui.horizontal_wrapped(|ui| {
+                for i in [1, 24, 29] {
+                    bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
+                }
+            });
+
+            ui.add_space(8.0);
+
+            ui.horizontal_wrapped(|ui| {
+                for (t, id_salt) in TAP_DIVISION_IDS.iter().enumerate() {
+                    let base = TAP_BASE + t * TAP_FIELDS;
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Tap {}", t + 1));
+                            bridged_combo(
+                                ui,
+                                bridge,
+                                slot,
+                                ParamIndex(base + 1),
+                                id_salt,
+                                DIVISION_LABELS,
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            for field in [0, 2, 3, 4] {
+                                bridged_fader(
+                                    ui,
+                                    bridge,
+                                    slot,
+                                    ParamIndex(base + field),
+                                    fader_w,
+                                    fader_h,
+                                );
+                            }
+                        });
+                    });
+                    ui.add_space(8.0);
+                }
+            });
+        });
+    }
+}
+
+impl Default for MultiTapDelayPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
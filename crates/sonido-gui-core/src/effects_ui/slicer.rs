@@ -0,0 +1,78 @@
+//! Slicer effect UI panel.
+
+use crate::theme::SonidoTheme;
+use crate::widgets::{bridged_combo, bridged_fader, bridged_step_pattern};
+use crate::{ParamBridge, ParamIndex, SlotIndex};
+use egui::Ui;
+use sonido_core::DIVISION_LABELS;
+
+/// Number of pattern steps (params 0–15).
+const STEPS: usize = 16;
+
+/// UI panel for the slicer effect.
+pub struct SlicerPanel;
+
+impl SlicerPanel {
+    /// Create a new slicer panel.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render the slicer effect controls.
+    ///
+    /// Param indices: 0–15 = step levels (Off/On/Accent), 16 = steps
+    /// (pattern length), 17 = division (note value), 18 = shuffle (%),
+    /// 19 = attack (ms), 20 = release (ms), 21 = depth (%), 22 = accent (dB),
+    /// 23 = mix (%), 24 = position (read-only), 25 = output (dB).
+    pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
+        let theme = SonidoTheme::get(ui.ctx());
+        let fader_indices: &[usize] = &[16, 18, 19, 20, 21, 22, 23, 25];
+        let param_count = fader_indices.len();
+        let avail_w = ui.available_width();
+        let fader_w = theme.layout.fader_width(avail_w, param_count);
+        let fader_h = theme.layout.fader_height(ui.available_height().min(200.0));
+
+        let length = (bridge.get(slot, ParamIndex(16)) + 0.5) as usize;
+        let position = (bridge.get(slot, ParamIndex(24)) + 0.5) as usize;
+
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Div:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(17),
+                    "slicer_division",
+                    DIVISION_LABELS,
+                );
+            });
+
+            ui.add_space(8.0);
+
+            bridged_step_pattern(
+                ui,
+                bridge,
+                slot,
+                ParamIndex(0),
+                STEPS,
+                length,
+                Some(position),
+            );
+
+            ui.add_space(12.0);
+
+            ui.horizontal_wrapped(|ui| {
+                for &i in fader_indices {
+                    bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
+                }
+            });
+        });
+    }
+}
+
+impl Default for SlicerPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
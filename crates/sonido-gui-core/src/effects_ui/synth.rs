@@ -0,0 +1,101 @@
+//! Polyphonic synthesizer UI panel.
+
+use crate::theme::SonidoTheme;
+use crate::widgets::{bridged_combo, bridged_fader};
+use crate::{ParamBridge, ParamIndex, SlotIndex};
+use egui::Ui;
+
+/// Oscillator waveform labels (params 0 and 1).
+const OSC_WAVEFORMS: &[&str] = &["Sine", "Triangle", "Saw", "Square", "Noise"];
+
+/// LFO waveform labels (param 16).
+const LFO_WAVEFORMS: &[&str] = &["Sine", "Triangle", "Saw", "Square", "S&H"];
+
+/// UI panel for the polyphonic synthesizer (`sonido_synth::SynthNode`).
+pub struct SynthPanel;
+
+impl SynthPanel {
+    /// Create a new synth panel.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render the synthesizer controls.
+    ///
+    /// Param indices: 0/1 = osc 1/2 waveform (enum), 2 = osc 2 detune (cents),
+    /// 3 = osc mix (%), 4 = cutoff (Hz), 5 = resonance, 6 = filter env
+    /// amount (Hz), 7–10 = amp ADSR, 11–14 = filter ADSR, 15 = LFO rate (Hz),
+    /// 16 = LFO waveform (enum), 17 = LFO → pitch (st), 18 = LFO → filter
+    /// (Hz), 19 = output (dB).
+    pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, slot: SlotIndex) {
+        let theme = SonidoTheme::get(ui.ctx());
+        // Widest row is an envelope: label + 4 faders
+        let avail_w = ui.available_width();
+        let fader_w = theme.layout.fader_width(avail_w, 5);
+        let fader_h = theme.layout.fader_height(ui.available_height().min(120.0));
+
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Osc 1:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(0),
+                    "synth_osc1_wave",
+                    OSC_WAVEFORMS,
+                );
+
+                ui.add_space(8.0);
+
+                ui.label("Osc 2:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(1),
+                    "synth_osc2_wave",
+                    OSC_WAVEFORMS,
+                );
+
+                ui.add_space(8.0);
+
+                ui.label("LFO:");
+                bridged_combo(
+                    ui,
+                    bridge,
+                    slot,
+                    ParamIndex(16),
+                    "synth_lfo_wave",
+                    LFO_WAVEFORMS,
+                );
+            });
+
+            ui.add_space(8.0);
+
+            let rows: [(&str, &[usize]); 5] = [
+                ("OSC", &[2, 3, 19]),
+                ("FILTER", &[4, 5, 6]),
+                ("AMP", &[7, 8, 9, 10]),
+                ("F.ENV", &[11, 12, 13, 14]),
+                ("LFO", &[15, 17, 18]),
+            ];
+            for (label, indices) in rows {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(label).color(theme.colors.cyan).small());
+                    ui.add_space(8.0);
+                    for &i in indices {
+                        bridged_fader(ui, bridge, slot, ParamIndex(i), fader_w, fader_h);
+                    }
+                });
+                ui.add_space(4.0);
+            }
+        });
+    }
+}
+
+impl Default for SynthPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -8,25 +8,30 @@
 //! # Modules
 //!
 //! - [`param_bridge`] — Parameter bridge trait with gesture protocol for GUI↔audio communication
+//! - [`clipboard`] — Text format for copying an effect's parameters between slots and instances
 //! - [`theme`] — Visual styling constants and egui theme application
 //! - [`widgets`] — Audio-specific widgets (knobs, meters, toggles, spectrum, waveform)
 //! - [`effects_ui`] — Per-effect UI panels (one per effect type)
 //! - [`undo`] — Undo/redo history with gesture grouping for parameter mutations
 
 pub mod accessibility;
+pub mod clipboard;
 pub mod effects_ui;
 pub mod param_bridge;
 pub mod theme;
 pub mod undo;
 pub mod widgets;
 
+pub use clipboard::{EffectSettings, PasteError};
 pub use effects_ui::{EffectPanel, create_panel};
-pub use param_bridge::{ChainMutator, ParamBridge, ParamIndex, SlotIndex};
+pub use param_bridge::{ChainMutator, ParamBridge, ParamIndex, SlotIndex, set_grouped};
 pub use theme::{SonidoTheme, Theme};
 pub use undo::{Mutation, UndoHistory};
 pub use widgets::{
-    BypassToggle, Fader, FootswitchToggle, GainReductionMeter, Knob, LedDisplay, LevelMeter,
-    MorphBarResponse, SpectrumState, SpectrumWidget, WaveformState, WaveformWidget, bridged_combo,
-    bridged_fader, bridged_knob, bridged_knob_fmt, bridged_knob_with_morph, gesture_wrap,
-    morph_bar,
+    BypassToggle, CorrelationMeter, EqBand, EqBandParams, EqCurve, Fader, FootswitchToggle,
+    GainReductionMeter, GoniometerState, GoniometerWidget, Knob, LedDisplay, LevelMeter,
+    LoudnessMeter, MorphBarResponse, PeakPyramid, SpectrumState, SpectrumWidget, StepPatternEditor,
+    WaveformMarker, WaveformState, WaveformView, WaveformViewResponse, WaveformViewState,
+    WaveformWidget, bridged_combo, bridged_eq_curve, bridged_fader, bridged_knob, bridged_knob_fmt,
+    bridged_knob_with_morph, bridged_step_pattern, gesture_wrap, morph_bar,
 };
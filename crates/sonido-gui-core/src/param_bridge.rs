@@ -16,7 +16,7 @@
 //!                 end_set(slot, param)
 //!                         │
 //!                    ┌────┴────┐
-//!                    │ Atomic  │  (standalone — begin/end are no-ops)
+//!                    │ Atomic  │  (standalone — begin/end group undo steps)
 //!                    │ Clack   │  (CLAP plugin — begin/end map to gesture events)
 //!                    └────┬────┘
 //!                         │
@@ -30,6 +30,11 @@
 //! Call [`begin_set`](ParamBridge::begin_set) before the first `set` in a drag
 //! gesture, and [`end_set`](ParamBridge::end_set) after the last. For standalone
 //! use, both are no-ops by default.
+//!
+//! Gestures that overlap form one undo step: hosts group every change made
+//! while any gesture is open. Edits that touch several parameters at once
+//! (paste, script, pattern editing) should go through [`set_grouped`] rather
+//! than wrapping each parameter in its own begin/set/end.
 
 use core::fmt;
 use sonido_core::ParamDescriptor;
@@ -138,6 +143,23 @@ pub trait ParamBridge: Send + Sync {
     fn end_set(&self, _slot: SlotIndex, _param: ParamIndex) {}
 }
 
+/// Apply several parameter changes as a single gesture.
+///
+/// Every `begin_set` is sent before the first `set` and every `end_set`
+/// after the last, so the gestures overlap and the whole batch becomes one
+/// undo step instead of one per parameter.
+pub fn set_grouped(bridge: &dyn ParamBridge, changes: &[(SlotIndex, ParamIndex, f32)]) {
+    for &(slot, param, _) in changes {
+        bridge.begin_set(slot, param);
+    }
+    for &(slot, param, value) in changes {
+        bridge.set(slot, param, value);
+    }
+    for &(slot, param, _) in changes {
+        bridge.end_set(slot, param);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +285,69 @@ mod tests {
         assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 800.0);
     }
 
+    /// Wraps a mock bridge and records value and gesture calls in order.
+    struct GestureLog {
+        inner: MockParamBridge,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl ParamBridge for GestureLog {
+        fn slot_count(&self) -> usize {
+            self.inner.slot_count()
+        }
+        fn effect_id(&self, slot: SlotIndex) -> &str {
+            self.inner.effect_id(slot)
+        }
+        fn param_count(&self, slot: SlotIndex) -> usize {
+            self.inner.param_count(slot)
+        }
+        fn param_descriptor(&self, slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+            self.inner.param_descriptor(slot, param)
+        }
+        fn get(&self, slot: SlotIndex, param: ParamIndex) -> f32 {
+            self.inner.get(slot, param)
+        }
+        fn set(&self, slot: SlotIndex, param: ParamIndex, value: f32) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("set {param}={value}"));
+            self.inner.set(slot, param, value);
+        }
+        fn is_bypassed(&self, slot: SlotIndex) -> bool {
+            self.inner.is_bypassed(slot)
+        }
+        fn set_bypassed(&self, slot: SlotIndex, bypassed: bool) {
+            self.inner.set_bypassed(slot, bypassed);
+        }
+        fn begin_set(&self, _slot: SlotIndex, param: ParamIndex) {
+            self.calls.lock().unwrap().push(format!("begin {param}"));
+        }
+        fn end_set(&self, _slot: SlotIndex, param: ParamIndex) {
+            self.calls.lock().unwrap().push(format!("end {param}"));
+        }
+    }
+
+    #[test]
+    fn set_grouped_overlaps_gestures() {
+        let log = GestureLog {
+            inner: MockParamBridge::new(&[("eq", &[0.0, 0.0])]),
+            calls: Mutex::new(Vec::new()),
+        };
+        set_grouped(
+            &log,
+            &[
+                (SlotIndex(0), ParamIndex(0), 1.0),
+                (SlotIndex(0), ParamIndex(1), 2.0),
+            ],
+        );
+        assert_eq!(
+            *log.calls.lock().unwrap(),
+            ["begin 0", "begin 1", "set 0=1", "set 1=2", "end 0", "end 1"]
+        );
+        assert_eq!(log.get(SlotIndex(0), ParamIndex(1)), 2.0);
+    }
+
     #[test]
     fn slot_index_display_and_from() {
         let s = SlotIndex::from(3usize);
@@ -1,9 +1,15 @@
 //! Arcade CRT visual theme for the Sonido GUI.
 //!
-//! All colors, sizing, glow parameters, and scanline config live in
-//! [`SonidoTheme`]. Widgets read this from `egui::Context::data()`.
+//! All colors, sizing, glow parameters, scanline config, and knob
+//! interaction settings live in [`SonidoTheme`]. Widgets read this from `egui::Context::data()`.
 //! The theme produces a CRT phosphor aesthetic: amber-dominant colors,
 //! bloom/glow on active elements, void backgrounds, and scanline textures.
+//!
+//! Three [`ColorScheme`]s are built in — the dark CRT default, a light scheme,
+//! and a high-contrast scheme — and the primary and secondary accents can be
+//! overridden with [`SonidoTheme::set_accents`]. Widgets draw only with
+//! [`ThemeColors`], so a scheme change restyles knobs, meters, and the graph
+//! editor alike.
 
 use egui::{
     Color32, Context, CornerRadius, FontDefinitions, FontFamily, Id, Stroke, Style, Vec2, Visuals,
@@ -13,6 +19,8 @@ use egui::{
 /// Complete arcade CRT theme — single source of truth for all visual parameters.
 #[derive(Clone, Debug, Default)]
 pub struct SonidoTheme {
+    /// Color scheme the palette was built from.
+    pub scheme: ColorScheme,
     /// Color palette.
     pub colors: ThemeColors,
     /// Sizing constants.
@@ -23,10 +31,72 @@ pub struct SonidoTheme {
     pub scanlines: ScanlineConfig,
     /// Responsive layout ratios and clamps.
     pub layout: ThemeLayout,
+    /// Knob drag and scroll behavior (per-user preference).
+    pub knob: KnobConfig,
     /// Skip bloom + scanlines for performance (WASM fallback).
     pub reduced_fx: bool,
 }
 
+/// Built-in color schemes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// Amber phosphor on a near-black void, with scanlines.
+    #[default]
+    Dark,
+    /// Dark traces on a light background; no scanlines.
+    Light,
+    /// Saturated colors on black, meeting WCAG AA contrast; no scanlines.
+    HighContrast,
+}
+
+impl ColorScheme {
+    /// Every scheme, in menu order.
+    pub const ALL: [Self; 3] = [Self::Dark, Self::Light, Self::HighContrast];
+
+    /// Human-readable name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+            Self::HighContrast => "High contrast",
+        }
+    }
+
+    /// Whether the scheme has a dark background (selects egui's dark visuals).
+    pub fn is_dark(self) -> bool {
+        !matches!(self, Self::Light)
+    }
+
+    /// The scheme's palette.
+    pub fn colors(self) -> ThemeColors {
+        match self {
+            Self::Dark => ThemeColors::dark(),
+            Self::Light => ThemeColors::light(),
+            Self::HighContrast => ThemeColors::high_contrast(),
+        }
+    }
+
+    /// Glow tuned for the scheme's background.
+    pub fn glow(self) -> GlowConfig {
+        match self {
+            Self::Dark => GlowConfig::default(),
+            // Halos read as smudges on a light background; keep them faint and
+            // make ghost segments visible against it.
+            Self::Light => GlowConfig {
+                bloom_alpha: 0.12,
+                ghost_alpha: 0.18,
+                ..GlowConfig::default()
+            },
+            // Inactive segments must stay legible.
+            Self::HighContrast => GlowConfig {
+                bloom_alpha: 0.2,
+                ghost_alpha: 0.35,
+                ..GlowConfig::default()
+            },
+        }
+    }
+}
+
 /// Phosphor color palette — each color is a "trace" on the CRT.
 #[derive(Clone, Debug)]
 pub struct ThemeColors {
@@ -103,6 +173,36 @@ pub struct ScanlineConfig {
     pub enabled: bool,
 }
 
+/// How dragging a knob maps to its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KnobDragMode {
+    /// Relative: vertical drag distance changes the value (up = increase).
+    #[default]
+    Vertical,
+    /// Absolute: the value follows the pointer's angle around the knob.
+    /// Shift+drag falls back to fine vertical dragging.
+    Circular,
+}
+
+/// Knob interaction settings.
+///
+/// Widgets read these from the installed theme, so a host can apply a user's
+/// preferences to every knob at once.
+#[derive(Clone, Debug)]
+pub struct KnobConfig {
+    /// Drag behavior.
+    pub drag_mode: KnobDragMode,
+    /// Multiplier on drag and scroll-wheel speed (1.0 = default).
+    pub sensitivity: f32,
+    /// Whether the scroll wheel adjusts the hovered knob.
+    pub scroll: bool,
+}
+
+impl KnobConfig {
+    /// Allowed range for [`sensitivity`](Self::sensitivity).
+    pub const SENSITIVITY_RANGE: std::ops::RangeInclusive<f32> = 0.25..=4.0;
+}
+
 /// Responsive layout ratios and clamps.
 ///
 /// All sizing derives from available space using these ratios, with min/max
@@ -135,6 +235,13 @@ pub struct ThemeLayout {
 
 impl Default for ThemeColors {
     fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl ThemeColors {
+    /// Dark CRT palette (the default).
+    pub fn dark() -> Self {
         Self {
             // Polybius arcade CRT phosphors — saturated, high-contrast
             amber: Color32::from_rgb(255, 176, 32),
@@ -150,6 +257,40 @@ impl Default for ThemeColors {
             text_secondary: Color32::from_rgb(100, 115, 120),
         }
     }
+
+    /// Light palette — darker traces so they hold contrast on a light background.
+    pub fn light() -> Self {
+        Self {
+            amber: Color32::from_rgb(168, 88, 0),
+            green: Color32::from_rgb(0, 140, 60),
+            cyan: Color32::from_rgb(0, 115, 165),
+            red: Color32::from_rgb(205, 30, 30),
+            magenta: Color32::from_rgb(185, 20, 115),
+            yellow: Color32::from_rgb(170, 130, 0),
+            purple: Color32::from_rgb(115, 55, 200),
+            dim: Color32::from_rgb(212, 212, 220),
+            void: Color32::from_rgb(246, 246, 248),
+            text_primary: Color32::from_rgb(25, 25, 32),
+            text_secondary: Color32::from_rgb(90, 96, 108),
+        }
+    }
+
+    /// High-contrast palette — pure, saturated colors on black.
+    pub fn high_contrast() -> Self {
+        Self {
+            amber: Color32::from_rgb(255, 200, 0),
+            green: Color32::from_rgb(0, 255, 0),
+            cyan: Color32::from_rgb(0, 255, 255),
+            red: Color32::from_rgb(255, 80, 80),
+            magenta: Color32::from_rgb(255, 100, 255),
+            yellow: Color32::from_rgb(255, 255, 0),
+            purple: Color32::from_rgb(200, 150, 255),
+            dim: Color32::from_rgb(90, 90, 96),
+            void: Color32::BLACK,
+            text_primary: Color32::WHITE,
+            text_secondary: Color32::from_rgb(200, 200, 200),
+        }
+    }
 }
 
 impl Default for ThemeSizing {
@@ -190,6 +331,16 @@ impl Default for ScanlineConfig {
     }
 }
 
+impl Default for KnobConfig {
+    fn default() -> Self {
+        Self {
+            drag_mode: KnobDragMode::Vertical,
+            sensitivity: 1.0,
+            scroll: true,
+        }
+    }
+}
+
 impl Default for ThemeLayout {
     fn default() -> Self {
         Self {
@@ -244,6 +395,37 @@ impl ThemeLayout {
 const THEME_ID: &str = "sonido_theme";
 
 impl SonidoTheme {
+    /// Theme with the given color scheme.
+    pub fn with_scheme(scheme: ColorScheme) -> Self {
+        let mut theme = Self::default();
+        theme.set_scheme(scheme);
+        theme
+    }
+
+    /// Switch color scheme: replaces the palette and glow, and enables
+    /// scanlines only for the dark CRT scheme.
+    ///
+    /// Accent overrides are discarded; re-apply them with
+    /// [`set_accents`](Self::set_accents).
+    pub fn set_scheme(&mut self, scheme: ColorScheme) {
+        self.scheme = scheme;
+        self.colors = scheme.colors();
+        self.glow = scheme.glow();
+        self.scanlines.enabled = scheme == ColorScheme::Dark;
+    }
+
+    /// Override the accent colors. `primary` replaces amber (knob arcs,
+    /// headings, panel borders); `secondary` replaces cyan (parameter labels).
+    /// `None` keeps the scheme's color.
+    pub fn set_accents(&mut self, primary: Option<Color32>, secondary: Option<Color32>) {
+        if let Some(color) = primary {
+            self.colors.amber = color;
+        }
+        if let Some(color) = secondary {
+            self.colors.cyan = color;
+        }
+    }
+
     /// Store this theme in the egui context for global access by widgets.
     pub fn install(&self, ctx: &Context) {
         ctx.data_mut(|d| d.insert_temp(Id::new(THEME_ID), self.clone()));
@@ -283,11 +465,21 @@ impl SonidoTheme {
     ///
     /// Call once at startup, or when the theme changes.
     pub fn apply(&self, ctx: &Context) {
-        self.install(ctx);
         Self::load_fonts(ctx);
+        self.apply_style(ctx);
+    }
+
+    /// Install the theme and set egui visuals and spacing, without reloading
+    /// fonts. Use for runtime scheme and accent changes.
+    pub fn apply_style(&self, ctx: &Context) {
+        self.install(ctx);
 
         let mut style = Style::default();
-        let mut visuals = Visuals::dark();
+        let mut visuals = if self.scheme.is_dark() {
+            Visuals::dark()
+        } else {
+            Visuals::light()
+        };
 
         // Void backgrounds
         visuals.window_fill = self.colors.void;
@@ -304,11 +496,11 @@ impl SonidoTheme {
         visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, self.colors.text_primary);
         visuals.widgets.inactive.corner_radius = CornerRadius::same(4);
 
-        visuals.widgets.hovered.bg_fill = Color32::from_rgb(35, 30, 15);
+        visuals.widgets.hovered.bg_fill = mix(self.colors.void, self.colors.amber, 0.12);
         visuals.widgets.hovered.fg_stroke = Stroke::new(1.5, self.colors.amber);
         visuals.widgets.hovered.corner_radius = CornerRadius::same(4);
 
-        visuals.widgets.active.bg_fill = Color32::from_rgb(45, 38, 12);
+        visuals.widgets.active.bg_fill = mix(self.colors.void, self.colors.amber, 0.16);
         visuals.widgets.active.fg_stroke = Stroke::new(2.0, self.colors.amber);
         visuals.widgets.active.corner_radius = CornerRadius::same(4);
 
@@ -338,6 +530,16 @@ impl SonidoTheme {
     }
 }
 
+/// Blend two opaque colors: `t = 0` gives `a`, `t = 1` gives `b`.
+fn mix(a: Color32, b: Color32, t: f32) -> Color32 {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgb(
+        channel(a.r(), b.r()),
+        channel(a.g(), b.g()),
+        channel(a.b(), b.b()),
+    )
+}
+
 // === Backward compatibility ===
 
 /// Type alias for the old `Theme` name. Use `SonidoTheme` for new code.
@@ -377,3 +579,51 @@ mod layout_tests {
         assert_eq!(w, 32.0);
     }
 }
+
+#[cfg(test)]
+mod scheme_tests {
+    use super::*;
+
+    #[test]
+    fn schemes_swap_palette_and_keep_accents_optional() {
+        let mut theme = SonidoTheme::with_scheme(ColorScheme::Light);
+        assert!(!theme.scheme.is_dark());
+        assert!(!theme.scanlines.enabled);
+        assert_eq!(theme.colors.void, ThemeColors::light().void);
+
+        let accent = Color32::from_rgb(10, 200, 120);
+        theme.set_accents(Some(accent), None);
+        assert_eq!(theme.colors.amber, accent);
+        assert_eq!(theme.colors.cyan, ThemeColors::light().cyan);
+
+        theme.set_scheme(ColorScheme::Dark);
+        assert!(theme.scanlines.enabled);
+        assert_eq!(theme.colors.amber, ThemeColors::dark().amber);
+    }
+
+    #[test]
+    fn light_and_high_contrast_text_meet_wcag_aa() {
+        // Relative luminance per WCAG 2.x.
+        fn luminance(c: Color32) -> f32 {
+            let lin = |v: u8| {
+                let v = v as f32 / 255.0;
+                if v <= 0.03928 {
+                    v / 12.92
+                } else {
+                    ((v + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * lin(c.r()) + 0.7152 * lin(c.g()) + 0.0722 * lin(c.b())
+        }
+        let contrast = |a: Color32, b: Color32| {
+            let (la, lb) = (luminance(a), luminance(b));
+            (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+        };
+        for scheme in [ColorScheme::Light, ColorScheme::HighContrast] {
+            let c = scheme.colors();
+            for text in [c.text_primary, c.text_secondary, c.amber, c.cyan] {
+                assert!(contrast(text, c.void) >= 4.5, "{scheme:?} {text:?}");
+            }
+        }
+    }
+}
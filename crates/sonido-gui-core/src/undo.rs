@@ -6,6 +6,12 @@
 //! boundary: a single Ctrl+Z undoes the entire drag rather than each
 //! individual sample.
 //!
+//! [`begin_param`](UndoHistory::begin_param) and
+//! [`end_param`](UndoHistory::end_param) mirror the
+//! [`ParamBridge`](crate::ParamBridge) gesture protocol: they record each
+//! parameter's value at the start and end of its gesture, and overlapping
+//! gestures (a paste, an XY pad, a two-axis EQ drag) share one group.
+//!
 //! # Invariants
 //!
 //! - `position` ≤ `buffer.len()`.
@@ -61,6 +67,10 @@ pub struct UndoHistory {
     gesture_buf: Vec<Mutation>,
     /// Whether a gesture is currently open.
     gesture_active: bool,
+    /// Parameters inside an open `begin_param` gesture, with their start value.
+    open_params: Vec<(usize, usize, f32)>,
+    /// Whether the open group was started by `begin_param`.
+    param_group: bool,
 }
 
 impl UndoHistory {
@@ -111,6 +121,50 @@ impl UndoHistory {
         }
     }
 
+    /// Start a gesture on one parameter, recording its current `value`.
+    ///
+    /// The first open parameter opens a gesture group (unless one is already
+    /// open); later calls join it. Beginning a parameter that is already
+    /// open is a no-op.
+    pub fn begin_param(&mut self, slot: usize, param: usize, value: f32) {
+        if self
+            .open_params
+            .iter()
+            .any(|&(s, p, _)| s == slot && p == param)
+        {
+            return;
+        }
+        if self.open_params.is_empty() && !self.gesture_active {
+            self.begin_gesture();
+            self.param_group = true;
+        }
+        self.open_params.push((slot, param, value));
+    }
+
+    /// End the gesture on one parameter, which now holds `value`.
+    ///
+    /// Records a single mutation from the value at
+    /// [`begin_param`](Self::begin_param) to `value` if they differ. When the
+    /// last open parameter ends, the group it opened is committed. Ending a
+    /// parameter that was never begun is a no-op.
+    pub fn end_param(&mut self, slot: usize, param: usize, value: f32) {
+        let Some(i) = self
+            .open_params
+            .iter()
+            .position(|&(s, p, _)| s == slot && p == param)
+        else {
+            return;
+        };
+        let (_, _, start) = self.open_params.remove(i);
+        if start != value {
+            self.push(Mutation::new(slot, param, start, value));
+        }
+        if self.open_params.is_empty() && self.param_group {
+            self.param_group = false;
+            self.end_gesture();
+        }
+    }
+
     /// Returns `true` if a gesture group is currently open.
     pub fn gesture_active(&self) -> bool {
         self.gesture_active
@@ -157,6 +211,8 @@ impl UndoHistory {
         self.position = 0;
         self.gesture_active = false;
         self.gesture_buf.clear();
+        self.open_params.clear();
+        self.param_group = false;
     }
 
     /// Number of committed history entries.
@@ -282,6 +338,44 @@ mod tests {
         assert_eq!(group.len(), 2);
     }
 
+    #[test]
+    fn param_gesture_records_start_and_end() {
+        let mut h = UndoHistory::new();
+        h.begin_param(0, 1, 0.25);
+        assert!(h.gesture_active());
+        h.end_param(0, 1, 0.75);
+        assert!(!h.gesture_active());
+        assert_eq!(h.undo().unwrap(), vec![m(0, 1, 0.25, 0.75)]);
+    }
+
+    #[test]
+    fn overlapping_param_gestures_share_a_group() {
+        let mut h = UndoHistory::new();
+        h.begin_param(0, 0, 100.0);
+        h.begin_param(0, 1, 0.0);
+        h.begin_param(0, 0, 150.0); // already open — keeps the first start value
+        h.end_param(0, 0, 200.0);
+        assert!(h.gesture_active());
+        h.end_param(0, 1, -3.0);
+        assert!(!h.gesture_active());
+
+        assert_eq!(h.len(), 1);
+        assert_eq!(
+            h.undo().unwrap(),
+            vec![m(0, 0, 100.0, 200.0), m(0, 1, 0.0, -3.0)]
+        );
+    }
+
+    #[test]
+    fn unchanged_param_gesture_does_not_commit() {
+        let mut h = UndoHistory::new();
+        h.begin_param(0, 0, 0.5);
+        h.end_param(0, 0, 0.5);
+        h.end_param(0, 0, 1.0); // not open — no-op
+        assert!(h.is_empty());
+        assert!(!h.gesture_active());
+    }
+
     #[test]
     fn max_history_enforced() {
         let mut h = UndoHistory::new();
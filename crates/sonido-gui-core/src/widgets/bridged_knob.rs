@@ -4,6 +4,12 @@
 //! handling descriptor lookup, auto-formatting based on [`ParamUnit`],
 //! and VST3/CLAP gesture events (`begin_set` on drag start, `end_set` on drag stop).
 //!
+//! Clicking a knob's LED readout (or pressing Enter on a focused knob) opens a
+//! text entry in its place; typed values go through
+//! [`ParamDescriptor::parse_value`], so "-6 dB", "350 ms" and "1.2 kHz" work.
+//! Hovering a control shows the parameter's
+//! [`description`](ParamDescriptor::description), when it has one.
+//!
 //! # Functions
 //!
 //! - [`bridged_knob`] — auto-formatted knob from `ParamUnit`
@@ -16,8 +22,9 @@ use super::fader::Fader;
 use crate::theme::SonidoTheme;
 use crate::widgets::glow;
 use crate::widgets::led_display::LedDisplay;
+use crate::widgets::value_entry::{self, EntryOutcome};
 use crate::{ParamBridge, ParamIndex, SlotIndex};
-use egui::{Response, Ui};
+use egui::{Response, Sense, Ui, vec2};
 use sonido_core::{ParamDescriptor, ParamUnit};
 
 /// Normalize a plain value to \[0, 1\] using the descriptor's scale, or linear fallback.
@@ -66,21 +73,33 @@ fn format_plain_value(value: f32, unit: ParamUnit) -> String {
                 format!("{value:.0}ms")
             }
         }
+        ParamUnit::Seconds => format!("{value:.2} s"),
         ParamUnit::Percent => format!("{value:.0} %"),
         ParamUnit::Ratio => format!("{value:.1}:1"),
+        ParamUnit::Semitones => format!("{value:.1}st"),
+        ParamUnit::Cents => format!("{value:.0}ct"),
         ParamUnit::None => format!("{value:.2}"),
         _ => format!("{value:.2}{}", unit.suffix()),
     }
 }
 
+/// Attach the parameter description as hover text, if it has one.
+fn describe(response: Response, desc: Option<&ParamDescriptor>) -> Response {
+    match desc {
+        Some(d) if !d.description.is_empty() => response.on_hover_text(d.description),
+        _ => response,
+    }
+}
+
 /// Apply the gesture protocol to a widget response.
 ///
-/// Wraps `begin_set`/`end_set` around drag and double-click interactions.
+/// Wraps `begin_set`/`end_set` around drag, keyboard, and double-click interactions.
 /// Use this with raw [`Knob`] widgets that need custom properties
 /// (e.g., `.diameter()`, `.sensitivity()`) but still want gesture support.
 ///
 /// For double-click resets, a complete `begin_set → set(default) → end_set`
-/// sequence is emitted. Regular drags emit `begin_set` on drag start,
+/// sequence is emitted, as it is for changes made without dragging (arrow
+/// keys, typed values). Regular drags emit `begin_set` on drag start,
 /// `set(value)` on each change, and `end_set` on drag stop.
 pub fn gesture_wrap(
     response: &Response,
@@ -94,6 +113,10 @@ pub fn gesture_wrap(
         bridge.begin_set(slot, param);
         bridge.set(slot, param, default);
         bridge.end_set(slot, param);
+    } else if response.changed() && !response.dragged() {
+        bridge.begin_set(slot, param);
+        bridge.set(slot, param, value);
+        bridge.end_set(slot, param);
     } else {
         if response.drag_started() {
             bridge.begin_set(slot, param);
@@ -107,6 +130,56 @@ pub fn gesture_wrap(
     }
 }
 
+/// Show the LED value readout below a knob, or the typed-value entry in its place.
+///
+/// Clicking the readout opens the entry for the knob (`knob.id`). A committed
+/// value is parsed with the descriptor (plain number without one), clamped to
+/// the parameter range, and applied as one complete gesture. Unparseable text
+/// leaves the parameter unchanged.
+#[allow(clippy::too_many_arguments)]
+fn value_readout(
+    ui: &mut Ui,
+    knob: &Response,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    param: ParamIndex,
+    desc: Option<&ParamDescriptor>,
+    plain_value: f32,
+    led_text: String,
+) {
+    let theme = SonidoTheme::get(ui.ctx());
+
+    if !value_entry::is_open(ui.ctx(), knob.id) {
+        let led = ui
+            .add(LedDisplay::new(led_text).color(theme.colors.amber))
+            .interact(Sense::click())
+            .on_hover_text("Click to type a value");
+        if led.clicked() {
+            let text = desc.map_or_else(
+                || format!("{plain_value:.2}"),
+                |d| d.format_value(plain_value),
+            );
+            value_entry::open(ui.ctx(), knob.id, text);
+        }
+        return;
+    }
+
+    let size = vec2(
+        knob.rect.width().max(64.0),
+        theme.sizing.led_digit_height.max(16.0),
+    );
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    if let EntryOutcome::Commit(text) = value_entry::show(ui, knob.id, rect) {
+        let parsed = desc.map_or_else(|| text.parse().ok(), |d| d.parse_value(&text));
+        if let Some(value) = parsed {
+            let (min, max) = desc.map_or((0.0, 1.0), |d| (d.min, d.max));
+            bridge.begin_set(slot, param);
+            bridge.set(slot, param, value.clamp(min, max));
+            bridge.end_set(slot, param);
+        }
+    }
+}
+
 /// Render a parameter knob bound to a [`ParamBridge`] slot.
 ///
 /// Handles descriptor lookup (min/max/default), auto-formatting based on
@@ -125,8 +198,11 @@ pub fn gesture_wrap(
 /// | `Decibels`       | `"-3.5 dB"`                 |
 /// | `Hertz`          | `"1.2 kHz"` / `"440 Hz"`   |
 /// | `Milliseconds`   | `"1.50 s"` / `"100.0 ms"`  |
+/// | `Seconds`        | `"2.50 s"`                  |
 /// | `Percent`        | `"50%"` (value is 0–100)    |
 /// | `Ratio`          | `"4.0:1"`                   |
+/// | `Semitones`      | `"-7.0 st"`                 |
+/// | `Cents`          | `"12 ct"`                   |
 /// | `None` / unknown | `"0.50"` (2 decimal places) |
 pub fn bridged_knob(
     ui: &mut Ui,
@@ -135,7 +211,6 @@ pub fn bridged_knob(
     param: ParamIndex,
     label: &str,
 ) -> Response {
-    let theme = SonidoTheme::get(ui.ctx());
     let desc = bridge.param_descriptor(slot, param);
     let (min, max, default) = desc.map_or((0.0, 1.0, 0.5), |d| (d.min, d.max, d.default));
 
@@ -189,19 +264,25 @@ pub fn bridged_knob(
                 let v = d.denormalize(n);
                 format!("{v:.2}")
             }),
-            _ => knob.format(move |n| {
-                let v = d.denormalize(n);
-                format!("{v:.2}{}", d.unit.suffix())
-            }),
+            _ => knob.format(move |n| d.format_value(d.denormalize(n))),
         }
     } else {
         knob
     };
 
-    let response = ui.add(knob);
-
-    // 7-segment LED value display below the knob
-    ui.add(LedDisplay::new(formatted).color(theme.colors.amber));
+    let response = describe(ui.add(knob), desc.as_ref());
+
+    // 7-segment LED value display below the knob (click to type a value)
+    value_readout(
+        ui,
+        &response,
+        bridge,
+        slot,
+        param,
+        desc.as_ref(),
+        plain_value,
+        formatted,
+    );
 
     // Denormalize back to plain value for the bridge
     let plain_out = denormalize(desc.as_ref(), normalized, min, max);
@@ -225,7 +306,6 @@ pub fn bridged_knob_fmt(
     label: &str,
     format: impl Fn(f32) -> String + 'static,
 ) -> Response {
-    let theme = SonidoTheme::get(ui.ctx());
     let desc = bridge.param_descriptor(slot, param);
     let (min, max, default) = desc.map_or((0.0, 1.0, 0.5), |d| (d.min, d.max, d.default));
 
@@ -246,10 +326,19 @@ pub fn bridged_knob_fmt(
             format(plain)
         });
 
-    let response = ui.add(knob);
-
-    // 7-segment LED value display below the knob
-    ui.add(LedDisplay::new(led_text).color(theme.colors.amber));
+    let response = describe(ui.add(knob), desc.as_ref());
+
+    // 7-segment LED value display below the knob (click to type a value)
+    value_readout(
+        ui,
+        &response,
+        bridge,
+        slot,
+        param,
+        desc.as_ref(),
+        plain_value,
+        led_text,
+    );
 
     let plain_out = denormalize(desc.as_ref(), normalized, min, max);
     gesture_wrap(&response, bridge, slot, param, plain_out, default);
@@ -383,7 +472,10 @@ pub fn bridged_combo(
             }
         });
 
-    response.response
+    describe(
+        response.response,
+        bridge.param_descriptor(slot, param).as_ref(),
+    )
 }
 
 /// Render a vertical fader bridged to a parameter slot.
@@ -419,12 +511,15 @@ pub fn bridged_fader(
     let color = theme.colors.amber;
 
     let mut norm = normalized;
-    let response = ui.add(
-        Fader::new(&mut norm, label)
-            .display(&formatted)
-            .color(color)
-            .size(fader_w, fader_h)
-            .default_value(default_normalized),
+    let response = describe(
+        ui.add(
+            Fader::new(&mut norm, label)
+                .display(&formatted)
+                .color(color)
+                .size(fader_w, fader_h)
+                .default_value(default_normalized),
+        ),
+        desc.as_ref(),
     );
 
     // Double-click reset
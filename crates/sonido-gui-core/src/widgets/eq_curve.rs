@@ -0,0 +1,417 @@
+//! EQ frequency-response plot with draggable band handles.
+//!
+//! [`EqCurve`] draws the combined magnitude response of a set of peaking
+//! bands on a log frequency axis (20 Hz – 20 kHz) with a handle at each
+//! band's centre. Dragging a handle sets the band's frequency and gain from
+//! the pointer position; scrolling over a handle changes its Q.
+//!
+//! The curve is evaluated from the RBJ cookbook peaking coefficients
+//! ([`peaking_eq_coefficients`]) at a fixed display rate of 48 kHz, so it
+//! matches the EQ kernel at 48 kHz and is close at any other rate below
+//! the top octave.
+//!
+//! [`bridged_eq_curve`] binds the plot to frequency/gain/Q parameter
+//! triples on a [`ParamBridge`] slot.
+
+use egui::{Align2, FontId, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, pos2, vec2};
+use sonido_core::peaking_eq_coefficients;
+
+use crate::theme::SonidoTheme;
+use crate::widgets::glow;
+use crate::{ParamBridge, ParamIndex, SlotIndex};
+
+/// Lowest frequency on the plot.
+const FREQ_MIN: f32 = 20.0;
+/// Highest frequency on the plot.
+const FREQ_MAX: f32 = 20_000.0;
+/// Sample rate the curve is evaluated at.
+const DISPLAY_SAMPLE_RATE: f32 = 48_000.0;
+/// Points evaluated along the curve.
+const CURVE_POINTS: usize = 160;
+/// Handle radius in points.
+const HANDLE_RADIUS: f32 = 5.0;
+/// Q change per point of scroll (multiplicative).
+const Q_SCROLL_RATE: f32 = 0.004;
+/// Lowest Q reachable by scrolling.
+const Q_MIN: f32 = 0.1;
+/// Highest Q reachable by scrolling.
+const Q_MAX: f32 = 20.0;
+
+/// One peaking EQ band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqBand {
+    /// Centre frequency in Hz.
+    pub freq_hz: f32,
+    /// Gain at the centre frequency in dB.
+    pub gain_db: f32,
+    /// Quality factor (bandwidth).
+    pub q: f32,
+}
+
+impl EqBand {
+    /// Magnitude response of this band at `freq_hz`, in dB.
+    pub fn response_db(&self, freq_hz: f32, sample_rate: f32) -> f32 {
+        let centre = self.freq_hz.clamp(FREQ_MIN, sample_rate * 0.475);
+        let (b0, b1, b2, a0, a1, a2) =
+            peaking_eq_coefficients(centre, self.q.max(Q_MIN), self.gain_db, sample_rate);
+        let w = core::f32::consts::TAU * freq_hz / sample_rate;
+        let (s1, c1) = w.sin_cos();
+        let (s2, c2) = (2.0 * w).sin_cos();
+        let num = (b0 + b1 * c1 + b2 * c2).powi(2) + (b1 * s1 + b2 * s2).powi(2);
+        let den = (a0 + a1 * c1 + a2 * c2).powi(2) + (a1 * s1 + a2 * s2).powi(2);
+        10.0 * (num / den.max(1e-20)).max(1e-20).log10()
+    }
+}
+
+/// Combined response of cascaded `bands` at `freq_hz`, in dB.
+pub fn eq_response_db(bands: &[EqBand], freq_hz: f32, sample_rate: f32) -> f32 {
+    bands
+        .iter()
+        .map(|band| band.response_db(freq_hz, sample_rate))
+        .sum()
+}
+
+/// Result of showing an [`EqCurve`].
+pub struct EqCurveResponse {
+    /// Response for the whole plot area.
+    pub response: Response,
+    /// One response per band handle, in band order.
+    pub handles: Vec<Response>,
+    /// Band whose Q was changed by scrolling this frame.
+    pub scrolled: Option<usize>,
+}
+
+/// Frequency-response plot with one draggable handle per band.
+///
+/// ## Parameters
+/// - `bands`: Bands to draw and edit.
+/// - `gain_range`: Plot spans ±`gain_range` dB (default 18.0).
+/// - `width`, `height`: Plot size in pixels (default 300.0 × 120.0).
+pub struct EqCurve<'a> {
+    bands: &'a mut [EqBand],
+    gain_range: f32,
+    width: f32,
+    height: f32,
+}
+
+impl<'a> EqCurve<'a> {
+    /// Create a plot editing `bands`.
+    pub fn new(bands: &'a mut [EqBand]) -> Self {
+        Self {
+            bands,
+            gain_range: 18.0,
+            width: 300.0,
+            height: 120.0,
+        }
+    }
+
+    /// Set the gain span: the plot covers ±`db`.
+    pub fn gain_range(mut self, db: f32) -> Self {
+        self.gain_range = db.max(1.0);
+        self
+    }
+
+    /// Set the plot dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Draw the plot and handle interaction.
+    pub fn show(self, ui: &mut Ui) -> EqCurveResponse {
+        let theme = SonidoTheme::get(ui.ctx());
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(self.width, self.height), Sense::hover());
+        let plot = Plot {
+            rect: rect.shrink(2.0),
+            gain_range: self.gain_range,
+        };
+        let colors = [theme.colors.cyan, theme.colors.green, theme.colors.magenta];
+
+        // Handles first, so drags apply before the curve is drawn.
+        let mut handles = Vec::with_capacity(self.bands.len());
+        let mut scrolled = None;
+        for (i, band) in self.bands.iter_mut().enumerate() {
+            let centre = plot.point(band.freq_hz, band.gain_db);
+            let hit = Rect::from_center_size(centre, Vec2::splat(HANDLE_RADIUS * 3.0));
+            let mut handle = ui.interact(hit, response.id.with(i), Sense::click_and_drag());
+
+            if handle.dragged()
+                && let Some(pos) = handle.interact_pointer_pos()
+            {
+                let (freq, gain) = plot.value(pos);
+                if freq != band.freq_hz || gain != band.gain_db {
+                    band.freq_hz = freq;
+                    band.gain_db = gain;
+                    handle.mark_changed();
+                }
+            }
+
+            if handle.hovered() {
+                let scroll = ui.input_mut(|input| {
+                    let delta = input.smooth_scroll_delta;
+                    // Consume it so an enclosing scroll area stays put.
+                    input.smooth_scroll_delta = Vec2::ZERO;
+                    delta.x + delta.y
+                });
+                if scroll != 0.0 {
+                    band.q = (band.q * (scroll * Q_SCROLL_RATE).exp()).clamp(Q_MIN, Q_MAX);
+                    scrolled = Some(i);
+                }
+            }
+
+            let handle = handle.on_hover_text(format!(
+                "{:.0} Hz  {:+.1} dB  Q {:.2}\nDrag to move, scroll for Q",
+                band.freq_hz, band.gain_db, band.q
+            ));
+            handles.push(handle);
+        }
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, theme.colors.void);
+            painter.rect_stroke(
+                rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+
+            // Grid: decades and ±6 dB steps, 0 dB brighter.
+            let grid = Stroke::new(1.0, theme.colors.dim);
+            let font = FontId::proportional(8.0);
+            for (hz, label) in [(100.0, "100"), (1000.0, "1k"), (10_000.0, "10k")] {
+                let x = plot.freq_to_x(hz);
+                painter.line_segment(
+                    [pos2(x, plot.rect.top()), pos2(x, plot.rect.bottom())],
+                    grid,
+                );
+                painter.text(
+                    pos2(x + 2.0, plot.rect.bottom() - 1.0),
+                    Align2::LEFT_BOTTOM,
+                    label,
+                    font.clone(),
+                    theme.colors.text_secondary,
+                );
+            }
+            let steps = ((self.gain_range - 0.5) / 6.0) as i32;
+            for step in -steps..=steps {
+                let y = plot.gain_to_y(step as f32 * 6.0);
+                let stroke = if step == 0 {
+                    Stroke::new(1.0, theme.colors.text_secondary)
+                } else {
+                    grid
+                };
+                painter.line_segment(
+                    [pos2(plot.rect.left(), y), pos2(plot.rect.right(), y)],
+                    stroke,
+                );
+            }
+
+            // Composite curve.
+            let points: Vec<egui::Pos2> = (0..CURVE_POINTS)
+                .map(|i| {
+                    let t = i as f32 / (CURVE_POINTS - 1) as f32;
+                    let x = plot.rect.left() + t * plot.rect.width();
+                    let freq = plot.x_to_freq(x);
+                    let db = eq_response_db(self.bands, freq, DISPLAY_SAMPLE_RATE);
+                    pos2(x, plot.gain_to_y(db))
+                })
+                .collect();
+            let clipped = painter.with_clip_rect(painter.clip_rect().intersect(plot.rect));
+            for win in points.windows(2) {
+                glow::glow_line(&clipped, win[0], win[1], theme.colors.amber, 1.5, &theme);
+            }
+
+            // Handles.
+            for (i, (band, handle)) in self.bands.iter().zip(&handles).enumerate() {
+                let centre = plot.point(band.freq_hz, band.gain_db);
+                let color = colors[i % colors.len()];
+                let radius = if handle.hovered() || handle.dragged() {
+                    HANDLE_RADIUS + 1.5
+                } else {
+                    HANDLE_RADIUS
+                };
+                glow::glow_circle(painter, centre, radius, color, &theme);
+            }
+        }
+
+        EqCurveResponse {
+            response,
+            handles,
+            scrolled,
+        }
+    }
+}
+
+/// Mapping between plot coordinates and frequency/gain.
+struct Plot {
+    rect: Rect,
+    gain_range: f32,
+}
+
+impl Plot {
+    fn freq_to_x(&self, freq_hz: f32) -> f32 {
+        let t = (freq_hz.clamp(FREQ_MIN, FREQ_MAX) / FREQ_MIN).ln() / (FREQ_MAX / FREQ_MIN).ln();
+        self.rect.left() + t * self.rect.width()
+    }
+
+    fn x_to_freq(&self, x: f32) -> f32 {
+        let t = ((x - self.rect.left()) / self.rect.width()).clamp(0.0, 1.0);
+        FREQ_MIN * (FREQ_MAX / FREQ_MIN).powf(t)
+    }
+
+    fn gain_to_y(&self, gain_db: f32) -> f32 {
+        let t = (gain_db / self.gain_range).clamp(-1.0, 1.0);
+        self.rect.center().y - t * self.rect.height() * 0.5
+    }
+
+    fn y_to_gain(&self, y: f32) -> f32 {
+        let t = (self.rect.center().y - y) / (self.rect.height() * 0.5);
+        t.clamp(-1.0, 1.0) * self.gain_range
+    }
+
+    fn point(&self, freq_hz: f32, gain_db: f32) -> egui::Pos2 {
+        pos2(self.freq_to_x(freq_hz), self.gain_to_y(gain_db))
+    }
+
+    /// Frequency and gain under `pos`, clamped to the plot.
+    fn value(&self, pos: egui::Pos2) -> (f32, f32) {
+        (self.x_to_freq(pos.x), self.y_to_gain(pos.y))
+    }
+}
+
+/// Parameter indices for one band of a [`bridged_eq_curve`].
+#[derive(Debug, Clone, Copy)]
+pub struct EqBandParams {
+    /// Centre frequency (Hz).
+    pub freq: ParamIndex,
+    /// Gain (dB).
+    pub gain: ParamIndex,
+    /// Quality factor.
+    pub q: ParamIndex,
+}
+
+/// Render an [`EqCurve`] bound to bridge parameters.
+///
+/// Each handle drag is one gesture on its band's frequency and gain
+/// (`begin_set` on drag start, `set` while moving, `end_set` on release).
+/// A scroll step is a complete `begin_set → set → end_set` on the Q.
+/// Values are clamped to each parameter's descriptor range.
+pub fn bridged_eq_curve(
+    ui: &mut Ui,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    params: &[EqBandParams],
+    width: f32,
+    height: f32,
+) -> Response {
+    let clamp = |param: ParamIndex, value: f32| {
+        bridge
+            .param_descriptor(slot, param)
+            .map_or(value, |d| d.clamp(value))
+    };
+    let mut bands: Vec<EqBand> = params
+        .iter()
+        .map(|p| EqBand {
+            freq_hz: bridge.get(slot, p.freq),
+            gain_db: bridge.get(slot, p.gain),
+            q: bridge.get(slot, p.q),
+        })
+        .collect();
+
+    let out = EqCurve::new(&mut bands).size(width, height).show(ui);
+
+    for ((p, band), handle) in params.iter().zip(&bands).zip(&out.handles) {
+        if handle.drag_started() {
+            bridge.begin_set(slot, p.freq);
+            bridge.begin_set(slot, p.gain);
+        }
+        if handle.changed() {
+            bridge.set(slot, p.freq, clamp(p.freq, band.freq_hz));
+            bridge.set(slot, p.gain, clamp(p.gain, band.gain_db));
+        }
+        if handle.drag_stopped() {
+            bridge.end_set(slot, p.freq);
+            bridge.end_set(slot, p.gain);
+        }
+    }
+
+    if let Some(i) = out.scrolled {
+        let p = params[i];
+        bridge.begin_set(slot, p.q);
+        bridge.set(slot, p.q, clamp(p.q, bands[i].q));
+        bridge.end_set(slot, p.q);
+    }
+
+    out.response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn band(freq_hz: f32, gain_db: f32, q: f32) -> EqBand {
+        EqBand {
+            freq_hz,
+            gain_db,
+            q,
+        }
+    }
+
+    #[test]
+    fn peak_reaches_band_gain_at_centre() {
+        let b = band(1000.0, 9.0, 1.0);
+        assert!((b.response_db(1000.0, SR) - 9.0).abs() < 0.05);
+        // Far from the centre the band is flat.
+        assert!(b.response_db(20.0, SR).abs() < 0.2);
+        assert!(b.response_db(18_000.0, SR).abs() < 0.2);
+    }
+
+    #[test]
+    fn flat_band_is_zero_everywhere() {
+        let b = band(500.0, 0.0, 2.0);
+        for f in [30.0, 500.0, 5000.0, 15_000.0] {
+            assert!(b.response_db(f, SR).abs() < 1e-3, "f = {f}");
+        }
+    }
+
+    #[test]
+    fn composite_sums_bands() {
+        let bands = [band(100.0, 6.0, 1.0), band(5000.0, -6.0, 1.0)];
+        for f in [50.0, 100.0, 1000.0, 5000.0] {
+            let sum = bands[0].response_db(f, SR) + bands[1].response_db(f, SR);
+            assert!((eq_response_db(&bands, f, SR) - sum).abs() < 1e-4);
+        }
+        assert!(eq_response_db(&bands, 100.0, SR) > 5.0);
+        assert!(eq_response_db(&bands, 5000.0, SR) < -5.0);
+    }
+
+    #[test]
+    fn higher_q_narrows_the_peak() {
+        let wide = band(1000.0, 12.0, 0.5);
+        let narrow = band(1000.0, 12.0, 5.0);
+        assert!(narrow.response_db(2000.0, SR) < wide.response_db(2000.0, SR));
+    }
+
+    #[test]
+    fn plot_mapping_round_trips() {
+        let plot = Plot {
+            rect: Rect::from_min_size(pos2(10.0, 20.0), vec2(300.0, 120.0)),
+            gain_range: 18.0,
+        };
+        assert_eq!(plot.freq_to_x(FREQ_MIN), 10.0);
+        assert_eq!(plot.freq_to_x(FREQ_MAX), 310.0);
+        assert_eq!(plot.gain_to_y(0.0), 80.0);
+
+        let (freq, gain) = plot.value(plot.point(1000.0, -6.0));
+        assert!((freq - 1000.0).abs() < 0.5);
+        assert!((gain + 6.0).abs() < 1e-3);
+
+        // Off-plot positions clamp to the edges.
+        assert_eq!(plot.value(pos2(-100.0, -100.0)), (FREQ_MIN, 18.0));
+    }
+}
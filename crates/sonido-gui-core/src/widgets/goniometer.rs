@@ -0,0 +1,440 @@
+//! Stereo imaging displays — goniometer and phase-correlation meter.
+//!
+//! [`GoniometerWidget`] plots recent stereo sample pairs as a Lissajous
+//! figure rotated 45°, so mid (L+R) runs vertically and side (L−R)
+//! horizontally. A mono signal draws a vertical line, a hard-left or
+//! hard-right signal leans along the matching diagonal, and out-of-phase
+//! content spreads toward the horizontal axis.
+//!
+//! [`CorrelationMeter`] shows the phase correlation between channels on a
+//! −1 to +1 bar. +1 is mono, 0 is uncorrelated (wide), and negative values
+//! warn that content will cancel when summed to mono.
+//!
+//! [`GoniometerState`] holds the sample ring for the goniometer and the
+//! smoothed correlation for the meter.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! let mut imager = GoniometerState::new(2048);
+//!
+//! // Push each new stereo block with its sample rate.
+//! imager.push(&left, &right, 48000.0);
+//!
+//! ui.add(GoniometerWidget::new(&imager).size(160.0));
+//! ui.add(CorrelationMeter::new(imager.correlation()).size(160.0, 10.0));
+//! ```
+
+use egui::{Align2, FontId, Rect, Response, Sense, Stroke, StrokeKind, Ui, Widget, pos2, vec2};
+
+use crate::theme::SonidoTheme;
+use crate::widgets::glow;
+
+/// Correlation integration time constant in seconds.
+const CORRELATION_TIME_S: f32 = 0.3;
+
+/// Channel energy below which the correlation reads 0 (silence).
+const ENERGY_FLOOR: f32 = 1e-9;
+
+/// Correlation below which the meter turns red (phase trouble).
+const CORRELATION_WARN: f32 = 0.0;
+
+/// Ring of recent stereo samples plus a running phase correlation.
+#[derive(Clone, Debug)]
+pub struct GoniometerState {
+    /// Circular buffer of `[left, right]` pairs.
+    pairs: Vec<[f32; 2]>,
+    /// Write cursor (next write position).
+    write: usize,
+    /// Total pairs ever written (used to compute fill count).
+    total: usize,
+    /// Smoothed `L·R`, `L²`, and `R²`.
+    sum_lr: f32,
+    sum_ll: f32,
+    sum_rr: f32,
+}
+
+impl GoniometerState {
+    /// Create a state holding the newest `capacity` sample pairs.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            pairs: vec![[0.0; 2]; capacity.max(1)],
+            write: 0,
+            total: 0,
+            sum_lr: 0.0,
+            sum_ll: 0.0,
+            sum_rr: 0.0,
+        }
+    }
+
+    /// Push a block of stereo samples.
+    ///
+    /// `left` and `right` are paired up to the shorter length. The
+    /// correlation integrates over roughly 300 ms at `sample_rate`.
+    pub fn push(&mut self, left: &[f32], right: &[f32], sample_rate: f32) {
+        let coeff = (-1.0 / (CORRELATION_TIME_S * sample_rate.max(1.0))).exp();
+        let capacity = self.pairs.len();
+        for (&l, &r) in left.iter().zip(right) {
+            self.pairs[self.write] = [l, r];
+            self.write = (self.write + 1) % capacity;
+            self.total += 1;
+
+            self.sum_lr = coeff * self.sum_lr + (1.0 - coeff) * l * r;
+            self.sum_ll = coeff * self.sum_ll + (1.0 - coeff) * l * l;
+            self.sum_rr = coeff * self.sum_rr + (1.0 - coeff) * r * r;
+        }
+    }
+
+    /// Phase correlation from −1.0 (inverted) to +1.0 (mono).
+    ///
+    /// Reads 0.0 while either channel is silent.
+    pub fn correlation(&self) -> f32 {
+        let energy = self.sum_ll * self.sum_rr;
+        if energy <= ENERGY_FLOOR * ENERGY_FLOOR {
+            return 0.0;
+        }
+        (self.sum_lr / energy.sqrt()).clamp(-1.0, 1.0)
+    }
+
+    /// Number of pairs currently held (up to capacity).
+    pub fn len(&self) -> usize {
+        self.total.min(self.pairs.len())
+    }
+
+    /// Returns true if no samples have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Read the most recent `count` pairs in chronological order.
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = [f32; 2]> + '_ {
+        let capacity = self.pairs.len();
+        let available = self.len().min(count);
+        let start = (self.write + capacity - available) % capacity;
+        (0..available).map(move |i| self.pairs[(start + i) % capacity])
+    }
+
+    /// Clear the sample ring and the correlation.
+    pub fn reset(&mut self) {
+        self.pairs.fill([0.0; 2]);
+        self.write = 0;
+        self.total = 0;
+        self.sum_lr = 0.0;
+        self.sum_ll = 0.0;
+        self.sum_rr = 0.0;
+    }
+
+    /// Ring capacity in sample pairs.
+    pub fn capacity(&self) -> usize {
+        self.pairs.len()
+    }
+}
+
+/// Map a stereo pair to goniometer coordinates `(side, mid)`.
+///
+/// Side is positive toward the right channel, so hard-left content leans
+/// up and to the left.
+fn mid_side(left: f32, right: f32) -> (f32, f32) {
+    let k = core::f32::consts::FRAC_1_SQRT_2;
+    ((right - left) * k, (left + right) * k)
+}
+
+/// Goniometer (vectorscope) display.
+///
+/// Plots the newest pairs from [`GoniometerState`] as dots that fade with
+/// age, over L, R, M, and S guide axes.
+///
+/// ## Parameters
+/// - `state`: Reference to [`GoniometerState`].
+/// - `points`: Number of recent pairs to plot (default 1024).
+/// - `amplitude_scale`: Level that reaches the edge of the display (default 1.0).
+/// - `size`: Edge length of the square display in pixels (default 120.0).
+pub struct GoniometerWidget<'a> {
+    state: &'a GoniometerState,
+    points: usize,
+    amplitude_scale: f32,
+    size: f32,
+}
+
+impl<'a> GoniometerWidget<'a> {
+    /// Create a goniometer reading from `state`.
+    pub fn new(state: &'a GoniometerState) -> Self {
+        Self {
+            state,
+            points: 1024,
+            amplitude_scale: 1.0,
+            size: 120.0,
+        }
+    }
+
+    /// Set how many recent sample pairs are plotted.
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = points;
+        self
+    }
+
+    /// Set the level that reaches the edge of the display.
+    ///
+    /// `scale` must be > 0. Default is 1.0 (full scale).
+    pub fn amplitude_scale(mut self, scale: f32) -> Self {
+        self.amplitude_scale = scale.max(1e-6);
+        self
+    }
+
+    /// Set the edge length of the square display.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Widget for GoniometerWidget<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = SonidoTheme::get(ui.ctx());
+        let (rect, response) = ui.allocate_exact_size(vec2(self.size, self.size), Sense::hover());
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let painter = ui.painter();
+        painter.rect_filled(rect, 2.0, theme.colors.void);
+        painter.rect_stroke(
+            rect,
+            2.0,
+            Stroke::new(1.0, theme.colors.dim),
+            StrokeKind::Inside,
+        );
+
+        let inner = rect.shrink(4.0);
+        let center = inner.center();
+        let radius = inner.width().min(inner.height()) * 0.5;
+
+        // Guide axes: M vertical, S horizontal, L and R diagonals.
+        let guide = Stroke::new(1.0, theme.colors.dim);
+        let diag = radius * core::f32::consts::FRAC_1_SQRT_2;
+        painter.line_segment(
+            [
+                pos2(center.x, center.y - radius),
+                pos2(center.x, center.y + radius),
+            ],
+            guide,
+        );
+        painter.line_segment(
+            [
+                pos2(center.x - radius, center.y),
+                pos2(center.x + radius, center.y),
+            ],
+            guide,
+        );
+        painter.line_segment(
+            [
+                pos2(center.x - diag, center.y - diag),
+                pos2(center.x + diag, center.y + diag),
+            ],
+            guide,
+        );
+        painter.line_segment(
+            [
+                pos2(center.x + diag, center.y - diag),
+                pos2(center.x - diag, center.y + diag),
+            ],
+            guide,
+        );
+
+        let font = FontId::proportional(9.0);
+        for (text, pos, align) in [
+            ("M", pos2(center.x + 3.0, inner.top()), Align2::LEFT_TOP),
+            (
+                "L",
+                pos2(center.x - diag + 2.0, center.y - diag),
+                Align2::LEFT_BOTTOM,
+            ),
+            (
+                "R",
+                pos2(center.x + diag - 2.0, center.y - diag),
+                Align2::RIGHT_BOTTOM,
+            ),
+        ] {
+            painter.text(pos, align, text, font.clone(), theme.colors.text_secondary);
+        }
+
+        let pairs: Vec<[f32; 2]> = self.state.recent(self.points).collect();
+        let n = pairs.len();
+        let scale = radius / self.amplitude_scale;
+        for (i, &[l, r]) in pairs.iter().enumerate() {
+            // Older dots fade out so the figure reads as a trace.
+            let age = (i + 1) as f32 / n as f32;
+            let (side, mid) = mid_side(l, r);
+            let x = center.x + (side * scale).clamp(-radius, radius);
+            let y = center.y - (mid * scale).clamp(-radius, radius);
+            painter.circle_filled(
+                pos2(x, y),
+                1.0,
+                theme.colors.green.gamma_multiply(0.2 + 0.8 * age),
+            );
+        }
+
+        response
+    }
+}
+
+/// Horizontal phase-correlation meter.
+///
+/// A bar grows from the centre toward the reading: green toward +1
+/// (mono-compatible), red toward −1 (cancels in mono).
+///
+/// ## Parameters
+/// - `correlation`: Correlation from −1.0 to +1.0 (clamped).
+/// - `width`: Meter width in pixels (default 120.0).
+/// - `height`: Bar height in pixels (default 10.0).
+pub struct CorrelationMeter {
+    correlation: f32,
+    width: f32,
+    height: f32,
+}
+
+impl CorrelationMeter {
+    /// Create a meter showing `correlation`.
+    pub fn new(correlation: f32) -> Self {
+        Self {
+            correlation: correlation.clamp(-1.0, 1.0),
+            width: 120.0,
+            height: 10.0,
+        }
+    }
+
+    /// Set dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+}
+
+impl Widget for CorrelationMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = SonidoTheme::get(ui.ctx());
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(self.width, self.height), Sense::hover());
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, theme.colors.void);
+            painter.rect_stroke(
+                rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+
+            let inner = rect.shrink(2.0);
+            let center_x = inner.center().x;
+            let x = center_x + self.correlation * inner.width() * 0.5;
+            if (x - center_x).abs() > 0.5 {
+                let fill = Rect::from_min_max(
+                    pos2(x.min(center_x), inner.top()),
+                    pos2(x.max(center_x), inner.bottom()),
+                );
+                let color = if self.correlation < CORRELATION_WARN {
+                    theme.colors.red
+                } else {
+                    theme.colors.green
+                };
+                glow::glow_rect(painter, fill, color, 0.0, &theme);
+            }
+
+            painter.line_segment(
+                [pos2(center_x, rect.top()), pos2(center_x, rect.bottom())],
+                Stroke::new(1.0, theme.colors.text_secondary),
+            );
+        }
+
+        response.on_hover_text(format!("Correlation {:+.2}", self.correlation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(n: usize) -> Vec<f32> {
+        (0..n)
+            .map(|i| (core::f32::consts::TAU * 440.0 * i as f32 / 48000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn correlation_tracks_phase_relationship() {
+        let signal = sine(48000);
+        let inverted: Vec<f32> = signal.iter().map(|s| -s).collect();
+
+        let mut mono = GoniometerState::new(256);
+        mono.push(&signal, &signal, 48000.0);
+        assert!(mono.correlation() > 0.99, "mono = {}", mono.correlation());
+
+        let mut flipped = GoniometerState::new(256);
+        flipped.push(&signal, &inverted, 48000.0);
+        assert!(
+            flipped.correlation() < -0.99,
+            "inverted = {}",
+            flipped.correlation()
+        );
+
+        // Sine against cosine is uncorrelated.
+        let quadrature: Vec<f32> = (0..48000)
+            .map(|i| (core::f32::consts::TAU * 440.0 * i as f32 / 48000.0).cos())
+            .collect();
+        let mut wide = GoniometerState::new(256);
+        wide.push(&signal, &quadrature, 48000.0);
+        assert!(
+            wide.correlation().abs() < 0.05,
+            "wide = {}",
+            wide.correlation()
+        );
+    }
+
+    #[test]
+    fn silence_reads_zero_and_reset_clears() {
+        let mut s = GoniometerState::new(64);
+        s.push(&[0.0; 128], &[0.0; 128], 48000.0);
+        assert_eq!(s.correlation(), 0.0);
+
+        // One silent channel is not a correlation either.
+        s.push(&sine(4800), &vec![0.0; 4800], 48000.0);
+        assert_eq!(s.correlation(), 0.0);
+
+        s.reset();
+        assert!(s.is_empty());
+        assert_eq!(s.correlation(), 0.0);
+    }
+
+    #[test]
+    fn ring_keeps_newest_pairs_in_order() {
+        let mut s = GoniometerState::new(4);
+        let left: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let right: Vec<f32> = left.iter().map(|v| -v).collect();
+        s.push(&left, &right, 48000.0);
+        assert_eq!(s.len(), 4);
+        let recent: Vec<[f32; 2]> = s.recent(2).collect();
+        assert_eq!(recent, vec![[4.0, -4.0], [5.0, -5.0]]);
+    }
+
+    #[test]
+    fn mid_side_orientation() {
+        // Mono sits on the vertical axis.
+        let (side, mid) = mid_side(0.5, 0.5);
+        assert!(side.abs() < 1e-6 && mid > 0.0);
+        // Hard left leans left, hard right leans right.
+        assert!(mid_side(1.0, 0.0).0 < 0.0);
+        assert!(mid_side(0.0, 1.0).0 > 0.0);
+        // Inverted polarity sits on the horizontal axis.
+        assert!(mid_side(0.5, -0.5).1.abs() < 1e-6);
+    }
+
+    #[test]
+    fn correlation_meter_clamps() {
+        assert_eq!(CorrelationMeter::new(3.0).correlation, 1.0);
+        assert_eq!(CorrelationMeter::new(-3.0).correlation, -1.0);
+    }
+}
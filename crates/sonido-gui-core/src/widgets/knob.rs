@@ -4,17 +4,54 @@
 //! and pointer line emerging from darkness. Uses [`glow`](super::glow)
 //! primitives for phosphor bloom on all drawn elements.
 //!
-//! Interaction (unchanged from original):
-//! - Drag vertically to adjust value
-//! - Shift+drag for fine control (10x reduction)
+//! Interaction:
+//! - Drag vertically to adjust value, or drag around the knob to set it by
+//!   angle ([`KnobDragMode::Circular`])
+//! - Scroll wheel over the knob adjusts the value
+//! - Shift+drag / Shift+scroll for fine control (10x reduction)
 //! - Double-click to reset to default
+//! - When focused (Tab): arrow keys step the value, Shift for a fine step;
+//!   Enter opens a text entry
+//! - Click the value text to type a value (see [`value_entry`](super::value_entry))
 //! - Cyan label, amber value text below knob
+//!
+//! Drag mode, speed, and scroll support default to the installed theme's
+//! [`KnobConfig`](crate::theme::KnobConfig), so a user preference applies to
+//! every knob; the builder methods override it per knob.
 
-use egui::{Response, Sense, Ui, Widget, pos2, vec2};
-use std::f32::consts::PI;
+use egui::{
+    EventFilter, Key, Modifiers, Pos2, Rect, Response, Sense, Ui, Vec2, Widget, pos2, vec2,
+};
+use std::f32::consts::{PI, TAU};
 
-use crate::theme::SonidoTheme;
+use crate::theme::{KnobDragMode, SonidoTheme};
 use crate::widgets::glow;
+use crate::widgets::value_entry::{self, EntryOutcome};
+
+/// Keyboard step as a fraction of the knob range.
+const KEY_STEP: f32 = 0.01;
+
+/// Fine (Shift) keyboard step as a fraction of the knob range.
+const KEY_STEP_FINE: f32 = 0.001;
+
+/// Fine-control (Shift) speed factor for drag and scroll.
+const FINE_FACTOR: f32 = 0.1;
+
+/// Scroll step as a fraction of the knob range per point scrolled
+/// (a typical wheel notch scrolls 50 points, so 2% per notch).
+const SCROLL_STEP: f32 = 0.0004;
+
+/// Arc start angle (bottom-left, 135°).
+const START_ANGLE: f32 = PI * 0.75;
+
+/// Arc sweep (270°).
+const SWEEP: f32 = PI * 1.5;
+
+/// Circular drags closer to the center than this are ignored (angle is noisy).
+const CIRCULAR_DEAD_RADIUS: f32 = 4.0;
+
+/// Parser for typed-in values; `None` rejects the text.
+type ValueParser<'a> = Box<dyn Fn(&str) -> Option<f32> + 'a>;
 
 /// Rotary knob parameters.
 pub struct Knob<'a> {
@@ -24,8 +61,11 @@ pub struct Knob<'a> {
     default: f32,
     label: &'a str,
     format_value: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    parse_value: Option<ValueParser<'a>>,
     diameter: f32,
     sensitivity: f32,
+    drag_mode: Option<KnobDragMode>,
+    scroll: Option<bool>,
     show_value: bool,
 }
 
@@ -39,8 +79,11 @@ impl<'a> Knob<'a> {
             default: (min + max) / 2.0,
             label,
             format_value: None,
+            parse_value: None,
             diameter: 60.0,
             sensitivity: 0.004,
+            drag_mode: None,
+            scroll: None,
             show_value: true,
         }
     }
@@ -57,18 +100,41 @@ impl<'a> Knob<'a> {
         self
     }
 
+    /// Set a parser for typed-in values.
+    ///
+    /// Receives the text from the value entry and returns the new value, or
+    /// `None` to reject it. Without a parser the text must be a plain number.
+    pub fn parse(mut self, parser: impl Fn(&str) -> Option<f32> + 'a) -> Self {
+        self.parse_value = Some(Box::new(parser));
+        self
+    }
+
     /// Set knob diameter in pixels.
     pub fn diameter(mut self, diameter: f32) -> Self {
         self.diameter = diameter;
         self
     }
 
-    /// Set sensitivity (value change per pixel dragged).
+    /// Set sensitivity (fraction of the range per pixel dragged).
+    ///
+    /// Scaled by the theme's [`KnobConfig::sensitivity`](crate::theme::KnobConfig::sensitivity).
     pub fn sensitivity(mut self, sensitivity: f32) -> Self {
         self.sensitivity = sensitivity;
         self
     }
 
+    /// Override the theme's drag mode for this knob.
+    pub fn drag_mode(mut self, mode: KnobDragMode) -> Self {
+        self.drag_mode = Some(mode);
+        self
+    }
+
+    /// Override the theme's scroll-wheel setting for this knob.
+    pub fn scroll(mut self, enabled: bool) -> Self {
+        self.scroll = Some(enabled);
+        self
+    }
+
     /// Hide the value text below the knob.
     ///
     /// Use when an external display (e.g., LED) shows the value instead.
@@ -113,10 +179,74 @@ impl<'a> Knob<'a> {
     pub fn format_ratio(self) -> Self {
         self.format(|v| format!("{:.1}:1", v))
     }
+
+    /// Format a value with the custom formatter, or two decimals.
+    fn display(&self, value: f32) -> String {
+        match self.format_value {
+            Some(ref formatter) => formatter(value),
+            None => format!("{value:.2}"),
+        }
+    }
+
+    /// Parse typed text with the custom parser, or as a plain number.
+    fn parse_text(&self, text: &str) -> Option<f32> {
+        match self.parse_value {
+            Some(ref parser) => parser(text),
+            None => text.trim().parse().ok(),
+        }
+    }
+
+    /// Handle arrow-key stepping and Enter while the knob has focus.
+    ///
+    /// Returns `true` if the value changed.
+    fn keyboard(&mut self, ui: &Ui, response: &Response) -> bool {
+        // Keep arrow keys for stepping instead of moving focus.
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                response.id,
+                EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            );
+        });
+
+        let (up, down, enter, fine) = ui.input_mut(|i| {
+            let mut up = 0;
+            let mut down = 0;
+            // `Modifiers::NONE` also matches Shift+arrow; Shift picks the fine step.
+            while i.consume_key(Modifiers::NONE, Key::ArrowUp)
+                || i.consume_key(Modifiers::NONE, Key::ArrowRight)
+            {
+                up += 1;
+            }
+            while i.consume_key(Modifiers::NONE, Key::ArrowDown)
+                || i.consume_key(Modifiers::NONE, Key::ArrowLeft)
+            {
+                down += 1;
+            }
+            let enter = i.consume_key(Modifiers::NONE, Key::Enter);
+            (up, down, enter, i.modifiers.shift)
+        });
+
+        if enter {
+            value_entry::open(ui.ctx(), response.id, self.display(*self.value));
+        }
+
+        let steps = up - down;
+        if steps == 0 {
+            return false;
+        }
+        let fraction = if fine { KEY_STEP_FINE } else { KEY_STEP };
+        let delta = steps as f32 * fraction * (self.max - self.min);
+        *self.value = (*self.value + delta).clamp(self.min, self.max);
+        true
+    }
 }
 
 impl Widget for Knob<'_> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         // Extra space: label only (20px) or label + value text (35px)
         let extra = if self.show_value { 35.0 } else { 20.0 };
         let size = vec2(self.diameter, self.diameter + extra);
@@ -127,6 +257,26 @@ impl Widget for Knob<'_> {
 
         // Handle interaction
         let mut changed = false;
+        let value_rect = Rect::from_center_size(
+            pos2(rect.center().x, center.y + radius + 28.0),
+            vec2(self.diameter.max(64.0), 16.0),
+        );
+        let editing = value_entry::is_open(ui.ctx(), response.id);
+
+        // Click on the value text to type a value
+        if self.show_value
+            && !editing
+            && response.clicked()
+            && response
+                .interact_pointer_pos()
+                .is_some_and(|p| value_rect.contains(p))
+        {
+            value_entry::open(ui.ctx(), response.id, self.display(*self.value));
+        }
+
+        if response.has_focus() && !editing {
+            changed |= self.keyboard(ui, &response);
+        }
 
         // Double-click to reset
         if response.double_clicked() {
@@ -134,21 +284,46 @@ impl Widget for Knob<'_> {
             changed = true;
         }
 
+        let config = SonidoTheme::get(ui.ctx()).knob;
+        let fine = ui.input(|i| i.modifiers.shift);
+        let speed = config.sensitivity * if fine { FINE_FACTOR } else { 1.0 };
+        let range = self.max - self.min;
+
         // Drag to adjust
         if response.dragged() {
-            let delta = response.drag_delta();
-            let sensitivity = if ui.input(|i| i.modifiers.shift) {
-                self.sensitivity * 0.1 // Fine control
-            } else {
-                self.sensitivity
+            let drag_mode = self.drag_mode.unwrap_or(config.drag_mode);
+            let absolute = match drag_mode {
+                KnobDragMode::Circular if !fine => response
+                    .interact_pointer_pos()
+                    .and_then(|p| angle_fraction(center, p)),
+                _ => None,
             };
-
-            // Vertical drag changes value (up = increase)
-            let value_delta = -delta.y * sensitivity * (self.max - self.min);
-            *self.value = (*self.value + value_delta).clamp(self.min, self.max);
+            if let Some(fraction) = absolute {
+                *self.value = self.min + fraction * range;
+            } else {
+                // Vertical drag changes value (up = increase)
+                let delta = response.drag_delta();
+                let value_delta = -delta.y * self.sensitivity * speed * range;
+                *self.value = (*self.value + value_delta).clamp(self.min, self.max);
+            }
             changed = true;
         }
 
+        // Scroll to adjust. Shift turns wheel motion horizontal, so both axes count.
+        if self.scroll.unwrap_or(config.scroll) && response.hovered() && !editing {
+            let scrolled = ui.input_mut(|i| {
+                let delta = i.smooth_scroll_delta;
+                // Consume it so an enclosing scroll area stays put.
+                i.smooth_scroll_delta = Vec2::ZERO;
+                delta.x + delta.y
+            });
+            if scrolled != 0.0 {
+                let value_delta = scrolled * SCROLL_STEP * speed * range;
+                *self.value = (*self.value + value_delta).clamp(self.min, self.max);
+                changed = true;
+            }
+        }
+
         // Draw knob
         if ui.is_rect_visible(rect) {
             let painter = ui.painter();
@@ -162,21 +337,19 @@ impl Widget for Knob<'_> {
                 1.0
             };
 
-            // Knob arc angles (270 degree sweep, starting from bottom-left)
-            let start_angle = PI * 0.75; // 135 degrees
-            let end_angle = PI * 2.25; // 405 degrees (wraps around)
-            let sweep = end_angle - start_angle;
+            // Knob arc: 270 degree sweep from bottom-left, wrapping to 405 degrees
+            let end_angle = START_ANGLE + SWEEP;
 
             // Normalized value position
             let normalized = (*self.value - self.min) / (self.max - self.min);
-            let value_angle = start_angle + normalized * sweep;
+            let value_angle = START_ANGLE + normalized * SWEEP;
 
             // Track (background arc) — dim ghost trace
             glow::glow_arc(
                 painter,
                 center,
                 radius - 2.0,
-                start_angle,
+                START_ANGLE,
                 end_angle,
                 theme.colors.dim,
                 4.0,
@@ -190,7 +363,7 @@ impl Widget for Knob<'_> {
                     painter,
                     center,
                     radius - 2.0,
-                    start_angle,
+                    START_ANGLE,
                     value_angle,
                     theme.colors.amber,
                     6.0 * hover_mult,
@@ -233,12 +406,8 @@ impl Widget for Knob<'_> {
             );
 
             // Value text (hidden when an external LED display is used)
-            if self.show_value {
-                let value_text = if let Some(ref formatter) = self.format_value {
-                    formatter(*self.value)
-                } else {
-                    format!("{:.2}", *self.value)
-                };
+            if self.show_value && !value_entry::is_open(ui.ctx(), response.id) {
+                let value_text = self.display(*self.value);
                 let value_pos = pos2(rect.center().x, center.y + radius + 22.0);
                 painter.text(
                     value_pos,
@@ -250,6 +419,17 @@ impl Widget for Knob<'_> {
             }
         }
 
+        // Typed entry replaces the value text; an external display hosts it
+        // itself when the built-in text is hidden.
+        if self.show_value
+            && value_entry::is_open(ui.ctx(), response.id)
+            && let EntryOutcome::Commit(text) = value_entry::show(ui, response.id, value_rect)
+            && let Some(v) = self.parse_text(&text)
+        {
+            *self.value = v.clamp(self.min, self.max);
+            changed = true;
+        }
+
         if changed {
             response.mark_changed();
         }
@@ -258,6 +438,25 @@ impl Widget for Knob<'_> {
     }
 }
 
+/// Position along the knob's arc (0–1) for a pointer at `pos`.
+///
+/// Angles in the dead zone below the knob snap to the nearer end. Returns
+/// `None` too close to the center to read an angle.
+fn angle_fraction(center: Pos2, pos: Pos2) -> Option<f32> {
+    let d = pos - center;
+    if d.length() < CIRCULAR_DEAD_RADIUS {
+        return None;
+    }
+    let t = (d.y.atan2(d.x) - START_ANGLE).rem_euclid(TAU);
+    Some(if t <= SWEEP {
+        t / SWEEP
+    } else if t < SWEEP + (TAU - SWEEP) / 2.0 {
+        1.0
+    } else {
+        0.0
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,4 +467,32 @@ mod tests {
         let knob = Knob::new(&mut value, 0.0, 1.0, "Test").default(0.25);
         assert_eq!(knob.default, 0.25);
     }
+
+    #[test]
+    fn test_angle_fraction() {
+        let c = pos2(0.0, 0.0);
+        let at = |deg: f32| {
+            let r = deg.to_radians();
+            angle_fraction(c, pos2(r.cos() * 20.0, r.sin() * 20.0)).unwrap()
+        };
+        // Screen y points down: 135° is bottom-left, 270° is straight up.
+        assert!(at(135.0).abs() < 1e-4);
+        assert!((at(270.0) - 0.5).abs() < 1e-4);
+        assert!((at(45.0) - 1.0).abs() < 1e-4);
+        // Dead zone below the knob snaps to the nearer end.
+        assert_eq!(at(80.0), 1.0);
+        assert_eq!(at(100.0), 0.0);
+        assert_eq!(angle_fraction(c, pos2(1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_knob_parse_text() {
+        let mut value = 0.5;
+        let knob = Knob::new(&mut value, 0.0, 1.0, "Test");
+        assert_eq!(knob.parse_text(" 0.25 "), Some(0.25));
+        assert_eq!(knob.parse_text("abc"), None);
+
+        let knob = knob.parse(|t| t.strip_suffix('%')?.parse::<f32>().ok().map(|v| v / 100.0));
+        assert_eq!(knob.parse_text("40%"), Some(0.4));
+    }
 }
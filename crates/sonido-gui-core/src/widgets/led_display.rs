@@ -110,8 +110,11 @@ impl LedDisplay {
                     format!("{value:.0}ms")
                 }
             }
+            ParamUnit::Seconds => format!("{value:.2} s"),
             ParamUnit::Percent => format!("{value:.0} %"),
             ParamUnit::Ratio => format!("{value:.1}:1"),
+            ParamUnit::Semitones => format!("{value:.1}st"),
+            ParamUnit::Cents => format!("{value:.0}ct"),
             ParamUnit::None => format!("{value:.2}"),
             _ => format!("{value:.2}{}", unit.suffix()),
         };
@@ -11,6 +11,10 @@
 //!
 //! - [`GainReductionMeter`] — Segmented LED-bar meter for compressor gain
 //!   reduction display. Lights top-down in amber with phosphor bloom.
+//!
+//! - [`LoudnessMeter`] — Horizontal LUFS bar (momentary fill, short-term
+//!   line, optional target marker) with momentary / short-term / integrated /
+//!   true-peak readouts.
 
 use egui::{Rect, Response, Sense, Stroke, StrokeKind, Ui, Widget, pos2, vec2};
 
@@ -39,6 +43,15 @@ const DB_MARKS: &[(f32, &str)] = &[
     (0.063, "-24"),
 ];
 
+/// Lowest loudness on the [`LoudnessMeter`] bar, in LUFS.
+const LUFS_FLOOR: f32 = -60.0;
+
+/// Highest loudness on the [`LoudnessMeter`] bar, in LUFS.
+const LUFS_CEILING: f32 = 0.0;
+
+/// Height of the readout row below the loudness bar, in pixels.
+const READOUT_HEIGHT: f32 = 16.0;
+
 /// Continuous dual-bar level meter with dB scale and clip indicator.
 ///
 /// Renders an RMS bar (filled rectangle) and a peak line overlaid on a void
@@ -416,6 +429,150 @@ impl Widget for GainReductionMeter {
     }
 }
 
+/// Loudness meter for BS.1770 readings.
+///
+/// Draws a horizontal bar from −60 to 0 LUFS: the momentary loudness fills
+/// the bar and the short-term loudness is a thin line. The fill turns yellow
+/// above the target. A row below shows momentary, short-term, integrated,
+/// and true-peak values; non-finite readings (no complete block yet) show
+/// as `--`. The widget senses clicks so the caller can reset the
+/// measurement.
+///
+/// ## Parameters
+/// - `momentary`, `short_term`, `integrated`: Loudness in LUFS.
+/// - `true_peak_db`: True peak in dBTP.
+/// - `target`: Optional target loudness in LUFS, drawn as an amber marker.
+/// - `width`: Meter width in pixels (default 240.0).
+/// - `height`: Bar height in pixels (default 14.0), excluding the readout row.
+pub struct LoudnessMeter {
+    momentary: f32,
+    short_term: f32,
+    integrated: f32,
+    true_peak_db: f32,
+    target: Option<f32>,
+    width: f32,
+    height: f32,
+}
+
+impl LoudnessMeter {
+    /// Create a loudness meter from the current readings.
+    pub fn new(momentary: f32, short_term: f32, integrated: f32, true_peak_db: f32) -> Self {
+        Self {
+            momentary,
+            short_term,
+            integrated,
+            true_peak_db,
+            target: None,
+            width: 240.0,
+            height: 14.0,
+        }
+    }
+
+    /// Mark a target loudness in LUFS (e.g. −14 for streaming).
+    pub fn target(mut self, lufs: f32) -> Self {
+        self.target = Some(lufs);
+        self
+    }
+
+    /// Set dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+}
+
+/// Position of `lufs` on the loudness bar, 0.0 (floor) to 1.0 (ceiling).
+fn lufs_fraction(lufs: f32) -> f32 {
+    if lufs.is_finite() {
+        ((lufs - LUFS_FLOOR) / (LUFS_CEILING - LUFS_FLOOR)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Format a loudness or peak reading with one decimal, `--` when non-finite.
+fn format_reading(value: f32) -> String {
+    if value.is_finite() && value > -200.0 {
+        format!("{value:.1}")
+    } else {
+        "--".to_string()
+    }
+}
+
+impl Widget for LoudnessMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = SonidoTheme::get(ui.ctx());
+        let size = vec2(self.width, self.height + READOUT_HEIGHT);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let bar_rect = Rect::from_min_size(rect.min, vec2(self.width, self.height));
+
+            painter.rect_filled(bar_rect, 2.0, theme.colors.void);
+            painter.rect_stroke(
+                bar_rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+
+            let inner = bar_rect.shrink(2.0);
+            let x_at = |lufs: f32| inner.left() + inner.width() * lufs_fraction(lufs);
+
+            // Momentary fill
+            let fill = lufs_fraction(self.momentary);
+            if fill > 0.0 {
+                let over = self.target.is_some_and(|t| self.momentary > t);
+                let color = if over {
+                    theme.colors.yellow
+                } else {
+                    theme.colors.green
+                };
+                let fill_rect =
+                    Rect::from_min_size(inner.min, vec2(inner.width() * fill, inner.height()));
+                painter.rect_filled(fill_rect, 0.0, color);
+            }
+
+            // Short-term line
+            if lufs_fraction(self.short_term) > 0.0 {
+                let x = x_at(self.short_term);
+                painter.line_segment(
+                    [pos2(x, inner.top()), pos2(x, inner.bottom())],
+                    Stroke::new(1.0, theme.colors.text_primary),
+                );
+            }
+
+            // Target marker
+            if let Some(target) = self.target {
+                let x = x_at(target);
+                painter.line_segment(
+                    [pos2(x, bar_rect.top()), pos2(x, bar_rect.bottom())],
+                    Stroke::new(2.0, theme.colors.amber),
+                );
+            }
+
+            let readout = format!(
+                "M {}  S {}  I {} LUFS  TP {} dBTP",
+                format_reading(self.momentary),
+                format_reading(self.short_term),
+                format_reading(self.integrated),
+                format_reading(self.true_peak_db),
+            );
+            painter.text(
+                pos2(rect.left(), bar_rect.bottom() + 3.0),
+                egui::Align2::LEFT_TOP,
+                readout,
+                egui::FontId::proportional(11.0),
+                theme.colors.text_secondary,
+            );
+        }
+
+        response
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -512,4 +669,29 @@ mod tests {
         assert_eq!(meter.width, 16.0);
         assert_eq!(meter.height, 60.0);
     }
+
+    #[test]
+    fn loudness_meter_builder() {
+        let meter = LoudnessMeter::new(-14.0, -15.0, -16.0, -1.0)
+            .target(-14.0)
+            .size(300.0, 20.0);
+        assert_eq!(meter.target, Some(-14.0));
+        assert_eq!(meter.width, 300.0);
+        assert_eq!(meter.height, 20.0);
+    }
+
+    #[test]
+    fn lufs_fraction_maps_scale() {
+        assert_eq!(lufs_fraction(LUFS_FLOOR), 0.0);
+        assert_eq!(lufs_fraction(-30.0), 0.5);
+        assert_eq!(lufs_fraction(6.0), 1.0);
+        assert_eq!(lufs_fraction(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn readings_format_silence_as_dashes() {
+        assert_eq!(format_reading(-14.04), "-14.0");
+        assert_eq!(format_reading(f32::NEG_INFINITY), "--");
+        assert_eq!(format_reading(-200.0), "--");
+    }
 }
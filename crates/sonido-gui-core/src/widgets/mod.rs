@@ -1,7 +1,7 @@
 //! Audio-specific GUI widgets.
 //!
 //! Reusable widgets for building audio effect interfaces:
-//! - [`Knob`] — Rotary control with drag, fine control, and double-click reset
+//! - [`Knob`] — Rotary control with drag, fine control, keyboard stepping, and double-click reset
 //! - [`Fader`] — Vertical slot fader with LED-segment fill
 //! - [`bridged_knob`] — Bridge-aware knob with auto-format and gesture protocol
 //! - [`bridged_knob_fmt`] — Bridge-aware knob with custom formatter
@@ -10,31 +10,55 @@
 //! - [`gesture_wrap`] — Gesture protocol helper for custom widget layouts
 //! - [`LevelMeter`] — Continuous dual-bar (RMS + peak) meter with dB scale
 //! - [`GainReductionMeter`] — Compressor gain reduction display
+//! - [`LoudnessMeter`] — LUFS bar with momentary / short-term / integrated / true-peak readouts
 //! - [`BypassToggle`] — Small bypass indicator for effect panels
 //! - [`FootswitchToggle`] — Large pedal-style toggle for the chain view
+//! - [`EqCurve`] — EQ frequency-response plot with draggable band handles
+//! - [`bridged_eq_curve`] — EQ curve bound to frequency/gain/Q parameters
+//! - [`StepPatternEditor`] — Clickable step cells for rhythmic patterns
+//! - [`bridged_step_pattern`] — Step pattern editor bound to consecutive parameters
+//! - [`TapTimeline`] — Delay taps as level bars on a time axis, with pan ticks
+//! - [`bridged_tap_timeline`] — Tap timeline read from time/level/pan parameters
 //! - [`SpectrumWidget`] / [`SpectrumState`] — FFT magnitude display on log frequency axis
 //! - [`WaveformWidget`] / [`WaveformState`] — Scrolling time-domain waveform display
+//! - [`WaveformView`] / [`WaveformViewState`] — Zoomable file/recording timeline with selection and markers
+//! - [`GoniometerWidget`] / [`GoniometerState`] — Stereo Lissajous display (mid/side)
+//! - [`CorrelationMeter`] — Phase correlation bar from −1 to +1
+//! - [`value_entry`] — Inline text entry for typing parameter values
 
 mod bridged_knob;
+pub mod eq_curve;
 pub mod fader;
 pub mod glow;
+pub mod goniometer;
 mod knob;
 pub mod led_display;
 mod meter;
 mod morph_bar;
 pub mod spectrum;
+mod step_pattern;
+pub mod tap_timeline;
 mod toggle;
+pub mod value_entry;
 pub mod waveform;
+pub mod waveform_view;
 
 pub use bridged_knob::{
     bridged_combo, bridged_fader, bridged_knob, bridged_knob_fmt, bridged_knob_with_morph,
     gesture_wrap,
 };
+pub use eq_curve::{EqBand, EqBandParams, EqCurve, EqCurveResponse, bridged_eq_curve};
 pub use fader::Fader;
+pub use goniometer::{CorrelationMeter, GoniometerState, GoniometerWidget};
 pub use knob::Knob;
 pub use led_display::LedDisplay;
-pub use meter::{GainReductionMeter, LevelMeter};
+pub use meter::{GainReductionMeter, LevelMeter, LoudnessMeter};
 pub use morph_bar::{MorphBarResponse, morph_bar};
-pub use spectrum::{SpectrumState, SpectrumWidget};
+pub use spectrum::{SpectrumState, SpectrumWidget, frequency_x};
+pub use step_pattern::{StepPatternEditor, bridged_step_pattern};
+pub use tap_timeline::{Tap, TapParams, TapTimeline, bridged_tap_timeline};
 pub use toggle::{BypassToggle, FootswitchToggle};
 pub use waveform::{WaveformState, WaveformWidget};
+pub use waveform_view::{
+    PeakPyramid, WaveformMarker, WaveformView, WaveformViewResponse, WaveformViewState,
+};
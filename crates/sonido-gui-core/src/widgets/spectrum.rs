@@ -34,6 +34,15 @@ const FREQ_MIN: f32 = 20.0;
 /// Maximum frequency on the log axis.
 const FREQ_MAX: f32 = 20_000.0;
 
+/// Horizontal position of `freq_hz` on the log frequency axis of a
+/// [`SpectrumWidget`] drawn in `rect`, for overlays. Frequencies outside
+/// 20 Hz – 20 kHz land outside the plot.
+pub fn frequency_x(rect: Rect, freq_hz: f32) -> f32 {
+    let inner = rect.shrink(2.0);
+    let t = (freq_hz.max(1.0).log10() - FREQ_MIN.log10()) / (FREQ_MAX.log10() - FREQ_MIN.log10());
+    inner.left() + t * inner.width()
+}
+
 /// Per-bin state for the spectrum analyzer (peak-hold with decay).
 #[derive(Clone, Debug)]
 pub struct SpectrumState {
@@ -187,14 +196,8 @@ impl Widget for SpectrumWidget<'_> {
         let bar_w = (inner.width() / cols as f32).max(1.0);
 
         // Draw frequency grid lines (1kHz, 5kHz, 10kHz) at dim intensity.
-        let log_min = FREQ_MIN.log10();
-        let log_max = FREQ_MAX.log10();
         for &grid_hz in &[100.0f32, 1000.0, 5000.0, 10_000.0] {
-            let t = (grid_hz.log10() - log_min) / (log_max - log_min);
-            if !(0.0..=1.0).contains(&t) {
-                continue;
-            }
-            let x = inner.left() + t * inner.width();
+            let x = frequency_x(rect, grid_hz);
             painter.line_segment(
                 [pos2(x, inner.top()), pos2(x, inner.bottom())],
                 Stroke::new(1.0, theme.colors.dim),
@@ -277,6 +280,15 @@ impl Widget for SpectrumWidget<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn frequency_x_spans_the_axis() {
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(204.0, 100.0));
+        assert!((frequency_x(rect, FREQ_MIN) - 2.0).abs() < 1e-3);
+        assert!((frequency_x(rect, FREQ_MAX) - 202.0).abs() < 1e-3);
+        // 20 Hz – 20 kHz is three decades; 632 Hz is halfway
+        assert!((frequency_x(rect, 632.456) - 102.0).abs() < 0.01);
+    }
+
     #[test]
     fn spectrum_state_new() {
         let s = SpectrumState::new(128);
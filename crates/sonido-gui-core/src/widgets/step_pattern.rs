@@ -0,0 +1,235 @@
+//! Step pattern editor for rhythmic effects.
+//!
+//! [`StepPatternEditor`] draws a row of step cells, each holding a level
+//! (for the slicer: Off, On, Accent). Clicking a cell cycles its level
+//! upward and wraps back to zero; right-clicking clears it. Steps past the
+//! pattern length are drawn ghosted, and an optional playhead outlines the
+//! step currently playing.
+//!
+//! [`bridged_step_pattern`] binds the editor to a run of consecutive
+//! parameters on a [`ParamBridge`] slot, one parameter per step.
+
+use egui::{Rect, Response, Sense, Stroke, StrokeKind, Ui, Widget, pos2, vec2};
+
+use crate::theme::SonidoTheme;
+use crate::widgets::glow;
+use crate::{ParamBridge, ParamIndex, SlotIndex, set_grouped};
+
+/// Gap between step cells in points.
+const CELL_GAP: f32 = 3.0;
+
+/// Steps per visual group (a beat of sixteenths).
+const GROUP_SIZE: usize = 4;
+
+/// Extra gap between groups in points.
+const GROUP_GAP: f32 = 5.0;
+
+/// A row of clickable step cells.
+pub struct StepPatternEditor<'a> {
+    steps: &'a mut [u8],
+    levels: u8,
+    length: usize,
+    playhead: Option<usize>,
+    cell_size: f32,
+}
+
+impl<'a> StepPatternEditor<'a> {
+    /// Create an editor over `steps`, each holding a level below `levels`.
+    ///
+    /// `levels` is clamped to at least 2 (off and on).
+    pub fn new(steps: &'a mut [u8], levels: u8) -> Self {
+        let length = steps.len();
+        Self {
+            steps,
+            levels: levels.max(2),
+            length,
+            playhead: None,
+            cell_size: 22.0,
+        }
+    }
+
+    /// Number of active steps; later cells are drawn ghosted.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length.min(self.steps.len());
+        self
+    }
+
+    /// Outline the step currently playing.
+    pub fn playhead(mut self, step: Option<usize>) -> Self {
+        self.playhead = step;
+        self
+    }
+
+    /// Set the cell edge length in points.
+    pub fn cell_size(mut self, size: f32) -> Self {
+        self.cell_size = size;
+        self
+    }
+
+    /// Next level after `level`, wrapping to zero.
+    fn cycle(level: u8, levels: u8) -> u8 {
+        level.saturating_add(1) % levels
+    }
+
+    /// Rect of step `i` within a row starting at `origin`.
+    fn cell_rect(&self, origin: egui::Pos2, i: usize) -> Rect {
+        let x =
+            origin.x + i as f32 * (self.cell_size + CELL_GAP) + (i / GROUP_SIZE) as f32 * GROUP_GAP;
+        Rect::from_min_size(pos2(x, origin.y), vec2(self.cell_size, self.cell_size))
+    }
+
+    /// Total width of the row in points.
+    fn row_width(&self) -> f32 {
+        let n = self.steps.len();
+        if n == 0 {
+            return 0.0;
+        }
+        n as f32 * self.cell_size
+            + (n - 1) as f32 * CELL_GAP
+            + ((n - 1) / GROUP_SIZE) as f32 * GROUP_GAP
+    }
+}
+
+impl Widget for StepPatternEditor<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = vec2(self.row_width(), self.cell_size);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click());
+
+        if let Some(pos) = response.interact_pointer_pos()
+            && (response.clicked() || response.secondary_clicked())
+            && let Some(i) =
+                (0..self.steps.len()).find(|&i| self.cell_rect(rect.min, i).contains(pos))
+        {
+            self.steps[i] = if response.secondary_clicked() {
+                0
+            } else {
+                Self::cycle(self.steps[i], self.levels)
+            };
+            response.mark_changed();
+        }
+
+        if ui.is_rect_visible(rect) {
+            let theme = SonidoTheme::get(ui.ctx());
+            let painter = ui.painter();
+            let top = f32::from(self.levels - 1);
+
+            for (i, &level) in self.steps.iter().enumerate() {
+                let cell = self.cell_rect(rect.min, i);
+                let active = i < self.length;
+
+                painter.rect_filled(cell, 3.0, theme.colors.void);
+                if level > 0 {
+                    // Fill height follows the level; the top level glows amber
+                    let fraction = f32::from(level.min(self.levels - 1)) / top;
+                    let fill = Rect::from_min_max(
+                        pos2(
+                            cell.left() + 2.0,
+                            cell.bottom() - 2.0 - (cell.height() - 4.0) * fraction,
+                        ),
+                        pos2(cell.right() - 2.0, cell.bottom() - 2.0),
+                    );
+                    let color = if level >= self.levels - 1 && self.levels > 2 {
+                        theme.colors.amber
+                    } else {
+                        theme.colors.green
+                    };
+                    if active {
+                        glow::glow_rect(painter, fill, color, 2.0, &theme);
+                    } else {
+                        painter.rect_filled(fill, 2.0, glow::ghost(color, &theme));
+                    }
+                }
+
+                let border = if self.playhead == Some(i) && active {
+                    Stroke::new(1.5, theme.colors.cyan)
+                } else if active {
+                    Stroke::new(1.0, theme.colors.dim)
+                } else {
+                    Stroke::new(1.0, glow::ghost(theme.colors.dim, &theme))
+                };
+                painter.rect_stroke(cell, 3.0, border, StrokeKind::Inside);
+            }
+        }
+
+        response
+    }
+}
+
+/// Render a step pattern editor bound to consecutive parameters.
+///
+/// Step `i` reads and writes parameter `first.0 + i` on `slot`; `steps` is
+/// the number of step parameters. The level count comes from the first
+/// step's descriptor (`max + 1`). The steps changed by one edit are written
+/// as a single grouped gesture via [`set_grouped`]. `length` and `playhead`
+/// are passed through to [`StepPatternEditor`].
+pub fn bridged_step_pattern(
+    ui: &mut Ui,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    first: ParamIndex,
+    steps: usize,
+    length: usize,
+    playhead: Option<usize>,
+) -> Response {
+    let levels = bridge
+        .param_descriptor(slot, first)
+        .map_or(2, |d| (d.max + 1.5) as u8);
+    let before: Vec<u8> = (0..steps)
+        .map(|i| (bridge.get(slot, ParamIndex(first.0 + i)) + 0.5) as u8)
+        .collect();
+    let mut pattern = before.clone();
+
+    let response = ui.add(
+        StepPatternEditor::new(&mut pattern, levels)
+            .length(length)
+            .playhead(playhead),
+    );
+
+    if response.changed() {
+        let changes: Vec<_> = before
+            .iter()
+            .zip(&pattern)
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(i, (_, &new))| (slot, ParamIndex(first.0 + i), f32::from(new)))
+            .collect();
+        set_grouped(bridge, &changes);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_wraps_through_levels() {
+        assert_eq!(StepPatternEditor::cycle(0, 3), 1);
+        assert_eq!(StepPatternEditor::cycle(1, 3), 2);
+        assert_eq!(StepPatternEditor::cycle(2, 3), 0);
+        assert_eq!(StepPatternEditor::cycle(1, 2), 0);
+    }
+
+    #[test]
+    fn builder_clamps_length_and_levels() {
+        let mut steps = [0u8; 16];
+        let editor = StepPatternEditor::new(&mut steps, 1)
+            .length(40)
+            .playhead(Some(3));
+        assert_eq!(editor.levels, 2);
+        assert_eq!(editor.length, 16);
+        assert_eq!(editor.playhead, Some(3));
+    }
+
+    #[test]
+    fn cells_are_grouped_by_beat() {
+        let mut steps = [0u8; 8];
+        let editor = StepPatternEditor::new(&mut steps, 3).cell_size(10.0);
+        let origin = pos2(0.0, 0.0);
+        assert_eq!(editor.cell_rect(origin, 1).left(), 13.0);
+        // Step 4 starts a new group and picks up the extra gap
+        assert_eq!(editor.cell_rect(origin, 4).left(), 4.0 * 13.0 + GROUP_GAP);
+        assert_eq!(editor.row_width(), 8.0 * 10.0 + 7.0 * CELL_GAP + GROUP_GAP);
+    }
+}
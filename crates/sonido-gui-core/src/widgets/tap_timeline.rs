@@ -0,0 +1,251 @@
+//! Tap-time display for multi-tap delays.
+//!
+//! [`TapTimeline`] draws each delay tap as a vertical bar on a time axis
+//! running from zero to a given span: the bar's position is the tap time,
+//! its height the tap level. A short tick under each bar leans left or
+//! right with the tap's pan. The display is read-only; hovering a bar shows
+//! its exact values.
+//!
+//! [`bridged_tap_timeline`] reads time/level/pan parameter triples from a
+//! [`ParamBridge`] slot.
+
+use egui::{
+    Align2, FontId, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, Widget, pos2, vec2,
+};
+
+use crate::theme::SonidoTheme;
+use crate::widgets::glow;
+use crate::{ParamBridge, ParamIndex, SlotIndex};
+
+/// Height of the pan strip under the bars in points.
+const PAN_STRIP: f32 = 10.0;
+/// Horizontal reach of a hard-panned tick in points.
+const PAN_REACH: f32 = 8.0;
+/// Bar width in points.
+const BAR_WIDTH: f32 = 4.0;
+/// Shortest span the axis will show, in milliseconds.
+const MIN_SPAN_MS: f32 = 10.0;
+
+/// One delay tap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tap {
+    /// Delay time in milliseconds.
+    pub time_ms: f32,
+    /// Output level, 0.0 to 1.0.
+    pub level: f32,
+    /// Stereo position, -1.0 (left) to 1.0 (right).
+    pub pan: f32,
+}
+
+/// A read-only timeline of delay taps.
+pub struct TapTimeline<'a> {
+    taps: &'a [Tap],
+    span_ms: f32,
+    size: Vec2,
+}
+
+impl<'a> TapTimeline<'a> {
+    /// Create a timeline over `taps` with an axis from 0 to `span_ms`.
+    ///
+    /// The span is clamped to at least 10 ms.
+    pub fn new(taps: &'a [Tap], span_ms: f32) -> Self {
+        Self {
+            taps,
+            span_ms: span_ms.max(MIN_SPAN_MS),
+            size: vec2(320.0, 80.0),
+        }
+    }
+
+    /// Set the widget size in points.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = vec2(width, height);
+        self
+    }
+
+    /// Horizontal position of `time_ms` within `rect`, clamped to the axis.
+    fn time_to_x(&self, rect: Rect, time_ms: f32) -> f32 {
+        let t = (time_ms / self.span_ms).clamp(0.0, 1.0);
+        rect.left() + t * rect.width()
+    }
+}
+
+impl Widget for TapTimeline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::hover());
+        let plot = Rect::from_min_max(
+            pos2(rect.left() + BAR_WIDTH, rect.top() + 4.0),
+            pos2(rect.right() - BAR_WIDTH, rect.bottom() - PAN_STRIP),
+        );
+
+        let hovered_tap = response.hover_pos().and_then(|pos| {
+            self.taps
+                .iter()
+                .enumerate()
+                .filter(|(_, tap)| (self.time_to_x(plot, tap.time_ms) - pos.x).abs() <= 6.0)
+                .min_by(|(_, a), (_, b)| {
+                    let da = (self.time_to_x(plot, a.time_ms) - pos.x).abs();
+                    let db = (self.time_to_x(plot, b.time_ms) - pos.x).abs();
+                    da.total_cmp(&db)
+                })
+                .map(|(i, tap)| (i, *tap))
+        });
+
+        if ui.is_rect_visible(rect) {
+            let theme = SonidoTheme::get(ui.ctx());
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, theme.colors.void);
+            painter.rect_stroke(
+                rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+
+            // Quarter-span grid with the span labelled at the right edge.
+            let grid = Stroke::new(1.0, theme.colors.dim);
+            for q in 1..4 {
+                let x = plot.left() + plot.width() * q as f32 / 4.0;
+                painter.line_segment([pos2(x, plot.top()), pos2(x, plot.bottom())], grid);
+            }
+            painter.line_segment(
+                [
+                    pos2(plot.left(), plot.bottom()),
+                    pos2(plot.right(), plot.bottom()),
+                ],
+                Stroke::new(1.0, theme.colors.text_secondary),
+            );
+            painter.text(
+                pos2(rect.right() - 2.0, rect.top() + 1.0),
+                Align2::RIGHT_TOP,
+                format!("{:.0} ms", self.span_ms),
+                FontId::proportional(8.0),
+                theme.colors.text_secondary,
+            );
+
+            let pan_y = plot.bottom() + PAN_STRIP * 0.5;
+            for (i, tap) in self.taps.iter().enumerate() {
+                let x = self.time_to_x(plot, tap.time_ms);
+                let level = tap.level.clamp(0.0, 1.0);
+                let color = if hovered_tap.is_some_and(|(h, _)| h == i) {
+                    theme.colors.cyan
+                } else {
+                    theme.colors.amber
+                };
+
+                if level > 0.0 {
+                    let bar = Rect::from_min_max(
+                        pos2(x - BAR_WIDTH * 0.5, plot.bottom() - plot.height() * level),
+                        pos2(x + BAR_WIDTH * 0.5, plot.bottom()),
+                    );
+                    glow::glow_rect(painter, bar, color, 1.0, &theme);
+                } else {
+                    painter.line_segment(
+                        [pos2(x, plot.top()), pos2(x, plot.bottom())],
+                        Stroke::new(1.0, glow::ghost(color, &theme)),
+                    );
+                }
+
+                let lean = tap.pan.clamp(-1.0, 1.0) * PAN_REACH;
+                glow::glow_line(
+                    painter,
+                    pos2(x, pan_y),
+                    pos2(x + lean, pan_y),
+                    theme.colors.green,
+                    1.5,
+                    &theme,
+                );
+                painter.circle_filled(pos2(x, pan_y), 1.5, theme.colors.green);
+            }
+        }
+
+        match hovered_tap {
+            Some((i, tap)) => response.on_hover_text(format!(
+                "Tap {}: {:.1} ms  {:.0}%  {}",
+                i + 1,
+                tap.time_ms,
+                tap.level * 100.0,
+                pan_label(tap.pan)
+            )),
+            None => response,
+        }
+    }
+}
+
+/// Pan position as "C", "L50" or "R100".
+fn pan_label(pan: f32) -> String {
+    let pct = (pan.clamp(-1.0, 1.0) * 100.0).round();
+    if pct == 0.0 {
+        "C".to_owned()
+    } else if pct < 0.0 {
+        format!("L{:.0}", -pct)
+    } else {
+        format!("R{pct:.0}")
+    }
+}
+
+/// Parameter indices for one tap of a [`bridged_tap_timeline`].
+#[derive(Debug, Clone, Copy)]
+pub struct TapParams {
+    /// Delay time (ms).
+    pub time: ParamIndex,
+    /// Level (%).
+    pub level: ParamIndex,
+    /// Pan (%, -100 to 100).
+    pub pan: ParamIndex,
+}
+
+/// Render a [`TapTimeline`] from bridge parameters.
+///
+/// Times are read in milliseconds, levels and pans in percent. The span is
+/// the larger of `span_ms` and the longest tap, so no tap falls off the end.
+pub fn bridged_tap_timeline(
+    ui: &mut Ui,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    params: &[TapParams],
+    span_ms: f32,
+    width: f32,
+    height: f32,
+) -> Response {
+    let taps: Vec<Tap> = params
+        .iter()
+        .map(|p| Tap {
+            time_ms: bridge.get(slot, p.time),
+            level: bridge.get(slot, p.level) / 100.0,
+            pan: bridge.get(slot, p.pan) / 100.0,
+        })
+        .collect();
+    let span = taps.iter().map(|t| t.time_ms).fold(span_ms, f32::max);
+
+    ui.add(TapTimeline::new(&taps, span).size(width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_map_across_the_axis() {
+        let timeline = TapTimeline::new(&[], 400.0);
+        let rect = Rect::from_min_size(pos2(10.0, 0.0), vec2(200.0, 50.0));
+        assert_eq!(timeline.time_to_x(rect, 0.0), 10.0);
+        assert_eq!(timeline.time_to_x(rect, 100.0), 60.0);
+        assert_eq!(timeline.time_to_x(rect, 400.0), 210.0);
+        // Past the span pins to the right edge
+        assert_eq!(timeline.time_to_x(rect, 900.0), 210.0);
+    }
+
+    #[test]
+    fn span_has_a_floor() {
+        let timeline = TapTimeline::new(&[], 0.0);
+        assert_eq!(timeline.span_ms, MIN_SPAN_MS);
+    }
+
+    #[test]
+    fn pan_labels() {
+        assert_eq!(pan_label(0.0), "C");
+        assert_eq!(pan_label(-0.5), "L50");
+        assert_eq!(pan_label(1.0), "R100");
+        assert_eq!(pan_label(-3.0), "L100");
+    }
+}
@@ -0,0 +1,134 @@
+//! Inline text entry for typing parameter values.
+//!
+//! A value entry replaces a widget's value readout with a single-line text
+//! field. The open/closed state and the in-progress text live in egui temp
+//! memory keyed by the owning widget's [`Id`], so any widget can host one
+//! without extra fields:
+//!
+//! 1. [`open`] it (e.g. on a click on the value label or Enter on the knob),
+//!    pre-filled with the current formatted value.
+//! 2. Each frame while [`is_open`], call [`show`] in place of the readout.
+//! 3. Apply [`EntryOutcome::Commit`] through the parameter's parser; ignore
+//!    unparseable text so a typo never moves the parameter.
+//!
+//! Enter or clicking elsewhere commits; Escape cancels. If the owning widget
+//! had keyboard focus when the entry opened, focus returns to it afterwards so
+//! keyboard control continues uninterrupted.
+
+use egui::{Context, Id, Key, Rect, TextEdit, Ui};
+
+/// Result of one frame of a value entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryOutcome {
+    /// Still editing.
+    Editing,
+    /// The user confirmed this text.
+    Commit(String),
+    /// The user dismissed the entry without changing the value.
+    Cancel,
+}
+
+/// In-progress entry, stored in egui temp memory.
+#[derive(Debug, Clone)]
+struct EntryState {
+    text: String,
+    /// Give focus back to the owner when the entry closes.
+    refocus: bool,
+}
+
+/// Temp-memory slot holding the entry state for `owner`.
+fn state_id(owner: Id) -> Id {
+    owner.with("value_entry_state")
+}
+
+/// Id of the text field itself.
+fn edit_id(owner: Id) -> Id {
+    owner.with("value_entry_edit")
+}
+
+/// Open the value entry for `owner`, pre-filled with `initial` (fully selected).
+pub fn open(ctx: &Context, owner: Id, initial: impl Into<String>) {
+    let text = initial.into();
+    let len = text.chars().count();
+    let refocus = ctx.memory(|m| m.has_focus(owner));
+    ctx.data_mut(|d| d.insert_temp(state_id(owner), EntryState { text, refocus }));
+
+    let edit_id = edit_id(owner);
+    let mut edit_state = egui::text_edit::TextEditState::load(ctx, edit_id).unwrap_or_default();
+    edit_state
+        .cursor
+        .set_char_range(Some(egui::text::CCursorRange::two(
+            egui::text::CCursor::new(0),
+            egui::text::CCursor::new(len),
+        )));
+    edit_state.store(ctx, edit_id);
+    ctx.memory_mut(|m| m.request_focus(edit_id));
+}
+
+/// Whether the value entry for `owner` is open.
+pub fn is_open(ctx: &Context, owner: Id) -> bool {
+    ctx.data(|d| d.get_temp::<EntryState>(state_id(owner)).is_some())
+}
+
+/// Close the value entry for `owner` without committing.
+pub fn close(ctx: &Context, owner: Id) {
+    ctx.data_mut(|d| d.remove::<EntryState>(state_id(owner)));
+}
+
+/// Draw the open entry for `owner` inside `rect` and report what happened.
+///
+/// Returns [`EntryOutcome::Cancel`] if the entry is not open.
+pub fn show(ui: &mut Ui, owner: Id, rect: Rect) -> EntryOutcome {
+    let ctx = ui.ctx().clone();
+    let Some(mut state) = ctx.data(|d| d.get_temp::<EntryState>(state_id(owner))) else {
+        return EntryOutcome::Cancel;
+    };
+
+    let response = ui.put(
+        rect,
+        TextEdit::singleline(&mut state.text)
+            .id(edit_id(owner))
+            .font(egui::FontId::monospace(11.0))
+            .horizontal_align(egui::Align::Center)
+            .desired_width(rect.width()),
+    );
+
+    let outcome = if response.lost_focus() {
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            EntryOutcome::Cancel
+        } else {
+            EntryOutcome::Commit(state.text.trim().to_owned())
+        }
+    } else {
+        EntryOutcome::Editing
+    };
+
+    if outcome == EntryOutcome::Editing {
+        ctx.data_mut(|d| d.insert_temp(state_id(owner), state));
+    } else {
+        close(&ctx, owner);
+        // Hand keyboard control back unless the user clicked another widget.
+        if state.refocus && ctx.memory(|m| m.focused().is_none()) {
+            ctx.memory_mut(|m| m.request_focus(owner));
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_and_close_round_trip() {
+        let ctx = Context::default();
+        let owner = Id::new("knob");
+        assert!(!is_open(&ctx, owner));
+        open(&ctx, owner, "-6.0 dB");
+        assert!(is_open(&ctx, owner));
+        let state = ctx.data(|d| d.get_temp::<EntryState>(state_id(owner)));
+        assert_eq!(state.map(|s| s.text).as_deref(), Some("-6.0 dB"));
+        close(&ctx, owner);
+        assert!(!is_open(&ctx, owner));
+    }
+}
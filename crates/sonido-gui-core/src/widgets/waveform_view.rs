@@ -0,0 +1,574 @@
+//! Zoomable waveform timeline with selection and markers.
+//!
+//! [`WaveformView`] draws a recorded or loaded signal as min/max peaks, one
+//! stroke per pixel column, from a [`PeakPyramid`]. The pyramid stores
+//! peaks for blocks of [`PeakPyramid::BASE_BLOCK`] samples and each coarser
+//! level halves the block count, so any zoom level reads about one cached
+//! block per pixel instead of scanning the samples.
+//!
+//! [`WaveformViewState`] holds the pyramid, the visible window, an optional
+//! selection, and markers. Interaction:
+//!
+//! - Click or drag: seek (reported in [`WaveformViewResponse::seek`])
+//! - Shift + drag: select a region
+//! - Ctrl + scroll or pinch: zoom around the pointer
+//! - Scroll: pan
+//! - Double-click: zoom to fit
+//!
+//! An optional background image spanning the whole timeline (for example a
+//! spectrogram texture) is cropped to the visible window and drawn behind
+//! the peaks.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! let mut view = WaveformViewState::new(PeakPyramid::new(&samples), 48000.0);
+//! view.add_marker(1.5, "Verse");
+//!
+//! let out = WaveformView::new(&mut view).playhead(Some(position)).show(ui);
+//! if let Some(secs) = out.seek {
+//!     seek_to(secs);
+//! }
+//! ```
+
+use egui::{
+    Align2, Color32, FontId, Rect, Response, Sense, Stroke, StrokeKind, TextureId, Ui, Vec2, pos2,
+    vec2,
+};
+
+use crate::theme::SonidoTheme;
+
+/// Fewest samples the view can zoom in to.
+const MIN_SPAN_SAMPLES: f32 = 256.0;
+
+/// Pan distance per point of scroll, as a fraction of the view width.
+const PAN_RATE: f32 = 0.002;
+
+/// Min/max peak cache at successively halved resolutions.
+#[derive(Debug, Clone, Default)]
+pub struct PeakPyramid {
+    /// `levels[0]` covers [`BASE_BLOCK`](Self::BASE_BLOCK) samples per
+    /// entry; each further level covers twice as many.
+    levels: Vec<Vec<(f32, f32)>>,
+    /// Number of samples summarized.
+    len: usize,
+}
+
+impl PeakPyramid {
+    /// Samples per entry at the finest level.
+    pub const BASE_BLOCK: usize = 32;
+
+    /// Build the pyramid for `samples`.
+    pub fn new(samples: &[f32]) -> Self {
+        let base: Vec<(f32, f32)> = samples
+            .chunks(Self::BASE_BLOCK)
+            .map(|block| {
+                block
+                    .iter()
+                    .fold((f32::MAX, f32::MIN), |(lo, hi), &s| (lo.min(s), hi.max(s)))
+            })
+            .collect();
+
+        let mut levels = vec![base];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| {
+                    pair.iter().fold((f32::MAX, f32::MIN), |(lo, hi), &(a, b)| {
+                        (lo.min(a), hi.max(b))
+                    })
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Self {
+            levels,
+            len: samples.len(),
+        }
+    }
+
+    /// Number of samples summarized.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if built from no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Minimum and maximum over samples `start..end`.
+    ///
+    /// Uses the coarsest level with at least two entries in the range, so the
+    /// result may include up to one block beyond each end. Returns `(0, 0)`
+    /// for an empty range.
+    pub fn range(&self, start: usize, end: usize) -> (f32, f32) {
+        let end = end.min(self.len);
+        if start >= end {
+            return (0.0, 0.0);
+        }
+        let span = end - start;
+        let mut level = 0;
+        while level + 1 < self.levels.len() && span >= (Self::BASE_BLOCK << (level + 1)) * 2 {
+            level += 1;
+        }
+        let block = Self::BASE_BLOCK << level;
+        let entries = &self.levels[level];
+        let first = start / block;
+        let last = (end - 1) / block;
+        entries[first..=last.min(entries.len() - 1)]
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &(a, b)| {
+                (lo.min(a), hi.max(b))
+            })
+    }
+}
+
+/// A labelled position on the timeline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveformMarker {
+    /// Position in seconds.
+    pub secs: f32,
+    /// Label drawn at the top of the marker line.
+    pub label: String,
+}
+
+/// Peaks, visible window, selection, and markers for a [`WaveformView`].
+#[derive(Debug, Clone)]
+pub struct WaveformViewState {
+    peaks: PeakPyramid,
+    sample_rate: f32,
+    view_start: f32,
+    view_span: f32,
+    selection: Option<(f32, f32)>,
+    markers: Vec<WaveformMarker>,
+    /// Where the current shift-drag selection started.
+    drag_anchor: Option<f32>,
+}
+
+impl WaveformViewState {
+    /// Create a state showing the whole of `peaks`.
+    pub fn new(peaks: PeakPyramid, sample_rate: f32) -> Self {
+        let mut state = Self {
+            peaks,
+            sample_rate: sample_rate.max(1.0),
+            view_start: 0.0,
+            view_span: 0.0,
+            selection: None,
+            markers: Vec::new(),
+            drag_anchor: None,
+        };
+        state.zoom_to_fit();
+        state
+    }
+
+    /// Length of the signal in seconds.
+    pub fn duration_secs(&self) -> f32 {
+        self.peaks.len() as f32 / self.sample_rate
+    }
+
+    /// Visible window as `(start_secs, span_secs)`.
+    pub fn view(&self) -> (f32, f32) {
+        (self.view_start, self.view_span)
+    }
+
+    /// Show the whole signal.
+    pub fn zoom_to_fit(&mut self) {
+        self.view_start = 0.0;
+        self.view_span = self.duration_secs().max(self.min_span());
+    }
+
+    /// Zoom by `factor` (> 1 zooms in) keeping `anchor_secs` in place.
+    pub fn zoom(&mut self, factor: f32, anchor_secs: f32) {
+        if factor <= 0.0 {
+            return;
+        }
+        let max_span = self.duration_secs().max(self.min_span());
+        let span = (self.view_span / factor).clamp(self.min_span(), max_span);
+        let fraction = (anchor_secs - self.view_start) / self.view_span;
+        self.view_span = span;
+        self.set_start(anchor_secs - fraction * span);
+    }
+
+    /// Move the window by `delta_secs`, stopping at either end.
+    pub fn pan(&mut self, delta_secs: f32) {
+        self.set_start(self.view_start + delta_secs);
+    }
+
+    /// Scroll so `secs` is visible, paging forward or back if it is not.
+    pub fn reveal(&mut self, secs: f32) {
+        if secs < self.view_start || secs > self.view_start + self.view_span {
+            self.set_start(secs);
+        }
+    }
+
+    /// Selected region as `(start_secs, end_secs)`, start first.
+    pub fn selection(&self) -> Option<(f32, f32)> {
+        self.selection
+    }
+
+    /// Select `a..b` (in either order), clamped to the signal.
+    pub fn set_selection(&mut self, a: f32, b: f32) {
+        let duration = self.duration_secs();
+        let (a, b) = (a.clamp(0.0, duration), b.clamp(0.0, duration));
+        self.selection = Some((a.min(b), a.max(b)));
+    }
+
+    /// Remove the selection.
+    pub fn clear_selection(&mut self) {
+        self.selection = None;
+    }
+
+    /// Add a marker at `secs`.
+    pub fn add_marker(&mut self, secs: f32, label: impl Into<String>) {
+        self.markers.push(WaveformMarker {
+            secs,
+            label: label.into(),
+        });
+    }
+
+    /// Current markers, in insertion order.
+    pub fn markers(&self) -> &[WaveformMarker] {
+        &self.markers
+    }
+
+    /// Remove all markers.
+    pub fn clear_markers(&mut self) {
+        self.markers.clear();
+    }
+
+    fn min_span(&self) -> f32 {
+        MIN_SPAN_SAMPLES / self.sample_rate
+    }
+
+    fn set_start(&mut self, start: f32) {
+        let latest = (self.duration_secs() - self.view_span).max(0.0);
+        self.view_start = start.clamp(0.0, latest);
+    }
+
+    /// Time under screen `x` within `rect`.
+    fn secs_at(&self, rect: Rect, x: f32) -> f32 {
+        let t = ((x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+        self.view_start + t * self.view_span
+    }
+
+    /// Screen `x` for `secs` within `rect`.
+    fn x_at(&self, rect: Rect, secs: f32) -> f32 {
+        rect.left() + (secs - self.view_start) / self.view_span * rect.width()
+    }
+}
+
+/// Result of showing a [`WaveformView`].
+pub struct WaveformViewResponse {
+    /// Response for the whole timeline.
+    pub response: Response,
+    /// Position clicked or dragged to, in seconds.
+    pub seek: Option<f32>,
+    /// The selection was changed by a shift-drag this frame.
+    pub selection_changed: bool,
+}
+
+/// Zoomable waveform timeline.
+///
+/// ## Parameters
+/// - `state`: Peaks, view window, selection, and markers.
+/// - `playhead`: Playback position in seconds, drawn as a cyan line.
+/// - `follow`: Page the view to keep the playhead visible (default off).
+/// - `background`: Texture spanning the whole timeline, drawn behind the peaks.
+/// - `height`: Timeline height in pixels (default 80.0); width fills the row.
+pub struct WaveformView<'a> {
+    state: &'a mut WaveformViewState,
+    playhead: Option<f32>,
+    follow: bool,
+    background: Option<TextureId>,
+    height: f32,
+}
+
+impl<'a> WaveformView<'a> {
+    /// Create a timeline over `state`.
+    pub fn new(state: &'a mut WaveformViewState) -> Self {
+        Self {
+            state,
+            playhead: None,
+            follow: false,
+            background: None,
+            height: 80.0,
+        }
+    }
+
+    /// Draw a playhead at `secs`.
+    pub fn playhead(mut self, secs: Option<f32>) -> Self {
+        self.playhead = secs;
+        self
+    }
+
+    /// Keep the playhead in view while it moves.
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Draw `texture` behind the peaks, stretched over the whole timeline.
+    pub fn background(mut self, texture: TextureId) -> Self {
+        self.background = Some(texture);
+        self
+    }
+
+    /// Set the timeline height.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Draw the timeline and apply interaction to the state.
+    pub fn show(self, ui: &mut Ui) -> WaveformViewResponse {
+        let theme = SonidoTheme::get(ui.ctx());
+        let (rect, response) = ui.allocate_exact_size(
+            vec2(ui.available_width(), self.height),
+            Sense::click_and_drag(),
+        );
+        let inner = rect.shrink(2.0);
+        let state = self.state;
+
+        let mut seek = None;
+        let mut selection_changed = false;
+        let shift = ui.input(|i| i.modifiers.shift);
+
+        if response.double_clicked() {
+            state.zoom_to_fit();
+        } else if let Some(pos) = response.interact_pointer_pos() {
+            let secs = state.secs_at(inner, pos.x);
+            if response.drag_started() && shift {
+                state.drag_anchor = Some(secs);
+            }
+            if let Some(anchor) = state.drag_anchor
+                && response.dragged()
+            {
+                state.set_selection(anchor, secs);
+                selection_changed = true;
+            } else if response.clicked() || response.dragged() {
+                seek = Some(secs.min(state.duration_secs()));
+            }
+        }
+        if response.drag_stopped() {
+            state.drag_anchor = None;
+        }
+
+        if response.hovered() {
+            let (zoom, scroll) = ui.input_mut(|i| {
+                let scroll = i.smooth_scroll_delta;
+                // Consume it so an enclosing scroll area stays put.
+                i.smooth_scroll_delta = Vec2::ZERO;
+                (i.zoom_delta(), scroll)
+            });
+            if zoom != 1.0
+                && let Some(pos) = response.hover_pos()
+            {
+                state.zoom(zoom, state.secs_at(inner, pos.x));
+            } else if scroll != Vec2::ZERO {
+                state.pan(-(scroll.x + scroll.y) * PAN_RATE * state.view_span);
+            }
+        }
+
+        if self.follow
+            && let Some(secs) = self.playhead
+            && state.drag_anchor.is_none()
+        {
+            state.reveal(secs);
+        }
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, theme.colors.void);
+            let clipped = painter.with_clip_rect(painter.clip_rect().intersect(inner));
+            let duration = state.duration_secs();
+
+            if let Some(texture) = self.background
+                && duration > 0.0
+            {
+                let u0 = state.view_start / duration;
+                let u1 = (state.view_start + state.view_span) / duration;
+                clipped.image(
+                    texture,
+                    inner,
+                    Rect::from_min_max(pos2(u0, 0.0), pos2(u1, 1.0)),
+                    Color32::WHITE,
+                );
+            }
+
+            if let Some((a, b)) = state.selection {
+                let sel = Rect::from_x_y_ranges(
+                    state.x_at(inner, a)..=state.x_at(inner, b),
+                    inner.y_range(),
+                );
+                clipped.rect_filled(sel, 0.0, theme.colors.cyan.gamma_multiply(0.2));
+            }
+
+            // Peaks: one min/max stroke per pixel column.
+            let wave_color = if self.background.is_some() {
+                theme.colors.text_primary.gamma_multiply(0.6)
+            } else {
+                theme.colors.green
+            };
+            let wave = Stroke::new(1.0, wave_color);
+            let mid = inner.center().y;
+            let half = inner.height() * 0.5;
+            let columns = inner.width().max(1.0) as usize;
+            let samples_per_col = state.view_span * state.sample_rate / columns as f32;
+            let first = state.view_start * state.sample_rate;
+            for col in 0..columns {
+                let start = (first + col as f32 * samples_per_col) as usize;
+                let end = (first + (col + 1) as f32 * samples_per_col).ceil() as usize;
+                if start >= state.peaks.len() {
+                    break;
+                }
+                let (lo, hi) = state.peaks.range(start, end.max(start + 1));
+                let x = inner.left() + col as f32 + 0.5;
+                clipped.line_segment(
+                    [
+                        pos2(x, mid - hi.clamp(-1.0, 1.0) * half),
+                        pos2(x, mid - lo.clamp(-1.0, 1.0) * half),
+                    ],
+                    wave,
+                );
+            }
+
+            let font = FontId::proportional(9.0);
+            for marker in &state.markers {
+                let x = state.x_at(inner, marker.secs);
+                if !(inner.left()..=inner.right()).contains(&x) {
+                    continue;
+                }
+                clipped.line_segment(
+                    [pos2(x, inner.top()), pos2(x, inner.bottom())],
+                    Stroke::new(1.0, theme.colors.amber),
+                );
+                clipped.text(
+                    pos2(x + 2.0, inner.top()),
+                    Align2::LEFT_TOP,
+                    &marker.label,
+                    font.clone(),
+                    theme.colors.amber,
+                );
+            }
+
+            if let Some(secs) = self.playhead {
+                let x = state.x_at(inner, secs);
+                if (inner.left()..=inner.right()).contains(&x) {
+                    clipped.line_segment(
+                        [pos2(x, inner.top()), pos2(x, inner.bottom())],
+                        Stroke::new(1.5, theme.colors.cyan),
+                    );
+                }
+            }
+
+            painter.rect_stroke(
+                rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+        }
+
+        WaveformViewResponse {
+            response,
+            seek,
+            selection_changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(n: usize) -> Vec<f32> {
+        (0..n)
+            .map(|i| (i as f32 * 0.37).sin() * (i as f32 / n as f32))
+            .collect()
+    }
+
+    fn brute(samples: &[f32], start: usize, end: usize) -> (f32, f32) {
+        samples[start..end]
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &s| (lo.min(s), hi.max(s)))
+    }
+
+    #[test]
+    fn pyramid_range_covers_exact_peaks() {
+        let samples = ramp(10_000);
+        let pyramid = PeakPyramid::new(&samples);
+        assert_eq!(pyramid.len(), 10_000);
+
+        for (start, end) in [(0, 10_000), (0, 32), (100, 5_000), (9_000, 9_999)] {
+            let (lo, hi) = pyramid.range(start, end);
+            let (exact_lo, exact_hi) = brute(&samples, start, end);
+            // Block-aligned reads may see a little beyond the range.
+            assert!(lo <= exact_lo && hi >= exact_hi, "{start}..{end}");
+        }
+
+        // Block-aligned ranges are exact.
+        assert_eq!(pyramid.range(0, 10_000), brute(&samples, 0, 10_000));
+        assert_eq!(pyramid.range(64, 128), brute(&samples, 64, 128));
+        assert_eq!(pyramid.range(500, 500), (0.0, 0.0));
+    }
+
+    #[test]
+    fn pyramid_levels_halve() {
+        let pyramid = PeakPyramid::new(&ramp(PeakPyramid::BASE_BLOCK * 8));
+        let sizes: Vec<usize> = pyramid.levels.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![8, 4, 2, 1]);
+        assert!(PeakPyramid::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn zoom_keeps_anchor_and_clamps() {
+        let mut state = WaveformViewState::new(PeakPyramid::new(&ramp(48_000)), 48_000.0);
+        assert_eq!(state.view(), (0.0, 1.0));
+
+        state.zoom(4.0, 0.5);
+        let (start, span) = state.view();
+        assert!((span - 0.25).abs() < 1e-6);
+        // 0.5 s was at the centre and stays there.
+        assert!((start + span * 0.5 - 0.5).abs() < 1e-6);
+
+        // Cannot zoom out past the whole signal or in past the minimum span.
+        state.zoom(0.001, 0.5);
+        assert_eq!(state.view(), (0.0, 1.0));
+        state.zoom(1e6, 0.5);
+        assert!((state.view().1 - MIN_SPAN_SAMPLES / 48_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pan_and_reveal_stay_in_bounds() {
+        let mut state = WaveformViewState::new(PeakPyramid::new(&ramp(48_000)), 48_000.0);
+        state.zoom(10.0, 0.0);
+        state.pan(-5.0);
+        assert_eq!(state.view().0, 0.0);
+        state.pan(5.0);
+        assert!((state.view().0 - 0.9).abs() < 1e-5);
+
+        state.reveal(0.25);
+        assert!((state.view().0 - 0.25).abs() < 1e-6);
+        // Already visible: no jump.
+        state.reveal(0.3);
+        assert!((state.view().0 - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn selection_and_markers() {
+        let mut state = WaveformViewState::new(PeakPyramid::new(&ramp(48_000)), 48_000.0);
+        state.set_selection(0.8, 0.2);
+        assert_eq!(state.selection(), Some((0.2, 0.8)));
+        state.set_selection(-1.0, 9.0);
+        assert_eq!(state.selection(), Some((0.0, 1.0)));
+        state.clear_selection();
+        assert_eq!(state.selection(), None);
+
+        state.add_marker(0.5, "Drop");
+        assert_eq!(state.markers()[0].label, "Drop");
+        state.clear_markers();
+        assert!(state.markers().is_empty());
+    }
+}
@@ -0,0 +1,589 @@
+//! Output analyzer: loudness, spectrum, oscilloscope, and stereo image of
+//! the post-master signal, or a mono compatibility check.
+//!
+//! The audio processor pushes its stereo output into an [`AnalyzerTap`].
+//! [`Analyzer`] pulls the newest samples each frame it is visible, feeds
+//! them to a BS.1770 [`LufsMeter`] and the goniometer, and sends the mono
+//! sum to the scope and a Hann-windowed FFT for the spectrum. Nothing is
+//! computed while the analyzer tile is closed, so integrated loudness only
+//! covers the time the tile was open; clicking the meter restarts the
+//! measurement.
+//!
+//! The **Tilt** overlay draws the octave-band deviation of the long-term
+//! average spectrum from a pink-noise reference (a [`TiltAnalyzer`]) over the
+//! spectrum, for checking tonal balance.
+//!
+//! The **Mono** view swaps the spectrum and scope for a [`MonoCheck`]: the
+//! mono-sum loss per third-octave band, the correlation over the last few
+//! seconds, and, on request, a probe of each effect in the chain.
+
+use crate::audio_bridge::AnalyzerTap;
+use egui::{Sense, Stroke, StrokeKind, Ui, pos2, vec2};
+use sonido_analysis::fft::{Fft, Window};
+use sonido_analysis::loudness::LufsMeter;
+use sonido_analysis::monocheck::{MONO_LOSS_THRESHOLD_DB, MonoCheck, MonoReport};
+use sonido_analysis::tilt::{DEVIATION_THRESHOLD_DB, TiltAnalyzer, TiltReport};
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::widgets::{
+    CorrelationMeter, GoniometerState, GoniometerWidget, LoudnessMeter, SpectrumState,
+    SpectrumWidget, WaveformState, WaveformWidget, frequency_x,
+};
+
+/// FFT length (~85 ms at 48 kHz; 11.7 Hz bins).
+const FFT_SIZE: usize = 4096;
+
+/// Spectrum display columns.
+const SPECTRUM_COLUMNS: usize = 128;
+
+/// Scope window in milliseconds.
+const SCOPE_WINDOW_MS: f32 = 50.0;
+
+/// Fraction of the tile height given to the spectrum.
+const SPECTRUM_SHARE: f32 = 0.65;
+
+/// Loudness target marked on the meter (streaming reference level).
+const LOUDNESS_TARGET_LUFS: f32 = -14.0;
+
+/// Sample pairs kept for the goniometer trace.
+const GONIOMETER_POINTS: usize = 2048;
+
+/// Correlation meter bar height in pixels.
+const CORRELATION_HEIGHT: f32 = 10.0;
+
+/// Band loss at the top of the mono view's bar scale, in dB.
+const MONO_LOSS_SCALE_DB: f32 = 12.0;
+
+/// Correlation history shown in the mono view, in 100 ms segments.
+const MONO_HISTORY_POINTS: usize = 100;
+
+/// Mono view refresh interval in GUI frames (a report clones the timeline).
+const MONO_REPORT_FRAMES: u32 = 10;
+
+/// Tilt deviation at the top and bottom of the spectrum overlay, in dB.
+const TILT_SCALE_DB: f32 = 12.0;
+
+/// Which display fills the analyzer below the meters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalyzerView {
+    /// Spectrum, scope, and goniometer.
+    #[default]
+    Spectrum,
+    /// Mono compatibility check.
+    Mono,
+}
+
+/// Mono probe result for one effect in the chain.
+#[derive(Debug, Clone)]
+pub struct EffectMonoCheck {
+    /// Effect display name.
+    pub name: String,
+    /// What the effect alone does to mono noise.
+    pub report: MonoReport,
+}
+
+/// Spectrum, scope, and stereo image state for the analyzer tile.
+pub struct Analyzer {
+    fft: Fft,
+    window: Vec<f32>,
+    left: Vec<f32>,
+    right: Vec<f32>,
+    block: Vec<f32>,
+    magnitudes: Vec<f32>,
+    spectrum: SpectrumState,
+    scope: WaveformState,
+    imager: GoniometerState,
+    loudness: LufsMeter,
+    view: AnalyzerView,
+    /// Long-term spectrum for the tilt overlay, fed only while it is shown.
+    tilt: TiltAnalyzer,
+    tilt_overlay: bool,
+    tilt_report: Option<TiltReport>,
+    mono: MonoCheck,
+    /// Latest mono report, refreshed every [`MONO_REPORT_FRAMES`].
+    mono_report: Option<MonoReport>,
+    mono_frames: u32,
+    /// Per-effect probes from the last "Check effects".
+    effect_checks: Vec<EffectMonoCheck>,
+    /// Set when "Check effects" is clicked; taken by the app.
+    effect_check_requested: bool,
+    /// Tap write count at the last update.
+    read: usize,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Analyzer {
+    /// Create an idle analyzer.
+    pub fn new() -> Self {
+        Self {
+            fft: Fft::new(FFT_SIZE),
+            window: Window::Hann.coefficients(FFT_SIZE),
+            left: vec![0.0; AnalyzerTap::CAPACITY],
+            right: vec![0.0; AnalyzerTap::CAPACITY],
+            block: vec![0.0; AnalyzerTap::CAPACITY],
+            magnitudes: vec![0.0; FFT_SIZE / 2],
+            spectrum: SpectrumState::new(SPECTRUM_COLUMNS),
+            scope: WaveformState::new(AnalyzerTap::CAPACITY),
+            imager: GoniometerState::new(GONIOMETER_POINTS),
+            loudness: LufsMeter::new(48000.0),
+            view: AnalyzerView::default(),
+            tilt: TiltAnalyzer::new(48000.0),
+            tilt_overlay: false,
+            tilt_report: None,
+            mono: MonoCheck::new(48000.0),
+            mono_report: None,
+            mono_frames: 0,
+            effect_checks: Vec::new(),
+            effect_check_requested: false,
+            read: 0,
+        }
+    }
+
+    /// Pull new samples from `tap` and refresh the loudness, spectrum, scope,
+    /// and stereo image.
+    ///
+    /// Does nothing if no audio arrived since the last call, so the display
+    /// holds its last state while the stream is stopped.
+    pub fn update(&mut self, tap: &AnalyzerTap, sample_rate: f32) {
+        let written = tap.written();
+        let fresh = written.wrapping_sub(self.read).min(AnalyzerTap::CAPACITY);
+        self.read = written;
+        if fresh == 0 {
+            return;
+        }
+
+        if self.loudness.sample_rate() != sample_rate {
+            self.loudness = LufsMeter::new(sample_rate);
+        }
+
+        let len = fresh.max(FFT_SIZE);
+        let (left, right) = (&mut self.left[..len], &mut self.right[..len]);
+        tap.copy_latest(left, right);
+        let (new_l, new_r) = (&left[len - fresh..], &right[len - fresh..]);
+        self.loudness.push_samples(new_l, new_r);
+        self.imager.push(new_l, new_r, sample_rate);
+        if self.tilt_overlay && self.view == AnalyzerView::Spectrum {
+            if self.tilt.sample_rate() != sample_rate {
+                self.tilt = TiltAnalyzer::new(sample_rate);
+            }
+            self.tilt.push_samples(new_l, new_r);
+            if self.tilt.has_data() {
+                self.tilt_report = Some(self.tilt.report(0.0));
+            }
+        }
+        if self.view == AnalyzerView::Mono {
+            if self.mono.sample_rate() != sample_rate {
+                self.mono = MonoCheck::new(sample_rate);
+            }
+            self.mono.push_samples(new_l, new_r);
+            self.mono_frames += 1;
+            if self.mono_report.is_none() || self.mono_frames >= MONO_REPORT_FRAMES {
+                self.mono_report = Some(self.mono.report());
+                self.mono_frames = 0;
+            }
+        }
+
+        let block = &mut self.block[..len];
+        for ((mono, &l), &r) in block.iter_mut().zip(left.iter()).zip(right.iter()) {
+            *mono = (l + r) * 0.5;
+        }
+        self.scope.push(&block[len - fresh..]);
+
+        let frame = &mut block[len - FFT_SIZE..];
+        for (sample, w) in frame.iter_mut().zip(&self.window) {
+            *sample *= w;
+        }
+        let spectrum = self.fft.forward(frame);
+        // Hann coherent gain is 0.5: a full-scale sine reads 1.0 (0 dB).
+        let scale = 4.0 / FFT_SIZE as f32;
+        for (mag, bin) in self.magnitudes.iter_mut().zip(&spectrum) {
+            *mag = bin.norm() * scale;
+        }
+        self.spectrum.update(&self.magnitudes, sample_rate);
+    }
+
+    /// Draw the loudness and correlation meters, then the spectrum above the
+    /// scope and goniometer, filling the available space. Clicking the
+    /// loudness meter resets it.
+    pub fn ui(&mut self, ui: &mut Ui, sample_rate: f32) {
+        let width = ui.available_width();
+        let meter = LoudnessMeter::new(
+            self.loudness.momentary(),
+            self.loudness.short_term(),
+            self.loudness.integrated(),
+            self.loudness.true_peak_dbtp(),
+        )
+        .target(LOUDNESS_TARGET_LUFS)
+        .size(width, 14.0);
+        if ui
+            .add(meter)
+            .on_hover_text("Click to reset loudness")
+            .clicked()
+        {
+            self.loudness.reset();
+        }
+        ui.add(CorrelationMeter::new(self.imager.correlation()).size(width, CORRELATION_HEIGHT));
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.view, AnalyzerView::Spectrum, "Spectrum");
+            ui.selectable_value(&mut self.view, AnalyzerView::Mono, "Mono")
+                .on_hover_text("Mono compatibility: what summing to mono cancels");
+            if self.view == AnalyzerView::Spectrum {
+                ui.separator();
+                let toggled = ui
+                    .checkbox(&mut self.tilt_overlay, "Tilt")
+                    .on_hover_text(
+                        "Long-term spectrum per octave against pink noise; \
+                         click again to restart the average",
+                    )
+                    .changed();
+                if toggled {
+                    self.tilt.reset();
+                    self.tilt_report = None;
+                }
+            }
+        });
+        if self.view == AnalyzerView::Mono {
+            self.mono_ui(ui);
+            return;
+        }
+
+        let spacing = ui.spacing().item_spacing;
+        let height = ui.available_height() - spacing.y;
+        let spectrum_height = height * SPECTRUM_SHARE;
+        let spectrum = ui.add(SpectrumWidget::new(&self.spectrum).size(width, spectrum_height));
+        if self.tilt_overlay {
+            self.draw_tilt_overlay(ui, spectrum.rect);
+        }
+
+        // Goniometer is square, beside the scope.
+        let bottom = height - spectrum_height;
+        let gonio = bottom.min(width * 0.5);
+        ui.horizontal(|ui| {
+            ui.add(
+                WaveformWidget::new(&self.scope)
+                    .window_ms(SCOPE_WINDOW_MS, sample_rate)
+                    .size(width - gonio - spacing.x, bottom),
+            );
+            ui.add(GoniometerWidget::new(&self.imager).size(gonio));
+        });
+    }
+
+    /// Draw each octave's deviation from pink over the spectrum: a level line
+    /// per band around the centre (the reference), amber past the threshold.
+    fn draw_tilt_overlay(&self, ui: &Ui, rect: egui::Rect) {
+        let theme = SonidoTheme::get(ui.ctx());
+        let painter = ui.painter_at(rect);
+        let centre = rect.center().y;
+        let db_to_y = |db: f32| {
+            centre - db.clamp(-TILT_SCALE_DB, TILT_SCALE_DB) / TILT_SCALE_DB * rect.height() * 0.45
+        };
+        painter.hline(rect.x_range(), centre, Stroke::new(1.0, theme.colors.dim));
+
+        let Some(report) = &self.tilt_report else {
+            return;
+        };
+        for band in &report.bands {
+            let color = if band.deviation_db.abs() > DEVIATION_THRESHOLD_DB {
+                theme.colors.amber
+            } else {
+                theme.colors.cyan
+            };
+            let y = db_to_y(band.deviation_db);
+            let (x0, x1) = (
+                frequency_x(rect, band.low_hz),
+                frequency_x(rect, band.high_hz),
+            );
+            painter.line_segment([pos2(x0, y), pos2(x1, y)], Stroke::new(2.0, color));
+            painter.line_segment(
+                [pos2((x0 + x1) * 0.5, centre), pos2((x0 + x1) * 0.5, y)],
+                Stroke::new(1.0, color.gamma_multiply(0.5)),
+            );
+        }
+        painter.text(
+            rect.left_top() + vec2(6.0, 4.0),
+            egui::Align2::LEFT_TOP,
+            format!("{:+.1} dB/oct vs pink", report.slope_db_per_octave),
+            egui::FontId::monospace(9.0),
+            theme.colors.text_secondary,
+        );
+    }
+
+    /// Whether "Check effects" was clicked since the last call.
+    pub fn take_effect_check_request(&mut self) -> bool {
+        std::mem::take(&mut self.effect_check_requested)
+    }
+
+    /// Show per-effect mono probes in the mono view.
+    pub fn set_effect_checks(&mut self, checks: Vec<EffectMonoCheck>) {
+        self.effect_checks = checks;
+    }
+
+    /// Draw the mono view: summary, band losses, correlation history, and
+    /// effect probes.
+    fn mono_ui(&mut self, ui: &mut Ui) {
+        let theme = SonidoTheme::get(ui.ctx());
+        let width = ui.available_width();
+
+        ui.horizontal(|ui| {
+            match &self.mono_report {
+                Some(report) => {
+                    let problems = report.problem_bands(MONO_LOSS_THRESHOLD_DB).count();
+                    let (text, color) = if problems == 0 {
+                        ("Mono compatible".to_string(), theme.colors.green)
+                    } else {
+                        (
+                            format!("{problems} band(s) cancel in mono"),
+                            theme.colors.amber,
+                        )
+                    };
+                    ui.colored_label(color, text);
+                    ui.label(format!(
+                        "corr {:+.2}, loss {:.1} dB",
+                        report.correlation, report.loss_db
+                    ));
+                }
+                None => {
+                    ui.label("Waiting for audio...");
+                }
+            }
+            if ui.small_button("Reset").clicked() {
+                self.mono.reset();
+                self.mono_report = None;
+            }
+        });
+
+        // Band losses: bar height is dB lost, red past the threshold.
+        let bars_height = (ui.available_height() * 0.45).max(40.0);
+        let (rect, _) = ui.allocate_exact_size(vec2(width, bars_height), Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, theme.colors.void);
+        let threshold_y =
+            rect.bottom() - rect.height() * MONO_LOSS_THRESHOLD_DB / MONO_LOSS_SCALE_DB;
+        painter.hline(
+            rect.x_range(),
+            threshold_y,
+            Stroke::new(1.0, theme.colors.dim),
+        );
+        if let Some(report) = &self.mono_report
+            && !report.bands.is_empty()
+        {
+            let bar = rect.width() / report.bands.len() as f32;
+            for (i, band) in report.bands.iter().enumerate() {
+                let loss = band.loss_db.clamp(0.0, MONO_LOSS_SCALE_DB);
+                let top = rect.bottom() - rect.height() * loss / MONO_LOSS_SCALE_DB;
+                let x = rect.left() + i as f32 * bar;
+                let color = if band.loss_db > MONO_LOSS_THRESHOLD_DB {
+                    theme.colors.red
+                } else {
+                    theme.colors.cyan
+                };
+                painter.rect_filled(
+                    egui::Rect::from_min_max(
+                        pos2(x + 1.0, top),
+                        pos2(x + bar - 1.0, rect.bottom()),
+                    ),
+                    0.0,
+                    color,
+                );
+            }
+        }
+        painter.rect_stroke(
+            rect,
+            2.0,
+            Stroke::new(1.0, theme.colors.dim),
+            StrokeKind::Inside,
+        );
+
+        // Correlation history: +1 at the top, -1 at the bottom.
+        let history_height = (ui.available_height() * 0.35).max(30.0);
+        let (rect, _) = ui.allocate_exact_size(vec2(width, history_height), Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, theme.colors.void);
+        painter.hline(
+            rect.x_range(),
+            rect.center().y,
+            Stroke::new(1.0, theme.colors.dim),
+        );
+        if let Some(report) = &self.mono_report {
+            let recent =
+                &report.timeline[report.timeline.len().saturating_sub(MONO_HISTORY_POINTS)..];
+            let step = rect.width() / (MONO_HISTORY_POINTS - 1) as f32;
+            let points: Vec<_> = recent
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    pos2(
+                        rect.left() + i as f32 * step,
+                        rect.center().y - p.correlation * rect.height() * 0.5,
+                    )
+                })
+                .collect();
+            painter.add(egui::Shape::line(
+                points,
+                Stroke::new(1.5, theme.colors.green),
+            ));
+        }
+        painter.rect_stroke(
+            rect,
+            2.0,
+            Stroke::new(1.0, theme.colors.dim),
+            StrokeKind::Inside,
+        );
+
+        ui.horizontal(|ui| {
+            if ui
+                .button("Check effects")
+                .on_hover_text("Run each effect in the chain alone on mono noise")
+                .clicked()
+            {
+                self.effect_check_requested = true;
+            }
+        });
+        for check in &self.effect_checks {
+            let worst = check
+                .report
+                .worst_band()
+                .map(|b| format!("worst -{:.1} dB at {:.0} Hz", b.loss_db, b.centre_hz))
+                .unwrap_or_default();
+            if check.report.is_problematic() {
+                ui.colored_label(
+                    theme.colors.red,
+                    format!("{}: cancels in mono ({worst})", check.name),
+                );
+            } else {
+                ui.label(format!("{}: ok ({worst})", check.name));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_scale_sine_peaks_near_zero_db() {
+        let tap = AnalyzerTap::new();
+        let sample_rate = 48000.0;
+        // Bin-centred frequency so the peak lands on one bin.
+        let freq = 64.0 * sample_rate / FFT_SIZE as f32;
+        let block: Vec<f32> = (0..FFT_SIZE)
+            .map(|i| (std::f32::consts::TAU * freq * i as f32 / sample_rate).sin())
+            .collect();
+        tap.push(&block, &block);
+
+        let mut analyzer = Analyzer::new();
+        analyzer.update(&tap, sample_rate);
+        let peak = analyzer.magnitudes.iter().copied().fold(0.0, f32::max);
+        assert!((peak - 1.0).abs() < 0.01, "peak = {peak}");
+        assert_eq!(analyzer.scope.len(), FFT_SIZE);
+
+        // No new audio: nothing is re-read.
+        analyzer.update(&tap, sample_rate);
+        assert_eq!(analyzer.scope.len(), FFT_SIZE);
+    }
+
+    #[test]
+    fn loudness_follows_tap() {
+        let tap = AnalyzerTap::new();
+        let sample_rate = 48000.0;
+        let mut analyzer = Analyzer::new();
+        assert_eq!(analyzer.loudness.momentary(), f32::NEG_INFINITY);
+
+        // 1 s of a 1 kHz sine, pulled in tap-sized chunks like GUI frames.
+        let block: Vec<f32> = (0..4800)
+            .map(|i| 0.5 * (std::f32::consts::TAU * 1000.0 * i as f32 / sample_rate).sin())
+            .collect();
+        for _ in 0..10 {
+            tap.push(&block, &block);
+            analyzer.update(&tap, sample_rate);
+        }
+        let momentary = analyzer.loudness.momentary();
+        assert!((momentary + 6.0).abs() < 1.0, "M = {momentary}");
+
+        analyzer.loudness.reset();
+        assert_eq!(analyzer.loudness.integrated(), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn tilt_overlay_follows_tap_while_shown() {
+        let tap = AnalyzerTap::new();
+        let sample_rate = 48000.0;
+        let mut seed = 3_u32;
+        let noise: Vec<f32> = (0..4800)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect();
+
+        let mut analyzer = Analyzer::new();
+        tap.push(&noise, &noise);
+        analyzer.update(&tap, sample_rate);
+        assert!(analyzer.tilt_report.is_none());
+
+        analyzer.tilt_overlay = true;
+        for _ in 0..10 {
+            tap.push(&noise, &noise);
+            analyzer.update(&tap, sample_rate);
+        }
+        // White noise: about +3 dB/octave against pink
+        let slope = analyzer.tilt_report.as_ref().unwrap().slope_db_per_octave;
+        assert!((slope - 3.0).abs() < 1.0, "slope = {slope}");
+    }
+
+    #[test]
+    fn mono_view_reports_cancelling_audio() {
+        let tap = AnalyzerTap::new();
+        let sample_rate = 48000.0;
+        let left: Vec<f32> = (0..4800)
+            .map(|i| 0.5 * (std::f32::consts::TAU * 1000.0 * i as f32 / sample_rate).sin())
+            .collect();
+        let inverted: Vec<f32> = left.iter().map(|s| -s).collect();
+
+        let mut analyzer = Analyzer::new();
+        // Nothing is accumulated while the spectrum is showing.
+        tap.push(&left, &inverted);
+        analyzer.update(&tap, sample_rate);
+        assert!(analyzer.mono_report.is_none());
+
+        analyzer.view = AnalyzerView::Mono;
+        for _ in 0..MONO_REPORT_FRAMES {
+            tap.push(&left, &inverted);
+            analyzer.update(&tap, sample_rate);
+        }
+        let report = analyzer.mono_report.as_ref().unwrap();
+        assert!(report.correlation < -0.99);
+        assert!(report.problem_bands(MONO_LOSS_THRESHOLD_DB).count() > 0);
+    }
+
+    #[test]
+    fn correlation_follows_stereo_image() {
+        let tap = AnalyzerTap::new();
+        let sample_rate = 48000.0;
+        let left: Vec<f32> = (0..4800)
+            .map(|i| 0.5 * (std::f32::consts::TAU * 1000.0 * i as f32 / sample_rate).sin())
+            .collect();
+        let inverted: Vec<f32> = left.iter().map(|s| -s).collect();
+
+        let mut analyzer = Analyzer::new();
+        for _ in 0..5 {
+            tap.push(&left, &left);
+            analyzer.update(&tap, sample_rate);
+        }
+        assert!(analyzer.imager.correlation() > 0.99);
+
+        for _ in 0..30 {
+            tap.push(&left, &inverted);
+            analyzer.update(&tap, sample_rate);
+        }
+        assert!(analyzer.imager.correlation() < -0.99);
+        // The mono sum cancels, so the scope goes flat.
+        assert!(analyzer.scope.recent(4800).all(|s| s.abs() < 1e-6));
+    }
+}
@@ -1,39 +1,93 @@
 //! Main application state and UI layout.
 //!
+//! Multi-effect mode lays its panels out as dockable tiles (see
+//! [`layout`](crate::layout)); single-effect mode keeps a fixed
+//! input | effect | output row.
+//!
 //! Audio-thread processing (the `AudioProcessor` and stream construction) lives
 //! in the sibling `audio_processor` module to keep GUI and real-time concerns
 //! cleanly separated.
 
+use crate::analyzer::{Analyzer, EffectMonoCheck};
+use crate::appearance::{Appearance, AppearanceEditor};
 use crate::atomic_param_bridge::AtomicParamBridge;
 use crate::audio_bridge::{AudioBridge, MeteringData};
 use crate::audio_processor::build_audio_streams;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::autosave::{self, Autosave, RecoveryChoice, RecoveryPrompt};
+use crate::chain_manager::GraphCommand;
+use crate::chain_view::{ChainEdit, ChainView, Pedal, PendingValues};
+use crate::effect_browser::{BrowserInsert, EffectBrowser};
+use crate::expression::{ExpressionEditor, ExpressionMap};
 use crate::file_player::FilePlayer;
 use crate::graph_view::{GraphView, SonidoNode};
+use crate::input_calibration::{InputCalibrationWizard, InputCalibrations};
+use crate::knob_settings::{self, KnobSettingsEditor};
+use crate::layout::{self, Tile};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::log_console::{LogBuffer, LogConsole};
+use crate::macros::{MacroBank, MacroEditor};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::midi_input::{MidiControlInput, MidiMessage};
 use crate::morph_state::MorphState;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::preset_manager::EffectPresetLibrary;
+use crate::preset_manager::{self, PresetManager};
+use crate::program_map::{ProgramMap, ProgramMapEditor};
+use crate::scenes::SceneBank;
+#[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+use crate::script_console::{self, ConsoleHost, ScriptConsole};
+use crate::shortcuts::{Keybindings, ShortcutAction, ShortcutsEditor};
 use crate::theme::Theme;
 use crate::widgets::{Knob, LevelMeter};
 use egui::{
-    Align, CentralPanel, Context, FontId, Frame, Layout, Margin, Rect, Stroke, TopBottomPanel,
-    UiBuilder, pos2, vec2,
+    Align, CentralPanel, Context, FontId, Frame, Key, KeyboardShortcut, Layout, Margin, Modifiers,
+    Rect, Stroke, TopBottomPanel, UiBuilder, pos2, vec2,
 };
+use egui_dock::{DockArea, DockState, TabViewer};
+use egui_snarl::NodeId;
+use sonido_analysis::monocheck::probe_effect;
+use sonido_core::gain::InputRouting;
+use sonido_core::graph::{Rig, RigOutput, Transition};
+#[cfg(not(target_arch = "wasm32"))]
+use sonido_gui_core::clipboard;
 use sonido_gui_core::effects_ui;
 use sonido_gui_core::theme::SonidoTheme;
 use sonido_gui_core::widgets::glow;
 use sonido_gui_core::widgets::morph_bar;
-use sonido_gui_core::{ParamBridge, SlotIndex};
-use sonido_registry::EffectRegistry;
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use sonido_registry::{EffectCategory, EffectRegistry, EffectWithParams};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Duration;
 
+/// Shows or hides the Dev menu. Fixed rather than rebindable so it stays
+/// out of the shortcuts editor.
+const DEVELOPER_MENU_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::D);
+
+/// Undoes the last parameter gesture. Fixed like [`DEVELOPER_MENU_SHORTCUT`]
+/// so it matches every other editor.
+const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+
+/// Redoes the last undone parameter gesture.
+const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+
+/// How long Esc must be held to toggle safe mode, in seconds. Fixed like
+/// [`DEVELOPER_MENU_SHORTCUT`] so a stage panic never depends on bindings.
+const SAFE_MODE_HOLD_SECS: f64 = 0.6;
+
 /// Main application state.
 pub struct SonidoApp {
     // Audio
     audio_bridge: AudioBridge,
     /// Live cpal streams -- dropped to stop audio.
     _audio_streams: Vec<cpal::Stream>,
+    /// Name of the audio device the streams run on (keys input calibrations).
+    audio_device: String,
     /// Whether we've re-called play() after a user gesture (wasm autoplay policy).
     #[cfg(target_arch = "wasm32")]
     audio_resumed: bool,
@@ -48,6 +102,11 @@ pub struct SonidoApp {
     // UI
     theme: Theme,
     graph_view: GraphView,
+    /// Rig shown in the graph and pedal tiles.
+    active_rig: Rig,
+    /// The other rig's editor while two rigs run (`None` for a single rig).
+    /// Swapped with `graph_view` when switching rig tabs.
+    parked_rig: Option<GraphView>,
     morph_state: MorphState,
     file_player: FilePlayer,
 
@@ -75,11 +134,102 @@ pub struct SonidoApp {
     compile_error: Option<String>,
     /// Frames remaining for compile success flash.
     compile_success_frames: u32,
+    /// Whether the hidden Dev menu is shown (toggled with [`DEVELOPER_MENU_SHORTCUT`]).
+    developer_menu: bool,
+    /// Input time at which Esc went down, while it may still become a
+    /// safe-mode long-press.
+    esc_down_since: Option<f64>,
 
     /// Latched clip indicator for input meter (click to reset).
     input_clip_latched: bool,
     /// Latched clip indicator for output meter (click to reset).
     output_clip_latched: bool,
+
+    /// Keyboard shortcut map (persisted in the config directory).
+    keybindings: Keybindings,
+    /// Shortcuts editor dialog.
+    shortcuts_editor: ShortcutsEditor,
+    /// Knob settings dialog (edits `theme.knob`).
+    knob_settings_editor: KnobSettingsEditor,
+    /// Color scheme and accents (persisted in the config directory).
+    appearance: Appearance,
+    /// Appearance dialog.
+    appearance_editor: AppearanceEditor,
+    /// Accepted input calibrations per device (persisted in the config directory).
+    input_calibrations: InputCalibrations,
+    /// Input calibration dialog.
+    input_calibration_wizard: InputCalibrationWizard,
+    /// Preset list for the preset tile and number-key shortcuts.
+    preset_manager: PresetManager,
+    /// Single-effect presets for the effect panel's Presets menu.
+    #[cfg(not(target_arch = "wasm32"))]
+    effect_presets: EffectPresetLibrary,
+    /// Name typed into the effect panel's Presets menu.
+    #[cfg(not(target_arch = "wasm32"))]
+    effect_preset_name: String,
+    /// Last single-effect preset save/load error.
+    #[cfg(not(target_arch = "wasm32"))]
+    effect_preset_error: Option<String>,
+    /// Name typed into the presets tile's save row.
+    #[cfg(not(target_arch = "wasm32"))]
+    preset_name: String,
+    /// Last chain preset save error.
+    #[cfg(not(target_arch = "wasm32"))]
+    preset_error: Option<String>,
+    /// Last failed settings paste and when it happened (seconds, egui time).
+    #[cfg(not(target_arch = "wasm32"))]
+    paste_error: Option<(String, f64)>,
+    /// Dockable tile layout (multi-effect mode; persisted in the config directory).
+    dock: DockState<Tile>,
+    /// Spectrum and scope for the analyzer tile.
+    analyzer: Analyzer,
+    /// Pedal strip for the pedals tile.
+    chain_view: ChainView,
+    /// "Add Effect" browser window.
+    effect_browser: EffectBrowser,
+    /// Parameter values waiting for the audio thread to rebuild the bridge
+    /// after a pedal-strip or browser edit.
+    pending_values: Option<PendingValues>,
+    /// Periodic recovery-file writer (multi-effect mode only).
+    #[cfg(not(target_arch = "wasm32"))]
+    autosave: Option<Autosave>,
+    /// Session found in the recovery file at startup, awaiting the user's
+    /// answer. Autosave holds off until it is answered.
+    #[cfg(not(target_arch = "wasm32"))]
+    recovery: Option<RecoveryPrompt>,
+    /// Scenes of the current session.
+    scenes: SceneBank,
+    /// Expression pedal (MIDI CC) mappings of the current session.
+    expression: ExpressionMap,
+    /// Expression mapping dialog.
+    expression_editor: ExpressionEditor,
+    /// Macro knobs, loaded with presets and sessions.
+    macros: MacroBank,
+    /// Macro assignment dialog.
+    macro_editor: MacroEditor,
+    /// MIDI input for presets, scenes, and expression mappings.
+    #[cfg(not(target_arch = "wasm32"))]
+    midi_input: MidiControlInput,
+    /// Last MIDI port connection error.
+    #[cfg(not(target_arch = "wasm32"))]
+    midi_error: Option<String>,
+    /// Program change → preset table and spillover switch (persisted in the
+    /// config directory).
+    program_map: ProgramMap,
+    /// Program map dialog.
+    program_map_editor: ProgramMapEditor,
+
+    /// Log console window; `None` unless the binary installed a log layer
+    /// (see [`with_log_buffer`](Self::with_log_buffer)).
+    #[cfg(not(target_arch = "wasm32"))]
+    log_console: Option<LogConsole>,
+
+    /// Script console window.
+    #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+    script_console: ScriptConsole,
+    /// Script chain waiting for the audio thread to rebuild the bridge.
+    #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+    pending_script_chain: Option<Vec<sonido_script::ChainSlot>>,
 }
 
 impl SonidoApp {
@@ -136,6 +286,7 @@ impl SonidoApp {
         let mut app = Self {
             audio_bridge,
             _audio_streams: Vec::new(),
+            audio_device: String::new(),
             #[cfg(target_arch = "wasm32")]
             audio_resumed: false,
             metering: MeteringData::default(),
@@ -143,6 +294,8 @@ impl SonidoApp {
             registry,
             theme: Theme::default(),
             graph_view: GraphView::new(),
+            active_rig: Rig::A,
+            parked_rig: None,
             morph_state: MorphState::new(),
             file_player: FilePlayer::new(transport_tx),
             cached_panel: None,
@@ -154,11 +307,85 @@ impl SonidoApp {
             single_effect,
             compile_error: None,
             compile_success_frames: 0,
+            developer_menu: false,
+            esc_down_since: None,
             input_clip_latched: false,
             output_clip_latched: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            keybindings: Keybindings::load_or_default(&Keybindings::default_path()),
+            #[cfg(target_arch = "wasm32")]
+            keybindings: Keybindings::default(),
+            shortcuts_editor: ShortcutsEditor::default(),
+            knob_settings_editor: KnobSettingsEditor::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            appearance: Appearance::load_or_default(&Appearance::default_path()),
+            #[cfg(target_arch = "wasm32")]
+            appearance: Appearance::default(),
+            appearance_editor: AppearanceEditor::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            input_calibrations: InputCalibrations::load_or_default(
+                &InputCalibrations::default_path(),
+            ),
+            #[cfg(target_arch = "wasm32")]
+            input_calibrations: InputCalibrations::default(),
+            input_calibration_wizard: InputCalibrationWizard::default(),
+            preset_manager: PresetManager::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            effect_presets: EffectPresetLibrary::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            effect_preset_name: String::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            effect_preset_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            preset_name: String::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            preset_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            paste_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            dock: layout::load_or_default(&layout::default_path()),
+            #[cfg(target_arch = "wasm32")]
+            dock: layout::default_layout(),
+            analyzer: Analyzer::new(),
+            chain_view: ChainView::default(),
+            effect_browser: EffectBrowser::default(),
+            pending_values: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            autosave: (!single_effect).then(|| Autosave::new(autosave::default_path())),
+            #[cfg(not(target_arch = "wasm32"))]
+            recovery: if single_effect {
+                None
+            } else {
+                autosave::load_recovery(&autosave::default_path()).map(RecoveryPrompt::new)
+            },
+            scenes: SceneBank::default(),
+            expression: ExpressionMap::default(),
+            expression_editor: ExpressionEditor::default(),
+            macros: MacroBank::default(),
+            macro_editor: MacroEditor::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            midi_input: MidiControlInput::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            midi_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            program_map: ProgramMap::load_or_default(&ProgramMap::default_path()),
+            #[cfg(target_arch = "wasm32")]
+            program_map: ProgramMap::default(),
+            program_map_editor: ProgramMapEditor::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            log_console: None,
+            #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+            script_console: ScriptConsole::default(),
+            #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+            pending_script_chain: None,
         };
 
-        // Apply theme
+        // Apply theme with the user's appearance and knob settings
+        app.appearance.apply_to(&mut app.theme);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.theme.knob = knob_settings::load_or_default(&knob_settings::default_path());
+        }
         app.theme.apply(&cc.egui_ctx);
 
         // Start audio first — detects actual device sample rate
@@ -166,6 +393,16 @@ impl SonidoApp {
             app.audio_error = Some(e);
         }
 
+        // Restore this device's input calibration (a loaded session overrides it)
+        if let Some(calibration) = app.input_calibrations.get(&app.audio_device) {
+            app.audio_bridge.input_gain().set(calibration.gain_db);
+            tracing::info!(
+                device = %app.audio_device,
+                gain_db = calibration.gain_db,
+                "input calibration restored"
+            );
+        }
+
         tracing::info!(
             sample_rate = app.sample_rate,
             buffer_size = app.buffer_size,
@@ -182,17 +419,29 @@ impl SonidoApp {
         app
     }
 
+    /// Show `buffer` in the log console. Call with the buffer behind the
+    /// [`LogLayer`](crate::log_console::LogLayer) installed in the tracing
+    /// subscriber.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_log_buffer(mut self, buffer: LogBuffer) -> Self {
+        self.log_console = Some(LogConsole::new(buffer));
+        self
+    }
+
     /// Compile the current graph and send it to the audio thread.
     ///
-    /// On success, clears any previous compile error and arms the success flash.
-    /// On failure, stores the error string for display in the header.
+    /// With two rigs, both compile into one dual-rig engine, rig B's slots
+    /// following rig A's. On success, clears any previous compile error and
+    /// arms the success flash. On failure, stores the error string for
+    /// display in the header.
     fn compile_and_apply(&mut self) {
-        match self
-            .graph_view
-            .compile_to_engine(self.sample_rate, self.buffer_size, &self.registry)
-        {
+        match self.compile_current() {
             Ok(cmd) => {
                 self.audio_bridge.send_command(cmd);
+                self.graph_view.mark_applied();
+                if let Some(parked) = &mut self.parked_rig {
+                    parked.mark_applied();
+                }
                 self.compile_error = None;
                 self.compile_success_frames = 90;
             }
@@ -203,6 +452,95 @@ impl SonidoApp {
         }
     }
 
+    /// Compile the current graph (both rigs, when two run) into a
+    /// [`GraphCommand::ReplaceTopology`].
+    fn compile_current(&mut self) -> Result<GraphCommand, crate::graph_view::CompileError> {
+        match self.parked_rig.as_mut() {
+            None => {
+                self.graph_view.slot_offset = 0;
+                self.graph_view.compile_to_engine(
+                    self.sample_rate,
+                    self.buffer_size,
+                    &self.registry,
+                )
+            }
+            Some(parked) => {
+                let (rig_a, rig_b) = match self.active_rig {
+                    Rig::A => (&mut self.graph_view, parked),
+                    Rig::B => (parked, &mut self.graph_view),
+                };
+                rig_a.slot_offset = 0;
+                rig_b.slot_offset = rig_a.effect_node_count();
+                GraphView::compile_rigs(
+                    rig_a,
+                    rig_b,
+                    self.sample_rate,
+                    self.buffer_size,
+                    &self.registry,
+                )
+            }
+        }
+    }
+
+    /// Editors of the running rigs in slot order (rig A first).
+    fn rig_views(&self) -> Vec<(Rig, &GraphView)> {
+        let active = (self.active_rig, &self.graph_view);
+        match &self.parked_rig {
+            None => vec![active],
+            Some(parked) => {
+                let parked = (self.active_rig.other(), parked);
+                match self.active_rig {
+                    Rig::A => vec![active, parked],
+                    Rig::B => vec![parked, active],
+                }
+            }
+        }
+    }
+
+    /// Swap the parked rig into the editor tiles (no-op with a single rig).
+    fn swap_rigs(&mut self) {
+        if let Some(parked) = &mut self.parked_rig {
+            std::mem::swap(&mut self.graph_view, parked);
+            self.active_rig = self.active_rig.other();
+        }
+    }
+
+    /// Show `rig` in the graph and pedal tiles. Opening rig B the first time
+    /// starts it as a straight Input → Output graph running beside rig A.
+    fn select_rig(&mut self, rig: Rig) {
+        if rig == self.active_rig {
+            return;
+        }
+        if self.parked_rig.is_some() {
+            self.swap_rigs();
+            return;
+        }
+        self.parked_rig = Some(GraphView::new());
+        self.swap_rigs();
+        self.recompile_keeping_values(&[]);
+    }
+
+    /// Drop rig B, leaving rig A alone in the editor tiles. Does not recompile.
+    fn collapse_to_rig_a(&mut self) {
+        if self.active_rig == Rig::B {
+            self.swap_rigs();
+        }
+        self.parked_rig = None;
+        self.audio_bridge.rig_morph().set(Rig::A.morph());
+    }
+
+    /// A/B footswitch: crossfade the output to the other rig. Ignored with a
+    /// single rig.
+    fn toggle_rig(&mut self) {
+        if self.parked_rig.is_none() {
+            return;
+        }
+        let morph = self.audio_bridge.rig_morph();
+        let next = Rig::from_morph(morph.get()).other();
+        morph.set(next.morph());
+        tracing::info!(rig = next.label(), "rig switched");
+    }
+
     /// Build cpal streams and start audio processing.
     ///
     /// Streams are stored in `_audio_streams` and stay alive until dropped.
@@ -213,11 +551,20 @@ impl SonidoApp {
         let registry = Arc::clone(&self.registry);
         let input_gain = self.audio_bridge.input_gain();
         let master_volume = self.audio_bridge.master_volume();
+        let input_routing = self.audio_bridge.input_routing();
+        let rig_morph = self.audio_bridge.rig_morph();
+        let rig_output = self.audio_bridge.rig_output();
         let running = self.audio_bridge.running();
         let metering_tx = self.audio_bridge.metering_sender();
+        let analyzer_tap = self.audio_bridge.analyzer_tap();
         let command_rx = self.audio_bridge.command_receiver();
         let transport_rx = self.audio_bridge.transport_receiver();
         let chain_bypass = self.audio_bridge.chain_bypass();
+        let safe_mode = self.audio_bridge.safe_mode();
+        let output_limiter = self.audio_bridge.output_limiter();
+        let limiter_ceiling = self.audio_bridge.limiter_ceiling();
+        let auto_trim = self.audio_bridge.auto_trim();
+        let trim_measurement = self.audio_bridge.trim_measurement();
 
         running.store(true, Ordering::SeqCst);
 
@@ -228,11 +575,20 @@ impl SonidoApp {
             &registry,
             input_gain,
             master_volume,
+            input_routing,
+            rig_morph,
+            rig_output,
             running,
             metering_tx,
+            analyzer_tap,
             command_rx,
             transport_rx,
             chain_bypass,
+            safe_mode,
+            output_limiter,
+            limiter_ceiling,
+            auto_trim,
+            trim_measurement,
             error_count,
             self.sample_rate,
             self.buffer_size,
@@ -242,6 +598,7 @@ impl SonidoApp {
         self.sample_rate = config.sample_rate;
         self.buffer_size = config.buffer_size;
         self._audio_streams = config.streams;
+        self.audio_device = config.device_name;
         Ok(())
     }
 
@@ -395,6 +752,48 @@ impl SonidoApp {
                     .store(!chain_bypassed, Ordering::SeqCst);
             }
 
+            // PANIC: fade out, clear all effect state, fade back in
+            if ui
+                .button(
+                    egui::RichText::new("PANIC")
+                        .font(FontId::monospace(11.0))
+                        .color(theme.colors.red)
+                        .strong(),
+                )
+                .on_hover_text("Reset all effect state (runaway feedback, self-oscillation)")
+                .clicked()
+            {
+                self.audio_bridge.send_command(GraphCommand::Panic);
+            }
+
+            // SAFE: latency-compensated dry path, feedback-capable effects muted
+            let safe = self.audio_bridge.safe_mode().load(Ordering::Relaxed);
+            let safe_btn = ui
+                .add(
+                    egui::Button::new(
+                        egui::RichText::new("SAFE")
+                            .font(FontId::monospace(11.0))
+                            .color(if safe {
+                                theme.colors.text_primary
+                            } else {
+                                theme.colors.red
+                            })
+                            .strong(),
+                    )
+                    .fill(if safe {
+                        theme.colors.red
+                    } else {
+                        ui.visuals().widgets.inactive.weak_bg_fill
+                    }),
+                )
+                .on_hover_text(
+                    "Safe mode: bypass the whole chain to the dry signal and mute delays \
+                     and reverbs. Also: hold Esc, or the safe-mode MIDI CC.",
+                );
+            if safe_btn.clicked() {
+                self.set_safe_mode(!safe);
+            }
+
             ui.separator();
 
             // Save / Load (placeholder — Task 12 fills in)
@@ -422,6 +821,112 @@ impl SonidoApp {
                 }
             }
 
+            if !self.single_effect {
+                self.render_view_menu(ui, &theme);
+                if self.developer_menu {
+                    self.render_developer_menu(ui, &theme);
+                }
+
+                if ui
+                    .button(
+                        egui::RichText::new("Add Effect")
+                            .font(FontId::monospace(12.0))
+                            .color(theme.colors.text_primary),
+                    )
+                    .on_hover_text("Browse effects and insert one into the chain")
+                    .clicked()
+                {
+                    self.effect_browser.open = !self.effect_browser.open;
+                }
+            }
+
+            if ui
+                .button(
+                    egui::RichText::new("Keys")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Keyboard shortcuts")
+                .clicked()
+            {
+                self.shortcuts_editor.open = !self.shortcuts_editor.open;
+            }
+
+            if ui
+                .button(
+                    egui::RichText::new("Knobs")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Knob drag mode, sensitivity, and scroll wheel")
+                .clicked()
+            {
+                self.knob_settings_editor.open = !self.knob_settings_editor.open;
+            }
+
+            if ui
+                .button(
+                    egui::RichText::new("Theme")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Color scheme and accent colors")
+                .clicked()
+            {
+                self.appearance_editor.open = !self.appearance_editor.open;
+            }
+
+            if ui
+                .button(
+                    egui::RichText::new("MIDI")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Program change → preset table and spillover")
+                .clicked()
+            {
+                self.program_map_editor.open = !self.program_map_editor.open;
+            }
+
+            if ui
+                .button(
+                    egui::RichText::new("Expr")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Expression pedal: MIDI CC → parameter mappings and curves")
+                .clicked()
+            {
+                self.expression_editor.open = !self.expression_editor.open;
+            }
+
+            #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+            if ui
+                .button(
+                    egui::RichText::new("Script")
+                        .font(FontId::monospace(12.0))
+                        .color(theme.colors.text_primary),
+                )
+                .on_hover_text("Script console")
+                .clicked()
+            {
+                self.script_console.open = !self.script_console.open;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(console) = &mut self.log_console
+                && ui
+                    .button(
+                        egui::RichText::new("Log")
+                            .font(FontId::monospace(12.0))
+                            .color(theme.colors.text_primary),
+                    )
+                    .on_hover_text("Log console: filter and copy log messages")
+                    .clicked()
+            {
+                console.open = !console.open;
+            }
+
             ui.separator();
 
             // FILE source toggle
@@ -484,6 +989,82 @@ impl SonidoApp {
         });
     }
 
+    /// Render the View menu: show or hide tiles, reset the layout.
+    fn render_view_menu(&mut self, ui: &mut egui::Ui, theme: &SonidoTheme) {
+        ui.menu_button(
+            egui::RichText::new("View")
+                .font(FontId::monospace(12.0))
+                .color(theme.colors.text_primary),
+            |ui| {
+                for tile in Tile::ALL {
+                    let mut open = layout::is_open(&self.dock, tile);
+                    if ui.checkbox(&mut open, tile.title()).changed() {
+                        layout::toggle(&mut self.dock, tile);
+                    }
+                }
+                ui.separator();
+                if ui.button("Reset layout").clicked() {
+                    self.dock = layout::default_layout();
+                    ui.close_menu();
+                }
+            },
+        );
+    }
+
+    /// Render the hidden Dev menu: graph debugging exports.
+    fn render_developer_menu(&mut self, ui: &mut egui::Ui, theme: &SonidoTheme) {
+        ui.menu_button(
+            egui::RichText::new("Dev")
+                .font(FontId::monospace(12.0))
+                .color(theme.colors.amber),
+            |ui| {
+                if ui
+                    .button("Copy graph as DOT")
+                    .on_hover_text("Topology, buffers, latencies, and compiled schedule (GraphViz)")
+                    .clicked()
+                {
+                    match self.graph_dot() {
+                        Ok(dot) => ui.ctx().copy_text(dot),
+                        Err(e) => self.compile_error = Some(e),
+                    }
+                    ui.close_menu();
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui.button("Save graph as DOT...").clicked() {
+                    self.save_graph_dot();
+                    ui.close_menu();
+                }
+            },
+        );
+    }
+
+    /// Render the editor graph as DOT at the current sample rate and buffer size.
+    fn graph_dot(&self) -> Result<String, String> {
+        self.graph_view
+            .to_dot(self.sample_rate, self.buffer_size, &self.registry)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Save the editor graph as a DOT file via file dialog.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_graph_dot(&mut self) {
+        let dot = match self.graph_dot() {
+            Ok(dot) => dot,
+            Err(e) => {
+                self.compile_error = Some(e);
+                return;
+            }
+        };
+        if let Some(path) = rfd::FileDialog::new()
+            .set_title("Save Graph DOT")
+            .add_filter("GraphViz", &["dot", "gv"])
+            .save_file()
+            && let Err(e) = std::fs::write(&path, dot)
+        {
+            tracing::error!(error = %e, "failed to save graph DOT");
+        }
+    }
+
     /// Render a unified I/O strip (INPUT or OUTPUT endpoint).
     ///
     /// `is_input` selects between input gain / output master controls and metering.
@@ -550,6 +1131,46 @@ impl SonidoApp {
                     {
                         input_gain.set(gain_val);
                     }
+
+                    // Which interface channels feed the chain
+                    let input_routing = self.audio_bridge.input_routing();
+                    let current = input_routing.get();
+                    egui::ComboBox::from_id_salt("input_routing")
+                        .width(56.0)
+                        .selected_text(
+                            egui::RichText::new(current.label()).font(FontId::monospace(9.0)),
+                        )
+                        .show_ui(ui, |ui| {
+                            for routing in InputRouting::ALL {
+                                if ui
+                                    .selectable_label(routing == current, routing.label())
+                                    .clicked()
+                                {
+                                    input_routing.set(routing);
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text(
+                            "Input channels: Left or Right copies one channel to both \
+                             sides (for instruments on one input), Mono sums both at -3 dB",
+                        );
+
+                    // Guided input calibration: measure, review, then apply.
+                    let response = ui
+                        .add_enabled(
+                            self.audio_bridge.is_running(),
+                            egui::Button::new(
+                                egui::RichText::new("Calibrate…").font(FontId::monospace(9.0)),
+                            ),
+                        )
+                        .on_hover_text(
+                            "Measure the input level and set the input gain \
+                             so peaks hit -12 dBFS",
+                        );
+                    if response.clicked() {
+                        self.input_calibration_wizard.open();
+                    }
                 } else {
                     let master_vol_param = self.audio_bridge.master_volume();
                     let mut master_val = master_vol_param.get();
@@ -564,6 +1185,50 @@ impl SonidoApp {
                     {
                         master_vol_param.set(master_val);
                     }
+
+                    // Output safety limiter: lit amber while reducing gain
+                    let limiter = self.audio_bridge.output_limiter();
+                    let enabled = limiter.load(Ordering::Relaxed);
+                    let limiting = enabled && self.metering.gain_reduction > 0.1;
+                    let lim_color = if limiting {
+                        theme.colors.amber
+                    } else if enabled {
+                        theme.colors.green
+                    } else {
+                        theme.colors.dim
+                    };
+                    let lim_resp = ui
+                        .button(
+                            egui::RichText::new("LIM")
+                                .font(FontId::monospace(8.0))
+                                .color(lim_color),
+                        )
+                        .on_hover_text(format!(
+                            "Output safety limiter ({}): brickwall at the ceiling after \
+                             every effect, whatever the preset. Reducing {:.1} dB.",
+                            if enabled { "on" } else { "off" },
+                            self.metering.gain_reduction,
+                        ));
+                    if lim_resp.clicked() {
+                        limiter.store(!enabled, Ordering::Relaxed);
+                    }
+
+                    let ceiling = self.audio_bridge.limiter_ceiling();
+                    let mut ceiling_db = ceiling.get();
+                    if ui
+                        .add_enabled(
+                            enabled,
+                            egui::DragValue::new(&mut ceiling_db)
+                                .range(ceiling.min()..=ceiling.max())
+                                .speed(0.1)
+                                .fixed_decimals(1)
+                                .suffix(" dB"),
+                        )
+                        .on_hover_text("Limiter ceiling (dBFS)")
+                        .changed()
+                    {
+                        ceiling.set(ceiling_db);
+                    }
                 }
             });
         });
@@ -585,18 +1250,6 @@ impl SonidoApp {
         });
     }
 
-    /// Estimate the needed effect panel height from the cached panel's param count.
-    fn estimate_panel_height(&self) -> f32 {
-        let param_count = self
-            .cached_panel
-            .as_ref()
-            .map(|(slot, _, _)| self.bridge.param_count(*slot))
-            .unwrap_or(6);
-        // Rough estimate: title row + ~40px per row of 4-5 knobs
-        let rows = param_count.div_ceil(5).max(1);
-        80.0 + rows as f32 * 60.0
-    }
-
     /// Render the effect panel for the selected slot.
     ///
     /// The panel widget is cached in `self.cached_panel` and only reconstructed
@@ -638,6 +1291,9 @@ impl SonidoApp {
                         .strong(),
                 );
 
+                #[cfg(not(target_arch = "wasm32"))]
+                self.render_clipboard_buttons(ui, slot, &theme);
+
                 // Inline morph bar
                 if !self.single_effect {
                     ui.add_space(8.0);
@@ -676,6 +1332,12 @@ impl SonidoApp {
                     if led_resp.clicked() {
                         self.bridge.set_bypassed(slot, !is_bypassed);
                     }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    self.render_effect_presets_menu(ui, slot, &theme);
+                    if !self.single_effect {
+                        self.render_lock_menu(ui, slot, &theme);
+                    }
                 });
             });
 
@@ -692,53 +1354,798 @@ impl SonidoApp {
         glow::scanlines(ui.painter(), panel_rect, &theme);
     }
 
-    /// Render the status bar.
-    fn render_status_bar(&mut self, ui: &mut egui::Ui) {
-        let theme = SonidoTheme::get(ui.ctx());
-
-        ui.horizontal(|ui| {
-            // Sample rate
-            ui.label(
-                egui::RichText::new(format!("{:.0}Hz", self.sample_rate))
-                    .font(FontId::monospace(11.0))
-                    .color(theme.colors.amber),
-            );
-            ui.separator();
-
-            // Latency
-            let latency_ms = self.buffer_size as f32 / self.sample_rate * 1000.0;
-            ui.label(
-                egui::RichText::new(format!("{latency_ms:.1}ms"))
-                    .font(FontId::monospace(11.0))
-                    .color(theme.colors.amber),
-            );
-            ui.separator();
-
-            // CPU meter — fixed-width allocation to prevent sparkline jitter
-            let cpu_text = format!("CPU: {:.1}%", self.cpu_usage);
-            #[cfg(debug_assertions)]
-            let cpu_text = format!("{cpu_text} (debug)");
-            let cpu_color = if self.cpu_usage > 100.0 {
-                theme.colors.red
-            } else if self.cpu_usage > 80.0 {
-                theme.colors.yellow
+    /// Copy/Paste of the slot's settings through the system clipboard
+    /// (native only). A failed paste shows its reason for a few seconds.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_clipboard_buttons(
+        &mut self,
+        ui: &mut egui::Ui,
+        slot: SlotIndex,
+        theme: &SonidoTheme,
+    ) {
+        const ERROR_SECS: f64 = 3.0;
+
+        let read = || {
+            arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.get_text())
+                .ok()
+        };
+        let now = ui.input(|i| i.time);
+        match clipboard::copy_paste_buttons(ui, &*self.bridge, slot, read) {
+            Some(Ok(_)) => {
+                self.paste_error = None;
+                self.preset_manager.mark_modified();
+            }
+            Some(Err(e)) => self.paste_error = Some((e.to_string(), now)),
+            None => {}
+        }
+        if let Some((error, since)) = &self.paste_error {
+            if now - since < ERROR_SECS {
+                ui.label(
+                    egui::RichText::new(error)
+                        .font(FontId::monospace(10.0))
+                        .color(theme.colors.red),
+                );
+                ui.ctx()
+                    .request_repaint_after(Duration::from_secs_f64(ERROR_SECS));
             } else {
-                theme.colors.green
-            };
+                self.paste_error = None;
+            }
+        }
+    }
 
-            ui.allocate_ui_with_layout(
-                vec2(240.0, 24.0),
-                Layout::left_to_right(Align::Center),
-                |ui| {
-                    ui.set_min_width(240.0);
-                    // Fixed-width label so sparkline position doesn't jitter
-                    ui.allocate_ui_with_layout(
-                        vec2(120.0, 24.0),
-                        Layout::left_to_right(Align::Center),
-                        |ui| {
-                            ui.label(
-                                egui::RichText::new(&cpu_text)
-                                    .font(FontId::monospace(11.0))
+    /// Lock menu on the effect panel: keep the whole slot or single
+    /// parameters when a chain preset loads. Lit while anything is locked.
+    fn render_lock_menu(&mut self, ui: &mut egui::Ui, slot: SlotIndex, theme: &SonidoTheme) {
+        let locks = self.preset_manager.locks_mut();
+        let color = if locks.has_locks(slot.0) {
+            theme.colors.amber
+        } else {
+            theme.colors.text_secondary
+        };
+        ui.menu_button(
+            egui::RichText::new("Lock")
+                .font(FontId::monospace(10.0))
+                .color(color),
+            |ui| {
+                ui.label(
+                    egui::RichText::new("Kept when a preset loads")
+                        .color(theme.colors.text_secondary)
+                        .italics(),
+                );
+                let mut slot_locked = locks.is_slot_locked(slot.0);
+                if ui
+                    .checkbox(&mut slot_locked, "Whole effect")
+                    .on_hover_text("Keep every parameter and the bypass state")
+                    .changed()
+                {
+                    locks.set_slot_locked(slot.0, slot_locked);
+                }
+                ui.separator();
+                for p in 0..self.bridge.param_count(slot) {
+                    let Some(desc) = self.bridge.param_descriptor(slot, ParamIndex(p)) else {
+                        continue;
+                    };
+                    let mut locked = locks.is_param_locked(slot.0, p);
+                    if ui
+                        .add_enabled(!slot_locked, egui::Checkbox::new(&mut locked, desc.name))
+                        .changed()
+                    {
+                        locks.set_param_locked(slot.0, p, locked);
+                    }
+                }
+            },
+        );
+    }
+
+    /// Presets menu on the effect panel: recall or save this slot's
+    /// parameters as a single-effect preset (native only).
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_effect_presets_menu(
+        &mut self,
+        ui: &mut egui::Ui,
+        slot: SlotIndex,
+        theme: &SonidoTheme,
+    ) {
+        let effect_id = self.bridge.effect_id(slot);
+        ui.menu_button(
+            egui::RichText::new("Presets")
+                .font(FontId::monospace(10.0))
+                .color(theme.colors.text_secondary),
+            |ui| {
+                let mut load = None;
+                let presets = self.effect_presets.list(effect_id);
+                if presets.is_empty() {
+                    ui.label(
+                        egui::RichText::new("No saved presets")
+                            .color(theme.colors.text_secondary)
+                            .italics(),
+                    );
+                }
+                for preset in presets {
+                    if ui.button(&preset.name).clicked() {
+                        load = Some(preset.path.clone());
+                        ui.close_menu();
+                    }
+                }
+                if let Some(path) = load {
+                    match self.effect_presets.load(&path, &*self.bridge, slot) {
+                        Ok(()) => {
+                            self.effect_preset_error = None;
+                            self.preset_manager.mark_modified();
+                        }
+                        Err(e) => self.effect_preset_error = Some(e),
+                    }
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.effect_preset_name)
+                            .hint_text("Preset name")
+                            .desired_width(120.0),
+                    );
+                    let name = self.effect_preset_name.trim().to_string();
+                    if ui
+                        .add_enabled(!name.is_empty(), egui::Button::new("Save"))
+                        .on_hover_text("Save this effect's settings")
+                        .clicked()
+                    {
+                        match self.effect_presets.save(&name, &*self.bridge, slot) {
+                            Ok(()) => {
+                                self.effect_preset_error = None;
+                                self.effect_preset_name.clear();
+                            }
+                            Err(e) => self.effect_preset_error = Some(e),
+                        }
+                    }
+                });
+                if ui.small_button("Reload").clicked() {
+                    self.effect_presets.reload();
+                }
+                if let Some(error) = &self.effect_preset_error {
+                    ui.label(egui::RichText::new(error).color(theme.colors.red));
+                }
+            },
+        );
+    }
+
+    /// Single-effect mode: input strip | effect panel | output strip.
+    fn render_single_effect(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(4.0);
+
+        let theme = SonidoTheme::get(ui.ctx());
+        let avail = ui.available_rect_before_wrap();
+
+        // Responsive I/O strip widths from ThemeLayout
+        let io_width = theme.layout.io_strip_width(avail.width());
+        let gap = 8.0;
+        let center_width = (avail.width() - 2.0 * io_width - 2.0 * gap).max(200.0);
+
+        let input_rect = Rect::from_min_size(avail.min, vec2(io_width, avail.height()));
+        let center_rect = Rect::from_min_size(
+            pos2(avail.min.x + io_width + gap, avail.min.y),
+            vec2(center_width, avail.height()),
+        );
+        let output_rect = Rect::from_min_size(
+            pos2(
+                avail.min.x + io_width + gap + center_width + gap,
+                avail.min.y,
+            ),
+            vec2(io_width, avail.height()),
+        );
+
+        // Input strip
+        {
+            let mut child = ui.new_child(
+                UiBuilder::new()
+                    .id_salt("input_col")
+                    .max_rect(input_rect)
+                    .layout(Layout::top_down(Align::Center)),
+            );
+            self.render_io_strip(&mut child, true);
+        }
+
+        // Effect panel
+        {
+            let mut child = ui.new_child(
+                UiBuilder::new()
+                    .id_salt("center_col")
+                    .max_rect(center_rect)
+                    .layout(Layout::top_down(Align::LEFT)),
+            );
+            self.render_effect_panel(&mut child, SlotIndex(0));
+        }
+
+        // Output strip
+        {
+            let mut child = ui.new_child(
+                UiBuilder::new()
+                    .id_salt("output_col")
+                    .max_rect(output_rect)
+                    .layout(Layout::top_down(Align::Center)),
+            );
+            self.render_io_strip(&mut child, false);
+        }
+
+        // Advance parent cursor past all three columns
+        ui.advance_cursor_after_rect(Rect::from_min_max(
+            avail.min,
+            pos2(
+                avail.min.x + io_width + gap + center_width + gap + io_width,
+                avail.max.y,
+            ),
+        ));
+    }
+
+    /// Chain tile: the node graph. Recompiles when the topology changes.
+    fn render_chain_tile(&mut self, ui: &mut egui::Ui) {
+        self.render_rig_bar(ui);
+
+        // Update per-slot activity from output metering
+        let slot_count = self
+            .graph_view
+            .snarl
+            .node_ids()
+            .filter(|(_, n)| matches!(n, SonidoNode::Effect { .. }))
+            .count();
+        self.graph_view.slot_activity = vec![self.metering.output_peak; slot_count];
+
+        self.graph_view.show(ui);
+
+        // Auto-compile when topology changes (connect/disconnect/remove)
+        if self.graph_view.topology_changed {
+            self.compile_and_apply();
+        } else if let Some((slot, mode)) = self.graph_view.bypass_mode_changed {
+            self.audio_bridge.send_command(GraphCommand::SetBypassMode {
+                slot: SlotIndex(slot),
+                mode,
+            });
+        }
+    }
+
+    /// Pedals tile: the chain as a pedal strip with an effect palette.
+    fn render_pedals_tile(&mut self, ui: &mut egui::Ui) {
+        self.render_rig_bar(ui);
+
+        let chain = self.graph_view.serial_chain();
+        let pedals: Option<Vec<Pedal<'_>>> = chain.as_ref().map(|ids| {
+            ids.iter()
+                .filter_map(|&id| match &self.graph_view.snarl[id] {
+                    SonidoNode::Effect { name, category, .. } => Some(Pedal {
+                        name: *name,
+                        category: *category,
+                        bypassed: self.graph_view.slot_of(id).is_some_and(|slot| {
+                            slot < self.bridge.slot_count()
+                                && self.bridge.is_bypassed(SlotIndex(slot))
+                        }),
+                        selected: self.graph_view.selected_node == Some(id),
+                    }),
+                    _ => None,
+                })
+                .collect()
+        });
+        let response = self.chain_view.show(ui, pedals.as_deref(), &self.registry);
+
+        let Some(chain) = chain else {
+            return;
+        };
+        if let Some(i) = response.clicked {
+            self.graph_view.selected_node = Some(chain[i]);
+        }
+        if let Some(edit) = response.edit {
+            self.apply_chain_edit(chain, edit);
+        }
+    }
+
+    /// Rig A / Rig B tabs above the graph and pedal tiles. Once rig B runs,
+    /// also shows the A/B footswitch, the morph slider, and the output mode.
+    fn render_rig_bar(&mut self, ui: &mut egui::Ui) {
+        let mut select = None;
+        let mut remove = false;
+        let mut toggle = false;
+        ui.horizontal(|ui| {
+            for rig in Rig::ALL {
+                let label = if rig == Rig::B && self.parked_rig.is_none() {
+                    "+ Rig B"
+                } else {
+                    rig.label()
+                };
+                let response = ui.selectable_label(self.active_rig == rig, label);
+                let response = if self.parked_rig.is_none() && rig == Rig::B {
+                    response.on_hover_text("Add a second rig fed from the same input")
+                } else {
+                    response
+                };
+                if response.clicked() {
+                    select = Some(rig);
+                }
+            }
+            if self.parked_rig.is_none() {
+                return;
+            }
+            remove = ui.small_button("✕").on_hover_text("Remove rig B").clicked();
+            ui.separator();
+
+            let morph = self.audio_bridge.rig_morph();
+            let hearing = Rig::from_morph(morph.get());
+            toggle = ui
+                .button(format!("⇄ {}", hearing.label()))
+                .on_hover_text("Footswitch: crossfade to the other rig (R)")
+                .clicked();
+            let mut value = morph.get();
+            if ui
+                .add(
+                    egui::Slider::new(&mut value, 0.0..=1.0)
+                        .text("A ↔ B")
+                        .show_value(false),
+                )
+                .on_hover_text("Morph: equal-power blend between rig A and rig B")
+                .changed()
+            {
+                morph.set(value);
+            }
+
+            let rig_output = self.audio_bridge.rig_output();
+            let current = rig_output.get();
+            egui::ComboBox::from_id_salt("rig_output")
+                .width(64.0)
+                .selected_text(current.label())
+                .show_ui(ui, |ui| {
+                    for output in RigOutput::ALL {
+                        if ui
+                            .selectable_label(output == current, output.label())
+                            .clicked()
+                        {
+                            rig_output.set(output);
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Blend: morph between the rigs. Split: rig A on the left output, \
+                     rig B on the right.",
+                );
+        });
+        if toggle {
+            self.toggle_rig();
+        }
+        if let Some(rig) = select {
+            self.select_rig(rig);
+        }
+        if remove {
+            self.collapse_to_rig_a();
+            let old_slots: Vec<NodeId> = self.graph_view.effect_node_ids().collect();
+            self.recompile_keeping_values(&old_slots);
+        }
+    }
+
+    /// Reorder or extend the serial chain and recompile.
+    fn apply_chain_edit(&mut self, mut order: Vec<NodeId>, edit: ChainEdit) {
+        let old_slots: Vec<NodeId> = self.graph_view.effect_node_ids().collect();
+        match edit {
+            ChainEdit::Move { from, to } => {
+                let node = order.remove(from);
+                order.insert(to, node);
+            }
+            ChainEdit::Insert { index, effect_id } => {
+                let Some(node) = self.graph_view.add_effect(effect_id, &self.registry) else {
+                    return;
+                };
+                order.insert(index, node);
+                self.graph_view.selected_node = Some(node);
+            }
+        }
+        self.graph_view.set_serial_order(&order);
+        self.recompile_keeping_values(&old_slots);
+    }
+
+    /// Add an effect from the browser: into the serial chain at the chosen
+    /// index, or as an unwired node if the graph has parallel routing.
+    fn apply_browser_insert(&mut self, insert: BrowserInsert) {
+        match (insert.index, self.graph_view.serial_chain()) {
+            (Some(index), Some(chain)) => self.apply_chain_edit(
+                chain,
+                ChainEdit::Insert {
+                    index: index.min(chain.len()),
+                    effect_id: insert.effect_id,
+                },
+            ),
+            _ => {
+                let old_slots: Vec<NodeId> = self.graph_view.effect_node_ids().collect();
+                if let Some(node) = self.graph_view.add_effect(insert.effect_id, &self.registry) {
+                    self.graph_view.selected_node = Some(node);
+                    self.recompile_keeping_values(&old_slots);
+                }
+            }
+        }
+    }
+
+    /// Recompile after a structural edit, carrying each remaining effect's
+    /// parameter values and bypass state over to the rebuilt bridge.
+    ///
+    /// `old_slots` is the shown rig's effect node list (slot order) before
+    /// the edit; the other rig's effects keep their values too.
+    fn recompile_keeping_values(&mut self, old_slots: &[NodeId]) {
+        let mut effect_ids = Vec::new();
+        let mut sources = Vec::new();
+        for (rig, view) in self.rig_views() {
+            let old: Vec<NodeId> = if rig == self.active_rig {
+                old_slots.to_vec()
+            } else {
+                view.effect_node_ids().collect()
+            };
+            for id in view.effect_node_ids() {
+                if let SonidoNode::Effect { effect_id, .. } = &view.snarl[id] {
+                    effect_ids.push(*effect_id);
+                    sources.push(
+                        old.iter()
+                            .position(|&o| o == id)
+                            .map(|slot| view.slot_offset + slot),
+                    );
+                }
+            }
+        }
+        self.pending_values = Some(PendingValues::new(&self.bridge, effect_ids, &sources));
+        self.compile_and_apply();
+    }
+
+    /// Editor tile: the selected node's effect panel.
+    fn render_editor_tile(&mut self, ui: &mut egui::Ui) {
+        match self.graph_view.selected_slot() {
+            Some(slot) if slot < self.bridge.slot_count() => {
+                self.render_effect_panel(ui, SlotIndex(slot));
+            }
+            Some(_) => {}
+            None => Self::render_quick_reference(ui),
+        }
+    }
+
+    /// Scenes tile: MIDI input port (native only), scene footswitches, and
+    /// the selected effect's scene parameters.
+    fn render_scenes_tile(&mut self, ui: &mut egui::Ui) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.render_midi_port(ui);
+        self.scenes
+            .ui(ui, &*self.bridge, self.graph_view.selected_slot());
+    }
+
+    /// MIDI input port selector (program changes and expression CCs).
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_midi_port(&mut self, ui: &mut egui::Ui) {
+        let theme = SonidoTheme::get(ui.ctx());
+        ui.horizontal(|ui| {
+            ui.label("MIDI program change");
+            let current = self.midi_input.port().unwrap_or("Off").to_owned();
+            let mut picked = None;
+            egui::ComboBox::from_id_salt("scene_midi_port")
+                .selected_text(&current)
+                .show_ui(ui, |ui| {
+                    if ui
+                        .selectable_label(self.midi_input.port().is_none(), "Off")
+                        .clicked()
+                    {
+                        picked = Some(None);
+                    }
+                    for name in MidiControlInput::port_names() {
+                        if ui.selectable_label(name == current, &name).clicked() {
+                            picked = Some(Some(name));
+                        }
+                    }
+                });
+            match picked {
+                Some(Some(name)) => {
+                    self.midi_error = self.midi_input.connect(&name, ui.ctx()).err();
+                }
+                Some(None) => {
+                    self.midi_input.disconnect();
+                    self.midi_error = None;
+                }
+                None => {}
+            }
+            if let Some(error) = &self.midi_error {
+                ui.colored_label(theme.colors.red, error);
+            }
+        });
+        ui.separator();
+    }
+
+    /// Engage or release safe mode on the audio thread.
+    fn set_safe_mode(&mut self, engaged: bool) {
+        self.audio_bridge
+            .safe_mode()
+            .store(engaged, Ordering::SeqCst);
+        tracing::info!(engaged, "safe mode");
+    }
+
+    /// Toggle safe mode when Esc has been held for [`SAFE_MODE_HOLD_SECS`].
+    /// Works with text fields focused; a short press keeps its usual meaning.
+    fn poll_safe_mode_hold(&mut self, ctx: &Context) {
+        let (down, now) = ctx.input(|i| (i.key_down(Key::Escape), i.time));
+        match (down, self.esc_down_since) {
+            (false, _) => self.esc_down_since = None,
+            (true, None) => {
+                if ctx.input(|i| i.key_pressed(Key::Escape)) {
+                    self.esc_down_since = Some(now);
+                }
+            }
+            (true, Some(since)) if now - since >= SAFE_MODE_HOLD_SECS => {
+                // Fire once per press: stay latched until Esc is released.
+                self.esc_down_since = Some(f64::INFINITY);
+                let safe = self.audio_bridge.safe_mode().load(Ordering::Relaxed);
+                self.set_safe_mode(!safe);
+            }
+            (true, Some(since)) => {
+                if since.is_finite() {
+                    ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                        SAFE_MODE_HOLD_SECS - (now - since),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Load preset `index` onto the bridge.
+    ///
+    /// With a crossfade time set, the audio thread first switches to a fresh
+    /// engine that fades in on the new values while the running one fades
+    /// out (see [`crossfade_to_fresh_engine`](Self::crossfade_to_fresh_engine)).
+    /// Otherwise, with spillover on, each active time-based slot the preset
+    /// configures first gets a fresh instance carrying the new values, so the
+    /// old instance's tail rings out rather than being retuned mid-decay.
+    fn load_preset(&mut self, index: usize) {
+        let transition = self.program_map.transition();
+        let fresh = if transition.is_none() && self.program_map.spillover {
+            self.spillover_instances(index)
+        } else {
+            Vec::new()
+        };
+        if let Some(transition) = transition {
+            self.crossfade_to_fresh_engine(transition);
+        }
+        self.preset_manager.select(index, &*self.bridge);
+        if let Some(entry) = self.preset_manager.current() {
+            self.macros = MacroBank::from_configs(&entry.preset.macros, &*self.bridge);
+        }
+        for (slot, mut effect) in fresh {
+            for p in 0..self.bridge.param_count(slot) {
+                effect.effect_set_param(p, self.bridge.get(slot, ParamIndex(p)));
+            }
+            effect.reset();
+            self.audio_bridge
+                .send_command(GraphCommand::ReplaceEffect { slot, effect });
+        }
+    }
+
+    /// Send a fresh build of the current graph to the audio thread to
+    /// crossfade to.
+    ///
+    /// Call before changing bridge values: the command is queued first, so
+    /// the running engine never picks up the new values and the fresh one
+    /// syncs them from the bridge as it fades in.
+    fn crossfade_to_fresh_engine(&mut self, transition: Transition) {
+        match self.compile_current() {
+            Ok(GraphCommand::ReplaceTopology { engine, .. }) => {
+                self.audio_bridge
+                    .send_command(GraphCommand::CrossfadeTo { engine, transition });
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "preset crossfade skipped, graph does not compile");
+            }
+        }
+    }
+
+    /// Fresh effect instances for the active, unlocked time-based slots that
+    /// preset `index` configures. Created before the preset is applied so the
+    /// swap follows the new values as closely as possible.
+    fn spillover_instances(
+        &self,
+        index: usize,
+    ) -> Vec<(SlotIndex, Box<dyn EffectWithParams + Send>)> {
+        let Some(entry) = self.preset_manager.presets().get(index) else {
+            return Vec::new();
+        };
+        (0..self.bridge.slot_count())
+            .map(SlotIndex)
+            .filter_map(|slot| {
+                let id = self.bridge.effect_id(slot);
+                let time_based = self
+                    .registry
+                    .get(id)
+                    .is_some_and(|d| d.category == EffectCategory::TimeBased);
+                (time_based
+                    && !self.bridge.is_bypassed(slot)
+                    && !self.preset_manager.locks().is_slot_locked(slot.0)
+                    && preset_manager::preset_configures(&entry.preset, id))
+                .then(|| self.registry.create(id, self.sample_rate))
+                .flatten()
+                .map(|effect| (slot, effect))
+            })
+            .collect()
+    }
+
+    /// Probe each active effect in the chain, at its current settings, for
+    /// mono compatibility and hand the results to the analyzer.
+    fn check_effects_mono(&mut self) {
+        let checks = self
+            .bridge
+            .get_order()
+            .into_iter()
+            .map(SlotIndex)
+            .filter(|&slot| !self.bridge.is_bypassed(slot))
+            .filter_map(|slot| {
+                let id = self.bridge.effect_id(slot);
+                let mut effect = self.registry.create(id, self.sample_rate)?;
+                for param in 0..self.bridge.param_count(slot) {
+                    effect.effect_set_param(param, self.bridge.get(slot, ParamIndex(param)));
+                }
+                let name = self.registry.get(id).map_or(id, |d| d.name).to_string();
+                let report = probe_effect(effect.as_mut(), self.sample_rate);
+                Some(EffectMonoCheck { name, report })
+            })
+            .collect();
+        self.analyzer.set_effect_checks(checks);
+    }
+
+    /// Handle a MIDI program change: load the mapped preset, or recall the
+    /// scene of the same number when the program is unmapped.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn program_change(&mut self, program: u8) {
+        let Some(name) = self.program_map.preset_for(program).map(str::to_owned) else {
+            self.scenes.recall(usize::from(program), &*self.bridge);
+            return;
+        };
+        match self
+            .preset_manager
+            .presets()
+            .iter()
+            .position(|e| e.preset.name == name)
+        {
+            Some(index) => {
+                self.load_preset(index);
+                tracing::info!(program, preset = %name, "preset loaded by program change");
+            }
+            None => {
+                tracing::warn!(program, preset = %name, "program change maps to a missing preset")
+            }
+        }
+    }
+
+    /// Presets tile: click a preset to load it.
+    fn render_presets_tile(&mut self, ui: &mut egui::Ui) {
+        let theme = SonidoTheme::get(ui.ctx());
+
+        if ui
+            .small_button("Reload")
+            .on_hover_text("Rescan the user presets folder")
+            .clicked()
+        {
+            self.preset_manager.reload();
+        }
+        ui.add_space(4.0);
+
+        let current = self.preset_manager.current_preset();
+        let mut clicked = None;
+        for (i, entry) in self.preset_manager.presets().iter().enumerate() {
+            // User presets in the secondary accent, factory in the primary text color
+            let color = if entry.is_user() {
+                theme.colors.cyan
+            } else {
+                theme.colors.text_primary
+            };
+            let mut response = ui.selectable_label(
+                i == current,
+                egui::RichText::new(&entry.preset.name)
+                    .font(FontId::monospace(11.0))
+                    .color(color),
+            );
+            if let Some(description) = &entry.preset.description {
+                response = response.on_hover_text(description);
+            }
+            if response.clicked() {
+                clicked = Some(i);
+            }
+        }
+
+        if let Some(index) = clicked {
+            self.load_preset(index);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.render_preset_save(ui, &theme);
+    }
+
+    /// Save row under the preset list: the chain's parameters and macros.
+    /// Saving under the current user preset's name overwrites it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_preset_save(&mut self, ui: &mut egui::Ui, theme: &SonidoTheme) {
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.preset_name)
+                    .hint_text("Preset name")
+                    .desired_width(120.0),
+            );
+            let name = self.preset_name.trim().to_string();
+            if ui
+                .add_enabled(!name.is_empty(), egui::Button::new("Save"))
+                .on_hover_text("Save the chain's parameters and macros as a user preset")
+                .clicked()
+            {
+                let macros = self.macros.to_configs(&*self.bridge);
+                let overwrite = self
+                    .preset_manager
+                    .current()
+                    .is_some_and(|e| e.is_user() && e.preset.name == name);
+                let result = if overwrite {
+                    self.preset_manager
+                        .save_current(None, &*self.bridge, macros)
+                } else {
+                    self.preset_manager
+                        .save_as(&name, None, None, &*self.bridge, macros)
+                };
+                match result {
+                    Ok(()) => {
+                        self.preset_error = None;
+                        self.preset_name.clear();
+                    }
+                    Err(e) => self.preset_error = Some(e),
+                }
+            }
+        });
+        if let Some(error) = &self.preset_error {
+            ui.label(egui::RichText::new(error).color(theme.colors.red));
+        }
+    }
+
+    /// Render the status bar.
+    fn render_status_bar(&mut self, ui: &mut egui::Ui) {
+        let theme = SonidoTheme::get(ui.ctx());
+
+        ui.horizontal(|ui| {
+            // Sample rate
+            ui.label(
+                egui::RichText::new(format!("{:.0}Hz", self.sample_rate))
+                    .font(FontId::monospace(11.0))
+                    .color(theme.colors.amber),
+            );
+            ui.separator();
+
+            // Latency
+            let latency_ms = self.buffer_size as f32 / self.sample_rate * 1000.0;
+            ui.label(
+                egui::RichText::new(format!("{latency_ms:.1}ms"))
+                    .font(FontId::monospace(11.0))
+                    .color(theme.colors.amber),
+            );
+            ui.separator();
+
+            // CPU meter — fixed-width allocation to prevent sparkline jitter
+            let cpu_text = format!("CPU: {:.1}%", self.cpu_usage);
+            #[cfg(debug_assertions)]
+            let cpu_text = format!("{cpu_text} (debug)");
+            let cpu_color = if self.cpu_usage > 100.0 {
+                theme.colors.red
+            } else if self.cpu_usage > 80.0 {
+                theme.colors.yellow
+            } else {
+                theme.colors.green
+            };
+
+            ui.allocate_ui_with_layout(
+                vec2(240.0, 24.0),
+                Layout::left_to_right(Align::Center),
+                |ui| {
+                    ui.set_min_width(240.0);
+                    // Fixed-width label so sparkline position doesn't jitter
+                    ui.allocate_ui_with_layout(
+                        vec2(120.0, 24.0),
+                        Layout::left_to_right(Align::Center),
+                        |ui| {
+                            ui.label(
+                                egui::RichText::new(&cpu_text)
+                                    .font(FontId::monospace(11.0))
                                     .color(cpu_color),
                             );
                         },
@@ -763,12 +2170,192 @@ impl SonidoApp {
                     .color(daisy_color),
             );
 
-            // File player / generator transport (inline)
-            ui.separator();
-            self.file_player.render_compact(ui);
+            // File player / generator transport (inline) unless the player tile is open
+            if self.single_effect || !layout::is_open(&self.dock, Tile::Player) {
+                ui.separator();
+                self.file_player.render_compact(ui);
+            }
         });
     }
 
+    /// Perform a keyboard shortcut action.
+    fn run_shortcut(&mut self, action: ShortcutAction) {
+        match action {
+            ShortcutAction::PlayPause => {
+                let can_play = match self.file_player.source_mode() {
+                    crate::signal_generator::SourceMode::Generator => true,
+                    crate::signal_generator::SourceMode::File => self.file_player.has_file(),
+                };
+                if can_play {
+                    self.file_player.toggle_play_pause();
+                }
+            }
+            ShortcutAction::BypassSelected => {
+                let slot = if self.single_effect {
+                    Some(0)
+                } else {
+                    self.graph_view.selected_slot()
+                };
+                if let Some(slot) = slot.map(SlotIndex)
+                    && slot.0 < self.bridge.slot_count()
+                {
+                    let bypassed = !self.bridge.is_bypassed(slot);
+                    self.bridge.set_bypassed(slot, bypassed);
+                }
+            }
+            ShortcutAction::SelectPrevious if !self.single_effect => {
+                self.graph_view.select_adjacent_effect(-1);
+            }
+            ShortcutAction::SelectNext if !self.single_effect => {
+                self.graph_view.select_adjacent_effect(1);
+            }
+            ShortcutAction::SelectPrevious | ShortcutAction::SelectNext => {}
+            ShortcutAction::ToggleRig => self.toggle_rig(),
+            ShortcutAction::Scene(index) => {
+                self.scenes.recall(index, &*self.bridge);
+            }
+            ShortcutAction::Preset(index) => {
+                if index < self.preset_manager.presets().len() {
+                    self.load_preset(index);
+                    if let Some(entry) = self.preset_manager.current() {
+                        tracing::info!(preset = %entry.preset.name, "preset loaded by shortcut");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run a console script against the current effect chain.
+    ///
+    /// Output and errors go to the console log. Parameter and bypass edits are
+    /// applied even if the script fails part-way, matching what it printed.
+    #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+    fn run_script(&mut self, source: &str) {
+        /// Operation budget per run, so a runaway loop cannot freeze the UI.
+        const MAX_OPERATIONS: u64 = 10_000_000;
+
+        let slots = script_console::chain_from_bridge(&*self.bridge, &self.registry);
+        let mut chain = sonido_script::ScriptChain::from_slots(slots);
+        if self.single_effect {
+            chain = chain.lock_topology();
+        }
+
+        let mut runner = sonido_script::ScriptRunner::new(chain, ConsoleHost::default());
+        runner.set_max_operations(MAX_OPERATIONS);
+        let result = runner.run(source);
+        let (chain, host) = runner.into_parts();
+
+        for line in host.output {
+            self.script_console.log(line);
+        }
+        if let Err(e) = result {
+            self.script_console.log(e.to_string());
+        }
+
+        if chain.topology_changed() {
+            self.replace_chain(chain.slots().to_vec());
+        } else {
+            script_console::apply_to_bridge(&*self.bridge, chain.slots());
+        }
+
+        for command in host.transport {
+            match command {
+                sonido_script::Transport::Load(path) => {
+                    if !self.file_player.open_file(path.clone()) {
+                        self.script_console
+                            .log(format!("{}: could not load", path.display()));
+                    }
+                }
+                sonido_script::Transport::Play => self.file_player.play(),
+                sonido_script::Transport::Pause => self.file_player.pause(),
+                sonido_script::Transport::Stop => self.file_player.stop(),
+                sonido_script::Transport::Seek(secs) => self.file_player.seek(secs),
+            }
+        }
+    }
+
+    /// Replace the graph with a linear chain of `slots`.
+    ///
+    /// The audio thread rebuilds the bridge with default values when it
+    /// receives the new topology, so the script's values are applied once the
+    /// bridge holds the new effects (see `update`).
+    #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+    fn replace_chain(&mut self, slots: Vec<sonido_script::ChainSlot>) {
+        if script_console::bridge_matches(&*self.bridge, &slots) {
+            script_console::apply_to_bridge(&*self.bridge, &slots);
+            return;
+        }
+        let effects = slots
+            .iter()
+            .map(|slot| crate::session::EffectState {
+                effect_id: slot.effect_id.to_string(),
+                params: slot.params.clone(),
+                bypassed: slot.bypassed,
+                bypass_mode: String::new(),
+            })
+            .collect();
+        let session = crate::session::Session::linear(
+            effects,
+            self.audio_bridge.input_gain().get(),
+            self.audio_bridge.master_volume().get(),
+        );
+        self.collapse_to_rig_a();
+        self.graph_view.restore_session(&session, &self.registry);
+        self.compile_and_apply();
+        self.pending_script_chain = Some(slots);
+    }
+
+    /// Persist keybindings to the config directory (native only).
+    fn save_keybindings(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = self.keybindings.save(&Keybindings::default_path()) {
+            tracing::error!(error = %e, "failed to save keybindings");
+        }
+    }
+
+    /// Persist input calibrations to the config directory (native only).
+    fn save_input_calibrations(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = self
+            .input_calibrations
+            .save(&InputCalibrations::default_path())
+        {
+            tracing::error!(error = %e, "failed to save input calibrations");
+        }
+    }
+
+    /// Persist knob settings to the config directory (native only).
+    fn save_knob_settings(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = knob_settings::save(&self.theme.knob, &knob_settings::default_path()) {
+            tracing::error!(error = %e, "failed to save knob settings");
+        }
+    }
+
+    /// Persist the appearance to the config directory (native only).
+    fn save_appearance(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = self.appearance.save(&Appearance::default_path()) {
+            tracing::error!(error = %e, "failed to save appearance");
+        }
+    }
+
+    /// Persist the program map to the config directory (native only).
+    fn save_program_map(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = self.program_map.save(&ProgramMap::default_path()) {
+            tracing::error!(error = %e, "failed to save program map");
+        }
+    }
+
+    /// Persist the tile layout to the config directory (native only).
+    fn save_layout(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = layout::save(&self.dock, &layout::default_path()) {
+            tracing::error!(error = %e, "failed to save layout");
+        }
+    }
+
     /// Save the current session to a JSON file via file dialog.
     #[cfg(not(target_arch = "wasm32"))]
     fn save_session(&self) {
@@ -776,18 +2363,35 @@ impl SonidoApp {
             .set_title("Save Session")
             .add_filter("Sonido Session", &["json"])
             .save_file()
+            && let Err(e) = self.capture_session().save(&path)
         {
-            let session = self.graph_view.capture_session(
-                &*self.bridge,
-                self.audio_bridge.input_gain().get(),
-                self.audio_bridge.master_volume().get(),
-            );
-            if let Err(e) = session.save(&path) {
-                tracing::error!(error = %e, "failed to save session");
-            }
+            tracing::error!(error = %e, "failed to save session");
         }
     }
 
+    /// Snapshot the full editor state: graph, gains, scenes, expression,
+    /// macros, and locks.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn capture_session(&self) -> crate::session::Session {
+        let rigs = self.rig_views();
+        let mut session = rigs[0].1.capture_session(
+            &*self.bridge,
+            self.audio_bridge.input_gain().get(),
+            self.audio_bridge.master_volume().get(),
+        );
+        session.input_routing = self.audio_bridge.input_routing().get().label().to_string();
+        session.rig_b = rigs
+            .get(1)
+            .map(|(_, rig_b)| Box::new(rig_b.capture_session(&*self.bridge, 0.0, 0.0)));
+        session.rig_morph = self.audio_bridge.rig_morph().get();
+        session.rig_output = self.audio_bridge.rig_output().get().label().to_string();
+        session.scenes = self.scenes.clone();
+        session.expression = self.expression.clone();
+        session.macros = self.macros.to_configs(&*self.bridge);
+        session.locks = self.preset_manager.locks().clone();
+        session
+    }
+
     /// Load a session from a JSON file via file dialog.
     #[cfg(not(target_arch = "wasm32"))]
     fn load_session(&mut self) {
@@ -797,42 +2401,101 @@ impl SonidoApp {
             .pick_file()
         {
             match crate::session::Session::load(&path) {
-                Ok(session) => {
-                    self.graph_view.restore_session(&session, &self.registry);
-                    // Compile the restored graph and send to audio thread
-                    self.compile_and_apply();
-                    // Restore I/O gains
-                    self.audio_bridge.input_gain().set(session.input_gain);
-                    self.audio_bridge.master_volume().set(session.master_volume);
-                    // Restore per-effect params
-                    for (node_idx, state) in &session.params {
-                        let mut slot = 0usize;
-                        for (i, entry) in session.nodes.iter().enumerate() {
-                            if matches!(entry.node, crate::session::SessionNode::Effect { .. }) {
-                                if i == *node_idx {
-                                    for (p, &val) in state.params.iter().enumerate() {
-                                        self.bridge.set(
-                                            SlotIndex(slot),
-                                            sonido_gui_core::ParamIndex(p),
-                                            val,
-                                        );
-                                    }
-                                    if state.bypassed {
-                                        self.bridge.set_bypassed(SlotIndex(slot), true);
-                                    }
-                                    break;
-                                }
-                                slot += 1;
-                            }
-                        }
-                    }
-                }
+                Ok(session) => self.apply_session(&session),
                 Err(e) => {
                     tracing::error!(error = %e, "failed to load session");
                 }
             }
         }
     }
+
+    /// Replace the editor state with `session` and send the new graph to
+    /// the audio thread.
+    ///
+    /// Parameter values and bypass states are applied once the audio thread
+    /// has rebuilt the bridge for the new topology; setting them right away
+    /// would be undone by that rebuild.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn apply_session(&mut self, session: &crate::session::Session) {
+        self.collapse_to_rig_a();
+        self.graph_view.restore_session(session, &self.registry);
+        self.parked_rig = session.rig_b.as_deref().map(|rig_b| {
+            let mut view = GraphView::new();
+            view.restore_session(rig_b, &self.registry);
+            view
+        });
+
+        // Session values per effect slot, in node order, rig A then rig B
+        let values = std::iter::once(session)
+            .chain(session.rig_b.as_deref())
+            .flat_map(|rig| {
+                rig.nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| {
+                        matches!(entry.node, crate::session::SessionNode::Effect { .. })
+                    })
+                    .map(|(i, _)| {
+                        rig.params
+                            .get(&i)
+                            .map(|state| (state.params.clone(), state.bypassed))
+                    })
+            })
+            .collect();
+        let effect_ids = self
+            .rig_views()
+            .into_iter()
+            .flat_map(|(_, view)| {
+                view.effect_node_ids()
+                    .filter_map(move |id| match &view.snarl[id] {
+                        SonidoNode::Effect { effect_id, .. } => Some(*effect_id),
+                        _ => None,
+                    })
+            })
+            .collect();
+        self.pending_values = Some(PendingValues::with_values(&self.bridge, effect_ids, values));
+        self.compile_and_apply();
+
+        self.audio_bridge.input_gain().set(session.input_gain);
+        self.audio_bridge
+            .input_routing()
+            .set(InputRouting::from_label(&session.input_routing).unwrap_or_default());
+        self.audio_bridge.master_volume().set(session.master_volume);
+        self.audio_bridge.rig_morph().set(session.rig_morph);
+        self.audio_bridge
+            .rig_output()
+            .set(RigOutput::from_label(&session.rig_output).unwrap_or_default());
+        self.scenes = session.scenes.clone();
+        self.expression = session.expression.clone();
+        *self.preset_manager.locks_mut() = session.locks.clone();
+        self.macros = MacroBank::from_configs(&session.macros, &*self.bridge);
+    }
+
+    /// Show the crash-recovery prompt if one is pending, then autosave the
+    /// session when due.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_autosave(&mut self, ctx: &Context) {
+        if let Some(prompt) = &self.recovery {
+            match prompt.show(ctx) {
+                Some(RecoveryChoice::Restore) => {
+                    if let Some(prompt) = self.recovery.take() {
+                        self.apply_session(prompt.session());
+                    }
+                }
+                Some(RecoveryChoice::Discard) => self.recovery = None,
+                None => {}
+            }
+            return;
+        }
+        // Wait out a pending rebuild: the bridge still holds the old chain.
+        let now = std::time::Instant::now();
+        if self.pending_values.is_none() && self.autosave.as_ref().is_some_and(|a| a.due(now)) {
+            let session = self.capture_session();
+            if let Some(autosave) = &mut self.autosave {
+                autosave.save(&session, now);
+            }
+        }
+    }
 }
 
 /// Draw a sparkline graph with phosphor glow from a history of values.
@@ -884,6 +2547,50 @@ fn draw_sparkline(
     }
 }
 
+/// Draws the workspace tiles for the dock area.
+struct TileViewer<'a> {
+    app: &'a mut SonidoApp,
+}
+
+impl TabViewer for TileViewer<'_> {
+    type Tab = Tile;
+
+    fn title(&mut self, tile: &mut Tile) -> egui::WidgetText {
+        tile.title().into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tile: &mut Tile) {
+        let app = &mut *self.app;
+        match tile {
+            Tile::Input => app.render_io_strip(ui, true),
+            Tile::Output => app.render_io_strip(ui, false),
+            Tile::Chain => app.render_chain_tile(ui),
+            Tile::Pedals => app.render_pedals_tile(ui),
+            Tile::Editor => app.render_editor_tile(ui),
+            Tile::Presets => app.render_presets_tile(ui),
+            Tile::Scenes => app.render_scenes_tile(ui),
+            Tile::Macros => app.macros.ui(ui, &*app.bridge, &mut app.macro_editor),
+            Tile::Analyzer => {
+                app.analyzer
+                    .update(&app.audio_bridge.analyzer_tap(), app.sample_rate);
+                app.analyzer.ui(ui, app.sample_rate);
+                if app.analyzer.take_effect_check_request() {
+                    app.check_effects_mono();
+                }
+            }
+            Tile::Player => app.file_player.ui(ui),
+        }
+    }
+
+    /// The graph canvas and analyzer fill their tile; the rest scroll.
+    fn scroll_bars(&self, tile: &Tile) -> [bool; 2] {
+        match tile {
+            Tile::Chain | Tile::Analyzer => [false, false],
+            _ => [true, true],
+        }
+    }
+}
+
 impl eframe::App for SonidoApp {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
         // Update metering data
@@ -924,15 +2631,139 @@ impl eframe::App for SonidoApp {
         #[cfg(not(target_arch = "wasm32"))]
         ctx.request_repaint_after(Duration::from_millis(if is_animating { 16 } else { 250 }));
 
+        // Shortcuts editor (captures keys before they reach the app shortcuts)
+        if self.shortcuts_editor.show(ctx, &mut self.keybindings) {
+            self.save_keybindings();
+        }
+
+        // Knob settings apply to every knob through the installed theme
+        if self.knob_settings_editor.show(ctx, &mut self.theme.knob) {
+            self.theme.install(ctx);
+            self.save_knob_settings();
+        }
+
+        let saved = self.input_calibrations.get(&self.audio_device);
+        if let Some(calibration) =
+            self.input_calibration_wizard
+                .show(ctx, &self.audio_bridge, &self.audio_device, saved)
+        {
+            self.audio_bridge.input_gain().set(calibration.gain_db);
+            self.input_calibrations.set(&self.audio_device, calibration);
+            self.save_input_calibrations();
+        }
+
+        if self.appearance_editor.show(ctx, &mut self.appearance) {
+            self.appearance.apply_to(&mut self.theme);
+            self.theme.apply_style(ctx);
+            self.save_appearance();
+        }
+
+        self.expression_editor
+            .show(ctx, &mut self.expression, &*self.bridge);
+        self.macro_editor.show(ctx, &mut self.macros, &*self.bridge);
+
+        let preset_names: Vec<&str> = self
+            .preset_manager
+            .presets()
+            .iter()
+            .map(|e| e.preset.name.as_str())
+            .collect();
+        if self
+            .program_map_editor
+            .show(ctx, &mut self.program_map, &preset_names)
+        {
+            self.save_program_map();
+        }
+
+        if !self.single_effect {
+            let chain = self.graph_view.serial_chain().map(|ids| {
+                ids.iter()
+                    .filter_map(|&id| match &self.graph_view.snarl[id] {
+                        SonidoNode::Effect { name, .. } => Some(*name),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            });
+            if let Some(insert) = self
+                .effect_browser
+                .show(ctx, &self.registry, chain.as_deref())
+            {
+                self.apply_browser_insert(insert);
+            }
+        }
+
         // Global keyboard shortcuts (only when no text widget is focused)
         let no_widget_focused = ctx.memory(|m| m.focused().is_none());
-        if no_widget_focused && ctx.input(|i| i.key_pressed(egui::Key::Space)) {
-            let can_play = match self.file_player.source_mode() {
-                crate::signal_generator::SourceMode::Generator => true,
-                crate::signal_generator::SourceMode::File => self.file_player.has_file(),
+        if no_widget_focused && !self.shortcuts_editor.is_capturing() {
+            for action in self.keybindings.poll(ctx) {
+                self.run_shortcut(action);
+            }
+            // Redo first: Cmd+Shift+Z also matches the undo shortcut.
+            let changed = if ctx.input_mut(|i| i.consume_shortcut(&REDO_SHORTCUT)) {
+                self.bridge.redo()
+            } else if ctx.input_mut(|i| i.consume_shortcut(&UNDO_SHORTCUT)) {
+                self.bridge.undo()
+            } else {
+                false
             };
-            if can_play {
-                self.file_player.toggle_play_pause();
+            if changed {
+                self.preset_manager.mark_modified();
+            }
+        }
+        self.poll_safe_mode_hold(ctx);
+        if ctx.input_mut(|i| i.consume_shortcut(&DEVELOPER_MENU_SHORTCUT)) {
+            self.developer_menu = !self.developer_menu;
+        }
+
+        if let Some(pending) = &self.pending_values
+            && pending.try_apply(&self.bridge)
+        {
+            self.pending_values = None;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.update_autosave(ctx);
+
+        // MIDI program change (preset or scene) and expression CCs, then
+        // the running scene crossfade
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let messages: Vec<MidiMessage> = self.midi_input.poll().collect();
+            for message in messages {
+                match message {
+                    MidiMessage::ProgramChange(program) => self.program_change(program),
+                    MidiMessage::ControlChange { cc, value } => {
+                        if let Some(engaged) = self.program_map.safe_mode_for(cc, value) {
+                            self.set_safe_mode(engaged);
+                            continue;
+                        }
+                        if let Some(control) = self.expression.handle_cc(cc, value, &*self.bridge) {
+                            self.expression_editor.learn(control);
+                        }
+                    }
+                }
+            }
+        }
+        if self.scenes.tick(&*self.bridge, ctx.input(|i| i.time)) {
+            ctx.request_repaint();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(console) = &mut self.log_console {
+            console.show(ctx);
+        }
+
+        // Script console
+        #[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+        {
+            if let Some(source) = self.script_console.show(ctx) {
+                self.run_script(&source);
+            }
+            if let Some(slots) = &self.pending_script_chain
+                && script_console::bridge_matches(&*self.bridge, slots)
+            {
+                script_console::apply_to_bridge(&*self.bridge, slots);
+                self.pending_script_chain = None;
             }
         }
 
@@ -962,121 +2793,27 @@ impl eframe::App for SonidoApp {
                 );
             }
 
-            ui.add_space(4.0);
-
-            let theme = SonidoTheme::get(ui.ctx());
-            let avail = ui.available_rect_before_wrap();
-
-            // Responsive I/O strip widths from ThemeLayout
-            let io_width = theme.layout.io_strip_width(avail.width());
-            let gap = 8.0;
-            let center_width = (avail.width() - 2.0 * io_width - 2.0 * gap).max(200.0);
-
-            let input_rect = Rect::from_min_size(avail.min, vec2(io_width, avail.height()));
-            let center_rect = Rect::from_min_size(
-                pos2(avail.min.x + io_width + gap, avail.min.y),
-                vec2(center_width, avail.height()),
-            );
-            let output_rect = Rect::from_min_size(
-                pos2(
-                    avail.min.x + io_width + gap + center_width + gap,
-                    avail.min.y,
-                ),
-                vec2(io_width, avail.height()),
-            );
-
-            // Input strip
-            {
-                let mut child = ui.new_child(
-                    UiBuilder::new()
-                        .id_salt("input_col")
-                        .max_rect(input_rect)
-                        .layout(Layout::top_down(Align::Center)),
-                );
-                self.render_io_strip(&mut child, true);
-            }
-
-            // Center column (graph editor + effect panel)
-            {
-                let mut child = ui.new_child(
-                    UiBuilder::new()
-                        .id_salt("center_col")
-                        .max_rect(center_rect)
-                        .layout(Layout::top_down(Align::LEFT)),
-                );
-
-                if self.single_effect {
-                    // Single-effect mode: show only the effect panel, no graph
-                    self.render_effect_panel(&mut child, SlotIndex(0));
-                } else {
-                    // Dynamic graph/panel split from ThemeLayout
-                    let content_h = child.available_height();
-                    let panel_content_h = self.estimate_panel_height();
-                    let (graph_h, _panel_h) =
-                        theme.layout.split_vertical(content_h, panel_content_h);
-
-                    let selected_slot = child
-                        .group(|ui| {
-                            ui.set_max_height(graph_h);
-                            ui.vertical_centered(|ui| {
-                                // Update per-slot activity from output metering
-                                let slot_count = self
-                                    .graph_view
-                                    .snarl
-                                    .node_ids()
-                                    .filter(|(_, n)| matches!(n, SonidoNode::Effect { .. }))
-                                    .count();
-                                self.graph_view.slot_activity =
-                                    vec![self.metering.output_peak; slot_count];
-
-                                self.graph_view.show(ui)
-                            })
-                            .inner
-                        })
-                        .inner;
-
-                    // Auto-compile when topology changes (connect/disconnect/remove)
-                    if self.graph_view.topology_changed {
-                        self.compile_and_apply();
-                    }
-
-                    child.add_space(8.0);
-
-                    // Effect panel for the selected node
-                    if let Some(slot_idx) = selected_slot {
-                        let slot = SlotIndex(slot_idx);
-                        if slot.0 < self.bridge.slot_count() {
-                            self.render_effect_panel(&mut child, slot);
-                        }
-                    } else {
-                        Self::render_quick_reference(&mut child);
-                    }
-                }
-            }
-
-            // Output strip
-            {
-                let mut child = ui.new_child(
-                    UiBuilder::new()
-                        .id_salt("output_col")
-                        .max_rect(output_rect)
-                        .layout(Layout::top_down(Align::Center)),
-                );
-                self.render_io_strip(&mut child, false);
+            if self.single_effect {
+                self.render_single_effect(ui);
+            } else {
+                // The viewer borrows the app, so take the dock out while drawing.
+                let mut dock = std::mem::replace(&mut self.dock, DockState::new(Vec::new()));
+                DockArea::new(&mut dock)
+                    .style(egui_dock::Style::from_egui(ui.style().as_ref()))
+                    .show_inside(ui, &mut TileViewer { app: self });
+                self.dock = dock;
             }
-
-            // Advance parent cursor past all three columns
-            ui.advance_cursor_after_rect(Rect::from_min_max(
-                avail.min,
-                pos2(
-                    avail.min.x + io_width + gap + center_width + gap + io_width,
-                    avail.max.y,
-                ),
-            ));
         });
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        if !self.single_effect {
+            self.save_layout();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(autosave) = &mut self.autosave {
+            autosave.finish();
+        }
         self.stop_audio();
     }
 }
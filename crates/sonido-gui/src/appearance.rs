@@ -0,0 +1,271 @@
+//! Per-user appearance: color scheme and accent colors.
+//!
+//! [`Appearance`] builds the [`SonidoTheme`] palette: a [`ColorScheme`]
+//! (dark, light, high contrast) plus optional primary and secondary accent
+//! overrides. It persists as JSON in `appearance.json` under the user config
+//! directory (native only); missing fields keep their defaults.
+//! [`AppearanceEditor`] is the settings dialog.
+
+use egui::{Color32, Context};
+use serde::{Deserialize, Serialize};
+use sonido_gui_core::theme::{ColorScheme, SonidoTheme};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Appearance file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const APPEARANCE_FILE: &str = "appearance.json";
+
+/// Color scheme and accent overrides.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Appearance {
+    /// Base color scheme.
+    pub scheme: ColorScheme,
+    /// Primary accent (knob arcs, headings, panel borders); `None` = scheme default.
+    pub accent: Option<Color32>,
+    /// Secondary accent (parameter labels); `None` = scheme default.
+    pub secondary_accent: Option<Color32>,
+}
+
+impl Appearance {
+    /// Set the scheme and accents on `theme`, keeping its other settings.
+    pub fn apply_to(&self, theme: &mut SonidoTheme) {
+        theme.set_scheme(self.scheme);
+        theme.set_accents(self.accent, self.secondary_accent);
+    }
+
+    /// Serialize to the appearance JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&AppearanceFile::from_appearance(self))
+    }
+
+    /// Parse the appearance JSON format. Unknown schemes and unparseable
+    /// colors fall back to the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(serde_json::from_str::<AppearanceFile>(json)?.to_appearance())
+    }
+
+    /// Default appearance file path (`<config dir>/appearance.json`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn default_path() -> PathBuf {
+        sonido_config::paths::user_config_dir().join(APPEARANCE_FILE)
+    }
+
+    /// Load appearance from `path`, falling back to defaults if the file is
+    /// missing or unreadable.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_or_default(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                tracing::warn!(path = ?path, error = %e, "invalid appearance file, using defaults");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save appearance to `path`, creating the config directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or file I/O fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+/// On-disk form: scheme id and `#rrggbb` accents (empty = scheme default).
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct AppearanceFile {
+    scheme: String,
+    accent: String,
+    secondary_accent: String,
+}
+
+impl AppearanceFile {
+    fn from_appearance(appearance: &Appearance) -> Self {
+        let hex = |c: Option<Color32>| {
+            c.map(|c| format!("#{:02x}{:02x}{:02x}", c.r(), c.g(), c.b()))
+                .unwrap_or_default()
+        };
+        Self {
+            scheme: scheme_id(appearance.scheme).to_string(),
+            accent: hex(appearance.accent),
+            secondary_accent: hex(appearance.secondary_accent),
+        }
+    }
+
+    fn to_appearance(&self) -> Appearance {
+        let scheme = match self.scheme.as_str() {
+            "" | "dark" => ColorScheme::Dark,
+            "light" => ColorScheme::Light,
+            "high-contrast" => ColorScheme::HighContrast,
+            other => {
+                tracing::warn!(scheme = %other, "unknown color scheme, using dark");
+                ColorScheme::Dark
+            }
+        };
+        Appearance {
+            scheme,
+            accent: parse_color(&self.accent),
+            secondary_accent: parse_color(&self.secondary_accent),
+        }
+    }
+}
+
+/// Stable file identifier for a scheme.
+fn scheme_id(scheme: ColorScheme) -> &'static str {
+    match scheme {
+        ColorScheme::Dark => "dark",
+        ColorScheme::Light => "light",
+        ColorScheme::HighContrast => "high-contrast",
+    }
+}
+
+/// Parse an opaque `#rrggbb` color; empty means no override.
+fn parse_color(text: &str) -> Option<Color32> {
+    if text.is_empty() {
+        return None;
+    }
+    if let Ok(c) = Color32::from_hex(text) {
+        Some(Color32::from_rgb(c.r(), c.g(), c.b()))
+    } else {
+        tracing::warn!(color = %text, "unparseable accent color, using scheme default");
+        None
+    }
+}
+
+/// Appearance settings dialog.
+#[derive(Debug, Default)]
+pub struct AppearanceEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+}
+
+impl AppearanceEditor {
+    /// Draw the dialog. Returns `true` if `appearance` changed.
+    pub fn show(&mut self, ctx: &Context, appearance: &mut Appearance) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("Appearance")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("appearance")
+                    .num_columns(2)
+                    .spacing([24.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Scheme");
+                        ui.horizontal(|ui| {
+                            for scheme in ColorScheme::ALL {
+                                changed |= ui
+                                    .radio_value(&mut appearance.scheme, scheme, scheme.label())
+                                    .changed();
+                            }
+                        });
+                        ui.end_row();
+
+                        let defaults = appearance.scheme.colors();
+                        changed |= accent_row(ui, "Accent", &mut appearance.accent, defaults.amber);
+                        changed |= accent_row(
+                            ui,
+                            "Labels",
+                            &mut appearance.secondary_accent,
+                            defaults.cyan,
+                        );
+                    });
+
+                ui.add_space(4.0);
+                if ui.button("Reset to defaults").clicked() {
+                    *appearance = Appearance::default();
+                    changed = true;
+                }
+            });
+        self.open = open;
+        changed
+    }
+}
+
+/// One accent picker row with a reset button. Returns `true` if changed.
+fn accent_row(
+    ui: &mut egui::Ui,
+    label: &str,
+    accent: &mut Option<Color32>,
+    scheme_default: Color32,
+) -> bool {
+    let mut changed = false;
+    ui.label(label);
+    ui.horizontal(|ui| {
+        let mut color = accent.unwrap_or(scheme_default);
+        if ui.color_edit_button_srgba(&mut color).changed() {
+            *accent = Some(color);
+            changed = true;
+        }
+        if ui
+            .add_enabled(accent.is_some(), egui::Button::new("Default"))
+            .clicked()
+        {
+            *accent = None;
+            changed = true;
+        }
+    });
+    ui.end_row();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let appearance = Appearance {
+            scheme: ColorScheme::HighContrast,
+            accent: Some(Color32::from_rgb(10, 200, 120)),
+            secondary_accent: None,
+        };
+        let parsed = Appearance::from_json(&appearance.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, appearance);
+    }
+
+    #[test]
+    fn invalid_fields_fall_back_to_defaults() {
+        let parsed = Appearance::from_json(r#"{"scheme":"sepia","accent":"orange"}"#).unwrap();
+        assert_eq!(parsed, Appearance::default());
+        assert!(Appearance::from_json("[").is_err());
+    }
+
+    #[test]
+    fn apply_keeps_other_theme_settings() {
+        let mut theme = SonidoTheme::default();
+        theme.knob.sensitivity = 2.0;
+        let appearance = Appearance {
+            scheme: ColorScheme::Light,
+            accent: Some(Color32::from_rgb(1, 2, 3)),
+            secondary_accent: None,
+        };
+        appearance.apply_to(&mut theme);
+        assert_eq!(theme.scheme, ColorScheme::Light);
+        assert_eq!(theme.colors.amber, Color32::from_rgb(1, 2, 3));
+        assert_eq!(theme.knob.sensitivity, 2.0);
+    }
+}
@@ -7,13 +7,18 @@
 //! All hot-path reads go through [`ArcSwap::load`], which is wait-free.
 //! Structural mutations (add/remove slot, reorder) use RCU via
 //! [`ArcSwap::rcu`] — readers never block.
+//!
+//! The bridge also keeps the standalone undo history: `begin_set`/`end_set`
+//! feed an [`UndoHistory`], so one knob drag (or one paste) is one
+//! [`undo`](AtomicParamBridge::undo) step, matching what plugin hosts record.
 
 use arc_swap::ArcSwap;
-use sonido_core::ParamDescriptor;
-use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use sonido_core::param_map::map_params;
+use sonido_core::{ParamDescriptor, ParamFlags};
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex, UndoHistory};
 use sonido_registry::EffectRegistry;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// Per-slot parameter storage.
 ///
@@ -42,6 +47,14 @@ pub(crate) struct SharedAudioState {
     order: Vec<usize>,
 }
 
+/// Undo history tagged with the slot layout it was recorded against.
+#[derive(Default)]
+struct History {
+    undo: UndoHistory,
+    /// [`AtomicParamBridge::layouts`] value when `undo` was last used.
+    layout: u64,
+}
+
 /// Thread-safe parameter bridge for the standalone dashboard.
 ///
 /// Each slot corresponds to one effect in the processing chain, with
@@ -53,6 +66,13 @@ pub(crate) struct SharedAudioState {
 /// (`add_slot`, `remove_slot`) use RCU — readers never block.
 pub struct AtomicParamBridge {
     state: ArcSwap<SharedAudioState>,
+    /// Count of [`rebuild_from_manifest`](Self::rebuild_from_manifest) calls.
+    rebuilds: AtomicU64,
+    /// Bumped whenever slot indices change meaning (remove, rebuild).
+    layouts: AtomicU64,
+    /// Parameter undo history. GUI thread only; the audio thread bumps
+    /// `layouts` instead of locking, and the history clears on next use.
+    history: Mutex<History>,
 }
 
 impl AtomicParamBridge {
@@ -94,6 +114,9 @@ impl AtomicParamBridge {
 
         Self {
             state: ArcSwap::from_pointee(SharedAudioState { slots, order }),
+            rebuilds: AtomicU64::new(0),
+            layouts: AtomicU64::new(0),
+            history: Mutex::default(),
         }
     }
 
@@ -160,6 +183,7 @@ impl AtomicParamBridge {
             }
             Arc::new(SharedAudioState { slots, order })
         });
+        self.layouts.fetch_add(1, Ordering::Release);
     }
 
     /// Rebuilds all slots from a topology manifest.
@@ -167,24 +191,59 @@ impl AtomicParamBridge {
     /// Called on the audio thread when processing a `GraphCommand::ReplaceTopology`.
     /// Replaces the entire slot list atomically. Readers see the old snapshot
     /// until they reload.
+    ///
+    /// Slots start at their defaults, except the `(old_slot, new_slot)` pairs
+    /// in `carry`. A pair naming the same effect keeps the old slot's values;
+    /// a pair naming a different effect (the node's effect was replaced) takes
+    /// the compatible settings chosen by [`map_params`]. Both keep the old
+    /// slot's bypass state.
     pub(crate) fn rebuild_from_manifest(
         &self,
         effect_ids: &[&'static str],
         slot_descriptors: &[Vec<ParamDescriptor>],
+        carry: &[(usize, usize)],
     ) {
+        let old = self.state.load();
         let slots: Vec<Arc<SlotState>> = effect_ids
             .iter()
             .zip(slot_descriptors.iter())
-            .map(|(&id, descs)| {
-                let values = descs
+            .enumerate()
+            .map(|(slot, (&id, descs))| {
+                let prev = carry
                     .iter()
-                    .map(|d| AtomicU32::new(d.default.to_bits()))
-                    .collect();
+                    .find(|&&(_, to)| to == slot)
+                    .and_then(|&(from, _)| old.slots.get(from));
+                let values = match prev {
+                    Some(prev) if prev.effect_id == id && prev.values.len() == descs.len() => prev
+                        .values
+                        .iter()
+                        .map(|v| AtomicU32::new(v.load(Ordering::Relaxed)))
+                        .collect(),
+                    Some(prev) => {
+                        let old_values: Vec<f32> = prev
+                            .values
+                            .iter()
+                            .map(|v| f32::from_bits(v.load(Ordering::Relaxed)))
+                            .collect();
+                        map_params(&prev.descriptors, &old_values, descs)
+                            .into_iter()
+                            .zip(descs)
+                            .map(|(mapped, d)| {
+                                AtomicU32::new(mapped.unwrap_or(d.default).to_bits())
+                            })
+                            .collect()
+                    }
+                    None => descs
+                        .iter()
+                        .map(|d| AtomicU32::new(d.default.to_bits()))
+                        .collect(),
+                };
+                let bypassed = prev.is_some_and(|prev| prev.bypassed.load(Ordering::Relaxed));
                 Arc::new(SlotState {
                     effect_id: id,
                     values,
                     descriptors: descs.clone(),
-                    bypassed: AtomicBool::new(false),
+                    bypassed: AtomicBool::new(bypassed),
                     dirty: AtomicBool::new(true),
                 })
             })
@@ -192,6 +251,68 @@ impl AtomicParamBridge {
         let order: Vec<usize> = (0..slots.len()).collect();
         self.state
             .store(Arc::new(SharedAudioState { slots, order }));
+        self.rebuilds.fetch_add(1, Ordering::Release);
+        self.layouts.fetch_add(1, Ordering::Release);
+    }
+
+    /// Number of topology rebuilds so far.
+    ///
+    /// A rebuild resets every slot to its defaults; compare counts to tell
+    /// whether a `ReplaceTopology` has reached the audio thread yet.
+    pub fn rebuild_count(&self) -> u64 {
+        self.rebuilds.load(Ordering::Acquire)
+    }
+
+    // ── Undo ──────────────────────────────────────────────────────────────
+
+    /// Lock the undo history, clearing it if the slot layout has changed
+    /// since it was recorded.
+    fn history(&self) -> MutexGuard<'_, History> {
+        let mut history = self.history.lock().unwrap_or_else(PoisonError::into_inner);
+        let layout = self.layouts.load(Ordering::Acquire);
+        if history.layout != layout {
+            history.undo.clear();
+            history.layout = layout;
+        }
+        history
+    }
+
+    /// Revert the last parameter gesture. Returns `false` if there is
+    /// nothing to undo or a gesture is still in progress.
+    pub fn undo(&self) -> bool {
+        let group = {
+            let mut history = self.history();
+            if history.undo.gesture_active() {
+                return false;
+            }
+            history.undo.undo()
+        };
+        let Some(group) = group else {
+            return false;
+        };
+        for m in group.iter().rev() {
+            self.set(SlotIndex(m.slot), ParamIndex(m.param), m.old);
+        }
+        true
+    }
+
+    /// Reapply the last undone parameter gesture. Returns `false` if there
+    /// is nothing to redo or a gesture is still in progress.
+    pub fn redo(&self) -> bool {
+        let group = {
+            let mut history = self.history();
+            if history.undo.gesture_active() {
+                return false;
+            }
+            history.undo.redo()
+        };
+        let Some(group) = group else {
+            return false;
+        };
+        for m in &group {
+            self.set(SlotIndex(m.slot), ParamIndex(m.param), m.new);
+        }
+        true
     }
 
     // ── Order operations ──────────────────────────────────────────────────
@@ -218,6 +339,29 @@ impl AtomicParamBridge {
             .map(|&i| snap.slots[i].bypassed.load(Ordering::Acquire))
             .collect()
     }
+
+    /// Store the values an effect reports for its READ_ONLY params in `slot`.
+    ///
+    /// `read(param)` returns the effect's current value, or `None` to leave
+    /// the bridge value unchanged. Called on the audio thread after
+    /// processing; does not mark the slot dirty.
+    pub(crate) fn publish_read_only(
+        &self,
+        slot: SlotIndex,
+        mut read: impl FnMut(usize) -> Option<f32>,
+    ) {
+        let snap = self.state.load();
+        let Some(s) = snap.slots.get(slot.0) else {
+            return;
+        };
+        for (i, (atomic, desc)) in s.values.iter().zip(&s.descriptors).enumerate() {
+            if desc.flags.contains(ParamFlags::READ_ONLY)
+                && let Some(value) = read(i)
+            {
+                atomic.store(value.to_bits(), Ordering::Release);
+            }
+        }
+    }
 }
 
 impl ParamBridge for AtomicParamBridge {
@@ -286,6 +430,20 @@ impl ParamBridge for AtomicParamBridge {
             s.bypassed.store(bypassed, Ordering::Release);
         }
     }
+
+    fn begin_set(&self, slot: SlotIndex, param: ParamIndex) {
+        if param.0 < self.param_count(slot) {
+            let value = self.get(slot, param);
+            self.history().undo.begin_param(slot.0, param.0, value);
+        }
+    }
+
+    fn end_set(&self, slot: SlotIndex, param: ParamIndex) {
+        if param.0 < self.param_count(slot) {
+            let value = self.get(slot, param);
+            self.history().undo.end_param(slot.0, param.0, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -337,6 +495,116 @@ mod tests {
         assert!(bridge.is_bypassed(SlotIndex(1)));
     }
 
+    #[test]
+    fn rebuild_keeps_carried_slots() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+        bridge.set(SlotIndex(0), ParamIndex(0), 12.0);
+        bridge.set_bypassed(SlotIndex(1), true);
+
+        let ids = ["reverb", "distortion", "chorus"];
+        let fresh = AtomicParamBridge::new(&registry, &ids, 48000.0);
+        let descriptors: Vec<Vec<_>> = (0..ids.len())
+            .map(|s| {
+                (0..fresh.param_count(SlotIndex(s)))
+                    .filter_map(|p| fresh.param_descriptor(SlotIndex(s), ParamIndex(p)))
+                    .collect()
+            })
+            .collect();
+        // (0, 2) names a different effect: chorus Rate has no counterpart.
+        bridge.rebuild_from_manifest(&ids, &descriptors, &[(1, 0), (0, 1), (0, 2)]);
+
+        assert!(bridge.is_bypassed(SlotIndex(0)));
+        assert_eq!(bridge.get(SlotIndex(1), ParamIndex(0)), 12.0);
+        assert!(!bridge.is_bypassed(SlotIndex(1)));
+        assert_eq!(
+            bridge.get(SlotIndex(2), ParamIndex(0)),
+            fresh.get(SlotIndex(2), ParamIndex(0))
+        );
+    }
+
+    #[test]
+    fn publish_read_only_skips_writable_params() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["gate"], 48000.0);
+        let slot = SlotIndex(0);
+        // Gate: 0 = threshold (writable), 14 = learned threshold (read-only).
+        bridge.set(slot, ParamIndex(0), -30.0);
+        bridge.publish_read_only(slot, |p| Some(if p == 14 { -52.0 } else { -10.0 }));
+        assert_eq!(bridge.get(slot, ParamIndex(0)), -30.0);
+        assert_eq!(bridge.get(slot, ParamIndex(14)), -52.0);
+    }
+
+    #[test]
+    fn rebuild_maps_replaced_effect_params() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion"], 48000.0);
+        bridge.set(SlotIndex(0), ParamIndex(0), 30.0); // Drive
+        bridge.set(SlotIndex(0), ParamIndex(2), -2.0); // Output
+        bridge.set_bypassed(SlotIndex(0), true);
+
+        let fresh = AtomicParamBridge::new(&registry, &["tape"], 48000.0);
+        let descriptors: Vec<ParamDescriptor> = (0..fresh.param_count(SlotIndex(0)))
+            .filter_map(|p| fresh.param_descriptor(SlotIndex(0), ParamIndex(p)))
+            .collect();
+        let param =
+            |name: &str| ParamIndex(descriptors.iter().position(|d| d.name == name).unwrap());
+        bridge.rebuild_from_manifest(&["tape"], &[descriptors.clone()], &[(0, 0)]);
+
+        // Drive clamps to tape's 24 dB ceiling; Output carries as-is.
+        assert_eq!(bridge.get(SlotIndex(0), param("Drive")), 24.0);
+        assert_eq!(bridge.get(SlotIndex(0), param("Output")), -2.0);
+        let wow = param("Wow");
+        assert_eq!(bridge.get(SlotIndex(0), wow), descriptors[wow.0].default);
+        assert!(bridge.is_bypassed(SlotIndex(0)));
+    }
+
+    #[test]
+    fn gesture_is_one_undo_step() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion"], 48000.0);
+        let (slot, drive, tone) = (SlotIndex(0), ParamIndex(0), ParamIndex(1));
+        let start = (bridge.get(slot, drive), bridge.get(slot, tone));
+
+        // A drag: many values, one gesture.
+        bridge.begin_set(slot, drive);
+        for value in [5.0, 10.0, 15.0] {
+            bridge.set(slot, drive, value);
+        }
+        assert!(!bridge.undo(), "no undo mid-gesture");
+        bridge.end_set(slot, drive);
+
+        // A paste: overlapping gestures on two params.
+        sonido_gui_core::set_grouped(&bridge, &[(slot, drive, 20.0), (slot, tone, 1000.0)]);
+        let pasted = (bridge.get(slot, drive), bridge.get(slot, tone));
+
+        assert!(bridge.undo());
+        assert_eq!(
+            (bridge.get(slot, drive), bridge.get(slot, tone)),
+            (15.0, start.1)
+        );
+        assert!(bridge.undo());
+        assert_eq!((bridge.get(slot, drive), bridge.get(slot, tone)), start);
+        assert!(!bridge.undo());
+
+        assert!(bridge.redo());
+        assert!(bridge.redo());
+        assert_eq!((bridge.get(slot, drive), bridge.get(slot, tone)), pasted);
+        assert!(!bridge.redo());
+    }
+
+    #[test]
+    fn layout_change_clears_undo() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+        bridge.begin_set(SlotIndex(1), ParamIndex(0));
+        bridge.set(SlotIndex(1), ParamIndex(0), 0.9);
+        bridge.end_set(SlotIndex(1), ParamIndex(0));
+
+        bridge.remove_slot(SlotIndex(0));
+        assert!(!bridge.undo());
+    }
+
     #[test]
     fn out_of_range_safe() {
         let registry = EffectRegistry::new();
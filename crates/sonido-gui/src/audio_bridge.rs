@@ -1,15 +1,79 @@
 //! Lock-free audio↔GUI communication.
 //!
-//! Provides metering data transport, transport state (running flag), and
+//! Provides metering data transport, the analyzer sample tap, transport state
+//! (running flag), input routing, the A/B rig morph and output mode, and
 //! standalone input/master gain controls. Per-effect parameter sharing is
 //! handled by [`AtomicParamBridge`](super::atomic_param_bridge) — this module
-//! only owns the two global gain knobs that live outside the effect chain.
+//! only owns the global controls that live outside the effect chain.
 
 use crate::chain_manager::GraphCommand;
 use crate::file_player::TransportCommand;
 use crossbeam_channel::{Receiver, Sender, bounded, unbounded};
+use sonido_core::gain::InputRouting;
+use sonido_core::graph::RigOutput;
+use sonido_core::safety_limiter::{DEFAULT_CEILING_DB, MIN_CEILING_DB};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicUsize, Ordering};
+
+/// Levels measured by one input-calibration window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrimReading {
+    /// Peak level of the pre-gain input in dBFS.
+    pub peak_db: f32,
+    /// RMS level of the pre-gain input in dBFS.
+    pub rms_db: f32,
+    /// Input gain in dB that puts the peak at the auto-trim target.
+    pub gain_db: f32,
+}
+
+/// Input-calibration results, written by the audio thread.
+///
+/// While listening, the audio processor publishes the window's progress; when
+/// the window completes with signal present it publishes a [`TrimReading`],
+/// which the GUI [takes](Self::take) once. Nothing is applied to the input
+/// gain until the user accepts the suggestion.
+#[derive(Debug, Default)]
+pub struct TrimMeasurement {
+    progress: AtomicU32,
+    peak_db: AtomicU32,
+    rms_db: AtomicU32,
+    gain_db: AtomicU32,
+    ready: AtomicBool,
+}
+
+impl TrimMeasurement {
+    /// Record how much of the listening window has elapsed (audio thread).
+    pub fn set_progress(&self, progress: f32) {
+        self.progress.store(progress.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Fraction of the listening window elapsed, in `[0, 1]`.
+    pub fn progress(&self) -> f32 {
+        f32::from_bits(self.progress.load(Ordering::Relaxed))
+    }
+
+    /// Publish a finished measurement (audio thread).
+    pub fn publish(&self, reading: TrimReading) {
+        self.peak_db
+            .store(reading.peak_db.to_bits(), Ordering::Relaxed);
+        self.rms_db
+            .store(reading.rms_db.to_bits(), Ordering::Relaxed);
+        self.gain_db
+            .store(reading.gain_db.to_bits(), Ordering::Relaxed);
+        self.ready.store(true, Ordering::Release);
+    }
+
+    /// Take the latest finished measurement, if one arrived since the last call.
+    pub fn take(&self) -> Option<TrimReading> {
+        self.ready
+            .swap(false, Ordering::Acquire)
+            .then(|| TrimReading {
+                peak_db: f32::from_bits(self.peak_db.load(Ordering::Relaxed)),
+                rms_db: f32::from_bits(self.rms_db.load(Ordering::Relaxed)),
+                gain_db: f32::from_bits(self.gain_db.load(Ordering::Relaxed)),
+            })
+    }
+}
 
 /// A thread-safe atomic parameter using bit-cast f32.
 ///
@@ -78,6 +142,43 @@ impl Clone for AtomicParam {
     }
 }
 
+/// The input routing mode, shared lock-free between GUI and audio thread.
+///
+/// GUI thread writes, audio thread reads once per buffer.
+#[derive(Debug, Default)]
+pub struct SharedInputRouting(AtomicU8);
+
+impl SharedInputRouting {
+    /// Select the routing mode (GUI thread).
+    pub fn set(&self, routing: InputRouting) {
+        self.0.store(routing.index(), Ordering::Release);
+    }
+
+    /// Current routing mode (audio thread).
+    pub fn get(&self) -> InputRouting {
+        InputRouting::from_index(self.0.load(Ordering::Acquire))
+    }
+}
+
+/// How rig A and rig B reach the output, shared lock-free between GUI and
+/// audio thread.
+///
+/// GUI thread writes, audio thread reads once per buffer.
+#[derive(Debug, Default)]
+pub struct SharedRigOutput(AtomicU8);
+
+impl SharedRigOutput {
+    /// Select the output mode (GUI thread).
+    pub fn set(&self, output: RigOutput) {
+        self.0.store(output.index(), Ordering::Release);
+    }
+
+    /// Current output mode (audio thread).
+    pub fn get(&self) -> RigOutput {
+        RigOutput::from_index(self.0.load(Ordering::Acquire))
+    }
+}
+
 /// Metering data sent from audio thread to GUI.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct MeteringData {
@@ -89,7 +190,7 @@ pub struct MeteringData {
     pub output_peak: f32,
     /// Output signal RMS level (linear).
     pub output_rms: f32,
-    /// Compressor gain reduction in dB.
+    /// Output safety limiter peak gain reduction in dB (0 when not limiting).
     pub gain_reduction: f32,
     /// Audio thread CPU usage (0.0 to 100.0).
     pub cpu_usage: f32,
@@ -97,10 +198,82 @@ pub struct MeteringData {
     pub playback_position_secs: f32,
 }
 
+/// Ring of recent output samples for the analyzer (audio thread → GUI).
+///
+/// The audio thread pushes the stereo output; the GUI copies out the newest
+/// samples each frame. Single writer, lock-free, no allocation after
+/// construction. A read that races a write may see a few samples from the
+/// next block, which is harmless for display.
+#[derive(Debug)]
+pub struct AnalyzerTap {
+    left: Box<[AtomicU32]>,
+    right: Box<[AtomicU32]>,
+    /// Total samples written; the next write goes to `written % CAPACITY`.
+    written: AtomicUsize,
+}
+
+impl AnalyzerTap {
+    /// Samples kept in the ring (power of two).
+    pub const CAPACITY: usize = 8192;
+
+    /// Create an empty tap.
+    pub fn new() -> Self {
+        Self {
+            left: (0..Self::CAPACITY).map(|_| AtomicU32::new(0)).collect(),
+            right: (0..Self::CAPACITY).map(|_| AtomicU32::new(0)).collect(),
+            written: AtomicUsize::new(0),
+        }
+    }
+
+    /// Append a stereo block (audio thread only).
+    ///
+    /// Channels are paired up to the shorter length.
+    pub fn push(&self, left: &[f32], right: &[f32]) {
+        let start = self.written.load(Ordering::Relaxed);
+        let len = left.len().min(right.len());
+        for (i, (&l, &r)) in left.iter().zip(right).enumerate() {
+            let slot = (start + i) & (Self::CAPACITY - 1);
+            self.left[slot].store(l.to_bits(), Ordering::Relaxed);
+            self.right[slot].store(r.to_bits(), Ordering::Relaxed);
+        }
+        self.written
+            .store(start.wrapping_add(len), Ordering::Release);
+    }
+
+    /// Total samples written so far (wraps on overflow).
+    pub fn written(&self) -> usize {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Copy the newest samples into `left` and `right`, oldest first.
+    ///
+    /// Copies the shorter buffer's length, limited to
+    /// [`CAPACITY`](Self::CAPACITY) samples; slots not yet written read as
+    /// silence.
+    pub fn copy_latest(&self, left: &mut [f32], right: &mut [f32]) {
+        let len = left.len().min(right.len()).min(Self::CAPACITY);
+        let end = self.written();
+        let start = end.wrapping_sub(len);
+        for (i, (l, r)) in left[..len].iter_mut().zip(&mut right[..len]).enumerate() {
+            let slot = start.wrapping_add(i) & (Self::CAPACITY - 1);
+            *l = f32::from_bits(self.left[slot].load(Ordering::Relaxed));
+            *r = f32::from_bits(self.right[slot].load(Ordering::Relaxed));
+        }
+    }
+}
+
+impl Default for AnalyzerTap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Audio bridge for communication between GUI and audio threads.
 ///
-/// Owns the two global gain controls (input gain, master volume) that sit
-/// outside the per-effect parameter system, plus metering transport,
+/// Owns the two global gain controls (input gain, master volume), the
+/// input routing, and the A/B rig controls that sit outside the per-effect
+/// parameter system, plus
+/// metering transport,
 /// the running flag, and a command channel for dynamic chain mutations.
 /// Buffer size selection lives in [`SonidoApp`](super::app::SonidoApp).
 #[derive(Debug)]
@@ -109,6 +282,12 @@ pub struct AudioBridge {
     input_gain: Arc<AtomicParam>,
     /// Master volume control (-40 to +6 dB)
     master_volume: Arc<AtomicParam>,
+    /// Which input channels feed the chain
+    input_routing: Arc<SharedInputRouting>,
+    /// A/B rig morph (0 = rig A, 1 = rig B); ignored without rig B
+    rig_morph: Arc<AtomicParam>,
+    /// How the two rigs are combined
+    rig_output: Arc<SharedRigOutput>,
     /// Audio processing running flag
     running: Arc<AtomicBool>,
     /// Sender for metering data (audio thread → GUI)
@@ -125,8 +304,20 @@ pub struct AudioBridge {
     transport_rx: Receiver<TransportCommand>,
     /// Global chain bypass flag
     chain_bypass: Arc<AtomicBool>,
+    /// Safe mode flag (latency-compensated dry path, feedback muted)
+    safe_mode: Arc<AtomicBool>,
+    /// Output safety limiter flag (on by default)
+    output_limiter: Arc<AtomicBool>,
+    /// Output safety limiter ceiling (-24 to 0 dBFS)
+    limiter_ceiling: Arc<AtomicParam>,
+    /// Input auto-trim request (GUI sets, audio thread clears when done)
+    auto_trim: Arc<AtomicBool>,
+    /// Input-calibration progress and results (audio thread → GUI)
+    trim_measurement: Arc<TrimMeasurement>,
     /// Audio stream error counter
     error_count: Arc<AtomicU32>,
+    /// Output samples for the analyzer
+    analyzer_tap: Arc<AnalyzerTap>,
 }
 
 impl AudioBridge {
@@ -138,6 +329,9 @@ impl AudioBridge {
         Self {
             input_gain: Arc::new(AtomicParam::new(0.0, -20.0, 20.0)),
             master_volume: Arc::new(AtomicParam::new(0.0, -40.0, 6.0)),
+            input_routing: Arc::new(SharedInputRouting::default()),
+            rig_morph: Arc::new(AtomicParam::new(0.0, 0.0, 1.0)),
+            rig_output: Arc::new(SharedRigOutput::default()),
             running: Arc::new(AtomicBool::new(false)),
             metering_tx,
             metering_rx,
@@ -146,7 +340,13 @@ impl AudioBridge {
             transport_tx,
             transport_rx,
             chain_bypass: Arc::new(AtomicBool::new(false)),
+            safe_mode: Arc::new(AtomicBool::new(false)),
+            output_limiter: Arc::new(AtomicBool::new(true)),
+            limiter_ceiling: Arc::new(AtomicParam::new(DEFAULT_CEILING_DB, MIN_CEILING_DB, 0.0)),
+            auto_trim: Arc::new(AtomicBool::new(false)),
+            trim_measurement: Arc::new(TrimMeasurement::default()),
             error_count: Arc::new(AtomicU32::new(0)),
+            analyzer_tap: Arc::new(AnalyzerTap::new()),
         }
     }
 
@@ -160,6 +360,27 @@ impl AudioBridge {
         Arc::clone(&self.master_volume)
     }
 
+    /// Get the input routing control.
+    ///
+    /// The audio processor applies the routing to the raw input, ahead of
+    /// auto-trim and input gain.
+    pub fn input_routing(&self) -> Arc<SharedInputRouting> {
+        Arc::clone(&self.input_routing)
+    }
+
+    /// Get the A/B rig morph control (0 = rig A, 1 = rig B).
+    ///
+    /// The audio processor hands it to a dual-rig engine each buffer, which
+    /// smooths the change, so a toggle between 0 and 1 is a short crossfade.
+    pub fn rig_morph(&self) -> Arc<AtomicParam> {
+        Arc::clone(&self.rig_morph)
+    }
+
+    /// Get the rig output mode (blend or split).
+    pub fn rig_output(&self) -> Arc<SharedRigOutput> {
+        Arc::clone(&self.rig_output)
+    }
+
     /// Get the running flag.
     pub fn running(&self) -> Arc<AtomicBool> {
         Arc::clone(&self.running)
@@ -230,6 +451,44 @@ impl AudioBridge {
         Arc::clone(&self.chain_bypass)
     }
 
+    /// Get the safe mode flag.
+    ///
+    /// When true, the engine crossfades to the dry input, delayed by the
+    /// chain latency, and mutes every feedback-capable effect (see
+    /// [`GraphEngine::set_safe_mode`](sonido_core::graph::GraphEngine::set_safe_mode)).
+    pub fn safe_mode(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.safe_mode)
+    }
+
+    /// Get the output safety limiter flag.
+    ///
+    /// When true (the default), the engine brickwall-limits its output to the
+    /// [ceiling](Self::limiter_ceiling) after every effect, whatever the chain
+    /// or preset (see
+    /// [`GraphEngine::set_output_limiter`](sonido_core::graph::GraphEngine::set_output_limiter)).
+    pub fn output_limiter(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.output_limiter)
+    }
+
+    /// Get the output safety limiter ceiling in dBFS.
+    pub fn limiter_ceiling(&self) -> Arc<AtomicParam> {
+        Arc::clone(&self.limiter_ceiling)
+    }
+
+    /// Get the input auto-trim request flag.
+    ///
+    /// The GUI sets this to start listening (clearing it cancels); the audio
+    /// processor measures the input for a few seconds, publishes the result to
+    /// [`trim_measurement`](Self::trim_measurement), and clears the flag.
+    pub fn auto_trim(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.auto_trim)
+    }
+
+    /// Get the input-calibration progress and results.
+    pub fn trim_measurement(&self) -> Arc<TrimMeasurement> {
+        Arc::clone(&self.trim_measurement)
+    }
+
     /// Get the cumulative audio stream error count.
     ///
     /// Incremented by cpal error callbacks on both input and output streams.
@@ -237,6 +496,14 @@ impl AudioBridge {
     pub fn error_count(&self) -> Arc<AtomicU32> {
         Arc::clone(&self.error_count)
     }
+
+    /// Get the analyzer tap.
+    ///
+    /// The audio processor pushes every output block; the analyzer tile
+    /// reads the newest samples for its spectrum and scope.
+    pub fn analyzer_tap(&self) -> Arc<AnalyzerTap> {
+        Arc::clone(&self.analyzer_tap)
+    }
 }
 
 impl Default for AudioBridge {
@@ -351,4 +618,71 @@ mod tests {
         master.set(-10.0);
         assert_eq!(bridge.master_volume().get(), -10.0);
     }
+
+    #[test]
+    fn test_audio_bridge_input_routing_shared() {
+        let bridge = AudioBridge::new();
+        let routing = bridge.input_routing();
+        assert_eq!(routing.get(), InputRouting::Stereo);
+        routing.set(InputRouting::Right);
+        assert_eq!(bridge.input_routing().get(), InputRouting::Right);
+    }
+
+    #[test]
+    fn test_audio_bridge_rig_controls_shared() {
+        let bridge = AudioBridge::new();
+        assert_eq!(bridge.rig_morph().get(), 0.0);
+        bridge.rig_morph().set(1.0);
+        assert_eq!(bridge.rig_morph().get(), 1.0);
+        assert_eq!(bridge.rig_output().get(), RigOutput::Blend);
+        bridge.rig_output().set(RigOutput::Split);
+        assert_eq!(bridge.rig_output().get(), RigOutput::Split);
+    }
+
+    #[test]
+    fn test_audio_bridge_auto_trim_flag_shared() {
+        let bridge = AudioBridge::new();
+        let flag = bridge.auto_trim();
+        assert!(!flag.load(Ordering::Acquire));
+        flag.store(true, Ordering::Release);
+        assert!(bridge.auto_trim().load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_trim_measurement_taken_once() {
+        let bridge = AudioBridge::new();
+        let measurement = bridge.trim_measurement();
+        assert_eq!(measurement.take(), None);
+
+        let reading = TrimReading {
+            peak_db: -6.0,
+            rms_db: -18.0,
+            gain_db: -6.0,
+        };
+        measurement.set_progress(1.0);
+        measurement.publish(reading);
+        assert_eq!(bridge.trim_measurement().progress(), 1.0);
+        assert_eq!(bridge.trim_measurement().take(), Some(reading));
+        assert_eq!(measurement.take(), None);
+    }
+
+    #[test]
+    fn test_analyzer_tap_wraps_and_orders() {
+        let tap = AnalyzerTap::new();
+        let mut left = [1.0f32; 4];
+        let mut right = [1.0f32; 4];
+        tap.copy_latest(&mut left, &mut right);
+        assert_eq!(left, [0.0; 4]);
+        assert_eq!(right, [0.0; 4]);
+
+        let block: Vec<f32> = (0..AnalyzerTap::CAPACITY + 3).map(|i| i as f32).collect();
+        let inverted: Vec<f32> = block.iter().map(|s| -s).collect();
+        tap.push(&block, &inverted);
+        assert_eq!(tap.written(), block.len());
+
+        tap.copy_latest(&mut left, &mut right);
+        let n = block.len() as f32;
+        assert_eq!(left, [n - 4.0, n - 3.0, n - 2.0, n - 1.0]);
+        assert_eq!(right, [4.0 - n, 3.0 - n, 2.0 - n, 1.0 - n]);
+    }
 }
@@ -10,11 +10,15 @@
 //! playback — there is no microphone input stream.
 
 use crate::atomic_param_bridge::AtomicParamBridge;
-use crate::audio_bridge::{AtomicParam, MeteringData};
+use crate::audio_bridge::{
+    AnalyzerTap, AtomicParam, MeteringData, SharedInputRouting, SharedRigOutput, TrimMeasurement,
+    TrimReading,
+};
 use crate::chain_manager::GraphCommand;
 use crate::file_player::TransportCommand;
 use crate::signal_generator::{SignalGenerator, SourceMode};
 use crossbeam_channel::{Receiver, Sender};
+use sonido_core::gain::AutoTrim;
 use sonido_core::graph::GraphEngine;
 use sonido_gui_core::{ParamBridge, SlotIndex};
 use sonido_registry::EffectRegistry;
@@ -29,7 +33,8 @@ use web_time::Instant;
 /// File playback state owned by [`AudioProcessor`].
 ///
 /// Manages in-memory audio buffers (left/right channels) and playback position.
-/// Supports looping and file-mode switching from the GUI transport controls.
+/// Supports looping (the whole file, or a loop region stored in it) and
+/// file-mode switching from the GUI transport controls.
 pub(crate) struct FilePlayback {
     left: Vec<f32>,
     right: Vec<f32>,
@@ -37,6 +42,8 @@ pub(crate) struct FilePlayback {
     file_sample_rate: f32,
     playing: bool,
     looping: bool,
+    /// Frames `start..end` looped instead of the whole file.
+    loop_region: Option<(usize, usize)>,
 }
 
 impl FilePlayback {
@@ -48,6 +55,7 @@ impl FilePlayback {
             file_sample_rate: 48000.0,
             playing: false,
             looping: false,
+            loop_region: None,
         }
     }
 
@@ -56,6 +64,12 @@ impl FilePlayback {
         if self.left.is_empty() || !self.playing {
             return (0.0, 0.0);
         }
+        if self.looping
+            && let Some((start, end)) = self.loop_region
+            && self.position == end
+        {
+            self.position = start;
+        }
         if self.position >= self.left.len() {
             if self.looping {
                 self.position = 0;
@@ -94,11 +108,33 @@ pub(crate) struct AudioProcessor {
     bridge: Arc<AtomicParamBridge>,
     input_gain: Arc<AtomicParam>,
     master_volume: Arc<AtomicParam>,
+    /// Input channel routing, applied before auto-trim and input gain.
+    input_routing: Arc<SharedInputRouting>,
+    /// A/B rig morph, handed to a dual-rig engine each buffer.
+    rig_morph: Arc<AtomicParam>,
+    /// How a dual-rig engine combines its rigs.
+    rig_output: Arc<SharedRigOutput>,
     chain_bypass: Arc<AtomicBool>,
     bypass_fade: sonido_core::SmoothedParam,
+    /// Safe mode flag, handed to the engine each buffer.
+    safe_mode: Arc<AtomicBool>,
+    /// Output safety limiter flag and ceiling, handed to the engine each buffer.
+    output_limiter: Arc<AtomicBool>,
+    limiter_ceiling: Arc<AtomicParam>,
+    /// Input level meter for the calibration wizard.
+    auto_trim: AutoTrim,
+    /// Auto-trim request flag shared with the GUI.
+    auto_trim_request: Arc<AtomicBool>,
+    /// Calibration progress and results published to the GUI.
+    trim_measurement: Arc<TrimMeasurement>,
     command_rx: Receiver<GraphCommand>,
     transport_rx: Receiver<TransportCommand>,
     metering_tx: Sender<MeteringData>,
+    /// Output samples for the analyzer tile.
+    analyzer_tap: Arc<AnalyzerTap>,
+    /// Stereo output of the current block, handed to `analyzer_tap`.
+    analyzer_left: Vec<f32>,
+    analyzer_right: Vec<f32>,
     file_pb: FilePlayback,
     /// Built-in signal generator (sine, sweep, noise, etc.).
     signal_gen: SignalGenerator,
@@ -109,6 +145,35 @@ pub(crate) struct AudioProcessor {
 }
 
 impl AudioProcessor {
+    /// Drive the input auto-trim state machine for one pre-gain buffer.
+    ///
+    /// Starts listening when the GUI raises the request flag. When the
+    /// listening window ends, publishes the measured levels and suggested
+    /// gain (unless the input stayed silent) and clears the flag. The GUI
+    /// decides whether to apply the gain.
+    fn update_auto_trim(&mut self, left: &[f32], right: &[f32]) {
+        if !self.auto_trim_request.load(Ordering::Acquire) {
+            self.auto_trim.cancel();
+            return;
+        }
+        if !self.auto_trim.is_active() {
+            self.auto_trim.start();
+        }
+        let result = self.auto_trim.process_block(left, right);
+        self.trim_measurement
+            .set_progress(self.auto_trim.progress());
+        if let Some(gain_db) = result {
+            self.trim_measurement.publish(TrimReading {
+                peak_db: self.auto_trim.peak_db(),
+                rms_db: self.auto_trim.rms_db(),
+                gain_db,
+            });
+        }
+        if !self.auto_trim.is_active() {
+            self.auto_trim_request.store(false, Ordering::Release);
+        }
+    }
+
     /// Sync bridge parameter values into the graph's effect nodes.
     ///
     /// Called once per audio buffer. Reads atomic values from the bridge and
@@ -139,6 +204,20 @@ impl AudioProcessor {
         }
     }
 
+    /// Copy READ_ONLY parameter values from the graph's effects into the bridge.
+    ///
+    /// Called after processing so panels can show values the effects report
+    /// (gate learn results, gain reduction). User-settable params are left to
+    /// [`sync_bridge_to_graph`](Self::sync_bridge_to_graph).
+    fn sync_graph_to_bridge(&self) {
+        let slot_count = self.bridge.slot_count().min(self.graph.slot_count());
+        for slot_raw in 0..slot_count {
+            self.bridge.publish_read_only(SlotIndex(slot_raw), |param| {
+                self.graph.get_param_at(slot_raw, param)
+            });
+        }
+    }
+
     /// Process one output buffer: drain commands, sync params, run effects,
     /// apply gain, write interleaved output, and send metering.
     pub(crate) fn process_buffer(&mut self, data: &mut [f32]) {
@@ -157,6 +236,7 @@ impl AudioProcessor {
                     self.file_pb.file_sample_rate = sr;
                     self.file_pb.position = 0;
                     self.file_pb.playing = false;
+                    self.file_pb.loop_region = None;
                 }
                 TransportCommand::UnloadFile => {
                     self.file_pb.left.clear();
@@ -186,6 +266,11 @@ impl AudioProcessor {
                     }
                 }
                 TransportCommand::SetLoop(v) => self.file_pb.looping = v,
+                TransportCommand::SetLoopRegion(region) => {
+                    let len = self.file_pb.left.len();
+                    self.file_pb.loop_region =
+                        region.filter(|&(start, end)| start < end && end <= len);
+                }
                 TransportCommand::SetSourceMode(mode) => self.source_mode = mode,
                 TransportCommand::SetSignalType(t) => self.signal_gen.set_signal_type(t),
                 TransportCommand::SetGeneratorFreq(hz) => self.signal_gen.set_frequency(hz),
@@ -223,26 +308,57 @@ impl AudioProcessor {
                         tracing::info!(slot = slot.0, "effect removed from graph");
                     }
                 }
+                GraphCommand::SetBypassMode { slot, mode } => {
+                    self.graph.set_bypass_mode_at(slot.0, mode);
+                    tracing::info!(slot = slot.0, mode = mode.label(), "bypass mode changed");
+                }
+                GraphCommand::ReplaceEffect { slot, effect } => {
+                    if self.graph.replace_at(slot.0, effect) {
+                        tracing::info!(
+                            slot = slot.0,
+                            "effect replaced, previous instance spilling over"
+                        );
+                    }
+                }
+                GraphCommand::Panic => {
+                    self.graph.panic();
+                    tracing::info!("panic reset");
+                }
                 GraphCommand::ReplaceTopology {
-                    engine,
+                    mut engine,
                     effect_ids,
                     slot_descriptors,
+                    carry,
                 } => {
+                    let carried = engine.adopt_effects(&mut self.graph, &carry);
+                    // Start the new engine where the running one's morph is,
+                    // rather than fading in from rig A.
+                    engine.set_rig_morph_immediate(self.rig_morph.get());
                     self.bridge
-                        .rebuild_from_manifest(&effect_ids, &slot_descriptors);
+                        .rebuild_from_manifest(&effect_ids, &slot_descriptors, &carry);
                     self.graph = *engine;
                     tracing::info!(
                         effects = effect_ids.len(),
+                        carried,
                         "topology replaced via ReplaceTopology"
                     );
                 }
+                GraphCommand::CrossfadeTo {
+                    mut engine,
+                    transition,
+                } => {
+                    engine.set_rig_morph_immediate(self.rig_morph.get());
+                    std::mem::swap(&mut self.graph, &mut *engine);
+                    self.graph.crossfade_from(engine, transition);
+                    tracing::info!(
+                        time_ms = transition.time_ms,
+                        tails = transition.tails.label(),
+                        "crossfading to new engine"
+                    );
+                }
             }
         }
 
-        // Global gain levels
-        let ig = sonido_core::db_to_linear(self.input_gain.get());
-        let mv = sonido_core::db_to_linear(self.master_volume.get());
-
         // Sync bridge -> graph effect parameters and bypass states
         self.sync_bridge_to_graph();
 
@@ -256,10 +372,6 @@ impl AudioProcessor {
         match self.source_mode {
             SourceMode::Generator => {
                 self.signal_gen.generate(&mut raw_left, &mut raw_right);
-                for (l, r) in raw_left.iter_mut().zip(raw_right.iter_mut()) {
-                    *l *= ig;
-                    *r *= ig;
-                }
             }
             SourceMode::File => {
                 for i in 0..frames {
@@ -268,14 +380,28 @@ impl AudioProcessor {
                     } else {
                         (0.0, 0.0)
                     };
-                    let in_l = if in_l_raw.is_finite() { in_l_raw } else { 0.0 };
-                    let in_r = if in_r_raw.is_finite() { in_r_raw } else { 0.0 };
-                    raw_left[i] = in_l * ig;
-                    raw_right[i] = in_r * ig;
+                    raw_left[i] = if in_l_raw.is_finite() { in_l_raw } else { 0.0 };
+                    raw_right[i] = if in_r_raw.is_finite() { in_r_raw } else { 0.0 };
                 }
             }
         }
 
+        // Route the input channels (e.g. a guitar on one side) before any gain staging
+        self.input_routing
+            .get()
+            .route_block(&mut raw_left, &mut raw_right);
+
+        // Auto-trim listens to the pre-gain input and may update the input gain
+        self.update_auto_trim(&raw_left, &raw_right);
+
+        // Global gain levels
+        let ig = sonido_core::db_to_linear(self.input_gain.get());
+        let mv = sonido_core::db_to_linear(self.master_volume.get());
+        for (l, r) in raw_left.iter_mut().zip(raw_right.iter_mut()) {
+            *l *= ig;
+            *r *= ig;
+        }
+
         // Compute input metering (pre-chain)
         let mut input_peak = 0.0_f32;
         let mut input_rms_sum = 0.0_f32;
@@ -299,12 +425,25 @@ impl AudioProcessor {
         let mut wet_right = vec![0.0f32; frames];
 
         // Run the graph for the entire block
+        self.graph
+            .set_safe_mode(self.safe_mode.load(Ordering::Relaxed));
+        // Master volume is applied after the graph; pull the ceiling down by
+        // any boost so the final output still respects it.
+        self.graph
+            .set_output_limiter(self.output_limiter.load(Ordering::Relaxed));
+        self.graph
+            .set_limiter_ceiling_db(self.limiter_ceiling.get() - self.master_volume.get().max(0.0));
+        self.graph.set_rig_output(self.rig_output.get());
+        self.graph.set_rig_morph(self.rig_morph.get());
         self.graph
             .process_block_stereo(&raw_left, &raw_right, &mut wet_left, &mut wet_right);
+        self.sync_graph_to_bridge();
 
         // Apply global bypass crossfade per sample and master volume, write output
         let mut output_peak = 0.0_f32;
         let mut output_rms_sum = 0.0_f32;
+        self.analyzer_left.resize(frames, 0.0);
+        self.analyzer_right.resize(frames, 0.0);
 
         for i in 0..frames {
             let dry_l = raw_left[i];
@@ -332,6 +471,8 @@ impl AudioProcessor {
             let mono_out = (l + r) * 0.5;
             output_peak = output_peak.max(mono_out.abs());
             output_rms_sum += mono_out * mono_out;
+            self.analyzer_left[i] = l;
+            self.analyzer_right[i] = r;
 
             // Interleave output
             let idx = i * self.out_ch;
@@ -351,6 +492,9 @@ impl AudioProcessor {
             }
         }
 
+        self.analyzer_tap
+            .push(&self.analyzer_left, &self.analyzer_right);
+
         // CPU usage measurement
         let elapsed = process_start.elapsed().as_secs_f64();
         let cpu_pct = (elapsed / self.buffer_time_secs * 100.0) as f32;
@@ -362,7 +506,7 @@ impl AudioProcessor {
             input_rms: (input_rms_sum / count).sqrt(),
             output_peak,
             output_rms: (output_rms_sum / count).sqrt(),
-            gain_reduction: 0.0,
+            gain_reduction: self.graph.take_limiter_reduction_db(),
             cpu_usage: cpu_pct,
             playback_position_secs: self.file_pb.position_secs(),
         });
@@ -377,6 +521,8 @@ pub(crate) struct AudioStreamConfig {
     pub sample_rate: f32,
     /// Buffer size requested (device may differ per callback).
     pub buffer_size: usize,
+    /// Name of the output device the streams run on.
+    pub device_name: String,
 }
 
 /// Build and start the cpal output stream.
@@ -390,11 +536,20 @@ pub(crate) fn build_audio_streams(
     registry: &EffectRegistry,
     input_gain: Arc<AtomicParam>,
     master_volume: Arc<AtomicParam>,
+    input_routing: Arc<SharedInputRouting>,
+    rig_morph: Arc<AtomicParam>,
+    rig_output: Arc<SharedRigOutput>,
     running: Arc<AtomicBool>,
     metering_tx: Sender<MeteringData>,
+    analyzer_tap: Arc<AnalyzerTap>,
     command_rx: Receiver<GraphCommand>,
     transport_rx: Receiver<TransportCommand>,
     chain_bypass: Arc<AtomicBool>,
+    safe_mode: Arc<AtomicBool>,
+    output_limiter: Arc<AtomicBool>,
+    limiter_ceiling: Arc<AtomicParam>,
+    auto_trim_request: Arc<AtomicBool>,
+    trim_measurement: Arc<TrimMeasurement>,
     error_count: Arc<AtomicU32>,
     sample_rate: f32,
     buffer_size: usize,
@@ -405,6 +560,10 @@ pub(crate) fn build_audio_streams(
     let output_device = host
         .default_output_device()
         .ok_or("No output device available")?;
+    let device_name = output_device
+        .description()
+        .map(|d| d.name().to_string())
+        .unwrap_or_else(|_| String::from("default"));
 
     // Use device's actual sample rate; fall back to passed-in value on error
     let (output_channels, sample_rate) = match output_device.default_output_config() {
@@ -447,11 +606,23 @@ pub(crate) fn build_audio_streams(
         bridge,
         input_gain,
         master_volume,
+        input_routing,
+        rig_morph,
+        rig_output,
         chain_bypass,
         bypass_fade: sonido_core::SmoothedParam::fast(1.0, sample_rate),
+        safe_mode,
+        output_limiter,
+        limiter_ceiling,
+        auto_trim: AutoTrim::new(sample_rate).with_gain_range(-20.0, 20.0),
+        auto_trim_request,
+        trim_measurement,
         command_rx,
         transport_rx,
         metering_tx,
+        analyzer_tap,
+        analyzer_left: Vec::with_capacity(buffer_size),
+        analyzer_right: Vec::with_capacity(buffer_size),
         file_pb: FilePlayback::new(),
         signal_gen: SignalGenerator::new(sample_rate),
         source_mode: SourceMode::Generator,
@@ -497,5 +668,6 @@ pub(crate) fn build_audio_streams(
         streams,
         sample_rate,
         buffer_size,
+        device_name,
     })
 }
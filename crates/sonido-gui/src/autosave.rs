@@ -0,0 +1,235 @@
+//! Crash recovery: periodic session autosave (native only).
+//!
+//! While the editor runs, [`Autosave`] snapshots the session to a recovery
+//! file in the config directory every [`AUTOSAVE_INTERVAL`]. Serializing
+//! happens on the GUI thread; the file write runs on a background thread
+//! through [`sonido_config::write_atomic`], so a crash mid-write never leaves
+//! a truncated file. A clean exit deletes the recovery file, so finding one
+//! at startup means the last run did not shut down — [`RecoveryPrompt`] then
+//! offers to restore it.
+
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::Sender;
+use egui::Context;
+
+use crate::session::Session;
+
+/// Recovery file name inside the config directory.
+const RECOVERY_FILE: &str = "recovery.json";
+
+/// Time between autosaves.
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default recovery file path.
+pub fn default_path() -> PathBuf {
+    sonido_config::paths::user_config_dir().join(RECOVERY_FILE)
+}
+
+/// Load the session left behind by a run that did not exit cleanly.
+///
+/// Returns `None` if there is no recovery file. A corrupt file is logged and
+/// ignored; the next autosave overwrites it.
+pub fn load_recovery(path: &Path) -> Option<Session> {
+    if !path.exists() {
+        return None;
+    }
+    match Session::load(path) {
+        Ok(session) => Some(session),
+        Err(e) => {
+            tracing::warn!(path = ?path, error = %e, "ignoring unreadable recovery file");
+            None
+        }
+    }
+}
+
+/// Periodic session writer with a background I/O thread.
+pub struct Autosave {
+    path: PathBuf,
+    /// Serialized sessions for the writer thread; `None` after
+    /// [`finish`](Self::finish).
+    sender: Option<Sender<String>>,
+    writer: Option<JoinHandle<()>>,
+    /// Last JSON handed to the writer, to skip unchanged sessions.
+    last_json: String,
+    next_due: Instant,
+}
+
+impl Autosave {
+    /// Start the writer thread. The first save is due one interval from now.
+    pub fn new(path: PathBuf) -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded::<String>();
+        let target = path.clone();
+        let writer = std::thread::Builder::new()
+            .name("sonido-autosave".into())
+            .spawn(move || {
+                while let Ok(mut json) = receiver.recv() {
+                    // Only the newest snapshot matters.
+                    while let Ok(newer) = receiver.try_recv() {
+                        json = newer;
+                    }
+                    if let Err(e) = sonido_config::write_atomic(&target, json) {
+                        tracing::error!(error = %e, "autosave failed");
+                    }
+                }
+            })
+            .map_err(|e| tracing::error!(error = %e, "failed to start autosave thread"))
+            .ok();
+        Self {
+            path,
+            sender: writer.is_some().then_some(sender),
+            writer,
+            last_json: String::new(),
+            next_due: Instant::now() + AUTOSAVE_INTERVAL,
+        }
+    }
+
+    /// Whether the next autosave is due at `now`.
+    pub fn due(&self, now: Instant) -> bool {
+        self.sender.is_some() && now >= self.next_due
+    }
+
+    /// Queue `session` for writing unless it matches the last one saved,
+    /// and schedule the next save one interval after `now`.
+    pub fn save(&mut self, session: &Session, now: Instant) {
+        self.next_due = now + AUTOSAVE_INTERVAL;
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        let json = match serde_json::to_string_pretty(session) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::error!(error = %e, "failed to serialize autosave");
+                return;
+            }
+        };
+        if json != self.last_json {
+            self.last_json.clone_from(&json);
+            let _ = sender.send(json);
+        }
+    }
+
+    /// Stop the writer after its pending write and delete the recovery file.
+    ///
+    /// Call on clean exit; later calls do nothing.
+    pub fn finish(&mut self) {
+        self.sender = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+            if let Err(e) = std::fs::remove_file(&self.path)
+                && e.kind() != std::io::ErrorKind::NotFound
+            {
+                tracing::warn!(path = ?self.path, error = %e, "failed to remove recovery file");
+            }
+        }
+    }
+}
+
+impl Drop for Autosave {
+    fn drop(&mut self) {
+        // Flush the pending write but keep the file: dropping without
+        // `finish` is not a clean exit.
+        self.sender = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// The user's answer to the [`RecoveryPrompt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryChoice {
+    /// Load the recovered session.
+    Restore,
+    /// Keep the current session; the recovery file is overwritten by the
+    /// next autosave.
+    Discard,
+}
+
+/// "Restore previous session?" dialog shown after an unclean exit.
+pub struct RecoveryPrompt {
+    session: Session,
+}
+
+impl RecoveryPrompt {
+    /// Prompt for `session`, read from the recovery file.
+    pub fn new(session: Session) -> Self {
+        Self { session }
+    }
+
+    /// The recovered session.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Draw the dialog. Returns the user's choice once a button is clicked.
+    pub fn show(&self, ctx: &Context) -> Option<RecoveryChoice> {
+        let mut choice = None;
+        egui::Window::new("Restore Previous Session?")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label("Sonido did not shut down cleanly last time.");
+                ui.label(format!(
+                    "An autosaved session with {} node(s) is available.",
+                    self.session.nodes.len()
+                ));
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Restore").clicked() {
+                        choice = Some(RecoveryChoice::Restore);
+                    }
+                    if ui.button("Discard").clicked() {
+                        choice = Some(RecoveryChoice::Discard);
+                    }
+                });
+            });
+        choice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sonido-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn save_then_finish_removes_recovery_file() {
+        let dir = scratch_dir("autosave");
+        let path = dir.join(RECOVERY_FILE);
+        let session = Session::linear(Vec::new(), 0.75, 0.5);
+
+        let mut autosave = Autosave::new(path.clone());
+        assert!(!autosave.due(Instant::now()));
+        assert!(autosave.due(Instant::now() + AUTOSAVE_INTERVAL));
+        autosave.save(&session, Instant::now());
+        drop(autosave);
+
+        let recovered = load_recovery(&path).expect("recovery file written");
+        assert!((recovered.input_gain - 0.75).abs() < f32::EPSILON);
+
+        let mut autosave = Autosave::new(path.clone());
+        autosave.finish();
+        assert!(!path.exists());
+        assert!(load_recovery(&path).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_recovery_file_is_ignored() {
+        let dir = scratch_dir("recovery-corrupt");
+        let path = dir.join(RECOVERY_FILE);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "{\"version\": 1, \"nodes\": [").unwrap();
+        assert!(load_recovery(&path).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
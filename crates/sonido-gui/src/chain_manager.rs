@@ -5,7 +5,7 @@
 //! of each buffer.
 
 use sonido_core::ParamDescriptor;
-use sonido_core::graph::GraphEngine;
+use sonido_core::graph::{BypassMode, GraphEngine, Transition};
 use sonido_gui_core::SlotIndex;
 use sonido_registry::EffectWithParams;
 
@@ -29,12 +29,40 @@ pub enum GraphCommand {
         /// Slot index to remove.
         slot: SlotIndex,
     },
+    /// Change what an effect slot outputs while bypassed.
+    ///
+    /// Applied in place on the running engine — no recompile, so effect
+    /// state (and any ringing tail) is preserved.
+    SetBypassMode {
+        /// Slot index to update.
+        slot: SlotIndex,
+        /// New bypass mode.
+        mode: BypassMode,
+    },
+    /// Swap a fresh instance into an effect slot, letting the previous one
+    /// ring out as a spillover tail.
+    ///
+    /// The instance must be the same effect type as the slot's current one,
+    /// with its parameters already set and smoothers snapped; the topology
+    /// and bridge are untouched.
+    ReplaceEffect {
+        /// Slot index to update.
+        slot: SlotIndex,
+        /// Pre-created effect instance (constructed on the GUI thread).
+        effect: Box<dyn EffectWithParams + Send>,
+    },
+    /// Panic: fade out, reset every effect's state, fade back in.
+    ///
+    /// Recovers from runaway feedback or self-oscillation without touching
+    /// topology or parameters.
+    Panic,
     /// Replace the entire topology with a pre-compiled DAG.
     ///
     /// The GUI thread builds the graph, compiles it, and creates a
     /// [`GraphEngine`] via [`new_dag()`](GraphEngine::new_dag). The audio
-    /// thread swaps the entire engine atomically. The old engine drops on
-    /// the audio thread (Vec drops only, no syscalls).
+    /// thread moves the `carry` instances over from the running engine, then
+    /// swaps the entire engine atomically. The old engine drops on the audio
+    /// thread (Vec drops only, no syscalls).
     ReplaceTopology {
         /// Pre-compiled graph engine, ready to process audio.
         engine: Box<GraphEngine>,
@@ -42,5 +70,23 @@ pub enum GraphCommand {
         effect_ids: Vec<&'static str>,
         /// Parameter descriptors per slot (for bridge rebuild).
         slot_descriptors: Vec<Vec<ParamDescriptor>>,
+        /// `(old_slot, new_slot)` pairs for effects that survive the edit.
+        ///
+        /// Their running instances (with delay lines and tails), parameter
+        /// values, and bypass state carry over instead of starting fresh. See
+        /// [`GraphEngine::adopt_effects()`].
+        carry: Vec<(usize, usize)>,
+    },
+    /// Crossfade to a freshly built engine with the same slot layout.
+    ///
+    /// Used for preset changes: the running engine keeps its parameter values
+    /// while it fades (or rings) out, and the new engine picks up the bridge
+    /// values. The bridge is not rebuilt. See
+    /// [`GraphEngine::crossfade_from()`].
+    CrossfadeTo {
+        /// Pre-compiled graph engine, ready to process audio.
+        engine: Box<GraphEngine>,
+        /// Crossfade time and tail treatment.
+        transition: Transition,
     },
 }
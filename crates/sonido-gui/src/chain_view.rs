@@ -0,0 +1,433 @@
+//! Pedal-strip view of a serial effect chain.
+//!
+//! [`ChainView`] draws the chain's effects as a row of pedals in signal
+//! order. Drag a pedal to move it, or drag an effect from the palette into
+//! the row to insert it (clicking a palette entry appends it). Open slots
+//! widen smoothly under the pointer. Edits come back as a [`ChainEdit`]
+//! for the app to apply to the [`GraphView`](crate::graph_view::GraphView)
+//! and recompile; [`PendingValues`] carries parameter values across that
+//! recompile.
+//!
+//! Graphs with splits or merges have no single order, so the strip is
+//! read-only for them and points to the graph editor.
+
+use egui::{
+    Align2, DragAndDrop, FontId, Id, LayerId, Order, Rect, RichText, Sense, Stroke, StrokeKind, Ui,
+    vec2,
+};
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::widgets::glow;
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use sonido_registry::{EffectCategory, EffectRegistry};
+
+use crate::atomic_param_bridge::AtomicParamBridge;
+use crate::graph_view::category_color;
+
+/// Pedal size in points.
+const PEDAL_SIZE: egui::Vec2 = vec2(84.0, 104.0);
+
+/// Duration of the slot open/close animation in seconds.
+const GAP_ANIMATION_SECS: f32 = 0.12;
+
+/// One pedal in the strip.
+pub struct Pedal<'a> {
+    /// Display name.
+    pub name: &'a str,
+    /// Effect category (pedal color).
+    pub category: EffectCategory,
+    /// Whether the effect is bypassed.
+    pub bypassed: bool,
+    /// Whether the pedal's node is selected.
+    pub selected: bool,
+}
+
+/// A chain edit requested by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainEdit {
+    /// Move the pedal at `from` so it ends up at index `to`.
+    Move {
+        /// Current index.
+        from: usize,
+        /// Index after the move.
+        to: usize,
+    },
+    /// Insert a new effect at `index`.
+    Insert {
+        /// Insertion index (0 = first, `len` = last).
+        index: usize,
+        /// Registry effect ID.
+        effect_id: &'static str,
+    },
+}
+
+/// What the user did in the strip this frame.
+#[derive(Debug, Default)]
+pub struct ChainViewResponse {
+    /// Pedal clicked (select it).
+    pub clicked: Option<usize>,
+    /// Chain edit to apply.
+    pub edit: Option<ChainEdit>,
+}
+
+/// Drag-and-drop payload.
+#[derive(Debug, Clone, Copy)]
+enum DragItem {
+    /// A pedal already in the chain, by index.
+    Pedal(usize),
+    /// A palette entry, by effect ID.
+    Palette(&'static str),
+}
+
+/// Pedal strip state.
+#[derive(Debug, Default)]
+pub struct ChainView {
+    /// Insertion index under the pointer during the last frame's drag.
+    drop_index: Option<usize>,
+}
+
+impl ChainView {
+    /// Draw the strip and palette.
+    ///
+    /// `pedals` is the chain in signal order, or `None` if the graph is not
+    /// a serial chain.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        pedals: Option<&[Pedal<'_>]>,
+        registry: &EffectRegistry,
+    ) -> ChainViewResponse {
+        let theme = SonidoTheme::get(ui.ctx());
+        let mut response = ChainViewResponse::default();
+
+        let Some(pedals) = pedals else {
+            self.drop_index = None;
+            ui.label(
+                RichText::new(
+                    "The chain has splits or merges. Use the Chain tile to edit parallel routing.",
+                )
+                .font(FontId::monospace(10.0))
+                .color(theme.colors.text_secondary)
+                .italics(),
+            );
+            return response;
+        };
+
+        let payload = DragAndDrop::payload::<DragItem>(ui.ctx());
+        let pointer = ui.ctx().pointer_latest_pos();
+
+        // Strip
+        let strip = egui::ScrollArea::horizontal()
+            .id_salt("pedal_strip")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.set_min_height(PEDAL_SIZE.y);
+                    let mut centers = Vec::with_capacity(pedals.len());
+                    for (i, pedal) in pedals.iter().enumerate() {
+                        self.gap(ui, i);
+                        let (rect, pedal_response) =
+                            ui.allocate_exact_size(PEDAL_SIZE, Sense::click_and_drag());
+                        centers.push(rect.center().x);
+
+                        if pedal_response.drag_started() {
+                            DragAndDrop::set_payload(ui.ctx(), DragItem::Pedal(i));
+                        }
+                        if pedal_response.clicked() {
+                            response.clicked = Some(i);
+                        }
+
+                        if pedal_response.dragged() {
+                            // Outline the origin; the pedal follows the pointer.
+                            ui.painter().rect_stroke(
+                                rect,
+                                6.0,
+                                Stroke::new(1.0, theme.colors.dim),
+                                StrokeKind::Inside,
+                            );
+                            if let Some(pos) = pointer {
+                                let layer = LayerId::new(Order::Tooltip, Id::new("dragged_pedal"));
+                                let painter = ui.ctx().layer_painter(layer);
+                                paint_pedal(
+                                    &painter,
+                                    Rect::from_center_size(pos, PEDAL_SIZE),
+                                    pedal,
+                                    &theme,
+                                );
+                            }
+                        } else {
+                            paint_pedal(ui.painter(), rect, pedal, &theme);
+                            pedal_response
+                                .on_hover_cursor(egui::CursorIcon::Grab)
+                                .on_hover_text("Drag to reorder; click to edit");
+                        }
+                    }
+                    self.gap(ui, pedals.len());
+                    if pedals.is_empty() {
+                        ui.label(
+                            RichText::new("Drag effects here")
+                                .font(FontId::monospace(10.0))
+                                .color(theme.colors.text_secondary)
+                                .italics(),
+                        );
+                    }
+                    centers
+                })
+                .inner
+            });
+        let strip_rect = strip.inner_rect;
+        let centers = strip.inner;
+
+        // Drop target: the slot between the pedals either side of the pointer.
+        self.drop_index = pointer
+            .filter(|p| payload.is_some() && strip_rect.contains(*p))
+            .map(|p| centers.iter().filter(|&&x| x < p.x).count());
+
+        if let Some(index) = self.drop_index
+            && ui.input(|i| i.pointer.any_released())
+            && let Some(item) = DragAndDrop::take_payload::<DragItem>(ui.ctx())
+        {
+            response.edit = match *item {
+                DragItem::Pedal(from) => {
+                    let to = if index > from { index - 1 } else { index };
+                    (to != from).then_some(ChainEdit::Move { from, to })
+                }
+                DragItem::Palette(effect_id) => Some(ChainEdit::Insert { index, effect_id }),
+            };
+            self.drop_index = None;
+        }
+
+        ui.separator();
+
+        // Palette
+        for category in EffectCategory::ALL {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(
+                    RichText::new(category.name())
+                        .font(FontId::monospace(10.0))
+                        .color(theme.colors.text_secondary),
+                );
+                let color = category_color(category, &theme);
+                for desc in registry.effects_in_category(category) {
+                    let chip = ui
+                        .add(
+                            egui::Button::new(
+                                RichText::new(desc.name)
+                                    .font(FontId::monospace(10.0))
+                                    .color(color),
+                            )
+                            .sense(Sense::click_and_drag()),
+                        )
+                        .on_hover_text("Drag into the chain, or click to append");
+                    if chip.drag_started() {
+                        DragAndDrop::set_payload(ui.ctx(), DragItem::Palette(desc.id));
+                    }
+                    if chip.dragged()
+                        && let Some(pos) = pointer
+                    {
+                        let layer = LayerId::new(Order::Tooltip, Id::new("dragged_effect"));
+                        ui.ctx().layer_painter(layer).text(
+                            pos,
+                            Align2::CENTER_BOTTOM,
+                            desc.name,
+                            FontId::monospace(11.0),
+                            color,
+                        );
+                    }
+                    if chip.clicked() {
+                        response.edit = Some(ChainEdit::Insert {
+                            index: pedals.len(),
+                            effect_id: desc.id,
+                        });
+                    }
+                }
+            });
+        }
+
+        response
+    }
+
+    /// Space before slot `index`, widened while a drag hovers over it.
+    fn gap(&self, ui: &mut Ui, index: usize) {
+        let target = if self.drop_index == Some(index) {
+            PEDAL_SIZE.x * 0.5
+        } else {
+            0.0
+        };
+        let width = ui.ctx().animate_value_with_time(
+            Id::new(("pedal_gap", index)),
+            target,
+            GAP_ANIMATION_SECS,
+        );
+        if width > 0.5 {
+            ui.add_space(width);
+        }
+    }
+}
+
+/// Draw one pedal: body, bypass LED, and name.
+fn paint_pedal(painter: &egui::Painter, rect: Rect, pedal: &Pedal<'_>, theme: &SonidoTheme) {
+    let color = category_color(pedal.category, theme);
+    let border = if pedal.selected {
+        theme.colors.amber
+    } else {
+        color
+    };
+    painter.rect_filled(rect, 6.0, theme.colors.void);
+    painter.rect_stroke(
+        rect,
+        6.0,
+        Stroke::new(if pedal.selected { 2.0 } else { 1.0 }, border),
+        StrokeKind::Inside,
+    );
+
+    let led = if pedal.bypassed {
+        theme.colors.red
+    } else {
+        theme.colors.green
+    };
+    glow::glow_circle(
+        painter,
+        rect.center_top() + vec2(0.0, 16.0),
+        4.0,
+        led,
+        theme,
+    );
+
+    let text_color = if pedal.bypassed {
+        theme.colors.text_secondary
+    } else {
+        color
+    };
+    painter.text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        pedal.name,
+        FontId::monospace(10.0),
+        text_color,
+    );
+    // Footswitch
+    painter.circle_stroke(
+        rect.center_bottom() - vec2(0.0, 20.0),
+        8.0,
+        Stroke::new(1.0, theme.colors.dim),
+    );
+}
+
+/// Parameter values to restore once the audio thread rebuilds the bridge.
+///
+/// Recompiling the graph resets every slot to its defaults. Capture the
+/// values before sending the new topology, then call
+/// [`try_apply`](Self::try_apply) each frame until the audio thread has
+/// rebuilt the bridge.
+pub struct PendingValues {
+    /// Bridge rebuild count when the values were captured.
+    rebuild_count: u64,
+    /// Effect ID per slot of the new chain.
+    effect_ids: Vec<&'static str>,
+    /// Values per slot of the new chain: parameters and bypass, or `None`
+    /// to keep the defaults (newly inserted effects).
+    values: Vec<Option<(Vec<f32>, bool)>>,
+}
+
+impl PendingValues {
+    /// Build from the new chain's effect IDs and, per slot, the old slot it
+    /// came from (`None` for new effects).
+    pub fn new(
+        bridge: &AtomicParamBridge,
+        effect_ids: Vec<&'static str>,
+        old_slots: &[Option<usize>],
+    ) -> Self {
+        let values = old_slots
+            .iter()
+            .map(|old| {
+                old.map(|slot| {
+                    let slot = SlotIndex(slot);
+                    let params = (0..bridge.param_count(slot))
+                        .map(|p| bridge.get(slot, ParamIndex(p)))
+                        .collect();
+                    (params, bridge.is_bypassed(slot))
+                })
+            })
+            .collect();
+        Self::with_values(bridge, effect_ids, values)
+    }
+
+    /// Build from explicit per-slot values, e.g. those stored in a session.
+    pub fn with_values(
+        bridge: &AtomicParamBridge,
+        effect_ids: Vec<&'static str>,
+        values: Vec<Option<(Vec<f32>, bool)>>,
+    ) -> Self {
+        Self {
+            rebuild_count: bridge.rebuild_count(),
+            effect_ids,
+            values,
+        }
+    }
+
+    /// Apply the values if the bridge has been rebuilt since capture.
+    ///
+    /// Returns `true` once done: applied, or dropped because the rebuilt
+    /// bridge holds a different chain (another edit superseded this one).
+    pub fn try_apply(&self, bridge: &AtomicParamBridge) -> bool {
+        if bridge.rebuild_count() == self.rebuild_count {
+            return false;
+        }
+        let matches = bridge.slot_count() == self.effect_ids.len()
+            && self
+                .effect_ids
+                .iter()
+                .enumerate()
+                .all(|(i, id)| bridge.effect_id(SlotIndex(i)) == *id);
+        if !matches {
+            return true;
+        }
+        for (i, values) in self.values.iter().enumerate() {
+            let Some((params, bypassed)) = values else {
+                continue;
+            };
+            let slot = SlotIndex(i);
+            for (p, &value) in params.iter().enumerate() {
+                bridge.set(slot, ParamIndex(p), value);
+            }
+            bridge.set_bypassed(slot, *bypassed);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_values_wait_for_matching_bridge() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+        bridge.set(SlotIndex(0), ParamIndex(0), 12.0);
+        bridge.set_bypassed(SlotIndex(1), true);
+
+        // Swap the two and add a chorus.
+        let pending = PendingValues::new(
+            &bridge,
+            vec!["reverb", "distortion", "chorus"],
+            &[Some(1), Some(0), None],
+        );
+        assert!(!pending.try_apply(&bridge));
+
+        // What the audio thread does on ReplaceTopology.
+        let ids = ["reverb", "distortion", "chorus"];
+        let fresh = AtomicParamBridge::new(&registry, &ids, 48000.0);
+        let descriptors: Vec<Vec<_>> = (0..ids.len())
+            .map(|s| {
+                (0..fresh.param_count(SlotIndex(s)))
+                    .filter_map(|p| fresh.param_descriptor(SlotIndex(s), ParamIndex(p)))
+                    .collect()
+            })
+            .collect();
+        bridge.rebuild_from_manifest(&ids, &descriptors, &[]);
+        assert!(pending.try_apply(&bridge));
+        assert_eq!(bridge.get(SlotIndex(1), ParamIndex(0)), 12.0);
+        assert!(bridge.is_bypassed(SlotIndex(0)));
+        assert!(!bridge.is_bypassed(SlotIndex(2)));
+    }
+}
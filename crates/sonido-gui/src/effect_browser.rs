@@ -0,0 +1,249 @@
+//! "Add Effect" browser window.
+//!
+//! Lists every registry effect grouped by [`EffectCategory`], filtered by a
+//! category selector and a search over names and descriptions. Selecting an
+//! entry previews its parameters (range and default); the `+` button inserts
+//! it at the chosen chain position in one click.
+
+use egui::{Context, FontId, RichText};
+use sonido_core::ParamDescriptor;
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_registry::{EffectCategory, EffectDescriptor, EffectRegistry};
+
+use crate::graph_view::category_color;
+
+/// An insert requested from the browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrowserInsert {
+    /// Registry effect ID.
+    pub effect_id: &'static str,
+    /// Chain index to insert at, or `None` to add an unwired node (the graph
+    /// is not a serial chain).
+    pub index: Option<usize>,
+}
+
+/// Effect browser state.
+#[derive(Debug, Default)]
+pub struct EffectBrowser {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Search text.
+    query: String,
+    /// Category filter (`None` = all).
+    category: Option<EffectCategory>,
+    /// Effect shown in the preview pane.
+    selected: Option<&'static str>,
+    /// Insert position (`None` = end of chain).
+    position: Option<usize>,
+}
+
+impl EffectBrowser {
+    /// Draw the window.
+    ///
+    /// `chain` names the effects of a serial chain in signal order, for the
+    /// position selector; `None` if the graph has parallel routing.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        registry: &EffectRegistry,
+        chain: Option<&[&str]>,
+    ) -> Option<BrowserInsert> {
+        if !self.open {
+            return None;
+        }
+
+        let theme = SonidoTheme::get(ctx);
+        let mut insert = None;
+        let mut open = self.open;
+        egui::Window::new("Add Effect")
+            .open(&mut open)
+            .default_size([380.0, 480.0])
+            .show(ctx, |ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("Search name or description")
+                        .desired_width(f32::INFINITY),
+                );
+                ui.horizontal_wrapped(|ui| {
+                    ui.selectable_value(&mut self.category, None, "All");
+                    for category in EffectCategory::ALL {
+                        ui.selectable_value(&mut self.category, Some(category), category.name());
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Insert at");
+                    match chain {
+                        Some(chain) => self.position_selector(ui, chain),
+                        None => {
+                            ui.label(
+                                RichText::new("unwired (graph has parallel routing)")
+                                    .color(theme.colors.text_secondary)
+                                    .italics(),
+                            );
+                        }
+                    }
+                });
+                ui.separator();
+
+                let index = chain.map(|c| self.position.map_or(c.len(), |p| p.min(c.len())));
+                let list_height = ui.available_height() * 0.6;
+                egui::ScrollArea::vertical()
+                    .id_salt("effect_list")
+                    .max_height(list_height)
+                    .show(ui, |ui| {
+                        for category in EffectCategory::ALL {
+                            if self.category.is_some_and(|c| c != category) {
+                                continue;
+                            }
+                            let effects: Vec<_> = registry
+                                .effects_in_category(category)
+                                .into_iter()
+                                .filter(|d| matches(d, &self.query))
+                                .collect();
+                            if effects.is_empty() {
+                                continue;
+                            }
+                            ui.label(
+                                RichText::new(category.name())
+                                    .font(FontId::monospace(10.0))
+                                    .color(theme.colors.text_secondary),
+                            );
+                            let color = category_color(category, &theme);
+                            for desc in effects {
+                                ui.horizontal(|ui| {
+                                    if ui.small_button("+").on_hover_text("Insert").clicked() {
+                                        insert = Some(BrowserInsert {
+                                            effect_id: desc.id,
+                                            index,
+                                        });
+                                    }
+                                    let selected = self.selected == Some(desc.id);
+                                    if ui
+                                        .selectable_label(
+                                            selected,
+                                            RichText::new(desc.name).color(color),
+                                        )
+                                        .clicked()
+                                    {
+                                        self.selected = Some(desc.id);
+                                    }
+                                    ui.label(
+                                        RichText::new(desc.description)
+                                            .small()
+                                            .color(theme.colors.text_secondary),
+                                    );
+                                });
+                            }
+                        }
+                    });
+
+                ui.separator();
+                match self.selected.and_then(|id| registry.get(id)) {
+                    Some(desc) => {
+                        ui.label(
+                            RichText::new(desc.name)
+                                .strong()
+                                .color(category_color(desc.category, &theme)),
+                        );
+                        ui.label(desc.description);
+                        let params = registry.param_descriptors(desc.id).unwrap_or(&[]);
+                        egui::ScrollArea::vertical()
+                            .id_salt("effect_preview")
+                            .show(ui, |ui| {
+                                for line in param_preview(params) {
+                                    ui.label(RichText::new(line).font(FontId::monospace(10.0)));
+                                }
+                            });
+                    }
+                    None => {
+                        ui.label(
+                            RichText::new("Select an effect to preview its parameters.")
+                                .color(theme.colors.text_secondary)
+                                .italics(),
+                        );
+                    }
+                }
+            });
+        self.open = open;
+        insert
+    }
+
+    /// Position combo box: start, after each chain effect (the last being
+    /// the end).
+    fn position_selector(&mut self, ui: &mut egui::Ui, chain: &[&str]) {
+        let label = |index: usize| match index {
+            0 => "Start".to_string(),
+            i if i == chain.len() => "End".to_string(),
+            i => format!("After {} ({i})", chain[i - 1]),
+        };
+        let current = self.position.map_or(chain.len(), |p| p.min(chain.len()));
+        egui::ComboBox::from_id_salt("insert_position")
+            .selected_text(label(current))
+            .show_ui(ui, |ui| {
+                for i in 0..=chain.len() {
+                    let value = if i == chain.len() { None } else { Some(i) };
+                    ui.selectable_value(&mut self.position, value, label(i));
+                }
+            });
+    }
+}
+
+/// Whether `desc` matches a case-insensitive search over its name, short
+/// name, ID, and description. An empty query matches everything.
+pub fn matches(desc: &EffectDescriptor, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || [desc.name, desc.short_name, desc.id, desc.description]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+}
+
+/// One line per parameter: name, range, and default.
+pub fn param_preview(params: &[ParamDescriptor]) -> Vec<String> {
+    params
+        .iter()
+        .map(|p| {
+            format!(
+                "{:<14} {} – {} (default {})",
+                p.name,
+                p.format_value(p.min),
+                p.format_value(p.max),
+                p.format_value(p.default)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_covers_names_and_descriptions() {
+        let registry = EffectRegistry::new();
+        let reverb = registry.get("reverb").unwrap();
+        assert!(matches(reverb, ""));
+        assert!(matches(reverb, "  REVERB "));
+        assert!(matches(reverb, &reverb.description[..6]));
+        assert!(!matches(reverb, "no such effect"));
+
+        let hits: Vec<_> = registry
+            .all_effects()
+            .into_iter()
+            .filter(|d| matches(d, "delay"))
+            .map(|d| d.id)
+            .collect();
+        assert!(hits.contains(&"delay"));
+    }
+
+    #[test]
+    fn preview_lists_every_parameter() {
+        let registry = EffectRegistry::new();
+        let params = registry.param_descriptors("distortion").unwrap();
+        let preview = param_preview(params);
+        assert_eq!(preview.len(), params.len());
+        assert!(preview[0].starts_with(params[0].name));
+        assert!(preview[0].contains("default"));
+    }
+}
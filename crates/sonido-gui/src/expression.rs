@@ -0,0 +1,869 @@
+//! Expression pedal mapping: MIDI CC → parameters through response curves.
+//!
+//! [`ExpressionMap`] wraps a [`ControlMapper`] addressed by `(slot, param)`.
+//! One CC (an expression pedal on CC 11, say) can drive any number of
+//! parameters, each through its own [`ResponseCurve`] and heel/toe window,
+//! so a single sweep can open a wah while fading a delay in, or morph
+//! between two settings. Mappings refer to effects by chain position, like
+//! scenes, and are saved in the session.
+//!
+//! Incoming CCs pass through a [`MidiCcDecoder`], so a source can also be a
+//! 14-bit CC pair (CCs 0–31 with their LSB on 32–63) or an NRPN, for
+//! parameters like delay time that need more than 128 steps. Pairs are
+//! detected from the first LSB, or fixed per mapping to 7 or 14 bits.
+//!
+//! [`ExpressionEditor`] is the mapping dialog, with a curve editor for the
+//! selected mapping.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use egui::{Context, FontId, Pos2, Rect, RichText, Sense, Stroke, Ui, vec2};
+use serde::{Deserialize, Serialize};
+use sonido_config::CurveConfig;
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use sonido_platform::{
+    Breakpoints, CcResolution, ControlId, ControlMapper, ControlResponse, MidiCcDecoder,
+    ResponseCurve,
+};
+
+/// Maximum number of CC → parameter mappings.
+pub const MAX_MAPPINGS: usize = 32;
+
+/// Highest MIDI CC number.
+const MIDI_MAX: u8 = 127;
+
+/// Highest NRPN number.
+const NRPN_MAX: u16 = 16383;
+
+/// CCs below this can pair with an LSB for 14-bit values.
+const PAIRED_CCS: u8 = 32;
+
+/// Mapped parameter: `(slot, param)`.
+pub type Target = (usize, usize);
+
+/// MIDI CC and NRPN → parameter mappings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<MappingFile>", into = "Vec<MappingFile>")]
+pub struct ExpressionMap {
+    mapper: ControlMapper<MAX_MAPPINGS, Target>,
+    /// 14-bit pairing and NRPN state, and per-CC resolution settings.
+    decoder: MidiCcDecoder,
+    /// Last normalized value per control, for the curve editor's position
+    /// marker.
+    positions: BTreeMap<u16, f32>,
+}
+
+impl ExpressionMap {
+    /// Number of mappings.
+    pub fn len(&self) -> usize {
+        self.mapper.len()
+    }
+
+    /// Whether there are no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.mapper.is_empty()
+    }
+
+    /// Map `control` (a [`ControlId::midi`] CC or [`ControlId::nrpn`]) to
+    /// `target` over the full range. Returns `false` if the table is full;
+    /// an existing mapping is left as is.
+    pub fn add(&mut self, control: ControlId, target: Target) -> bool {
+        self.response_mut(control, target).is_some()
+            || self
+                .mapper
+                .add_target(control, target, ControlResponse::FULL)
+    }
+
+    /// Remove one mapping.
+    pub fn remove(&mut self, control: ControlId, target: Target) {
+        self.mapper.remove_target(control, target);
+    }
+
+    /// Mutable response of one mapping.
+    pub fn response_mut(
+        &mut self,
+        control: ControlId,
+        target: Target,
+    ) -> Option<&mut ControlResponse> {
+        self.mapper.response_mut(control, target)
+    }
+
+    /// All mappings as `(control, target, response)`.
+    pub fn mappings(&self) -> impl Iterator<Item = (ControlId, Target, &ControlResponse)> {
+        self.mapper
+            .entries()
+            .filter(|(control, _, _)| control.is_midi() || control.is_nrpn())
+    }
+
+    /// Last value received on `control` (0.0–1.0).
+    pub fn position(&self, control: ControlId) -> Option<f32> {
+        self.positions.get(&control.raw()).copied()
+    }
+
+    /// Resolution setting of a CC that can be 14-bit (CCs 0–31).
+    pub fn resolution(&self, control: ControlId) -> Option<CcResolution> {
+        if control.is_midi() {
+            self.decoder.resolution(control.index())
+        } else {
+            None
+        }
+    }
+
+    /// Fix a pairable CC to 7 or 14 bits, or detect it.
+    pub fn set_resolution(&mut self, control: ControlId, resolution: CcResolution) {
+        if control.is_midi() {
+            self.decoder.set_resolution(control.index(), resolution);
+        }
+    }
+
+    /// Whether a CC is currently decoded as 14-bit.
+    pub fn is_fine(&self, control: ControlId) -> bool {
+        control.is_midi() && self.decoder.is_fine(control.index())
+    }
+
+    /// Decode a raw CC message and apply it to every parameter the control
+    /// drives. Returns the control it moved (mapped or not), or `None` for
+    /// NRPN selection and other messages that carry no value.
+    pub fn handle_cc(&mut self, cc: u8, value: u8, bridge: &dyn ParamBridge) -> Option<ControlId> {
+        let (control, value) = self.decoder.decode(cc, value)?;
+        self.handle_control(control, value, bridge);
+        Some(control)
+    }
+
+    /// Apply a normalized control value to every parameter it drives.
+    /// Targets that no longer exist in the chain are skipped. Returns `true`
+    /// if `control` is mapped.
+    pub fn handle_control(
+        &mut self,
+        control: ControlId,
+        value: f32,
+        bridge: &dyn ParamBridge,
+    ) -> bool {
+        self.positions.insert(control.raw(), value);
+        let mut mapped = false;
+        for ((slot, param), position) in self.mapper.resolve(control, value) {
+            mapped = true;
+            let (slot, param) = (SlotIndex(slot), ParamIndex(param));
+            if slot.0 < bridge.slot_count()
+                && let Some(desc) = bridge.param_descriptor(slot, param)
+            {
+                bridge.set(slot, param, desc.denormalize(position));
+            }
+        }
+        mapped
+    }
+}
+
+/// Session form of one mapping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MappingFile {
+    /// Source CC; ignored when `nrpn` is set.
+    #[serde(default)]
+    cc: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nrpn: Option<u16>,
+    /// CC resolution, for CCs 0–31.
+    #[serde(default)]
+    resolution: ResolutionFile,
+    slot: usize,
+    param: usize,
+    #[serde(default)]
+    curve: CurveConfig,
+    min: f32,
+    max: f32,
+}
+
+/// Session form of [`CcResolution`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ResolutionFile {
+    #[default]
+    Auto,
+    Coarse,
+    Fine,
+}
+
+impl From<ResolutionFile> for CcResolution {
+    fn from(file: ResolutionFile) -> Self {
+        match file {
+            ResolutionFile::Auto => Self::Auto,
+            ResolutionFile::Coarse => Self::Coarse,
+            ResolutionFile::Fine => Self::Fine,
+        }
+    }
+}
+
+impl From<CcResolution> for ResolutionFile {
+    fn from(resolution: CcResolution) -> Self {
+        match resolution {
+            CcResolution::Auto => Self::Auto,
+            CcResolution::Coarse => Self::Coarse,
+            CcResolution::Fine => Self::Fine,
+        }
+    }
+}
+
+/// Curve from its stored form. Custom curves with too many points fall back
+/// to linear.
+pub(crate) fn curve_from_config(config: CurveConfig) -> ResponseCurve {
+    match config {
+        CurveConfig::Linear => ResponseCurve::Linear,
+        CurveConfig::Log => ResponseCurve::Log,
+        CurveConfig::Custom(points) => {
+            let points: Vec<_> = points.iter().map(|&[x, y]| (x, y)).collect();
+            Breakpoints::from_points(&points).map_or_else(
+                || {
+                    tracing::warn!("too many curve points, using linear");
+                    ResponseCurve::Linear
+                },
+                ResponseCurve::Custom,
+            )
+        }
+    }
+}
+
+/// Stored form of a curve.
+pub(crate) fn curve_to_config(curve: &ResponseCurve) -> CurveConfig {
+    match curve {
+        ResponseCurve::Linear => CurveConfig::Linear,
+        ResponseCurve::Log => CurveConfig::Log,
+        ResponseCurve::Custom(points) => {
+            CurveConfig::Custom(points.points().iter().map(|&(x, y)| [x, y]).collect())
+        }
+    }
+}
+
+impl From<Vec<MappingFile>> for ExpressionMap {
+    fn from(files: Vec<MappingFile>) -> Self {
+        let mut map = Self::default();
+        for file in files {
+            let curve = curve_from_config(file.curve);
+            let response = ControlResponse::new(curve, file.min, file.max);
+            let control = file.nrpn.map_or(ControlId::midi(file.cc), ControlId::nrpn);
+            map.set_resolution(control, file.resolution.into());
+            if !map
+                .mapper
+                .add_target(control, (file.slot, file.param), response)
+            {
+                tracing::warn!(
+                    control = %control_label(control),
+                    "expression mapping table full, dropping mapping"
+                );
+            }
+        }
+        map
+    }
+}
+
+impl From<ExpressionMap> for Vec<MappingFile> {
+    fn from(map: ExpressionMap) -> Self {
+        map.mappings()
+            .map(|(control, (slot, param), response)| MappingFile {
+                cc: if control.is_midi() {
+                    control.index()
+                } else {
+                    0
+                },
+                nrpn: control.nrpn_number(),
+                resolution: map.resolution(control).unwrap_or_default().into(),
+                slot,
+                param,
+                curve: curve_to_config(&response.curve),
+                min: response.min,
+                max: response.max,
+            })
+            .collect()
+    }
+}
+
+/// Expression mapping dialog.
+#[derive(Debug, Default)]
+pub struct ExpressionEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+    /// Whether the next added mapping is driven by an NRPN rather than a CC.
+    nrpn: bool,
+    /// CC or NRPN number for the next added mapping.
+    number: u16,
+    /// Waiting for the next incoming control to fill in `number`.
+    learning: bool,
+    /// Target slot for the next added mapping.
+    slot: usize,
+    /// Target parameter for the next added mapping.
+    param: usize,
+    /// Mapping shown in the curve editor.
+    selected: Option<(ControlId, Target)>,
+}
+
+impl ExpressionEditor {
+    /// Feed an incoming control; while learning, it becomes the source for
+    /// the next mapping.
+    pub fn learn(&mut self, control: ControlId) {
+        if !self.learning {
+            return;
+        }
+        if let Some(number) = control.nrpn_number() {
+            self.nrpn = true;
+            self.number = number;
+        } else if control.is_midi() {
+            self.nrpn = false;
+            self.number = u16::from(control.index());
+        } else {
+            return;
+        }
+        self.learning = false;
+    }
+
+    /// Source for the next added mapping.
+    fn control(&self) -> ControlId {
+        if self.nrpn {
+            ControlId::nrpn(self.number.min(NRPN_MAX))
+        } else {
+            ControlId::midi(self.number.min(u16::from(MIDI_MAX)) as u8)
+        }
+    }
+
+    /// Draw the dialog. Returns `true` if `map` changed.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        map: &mut ExpressionMap,
+        bridge: &dyn ParamBridge,
+    ) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let theme = SonidoTheme::get(ctx);
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("Expression")
+            .open(&mut open)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                changed |= self.mapping_list(ui, map, bridge, &theme);
+                ui.separator();
+                changed |= self.selected_curve(ui, map, &theme);
+                ui.separator();
+                changed |= self.add_row(ui, map, bridge);
+            });
+        self.open = open;
+        if !self.open {
+            self.learning = false;
+        }
+        changed
+    }
+
+    /// One row per mapping: source, target, curve, heel/toe window, remove.
+    fn mapping_list(
+        &mut self,
+        ui: &mut Ui,
+        map: &mut ExpressionMap,
+        bridge: &dyn ParamBridge,
+        theme: &SonidoTheme,
+    ) -> bool {
+        if map.is_empty() {
+            ui.label(
+                RichText::new("No mappings yet. Pick a control and a parameter below, then Add.")
+                    .color(theme.colors.text_secondary)
+                    .italics(),
+            );
+            return false;
+        }
+
+        let mut changed = false;
+        let mut remove = None;
+        let rows: Vec<_> = map
+            .mappings()
+            .map(|(control, target, _)| (control, target))
+            .collect();
+        egui::Grid::new("expression_mappings")
+            .num_columns(7)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                for (control, target) in rows {
+                    let selected = self.selected == Some((control, target));
+                    if ui
+                        .selectable_label(selected, control_label(control))
+                        .on_hover_text("Edit this mapping's curve")
+                        .clicked()
+                    {
+                        self.selected = Some((control, target));
+                    }
+                    changed |= resolution_selector(ui, map, (control, target));
+                    let desc = bridge.param_descriptor(SlotIndex(target.0), ParamIndex(target.1));
+                    ui.label(target_label(bridge, target));
+
+                    let Some(response) = map.response_mut(control, target) else {
+                        ui.end_row();
+                        continue;
+                    };
+                    changed |= curve_selector(ui, (control.raw(), target), &mut response.curve);
+                    for (label, value) in [("Heel", &mut response.min), ("Toe", &mut response.max)]
+                    {
+                        let drag = ui.add(
+                            egui::DragValue::new(value)
+                                .range(0.0..=1.0)
+                                .speed(0.005)
+                                .fixed_decimals(2)
+                                .prefix(format!("{label} ")),
+                        );
+                        let drag = match &desc {
+                            Some(desc) => {
+                                drag.on_hover_text(desc.format_value(desc.denormalize(*value)))
+                            }
+                            None => drag,
+                        };
+                        changed |= drag.changed();
+                    }
+                    if ui.small_button("✕").on_hover_text("Remove").clicked() {
+                        remove = Some((control, target));
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some((control, target)) = remove {
+            map.remove(control, target);
+            if self.selected == Some((control, target)) {
+                self.selected = None;
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    /// Curve editor for the selected mapping.
+    fn selected_curve(
+        &mut self,
+        ui: &mut Ui,
+        map: &mut ExpressionMap,
+        theme: &SonidoTheme,
+    ) -> bool {
+        let Some((control, target)) = self.selected else {
+            ui.label(
+                RichText::new("Select a mapping's source to edit its curve.")
+                    .color(theme.colors.text_secondary)
+                    .italics(),
+            );
+            return false;
+        };
+        let position = map.position(control);
+        let Some(response) = map.response_mut(control, target) else {
+            self.selected = None;
+            return false;
+        };
+        ui.label(
+            RichText::new("Custom: drag points, double-click to add, right-click to remove")
+                .font(FontId::monospace(10.0))
+                .color(theme.colors.text_secondary),
+        );
+        curve_editor(ui, &mut response.curve, position)
+    }
+
+    /// Source, learn, target selectors, and Add.
+    fn add_row(&mut self, ui: &mut Ui, map: &mut ExpressionMap, bridge: &dyn ParamBridge) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("expression_source")
+                .selected_text(if self.nrpn { "NRPN" } else { "CC" })
+                .width(56.0)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.nrpn, false, "CC");
+                    ui.selectable_value(&mut self.nrpn, true, "NRPN");
+                });
+            let max = if self.nrpn {
+                NRPN_MAX
+            } else {
+                u16::from(MIDI_MAX)
+            };
+            ui.add(egui::DragValue::new(&mut self.number).range(0..=max));
+            ui.toggle_value(&mut self.learning, "Learn")
+                .on_hover_text("Move a pedal or controller to pick its CC or NRPN");
+
+            if !target_picker(ui, "expression", bridge, &mut self.slot, &mut self.param) {
+                return;
+            }
+
+            let full = map.len() >= MAX_MAPPINGS;
+            if ui
+                .add_enabled(!full, egui::Button::new("Add"))
+                .on_hover_text(
+                    "Map this control to the parameter; a control can drive several parameters",
+                )
+                .clicked()
+            {
+                let control = self.control();
+                let target = (self.slot, self.param);
+                if map.add(control, target) {
+                    self.selected = Some((control, target));
+                    changed = true;
+                }
+            }
+        });
+        changed
+    }
+}
+
+/// `"CC <n>"` or `"NRPN <n>"` label for a mapping source.
+fn control_label(control: ControlId) -> String {
+    match control.nrpn_number() {
+        Some(number) => format!("NRPN {number}"),
+        None => format!("CC {}", control.index()),
+    }
+}
+
+/// Auto / 7-bit / 14-bit selector for CCs that can pair with an LSB; other
+/// sources get an empty cell. Returns `true` if the setting changed.
+fn resolution_selector(
+    ui: &mut Ui,
+    map: &mut ExpressionMap,
+    (control, target): (ControlId, Target),
+) -> bool {
+    let Some(current) = map.resolution(control) else {
+        ui.label("");
+        return false;
+    };
+    let name = |resolution: CcResolution| match resolution {
+        CcResolution::Auto if map.is_fine(control) => "Auto (14)",
+        CcResolution::Auto => "Auto",
+        CcResolution::Coarse => "7-bit",
+        CcResolution::Fine => "14-bit",
+    };
+    let mut selected = current;
+    egui::ComboBox::from_id_salt(("cc_resolution", control.raw(), target))
+        .selected_text(name(current))
+        .width(70.0)
+        .show_ui(ui, |ui| {
+            for option in [CcResolution::Auto, CcResolution::Coarse, CcResolution::Fine] {
+                ui.selectable_value(&mut selected, option, name(option));
+            }
+        })
+        .response
+        .on_hover_text(format!(
+            "CC {0} pairs with CC {1} for 14-bit values; Auto switches on the first CC {1}",
+            control.index(),
+            control.index() + PAIRED_CCS,
+        ));
+    if selected == current {
+        return false;
+    }
+    map.set_resolution(control, selected);
+    true
+}
+
+/// Slot and parameter drop-downs. Returns `false` (after drawing what it
+/// can) if the chain has no slot with parameters to pick.
+pub(crate) fn target_picker(
+    ui: &mut Ui,
+    id: &str,
+    bridge: &dyn ParamBridge,
+    slot: &mut usize,
+    param: &mut usize,
+) -> bool {
+    let slots = bridge.slot_count();
+    if slots == 0 {
+        return false;
+    }
+    *slot = (*slot).min(slots - 1);
+    egui::ComboBox::from_id_salt((id, "slot"))
+        .selected_text(slot_label(bridge, *slot))
+        .show_ui(ui, |ui| {
+            for s in 0..slots {
+                ui.selectable_value(slot, s, slot_label(bridge, s));
+            }
+        });
+    let params = bridge.param_count(SlotIndex(*slot));
+    if params == 0 {
+        return false;
+    }
+    *param = (*param).min(params - 1);
+    let param_name = |p: usize| {
+        bridge
+            .param_descriptor(SlotIndex(*slot), ParamIndex(p))
+            .map_or_else(|| format!("#{p}"), |d| d.name.to_string())
+    };
+    let selected = param_name(*param);
+    egui::ComboBox::from_id_salt((id, "param"))
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            for p in 0..params {
+                let name = param_name(p);
+                ui.selectable_value(param, p, name);
+            }
+        });
+    true
+}
+
+/// `"<slot>: <effect>"` label for a chain slot.
+pub(crate) fn slot_label(bridge: &dyn ParamBridge, slot: usize) -> String {
+    format!("{}: {}", slot + 1, bridge.effect_id(SlotIndex(slot)))
+}
+
+/// `"<effect> · <param>"`, or a placeholder if the target is gone.
+pub(crate) fn target_label(bridge: &dyn ParamBridge, (slot, param): Target) -> String {
+    if slot >= bridge.slot_count() {
+        return format!("slot {} (missing)", slot + 1);
+    }
+    match bridge.param_descriptor(SlotIndex(slot), ParamIndex(param)) {
+        Some(desc) => format!("{} · {}", slot_label(bridge, slot), desc.name),
+        None => format!("{} · #{param} (missing)", slot_label(bridge, slot)),
+    }
+}
+
+/// Linear / Log / Custom selector. Returns `true` if the curve changed.
+pub(crate) fn curve_selector(ui: &mut Ui, id: impl Hash, curve: &mut ResponseCurve) -> bool {
+    let name = |curve: &ResponseCurve| match curve {
+        ResponseCurve::Linear => "Linear",
+        ResponseCurve::Log => "Log",
+        ResponseCurve::Custom(_) => "Custom",
+    };
+    let mut changed = false;
+    egui::ComboBox::from_id_salt(("response_curve", id))
+        .selected_text(name(curve))
+        .width(70.0)
+        .show_ui(ui, |ui| {
+            for option in [
+                ResponseCurve::Linear,
+                ResponseCurve::Log,
+                ResponseCurve::Custom(Breakpoints::new()),
+            ] {
+                let current = std::mem::discriminant(curve) == std::mem::discriminant(&option);
+                if ui.selectable_label(current, name(&option)).clicked() && !current {
+                    *curve = option;
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+/// Plot of `curve` over the control's travel, with draggable points for
+/// custom curves and a marker at the current control `position`.
+/// Returns `true` if the curve changed.
+pub fn curve_editor(ui: &mut Ui, curve: &mut ResponseCurve, position: Option<f32>) -> bool {
+    /// Pick radius around a point, in points.
+    const HANDLE_RADIUS: f32 = 6.0;
+
+    let theme = SonidoTheme::get(ui.ctx());
+    let size = vec2(ui.available_width().min(320.0), 180.0);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+    let plot = rect.shrink(HANDLE_RADIUS);
+    let to_screen = |(x, y): (f32, f32)| plot.lerp_inside(vec2(x, 1.0 - y));
+    let from_screen = |p: Pos2| {
+        let v = (p - plot.min) / plot.size();
+        (v.x.clamp(0.0, 1.0), (1.0 - v.y).clamp(0.0, 1.0))
+    };
+
+    let mut changed = false;
+    if let ResponseCurve::Custom(points) = curve {
+        let drag_id = response.id.with("dragged_point");
+        let nearest = |p: Pos2, points: &Breakpoints| {
+            points
+                .points()
+                .iter()
+                .position(|&pt| to_screen(pt).distance(p) <= HANDLE_RADIUS * 1.5)
+        };
+        if response.drag_started()
+            && let Some(p) = response.interact_pointer_pos()
+        {
+            let index = nearest(p, points);
+            ui.memory_mut(|m| m.data.insert_temp(drag_id, index));
+        }
+        if response.dragged()
+            && let Some(p) = response.interact_pointer_pos()
+            && let Some(index) = ui
+                .memory(|m| m.data.get_temp::<Option<usize>>(drag_id))
+                .flatten()
+        {
+            let (x, y) = from_screen(p);
+            points.set(index, x, y);
+            changed = true;
+        }
+        if response.double_clicked()
+            && let Some(p) = response.interact_pointer_pos()
+            && nearest(p, points).is_none()
+        {
+            let (x, y) = from_screen(p);
+            changed |= points.insert(x, y);
+        }
+        if response.secondary_clicked()
+            && let Some(p) = response.interact_pointer_pos()
+            && let Some(index) = nearest(p, points)
+            && points.points().len() > 2
+        {
+            points.remove(index);
+            changed = true;
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, theme.colors.void);
+    let grid = Stroke::new(1.0, theme.colors.dim);
+    for i in 0..=4 {
+        let t = i as f32 / 4.0;
+        painter.line_segment([to_screen((t, 0.0)), to_screen((t, 1.0))], grid);
+        painter.line_segment([to_screen((0.0, t)), to_screen((1.0, t))], grid);
+    }
+
+    let line: Vec<Pos2> = (0..=64)
+        .map(|i| {
+            let x = i as f32 / 64.0;
+            to_screen((x, curve.apply(x)))
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        line,
+        Stroke::new(2.0, theme.colors.amber),
+    ));
+
+    if let ResponseCurve::Custom(points) = curve {
+        for &point in points.points() {
+            painter.circle_filled(to_screen(point), HANDLE_RADIUS * 0.7, theme.colors.cyan);
+        }
+    }
+    if let Some(x) = position {
+        painter.line_segment(
+            [to_screen((x, 0.0)), to_screen((x, 1.0))],
+            Stroke::new(1.0, theme.colors.text_secondary),
+        );
+        painter.circle_filled(to_screen((x, curve.apply(x))), 4.0, theme.colors.green);
+    }
+    painter.rect_stroke(
+        Rect::from_min_max(plot.min, plot.max),
+        0.0,
+        Stroke::new(1.0, theme.colors.text_secondary),
+        egui::StrokeKind::Inside,
+    );
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::ParamDescriptor;
+    use std::sync::Mutex;
+
+    struct Bridge {
+        descriptors: Vec<ParamDescriptor>,
+        values: Mutex<Vec<f32>>,
+    }
+
+    impl Bridge {
+        fn new() -> Self {
+            let descriptors = vec![
+                ParamDescriptor::custom("Mix", "Mix", 0.0, 100.0, 50.0),
+                ParamDescriptor::custom("Feedback", "Fdbk", 0.0, 100.0, 40.0),
+            ];
+            let values = Mutex::new(descriptors.iter().map(|d| d.default).collect());
+            Self {
+                descriptors,
+                values,
+            }
+        }
+    }
+
+    impl ParamBridge for Bridge {
+        fn slot_count(&self) -> usize {
+            1
+        }
+        fn effect_id(&self, _slot: SlotIndex) -> &'static str {
+            "delay"
+        }
+        fn param_count(&self, _slot: SlotIndex) -> usize {
+            self.descriptors.len()
+        }
+        fn param_descriptor(&self, _slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+            self.descriptors.get(param.0).copied()
+        }
+        fn get(&self, _slot: SlotIndex, param: ParamIndex) -> f32 {
+            self.values.lock().unwrap()[param.0]
+        }
+        fn set(&self, _slot: SlotIndex, param: ParamIndex, value: f32) {
+            self.values.lock().unwrap()[param.0] = value;
+        }
+        fn is_bypassed(&self, _slot: SlotIndex) -> bool {
+            false
+        }
+        fn set_bypassed(&self, _slot: SlotIndex, _bypassed: bool) {}
+    }
+
+    const CC11: ControlId = ControlId::midi(11);
+
+    #[test]
+    fn one_cc_morphs_several_parameters() {
+        let bridge = Bridge::new();
+        let mut map = ExpressionMap::default();
+        assert!(map.add(CC11, (0, 0)));
+        assert!(map.add(CC11, (0, 1)));
+        // Feedback runs toe-to-heel over the upper half of its range
+        *map.response_mut(CC11, (0, 1)).unwrap() =
+            ControlResponse::new(ResponseCurve::Linear, 1.0, 0.5);
+
+        assert_eq!(map.handle_cc(11, 127, &bridge), Some(CC11));
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 100.0);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(1)), 50.0);
+        assert_eq!(map.handle_cc(11, 0, &bridge), Some(CC11));
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 0.0);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(1)), 100.0);
+        assert_eq!(map.position(CC11), Some(0.0));
+
+        assert!(!map.handle_control(ControlId::midi(1), 0.5, &bridge));
+    }
+
+    #[test]
+    fn fine_cc_and_nrpn_sources() {
+        let bridge = Bridge::new();
+        let mut map = ExpressionMap::default();
+        let cc4 = ControlId::midi(4);
+        let nrpn = ControlId::nrpn(300);
+        map.add(cc4, (0, 0));
+        map.add(nrpn, (0, 1));
+
+        // The first LSB on CC 36 switches CC 4 to 14-bit
+        map.handle_cc(4, 64, &bridge);
+        assert!(!map.is_fine(cc4));
+        assert_eq!(map.handle_cc(36, 0, &bridge), Some(cc4));
+        assert!(map.is_fine(cc4));
+        let mix = bridge.get(SlotIndex(0), ParamIndex(0));
+        assert!((mix - 8192.0 / 16383.0 * 100.0).abs() < 1e-3, "{mix}");
+
+        // NRPN 300 = MSB 2, LSB 44; selection alone moves nothing
+        assert_eq!(map.handle_cc(99, 2, &bridge), None);
+        assert_eq!(map.handle_cc(98, 44, &bridge), None);
+        assert_eq!(map.handle_cc(6, 127, &bridge), Some(nrpn));
+        assert_eq!(map.handle_cc(38, 127, &bridge), Some(nrpn));
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(1)), 100.0);
+        assert_eq!(map.position(nrpn), Some(1.0));
+
+        let mut editor = ExpressionEditor {
+            learning: true,
+            ..Default::default()
+        };
+        editor.learn(nrpn);
+        assert_eq!(editor.control(), nrpn);
+    }
+
+    #[test]
+    fn session_round_trip() {
+        let mut map = ExpressionMap::default();
+        map.add(CC11, (0, 0));
+        map.add(ControlId::midi(4), (2, 3));
+        map.add(ControlId::nrpn(1000), (1, 0));
+        map.set_resolution(ControlId::midi(4), CcResolution::Fine);
+        let points = Breakpoints::from_points(&[(0.0, 0.0), (0.3, 0.7), (1.0, 1.0)]).unwrap();
+        *map.response_mut(ControlId::midi(4), (2, 3)).unwrap() =
+            ControlResponse::new(ResponseCurve::Custom(points), 0.1, 0.9);
+
+        let json = serde_json::to_string(&map).unwrap();
+        let restored: ExpressionMap = serde_json::from_str(&json).unwrap();
+        let before: Vec<_> = map.mappings().map(|(c, t, r)| (c, t, *r)).collect();
+        let after: Vec<_> = restored.mappings().map(|(c, t, r)| (c, t, *r)).collect();
+        assert_eq!(before, after);
+        assert_eq!(
+            restored.resolution(ControlId::midi(4)),
+            Some(CcResolution::Fine)
+        );
+    }
+}
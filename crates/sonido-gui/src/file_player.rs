@@ -6,15 +6,22 @@
 //!
 //! On native, uses synchronous `rfd::FileDialog`. On wasm, uses
 //! `rfd::AsyncFileDialog` with bytes-based WAV parsing via `hound`.
+//!
+//! Below the transport row, a [`SpectrogramView`] shows the loaded file's
+//! spectrogram and a zoomable waveform with a playhead; clicking it seeks.
+//! Cue points and the sustain loop stored in the file (`cue `/`smpl`
+//! chunks) show as markers, and looping plays the stored loop instead of
+//! the whole file.
 
 use crate::signal_generator::{SignalType, SourceMode};
+use crate::spectrogram_view::SpectrogramView;
 use crossbeam_channel::Sender;
 use egui::{Rect, Sense, Stroke, StrokeKind, Ui, pos2, vec2};
 use sonido_gui_core::theme::SonidoTheme;
 use sonido_gui_core::widgets::glow;
 use sonido_gui_core::widgets::led_display::LedDisplay;
 #[cfg(not(target_arch = "wasm32"))]
-use sonido_io::{read_wav_info, read_wav_stereo};
+use sonido_io::{WavMetadata, read_wav_info, read_wav_metadata, read_wav_stereo};
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
 
@@ -48,6 +55,9 @@ pub enum TransportCommand {
     Seek(f32),
     /// Enable or disable loop mode.
     SetLoop(bool),
+    /// Loop frames `start..end` instead of the whole file (`None` = whole
+    /// file). Reset by [`LoadFile`](Self::LoadFile).
+    SetLoopRegion(Option<(usize, usize)>),
     /// Set the active source mode (generator or file).
     SetSourceMode(SourceMode),
     /// Change signal generator type.
@@ -73,7 +83,8 @@ pub enum TransportCommand {
 
 /// GUI-side file player state and controls.
 ///
-/// Renders transport buttons, a position scrubber, and file info.
+/// Renders transport buttons, a position scrubber, file info, and the
+/// file's spectrogram.
 /// Communicates with the audio thread exclusively through commands.
 #[allow(clippy::struct_excessive_bools)]
 pub struct FilePlayer {
@@ -85,6 +96,8 @@ pub struct FilePlayer {
     position_secs: f32,
     is_playing: bool,
     is_looping: bool,
+    /// Loop stored in the file, in frames (end exclusive).
+    loop_region: Option<(usize, usize)>,
     /// Active audio source mode.
     source_mode: SourceMode,
     /// Current generator signal type (GUI state mirror).
@@ -95,6 +108,8 @@ pub struct FilePlayer {
     gen_amplitude: f32,
     sample_rate: f32,
     has_file: bool,
+    /// Spectrogram and waveform of the loaded file.
+    spectrogram: SpectrogramView,
     /// Receives file path from background file dialog (native only).
     #[cfg(not(target_arch = "wasm32"))]
     native_file_rx: crossbeam_channel::Receiver<PathBuf>,
@@ -124,12 +139,14 @@ impl FilePlayer {
             position_secs: 0.0,
             is_playing: false,
             is_looping: true,
+            loop_region: None,
             source_mode: SourceMode::Generator,
             gen_signal_type: SignalType::Sine,
             gen_frequency: 440.0,
             gen_amplitude: 0.5,
             sample_rate: 48000.0,
             has_file: false,
+            spectrogram: SpectrogramView::default(),
             #[cfg(not(target_arch = "wasm32"))]
             native_file_rx,
             #[cfg(not(target_arch = "wasm32"))]
@@ -195,6 +212,52 @@ impl FilePlayer {
         }
     }
 
+    /// Start or resume playback. In file mode, no-op if no file is loaded.
+    pub fn play(&mut self) {
+        if self.source_mode == SourceMode::File && !self.has_file {
+            return;
+        }
+        self.is_playing = true;
+        let _ = self.transport_tx.send(TransportCommand::Play);
+    }
+
+    /// Pause playback, keeping the position.
+    pub fn pause(&mut self) {
+        self.is_playing = false;
+        let _ = self.transport_tx.send(TransportCommand::Pause);
+    }
+
+    /// Stop playback and return to the start.
+    pub fn stop(&mut self) {
+        self.is_playing = false;
+        self.position_secs = 0.0;
+        let _ = self.transport_tx.send(TransportCommand::Stop);
+    }
+
+    /// Seek the loaded file to `secs`, clamped to its duration.
+    pub fn seek(&mut self, secs: f32) {
+        let secs = secs.clamp(0.0, self.duration_secs);
+        self.position_secs = secs;
+        let _ = self.transport_tx.send(TransportCommand::Seek(secs));
+    }
+
+    /// Load a WAV file and switch the source to file playback (native only).
+    ///
+    /// Returns `false` if the file could not be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open_file(&mut self, path: PathBuf) -> bool {
+        if !self.load_file(path) {
+            return false;
+        }
+        if self.source_mode != SourceMode::File {
+            self.source_mode = SourceMode::File;
+            let _ = self
+                .transport_tx
+                .send(TransportCommand::SetSourceMode(SourceMode::File));
+        }
+        true
+    }
+
     /// Re-send current source mode, generator state, and file data to the
     /// audio thread.
     ///
@@ -230,6 +293,9 @@ impl FilePlayer {
             let _ = self
                 .transport_tx
                 .send(TransportCommand::SetLoop(self.is_looping));
+            let _ = self
+                .transport_tx
+                .send(TransportCommand::SetLoopRegion(self.loop_region));
             if self.position_secs > 0.0 {
                 let _ = self
                     .transport_tx
@@ -242,14 +308,16 @@ impl FilePlayer {
     }
 
     /// Load a WAV file from disk (native only).
+    ///
+    /// Returns `false` if the file could not be read.
     #[cfg(not(target_arch = "wasm32"))]
-    fn load_file(&mut self, path: PathBuf) {
+    fn load_file(&mut self, path: PathBuf) -> bool {
         // Read metadata first for duration
         let info = match read_wav_info(&path) {
             Ok(info) => info,
             Err(e) => {
                 tracing::error!("Failed to read WAV info: {e}");
-                return;
+                return false;
             }
         };
 
@@ -258,9 +326,13 @@ impl FilePlayer {
             Ok(s) => s,
             Err(e) => {
                 tracing::error!("Failed to load WAV: {e}");
-                return;
+                return false;
             }
         };
+        let metadata = read_wav_metadata(&path).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring WAV metadata: {e}");
+            WavMetadata::default()
+        });
 
         self.file_name = path
             .file_name()
@@ -272,6 +344,13 @@ impl FilePlayer {
         self.is_playing = false;
         self.file_path = Some(path);
         self.has_file = true;
+        self.loop_region = metadata
+            .loop_frames()
+            .filter(|&(_, end)| end <= samples.len());
+        self.spectrogram
+            .analyze(mono_mix(&samples.left, &samples.right), self.sample_rate);
+        self.spectrogram
+            .set_markers(file_markers(&metadata, self.sample_rate));
 
         let _ = self.transport_tx.send(TransportCommand::LoadFile {
             left: samples.left,
@@ -282,6 +361,10 @@ impl FilePlayer {
         let _ = self
             .transport_tx
             .send(TransportCommand::SetLoop(self.is_looping));
+        let _ = self
+            .transport_tx
+            .send(TransportCommand::SetLoopRegion(self.loop_region));
+        true
     }
 
     /// Load a WAV file from raw bytes (wasm).
@@ -348,6 +431,8 @@ impl FilePlayer {
         self.position_secs = 0.0;
         self.is_playing = false;
         self.has_file = true;
+        self.spectrogram
+            .analyze(mono_mix(&left, &right), sample_rate);
 
         let _ = self.transport_tx.send(TransportCommand::LoadFile {
             left,
@@ -749,7 +834,16 @@ impl FilePlayer {
                 } else {
                     theme.colors.dim
                 };
-                if arcade_led_button(ui, "L", loop_color, self.is_looping, &theme).clicked() {
+                let loop_button = arcade_led_button(ui, "L", loop_color, self.is_looping, &theme);
+                let loop_button = match self.loop_region {
+                    Some((start, end)) => loop_button.on_hover_text(format!(
+                        "Loop {:.2}s - {:.2}s (stored in file)",
+                        start as f32 / self.sample_rate,
+                        end as f32 / self.sample_rate
+                    )),
+                    None => loop_button.on_hover_text("Loop whole file"),
+                };
+                if loop_button.clicked() {
                     self.is_looping = !self.is_looping;
                     let _ = self
                         .transport_tx
@@ -782,6 +876,16 @@ impl FilePlayer {
             }
         });
 
+        if self.has_file {
+            let height = (ui.available_height() - 4.0).clamp(48.0, 160.0);
+            if let Some(secs) = self
+                .spectrogram
+                .ui(ui, self.position_secs, self.is_playing, height)
+            {
+                self.seek(secs);
+            }
+        }
+
         // Handle drag-and-drop (native only — wasm drag-and-drop doesn't provide paths)
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -811,6 +915,35 @@ impl FilePlayer {
     }
 }
 
+/// Waveform markers for a file's cue points and loop boundaries.
+#[cfg(not(target_arch = "wasm32"))]
+fn file_markers(metadata: &WavMetadata, sample_rate: f32) -> Vec<(f32, String)> {
+    let mut markers: Vec<(f32, String)> = metadata
+        .cues
+        .iter()
+        .map(|cue| {
+            let label = cue
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("Cue {}", cue.id));
+            (cue.frame as f32 / sample_rate, label)
+        })
+        .collect();
+    if let Some((start, end)) = metadata.loop_frames() {
+        markers.push((start as f32 / sample_rate, "Loop".into()));
+        markers.push((end as f32 / sample_rate, "Loop end".into()));
+    }
+    markers
+}
+
+/// Average two channels into one for analysis.
+fn mono_mix(left: &[f32], right: &[f32]) -> Vec<f32> {
+    left.iter()
+        .zip(right)
+        .map(|(&l, &r)| (l + r) * 0.5)
+        .collect()
+}
+
 /// Format seconds as `M:SS`.
 fn format_time(secs: f32) -> String {
     let total = secs.max(0.0) as u32;
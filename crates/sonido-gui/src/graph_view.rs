@@ -5,6 +5,11 @@
 //! [`ProcessingGraph`] via
 //! [`compile_to_engine()`](GraphView::compile_to_engine), producing a
 //! [`GraphCommand::ReplaceTopology`] for atomic swap on the audio thread.
+//!
+//! With two rigs (A/B), each rig has its own [`GraphView`];
+//! [`compile_rigs()`](GraphView::compile_rigs) builds both into one graph on
+//! separate bus pairs. Rig B's effect slots follow rig A's, so every slot
+//! index a view reports is offset by its [`slot_offset`](GraphView::slot_offset).
 
 use std::collections::{HashMap, HashSet};
 
@@ -12,21 +17,34 @@ use egui::{Color32, FontId, RichText, Stroke, Ui};
 use egui_snarl::ui::{PinInfo, SnarlStyle, SnarlViewer};
 use egui_snarl::{InPin, InPinId, NodeId, OutPin, OutPinId, Snarl};
 
-use sonido_core::graph::{GraphEngine, MAX_SPLIT_TARGETS, ProcessingGraph};
+use sonido_core::graph::{BypassMode, GraphEngine, ProcessingGraph, SEND_MAX_DB, SendTap};
 use sonido_core::{ParamDescriptor, SmoothingStyle};
 use sonido_gui_core::theme::SonidoTheme;
 use sonido_gui_core::widgets::glow;
-use sonido_registry::{EffectCategory, EffectRegistry};
+use sonido_registry::{EffectCategory, EffectDescriptor, EffectRegistry};
 
 use crate::chain_manager::GraphCommand;
 
-/// Maximum number of fan-out/fan-in ports on Split/Merge nodes.
-const MAX_PORTS: usize = MAX_SPLIT_TARGETS;
+/// Number of fan-out/fan-in ports drawn on Split/Merge nodes.
+///
+/// Only limits the legacy explicit nodes: fan-out wired straight from a
+/// node's output compiles to an auto-inserted Split of any width.
+const MAX_PORTS: usize = 8;
 
 /// Horizontal offset (pixels) for auto-inserted Merge nodes relative to their
 /// downstream target.
 const AUTO_MERGE_OFFSET_PX: f32 = 80.0;
 
+/// Lowest level offered by a Send node's level control.
+const SEND_MIN_DB: f32 = -60.0;
+
+/// Output pin of a Send node that feeds its aux bus; pin 0 is the through path.
+const SEND_AUX_PIN: usize = 1;
+
+/// Horizontal spacing (pixels) between effects laid out by
+/// [`GraphView::set_serial_order`].
+const SERIAL_SPACING_PX: f32 = 220.0;
+
 /// A node in the visual graph editor.
 #[derive(Clone, Debug)]
 pub enum SonidoNode {
@@ -46,14 +64,38 @@ pub enum SonidoNode {
         descriptors: Vec<ParamDescriptor>,
         /// Per-parameter smoothing hints.
         smoothing: Vec<SmoothingStyle>,
+        /// What the effect outputs while bypassed.
+        bypass_mode: BypassMode,
     },
     /// Signal splitter: 1 input, up to 8 outputs.
     Split,
     /// Signal merger: up to 8 inputs, 1 output.
     Merge,
+    /// Aux bus: sums up to 8 sends (or other inputs) at unity, 1 output.
+    AuxBus,
+    /// Inline send: 1 input; output 0 passes the signal through, output 1
+    /// feeds the aux bus it is wired to.
+    Send {
+        /// Send level in dB.
+        level_db: f32,
+        /// Pre- or post-fader tap.
+        tap: SendTap,
+    },
 }
 
 impl SonidoNode {
+    /// Effect node for a registry entry, with default bypass mode.
+    pub fn effect(desc: &EffectDescriptor) -> Self {
+        SonidoNode::Effect {
+            effect_id: desc.id,
+            name: desc.name,
+            category: desc.category,
+            descriptors: collect_descriptors(desc.id, 48000.0),
+            smoothing: collect_smoothing(desc.id, 48000.0),
+            bypass_mode: BypassMode::Dry,
+        }
+    }
+
     /// Convert to a serializable session node.
     pub fn to_session(&self) -> crate::session::SessionNode {
         match self {
@@ -64,6 +106,11 @@ impl SonidoNode {
             },
             SonidoNode::Split => crate::session::SessionNode::Split,
             SonidoNode::Merge => crate::session::SessionNode::Merge,
+            SonidoNode::AuxBus => crate::session::SessionNode::AuxBus,
+            SonidoNode::Send { level_db, tap } => crate::session::SessionNode::Send {
+                level_db: *level_db,
+                pre_fader: *tap == SendTap::PreFader,
+            },
         }
     }
 }
@@ -86,6 +133,9 @@ pub enum CompileError {
     /// An effect could not be created from the registry.
     #[error("failed to create effect '{0}' from registry")]
     EffectCreation(String),
+    /// A Send node's aux output is not wired.
+    #[error("send has no aux bus wired to its aux output")]
+    UnwiredSend,
     /// Graph compilation failed.
     #[error("graph compilation failed: {0}")]
     GraphError(#[from] sonido_core::graph::GraphError),
@@ -105,12 +155,35 @@ pub struct GraphView {
     /// Set to `true` when a connect/disconnect/remove changes the topology.
     /// Checked by the app after `show()` to trigger auto-compile.
     pub topology_changed: bool,
+    /// Set when the user picks a new bypass mode from a node's context menu:
+    /// `(effect slot, mode)`. Checked by the app after `show()` to forward
+    /// the change to the audio thread without recompiling.
+    pub bypass_mode_changed: Option<(usize, BypassMode)>,
     /// Per-effect-slot activity level (0.0--1.0), updated each frame from
     /// audio-thread metering data. Drives the glow LED on each effect node.
     pub slot_activity: Vec<f32>,
     /// Per-effect-slot L/R peak levels (0.0--1.0), updated each frame from
     /// audio-thread metering data. Drives the inline L/R meter strips.
     pub slot_peaks: Vec<(f32, f32)>,
+    /// Engine slot of this view's first effect: 0 for rig A (or a single
+    /// rig), rig A's effect count for rig B. Added to every slot index the
+    /// view reports.
+    pub slot_offset: usize,
+    /// Effect nodes in slot order as of the last topology sent to the audio
+    /// thread. Nodes still present at the next compile keep their running
+    /// instances.
+    applied_slots: Vec<NodeId>,
+    /// [`slot_offset`](Self::slot_offset) as of the last applied topology.
+    applied_offset: usize,
+}
+
+/// Effect slots gathered while building one or more views into a graph.
+#[derive(Default)]
+struct GraphBuild {
+    manifest: Vec<(sonido_core::graph::NodeId, &'static str)>,
+    effect_ids: Vec<&'static str>,
+    slot_descriptors: Vec<Vec<ParamDescriptor>>,
+    carry: Vec<(usize, usize)>,
 }
 
 impl GraphView {
@@ -143,8 +216,12 @@ impl GraphView {
             selected_node: None,
             style,
             topology_changed: false,
+            bypass_mode_changed: None,
             slot_activity: Vec::new(),
             slot_peaks: Vec::new(),
+            slot_offset: 0,
+            applied_slots: Vec::new(),
+            applied_offset: 0,
         }
     }
 
@@ -205,6 +282,7 @@ impl GraphView {
     /// all Effect nodes in the graph (useful for param-bridge indexing).
     pub fn show(&mut self, ui: &mut Ui) -> Option<usize> {
         self.topology_changed = false;
+        self.bypass_mode_changed = None;
         self.pin_io_nodes();
         let theme = SonidoTheme::get(ui.ctx());
         let mut click_handled = false;
@@ -212,12 +290,17 @@ impl GraphView {
             selected_node: &mut self.selected_node,
             click_handled: &mut click_handled,
             topology_changed: &mut self.topology_changed,
+            bypass_mode_changed: &mut self.bypass_mode_changed,
             theme,
             slot_activity: &self.slot_activity,
             slot_peaks: &self.slot_peaks,
         };
         self.snarl
             .show(&mut viewer, &self.style, "sonido_graph", ui);
+        let offset = self.slot_offset;
+        self.bypass_mode_changed = self
+            .bypass_mode_changed
+            .map(|(slot, mode)| (offset + slot, mode));
 
         // Click on empty space deselects — only within the graph area.
         // Without the rect check, clicks on the effect panel (below the graph)
@@ -230,18 +313,157 @@ impl GraphView {
             self.selected_node = None;
         }
 
-        // Map selected NodeId to an effect slot index.
-        let selected = self.selected_node?;
-        let mut slot = 0usize;
+        self.selected_slot()
+    }
+
+    /// Slot index of the selected effect node, if any.
+    ///
+    /// Slots number Effect nodes in graph order from
+    /// [`slot_offset`](Self::slot_offset), matching the param bridge.
+    pub fn selected_slot(&self) -> Option<usize> {
+        self.slot_of(self.selected_node?)
+    }
+
+    /// Select the effect node `delta` slots away from the current selection,
+    /// wrapping at either end. With nothing selected, a positive step selects
+    /// the first effect and a negative step the last.
+    ///
+    /// Returns the newly selected slot, or `None` if the graph has no effects.
+    pub fn select_adjacent_effect(&mut self, delta: isize) -> Option<usize> {
+        let ids: Vec<NodeId> = self.effect_node_ids().collect();
+        if ids.is_empty() {
+            return None;
+        }
+        let count = ids.len() as isize;
+        let slot = match self.selected_slot() {
+            Some(current) => ((current - self.slot_offset) as isize + delta).rem_euclid(count),
+            None if delta < 0 => count - 1,
+            None => 0,
+        } as usize;
+        self.selected_node = Some(ids[slot]);
+        Some(self.slot_offset + slot)
+    }
+
+    /// Effect node IDs in slot order.
+    pub fn effect_node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.snarl
+            .node_ids()
+            .filter(|(_, node)| matches!(node, SonidoNode::Effect { .. }))
+            .map(|(id, _)| id)
+    }
+
+    /// Effect nodes in signal order, if the graph is one serial chain.
+    ///
+    /// A serial chain is Input → effects → Output with one wire per hop, no
+    /// Split/Merge nodes, and no unconnected effects. Returns `None` for any
+    /// other topology.
+    pub fn serial_chain(&self) -> Option<Vec<NodeId>> {
+        let mut input = None;
+        let mut output = None;
+        let mut effect_count = 0;
         for (id, node) in self.snarl.node_ids() {
-            if matches!(node, SonidoNode::Effect { .. }) {
-                if id == selected {
-                    return Some(slot);
-                }
-                slot += 1;
+            match node {
+                SonidoNode::Input => input = Some(id),
+                SonidoNode::Output => output = Some(id),
+                SonidoNode::Effect { .. } => effect_count += 1,
+                SonidoNode::Split
+                | SonidoNode::Merge
+                | SonidoNode::AuxBus
+                | SonidoNode::Send { .. } => return None,
+            }
+        }
+        let (input, output) = (input?, output?);
+
+        let mut next: HashMap<NodeId, NodeId> = HashMap::new();
+        for (out_pin, in_pin) in self.snarl.wires() {
+            if next.insert(out_pin.node, in_pin.node).is_some() {
+                return None; // fan-out
             }
         }
-        None
+        // Input and every effect each have exactly one outgoing wire.
+        if next.len() != effect_count + 1 {
+            return None;
+        }
+
+        let mut chain = Vec::with_capacity(effect_count);
+        let mut node = input;
+        loop {
+            node = *next.get(&node)?;
+            if node == output {
+                break;
+            }
+            if chain.contains(&node) || chain.len() == effect_count {
+                return None; // cycle
+            }
+            chain.push(node);
+        }
+        (chain.len() == effect_count).then_some(chain)
+    }
+
+    /// Rewire the graph as a serial chain running `order`, laying the
+    /// effects out left to right.
+    ///
+    /// Every existing wire is replaced. Sets
+    /// [`topology_changed`](Self::topology_changed) so the app recompiles.
+    pub fn set_serial_order(&mut self, order: &[NodeId]) {
+        let mut input = None;
+        let mut output = None;
+        for (id, node) in self.snarl.node_ids() {
+            match node {
+                SonidoNode::Input => input = Some(id),
+                SonidoNode::Output => output = Some(id),
+                _ => {}
+            }
+        }
+        let (Some(input), Some(output)) = (input, output) else {
+            return;
+        };
+
+        let wires: Vec<(OutPinId, InPinId)> = self.snarl.wires().collect();
+        for (from, to) in wires {
+            self.snarl.disconnect(from, to);
+        }
+
+        let path: Vec<NodeId> = std::iter::once(input)
+            .chain(order.iter().copied())
+            .chain(std::iter::once(output))
+            .collect();
+        for hop in path.windows(2) {
+            self.snarl.connect(
+                OutPinId {
+                    node: hop[0],
+                    output: 0,
+                },
+                InPinId {
+                    node: hop[1],
+                    input: 0,
+                },
+            );
+        }
+
+        // Input/Output follow the effects via pin_io_nodes().
+        for (i, &id) in order.iter().enumerate() {
+            if let Some(info) = self.snarl.get_node_info_mut(id) {
+                info.pos = egui::pos2(200.0 + i as f32 * SERIAL_SPACING_PX, 200.0);
+            }
+        }
+        self.topology_changed = true;
+    }
+
+    /// Add an unwired effect node. Returns `None` if `effect_id` is unknown.
+    pub fn add_effect(&mut self, effect_id: &str, registry: &EffectRegistry) -> Option<NodeId> {
+        let desc = registry.get(effect_id)?;
+        Some(
+            self.snarl
+                .insert_node(egui::pos2(200.0, 200.0), SonidoNode::effect(desc)),
+        )
+    }
+
+    /// Slot index of an effect node (its position in param-bridge order).
+    pub fn slot_of(&self, node: NodeId) -> Option<usize> {
+        self.effect_node_ids()
+            .position(|id| id == node)
+            .map(|slot| self.slot_offset + slot)
     }
 
     /// Count of [`SonidoNode::Effect`] nodes currently in the graph.
@@ -255,16 +477,28 @@ impl GraphView {
             .count()
     }
 
+    /// Records the current effect nodes as the topology running on the audio
+    /// thread. Call after sending the result of
+    /// [`compile_to_engine()`](Self::compile_to_engine) or
+    /// [`compile_rigs()`](Self::compile_rigs).
+    pub fn mark_applied(&mut self) {
+        self.applied_slots = self.effect_node_ids().collect();
+        self.applied_offset = self.slot_offset;
+    }
+
     /// Compiles the Snarl topology into a [`GraphCommand::ReplaceTopology`].
     ///
     /// Walks all nodes and connections, builds a [`ProcessingGraph`], creates
     /// effects via the registry, and produces a compiled engine ready for
-    /// atomic swap on the audio thread.
+    /// atomic swap on the audio thread. A Send node's aux output wire names
+    /// its aux bus rather than becoming a plain edge. Effect nodes that were
+    /// part of the last [applied](Self::mark_applied) topology are listed for
+    /// carry-over, so they keep their state across the swap.
     ///
     /// # Errors
     ///
     /// Returns [`CompileError`] if the graph is malformed (missing Input/Output,
-    /// unknown effects, cycles, etc.).
+    /// unknown effects, unwired sends, cycles, etc.).
     pub fn compile_to_engine(
         &self,
         sample_rate: f32,
@@ -272,12 +506,66 @@ impl GraphView {
         registry: &EffectRegistry,
     ) -> Result<GraphCommand, CompileError> {
         let mut graph = ProcessingGraph::new(sample_rate, block_size);
+        let mut build = GraphBuild::default();
+        self.build_into(&mut graph, &mut build, sample_rate, registry)?;
+        graph.compile()?;
+
+        let engine = GraphEngine::new_dag(graph, build.manifest);
+
+        Ok(GraphCommand::ReplaceTopology {
+            engine: Box::new(engine),
+            effect_ids: build.effect_ids,
+            slot_descriptors: build.slot_descriptors,
+            carry: build.carry,
+        })
+    }
+
+    /// Compiles two rigs fed from the same input into one dual-rig
+    /// [`GraphCommand::ReplaceTopology`].
+    ///
+    /// Rig A takes input/output bus 0 and the first slots; rig B takes bus 1
+    /// and the slots after them (see [`GraphEngine::new_dual()`]). Set
+    /// `rig_b`'s [`slot_offset`](Self::slot_offset) to rig A's effect count
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompileError`] if either rig fails to compile on its own.
+    pub fn compile_rigs(
+        rig_a: &GraphView,
+        rig_b: &GraphView,
+        sample_rate: f32,
+        block_size: usize,
+        registry: &EffectRegistry,
+    ) -> Result<GraphCommand, CompileError> {
+        let mut graph = ProcessingGraph::new(sample_rate, block_size);
+        let mut build = GraphBuild::default();
+        rig_a.build_into(&mut graph, &mut build, sample_rate, registry)?;
+        let rig_b_start = build.manifest.len();
+        rig_b.build_into(&mut graph, &mut build, sample_rate, registry)?;
+        graph.compile()?;
+
+        let engine = GraphEngine::new_dual(graph, build.manifest, rig_b_start)?;
+
+        Ok(GraphCommand::ReplaceTopology {
+            engine: Box::new(engine),
+            effect_ids: build.effect_ids,
+            slot_descriptors: build.slot_descriptors,
+            carry: build.carry,
+        })
+    }
 
+    /// Adds this view's nodes and wires to `graph`, appending its effects to
+    /// `build` in slot order. The graph is left uncompiled.
+    fn build_into(
+        &self,
+        graph: &mut ProcessingGraph,
+        build: &mut GraphBuild,
+        sample_rate: f32,
+        registry: &EffectRegistry,
+    ) -> Result<(), CompileError> {
         // Map Snarl NodeIds to ProcessingGraph NodeIds.
         let mut snarl_to_graph: HashMap<NodeId, sonido_core::graph::NodeId> = HashMap::new();
-        let mut manifest: Vec<(sonido_core::graph::NodeId, &'static str)> = Vec::new();
-        let mut slot_descriptors: Vec<Vec<ParamDescriptor>> = Vec::new();
-        let mut effect_ids: Vec<&'static str> = Vec::new();
 
         let mut input_count = 0u32;
         let mut output_count = 0u32;
@@ -302,24 +590,56 @@ impl GraphView {
                 SonidoNode::Effect {
                     effect_id,
                     descriptors,
+                    bypass_mode,
                     ..
                 } => {
                     let effect = registry
                         .create(effect_id, sample_rate)
                         .ok_or_else(|| CompileError::EffectCreation((*effect_id).to_string()))?;
                     let gid = graph.add_effect(effect);
-                    manifest.push((gid, effect_id));
-                    effect_ids.push(effect_id);
-                    slot_descriptors.push(descriptors.clone());
+                    graph.set_bypass_mode(gid, *bypass_mode);
+                    if let Some(old_slot) = self.applied_slots.iter().position(|&n| n == snarl_id) {
+                        build
+                            .carry
+                            .push((self.applied_offset + old_slot, build.manifest.len()));
+                    }
+                    build.manifest.push((gid, effect_id));
+                    build.effect_ids.push(effect_id);
+                    build.slot_descriptors.push(descriptors.clone());
                     gid
                 }
                 // Legacy Split/Merge from old sessions — preserve them.
                 SonidoNode::Split => graph.add_split(),
                 SonidoNode::Merge => graph.add_merge(),
+                SonidoNode::AuxBus => graph.add_aux_bus(),
+                // Sends need their aux bus to exist first; added below.
+                SonidoNode::Send { .. } => continue,
             };
             snarl_to_graph.insert(snarl_id, graph_id);
         }
 
+        for (snarl_id, node) in self.snarl.node_ids() {
+            if let SonidoNode::Send { level_db, tap } = node {
+                let aux = self
+                    .snarl
+                    .wires()
+                    .find(|(out_pin, _)| out_pin.node == snarl_id && out_pin.output == SEND_AUX_PIN)
+                    .and_then(|(_, in_pin)| snarl_to_graph.get(&in_pin.node).copied())
+                    .ok_or(CompileError::UnwiredSend)?;
+                let graph_id = graph.add_send(aux, *level_db, *tap)?;
+                snarl_to_graph.insert(snarl_id, graph_id);
+            }
+        }
+        // add_send() already made each send → aux edge.
+        let wires: Vec<(OutPinId, InPinId)> = self
+            .snarl
+            .wires()
+            .filter(|(out_pin, _)| {
+                out_pin.output != SEND_AUX_PIN
+                    || !matches!(self.snarl[out_pin.node], SonidoNode::Send { .. })
+            })
+            .collect();
+
         if input_count == 0 {
             return Err(CompileError::NoInput);
         }
@@ -332,7 +652,7 @@ impl GraphView {
         // Build per-snarl-node target/source lists from wires.
         let mut out_targets: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
         let mut in_sources: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
-        for (out_pin, in_pin) in self.snarl.wires() {
+        for &(out_pin, in_pin) in &wires {
             let targets = out_targets.entry(out_pin.node).or_default();
             if !targets.contains(&in_pin.node) {
                 targets.push(in_pin.node);
@@ -355,10 +675,10 @@ impl GraphView {
         }
 
         // Auto-insert Merges for fan-in: any node with >1 distinct sources
-        // that isn't already a Merge node.
+        // that doesn't already sum its inputs.
         let mut merge_map: HashMap<NodeId, sonido_core::graph::NodeId> = HashMap::new();
         for (&snarl_id, sources) in &in_sources {
-            if sources.len() > 1 && !matches!(self.snarl[snarl_id], SonidoNode::Merge) {
+            if sources.len() > 1 && !is_summing(&self.snarl[snarl_id]) {
                 let merge_gid = graph.add_merge();
                 let target_gid = snarl_to_graph[&snarl_id];
                 graph.connect(merge_gid, target_gid)?;
@@ -370,7 +690,7 @@ impl GraphView {
         // Deduplicate because multiple snarl pins can map to the same graph edge.
         let mut wired: HashSet<(sonido_core::graph::NodeId, sonido_core::graph::NodeId)> =
             HashSet::new();
-        for (out_pin, in_pin) in self.snarl.wires() {
+        for &(out_pin, in_pin) in &wires {
             let from = split_map
                 .get(&out_pin.node)
                 .copied()
@@ -384,15 +704,27 @@ impl GraphView {
             }
         }
 
-        graph.compile()?;
-
-        let engine = GraphEngine::new_dag(graph, manifest);
+        Ok(())
+    }
 
-        Ok(GraphCommand::ReplaceTopology {
-            engine: Box::new(engine),
-            effect_ids,
-            slot_descriptors,
-        })
+    /// Compiles the Snarl topology (without applying it) and renders the
+    /// result as GraphViz DOT: nodes, buffer assignments, latencies, and the
+    /// compiled schedule. Backs the developer menu's DOT export.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompileError`] under the same conditions as
+    /// [`compile_to_engine()`](Self::compile_to_engine).
+    pub fn to_dot(
+        &self,
+        sample_rate: f32,
+        block_size: usize,
+        registry: &EffectRegistry,
+    ) -> Result<String, CompileError> {
+        match self.compile_to_engine(sample_rate, block_size, registry)? {
+            GraphCommand::ReplaceTopology { engine, .. } => Ok(engine.to_dot()),
+            _ => unreachable!("compile_to_engine only produces ReplaceTopology"),
+        }
     }
 
     /// Capture the current graph state as a [`Session`](crate::session::Session).
@@ -411,9 +743,14 @@ impl GraphView {
         let mut nodes = Vec::new();
         let mut node_id_to_idx: HashMap<NodeId, usize> = HashMap::new();
 
+        let mut bypass_modes: HashMap<usize, BypassMode> = HashMap::new();
+
         for (id, node) in self.snarl.node_ids() {
             let idx = nodes.len();
             node_id_to_idx.insert(id, idx);
+            if let SonidoNode::Effect { bypass_mode, .. } = node {
+                bypass_modes.insert(idx, *bypass_mode);
+            }
             let pos = self
                 .snarl
                 .get_node_info(id)
@@ -435,7 +772,7 @@ impl GraphView {
         }
 
         let mut params = HashMap::new();
-        let mut effect_slot = 0usize;
+        let mut effect_slot = self.slot_offset;
         for (idx, entry) in nodes.iter().enumerate() {
             if let crate::session::SessionNode::Effect { ref effect_id } = entry.node {
                 let slot = SlotIndex(effect_slot);
@@ -449,6 +786,12 @@ impl GraphView {
                         effect_id: effect_id.clone(),
                         params: param_values,
                         bypassed: bridge.is_bypassed(slot),
+                        bypass_mode: bypass_modes
+                            .get(&idx)
+                            .copied()
+                            .unwrap_or_default()
+                            .label()
+                            .to_string(),
                     },
                 );
                 effect_slot += 1;
@@ -461,7 +804,15 @@ impl GraphView {
             wires,
             params,
             input_gain,
+            input_routing: String::new(),
             master_volume,
+            scenes: crate::scenes::SceneBank::default(),
+            expression: crate::expression::ExpressionMap::default(),
+            macros: Vec::new(),
+            locks: crate::preset_manager::ParamLocks::default(),
+            rig_b: None,
+            rig_morph: 0.0,
+            rig_output: String::new(),
         }
     }
 
@@ -480,7 +831,7 @@ impl GraphView {
         let mut snarl = Snarl::new();
         let mut idx_to_node_id: Vec<Option<NodeId>> = Vec::new();
 
-        for entry in &session.nodes {
+        for (idx, entry) in session.nodes.iter().enumerate() {
             let pos = egui::pos2(entry.pos[0], entry.pos[1]);
             let node = match &entry.node {
                 SessionNode::Input => SonidoNode::Input,
@@ -489,12 +840,18 @@ impl GraphView {
                     if let Some(desc) = registry.get(effect_id) {
                         let descriptors = collect_descriptors(desc.id, 48000.0);
                         let smoothing = collect_smoothing(desc.id, 48000.0);
+                        let bypass_mode = session
+                            .params
+                            .get(&idx)
+                            .and_then(|state| BypassMode::from_label(&state.bypass_mode))
+                            .unwrap_or_default();
                         SonidoNode::Effect {
                             effect_id: desc.id,
                             name: desc.name,
                             category: desc.category,
                             descriptors,
                             smoothing,
+                            bypass_mode,
                         }
                     } else {
                         tracing::warn!("unknown effect in session: {effect_id}");
@@ -504,6 +861,18 @@ impl GraphView {
                 }
                 SessionNode::Split => SonidoNode::Split,
                 SessionNode::Merge => SonidoNode::Merge,
+                SessionNode::AuxBus => SonidoNode::AuxBus,
+                SessionNode::Send {
+                    level_db,
+                    pre_fader,
+                } => SonidoNode::Send {
+                    level_db: *level_db,
+                    tap: if *pre_fader {
+                        SendTap::PreFader
+                    } else {
+                        SendTap::PostFader
+                    },
+                },
             };
             let id = snarl.insert_node(pos, node);
             idx_to_node_id.push(Some(id));
@@ -530,6 +899,8 @@ impl GraphView {
         self.snarl = snarl;
         self.selected_node = None;
         self.topology_changed = true;
+        // Restored node IDs say nothing about the running instances.
+        self.applied_slots.clear();
     }
 }
 
@@ -548,7 +919,7 @@ impl Default for GraphView {
 /// - Filter    -> yellow (caution / filter)
 /// - TimeBased -> purple (delay / reverb)
 /// - Utility   -> amber (brand primary / default)
-fn category_color(cat: EffectCategory, theme: &SonidoTheme) -> Color32 {
+pub(crate) fn category_color(cat: EffectCategory, theme: &SonidoTheme) -> Color32 {
     match cat {
         EffectCategory::Dynamics => theme.colors.cyan,
         EffectCategory::Distortion => theme.colors.red,
@@ -559,6 +930,11 @@ fn category_color(cat: EffectCategory, theme: &SonidoTheme) -> Color32 {
     }
 }
 
+/// Whether a node sums several inputs itself, so fan-in needs no auto-Merge.
+fn is_summing(node: &SonidoNode) -> bool {
+    matches!(node, SonidoNode::Merge | SonidoNode::AuxBus)
+}
+
 /// Color for structural nodes (Input, Output, Split, Merge) — uses theme dim.
 fn structural_color(theme: &SonidoTheme) -> Color32 {
     theme.colors.text_secondary
@@ -594,6 +970,8 @@ struct SonidoViewer<'a> {
     /// Set to `true` when a connect/disconnect/remove changes the topology,
     /// signalling the app to auto-compile.
     topology_changed: &'a mut bool,
+    /// Set when a node's bypass mode changes: `(effect slot, mode)`.
+    bypass_mode_changed: &'a mut Option<(usize, BypassMode)>,
     /// Arcade CRT theme snapshot for palette access.
     theme: SonidoTheme,
     /// Per-effect-slot activity level (0.0--1.0) for LED indicators.
@@ -621,6 +999,8 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
             SonidoNode::Effect { name, .. } => (*name).to_string(),
             SonidoNode::Split => "Split".to_string(),
             SonidoNode::Merge => "Merge".to_string(),
+            SonidoNode::AuxBus => "Aux Bus".to_string(),
+            SonidoNode::Send { .. } => "Send".to_string(),
         }
     }
 
@@ -750,7 +1130,8 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
             SonidoNode::Output => 1,
             SonidoNode::Effect { .. } => 1,
             SonidoNode::Split => 1,
-            SonidoNode::Merge => MAX_PORTS,
+            SonidoNode::Merge | SonidoNode::AuxBus => MAX_PORTS,
+            SonidoNode::Send { .. } => 1,
         }
     }
 
@@ -760,7 +1141,8 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
             SonidoNode::Output => 0,
             SonidoNode::Effect { .. } => 1,
             SonidoNode::Split => MAX_PORTS,
-            SonidoNode::Merge => 1,
+            SonidoNode::Merge | SonidoNode::AuxBus => 1,
+            SonidoNode::Send { .. } => 2,
         }
     }
 
@@ -782,13 +1164,19 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
         _scale: f32,
         snarl: &mut Snarl<SonidoNode>,
     ) -> impl egui_snarl::ui::SnarlPin + 'static {
-        // Wire color follows the source (output) node's category.
-        let color = self.node_accent(&snarl[pin.id.node]);
+        // Wire color follows the source (output) node's category; a send's
+        // aux output stands out from its through path.
+        let node = &snarl[pin.id.node];
+        let color = if matches!(node, SonidoNode::Send { .. }) && pin.id.output == SEND_AUX_PIN {
+            self.theme.colors.purple
+        } else {
+            self.node_accent(node)
+        };
         PinInfo::circle().with_fill(color).with_wire_color(color)
     }
 
     fn has_body(&mut self, node: &SonidoNode) -> bool {
-        matches!(node, SonidoNode::Effect { .. })
+        matches!(node, SonidoNode::Effect { .. } | SonidoNode::Send { .. })
     }
 
     fn show_body(
@@ -800,6 +1188,33 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
         _scale: f32,
         snarl: &mut Snarl<SonidoNode>,
     ) {
+        if let SonidoNode::Send { level_db, tap } = &mut snarl[node] {
+            // Send settings are baked into the compiled graph, so edits
+            // recompile — once per drag, not every frame of it.
+            let response = ui.add(
+                egui::DragValue::new(level_db)
+                    .range(SEND_MIN_DB..=SEND_MAX_DB)
+                    .speed(0.2)
+                    .suffix(" dB"),
+            );
+            if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                *self.topology_changed = true;
+            }
+            ui.horizontal(|ui| {
+                for option in SendTap::ALL {
+                    if ui
+                        .selectable_label(*tap == option, option.label())
+                        .clicked()
+                        && *tap != option
+                    {
+                        *tap = option;
+                        *self.topology_changed = true;
+                    }
+                }
+            });
+            return;
+        }
+
         if let SonidoNode::Effect {
             category,
             descriptors,
@@ -906,33 +1321,32 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
         ui.separator();
 
         if filter.is_empty() {
+            ui.menu_button("Routing", |ui| {
+                let nodes = [
+                    (
+                        "Send",
+                        SonidoNode::Send {
+                            level_db: -12.0,
+                            tap: SendTap::PostFader,
+                        },
+                    ),
+                    ("Aux Bus", SonidoNode::AuxBus),
+                ];
+                for (label, node) in nodes {
+                    if ui.button(label).clicked() {
+                        snarl.insert_node(pos, node);
+                        *self.topology_changed = true;
+                        ui.close_menu();
+                    }
+                }
+            });
             // Category submenus (existing behavior when no filter)
             let registry = EffectRegistry::new();
-            let categories = [
-                EffectCategory::Dynamics,
-                EffectCategory::Distortion,
-                EffectCategory::Modulation,
-                EffectCategory::Filter,
-                EffectCategory::TimeBased,
-                EffectCategory::Utility,
-            ];
-
-            for cat in categories {
+            for cat in EffectCategory::ALL {
                 ui.menu_button(cat.name(), |ui| {
                     for desc in registry.effects_in_category(cat) {
                         if ui.button(desc.name).clicked() {
-                            let descriptors = collect_descriptors(desc.id, 48000.0);
-                            let smoothing = collect_smoothing(desc.id, 48000.0);
-                            snarl.insert_node(
-                                pos,
-                                SonidoNode::Effect {
-                                    effect_id: desc.id,
-                                    name: desc.name,
-                                    category: desc.category,
-                                    descriptors,
-                                    smoothing,
-                                },
-                            );
+                            snarl.insert_node(pos, SonidoNode::effect(desc));
                             *self.topology_changed = true;
                             ui.close_menu();
                         }
@@ -951,18 +1365,7 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
                         .button(RichText::new(desc.name).color(cat_color))
                         .clicked()
                     {
-                        let descriptors = collect_descriptors(desc.id, 48000.0);
-                        let smoothing = collect_smoothing(desc.id, 48000.0);
-                        snarl.insert_node(
-                            pos,
-                            SonidoNode::Effect {
-                                effect_id: desc.id,
-                                name: desc.name,
-                                category: desc.category,
-                                descriptors,
-                                smoothing,
-                            },
-                        );
+                        snarl.insert_node(pos, SonidoNode::effect(desc));
                         *self.topology_changed = true;
                         // Clear filter for next open
                         ui.data_mut(|d| d.insert_temp::<String>(filter_id, String::new()));
@@ -1007,6 +1410,60 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
             snarl.insert_node(original_pos + offset, original);
             *self.topology_changed = true;
             ui.close_menu();
+            return;
+        }
+
+        // Swap the effect type in place. The node keeps its id, so the next
+        // compile carries the slot over and the bridge maps compatible
+        // settings (drive, tone, mix, output) onto the new effect.
+        if let SonidoNode::Effect {
+            effect_id,
+            bypass_mode,
+            ..
+        } = &snarl[node]
+        {
+            let (current, mode) = (*effect_id, *bypass_mode);
+            let registry = EffectRegistry::new();
+            ui.menu_button("Replace With", |ui| {
+                for cat in EffectCategory::ALL {
+                    ui.menu_button(cat.name(), |ui| {
+                        for desc in registry.effects_in_category(cat) {
+                            if desc.id != current && ui.button(desc.name).clicked() {
+                                let mut replacement = SonidoNode::effect(desc);
+                                if let SonidoNode::Effect { bypass_mode, .. } = &mut replacement {
+                                    *bypass_mode = mode;
+                                }
+                                snarl[node] = replacement;
+                                *self.topology_changed = true;
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                }
+            });
+        }
+
+        // Bypass mode — only effects have one. Applied live, no recompile.
+        let slot = snarl
+            .node_ids()
+            .filter(|(_, n)| matches!(n, SonidoNode::Effect { .. }))
+            .position(|(id, _)| id == node);
+        if let Some(slot) = slot
+            && let SonidoNode::Effect { bypass_mode, .. } = &mut snarl[node]
+        {
+            ui.menu_button("Bypass Mode", |ui| {
+                for mode in BypassMode::ALL {
+                    if ui
+                        .radio(*bypass_mode == mode, mode.label())
+                        .on_hover_text(bypass_mode_hint(mode))
+                        .clicked()
+                    {
+                        *bypass_mode = mode;
+                        *self.bypass_mode_changed = Some((slot, mode));
+                        ui.close_menu();
+                    }
+                }
+            });
         }
     }
 
@@ -1044,8 +1501,16 @@ impl SnarlViewer<SonidoNode> for SonidoViewer<'_> {
     }
 
     fn connect(&mut self, from: &OutPin, to: &InPin, snarl: &mut Snarl<SonidoNode>) {
-        if matches!(snarl[to.id.node], SonidoNode::Merge) {
-            // Merge nodes accept multiple inputs directly.
+        if matches!(snarl[from.id.node], SonidoNode::Send { .. }) && from.id.output == SEND_AUX_PIN
+        {
+            // A send feeds exactly one aux bus.
+            if !matches!(snarl[to.id.node], SonidoNode::AuxBus) {
+                return;
+            }
+            snarl.drop_outputs(from.id);
+            snarl.connect(from.id, to.id);
+        } else if is_summing(&snarl[to.id.node]) {
+            // Merge and aux bus nodes accept multiple inputs directly.
             snarl.connect(from.id, to.id);
         } else {
             // Check if the target already has an incoming wire.
@@ -1148,6 +1613,15 @@ fn insert_merge_between(
     );
 }
 
+/// Tooltip text for a bypass mode entry in the node context menu.
+fn bypass_mode_hint(mode: BypassMode) -> &'static str {
+    match mode {
+        BypassMode::Mute => "Bypassed effect outputs silence",
+        BypassMode::Tails => "Input passes dry; delay/reverb tails ring out",
+        _ => "Input passes through unchanged",
+    }
+}
+
 /// Collect parameter descriptors for an effect by creating a temporary instance.
 fn collect_descriptors(effect_id: &str, sample_rate: f32) -> Vec<ParamDescriptor> {
     let registry = EffectRegistry::new();
@@ -1161,15 +1635,18 @@ fn collect_descriptors(effect_id: &str, sample_rate: f32) -> Vec<ParamDescriptor
 
 /// Collect smoothing styles for an effect by creating a temporary instance.
 ///
-/// Uses the `KernelParams::smoothing()` function indirectly via the registry's
-/// param count. Since we cannot call the trait method without a concrete type,
-/// we store default smoothing for all params.
+/// Kernel effects report their `KernelParams::smoothing()` choice through
+/// each parameter's descriptor.
 fn collect_smoothing(effect_id: &str, sample_rate: f32) -> Vec<SmoothingStyle> {
     let registry = EffectRegistry::new();
     let Some(effect) = registry.create(effect_id, sample_rate) else {
         return Vec::new();
     };
-    // Default to Standard smoothing for all params since we cannot access
-    // the concrete KernelParams type through the trait-object registry.
-    vec![SmoothingStyle::default(); effect.effect_param_count()]
+    (0..effect.effect_param_count())
+        .map(|i| {
+            effect
+                .effect_param_info(i)
+                .map_or_else(SmoothingStyle::default, |d| d.smoothing)
+        })
+        .collect()
 }
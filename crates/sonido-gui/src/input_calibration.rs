@@ -0,0 +1,319 @@
+//! Input gain calibration: a guided measurement and per-device storage.
+//!
+//! [`InputCalibrationWizard`] walks the user through one measurement: play
+//! the loudest part, let the audio thread listen for a few seconds, then
+//! review the measured peak and RMS levels and the suggested input gain before
+//! applying it. Accepted results are kept per audio device in
+//! [`InputCalibrations`], which persists as JSON in `input_calibration.json`
+//! under the user config directory (native only) and is applied again the next
+//! time the app opens the same device.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+
+use egui::Context;
+use serde::{Deserialize, Serialize};
+use sonido_core::gain::{AUTO_TRIM_TARGET_DB, AUTO_TRIM_WINDOW_SECS};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+use crate::audio_bridge::{AudioBridge, TrimReading};
+
+/// Calibration file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const CALIBRATION_FILE: &str = "input_calibration.json";
+
+/// An accepted input calibration for one device.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCalibration {
+    /// Input gain in dB.
+    pub gain_db: f32,
+    /// Pre-gain peak level measured during calibration, in dBFS.
+    pub peak_db: f32,
+    /// Pre-gain RMS level measured during calibration, in dBFS.
+    pub rms_db: f32,
+}
+
+impl From<TrimReading> for DeviceCalibration {
+    fn from(reading: TrimReading) -> Self {
+        Self {
+            gain_db: reading.gain_db,
+            peak_db: reading.peak_db,
+            rms_db: reading.rms_db,
+        }
+    }
+}
+
+/// Accepted input calibrations keyed by audio device name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputCalibrations {
+    /// Calibration per device name.
+    pub devices: BTreeMap<String, DeviceCalibration>,
+}
+
+impl InputCalibrations {
+    /// Calibration stored for `device`, if any.
+    pub fn get(&self, device: &str) -> Option<DeviceCalibration> {
+        self.devices.get(device).copied()
+    }
+
+    /// Store (or replace) the calibration for `device`.
+    pub fn set(&mut self, device: &str, calibration: DeviceCalibration) {
+        self.devices.insert(device.to_string(), calibration);
+    }
+
+    /// Serialize to the calibration JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parse the calibration JSON format; missing fields keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Default calibration file path.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn default_path() -> PathBuf {
+        sonido_config::paths::user_config_dir().join(CALIBRATION_FILE)
+    }
+
+    /// Load calibrations from `path`, falling back to none if the file is
+    /// missing or unreadable.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_or_default(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                tracing::warn!(path = ?path, error = %e, "invalid input calibration file, ignoring");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save calibrations to `path`, creating the config directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or file I/O fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+/// Where the wizard is in its flow.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Step {
+    /// Instructions and a Start button.
+    #[default]
+    Ready,
+    /// The audio thread is measuring.
+    Listening,
+    /// A measurement finished; waiting for the user to apply or retry.
+    Measured(TrimReading),
+    /// The window finished without signal above the silence floor.
+    NoSignal,
+}
+
+impl Step {
+    /// Advance a listening step from the audio thread's state: the request
+    /// flag it clears when done and the reading it publishes on success.
+    fn poll(self, listening: bool, reading: Option<TrimReading>) -> Self {
+        match (self, reading) {
+            (Step::Listening, Some(reading)) => Step::Measured(reading),
+            (Step::Listening, None) if !listening => Step::NoSignal,
+            (step, _) => step,
+        }
+    }
+}
+
+/// Guided input-calibration dialog.
+#[derive(Debug, Default)]
+pub struct InputCalibrationWizard {
+    /// Whether the dialog is open.
+    pub open: bool,
+    step: Step,
+}
+
+impl InputCalibrationWizard {
+    /// Open the dialog at its first step.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.step = Step::Ready;
+    }
+
+    /// Draw the dialog for `device`, showing its `saved` calibration if any.
+    ///
+    /// Returns a calibration when the user applies one (a new measurement or
+    /// the saved one); the caller sets the input gain and stores it.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        audio: &AudioBridge,
+        device: &str,
+        saved: Option<DeviceCalibration>,
+    ) -> Option<DeviceCalibration> {
+        if !self.open {
+            return None;
+        }
+
+        let request = audio.auto_trim();
+        let measurement = audio.trim_measurement();
+        if self.step == Step::Listening {
+            self.step = self
+                .step
+                .poll(request.load(Ordering::Acquire), measurement.take());
+            ctx.request_repaint();
+        }
+
+        let mut applied = None;
+        let mut open = self.open;
+        egui::Window::new("Input Calibration")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(300.0)
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(format!("Device: {device}")).small());
+                if let Some(saved) = saved {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(format!("Saved gain: {:+.1} dB", saved.gain_db))
+                                .small(),
+                        );
+                        if ui.small_button("Use saved").clicked() {
+                            applied = Some(saved);
+                        }
+                    });
+                }
+                ui.separator();
+
+                match self.step {
+                    Step::Ready | Step::NoSignal => {
+                        if self.step == Step::NoSignal {
+                            ui.label("No signal detected. Check the source is playing.");
+                            ui.add_space(4.0);
+                        }
+                        ui.label(format!(
+                            "Play the loudest part you will perform, then press Start. \
+                             Sonido listens for {AUTO_TRIM_WINDOW_SECS:.0} s and suggests an \
+                             input gain that puts peaks at {AUTO_TRIM_TARGET_DB:.0} dBFS."
+                        ));
+                        ui.add_space(6.0);
+                        let start = ui.add_enabled(audio.is_running(), egui::Button::new("Start"));
+                        if start.clicked() {
+                            // Drop any stale result before listening again.
+                            let _ = measurement.take();
+                            measurement.set_progress(0.0);
+                            request.store(true, Ordering::Release);
+                            self.step = Step::Listening;
+                        }
+                    }
+                    Step::Listening => {
+                        ui.label("Listening… keep playing.");
+                        ui.add(egui::ProgressBar::new(measurement.progress()).show_percentage());
+                        if ui.button("Cancel").clicked() {
+                            request.store(false, Ordering::Release);
+                            self.step = Step::Ready;
+                        }
+                    }
+                    Step::Measured(reading) => {
+                        egui::Grid::new("input_calibration")
+                            .num_columns(2)
+                            .spacing([24.0, 4.0])
+                            .show(ui, |ui| {
+                                ui.label("Peak");
+                                ui.label(format!("{:.1} dBFS", reading.peak_db));
+                                ui.end_row();
+                                ui.label("RMS");
+                                ui.label(format!("{:.1} dBFS", reading.rms_db));
+                                ui.end_row();
+                                ui.label("Suggested gain");
+                                ui.label(format!("{:+.1} dB", reading.gain_db));
+                                ui.end_row();
+                            });
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Apply").clicked() {
+                                applied = Some(reading.into());
+                            }
+                            if ui.button("Measure again").clicked() {
+                                self.step = Step::Ready;
+                            }
+                        });
+                    }
+                }
+            });
+
+        if applied.is_some() {
+            open = false;
+        }
+        if !open && self.step == Step::Listening {
+            request.store(false, Ordering::Release);
+        }
+        if !open {
+            self.step = Step::Ready;
+        }
+        self.open = open;
+        applied
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const READING: TrimReading = TrimReading {
+        peak_db: -20.0,
+        rms_db: -32.5,
+        gain_db: 8.0,
+    };
+
+    #[test]
+    fn json_round_trip() {
+        let mut calibrations = InputCalibrations::default();
+        calibrations.set("Scarlett 2i2", READING.into());
+        calibrations.set("Built-in Output", READING.into());
+        let parsed = InputCalibrations::from_json(&calibrations.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, calibrations);
+        assert_eq!(
+            parsed.get("Scarlett 2i2").map(|c| c.gain_db),
+            Some(READING.gain_db)
+        );
+        assert_eq!(parsed.get("Unknown"), None);
+        assert_eq!(
+            InputCalibrations::from_json("{}").unwrap(),
+            InputCalibrations::default()
+        );
+        assert!(InputCalibrations::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn listening_step_follows_audio_thread() {
+        // Still measuring.
+        assert_eq!(Step::Listening.poll(true, None), Step::Listening);
+        // Window finished with signal.
+        assert_eq!(
+            Step::Listening.poll(false, Some(READING)),
+            Step::Measured(READING)
+        );
+        // Window finished on silence: flag cleared, nothing published.
+        assert_eq!(Step::Listening.poll(false, None), Step::NoSignal);
+        // Other steps ignore the audio thread.
+        assert_eq!(Step::Ready.poll(false, Some(READING)), Step::Ready);
+    }
+}
@@ -0,0 +1,236 @@
+//! Per-user knob interaction settings.
+//!
+//! Drag mode, sensitivity, and scroll-wheel support live in the theme's
+//! [`KnobConfig`], which every knob reads. They persist as JSON in
+//! `knob_settings.json` under the user config directory (native only);
+//! missing fields keep their defaults. [`KnobSettingsEditor`] is the settings
+//! dialog.
+
+use egui::Context;
+use serde::{Deserialize, Serialize};
+use sonido_gui_core::theme::{KnobConfig, KnobDragMode};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Settings file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const KNOB_SETTINGS_FILE: &str = "knob_settings.json";
+
+/// On-disk form of [`KnobConfig`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+struct KnobSettingsFile {
+    /// `"vertical"` or `"circular"`.
+    drag_mode: String,
+    sensitivity: f32,
+    scroll: bool,
+}
+
+impl Default for KnobSettingsFile {
+    fn default() -> Self {
+        Self::from_config(&KnobConfig::default())
+    }
+}
+
+impl KnobSettingsFile {
+    fn from_config(config: &KnobConfig) -> Self {
+        Self {
+            drag_mode: drag_mode_id(config.drag_mode).to_string(),
+            sensitivity: config.sensitivity,
+            scroll: config.scroll,
+        }
+    }
+
+    fn to_config(&self) -> KnobConfig {
+        let defaults = KnobConfig::default();
+        let drag_mode = match self.drag_mode.as_str() {
+            "vertical" => KnobDragMode::Vertical,
+            "circular" => KnobDragMode::Circular,
+            other => {
+                tracing::warn!(drag_mode = %other, "unknown knob drag mode, using default");
+                defaults.drag_mode
+            }
+        };
+        let range = KnobConfig::SENSITIVITY_RANGE;
+        let sensitivity = if self.sensitivity.is_finite() {
+            self.sensitivity.clamp(*range.start(), *range.end())
+        } else {
+            defaults.sensitivity
+        };
+        KnobConfig {
+            drag_mode,
+            sensitivity,
+            scroll: self.scroll,
+        }
+    }
+}
+
+/// Stable file identifier for a drag mode.
+fn drag_mode_id(mode: KnobDragMode) -> &'static str {
+    match mode {
+        KnobDragMode::Vertical => "vertical",
+        KnobDragMode::Circular => "circular",
+    }
+}
+
+/// Serialize knob settings to JSON.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn to_json(config: &KnobConfig) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&KnobSettingsFile::from_config(config))
+}
+
+/// Parse knob settings JSON. Out-of-range values are clamped and unknown
+/// drag modes fall back to the default.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed.
+pub fn from_json(json: &str) -> Result<KnobConfig, serde_json::Error> {
+    Ok(serde_json::from_str::<KnobSettingsFile>(json)?.to_config())
+}
+
+/// Default settings file path (`<config dir>/knob_settings.json`).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn default_path() -> PathBuf {
+    sonido_config::paths::user_config_dir().join(KNOB_SETTINGS_FILE)
+}
+
+/// Load knob settings from `path`, falling back to defaults if the file is
+/// missing or unreadable.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_or_default(path: &Path) -> KnobConfig {
+    match std::fs::read_to_string(path) {
+        Ok(json) => from_json(&json).unwrap_or_else(|e| {
+            tracing::warn!(path = ?path, error = %e, "invalid knob settings file, using defaults");
+            KnobConfig::default()
+        }),
+        Err(_) => KnobConfig::default(),
+    }
+}
+
+/// Save knob settings to `path`, creating the config directory if needed.
+///
+/// # Errors
+///
+/// Returns an error if serialization or file I/O fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(config: &KnobConfig, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, to_json(config)?)?;
+    Ok(())
+}
+
+/// Knob settings dialog.
+#[derive(Debug, Default)]
+pub struct KnobSettingsEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+}
+
+impl KnobSettingsEditor {
+    /// Draw the dialog. Returns `true` if `config` changed.
+    pub fn show(&mut self, ctx: &Context, config: &mut KnobConfig) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("Knob Settings")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("knob_settings")
+                    .num_columns(2)
+                    .spacing([24.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Drag");
+                        ui.horizontal(|ui| {
+                            changed |= ui
+                                .radio_value(
+                                    &mut config.drag_mode,
+                                    KnobDragMode::Vertical,
+                                    "Vertical",
+                                )
+                                .on_hover_text("Drag up/down; distance changes the value")
+                                .changed();
+                            changed |= ui
+                                .radio_value(
+                                    &mut config.drag_mode,
+                                    KnobDragMode::Circular,
+                                    "Circular",
+                                )
+                                .on_hover_text("Drag around the knob; the value follows the angle")
+                                .changed();
+                        });
+                        ui.end_row();
+
+                        ui.label("Sensitivity");
+                        changed |= ui
+                            .add(
+                                egui::Slider::new(
+                                    &mut config.sensitivity,
+                                    KnobConfig::SENSITIVITY_RANGE,
+                                )
+                                .logarithmic(true)
+                                .suffix("×"),
+                            )
+                            .changed();
+                        ui.end_row();
+
+                        ui.label("Scroll wheel");
+                        changed |= ui
+                            .checkbox(&mut config.scroll, "Adjust hovered knob")
+                            .changed();
+                        ui.end_row();
+                    });
+
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new("Hold Shift for fine control while dragging or scrolling.")
+                        .small()
+                        .weak(),
+                );
+                ui.add_space(4.0);
+                if ui.button("Reset to defaults").clicked() {
+                    *config = KnobConfig::default();
+                    changed = true;
+                }
+            });
+        self.open = open;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let config = KnobConfig {
+            drag_mode: KnobDragMode::Circular,
+            sensitivity: 2.0,
+            scroll: false,
+        };
+        let parsed = from_json(&to_json(&config).unwrap()).unwrap();
+        assert_eq!(parsed.drag_mode, KnobDragMode::Circular);
+        assert_eq!(parsed.sensitivity, 2.0);
+        assert!(!parsed.scroll);
+    }
+
+    #[test]
+    fn missing_and_invalid_fields_use_defaults() {
+        let config = from_json(r#"{"drag_mode":"spiral","sensitivity":100.0}"#).unwrap();
+        assert_eq!(config.drag_mode, KnobDragMode::Vertical);
+        assert_eq!(config.sensitivity, *KnobConfig::SENSITIVITY_RANGE.end());
+        assert!(config.scroll);
+        assert!(from_json("not json").is_err());
+    }
+}
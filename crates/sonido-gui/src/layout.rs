@@ -0,0 +1,192 @@
+//! Dockable workspace layout.
+//!
+//! The multi-effect window is a dock of [`Tile`]s: tabs can be dragged into
+//! splits, stacked, resized, closed, or torn out into floating windows. The
+//! arrangement persists as JSON in `layout.json` under the user config
+//! directory (native only); a missing or unreadable file gives
+//! [`default_layout`].
+
+use egui_dock::{DockState, NodeIndex};
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Layout file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const LAYOUT_FILE: &str = "layout.json";
+
+/// A panel in the workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tile {
+    /// Input meter, gain, and auto-trim.
+    Input,
+    /// Node graph of the effect chain.
+    Chain,
+    /// Pedal strip with drag-and-drop reordering and effect palette.
+    Pedals,
+    /// Parameters of the selected effect.
+    Editor,
+    /// Factory and user preset list.
+    Presets,
+    /// Scene footswitches and scene parameters.
+    Scenes,
+    /// Macro knobs.
+    Macros,
+    /// Output spectrum and scope.
+    Analyzer,
+    /// File player and signal generator.
+    Player,
+    /// Output meter and master volume.
+    Output,
+}
+
+impl Tile {
+    /// Every tile, in View menu order.
+    pub const ALL: [Tile; 10] = [
+        Tile::Chain,
+        Tile::Pedals,
+        Tile::Editor,
+        Tile::Presets,
+        Tile::Scenes,
+        Tile::Macros,
+        Tile::Analyzer,
+        Tile::Player,
+        Tile::Input,
+        Tile::Output,
+    ];
+
+    /// Tab title.
+    pub fn title(self) -> &'static str {
+        match self {
+            Tile::Input => "Input",
+            Tile::Chain => "Chain",
+            Tile::Pedals => "Pedals",
+            Tile::Editor => "Editor",
+            Tile::Presets => "Presets",
+            Tile::Scenes => "Scenes",
+            Tile::Macros => "Macros",
+            Tile::Analyzer => "Analyzer",
+            Tile::Player => "Player",
+            Tile::Output => "Output",
+        }
+    }
+}
+
+/// Default arrangement: input and output strips at the sides, the chain
+/// (graph and pedal tabs) above the editor in the middle, and presets,
+/// scenes, macros, analyzer, and player tabbed beside the editor.
+pub fn default_layout() -> DockState<Tile> {
+    let mut dock = DockState::new(vec![Tile::Input]);
+    let surface = dock.main_surface_mut();
+    let [_, rest] = surface.split_right(NodeIndex::root(), 0.09, vec![Tile::Chain, Tile::Pedals]);
+    let [center, _] = surface.split_right(rest, 0.9, vec![Tile::Output]);
+    let [_, bottom] = surface.split_below(center, 0.55, vec![Tile::Editor]);
+    surface.split_right(
+        bottom,
+        0.62,
+        vec![
+            Tile::Presets,
+            Tile::Scenes,
+            Tile::Macros,
+            Tile::Analyzer,
+            Tile::Player,
+        ],
+    );
+    dock
+}
+
+/// Whether `tile` is in the layout (docked or floating).
+pub fn is_open(dock: &DockState<Tile>, tile: Tile) -> bool {
+    dock.find_tab(&tile).is_some()
+}
+
+/// Close `tile` if it is open, otherwise add it to the focused tab group.
+pub fn toggle(dock: &mut DockState<Tile>, tile: Tile) {
+    match dock.find_tab(&tile) {
+        Some(location) => {
+            dock.remove_tab(location);
+        }
+        None => dock.push_to_focused_leaf(tile),
+    }
+}
+
+/// Serialize a layout to JSON.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn to_json(dock: &DockState<Tile>) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(dock)
+}
+
+/// Parse a layout from JSON.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed or names an unknown tile.
+pub fn from_json(json: &str) -> Result<DockState<Tile>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Default layout file path (`<config dir>/layout.json`).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn default_path() -> PathBuf {
+    sonido_config::paths::user_config_dir().join(LAYOUT_FILE)
+}
+
+/// Load a layout from `path`, falling back to [`default_layout`] if the file
+/// is missing or unreadable.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_or_default(path: &Path) -> DockState<Tile> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => from_json(&json).unwrap_or_else(|e| {
+            tracing::warn!(path = ?path, error = %e, "invalid layout file, using default");
+            default_layout()
+        }),
+        Err(_) => default_layout(),
+    }
+}
+
+/// Save a layout to `path`, creating the config directory if needed.
+///
+/// # Errors
+///
+/// Returns an error if serialization or file I/O fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(dock: &DockState<Tile>, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, to_json(dock)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_layout_has_every_tile_once() {
+        let dock = default_layout();
+        for tile in Tile::ALL {
+            assert!(is_open(&dock, tile), "{tile:?} missing");
+        }
+        assert_eq!(dock.iter_all_tabs().count(), Tile::ALL.len());
+    }
+
+    #[test]
+    fn toggle_and_json_round_trip() {
+        let mut dock = default_layout();
+        toggle(&mut dock, Tile::Analyzer);
+        assert!(!is_open(&dock, Tile::Analyzer));
+
+        let parsed = from_json(&to_json(&dock).unwrap()).unwrap();
+        assert!(!is_open(&parsed, Tile::Analyzer));
+        assert!(is_open(&parsed, Tile::Presets));
+
+        toggle(&mut dock, Tile::Analyzer);
+        assert!(is_open(&dock, Tile::Analyzer));
+        assert!(from_json(r#"{"surfaces":"nope"}"#).is_err());
+    }
+}
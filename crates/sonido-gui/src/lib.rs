@@ -3,17 +3,38 @@
 //! This crate provides a real-time audio effects GUI built on egui,
 //! designed for musicians and audio engineers.
 
+pub mod analyzer;
 pub mod app;
+pub mod appearance;
 pub mod atomic_param_bridge;
 pub mod audio_bridge;
 mod audio_processor;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod autosave;
 pub mod chain_manager;
+pub mod chain_view;
+pub mod effect_browser;
+pub mod expression;
 pub mod file_player;
 pub mod graph_view;
+pub mod input_calibration;
+pub mod knob_settings;
+pub mod layout;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod log_console;
+pub mod macros;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod midi_input;
 pub mod morph_state;
 pub mod preset_manager;
+pub mod program_map;
+pub mod scenes;
+#[cfg(all(feature = "script", not(target_arch = "wasm32")))]
+pub mod script_console;
 pub mod session;
+pub mod shortcuts;
 pub mod signal_generator;
+pub mod spectrogram_view;
 pub mod theme;
 pub mod widgets;
 
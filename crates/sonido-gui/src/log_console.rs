@@ -0,0 +1,385 @@
+//! In-app log console (native only).
+//!
+//! [`LogLayer`] is a `tracing_subscriber` layer that copies every event the
+//! subscriber lets through into a shared [`LogBuffer`], a bounded ring of
+//! formatted lines. [`LogConsole`] draws that buffer in a window with a level
+//! filter, a text filter, and a button that copies the visible lines to the
+//! clipboard for bug reports.
+//!
+//! The layer sits behind the subscriber's `RUST_LOG` filter, so the console
+//! never shows more than that filter admits; the console's level filter only
+//! narrows it further.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use egui::{Color32, Context, FontId, RichText, TextEdit};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::layer::{Context as LayerContext, Layer};
+
+/// Lines kept in a [`LogBuffer`] by default.
+pub const DEFAULT_CAPACITY: usize = 2000;
+
+/// Levels offered by the console filter, least to most verbose.
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// One captured log event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    /// Time since the buffer was created.
+    pub elapsed: Duration,
+    /// Event level.
+    pub level: Level,
+    /// Event target (module path by default).
+    pub target: String,
+    /// Message followed by the remaining fields as `key=value`.
+    pub message: String,
+}
+
+impl fmt::Display for LogLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:>9.3}s] {:>5} {}: {}",
+            self.elapsed.as_secs_f64(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+struct Ring {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    /// Bumped on every push and clear, so readers can tell when the ring
+    /// changed.
+    pushed: u64,
+}
+
+/// Bounded, thread-safe ring of log lines shared by [`LogLayer`] and
+/// [`LogConsole`]. Cloning shares the ring.
+#[derive(Clone)]
+pub struct LogBuffer {
+    ring: Arc<Mutex<Ring>>,
+    start: Instant,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// Create a buffer keeping the newest `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            ring: Arc::new(Mutex::new(Ring {
+                lines: VecDeque::with_capacity(capacity),
+                capacity,
+                pushed: 0,
+            })),
+            start: Instant::now(),
+        }
+    }
+
+    /// Append a line, dropping the oldest beyond the capacity.
+    pub fn push(&self, line: LogLine) {
+        let mut ring = self.lock();
+        if ring.capacity == 0 {
+            return;
+        }
+        if ring.lines.len() == ring.capacity {
+            ring.lines.pop_front();
+        }
+        ring.lines.push_back(line);
+        ring.pushed += 1;
+    }
+
+    /// Remove all lines.
+    pub fn clear(&self) {
+        let mut ring = self.lock();
+        ring.lines.clear();
+        ring.pushed += 1;
+    }
+
+    /// Number of lines held.
+    pub fn len(&self) -> usize {
+        self.lock().lines.len()
+    }
+
+    /// Whether the buffer holds no lines.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Formatted lines at or below `max_level` whose text contains `filter`
+    /// (case-insensitive), oldest first.
+    pub fn filtered(&self, max_level: Level, filter: &str) -> Vec<(Level, String)> {
+        let filter = filter.to_lowercase();
+        self.lock()
+            .lines
+            .iter()
+            .filter(|line| line.level <= max_level)
+            .map(|line| (line.level, line.to_string()))
+            .filter(|(_, text)| filter.is_empty() || text.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    /// Change counter: differs whenever lines were pushed or cleared.
+    fn generation(&self) -> u64 {
+        self.lock().pushed
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ring> {
+        // A panic while holding the lock cannot leave the ring inconsistent.
+        self.ring.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// `tracing_subscriber` layer that records events into a [`LogBuffer`].
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl LogLayer {
+    /// Record into `buffer`.
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+        // Events bridged from the `log` crate carry their real target and
+        // level in `log.*` fields.
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.buffer.push(LogLine {
+            elapsed: self.buffer.start.elapsed(),
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.finish(),
+        });
+    }
+}
+
+/// Collects the `message` field and the other fields as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(mut self) -> String {
+        if self.message.is_empty() {
+            self.fields.trim_start().to_owned()
+        } else {
+            self.message.push_str(&self.fields);
+            self.message
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else if !field.name().starts_with("log.") {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else if !field.name().starts_with("log.") {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Log console window state.
+pub struct LogConsole {
+    /// Whether the window is open.
+    pub open: bool,
+    buffer: LogBuffer,
+    max_level: Level,
+    filter: String,
+    /// Filtered lines and the buffer generation / filter they were built
+    /// for, so the lines are only rebuilt when something changed.
+    cache: Vec<(Level, String)>,
+    cache_key: Option<(u64, Level, String)>,
+}
+
+impl LogConsole {
+    /// Console over `buffer`, showing `INFO` and above.
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self {
+            open: false,
+            buffer,
+            max_level: Level::INFO,
+            filter: String::new(),
+            cache: Vec::new(),
+            cache_key: None,
+        }
+    }
+
+    /// Draw the console window.
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        egui::Window::new("Log")
+            .open(&mut open)
+            .default_size([640.0, 320.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("log_level")
+                        .selected_text(self.max_level.as_str())
+                        .show_ui(ui, |ui| {
+                            for level in LEVELS {
+                                ui.selectable_value(&mut self.max_level, level, level.as_str());
+                            }
+                        })
+                        .response
+                        .on_hover_text("Most verbose level shown");
+                    ui.add(
+                        TextEdit::singleline(&mut self.filter)
+                            .hint_text("Filter")
+                            .desired_width(160.0),
+                    );
+                    if ui
+                        .button("Copy")
+                        .on_hover_text("Copy the shown lines to the clipboard")
+                        .clicked()
+                    {
+                        let text = self
+                            .cache
+                            .iter()
+                            .map(|(_, line)| line.as_str())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        ctx.copy_text(text);
+                    }
+                    if ui.button("Clear").clicked() {
+                        self.buffer.clear();
+                    }
+                });
+                ui.separator();
+
+                self.refresh();
+                let font = FontId::monospace(11.0);
+                let row_height = ui.fonts(|f| f.row_height(&font));
+                egui::ScrollArea::both()
+                    .stick_to_bottom(true)
+                    .auto_shrink([false, false])
+                    .show_rows(ui, row_height, self.cache.len(), |ui, rows| {
+                        for (level, line) in &self.cache[rows] {
+                            let color = level_color(*level, ui);
+                            ui.label(RichText::new(line).font(font.clone()).color(color));
+                        }
+                    });
+            });
+        self.open = open;
+    }
+
+    /// Rebuild the filtered lines if the buffer or the filters changed.
+    fn refresh(&mut self) {
+        let key = (
+            self.buffer.generation(),
+            self.max_level,
+            self.filter.clone(),
+        );
+        if self.cache_key.as_ref() != Some(&key) {
+            self.cache = self.buffer.filtered(self.max_level, &self.filter);
+            self.cache_key = Some(key);
+        }
+    }
+}
+
+fn level_color(level: Level, ui: &egui::Ui) -> Color32 {
+    match level {
+        Level::ERROR => ui.visuals().error_fg_color,
+        Level::WARN => ui.visuals().warn_fg_color,
+        Level::INFO => ui.visuals().text_color(),
+        _ => ui.visuals().weak_text_color(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn line(level: Level, message: &str) -> LogLine {
+        LogLine {
+            elapsed: Duration::ZERO,
+            level,
+            target: "test".into(),
+            message: message.into(),
+        }
+    }
+
+    #[test]
+    fn buffer_drops_oldest_beyond_capacity() {
+        let buffer = LogBuffer::new(2);
+        for message in ["a", "b", "c"] {
+            buffer.push(line(Level::INFO, message));
+        }
+        let lines = buffer.filtered(Level::TRACE, "");
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].1.ends_with(": b"));
+        assert!(lines[1].1.ends_with(": c"));
+    }
+
+    #[test]
+    fn filter_by_level_and_text() {
+        let buffer = LogBuffer::default();
+        buffer.push(line(Level::ERROR, "device lost"));
+        buffer.push(line(Level::INFO, "device opened"));
+        buffer.push(line(Level::DEBUG, "block 12"));
+
+        assert_eq!(buffer.filtered(Level::INFO, "").len(), 2);
+        assert_eq!(buffer.filtered(Level::TRACE, "").len(), 3);
+        assert_eq!(buffer.filtered(Level::WARN, "").len(), 1);
+        assert_eq!(buffer.filtered(Level::TRACE, "DEVICE").len(), 2);
+    }
+
+    #[test]
+    fn layer_records_message_and_fields() {
+        let buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(LogLayer::new(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(slot = 3, name = "reverb", "param out of range");
+        });
+
+        let lines = buffer.filtered(Level::TRACE, "");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].0, Level::WARN);
+        assert!(
+            lines[0]
+                .1
+                .ends_with("param out of range slot=3 name=reverb"),
+            "{}",
+            lines[0].1
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Macro controls: one knob driving many parameters.
+//!
+//! [`MacroBank`] holds [`MACRO_COUNT`] knobs. Each drives any number of
+//! `(slot, param)` targets through its own [`ControlResponse`]: a min/max
+//! window, a response curve, and an invert switch. A single "Intensity"
+//! knob can then raise drive, mix, and reverb size together, each at its own
+//! rate. The bank is stored in presets and sessions as
+//! [`MacroConfig`]s, with parameters keyed by name so they survive
+//! parameter reordering.
+//!
+//! The macros tile draws the knobs; [`MacroEditor`] is the assignment
+//! dialog.
+
+use egui::{Context, FontId, RichText, Ui};
+use sonido_config::{MacroConfig, MacroTarget};
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::widgets::Knob;
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use sonido_platform::{ControlId, ControlMapper, ControlResponse};
+
+use crate::expression::{
+    Target, curve_editor, curve_from_config, curve_selector, curve_to_config, target_label,
+    target_picker,
+};
+use crate::preset_manager::{find_param_by_key, param_key};
+
+/// Number of macro knobs.
+pub const MACRO_COUNT: usize = 8;
+
+/// Maximum number of targets across all macros.
+pub const MAX_MACRO_TARGETS: usize = 64;
+
+/// Control ID of macro `index` (GUI namespace).
+fn control(index: usize) -> ControlId {
+    ControlId::gui(index as u8)
+}
+
+/// The macro knobs and their targets.
+#[derive(Debug, Clone)]
+pub struct MacroBank {
+    names: [String; MACRO_COUNT],
+    values: [f32; MACRO_COUNT],
+    mapper: ControlMapper<MAX_MACRO_TARGETS, Target>,
+}
+
+impl Default for MacroBank {
+    fn default() -> Self {
+        Self {
+            names: std::array::from_fn(default_name),
+            values: [0.0; MACRO_COUNT],
+            mapper: ControlMapper::new(),
+        }
+    }
+}
+
+/// `"Macro <n>"`, 1-based.
+fn default_name(index: usize) -> String {
+    format!("Macro {}", index + 1)
+}
+
+impl MacroBank {
+    /// Label of macro `index`.
+    pub fn name(&self, index: usize) -> &str {
+        &self.names[index]
+    }
+
+    /// Knob position of macro `index` (0.0–1.0).
+    pub fn value(&self, index: usize) -> f32 {
+        self.values[index]
+    }
+
+    /// Move macro `index` to `value` and set every parameter it drives.
+    pub fn set_value(&mut self, index: usize, value: f32, bridge: &dyn ParamBridge) {
+        self.values[index] = value.clamp(0.0, 1.0);
+        self.apply(index, bridge);
+    }
+
+    /// Set the targets of macro `index` from its current position. Targets
+    /// that no longer exist in the chain are skipped.
+    pub fn apply(&self, index: usize, bridge: &dyn ParamBridge) {
+        for ((slot, param), position) in self.mapper.resolve(control(index), self.values[index]) {
+            let (slot, param) = (SlotIndex(slot), ParamIndex(param));
+            if slot.0 < bridge.slot_count()
+                && let Some(desc) = bridge.param_descriptor(slot, param)
+            {
+                bridge.set(slot, param, desc.denormalize(position));
+            }
+        }
+    }
+
+    /// Targets of macro `index` with their responses.
+    pub fn targets(&self, index: usize) -> impl Iterator<Item = (Target, &ControlResponse)> {
+        self.mapper.targets(control(index))
+    }
+
+    /// Total number of targets across all macros.
+    pub fn target_count(&self) -> usize {
+        self.mapper.len()
+    }
+
+    /// Assign `target` to macro `index` over its full range. Returns `false`
+    /// if the target table is full; an existing assignment is left as is.
+    pub fn add_target(&mut self, index: usize, target: Target) -> bool {
+        self.response_mut(index, target).is_some()
+            || self
+                .mapper
+                .add_target(control(index), target, ControlResponse::FULL)
+    }
+
+    /// Remove one target from macro `index`.
+    pub fn remove_target(&mut self, index: usize, target: Target) {
+        self.mapper.remove_target(control(index), target);
+    }
+
+    /// Mutable response of one target of macro `index`.
+    pub fn response_mut(&mut self, index: usize, target: Target) -> Option<&mut ControlResponse> {
+        self.mapper.response_mut(control(index), target)
+    }
+
+    /// Build a bank from stored macros, resolving parameter keys against the
+    /// current chain. Targets naming a missing slot or parameter are dropped.
+    pub fn from_configs(configs: &[MacroConfig], bridge: &dyn ParamBridge) -> Self {
+        let mut bank = Self::default();
+        for (index, config) in configs.iter().take(MACRO_COUNT).enumerate() {
+            config.name.clone_into(&mut bank.names[index]);
+            bank.values[index] = config.value.clamp(0.0, 1.0);
+            for target in &config.targets {
+                let slot = SlotIndex(target.slot);
+                let param = (target.slot < bridge.slot_count())
+                    .then(|| find_param_by_key(bridge, slot, &target.param))
+                    .flatten();
+                let Some(param) = param else {
+                    tracing::warn!(
+                        macro_name = %config.name,
+                        slot = target.slot,
+                        param = %target.param,
+                        "macro target not in chain, dropping it"
+                    );
+                    continue;
+                };
+                let response = ControlResponse::new(
+                    curve_from_config(target.curve.clone()),
+                    target.min,
+                    target.max,
+                )
+                .inverted(target.invert);
+                if !bank
+                    .mapper
+                    .add_target(control(index), (target.slot, param.0), response)
+                {
+                    tracing::warn!("macro target table full, dropping target");
+                }
+            }
+        }
+        bank
+    }
+
+    /// Stored form of the bank. Macros after the last one in use are left
+    /// out; targets whose parameter is gone are dropped.
+    pub fn to_configs(&self, bridge: &dyn ParamBridge) -> Vec<MacroConfig> {
+        let mut configs: Vec<MacroConfig> = (0..MACRO_COUNT)
+            .map(|index| MacroConfig {
+                name: self.names[index].clone(),
+                value: self.values[index],
+                targets: self
+                    .targets(index)
+                    .filter_map(|((slot, param), response)| {
+                        let desc = (slot < bridge.slot_count())
+                            .then(|| bridge.param_descriptor(SlotIndex(slot), ParamIndex(param)))
+                            .flatten()?;
+                        Some(MacroTarget {
+                            slot,
+                            param: param_key(desc.name),
+                            min: response.min,
+                            max: response.max,
+                            curve: curve_to_config(&response.curve),
+                            invert: response.invert,
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+        while configs.last().is_some_and(|config| {
+            config.targets.is_empty() && config.name == default_name(configs.len() - 1)
+        }) {
+            configs.pop();
+        }
+        configs
+    }
+
+    /// Macros tile: one knob per macro, with an **Assign** button under each.
+    pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, editor: &mut MacroEditor) {
+        let theme = SonidoTheme::get(ui.ctx());
+        ui.horizontal_wrapped(|ui| {
+            for index in 0..MACRO_COUNT {
+                ui.vertical(|ui| {
+                    let mut value = self.values[index];
+                    let knob = ui
+                        .add(
+                            Knob::new(&mut value, 0.0, 1.0, &self.names[index])
+                                .default(0.0)
+                                .diameter(48.0)
+                                .format_percent(),
+                        )
+                        .on_hover_text(format!(
+                            "{} target(s)",
+                            self.mapper.targets(control(index)).count()
+                        ));
+                    if knob.changed() {
+                        self.set_value(index, value, bridge);
+                    }
+                    let editing = editor.open && editor.index == index;
+                    if ui
+                        .selectable_label(
+                            editing,
+                            RichText::new("Assign")
+                                .font(FontId::monospace(10.0))
+                                .color(theme.colors.text_secondary),
+                        )
+                        .clicked()
+                    {
+                        editor.edit(index);
+                    }
+                });
+            }
+        });
+    }
+}
+
+/// Macro assignment dialog.
+#[derive(Debug, Default)]
+pub struct MacroEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+    /// Macro being edited.
+    index: usize,
+    /// Target slot for the next added target.
+    slot: usize,
+    /// Target parameter for the next added target.
+    param: usize,
+    /// Target shown in the curve editor.
+    selected: Option<Target>,
+}
+
+impl MacroEditor {
+    /// Open the dialog on macro `index`.
+    pub fn edit(&mut self, index: usize) {
+        if self.index != index {
+            self.selected = None;
+        }
+        self.index = index.min(MACRO_COUNT - 1);
+        self.open = true;
+    }
+
+    /// Draw the dialog. Returns `true` if `bank` changed.
+    pub fn show(&mut self, ctx: &Context, bank: &mut MacroBank, bridge: &dyn ParamBridge) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let theme = SonidoTheme::get(ctx);
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("Macro")
+            .open(&mut open)
+            .default_width(440.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("macro_index")
+                        .selected_text(bank.name(self.index))
+                        .show_ui(ui, |ui| {
+                            for index in 0..MACRO_COUNT {
+                                if ui
+                                    .selectable_label(index == self.index, bank.name(index))
+                                    .clicked()
+                                {
+                                    self.edit(index);
+                                }
+                            }
+                        });
+                    ui.label("Name");
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut bank.names[self.index])
+                                .desired_width(120.0),
+                        )
+                        .changed();
+                });
+                ui.separator();
+                changed |= self.target_list(ui, bank, bridge, &theme);
+                ui.separator();
+                changed |= self.selected_curve(ui, bank, &theme);
+                ui.separator();
+                changed |= self.add_row(ui, bank, bridge);
+            });
+        self.open = open;
+        changed
+    }
+
+    /// One row per target: label, curve, min/max window, invert, remove.
+    fn target_list(
+        &mut self,
+        ui: &mut Ui,
+        bank: &mut MacroBank,
+        bridge: &dyn ParamBridge,
+        theme: &SonidoTheme,
+    ) -> bool {
+        let index = self.index;
+        let rows: Vec<Target> = bank.targets(index).map(|(target, _)| target).collect();
+        if rows.is_empty() {
+            ui.label(
+                RichText::new("No targets yet. Pick a parameter below, then Add.")
+                    .color(theme.colors.text_secondary)
+                    .italics(),
+            );
+            return false;
+        }
+
+        let mut changed = false;
+        let mut remove = None;
+        egui::Grid::new("macro_targets")
+            .num_columns(6)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                for target in rows {
+                    if ui
+                        .selectable_label(
+                            self.selected == Some(target),
+                            target_label(bridge, target),
+                        )
+                        .on_hover_text("Edit this target's curve")
+                        .clicked()
+                    {
+                        self.selected = Some(target);
+                    }
+                    let desc = bridge.param_descriptor(SlotIndex(target.0), ParamIndex(target.1));
+                    let Some(response) = bank.response_mut(index, target) else {
+                        ui.end_row();
+                        continue;
+                    };
+                    changed |= curve_selector(ui, ("macro", index, target), &mut response.curve);
+                    for (label, value) in [("Min", &mut response.min), ("Max", &mut response.max)] {
+                        let drag = ui.add(
+                            egui::DragValue::new(value)
+                                .range(0.0..=1.0)
+                                .speed(0.005)
+                                .fixed_decimals(2)
+                                .prefix(format!("{label} ")),
+                        );
+                        let drag = match &desc {
+                            Some(desc) => {
+                                drag.on_hover_text(desc.format_value(desc.denormalize(*value)))
+                            }
+                            None => drag,
+                        };
+                        changed |= drag.changed();
+                    }
+                    changed |= ui
+                        .checkbox(&mut response.invert, "Inv")
+                        .on_hover_text("Turn this target down as the macro goes up")
+                        .changed();
+                    if ui.small_button("✕").on_hover_text("Remove").clicked() {
+                        remove = Some(target);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(target) = remove {
+            bank.remove_target(index, target);
+            if self.selected == Some(target) {
+                self.selected = None;
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    /// Curve editor for the selected target.
+    fn selected_curve(&mut self, ui: &mut Ui, bank: &mut MacroBank, theme: &SonidoTheme) -> bool {
+        let Some(target) = self.selected else {
+            ui.label(
+                RichText::new("Select a target to edit its curve.")
+                    .color(theme.colors.text_secondary)
+                    .italics(),
+            );
+            return false;
+        };
+        let position = bank.value(self.index);
+        let Some(response) = bank.response_mut(self.index, target) else {
+            self.selected = None;
+            return false;
+        };
+        let position = if response.invert {
+            1.0 - position
+        } else {
+            position
+        };
+        ui.label(
+            RichText::new("Custom: drag points, double-click to add, right-click to remove")
+                .font(FontId::monospace(10.0))
+                .color(theme.colors.text_secondary),
+        );
+        curve_editor(ui, &mut response.curve, Some(position))
+    }
+
+    /// Target selectors and Add.
+    fn add_row(&mut self, ui: &mut Ui, bank: &mut MacroBank, bridge: &dyn ParamBridge) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            if !target_picker(ui, "macro", bridge, &mut self.slot, &mut self.param) {
+                return;
+            }
+            let full = bank.target_count() >= MAX_MACRO_TARGETS;
+            if ui
+                .add_enabled(!full, egui::Button::new("Add"))
+                .on_hover_text("Let this macro drive the parameter")
+                .clicked()
+            {
+                let target = (self.slot, self.param);
+                if bank.add_target(self.index, target) {
+                    self.selected = Some(target);
+                    changed = true;
+                }
+            }
+        });
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_config::CurveConfig;
+    use sonido_core::ParamDescriptor;
+    use std::sync::Mutex;
+
+    /// Two slots: "Drive" and "Mix" in slot 0, "Room Size" in slot 1.
+    struct Bridge {
+        values: Mutex<[[f32; 2]; 2]>,
+    }
+
+    impl Bridge {
+        fn new() -> Self {
+            Self {
+                values: Mutex::new([[0.0; 2]; 2]),
+            }
+        }
+
+        fn value(&self, slot: usize, param: usize) -> f32 {
+            self.values.lock().unwrap()[slot][param]
+        }
+    }
+
+    impl ParamBridge for Bridge {
+        fn slot_count(&self) -> usize {
+            2
+        }
+        fn effect_id(&self, slot: SlotIndex) -> &'static str {
+            if slot.0 == 0 { "distortion" } else { "reverb" }
+        }
+        fn param_count(&self, slot: SlotIndex) -> usize {
+            if slot.0 == 0 { 2 } else { 1 }
+        }
+        fn param_descriptor(&self, slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+            let name = match (slot.0, param.0) {
+                (0, 0) => "Drive",
+                (0, 1) => "Mix",
+                (1, 0) => "Room Size",
+                _ => return None,
+            };
+            Some(ParamDescriptor::custom(name, name, 0.0, 100.0, 0.0))
+        }
+        fn get(&self, slot: SlotIndex, param: ParamIndex) -> f32 {
+            self.value(slot.0, param.0)
+        }
+        fn set(&self, slot: SlotIndex, param: ParamIndex, value: f32) {
+            self.values.lock().unwrap()[slot.0][param.0] = value;
+        }
+        fn is_bypassed(&self, _slot: SlotIndex) -> bool {
+            false
+        }
+        fn set_bypassed(&self, _slot: SlotIndex, _bypassed: bool) {}
+    }
+
+    #[test]
+    fn one_macro_drives_several_targets() {
+        let bridge = Bridge::new();
+        let mut bank = MacroBank::default();
+        assert!(bank.add_target(0, (0, 0)));
+        assert!(bank.add_target(0, (0, 1)));
+        assert!(bank.add_target(0, (1, 0)));
+        *bank.response_mut(0, (0, 0)).unwrap() =
+            ControlResponse::new(sonido_platform::ResponseCurve::Linear, 0.2, 0.6);
+        bank.response_mut(0, (0, 1)).unwrap().invert = true;
+
+        bank.set_value(0, 0.5, &bridge);
+        assert!((bridge.value(0, 0) - 40.0).abs() < 1e-3);
+        assert!((bridge.value(0, 1) - 50.0).abs() < 1e-3);
+        bank.set_value(0, 1.0, &bridge);
+        assert!((bridge.value(0, 0) - 60.0).abs() < 1e-3);
+        assert!(bridge.value(0, 1).abs() < 1e-3);
+        assert!((bridge.value(1, 0) - 100.0).abs() < 1e-3);
+
+        // Other macros are independent
+        bank.set_value(1, 0.0, &bridge);
+        assert!((bridge.value(1, 0) - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn configs_round_trip_by_parameter_key() {
+        let bridge = Bridge::new();
+        let mut bank = MacroBank::default();
+        bank.add_target(2, (1, 0));
+        let response = bank.response_mut(2, (1, 0)).unwrap();
+        response.max = 0.7;
+        response.invert = true;
+        bank.set_value(2, 0.25, &bridge);
+
+        let configs = bank.to_configs(&bridge);
+        // Unused macros after the last one in use are left out
+        assert_eq!(configs.len(), 3);
+        assert_eq!(configs[2].targets[0].param, "room_size");
+        assert_eq!(configs[2].targets[0].curve, CurveConfig::Linear);
+
+        let restored = MacroBank::from_configs(&configs, &bridge);
+        assert_eq!(restored.value(2), 0.25);
+        let targets: Vec<_> = restored.targets(2).collect();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].0, (1, 0));
+        assert!(targets[0].1.invert);
+        assert_eq!(targets[0].1.max, 0.7);
+
+        // Keys that don't resolve are dropped
+        let mut stale = configs;
+        stale[2].targets[0].param = "decay".into();
+        assert_eq!(MacroBank::from_configs(&stale, &bridge).target_count(), 0);
+    }
+}
@@ -36,20 +36,46 @@ struct Args {
     /// Effect names match the registry IDs: distortion, reverb, compressor, etc.
     #[arg(long)]
     effect: Option<String>,
+
+    /// Also write log messages to this file (appended, without colors).
+    ///
+    /// Verbosity follows `RUST_LOG` (default: info).
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<std::path::PathBuf>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result<()> {
-    use tracing_subscriber::EnvFilter;
+    use sonido_gui::log_console::{LogBuffer, LogLayer};
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::{EnvFilter, fmt};
+
+    let args = Args::parse();
 
-    // Initialize tracing subscriber; bridge legacy log:: calls from eframe/egui
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+    // Log to stderr, the optional log file, and the in-app console; bridge
+    // legacy log:: calls from eframe/egui
+    let log_file = args.log_file.as_ref().and_then(|path| {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| eprintln!("cannot open log file {}: {e}", path.display()))
+            .ok()
+    });
+    let log_buffer = LogBuffer::default();
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .with(fmt::layer())
+        .with(log_file.map(|file| {
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(std::sync::Mutex::new(file))
+        }))
+        .with(LogLayer::new(log_buffer.clone()))
         .init();
     tracing_log::LogTracer::init().ok();
 
-    let args = Args::parse();
-
     tracing::info!("Starting Sonido GUI");
     tracing::info!(sample_rate = args.sample_rate, "audio config");
     tracing::info!(buffer_size = args.buffer_size, "audio config");
@@ -73,12 +99,10 @@ fn main() -> eframe::Result<()> {
         "Sonido",
         options,
         Box::new(move |cc| {
-            Ok(Box::new(SonidoApp::new(
-                cc,
-                effect.as_deref(),
-                Some(sample_rate),
-                Some(buffer_size),
-            )))
+            Ok(Box::new(
+                SonidoApp::new(cc, effect.as_deref(), Some(sample_rate), Some(buffer_size))
+                    .with_log_buffer(log_buffer),
+            ))
         }),
     )
 }
@@ -0,0 +1,157 @@
+//! MIDI input for presets, scenes, and expression pedals (native only).
+//!
+//! [`MidiControlInput`] listens on one MIDI input port and forwards program
+//! changes and control changes on any channel to the GUI thread, where they
+//! load presets, recall scenes, and drive expression mappings. The midir
+//! callback runs on the MIDI driver thread, so it only queues the message
+//! and wakes the UI.
+
+use crossbeam_channel::{Receiver, Sender};
+
+/// Client name shown to the MIDI system.
+const CLIENT_NAME: &str = "sonido";
+
+/// A MIDI message the GUI reacts to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidiMessage {
+    /// Program change (status `0xCn`).
+    ProgramChange(u8),
+    /// Control change (status `0xBn`).
+    ControlChange {
+        /// Controller number.
+        cc: u8,
+        /// Controller value.
+        value: u8,
+    },
+}
+
+impl MidiMessage {
+    /// Parse a raw MIDI message; other message types give `None`.
+    pub fn parse(message: &[u8]) -> Option<Self> {
+        match message {
+            [status, program, ..] if status & 0xF0 == 0xC0 => {
+                Some(Self::ProgramChange(program & 0x7F))
+            }
+            [status, cc, value, ..] if status & 0xF0 == 0xB0 => Some(Self::ControlChange {
+                cc: cc & 0x7F,
+                value: value & 0x7F,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Program and control change listener on one MIDI input port.
+pub struct MidiControlInput {
+    connection: Option<midir::MidiInputConnection<()>>,
+    /// Name of the connected port.
+    port: Option<String>,
+    tx: Sender<MidiMessage>,
+    rx: Receiver<MidiMessage>,
+}
+
+impl Default for MidiControlInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MidiControlInput {
+    /// Create a disconnected listener.
+    pub fn new() -> Self {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        Self {
+            connection: None,
+            port: None,
+            tx,
+            rx,
+        }
+    }
+
+    /// Names of the available MIDI input ports.
+    pub fn port_names() -> Vec<String> {
+        midir::MidiInput::new(CLIENT_NAME)
+            .map(|midi| {
+                midi.ports()
+                    .iter()
+                    .filter_map(|port| midi.port_name(port).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Name of the connected port, if any.
+    pub fn port(&self) -> Option<&str> {
+        self.port.as_deref()
+    }
+
+    /// Listen on the port called `name`, replacing any current connection.
+    /// `ctx` is repainted when a program or control change arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if MIDI is unavailable, the port is gone, or it
+    /// cannot be opened.
+    pub fn connect(&mut self, name: &str, ctx: &egui::Context) -> Result<(), String> {
+        self.disconnect();
+        let midi = midir::MidiInput::new(CLIENT_NAME).map_err(|e| e.to_string())?;
+        let port = midi
+            .ports()
+            .into_iter()
+            .find(|port| midi.port_name(port).is_ok_and(|n| n == name))
+            .ok_or_else(|| format!("MIDI port not found: {name}"))?;
+
+        let tx = self.tx.clone();
+        let ctx = ctx.clone();
+        let connection = midi
+            .connect(
+                &port,
+                "sonido-control",
+                move |_timestamp, message, _| {
+                    if let Some(message) = MidiMessage::parse(message) {
+                        let _ = tx.send(message);
+                        ctx.request_repaint();
+                    }
+                },
+                (),
+            )
+            .map_err(|e| e.to_string())?;
+        tracing::info!(port = name, "MIDI input connected");
+        self.connection = Some(connection);
+        self.port = Some(name.to_owned());
+        Ok(())
+    }
+
+    /// Close the connection.
+    pub fn disconnect(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            connection.close();
+        }
+        self.port = None;
+    }
+
+    /// Messages received since the last call, oldest first.
+    pub fn poll(&self) -> impl Iterator<Item = MidiMessage> + '_ {
+        self.rx.try_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_program_and_control_change_on_any_channel() {
+        use MidiMessage::{ControlChange, ProgramChange};
+        assert_eq!(MidiMessage::parse(&[0xC0, 3]), Some(ProgramChange(3)));
+        assert_eq!(MidiMessage::parse(&[0xCF, 127]), Some(ProgramChange(127)));
+        assert_eq!(
+            MidiMessage::parse(&[0xB3, 11, 64]),
+            Some(ControlChange { cc: 11, value: 64 })
+        );
+        assert_eq!(MidiMessage::parse(&[0xB0, 11]), None);
+        assert_eq!(MidiMessage::parse(&[0x90, 60, 100]), None);
+        assert_eq!(MidiMessage::parse(&[0xC0]), None);
+        assert_eq!(MidiMessage::parse(&[]), None);
+    }
+}
@@ -4,11 +4,28 @@
 //! [`ParamBridge`] for real-time atomic parameter access in the audio thread.
 //! Parameter mapping is fully generic — iterating bridge slots and descriptors
 //! instead of hand-mapping individual fields.
+//!
+//! Besides whole-chain presets, [`EffectPresetLibrary`] keeps single-effect
+//! presets: one slot's parameters, stored per effect type under
+//! `<presets>/effects/<effect_id>/`.
+//!
+//! [`ParamLocks`] marks slots and parameters that loading a chain preset
+//! leaves alone, so a level or gate threshold set for the room survives a
+//! preset change.
 
+use serde::{Deserialize, Serialize};
 #[cfg(not(target_arch = "wasm32"))]
-use sonido_config::paths::{ensure_user_presets_dir, list_user_presets, user_presets_dir};
+use sonido_config::MacroConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use sonido_config::paths::{
+    ensure_user_presets_dir, list_user_effect_presets, list_user_presets, user_effect_presets_dir,
+    user_presets_dir,
+};
 use sonido_config::{EffectConfig, Preset, factory_presets};
 use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+use std::collections::BTreeSet;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Convert bridge parameters to a sonido_config::Preset.
@@ -33,22 +50,70 @@ pub fn params_to_preset(
     }
 
     for slot_raw in 0..bridge.slot_count() {
-        let slot = SlotIndex(slot_raw);
-        let effect_id = bridge.effect_id(slot);
-        let mut config = EffectConfig::new(effect_id).with_bypass(bridge.is_bypassed(slot));
+        preset = preset.with_effect(slot_to_config(bridge, SlotIndex(slot_raw)));
+    }
 
-        for p_raw in 0..bridge.param_count(slot) {
-            let p = ParamIndex(p_raw);
-            if let Some(desc) = bridge.param_descriptor(slot, p) {
-                config =
-                    config.with_param(to_snake_case(desc.name), format!("{}", bridge.get(slot, p)));
-            }
-        }
+    preset
+}
+
+/// Capture one slot's effect type, bypass state, and parameters.
+fn slot_to_config(bridge: &dyn ParamBridge, slot: SlotIndex) -> EffectConfig {
+    let mut config =
+        EffectConfig::new(bridge.effect_id(slot)).with_bypass(bridge.is_bypassed(slot));
 
-        preset = preset.with_effect(config);
+    for p_raw in 0..bridge.param_count(slot) {
+        let p = ParamIndex(p_raw);
+        if let Some(desc) = bridge.param_descriptor(slot, p) {
+            config =
+                config.with_param(to_snake_case(desc.name), format!("{}", bridge.get(slot, p)));
+        }
     }
 
-    preset
+    config
+}
+
+/// Convert one slot's parameters to a single-effect preset.
+///
+/// The preset holds a single effect. Bypass is not stored: recalling a
+/// favorite setting shouldn't switch the effect on or off.
+pub fn slot_to_preset(name: &str, bridge: &dyn ParamBridge, slot: SlotIndex) -> Preset {
+    Preset::new(name).with_effect(slot_to_config(bridge, slot).with_bypass(false))
+}
+
+/// Apply a single-effect preset to one slot.
+///
+/// Uses the preset's entry for the slot's effect type, matching parameters by
+/// name as [`preset_to_params`] does. Leaves the bypass state alone. Returns
+/// `false` if the preset has no entry for the slot's effect.
+pub fn preset_to_slot(preset: &Preset, bridge: &dyn ParamBridge, slot: SlotIndex) -> bool {
+    let effect_id = bridge.effect_id(slot);
+    let Some(config) = preset
+        .effects
+        .iter()
+        .find(|e| effect_type_matches(&e.effect_type, effect_id))
+    else {
+        return false;
+    };
+    apply_config_params(config, bridge, slot, &ParamLocks::default());
+    true
+}
+
+/// Set every unlocked parameter of `slot` that `config` names.
+fn apply_config_params(
+    config: &EffectConfig,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    locks: &ParamLocks,
+) {
+    for p_raw in 0..bridge.param_count(slot) {
+        let p = ParamIndex(p_raw);
+        if !locks.is_param_locked(slot.0, p_raw)
+            && let Some(desc) = bridge.param_descriptor(slot, p)
+            && let Some(v) = find_param_in_config(config, desc.name)
+        {
+            bridge.set(slot, p, v);
+        }
+    }
 }
 
 /// Apply a sonido_config::Preset to a ParamBridge.
@@ -61,7 +126,16 @@ pub fn params_to_preset(
 /// match the same parameter. Legacy aliases (intensity→Depth, warmth→Saturation)
 /// are tried when a direct match fails.
 pub fn preset_to_params(preset: &Preset, bridge: &dyn ParamBridge) {
+    preset_to_params_locked(preset, bridge, &ParamLocks::default());
+}
+
+/// Apply a preset like [`preset_to_params`], skipping locked slots (bypass
+/// state and every parameter) and locked parameters.
+pub fn preset_to_params_locked(preset: &Preset, bridge: &dyn ParamBridge, locks: &ParamLocks) {
     for slot_raw in 0..bridge.slot_count() {
+        if locks.is_slot_locked(slot_raw) {
+            continue;
+        }
         let slot = SlotIndex(slot_raw);
         let effect_id = bridge.effect_id(slot);
         let config = preset
@@ -71,17 +145,109 @@ pub fn preset_to_params(preset: &Preset, bridge: &dyn ParamBridge) {
 
         if let Some(config) = config {
             bridge.set_bypassed(slot, config.bypassed);
+            apply_config_params(config, bridge, slot, locks);
+        }
+    }
+}
 
-            for p_raw in 0..bridge.param_count(slot) {
-                let p = ParamIndex(p_raw);
-                if let Some(desc) = bridge.param_descriptor(slot, p)
-                    && let Some(v) = find_param_in_config(config, desc.name)
-                {
-                    bridge.set(slot, p, v);
-                }
-            }
+/// Slots and parameters that preset loads leave alone.
+///
+/// Locks refer to chain positions, like scenes; they are saved with the
+/// session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParamLocks {
+    /// Whole slots: bypass state and every parameter.
+    slots: BTreeSet<usize>,
+    /// Single parameters as `(slot, param)`.
+    params: BTreeSet<(usize, usize)>,
+}
+
+impl ParamLocks {
+    /// Whether nothing is locked.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty() && self.params.is_empty()
+    }
+
+    /// Whether the whole of `slot` is locked.
+    pub fn is_slot_locked(&self, slot: usize) -> bool {
+        self.slots.contains(&slot)
+    }
+
+    /// Whether `param` of `slot` is kept, on its own or with its slot.
+    pub fn is_param_locked(&self, slot: usize, param: usize) -> bool {
+        self.is_slot_locked(slot) || self.params.contains(&(slot, param))
+    }
+
+    /// Whether `slot` or any of its parameters is locked.
+    pub fn has_locks(&self, slot: usize) -> bool {
+        self.is_slot_locked(slot)
+            || self
+                .params
+                .range((slot, 0)..=(slot, usize::MAX))
+                .next()
+                .is_some()
+    }
+
+    /// Lock or unlock the whole of `slot`.
+    pub fn set_slot_locked(&mut self, slot: usize, locked: bool) {
+        if locked {
+            self.slots.insert(slot);
+        } else {
+            self.slots.remove(&slot);
+        }
+    }
+
+    /// Lock or unlock one parameter.
+    pub fn set_param_locked(&mut self, slot: usize, param: usize, locked: bool) {
+        if locked {
+            self.params.insert((slot, param));
+        } else {
+            self.params.remove(&(slot, param));
         }
     }
+
+    /// Unlock everything.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.params.clear();
+    }
+}
+
+/// Whether `preset` has settings for effect type `effect_id`.
+pub fn preset_configures(preset: &Preset, effect_id: &str) -> bool {
+    preset
+        .effects
+        .iter()
+        .any(|e| effect_type_matches(&e.effect_type, effect_id))
+}
+
+/// Preset file key of a parameter: its descriptor name in snake_case.
+pub fn param_key(descriptor_name: &str) -> String {
+    to_snake_case(descriptor_name)
+}
+
+/// Parameter of `slot` named by preset key `key`, matched as parameter
+/// values are (normalized names, then legacy aliases).
+pub fn find_param_by_key(
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    key: &str,
+) -> Option<ParamIndex> {
+    let target = normalize_key(key);
+    let names = |p: usize| bridge.param_descriptor(slot, ParamIndex(p)).map(|d| d.name);
+    let count = bridge.param_count(slot);
+    (0..count)
+        .find(|&p| names(p).is_some_and(|n| normalize_key(n) == target))
+        .or_else(|| {
+            (0..count).find(|&p| {
+                names(p).is_some_and(|n| {
+                    let alias = param_alias(n);
+                    !alias.is_empty() && normalize_key(alias) == target
+                })
+            })
+        })
+        .map(ParamIndex)
 }
 
 /// Look up a parameter value in the config by descriptor name.
@@ -221,6 +387,8 @@ pub struct PresetManager {
     current_preset: usize,
     /// Whether the current preset has been modified.
     modified: bool,
+    /// Slots and parameters that [`select`](Self::select) leaves alone.
+    locks: ParamLocks,
 }
 
 impl PresetManager {
@@ -232,6 +400,7 @@ impl PresetManager {
             presets: Vec::new(),
             current_preset: 0,
             modified: false,
+            locks: ParamLocks::default(),
         };
 
         manager.load_factory_presets();
@@ -287,14 +456,15 @@ impl PresetManager {
         self.presets.get(self.current_preset)
     }
 
-    /// Select a preset by index and apply it to the parameters.
+    /// Select a preset by index and apply it to the parameters, leaving
+    /// [locked](Self::locks) slots and parameters alone.
     ///
     /// Returns the topology string from the loaded preset, if any.
     /// The caller can use this to update the graph topology accordingly.
     pub fn select(&mut self, index: usize, bridge: &dyn ParamBridge) -> Option<String> {
         if index < self.presets.len() {
             self.current_preset = index;
-            preset_to_params(&self.presets[index].preset, bridge);
+            preset_to_params_locked(&self.presets[index].preset, bridge, &self.locks);
             self.modified = false;
             self.presets[index].preset.topology.clone()
         } else {
@@ -302,6 +472,16 @@ impl PresetManager {
         }
     }
 
+    /// Slots and parameters kept when a preset is selected.
+    pub fn locks(&self) -> &ParamLocks {
+        &self.locks
+    }
+
+    /// Mutable access to the locks.
+    pub fn locks_mut(&mut self) -> &mut ParamLocks {
+        &mut self.locks
+    }
+
     /// Mark the current preset as modified.
     pub fn mark_modified(&mut self) {
         self.modified = true;
@@ -323,8 +503,10 @@ impl PresetManager {
         description: Option<&str>,
         topology: Option<&str>,
         bridge: &dyn ParamBridge,
+        macros: Vec<MacroConfig>,
     ) -> Result<(), String> {
-        let preset = params_to_preset(name, description, topology, bridge);
+        let mut preset = params_to_preset(name, description, topology, bridge);
+        preset.macros = macros;
 
         ensure_user_presets_dir()
             .map_err(|e| format!("Failed to create presets directory: {}", e))?;
@@ -353,6 +535,7 @@ impl PresetManager {
         &mut self,
         topology: Option<&str>,
         bridge: &dyn ParamBridge,
+        macros: Vec<MacroConfig>,
     ) -> Result<(), String> {
         let entry = self
             .presets
@@ -369,12 +552,13 @@ impl PresetManager {
             }
         };
 
-        let preset = params_to_preset(
+        let mut preset = params_to_preset(
             &entry.preset.name,
             entry.preset.description.as_deref(),
             topology,
             bridge,
         );
+        preset.macros = macros;
 
         preset
             .save(&path)
@@ -456,6 +640,100 @@ impl Default for PresetManager {
     }
 }
 
+/// A saved single-effect preset.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct EffectPreset {
+    /// Display name.
+    pub name: String,
+    /// File path.
+    pub path: PathBuf,
+}
+
+/// Single-effect presets per effect type (native only).
+///
+/// Each effect type's directory is scanned the first time it is listed and
+/// cached after that; [`save`](Self::save) and [`reload`](Self::reload)
+/// keep the cache current.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct EffectPresetLibrary {
+    cache: HashMap<String, Vec<EffectPreset>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl EffectPresetLibrary {
+    /// Presets saved for `effect_id`, sorted by name.
+    pub fn list(&mut self, effect_id: &str) -> &[EffectPreset] {
+        self.cache
+            .entry(effect_id.to_string())
+            .or_insert_with(|| scan_effect_presets(effect_id))
+    }
+
+    /// Save `slot`'s parameters as a preset for its effect type, replacing
+    /// any preset of the same name.
+    pub fn save(
+        &mut self,
+        name: &str,
+        bridge: &dyn ParamBridge,
+        slot: SlotIndex,
+    ) -> Result<(), String> {
+        let effect_id = bridge.effect_id(slot).to_string();
+        let dir = user_effect_presets_dir(&effect_id);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create presets directory: {}", e))?;
+
+        let path = dir.join(format!("{}.toml", name.to_lowercase().replace(' ', "_")));
+        slot_to_preset(name, bridge, slot)
+            .save(&path)
+            .map_err(|e| format!("Failed to save preset: {}", e))?;
+
+        tracing::info!(name, effect_id, "effect preset saved");
+        self.cache.remove(&effect_id);
+        Ok(())
+    }
+
+    /// Load the preset at `path` into `slot`.
+    pub fn load(
+        &self,
+        path: &std::path::Path,
+        bridge: &dyn ParamBridge,
+        slot: SlotIndex,
+    ) -> Result<(), String> {
+        let preset = Preset::load(path).map_err(|e| format!("Failed to load preset: {}", e))?;
+        if preset_to_slot(&preset, bridge, slot) {
+            Ok(())
+        } else {
+            Err(format!("Preset '{}' is not for this effect", preset.name))
+        }
+    }
+
+    /// Forget cached listings so the next [`list`](Self::list) rescans.
+    pub fn reload(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Read the single-effect presets for `effect_id`, skipping unreadable files.
+#[cfg(not(target_arch = "wasm32"))]
+fn scan_effect_presets(effect_id: &str) -> Vec<EffectPreset> {
+    let mut presets: Vec<EffectPreset> = list_user_effect_presets(effect_id)
+        .into_iter()
+        .filter_map(|path| match Preset::load(&path) {
+            Ok(preset) => Some(EffectPreset {
+                name: preset.name,
+                path,
+            }),
+            Err(e) => {
+                tracing::warn!(path = ?path, error = %e, "failed to load effect preset");
+                None
+            }
+        })
+        .collect();
+    presets.sort_by_key(|p| p.name.to_lowercase());
+    presets
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -572,6 +850,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_locks_survive_preset_select() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+        let drive = find_param(&bridge, SlotIndex(0), "Drive").unwrap();
+        let tone = find_param(&bridge, SlotIndex(0), "Tone").unwrap();
+        let decay = find_param(&bridge, SlotIndex(1), "Decay").unwrap();
+
+        bridge.set(SlotIndex(0), drive, 20.0);
+        bridge.set(SlotIndex(0), tone, 3.0);
+        bridge.set(SlotIndex(1), decay, 0.7);
+        bridge.set_bypassed(SlotIndex(1), true);
+        let preset = params_to_preset("Test", None, None, &bridge);
+
+        bridge.set(SlotIndex(0), drive, 5.0);
+        bridge.set(SlotIndex(0), tone, -3.0);
+        bridge.set(SlotIndex(1), decay, 0.2);
+        bridge.set_bypassed(SlotIndex(1), false);
+
+        let mut manager = PresetManager::new();
+        manager.presets.push(PresetEntry::unsaved(preset));
+        manager.locks_mut().set_param_locked(0, drive.0, true);
+        manager.locks_mut().set_slot_locked(1, true);
+        assert!(manager.locks().has_locks(0));
+        manager.select(manager.presets.len() - 1, &bridge);
+
+        // Locked values stay; the rest of the slot loads
+        assert!((bridge.get(SlotIndex(0), drive) - 5.0).abs() < 0.01);
+        assert!((bridge.get(SlotIndex(0), tone) - 3.0).abs() < 0.01);
+        // A locked slot keeps its parameters and bypass state
+        assert!((bridge.get(SlotIndex(1), decay) - 0.2).abs() < 0.01);
+        assert!(!bridge.is_bypassed(SlotIndex(1)));
+
+        manager.locks_mut().clear();
+        assert!(manager.locks().is_empty());
+        manager.select(manager.presets.len() - 1, &bridge);
+        assert!((bridge.get(SlotIndex(0), drive) - 20.0).abs() < 0.01);
+        assert!(bridge.is_bypassed(SlotIndex(1)));
+    }
+
+    #[test]
+    fn test_slot_preset_roundtrip() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["reverb", "distortion"], 48000.0);
+        let drive = find_param(&bridge, SlotIndex(1), "Drive").unwrap();
+        bridge.set(SlotIndex(1), drive, 25.0);
+        bridge.set_bypassed(SlotIndex(1), true);
+
+        let preset = slot_to_preset("Fuzzy", &bridge, SlotIndex(1));
+        assert_eq!(preset.effects.len(), 1);
+        assert!(!preset.effects[0].bypassed);
+
+        // Applies to a distortion slot at any position, leaving bypass alone
+        let bridge2 = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+        bridge2.set_bypassed(SlotIndex(0), true);
+        assert!(preset_to_slot(&preset, &bridge2, SlotIndex(0)));
+        assert!((bridge2.get(SlotIndex(0), drive) - 25.0).abs() < 0.01);
+        assert!(bridge2.is_bypassed(SlotIndex(0)));
+
+        // Not for a reverb slot
+        assert!(!preset_to_slot(&preset, &bridge2, SlotIndex(1)));
+    }
+
     #[test]
     fn test_preset_entry_sources() {
         let preset = Preset::new("Test");
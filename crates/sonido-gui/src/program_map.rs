@@ -0,0 +1,302 @@
+//! MIDI program change → preset table, preset spillover, and the safe-mode CC.
+//!
+//! [`ProgramMap`] maps program numbers (0–127, any channel) to preset names;
+//! a mapped program loads that preset, an unmapped one recalls the scene of
+//! the same number. It also holds the spillover switch: when on, loading a
+//! preset swaps fresh instances into the active delay and reverb slots so the
+//! old instances' tails ring out across the switch instead of being retuned
+//! mid-decay. With a crossfade time set, loading a preset instead fades from
+//! the running engine to a fresh one, and the spillover switch decides whether
+//! the old engine's tails ring out. It persists as JSON in `program_map.json` under the user config
+//! directory (native only). [`ProgramMapEditor`] is the settings dialog.
+//!
+//! The optional safe-mode controller ([`ProgramMap::safe_mode_cc`]) engages
+//! safe mode at values of 64 and up and releases it below, so a latching
+//! footswitch sending 127/0 maps directly.
+
+use std::collections::BTreeMap;
+
+use egui::Context;
+use serde::{Deserialize, Serialize};
+use sonido_core::graph::transition::MAX_TRANSITION_MS;
+use sonido_core::graph::{TailMode, Transition};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Program map file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const PROGRAM_MAP_FILE: &str = "program_map.json";
+
+/// Highest MIDI program number.
+const MAX_PROGRAM: u8 = 127;
+
+/// Program change → preset table plus the spillover switch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProgramMap {
+    /// Preset name per program number.
+    pub presets: BTreeMap<u8, String>,
+    /// Let delay and reverb tails ring out when a preset is loaded.
+    pub spillover: bool,
+    /// Crossfade between the old and new preset, in milliseconds; `0`
+    /// retunes the running effects instead.
+    pub crossfade_ms: u32,
+    /// Controller number that engages (≥ 64) and releases (< 64) safe mode.
+    pub safe_mode_cc: Option<u8>,
+}
+
+impl ProgramMap {
+    /// Preset mapped to `program`, if any.
+    pub fn preset_for(&self, program: u8) -> Option<&str> {
+        self.presets.get(&program).map(String::as_str)
+    }
+
+    /// Safe-mode state requested by a control change, if `cc` is the
+    /// safe-mode controller.
+    pub fn safe_mode_for(&self, cc: u8, value: u8) -> Option<bool> {
+        (self.safe_mode_cc == Some(cc)).then_some(value >= 64)
+    }
+
+    /// Engine crossfade for loading a preset, or `None` to retune the running
+    /// effects in place. With spillover on, the old engine's tails ring out.
+    pub fn transition(&self) -> Option<Transition> {
+        (self.crossfade_ms > 0).then(|| {
+            let tails = if self.spillover {
+                TailMode::RingOut
+            } else {
+                TailMode::Fade
+            };
+            Transition::new(self.crossfade_ms as f32, tails)
+        })
+    }
+
+    /// Serialize to the program map JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parse the program map JSON format; missing fields keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Default program map file path.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn default_path() -> PathBuf {
+        sonido_config::paths::user_config_dir().join(PROGRAM_MAP_FILE)
+    }
+
+    /// Load the program map from `path`, falling back to an empty table if
+    /// the file is missing or unreadable.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_or_default(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                tracing::warn!(path = ?path, error = %e, "invalid program map file, using defaults");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save the program map to `path`, creating the config directory if
+    /// needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or file I/O fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+/// Program map settings dialog.
+#[derive(Debug, Default)]
+pub struct ProgramMapEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+    /// Program number for the next added row.
+    program: u8,
+}
+
+impl ProgramMapEditor {
+    /// Draw the dialog. `presets` lists the loadable preset names.
+    /// Returns `true` if `map` changed.
+    pub fn show(&mut self, ctx: &Context, map: &mut ProgramMap, presets: &[&str]) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("MIDI Program Change")
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                changed |= ui
+                    .checkbox(&mut map.spillover, "Spillover")
+                    .on_hover_text(
+                        "Let delay and reverb tails ring out when a preset is loaded \
+                         (from MIDI, the preset list, or a shortcut)",
+                    )
+                    .changed();
+                ui.horizontal(|ui| {
+                    ui.label("Crossfade");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut map.crossfade_ms)
+                                .range(0..=MAX_TRANSITION_MS as u32)
+                                .speed(5.0)
+                                .suffix(" ms"),
+                        )
+                        .on_hover_text(
+                            "Fade from the old preset to the new one over this time. \
+                             0 retunes the running effects instead.",
+                        )
+                        .changed();
+                });
+                ui.horizontal(|ui| {
+                    let mut enabled = map.safe_mode_cc.is_some();
+                    let mut cc = map.safe_mode_cc.unwrap_or(MAX_PROGRAM);
+                    changed |= ui
+                        .checkbox(&mut enabled, "Safe mode CC")
+                        .on_hover_text(
+                            "Values 64–127 engage safe mode (dry signal, feedback muted); \
+                             0–63 release it",
+                        )
+                        .changed();
+                    changed |= ui
+                        .add_enabled(
+                            enabled,
+                            egui::DragValue::new(&mut cc).range(0..=MAX_PROGRAM),
+                        )
+                        .changed();
+                    map.safe_mode_cc = enabled.then_some(cc);
+                });
+                ui.label(
+                    egui::RichText::new("Unmapped programs recall the scene of the same number.")
+                        .small(),
+                );
+                ui.separator();
+
+                let mut remove = None;
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("program_map")
+                            .num_columns(3)
+                            .spacing([12.0, 4.0])
+                            .show(ui, |ui| {
+                                for (&program, name) in &mut map.presets {
+                                    ui.label(format!("PC {program}"));
+                                    egui::ComboBox::from_id_salt(("program_preset", program))
+                                        .selected_text(name.as_str())
+                                        .show_ui(ui, |ui| {
+                                            for &preset in presets {
+                                                if ui
+                                                    .selectable_label(name == preset, preset)
+                                                    .clicked()
+                                                {
+                                                    preset.clone_into(name);
+                                                    changed = true;
+                                                }
+                                            }
+                                        });
+                                    if ui.small_button("✕").on_hover_text("Remove").clicked() {
+                                        remove = Some(program);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                if let Some(program) = remove {
+                    map.presets.remove(&program);
+                    changed = true;
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.program)
+                            .range(0..=MAX_PROGRAM)
+                            .prefix("PC "),
+                    );
+                    let free = !map.presets.contains_key(&self.program);
+                    if ui
+                        .add_enabled(free && !presets.is_empty(), egui::Button::new("Add"))
+                        .on_hover_text("Map this program to a preset")
+                        .clicked()
+                    {
+                        map.presets.insert(self.program, presets[0].to_owned());
+                        self.program = self.program.saturating_add(1).min(MAX_PROGRAM);
+                        changed = true;
+                    }
+                });
+            });
+        self.open = open;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let mut map = ProgramMap {
+            spillover: true,
+            ..ProgramMap::default()
+        };
+        map.presets.insert(0, "Clean".into());
+        map.presets.insert(12, "Ambient Wash".into());
+
+        let restored = ProgramMap::from_json(&map.to_json().unwrap()).unwrap();
+        assert_eq!(restored, map);
+        assert_eq!(restored.preset_for(12), Some("Ambient Wash"));
+        assert_eq!(restored.preset_for(1), None);
+
+        // Missing fields keep their defaults
+        assert_eq!(ProgramMap::from_json("{}").unwrap(), ProgramMap::default());
+    }
+
+    #[test]
+    fn safe_mode_cc_latches_on_high_values() {
+        let map = ProgramMap {
+            safe_mode_cc: Some(80),
+            ..ProgramMap::default()
+        };
+        assert_eq!(map.safe_mode_for(80, 127), Some(true));
+        assert_eq!(map.safe_mode_for(80, 63), Some(false));
+        assert_eq!(map.safe_mode_for(81, 127), None);
+        assert_eq!(ProgramMap::default().safe_mode_for(80, 127), None);
+    }
+
+    #[test]
+    fn transition_follows_crossfade_and_spillover() {
+        let mut map = ProgramMap::default();
+        assert_eq!(map.transition(), None);
+
+        map.crossfade_ms = 250;
+        assert_eq!(
+            map.transition(),
+            Some(Transition::new(250.0, TailMode::Fade))
+        );
+        map.spillover = true;
+        assert_eq!(map.transition().map(|t| t.tails), Some(TailMode::RingOut));
+    }
+}
@@ -0,0 +1,463 @@
+//! Scenes: instant recall of bypass states and selected parameters.
+//!
+//! A [`Scene`] is lighter than a preset. It stores every slot's bypass state
+//! but only the parameters marked as scene parameters
+//! ([`SceneBank::is_tracked`]), so switching from a verse to a chorus sound
+//! changes what matters and leaves the rest of the chain alone. Recalling a
+//! scene sets bypass states at once and glides the parameters over the
+//! scene's crossfade time. Stepped parameters switch halfway, as in the A/B
+//! morph.
+//!
+//! Scenes address slots by index and check the effect ID, so slots whose
+//! effect changed since the scene was stored are skipped. The bank is saved
+//! with the session. Scenes are recalled from the scenes tile footswitches,
+//! the `Scene n` keyboard shortcuts, or MIDI program change.
+
+use std::collections::BTreeSet;
+
+use egui::{FontId, RichText, Ui};
+use serde::{Deserialize, Serialize};
+use sonido_core::ParamFlags;
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::widgets::FootswitchToggle;
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex};
+
+/// Maximum number of scenes in a bank.
+pub const MAX_SCENES: usize = 8;
+
+/// Crossfade time of new scenes, in milliseconds.
+pub const DEFAULT_CROSSFADE_MS: f32 = 50.0;
+
+/// Longest crossfade offered in the editor, in milliseconds.
+const MAX_CROSSFADE_MS: f32 = 5000.0;
+
+/// One slot's part of a scene.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneSlot {
+    /// Registry effect ID at capture time.
+    pub effect_id: String,
+    /// Whether the slot is bypassed in this scene.
+    pub bypassed: bool,
+    /// `(parameter index, value)` for each scene parameter of the slot.
+    pub params: Vec<(usize, f32)>,
+}
+
+/// A stored scene.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    /// Display name (footswitch label).
+    pub name: String,
+    /// Parameter glide time on recall, in milliseconds (0 = instant).
+    pub crossfade_ms: f32,
+    /// One entry per effect slot in chain order.
+    pub slots: Vec<SceneSlot>,
+}
+
+/// One parameter of a running crossfade.
+#[derive(Debug, Clone)]
+struct FadeParam {
+    slot: SlotIndex,
+    param: ParamIndex,
+    from: f32,
+    to: f32,
+    stepped: bool,
+}
+
+/// A crossfade in progress.
+#[derive(Debug, Clone)]
+struct Fade {
+    params: Vec<FadeParam>,
+    /// Start time in seconds (egui input time), set by the first tick.
+    start: Option<f64>,
+    /// Length in seconds.
+    duration: f64,
+}
+
+/// The scenes of a session and the parameters they capture.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SceneBank {
+    /// Stored scenes, in footswitch order.
+    pub scenes: Vec<Scene>,
+    /// Scene parameters as `(slot, param)` pairs.
+    tracked: BTreeSet<(usize, usize)>,
+    /// Last recalled or stored scene.
+    #[serde(skip)]
+    active: Option<usize>,
+    #[serde(skip)]
+    fade: Option<Fade>,
+}
+
+impl SceneBank {
+    /// Index of the last recalled or stored scene.
+    pub fn active(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Whether scenes capture parameter `param` of `slot`.
+    pub fn is_tracked(&self, slot: usize, param: usize) -> bool {
+        self.tracked.contains(&(slot, param))
+    }
+
+    /// Add or remove a scene parameter. Takes effect for scenes stored
+    /// afterwards.
+    pub fn set_tracked(&mut self, slot: usize, param: usize, tracked: bool) {
+        if tracked {
+            self.tracked.insert((slot, param));
+        } else {
+            self.tracked.remove(&(slot, param));
+        }
+    }
+
+    /// Capture the current state as a new scene. Returns its index, or
+    /// `None` if the bank is full.
+    pub fn add(&mut self, bridge: &dyn ParamBridge) -> Option<usize> {
+        if self.scenes.len() >= MAX_SCENES {
+            return None;
+        }
+        let index = self.scenes.len();
+        self.scenes.push(Scene {
+            name: format!("Scene {}", index + 1),
+            crossfade_ms: DEFAULT_CROSSFADE_MS,
+            slots: self.capture(bridge),
+        });
+        self.active = Some(index);
+        Some(index)
+    }
+
+    /// Overwrite scene `index` with the current state, keeping its name and
+    /// crossfade time.
+    pub fn store(&mut self, index: usize, bridge: &dyn ParamBridge) {
+        let slots = self.capture(bridge);
+        if let Some(scene) = self.scenes.get_mut(index) {
+            scene.slots = slots;
+            self.active = Some(index);
+        }
+    }
+
+    /// Delete scene `index`.
+    pub fn remove(&mut self, index: usize) {
+        if index < self.scenes.len() {
+            self.scenes.remove(index);
+            self.active = match self.active {
+                Some(a) if a == index => None,
+                Some(a) if a > index => Some(a - 1),
+                other => other,
+            };
+        }
+    }
+
+    /// Recall scene `index`: set bypass states and queue the parameter
+    /// crossfade, which starts at the next [`tick`](Self::tick). Returns
+    /// `false` if there is no such scene.
+    pub fn recall(&mut self, index: usize, bridge: &dyn ParamBridge) -> bool {
+        let Some(scene) = self.scenes.get(index) else {
+            return false;
+        };
+
+        let mut params = Vec::new();
+        for (i, stored) in scene.slots.iter().enumerate().take(bridge.slot_count()) {
+            let slot = SlotIndex(i);
+            if bridge.effect_id(slot) != stored.effect_id {
+                continue;
+            }
+            bridge.set_bypassed(slot, stored.bypassed);
+            for &(p, to) in &stored.params {
+                let param = ParamIndex(p);
+                let Some(desc) = bridge.param_descriptor(slot, param) else {
+                    continue;
+                };
+                params.push(FadeParam {
+                    slot,
+                    param,
+                    from: bridge.get(slot, param),
+                    to,
+                    stepped: desc.flags.contains(ParamFlags::STEPPED),
+                });
+            }
+        }
+
+        self.active = Some(index);
+        self.fade = Some(Fade {
+            params,
+            start: None,
+            duration: f64::from(scene.crossfade_ms) / 1000.0,
+        });
+        true
+    }
+
+    /// Advance the crossfade to `now` (seconds). Returns `true` while it is
+    /// still running.
+    pub fn tick(&mut self, bridge: &dyn ParamBridge, now: f64) -> bool {
+        let Some(fade) = &mut self.fade else {
+            return false;
+        };
+        let start = *fade.start.get_or_insert(now);
+        let t = if fade.duration > 0.0 {
+            ((now - start) / fade.duration).clamp(0.0, 1.0) as f32
+        } else {
+            1.0
+        };
+        for p in &fade.params {
+            let value = if p.stepped {
+                if t < 0.5 { p.from } else { p.to }
+            } else {
+                p.from + (p.to - p.from) * t
+            };
+            bridge.set(p.slot, p.param, value);
+        }
+        if t >= 1.0 {
+            self.fade = None;
+        }
+        self.fade.is_some()
+    }
+
+    /// Bypass states and scene parameter values of every slot.
+    fn capture(&self, bridge: &dyn ParamBridge) -> Vec<SceneSlot> {
+        (0..bridge.slot_count())
+            .map(|i| {
+                let slot = SlotIndex(i);
+                let params = self
+                    .tracked
+                    .range((i, 0)..(i + 1, 0))
+                    .map(|&(_, p)| p)
+                    .filter(|&p| p < bridge.param_count(slot))
+                    .map(|p| (p, bridge.get(slot, ParamIndex(p))))
+                    .collect();
+                SceneSlot {
+                    effect_id: bridge.effect_id(slot).to_owned(),
+                    bypassed: bridge.is_bypassed(slot),
+                    params,
+                }
+            })
+            .collect()
+    }
+
+    /// Scenes tile: a footswitch per scene, the active scene's settings,
+    /// and the scene parameter checklist for the `selected` slot.
+    pub fn ui(&mut self, ui: &mut Ui, bridge: &dyn ParamBridge, selected: Option<usize>) {
+        let theme = SonidoTheme::get(ui.ctx());
+
+        ui.horizontal_wrapped(|ui| {
+            for index in 0..self.scenes.len() {
+                let mut on = self.active == Some(index);
+                let label = self.scenes[index].name.clone();
+                if ui.add(FootswitchToggle::new(&mut on, &label)).clicked() {
+                    self.recall(index, bridge);
+                    ui.ctx().request_repaint();
+                }
+            }
+            if self.scenes.is_empty() {
+                ui.label(
+                    RichText::new("No scenes yet. Set bypasses and scene parameters, then Add.")
+                        .color(theme.colors.text_secondary)
+                        .italics(),
+                );
+            }
+        });
+
+        ui.horizontal(|ui| {
+            let full = self.scenes.len() >= MAX_SCENES;
+            if ui
+                .add_enabled(!full, egui::Button::new("Add"))
+                .on_hover_text("Store the current bypasses and scene parameters as a new scene")
+                .clicked()
+            {
+                self.add(bridge);
+            }
+            let Some(index) = self.active.filter(|&i| i < self.scenes.len()) else {
+                return;
+            };
+            if ui
+                .button("Store")
+                .on_hover_text("Overwrite the active scene with the current state")
+                .clicked()
+            {
+                self.store(index, bridge);
+            }
+            if ui.button("Delete").clicked() {
+                self.remove(index);
+                return;
+            }
+            let scene = &mut self.scenes[index];
+            ui.add(egui::TextEdit::singleline(&mut scene.name).desired_width(90.0));
+            ui.label("Fade");
+            ui.add(
+                egui::DragValue::new(&mut scene.crossfade_ms)
+                    .range(0.0..=MAX_CROSSFADE_MS)
+                    .speed(5.0)
+                    .suffix(" ms"),
+            );
+        });
+
+        ui.separator();
+        let Some(slot) = selected.filter(|&s| s < bridge.slot_count()) else {
+            ui.label(
+                RichText::new("Select an effect to choose its scene parameters.")
+                    .color(theme.colors.text_secondary)
+                    .italics(),
+            );
+            return;
+        };
+        ui.label(
+            RichText::new(format!(
+                "Scene parameters: {}",
+                bridge.effect_id(SlotIndex(slot))
+            ))
+            .font(FontId::monospace(10.0))
+            .color(theme.colors.text_secondary),
+        );
+        egui::ScrollArea::vertical()
+            .id_salt("scene_params")
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for p in 0..bridge.param_count(SlotIndex(slot)) {
+                        let Some(desc) = bridge.param_descriptor(SlotIndex(slot), ParamIndex(p))
+                        else {
+                            continue;
+                        };
+                        let mut tracked = self.is_tracked(slot, p);
+                        if ui.checkbox(&mut tracked, desc.name).changed() {
+                            self.set_tracked(slot, p, tracked);
+                        }
+                    }
+                });
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_core::ParamDescriptor;
+    use std::sync::Mutex;
+
+    struct Bridge {
+        effect_id: String,
+        descriptors: Vec<ParamDescriptor>,
+        values: Mutex<Vec<Vec<f32>>>,
+        bypassed: Mutex<Vec<bool>>,
+    }
+
+    impl Bridge {
+        /// Two "dist" slots with a continuous and a stepped parameter.
+        fn new() -> Self {
+            let descriptors = vec![
+                ParamDescriptor::custom("Drive", "Drive", 0.0, 10.0, 0.0),
+                ParamDescriptor::custom("Mode", "Mode", 0.0, 3.0, 0.0)
+                    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED)),
+            ];
+            Self {
+                effect_id: "dist".into(),
+                descriptors,
+                values: Mutex::new(vec![vec![0.0, 0.0]; 2]),
+                bypassed: Mutex::new(vec![false; 2]),
+            }
+        }
+    }
+
+    impl ParamBridge for Bridge {
+        fn slot_count(&self) -> usize {
+            2
+        }
+        fn effect_id(&self, _slot: SlotIndex) -> &str {
+            &self.effect_id
+        }
+        fn param_count(&self, _slot: SlotIndex) -> usize {
+            self.descriptors.len()
+        }
+        fn param_descriptor(&self, _slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+            self.descriptors.get(param.0).copied()
+        }
+        fn get(&self, slot: SlotIndex, param: ParamIndex) -> f32 {
+            self.values.lock().unwrap()[slot.0][param.0]
+        }
+        fn set(&self, slot: SlotIndex, param: ParamIndex, value: f32) {
+            self.values.lock().unwrap()[slot.0][param.0] = value;
+        }
+        fn is_bypassed(&self, slot: SlotIndex) -> bool {
+            self.bypassed.lock().unwrap()[slot.0]
+        }
+        fn set_bypassed(&self, slot: SlotIndex, bypassed: bool) {
+            self.bypassed.lock().unwrap()[slot.0] = bypassed;
+        }
+    }
+
+    #[test]
+    fn scenes_capture_bypass_and_tracked_params_only() {
+        let bridge = Bridge::new();
+        let mut bank = SceneBank::default();
+        bank.set_tracked(0, 0, true);
+
+        bridge.set(SlotIndex(0), ParamIndex(0), 2.0);
+        bridge.set(SlotIndex(1), ParamIndex(0), 2.0);
+        assert_eq!(bank.add(&bridge), Some(0));
+
+        bridge.set(SlotIndex(0), ParamIndex(0), 8.0);
+        bridge.set(SlotIndex(1), ParamIndex(0), 8.0);
+        bridge.set_bypassed(SlotIndex(1), true);
+        assert_eq!(bank.add(&bridge), Some(1));
+
+        assert!(bank.recall(0, &bridge));
+        assert_eq!(bank.scenes[0].crossfade_ms, DEFAULT_CROSSFADE_MS);
+        assert!(bank.tick(&bridge, 0.0));
+        assert!(bank.tick(&bridge, 0.025));
+        assert!(!bank.tick(&bridge, 1.0));
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 2.0);
+        // Untracked parameters are left alone; bypass always follows.
+        assert_eq!(bridge.get(SlotIndex(1), ParamIndex(0)), 8.0);
+        assert!(!bridge.is_bypassed(SlotIndex(1)));
+        assert_eq!(bank.active(), Some(0));
+
+        bank.remove(0);
+        assert_eq!(bank.active(), None);
+        assert_eq!(bank.scenes.len(), 1);
+        assert!(!bank.recall(1, &bridge));
+    }
+
+    #[test]
+    fn crossfade_glides_continuous_and_switches_stepped_halfway() {
+        let bridge = Bridge::new();
+        let mut bank = SceneBank::default();
+        bank.set_tracked(0, 0, true);
+        bank.set_tracked(0, 1, true);
+
+        bridge.set(SlotIndex(0), ParamIndex(0), 10.0);
+        bridge.set(SlotIndex(0), ParamIndex(1), 3.0);
+        bank.add(&bridge);
+        bank.scenes[0].crossfade_ms = 100.0;
+
+        bridge.set(SlotIndex(0), ParamIndex(0), 0.0);
+        bridge.set(SlotIndex(0), ParamIndex(1), 0.0);
+        bank.recall(0, &bridge);
+        bank.tick(&bridge, 1.0);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 0.0);
+
+        bank.tick(&bridge, 1.04);
+        assert!((bridge.get(SlotIndex(0), ParamIndex(0)) - 4.0).abs() < 1e-3);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(1)), 0.0);
+
+        bank.tick(&bridge, 1.06);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(1)), 3.0);
+
+        // Zero crossfade applies at once.
+        bank.scenes[0].crossfade_ms = 0.0;
+        bridge.set(SlotIndex(0), ParamIndex(0), 0.0);
+        bank.recall(0, &bridge);
+        assert!(!bank.tick(&bridge, 2.0));
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 10.0);
+    }
+
+    #[test]
+    fn bank_json_roundtrip() {
+        let bridge = Bridge::new();
+        let mut bank = SceneBank::default();
+        bank.set_tracked(1, 0, true);
+        bank.add(&bridge);
+
+        let restored: SceneBank =
+            serde_json::from_str(&serde_json::to_string(&bank).unwrap()).unwrap();
+        assert_eq!(restored.scenes, bank.scenes);
+        assert!(restored.is_tracked(1, 0));
+        assert_eq!(restored.active(), None);
+    }
+}
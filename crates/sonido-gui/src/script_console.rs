@@ -0,0 +1,237 @@
+//! Script console: run rhai snippets against the live effect chain.
+//!
+//! The console seeds a [`ScriptChain`] from the parameter bridge, runs the
+//! script, and hands the edited chain back to the app. Parameter and bypass
+//! edits go through the bridge like knob gestures; adding or removing effects
+//! replaces the graph with a linear chain. Transport commands are queued
+//! during the run and applied to the file player afterwards.
+//!
+//! Native only — scripts read and write files directly.
+
+use egui::{Context, FontId, Key, KeyboardShortcut, Modifiers, RichText, TextEdit};
+use sonido_gui_core::{ParamBridge, ParamIndex, SlotIndex, set_grouped};
+use sonido_registry::EffectRegistry;
+use sonido_script::{ChainSlot, ScriptChain, ScriptError, ScriptHost, Transport};
+use std::path::Path;
+
+/// Lines kept in the console log.
+const MAX_LOG_LINES: usize = 500;
+
+/// Parameter changes smaller than this are not re-sent to the bridge.
+const PARAM_EPSILON: f32 = 1e-6;
+
+/// Run shortcut inside the editor.
+const RUN_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Enter);
+
+/// Console window state.
+pub struct ScriptConsole {
+    /// Whether the window is open.
+    pub open: bool,
+    source: String,
+    log: Vec<String>,
+}
+
+impl Default for ScriptConsole {
+    fn default() -> Self {
+        Self {
+            open: false,
+            source: String::from("print(chain());\n"),
+            log: Vec::new(),
+        }
+    }
+}
+
+impl ScriptConsole {
+    /// Draw the console window. Returns the script to run when the user
+    /// presses Run (or Ctrl+Enter in the editor).
+    pub fn show(&mut self, ctx: &Context) -> Option<String> {
+        if !self.open {
+            return None;
+        }
+
+        let mut run = false;
+        let mut open = self.open;
+        egui::Window::new("Script Console")
+            .open(&mut open)
+            .default_size([460.0, 360.0])
+            .show(ctx, |ui| {
+                // Check before the editor handles Enter as a newline.
+                let editor_id = ui.make_persistent_id("script_source");
+                if ui.memory(|m| m.has_focus(editor_id))
+                    && ui.input_mut(|i| i.consume_shortcut(&RUN_SHORTCUT))
+                {
+                    run = true;
+                }
+                ui.add(
+                    TextEdit::multiline(&mut self.source)
+                        .id(editor_id)
+                        .code_editor()
+                        .desired_rows(10)
+                        .desired_width(f32::INFINITY),
+                );
+
+                ui.horizontal(|ui| {
+                    run |= ui
+                        .button("Run")
+                        .on_hover_text(ctx.format_shortcut(&RUN_SHORTCUT))
+                        .clicked();
+                    if ui.button("Clear log").clicked() {
+                        self.log.clear();
+                    }
+                });
+
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .stick_to_bottom(true)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for line in &self.log {
+                            ui.label(RichText::new(line).font(FontId::monospace(11.0)));
+                        }
+                    });
+            });
+        self.open = open;
+
+        run.then(|| self.source.clone())
+    }
+
+    /// Append a line to the log, dropping the oldest beyond the limit.
+    pub fn log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > MAX_LOG_LINES {
+            let excess = self.log.len() - MAX_LOG_LINES;
+            self.log.drain(..excess);
+        }
+    }
+}
+
+/// Script host for the console: collects output and transport commands.
+#[derive(Default)]
+pub struct ConsoleHost {
+    /// Lines printed by the script.
+    pub output: Vec<String>,
+    /// Transport commands to apply once the script finishes.
+    pub transport: Vec<Transport>,
+}
+
+impl ScriptHost for ConsoleHost {
+    fn print(&mut self, text: &str) {
+        self.output.push(text.to_string());
+    }
+
+    fn transport(&mut self, command: Transport) -> Result<(), ScriptError> {
+        // Check up front so a bad path stops the script where it was named.
+        if let Transport::Load(path) = &command
+            && !path.is_file()
+        {
+            return Err(ScriptError::Host(format!(
+                "{}: file not found",
+                path.display()
+            )));
+        }
+        self.transport.push(command);
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        chain: &ScriptChain,
+        input: &Path,
+        output: &Path,
+    ) -> Result<(), ScriptError> {
+        let (samples, spec) = sonido_io::read_wav_stereo(input)
+            .map_err(|e| ScriptError::Host(format!("{}: {e}", input.display())))?;
+        let rendered = chain.render(&samples, spec.sample_rate as f32);
+        let out_spec = sonido_io::WavSpec {
+            channels: 2,
+            sample_rate: spec.sample_rate,
+            bits_per_sample: 32,
+        };
+        sonido_io::write_wav_stereo(output, &rendered, out_spec)
+            .map_err(|e| ScriptError::Host(format!("{}: {e}", output.display())))?;
+        self.output.push(format!(
+            "rendered {} -> {}",
+            input.display(),
+            output.display()
+        ));
+        Ok(())
+    }
+}
+
+/// Snapshot the bridge's effects in slot order.
+///
+/// Slots whose effect is unknown to the registry are skipped.
+pub fn chain_from_bridge(bridge: &dyn ParamBridge, registry: &EffectRegistry) -> Vec<ChainSlot> {
+    (0..bridge.slot_count())
+        .map(SlotIndex)
+        .filter_map(|slot| {
+            let effect_id = registry.get(bridge.effect_id(slot))?.id;
+            let params = (0..bridge.param_count(slot))
+                .map(|p| bridge.get(slot, ParamIndex(p)))
+                .collect();
+            Some(ChainSlot {
+                effect_id,
+                params,
+                bypassed: bridge.is_bypassed(slot),
+            })
+        })
+        .collect()
+}
+
+/// Whether the bridge holds exactly the effects in `slots`, in order.
+pub fn bridge_matches(bridge: &dyn ParamBridge, slots: &[ChainSlot]) -> bool {
+    bridge.slot_count() == slots.len()
+        && slots
+            .iter()
+            .enumerate()
+            .all(|(i, s)| bridge.effect_id(SlotIndex(i)) == s.effect_id)
+}
+
+/// Push parameter values and bypass states that differ from the bridge.
+///
+/// Parameter changes are sent as one grouped gesture, so a script run is a
+/// single undo step.
+pub fn apply_to_bridge(bridge: &dyn ParamBridge, slots: &[ChainSlot]) {
+    let mut changes = Vec::new();
+    for (i, entry) in slots.iter().enumerate() {
+        let slot = SlotIndex(i);
+        for (p, &value) in entry.params.iter().enumerate() {
+            let param = ParamIndex(p);
+            if (bridge.get(slot, param) - value).abs() > PARAM_EPSILON {
+                changes.push((slot, param, value));
+            }
+        }
+    }
+    set_grouped(bridge, &changes);
+    for (i, entry) in slots.iter().enumerate() {
+        let slot = SlotIndex(i);
+        if bridge.is_bypassed(slot) != entry.bypassed {
+            bridge.set_bypassed(slot, entry.bypassed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atomic_param_bridge::AtomicParamBridge;
+
+    #[test]
+    fn bridge_round_trip() {
+        let registry = EffectRegistry::new();
+        let bridge = AtomicParamBridge::new(&registry, &["distortion", "reverb"], 48000.0);
+
+        let mut slots = chain_from_bridge(&bridge, &registry);
+        assert!(bridge_matches(&bridge, &slots));
+        assert_eq!(slots[1].effect_id, "reverb");
+
+        slots[0].params[0] = 12.0;
+        slots[1].bypassed = true;
+        apply_to_bridge(&bridge, &slots);
+        assert_eq!(bridge.get(SlotIndex(0), ParamIndex(0)), 12.0);
+        assert!(bridge.is_bypassed(SlotIndex(1)));
+
+        slots.pop();
+        assert!(!bridge_matches(&bridge, &slots));
+    }
+}
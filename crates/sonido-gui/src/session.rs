@@ -5,9 +5,14 @@
 //! Sessions serialize to JSON.
 
 use serde::{Deserialize, Serialize};
+use sonido_config::MacroConfig;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::expression::ExpressionMap;
+use crate::preset_manager::ParamLocks;
+use crate::scenes::SceneBank;
+
 /// Complete session state.
 ///
 /// ## Fields
@@ -16,7 +21,15 @@ use std::path::Path;
 /// - `wires`: Connections as `(from_idx, from_output, to_idx, to_input)` tuples.
 /// - `params`: Per-effect parameter snapshots, keyed by node index.
 /// - `input_gain`: Input gain in dB.
+/// - `input_routing`: Input routing label (empty in older sessions, meaning stereo).
 /// - `master_volume`: Master volume in dB.
+/// - `scenes`: Scene bank (empty in sessions saved before scenes existed).
+/// - `expression`: MIDI CC → parameter mappings (empty in older sessions).
+/// - `macros`: Macro knobs and their targets (empty in older sessions).
+/// - `locks`: Slots and parameters kept across preset loads (none in older sessions).
+/// - `rig_b`: Rig B's graph when two rigs run in parallel (none in older sessions).
+/// - `rig_morph`: A/B rig morph, 0 = rig A, 1 = rig B.
+/// - `rig_output`: Rig output mode label (empty means blend).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Session {
     /// Schema version (currently 1).
@@ -29,8 +42,33 @@ pub struct Session {
     pub params: HashMap<usize, EffectState>,
     /// Input gain in dB.
     pub input_gain: f32,
+    /// Input routing label (`"Stereo"`, `"Left"`, `"Right"`, or `"Mono"`).
+    #[serde(default)]
+    pub input_routing: String,
     /// Master volume in dB.
     pub master_volume: f32,
+    /// Scenes stored with the session.
+    #[serde(default)]
+    pub scenes: SceneBank,
+    /// Expression pedal mappings stored with the session.
+    #[serde(default)]
+    pub expression: ExpressionMap,
+    /// Macro knobs, with targets keyed by parameter name.
+    #[serde(default)]
+    pub macros: Vec<MacroConfig>,
+    /// Preset-load locks.
+    #[serde(default)]
+    pub locks: ParamLocks,
+    /// Rig B's graph. Only its `nodes`, `wires`, and `params` are used; its
+    /// effects take the slots after rig A's.
+    #[serde(default)]
+    pub rig_b: Option<Box<Session>>,
+    /// A/B rig morph (0 = rig A, 1 = rig B).
+    #[serde(default)]
+    pub rig_morph: f32,
+    /// Rig output mode label (`"Blend"` or `"Split"`).
+    #[serde(default)]
+    pub rig_output: String,
 }
 
 /// A node entry with type and 2D position.
@@ -61,6 +99,16 @@ pub enum SessionNode {
     Split,
     /// Signal merger.
     Merge,
+    /// Summing aux bus fed by sends.
+    AuxBus,
+    /// Inline send into the aux bus wired to its second output.
+    Send {
+        /// Send level in dB.
+        level_db: f32,
+        /// Tap before the fader instead of after it.
+        #[serde(default)]
+        pre_fader: bool,
+    },
 }
 
 /// Parameter state snapshot for a single effect.
@@ -72,20 +120,72 @@ pub struct EffectState {
     pub params: Vec<f32>,
     /// Whether the effect is bypassed.
     pub bypassed: bool,
+    /// Bypass mode label (`"Dry"`, `"Mute"`, or `"Tails"`).
+    ///
+    /// Missing in sessions saved before bypass modes existed; those load as dry.
+    #[serde(default)]
+    pub bypass_mode: String,
 }
 
 impl Session {
     /// Current schema version.
     pub const VERSION: u32 = 1;
 
+    /// Build a linear session: Input → `effects` in order → Output.
+    ///
+    /// Used when a script replaces the effect chain.
+    pub fn linear(effects: Vec<EffectState>, input_gain: f32, master_volume: f32) -> Self {
+        let count = effects.len();
+        let pos = |i: usize| [100.0 + 200.0 * i as f32, 200.0];
+
+        let mut nodes = Vec::with_capacity(count + 2);
+        nodes.push(SessionNodeEntry {
+            node: SessionNode::Input,
+            pos: pos(0),
+        });
+        let mut params = HashMap::with_capacity(count);
+        for (i, state) in effects.into_iter().enumerate() {
+            nodes.push(SessionNodeEntry {
+                node: SessionNode::Effect {
+                    effect_id: state.effect_id.clone(),
+                },
+                pos: pos(i + 1),
+            });
+            params.insert(i + 1, state);
+        }
+        nodes.push(SessionNodeEntry {
+            node: SessionNode::Output,
+            pos: pos(count + 1),
+        });
+
+        Self {
+            version: Self::VERSION,
+            nodes,
+            wires: (0..=count).map(|i| (i, 0, i + 1, 0)).collect(),
+            params,
+            input_gain,
+            input_routing: String::new(),
+            master_volume,
+            scenes: SceneBank::default(),
+            expression: ExpressionMap::default(),
+            macros: Vec::new(),
+            locks: ParamLocks::default(),
+            rig_b: None,
+            rig_morph: 0.0,
+            rig_output: String::new(),
+        }
+    }
+
     /// Save the session to a JSON file.
     ///
+    /// The write is atomic: a crash mid-save keeps the previous file intact.
+    ///
     /// # Errors
     ///
     /// Returns an error if serialization or file I/O fails.
     pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, json)?;
+        sonido_config::write_atomic(path, json)?;
         Ok(())
     }
 
@@ -134,12 +234,21 @@ mod tests {
                         effect_id: "reverb".into(),
                         params: vec![0.5, 0.7, 0.3],
                         bypassed: false,
+                        bypass_mode: "Tails".into(),
                     },
                 );
                 m
             },
             input_gain: 0.0,
+            input_routing: "Left".into(),
             master_volume: -3.0,
+            scenes: SceneBank::default(),
+            expression: ExpressionMap::default(),
+            macros: Vec::new(),
+            locks: ParamLocks::default(),
+            rig_b: None,
+            rig_morph: 0.0,
+            rig_output: String::new(),
         };
 
         let json = serde_json::to_string(&session).unwrap();
@@ -148,5 +257,55 @@ mod tests {
         assert_eq!(restored.nodes.len(), 3);
         assert_eq!(restored.wires.len(), 2);
         assert_eq!(restored.master_volume, -3.0);
+        assert_eq!(restored.params[&1].bypass_mode, "Tails");
+        assert_eq!(restored.input_routing, "Left");
+    }
+
+    #[test]
+    fn linear_session_wires_effects_in_order() {
+        let state = |id: &str| EffectState {
+            effect_id: id.into(),
+            params: vec![1.0],
+            bypassed: false,
+            bypass_mode: String::new(),
+        };
+        let session = Session::linear(vec![state("distortion"), state("reverb")], 0.0, 0.0);
+        assert_eq!(session.nodes.len(), 4);
+        assert!(matches!(session.nodes[0].node, SessionNode::Input));
+        assert!(matches!(session.nodes[3].node, SessionNode::Output));
+        assert_eq!(session.wires, [(0, 0, 1, 0), (1, 0, 2, 0), (2, 0, 3, 0)]);
+        assert_eq!(session.params[&2].effect_id, "reverb");
+
+        let empty = Session::linear(Vec::new(), 0.0, 0.0);
+        assert_eq!(empty.wires, [(0, 0, 1, 0)]);
+    }
+
+    #[test]
+    fn rig_b_session_roundtrips() {
+        let state = |id: &str| EffectState {
+            effect_id: id.into(),
+            params: vec![1.0],
+            bypassed: false,
+            bypass_mode: String::new(),
+        };
+        let mut session = Session::linear(vec![state("distortion")], 0.0, 0.0);
+        session.rig_b = Some(Box::new(Session::linear(vec![state("reverb")], 0.0, 0.0)));
+        session.rig_morph = 1.0;
+        session.rig_output = "Split".into();
+
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: Session = serde_json::from_str(&json).unwrap();
+        let rig_b = restored.rig_b.expect("rig B restored");
+        assert_eq!(rig_b.params[&1].effect_id, "reverb");
+        assert_eq!(restored.rig_morph, 1.0);
+        assert_eq!(restored.rig_output, "Split");
+    }
+
+    #[test]
+    fn effect_state_without_bypass_mode_loads() {
+        let json = r#"{"effect_id":"delay","params":[1.0],"bypassed":true}"#;
+        let state: EffectState = serde_json::from_str(json).unwrap();
+        assert!(state.bypassed);
+        assert!(state.bypass_mode.is_empty());
     }
 }
@@ -0,0 +1,590 @@
+//! Configurable keyboard shortcuts for the Sonido GUI.
+//!
+//! Each [`ShortcutAction`] has at most one [`KeyboardShortcut`]. Defaults:
+//!
+//! | Action | Key |
+//! |--------|-----|
+//! | Play / pause | Space |
+//! | Bypass selected effect | B |
+//! | Select previous / next effect | ← / → |
+//! | Switch rig A / B | R |
+//! | Load preset 1–9 | 1–9 |
+//! | Recall scene 1–8 | F1–F8 |
+//!
+//! Bindings persist as JSON in `keybindings.json` under the user config
+//! directory (native only); actions missing from the file keep their
+//! defaults. [`ShortcutsEditor`] is the rebinding dialog.
+
+use egui::{Context, Key, KeyboardShortcut, Modifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Number of preset slots reachable by shortcut (number keys 1–9).
+pub const PRESET_SHORTCUTS: usize = 9;
+
+/// Number of scenes reachable by shortcut (function keys F1–F8).
+pub const SCENE_SHORTCUTS: usize = crate::scenes::MAX_SCENES;
+
+/// Keybindings file name inside the user config directory.
+#[cfg(not(target_arch = "wasm32"))]
+const KEYBINDINGS_FILE: &str = "keybindings.json";
+
+/// An app command that can be bound to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortcutAction {
+    /// Toggle file playback or the signal generator.
+    PlayPause,
+    /// Toggle bypass on the selected effect.
+    BypassSelected,
+    /// Select the previous effect slot.
+    SelectPrevious,
+    /// Select the next effect slot.
+    SelectNext,
+    /// Crossfade to the other rig (A/B footswitch).
+    ToggleRig,
+    /// Load the preset at this index (0-based) in the preset list.
+    Preset(usize),
+    /// Recall the scene at this index (0-based).
+    Scene(usize),
+}
+
+impl ShortcutAction {
+    /// Every bindable action, in display order.
+    pub fn all() -> Vec<Self> {
+        let mut actions = vec![
+            Self::PlayPause,
+            Self::BypassSelected,
+            Self::SelectPrevious,
+            Self::SelectNext,
+            Self::ToggleRig,
+        ];
+        actions.extend((0..PRESET_SHORTCUTS).map(Self::Preset));
+        actions.extend((0..SCENE_SHORTCUTS).map(Self::Scene));
+        actions
+    }
+
+    /// Stable identifier used in the keybindings file.
+    pub fn id(self) -> String {
+        match self {
+            Self::PlayPause => "play_pause".into(),
+            Self::BypassSelected => "bypass_selected".into(),
+            Self::SelectPrevious => "select_previous".into(),
+            Self::SelectNext => "select_next".into(),
+            Self::ToggleRig => "toggle_rig".into(),
+            Self::Preset(i) => format!("preset_{}", i + 1),
+            Self::Scene(i) => format!("scene_{}", i + 1),
+        }
+    }
+
+    /// Parse an identifier produced by [`id()`](Self::id).
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::all().into_iter().find(|action| action.id() == id)
+    }
+
+    /// Human-readable label for the editor.
+    pub fn label(self) -> String {
+        match self {
+            Self::PlayPause => "Play / pause".into(),
+            Self::BypassSelected => "Bypass selected effect".into(),
+            Self::SelectPrevious => "Select previous effect".into(),
+            Self::SelectNext => "Select next effect".into(),
+            Self::ToggleRig => "Switch rig A / B".into(),
+            Self::Preset(i) => format!("Load preset {}", i + 1),
+            Self::Scene(i) => format!("Recall scene {}", i + 1),
+        }
+    }
+
+    /// Default binding.
+    fn default_shortcut(self) -> KeyboardShortcut {
+        const NUMBER_KEYS: [Key; PRESET_SHORTCUTS] = [
+            Key::Num1,
+            Key::Num2,
+            Key::Num3,
+            Key::Num4,
+            Key::Num5,
+            Key::Num6,
+            Key::Num7,
+            Key::Num8,
+            Key::Num9,
+        ];
+        const FUNCTION_KEYS: [Key; SCENE_SHORTCUTS] = [
+            Key::F1,
+            Key::F2,
+            Key::F3,
+            Key::F4,
+            Key::F5,
+            Key::F6,
+            Key::F7,
+            Key::F8,
+        ];
+        let key = match self {
+            Self::PlayPause => Key::Space,
+            Self::BypassSelected => Key::B,
+            Self::SelectPrevious => Key::ArrowLeft,
+            Self::SelectNext => Key::ArrowRight,
+            Self::ToggleRig => Key::R,
+            Self::Preset(i) => NUMBER_KEYS[i.min(PRESET_SHORTCUTS - 1)],
+            Self::Scene(i) => FUNCTION_KEYS[i.min(SCENE_SHORTCUTS - 1)],
+        };
+        KeyboardShortcut::new(Modifiers::NONE, key)
+    }
+}
+
+/// Format a shortcut as `"Ctrl+Shift+B"` for the keybindings file.
+pub fn format_shortcut(shortcut: &KeyboardShortcut) -> String {
+    let mods = shortcut.modifiers;
+    let mut parts = Vec::new();
+    if mods.ctrl {
+        parts.push("Ctrl");
+    }
+    if mods.mac_cmd {
+        parts.push("Cmd");
+    }
+    if mods.alt {
+        parts.push("Alt");
+    }
+    if mods.shift {
+        parts.push("Shift");
+    }
+    parts.push(shortcut.logical_key.name());
+    parts.join("+")
+}
+
+/// Parse a shortcut written by [`format_shortcut`]. Case-insensitive for
+/// modifier names; the key name uses egui's [`Key::from_name`].
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+    // "Ctrl++" binds the plus key.
+    if text.ends_with("++") {
+        parts.truncate(parts.len().saturating_sub(2));
+        parts.push("+");
+    }
+    let (key_name, mod_names) = parts.split_last()?;
+    let key = Key::from_name(key_name)?;
+    let mut modifiers = Modifiers::NONE;
+    for name in mod_names {
+        match name.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers.ctrl = true,
+            "cmd" => {
+                modifiers.mac_cmd = true;
+                modifiers.command = true;
+            }
+            "alt" => modifiers.alt = true,
+            "shift" => modifiers.shift = true,
+            _ => return None,
+        }
+    }
+    if modifiers.ctrl && !modifiers.mac_cmd {
+        modifiers.command = cfg!(not(target_os = "macos"));
+    }
+    Some(KeyboardShortcut::new(modifiers, key))
+}
+
+/// On-disk form: action id → shortcut text (empty = unbound).
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct KeybindingsFile {
+    bindings: BTreeMap<String, String>,
+}
+
+/// The active shortcut map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keybindings {
+    /// One entry per [`ShortcutAction::all()`], in the same order.
+    bindings: Vec<(ShortcutAction, Option<KeyboardShortcut>)>,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self {
+            bindings: ShortcutAction::all()
+                .into_iter()
+                .map(|action| (action, Some(action.default_shortcut())))
+                .collect(),
+        }
+    }
+}
+
+impl Keybindings {
+    /// All actions with their current binding, in display order.
+    pub fn iter(&self) -> impl Iterator<Item = (ShortcutAction, Option<KeyboardShortcut>)> + '_ {
+        self.bindings.iter().copied()
+    }
+
+    /// Current binding for `action`.
+    pub fn get(&self, action: ShortcutAction) -> Option<KeyboardShortcut> {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .and_then(|(_, shortcut)| *shortcut)
+    }
+
+    /// Bind `action` to `shortcut` (or unbind with `None`).
+    ///
+    /// Any other action using the same shortcut is unbound, so each key
+    /// triggers at most one action. Returns the action that lost its binding.
+    pub fn set(
+        &mut self,
+        action: ShortcutAction,
+        shortcut: Option<KeyboardShortcut>,
+    ) -> Option<ShortcutAction> {
+        let mut displaced = None;
+        for (a, bound) in &mut self.bindings {
+            if *a == action {
+                *bound = shortcut;
+            } else if shortcut.is_some() && *bound == shortcut {
+                *bound = None;
+                displaced = Some(*a);
+            }
+        }
+        displaced
+    }
+
+    /// Consume this frame's key presses and return the triggered actions.
+    ///
+    /// Shortcuts with more modifiers are checked first so `Ctrl+B` isn't
+    /// also read as `B`.
+    pub fn poll(&self, ctx: &Context) -> Vec<ShortcutAction> {
+        let mut bound: Vec<(ShortcutAction, KeyboardShortcut)> = self
+            .bindings
+            .iter()
+            .filter_map(|(action, shortcut)| shortcut.map(|s| (*action, s)))
+            .collect();
+        bound.sort_by_key(|(_, s)| {
+            core::cmp::Reverse(
+                [
+                    s.modifiers.ctrl,
+                    s.modifiers.command,
+                    s.modifiers.alt,
+                    s.modifiers.shift,
+                ]
+                .iter()
+                .filter(|&&m| m)
+                .count(),
+            )
+        });
+        ctx.input_mut(|input| {
+            bound
+                .into_iter()
+                .filter(|(_, shortcut)| input.consume_shortcut(shortcut))
+                .map(|(action, _)| action)
+                .collect()
+        })
+    }
+
+    fn to_file(&self) -> KeybindingsFile {
+        KeybindingsFile {
+            bindings: self
+                .bindings
+                .iter()
+                .map(|(action, shortcut)| {
+                    (
+                        action.id(),
+                        shortcut.as_ref().map(format_shortcut).unwrap_or_default(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn from_file(file: &KeybindingsFile) -> Self {
+        let mut bindings = Self::default();
+        for (id, text) in &file.bindings {
+            let Some(action) = ShortcutAction::from_id(id) else {
+                tracing::warn!(action = %id, "unknown shortcut action in keybindings");
+                continue;
+            };
+            if text.is_empty() {
+                bindings.set(action, None);
+            } else if let Some(shortcut) = parse_shortcut(text) {
+                bindings.set(action, Some(shortcut));
+            } else {
+                tracing::warn!(action = %id, key = %text, "unparseable shortcut in keybindings");
+            }
+        }
+        bindings
+    }
+
+    /// Serialize to the keybindings JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self.to_file())
+    }
+
+    /// Parse the keybindings JSON format. Missing actions keep defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self::from_file(&serde_json::from_str(json)?))
+    }
+
+    /// Default keybindings file path (`<config dir>/keybindings.json`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn default_path() -> PathBuf {
+        sonido_config::paths::user_config_dir().join(KEYBINDINGS_FILE)
+    }
+
+    /// Load keybindings from `path`, falling back to defaults if the file is
+    /// missing or unreadable.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_or_default(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                tracing::warn!(path = ?path, error = %e, "invalid keybindings file, using defaults");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save keybindings to `path`, creating the config directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or file I/O fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+/// Shortcuts editor dialog.
+///
+/// Lists every action with its binding. Clicking a binding waits for the
+/// next key press (with modifiers) and assigns it; Escape cancels, Delete
+/// unbinds.
+#[derive(Debug, Default)]
+pub struct ShortcutsEditor {
+    /// Whether the dialog is open.
+    pub open: bool,
+    /// Action waiting for a key press.
+    capturing: Option<ShortcutAction>,
+    /// Feedback shown under the list (e.g. a displaced binding).
+    message: Option<String>,
+}
+
+impl ShortcutsEditor {
+    /// Whether the editor is waiting for a key press. App shortcuts should
+    /// not fire while capturing.
+    pub fn is_capturing(&self) -> bool {
+        self.open && self.capturing.is_some()
+    }
+
+    /// Show the dialog. Returns `true` when `bindings` changed.
+    pub fn show(&mut self, ctx: &Context, bindings: &mut Keybindings) -> bool {
+        if !self.open {
+            self.capturing = None;
+            return false;
+        }
+
+        let mut changed = false;
+        if let Some(action) = self.capturing
+            && let Some(captured) = capture_key(ctx)
+        {
+            self.capturing = None;
+            match captured {
+                Captured::Cancel => self.message = None,
+                Captured::Clear => {
+                    bindings.set(action, None);
+                    self.message = None;
+                    changed = true;
+                }
+                Captured::Shortcut(shortcut) => {
+                    self.message = bindings.set(action, Some(shortcut)).map(|other| {
+                        format!(
+                            "{} was bound to {}; now unbound",
+                            other.label(),
+                            ctx.format_shortcut(&shortcut)
+                        )
+                    });
+                    changed = true;
+                }
+            }
+        }
+
+        let mut open = self.open;
+        egui::Window::new("Keyboard Shortcuts")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcut_bindings")
+                    .num_columns(2)
+                    .spacing([24.0, 4.0])
+                    .show(ui, |ui| {
+                        for (action, shortcut) in bindings.iter() {
+                            ui.label(action.label());
+                            let text = if self.capturing == Some(action) {
+                                "Press a key…".to_string()
+                            } else {
+                                shortcut
+                                    .map(|s| ctx.format_shortcut(&s))
+                                    .unwrap_or_else(|| "—".to_string())
+                            };
+                            if ui
+                                .add(egui::Button::new(text).min_size(egui::vec2(110.0, 0.0)))
+                                .clicked()
+                            {
+                                self.capturing = Some(action);
+                                self.message = None;
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new(
+                        "Click a binding, then press a key. Esc cancels, Delete unbinds.",
+                    )
+                    .small()
+                    .weak(),
+                );
+                if let Some(message) = &self.message {
+                    ui.label(egui::RichText::new(message).small());
+                }
+                ui.add_space(4.0);
+                if ui.button("Reset to defaults").clicked() {
+                    *bindings = Keybindings::default();
+                    self.capturing = None;
+                    self.message = None;
+                    changed = true;
+                }
+            });
+        self.open = open;
+        changed
+    }
+}
+
+/// Result of waiting for a key press in the editor.
+enum Captured {
+    Cancel,
+    Clear,
+    Shortcut(KeyboardShortcut),
+}
+
+/// Take the first key press this frame, consuming it so it doesn't also
+/// trigger an action.
+fn capture_key(ctx: &Context) -> Option<Captured> {
+    ctx.input_mut(|input| {
+        let index = input.events.iter().position(|event| {
+            matches!(
+                event,
+                egui::Event::Key {
+                    pressed: true,
+                    repeat: false,
+                    ..
+                }
+            )
+        })?;
+        let egui::Event::Key { key, modifiers, .. } = input.events.remove(index) else {
+            return None;
+        };
+        Some(match key {
+            Key::Escape => Captured::Cancel,
+            Key::Delete | Key::Backspace => Captured::Clear,
+            _ => Captured::Shortcut(KeyboardShortcut::new(modifiers, key)),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_cover_every_action() {
+        let bindings = Keybindings::default();
+        assert_eq!(
+            bindings.iter().count(),
+            5 + PRESET_SHORTCUTS + SCENE_SHORTCUTS
+        );
+        assert_eq!(
+            bindings.get(ShortcutAction::PlayPause),
+            Some(KeyboardShortcut::new(Modifiers::NONE, Key::Space))
+        );
+        assert_eq!(
+            bindings.get(ShortcutAction::Preset(8)),
+            Some(KeyboardShortcut::new(Modifiers::NONE, Key::Num9))
+        );
+        assert_eq!(
+            bindings.get(ShortcutAction::Scene(0)),
+            Some(KeyboardShortcut::new(Modifiers::NONE, Key::F1))
+        );
+    }
+
+    #[test]
+    fn action_ids_roundtrip() {
+        for action in ShortcutAction::all() {
+            assert_eq!(ShortcutAction::from_id(&action.id()), Some(action));
+        }
+        assert_eq!(ShortcutAction::from_id("nope"), None);
+    }
+
+    #[test]
+    fn shortcut_text_roundtrip() {
+        for text in ["Space", "B", "Ctrl+Shift+B", "Alt+Left", "9"] {
+            let shortcut = parse_shortcut(text).unwrap();
+            assert_eq!(format_shortcut(&shortcut), text);
+        }
+        assert!(parse_shortcut("Hyper+B").is_none());
+        assert!(parse_shortcut("NotAKey").is_none());
+    }
+
+    #[test]
+    fn rebinding_displaces_conflict() {
+        let mut bindings = Keybindings::default();
+        let space = bindings.get(ShortcutAction::PlayPause);
+        let displaced = bindings.set(ShortcutAction::BypassSelected, space);
+        assert_eq!(displaced, Some(ShortcutAction::PlayPause));
+        assert_eq!(bindings.get(ShortcutAction::PlayPause), None);
+        assert_eq!(bindings.get(ShortcutAction::BypassSelected), space);
+    }
+
+    #[test]
+    fn json_roundtrip_and_partial_file() {
+        let mut bindings = Keybindings::default();
+        bindings.set(ShortcutAction::BypassSelected, parse_shortcut("Ctrl+B"));
+        bindings.set(ShortcutAction::Preset(0), None);
+        let restored = Keybindings::from_json(&bindings.to_json().unwrap()).unwrap();
+        assert_eq!(restored, bindings);
+
+        // Actions missing from the file keep their defaults.
+        let partial = Keybindings::from_json(r#"{"bindings":{"select_next":"D"}}"#).unwrap();
+        assert_eq!(
+            partial.get(ShortcutAction::SelectNext),
+            Some(KeyboardShortcut::new(Modifiers::NONE, Key::D))
+        );
+        assert_eq!(
+            partial.get(ShortcutAction::PlayPause),
+            Keybindings::default().get(ShortcutAction::PlayPause)
+        );
+    }
+
+    #[test]
+    fn poll_consumes_bound_keys() {
+        let ctx = Context::default();
+        let bindings = Keybindings::default();
+        let mut input = egui::RawInput::default();
+        input.events.push(egui::Event::Key {
+            key: Key::B,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        });
+        let mut actions = Vec::new();
+        let _ = ctx.run(input, |ctx| actions = bindings.poll(ctx));
+        assert_eq!(actions, vec![ShortcutAction::BypassSelected]);
+    }
+}
@@ -0,0 +1,295 @@
+//! Spectrogram and waveform overview of the loaded file.
+//!
+//! When the file player loads a file, [`SpectrogramView::analyze`] hands a
+//! mono mix to a worker thread (inline on wasm), which runs an
+//! [`StftAnalyzer`] and reduces the result to a [`FileOverview`]: one column
+//! per STFT frame, log-spaced frequency rows, and a [`PeakPyramid`] of the
+//! waveform. The GUI thread turns the spectrogram into a texture once and
+//! draws it as the background of a [`WaveformView`], which handles zoom,
+//! pan, selection, the playhead, and click-to-seek.
+
+use crossbeam_channel::Receiver;
+use egui::{
+    Color32, ColorImage, Sense, Stroke, StrokeKind, TextureHandle, TextureOptions, Ui, vec2,
+};
+use sonido_analysis::fft::Window;
+use sonido_analysis::spectrogram::StftAnalyzer;
+use sonido_gui_core::theme::SonidoTheme;
+use sonido_gui_core::widgets::{PeakPyramid, WaveformView, WaveformViewState};
+
+/// STFT size (21 ms at 48 kHz; 23 Hz bins).
+const FFT_SIZE: usize = 2048;
+
+/// Smallest hop between frames.
+const MIN_HOP: usize = FFT_SIZE / 4;
+
+/// Most frames kept; longer files use a larger hop so the texture fits.
+const MAX_FRAMES: usize = 4096;
+
+/// Frequency rows in the overview (log-spaced).
+const ROWS: usize = 128;
+
+/// Lowest frequency shown.
+const FREQ_MIN: f32 = 30.0;
+
+/// Dynamic range below the loudest bin that is drawn, in dB.
+const RANGE_DB: f32 = 80.0;
+
+/// Precomputed spectrogram and waveform peaks for a file.
+#[derive(Debug, Clone)]
+pub struct FileOverview {
+    /// Number of columns (STFT frames).
+    pub columns: usize,
+    /// Intensity per cell, row-major with the highest frequency first.
+    pub intensity: Vec<u8>,
+    /// Waveform peaks at every zoom level.
+    pub peaks: PeakPyramid,
+    /// Seconds covered by one column.
+    pub column_secs: f32,
+    /// Sample rate of the analyzed signal.
+    pub sample_rate: f32,
+}
+
+impl FileOverview {
+    /// Analyze a mono signal.
+    ///
+    /// Signals shorter than one STFT frame produce an empty overview.
+    pub fn compute(mono: &[f32], sample_rate: f32) -> Self {
+        let span = mono.len().saturating_sub(FFT_SIZE);
+        let hop = span.div_ceil(MAX_FRAMES - 1).max(MIN_HOP);
+        let stft = StftAnalyzer::new(sample_rate, FFT_SIZE, hop, Window::Hann);
+        let spectrogram = stft.analyze(mono);
+        let columns = spectrogram.num_frames;
+
+        // Bin range for each row, top (high) to bottom (low).
+        let nyquist = sample_rate * 0.5;
+        let bin_hz = sample_rate / FFT_SIZE as f32;
+        let max_bin = spectrogram.num_bins.saturating_sub(1);
+        let rows: Vec<(usize, usize)> = (0..ROWS)
+            .map(|row| {
+                let t_hi = 1.0 - row as f32 / ROWS as f32;
+                let t_lo = 1.0 - (row + 1) as f32 / ROWS as f32;
+                let f_lo = FREQ_MIN * (nyquist / FREQ_MIN).powf(t_lo);
+                let f_hi = FREQ_MIN * (nyquist / FREQ_MIN).powf(t_hi);
+                let lo = ((f_lo / bin_hz) as usize).min(max_bin);
+                let hi = ((f_hi / bin_hz) as usize).clamp(lo, max_bin);
+                (lo, hi)
+            })
+            .collect();
+
+        let mut levels = vec![0.0f32; ROWS * columns];
+        for (col, frame) in spectrogram.data.iter().enumerate() {
+            for (row, &(lo, hi)) in rows.iter().enumerate() {
+                levels[row * columns + col] = frame[lo..=hi].iter().copied().fold(0.0, f32::max);
+            }
+        }
+
+        // Scale against the loudest cell so quiet files still show detail.
+        let peak = levels.iter().copied().fold(0.0f32, f32::max);
+        let intensity = levels
+            .iter()
+            .map(|&mag| {
+                if peak <= 0.0 || mag <= 0.0 {
+                    return 0;
+                }
+                let db = 20.0 * (mag / peak).log10();
+                (((db + RANGE_DB) / RANGE_DB).clamp(0.0, 1.0) * 255.0) as u8
+            })
+            .collect();
+
+        Self {
+            columns,
+            intensity,
+            peaks: PeakPyramid::new(mono),
+            column_secs: hop as f32 / sample_rate,
+            sample_rate,
+        }
+    }
+
+    /// Seconds covered by the overview.
+    pub fn duration_secs(&self) -> f32 {
+        self.columns as f32 * self.column_secs
+    }
+}
+
+/// Heat-map color for an intensity: void, then green, then amber.
+fn heat(intensity: u8, theme: &SonidoTheme) -> Color32 {
+    let t = f32::from(intensity) / 255.0;
+    if t < 0.5 {
+        lerp(theme.colors.void, theme.colors.green, t * 2.0)
+    } else {
+        lerp(theme.colors.green, theme.colors.amber, t * 2.0 - 1.0)
+    }
+}
+
+/// Linear blend between two colors.
+fn lerp(a: Color32, b: Color32, t: f32) -> Color32 {
+    let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t) as u8;
+    Color32::from_rgb(mix(a.r(), b.r()), mix(a.g(), b.g()), mix(a.b(), b.b()))
+}
+
+/// File spectrogram display with background analysis.
+#[derive(Default)]
+pub struct SpectrogramView {
+    pending: Option<Receiver<FileOverview>>,
+    overview: Option<FileOverview>,
+    texture: Option<TextureHandle>,
+    view: Option<WaveformViewState>,
+    /// Markers `(secs, label)` added to the waveform once it exists.
+    markers: Vec<(f32, String)>,
+}
+
+impl SpectrogramView {
+    /// Start analyzing a new file, discarding the previous overview.
+    pub fn analyze(&mut self, mono: Vec<f32>, sample_rate: f32) {
+        self.overview = None;
+        self.texture = None;
+        self.view = None;
+        self.markers.clear();
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        self.pending = Some(rx);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::spawn(move || {
+            // The receiver is gone if another file was loaded meanwhile.
+            let _ = tx.send(FileOverview::compute(&mono, sample_rate));
+        });
+        #[cfg(target_arch = "wasm32")]
+        let _ = tx.send(FileOverview::compute(&mono, sample_rate));
+    }
+
+    /// Replace the waveform markers, e.g. a file's cue points.
+    ///
+    /// Cleared by [`analyze`](Self::analyze), so call this after it.
+    pub fn set_markers(&mut self, markers: Vec<(f32, String)>) {
+        if let Some(view) = &mut self.view {
+            view.clear_markers();
+            for (secs, label) in &markers {
+                view.add_marker(*secs, label.clone());
+            }
+        }
+        self.markers = markers;
+    }
+
+    /// Draw the overview at `position_secs`.
+    ///
+    /// While `playing`, the view pages to keep the playhead visible.
+    /// Returns the clicked or dragged-to position in seconds.
+    pub fn ui(
+        &mut self,
+        ui: &mut Ui,
+        position_secs: f32,
+        playing: bool,
+        height: f32,
+    ) -> Option<f32> {
+        if let Some(rx) = &self.pending
+            && let Ok(overview) = rx.try_recv()
+        {
+            let mut view = WaveformViewState::new(overview.peaks.clone(), overview.sample_rate);
+            for (secs, label) in &self.markers {
+                view.add_marker(*secs, label.clone());
+            }
+            self.view = Some(view);
+            self.overview = Some(overview);
+            self.pending = None;
+        }
+        if self.pending.is_some() {
+            // Keep polling while the worker runs.
+            ui.ctx().request_repaint();
+        }
+
+        let theme = SonidoTheme::get(ui.ctx());
+        let (Some(overview), Some(view)) = (&self.overview, &mut self.view) else {
+            let (rect, _) =
+                ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, theme.colors.void);
+            painter.rect_stroke(
+                rect,
+                2.0,
+                Stroke::new(1.0, theme.colors.dim),
+                StrokeKind::Inside,
+            );
+            if self.pending.is_some() {
+                painter.text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    "Analyzing...",
+                    egui::FontId::monospace(10.0),
+                    theme.colors.text_secondary,
+                );
+            }
+            return None;
+        };
+
+        let mut timeline = WaveformView::new(view)
+            .playhead(Some(position_secs))
+            .follow(playing)
+            .height(height);
+        if overview.columns > 0 {
+            let texture = self.texture.get_or_insert_with(|| {
+                let pixels = overview
+                    .intensity
+                    .iter()
+                    .map(|&i| heat(i, &theme))
+                    .collect();
+                let image = ColorImage {
+                    size: [overview.columns, ROWS],
+                    pixels,
+                };
+                ui.ctx()
+                    .load_texture("file_spectrogram", image, TextureOptions::LINEAR)
+            });
+            timeline = timeline.background(texture.id());
+        }
+        timeline.show(ui).seek
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(freq: f32, secs: f32, sample_rate: f32) -> Vec<f32> {
+        (0..(secs * sample_rate) as usize)
+            .map(|i| 0.5 * (std::f32::consts::TAU * freq * i as f32 / sample_rate).sin())
+            .collect()
+    }
+
+    #[test]
+    fn tone_lights_its_row() {
+        let sr = 48000.0;
+        let overview = FileOverview::compute(&tone(1000.0, 1.0, sr), sr);
+        assert!(overview.columns > 0);
+        assert_eq!(overview.intensity.len(), overview.columns * ROWS);
+
+        // The brightest row in a middle column is near 1 kHz on the log axis.
+        let col = overview.columns / 2;
+        let row = (0..ROWS)
+            .max_by_key(|&r| overview.intensity[r * overview.columns + col])
+            .unwrap();
+        let t = 1.0 - (row as f32 + 0.5) / ROWS as f32;
+        let freq = FREQ_MIN * (24000.0f32 / FREQ_MIN).powf(t);
+        assert!((700.0..1400.0).contains(&freq), "peak row at {freq} Hz");
+
+        // Waveform peaks follow the amplitude.
+        let (lo, hi) = overview.peaks.range(24000, 24480);
+        assert!((hi - 0.5).abs() < 0.01 && (lo + 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn long_files_cap_the_column_count() {
+        let sr = 8000.0;
+        let overview = FileOverview::compute(&vec![0.1; 8000 * 600], sr);
+        assert!(overview.columns <= MAX_FRAMES);
+        assert!((overview.duration_secs() - 600.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn short_or_silent_input_is_empty() {
+        let overview = FileOverview::compute(&[0.0; 100], 48000.0);
+        assert_eq!(overview.columns, 0);
+        let silent = FileOverview::compute(&vec![0.0; 48000], 48000.0);
+        assert!(silent.intensity.iter().all(|&i| i == 0));
+    }
+}
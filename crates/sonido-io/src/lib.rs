@@ -3,6 +3,10 @@
 //! This crate provides:
 //!
 //! - **WAV file I/O**: [`read_wav`] and [`write_wav`] for loading/saving audio files
+//! - **Streaming file processing**: [`WavBlockReader`], [`WavBlockWriter`], and
+//!   [`process_wav_stream`] render a file block by block in constant memory
+//! - **WAV metadata**: [`read_wav_metadata`] and [`write_wav_metadata`] for broadcast WAV,
+//!   cue points, and sampler loops
 //! - **Real-time streaming**: [`AudioStream`] for live audio input/output
 //! - **Pluggable audio backends**: [`backend::AudioBackend`] trait for platform abstraction
 //! - **Effect processing**: [`GraphEngine`] for applying effect chains to audio via DAG routing
@@ -47,6 +51,8 @@ pub mod cpal_backend;
 mod graph_engine;
 pub(crate) mod stream;
 mod wav;
+mod wav_meta;
+mod wav_stream;
 
 pub use graph_engine::{GraphEngine, GraphSnapshot, SnapshotEntry};
 pub use stream::{
@@ -57,6 +63,11 @@ pub use wav::{
     StereoSamples, WavFormat, WavInfo, WavSpec, read_wav, read_wav_info, read_wav_stereo,
     write_wav, write_wav_stereo,
 };
+pub use wav_meta::{
+    BroadcastExtension, CuePoint, LoopKind, SampleLoop, WavMetadata, read_wav_metadata,
+    write_wav_metadata,
+};
+pub use wav_stream::{StreamBlock, WavBlockReader, WavBlockWriter, process_wav_stream};
 
 /// Error types for audio I/O operations.
 #[derive(Debug, thiserror::Error)]
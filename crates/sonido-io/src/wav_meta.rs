@@ -0,0 +1,555 @@
+//! WAV metadata chunks: broadcast extension, cue points, and sampler loops.
+//!
+//! `hound` reads and writes only the `fmt ` and `data` chunks, so this module
+//! walks the RIFF chunk list itself. It understands:
+//!
+//! | Chunk | Contents | Type |
+//! |-------|----------|------|
+//! | `bext` | Broadcast WAV (EBU Tech 3285) description, origin, timecode, loudness | [`BroadcastExtension`] |
+//! | `cue ` | Marker positions | [`CuePoint`] |
+//! | `LIST`/`adtl` | Marker names (`labl` sub-chunks) | [`CuePoint::label`] |
+//! | `smpl` | Root key and loop points | [`SampleLoop`] |
+//!
+//! Chunks that are truncated or malformed are skipped rather than failing
+//! the read, since the audio itself is still usable.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use sonido_io::{read_wav_metadata, read_wav_stereo, write_wav_metadata, write_wav_stereo};
+//!
+//! let (samples, spec) = read_wav_stereo("in.wav")?;
+//! let metadata = read_wav_metadata("in.wav")?;
+//! // ... process without changing length ...
+//! write_wav_stereo("out.wav", &samples, spec)?;
+//! write_wav_metadata("out.wav", &metadata)?;
+//! ```
+
+use crate::{Error, Result};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Size of the fixed part of a `bext` chunk, before the coding history.
+const BEXT_FIXED_LEN: usize = 602;
+
+/// `bext` loudness fields hold this when a value was not measured.
+const LOUDNESS_UNSET: i16 = 0x7FFF;
+
+/// Broadcast WAV extension (`bext` chunk).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastExtension {
+    /// Free-text description (at most 256 bytes).
+    pub description: String,
+    /// Name of the originating organisation or tool (at most 32 bytes).
+    pub originator: String,
+    /// Originator's unique reference (at most 32 bytes).
+    pub originator_reference: String,
+    /// Creation date as `yyyy-mm-dd`.
+    pub origination_date: String,
+    /// Creation time as `hh:mm:ss`.
+    pub origination_time: String,
+    /// Timecode of the first sample, in samples since midnight.
+    pub time_reference: u64,
+    /// BWF version (0, 1, or 2; loudness fields need 2).
+    pub version: u16,
+    /// SMPTE UMID (version 1 and later), all zero if unused.
+    pub umid: [u8; 64],
+    /// Integrated loudness in LUFS.
+    pub loudness_value: Option<f32>,
+    /// Loudness range in LU.
+    pub loudness_range: Option<f32>,
+    /// Maximum true peak in dBTP.
+    pub max_true_peak: Option<f32>,
+    /// Maximum momentary loudness in LUFS.
+    pub max_momentary_loudness: Option<f32>,
+    /// Maximum short-term loudness in LUFS.
+    pub max_short_term_loudness: Option<f32>,
+    /// Processing history, one line per step.
+    pub coding_history: String,
+}
+
+impl Default for BroadcastExtension {
+    fn default() -> Self {
+        Self {
+            description: String::new(),
+            originator: String::new(),
+            originator_reference: String::new(),
+            origination_date: String::new(),
+            origination_time: String::new(),
+            time_reference: 0,
+            version: 2,
+            umid: [0; 64],
+            loudness_value: None,
+            loudness_range: None,
+            max_true_peak: None,
+            max_momentary_loudness: None,
+            max_short_term_loudness: None,
+            coding_history: String::new(),
+        }
+    }
+}
+
+/// A marker position (`cue ` chunk), optionally named by a `labl` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuePoint {
+    /// Cue identifier, referenced by labels and loops.
+    pub id: u32,
+    /// Position in sample frames.
+    pub frame: u32,
+    /// Marker name, if the file has one.
+    pub label: Option<String>,
+}
+
+/// Playback direction of a [`SampleLoop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopKind {
+    /// Start to end, then jump back to start.
+    Forward,
+    /// Start to end, then end to start, and so on.
+    PingPong,
+    /// End to start.
+    Backward,
+}
+
+/// A sustain loop (`smpl` chunk).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleLoop {
+    /// Cue identifier of the loop.
+    pub id: u32,
+    /// Playback direction.
+    pub kind: LoopKind,
+    /// First frame of the loop.
+    pub start: u32,
+    /// Frame after the last one played (exclusive; the chunk stores it
+    /// inclusive).
+    pub end: u32,
+    /// Number of repetitions, 0 for infinite.
+    pub play_count: u32,
+}
+
+/// Metadata chunks of a WAV file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WavMetadata {
+    /// Broadcast WAV extension.
+    pub bext: Option<BroadcastExtension>,
+    /// Markers, in file order.
+    pub cues: Vec<CuePoint>,
+    /// Sustain loops, in file order.
+    pub loops: Vec<SampleLoop>,
+    /// MIDI note the sample sounds at unshifted (`smpl` unity note).
+    pub root_note: Option<u8>,
+}
+
+impl WavMetadata {
+    /// Whether the file has none of the supported chunks.
+    pub fn is_empty(&self) -> bool {
+        self.bext.is_none()
+            && self.cues.is_empty()
+            && self.loops.is_empty()
+            && self.root_note.is_none()
+    }
+
+    /// First non-empty loop as `(start, end)` frames, end exclusive.
+    ///
+    /// Matches the arguments of `sonido_synth::Sample::with_loop` and the
+    /// GUI file player's loop region.
+    pub fn loop_frames(&self) -> Option<(usize, usize)> {
+        self.loops
+            .iter()
+            .find(|l| l.start < l.end)
+            .map(|l| (l.start as usize, l.end as usize))
+    }
+}
+
+/// Read the metadata chunks of a WAV file without loading sample data.
+///
+/// Returns an empty [`WavMetadata`] for files with none of the supported
+/// chunks.
+pub fn read_wav_metadata<P: AsRef<Path>>(path: P) -> Result<WavMetadata> {
+    let mut file = std::io::BufReader::new(std::fs::File::open(path)?);
+    let mut header = [0u8; 12];
+    file.read_exact(&mut header)?;
+    check_riff(&header)?;
+
+    let mut metadata = WavMetadata::default();
+    let mut labels = Vec::new();
+    loop {
+        let mut chunk = [0u8; 8];
+        match file.read_exact(&mut chunk) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        }
+        let id: [u8; 4] = chunk[..4].try_into().unwrap_or_default();
+        let size = u32_at(&chunk, 4) as u64;
+        if is_metadata_chunk(id) {
+            let mut body = Vec::new();
+            (&mut file).take(size).read_to_end(&mut body)?;
+            if (body.len() as u64) < size {
+                break;
+            }
+            parse_chunk(id, &body, &mut metadata, &mut labels);
+            file.seek(SeekFrom::Current((size & 1) as i64))?;
+        } else {
+            file.seek(SeekFrom::Current((size + (size & 1)) as i64))?;
+        }
+    }
+
+    for (id, label) in labels {
+        if let Some(cue) = metadata.cues.iter_mut().find(|c| c.id == id) {
+            cue.label = Some(label);
+        }
+    }
+    Ok(metadata)
+}
+
+/// Replace the metadata chunks of an existing WAV file.
+///
+/// Any `bext`, `cue `, `LIST`/`adtl`, and `smpl` chunks already in the file
+/// are dropped; every other chunk keeps its order. The new `bext` goes right
+/// after `fmt `, as EBU Tech 3285 recommends, and the cue list, labels, and
+/// loops go at the end. Typically called right after [`write_wav`](crate::write_wav)
+/// or [`write_wav_stereo`](crate::write_wav_stereo).
+pub fn write_wav_metadata<P: AsRef<Path>>(path: P, metadata: &WavMetadata) -> Result<()> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path)?;
+    if bytes.len() < 12 {
+        return Err(not_wav());
+    }
+    check_riff(&bytes[..12])?;
+
+    let mut out = Vec::with_capacity(bytes.len() + 1024);
+    out.extend_from_slice(b"RIFF\0\0\0\0WAVE");
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id: [u8; 4] = bytes[pos..pos + 4].try_into().unwrap_or_default();
+        let size = u32_at(&bytes, pos + 4) as usize;
+        let end = (pos + 8 + size).min(bytes.len());
+        if !is_metadata_chunk(id) || (&id == b"LIST" && !is_adtl(&bytes[pos + 8..end])) {
+            push_chunk(&mut out, id, &bytes[pos + 8..end]);
+        }
+        if &id == b"fmt "
+            && let Some(bext) = &metadata.bext
+        {
+            push_chunk(&mut out, *b"bext", &encode_bext(bext));
+        }
+        pos = end + (size & 1);
+    }
+
+    if !metadata.cues.is_empty() {
+        let mut cue = Vec::with_capacity(4 + 24 * metadata.cues.len());
+        cue.extend((metadata.cues.len() as u32).to_le_bytes());
+        for point in &metadata.cues {
+            for field in [point.id, point.frame] {
+                cue.extend(field.to_le_bytes());
+            }
+            cue.extend(b"data");
+            for field in [0, 0, point.frame] {
+                cue.extend(field.to_le_bytes());
+            }
+        }
+        push_chunk(&mut out, *b"cue ", &cue);
+
+        let mut adtl = b"adtl".to_vec();
+        for point in &metadata.cues {
+            if let Some(label) = &point.label {
+                let mut labl = point.id.to_le_bytes().to_vec();
+                labl.extend(label.as_bytes());
+                labl.push(0);
+                push_chunk(&mut adtl, *b"labl", &labl);
+            }
+        }
+        if adtl.len() > 4 {
+            push_chunk(&mut out, *b"LIST", &adtl);
+        }
+    }
+
+    if !metadata.loops.is_empty() || metadata.root_note.is_some() {
+        let mut smpl = Vec::with_capacity(36 + 24 * metadata.loops.len());
+        let header = [
+            0,
+            0,
+            0,
+            u32::from(metadata.root_note.unwrap_or(60)),
+            0,
+            0,
+            0,
+            metadata.loops.len() as u32,
+            0,
+        ];
+        for field in header {
+            smpl.extend(field.to_le_bytes());
+        }
+        for l in &metadata.loops {
+            let kind = match l.kind {
+                LoopKind::Forward => 0,
+                LoopKind::PingPong => 1,
+                LoopKind::Backward => 2,
+            };
+            for field in [
+                l.id,
+                kind,
+                l.start,
+                l.end.saturating_sub(1),
+                0,
+                l.play_count,
+            ] {
+                smpl.extend(field.to_le_bytes());
+            }
+        }
+        push_chunk(&mut out, *b"smpl", &smpl);
+    }
+
+    let riff_size = u32::try_from(out.len() - 8)
+        .map_err(|_| Error::UnsupportedFormat("WAV file larger than 4 GiB".into()))?;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    std::fs::write(path, out)?;
+    Ok(())
+}
+
+fn not_wav() -> Error {
+    Error::UnsupportedFormat("not a RIFF/WAVE file".into())
+}
+
+fn check_riff(header: &[u8]) -> Result<()> {
+    if &header[..4] == b"RIFF" && &header[8..12] == b"WAVE" {
+        Ok(())
+    } else {
+        Err(not_wav())
+    }
+}
+
+fn is_metadata_chunk(id: [u8; 4]) -> bool {
+    matches!(&id, b"bext" | b"cue " | b"LIST" | b"smpl")
+}
+
+fn is_adtl(body: &[u8]) -> bool {
+    body.starts_with(b"adtl")
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> u32 {
+    bytes
+        .get(at..at + 4)
+        .map_or(0, |b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Append a chunk with its header and pad byte.
+fn push_chunk(out: &mut Vec<u8>, id: [u8; 4], body: &[u8]) {
+    out.extend(id);
+    out.extend((body.len() as u32).to_le_bytes());
+    out.extend(body);
+    if body.len() % 2 == 1 {
+        out.push(0);
+    }
+}
+
+/// Text up to the first NUL, lossily decoded.
+fn text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn parse_chunk(
+    id: [u8; 4],
+    body: &[u8],
+    metadata: &mut WavMetadata,
+    labels: &mut Vec<(u32, String)>,
+) {
+    match &id {
+        b"bext" if body.len() >= BEXT_FIXED_LEN => metadata.bext = Some(decode_bext(body)),
+        b"cue " => {
+            let count = u32_at(body, 0) as usize;
+            metadata.cues = body
+                .get(4..)
+                .unwrap_or_default()
+                .chunks_exact(24)
+                .take(count)
+                .map(|point| CuePoint {
+                    id: u32_at(point, 0),
+                    frame: u32_at(point, 20),
+                    label: None,
+                })
+                .collect();
+        }
+        b"LIST" if is_adtl(body) => {
+            let mut pos = 4;
+            while pos + 8 <= body.len() {
+                let size = u32_at(body, pos + 4) as usize;
+                let sub = &body[pos + 8..(pos + 8 + size).min(body.len())];
+                if &body[pos..pos + 4] == b"labl" && sub.len() >= 4 {
+                    labels.push((u32_at(sub, 0), text(&sub[4..])));
+                }
+                pos += 8 + size + (size & 1);
+            }
+        }
+        b"smpl" if body.len() >= 36 => {
+            let note = u32_at(body, 12);
+            metadata.root_note = u8::try_from(note).ok().filter(|n| *n < 128);
+            let count = u32_at(body, 28) as usize;
+            metadata.loops = body[36..]
+                .chunks_exact(24)
+                .take(count)
+                .map(|l| SampleLoop {
+                    id: u32_at(l, 0),
+                    kind: match u32_at(l, 4) {
+                        1 => LoopKind::PingPong,
+                        2 => LoopKind::Backward,
+                        _ => LoopKind::Forward,
+                    },
+                    start: u32_at(l, 8),
+                    end: u32_at(l, 12).saturating_add(1),
+                    play_count: u32_at(l, 20),
+                })
+                .collect();
+        }
+        _ => {}
+    }
+}
+
+fn decode_bext(body: &[u8]) -> BroadcastExtension {
+    let version = u16::from_le_bytes([body[346], body[347]]);
+    let loudness = |at: usize| {
+        let raw = i16::from_le_bytes([body[at], body[at + 1]]);
+        (version >= 2 && raw != LOUDNESS_UNSET).then_some(f32::from(raw) / 100.0)
+    };
+    let mut umid = [0; 64];
+    umid.copy_from_slice(&body[348..412]);
+    BroadcastExtension {
+        description: text(&body[0..256]),
+        originator: text(&body[256..288]),
+        originator_reference: text(&body[288..320]),
+        origination_date: text(&body[320..330]),
+        origination_time: text(&body[330..338]),
+        time_reference: u64::from(u32_at(body, 338)) | (u64::from(u32_at(body, 342)) << 32),
+        version,
+        umid,
+        loudness_value: loudness(412),
+        loudness_range: loudness(414),
+        max_true_peak: loudness(416),
+        max_momentary_loudness: loudness(418),
+        max_short_term_loudness: loudness(420),
+        coding_history: text(&body[BEXT_FIXED_LEN..]),
+    }
+}
+
+fn encode_bext(bext: &BroadcastExtension) -> Vec<u8> {
+    let mut body = vec![0u8; BEXT_FIXED_LEN];
+    let mut put_text = |at: usize, len: usize, value: &str| {
+        let bytes = value.as_bytes();
+        let n = bytes.len().min(len);
+        body[at..at + n].copy_from_slice(&bytes[..n]);
+    };
+    put_text(0, 256, &bext.description);
+    put_text(256, 32, &bext.originator);
+    put_text(288, 32, &bext.originator_reference);
+    put_text(320, 10, &bext.origination_date);
+    put_text(330, 8, &bext.origination_time);
+    body[338..346].copy_from_slice(&bext.time_reference.to_le_bytes());
+    body[346..348].copy_from_slice(&bext.version.to_le_bytes());
+    body[348..412].copy_from_slice(&bext.umid);
+    let loudness = [
+        bext.loudness_value,
+        bext.loudness_range,
+        bext.max_true_peak,
+        bext.max_momentary_loudness,
+        bext.max_short_term_loudness,
+    ];
+    for (i, value) in loudness.into_iter().enumerate() {
+        let raw = value.map_or(LOUDNESS_UNSET, |v| {
+            (v * 100.0).round().clamp(-32768.0, 32766.0) as i16
+        });
+        body[412 + 2 * i..414 + 2 * i].copy_from_slice(&raw.to_le_bytes());
+    }
+    body.extend(bext.coding_history.as_bytes());
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WavSpec, read_wav, write_wav};
+    use tempfile::NamedTempFile;
+
+    fn sample_metadata() -> WavMetadata {
+        WavMetadata {
+            bext: Some(BroadcastExtension {
+                description: "Snare top, take 3".into(),
+                originator: "sonido".into(),
+                origination_date: "2026-10-17".into(),
+                origination_time: "14:05:00".into(),
+                time_reference: 48_000 * 3600 * 10,
+                loudness_value: Some(-23.0),
+                max_true_peak: Some(-1.5),
+                coding_history: "A=PCM,F=48000,W=24,M=mono\r\n".into(),
+                ..BroadcastExtension::default()
+            }),
+            cues: vec![
+                CuePoint {
+                    id: 1,
+                    frame: 100,
+                    label: Some("Hit".into()),
+                },
+                CuePoint {
+                    id: 2,
+                    frame: 600,
+                    label: None,
+                },
+            ],
+            loops: vec![SampleLoop {
+                id: 0,
+                kind: LoopKind::Forward,
+                start: 200,
+                end: 800,
+                play_count: 0,
+            }],
+            root_note: Some(62),
+        }
+    }
+
+    #[test]
+    fn metadata_roundtrips_and_keeps_audio() {
+        let samples: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.01).sin()).collect();
+        let file = NamedTempFile::new().unwrap();
+        write_wav(file.path(), &samples, WavSpec::default()).unwrap();
+        assert!(read_wav_metadata(file.path()).unwrap().is_empty());
+
+        let metadata = sample_metadata();
+        write_wav_metadata(file.path(), &metadata).unwrap();
+        assert_eq!(read_wav_metadata(file.path()).unwrap(), metadata);
+        assert_eq!(metadata.loop_frames(), Some((200, 800)));
+
+        let (loaded, _) = read_wav(file.path()).unwrap();
+        assert_eq!(loaded, samples);
+        let riff_size = u32_at(&std::fs::read(file.path()).unwrap(), 4) as u64;
+        assert_eq!(riff_size + 8, std::fs::metadata(file.path()).unwrap().len());
+    }
+
+    #[test]
+    fn rewriting_replaces_previous_metadata() {
+        let file = NamedTempFile::new().unwrap();
+        write_wav(file.path(), &[0.0; 64], WavSpec::default()).unwrap();
+        write_wav_metadata(file.path(), &sample_metadata()).unwrap();
+
+        let cues_only = WavMetadata {
+            cues: vec![CuePoint {
+                id: 7,
+                frame: 3,
+                label: Some("Odd".into()),
+            }],
+            ..WavMetadata::default()
+        };
+        write_wav_metadata(file.path(), &cues_only).unwrap();
+        assert_eq!(read_wav_metadata(file.path()).unwrap(), cues_only);
+        assert_eq!(read_wav(file.path()).unwrap().0.len(), 64);
+    }
+
+    #[test]
+    fn rejects_non_wav() {
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), b"not a wave file at all").unwrap();
+        assert!(matches!(
+            read_wav_metadata(file.path()),
+            Err(Error::UnsupportedFormat(_))
+        ));
+    }
+}
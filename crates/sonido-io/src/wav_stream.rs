@@ -0,0 +1,391 @@
+//! Block-wise WAV reading, writing, and file processing.
+//!
+//! [`read_wav_stereo`](crate::read_wav_stereo) and
+//! [`write_wav_stereo`](crate::write_wav_stereo) hold a whole file in memory.
+//! The types here move one block at a time instead, so rendering a file
+//! through a [`GraphEngine`] uses the same memory for a ten-second clip and a
+//! two-hour recording.
+//!
+//! ```rust,ignore
+//! use sonido_io::{GraphEngine, WavBlockReader, WavBlockWriter, process_wav_stream};
+//! use std::ops::ControlFlow;
+//!
+//! let mut reader = WavBlockReader::open("input.wav")?;
+//! let spec = reader.spec();
+//! let mut writer = WavBlockWriter::create("output.wav", spec)?;
+//! let mut engine = GraphEngine::new_linear(spec.sample_rate as f32, 512);
+//! // ... add effects ...
+//! process_wav_stream(&mut engine, &mut reader, &mut writer, 512, |_, _| {
+//!     ControlFlow::Continue(())
+//! })?;
+//! writer.finalize()?;
+//! ```
+
+use crate::{GraphEngine, Result, WavSpec};
+use hound::{SampleFormat, WavReader, WavWriter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::ops::ControlFlow;
+use std::path::Path;
+
+/// Reads a WAV file as stereo blocks.
+///
+/// Channels are mapped the same way as [`read_wav_stereo`](crate::read_wav_stereo):
+/// mono is duplicated to both sides and files with more than two channels
+/// use the first two.
+pub struct WavBlockReader {
+    reader: WavReader<BufReader<File>>,
+    spec: WavSpec,
+    format: SampleFormat,
+    /// Total frames in the file.
+    len: usize,
+    /// Frames read so far.
+    position: usize,
+    /// Interleaved samples of the current block.
+    interleaved: Vec<f32>,
+}
+
+impl WavBlockReader {
+    /// Opens `path` and reads its header.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let reader = WavReader::open(path)?;
+        let spec = WavSpec::from(reader.spec());
+        let len = reader.len() as usize / usize::from(spec.channels.max(1));
+        Ok(Self {
+            format: reader.spec().sample_format,
+            reader,
+            spec,
+            len,
+            position: 0,
+            interleaved: Vec::new(),
+        })
+    }
+
+    /// Format of the file being read.
+    pub fn spec(&self) -> WavSpec {
+        self.spec
+    }
+
+    /// Total length in frames (samples per channel).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the file has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Frames read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Reads the next block into `left` and `right`, filling at most
+    /// `left.len()` frames. Returns the number of frames read, which is `0`
+    /// at the end of the file.
+    pub fn read_stereo(&mut self, left: &mut [f32], right: &mut [f32]) -> Result<usize> {
+        let channels = usize::from(self.spec.channels.max(1));
+        let frames = left.len().min(right.len()).min(self.len - self.position);
+        let wanted = frames * channels;
+
+        self.interleaved.clear();
+        match self.format {
+            SampleFormat::Float => {
+                for sample in self.reader.samples::<f32>().take(wanted) {
+                    self.interleaved.push(sample?);
+                }
+            }
+            SampleFormat::Int => {
+                let max_val = (1i32 << (self.spec.bits_per_sample - 1)) as f32;
+                for sample in self.reader.samples::<i32>().take(wanted) {
+                    self.interleaved.push(sample? as f32 / max_val);
+                }
+            }
+        }
+
+        let frames = self.interleaved.len() / channels;
+        for (i, frame) in self.interleaved.chunks_exact(channels).enumerate() {
+            left[i] = frame[0];
+            right[i] = frame.get(1).copied().unwrap_or(frame[0]);
+        }
+        self.position += frames;
+        Ok(frames)
+    }
+}
+
+/// Writes a WAV file one stereo block at a time.
+///
+/// A mono `spec` writes the average of both channels, like
+/// [`StereoSamples::to_mono`](crate::StereoSamples::to_mono). Call
+/// [`finalize()`](Self::finalize) when done; dropping the writer also
+/// finalizes the header but ignores errors.
+pub struct WavBlockWriter {
+    writer: WavWriter<BufWriter<File>>,
+    spec: WavSpec,
+    /// Frames written so far.
+    frames: usize,
+}
+
+impl WavBlockWriter {
+    /// Creates `path`. Only one or two channels are written; any other
+    /// channel count is written as stereo.
+    pub fn create<P: AsRef<Path>>(path: P, spec: WavSpec) -> Result<Self> {
+        let mut spec = spec;
+        if spec.channels != 1 {
+            spec.channels = 2;
+        }
+        let writer = WavWriter::create(path, hound::WavSpec::from(spec))?;
+        Ok(Self {
+            writer,
+            spec,
+            frames: 0,
+        })
+    }
+
+    /// Format being written.
+    pub fn spec(&self) -> WavSpec {
+        self.spec
+    }
+
+    /// Frames written so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Appends a stereo block. `left` and `right` must be the same length.
+    pub fn write_stereo(&mut self, left: &[f32], right: &[f32]) -> Result<()> {
+        debug_assert_eq!(left.len(), right.len());
+        let mono = self.spec.channels == 1;
+        for (&l, &r) in left.iter().zip(right) {
+            if mono {
+                self.write_sample((l + r) * 0.5)?;
+            } else {
+                self.write_sample(l)?;
+                self.write_sample(r)?;
+            }
+        }
+        self.frames += left.len().min(right.len());
+        Ok(())
+    }
+
+    fn write_sample(&mut self, sample: f32) -> Result<()> {
+        if self.spec.bits_per_sample == 32 {
+            self.writer.write_sample(sample)?;
+        } else {
+            let max_val = (1i32 << (self.spec.bits_per_sample - 1)) as f32;
+            let int_sample = (sample * max_val).clamp(-max_val, max_val - 1.0) as i32;
+            self.writer.write_sample(int_sample)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the final header sizes and closes the file.
+    pub fn finalize(self) -> Result<()> {
+        self.writer.finalize()?;
+        Ok(())
+    }
+}
+
+/// One block handed to the [`process_wav_stream`] callback.
+pub struct StreamBlock<'a> {
+    /// Frame offset of this block in the input file.
+    pub position: usize,
+    /// Input, left channel.
+    pub input_left: &'a [f32],
+    /// Input, right channel.
+    pub input_right: &'a [f32],
+    /// Processed output, left channel (already written).
+    pub output_left: &'a [f32],
+    /// Processed output, right channel (already written).
+    pub output_right: &'a [f32],
+}
+
+/// Streams `reader` through `engine` into `writer`, `block_size` frames at a
+/// time. Returns the number of frames processed.
+///
+/// Renders the same as
+/// [`process_file_stereo()`](GraphEngine::process_file_stereo) on the whole
+/// file, holding only one block in memory. After each block is written,
+/// `on_block` sees it along with the engine (to take stems, for example);
+/// returning [`ControlFlow::Break`] stops early, leaving `writer` with
+/// everything processed so far. The writer is not finalized, so callers can
+/// append a tail first.
+pub fn process_wav_stream<F>(
+    engine: &mut GraphEngine,
+    reader: &mut WavBlockReader,
+    writer: &mut WavBlockWriter,
+    block_size: usize,
+    mut on_block: F,
+) -> Result<usize>
+where
+    F: FnMut(&mut GraphEngine, &StreamBlock<'_>) -> ControlFlow<()>,
+{
+    let block_size = block_size.max(1);
+    let mut left_in = vec![0.0; block_size];
+    let mut right_in = vec![0.0; block_size];
+    let mut left_out = vec![0.0; block_size];
+    let mut right_out = vec![0.0; block_size];
+    let mut processed = 0;
+
+    loop {
+        let position = reader.position();
+        let n = reader.read_stereo(&mut left_in, &mut right_in)?;
+        if n == 0 {
+            break;
+        }
+        engine.process_file_chunk(
+            &left_in[..n],
+            &right_in[..n],
+            &mut left_out[..n],
+            &mut right_out[..n],
+            block_size,
+        );
+        writer.write_stereo(&left_out[..n], &right_out[..n])?;
+        processed += n;
+
+        let block = StreamBlock {
+            position,
+            input_left: &left_in[..n],
+            input_right: &right_in[..n],
+            output_left: &left_out[..n],
+            output_right: &right_out[..n],
+        };
+        if on_block(engine, &block).is_break() {
+            break;
+        }
+    }
+
+    Ok(processed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StereoSamples, read_wav_stereo, write_wav_stereo};
+    use tempfile::tempdir;
+
+    fn ramp(len: usize) -> StereoSamples {
+        let left: Vec<f32> = (0..len).map(|i| (i as f32 / len as f32) - 0.5).collect();
+        let right = left.iter().map(|s| -s).collect();
+        StereoSamples::new(left, right)
+    }
+
+    #[test]
+    fn blocks_read_like_whole_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("in.wav");
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+        };
+        write_wav_stereo(&path, &ramp(1000), spec).unwrap();
+        let (whole, _) = read_wav_stereo(&path).unwrap();
+
+        let mut reader = WavBlockReader::open(&path).unwrap();
+        assert_eq!(reader.len(), 1000);
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut l = [0.0; 300];
+        let mut r = [0.0; 300];
+        loop {
+            let n = reader.read_stereo(&mut l, &mut r).unwrap();
+            if n == 0 {
+                break;
+            }
+            left.extend_from_slice(&l[..n]);
+            right.extend_from_slice(&r[..n]);
+        }
+        assert_eq!(reader.position(), 1000);
+        assert_eq!(left, whole.left);
+        assert_eq!(right, whole.right);
+    }
+
+    #[test]
+    fn mono_writer_mixes_channels() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mono.wav");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+        };
+        let mut writer = WavBlockWriter::create(&path, spec).unwrap();
+        writer.write_stereo(&[1.0, 0.5], &[0.0, 0.5]).unwrap();
+        assert_eq!(writer.frames(), 2);
+        writer.finalize().unwrap();
+
+        let mut reader = WavBlockReader::open(&path).unwrap();
+        assert_eq!(reader.spec().channels, 1);
+        let mut l = [0.0; 4];
+        let mut r = [0.0; 4];
+        assert_eq!(reader.read_stereo(&mut l, &mut r).unwrap(), 2);
+        assert_eq!(l[..2], [0.5, 0.5]);
+        assert_eq!(r[..2], [0.5, 0.5]);
+    }
+
+    #[test]
+    fn stream_matches_in_memory_processing() {
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("in.wav");
+        let output = dir.path().join("out.wav");
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+        };
+        let samples = ramp(2000);
+        write_wav_stereo(&input, &samples, spec).unwrap();
+
+        let expected = GraphEngine::new_linear(48000.0, 64).process_file_stereo(&samples, 64);
+
+        let mut engine = GraphEngine::new_linear(48000.0, 64);
+        let mut reader = WavBlockReader::open(&input).unwrap();
+        let mut writer = WavBlockWriter::create(&output, spec).unwrap();
+        let mut blocks = 0;
+        let frames = process_wav_stream(&mut engine, &mut reader, &mut writer, 64, |_, block| {
+            assert_eq!(block.position, blocks * 64);
+            blocks += 1;
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        writer.finalize().unwrap();
+
+        assert_eq!(frames, 2000);
+        assert_eq!(blocks, 32);
+        let (written, _) = read_wav_stereo(&output).unwrap();
+        assert_eq!(written.left, expected.left);
+        assert_eq!(written.right, expected.right);
+    }
+
+    #[test]
+    fn stream_stops_on_break_with_valid_file() {
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("in.wav");
+        let output = dir.path().join("out.wav");
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 16,
+        };
+        write_wav_stereo(&input, &ramp(2000), spec).unwrap();
+
+        let mut engine = GraphEngine::new_linear(48000.0, 256);
+        let mut reader = WavBlockReader::open(&input).unwrap();
+        let mut writer = WavBlockWriter::create(&output, spec).unwrap();
+        let frames = process_wav_stream(&mut engine, &mut reader, &mut writer, 256, |_, block| {
+            if block.position >= 512 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        writer.finalize().unwrap();
+
+        assert_eq!(frames, 768);
+        let (written, _) = read_wav_stereo(&output).unwrap();
+        assert_eq!(written.len(), 768);
+    }
+}
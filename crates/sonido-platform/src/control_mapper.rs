@@ -2,6 +2,15 @@
 //!
 //! This module provides [`ControlMapper`] which maps platform controls to effect
 //! parameters using the [`ParameterInfo`] trait for automatic denormalization.
+//! Each mapping carries a [`ControlResponse`] (curve plus min/max window) that
+//! shapes the control value before dispatch, and one control can drive several
+//! parameters at once — e.g. an expression pedal morphing between settings.
+//! [`writeback()`](ControlMapper::writeback) runs a mapping backwards, so
+//! controls can follow parameters changed elsewhere; LEDs showing parameter
+//! and bypass state are handled by [`LedFeedback`](crate::LedFeedback).
+//! Controls that can't be moved (potentiometers) instead use a
+//! [`PickupMode`] so a preset change doesn't make the parameter leap on the
+//! next touch.
 //!
 //! # Example
 //!
@@ -28,32 +37,100 @@
 //! }
 //! ```
 
-use crate::{ControlId, ParamDescriptor, ParameterInfo};
+use crate::response_curve::ControlResponse;
+use crate::{ControlId, ParamDescriptor, ParameterInfo, PlatformController};
+
+/// Control movement, in parameter position, that counts as reaching the
+/// parameter in [`PickupMode::Pickup`].
+pub const PICKUP_TOLERANCE: f32 = 0.02;
+
+/// How an absolute control takes over a parameter that was changed
+/// elsewhere (preset load, GUI, another control).
+///
+/// Applies to [`move_control()`](ControlMapper::move_control) after
+/// [`sync()`](ControlMapper::sync) has reported the parameter's new position;
+/// [`resolve()`](ControlMapper::resolve) always jumps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PickupMode {
+    /// The parameter jumps to the control's position on the next move.
+    #[default]
+    Jump,
+    /// Nothing changes until the control passes the parameter's position,
+    /// then it takes over.
+    Pickup,
+    /// The control moves the parameter by as much as it moves, starting
+    /// from wherever the parameter is.
+    Relative,
+}
 
 /// A single mapping entry from control to parameter.
 #[derive(Debug, Clone, Copy, PartialEq)]
-struct MappingEntry {
+struct MappingEntry<T> {
     /// The control ID this mapping is for.
     control_id: ControlId,
-    /// The parameter index in the effect.
-    param_index: usize,
+    /// The parameter this control drives.
+    target: T,
+    /// Curve and range applied before dispatch.
+    response: ControlResponse,
+    /// Takeover behavior after the parameter moves elsewhere.
+    pickup: PickupMode,
+    /// Control value at the last move, once it has moved.
+    last_value: Option<f32>,
+    /// Parameter position as last set or synced, once known.
+    position: Option<f32>,
+    /// False while waiting for pickup.
+    engaged: bool,
+}
+
+impl<T> MappingEntry<T> {
+    /// Parameter position for a control move, if the move changes it.
+    fn track(&mut self, value: f32) -> Option<f32> {
+        let position = self.response.apply(value);
+        let previous = self
+            .last_value
+            .replace(value)
+            .map(|v| self.response.apply(v));
+        let moved = match (self.pickup, self.position) {
+            (PickupMode::Jump, _) | (_, None) => Some(position),
+            (PickupMode::Pickup, Some(held)) => {
+                if !self.engaged {
+                    let crossed = previous.is_some_and(|p| (p - held) * (position - held) <= 0.0);
+                    self.engaged = crossed || (position - held).abs() <= PICKUP_TOLERANCE;
+                }
+                self.engaged.then_some(position)
+            }
+            (PickupMode::Relative, Some(held)) => {
+                previous.map(|p| (held + position - p).clamp(0.0, 1.0))
+            }
+        };
+        if moved.is_some() {
+            self.position = moved;
+        }
+        moved
+    }
 }
 
 /// Maps controls to effect parameters.
 ///
 /// `ControlMapper` provides a fixed-capacity mapping table that associates
-/// control IDs with effect parameter indices. It uses the [`ParameterInfo`]
-/// trait to automatically denormalize control values (0.0-1.0) to parameter
-/// ranges.
+/// control IDs with parameters. It uses the [`ParameterInfo`] trait to
+/// automatically denormalize control values (0.0-1.0) to parameter ranges,
+/// after shaping them with each mapping's [`ControlResponse`].
+///
+/// A control may have several targets, each with its own response; every
+/// target counts against the capacity.
 ///
-/// # Type Parameter
+/// # Type Parameters
 ///
 /// - `N`: Maximum number of mappings (compile-time constant for no_std support)
+/// - `T`: Parameter address. Defaults to a single effect's parameter index;
+///   multi-effect hosts can use e.g. `(slot, param)` pairs and dispatch
+///   [`resolve()`](Self::resolve) results themselves.
 ///
 /// # Example
 ///
 /// ```rust
-/// use sonido_platform::{ControlMapper, ControlId};
+/// use sonido_platform::{ControlMapper, ControlId, ControlResponse, ResponseCurve};
 ///
 /// // Create a mapper with capacity for 16 mappings
 /// let mut mapper = ControlMapper::<16>::new();
@@ -66,16 +143,22 @@ struct MappingEntry {
 /// // Look up mappings
 /// assert_eq!(mapper.get_param_index(ControlId::hardware(0)), Some(0));
 /// assert_eq!(mapper.get_param_index(ControlId::midi(74)), Some(2));
+///
+/// // Expression pedal on CC 11 sweeps param 3 up and param 4 down
+/// let pedal = ControlId::midi(11);
+/// mapper.add_target(pedal, 3, ControlResponse::new(ResponseCurve::Log, 0.0, 1.0));
+/// mapper.add_target(pedal, 4, ControlResponse::new(ResponseCurve::Linear, 0.9, 0.1));
+/// assert_eq!(mapper.targets(pedal).count(), 2);
 /// ```
 #[derive(Debug, Clone)]
-pub struct ControlMapper<const N: usize> {
+pub struct ControlMapper<const N: usize, T = usize> {
     /// Mapping entries (control -> parameter).
-    mappings: [Option<MappingEntry>; N],
+    mappings: [Option<MappingEntry<T>>; N],
     /// Number of active mappings.
     count: usize,
 }
 
-impl<const N: usize> ControlMapper<N> {
+impl<const N: usize, T: Copy + PartialEq> ControlMapper<N, T> {
     /// Creates a new empty control mapper.
     pub const fn new() -> Self {
         Self {
@@ -102,9 +185,9 @@ impl<const N: usize> ControlMapper<N> {
         N
     }
 
-    /// Maps a control to a parameter index.
+    /// Maps a control to a parameter with a linear, full-range response.
     ///
-    /// If the control is already mapped, updates the mapping.
+    /// If the control is already mapped, its targets are replaced by this one.
     /// Returns `true` if the mapping was added/updated, `false` if at capacity.
     ///
     /// # Arguments
@@ -121,21 +204,50 @@ impl<const N: usize> ControlMapper<N> {
     /// assert!(mapper.map(ControlId::hardware(0), 0));
     /// assert!(mapper.map(ControlId::hardware(1), 1));
     /// ```
-    pub fn map(&mut self, control_id: ControlId, param_index: usize) -> bool {
-        // Check if already mapped - update if so
-        for entry in self.mappings.iter_mut().flatten() {
-            if entry.control_id == control_id {
-                entry.param_index = param_index;
-                return true;
-            }
+    pub fn map(&mut self, control_id: ControlId, param_index: T) -> bool {
+        self.unmap(control_id);
+        self.add_target(control_id, param_index, ControlResponse::FULL)
+    }
+
+    /// Adds a parameter to the control's targets, keeping existing ones.
+    ///
+    /// If the control already drives this parameter, only the response is
+    /// updated. Returns `false` if at capacity.
+    pub fn add_target(
+        &mut self,
+        control_id: ControlId,
+        target: T,
+        response: ControlResponse,
+    ) -> bool {
+        if let Some(entry) = self
+            .mappings
+            .iter_mut()
+            .flatten()
+            .find(|e| e.control_id == control_id && e.target == target)
+        {
+            entry.response = response;
+            return true;
         }
 
+        // New targets share the control's pickup mode and position
+        let (pickup, last_value) = self
+            .mappings
+            .iter()
+            .flatten()
+            .find(|e| e.control_id == control_id)
+            .map_or((PickupMode::Jump, None), |e| (e.pickup, e.last_value));
+
         // Find empty slot
         for slot in self.mappings.iter_mut() {
             if slot.is_none() {
                 *slot = Some(MappingEntry {
                     control_id,
-                    param_index,
+                    target,
+                    response,
+                    pickup,
+                    last_value,
+                    position: None,
+                    engaged: true,
                 });
                 self.count += 1;
                 return true;
@@ -145,14 +257,27 @@ impl<const N: usize> ControlMapper<N> {
         false // At capacity
     }
 
-    /// Removes the mapping for a control.
+    /// Removes every mapping for a control.
     ///
     /// Returns `true` if a mapping was removed, `false` if not found.
     pub fn unmap(&mut self, control_id: ControlId) -> bool {
+        let mut removed = false;
         for slot in self.mappings.iter_mut() {
-            if let Some(entry) = slot
-                && entry.control_id == control_id
-            {
+            if slot.is_some_and(|entry| entry.control_id == control_id) {
+                *slot = None;
+                self.count -= 1;
+                removed = true;
+            }
+        }
+        removed
+    }
+
+    /// Removes one target of a control.
+    ///
+    /// Returns `true` if the mapping was removed, `false` if not found.
+    pub fn remove_target(&mut self, control_id: ControlId, target: T) -> bool {
+        for slot in self.mappings.iter_mut() {
+            if slot.is_some_and(|e| e.control_id == control_id && e.target == target) {
                 *slot = None;
                 self.count -= 1;
                 return true;
@@ -161,17 +286,13 @@ impl<const N: usize> ControlMapper<N> {
         false
     }
 
-    /// Gets the parameter index mapped to a control.
+    /// Gets the parameter mapped to a control.
     ///
-    /// Returns `None` if the control is not mapped.
+    /// Returns `None` if the control is not mapped. If the control has
+    /// several targets, returns the first found.
     #[inline]
-    pub fn get_param_index(&self, control_id: ControlId) -> Option<usize> {
-        for entry in self.mappings.iter().flatten() {
-            if entry.control_id == control_id {
-                return Some(entry.param_index);
-            }
-        }
-        None
+    pub fn get_param_index(&self, control_id: ControlId) -> Option<T> {
+        self.targets(control_id).next().map(|(target, _)| target)
     }
 
     /// Gets the control ID mapped to a parameter.
@@ -179,15 +300,172 @@ impl<const N: usize> ControlMapper<N> {
     /// Returns `None` if no control is mapped to this parameter.
     /// If multiple controls map to the same parameter, returns the first found.
     #[inline]
-    pub fn get_control_for_param(&self, param_index: usize) -> Option<ControlId> {
+    pub fn get_control_for_param(&self, param_index: T) -> Option<ControlId> {
         for entry in self.mappings.iter().flatten() {
-            if entry.param_index == param_index {
+            if entry.target == param_index {
                 return Some(entry.control_id);
             }
         }
         None
     }
 
+    /// Targets of a control with their responses.
+    pub fn targets(&self, control_id: ControlId) -> impl Iterator<Item = (T, &ControlResponse)> {
+        self.mappings
+            .iter()
+            .flatten()
+            .filter(move |e| e.control_id == control_id)
+            .map(|e| (e.target, &e.response))
+    }
+
+    /// Mutable response of one mapping, if it exists.
+    pub fn response_mut(
+        &mut self,
+        control_id: ControlId,
+        target: T,
+    ) -> Option<&mut ControlResponse> {
+        self.mappings
+            .iter_mut()
+            .flatten()
+            .find(|e| e.control_id == control_id && e.target == target)
+            .map(|e| &mut e.response)
+    }
+
+    /// All mappings as `(control, target, response)`, in table order.
+    pub fn entries(&self) -> impl Iterator<Item = (ControlId, T, &ControlResponse)> {
+        self.mappings
+            .iter()
+            .flatten()
+            .map(|e| (e.control_id, e.target, &e.response))
+    }
+
+    /// Normalized parameter positions for a control value.
+    ///
+    /// Yields each of the control's targets with `normalized_value` passed
+    /// through that mapping's response. Use when the parameters live outside
+    /// a single [`ParameterInfo`] effect.
+    pub fn resolve(
+        &self,
+        control_id: ControlId,
+        normalized_value: f32,
+    ) -> impl Iterator<Item = (T, f32)> + '_ {
+        self.targets(control_id)
+            .map(move |(target, response)| (target, response.apply(normalized_value)))
+    }
+
+    /// Sets how a control takes over its targets after they change
+    /// elsewhere.
+    ///
+    /// Returns `false` if the control is not mapped.
+    pub fn set_pickup_mode(&mut self, control_id: ControlId, mode: PickupMode) -> bool {
+        let mut found = false;
+        for entry in self
+            .mappings
+            .iter_mut()
+            .flatten()
+            .filter(|e| e.control_id == control_id)
+        {
+            entry.pickup = mode;
+            entry.engaged = true;
+            found = true;
+        }
+        found
+    }
+
+    /// The control's pickup mode, if it is mapped.
+    pub fn pickup_mode(&self, control_id: ControlId) -> Option<PickupMode> {
+        self.mappings
+            .iter()
+            .flatten()
+            .find(|e| e.control_id == control_id)
+            .map(|e| e.pickup)
+    }
+
+    /// Normalized parameter positions for a control move, applying each
+    /// mapping's [`PickupMode`].
+    ///
+    /// The stateful counterpart of [`resolve()`](Self::resolve): yields only
+    /// the targets the move changes, with their new positions. Targets
+    /// waiting for pickup are skipped, and relative targets need one move to
+    /// learn where the control is. The mapper's state is updated as the
+    /// iterator is consumed.
+    pub fn move_control(
+        &mut self,
+        control_id: ControlId,
+        normalized_value: f32,
+    ) -> impl Iterator<Item = (T, f32)> + '_ {
+        self.mappings
+            .iter_mut()
+            .flatten()
+            .filter(move |e| e.control_id == control_id)
+            .filter_map(move |e| {
+                e.track(normalized_value)
+                    .map(|position| (e.target, position))
+            })
+    }
+
+    /// Reports a parameter's position after it changed elsewhere.
+    ///
+    /// Controls in [`PickupMode::Pickup`] that aren't already at the new
+    /// position stop driving it until they reach it; relative controls
+    /// continue from it.
+    pub fn sync(&mut self, target: T, position: f32) {
+        for entry in self
+            .mappings
+            .iter_mut()
+            .flatten()
+            .filter(|e| e.target == target)
+        {
+            entry.position = Some(position);
+            entry.engaged = entry
+                .last_value
+                .is_some_and(|v| (entry.response.apply(v) - position).abs() <= PICKUP_TOLERANCE);
+        }
+    }
+
+    /// True if any of the control's targets is waiting for pickup.
+    pub fn awaiting_pickup(&self, control_id: ControlId) -> bool {
+        self.mappings
+            .iter()
+            .flatten()
+            .any(|e| e.control_id == control_id && e.pickup == PickupMode::Pickup && !e.engaged)
+    }
+
+    /// Control positions that reproduce a parameter position.
+    ///
+    /// The reverse of [`resolve()`](Self::resolve): when a parameter changes
+    /// elsewhere (GUI, preset load, another control), yields every control
+    /// mapped to it with the value that would set it there, through
+    /// [`ControlResponse::position_for`]. Relative controls (encoders) and
+    /// motorized or LED-ring controllers can then follow without a jump on
+    /// the next move.
+    pub fn writeback(
+        &self,
+        target: T,
+        position: f32,
+    ) -> impl Iterator<Item = (ControlId, f32)> + '_ {
+        self.mappings
+            .iter()
+            .flatten()
+            .filter(move |e| e.target == target)
+            .map(move |e| (e.control_id, e.response.position_for(position)))
+    }
+
+    /// Writes the [`writeback()`](Self::writeback) positions for a parameter
+    /// to a controller.
+    ///
+    /// Returns the number of controls the controller accepted.
+    pub fn write_back<C: PlatformController>(
+        &self,
+        target: T,
+        position: f32,
+        controller: &mut C,
+    ) -> usize {
+        self.writeback(target, position)
+            .filter(|&(control, value)| controller.write_control(control, value))
+            .count()
+    }
+
     /// Clears all mappings.
     pub fn clear(&mut self) {
         for slot in self.mappings.iter_mut() {
@@ -195,11 +473,14 @@ impl<const N: usize> ControlMapper<N> {
         }
         self.count = 0;
     }
+}
 
+impl<const N: usize> ControlMapper<N> {
     /// Denormalizes a control value using the effect's parameter info.
     ///
     /// Converts a normalized control value (0.0-1.0) to the actual parameter
-    /// range using the [`ParamDescriptor`] for the mapped parameter.
+    /// range using the mapping's response and the [`ParamDescriptor`] for the
+    /// mapped parameter. With several targets, uses the first.
     ///
     /// Returns `None` if:
     /// - The control is not mapped
@@ -226,15 +507,16 @@ impl<const N: usize> ControlMapper<N> {
         normalized_value: f32,
         effect: &E,
     ) -> Option<f32> {
-        let param_index = self.get_param_index(control_id)?;
+        let (param_index, response) = self.targets(control_id).next()?;
         let descriptor = effect.param_info(param_index)?;
-        Some(descriptor.denormalize(normalized_value))
+        Some(descriptor.denormalize(response.apply(normalized_value)))
     }
 
     /// Normalizes a parameter value for control display.
     ///
     /// Converts a parameter value to normalized range (0.0-1.0) for
-    /// displaying on a control or sending via MIDI.
+    /// displaying on a control or sending via MIDI. The mapping's response
+    /// is not inverted.
     ///
     /// Returns `None` if:
     /// - The control is not mapped
@@ -253,10 +535,10 @@ impl<const N: usize> ControlMapper<N> {
 
     /// Applies a control change to an effect.
     ///
-    /// Convenience method that denormalizes the control value and sets
-    /// the corresponding effect parameter.
+    /// Convenience method that shapes the control value with each mapping's
+    /// response, denormalizes it, and sets every parameter the control drives.
     ///
-    /// Returns `true` if the parameter was set, `false` if the mapping
+    /// Returns `true` if any parameter was set, `false` if the mapping
     /// or parameter info was not found.
     ///
     /// # Example
@@ -275,21 +557,22 @@ impl<const N: usize> ControlMapper<N> {
         normalized_value: f32,
         effect: &mut E,
     ) -> bool {
-        if let Some(param_index) = self.get_param_index(control_id)
-            && let Some(descriptor) = effect.param_info(param_index)
-        {
-            let value = descriptor.denormalize(normalized_value);
-            effect.set_param(param_index, value);
-            return true;
+        let mut applied = false;
+        for (param_index, position) in self.resolve(control_id, normalized_value) {
+            if let Some(descriptor) = effect.param_info(param_index) {
+                effect.set_param(param_index, descriptor.denormalize(position));
+                applied = true;
+            }
         }
-        false
+        applied
     }
 
     /// Applies a control change to an effect using a custom transform.
     ///
-    /// Like [`apply()`](Self::apply), but instead of linear denormalization,
-    /// calls `map_fn` with the parameter descriptor and normalized value.
-    /// Use for biased mapping (e.g., noon presets on embedded hardware).
+    /// Like [`apply()`](Self::apply), but instead of the mapping's response
+    /// and linear denormalization, calls `map_fn` with the parameter
+    /// descriptor and normalized value. With several targets, only the first
+    /// is set. Use for biased mapping (e.g., noon presets on embedded hardware).
     ///
     /// Returns `true` if the parameter was set, `false` if the mapping
     /// or parameter info was not found.
@@ -335,7 +618,7 @@ impl<const N: usize> ControlMapper<N> {
     }
 }
 
-impl<const N: usize> Default for ControlMapper<N> {
+impl<const N: usize, T: Copy + PartialEq> Default for ControlMapper<N, T> {
     fn default() -> Self {
         Self::new()
     }
@@ -563,6 +846,54 @@ mod tests {
         assert!(mapper.is_empty());
     }
 
+    #[test]
+    fn test_multiple_targets_with_responses() {
+        use crate::response_curve::ResponseCurve;
+
+        let mut mapper = ControlMapper::<8>::new();
+        let mut effect = TestEffect::new();
+        let pedal = ControlId::midi(11);
+
+        // Heel: loud and dark; toe: quiet and bright
+        assert!(mapper.add_target(
+            pedal,
+            0,
+            ControlResponse::new(ResponseCurve::Linear, 1.0, 0.0)
+        ));
+        assert!(mapper.add_target(pedal, 1, ControlResponse::FULL));
+        assert_eq!(mapper.len(), 2);
+
+        assert!(mapper.apply(pedal, 0.0, &mut effect));
+        assert!((effect.gain - 12.0).abs() < 0.01);
+        assert!((effect.frequency - 20.0).abs() < 0.01);
+        assert!(mapper.apply(pedal, 1.0, &mut effect));
+        assert!((effect.gain - (-60.0)).abs() < 0.01);
+        assert!((effect.frequency - 20000.0).abs() < 0.01);
+
+        // Re-adding a target only updates its response
+        assert!(mapper.add_target(
+            pedal,
+            1,
+            ControlResponse::new(ResponseCurve::Linear, 0.0, 0.5)
+        ));
+        assert_eq!(mapper.len(), 2);
+        let resolved: Vec<_> = mapper.resolve(pedal, 1.0).collect();
+        assert_eq!(resolved, [(0, 0.0), (1, 0.5)]);
+
+        // map() replaces all targets; remove_target() drops one
+        mapper.add_target(ControlId::midi(1), 0, ControlResponse::FULL);
+        assert!(mapper.remove_target(pedal, 0));
+        assert!(!mapper.remove_target(pedal, 0));
+        assert!(mapper.map(pedal, 1));
+        assert_eq!(mapper.targets(pedal).count(), 1);
+        assert_eq!(
+            mapper.response_mut(pedal, 1).copied(),
+            Some(ControlResponse::FULL)
+        );
+        assert!(mapper.unmap(ControlId::midi(1)));
+        assert_eq!(mapper.len(), 1);
+    }
+
     #[test]
     fn test_apply_with_fn_uses_custom_transform() {
         let mut mapper = ControlMapper::<8>::new();
@@ -584,4 +915,150 @@ mod tests {
             |_desc, _norm| 0.0,
         ));
     }
+
+    #[test]
+    fn test_writeback_inverts_responses() {
+        use crate::response_curve::ResponseCurve;
+
+        let mut mapper = ControlMapper::<8, u8>::new();
+        mapper.add_target(
+            ControlId::hardware(0),
+            7,
+            ControlResponse::new(ResponseCurve::Linear, 0.2, 0.6),
+        );
+        mapper.add_target(
+            ControlId::hardware(1),
+            7,
+            ControlResponse::FULL.inverted(true),
+        );
+        mapper.map(ControlId::hardware(2), 3);
+
+        let positions: Vec<_> = mapper.writeback(7, 0.4).collect();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].0, ControlId::hardware(0));
+        assert!((positions[0].1 - 0.5).abs() < 1e-3);
+        assert_eq!(positions[1].0, ControlId::hardware(1));
+        assert!((positions[1].1 - 0.6).abs() < 1e-3);
+        assert_eq!(mapper.writeback(9, 0.4).count(), 0);
+    }
+
+    #[test]
+    fn test_write_back_to_controller() {
+        struct Recorder(Vec<(ControlId, f32)>);
+
+        impl PlatformController for Recorder {
+            fn control_count(&self) -> usize {
+                0
+            }
+            fn control_id(&self, _index: usize) -> Option<ControlId> {
+                None
+            }
+            fn control_type(&self, _id: ControlId) -> Option<crate::ControlType> {
+                None
+            }
+            fn read_control(&self, _id: ControlId) -> Option<crate::ControlState> {
+                None
+            }
+            fn write_control(&mut self, id: ControlId, value: f32) -> bool {
+                self.0.push((id, value));
+                id.is_hardware()
+            }
+        }
+
+        let mut mapper = ControlMapper::<8, u8>::new();
+        mapper.map(ControlId::hardware(0), 1);
+        mapper.map(ControlId::gui(0), 1);
+        let mut controller = Recorder(Vec::new());
+        assert_eq!(mapper.write_back(1, 0.25, &mut controller), 1);
+        assert_eq!(controller.0.len(), 2);
+        assert!((controller.0[0].1 - 0.25).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_move_control_jump_by_default() {
+        let mut mapper = ControlMapper::<4>::new();
+        let knob = ControlId::hardware(0);
+        mapper.map(knob, 0);
+        assert_eq!(mapper.pickup_mode(knob), Some(PickupMode::Jump));
+
+        mapper.sync(0, 0.8);
+        let moved: Vec<_> = mapper.move_control(knob, 0.2).collect();
+        assert_eq!(moved, [(0, 0.2)]);
+        assert!(!mapper.awaiting_pickup(knob));
+    }
+
+    #[test]
+    fn test_move_control_pickup_waits_for_crossing() {
+        let mut mapper = ControlMapper::<4>::new();
+        let knob = ControlId::hardware(0);
+        mapper.map(knob, 0);
+        assert!(mapper.set_pickup_mode(knob, PickupMode::Pickup));
+        assert_eq!(mapper.move_control(knob, 0.1).count(), 1);
+
+        // Preset load moves the parameter away from the knob
+        mapper.sync(0, 0.6);
+        assert!(mapper.awaiting_pickup(knob));
+        assert_eq!(mapper.move_control(knob, 0.3).count(), 0);
+        assert_eq!(mapper.move_control(knob, 0.5).count(), 0);
+
+        // Passing 0.6 picks it up
+        let moved: Vec<_> = mapper.move_control(knob, 0.65).collect();
+        assert_eq!(moved, [(0, 0.65)]);
+        assert!(!mapper.awaiting_pickup(knob));
+        assert_eq!(mapper.move_control(knob, 0.4).count(), 1);
+
+        // Syncing to where the knob already is keeps it engaged
+        mapper.sync(0, 0.41);
+        assert!(!mapper.awaiting_pickup(knob));
+    }
+
+    #[test]
+    fn test_move_control_pickup_from_unknown_position() {
+        let mut mapper = ControlMapper::<4>::new();
+        let knob = ControlId::hardware(0);
+        mapper.map(knob, 0);
+        mapper.set_pickup_mode(knob, PickupMode::Pickup);
+        mapper.sync(0, 0.5);
+
+        // First reading far from the parameter: wait
+        assert_eq!(mapper.move_control(knob, 0.9).count(), 0);
+        // First reading near it would have engaged; crossing does too
+        assert_eq!(mapper.move_control(knob, 0.45).count(), 1);
+    }
+
+    #[test]
+    fn test_move_control_relative() {
+        let mut mapper = ControlMapper::<4>::new();
+        let knob = ControlId::hardware(0);
+        mapper.map(knob, 0);
+        mapper.set_pickup_mode(knob, PickupMode::Relative);
+        mapper.sync(0, 0.8);
+
+        // The first reading only locates the knob
+        assert_eq!(mapper.move_control(knob, 0.2).count(), 0);
+        let moved: Vec<_> = mapper.move_control(knob, 0.3).collect();
+        assert_eq!(moved.len(), 1);
+        assert!((moved[0].1 - 0.9).abs() < 1e-6);
+        // Clamped at the end of the range
+        let moved: Vec<_> = mapper.move_control(knob, 0.5).collect();
+        assert_eq!(moved[0].1, 1.0);
+        let moved: Vec<_> = mapper.move_control(knob, 0.4).collect();
+        assert!((moved[0].1 - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pickup_mode_shared_by_new_targets() {
+        let mut mapper = ControlMapper::<4>::new();
+        let knob = ControlId::hardware(0);
+        mapper.map(knob, 0);
+        mapper.set_pickup_mode(knob, PickupMode::Pickup);
+        mapper.add_target(knob, 1, ControlResponse::FULL);
+        mapper.sync(1, 0.9);
+        mapper.sync(0, 0.1);
+
+        let moved: Vec<_> = mapper.move_control(knob, 0.1).collect();
+        assert_eq!(moved, [(0, 0.1)]);
+        assert!(mapper.awaiting_pickup(knob));
+        assert!(!mapper.set_pickup_mode(ControlId::hardware(1), PickupMode::Jump));
+    }
 }
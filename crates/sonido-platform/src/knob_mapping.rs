@@ -38,6 +38,7 @@ pub fn knob_map(effect_id: &str) -> Option<[u8; 6]> {
         "bitcrusher" => Some([0, 1, 2, NULL_KNOB, 3, 4]),
         "ringmod" => Some([0, 1, 2, NULL_KNOB, 3, 4]),
         "looper" => Some([0, 1, 2, 3, 4, 5]),
+        "gate" => Some([0, 1, 2, 3, 4, 7]),
         _ => None,
     }
 }
@@ -78,6 +79,7 @@ mod tests {
             "reverb",
             "ringmod",
             "filter",
+            "gate",
         ];
         for id in &ids {
             assert!(knob_map(id).is_some(), "missing knob_map for {id}");
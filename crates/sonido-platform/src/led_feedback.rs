@@ -0,0 +1,353 @@
+//! Parameter and bypass state shown on output controls.
+//!
+//! [`ControlMapper`](crate::ControlMapper) carries control movement to
+//! parameters; [`LedFeedback`] carries state the other way, to LEDs on a
+//! [`PlatformController`]. Each LED is bound to a target (a parameter, a
+//! slot's bypass, an output meter — whatever address the host uses) and a
+//! [`LedPattern`] that turns the target's latest value into a brightness.
+//! The host reports values with [`update()`](LedFeedback::update) as they
+//! change and calls [`flush()`](LedFeedback::flush) once per control cycle,
+//! which advances blink timers and writes every LED.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_platform::{ControlId, LedFeedback, LedPattern};
+//!
+//! #[derive(Clone, Copy, PartialEq)]
+//! enum Target {
+//!     Bypass(usize),
+//!     DelayTime,
+//!     Clip,
+//! }
+//!
+//! let mut leds = LedFeedback::<4, Target>::new();
+//! leds.bind(ControlId::hardware(4), Target::Bypass(0), LedPattern::Switch);
+//! leds.bind(ControlId::hardware(5), Target::DelayTime, LedPattern::Tempo);
+//! leds.bind(ControlId::hardware(6), Target::Clip, LedPattern::Peak { hold: 0.2 });
+//!
+//! leds.update(Target::Bypass(0), 1.0); // engaged
+//! leds.update(Target::DelayTime, 0.5); // blink every 500 ms
+//! leds.update(Target::Clip, 1.2); // output peak over full scale
+//! assert_eq!(leds.level(ControlId::hardware(6)), Some(1.0));
+//! ```
+
+use crate::{ControlId, PlatformController};
+
+/// Fraction of each tempo period the LED is lit.
+const TEMPO_DUTY: f32 = 0.25;
+
+/// How an LED shows its target's value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LedPattern {
+    /// Lit while the value is at least 0.5 (bypass, toggles).
+    #[default]
+    Switch,
+    /// Brightness follows the value (0.0–1.0).
+    Level,
+    /// Blinks once per period; the value is the period in seconds, and
+    /// periods of zero or less turn the LED off. Changing the period
+    /// restarts the blink, so the LED lands on the new tap.
+    Tempo,
+    /// Lit for `hold` seconds after the value reaches 1.0 (clip indication
+    /// from a linear peak level).
+    Peak {
+        /// Seconds the LED stays lit after the last clip.
+        hold: f32,
+    },
+}
+
+/// A single LED binding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LedEntry<T> {
+    /// The output control.
+    led: ControlId,
+    /// The state it shows.
+    target: T,
+    /// How the state is shown.
+    pattern: LedPattern,
+    /// Latest value reported for the target.
+    value: f32,
+    /// Seconds into the blink period (`Tempo`) or hold time left (`Peak`).
+    timer: f32,
+}
+
+impl<T> LedEntry<T> {
+    /// Brightness for the current value and timer.
+    fn level(&self) -> f32 {
+        let lit = match self.pattern {
+            LedPattern::Switch => self.value >= 0.5,
+            LedPattern::Level => return self.value.clamp(0.0, 1.0),
+            LedPattern::Tempo => self.value > 0.0 && self.timer < self.value * TEMPO_DUTY,
+            LedPattern::Peak { .. } => self.timer > 0.0,
+        };
+        if lit { 1.0 } else { 0.0 }
+    }
+
+    /// Advances blink and hold timers by `elapsed` seconds.
+    fn advance(&mut self, elapsed: f32) {
+        match self.pattern {
+            LedPattern::Tempo if self.value > 0.0 => {
+                self.timer = (self.timer + elapsed) % self.value;
+            }
+            LedPattern::Peak { .. } => self.timer = (self.timer - elapsed).max(0.0),
+            _ => {}
+        }
+    }
+}
+
+/// Drives LEDs from parameter and bypass state.
+///
+/// # Type Parameters
+///
+/// - `N`: Maximum number of bound LEDs (compile-time constant for no_std support)
+/// - `T`: Target address, as in [`ControlMapper`](crate::ControlMapper)
+#[derive(Debug, Clone)]
+pub struct LedFeedback<const N: usize, T = usize> {
+    /// LED bindings.
+    entries: [Option<LedEntry<T>>; N],
+    /// Number of bound LEDs.
+    count: usize,
+}
+
+impl<const N: usize, T: Copy + PartialEq> LedFeedback<N, T> {
+    /// Creates an empty set of bindings.
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            count: 0,
+        }
+    }
+
+    /// Returns the number of bound LEDs.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if no LEDs are bound.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the maximum number of bound LEDs.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Binds an LED to show `target`, replacing any existing binding for it.
+    ///
+    /// The LED starts dark until the target's value is reported. Returns
+    /// `false` if at capacity.
+    pub fn bind(&mut self, led: ControlId, target: T, pattern: LedPattern) -> bool {
+        let entry = LedEntry {
+            led,
+            target,
+            pattern,
+            value: 0.0,
+            timer: 0.0,
+        };
+        if let Some(existing) = self.entries.iter_mut().flatten().find(|e| e.led == led) {
+            *existing = entry;
+            return true;
+        }
+        match self.entries.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(entry);
+                self.count += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes an LED's binding.
+    ///
+    /// Returns `true` if it was bound. The LED keeps its last level.
+    pub fn unbind(&mut self, led: ControlId) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|e| e.led == led))
+        {
+            Some(slot) => {
+                *slot = None;
+                self.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over all bindings as `(led, target, pattern)`.
+    pub fn entries(&self) -> impl Iterator<Item = (ControlId, T, LedPattern)> + '_ {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|e| (e.led, e.target, e.pattern))
+    }
+
+    /// Reports a new value for `target` to every LED bound to it.
+    ///
+    /// For [`LedPattern::Peak`] this is a peak reading: values of 1.0 and
+    /// above restart the hold, lower ones leave it running out.
+    pub fn update(&mut self, target: T, value: f32) {
+        for entry in self.entries.iter_mut().flatten() {
+            if entry.target != target {
+                continue;
+            }
+            match entry.pattern {
+                LedPattern::Tempo if value != entry.value => entry.timer = 0.0,
+                LedPattern::Peak { hold } if value >= 1.0 => entry.timer = hold,
+                _ => {}
+            }
+            entry.value = value;
+        }
+    }
+
+    /// Current brightness of a bound LED.
+    pub fn level(&self, led: ControlId) -> Option<f32> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|e| e.led == led)
+            .map(LedEntry::level)
+    }
+
+    /// Writes every bound LED and flushes the controller.
+    ///
+    /// Call once per control cycle with the seconds since the previous call;
+    /// blink and hold timers advance by that much after the LEDs are
+    /// written, so a one-shot peak is shown for at least one cycle.
+    pub fn flush<C: PlatformController>(&mut self, controller: &mut C, elapsed: f32) {
+        for entry in self.entries.iter_mut().flatten() {
+            controller.write_control(entry.led, entry.level());
+            entry.advance(elapsed);
+        }
+        controller.flush();
+    }
+}
+
+impl<const N: usize, T: Copy + PartialEq> Default for LedFeedback<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ControlState, ControlType};
+
+    /// Four LEDs that remember their last written level.
+    struct Leds([f32; 4]);
+
+    impl PlatformController for Leds {
+        fn control_count(&self) -> usize {
+            4
+        }
+        fn control_id(&self, index: usize) -> Option<ControlId> {
+            (index < 4).then(|| ControlId::hardware(index as u8))
+        }
+        fn control_type(&self, _id: ControlId) -> Option<ControlType> {
+            Some(ControlType::Led)
+        }
+        fn read_control(&self, id: ControlId) -> Option<ControlState> {
+            self.0
+                .get(id.index() as usize)
+                .copied()
+                .map(ControlState::new)
+        }
+        fn write_control(&mut self, id: ControlId, value: f32) -> bool {
+            match self.0.get_mut(id.index() as usize) {
+                Some(level) => {
+                    *level = value;
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    const LED: ControlId = ControlId::hardware(0);
+
+    #[test]
+    fn test_bind_replaces_and_unbinds() {
+        let mut leds = LedFeedback::<2>::new();
+        assert!(leds.bind(LED, 0, LedPattern::Switch));
+        assert!(leds.bind(LED, 1, LedPattern::Level));
+        assert_eq!(leds.len(), 1);
+        assert_eq!(leds.entries().next(), Some((LED, 1, LedPattern::Level)));
+        assert!(leds.bind(ControlId::hardware(1), 1, LedPattern::Switch));
+        assert!(!leds.bind(ControlId::hardware(2), 1, LedPattern::Switch));
+        assert!(leds.unbind(LED));
+        assert!(!leds.unbind(LED));
+        assert_eq!(leds.len(), 1);
+    }
+
+    #[test]
+    fn test_switch_and_level_follow_target() {
+        let mut leds = LedFeedback::<4>::new();
+        let mut board = Leds([0.0; 4]);
+        leds.bind(ControlId::hardware(0), 7, LedPattern::Switch);
+        leds.bind(ControlId::hardware(1), 7, LedPattern::Level);
+        leds.bind(ControlId::hardware(2), 8, LedPattern::Switch);
+
+        leds.update(7, 0.6);
+        leds.flush(&mut board, 0.001);
+        assert_eq!(board.0[..3], [1.0, 0.6, 0.0]);
+
+        leds.update(7, 0.2);
+        leds.flush(&mut board, 0.001);
+        assert_eq!(board.0[..2], [0.0, 0.2]);
+    }
+
+    #[test]
+    fn test_tempo_blinks_and_restarts_on_change() {
+        let mut leds = LedFeedback::<1>::new();
+        let mut board = Leds([0.0; 4]);
+        leds.bind(LED, 0, LedPattern::Tempo);
+        leds.update(0, 0.5); // lit for the first 125 ms of each 500 ms
+
+        let mut lit = [false; 8];
+        for step in &mut lit {
+            leds.flush(&mut board, 0.0625);
+            *step = board.0[0] > 0.5;
+        }
+        assert_eq!(lit, [true, true, false, false, false, false, false, false]);
+        leds.flush(&mut board, 0.0625);
+        assert_eq!(board.0[0], 1.0);
+
+        // Partway through the dark phase, a new tap relights immediately
+        leds.flush(&mut board, 0.25);
+        leds.update(0, 0.25);
+        leds.flush(&mut board, 0.0625);
+        assert_eq!(board.0[0], 1.0);
+
+        leds.update(0, 0.0);
+        leds.flush(&mut board, 0.05);
+        assert_eq!(board.0[0], 0.0);
+    }
+
+    #[test]
+    fn test_peak_holds_after_clip() {
+        let mut leds = LedFeedback::<1>::new();
+        let mut board = Leds([0.0; 4]);
+        leds.bind(LED, 0, LedPattern::Peak { hold: 0.1 });
+
+        leds.update(0, 0.9);
+        leds.flush(&mut board, 0.04);
+        assert_eq!(board.0[0], 0.0);
+
+        leds.update(0, 1.0);
+        for _ in 0..3 {
+            leds.flush(&mut board, 0.04);
+            assert_eq!(board.0[0], 1.0);
+            leds.update(0, 0.5);
+        }
+        leds.flush(&mut board, 0.04);
+        assert_eq!(board.0[0], 0.0);
+    }
+}
@@ -16,6 +16,14 @@
 //!
 //! - [`PlatformController`] - Trait for hardware/software platform implementations
 //! - [`ControlMapper`] - Maps controls to single-effect parameters using [`ParameterInfo`]
+//! - [`PickupMode`] - Jump, pickup, or relative takeover after a preset change
+//! - [`ControlResponse`] - Per-mapping response curve and min/max range
+//! - [`LedFeedback`] - Shows parameter and bypass state on LEDs, with tempo
+//!   blink and clip hold patterns
+//! - [`MidiCcDecoder`] - 14-bit CC pairs and NRPNs to high-resolution
+//!   control values; [`decode_ump`] for MIDI 2.0 control changes
+//! - [`Pedalboard`] - GPIO/I2C controller (encoders, footswitches, LEDs) for
+//!   single-board computers, over a [`PedalboardIo`] pin backend
 //!
 //! # Control ID Namespaces
 //!
@@ -25,6 +33,7 @@
 //! - `0x01XX` - GUI controls (software UI elements)
 //! - `0x02XX` - MIDI controls (CC messages, program changes)
 //! - `0x03XX` - Automation (DAW automation lanes)
+//! - `0x4000`–`0x7FFF` - MIDI NRPNs (14-bit parameter number)
 //!
 //! # no_std Support
 //!
@@ -59,17 +68,25 @@ extern crate alloc;
 
 pub mod control_mapper;
 pub mod knob_mapping;
+pub mod led_feedback;
+pub mod midi_cc;
 pub mod noon;
 pub mod param_map;
+pub mod pedalboard;
+pub mod response_curve;
 
 // Re-export sonido-core types for convenience
 pub use sonido_core::{ParamDescriptor, ParamUnit, ParameterInfo};
 
 // Re-export main types at crate root
-pub use control_mapper::ControlMapper;
+pub use control_mapper::{ControlMapper, PICKUP_TOLERANCE, PickupMode};
 pub use knob_mapping::{NULL_KNOB, knob_map, knob_to_param};
+pub use led_feedback::{LedFeedback, LedPattern};
+pub use midi_cc::{CcResolution, MidiCcDecoder, decode_ump};
 pub use noon::{HARDWARE_MAPPED, noon_value};
 pub use param_map::{adc_to_param, adc_to_param_biased};
+pub use pedalboard::{FootswitchMode, Pedalboard, PedalboardIo};
+pub use response_curve::{Breakpoints, ControlResponse, MAX_BREAKPOINTS, ResponseCurve};
 
 /// Namespace identifiers for control sources.
 pub mod namespace {
@@ -81,6 +98,9 @@ pub mod namespace {
     pub const MIDI: u16 = 0x0200;
     /// Automation controls (DAW automation lanes).
     pub const AUTOMATION: u16 = 0x0300;
+    /// MIDI NRPNs; the low 14 bits are the parameter number, so these IDs
+    /// span `0x4000`–`0x7FFF`.
+    pub const NRPN: u16 = 0x4000;
 }
 
 /// A namespaced control identifier.
@@ -95,6 +115,7 @@ pub mod namespace {
 /// - `0x01XX` - GUI controls
 /// - `0x02XX` - MIDI controls
 /// - `0x03XX` - Automation controls
+/// - `0x4000`–`0x7FFF` - MIDI NRPNs, see [`ControlId::nrpn`]
 ///
 /// # Example
 ///
@@ -150,6 +171,16 @@ impl ControlId {
         Self(namespace::AUTOMATION | index as u16)
     }
 
+    /// Creates a MIDI NRPN control ID (`0x4000` plus the 14-bit number).
+    ///
+    /// NRPN numbers don't fit the 8-bit index, so these IDs take the whole
+    /// `0x4000`–`0x7FFF` block; use [`nrpn_number()`](Self::nrpn_number)
+    /// rather than [`index()`](Self::index). Numbers above 16383 wrap.
+    #[inline]
+    pub const fn nrpn(number: u16) -> Self {
+        Self(namespace::NRPN | (number & 0x3FFF))
+    }
+
     /// Returns the raw 16-bit value.
     #[inline]
     pub const fn raw(&self) -> u16 {
@@ -191,6 +222,22 @@ impl ControlId {
     pub const fn is_automation(&self) -> bool {
         self.namespace() == namespace::AUTOMATION
     }
+
+    /// Returns true if this is a MIDI NRPN control.
+    #[inline]
+    pub const fn is_nrpn(&self) -> bool {
+        self.0 & 0xC000 == namespace::NRPN
+    }
+
+    /// Returns the NRPN number (0–16383) of an NRPN control.
+    #[inline]
+    pub const fn nrpn_number(&self) -> Option<u16> {
+        if self.is_nrpn() {
+            Some(self.0 & 0x3FFF)
+        } else {
+            None
+        }
+    }
 }
 
 /// Physical or virtual control type.
@@ -473,6 +520,17 @@ mod tests {
         assert!(id.is_automation());
     }
 
+    #[test]
+    fn test_control_id_nrpn() {
+        let id = ControlId::nrpn(0x1234);
+        assert_eq!(id.raw(), 0x5234);
+        assert!(id.is_nrpn());
+        assert_eq!(id.nrpn_number(), Some(0x1234));
+        assert!(!id.is_midi() && !id.is_hardware());
+        assert_eq!(ControlId::nrpn(0x3FFF).raw(), 0x7FFF);
+        assert_eq!(ControlId::midi(74).nrpn_number(), None);
+    }
+
     #[test]
     fn test_control_id_from_raw() {
         let id = ControlId::from_raw(0x0205);
@@ -0,0 +1,430 @@
+//! MIDI control change decoding: 14-bit CC pairs, NRPNs, and MIDI 2.0.
+//!
+//! A MIDI 1.0 control change carries a 7-bit value, too coarse for
+//! parameters like delay time. Controllers get finer steps in two ways:
+//! CCs 0–31 pair with CCs 32–63 as MSB and LSB, and NRPNs select one of
+//! 16384 parameters with CCs 99/98, then send 14-bit values through data
+//! entry (CCs 6/38). [`MidiCcDecoder`] tracks this state and turns raw CCs
+//! into normalized values addressed by [`ControlId::midi`] (for a CC at
+//! either resolution) or [`ControlId::nrpn`]. MIDI 2.0 control changes
+//! carry 32-bit values without any pairing; [`decode_ump`] reads them.
+//!
+//! # Resolution
+//!
+//! A paired CC or the NRPN data stream starts out 7-bit
+//! ([`CcResolution::Auto`]) and switches to 14-bit when its first LSB
+//! arrives. Per the MIDI spec, an MSB resets the LSB to 0, so a
+//! controller sending MSB then LSB moves in two steps. Resolution can also
+//! be fixed per CC with [`set_resolution()`](MidiCcDecoder::set_resolution):
+//! a coarse CC leaves its LSB number (32–63) free to be a plain 7-bit CC.
+//!
+//! RPNs are recognized only so their data entry isn't mistaken for a CC;
+//! data increment/decrement (CCs 96/97) is ignored.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_platform::{ControlId, MidiCcDecoder};
+//!
+//! let mut midi = MidiCcDecoder::new();
+//!
+//! // 14-bit CC 1: MSB 64 then LSB 32
+//! midi.decode(1, 64);
+//! let (id, value) = midi.decode(33, 32).unwrap();
+//! assert_eq!(id, ControlId::midi(1));
+//! assert_eq!(value, f32::from(64u16 << 7 | 32) / 16383.0);
+//!
+//! // NRPN 261 (MSB 2, LSB 5) set to 8192 of 16383
+//! midi.decode(99, 2);
+//! midi.decode(98, 5);
+//! midi.decode(6, 64);
+//! let (id, value) = midi.decode(38, 0).unwrap();
+//! assert_eq!(id, ControlId::nrpn(261));
+//! assert!((value - 0.5).abs() < 1e-4);
+//! ```
+
+use crate::ControlId;
+
+/// CCs 0–31 can be 14-bit, with their LSB on the CC this many above.
+const LSB_OFFSET: u8 = 32;
+
+/// Data entry MSB.
+const DATA_ENTRY_MSB: u8 = 6;
+
+/// Data entry LSB.
+const DATA_ENTRY_LSB: u8 = 38;
+
+/// Data increment.
+const DATA_INCREMENT: u8 = 96;
+
+/// Data decrement.
+const DATA_DECREMENT: u8 = 97;
+
+/// NRPN number LSB.
+const NRPN_LSB: u8 = 98;
+
+/// NRPN number MSB.
+const NRPN_MSB: u8 = 99;
+
+/// RPN number LSB.
+const RPN_LSB: u8 = 100;
+
+/// RPN number MSB.
+const RPN_MSB: u8 = 101;
+
+/// RPN number byte that, in both bytes, deselects any parameter.
+const RPN_NULL: u8 = 0x7F;
+
+/// Largest 7-bit value.
+const MAX_7BIT: f32 = 127.0;
+
+/// Largest 14-bit value.
+const MAX_14BIT: f32 = 16383.0;
+
+/// MIDI 2.0 channel voice message type.
+const UMP_CHANNEL_VOICE: u32 = 0x4;
+
+/// MIDI 2.0 control change status.
+const UMP_CONTROL_CHANGE: u32 = 0xB;
+
+/// MIDI 2.0 assignable controller (NRPN) status.
+const UMP_ASSIGNABLE: u32 = 0x3;
+
+/// Value resolution of a CC pair or the NRPN data stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CcResolution {
+    /// 7-bit until an LSB arrives, then 14-bit.
+    #[default]
+    Auto,
+    /// Always 7-bit: LSBs are ignored (or, for CC pairs, passed on as
+    /// their own CC).
+    Coarse,
+    /// Always 14-bit.
+    Fine,
+}
+
+/// Parameter selected for data entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    /// None: data entry CCs are plain CCs.
+    None,
+    /// An NRPN (number in `MidiCcDecoder::nrpn`).
+    Nrpn,
+    /// An RPN; its data is consumed but not reported.
+    Rpn,
+}
+
+/// Decodes MIDI 1.0 control changes into high-resolution control values.
+///
+/// Feed every CC from one channel (or all channels, for a single
+/// controller) through [`decode()`](Self::decode); it returns the control
+/// and normalized value for messages that change one.
+#[derive(Debug, Clone)]
+pub struct MidiCcDecoder {
+    /// Resolution setting per pairable CC (0–31).
+    resolution: [CcResolution; LSB_OFFSET as usize],
+    /// Bit per pairable CC that has sent an LSB.
+    fine_detected: u32,
+    /// Last MSB per pairable CC.
+    msb: [u8; LSB_OFFSET as usize],
+    /// NRPN data stream resolution.
+    nrpn_resolution: CcResolution,
+    /// Whether the NRPN data stream has sent an LSB.
+    nrpn_fine_detected: bool,
+    /// Current data entry target.
+    selection: Selection,
+    /// NRPN number bytes (MSB, LSB).
+    nrpn: [u8; 2],
+    /// RPN number bytes (MSB, LSB).
+    rpn: [u8; 2],
+    /// Last data entry MSB.
+    data_msb: u8,
+}
+
+impl Default for MidiCcDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MidiCcDecoder {
+    /// Creates a decoder with every CC on [`CcResolution::Auto`].
+    pub const fn new() -> Self {
+        Self {
+            resolution: [CcResolution::Auto; LSB_OFFSET as usize],
+            fine_detected: 0,
+            msb: [0; LSB_OFFSET as usize],
+            nrpn_resolution: CcResolution::Auto,
+            nrpn_fine_detected: false,
+            selection: Selection::None,
+            nrpn: [0; 2],
+            rpn: [RPN_NULL; 2],
+            data_msb: 0,
+        }
+    }
+
+    /// Sets the resolution of CC `cc` (0–31, paired with `cc + 32`).
+    ///
+    /// Returns `false` for CCs that can't be paired.
+    pub fn set_resolution(&mut self, cc: u8, resolution: CcResolution) -> bool {
+        let Some(slot) = self.resolution.get_mut(usize::from(cc)) else {
+            return false;
+        };
+        *slot = resolution;
+        true
+    }
+
+    /// Resolution setting of CC `cc`, if it can be paired.
+    pub fn resolution(&self, cc: u8) -> Option<CcResolution> {
+        self.resolution.get(usize::from(cc)).copied()
+    }
+
+    /// Sets the resolution of NRPN data entry.
+    pub fn set_nrpn_resolution(&mut self, resolution: CcResolution) {
+        self.nrpn_resolution = resolution;
+    }
+
+    /// True if CC `cc` currently decodes as 14-bit.
+    pub fn is_fine(&self, cc: u8) -> bool {
+        match self.resolution(cc) {
+            Some(CcResolution::Fine) => true,
+            Some(CcResolution::Auto) => self.fine_detected & (1 << cc) != 0,
+            _ => false,
+        }
+    }
+
+    /// Forgets detected resolutions, NRPN selection, and stored MSBs.
+    /// Resolution settings are kept.
+    pub fn reset(&mut self) {
+        *self = Self {
+            resolution: self.resolution,
+            nrpn_resolution: self.nrpn_resolution,
+            ..Self::new()
+        };
+    }
+
+    /// Decodes one control change (controller number and value, both
+    /// 7-bit; higher bits are ignored).
+    ///
+    /// Returns the control and its normalized value, or `None` for
+    /// messages that only select a parameter or belong to an RPN.
+    pub fn decode(&mut self, cc: u8, value: u8) -> Option<(ControlId, f32)> {
+        let (cc, value) = (cc & 0x7F, value & 0x7F);
+        match cc {
+            NRPN_MSB | NRPN_LSB => {
+                self.nrpn[usize::from(cc == NRPN_LSB)] = value;
+                self.selection = Selection::Nrpn;
+                None
+            }
+            RPN_MSB | RPN_LSB => {
+                self.rpn[usize::from(cc == RPN_LSB)] = value;
+                self.selection = if self.rpn == [RPN_NULL; 2] {
+                    Selection::None
+                } else {
+                    Selection::Rpn
+                };
+                None
+            }
+            DATA_ENTRY_MSB | DATA_ENTRY_LSB | DATA_INCREMENT | DATA_DECREMENT
+                if self.selection != Selection::None =>
+            {
+                self.data_entry(cc, value)
+            }
+            0..LSB_OFFSET => {
+                self.msb[usize::from(cc)] = value;
+                let value = if self.is_fine(cc) {
+                    fine(value, 0)
+                } else {
+                    coarse(value)
+                };
+                Some((ControlId::midi(cc), value))
+            }
+            _ if cc < 2 * LSB_OFFSET => {
+                let msb_cc = cc - LSB_OFFSET;
+                if self.resolution[usize::from(msb_cc)] == CcResolution::Coarse {
+                    return Some((ControlId::midi(cc), coarse(value)));
+                }
+                self.fine_detected |= 1 << msb_cc;
+                let msb = self.msb[usize::from(msb_cc)];
+                Some((ControlId::midi(msb_cc), fine(msb, value)))
+            }
+            _ => Some((ControlId::midi(cc), coarse(value))),
+        }
+    }
+
+    /// Data entry for the selected NRPN or RPN.
+    fn data_entry(&mut self, cc: u8, value: u8) -> Option<(ControlId, f32)> {
+        if self.selection == Selection::Rpn {
+            return None;
+        }
+        let id = ControlId::nrpn(u16::from(self.nrpn[0]) << 7 | u16::from(self.nrpn[1]));
+        match (cc, self.nrpn_resolution) {
+            (DATA_ENTRY_MSB, resolution) => {
+                self.data_msb = value;
+                let fine_stream = resolution == CcResolution::Fine
+                    || (resolution == CcResolution::Auto && self.nrpn_fine_detected);
+                let value = if fine_stream {
+                    fine(value, 0)
+                } else {
+                    coarse(value)
+                };
+                Some((id, value))
+            }
+            (DATA_ENTRY_LSB, CcResolution::Auto | CcResolution::Fine) => {
+                self.nrpn_fine_detected = true;
+                Some((id, fine(self.data_msb, value)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Normalized 7-bit value.
+fn coarse(value: u8) -> f32 {
+    f32::from(value) / MAX_7BIT
+}
+
+/// Normalized 14-bit value.
+fn fine(msb: u8, lsb: u8) -> f32 {
+    f32::from(u16::from(msb) << 7 | u16::from(lsb)) / MAX_14BIT
+}
+
+/// Decodes a MIDI 2.0 control change or assignable controller (NRPN)
+/// from a 64-bit Universal MIDI Packet.
+///
+/// These carry 32-bit values and need no pairing state. Returns the
+/// control ([`ControlId::midi`] or [`ControlId::nrpn`]) and normalized
+/// value, or `None` for other messages.
+pub fn decode_ump(packet: [u32; 2]) -> Option<(ControlId, f32)> {
+    let [header, data] = packet;
+    if header >> 28 != UMP_CHANNEL_VOICE {
+        return None;
+    }
+    let index = ((header >> 8) & 0x7F) as u8;
+    let id = match (header >> 20) & 0xF {
+        UMP_CONTROL_CHANGE => ControlId::midi(index),
+        UMP_ASSIGNABLE => ControlId::nrpn(u16::from(index) << 7 | (header & 0x7F) as u16),
+        _ => return None,
+    };
+    Some((id, (f64::from(data) / f64::from(u32::MAX)) as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_cc_is_7bit() {
+        let mut midi = MidiCcDecoder::new();
+        assert_eq!(midi.decode(74, 127), Some((ControlId::midi(74), 1.0)));
+        assert_eq!(midi.decode(1, 0), Some((ControlId::midi(1), 0.0)));
+        assert!(!midi.is_fine(1));
+    }
+
+    #[test]
+    fn test_cc_pair_detected_as_14bit() {
+        let mut midi = MidiCcDecoder::new();
+        assert_eq!(midi.decode(7, 64), Some((ControlId::midi(7), coarse(64))));
+        // The LSB switches CC 7 to 14-bit and reports on CC 7
+        assert_eq!(
+            midi.decode(39, 127),
+            Some((ControlId::midi(7), fine(64, 127)))
+        );
+        assert!(midi.is_fine(7));
+        // An MSB now resets the LSB
+        assert_eq!(midi.decode(7, 10), Some((ControlId::midi(7), fine(10, 0))));
+        assert_eq!(midi.decode(39, 1), Some((ControlId::midi(7), fine(10, 1))));
+        assert_eq!(midi.decode(71, 64), Some((ControlId::midi(71), coarse(64))));
+
+        midi.reset();
+        assert!(!midi.is_fine(7));
+    }
+
+    #[test]
+    fn test_cc_resolution_settings() {
+        let mut midi = MidiCcDecoder::new();
+        assert!(midi.set_resolution(0, CcResolution::Coarse));
+        assert!(midi.set_resolution(1, CcResolution::Fine));
+        assert!(!midi.set_resolution(32, CcResolution::Fine));
+        assert_eq!(midi.resolution(1), Some(CcResolution::Fine));
+
+        // Coarse: CC 32 is its own control
+        assert_eq!(midi.decode(32, 5), Some((ControlId::midi(32), coarse(5))));
+        assert!(!midi.is_fine(0));
+        // Fine: 14-bit from the first MSB
+        assert_eq!(
+            midi.decode(1, 127),
+            Some((ControlId::midi(1), fine(127, 0)))
+        );
+    }
+
+    #[test]
+    fn test_nrpn_data_entry() {
+        let mut midi = MidiCcDecoder::new();
+        assert_eq!(midi.decode(NRPN_MSB, 1), None);
+        assert_eq!(midi.decode(NRPN_LSB, 2), None);
+        let id = ControlId::nrpn(1 << 7 | 2);
+        assert_eq!(midi.decode(DATA_ENTRY_MSB, 100), Some((id, coarse(100))));
+        assert_eq!(midi.decode(DATA_ENTRY_LSB, 3), Some((id, fine(100, 3))));
+        // Now 14-bit from the MSB on
+        assert_eq!(midi.decode(DATA_ENTRY_MSB, 20), Some((id, fine(20, 0))));
+        assert_eq!(midi.decode(DATA_INCREMENT, 0), None);
+
+        // Another NRPN keeps the stream's resolution
+        midi.decode(NRPN_LSB, 3);
+        assert_eq!(
+            midi.decode(DATA_ENTRY_MSB, 127),
+            Some((ControlId::nrpn(1 << 7 | 3), fine(127, 0)))
+        );
+    }
+
+    #[test]
+    fn test_rpn_consumes_data_entry() {
+        let mut midi = MidiCcDecoder::new();
+        // Without a selection, CC 6 is an ordinary CC
+        assert_eq!(
+            midi.decode(DATA_ENTRY_MSB, 2),
+            Some((ControlId::midi(6), coarse(2)))
+        );
+
+        midi.decode(RPN_MSB, 0);
+        midi.decode(RPN_LSB, 0); // pitch bend range
+        assert_eq!(midi.decode(DATA_ENTRY_MSB, 12), None);
+        assert_eq!(midi.decode(DATA_ENTRY_LSB, 0), None);
+
+        // RPN null deselects
+        midi.decode(RPN_MSB, RPN_NULL);
+        midi.decode(RPN_LSB, RPN_NULL);
+        assert_eq!(
+            midi.decode(DATA_ENTRY_MSB, 2),
+            Some((ControlId::midi(6), coarse(2)))
+        );
+    }
+
+    #[test]
+    fn test_nrpn_coarse_ignores_lsb() {
+        let mut midi = MidiCcDecoder::new();
+        midi.set_nrpn_resolution(CcResolution::Coarse);
+        midi.decode(NRPN_MSB, 0);
+        midi.decode(NRPN_LSB, 9);
+        assert_eq!(
+            midi.decode(DATA_ENTRY_MSB, 127),
+            Some((ControlId::nrpn(9), 1.0))
+        );
+        assert_eq!(midi.decode(DATA_ENTRY_LSB, 5), None);
+    }
+
+    #[test]
+    fn test_decode_ump() {
+        // Group 0, channel 2, CC 74, full scale
+        let cc = [0x40B2_4A00, u32::MAX];
+        assert_eq!(decode_ump(cc), Some((ControlId::midi(74), 1.0)));
+        // Assignable controller bank 1, index 5, half scale
+        let (id, value) = decode_ump([0x4030_0105, 0x8000_0000]).unwrap();
+        assert_eq!(id, ControlId::nrpn(1 << 7 | 5));
+        assert!((value - 0.5).abs() < 1e-6);
+        // Note on, and a MIDI 1.0 packet
+        assert_eq!(decode_ump([0x4090_3C00, 0xFFFF_0000]), None);
+        assert_eq!(decode_ump([0x20B0_4A7F, 0]), None);
+    }
+}
@@ -53,23 +53,30 @@ pub const HARDWARE_MAPPED: &[&str] = &[
 pub fn noon_value(effect_id: &str, param_idx: usize) -> Option<f32> {
     let values: &[f32] = match effect_id {
         "bitcrusher" => &[8.0, 1.0, 0.0, 100.0, 0.0],
-        "chorus" => &[1.0, 50.0, 50.0, 2.0, 0.0, 15.0, 0.0, 3.0, 0.0, 0.0],
+        "chorus" => &[1.0, 50.0, 50.0, 2.0, 0.0, 15.0, 0.0, 3.0, 0.0, 0.0, 50.0],
         "compressor" => &[
-            -18.0, 4.0, 10.0, 100.0, 0.0, 6.0, 0.0, 80.0, 0.0, 0.0, 50.0, 0.0,
+            -18.0, 4.0, 10.0, 100.0, 0.0, 6.0, 0.0, 80.0, 0.0, 0.0, 50.0, 0.0, 0.0, 0.0, 0.0,
+        ],
+        "delay" => &[
+            300.0, 40.0, 50.0, 0.0, 20000.0, 20.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0,
         ],
-        "delay" => &[300.0, 40.0, 50.0, 0.0, 20000.0, 20.0, 0.0, 0.0, 2.0, 0.0],
         "distortion" => &[15.0, 0.0, 0.0, 0.0, 100.0, 0.0],
         "eq" => &[100.0, 0.0, 1.0, 1000.0, 0.0, 1.0, 5000.0, 0.0, 1.0, 0.0],
         "filter" => &[1000.0, 2.5, 0.0, 0.0],
-        "flanger" => &[0.5, 50.0, 50.0, 50.0, 0.0, 0.0, 3.0, 0.0, 0.0],
-        "gate" => &[-40.0, 5.0, 100.0, 50.0, -80.0, 3.0, 80.0, 0.0, 0.0],
+        "flanger" => &[0.5, 50.0, 50.0, 50.0, 0.0, 0.0, 3.0, 0.0, 0.0, 50.0, 0.0],
+        "gate" => &[
+            -40.0, 5.0, 100.0, 50.0, -80.0, 3.0, 80.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -40.0,
+            50.0, 100.0,
+        ],
         "limiter" => &[-6.0, -0.3, 100.0, 5.0, 0.0],
         "looper" => &[0.0, 80.0, 0.0, 0.0, 50.0, 0.0],
         "phaser" => &[
             0.3, 50.0, 6.0, 50.0, 50.0, 200.0, 4000.0, 0.0, 3.0, 0.0, 0.0,
         ],
         "preamp" => &[15.0, 0.0, 0.0],
-        "reverb" => &[50.0, 50.0, 50.0, 10.0, 50.0, 100.0, 50.0, 0.0],
+        "reverb" => &[
+            50.0, 50.0, 50.0, 10.0, 50.0, 100.0, 50.0, 0.0, 0.0, 1.0, 1.0,
+        ],
         "ringmod" => &[440.0, 100.0, 0.0, 50.0, 0.0],
         "stage" => &[
             0.0, 100.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 120.0, 0.0, 0.0, 0.0,
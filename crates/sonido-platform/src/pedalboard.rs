@@ -0,0 +1,577 @@
+//! GPIO/I2C pedalboard controller for single-board computers.
+//!
+//! [`Pedalboard`] turns raw pin levels into platform controls so sonido can
+//! run as a physical pedal on a Raspberry Pi or similar board: rotary
+//! encoders (quadrature, decoded in software), potentiometers on an I2C ADC,
+//! debounced footswitches, and LEDs. Pin access goes through
+//! [`PedalboardIo`], which can be implemented over rppal, embedded-hal, or a
+//! test double; the controller itself has no hardware dependencies.
+//!
+//! # Control IDs
+//!
+//! Controls are hardware IDs numbered in the order they are added, so a
+//! mapping file can refer to them by index:
+//!
+//! | Kind       | [`ControlType`] | Value                                        |
+//! |------------|-----------------|----------------------------------------------|
+//! | Encoder    | `Knob`          | Position, moved by `step` per detent         |
+//! | Knob       | `Knob`          | ADC reading                                  |
+//! | Footswitch | `Footswitch`    | Toggles per press, or follows the switch     |
+//! | LED        | `Led`           | Written by the host; lit above 0.5           |
+//!
+//! # Polling
+//!
+//! Encoders are decoded from successive [`poll()`](PlatformController::poll)
+//! calls, so poll at least every millisecond to keep up with a fast turn.
+//! Footswitches change state after [`DEBOUNCE_POLLS`] identical reads.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_platform::pedalboard::{FootswitchMode, Pedalboard, PedalboardIo};
+//! use sonido_platform::PlatformController;
+//!
+//! struct Pins([bool; 32]);
+//!
+//! impl PedalboardIo for Pins {
+//!     fn read_pin(&mut self, pin: u8) -> bool {
+//!         self.0[pin as usize]
+//!     }
+//!     fn write_pin(&mut self, pin: u8, high: bool) {
+//!         self.0[pin as usize] = high;
+//!     }
+//!     fn read_adc(&mut self, _channel: u8) -> Option<f32> {
+//!         None
+//!     }
+//! }
+//!
+//! let mut board = Pedalboard::new(Pins([false; 32]));
+//! let encoder = board.add_encoder(17, 27, 0.02).unwrap();
+//! let switch = board.add_footswitch(22, FootswitchMode::Toggle).unwrap();
+//! let led = board.add_led(23).unwrap();
+//! assert_eq!((encoder.index(), switch.index(), led.index()), (0, 1, 2));
+//!
+//! board.write_control(led, 1.0);
+//! board.flush();
+//! assert!(board.io().0[23]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{ControlId, ControlState, ControlType, PlatformController};
+
+/// Consecutive identical reads before a footswitch changes state.
+pub const DEBOUNCE_POLLS: u8 = 5;
+
+/// Maximum number of controls (hardware control IDs are 8-bit).
+pub const MAX_CONTROLS: usize = 256;
+
+/// Knob movement ignored as ADC noise (normalized).
+const KNOB_HYSTERESIS: f32 = 0.004;
+
+/// Quadrature transitions per encoder detent.
+const TRANSITIONS_PER_DETENT: i8 = 4;
+
+/// Direction of a quadrature transition, indexed by `previous << 2 | current`
+/// where each state is `a << 1 | b`. Invalid (skipped) transitions count as 0.
+const QUADRATURE: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
+
+/// Pin access for a [`Pedalboard`].
+///
+/// Input polarity (pull-ups, active-low switches) is the implementation's
+/// concern: [`read_pin`](Self::read_pin) reports whether the contact is
+/// closed.
+pub trait PedalboardIo {
+    /// Whether the input on `pin` is active (switch closed).
+    fn read_pin(&mut self, pin: u8) -> bool;
+
+    /// Drives output `pin` high or low.
+    fn write_pin(&mut self, pin: u8, high: bool);
+
+    /// Reads ADC `channel`, normalized to 0.0–1.0.
+    ///
+    /// Returns `None` if no reading is available; the knob keeps its value.
+    fn read_adc(&mut self, channel: u8) -> Option<f32>;
+}
+
+/// How a footswitch drives its control value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FootswitchMode {
+    /// Each press flips the value between 0.0 and 1.0 (soft latching).
+    #[default]
+    Toggle,
+    /// The value is 1.0 while the switch is held.
+    Momentary,
+}
+
+/// Software quadrature decoder for a detented rotary encoder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QuadratureDecoder {
+    state: u8,
+    transitions: i8,
+}
+
+impl QuadratureDecoder {
+    /// Creates a decoder starting from pin levels `a` and `b`.
+    pub const fn new(a: bool, b: bool) -> Self {
+        Self {
+            state: (a as u8) << 1 | b as u8,
+            transitions: 0,
+        }
+    }
+
+    /// Feeds the current pin levels; returns +1 or -1 when a detent is
+    /// completed (clockwise is positive, A leading B), else 0.
+    pub fn update(&mut self, a: bool, b: bool) -> i8 {
+        let state = (a as u8) << 1 | b as u8;
+        self.transitions += QUADRATURE[usize::from(self.state << 2 | state)];
+        self.state = state;
+        if self.transitions >= TRANSITIONS_PER_DETENT {
+            self.transitions = 0;
+            1
+        } else if self.transitions <= -TRANSITIONS_PER_DETENT {
+            self.transitions = 0;
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+/// Counter-based switch debouncer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Debouncer {
+    stable: bool,
+    count: u8,
+}
+
+impl Debouncer {
+    /// Creates a debouncer in the given state.
+    pub const fn new(pressed: bool) -> Self {
+        Self {
+            stable: pressed,
+            count: 0,
+        }
+    }
+
+    /// Feeds a raw read; returns the new state when it changes.
+    pub fn update(&mut self, pressed: bool) -> Option<bool> {
+        if pressed == self.stable {
+            self.count = 0;
+            return None;
+        }
+        self.count += 1;
+        if self.count < DEBOUNCE_POLLS {
+            return None;
+        }
+        self.count = 0;
+        self.stable = pressed;
+        Some(pressed)
+    }
+
+    /// Current debounced state.
+    pub const fn pressed(&self) -> bool {
+        self.stable
+    }
+}
+
+/// Hardware behind one control.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Encoder {
+        a: u8,
+        b: u8,
+        step: f32,
+        decoder: QuadratureDecoder,
+    },
+    Knob {
+        channel: u8,
+    },
+    Footswitch {
+        pin: u8,
+        mode: FootswitchMode,
+        debouncer: Debouncer,
+    },
+    Led {
+        pin: u8,
+        lit: Option<bool>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Control {
+    kind: Kind,
+    state: ControlState,
+}
+
+/// GPIO/I2C pedalboard implementing [`PlatformController`].
+///
+/// Add controls with the `add_*` methods, then call
+/// [`poll()`](PlatformController::poll) every cycle to read inputs and
+/// [`flush()`](PlatformController::flush) to drive the LEDs.
+///
+/// Encoders and toggle footswitches also accept
+/// [`write_control()`](PlatformController::write_control), which sets their
+/// position without marking it changed, so the host can start them at the
+/// current parameter value.
+#[derive(Debug)]
+pub struct Pedalboard<IO> {
+    io: IO,
+    controls: Vec<Control>,
+}
+
+impl<IO: PedalboardIo> Pedalboard<IO> {
+    /// Creates a pedalboard with no controls.
+    pub fn new(io: IO) -> Self {
+        Self {
+            io,
+            controls: Vec::new(),
+        }
+    }
+
+    /// The pin access backend.
+    pub fn io(&self) -> &IO {
+        &self.io
+    }
+
+    /// Mutable pin access backend.
+    pub fn io_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// Adds a rotary encoder on pins `a` and `b`, starting at 0.0 and moving
+    /// `step` (normalized) per detent.
+    ///
+    /// Returns `None` if the board already has [`MAX_CONTROLS`] controls.
+    pub fn add_encoder(&mut self, a: u8, b: u8, step: f32) -> Option<ControlId> {
+        let decoder = QuadratureDecoder::new(self.io.read_pin(a), self.io.read_pin(b));
+        self.push(Kind::Encoder {
+            a,
+            b,
+            step,
+            decoder,
+        })
+    }
+
+    /// Adds a potentiometer on ADC `channel`.
+    ///
+    /// Returns `None` if the board already has [`MAX_CONTROLS`] controls.
+    pub fn add_knob(&mut self, channel: u8) -> Option<ControlId> {
+        let id = self.push(Kind::Knob { channel })?;
+        if let Some(value) = self.io.read_adc(channel) {
+            self.controls[usize::from(id.index())].state = ControlState::new(value);
+        }
+        Some(id)
+    }
+
+    /// Adds a footswitch on `pin`.
+    ///
+    /// Returns `None` if the board already has [`MAX_CONTROLS`] controls.
+    pub fn add_footswitch(&mut self, pin: u8, mode: FootswitchMode) -> Option<ControlId> {
+        let debouncer = Debouncer::new(self.io.read_pin(pin));
+        self.push(Kind::Footswitch {
+            pin,
+            mode,
+            debouncer,
+        })
+    }
+
+    /// Adds an LED on output `pin`, initially off.
+    ///
+    /// Returns `None` if the board already has [`MAX_CONTROLS`] controls.
+    pub fn add_led(&mut self, pin: u8) -> Option<ControlId> {
+        self.push(Kind::Led { pin, lit: None })
+    }
+
+    fn push(&mut self, kind: Kind) -> Option<ControlId> {
+        if self.controls.len() >= MAX_CONTROLS {
+            return None;
+        }
+        let id = ControlId::hardware(self.controls.len() as u8);
+        self.controls.push(Control {
+            kind,
+            state: ControlState::default(),
+        });
+        Some(id)
+    }
+
+    fn control(&self, id: ControlId) -> Option<&Control> {
+        if !id.is_hardware() {
+            return None;
+        }
+        self.controls.get(usize::from(id.index()))
+    }
+}
+
+impl<IO: PedalboardIo> PlatformController for Pedalboard<IO> {
+    fn control_count(&self) -> usize {
+        self.controls.len()
+    }
+
+    fn control_id(&self, index: usize) -> Option<ControlId> {
+        (index < self.controls.len()).then(|| ControlId::hardware(index as u8))
+    }
+
+    fn control_type(&self, id: ControlId) -> Option<ControlType> {
+        Some(match self.control(id)?.kind {
+            Kind::Encoder { .. } | Kind::Knob { .. } => ControlType::Knob,
+            Kind::Footswitch { .. } => ControlType::Footswitch,
+            Kind::Led { .. } => ControlType::Led,
+        })
+    }
+
+    fn read_control(&self, id: ControlId) -> Option<ControlState> {
+        self.control(id).map(|control| control.state)
+    }
+
+    fn write_control(&mut self, id: ControlId, value: f32) -> bool {
+        if self.control(id).is_none() {
+            return false;
+        }
+        let control = &mut self.controls[usize::from(id.index())];
+        let value = value.clamp(0.0, 1.0);
+        match control.kind {
+            Kind::Led { .. }
+            | Kind::Encoder { .. }
+            | Kind::Footswitch {
+                mode: FootswitchMode::Toggle,
+                ..
+            } => {
+                control.state.value = value;
+                true
+            }
+            Kind::Knob { .. }
+            | Kind::Footswitch {
+                mode: FootswitchMode::Momentary,
+                ..
+            } => false,
+        }
+    }
+
+    fn poll(&mut self) {
+        let io = &mut self.io;
+        for control in &mut self.controls {
+            control.state.clear_changed();
+            match &mut control.kind {
+                Kind::Encoder {
+                    a,
+                    b,
+                    step,
+                    decoder,
+                } => {
+                    let detent = decoder.update(io.read_pin(*a), io.read_pin(*b));
+                    if detent != 0 {
+                        let value = control.state.value + f32::from(detent) * *step;
+                        control.state.set(value.clamp(0.0, 1.0));
+                    }
+                }
+                Kind::Knob { channel } => {
+                    if let Some(value) = io.read_adc(*channel)
+                        && (value - control.state.value).abs() > KNOB_HYSTERESIS
+                    {
+                        control.state.set(value.clamp(0.0, 1.0));
+                    }
+                }
+                Kind::Footswitch {
+                    pin,
+                    mode,
+                    debouncer,
+                } => match (debouncer.update(io.read_pin(*pin)), *mode) {
+                    (Some(true), FootswitchMode::Toggle) => {
+                        let value = if control.state.value < 0.5 { 1.0 } else { 0.0 };
+                        control.state.set(value);
+                    }
+                    (Some(pressed), FootswitchMode::Momentary) => {
+                        control.state.set(if pressed { 1.0 } else { 0.0 });
+                    }
+                    _ => {}
+                },
+                Kind::Led { .. } => {}
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        for control in &mut self.controls {
+            if let Kind::Led { pin, lit } = &mut control.kind {
+                let on = control.state.value > 0.5;
+                if *lit != Some(on) {
+                    self.io.write_pin(*pin, on);
+                    *lit = Some(on);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MockIo {
+        pins: [bool; 32],
+        adc: [Option<f32>; 4],
+        writes: Vec<(u8, bool)>,
+    }
+
+    impl PedalboardIo for MockIo {
+        fn read_pin(&mut self, pin: u8) -> bool {
+            self.pins[usize::from(pin)]
+        }
+
+        fn write_pin(&mut self, pin: u8, high: bool) {
+            self.pins[usize::from(pin)] = high;
+            self.writes.push((pin, high));
+        }
+
+        fn read_adc(&mut self, channel: u8) -> Option<f32> {
+            self.adc[usize::from(channel)]
+        }
+    }
+
+    /// Drive the encoder on pins 0/1 through one detent of (a, b) states.
+    fn turn(board: &mut Pedalboard<MockIo>, states: [(bool, bool); 4]) {
+        for (a, b) in states {
+            board.io_mut().pins[0] = a;
+            board.io_mut().pins[1] = b;
+            board.poll();
+        }
+    }
+
+    const CLOCKWISE: [(bool, bool); 4] =
+        [(true, false), (true, true), (false, true), (false, false)];
+    const COUNTER: [(bool, bool); 4] = [(false, true), (true, true), (true, false), (false, false)];
+
+    #[test]
+    fn test_control_ids_and_types() {
+        let mut board = Pedalboard::new(MockIo::default());
+        let encoder = board.add_encoder(0, 1, 0.1).unwrap();
+        let knob = board.add_knob(0).unwrap();
+        let switch = board.add_footswitch(2, FootswitchMode::Toggle).unwrap();
+        let led = board.add_led(3).unwrap();
+
+        assert_eq!(board.control_count(), 4);
+        assert_eq!(board.control_id(2), Some(switch));
+        assert_eq!(board.control_id(4), None);
+        assert_eq!(board.control_type(encoder), Some(ControlType::Knob));
+        assert_eq!(board.control_type(knob), Some(ControlType::Knob));
+        assert_eq!(board.control_type(switch), Some(ControlType::Footswitch));
+        assert_eq!(board.control_type(led), Some(ControlType::Led));
+        assert_eq!(board.control_type(ControlId::midi(0)), None);
+    }
+
+    #[test]
+    fn test_encoder_steps_per_detent() {
+        let mut board = Pedalboard::new(MockIo::default());
+        let encoder = board.add_encoder(0, 1, 0.1).unwrap();
+        board.write_control(encoder, 0.5);
+
+        turn(&mut board, CLOCKWISE);
+        let state = board.read_control(encoder).unwrap();
+        assert!((state.value - 0.6).abs() < 1e-6);
+        assert!(state.changed);
+
+        board.poll();
+        assert!(!board.read_control(encoder).unwrap().changed);
+
+        turn(&mut board, COUNTER);
+        turn(&mut board, COUNTER);
+        assert!((board.read_control(encoder).unwrap().value - 0.4).abs() < 1e-6);
+
+        // Half a detent does nothing
+        board.io_mut().pins[0] = true;
+        board.poll();
+        assert!(!board.read_control(encoder).unwrap().changed);
+    }
+
+    #[test]
+    fn test_encoder_clamps() {
+        let mut board = Pedalboard::new(MockIo::default());
+        let encoder = board.add_encoder(0, 1, 0.3).unwrap();
+        for _ in 0..3 {
+            turn(&mut board, COUNTER);
+        }
+        assert_eq!(board.read_control(encoder).unwrap().value, 0.0);
+        for _ in 0..5 {
+            turn(&mut board, CLOCKWISE);
+        }
+        assert_eq!(board.read_control(encoder).unwrap().value, 1.0);
+    }
+
+    #[test]
+    fn test_footswitch_debounce_and_modes() {
+        let mut board = Pedalboard::new(MockIo::default());
+        let toggle = board.add_footswitch(2, FootswitchMode::Toggle).unwrap();
+        let momentary = board.add_footswitch(3, FootswitchMode::Momentary).unwrap();
+
+        // Contact bounce shorter than the debounce window is ignored
+        board.io_mut().pins[2] = true;
+        board.poll();
+        board.io_mut().pins[2] = false;
+        board.poll();
+        assert_eq!(board.read_control(toggle).unwrap().value, 0.0);
+
+        let press = |board: &mut Pedalboard<MockIo>, pin: usize, level: bool| {
+            board.io_mut().pins[pin] = level;
+            for _ in 0..DEBOUNCE_POLLS {
+                board.poll();
+            }
+        };
+        press(&mut board, 2, true);
+        assert_eq!(
+            board.read_control(toggle).unwrap(),
+            ControlState::changed(1.0)
+        );
+        press(&mut board, 2, false);
+        assert_eq!(board.read_control(toggle).unwrap().value, 1.0);
+        press(&mut board, 2, true);
+        assert_eq!(board.read_control(toggle).unwrap().value, 0.0);
+
+        press(&mut board, 3, true);
+        assert_eq!(board.read_control(momentary).unwrap().value, 1.0);
+        press(&mut board, 3, false);
+        assert_eq!(board.read_control(momentary).unwrap().value, 0.0);
+        assert!(!board.write_control(momentary, 1.0));
+    }
+
+    #[test]
+    fn test_knob_hysteresis() {
+        let mut io = MockIo::default();
+        io.adc[1] = Some(0.5);
+        let mut board = Pedalboard::new(io);
+        let knob = board.add_knob(1).unwrap();
+        assert_eq!(board.read_control(knob).unwrap(), ControlState::new(0.5));
+
+        board.io_mut().adc[1] = Some(0.501);
+        board.poll();
+        assert_eq!(board.read_control(knob).unwrap(), ControlState::new(0.5));
+
+        board.io_mut().adc[1] = Some(0.7);
+        board.poll();
+        assert_eq!(
+            board.read_control(knob).unwrap(),
+            ControlState::changed(0.7)
+        );
+
+        // A failed read keeps the last value
+        board.io_mut().adc[1] = None;
+        board.poll();
+        assert_eq!(board.read_control(knob).unwrap(), ControlState::new(0.7));
+    }
+
+    #[test]
+    fn test_led_flush_writes_on_change() {
+        let mut board = Pedalboard::new(MockIo::default());
+        let led = board.add_led(5).unwrap();
+
+        board.flush();
+        assert_eq!(board.io().writes, [(5, false)]);
+
+        board.write_control(led, 1.0);
+        board.flush();
+        board.flush();
+        assert_eq!(board.io().writes, [(5, false), (5, true)]);
+        assert!(board.io().pins[5]);
+    }
+}
@@ -0,0 +1,328 @@
+//! Response curves for shaping control input before parameter dispatch.
+//!
+//! A [`ControlResponse`] turns a normalized control value (0.0–1.0) into a
+//! normalized parameter position: the [`ResponseCurve`] shapes the travel,
+//! then the result is scaled into the mapping's `min..max` window. Setting
+//! `min > max` reverses the control, and giving several parameters different
+//! windows lets one expression pedal morph between two settings. `invert`
+//! flips the travel before the curve, so an inverted log taper is still slow
+//! at the start of its (reversed) travel.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_platform::{Breakpoints, ControlResponse, ResponseCurve};
+//!
+//! // Heel = 20 %, toe = 80 % of the parameter range, audio taper
+//! let response = ControlResponse::new(ResponseCurve::Log, 0.2, 0.8);
+//! assert_eq!(response.apply(0.0), 0.2);
+//! assert!((response.apply(1.0) - 0.8).abs() < 1e-6);
+//!
+//! // Custom curve: flat dead zone for the first quarter of travel
+//! let points = Breakpoints::from_points(&[(0.0, 0.0), (0.25, 0.0), (1.0, 1.0)]).unwrap();
+//! let curve = ResponseCurve::Custom(points);
+//! assert_eq!(curve.apply(0.2), 0.0);
+//! ```
+
+/// Maximum number of points in a custom curve.
+pub const MAX_BREAKPOINTS: usize = 8;
+
+/// Range of the [`ResponseCurve::Log`] taper in decades (40 dB).
+const LOG_DECADES: f32 = 2.0;
+
+/// Bisection steps in [`ControlResponse::position_for`] (error below 1e-6).
+const INVERSE_ITERATIONS: usize = 20;
+
+/// Piecewise-linear curve through up to [`MAX_BREAKPOINTS`] points.
+///
+/// Points are `(input, output)` pairs in 0.0–1.0, kept sorted by input.
+/// Inputs before the first point or after the last take that point's
+/// output. With no points the curve is the identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    points: [(f32, f32); MAX_BREAKPOINTS],
+    len: usize,
+}
+
+impl Breakpoints {
+    /// Creates a straight line from `(0, 0)` to `(1, 1)`.
+    pub const fn new() -> Self {
+        let mut points = [(0.0, 0.0); MAX_BREAKPOINTS];
+        points[1] = (1.0, 1.0);
+        Self { points, len: 2 }
+    }
+
+    /// Creates a curve through `points` (in any order).
+    ///
+    /// Returns `None` if there are more than [`MAX_BREAKPOINTS`] points.
+    pub fn from_points(points: &[(f32, f32)]) -> Option<Self> {
+        if points.len() > MAX_BREAKPOINTS {
+            return None;
+        }
+        let mut curve = Self {
+            points: [(0.0, 0.0); MAX_BREAKPOINTS],
+            len: 0,
+        };
+        for &(x, y) in points {
+            curve.insert(x, y);
+        }
+        Some(curve)
+    }
+
+    /// The points, sorted by input.
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points[..self.len]
+    }
+
+    /// Adds a point, replacing any point at the same input.
+    ///
+    /// Both coordinates are clamped to 0.0–1.0. Returns `false` if the curve
+    /// is full.
+    pub fn insert(&mut self, x: f32, y: f32) -> bool {
+        let (x, y) = (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0));
+        let index = self.points().partition_point(|&(px, _)| px < x);
+        if index < self.len && self.points[index].0 == x {
+            self.points[index].1 = y;
+            return true;
+        }
+        if self.len == MAX_BREAKPOINTS {
+            return false;
+        }
+        self.points.copy_within(index..self.len, index + 1);
+        self.points[index] = (x, y);
+        self.len += 1;
+        true
+    }
+
+    /// Moves point `index`, keeping the input between its neighbours so the
+    /// order is preserved. Out-of-range indices are ignored.
+    pub fn set(&mut self, index: usize, x: f32, y: f32) {
+        if index >= self.len {
+            return;
+        }
+        let lo = if index == 0 {
+            0.0
+        } else {
+            self.points[index - 1].0
+        };
+        let hi = if index + 1 == self.len {
+            1.0
+        } else {
+            self.points[index + 1].0
+        };
+        self.points[index] = (x.clamp(lo, hi), y.clamp(0.0, 1.0));
+    }
+
+    /// Removes point `index`. Out-of-range indices are ignored.
+    pub fn remove(&mut self, index: usize) {
+        if index < self.len {
+            self.points.copy_within(index + 1..self.len, index);
+            self.len -= 1;
+        }
+    }
+
+    /// Evaluates the curve at `x`.
+    pub fn apply(&self, x: f32) -> f32 {
+        let points = self.points();
+        let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+            return x;
+        };
+        if x <= first.0 {
+            return first.1;
+        }
+        if x >= last.0 {
+            return last.1;
+        }
+        let upper = points.partition_point(|&(px, _)| px <= x);
+        let (x0, y0) = points[upper - 1];
+        let (x1, y1) = points[upper];
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shape of a control's travel.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResponseCurve {
+    /// Output follows input.
+    #[default]
+    Linear,
+    /// Audio (logarithmic) taper: slow at the heel, fast at the toe, spanning
+    /// 40 dB like a log potentiometer.
+    Log,
+    /// User-drawn curve.
+    Custom(Breakpoints),
+}
+
+impl ResponseCurve {
+    /// Evaluates the curve at `x` (clamped to 0.0–1.0).
+    pub fn apply(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => x,
+            Self::Log => {
+                let span = libm::powf(10.0, LOG_DECADES);
+                (libm::powf(10.0, LOG_DECADES * x) - 1.0) / (span - 1.0)
+            }
+            Self::Custom(points) => points.apply(x),
+        }
+    }
+}
+
+/// How one mapping turns a control value into a parameter position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlResponse {
+    /// Shape of the control's travel.
+    pub curve: ResponseCurve,
+    /// Normalized parameter position at the bottom of the travel.
+    pub min: f32,
+    /// Normalized parameter position at the top of the travel.
+    pub max: f32,
+    /// Run the control's travel backwards before applying the curve.
+    pub invert: bool,
+}
+
+impl ControlResponse {
+    /// Linear over the whole parameter range.
+    pub const FULL: Self = Self {
+        curve: ResponseCurve::Linear,
+        min: 0.0,
+        max: 1.0,
+        invert: false,
+    };
+
+    /// Creates a response; `min` and `max` are clamped to 0.0–1.0.
+    pub fn new(curve: ResponseCurve, min: f32, max: f32) -> Self {
+        Self {
+            curve,
+            min: min.clamp(0.0, 1.0),
+            max: max.clamp(0.0, 1.0),
+            invert: false,
+        }
+    }
+
+    /// Returns the response with its travel inverted.
+    pub fn inverted(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+
+    /// Normalized parameter position for normalized control value `x`.
+    pub fn apply(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        let x = if self.invert { 1.0 - x } else { x };
+        self.min + (self.max - self.min) * self.curve.apply(x)
+    }
+
+    /// Control value that [`apply()`](Self::apply) maps to parameter
+    /// position `position`.
+    ///
+    /// Found by bisection, since the response is monotonic in the control.
+    /// Positions outside the `min..max` window give the nearest end of
+    /// travel. Use to move a control to a parameter set from elsewhere.
+    pub fn position_for(&self, position: f32) -> f32 {
+        let rising = self.apply(1.0) >= self.apply(0.0);
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+        for _ in 0..INVERSE_ITERATIONS {
+            let mid = 0.5 * (low + high);
+            if (self.apply(mid) < position) == rising {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        0.5 * (low + high)
+    }
+}
+
+impl Default for ControlResponse {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_taper_is_monotonic_with_fixed_ends() {
+        let curve = ResponseCurve::Log;
+        assert_eq!(curve.apply(0.0), 0.0);
+        assert!((curve.apply(1.0) - 1.0).abs() < 1e-6);
+        // Audio taper: under 10 % of the way at half travel
+        assert!(curve.apply(0.5) < 0.1);
+        let mut last = 0.0;
+        for i in 1..=100 {
+            let y = curve.apply(i as f32 / 100.0);
+            assert!(y > last);
+            last = y;
+        }
+    }
+
+    #[test]
+    fn breakpoints_interpolate_and_stay_sorted() {
+        let mut curve = Breakpoints::from_points(&[(1.0, 1.0), (0.0, 0.0), (0.5, 0.8)]).unwrap();
+        assert_eq!(curve.points(), &[(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)]);
+        assert!((curve.apply(0.25) - 0.4).abs() < 1e-6);
+        assert!((curve.apply(0.75) - 0.9).abs() < 1e-6);
+
+        // Moving a point cannot cross its neighbours
+        curve.set(1, 2.0, 0.5);
+        assert_eq!(curve.points()[1], (1.0, 0.5));
+
+        // Same input replaces; removal keeps the rest
+        assert!(curve.insert(0.0, 0.3));
+        assert_eq!(curve.points().len(), 3);
+        curve.remove(0);
+        assert_eq!(curve.points(), &[(1.0, 0.5), (1.0, 1.0)]);
+        assert_eq!(curve.apply(0.2), 0.5);
+
+        let full = [(0.0, 0.0); MAX_BREAKPOINTS + 1];
+        assert!(Breakpoints::from_points(&full).is_none());
+    }
+
+    #[test]
+    fn response_scales_into_window() {
+        let response = ControlResponse::new(ResponseCurve::Linear, 0.8, 0.2);
+        assert!((response.apply(0.0) - 0.8).abs() < 1e-6);
+        assert!((response.apply(0.5) - 0.5).abs() < 1e-6);
+        assert!((response.apply(1.0) - 0.2).abs() < 1e-6);
+        // Control values are clamped
+        assert!((response.apply(2.0) - 0.2).abs() < 1e-6);
+        assert_eq!(ControlResponse::default(), ControlResponse::FULL);
+    }
+
+    #[test]
+    fn invert_flips_travel_before_curve() {
+        let response = ControlResponse::new(ResponseCurve::Log, 0.0, 1.0).inverted(true);
+        assert!((response.apply(0.0) - 1.0).abs() < 1e-6);
+        assert_eq!(response.apply(1.0), 0.0);
+        // Still slow at the start of the reversed travel
+        assert!(response.apply(0.5) < 0.1);
+    }
+
+    #[test]
+    fn position_for_inverts_apply() {
+        let responses = [
+            ControlResponse::FULL,
+            ControlResponse::new(ResponseCurve::Log, 0.2, 0.9),
+            ControlResponse::new(ResponseCurve::Linear, 0.8, 0.2).inverted(true),
+        ];
+        for response in responses {
+            for x in [0.1, 0.5, 0.75] {
+                let position = response.apply(x);
+                assert!((response.position_for(position) - x).abs() < 1e-4);
+            }
+        }
+        // Outside the window: nearest end of travel
+        let window = ControlResponse::new(ResponseCurve::Linear, 0.2, 0.6);
+        assert!(window.position_for(0.9) > 0.999);
+        assert!(window.position_for(0.0) < 0.001);
+    }
+}
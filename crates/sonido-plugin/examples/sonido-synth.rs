@@ -0,0 +1,12 @@
+//! Sonido Synth — CLAP instrument plugin.
+//!
+//! 8-voice polyphonic subtractive synth with onboard chorus, delay, and reverb.
+
+use sonido_plugin::sonido_instrument_entry;
+
+sonido_instrument_entry! {
+    fx: ["chorus", "delay", "reverb"],
+    clap_id: "com.sonido.synth",
+    name: "Sonido Synth",
+    features: [INSTRUMENT, SYNTHESIZER, STEREO],
+}
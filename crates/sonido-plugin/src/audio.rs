@@ -1,16 +1,17 @@
 //! Audio processor for sonido CLAP plugins.
 //!
 //! Handles the real-time audio callback: reads parameter change events from
-//! the host, updates the effect, and processes stereo audio buffers.
+//! the host, updates the effect, and processes stereo audio buffers. Keyed
+//! effects (compressor, gate) also read the sidechain input port and pass it
+//! to the effect as an external key.
 
+use crate::gesture::{GestureSync, push_gesture};
 use crate::main_thread::SonidoMainThread;
-use crate::shared::{GESTURE_BEGIN, GESTURE_END, SonidoShared};
+use crate::shared::SonidoShared;
 use clack_extensions::params::PluginAudioProcessorParams;
 use clack_extensions::tail::{PluginTailImpl, TailLength};
 use clack_plugin::events::EventFlags;
-use clack_plugin::events::event_types::{
-    ParamGestureBeginEvent, ParamGestureEndEvent, ParamValueEvent,
-};
+use clack_plugin::events::event_types::ParamValueEvent;
 use clack_plugin::prelude::*;
 use clack_plugin::utils::Cookie;
 use sonido_registry::{EffectRegistry, EffectWithParams};
@@ -22,6 +23,12 @@ use sonido_registry::{EffectRegistry, EffectWithParams};
 pub struct SonidoAudioProcessor<'a> {
     shared: &'a SonidoShared,
     effect: Box<dyn EffectWithParams + Send>,
+    /// Sidechain key, left channel (sized to the host's max block).
+    key_left: Vec<f32>,
+    /// Sidechain key, right channel.
+    key_right: Vec<f32>,
+    /// Orders GUI gesture events sent to the host.
+    gestures: GestureSync,
 }
 
 impl<'a> PluginAudioProcessor<'a, SonidoShared, SonidoMainThread<'a>> for SonidoAudioProcessor<'a> {
@@ -42,7 +49,20 @@ impl<'a> PluginAudioProcessor<'a, SonidoShared, SonidoMainThread<'a>> for Sonido
         // Cache latency so the main thread can report it to the host.
         shared.set_latency_samples(effect.latency_samples() as u32);
 
-        Ok(Self { shared, effect })
+        // Preallocate key buffers — no allocation on the audio thread.
+        let key_len = if shared.has_key_input() {
+            audio_config.max_frames_count as usize
+        } else {
+            0
+        };
+
+        Ok(Self {
+            shared,
+            effect,
+            key_left: vec![0.0; key_len],
+            key_right: vec![0.0; key_len],
+            gestures: GestureSync::new(shared.param_count()),
+        })
     }
 
     fn process(
@@ -65,6 +85,9 @@ impl<'a> PluginAudioProcessor<'a, SonidoShared, SonidoMainThread<'a>> for Sonido
         // Process audio through the effect.
         self.process_audio(&mut audio)?;
 
+        // Publish read-only values (learn results, meters) for the GUI.
+        self.publish_read_only();
+
         Ok(ProcessStatus::ContinueIfNotQuiet)
     }
 
@@ -86,28 +109,30 @@ impl SonidoAudioProcessor<'_> {
     /// the GUI wrote a new value. We update the effect and emit a
     /// `ParamValueEvent` so the host updates its controls/automation.
     fn sync_gui_changes(&mut self, output: &mut OutputEvents) {
-        for i in 0..self.shared.param_count() {
-            let flags = self.shared.take_gesture_flags(i);
-            let has_begin = flags & GESTURE_BEGIN != 0;
-            let has_end = flags & GESTURE_END != 0;
+        let shared = self.shared;
 
-            // 1. Begin gesture (before value) — groups edits into one undo entry.
-            if has_begin && let Some(desc) = self.shared.descriptor(i) {
-                let event = ParamGestureBeginEvent::new(0, ClapId::new(desc.id.0))
-                    .with_flags(EventFlags::IS_LIVE);
-                let _ = output.try_push(event);
-            }
+        // 1. Begin gestures (before values) — every pending begin goes out
+        // first so simultaneous edits share one undo entry.
+        self.gestures.open(
+            |i| shared.take_gesture_flags(i),
+            |i, edge| push_gesture(output, shared, i, edge),
+        );
 
-            // 2. Value change — sync GUI-written values to effect + host.
-            let Some(shared_val) = self.shared.get_value(i) else {
+        // 2. Value changes — sync GUI-written values to effect + host.
+        // Read-only values flow the other way (see `publish_read_only`).
+        for i in 0..shared.param_count() {
+            let Some(shared_val) = shared.get_value(i) else {
                 continue;
             };
+            let read_only = shared
+                .descriptor(i)
+                .is_some_and(|d| d.flags.contains(sonido_core::ParamFlags::READ_ONLY));
             let effect_val = self.effect.effect_get_param(i);
 
-            if shared_val.to_bits() != effect_val.to_bits() {
+            if !read_only && shared_val.to_bits() != effect_val.to_bits() {
                 self.effect.effect_set_param(i, shared_val);
 
-                if let Some(desc) = self.shared.descriptor(i) {
+                if let Some(desc) = shared.descriptor(i) {
                     let event = ParamValueEvent::new(
                         0,
                         ClapId::new(desc.id.0),
@@ -119,12 +144,25 @@ impl SonidoAudioProcessor<'_> {
                     let _ = output.try_push(event);
                 }
             }
+        }
 
-            // 3. End gesture (after value) — closes the undo group.
-            if has_end && let Some(desc) = self.shared.descriptor(i) {
-                let event = ParamGestureEndEvent::new(0, ClapId::new(desc.id.0))
-                    .with_flags(EventFlags::IS_LIVE);
-                let _ = output.try_push(event);
+        // 3. End gestures (after values) — close the undo group.
+        self.gestures
+            .close(|i, edge| push_gesture(output, shared, i, edge));
+    }
+
+    /// Copy the effect's READ_ONLY parameter values into shared state.
+    ///
+    /// The GUI reads them from there; the host is not notified, since it
+    /// cannot change them.
+    fn publish_read_only(&mut self) {
+        for i in 0..self.shared.param_count() {
+            if self
+                .shared
+                .descriptor(i)
+                .is_some_and(|d| d.flags.contains(sonido_core::ParamFlags::READ_ONLY))
+            {
+                self.shared.set_value(i, self.effect.effect_get_param(i));
             }
         }
     }
@@ -132,7 +170,8 @@ impl SonidoAudioProcessor<'_> {
     /// Handle incoming parameter change events from the host.
     ///
     /// Updates both the shared atomic state (so the main thread sees the
-    /// latest values) and the effect's internal parameters directly.
+    /// latest values) and the effect's internal parameters directly, using
+    /// the value as snapped by the shared state.
     fn handle_events(&mut self, input: &InputEvents) {
         for event in input {
             if let Some(clack_plugin::events::spaces::CoreEventSpace::ParamValue(ev)) =
@@ -140,17 +179,43 @@ impl SonidoAudioProcessor<'_> {
                 && let Some(param_id) = ev.param_id()
             {
                 let id = param_id.get();
-                if let Some(index) = self.shared.index_by_id(id) {
-                    let value = ev.value() as f32;
-                    self.shared.set_value(index, value);
+                if let Some(index) = self.shared.index_by_id(id)
+                    && let Some(value) = self.shared.set_value(index, ev.value() as f32)
+                {
+                    // The effect ramps to the value with the parameter's
+                    // own smoothing; stepped values arrive already snapped.
                     self.effect.effect_set_param(index, value);
                 }
             }
         }
     }
 
+    /// Copy the sidechain input (port 1) into the key buffers.
+    ///
+    /// Returns the number of key frames, or `None` when the plugin has no key
+    /// port or the host supplied no buffers for it.
+    fn read_key(&mut self, audio: &Audio) -> Option<usize> {
+        if !self.shared.has_key_input() {
+            return None;
+        }
+        let port = audio.input_port(1)?;
+        let channels = port.channels().ok()?.into_f32()?;
+        let left = channels.channel(0)?;
+        let right = channels.channel(1).unwrap_or(left);
+
+        let frames = left.len().min(right.len()).min(self.key_left.len());
+        self.key_left[..frames].copy_from_slice(&left[..frames]);
+        self.key_right[..frames].copy_from_slice(&right[..frames]);
+        Some(frames)
+    }
+
     /// Process stereo audio through the effect.
+    ///
+    /// When a key is available it is routed to the effect's sidechain path;
+    /// the effect's `Key` parameter decides whether it is used.
     fn process_audio(&mut self, audio: &mut Audio) -> Result<(), PluginError> {
+        let key_frames = self.read_key(audio);
+
         for mut port_pair in audio {
             let channels = port_pair.channels()?;
 
@@ -177,12 +242,34 @@ impl SonidoAudioProcessor<'_> {
                         (
                             Some(ChannelPair::InputOutput(left_in, left_out)),
                             Some(ChannelPair::InputOutput(right_in, right_out)),
-                        ) => {
-                            self.effect
-                                .process_block_stereo(left_in, right_in, left_out, right_out);
-                        }
+                        ) => match key_frames {
+                            Some(n) if n == left_in.len() => {
+                                self.effect.process_block_stereo_with_sidechain(
+                                    left_in,
+                                    right_in,
+                                    left_out,
+                                    right_out,
+                                    &self.key_left[..n],
+                                    &self.key_right[..n],
+                                );
+                            }
+                            _ => {
+                                self.effect
+                                    .process_block_stereo(left_in, right_in, left_out, right_out);
+                            }
+                        },
                         (Some(ChannelPair::InPlace(left)), Some(ChannelPair::InPlace(right))) => {
-                            self.effect.process_block_stereo_inplace(left, right);
+                            match key_frames {
+                                Some(n) if n == left.len() => {
+                                    self.effect.process_block_stereo_inplace_with_sidechain(
+                                        left,
+                                        right,
+                                        &self.key_left[..n],
+                                        &self.key_right[..n],
+                                    );
+                                }
+                                _ => self.effect.process_block_stereo_inplace(left, right),
+                            }
                         }
                         _ => {
                             // Asymmetric layout — process channels independently.
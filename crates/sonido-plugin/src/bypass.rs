@@ -0,0 +1,109 @@
+//! Click-free bypass for the instrument's onboard effect slots.
+//!
+//! Switching an effect in or out between blocks would step the output, so
+//! [`BypassFade`] crossfades between the dry and processed signal with the
+//! same fast envelope graph nodes use for their bypass. Once the fade has
+//! landed on bypass the effect is skipped entirely.
+
+use sonido_core::SmoothedParam;
+
+/// Crossfade state for one bypassable effect.
+pub struct BypassFade {
+    /// 1.0 = processed (active), 0.0 = dry (bypassed).
+    fade: SmoothedParam,
+    /// Dry copy of the block while fading (left), sized at activation.
+    dry_left: Vec<f32>,
+    /// Dry copy of the block while fading (right).
+    dry_right: Vec<f32>,
+}
+
+impl BypassFade {
+    /// Create a fade resting at `bypassed`, for blocks up to `max_frames`.
+    pub fn new(bypassed: bool, sample_rate: f32, max_frames: usize) -> Self {
+        let mut fade = SmoothedParam::fast(if bypassed { 0.0 } else { 1.0 }, sample_rate);
+        fade.snap_to_target();
+        Self {
+            fade,
+            dry_left: vec![0.0; max_frames],
+            dry_right: vec![0.0; max_frames],
+        }
+    }
+
+    /// Whether the fade is heading to (or resting at) bypass.
+    pub fn is_bypassed(&self) -> bool {
+        self.fade.target() < 0.5
+    }
+
+    /// Start fading toward `bypassed`.
+    pub fn set_bypassed(&mut self, bypassed: bool) {
+        self.fade.set_target(if bypassed { 0.0 } else { 1.0 });
+    }
+
+    /// Land on the current target immediately (on reset).
+    pub fn snap(&mut self) {
+        self.fade.snap_to_target();
+    }
+
+    /// Run `process` over the block in place, crossfading with the dry
+    /// signal while the fade moves. Skips `process` once fully bypassed.
+    pub fn process(
+        &mut self,
+        left: &mut [f32],
+        right: &mut [f32],
+        process: impl FnOnce(&mut [f32], &mut [f32]),
+    ) {
+        if self.fade.is_settled() {
+            if !self.is_bypassed() {
+                process(left, right);
+            }
+            return;
+        }
+
+        let frames = left.len().min(right.len()).min(self.dry_left.len());
+        let (left, right) = (&mut left[..frames], &mut right[..frames]);
+        self.dry_left[..frames].copy_from_slice(left);
+        self.dry_right[..frames].copy_from_slice(right);
+        process(left, right);
+        for i in 0..frames {
+            let wet = self.fade.advance();
+            left[i] = self.dry_left[i] + (left[i] - self.dry_left[i]) * wet;
+            right[i] = self.dry_right[i] + (right[i] - self.dry_right[i]) * wet;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bypass_crossfades_then_skips() {
+        let mut fade = BypassFade::new(false, 48000.0, 64);
+        let invert = |l: &mut [f32], r: &mut [f32]| {
+            l.iter_mut().chain(r.iter_mut()).for_each(|s| *s = -*s);
+        };
+
+        let (mut left, mut right) = ([1.0; 64], [1.0; 64]);
+        fade.process(&mut left, &mut right, invert);
+        assert_eq!(left, [-1.0; 64], "active: fully processed");
+
+        fade.set_bypassed(true);
+        assert!(fade.is_bypassed());
+        let (mut left, mut right) = ([1.0; 64], [1.0; 64]);
+        fade.process(&mut left, &mut right, invert);
+        assert!(left[0] < -0.9, "fade starts from the processed signal");
+        assert!(
+            left.windows(2).all(|w| w[1] >= w[0]),
+            "no steps while fading"
+        );
+        assert_eq!(left, right);
+
+        // After the fade lands the effect is skipped.
+        for _ in 0..100 {
+            fade.process(&mut [0.0; 64], &mut [0.0; 64], invert);
+        }
+        let (mut left, mut right) = ([1.0; 64], [1.0; 64]);
+        fade.process(&mut left, &mut right, |_, _| panic!("bypassed effect ran"));
+        assert_eq!((left, right), ([1.0; 64], [1.0; 64]));
+    }
+}
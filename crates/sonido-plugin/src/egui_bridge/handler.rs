@@ -9,6 +9,18 @@
 //! 4. Render via `egui_glow::Painter`
 //! 5. Swap buffers
 //!
+//! Text copied by the UI ([`egui::OutputCommand::CopyText`]) is written to
+//! the system clipboard. The handler keeps the clipboard open for the window
+//! lifetime: on X11 the copied text is only served while it exists.
+//!
+//! # Scaling
+//!
+//! The bridge owns DPI scaling rather than baseview: the window is opened
+//! with a scale factor of 1.0 and sized in physical pixels, and egui is told
+//! the host scale as its native pixels-per-point. The user zoom is egui's
+//! zoom factor, so `pixels_per_point = host scale × zoom`. This lets the
+//! editor follow zoom changes without reopening the window.
+//!
 //! # References
 //!
 //! - egui rendering: <https://docs.rs/egui_glow>
@@ -19,12 +31,12 @@ use baseview::gl::GlConfig;
 use baseview::{
     Event, EventStatus, Size, Window, WindowHandler, WindowOpenOptions, WindowScalePolicy,
 };
-use egui::{Context, Pos2, RawInput, Rect, Vec2};
+use egui::{Context, Pos2, RawInput, Rect, Vec2, ViewportId};
 use raw_window_handle::HasRawWindowHandle;
 use std::sync::Arc;
 
 use super::translate;
-use crate::gui::PendingResize;
+use crate::gui::{PendingResize, to_host_pixels};
 
 /// Open an egui-rendered child window inside a host-provided parent.
 ///
@@ -46,7 +58,8 @@ use crate::gui::PendingResize;
 /// - `parent` — Host's parent window handle (from CLAP `set_parent`)
 /// - `title` — Window title (typically the effect name)
 /// - `width`, `height` — Window dimensions in logical pixels
-/// - `scale` — DPI scale factor from the host (1.0 = no scaling)
+/// - `scale` — DPI scale factor from the host (1.0 = no scaling), fixed for
+///   the window's lifetime
 /// - `state` — User state passed to both closures
 /// - `build` — One-time setup (e.g., font configuration)
 /// - `update` — Per-frame UI rendering
@@ -66,19 +79,19 @@ where
     P: HasRawWindowHandle,
     S: Send + 'static,
 {
+    let physical_width = to_host_pixels(width, scale);
+    let physical_height = to_host_pixels(height, scale);
+
     let options = WindowOpenOptions {
         title,
-        size: Size::new(f64::from(width), f64::from(height)),
-        scale: WindowScalePolicy::ScaleFactor(scale),
+        size: Size::new(f64::from(physical_width), f64::from(physical_height)),
+        scale: WindowScalePolicy::ScaleFactor(1.0),
         gl_config: Some(GlConfig {
             version: (3, 2),
             ..GlConfig::default()
         }),
     };
 
-    let logical_width = width as f32;
-    let logical_height = height as f32;
-
     baseview::Window::open_parented(parent, options, move |window: &mut Window<'_>| {
         let gl_context = window
             .gl_context()
@@ -98,20 +111,25 @@ where
 
         let ctx = Context::default();
 
-        let physical_width = (logical_width * scale as f32) as u32;
-        let physical_height = (logical_height * scale as f32) as u32;
+        let mut raw_input = RawInput::default();
+        raw_input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = Some(scale as f32);
 
         let mut handler = EguiBridgeHandler {
             ctx,
             gl,
             painter,
-            raw_input: RawInput::default(),
+            raw_input,
             physical_width,
             physical_height,
             scale,
             mouse_pos: Pos2::ZERO,
             pending_resize,
             last_applied_logical: (width, height),
+            clipboard: None,
             state,
             update_fn: Box::new(update),
         };
@@ -140,7 +158,7 @@ struct EguiBridgeHandler<S> {
     physical_width: u32,
     /// Viewport height in physical pixels.
     physical_height: u32,
-    /// DPI scale factor.
+    /// Host DPI scale factor (egui's native pixels-per-point).
     scale: f64,
     /// Last known mouse position in logical coordinates.
     mouse_pos: Pos2,
@@ -148,6 +166,8 @@ struct EguiBridgeHandler<S> {
     pending_resize: Arc<PendingResize>,
     /// Last logical size applied via `window.resize()`, used to detect changes.
     last_applied_logical: (u32, u32),
+    /// System clipboard, opened on first copy.
+    clipboard: Option<arboard::Clipboard>,
     /// User state passed to the update closure.
     state: S,
     /// Per-frame UI callback.
@@ -160,7 +180,10 @@ impl<S: Send + 'static> WindowHandler for EguiBridgeHandler<S> {
         // Apply any pending resize from the CLAP host before rendering.
         let pending = self.pending_resize.get();
         if pending != self.last_applied_logical {
-            window.resize(Size::new(f64::from(pending.0), f64::from(pending.1)));
+            window.resize(Size::new(
+                f64::from(to_host_pixels(pending.0, self.scale)),
+                f64::from(to_host_pixels(pending.1, self.scale)),
+            ));
             self.last_applied_logical = pending;
         }
 
@@ -173,7 +196,7 @@ impl<S: Send + 'static> WindowHandler for EguiBridgeHandler<S> {
             gl_context.make_current();
         }
 
-        let ppp = self.scale as f32;
+        let ppp = self.pixels_per_point() as f32;
         let logical_w = self.physical_width as f32 / ppp;
         let logical_h = self.physical_height as f32 / ppp;
 
@@ -187,6 +210,11 @@ impl<S: Send + 'static> WindowHandler for EguiBridgeHandler<S> {
         let full_output = self.ctx.run(self.raw_input.take(), |ctx| {
             (self.update_fn)(ctx, &mut self.state);
         });
+        for command in &full_output.platform_output.commands {
+            if let egui::OutputCommand::CopyText(text) = command {
+                self.copy_text(text);
+            }
+        }
 
         // Tessellate egui shapes into GPU-ready primitives.
         let primitives = self
@@ -224,7 +252,7 @@ impl<S: Send + 'static> WindowHandler for EguiBridgeHandler<S> {
             Event::Mouse(mouse_event) => {
                 translate::translate_mouse(
                     &mouse_event,
-                    self.scale,
+                    self.pixels_per_point(),
                     &mut self.raw_input,
                     &mut self.mouse_pos,
                 );
@@ -254,6 +282,27 @@ impl<S: Send + 'static> WindowHandler for EguiBridgeHandler<S> {
     }
 }
 
+impl<S> EguiBridgeHandler<S> {
+    /// Physical pixels per egui point: host scale × user zoom.
+    fn pixels_per_point(&self) -> f64 {
+        self.scale * f64::from(self.ctx.zoom_factor())
+    }
+
+    /// Put `text` on the system clipboard, opening it on first use.
+    fn copy_text(&mut self, text: &str) {
+        if self.clipboard.is_none() {
+            self.clipboard = arboard::Clipboard::new()
+                .map_err(|e| tracing::warn!(error = %e, "system clipboard unavailable"))
+                .ok();
+        }
+        if let Some(clipboard) = &mut self.clipboard
+            && let Err(e) = clipboard.set_text(text)
+        {
+            tracing::warn!(error = %e, "clipboard copy failed");
+        }
+    }
+}
+
 impl<S> Drop for EguiBridgeHandler<S> {
     fn drop(&mut self) {
         self.painter.destroy();
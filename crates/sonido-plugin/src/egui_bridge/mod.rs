@@ -26,8 +26,9 @@
 //!
 //! egui-baseview is a general-purpose bridge (~960 lines) supporting standalone
 //! windows, wgpu, clipboard, cursor icons, and full keyboard translation.
-//! Plugin UIs need none of that — they are always parented, always OpenGL,
-//! mouse-driven with only shift-modifier support. This bridge cuts the
+//! Plugin UIs need almost none of that — they are always parented, always
+//! OpenGL, mouse-driven with only shift-modifier support, and only copy
+//! plain text to the clipboard. This bridge cuts the
 //! dependency and gives sonido full control over its rendering pipeline.
 
 mod handler;
@@ -4,8 +4,9 @@
 //! - Mouse movement, clicks, scroll (knob/slider/combo interaction)
 //! - Keyboard modifier state (shift for fine-control on knobs)
 //!
-//! Clipboard, cursor icon, text input, and IME are intentionally omitted —
-//! no sonido effect panel uses text fields or clipboard operations.
+//! Cursor icon, text input, and IME are intentionally omitted — no sonido
+//! effect panel uses text fields. Clipboard copy is handled on the output
+//! side by the handler; the editor reads the clipboard directly for paste.
 
 use baseview::{MouseButton as BvMouseButton, MouseEvent, ScrollDelta, WindowEvent};
 use egui::{Event as EguiEvent, Modifiers, PointerButton, Pos2, RawInput, Vec2};
@@ -18,15 +19,16 @@ use keyboard_types::Modifiers as KbModifiers;
 ///
 /// # Coordinate system
 ///
-/// Baseview reports physical pixel coordinates. We divide by `scale` to get
-/// egui's logical coordinates (matching `pixels_per_point`).
+/// Baseview reports physical pixel coordinates (the window is opened with a
+/// scale factor of 1.0). We divide by `pixels_per_point` to get egui's
+/// logical coordinates.
 pub fn translate_mouse(
     event: &MouseEvent,
-    scale: f64,
+    pixels_per_point: f64,
     raw_input: &mut RawInput,
     mouse_pos: &mut Pos2,
 ) {
-    let scale_recip = 1.0 / scale as f32;
+    let scale_recip = 1.0 / pixels_per_point as f32;
 
     match event {
         MouseEvent::CursorMoved {
@@ -0,0 +1,146 @@
+//! Ordering of GUI gesture events sent to the host.
+//!
+//! The editor raises [`GESTURE_BEGIN`]/[`GESTURE_END`] flags per parameter,
+//! and the audio thread turns them into CLAP gesture events. Hosts group
+//! every value change made while any gesture is open into one undo step, so
+//! the order matters: emitting begin/value/end for one parameter before
+//! moving to the next would split a paste or a two-axis drag into one step
+//! per parameter. [`GestureSync`] emits every begin first, then the caller
+//! emits values, then [`GestureSync::close`] emits the ends.
+//!
+//! It also tracks which gestures the host has seen open, so an end without a
+//! begin is dropped and a begin on an open gesture closes the stale one
+//! first — the host always sees balanced pairs.
+
+use crate::shared::{GESTURE_BEGIN, GESTURE_END, SonidoShared};
+use clack_plugin::events::EventFlags;
+use clack_plugin::events::event_types::{ParamGestureBeginEvent, ParamGestureEndEvent};
+use clack_plugin::prelude::*;
+
+/// A gesture event to send to the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GestureEdge {
+    /// `CLAP_EVENT_PARAM_GESTURE_BEGIN`.
+    Begin,
+    /// `CLAP_EVENT_PARAM_GESTURE_END`.
+    End,
+}
+
+/// Per-parameter gesture state for one audio processor.
+///
+/// Buffers are sized at activation, so syncing never allocates.
+pub struct GestureSync {
+    /// Flags taken from shared state by the last [`open`](Self::open).
+    pending: Vec<u8>,
+    /// Whether the host has seen a begin without a matching end.
+    open: Vec<bool>,
+}
+
+impl GestureSync {
+    /// Create state for `param_count` parameters, all closed.
+    pub fn new(param_count: usize) -> Self {
+        Self {
+            pending: vec![0; param_count],
+            open: vec![false; param_count],
+        }
+    }
+
+    /// Take every parameter's pending flags via `take_flags` and emit the
+    /// begins. Call before emitting value changes.
+    pub fn open(
+        &mut self,
+        mut take_flags: impl FnMut(usize) -> u8,
+        mut emit: impl FnMut(usize, GestureEdge),
+    ) {
+        for (i, (pending, open)) in self.pending.iter_mut().zip(&mut self.open).enumerate() {
+            *pending = take_flags(i);
+            if *pending & GESTURE_BEGIN != 0 {
+                if *open {
+                    emit(i, GestureEdge::End);
+                }
+                emit(i, GestureEdge::Begin);
+                *open = true;
+            }
+        }
+    }
+
+    /// Emit the ends taken by the last [`open`](Self::open). Call after
+    /// emitting value changes.
+    pub fn close(&mut self, mut emit: impl FnMut(usize, GestureEdge)) {
+        for (i, (pending, open)) in self.pending.iter_mut().zip(&mut self.open).enumerate() {
+            if *pending & GESTURE_END != 0 && *open {
+                emit(i, GestureEdge::End);
+                *open = false;
+            }
+            *pending = 0;
+        }
+    }
+}
+
+/// Push a gesture event for parameter `index` of `shared` to the host.
+pub fn push_gesture(
+    output: &mut OutputEvents,
+    shared: &SonidoShared,
+    index: usize,
+    edge: GestureEdge,
+) {
+    let Some(id) = shared.descriptor(index).map(|d| ClapId::new(d.id.0)) else {
+        return;
+    };
+    let _ = match edge {
+        GestureEdge::Begin => {
+            output.try_push(ParamGestureBeginEvent::new(0, id).with_flags(EventFlags::IS_LIVE))
+        }
+        GestureEdge::End => {
+            output.try_push(ParamGestureEndEvent::new(0, id).with_flags(EventFlags::IS_LIVE))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one sync with `flags` and log the emitted events, with `"values"`
+    /// where value changes would be sent.
+    fn sync(gestures: &mut GestureSync, flags: &[u8]) -> Vec<String> {
+        let mut log = Vec::new();
+        gestures.open(|i| flags[i], |i, edge| log.push(format!("{edge:?} {i}")));
+        log.push("values".to_owned());
+        gestures.close(|i, edge| log.push(format!("{edge:?} {i}")));
+        log
+    }
+
+    #[test]
+    fn pending_gestures_overlap() {
+        let mut gestures = GestureSync::new(3);
+        let both = GESTURE_BEGIN | GESTURE_END;
+        assert_eq!(
+            sync(&mut gestures, &[both, 0, both]),
+            ["Begin 0", "Begin 2", "values", "End 0", "End 2"]
+        );
+    }
+
+    #[test]
+    fn gestures_stay_balanced_across_blocks() {
+        let mut gestures = GestureSync::new(2);
+
+        // A drag spans blocks: begin, then nothing, then end.
+        assert_eq!(
+            sync(&mut gestures, &[GESTURE_BEGIN, 0]),
+            ["Begin 0", "values"]
+        );
+        assert_eq!(sync(&mut gestures, &[0, 0]), ["values"]);
+        assert_eq!(sync(&mut gestures, &[GESTURE_END, 0]), ["values", "End 0"]);
+
+        // An end without a begin is dropped.
+        assert_eq!(sync(&mut gestures, &[0, GESTURE_END]), ["values"]);
+
+        // A gesture that ends and restarts within one block closes first.
+        sync(&mut gestures, &[GESTURE_BEGIN, 0]);
+        assert_eq!(
+            sync(&mut gestures, &[GESTURE_BEGIN | GESTURE_END, 0]),
+            ["End 0", "Begin 0", "values", "End 0"]
+        );
+    }
+}
@@ -7,6 +7,14 @@
 //! The egui bridge (`crate::egui_bridge`) replaces the external `egui-baseview`
 //! dependency with ~350 lines of focused glue code, giving sonido full control
 //! over its plugin rendering pipeline.
+//!
+//! # Sizes and scaling
+//!
+//! Editor sizes are tracked in *logical* pixels: host scale excluded, user
+//! zoom included. The CLAP host sees them multiplied by its scale (see
+//! [`to_host_pixels`]); on macOS, where the host doesn't set a scale, the
+//! two are the same. Window bounds grow with the zoom, so the panels always
+//! get between `MIN_*` and `MAX_*` points of space.
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -14,14 +22,17 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use baseview::WindowHandle;
 use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
+use sonido_config::SlotColor;
 use sonido_core::ParamDescriptor;
 use sonido_gui_core::{
+    clipboard::{self, PasteError},
     effects_ui::{EffectPanel, create_panel},
     param_bridge::{ParamBridge, ParamIndex, SlotIndex},
+    widgets::BypassToggle,
 };
 
 use crate::egui_bridge;
-use crate::shared::SonidoShared;
+use crate::shared::{MAX_SLOT_LABEL_CHARS, SonidoShared};
 
 // ── Window size constants ────────────────────────────────────────────────────
 
@@ -43,6 +54,59 @@ pub const MAX_WIDTH: u32 = 1920;
 /// Maximum plugin window height in logical pixels.
 pub const MAX_HEIGHT: u32 = 1080;
 
+/// Zoom levels offered in the editor's zoom menu.
+pub const ZOOM_LEVELS: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+// ── Size conversion ──────────────────────────────────────────────────────────
+
+/// Convert a logical size to host pixels at the host's `scale`.
+pub fn to_host_pixels(logical: u32, scale: f64) -> u32 {
+    (f64::from(logical) * scale).round() as u32
+}
+
+/// Convert a size in host pixels to logical pixels at the host's `scale`.
+pub fn from_host_pixels(host: u32, scale: f64) -> u32 {
+    (f64::from(host) / scale).round() as u32
+}
+
+/// Clamp a logical editor size to the window bounds at `zoom`.
+pub fn clamp_size(width: u32, height: u32, zoom: f32) -> (u32, u32) {
+    let bound = |v: u32| (v as f32 * zoom).round() as u32;
+    (
+        width.clamp(bound(MIN_WIDTH), bound(MAX_WIDTH)),
+        height.clamp(bound(MIN_HEIGHT), bound(MAX_HEIGHT)),
+    )
+}
+
+/// Rescale a logical editor size from zoom `from` to zoom `to`, keeping the
+/// same panel space visible.
+pub fn rezoom_size(size: (u32, u32), from: f32, to: f32) -> (u32, u32) {
+    let ratio = to / from;
+    clamp_size(
+        (size.0 as f32 * ratio).round() as u32,
+        (size.1 as f32 * ratio).round() as u32,
+        to,
+    )
+}
+
+/// Change the user zoom, resizing the editor to match.
+///
+/// Updates the shared zoom (persisted in plugin state), rescales the pending
+/// window size, and asks the host to resize its container. Safe to call with
+/// the editor closed: the new size is used when it next opens.
+pub fn apply_zoom(shared: &SonidoShared, pending_resize: &PendingResize, scale: f64, zoom: f32) {
+    let old = shared.ui_zoom();
+    shared.set_ui_zoom(zoom);
+    let new = shared.ui_zoom();
+    if old == new {
+        return;
+    }
+
+    let (width, height) = rezoom_size(pending_resize.get(), old, new);
+    pending_resize.set(width, height);
+    shared.request_host_resize(to_host_pixels(width, scale), to_host_pixels(height, scale));
+}
+
 // ── Atomic resize channel ────────────────────────────────────────────────────
 
 /// Lock-free resize channel between the CLAP host's main thread and baseview.
@@ -91,8 +155,10 @@ impl PendingResize {
 
 /// Implements [`ParamBridge`] backed by `SonidoShared` lock-free atomics.
 ///
-/// Single-slot (slot 0 = the effect). Thread-safe reads/writes via `Acquire`/`Release`
-/// atomics — safe to call from the egui render closure on any thread.
+/// Slot 0 is the effect (or the synth); the instrument's onboard effects
+/// follow as slots 1.., mapped onto the flat shared parameter list.
+/// Thread-safe reads/writes via `Acquire`/`Release` atomics — safe to call
+/// from the egui render closure on any thread.
 ///
 /// Gesture tracking (`begin_set`/`end_set`) sets atomic flags in shared state.
 /// The audio thread reads and clears these flags, emitting CLAP
@@ -106,50 +172,77 @@ impl PluginParamBridge {
     pub fn new(shared: SonidoShared) -> Self {
         Self { shared }
     }
+
+    /// Map a slot-relative parameter to its flat index in shared state.
+    fn flat_index(&self, slot: SlotIndex, param: ParamIndex) -> Option<usize> {
+        let range = self.shared.slot_params(slot.0);
+        let index = range.start + param.0;
+        range.contains(&index).then_some(index)
+    }
 }
 
 impl ParamBridge for PluginParamBridge {
     fn slot_count(&self) -> usize {
-        1 // plugin exposes a single effect slot
+        self.shared.slot_count()
     }
 
-    fn effect_id(&self, _slot: SlotIndex) -> &str {
-        self.shared.effect_id()
+    fn effect_id(&self, slot: SlotIndex) -> &str {
+        self.shared.slot_effect_id(slot.0).unwrap_or("")
     }
 
-    fn param_count(&self, _slot: SlotIndex) -> usize {
-        self.shared.param_count()
+    fn param_count(&self, slot: SlotIndex) -> usize {
+        self.shared.slot_params(slot.0).len()
     }
 
-    fn param_descriptor(&self, _slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
-        self.shared.descriptor(param.0).cloned()
+    fn param_descriptor(&self, slot: SlotIndex, param: ParamIndex) -> Option<ParamDescriptor> {
+        self.flat_index(slot, param)
+            .and_then(|i| self.shared.descriptor(i))
+            .cloned()
     }
 
-    fn get(&self, _slot: SlotIndex, param: ParamIndex) -> f32 {
-        self.shared.get_value(param.0).unwrap_or(0.0)
+    fn get(&self, slot: SlotIndex, param: ParamIndex) -> f32 {
+        self.flat_index(slot, param)
+            .and_then(|i| self.shared.get_value(i))
+            .unwrap_or(0.0)
     }
 
-    fn set(&self, _slot: SlotIndex, param: ParamIndex, value: f32) {
-        self.shared.set_value(param.0, value);
-        self.shared.notify_host();
+    fn set(&self, slot: SlotIndex, param: ParamIndex, value: f32) {
+        if let Some(i) = self.flat_index(slot, param) {
+            self.shared.set_value(i, value);
+            self.shared.notify_host();
+        }
     }
 
-    fn is_bypassed(&self, _slot: SlotIndex) -> bool {
-        self.shared.is_bypassed()
+    fn is_bypassed(&self, slot: SlotIndex) -> bool {
+        self.shared.is_slot_bypassed(slot.0)
     }
 
-    fn set_bypassed(&self, _slot: SlotIndex, bypassed: bool) {
-        self.shared.set_bypassed(bypassed);
+    /// Onboard effect slots record the toggle as a one-step gesture on
+    /// their `Bypass` parameter, so hosts can capture it as automation.
+    fn set_bypassed(&self, slot: SlotIndex, bypassed: bool) {
+        match self.shared.slot_bypass_param(slot.0) {
+            Some(i) => {
+                self.shared.gesture_begin(i);
+                self.shared.set_slot_bypassed(slot.0, bypassed);
+                self.shared.gesture_end(i);
+                self.shared.notify_host();
+            }
+            None => self.shared.set_slot_bypassed(slot.0, bypassed),
+        }
     }
 
-    fn begin_set(&self, _slot: SlotIndex, param: ParamIndex) {
-        self.shared.gesture_begin(param.0);
-        self.shared.notify_host();
+    fn begin_set(&self, slot: SlotIndex, param: ParamIndex) {
+        if let Some(i) = self.flat_index(slot, param) {
+            self.shared.gesture_begin(i);
+            self.shared.notify_host();
+        }
     }
 
-    fn end_set(&self, _slot: SlotIndex, param: ParamIndex) {
-        self.shared.gesture_end(param.0);
-        self.shared.notify_host();
+    fn end_set(&self, slot: SlotIndex, param: ParamIndex) {
+        if let Some(i) = self.flat_index(slot, param) {
+            self.shared.gesture_end(i);
+            self.shared.notify_host();
+        }
     }
 }
 
@@ -194,7 +287,11 @@ impl SonidoEditor {
     /// baseview handler applies it on the next `on_frame()`.
     ///
     /// Returns `None` if no UI panel is registered for the effect's ID
-    /// (should not happen for the 19 built-in effects).
+    /// (should not happen for the 19 built-in effects or the synth).
+    ///
+    /// Instruments render one panel per slot: the synth first, then each
+    /// onboard effect under a header with an on/off toggle, a color label,
+    /// and the slot's name (double-click to rename).
     pub fn open(
         parent_rwh: RawWindowHandle,
         shared: SonidoShared,
@@ -202,17 +299,34 @@ impl SonidoEditor {
         pending_resize: Arc<PendingResize>,
     ) -> Option<Self> {
         let effect_id = shared.effect_id().to_owned();
-        let panel: Box<dyn EffectPanel + Send + Sync> = create_panel(&effect_id)?;
-        let bridge = Arc::new(PluginParamBridge::new(shared));
+        let panels = (0..shared.slot_count())
+            .map(|slot| shared.slot_effect_id(slot).and_then(create_panel))
+            .collect::<Option<Vec<Box<dyn EffectPanel + Send + Sync>>>>()?;
+        let bridge = Arc::new(PluginParamBridge::new(shared.clone()));
 
         let (width, height) = pending_resize.get();
 
         struct GuiState {
             bridge: Arc<PluginParamBridge>,
-            panel: Box<dyn EffectPanel + Send + Sync>,
+            panels: Vec<Box<dyn EffectPanel + Send + Sync>>,
+            shared: SonidoShared,
+            pending_resize: Arc<PendingResize>,
+            scale: f64,
+            /// Last failed paste and its egui timestamp.
+            paste_error: Option<(PasteError, f64)>,
+            /// Slot being renamed and the edited text.
+            renaming: Option<(usize, String)>,
         }
 
-        let state = GuiState { bridge, panel };
+        let state = GuiState {
+            bridge,
+            panels,
+            shared,
+            pending_resize: Arc::clone(&pending_resize),
+            scale,
+            paste_error: None,
+            renaming: None,
+        };
 
         let window = egui_bridge::open_parented(
             &ParentWindow(parent_rwh),
@@ -222,18 +336,62 @@ impl SonidoEditor {
             scale,
             pending_resize,
             state,
-            // build: one-time setup (fonts, theme — none needed, gui-core theme applies)
-            |_ctx, _state| {},
+            // build: one-time setup. Apply the saved zoom up front so the
+            // first frame isn't drawn at 100%.
+            |ctx, state| ctx.set_zoom_factor(state.shared.ui_zoom()),
             // update: called each frame by the bridge's render loop
             |ctx, state| {
                 // Poll for host-originated parameter changes at 30 Hz.
                 // Full vsync repaint is wasteful; 33ms latency is imperceptible
                 // for knob positions updating from automation.
                 ctx.request_repaint_after(std::time::Duration::from_millis(33));
+                ctx.set_zoom_factor(state.shared.ui_zoom());
                 egui::CentralPanel::default().show(ctx, |ui| {
-                    state
-                        .panel
-                        .ui(ui, state.bridge.as_ref() as &dyn ParamBridge, SlotIndex(0));
+                    let bridge = state.bridge.as_ref() as &dyn ParamBridge;
+                    let now = ctx.input(|i| i.time);
+                    ui.horizontal(|ui| {
+                        clipboard_buttons(ui, bridge, SlotIndex(0), &mut state.paste_error);
+                        if let Some(zoom) = zoom_menu(ui, state.shared.ui_zoom()) {
+                            apply_zoom(&state.shared, &state.pending_resize, state.scale, zoom);
+                        }
+                    });
+                    if let Some((error, since)) = &state.paste_error {
+                        if now - since < PASTE_ERROR_SECS {
+                            ui.colored_label(ui.visuals().error_fg_color, error.to_string());
+                        } else {
+                            state.paste_error = None;
+                        }
+                    }
+                    if let [panel] = state.panels.as_mut_slice() {
+                        panel.ui(ui, bridge, SlotIndex(0));
+                        return;
+                    }
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        for (slot, panel) in state.panels.iter_mut().enumerate() {
+                            if slot == 0 {
+                                panel.ui(ui, bridge, SlotIndex(0));
+                                continue;
+                            }
+                            ui.separator();
+                            ui.horizontal(|ui| {
+                                let mut active = !bridge.is_bypassed(SlotIndex(slot));
+                                if ui.add(BypassToggle::new(&mut active, "")).changed() {
+                                    bridge.set_bypassed(SlotIndex(slot), !active);
+                                }
+                                slot_color_menu(ui, &state.shared, slot);
+                                slot_name(ui, &state.shared, slot, &mut state.renaming);
+                                clipboard_buttons(
+                                    ui,
+                                    bridge,
+                                    SlotIndex(slot),
+                                    &mut state.paste_error,
+                                );
+                            });
+                            ui.add_enabled_ui(!bridge.is_bypassed(SlotIndex(slot)), |ui| {
+                                panel.ui(ui, bridge, SlotIndex(slot));
+                            });
+                        }
+                    });
                 });
             },
         );
@@ -241,3 +399,171 @@ impl SonidoEditor {
         Some(Self { _window: window })
     }
 }
+
+/// Seconds a paste error stays on screen.
+const PASTE_ERROR_SECS: f64 = 3.0;
+
+/// Copy/Paste of one slot's settings. Copy goes out through the egui bridge;
+/// paste reads the system clipboard directly. A failed paste is stored in
+/// `error` with its timestamp.
+fn clipboard_buttons(
+    ui: &mut egui::Ui,
+    bridge: &dyn ParamBridge,
+    slot: SlotIndex,
+    error: &mut Option<(PasteError, f64)>,
+) {
+    let read = || {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .ok()
+    };
+    match clipboard::copy_paste_buttons(ui, bridge, slot, read) {
+        Some(Ok(_)) => *error = None,
+        Some(Err(e)) => *error = Some((e, ui.input(|i| i.time))),
+        None => {}
+    }
+}
+
+/// Swatch for a slot color label (hollow when unset).
+fn color_swatch(color: Option<SlotColor>) -> egui::RichText {
+    match color {
+        Some(color) => {
+            let [r, g, b] = color.rgb();
+            egui::RichText::new("●").color(egui::Color32::from_rgb(r, g, b))
+        }
+        None => egui::RichText::new("○"),
+    }
+}
+
+/// Color label picker for `slot`. Colors are not parameters, so the choice
+/// is written straight to the shared state and saved with the plugin state.
+fn slot_color_menu(ui: &mut egui::Ui, shared: &SonidoShared, slot: usize) {
+    let current = shared.slot_color(slot);
+    egui::ComboBox::from_id_salt(("sonido_slot_color", slot))
+        .width(28.0)
+        .selected_text(color_swatch(current))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(current.is_none(), "○ None").clicked() {
+                shared.set_slot_color(slot, None);
+            }
+            for color in SlotColor::ALL {
+                let [r, g, b] = color.rgb();
+                let text = egui::RichText::new(format!("● {color:?}"))
+                    .color(egui::Color32::from_rgb(r, g, b));
+                if ui.selectable_label(current == Some(color), text).clicked() {
+                    shared.set_slot_color(slot, Some(color));
+                }
+            }
+        })
+        .response
+        .on_hover_text("Slot color");
+}
+
+/// Name of `slot`, renamed by double-clicking it. Enter or clicking away
+/// keeps the edit, Escape cancels it, and an empty name restores the
+/// effect's name.
+fn slot_name(
+    ui: &mut egui::Ui,
+    shared: &SonidoShared,
+    slot: usize,
+    renaming: &mut Option<(usize, String)>,
+) {
+    match renaming {
+        Some((editing, text)) if *editing == slot => {
+            let response = ui.add(
+                egui::TextEdit::singleline(text)
+                    .desired_width(140.0)
+                    .char_limit(MAX_SLOT_LABEL_CHARS),
+            );
+            if response.lost_focus() {
+                let cancelled = ui.input(|i| i.key_pressed(egui::Key::Escape));
+                if !cancelled && text.trim() != shared.slot_display_name(slot) {
+                    shared.set_slot_label(slot, Some(text));
+                }
+                *renaming = None;
+            } else if !response.has_focus() {
+                // First frame of the edit.
+                response.request_focus();
+            }
+        }
+        _ => {
+            let name = egui::RichText::new(shared.slot_display_name(slot)).strong();
+            let response = ui
+                .add(egui::Label::new(name).sense(egui::Sense::click()))
+                .on_hover_text("Double-click to rename");
+            if response.double_clicked() {
+                *renaming = Some((slot, shared.slot_display_name(slot)));
+            }
+        }
+    }
+}
+
+/// Right-aligned zoom selector. Returns the newly picked zoom, if any.
+fn zoom_menu(ui: &mut egui::Ui, current: f32) -> Option<f32> {
+    let label = |zoom: f32| format!("{:.0}%", zoom * 100.0);
+    let mut picked = None;
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        egui::ComboBox::from_id_salt("sonido_ui_zoom")
+            .width(56.0)
+            .selected_text(label(current))
+            .show_ui(ui, |ui| {
+                for zoom in ZOOM_LEVELS {
+                    if ui.selectable_label(zoom == current, label(zoom)).clicked() {
+                        picked = Some(zoom);
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Editor zoom");
+    });
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_pixel_roundtrip() {
+        assert_eq!(to_host_pixels(480, 2.0), 960);
+        assert_eq!(from_host_pixels(960, 2.0), 480);
+        assert_eq!(to_host_pixels(480, 1.25), 600);
+        assert_eq!(from_host_pixels(to_host_pixels(381, 1.5), 1.5), 381);
+    }
+
+    #[test]
+    fn bounds_scale_with_zoom() {
+        assert_eq!(clamp_size(0, 0, 1.0), (MIN_WIDTH, MIN_HEIGHT));
+        assert_eq!(clamp_size(0, 0, 2.0), (MIN_WIDTH * 2, MIN_HEIGHT * 2));
+        assert_eq!(
+            clamp_size(u32::MAX, u32::MAX, 2.0),
+            (MAX_WIDTH * 2, MAX_HEIGHT * 2)
+        );
+    }
+
+    #[test]
+    fn rezoom_keeps_panel_space() {
+        assert_eq!(
+            rezoom_size((PLUGIN_WIDTH, PLUGIN_HEIGHT), 1.0, 1.5),
+            (720, 570)
+        );
+        assert_eq!(
+            rezoom_size((720, 570), 1.5, 1.0),
+            (PLUGIN_WIDTH, PLUGIN_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn apply_zoom_resizes_and_persists() {
+        let shared = SonidoShared::new("distortion", None);
+        let pending = PendingResize::new(PLUGIN_WIDTH, PLUGIN_HEIGHT);
+
+        apply_zoom(&shared, &pending, 1.0, 2.0);
+        assert_eq!(shared.ui_zoom(), 2.0);
+        assert_eq!(pending.get(), (PLUGIN_WIDTH * 2, PLUGIN_HEIGHT * 2));
+
+        // Same zoom again is a no-op.
+        apply_zoom(&shared, &pending, 1.0, 2.0);
+        assert_eq!(pending.get(), (PLUGIN_WIDTH * 2, PLUGIN_HEIGHT * 2));
+    }
+}
@@ -0,0 +1,339 @@
+//! Audio processor for the sonido synth CLAP instrument.
+//!
+//! Renders [`SynthNode`] from incoming note events, then runs the optional
+//! onboard effect slots over the synth output. Note events are applied
+//! sample-accurately: the block is rendered in sub-blocks split at each
+//! event's timestamp.
+//!
+//! Both CLAP note events and raw MIDI note on/off messages are accepted, so
+//! hosts that only speak the MIDI dialect still drive the synth.
+
+use crate::bypass::BypassFade;
+use crate::gesture::{GestureSync, push_gesture};
+use crate::main_thread::SonidoMainThread;
+use crate::ramp::ParamRamps;
+use crate::shared::SonidoShared;
+use clack_extensions::params::PluginAudioProcessorParams;
+use clack_plugin::events::EventFlags;
+use clack_plugin::events::event_types::ParamValueEvent;
+use clack_plugin::events::spaces::CoreEventSpace;
+use clack_plugin::events::{Match, UnknownEvent};
+use clack_plugin::prelude::*;
+use clack_plugin::utils::Cookie;
+use sonido_core::Effect;
+use sonido_core::panic::PanicFade;
+use sonido_registry::{EffectRegistry, EffectWithParams};
+use sonido_synth::SynthNode;
+
+/// MIDI status nibble for note-off.
+const MIDI_NOTE_OFF: u8 = 0x80;
+/// MIDI status nibble for note-on.
+const MIDI_NOTE_ON: u8 = 0x90;
+/// MIDI status nibble for control change.
+const MIDI_CONTROL_CHANGE: u8 = 0xB0;
+/// MIDI CC 120: All Sound Off — triggers a panic reset.
+const MIDI_CC_ALL_SOUND_OFF: u8 = 120;
+/// MIDI CC 123: All Notes Off.
+const MIDI_CC_ALL_NOTES_OFF: u8 = 123;
+
+/// Audio-thread processor for the synth instrument.
+///
+/// Created during `activate()`, destroyed during `deactivate()`. Slot 0 of
+/// the shared parameter list drives the synth; slots 1.. drive `fx`.
+pub struct SonidoInstrumentProcessor<'a> {
+    shared: &'a SonidoShared,
+    synth: SynthNode,
+    /// Smooths synth parameter changes, which the synth applies immediately.
+    synth_ramps: ParamRamps,
+    /// Onboard effects, one per shared slot after the synth.
+    fx: Vec<Box<dyn EffectWithParams + Send>>,
+    /// Bypass crossfade per onboard effect, driven by its `Bypass` parameter.
+    fx_bypass: Vec<BypassFade>,
+    /// Render scratch (left), sized to the host's maximum block length.
+    left: Vec<f32>,
+    /// Render scratch (right), sized to the host's maximum block length.
+    right: Vec<f32>,
+    /// Panic fade driven by MIDI CC 120 (All Sound Off).
+    panic: PanicFade,
+    /// Orders GUI gesture events sent to the host.
+    gestures: GestureSync,
+}
+
+impl<'a> PluginAudioProcessor<'a, SonidoShared, SonidoMainThread<'a>>
+    for SonidoInstrumentProcessor<'a>
+{
+    fn activate(
+        _host: HostAudioProcessorHandle<'a>,
+        _main_thread: &mut SonidoMainThread<'a>,
+        shared: &'a SonidoShared,
+        audio_config: PluginAudioConfiguration,
+    ) -> Result<Self, PluginError> {
+        let sample_rate = audio_config.sample_rate as f32;
+        let mut synth = SynthNode::new(sample_rate);
+        shared.apply_to_slot(0, &mut synth);
+        let synth_ramps = ParamRamps::new(&synth, sample_rate);
+
+        let max_frames = audio_config.max_frames_count as usize;
+        let registry = EffectRegistry::new();
+        let mut fx = Vec::with_capacity(shared.slot_count().saturating_sub(1));
+        let mut fx_bypass = Vec::with_capacity(fx.capacity());
+        for slot in 1..shared.slot_count() {
+            let id = shared
+                .slot_effect_id(slot)
+                .ok_or(PluginError::Message("Missing effect slot"))?;
+            let mut effect = registry
+                .create(id, sample_rate)
+                .ok_or(PluginError::Message("Failed to create effect"))?;
+            shared.apply_to_slot(slot, effect.as_mut());
+            fx.push(effect);
+            fx_bypass.push(BypassFade::new(
+                shared.is_slot_bypassed(slot),
+                sample_rate,
+                max_frames,
+            ));
+        }
+
+        Ok(Self {
+            shared,
+            synth,
+            synth_ramps,
+            fx,
+            fx_bypass,
+            left: vec![0.0; max_frames],
+            right: vec![0.0; max_frames],
+            panic: PanicFade::new(sample_rate),
+            gestures: GestureSync::new(shared.param_count()),
+        })
+    }
+
+    fn process(
+        &mut self,
+        _process: Process,
+        mut audio: Audio,
+        events: Events,
+    ) -> Result<ProcessStatus, PluginError> {
+        // Sync GUI-originated parameter changes before rendering.
+        self.sync_gui_changes(events.output);
+
+        let frames = (audio.frames_count() as usize).min(self.left.len());
+
+        // A panic requested in an earlier block has faded out: clear all state.
+        if self.panic.take_reset() {
+            self.reset();
+        }
+
+        // Render the synth in sub-blocks split at event timestamps.
+        for batch in events.input.batch() {
+            for event in batch.events() {
+                self.handle_event(event);
+            }
+            let bounds = batch.sample_bounds();
+            let start = bounds.start.min(frames);
+            let end = bounds.end.min(frames);
+            self.render_synth(start, end);
+        }
+
+        // Onboard effects run over the whole block in slot order,
+        // crossfading in and out as their bypass changes.
+        for (effect, bypass) in self.fx.iter_mut().zip(&mut self.fx_bypass) {
+            bypass.process(
+                &mut self.left[..frames],
+                &mut self.right[..frames],
+                |left, right| effect.process_block_stereo_inplace(left, right),
+            );
+        }
+
+        self.panic
+            .apply(&mut self.left[..frames], &mut self.right[..frames]);
+        self.write_output(&mut audio, frames)?;
+
+        if self.synth.active_voice_count() > 0 || self.panic.is_active() {
+            Ok(ProcessStatus::Continue)
+        } else {
+            Ok(ProcessStatus::ContinueIfNotQuiet)
+        }
+    }
+
+    fn deactivate(self, _main_thread: &mut SonidoMainThread<'_>) {
+        // Synth and effects are dropped here, releasing all DSP state.
+    }
+
+    fn reset(&mut self) {
+        self.synth_ramps.snap(&mut self.synth);
+        self.synth.reset();
+        for effect in &mut self.fx {
+            effect.reset();
+        }
+        for bypass in &mut self.fx_bypass {
+            bypass.snap();
+        }
+    }
+}
+
+impl SonidoInstrumentProcessor<'_> {
+    /// Render synth output into the scratch buffers for `start..end`.
+    fn render_synth(&mut self, start: usize, end: usize) {
+        if self.shared.is_bypassed() {
+            self.left[start..end].fill(0.0);
+            self.right[start..end].fill(0.0);
+            return;
+        }
+        for i in start..end {
+            self.synth_ramps.advance(&mut self.synth);
+            let (l, r) = self.synth.process_stereo(0.0, 0.0);
+            self.left[i] = l;
+            self.right[i] = r;
+        }
+    }
+
+    /// Copy the rendered scratch buffers into the host's main output port.
+    fn write_output(&self, audio: &mut Audio, frames: usize) -> Result<(), PluginError> {
+        let Some(mut port) = audio.output_port(0) else {
+            return Ok(());
+        };
+        let Some(mut channels) = port.channels()?.into_f32() else {
+            return Ok(());
+        };
+
+        let channel_count = channels.channel_count();
+        for ch in 0..channel_count {
+            let src = if ch % 2 == 0 { &self.left } else { &self.right };
+            if let Some(out) = channels.channel_mut(ch) {
+                let n = frames.min(out.len());
+                out[..n].copy_from_slice(&src[..n]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Dispatch one input event: notes, MIDI, or parameter changes.
+    fn handle_event(&mut self, event: &UnknownEvent) {
+        match event.as_core_event() {
+            Some(CoreEventSpace::NoteOn(ev)) => {
+                if let Match::Specific(key) = ev.key() {
+                    let velocity = (ev.velocity() * 127.0).round().clamp(1.0, 127.0) as u8;
+                    self.synth.note_on(key.min(127) as u8, velocity);
+                }
+            }
+            Some(CoreEventSpace::NoteOff(ev)) => match ev.key() {
+                Match::Specific(key) => self.synth.note_off(key.min(127) as u8),
+                Match::All => self.synth.all_notes_off(),
+            },
+            Some(CoreEventSpace::NoteChoke(_)) => self.synth.all_notes_off(),
+            Some(CoreEventSpace::Midi(ev)) => self.handle_midi(ev.data()),
+            Some(CoreEventSpace::ParamValue(ev)) => {
+                if let Some(param_id) = ev.param_id()
+                    && let Some(index) = self.shared.index_by_id(param_id.get())
+                    && let Some(value) = self.shared.set_value(index, ev.value() as f32)
+                {
+                    self.set_effect_param(index, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a raw 3-byte MIDI message (note on/off, all-notes-off,
+    /// all-sound-off).
+    ///
+    /// All Sound Off (CC 120) is the MIDI panic: the output fades out, the
+    /// synth and every onboard effect are reset, and the output fades back in.
+    fn handle_midi(&mut self, data: [u8; 3]) {
+        let [status, data1, data2] = data;
+        match status & 0xF0 {
+            // Note-on with velocity 0 is a note-off by MIDI convention.
+            MIDI_NOTE_ON if data2 > 0 => self.synth.note_on(data1 & 0x7F, data2 & 0x7F),
+            MIDI_NOTE_ON | MIDI_NOTE_OFF => self.synth.note_off(data1 & 0x7F),
+            MIDI_CONTROL_CHANGE if data1 == MIDI_CC_ALL_NOTES_OFF => self.synth.all_notes_off(),
+            MIDI_CONTROL_CHANGE if data1 == MIDI_CC_ALL_SOUND_OFF => self.panic.trigger(),
+            _ => {}
+        }
+    }
+
+    /// Route a flat parameter index to the synth or the owning effect slot.
+    ///
+    /// Synth values glide through `synth_ramps` at each parameter's
+    /// descriptor smoothing; effect slots smooth internally. A slot's
+    /// `Bypass` parameter starts its crossfade.
+    fn set_effect_param(&mut self, index: usize, value: f32) {
+        match self.shared.locate(index) {
+            Some((0, local)) => self.synth_ramps.set_target(local, value),
+            Some((slot, local)) => {
+                if let Some(effect) = self.fx.get_mut(slot - 1) {
+                    effect.effect_set_param(local, value);
+                }
+            }
+            None => {
+                if let Some(bypass) = self
+                    .shared
+                    .bypass_slot(index)
+                    .and_then(|slot| self.fx_bypass.get_mut(slot - 1))
+                {
+                    bypass.set_bypassed(value >= 0.5);
+                }
+            }
+        }
+    }
+
+    /// Read a flat parameter index from the synth or the owning effect slot.
+    ///
+    /// Synth parameters report their ramp target, matching what effect slots
+    /// return while smoothing; `Bypass` parameters report the fade target.
+    fn effect_param(&self, index: usize) -> Option<f32> {
+        match self.shared.locate(index) {
+            Some((0, local)) => self.synth_ramps.target(local),
+            Some((slot, local)) => self.fx.get(slot - 1).map(|e| e.effect_get_param(local)),
+            None => {
+                let slot = self.shared.bypass_slot(index)?;
+                let bypass = self.fx_bypass.get(slot - 1)?;
+                Some(if bypass.is_bypassed() { 1.0 } else { 0.0 })
+            }
+        }
+    }
+
+    /// Detect GUI-originated parameter changes and sync them to the DSP and
+    /// host. Mirrors `SonidoAudioProcessor::sync_gui_changes` across slots.
+    fn sync_gui_changes(&mut self, output: &mut OutputEvents) {
+        let shared = self.shared;
+        self.gestures.open(
+            |i| shared.take_gesture_flags(i),
+            |i, edge| push_gesture(output, shared, i, edge),
+        );
+
+        for i in 0..shared.param_count() {
+            let Some(id) = shared.descriptor(i).map(|d| ClapId::new(d.id.0)) else {
+                continue;
+            };
+            if let Some(shared_val) = shared.get_value(i)
+                && self
+                    .effect_param(i)
+                    .is_some_and(|v| v.to_bits() != shared_val.to_bits())
+            {
+                self.set_effect_param(i, shared_val);
+                let event = ParamValueEvent::new(
+                    0,
+                    id,
+                    Pckn::match_all(),
+                    f64::from(shared_val),
+                    Cookie::empty(),
+                )
+                .with_flags(EventFlags::IS_LIVE);
+                let _ = output.try_push(event);
+            }
+        }
+
+        self.gestures
+            .close(|i, edge| push_gesture(output, shared, i, edge));
+    }
+}
+
+impl PluginAudioProcessorParams for SonidoInstrumentProcessor<'_> {
+    fn flush(&mut self, input: &InputEvents, output: &mut OutputEvents) {
+        for event in input {
+            if let Some(CoreEventSpace::ParamValue(_)) = event.as_core_event() {
+                self.handle_event(event);
+            }
+        }
+        self.sync_gui_changes(output);
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This crate bridges sonido's `Effect` + `ParameterInfo` traits to the CLAP
 //! plugin format via the `clack-plugin` safe wrapper. Each effect becomes an
-//! independent `.clap` plugin binary.
+//! independent `.clap` plugin binary. The `sonido-synth` instrument wraps
+//! `sonido_synth::SynthNode` with note input and optional onboard effects.
 //!
 //! # Architecture
 //!
@@ -31,21 +32,40 @@
 //!     features: [AUDIO_EFFECT, DISTORTION, STEREO],
 //! }
 //! ```
+//!
+//! Instruments use [`sonido_instrument_entry!`] instead, listing the onboard
+//! effect slots that follow the synth.
+//!
+//! # Presets
+//!
+//! Both macros export a [`SonidoEntry`], which adds a CLAP preset-discovery
+//! factory next to the plugin factory, and register the preset-load
+//! extension. Hosts can then browse and load `sonido-config` factory, user,
+//! and system presets that contain the plugin's effects.
 
 pub mod audio;
+mod bypass;
 mod egui_bridge;
+mod gesture;
 pub mod gui;
+pub mod instrument;
 pub mod main_thread;
+pub mod preset_discovery;
+pub mod presets;
+mod ramp;
 pub mod shared;
 
 pub use audio::SonidoAudioProcessor;
+pub use instrument::SonidoInstrumentProcessor;
 pub use main_thread::SonidoMainThread;
+pub use preset_discovery::{SonidoEntry, SonidoPresetTarget};
 pub use shared::SonidoShared;
 
 /// Generate a complete CLAP plugin entry point for a sonido effect.
 ///
-/// This macro creates a zero-sized plugin type, implements `Plugin` and
-/// `DefaultPluginFactory`, and exports the `clap_entry` symbol.
+/// This macro creates a zero-sized plugin type, implements `Plugin`,
+/// `DefaultPluginFactory`, and [`SonidoPresetTarget`], and exports the
+/// `clap_entry` symbol as a [`SonidoEntry`].
 ///
 /// # Arguments
 ///
@@ -87,6 +107,7 @@ macro_rules! sonido_effect_entry {
                 use ::clack_extensions::gui::PluginGui;
                 use ::clack_extensions::latency::PluginLatency;
                 use ::clack_extensions::params::PluginParams;
+                use ::clack_extensions::preset_load::PluginPresetLoad;
                 use ::clack_extensions::state::PluginState;
                 use ::clack_extensions::tail::PluginTail;
                 use ::clack_extensions::track_info::PluginTrackInfo;
@@ -95,6 +116,7 @@ macro_rules! sonido_effect_entry {
                 builder.register::<PluginGui>();
                 builder.register::<PluginLatency>();
                 builder.register::<PluginParams>();
+                builder.register::<PluginPresetLoad>();
                 builder.register::<PluginState>();
                 builder.register::<PluginTail>();
                 builder.register::<PluginTrackInfo>();
@@ -121,19 +143,139 @@ macro_rules! sonido_effect_entry {
 
                 let notify: Box<dyn Fn() + Send + Sync> =
                     Box::new(move || { host.request_process(); });
-                Ok($crate::SonidoShared::new($effect_id, Some(notify)))
+                let resize: Box<dyn Fn(u32, u32) -> bool + Send + Sync> =
+                    Box::new(move |width, height| {
+                        let mut host = host;
+                        host.get_extension::<::clack_extensions::gui::HostGui>()
+                            .is_some_and(|gui| gui.request_resize(&mut host, width, height).is_ok())
+                    });
+                Ok($crate::SonidoShared::new($effect_id, Some(notify)).with_host_resize(resize))
             }
 
             fn new_main_thread<'a>(
-                _host: ::clack_plugin::prelude::HostMainThreadHandle<'a>,
+                host: ::clack_plugin::prelude::HostMainThreadHandle<'a>,
                 shared: &'a $crate::SonidoShared,
             ) -> Result<$crate::SonidoMainThread<'a>, ::clack_plugin::prelude::PluginError> {
-                Ok($crate::SonidoMainThread::new(shared))
+                Ok($crate::SonidoMainThread::new(host, shared))
             }
         }
 
-        ::clack_plugin::clack_export_entry!(
-            ::clack_plugin::prelude::SinglePluginEntry<SonidoPlugin>
-        );
+        impl $crate::SonidoPresetTarget for SonidoPlugin {
+            const SLOT_EFFECT_IDS: &'static [&'static str] = &[$effect_id];
+        }
+
+        ::clack_plugin::clack_export_entry!($crate::SonidoEntry<SonidoPlugin>);
+    };
+}
+
+/// Generate a complete CLAP plugin entry point for the sonido synth instrument.
+///
+/// Like [`sonido_effect_entry!`], but the plugin takes note input (CLAP and
+/// MIDI dialects), has no audio input port, and renders
+/// `sonido_synth::SynthNode` followed by the listed onboard effect slots.
+/// Effect slots start bypassed. Each has a host-automatable `Bypass`
+/// parameter, also toggled from the editor, that crossfades the slot in or out.
+///
+/// # Arguments
+///
+/// - `fx` — Registry IDs of the onboard effect slots, in processing order
+///   (an effect may repeat; later instances get slot-scoped parameter IDs)
+/// - `clap_id` — Reverse-DNS plugin identifier
+/// - `name` — Human-readable plugin name
+/// - `features` — CLAP feature tags (from `clack_plugin::plugin::features`)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// sonido_instrument_entry! {
+///     fx: ["chorus", "delay", "reverb"],
+///     clap_id: "com.sonido.synth",
+///     name: "Sonido Synth",
+///     features: [INSTRUMENT, SYNTHESIZER, STEREO],
+/// }
+/// ```
+#[macro_export]
+macro_rules! sonido_instrument_entry {
+    (
+        fx: [$($fx_id:literal),* $(,)?],
+        clap_id: $clap_id:literal,
+        name: $name:literal,
+        features: [$($feature:ident),+ $(,)?] $(,)?
+    ) => {
+        struct SonidoPlugin;
+
+        impl ::clack_plugin::prelude::Plugin for SonidoPlugin {
+            type AudioProcessor<'a> = $crate::SonidoInstrumentProcessor<'a>;
+            type Shared<'a> = $crate::SonidoShared;
+            type MainThread<'a> = $crate::SonidoMainThread<'a>;
+
+            fn declare_extensions(
+                builder: &mut ::clack_plugin::prelude::PluginExtensions<Self>,
+                _shared: Option<&$crate::SonidoShared>,
+            ) {
+                use ::clack_extensions::audio_ports::PluginAudioPorts;
+                use ::clack_extensions::gui::PluginGui;
+                use ::clack_extensions::note_ports::PluginNotePorts;
+                use ::clack_extensions::params::PluginParams;
+                use ::clack_extensions::preset_load::PluginPresetLoad;
+                use ::clack_extensions::state::PluginState;
+                use ::clack_extensions::track_info::PluginTrackInfo;
+
+                builder.register::<PluginAudioPorts>();
+                builder.register::<PluginGui>();
+                builder.register::<PluginNotePorts>();
+                builder.register::<PluginParams>();
+                builder.register::<PluginPresetLoad>();
+                builder.register::<PluginState>();
+                builder.register::<PluginTrackInfo>();
+            }
+        }
+
+        impl ::clack_plugin::prelude::DefaultPluginFactory for SonidoPlugin {
+            fn get_descriptor() -> ::clack_plugin::prelude::PluginDescriptor {
+                use ::clack_plugin::plugin::features::*;
+                ::clack_plugin::prelude::PluginDescriptor::new($clap_id, $name)
+                    .with_features([$($feature),+])
+            }
+
+            fn new_shared(
+                host: ::clack_plugin::prelude::HostSharedHandle<'_>,
+            ) -> Result<$crate::SonidoShared, ::clack_plugin::prelude::PluginError> {
+                // See `sonido_effect_entry!`: the host outlives the plugin, and
+                // the handle's lifetime is purely phantom.
+                let host: ::clack_plugin::prelude::HostSharedHandle<'static> =
+                    unsafe { ::core::mem::transmute(host) };
+
+                ::tracing::info!(clap_id = $clap_id, "instrument instance created");
+
+                let notify: Box<dyn Fn() + Send + Sync> =
+                    Box::new(move || { host.request_process(); });
+                let resize: Box<dyn Fn(u32, u32) -> bool + Send + Sync> =
+                    Box::new(move |width, height| {
+                        let mut host = host;
+                        host.get_extension::<::clack_extensions::gui::HostGui>()
+                            .is_some_and(|gui| gui.request_resize(&mut host, width, height).is_ok())
+                    });
+                let callback: Box<dyn Fn() + Send + Sync> =
+                    Box::new(move || { host.request_callback(); });
+                Ok($crate::SonidoShared::new_instrument(&[$($fx_id),*], Some(notify))
+                    .with_host_resize(resize)
+                    .with_host_callback(callback))
+            }
+
+            fn new_main_thread<'a>(
+                host: ::clack_plugin::prelude::HostMainThreadHandle<'a>,
+                shared: &'a $crate::SonidoShared,
+            ) -> Result<$crate::SonidoMainThread<'a>, ::clack_plugin::prelude::PluginError> {
+                Ok($crate::SonidoMainThread::new(host, shared))
+            }
+        }
+
+        impl $crate::SonidoPresetTarget for SonidoPlugin {
+            const SLOT_EFFECT_IDS: &'static [&'static str] =
+                &[$crate::shared::SYNTH_EFFECT_ID, $($fx_id),*];
+        }
+
+        ::clack_plugin::clack_export_entry!($crate::SonidoEntry<SonidoPlugin>);
     };
 }
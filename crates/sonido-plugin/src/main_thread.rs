@@ -1,7 +1,7 @@
 //! Main-thread plugin implementation for sonido CLAP plugins.
 //!
-//! Handles parameter metadata queries, state save/restore, and audio port
-//! configuration. All methods run on the host's main thread — never on the
+//! Handles parameter metadata queries, state save/restore, preset loading,
+//! and audio port configuration. All methods run on the host's main thread — never on the
 //! audio thread.
 
 use std::io::{Read, Write};
@@ -15,31 +15,40 @@ use clack_extensions::gui::{
     Window,
 };
 use clack_extensions::latency::PluginLatencyImpl;
+use clack_extensions::note_ports::{
+    NoteDialect, NoteDialects, NotePortInfo, NotePortInfoWriter, PluginNotePortsImpl,
+};
 use clack_extensions::params::{
-    ParamDisplayWriter, ParamInfo, ParamInfoFlags, ParamInfoWriter, PluginMainThreadParams,
+    HostParams, ParamDisplayWriter, ParamInfo, ParamInfoFlags, ParamInfoWriter, ParamRescanFlags,
+    PluginMainThreadParams,
 };
+use clack_extensions::preset_discovery::Location;
+use clack_extensions::preset_load::PluginPresetLoadImpl;
 use clack_extensions::state::PluginStateImpl;
 use clack_extensions::track_info::PluginTrackInfoImpl;
 use clack_plugin::prelude::*;
 use clack_plugin::stream::{InputStream, OutputStream};
 use clack_plugin::utils::Cookie;
 use raw_window_handle::HasRawWindowHandle;
+use sonido_config::PluginState;
 
 use crate::gui::{
-    MAX_HEIGHT, MAX_WIDTH, MIN_HEIGHT, MIN_WIDTH, PLUGIN_HEIGHT, PLUGIN_WIDTH, PendingResize,
-    SonidoEditor,
+    PLUGIN_HEIGHT, PLUGIN_WIDTH, PendingResize, SonidoEditor, apply_zoom, clamp_size,
+    from_host_pixels, to_host_pixels,
 };
 use crate::shared::SonidoShared;
+use crate::{preset_discovery, presets};
 
 /// Main-thread state for a sonido CLAP plugin.
 ///
 /// Provides parameter metadata to the host (count, info, display formatting),
 /// handles state save/restore, and declares audio port configuration.
 pub struct SonidoMainThread<'a> {
+    host: HostMainThreadHandle<'a>,
     shared: &'a SonidoShared,
     /// Raw window handle from the host, stored between `set_parent` and `show`.
     parent_rwh: Option<raw_window_handle::RawWindowHandle>,
-    /// DPI scale factor from the host (default 1.0).
+    /// DPI scale factor from the host (default 1.0; always 1.0 on macOS).
     scale: f64,
     /// Active editor window (dropped to close).
     editor: Option<SonidoEditor>,
@@ -51,8 +60,9 @@ pub struct SonidoMainThread<'a> {
 
 impl<'a> SonidoMainThread<'a> {
     /// Create a new main-thread handler referencing the shared state.
-    pub fn new(shared: &'a SonidoShared) -> Self {
+    pub fn new(host: HostMainThreadHandle<'a>, shared: &'a SonidoShared) -> Self {
         Self {
+            host,
             shared,
             parent_rwh: None,
             scale: 1.0,
@@ -62,7 +72,55 @@ impl<'a> SonidoMainThread<'a> {
     }
 }
 
-impl<'a> PluginMainThread<'a, SonidoShared> for SonidoMainThread<'a> {}
+impl SonidoMainThread<'_> {
+    /// Open the editor inside the stored parent window.
+    fn open_editor(&mut self) -> Result<(), PluginError> {
+        let rwh = self
+            .parent_rwh
+            .ok_or(PluginError::Message("No parent window"))?;
+        self.editor = SonidoEditor::open(
+            rwh,
+            self.shared.clone(),
+            self.scale,
+            Arc::clone(&self.pending_resize),
+        );
+        if self.editor.is_none() {
+            return Err(PluginError::Message("Failed to open editor window"));
+        }
+        Ok(())
+    }
+
+    /// Clamp a host-pixel size to the editor bounds at the current zoom,
+    /// returning the logical size.
+    fn clamp_host_size(&self, size: GuiSize) -> (u32, u32) {
+        clamp_size(
+            from_host_pixels(size.width, self.scale),
+            from_host_pixels(size.height, self.scale),
+            self.shared.ui_zoom(),
+        )
+    }
+
+    /// Convert a logical size to a host-pixel [`GuiSize`].
+    fn host_size(&self, (width, height): (u32, u32)) -> GuiSize {
+        GuiSize {
+            width: to_host_pixels(width, self.scale),
+            height: to_host_pixels(height, self.scale),
+        }
+    }
+}
+
+impl<'a> PluginMainThread<'a, SonidoShared> for SonidoMainThread<'a> {
+    /// Deliver slot renames: the callback is requested by
+    /// [`SonidoShared::set_slot_label`], and the host rereads parameter info
+    /// to pick up the new module paths.
+    fn on_main_thread(&mut self) {
+        if self.shared.take_labels_changed()
+            && let Some(params) = self.host.get_extension::<HostParams>()
+        {
+            params.rescan(&mut self.host, ParamRescanFlags::INFO);
+        }
+    }
+}
 
 // ── Parameter Extension ─────────────────────────────────────────────────────
 
@@ -99,10 +157,14 @@ impl PluginMainThreadParams for SonidoMainThread<'_> {
             return;
         };
 
+        let module = self
+            .shared
+            .module_path(param_index as usize)
+            .unwrap_or_default();
         info.set(&ParamInfo {
             id: ClapId::new(desc.id.0),
             name: desc.name.as_bytes(),
-            module: desc.group.as_bytes(),
+            module: module.as_bytes(),
             min_value: f64::from(desc.min),
             max_value: f64::from(desc.max),
             default_value: f64::from(desc.default),
@@ -162,7 +224,7 @@ impl PluginMainThreadParams for SonidoMainThread<'_> {
 
 // ── State Extension ─────────────────────────────────────────────────────────
 
-/// State format: JSON object mapping stable ParamId to f64 value.
+/// State format: a [`PluginState`] JSON object mapping stable ParamId to value.
 ///
 /// ```json
 /// {"200": 12.0, "201": 0.5, "202": 1.0}
@@ -170,20 +232,41 @@ impl PluginMainThreadParams for SonidoMainThread<'_> {
 ///
 /// Using stable IDs (not indices) ensures state survives parameter reordering
 /// across plugin versions.
+///
+/// The synth instrument additionally stores the bypass state of each onboard
+/// effect slot under `"bypass_<slot>"` keys, and every plugin stores its
+/// editor zoom under `"ui_zoom"`. Renamed and colored slots store
+/// `"label_<slot>"` and `"color_<slot>"`; loading replaces every slot's label
+/// and color, so slots missing from the state go back to their defaults.
+/// Older loaders skip all of these.
+///
+/// Onboard slots' `Bypass` parameters are saved with the other parameters
+/// as well; they are applied after the `"bypass_<slot>"` keys and agree with
+/// them.
 impl PluginStateImpl for SonidoMainThread<'_> {
     fn save(&mut self, output: &mut OutputStream) -> Result<(), PluginError> {
-        let mut state = serde_json::Map::new();
-
-        for (i, desc) in self.shared.descriptors().iter().enumerate() {
-            if let Some(val) = self.shared.get_value(i) {
-                state.insert(
-                    desc.id.0.to_string(),
-                    serde_json::Value::from(f64::from(val)),
-                );
-            }
-        }
+        let state = PluginState {
+            params: self
+                .shared
+                .descriptors()
+                .iter()
+                .enumerate()
+                .filter_map(|(i, desc)| Some((desc.id.0, self.shared.get_value(i)?)))
+                .collect(),
+            bypassed: (1..self.shared.slot_count())
+                .map(|slot| (slot, self.shared.is_slot_bypassed(slot)))
+                .collect(),
+            labels: (0..self.shared.slot_count())
+                .filter_map(|slot| Some((slot, self.shared.slot_label(slot)?)))
+                .collect(),
+            colors: (0..self.shared.slot_count())
+                .filter_map(|slot| Some((slot, self.shared.slot_color(slot)?)))
+                .collect(),
+            ui_zoom: Some(self.shared.ui_zoom()),
+        };
 
-        let json = serde_json::to_vec(&serde_json::Value::Object(state))
+        let json = state
+            .to_json()
             .map_err(|_| PluginError::Message("Failed to serialize state"))?;
 
         output
@@ -199,20 +282,27 @@ impl PluginStateImpl for SonidoMainThread<'_> {
             .read_to_end(&mut buf)
             .map_err(|_| PluginError::Message("Failed to read state"))?;
 
-        let value: serde_json::Value =
-            serde_json::from_slice(&buf).map_err(|_| PluginError::Message("Invalid state JSON"))?;
-
-        let Some(obj) = value.as_object() else {
-            return Err(PluginError::Message("State is not a JSON object"));
-        };
+        let state = PluginState::from_json(&buf).map_err(|e| {
+            tracing::warn!("rejected plugin state: {e}");
+            PluginError::Message("Invalid state")
+        })?;
 
-        for (key, val) in obj {
-            let Ok(id) = key.parse::<u32>() else {
-                continue;
-            };
-            let Some(v) = val.as_f64() else { continue };
+        if let Some(zoom) = state.ui_zoom {
+            apply_zoom(self.shared, &self.pending_resize, self.scale, zoom);
+        }
+        for (slot, bypassed) in state.bypassed {
+            self.shared.set_slot_bypassed(slot, bypassed);
+        }
+        for slot in 0..self.shared.slot_count() {
+            let label = state.labels.iter().find(|(s, _)| *s == slot);
+            self.shared
+                .set_slot_label(slot, label.map(|(_, l)| l.as_str()));
+            let color = state.colors.iter().find(|(s, _)| *s == slot);
+            self.shared.set_slot_color(slot, color.map(|&(_, c)| c));
+        }
+        for (id, value) in state.params {
             if let Some(index) = self.shared.index_by_id(id) {
-                self.shared.set_value(index, v as f32);
+                self.shared.set_value(index, value);
             }
         }
 
@@ -220,6 +310,32 @@ impl PluginStateImpl for SonidoMainThread<'_> {
     }
 }
 
+// ── Preset Load Extension ───────────────────────────────────────────────────
+
+/// Load a preset chosen in the host's preset browser.
+///
+/// Locations come from [`preset_discovery`]: factory presets are keyed by
+/// name at the plugin location, user and system presets are file paths.
+/// Values are written to the shared state; the audio processor picks them
+/// up on its next block and reports them to the host as parameter changes.
+impl PluginPresetLoadImpl for SonidoMainThread<'_> {
+    fn load_from_location(
+        &mut self,
+        location: Location,
+        load_key: Option<&core::ffi::CStr>,
+    ) -> Result<(), PluginError> {
+        let location = preset_discovery::preset_location(location, load_key)
+            .ok_or(PluginError::Message("Unknown preset location"))?;
+        let preset =
+            presets::load(&location).map_err(|_| PluginError::Message("Failed to load preset"))?;
+
+        if presets::apply_preset(self.shared, &preset) == 0 {
+            return Err(PluginError::Message("Preset does not match this plugin"));
+        }
+        Ok(())
+    }
+}
+
 // ── GUI Extension ──────────────────────────────────────────────────────────
 
 impl PluginGuiImpl for SonidoMainThread<'_> {
@@ -245,14 +361,28 @@ impl PluginGuiImpl for SonidoMainThread<'_> {
         self.parent_rwh = None;
     }
 
+    /// Accept the host's DPI scale (Windows, X11). On macOS, sizes are in
+    /// points and the OS handles scaling, so the request is refused as the
+    /// CLAP spec asks. A scale change reopens an open editor.
     fn set_scale(&mut self, scale: f64) -> Result<(), PluginError> {
+        if GuiApiType::default_for_current_platform() == Some(GuiApiType::COCOA) {
+            return Err(PluginError::Message("Scale is handled by the OS"));
+        }
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(PluginError::Message("Invalid scale"));
+        }
+        if scale == self.scale {
+            return Ok(());
+        }
         self.scale = scale;
+        if self.editor.take().is_some() {
+            self.open_editor()?;
+        }
         Ok(())
     }
 
     fn get_size(&mut self) -> Option<GuiSize> {
-        let (width, height) = self.pending_resize.get();
-        Some(GuiSize { width, height })
+        Some(self.host_size(self.pending_resize.get()))
     }
 
     fn can_resize(&mut self) -> bool {
@@ -268,36 +398,21 @@ impl PluginGuiImpl for SonidoMainThread<'_> {
     }
 
     fn adjust_size(&mut self, size: GuiSize) -> Option<GuiSize> {
-        Some(GuiSize {
-            width: size.width.clamp(MIN_WIDTH, MAX_WIDTH),
-            height: size.height.clamp(MIN_HEIGHT, MAX_HEIGHT),
-        })
+        Some(self.host_size(self.clamp_host_size(size)))
     }
 
     fn set_size(&mut self, size: GuiSize) -> Result<(), PluginError> {
-        let width = size.width.clamp(MIN_WIDTH, MAX_WIDTH);
-        let height = size.height.clamp(MIN_HEIGHT, MAX_HEIGHT);
+        let (width, height) = self.clamp_host_size(size);
         self.pending_resize.set(width, height);
         Ok(())
     }
 
     fn set_parent(&mut self, window: Window) -> Result<(), PluginError> {
-        let rwh = window.raw_window_handle();
-        self.parent_rwh = Some(rwh);
+        self.parent_rwh = Some(window.raw_window_handle());
 
         // Bitwig expects the child window to exist after set_parent.
         // Create the editor immediately rather than deferring to show().
-        self.editor = SonidoEditor::open(
-            rwh,
-            self.shared.clone(),
-            self.scale,
-            Arc::clone(&self.pending_resize),
-        );
-
-        if self.editor.is_none() {
-            return Err(PluginError::Message("Failed to open editor window"));
-        }
-        Ok(())
+        self.open_editor()
     }
 
     fn show(&mut self) -> Result<(), PluginError> {
@@ -325,20 +440,67 @@ impl PluginLatencyImpl for SonidoMainThread<'_> {
 
 // ── Audio Ports Extension ───────────────────────────────────────────────────
 
+/// Effects expose one stereo input and output. Compressor and gate add a
+/// second stereo input carrying the external sidechain key. The synth
+/// instrument has no audio input — its signal source is the note port.
 impl PluginAudioPortsImpl for SonidoMainThread<'_> {
-    fn count(&mut self, _is_input: bool) -> u32 {
-        1
+    fn count(&mut self, is_input: bool) -> u32 {
+        if !is_input {
+            1
+        } else if self.shared.is_instrument() {
+            0
+        } else if self.shared.has_key_input() {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn get(&mut self, index: u32, is_input: bool, writer: &mut AudioPortInfoWriter) {
+        match index {
+            0 if !(is_input && self.shared.is_instrument()) => {
+                writer.set(&AudioPortInfo {
+                    id: ClapId::new(0),
+                    name: b"Main",
+                    channel_count: 2,
+                    flags: AudioPortFlags::IS_MAIN,
+                    port_type: Some(AudioPortType::STEREO),
+                    in_place_pair: None,
+                });
+            }
+            1 if is_input && self.shared.has_key_input() => {
+                writer.set(&AudioPortInfo {
+                    id: ClapId::new(1),
+                    name: b"Sidechain",
+                    channel_count: 2,
+                    flags: AudioPortFlags::empty(),
+                    port_type: Some(AudioPortType::STEREO),
+                    in_place_pair: None,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+// ── Note Ports Extension ────────────────────────────────────────────────────
+
+/// One note input for the synth instrument, accepting CLAP and MIDI dialects.
+///
+/// Only registered by [`sonido_instrument_entry!`](crate::sonido_instrument_entry);
+/// effect plugins report no note ports.
+impl PluginNotePortsImpl for SonidoMainThread<'_> {
+    fn count(&mut self, is_input: bool) -> u32 {
+        u32::from(is_input && self.shared.is_instrument())
     }
 
-    fn get(&mut self, index: u32, _is_input: bool, writer: &mut AudioPortInfoWriter) {
-        if index == 0 {
-            writer.set(&AudioPortInfo {
+    fn get(&mut self, index: u32, is_input: bool, writer: &mut NotePortInfoWriter) {
+        if index == 0 && is_input && self.shared.is_instrument() {
+            writer.set(&NotePortInfo {
                 id: ClapId::new(0),
-                name: b"Main",
-                channel_count: 2,
-                flags: AudioPortFlags::IS_MAIN,
-                port_type: Some(AudioPortType::STEREO),
-                in_place_pair: None,
+                name: b"Notes",
+                supported_dialects: NoteDialects::CLAP | NoteDialects::MIDI,
+                preferred_dialect: Some(NoteDialect::Clap),
             });
         }
     }
@@ -0,0 +1,256 @@
+//! CLAP preset-discovery factory and plugin entry.
+//!
+//! Each sonido `.clap` binary exposes two factories: the usual plugin
+//! factory and a preset-discovery factory with one provider. The provider
+//! declares three locations to the host's indexer:
+//!
+//! | Location | CLAP kind | Load key |
+//! |----------|-----------|----------|
+//! | Factory presets | `CLAP_PRESET_DISCOVERY_LOCATION_PLUGIN` | factory preset name |
+//! | User presets dir | `CLAP_PRESET_DISCOVERY_LOCATION_FILE` | none |
+//! | System presets dir | `CLAP_PRESET_DISCOVERY_LOCATION_FILE` | none |
+//!
+//! and reports only presets that contain at least one of the plugin's
+//! effects (see [`crate::presets::is_compatible`]). The host then loads a
+//! chosen preset through the preset-load extension on
+//! [`SonidoMainThread`](crate::SonidoMainThread).
+
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use clack_extensions::preset_discovery::{
+    FileType, Flags, Indexer, Location, LocationInfo, MetadataReceiver, PresetDiscoveryFactoryImpl,
+    PresetDiscoveryFactoryWrapper, ProviderDescriptor, ProviderImpl, ProviderInstance,
+    UniversalPluginId,
+};
+use clack_plugin::entry::{Entry, EntryFactories, EntryLoadError};
+use clack_plugin::factory::plugin::{PluginFactory, PluginFactoryWrapper};
+use clack_plugin::prelude::*;
+
+use crate::presets::{self, PresetEntry, PresetLocation};
+
+/// Preset file extension declared to the host's indexer.
+const PRESET_FILE_EXTENSION: &CStr = c"toml";
+
+/// A sonido plugin type that can be exported through [`SonidoEntry`].
+///
+/// Implemented by [`sonido_effect_entry!`](crate::sonido_effect_entry) and
+/// [`sonido_instrument_entry!`](crate::sonido_instrument_entry).
+pub trait SonidoPresetTarget: DefaultPluginFactory {
+    /// Effect IDs of the plugin's slots, in slot order. Presets are offered
+    /// when they contain at least one of these.
+    const SLOT_EFFECT_IDS: &'static [&'static str];
+}
+
+/// Map a CLAP preset location and load key to a [`PresetLocation`].
+///
+/// Factory presets live at the plugin location and are keyed by their
+/// internal name; user and system presets are plain file paths.
+pub fn preset_location(location: Location, load_key: Option<&CStr>) -> Option<PresetLocation> {
+    match location {
+        Location::Plugin => {
+            let key = load_key?.to_str().ok()?;
+            sonido_config::factory_preset_names()
+                .into_iter()
+                .find(|name| name.eq_ignore_ascii_case(key))
+                .map(PresetLocation::Factory)
+        }
+        Location::File { path } => Some(PresetLocation::File(PathBuf::from(path.to_str().ok()?))),
+    }
+}
+
+/// CLAP plugin entry exporting the plugin factory and the preset-discovery
+/// factory for a single sonido plugin.
+pub struct SonidoEntry<P: SonidoPresetTarget> {
+    plugin_factory: PluginFactoryWrapper<SonidoPluginFactory<P>>,
+    preset_factory: PresetDiscoveryFactoryWrapper<SonidoPresetFactory>,
+}
+
+impl<P: SonidoPresetTarget> Entry for SonidoEntry<P> {
+    fn new(_bundle_path: &CStr) -> Result<Self, EntryLoadError> {
+        let descriptor = P::get_descriptor();
+        let preset_factory = SonidoPresetFactory::new(&descriptor, P::SLOT_EFFECT_IDS);
+        Ok(Self {
+            plugin_factory: PluginFactoryWrapper::new(SonidoPluginFactory {
+                descriptor,
+                _plugin: PhantomData,
+            }),
+            preset_factory: PresetDiscoveryFactoryWrapper::new(preset_factory),
+        })
+    }
+
+    fn declare_factories<'a>(&'a self, builder: &mut EntryFactories<'a>) {
+        builder
+            .register_factory(&self.plugin_factory)
+            .register_factory(&self.preset_factory);
+    }
+}
+
+/// Plugin factory for one sonido plugin type.
+///
+/// Equivalent to the factory inside `SinglePluginEntry`, which can't be
+/// combined with other factories.
+pub struct SonidoPluginFactory<P> {
+    descriptor: PluginDescriptor,
+    _plugin: PhantomData<fn() -> P>,
+}
+
+impl<P: DefaultPluginFactory> PluginFactory for SonidoPluginFactory<P> {
+    fn plugin_count(&self) -> u32 {
+        1
+    }
+
+    fn plugin_descriptor(&self, index: u32) -> Option<&PluginDescriptor> {
+        (index == 0).then_some(&self.descriptor)
+    }
+
+    fn create_plugin<'a>(
+        &'a self,
+        host_info: HostInfo<'a>,
+        plugin_id: &CStr,
+    ) -> Option<PluginInstance<'a>> {
+        (plugin_id == self.descriptor.id()).then(|| {
+            PluginInstance::new::<P>(
+                host_info,
+                &self.descriptor,
+                |host| P::new_shared(host),
+                |host, shared| P::new_main_thread(host, shared),
+            )
+        })
+    }
+}
+
+/// Preset-discovery factory with a single provider for the plugin.
+pub struct SonidoPresetFactory {
+    provider: ProviderDescriptor,
+    /// Plugin ID the reported presets apply to.
+    clap_id: CString,
+    effect_ids: &'static [&'static str],
+}
+
+impl SonidoPresetFactory {
+    fn new(plugin: &PluginDescriptor, effect_ids: &'static [&'static str]) -> Self {
+        let clap_id = plugin.id().to_owned();
+        let provider_id = CString::new(format!("{}.presets", clap_id.to_string_lossy()))
+            .unwrap_or_else(|_| c"com.sonido.presets".to_owned());
+        Self {
+            provider: ProviderDescriptor::new(&provider_id, c"Sonido Presets")
+                .with_vendor(c"Sonido"),
+            clap_id,
+            effect_ids,
+        }
+    }
+}
+
+impl PresetDiscoveryFactoryImpl for SonidoPresetFactory {
+    fn provider_count(&self) -> u32 {
+        1
+    }
+
+    fn provider_declaration(&self, index: u32) -> Option<&ProviderDescriptor> {
+        (index == 0).then_some(&self.provider)
+    }
+
+    fn create_provider<'a>(
+        &'a self,
+        indexer: Indexer<'a>,
+        provider_id: &CStr,
+    ) -> Option<ProviderInstance<'a>> {
+        (Some(provider_id) == self.provider.id()).then(|| {
+            ProviderInstance::new(indexer, &self.provider, |indexer| {
+                SonidoPresetProvider::new(indexer, self)
+            })
+        })
+    }
+}
+
+/// Preset provider reporting factory, user, and system presets.
+struct SonidoPresetProvider<'a> {
+    factory: &'a SonidoPresetFactory,
+}
+
+impl<'a> SonidoPresetProvider<'a> {
+    fn new(mut indexer: Indexer<'a>, factory: &'a SonidoPresetFactory) -> Self {
+        indexer.declare_filetype(FileType {
+            name: c"Sonido preset",
+            description: None,
+            file_extension: Some(PRESET_FILE_EXTENSION),
+        });
+        indexer.declare_location(LocationInfo {
+            name: c"Factory",
+            flags: Flags::IS_FACTORY_CONTENT,
+            location: Location::Plugin,
+        });
+
+        let dirs = [
+            (
+                c"User",
+                Flags::IS_USER_CONTENT,
+                sonido_config::user_presets_dir(),
+            ),
+            (
+                c"System",
+                Flags::empty(),
+                sonido_config::system_presets_dir(),
+            ),
+        ];
+        for (name, flags, dir) in dirs {
+            if let Some(path) = path_to_cstring(&dir) {
+                indexer.declare_location(LocationInfo {
+                    name,
+                    flags,
+                    location: Location::File { path: &path },
+                });
+            }
+        }
+
+        Self { factory }
+    }
+
+    /// Report one preset to the host.
+    fn emit(&self, receiver: &mut MetadataReceiver, entry: &PresetEntry, flags: Flags) {
+        let Ok(name) = CString::new(entry.name.as_str()) else {
+            return;
+        };
+        let load_key = match entry.location {
+            PresetLocation::Factory(key) => CString::new(key).ok(),
+            PresetLocation::File(_) => None,
+        };
+
+        receiver.begin_preset(Some(&name), load_key.as_deref());
+        receiver.add_plugin_id(UniversalPluginId::clap(&self.factory.clap_id));
+        receiver.set_flags(flags);
+        if let Some(description) = entry
+            .description
+            .as_deref()
+            .and_then(|d| CString::new(d).ok())
+        {
+            receiver.set_description(&description);
+        }
+    }
+}
+
+impl<'a> ProviderImpl<'a> for SonidoPresetProvider<'a> {
+    fn get_metadata(&mut self, location: Location, receiver: &mut MetadataReceiver) {
+        let effect_ids = self.factory.effect_ids;
+        match location {
+            Location::Plugin => {
+                for entry in presets::factory_entries(effect_ids) {
+                    self.emit(receiver, &entry, Flags::IS_FACTORY_CONTENT);
+                }
+            }
+            Location::File { path } => {
+                let Ok(path) = path.to_str() else { return };
+                if let Some(entry) = presets::file_entry(Path::new(path), effect_ids) {
+                    self.emit(receiver, &entry, Flags::empty());
+                }
+            }
+        }
+    }
+}
+
+/// Convert a directory path to a C string for the host indexer.
+fn path_to_cstring(path: &Path) -> Option<CString> {
+    CString::new(path.to_str()?).ok()
+}
@@ -0,0 +1,269 @@
+//! Preset browsing and loading for sonido CLAP plugins.
+//!
+//! Presets come from `sonido-config`: the factory presets compiled into the
+//! binary, plus TOML files in the user and system preset directories. A
+//! preset is a chain of effect configs; a plugin can use it when at least
+//! one of those effects matches one of its slots.
+//!
+//! Loading maps each matching [`EffectConfig`] onto its slot by effect ID.
+//! Parameter names are resolved against the slot's descriptors (name, short
+//! name, or string ID, case-insensitive), so the same lookup works for
+//! registry effects and the synth voice. Values go through
+//! [`parse_param_value`], matching `sonido_config::EffectChain`.
+//!
+//! This module is format-agnostic: the CLAP preset-load and
+//! preset-discovery glue lives in [`crate::preset_discovery`].
+
+use std::path::{Path, PathBuf};
+
+use sonido_config::{ConfigError, EffectConfig, Preset, parse_param_value};
+
+use crate::shared::SonidoShared;
+
+/// Where a preset is loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetLocation {
+    /// A factory preset compiled into the plugin, keyed by its internal name.
+    Factory(&'static str),
+    /// A preset TOML file on disk.
+    File(PathBuf),
+}
+
+/// A preset a plugin can load, as reported to the host's preset browser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresetEntry {
+    /// Display name (the preset's `name` field).
+    pub name: String,
+    /// Optional description from the preset file.
+    pub description: Option<String>,
+    /// Where to load the preset from.
+    pub location: PresetLocation,
+}
+
+/// Whether `preset` contains at least one effect handled by `effect_ids`.
+pub fn is_compatible(preset: &Preset, effect_ids: &[&str]) -> bool {
+    preset
+        .iter()
+        .any(|config| effect_ids.contains(&config.canonical_type()))
+}
+
+/// Factory presets compatible with `effect_ids`, in factory order.
+pub fn factory_entries(effect_ids: &[&str]) -> Vec<PresetEntry> {
+    sonido_config::factory_preset_names()
+        .into_iter()
+        .filter_map(|key| {
+            let preset = sonido_config::get_factory_preset(key)?;
+            is_compatible(&preset, effect_ids).then_some(PresetEntry {
+                name: preset.name,
+                description: preset.description,
+                location: PresetLocation::Factory(key),
+            })
+        })
+        .collect()
+}
+
+/// Read the preset file at `path`, returning an entry if it parses and is
+/// compatible with `effect_ids`.
+pub fn file_entry(path: &Path, effect_ids: &[&str]) -> Option<PresetEntry> {
+    let preset = Preset::load(path).ok()?;
+    is_compatible(&preset, effect_ids).then_some(PresetEntry {
+        name: preset.name,
+        description: preset.description,
+        location: PresetLocation::File(path.to_path_buf()),
+    })
+}
+
+/// All presets compatible with `effect_ids`: factory presets first, then
+/// user presets, then system presets.
+pub fn discover(effect_ids: &[&str]) -> Vec<PresetEntry> {
+    let mut entries = factory_entries(effect_ids);
+    entries.extend(
+        sonido_config::list_all_presets()
+            .iter()
+            .filter_map(|path| file_entry(path, effect_ids)),
+    );
+    entries
+}
+
+/// Load the preset stored at `location`.
+pub fn load(location: &PresetLocation) -> Result<Preset, ConfigError> {
+    match location {
+        PresetLocation::Factory(key) => sonido_config::get_factory_preset(key)
+            .ok_or_else(|| ConfigError::PresetNotFound((*key).to_string())),
+        PresetLocation::File(path) => Preset::load(path),
+    }
+}
+
+/// Apply `preset` to the plugin's parameters, slot bypass flags, and slot
+/// labels and colors.
+///
+/// Each slot takes the next unused effect config with the same effect ID
+/// (the second delay slot takes the preset's second delay): its
+/// parameters are reset to defaults, then the config's parameters are
+/// applied, and the slot's bypass flag, label and color follow the config.
+/// Onboard effect slots (slot 1 and up) that the preset doesn't mention are
+/// bypassed and lose their label and color, so a chain preset sounds the
+/// same in the instrument as in the standalone chain. Slot 0 is left
+/// untouched when unmatched.
+///
+/// Returns the number of slots the preset matched. Nothing is changed when
+/// it returns 0.
+pub fn apply_preset(shared: &SonidoShared, preset: &Preset) -> usize {
+    let matches: Vec<Option<&EffectConfig>> = (0..shared.slot_count())
+        .map(|slot| {
+            let effect_id = shared.slot_effect_id(slot)?;
+            let earlier = (0..slot)
+                .filter(|&s| shared.slot_effect_id(s) == Some(effect_id))
+                .count();
+            preset
+                .iter()
+                .filter(|config| config.canonical_type() == effect_id)
+                .nth(earlier)
+        })
+        .collect();
+
+    let matched = matches.iter().filter(|m| m.is_some()).count();
+    if matched == 0 {
+        return 0;
+    }
+
+    for (slot, config) in matches.into_iter().enumerate() {
+        match config {
+            Some(config) => {
+                apply_config(shared, slot, config);
+                shared.set_slot_bypassed(slot, config.bypassed);
+                shared.set_slot_label(slot, config.label.as_deref());
+                shared.set_slot_color(slot, config.color);
+            }
+            None if slot > 0 => {
+                shared.set_slot_bypassed(slot, true);
+                shared.set_slot_label(slot, None);
+                shared.set_slot_color(slot, None);
+            }
+            None => {}
+        }
+    }
+
+    shared.notify_host();
+    matched
+}
+
+/// Reset `slot` to defaults, then apply `config`'s parameters to it.
+fn apply_config(shared: &SonidoShared, slot: usize, config: &EffectConfig) {
+    let params = shared.slot_params(slot);
+    for index in params.clone() {
+        if let Some(desc) = shared.descriptor(index) {
+            shared.set_value(index, desc.default);
+        }
+    }
+
+    for (name, value) in &config.params {
+        let Some(value) = parse_param_value(value) else {
+            continue;
+        };
+        let found = params.clone().find(|&index| {
+            shared.descriptor(index).is_some_and(|desc| {
+                desc.name.eq_ignore_ascii_case(name)
+                    || desc.short_name.eq_ignore_ascii_case(name)
+                    || desc.string_id.eq_ignore_ascii_case(name)
+            })
+        });
+        if let Some(index) = found {
+            shared.set_value(index, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_config::SlotColor;
+
+    #[test]
+    fn factory_entries_filter_by_effect() {
+        let entries = factory_entries(&["reverb"]);
+        assert!(!entries.is_empty());
+        for entry in &entries {
+            let PresetLocation::Factory(key) = entry.location else {
+                panic!("factory entry with file location");
+            };
+            let preset = sonido_config::get_factory_preset(key).unwrap();
+            assert!(preset.canonical_types().contains(&"reverb"));
+        }
+
+        assert!(factory_entries(&["no_such_effect"]).is_empty());
+    }
+
+    #[test]
+    fn apply_preset_sets_matching_slot() {
+        let shared = SonidoShared::new("distortion", None);
+        let drive = shared.descriptor(0).unwrap();
+        let target = (drive.min + drive.max) * 0.5;
+        let preset = Preset::new("test").with_effect(
+            EffectConfig::new("distortion").with_param(drive.name, target.to_string()),
+        );
+
+        assert_eq!(apply_preset(&shared, &preset), 1);
+        assert_eq!(shared.get_value(0), Some(target));
+        assert!(!shared.is_bypassed());
+    }
+
+    #[test]
+    fn apply_preset_resets_unlisted_params() {
+        let shared = SonidoShared::new("distortion", None);
+        let desc = shared.descriptor(1).unwrap();
+        shared.set_value(1, desc.max);
+
+        let preset = Preset::new("test").with_effect(EffectConfig::new("distortion"));
+        apply_preset(&shared, &preset);
+        assert_eq!(shared.get_value(1), Some(desc.default));
+    }
+
+    #[test]
+    fn apply_incompatible_preset_changes_nothing() {
+        let shared = SonidoShared::new("distortion", None);
+        let desc = shared.descriptor(0).unwrap();
+        shared.set_value(0, desc.max);
+
+        let preset = Preset::new("test").with_effect(EffectConfig::new("reverb"));
+        assert_eq!(apply_preset(&shared, &preset), 0);
+        assert_eq!(shared.get_value(0), Some(desc.max));
+    }
+
+    #[test]
+    fn apply_preset_to_instrument_slots() {
+        let shared = SonidoShared::new_instrument(&["chorus", "delay", "reverb"], None);
+        shared.set_slot_bypassed(2, false);
+
+        shared.set_slot_label(2, Some("Slapback"));
+        let preset = Preset::new("test")
+            .with_effect(
+                EffectConfig::new("chorus")
+                    .with_label("Wide")
+                    .with_color(SlotColor::Teal),
+            )
+            .with_effect(EffectConfig::new("!reverb"));
+
+        assert_eq!(apply_preset(&shared, &preset), 2);
+        assert!(!shared.is_slot_bypassed(1), "chorus enabled by preset");
+        assert!(shared.is_slot_bypassed(2), "delay not in preset");
+        assert!(shared.is_slot_bypassed(3), "reverb bypassed in preset");
+        assert!(!shared.is_bypassed(), "synth slot untouched");
+        assert_eq!(shared.slot_display_name(1), "Wide");
+        assert_eq!(shared.slot_color(1), Some(SlotColor::Teal));
+        assert_eq!(shared.slot_label(2), None, "unmatched slot label cleared");
+    }
+
+    #[test]
+    fn apply_preset_to_repeated_slots_in_order() {
+        let shared = SonidoShared::new_instrument(&["delay", "delay", "delay"], None);
+        let preset = Preset::new("test")
+            .with_effect(EffectConfig::new("delay").with_label("Slap"))
+            .with_effect(EffectConfig::new("delay").with_label("Echo"));
+
+        assert_eq!(apply_preset(&shared, &preset), 2);
+        assert_eq!(shared.slot_label(1).as_deref(), Some("Slap"));
+        assert_eq!(shared.slot_label(2).as_deref(), Some("Echo"));
+        assert!(shared.is_slot_bypassed(3), "no third delay in preset");
+    }
+}
@@ -0,0 +1,111 @@
+//! Per-parameter ramps for processors that apply values immediately.
+//!
+//! Kernel effects smooth their own parameters, but [`SynthNode`] applies
+//! every change at once, so a cutoff automation lane would step audibly at
+//! each block boundary. [`ParamRamps`] sits between the shared atomic state
+//! and such a processor and glides each parameter to its target over the
+//! descriptor's [`smoothing_time_ms()`]. Stepped parameters snap.
+//!
+//! [`SynthNode`]: sonido_synth::SynthNode
+//! [`smoothing_time_ms()`]: sonido_core::ParamDescriptor::smoothing_time_ms
+
+use sonido_core::{ParameterInfo, SmoothedParam};
+
+/// One smoother per parameter of a [`ParameterInfo`] target.
+pub struct ParamRamps {
+    ramps: Vec<SmoothedParam>,
+    /// Whether every ramp has reached its target (skips `advance` work).
+    settled: bool,
+}
+
+impl ParamRamps {
+    /// Create ramps resting at `target`'s current values.
+    pub fn new<P: ParameterInfo + ?Sized>(target: &P, sample_rate: f32) -> Self {
+        let ramps = (0..target.param_count())
+            .map(|i| {
+                let time_ms = target.param_info(i).map_or(0.0, |d| d.smoothing_time_ms());
+                SmoothedParam::with_config(target.get_param(i), sample_rate, time_ms)
+            })
+            .collect();
+        Self {
+            ramps,
+            settled: true,
+        }
+    }
+
+    /// Start ramping parameter `index` toward `value`.
+    pub fn set_target(&mut self, index: usize, value: f32) {
+        if let Some(ramp) = self.ramps.get_mut(index) {
+            ramp.set_target(value);
+            self.settled &= ramp.is_settled();
+        }
+    }
+
+    /// The value parameter `index` is ramping toward.
+    pub fn target(&self, index: usize) -> Option<f32> {
+        self.ramps.get(index).map(SmoothedParam::target)
+    }
+
+    /// Advance every moving ramp by one sample and write it to `target`.
+    #[inline]
+    pub fn advance<P: ParameterInfo + ?Sized>(&mut self, target: &mut P) {
+        if self.settled {
+            return;
+        }
+        let mut settled = true;
+        for (i, ramp) in self.ramps.iter_mut().enumerate() {
+            if !ramp.is_settled() {
+                target.set_param(i, ramp.advance());
+                settled &= ramp.is_settled();
+            }
+        }
+        self.settled = settled;
+    }
+
+    /// Jump every ramp to its target and write the targets to `target`.
+    pub fn snap<P: ParameterInfo + ?Sized>(&mut self, target: &mut P) {
+        for (i, ramp) in self.ramps.iter_mut().enumerate() {
+            if !ramp.is_settled() {
+                ramp.snap_to_target();
+                target.set_param(i, ramp.target());
+            }
+        }
+        self.settled = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonido_synth::SynthNode;
+
+    fn index_of(synth: &SynthNode, string_id: &str) -> usize {
+        (0..synth.param_count())
+            .find(|&i| {
+                synth
+                    .param_info(i)
+                    .is_some_and(|d| d.string_id == string_id)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn cutoff_glides_and_wave_snaps() {
+        let mut synth = SynthNode::new(48000.0);
+        let mut ramps = ParamRamps::new(&synth, 48000.0);
+        let cutoff = index_of(&synth, "synth_cutoff");
+        let wave = index_of(&synth, "synth_osc1_wave");
+
+        ramps.set_target(cutoff, 5000.0);
+        ramps.set_target(wave, 0.0);
+        ramps.advance(&mut synth);
+
+        let after_one = synth.get_param(cutoff);
+        assert!(after_one > 1000.0 && after_one < 1100.0, "{after_one}");
+        assert_eq!(synth.get_param(wave), 0.0);
+        assert_eq!(ramps.target(cutoff), Some(5000.0));
+
+        ramps.snap(&mut synth);
+        assert_eq!(synth.get_param(cutoff), 5000.0);
+    }
+}
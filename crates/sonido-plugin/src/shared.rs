@@ -4,9 +4,33 @@
 //! accessible from both the main thread (params, state) and the audio
 //! thread (processing). Parameter values are stored as atomic `u32`
 //! (f32 bit-cast) for lock-free access.
+//!
+//! Parameters are stored as one flat list so the CLAP params extension can
+//! index them directly. The list is split into *slots*: effect plugins have a
+//! single slot, while the synth instrument has the synth in slot 0 followed
+//! by its onboard effect slots.
+//!
+//! The first slot running a given effect reports the effect's own stable
+//! `ParamId`s to the host. Later slots running the same effect remap theirs
+//! with [`slot_param_id`], so an instrument can carry two delays without
+//! colliding IDs, while instruments without repeats keep the IDs (and the
+//! saved state and automation) they always had.
+//!
+//! Each onboard effect slot also gets a `Bypass` parameter so hosts can
+//! automate it. These follow every slot's parameters at the end of the list,
+//! outside any slot's range, with IDs from [`BYPASS_PARAM_ID_BASE`].
+//!
+//! Slots can be renamed and given a color label from the editor. An
+//! instrument slot's name prefixes its parameters' CLAP module paths, so a
+//! rename asks the host to rescan parameter info (see
+//! [`SonidoShared::take_labels_changed`]).
 
-use sonido_core::ParamDescriptor;
+use core::ops::Range;
+use parking_lot::Mutex;
+use sonido_config::SlotColor;
+use sonido_core::{ParamDescriptor, ParamFlags, ParamId, ParameterInfo};
 use sonido_registry::EffectRegistry;
+use sonido_synth::SynthNode;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
 
@@ -15,14 +39,14 @@ use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
 /// Holds all data behind an `Arc` so that `SonidoShared` can be cheaply
 /// cloned into `'static + Send` GUI closures.
 struct SonidoSharedData {
-    /// Registry ID of the effect (e.g., `"distortion"`).
-    effect_id: &'static str,
-    /// Parameter descriptors, indexed by parameter position.
+    /// Processing slots in signal order. Slot 0 is the main effect or synth.
+    slots: Vec<SlotInfo>,
+    /// Whether slot 0 is the synth (note input, no audio input).
+    instrument: bool,
+    /// Parameter descriptors, indexed by flat parameter position.
     descriptors: Vec<ParamDescriptor>,
     /// Current parameter values as f32 bit-cast to u32 for atomic access.
     values: Vec<AtomicU32>,
-    /// Whether the effect is bypassed (GUI toggle, not host bypass).
-    bypassed: AtomicBool,
     /// Gesture flags per parameter: bit 0 = begin pending, bit 1 = end pending.
     ///
     /// GUI thread sets flags via `fetch_or`; audio thread clears via `swap(0)`.
@@ -37,8 +61,75 @@ struct SonidoSharedData {
     /// Without this, GUI-originated param changes passively wait for the
     /// next audio block — which may never come when playback is stopped.
     host_notify: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Host resize request callback, taking the new editor size in host
+    /// pixels. Returns `false` if the host refused or doesn't support it.
+    host_resize: Option<Box<dyn Fn(u32, u32) -> bool + Send + Sync>>,
+    /// Host main-thread callback request (`host.request_callback()`), used
+    /// to rescan parameter info after a slot rename.
+    host_callback: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Set when a slot label changes; cleared by the main thread once it has
+    /// asked the host to rescan.
+    labels_changed: AtomicBool,
+    /// User editor zoom as f32 bit-cast to u32. Persisted in plugin state.
+    ui_zoom: AtomicU32,
+}
+
+/// One processing slot within the flat parameter list.
+struct SlotInfo {
+    /// Registry ID of the slot's effect (`"synth"` for the instrument voice).
+    effect_id: &'static str,
+    /// Display name when the slot has no label (`"Chorus"`).
+    name: &'static str,
+    /// User-assigned name, shown instead of `name`.
+    label: Mutex<Option<String>>,
+    /// Color label: 0 = none, otherwise `SlotColor::index() + 1`.
+    color: AtomicU8,
+    /// Flat parameter indices owned by this slot.
+    params: Range<usize>,
+    /// Flat index of the slot's host-automatable `Bypass` parameter, which
+    /// holds its bypass state. `None` for slot 0.
+    bypass_param: Option<usize>,
+    /// Bypass state of slots without a `Bypass` parameter (GUI toggle).
+    bypassed: AtomicBool,
+}
+
+/// Effect ID reported for the synth slot of the instrument plugin.
+///
+/// Matches the `"synth"` panel in `sonido_gui_core::effects_ui::create_panel`.
+pub const SYNTH_EFFECT_ID: &str = "synth";
+
+/// CLAP ID of the `Bypass` parameter for onboard effect slot 1; slot `n`
+/// uses `BYPASS_PARAM_ID_BASE + n`. Far above every effect's `ParamId` range.
+pub const BYPASS_PARAM_ID_BASE: u32 = 0x7F00_0000;
+
+/// Base of the CLAP IDs given to parameters of a repeated effect slot; see
+/// [`slot_param_id`]. Below [`BYPASS_PARAM_ID_BASE`] and far above every
+/// effect's `ParamId` range.
+pub const SLOT_PARAM_ID_BASE: u32 = 0x7000_0000;
+
+/// Most onboard effect slots an instrument can have, bounded by the slot
+/// field of [`slot_param_id`].
+pub const MAX_FX_SLOTS: usize = 0xFF;
+
+/// CLAP ID of parameter `id` in `slot` when an earlier slot already runs the
+/// same effect: `SLOT_PARAM_ID_BASE | slot << 16 | id`.
+///
+/// Effect `ParamId`s are below `0x1_0000`, so every effect keeps its full
+/// parameter range whatever its parameter count.
+pub const fn slot_param_id(slot: usize, id: u32) -> u32 {
+    SLOT_PARAM_ID_BASE | ((slot as u32) << 16) | (id & 0xFFFF)
 }
 
+/// Longest slot label kept, in characters. Hosts show module paths in
+/// narrow columns, so longer names are truncated.
+pub const MAX_SLOT_LABEL_CHARS: usize = 32;
+
+/// Effects whose plugins expose a sidechain input port for an external key.
+///
+/// Each has a `Key` parameter choosing between the main input and the
+/// sidechain as the detector source.
+pub const KEYED_EFFECT_IDS: &[&str] = &["compressor", "gate"];
+
 /// Shared state accessible from all plugin threads.
 ///
 /// Holds the effect's parameter descriptors (immutable after construction)
@@ -53,6 +144,11 @@ pub struct SonidoShared {
     inner: Arc<SonidoSharedData>,
 }
 
+/// Smallest user editor zoom (75%).
+pub const MIN_UI_ZOOM: f32 = 0.75;
+/// Largest user editor zoom (200%).
+pub const MAX_UI_ZOOM: f32 = 2.0;
+
 /// Flag indicating a gesture-begin is pending (GUI → audio).
 pub const GESTURE_BEGIN: u8 = 1;
 /// Flag indicating a gesture-end is pending (GUI → audio).
@@ -74,35 +170,259 @@ impl SonidoShared {
             .create(effect_id, 48000.0)
             .expect("Unknown effect ID in SonidoShared::new");
 
-        let count = effect.effect_param_count();
-        let mut descriptors = Vec::with_capacity(count);
-        let mut values = Vec::with_capacity(count);
+        let descriptors = (0..effect.effect_param_count())
+            .filter_map(|i| effect.effect_param_info(i))
+            .collect();
+        Self::from_slots(vec![(effect_id, descriptors, false)], false, host_notify)
+    }
 
-        for i in 0..count {
-            if let Some(desc) = effect.effect_param_info(i) {
-                values.push(AtomicU32::new(desc.default.to_bits()));
-                descriptors.push(desc);
-            }
+    /// Create shared state for the synth instrument.
+    ///
+    /// Slot 0 holds the [`SynthNode`] parameters; each entry of `fx_ids`
+    /// appends an onboard effect slot, processed in order after the synth.
+    /// Effect slots start bypassed so the instrument sounds dry until the
+    /// user enables them.
+    ///
+    /// An effect may appear in several slots; repeats get slot-scoped
+    /// parameter IDs (see [`slot_param_id`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics on an unknown effect ID or more than [`MAX_FX_SLOTS`] slots.
+    pub fn new_instrument(
+        fx_ids: &[&'static str],
+        host_notify: Option<Box<dyn Fn() + Send + Sync>>,
+    ) -> Self {
+        let synth = SynthNode::new(48000.0);
+        let synth_descriptors = (0..synth.param_count())
+            .filter_map(|i| synth.param_info(i))
+            .collect();
+
+        assert!(
+            fx_ids.len() <= MAX_FX_SLOTS,
+            "Too many effect slots in SonidoShared::new_instrument"
+        );
+        let registry = EffectRegistry::new();
+        let mut slots = vec![(SYNTH_EFFECT_ID, synth_descriptors, false)];
+        for (i, &fx_id) in fx_ids.iter().enumerate() {
+            let effect = registry
+                .create(fx_id, 48000.0)
+                .expect("Unknown effect ID in SonidoShared::new_instrument");
+            let repeat = fx_ids[..i].contains(&fx_id);
+            let descriptors = (0..effect.effect_param_count())
+                .filter_map(|i| effect.effect_param_info(i))
+                .map(|mut desc| {
+                    if repeat {
+                        desc.id = ParamId(slot_param_id(slots.len(), desc.id.0));
+                    }
+                    desc
+                })
+                .collect();
+            slots.push((fx_id, descriptors, true));
+        }
+
+        Self::from_slots(slots, true, host_notify)
+    }
+
+    /// Flatten per-slot descriptors into the shared parameter list.
+    fn from_slots(
+        slot_descriptors: Vec<(&'static str, Vec<ParamDescriptor>, bool)>,
+        instrument: bool,
+        host_notify: Option<Box<dyn Fn() + Send + Sync>>,
+    ) -> Self {
+        let registry = EffectRegistry::new();
+        let mut slots = Vec::with_capacity(slot_descriptors.len());
+        let mut descriptors = Vec::new();
+
+        for (effect_id, slot_params, bypassed) in slot_descriptors {
+            let name = if effect_id == SYNTH_EFFECT_ID {
+                "Synth"
+            } else {
+                registry.get(effect_id).map_or(effect_id, |d| d.name)
+            };
+            let start = descriptors.len();
+            descriptors.extend(slot_params);
+            slots.push(SlotInfo {
+                effect_id,
+                name,
+                label: Mutex::new(None),
+                color: AtomicU8::new(0),
+                params: start..descriptors.len(),
+                bypass_param: None,
+                bypassed: AtomicBool::new(bypassed),
+            });
+        }
+
+        for (slot, info) in slots.iter_mut().enumerate().skip(1) {
+            info.bypass_param = Some(descriptors.len());
+            descriptors.push(bypass_descriptor(slot, *info.bypassed.get_mut()));
         }
 
+        let values = descriptors
+            .iter()
+            .map(|d| AtomicU32::new(d.default.to_bits()))
+            .collect();
         let gesture_flags = (0..descriptors.len()).map(|_| AtomicU8::new(0)).collect();
 
         Self {
             inner: Arc::new(SonidoSharedData {
-                effect_id,
+                slots,
+                instrument,
                 descriptors,
                 values,
-                bypassed: AtomicBool::new(false),
                 gesture_flags,
                 latency_samples: AtomicU32::new(0),
                 host_notify,
+                host_resize: None,
+                host_callback: None,
+                labels_changed: AtomicBool::new(false),
+                ui_zoom: AtomicU32::new(1.0_f32.to_bits()),
             }),
         }
     }
 
-    /// Registry ID of the wrapped effect.
+    /// Attach a host resize callback, used when the user changes the editor
+    /// zoom. Must be called before the shared state is cloned.
+    pub fn with_host_resize(mut self, resize: Box<dyn Fn(u32, u32) -> bool + Send + Sync>) -> Self {
+        if let Some(inner) = Arc::get_mut(&mut self.inner) {
+            inner.host_resize = Some(resize);
+        }
+        self
+    }
+
+    /// Attach a host main-thread callback request, used to rescan parameter
+    /// info after a slot rename. Must be called before the shared state is
+    /// cloned.
+    pub fn with_host_callback(mut self, callback: Box<dyn Fn() + Send + Sync>) -> Self {
+        if let Some(inner) = Arc::get_mut(&mut self.inner) {
+            inner.host_callback = Some(callback);
+        }
+        self
+    }
+
+    /// Registry ID of the wrapped effect (slot 0).
     pub fn effect_id(&self) -> &'static str {
-        self.inner.effect_id
+        self.inner.slots[0].effect_id
+    }
+
+    /// Whether this is the synth instrument (note input, no audio input).
+    pub fn is_instrument(&self) -> bool {
+        self.inner.instrument
+    }
+
+    /// Whether the plugin exposes a sidechain key input port.
+    pub fn has_key_input(&self) -> bool {
+        !self.is_instrument() && KEYED_EFFECT_IDS.contains(&self.effect_id())
+    }
+
+    /// Number of processing slots (1 for effect plugins).
+    pub fn slot_count(&self) -> usize {
+        self.inner.slots.len()
+    }
+
+    /// Registry ID of the effect in `slot`, or `None` if out of range.
+    pub fn slot_effect_id(&self, slot: usize) -> Option<&'static str> {
+        self.inner.slots.get(slot).map(|s| s.effect_id)
+    }
+
+    /// User label of `slot`, if it has been renamed.
+    pub fn slot_label(&self, slot: usize) -> Option<String> {
+        self.inner.slots.get(slot)?.label.lock().clone()
+    }
+
+    /// Rename `slot`. Surrounding whitespace is trimmed, long labels are
+    /// cut to [`MAX_SLOT_LABEL_CHARS`], and an empty label restores the
+    /// effect's name. No-op if out of range.
+    ///
+    /// A change flags the parameter info for a host rescan and requests a
+    /// main-thread callback to deliver it.
+    pub fn set_slot_label(&self, slot: usize, label: Option<&str>) {
+        let Some(info) = self.inner.slots.get(slot) else {
+            return;
+        };
+        let label = label
+            .map(|l| {
+                l.trim()
+                    .chars()
+                    .take(MAX_SLOT_LABEL_CHARS)
+                    .collect::<String>()
+            })
+            .filter(|l| !l.is_empty());
+        {
+            let mut current = info.label.lock();
+            if *current == label {
+                return;
+            }
+            *current = label;
+        }
+        self.inner.labels_changed.store(true, Ordering::Release);
+        if let Some(cb) = &self.inner.host_callback {
+            cb();
+        }
+    }
+
+    /// Name shown for `slot`: its label, or else the effect's name.
+    /// Empty if out of range.
+    pub fn slot_display_name(&self, slot: usize) -> String {
+        self.inner.slots.get(slot).map_or_else(String::new, |s| {
+            s.label.lock().clone().unwrap_or_else(|| s.name.to_owned())
+        })
+    }
+
+    /// Color label of `slot`, if one is assigned.
+    pub fn slot_color(&self, slot: usize) -> Option<SlotColor> {
+        let color = self.inner.slots.get(slot)?.color.load(Ordering::Acquire);
+        SlotColor::ALL
+            .get(usize::from(color).checked_sub(1)?)
+            .copied()
+    }
+
+    /// Assign or clear the color label of `slot`. No-op if out of range.
+    pub fn set_slot_color(&self, slot: usize, color: Option<SlotColor>) {
+        if let Some(info) = self.inner.slots.get(slot) {
+            let encoded = color.map_or(0, |c| c.index() as u8 + 1);
+            info.color.store(encoded, Ordering::Release);
+        }
+    }
+
+    /// Whether a slot label changed since the last call, clearing the flag.
+    ///
+    /// Called on the main thread, which then asks the host to rescan
+    /// parameter info so the new module paths show up.
+    pub fn take_labels_changed(&self) -> bool {
+        self.inner.labels_changed.swap(false, Ordering::AcqRel)
+    }
+
+    /// Flat parameter indices owned by `slot` (empty if out of range).
+    pub fn slot_params(&self, slot: usize) -> Range<usize> {
+        self.inner
+            .slots
+            .get(slot)
+            .map_or(0..0, |s| s.params.clone())
+    }
+
+    /// Flat index of `slot`'s `Bypass` parameter, if it has one.
+    pub fn slot_bypass_param(&self, slot: usize) -> Option<usize> {
+        self.inner.slots.get(slot).and_then(|s| s.bypass_param)
+    }
+
+    /// The slot whose `Bypass` parameter is at flat `index`, if any.
+    pub fn bypass_slot(&self, index: usize) -> Option<usize> {
+        self.inner
+            .slots
+            .iter()
+            .position(|s| s.bypass_param == Some(index))
+    }
+
+    /// Map a flat parameter index to `(slot, index within slot)`.
+    ///
+    /// `Bypass` parameters belong to no slot's range and return `None`.
+    pub fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        self.inner
+            .slots
+            .iter()
+            .position(|s| s.params.contains(&index))
+            .map(|slot| (slot, index - self.inner.slots[slot].params.start))
     }
 
     /// Number of parameters.
@@ -115,6 +435,28 @@ impl SonidoShared {
         self.inner.descriptors.get(index)
     }
 
+    /// CLAP module path for a parameter: `/`-separated, empty for top level.
+    ///
+    /// Instrument parameters are prefixed with their slot's display name
+    /// (`"Chorus/Tempo Sync"`, or `"Lead Boost/Tempo Sync"` once renamed) so
+    /// hosts can tell the synth's controls from each onboard effect's.
+    pub fn module_path(&self, index: usize) -> Option<String> {
+        let group = self.descriptor(index)?.group;
+        if !self.is_instrument() {
+            return Some(group.to_owned());
+        }
+        let slot = self
+            .locate(index)
+            .map(|(slot, _)| slot)
+            .or_else(|| self.bypass_slot(index))?;
+        let name = self.slot_display_name(slot);
+        Some(if group.is_empty() {
+            name
+        } else {
+            format!("{name}/{group}")
+        })
+    }
+
     /// All parameter descriptors.
     pub fn descriptors(&self) -> &[ParamDescriptor] {
         &self.inner.descriptors
@@ -133,27 +475,59 @@ impl SonidoShared {
             .map(|v| f32::from_bits(v.load(Ordering::Acquire)))
     }
 
-    /// Write a parameter value (lock-free). Clamps to descriptor bounds.
-    pub fn set_value(&self, index: usize, value: f32) {
-        if let Some((atomic, desc)) = self
+    /// Write a parameter value (lock-free). Clamps to descriptor bounds and
+    /// rounds stepped parameters to a whole step, so every reader sees the
+    /// same enum position.
+    ///
+    /// Returns the value actually stored, or `None` for an unknown index.
+    pub fn set_value(&self, index: usize, value: f32) -> Option<f32> {
+        let (atomic, desc) = self
             .inner
             .values
             .get(index)
-            .zip(self.inner.descriptors.get(index))
-        {
-            let clamped = value.clamp(desc.min, desc.max);
-            atomic.store(clamped.to_bits(), Ordering::Release);
-        }
+            .zip(self.inner.descriptors.get(index))?;
+        let snapped = desc.snap(value);
+        atomic.store(snapped.to_bits(), Ordering::Release);
+        Some(snapped)
     }
 
     /// Whether the effect is bypassed (GUI toggle).
     pub fn is_bypassed(&self) -> bool {
-        self.inner.bypassed.load(Ordering::Acquire)
+        self.is_slot_bypassed(0)
     }
 
     /// Set the bypass state (GUI toggle).
     pub fn set_bypassed(&self, bypassed: bool) {
-        self.inner.bypassed.store(bypassed, Ordering::Release);
+        self.set_slot_bypassed(0, bypassed);
+    }
+
+    /// Whether `slot` is bypassed. Out-of-range slots report `false`.
+    pub fn is_slot_bypassed(&self, slot: usize) -> bool {
+        match self.inner.slots.get(slot) {
+            Some(SlotInfo {
+                bypass_param: Some(index),
+                ..
+            }) => self.get_value(*index).is_some_and(|v| v >= 0.5),
+            Some(s) => s.bypassed.load(Ordering::Acquire),
+            None => false,
+        }
+    }
+
+    /// Set the bypass state of `slot`. No-op if out of range.
+    ///
+    /// For slots with a `Bypass` parameter this writes the parameter, so the
+    /// audio thread reports the change to the host like any other value.
+    pub fn set_slot_bypassed(&self, slot: usize, bypassed: bool) {
+        match self.inner.slots.get(slot) {
+            Some(SlotInfo {
+                bypass_param: Some(index),
+                ..
+            }) => {
+                self.set_value(*index, if bypassed { 1.0 } else { 0.0 });
+            }
+            Some(s) => s.bypassed.store(bypassed, Ordering::Release),
+            None => {}
+        }
     }
 
     /// Get the cached latency in samples.
@@ -182,6 +556,32 @@ impl SonidoShared {
         }
     }
 
+    /// Ask the host to resize the editor to `width` × `height` host pixels.
+    ///
+    /// Returns `false` if no callback is set or the host refused.
+    pub fn request_host_resize(&self, width: u32, height: u32) -> bool {
+        self.inner
+            .host_resize
+            .as_ref()
+            .is_some_and(|cb| cb(width, height))
+    }
+
+    /// User editor zoom factor (1.0 = 100%).
+    pub fn ui_zoom(&self) -> f32 {
+        f32::from_bits(self.inner.ui_zoom.load(Ordering::Acquire))
+    }
+
+    /// Set the user editor zoom, clamped to [`MIN_UI_ZOOM`]..=[`MAX_UI_ZOOM`].
+    /// Non-finite values reset to 100%.
+    pub fn set_ui_zoom(&self, zoom: f32) {
+        let zoom = if zoom.is_finite() {
+            zoom.clamp(MIN_UI_ZOOM, MAX_UI_ZOOM)
+        } else {
+            1.0
+        };
+        self.inner.ui_zoom.store(zoom.to_bits(), Ordering::Release);
+    }
+
     /// Signal the start of a parameter gesture (GUI drag start).
     ///
     /// Sets the `GESTURE_BEGIN` flag for the given parameter. The audio thread
@@ -217,15 +617,36 @@ impl SonidoShared {
     ///
     /// Called when the audio processor is activated or after loading state.
     pub fn apply_to_effect(&self, effect: &mut dyn sonido_registry::EffectWithParams) {
-        for (i, atomic) in self.inner.values.iter().enumerate() {
-            let val = f32::from_bits(atomic.load(Ordering::Acquire));
-            effect.effect_set_param(i, val);
+        self.apply_to_slot(0, effect);
+    }
+
+    /// Sync the current values of `slot`'s parameters into an effect instance.
+    pub fn apply_to_slot(&self, slot: usize, effect: &mut dyn sonido_registry::EffectWithParams) {
+        for (local, index) in self.slot_params(slot).enumerate() {
+            let val = f32::from_bits(self.inner.values[index].load(Ordering::Acquire));
+            effect.effect_set_param(local, val);
         }
     }
 }
 
 impl clack_plugin::prelude::PluginShared<'_> for SonidoShared {}
 
+/// Host-automatable bypass switch for onboard effect `slot`.
+fn bypass_descriptor(slot: usize, bypassed: bool) -> ParamDescriptor {
+    ParamDescriptor::custom(
+        "Bypass",
+        "Bypass",
+        0.0,
+        1.0,
+        if bypassed { 1.0 } else { 0.0 },
+    )
+    .with_step(1.0)
+    .with_id(ParamId(BYPASS_PARAM_ID_BASE + slot as u32), "bypass")
+    .with_description("Bypass this effect slot with a short crossfade.")
+    .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+    .with_step_labels(&["Off", "On"])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,6 +686,21 @@ mod tests {
         assert_eq!(shared.get_value(0).unwrap(), desc.min);
     }
 
+    #[test]
+    fn shared_set_value_snaps_stepped() {
+        let shared = SonidoShared::new("distortion", None);
+        let index = shared
+            .descriptors()
+            .iter()
+            .position(|d| d.flags.contains(sonido_core::ParamFlags::STEPPED))
+            .expect("distortion has a stepped parameter");
+        let desc = shared.descriptor(index).unwrap();
+
+        let stored = shared.set_value(index, desc.min + desc.step * 1.4);
+        assert_eq!(stored, Some(desc.min + desc.step));
+        assert_eq!(shared.get_value(index), stored);
+    }
+
     #[test]
     fn shared_index_by_id_finds_params() {
         let shared = SonidoShared::new("distortion", None);
@@ -405,4 +841,227 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn effect_plugin_has_single_slot() {
+        let shared = SonidoShared::new("reverb", None);
+        assert!(!shared.is_instrument());
+        assert!(!shared.has_key_input());
+        assert_eq!(shared.slot_count(), 1);
+        assert_eq!(shared.slot_params(0), 0..shared.param_count());
+        assert_eq!(shared.locate(0), Some((0, 0)));
+        assert_eq!(shared.locate(shared.param_count()), None);
+    }
+
+    #[test]
+    fn keyed_effects_have_key_input() {
+        for &id in KEYED_EFFECT_IDS {
+            let shared = SonidoShared::new(id, None);
+            assert!(shared.has_key_input(), "{id} should expose a key input");
+        }
+        assert!(!SonidoShared::new_instrument(&["compressor"], None).has_key_input());
+    }
+
+    #[test]
+    fn instrument_flattens_synth_and_fx_slots() {
+        let shared = SonidoShared::new_instrument(&["chorus", "reverb"], None);
+        assert!(shared.is_instrument());
+        assert_eq!(shared.effect_id(), SYNTH_EFFECT_ID);
+        assert_eq!(shared.slot_count(), 3);
+        assert_eq!(shared.slot_effect_id(1), Some("chorus"));
+        assert_eq!(shared.slot_effect_id(2), Some("reverb"));
+
+        let synth = shared.slot_params(0);
+        let chorus = shared.slot_params(1);
+        let reverb = shared.slot_params(2);
+        assert_eq!(synth.start, 0);
+        assert_eq!(synth.end, chorus.start);
+        assert_eq!(chorus.end, reverb.start);
+        assert_eq!(shared.slot_bypass_param(1), Some(reverb.end));
+        assert_eq!(shared.slot_bypass_param(2), Some(reverb.end + 1));
+        assert_eq!(shared.param_count(), reverb.end + 2);
+        assert_eq!(shared.locate(chorus.start + 1), Some((1, 1)));
+
+        // Every flat param ID resolves back to its own index.
+        for (i, desc) in shared.descriptors().iter().enumerate() {
+            assert_eq!(shared.index_by_id(desc.id.0), Some(i), "{}", desc.string_id);
+        }
+    }
+
+    #[test]
+    fn repeated_effects_get_slot_scoped_ids() {
+        let shared = SonidoShared::new_instrument(&["delay", "chorus", "delay"], None);
+        let first = shared.slot_params(1);
+        let repeat = shared.slot_params(3);
+        assert_eq!(first.len(), repeat.len());
+        for (a, b) in first.zip(repeat) {
+            let (a, b) = (shared.descriptor(a).unwrap(), shared.descriptor(b).unwrap());
+            assert!(a.id.0 < SLOT_PARAM_ID_BASE, "first instance keeps its IDs");
+            assert_eq!(b.id.0, slot_param_id(3, a.id.0));
+            assert_eq!(a.string_id, b.string_id);
+        }
+        for (i, desc) in shared.descriptors().iter().enumerate() {
+            assert_eq!(shared.index_by_id(desc.id.0), Some(i), "{}", desc.string_id);
+        }
+
+        // Slot-scoped IDs hold every effect's range and stay below bypass IDs.
+        let registry = EffectRegistry::new();
+        for desc in registry.all_effects() {
+            let shared = SonidoShared::new(desc.id, None);
+            assert!(shared.descriptors().iter().all(|d| d.id.0 < 0x1_0000));
+        }
+        assert!(slot_param_id(MAX_FX_SLOTS, 0xFFFF) < BYPASS_PARAM_ID_BASE);
+    }
+
+    #[test]
+    fn module_paths_follow_groups_and_slots() {
+        let shared = SonidoShared::new("compressor", None);
+        for (i, desc) in shared.descriptors().iter().enumerate() {
+            assert_eq!(shared.module_path(i).as_deref(), Some(desc.group));
+        }
+
+        let shared = SonidoShared::new_instrument(&["chorus"], None);
+        assert!(shared.module_path(0).unwrap().starts_with("Synth/"));
+        let chorus = shared.slot_params(1);
+        for i in chorus {
+            let desc = shared.descriptor(i).unwrap();
+            let expected = if desc.group.is_empty() {
+                "Chorus".to_owned()
+            } else {
+                format!("Chorus/{}", desc.group)
+            };
+            assert_eq!(shared.module_path(i), Some(expected));
+        }
+        assert_eq!(shared.module_path(shared.param_count()), None);
+    }
+
+    #[test]
+    fn slot_labels_rename_module_paths() {
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&requests);
+        let shared = SonidoShared::new_instrument(&["distortion"], None).with_host_callback(
+            Box::new(move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+        let first = shared.slot_params(1).start;
+        let bypass = shared.slot_bypass_param(1).unwrap();
+        assert_eq!(shared.slot_display_name(1), "Distortion");
+        assert!(!shared.take_labels_changed());
+
+        shared.set_slot_label(1, Some("  Lead Boost "));
+        assert_eq!(shared.slot_label(1).as_deref(), Some("Lead Boost"));
+        assert!(shared.module_path(first).unwrap().starts_with("Lead Boost"));
+        assert_eq!(shared.module_path(bypass).as_deref(), Some("Lead Boost"));
+        assert!(shared.take_labels_changed());
+        assert!(!shared.take_labels_changed());
+
+        // Setting the same label again is not a change.
+        shared.set_slot_label(1, Some("Lead Boost"));
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+
+        let long = "x".repeat(MAX_SLOT_LABEL_CHARS + 8);
+        shared.set_slot_label(1, Some(&long));
+        assert_eq!(
+            shared.slot_label(1).map(|l| l.chars().count()),
+            Some(MAX_SLOT_LABEL_CHARS)
+        );
+
+        shared.set_slot_label(1, Some(" "));
+        assert_eq!(shared.slot_label(1), None);
+        assert_eq!(shared.slot_display_name(1), "Distortion");
+        assert_eq!(requests.load(Ordering::Relaxed), 3);
+
+        // Out of range is safe.
+        shared.set_slot_label(9, Some("Nope"));
+        assert_eq!(shared.slot_display_name(9), "");
+    }
+
+    #[test]
+    fn slot_colors() {
+        let shared = SonidoShared::new_instrument(&["chorus"], None);
+        assert_eq!(shared.slot_color(1), None);
+        for color in SlotColor::ALL {
+            shared.set_slot_color(1, Some(color));
+            assert_eq!(shared.slot_color(1), Some(color));
+        }
+        shared.set_slot_color(1, None);
+        assert_eq!(shared.slot_color(1), None);
+        shared.set_slot_color(9, Some(SlotColor::Red));
+        assert_eq!(shared.slot_color(9), None);
+    }
+
+    #[test]
+    fn instrument_fx_slots_start_bypassed() {
+        let shared = SonidoShared::new_instrument(&["delay"], None);
+        assert!(!shared.is_slot_bypassed(0));
+        assert!(shared.is_slot_bypassed(1));
+        shared.set_slot_bypassed(1, false);
+        assert!(!shared.is_slot_bypassed(1));
+        // Out of range is safe.
+        shared.set_slot_bypassed(9, true);
+        assert!(!shared.is_slot_bypassed(9));
+    }
+
+    #[test]
+    fn fx_slot_bypass_is_a_host_param() {
+        let shared = SonidoShared::new_instrument(&["chorus", "delay"], None);
+        assert_eq!(shared.slot_bypass_param(0), None);
+        let index = shared.slot_bypass_param(2).unwrap();
+        assert_eq!(shared.bypass_slot(index), Some(2));
+        assert_eq!(shared.locate(index), None);
+        assert_eq!(shared.module_path(index).as_deref(), Some("Delay"));
+
+        let desc = shared.descriptor(index).unwrap();
+        assert_eq!(desc.id.0, BYPASS_PARAM_ID_BASE + 2);
+        assert!(desc.flags.contains(ParamFlags::AUTOMATABLE));
+        assert_eq!(shared.index_by_id(desc.id.0), Some(index));
+
+        // Host automation and the GUI toggle share the parameter.
+        shared.set_value(index, 0.0);
+        assert!(!shared.is_slot_bypassed(2));
+        shared.set_slot_bypassed(2, true);
+        assert_eq!(shared.get_value(index), Some(1.0));
+
+        // Effect plugins keep a plain toggle.
+        assert_eq!(SonidoShared::new("delay", None).slot_bypass_param(0), None);
+    }
+
+    #[test]
+    fn apply_to_slot_uses_local_indices() {
+        let shared = SonidoShared::new_instrument(&["distortion"], None);
+        let start = shared.slot_params(1).start;
+        shared.set_value(start, 20.0);
+
+        let registry = EffectRegistry::new();
+        let mut effect = registry.create("distortion", 48000.0).unwrap();
+        shared.apply_to_slot(1, effect.as_mut());
+        assert_eq!(effect.effect_get_param(0), 20.0);
+    }
+
+    #[test]
+    fn ui_zoom_defaults_and_clamps() {
+        let shared = SonidoShared::new("distortion", None);
+        assert_eq!(shared.ui_zoom(), 1.0);
+
+        shared.set_ui_zoom(1.5);
+        assert_eq!(shared.ui_zoom(), 1.5);
+        shared.set_ui_zoom(10.0);
+        assert_eq!(shared.ui_zoom(), MAX_UI_ZOOM);
+        shared.set_ui_zoom(0.1);
+        assert_eq!(shared.ui_zoom(), MIN_UI_ZOOM);
+        shared.set_ui_zoom(f32::NAN);
+        assert_eq!(shared.ui_zoom(), 1.0);
+    }
+
+    #[test]
+    fn host_resize_callback() {
+        let shared = SonidoShared::new("distortion", None);
+        assert!(!shared.request_host_resize(640, 480));
+
+        let shared = SonidoShared::new("distortion", None)
+            .with_host_resize(Box::new(|w, h| w == 640 && h == 480));
+        assert!(shared.request_host_resize(640, 480));
+        assert!(!shared.request_host_resize(1, 1));
+    }
 }
@@ -165,3 +165,25 @@ fn state_clamps_out_of_range_values() {
     // Should be clamped to max.
     assert_eq!(shared.get_value(0).unwrap(), desc.max);
 }
+
+#[test]
+fn instrument_state_roundtrip_across_slots() {
+    let shared = SonidoShared::new_instrument(&["chorus", "reverb"], None);
+    // Move every param (synth and effect slots) to its max.
+    for (i, desc) in shared.descriptors().iter().enumerate() {
+        shared.set_value(i, desc.max);
+    }
+    let json = serialize_state(&shared);
+
+    let shared2 = SonidoShared::new_instrument(&["chorus", "reverb"], None);
+    deserialize_state(&shared2, &json);
+
+    for (i, desc) in shared.descriptors().iter().enumerate() {
+        assert_eq!(
+            shared2.get_value(i).unwrap(),
+            desc.max,
+            "instrument param {} roundtrip mismatch",
+            desc.string_id
+        );
+    }
+}
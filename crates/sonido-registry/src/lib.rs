@@ -76,18 +76,21 @@ pub const PEDAL_EFFECT_IDS: &[&str] = &[
     "reverb",     // 5
     "ringmod",    // 6
     "filter",     // 7
+    "gate",       // 8
 ];
 
 // Re-export EffectWithParams from core (moved there to unblock ProcessingGraph).
 pub use sonido_core::EffectWithParams;
 use sonido_core::{Adapter, ParamDescriptor};
 use sonido_effects::kernels::{
-    AmpKernel, BitcrusherKernel, CabinetKernel, ChorusKernel, CompressorKernel, DeesserKernel,
-    DelayKernel, DistortionKernel, DroneKernel, EqKernel, FilterKernel, FlangerKernel, GateKernel,
-    GlitchKernel, LimiterKernel, LooperKernel, MultibandCompKernel, PhaserKernel, PitchShiftKernel,
-    PlateReverbKernel, PreampKernel, ReverbKernel, RingModKernel, ShelvingEqKernel,
-    SpringReverbKernel, StageKernel, StereoWidenerKernel, TapeKernel, TextureKernel,
-    TimeStretchKernel, TransientShaperKernel, TremoloKernel, TunerKernel, VibratoKernel, WahKernel,
+    AgcKernel, AlignKernel, AmpKernel, BitcrusherKernel, CabinetKernel, ChorusKernel,
+    CompressorKernel, DeesserKernel, DelayKernel, DistortionKernel, DroneKernel, EqKernel,
+    FdnReverbKernel, FilterKernel, FlangerKernel, GateKernel, GlitchKernel, LimiterKernel,
+    LooperKernel, MultiTapDelayKernel, MultibandCompKernel, PhaseRotatorKernel, PhaserKernel,
+    PitchShiftKernel, PlateReverbKernel, PreampKernel, ReverbKernel, RingModKernel,
+    ShelvingEqKernel, SlicerKernel, SpringReverbKernel, StageKernel, StereoWidenerKernel,
+    TapeKernel, TestSignalKernel, TextureKernel, TimeStretchKernel, TransientShaperKernel,
+    TremoloKernel, TunerKernel, VibratoKernel, WahKernel,
 };
 
 /// Category of audio effect for organization and filtering.
@@ -108,6 +111,17 @@ pub enum EffectCategory {
 }
 
 impl EffectCategory {
+    /// Every category, in menu order (signal-chain convention: dynamics and
+    /// drive first, time-based and utility last).
+    pub const ALL: [EffectCategory; 6] = [
+        EffectCategory::Dynamics,
+        EffectCategory::Distortion,
+        EffectCategory::Modulation,
+        EffectCategory::Filter,
+        EffectCategory::TimeBased,
+        EffectCategory::Utility,
+    ];
+
     /// Returns a human-readable name for the category.
     pub const fn name(&self) -> &'static str {
         match self {
@@ -186,7 +200,7 @@ impl EffectRegistry {
     /// Create a new registry with all built-in effects registered.
     pub fn new() -> Self {
         let mut registry = Self {
-            entries: Vec::with_capacity(35),
+            entries: Vec::with_capacity(36),
         };
         registry.register_builtin_effects();
         registry
@@ -226,7 +240,7 @@ impl EffectRegistry {
                 id: "chorus",
                 name: "Chorus",
                 short_name: "CHOR",
-                description: "Multi-voice modulated delay chorus with feedback and tempo sync",
+                description: "Multi-voice modulated delay chorus with stereo spread, feedback, and tempo sync",
                 category: EffectCategory::Modulation,
                 param_count: 0,
             },
@@ -239,7 +253,7 @@ impl EffectRegistry {
                 id: "flanger",
                 name: "Flanger",
                 short_name: "FLNG",
-                description: "Classic flanger with through-zero mode, bipolar feedback, and tempo sync",
+                description: "Stereo flanger with through-zero mode, bipolar and cross feedback, and tempo sync",
                 category: EffectCategory::Modulation,
                 param_count: 0,
             },
@@ -272,6 +286,19 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(DelayKernel::new(sr), sr)),
         );
 
+        // Multi-Tap Delay
+        self.register(
+            EffectDescriptor {
+                id: "multitap_delay",
+                name: "Multi-Tap Delay",
+                short_name: "MTAP",
+                description: "Four-tap delay with per-tap level, pan, and feedback, and rhythmic tap patterns",
+                category: EffectCategory::TimeBased,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(MultiTapDelayKernel::new(sr), sr)),
+        );
+
         // LowPass Filter
         self.register(
             EffectDescriptor {
@@ -337,6 +364,19 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(ReverbKernel::new(sr), sr)),
         );
 
+        // FDN Reverb
+        self.register(
+            EffectDescriptor {
+                id: "fdn_reverb",
+                name: "FDN Reverb",
+                short_name: "FDN",
+                description: "Modulated 8-line feedback delay network reverb with a Householder matrix",
+                category: EffectCategory::TimeBased,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(FdnReverbKernel::new(sr), sr)),
+        );
+
         // Tremolo
         self.register(
             EffectDescriptor {
@@ -454,6 +494,32 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(LooperKernel::new(sr), sr)),
         );
 
+        // AGC
+        self.register(
+            EffectDescriptor {
+                id: "agc",
+                name: "AGC",
+                short_name: "AGC",
+                description: "Slow automatic gain control toward a target RMS or LUFS level",
+                category: EffectCategory::Utility,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(AgcKernel::new(sr), sr)),
+        );
+
+        // Align
+        self.register(
+            EffectDescriptor {
+                id: "align",
+                name: "Align",
+                short_name: "Algn",
+                description: "Sample-accurate delay and polarity for aligning parallel signals",
+                category: EffectCategory::Utility,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(AlignKernel::new(sr), sr)),
+        );
+
         // Amp
         self.register(
             EffectDescriptor {
@@ -532,6 +598,19 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(MultibandCompKernel::new(sr), sr)),
         );
 
+        // Phase Rotator
+        self.register(
+            EffectDescriptor {
+                id: "phase_rotator",
+                name: "Phase Rotator",
+                short_name: "PRot",
+                description: "Cascaded allpasses that rotate phase around a chosen frequency",
+                category: EffectCategory::Utility,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(PhaseRotatorKernel::new(sr), sr)),
+        );
+
         // Pitch Shift
         self.register(
             EffectDescriptor {
@@ -571,6 +650,19 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(ShelvingEqKernel::new(sr), sr)),
         );
 
+        // Slicer
+        self.register(
+            EffectDescriptor {
+                id: "slicer",
+                name: "Slicer",
+                short_name: "Slcr",
+                description: "Tempo-synced 16-step rhythmic gate with accents, shuffle, and attack/release shaping",
+                category: EffectCategory::Modulation,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(SlicerKernel::new(sr), sr)),
+        );
+
         // Spring Reverb
         self.register(
             EffectDescriptor {
@@ -597,6 +689,19 @@ impl EffectRegistry {
             |sr| Box::new(Adapter::new(StereoWidenerKernel::new(sr), sr)),
         );
 
+        // Test Signal
+        self.register(
+            EffectDescriptor {
+                id: "test_signal",
+                name: "Test Signal",
+                short_name: "Test",
+                description: "Sine, white/pink noise, or sweep generator for calibrating chains",
+                category: EffectCategory::Utility,
+                param_count: 0,
+            },
+            |sr| Box::new(Adapter::new(TestSignalKernel::new(sr), sr)),
+        );
+
         // Texture
         self.register(
             EffectDescriptor {
@@ -711,6 +816,16 @@ impl EffectRegistry {
             .map(|e| (e.factory)(sample_rate))
     }
 
+    /// Parameter descriptors for an effect type, in `ParameterInfo` order.
+    ///
+    /// Returns the cached descriptors — no effect instance is created.
+    pub fn param_descriptors(&self, effect_id: &str) -> Option<&[ParamDescriptor]> {
+        self.entries
+            .iter()
+            .find(|e| e.descriptor.id == effect_id)
+            .map(|e| e.param_descriptors.as_slice())
+    }
+
     /// Find a parameter index by name for a given effect type.
     ///
     /// Scans cached parameter descriptors — no allocation required.
@@ -777,14 +892,14 @@ mod tests {
     #[test]
     fn test_registry_creation() {
         let registry = EffectRegistry::new();
-        assert_eq!(registry.len(), 35);
+        assert_eq!(registry.len(), 42);
     }
 
     #[test]
     fn test_all_effects() {
         let registry = EffectRegistry::new();
         let effects = registry.all_effects();
-        assert_eq!(effects.len(), 35);
+        assert_eq!(effects.len(), 42);
     }
 
     #[test]
@@ -816,7 +931,7 @@ mod tests {
         let registry = EffectRegistry::new();
 
         let modulation = registry.effects_in_category(EffectCategory::Modulation);
-        assert_eq!(modulation.len(), 9); // Chorus, Flanger, Phaser, Vibrato, Tremolo, RingMod, PitchShift, Texture, TimeStretch
+        assert_eq!(modulation.len(), 10); // Chorus, Flanger, Phaser, Vibrato, Tremolo, RingMod, PitchShift, Texture, TimeStretch, Slicer
 
         let dynamics = registry.effects_in_category(EffectCategory::Dynamics);
         assert_eq!(dynamics.len(), 6); // Compressor, Gate, Limiter, Deesser, MultibandComp, TransientShaper
@@ -825,13 +940,13 @@ mod tests {
         assert_eq!(distortion.len(), 6); // Distortion, Tape, Bitcrusher, Amp, Cabinet, Glitch
 
         let time_based = registry.effects_in_category(EffectCategory::TimeBased);
-        assert_eq!(time_based.len(), 6); // Delay, Reverb, Looper, Drone, PlateReverb, SpringReverb
+        assert_eq!(time_based.len(), 8); // Delay, MultiTapDelay, Reverb, FdnReverb, Looper, Drone, PlateReverb, SpringReverb
 
         let filter = registry.effects_in_category(EffectCategory::Filter);
         assert_eq!(filter.len(), 4); // Filter, Wah, ParametricEQ, ShelvingEQ
 
         let utility = registry.effects_in_category(EffectCategory::Utility);
-        assert_eq!(utility.len(), 4); // Preamp, Stage, StereoWidener, Tuner
+        assert_eq!(utility.len(), 8); // Preamp, Stage, Agc, Align, PhaseRotator, StereoWidener, TestSignal, Tuner
     }
 
     #[test]
@@ -849,7 +964,7 @@ mod tests {
         assert_eq!(reverb.name, "Reverb");
         assert_eq!(reverb.short_name, "VERB");
         assert_eq!(reverb.category, EffectCategory::TimeBased);
-        assert_eq!(reverb.param_count, 8);
+        assert_eq!(reverb.param_count, 11);
     }
 
     #[test]
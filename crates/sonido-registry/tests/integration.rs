@@ -357,6 +357,19 @@ fn param_index_by_name_returns_none_for_unknown_effect() {
     );
 }
 
+/// `param_descriptors` must match a live instance's `ParameterInfo`.
+#[test]
+fn param_descriptors_match_instance() {
+    let registry = EffectRegistry::new();
+    let descriptors = registry.param_descriptors("distortion").unwrap();
+    let effect = registry.create("distortion", 48_000.0).unwrap();
+    assert_eq!(descriptors.len(), effect.effect_param_count());
+    for (i, desc) in descriptors.iter().enumerate() {
+        assert_eq!(desc.name, effect.effect_param_info(i).unwrap().name);
+    }
+    assert!(registry.param_descriptors("ghost_effect").is_none());
+}
+
 // ── 8. `default_chain_ids()` validity ────────────────────────────────────────
 
 /// Every ID in `default_chain_ids()` must exist in the registry.
@@ -0,0 +1,329 @@
+//! The effect chain a script edits.
+//!
+//! [`ScriptChain`] is plain data — effect IDs, parameter values, bypass
+//! flags — so hosts can seed it from their current state, let a script edit
+//! it, and apply the result however suits them (rebuild a graph, push
+//! parameter changes through a bridge, or render a file offline).
+
+use crate::ScriptError;
+use sonido_core::ParamDescriptor;
+use sonido_core::graph::{GraphEngine, StereoSamples};
+use sonido_graph_dsl::{
+    EffectError, parse_effect_spec, parse_param_value, resolve_effect_name, resolve_param_index,
+};
+use sonido_registry::EffectRegistry;
+
+/// Block size used by [`ScriptChain::render`].
+pub const RENDER_BLOCK_SIZE: usize = 512;
+
+/// One effect in a [`ScriptChain`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainSlot {
+    /// Registry effect ID.
+    pub effect_id: &'static str,
+    /// Plain parameter values in `ParameterInfo` order.
+    pub params: Vec<f32>,
+    /// Whether the effect is bypassed.
+    pub bypassed: bool,
+}
+
+/// A linear effect chain edited by a script.
+pub struct ScriptChain {
+    registry: EffectRegistry,
+    slots: Vec<ChainSlot>,
+    topology_changed: bool,
+    locked: bool,
+}
+
+impl Default for ScriptChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self::from_slots(Vec::new())
+    }
+
+    /// Create a chain from a host's current effects.
+    pub fn from_slots(slots: Vec<ChainSlot>) -> Self {
+        Self {
+            registry: EffectRegistry::new(),
+            slots,
+            topology_changed: false,
+            locked: false,
+        }
+    }
+
+    /// Forbid adding and removing effects; parameters stay editable.
+    ///
+    /// For hosts with a fixed chain, such as the GUI's single-effect mode.
+    pub fn lock_topology(mut self) -> Self {
+        self.locked = true;
+        self
+    }
+
+    /// The effects in slot order.
+    pub fn slots(&self) -> &[ChainSlot] {
+        &self.slots
+    }
+
+    /// Whether effects were added or removed since the chain was created.
+    pub fn topology_changed(&self) -> bool {
+        self.topology_changed
+    }
+
+    /// The effect registry used for name resolution.
+    pub fn registry(&self) -> &EffectRegistry {
+        &self.registry
+    }
+
+    /// Append an effect from a chain spec (`"delay:time=300,feedback=40"`).
+    ///
+    /// Effect names, parameter names, and values accept the same aliases as
+    /// CLI chain specs. Returns the new slot index.
+    pub fn add(&mut self, spec: &str) -> Result<usize, ScriptError> {
+        self.check_unlocked()?;
+        let (name, params) = parse_effect_spec(spec)?;
+        let effect_id = self
+            .registry
+            .get(resolve_effect_name(&name))
+            .map(|d| d.id)
+            .ok_or_else(|| EffectError::UnknownEffect(name.clone()))?;
+        let descriptors = self.descriptors(effect_id);
+
+        let mut values: Vec<f32> = descriptors.iter().map(|d| d.default).collect();
+        for (key, value) in &params {
+            let index = resolve_param_index(&self.registry, effect_id, key).ok_or_else(|| {
+                EffectError::UnknownParameter {
+                    effect: name.clone(),
+                    param: key.clone(),
+                }
+            })?;
+            let desc = &descriptors[index];
+            values[index] = parse_param_value(desc, value)
+                .ok_or_else(|| invalid_value(key, value, desc))?
+                .clamp(desc.min, desc.max);
+        }
+
+        self.slots.push(ChainSlot {
+            effect_id,
+            params: values,
+            bypassed: false,
+        });
+        self.topology_changed = true;
+        Ok(self.slots.len() - 1)
+    }
+
+    /// Remove the effect in `slot`; later effects move up one slot.
+    pub fn remove(&mut self, slot: usize) -> Result<ChainSlot, ScriptError> {
+        self.check_unlocked()?;
+        self.slot(slot)?;
+        self.topology_changed = true;
+        Ok(self.slots.remove(slot))
+    }
+
+    /// Remove every effect.
+    pub fn clear(&mut self) -> Result<(), ScriptError> {
+        self.check_unlocked()?;
+        if !self.slots.is_empty() {
+            self.slots.clear();
+            self.topology_changed = true;
+        }
+        Ok(())
+    }
+
+    /// Current plain value of parameter `name` in `slot`.
+    pub fn param(&self, slot: usize, name: &str) -> Result<f32, ScriptError> {
+        let (index, _) = self.lookup(slot, name)?;
+        Ok(self.slots[slot].params[index])
+    }
+
+    /// Set parameter `name` in `slot`, clamped to its range. Returns the applied value.
+    pub fn set_param(&mut self, slot: usize, name: &str, value: f32) -> Result<f32, ScriptError> {
+        let (index, desc) = self.lookup(slot, name)?;
+        let value = value.clamp(desc.min, desc.max);
+        self.slots[slot].params[index] = value;
+        Ok(value)
+    }
+
+    /// Set parameter `name` in `slot` from text such as `"-6 dB"`, `"1.2 kHz"`,
+    /// or a step label (`"soft"`). Returns the applied value.
+    pub fn set_param_text(
+        &mut self,
+        slot: usize,
+        name: &str,
+        text: &str,
+    ) -> Result<f32, ScriptError> {
+        let (_, desc) = self.lookup(slot, name)?;
+        let value =
+            parse_param_value(&desc, text).ok_or_else(|| invalid_value(name, text, &desc))?;
+        self.set_param(slot, name, value)
+    }
+
+    /// Parameter names and formatted values of the effect in `slot`.
+    pub fn describe(&self, slot: usize) -> Result<Vec<(&'static str, String)>, ScriptError> {
+        let entry = self.slot(slot)?;
+        Ok(self
+            .descriptors(entry.effect_id)
+            .iter()
+            .zip(&entry.params)
+            .map(|(desc, &value)| (desc.name, desc.format_value(value)))
+            .collect())
+    }
+
+    /// Bypass or re-enable the effect in `slot`.
+    pub fn set_bypass(&mut self, slot: usize, bypassed: bool) -> Result<(), ScriptError> {
+        self.slot(slot)?;
+        self.slots[slot].bypassed = bypassed;
+        Ok(())
+    }
+
+    /// Build a linear engine for the chain; bypassed effects are left out.
+    pub fn build_engine(&self, sample_rate: f32, block_size: usize) -> GraphEngine {
+        let mut engine = GraphEngine::new_linear(sample_rate, block_size);
+        for entry in self.slots.iter().filter(|s| !s.bypassed) {
+            let Some(mut effect) = self.registry.create(entry.effect_id, sample_rate) else {
+                continue;
+            };
+            for (index, &value) in entry.params.iter().enumerate() {
+                effect.effect_set_param(index, value);
+            }
+            engine.add_effect(effect);
+        }
+        engine
+    }
+
+    /// Process `input` through the chain at `sample_rate`.
+    pub fn render(&self, input: &StereoSamples, sample_rate: f32) -> StereoSamples {
+        self.build_engine(sample_rate, RENDER_BLOCK_SIZE)
+            .process_file_stereo(input, RENDER_BLOCK_SIZE)
+    }
+
+    fn check_unlocked(&self) -> Result<(), ScriptError> {
+        if self.locked {
+            Err(ScriptError::TopologyLocked)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn slot(&self, slot: usize) -> Result<&ChainSlot, ScriptError> {
+        self.slots
+            .get(slot)
+            .ok_or(ScriptError::NoSuchSlot(slot as i64))
+    }
+
+    fn descriptors(&self, effect_id: &str) -> &[ParamDescriptor] {
+        self.registry.param_descriptors(effect_id).unwrap_or(&[])
+    }
+
+    /// Resolve parameter `name` of the effect in `slot` to its index and descriptor.
+    fn lookup(&self, slot: usize, name: &str) -> Result<(usize, ParamDescriptor), ScriptError> {
+        let effect_id = self.slot(slot)?.effect_id;
+        let index = resolve_param_index(&self.registry, effect_id, name).ok_or_else(|| {
+            EffectError::UnknownParameter {
+                effect: effect_id.to_string(),
+                param: name.to_string(),
+            }
+        })?;
+        Ok((index, self.descriptors(effect_id)[index]))
+    }
+}
+
+fn invalid_value(param: &str, value: &str, desc: &ParamDescriptor) -> EffectError {
+    EffectError::InvalidValue {
+        param: param.to_string(),
+        message: format!("'{}' is not a valid value for '{}'", value, desc.name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_resolves_aliases_and_params() {
+        let mut chain = ScriptChain::new();
+        let slot = chain.add("crusher:bits=6").unwrap();
+        assert_eq!(slot, 0);
+        assert_eq!(chain.slots()[0].effect_id, "bitcrusher");
+        assert_eq!(chain.param(0, "bit depth").unwrap(), 6.0);
+        assert!(chain.topology_changed());
+
+        assert!(matches!(
+            chain.add("nonexistent"),
+            Err(ScriptError::Effect(EffectError::UnknownEffect(_)))
+        ));
+        assert!(matches!(
+            chain.add("distortion:nonexistent=1"),
+            Err(ScriptError::Effect(EffectError::UnknownParameter { .. }))
+        ));
+        assert_eq!(chain.slots().len(), 1);
+    }
+
+    #[test]
+    fn set_param_clamps_and_parses_text() {
+        let mut chain = ScriptChain::new();
+        chain.add("delay").unwrap();
+        let (min, max) = {
+            let index = chain.registry().param_index_by_name("delay", "delay time");
+            let desc = &chain.registry().param_descriptors("delay").unwrap()[index.unwrap()];
+            (desc.min, desc.max)
+        };
+        assert_eq!(chain.set_param(0, "time", 1.0e9).unwrap(), max);
+        assert_eq!(chain.set_param(0, "time", -1.0).unwrap(), min);
+        assert_eq!(chain.set_param_text(0, "time", "350ms").unwrap(), 350.0);
+        assert_eq!(chain.param(0, "time").unwrap(), 350.0);
+        assert!(chain.set_param_text(0, "time", "loud").is_err());
+        assert!(matches!(
+            chain.set_param(3, "time", 1.0),
+            Err(ScriptError::NoSuchSlot(3))
+        ));
+    }
+
+    #[test]
+    fn locked_chain_rejects_topology_changes() {
+        let seeded = ScriptChain::new();
+        let registry = seeded.registry();
+        let params = registry
+            .param_descriptors("distortion")
+            .unwrap()
+            .iter()
+            .map(|d| d.default)
+            .collect();
+        let mut chain = ScriptChain::from_slots(vec![ChainSlot {
+            effect_id: "distortion",
+            params,
+            bypassed: false,
+        }])
+        .lock_topology();
+
+        assert!(matches!(
+            chain.add("reverb"),
+            Err(ScriptError::TopologyLocked)
+        ));
+        assert!(matches!(chain.remove(0), Err(ScriptError::TopologyLocked)));
+        assert!(matches!(chain.clear(), Err(ScriptError::TopologyLocked)));
+        chain.set_param(0, "drive", 10.0).unwrap();
+        chain.set_bypass(0, true).unwrap();
+        assert!(!chain.topology_changed());
+    }
+
+    #[test]
+    fn render_skips_bypassed_effects() {
+        let mut chain = ScriptChain::new();
+        chain.add("preamp:gain=12").unwrap();
+        let input = StereoSamples::new(vec![0.1; 4096], vec![0.1; 4096]);
+
+        let boosted = chain.render(&input, 48000.0);
+        assert_eq!(boosted.len(), input.len());
+        assert!(boosted.left[4095].abs() > 0.2);
+
+        chain.set_bypass(0, true).unwrap();
+        let dry = chain.render(&input, 48000.0);
+        assert!((dry.left[4095] - 0.1).abs() < 1e-6);
+    }
+}
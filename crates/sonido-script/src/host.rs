@@ -0,0 +1,50 @@
+//! Host integration: what a script can do beyond editing the chain.
+
+use crate::ScriptError;
+use crate::chain::ScriptChain;
+use std::path::{Path, PathBuf};
+
+/// Transport command issued by a script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transport {
+    /// Load a WAV file as the playback source.
+    Load(PathBuf),
+    /// Start or resume playback.
+    Play,
+    /// Pause playback, keeping the position.
+    Pause,
+    /// Stop playback and return to the start.
+    Stop,
+    /// Seek to a position in seconds.
+    Seek(f32),
+}
+
+/// Side effects that depend on where a script runs.
+///
+/// The CLI prints to stdout and renders files; the GUI console appends to its
+/// log and forwards transport commands to the file player. Commands a host
+/// does not implement fail with [`ScriptError::Unsupported`], which stops the
+/// script with a clear message.
+pub trait ScriptHost {
+    /// Show a line of script output (`print()`).
+    fn print(&mut self, text: &str);
+
+    /// Apply a transport command.
+    fn transport(&mut self, command: Transport) -> Result<(), ScriptError> {
+        let _ = command;
+        Err(ScriptError::Unsupported("transport"))
+    }
+
+    /// Process the WAV file at `input` through `chain` and write it to `output`.
+    ///
+    /// Implementations handle file I/O and call [`ScriptChain::render`].
+    fn render(
+        &mut self,
+        chain: &ScriptChain,
+        input: &Path,
+        output: &Path,
+    ) -> Result<(), ScriptError> {
+        let _ = (chain, input, output);
+        Err(ScriptError::Unsupported("render"))
+    }
+}
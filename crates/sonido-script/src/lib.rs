@@ -0,0 +1,95 @@
+//! Embedded scripting for Sonido effect chains.
+//!
+//! Scripts are written in [rhai](https://rhai.rs) and drive an effect chain:
+//! add and remove effects, set parameters, control the host's transport, and
+//! render files offline. They run from the CLI (`sonido script run patch.rhai`)
+//! and from the GUI's script console, which makes batch sound-design
+//! experiments and automated regression patches repeatable.
+//!
+//! # Architecture
+//!
+//! - **Chain** ([`chain`]): [`ScriptChain`] — the linear chain a script edits
+//!   (effect IDs, plain parameter values, bypass flags). Effect and parameter
+//!   names resolve through the same alias tables as CLI chain specs.
+//! - **Host** ([`host`]): [`ScriptHost`] — side effects that depend on where
+//!   the script runs (console output, transport, file I/O for rendering).
+//! - **Runner** ([`runner`]): [`ScriptRunner`] — the rhai engine with the
+//!   script API registered against a chain and a host.
+//!
+//! # Script API
+//!
+//! | Function | Description |
+//! |----------|-------------|
+//! | `effects()` | Array of available effect IDs |
+//! | `chain()` | Array of effect IDs in slot order |
+//! | `add(spec)` | Append an effect (`"distortion"`, `"delay:time=300,feedback=40"`); returns its slot |
+//! | `remove(slot)` / `clear()` | Remove one effect / all effects |
+//! | `set_param(slot, name, value)` | Set a parameter from a number or text (`"-6 dB"`, `"soft"`); returns the applied value |
+//! | `param(slot, name)` | Current plain value of a parameter |
+//! | `params(slot)` | Map of parameter name → formatted value |
+//! | `bypass(slot, on)` | Bypass or re-enable an effect |
+//! | `load(path)`, `play()`, `pause()`, `stop()`, `seek(secs)` | Transport (GUI console) |
+//! | `render(input, output)` | Process a WAV file through the chain |
+//!
+//! Numeric values are clamped to the parameter's range. `print()` goes to the
+//! host's console.
+//!
+//! ```rust,ignore
+//! use sonido_script::{ScriptChain, ScriptHost, ScriptRunner};
+//!
+//! struct Stdout;
+//! impl ScriptHost for Stdout {
+//!     fn print(&mut self, text: &str) {
+//!         println!("{text}");
+//!     }
+//! }
+//!
+//! let mut runner = ScriptRunner::new(ScriptChain::new(), Stdout);
+//! runner.run(r#"
+//!     let d = add("distortion:drive=20");
+//!     set_param(d, "level", "-6 dB");
+//!     print(params(d));
+//! "#)?;
+//! ```
+
+pub mod chain;
+pub mod host;
+pub mod runner;
+
+pub use chain::{ChainSlot, RENDER_BLOCK_SIZE, ScriptChain};
+pub use host::{ScriptHost, Transport};
+pub use runner::ScriptRunner;
+
+use sonido_graph_dsl::EffectError;
+
+/// Error type for script execution.
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptError {
+    /// Unknown effect or parameter, or an unparseable parameter value.
+    #[error(transparent)]
+    Effect(#[from] EffectError),
+
+    /// Slot index outside the chain.
+    #[error("no effect in slot {0}")]
+    NoSuchSlot(i64),
+
+    /// The host does not allow adding or removing effects.
+    #[error("the effect chain cannot be changed here")]
+    TopologyLocked,
+
+    /// The host does not provide this command.
+    #[error("{0} is not available here")]
+    Unsupported(&'static str),
+
+    /// A host command failed (file not found, audio device error, ...).
+    #[error("{0}")]
+    Host(String),
+
+    /// Script compile or runtime error, with its position.
+    #[error("script error: {0}")]
+    Eval(String),
+
+    /// Failed to read a script file.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,361 @@
+//! The rhai engine with the Sonido script API registered.
+
+use crate::ScriptError;
+use crate::chain::ScriptChain;
+use crate::host::{ScriptHost, Transport};
+use rhai::{Array, Dynamic, Engine, EvalAltResult, FLOAT, INT, Map, Position};
+use std::cell::{Ref, RefCell};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// Result type of registered script functions.
+type FnResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// Chain and host shared by the registered functions.
+struct State<H> {
+    chain: ScriptChain,
+    host: H,
+}
+
+/// Runs scripts against a [`ScriptChain`] and a [`ScriptHost`].
+///
+/// The chain persists across [`run`](Self::run) calls, so a console can run
+/// several snippets in sequence. Read the edited chain with
+/// [`chain`](Self::chain) or take it back with [`into_parts`](Self::into_parts).
+pub struct ScriptRunner<H: ScriptHost + 'static> {
+    engine: Engine,
+    state: Rc<RefCell<State<H>>>,
+}
+
+impl<H: ScriptHost + 'static> ScriptRunner<H> {
+    /// Create a runner editing `chain`, with side effects going to `host`.
+    pub fn new(chain: ScriptChain, host: H) -> Self {
+        let state = Rc::new(RefCell::new(State { chain, host }));
+        let mut engine = Engine::new();
+        register_api(&mut engine, &state);
+        Self { engine, state }
+    }
+
+    /// Abort scripts after `operations` rhai operations (0 = unlimited).
+    ///
+    /// Interactive hosts set a limit so a runaway loop cannot hang the UI.
+    pub fn set_max_operations(&mut self, operations: u64) {
+        self.engine.set_max_operations(operations);
+    }
+
+    /// Compile and run a script.
+    pub fn run(&mut self, source: &str) -> Result<(), ScriptError> {
+        self.engine
+            .run(source)
+            .map_err(|e| ScriptError::Eval(e.to_string()))
+    }
+
+    /// Read and run a script file.
+    pub fn run_file(&mut self, path: &Path) -> Result<(), ScriptError> {
+        let source = std::fs::read_to_string(path)?;
+        self.run(&source)
+    }
+
+    /// The chain as edited so far.
+    pub fn chain(&self) -> Ref<'_, ScriptChain> {
+        Ref::map(self.state.borrow(), |s| &s.chain)
+    }
+
+    /// The host.
+    pub fn host(&self) -> Ref<'_, H> {
+        Ref::map(self.state.borrow(), |s| &s.host)
+    }
+
+    /// Consume the runner, returning the edited chain and the host.
+    pub fn into_parts(self) -> (ScriptChain, H) {
+        let Self { engine, state } = self;
+        // The registered functions hold the only other references.
+        drop(engine);
+        let state = Rc::try_unwrap(state)
+            .ok()
+            .expect("engine dropped, no other references")
+            .into_inner();
+        (state.chain, state.host)
+    }
+}
+
+fn fail(e: ScriptError) -> EvalAltResult {
+    EvalAltResult::ErrorRuntime(e.to_string().into(), Position::NONE)
+}
+
+fn slot_index(slot: INT) -> FnResult<usize> {
+    usize::try_from(slot).map_err(|_| Box::new(fail(ScriptError::NoSuchSlot(slot))))
+}
+
+fn register_api<H: ScriptHost + 'static>(engine: &mut Engine, state: &Rc<RefCell<State<H>>>) {
+    let s = Rc::clone(state);
+    engine.on_print(move |text| s.borrow_mut().host.print(text));
+
+    // ── Chain ────────────────────────────────────────────────────────────
+    let s = Rc::clone(state);
+    engine.register_fn("effects", move || -> Array {
+        s.borrow()
+            .chain
+            .registry()
+            .all_effects()
+            .iter()
+            .map(|d| Dynamic::from(d.id.to_string()))
+            .collect()
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("chain", move || -> Array {
+        s.borrow()
+            .chain
+            .slots()
+            .iter()
+            .map(|slot| Dynamic::from(slot.effect_id.to_string()))
+            .collect()
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("add", move |spec: &str| -> FnResult<INT> {
+        let slot = s
+            .borrow_mut()
+            .chain
+            .add(spec)
+            .map_err(|e| Box::new(fail(e)))?;
+        Ok(slot as INT)
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("remove", move |slot: INT| -> FnResult<()> {
+        s.borrow_mut()
+            .chain
+            .remove(slot_index(slot)?)
+            .map(|_| ())
+            .map_err(|e| Box::new(fail(e)))
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("clear", move || -> FnResult<()> {
+        s.borrow_mut().chain.clear().map_err(|e| Box::new(fail(e)))
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("bypass", move |slot: INT, bypassed: bool| -> FnResult<()> {
+        s.borrow_mut()
+            .chain
+            .set_bypass(slot_index(slot)?, bypassed)
+            .map_err(|e| Box::new(fail(e)))
+    });
+
+    // ── Parameters ───────────────────────────────────────────────────────
+    let s = Rc::clone(state);
+    engine.register_fn(
+        "set_param",
+        move |slot: INT, name: &str, value: FLOAT| -> FnResult<FLOAT> {
+            let applied = s
+                .borrow_mut()
+                .chain
+                .set_param(slot_index(slot)?, name, value as f32)
+                .map_err(|e| Box::new(fail(e)))?;
+            Ok(applied as FLOAT)
+        },
+    );
+
+    let s = Rc::clone(state);
+    engine.register_fn(
+        "set_param",
+        move |slot: INT, name: &str, value: INT| -> FnResult<FLOAT> {
+            let applied = s
+                .borrow_mut()
+                .chain
+                .set_param(slot_index(slot)?, name, value as f32)
+                .map_err(|e| Box::new(fail(e)))?;
+            Ok(applied as FLOAT)
+        },
+    );
+
+    let s = Rc::clone(state);
+    engine.register_fn(
+        "set_param",
+        move |slot: INT, name: &str, text: &str| -> FnResult<FLOAT> {
+            let applied = s
+                .borrow_mut()
+                .chain
+                .set_param_text(slot_index(slot)?, name, text)
+                .map_err(|e| Box::new(fail(e)))?;
+            Ok(applied as FLOAT)
+        },
+    );
+
+    let s = Rc::clone(state);
+    engine.register_fn("param", move |slot: INT, name: &str| -> FnResult<FLOAT> {
+        let value = s
+            .borrow()
+            .chain
+            .param(slot_index(slot)?, name)
+            .map_err(|e| Box::new(fail(e)))?;
+        Ok(value as FLOAT)
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("params", move |slot: INT| -> FnResult<Map> {
+        let described = s
+            .borrow()
+            .chain
+            .describe(slot_index(slot)?)
+            .map_err(|e| Box::new(fail(e)))?;
+        Ok(described
+            .into_iter()
+            .map(|(name, value)| (name.into(), Dynamic::from(value)))
+            .collect())
+    });
+
+    // ── Transport ────────────────────────────────────────────────────────
+    let s = Rc::clone(state);
+    engine.register_fn("load", move |path: &str| -> FnResult<()> {
+        let command = Transport::Load(PathBuf::from(path));
+        s.borrow_mut()
+            .host
+            .transport(command)
+            .map_err(|e| Box::new(fail(e)))
+    });
+
+    for (name, command) in [
+        ("play", Transport::Play),
+        ("pause", Transport::Pause),
+        ("stop", Transport::Stop),
+    ] {
+        let s = Rc::clone(state);
+        engine.register_fn(name, move || -> FnResult<()> {
+            s.borrow_mut()
+                .host
+                .transport(command.clone())
+                .map_err(|e| Box::new(fail(e)))
+        });
+    }
+
+    let s = Rc::clone(state);
+    engine.register_fn("seek", move |secs: FLOAT| -> FnResult<()> {
+        let command = Transport::Seek(secs as f32);
+        s.borrow_mut()
+            .host
+            .transport(command)
+            .map_err(|e| Box::new(fail(e)))
+    });
+
+    let s = Rc::clone(state);
+    engine.register_fn("seek", move |secs: INT| -> FnResult<()> {
+        let command = Transport::Seek(secs as f32);
+        s.borrow_mut()
+            .host
+            .transport(command)
+            .map_err(|e| Box::new(fail(e)))
+    });
+
+    // ── Rendering ────────────────────────────────────────────────────────
+    let s = Rc::clone(state);
+    engine.register_fn("render", move |input: &str, output: &str| -> FnResult<()> {
+        let mut state = s.borrow_mut();
+        let State { chain, host } = &mut *state;
+        host.render(chain, Path::new(input), Path::new(output))
+            .map_err(|e| Box::new(fail(e)))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Host that records output and transport commands.
+    #[derive(Default)]
+    struct Recorder {
+        lines: Vec<String>,
+        transport: Vec<Transport>,
+    }
+
+    impl ScriptHost for Recorder {
+        fn print(&mut self, text: &str) {
+            self.lines.push(text.to_string());
+        }
+
+        fn transport(&mut self, command: Transport) -> Result<(), ScriptError> {
+            self.transport.push(command);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn script_edits_chain() {
+        let mut runner = ScriptRunner::new(ScriptChain::new(), Recorder::default());
+        runner
+            .run(
+                r#"
+                let d = add("distortion:drive=20");
+                add("reverb");
+                set_param(d, "drive", 12);
+                bypass(1, true);
+                print(chain());
+                print(param(d, "drive"));
+                "#,
+            )
+            .unwrap();
+
+        let chain = runner.chain();
+        assert_eq!(chain.slots().len(), 2);
+        assert_eq!(chain.param(0, "drive").unwrap(), 12.0);
+        assert!(chain.slots()[1].bypassed);
+        drop(chain);
+
+        let (_, host) = runner.into_parts();
+        assert_eq!(host.lines, ["[\"distortion\", \"reverb\"]", "12.0"]);
+    }
+
+    #[test]
+    fn text_values_and_transport() {
+        let mut runner = ScriptRunner::new(ScriptChain::new(), Recorder::default());
+        runner
+            .run(
+                r#"
+                let d = add("delay");
+                set_param(d, "time", "350 ms");
+                load("take.wav");
+                play();
+                seek(1.5);
+                stop();
+                "#,
+            )
+            .unwrap();
+        assert_eq!(runner.chain().param(0, "time").unwrap(), 350.0);
+
+        let (_, host) = runner.into_parts();
+        assert_eq!(
+            host.transport,
+            [
+                Transport::Load(PathBuf::from("take.wav")),
+                Transport::Play,
+                Transport::Seek(1.5),
+                Transport::Stop,
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_stop_the_script() {
+        let mut runner = ScriptRunner::new(ScriptChain::new(), Recorder::default());
+        let err = runner
+            .run(r#"add("nonexistent"); add("reverb");"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown effect"), "{err}");
+        assert!(runner.chain().slots().is_empty());
+
+        let err = runner.run(r#"render("in.wav", "out.wav");"#).unwrap_err();
+        assert!(err.to_string().contains("render is not available"), "{err}");
+
+        assert!(runner.run("let x = ;").is_err());
+    }
+
+    #[test]
+    fn operation_limit_stops_runaway_loops() {
+        let mut runner = ScriptRunner::new(ScriptChain::new(), Recorder::default());
+        runner.set_max_operations(10_000);
+        assert!(runner.run("loop {}").is_err());
+    }
+}
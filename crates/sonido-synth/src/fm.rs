@@ -25,6 +25,15 @@
 //! | 4 | D→C, D→B, then (B+C)→A | Two parallel stacks into carrier |
 //! | 5 | All parallel (A+B+C+D out) | Four independent carriers, additive |
 //!
+//! ## Synth Voices ([`FmVoice`])
+//!
+//! [`FmVoice`] is the FM engine a [`Voice`](crate::Voice) plays when set to
+//! [`VoiceEngine::Fm`](crate::VoiceEngine::Fm): a [`Fm4Op`] using 2, 3, or
+//! 4 of its operators, each with its own [`AdsrEnvelope`] so modulators can
+//! decay independently of the carrier (the bright attack of an electric
+//! piano or bell). Unused operators are silent, so with 2 operators every
+//! modulating algorithm reduces to B→A.
+//!
 //! ## Reference
 //!
 //! Chowning, J. (1973). "The Synthesis of Complex Audio Spectra by Means of
@@ -33,6 +42,8 @@
 
 use core::f32::consts::PI;
 
+use crate::envelope::AdsrEnvelope;
+
 /// A single FM operator: a sine oscillator with a frequency ratio and
 /// modulation index.
 ///
@@ -341,57 +352,197 @@ impl Fm4Op {
     /// Generate one output sample using the selected algorithm.
     #[inline]
     pub fn advance(&mut self) -> f32 {
+        self.advance_with([1.0; 4], 1.0)
+    }
+
+    /// Generate one output sample with per-operator gains and a shared
+    /// modulation depth.
+    ///
+    /// `gains` scale each operator's output (A, B, C, D) before it modulates
+    /// or is heard — envelope levels, or 0.0 to silence an operator.
+    /// `index_scale` multiplies every operator's `mod_index`.
+    #[inline]
+    pub fn advance_with(&mut self, gains: [f32; 4], index_scale: f32) -> f32 {
         let [a, b, c, d] = &mut self.ops;
+        let [ga, gb, gc, gd] = gains;
         match self.algorithm {
             Fm4Algorithm::Serial => {
                 // D→C→B→A
-                let d_out = d.advance(0.0) * d.mod_index;
-                let c_out = c.advance(d_out) * c.mod_index;
-                let b_out = b.advance(c_out) * b.mod_index;
-                a.advance(b_out)
+                let d_out = d.advance(0.0) * gd * d.mod_index * index_scale;
+                let c_out = c.advance(d_out) * gc * c.mod_index * index_scale;
+                let b_out = b.advance(c_out) * gb * b.mod_index * index_scale;
+                a.advance(b_out) * ga
             }
             Fm4Algorithm::Parallel2 => {
                 // (C+D)→B→A
-                let d_out = d.advance(0.0) * d.mod_index;
-                let c_out = c.advance(0.0) * c.mod_index;
-                let b_out = b.advance(d_out + c_out) * b.mod_index;
-                a.advance(b_out)
+                let d_out = d.advance(0.0) * gd * d.mod_index * index_scale;
+                let c_out = c.advance(0.0) * gc * c.mod_index * index_scale;
+                let b_out = b.advance(d_out + c_out) * gb * b.mod_index * index_scale;
+                a.advance(b_out) * ga
             }
             Fm4Algorithm::Parallel3 => {
                 // (B+C+D)→A
-                let d_out = d.advance(0.0) * d.mod_index;
-                let c_out = c.advance(0.0) * c.mod_index;
-                let b_out = b.advance(0.0) * b.mod_index;
-                a.advance(d_out + c_out + b_out)
+                let d_out = d.advance(0.0) * gd * d.mod_index * index_scale;
+                let c_out = c.advance(0.0) * gc * c.mod_index * index_scale;
+                let b_out = b.advance(0.0) * gb * b.mod_index * index_scale;
+                a.advance(d_out + c_out + b_out) * ga
             }
             Fm4Algorithm::DoubleStack => {
                 // D→C→A and D→B→A (D is shared modulator)
-                let d_raw = d.advance(0.0);
-                let d_mod = d_raw * d.mod_index;
-                let c_out = c.advance(d_mod) * c.mod_index;
-                let b_out = b.advance(d_mod) * b.mod_index;
-                a.advance(c_out + b_out)
+                let d_raw = d.advance(0.0) * gd;
+                let d_mod = d_raw * d.mod_index * index_scale;
+                let c_out = c.advance(d_mod) * gc * c.mod_index * index_scale;
+                let b_out = b.advance(d_mod) * gb * b.mod_index * index_scale;
+                a.advance(c_out + b_out) * ga
             }
             Fm4Algorithm::DualSerial => {
                 // D→C, D→B independently, then (C+B)→A
-                let d_raw = d.advance(0.0);
-                let d_mod = d_raw * d.mod_index;
-                let c_out = c.advance(d_mod) * c.mod_index;
-                let b_out = b.advance(d_mod) * b.mod_index;
-                a.advance((c_out + b_out) * 0.5) // average to keep level stable
+                let d_raw = d.advance(0.0) * gd;
+                let d_mod = d_raw * d.mod_index * index_scale;
+                let c_out = c.advance(d_mod) * gc * c.mod_index * index_scale;
+                let b_out = b.advance(d_mod) * gb * b.mod_index * index_scale;
+                a.advance((c_out + b_out) * 0.5) * ga // average to keep level stable
             }
             Fm4Algorithm::Additive => {
                 // All carriers, no modulation — just sum
-                let a_out = a.advance(0.0);
-                let b_out = b.advance(0.0);
-                let c_out = c.advance(0.0);
-                let d_out = d.advance(0.0);
+                let a_out = a.advance(0.0) * ga;
+                let b_out = b.advance(0.0) * gb;
+                let c_out = c.advance(0.0) * gc;
+                let d_out = d.advance(0.0) * gd;
                 (a_out + b_out + c_out + d_out) * 0.25
             }
         }
     }
 }
 
+/// FM engine for a synth voice: a [`Fm4Op`] with 2–4 active operators and
+/// an [`AdsrEnvelope`] per operator.
+///
+/// ## Parameters
+///
+/// - `operator_count`: Active operators, A first (2 to 4, default 2)
+/// - Per operator: `ratio`, `mod_index`, `output_level` (on [`FmVoice::engine`])
+///   and ADSR (on [`FmVoice::envelopes`], default 1/100/100%/200 ms)
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_synth::fm::{Fm4Algorithm, FmVoice};
+///
+/// // Bell: carrier A at 1:1, modulator B at 3.5:1 decaying quickly
+/// let mut fm = FmVoice::new(48000.0);
+/// fm.set_operator_count(2);
+/// fm.set_algorithm(Fm4Algorithm::Serial);
+/// fm.engine.ops[1].set_ratio(3.5);
+/// fm.engine.ops[1].set_mod_index(4.0);
+/// fm.envelopes[1].set_decay_ms(400.0);
+/// fm.envelopes[1].set_sustain(0.0);
+///
+/// fm.note_on();
+/// let sample = fm.advance(440.0, 1.0);
+/// assert!(sample.is_finite());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FmVoice {
+    /// Operators and routing.
+    pub engine: Fm4Op,
+    /// Per-operator envelopes: [A, B, C, D].
+    pub envelopes: [AdsrEnvelope; 4],
+    /// Number of active operators (2 to 4).
+    operator_count: usize,
+}
+
+impl Default for FmVoice {
+    fn default() -> Self {
+        Self::new(48000.0)
+    }
+}
+
+impl FmVoice {
+    /// Create a 2-operator FM voice.
+    pub fn new(sample_rate: f32) -> Self {
+        let envelopes = core::array::from_fn(|_| {
+            let mut env = AdsrEnvelope::new(sample_rate);
+            env.set_attack_ms(1.0);
+            env.set_sustain(1.0);
+            env
+        });
+        Self {
+            engine: Fm4Op::new(sample_rate),
+            envelopes,
+            operator_count: 2,
+        }
+    }
+
+    /// Set the number of active operators.
+    ///
+    /// Range: 2 to 4. Operators past the count (D first) are silent.
+    pub fn set_operator_count(&mut self, count: usize) {
+        self.operator_count = count.clamp(2, 4);
+    }
+
+    /// Get the number of active operators.
+    pub fn operator_count(&self) -> usize {
+        self.operator_count
+    }
+
+    /// Set the routing algorithm.
+    pub fn set_algorithm(&mut self, algorithm: Fm4Algorithm) {
+        self.engine.set_algorithm(algorithm);
+    }
+
+    /// Get the routing algorithm.
+    pub fn algorithm(&self) -> Fm4Algorithm {
+        self.engine.algorithm()
+    }
+
+    /// Set sample rate for operators and envelopes.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.engine.set_sample_rate(sample_rate);
+        for env in &mut self.envelopes {
+            env.set_sample_rate(sample_rate);
+        }
+    }
+
+    /// Restart operator phases and open every envelope.
+    pub fn note_on(&mut self) {
+        self.engine.reset();
+        for env in &mut self.envelopes {
+            env.gate_on();
+        }
+    }
+
+    /// Release every envelope.
+    pub fn note_off(&mut self) {
+        for env in &mut self.envelopes {
+            env.gate_off();
+        }
+    }
+
+    /// Reset phases and envelopes.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+        for env in &mut self.envelopes {
+            env.reset();
+        }
+    }
+
+    /// Generate one sample at `freq_hz`, with every modulation index scaled
+    /// by `index_scale` (1.0 = as set).
+    #[inline]
+    pub fn advance(&mut self, freq_hz: f32, index_scale: f32) -> f32 {
+        self.engine.set_base_frequency(freq_hz);
+        let mut gains = [0.0; 4];
+        for (i, (gain, env)) in gains.iter_mut().zip(&mut self.envelopes).enumerate() {
+            let level = env.advance();
+            if i < self.operator_count {
+                *gain = level;
+            }
+        }
+        self.engine.advance_with(gains, index_scale)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,6 +636,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fm4op_advance_with_unit_gains_matches_advance() {
+        let mut plain = Fm4Op::new(48000.0);
+        let mut gained = Fm4Op::new(48000.0);
+        plain.set_base_frequency(330.0);
+        gained.set_base_frequency(330.0);
+        for _ in 0..256 {
+            assert_eq!(plain.advance(), gained.advance_with([1.0; 4], 1.0));
+        }
+    }
+
+    #[test]
+    fn test_fm_voice_operator_count_silences_extra_operators() {
+        // With C and D silent, Serial is a plain B→A pair
+        let mut voice = FmVoice::new(48000.0);
+        voice.set_operator_count(2);
+        voice.engine.ops[2].set_mod_index(20.0);
+        voice.engine.ops[3].set_mod_index(20.0);
+        let mut pair = Fm2Op::new(48000.0);
+        pair.modulator
+            .set_mod_index(voice.engine.ops[1].mod_index());
+        pair.set_base_frequency(220.0);
+        voice.note_on();
+        // Let the 1 ms attacks reach their sustain level of 1.0
+        for _ in 0..480 {
+            voice.advance(220.0, 1.0);
+            pair.advance();
+        }
+        for _ in 0..512 {
+            let expected = pair.advance();
+            assert!((voice.advance(220.0, 1.0) - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_fm_voice_modulator_envelope_decays_to_sine() {
+        let mut voice = FmVoice::new(48000.0);
+        voice.engine.ops[1].set_mod_index(8.0);
+        voice.envelopes[1].set_decay_ms(5.0);
+        voice.envelopes[1].set_sustain(0.0);
+        voice.note_on();
+        for _ in 0..4800 {
+            voice.advance(100.0, 1.0);
+        }
+        // Modulator has decayed: the carrier alone is a 100 Hz sine, so a
+        // sample a half period later is its negation
+        let x = voice.advance(100.0, 1.0);
+        for _ in 0..239 {
+            voice.advance(100.0, 1.0);
+        }
+        let y = voice.advance(100.0, 1.0);
+        assert!((x + y).abs() < 0.01, "{x} vs {y}");
+    }
+
     #[test]
     fn test_fm_operator_ratio_affects_frequency() {
         // Two operators: one at ratio 1, one at ratio 2
@@ -48,6 +48,7 @@
 //! - [`fm::Fm4Op`] - 4-operator DX7-style FM engine
 //! - [`fm::FmOperator`] - Single FM operator building block
 //! - [`fm::Fm4Algorithm`] - Routing topologies for 4-op FM
+//! - [`fm::FmVoice`] - 2–4 operator FM with per-operator envelopes, for synth voices
 //!
 //! ```rust
 //! use sonido_synth::fm::{Fm2Op, Fm4Op, Fm4Algorithm};
@@ -84,7 +85,11 @@
 //! For building polyphonic synthesizers:
 //!
 //! - [`Voice`] - Single synthesizer voice with MPE support
-//! - [`VoiceManager`] - Polyphonic voice allocation
+//! - [`VoiceEngine`] - Subtractive oscillators, 2–4 operator FM ([`fm::FmVoice`]),
+//!   or sample playback ([`SamplerVoice`])
+//! - [`SampleMap`] / [`Sample`] - Key-mapped samples with root note, loop points, and one-shot mode
+//! - [`VoiceManager`] - Polyphonic voice allocation, with per-channel MPE expression
+//! - [`MpeZone`] - MPE zone layout and member pitch bend range
 //! - [`VoiceAllocationMode`] - Voice stealing strategies
 //!
 //! ## Modulation
@@ -103,6 +108,14 @@
 //! - [`PolyphonicSynth`] - Multi-voice synth
 //! - [`SynthNode`] - Polyphonic synth as a graph `Effect` node
 //!
+//! ## Note Sources
+//!
+//! Tempo-synced note generators that drive any [`NoteSink`] (the synths
+//! above and [`VoiceManager`]):
+//!
+//! - [`Arpeggiator`] - Plays held notes in [`ArpMode`] order over an octave range
+//! - [`StepSequencer`] - 16-step pitch/gate sequencer
+//!
 //! # no_std Support
 //!
 //! This crate is `no_std` compatible. Disable the default `std` feature:
@@ -150,7 +163,10 @@ pub mod audio_mod;
 pub mod envelope;
 pub mod fm;
 pub mod mod_matrix;
+pub mod mpe;
 pub mod oscillator;
+pub mod sampler;
+pub mod sequencer;
 pub mod synth;
 pub mod voice;
 pub mod wavetable;
@@ -161,11 +177,18 @@ pub use envelope::{AdsrEnvelope, EnvelopeState};
 pub use mod_matrix::{
     ModDestination, ModSourceId, ModulationMatrix, ModulationRoute, ModulationValues,
 };
+pub use mpe::{MpeZone, MpeZoneSide, pitch_bend_from_midi};
 pub use oscillator::{Oscillator, OscillatorWaveform};
-pub use synth::{MonophonicSynth, PolyphonicSynth, SynthNode};
+pub use sampler::{Sample, SampleMap, SampleZone, SamplerVoice};
+pub use sequencer::{
+    ArpMode, Arpeggiator, MAX_HELD_NOTES, NoteSink, SEQUENCER_STEPS, Step, StepSequencer,
+};
+pub use synth::{
+    GlideCurve, GlideMode, MonophonicSynth, PolyphonicSynth, SYNTH_PARAM_COUNT, SynthNode,
+};
 pub use voice::{
-    MAX_UNISON, SubVoice, Voice, VoiceAllocationMode, VoiceManager, cents_to_ratio, freq_to_midi,
-    midi_to_freq,
+    MAX_UNISON, SubVoice, Voice, VoiceAllocationMode, VoiceEngine, VoiceManager, cents_to_ratio,
+    freq_to_midi, midi_to_freq,
 };
 
 // Re-export commonly used types from sonido-core
@@ -63,6 +63,9 @@ pub enum ModDestination {
     EffectParam1,
     /// Effect parameter 2
     EffectParam2,
+    /// FM modulation depth (scales every operator's index; -1 silences
+    /// modulation, +1 doubles it)
+    FmIndex,
 }
 
 /// A single modulation route.
@@ -0,0 +1,183 @@
+//! MPE (MIDI Polyphonic Expression) zone configuration.
+//!
+//! MPE gives every sounding note its own MIDI channel, so pitch bend,
+//! channel pressure, and CC 74 (timbre) act on one note instead of the whole
+//! keyboard. A zone is a master channel plus a block of member channels:
+//!
+//! - Lower zone: master channel 1, members 2, 3, ... upward
+//! - Upper zone: master channel 16, members 15, 14, ... downward
+//!
+//! Messages on a member channel shape only the notes on that channel; pitch
+//! bend on the master channel moves every note in the zone. Members default
+//! to a ±48 semitone bend range and the master to ±2, per the MPE spec.
+//!
+//! Channels here are 1-based (1–16), as in [`Voice::set_mpe_channel`].
+//!
+//! [`Voice::set_mpe_channel`]: crate::Voice::set_mpe_channel
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_synth::{MpeZone, VoiceManager};
+//!
+//! let mut manager: VoiceManager<8> = VoiceManager::new(48000.0);
+//! manager.set_mpe(Some(MpeZone::lower(15)));
+//!
+//! // Two notes on their own channels; only the first one bends
+//! manager.note_on_channel(2, 60, 100);
+//! manager.note_on_channel(3, 64, 100);
+//! manager.pitch_bend(2, 0.25); // +12 semitones at the ±48 member range
+//! ```
+
+/// Highest MIDI channel (1-based).
+pub const MIDI_CHANNELS: u8 = 16;
+
+/// Default member channel pitch bend range in semitones.
+pub const MEMBER_BEND_RANGE: f32 = 48.0;
+
+/// Default master channel (and non-MPE) pitch bend range in semitones.
+pub const MASTER_BEND_RANGE: f32 = 2.0;
+
+/// Which end of the channel range a zone occupies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MpeZoneSide {
+    /// Master channel 1, members counting up from channel 2.
+    #[default]
+    Lower,
+    /// Master channel 16, members counting down from channel 15.
+    Upper,
+}
+
+/// An MPE zone: master channel, member channels, and member bend range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MpeZone {
+    /// Lower or upper zone.
+    side: MpeZoneSide,
+    /// Number of member channels (1–15).
+    members: u8,
+    /// Member channel pitch bend range in semitones.
+    bend_range: f32,
+}
+
+impl MpeZone {
+    /// Lower zone (master channel 1) with `members` member channels.
+    ///
+    /// Range: 1 to 15 members.
+    pub fn lower(members: u8) -> Self {
+        Self::new(MpeZoneSide::Lower, members)
+    }
+
+    /// Upper zone (master channel 16) with `members` member channels.
+    ///
+    /// Range: 1 to 15 members.
+    pub fn upper(members: u8) -> Self {
+        Self::new(MpeZoneSide::Upper, members)
+    }
+
+    fn new(side: MpeZoneSide, members: u8) -> Self {
+        Self {
+            side,
+            members: members.clamp(1, MIDI_CHANNELS - 1),
+            bend_range: MEMBER_BEND_RANGE,
+        }
+    }
+
+    /// Zone from an MPE Configuration Message (RPN 6 on the master channel,
+    /// data entry MSB = member count). Returns `None` for a count of zero,
+    /// which turns the zone off, or a channel that can't be a master.
+    pub fn from_mcm(channel: u8, members: u8) -> Option<Self> {
+        if members == 0 {
+            return None;
+        }
+        match channel {
+            1 => Some(Self::lower(members)),
+            MIDI_CHANNELS => Some(Self::upper(members)),
+            _ => None,
+        }
+    }
+
+    /// Set the member channel pitch bend range in semitones.
+    ///
+    /// Range: 0.0 to 96.0. Default 48.0.
+    pub fn with_bend_range(mut self, semitones: f32) -> Self {
+        self.bend_range = semitones.clamp(0.0, 96.0);
+        self
+    }
+
+    /// Lower or upper zone.
+    pub fn side(&self) -> MpeZoneSide {
+        self.side
+    }
+
+    /// Number of member channels.
+    pub fn members(&self) -> u8 {
+        self.members
+    }
+
+    /// Member channel pitch bend range in semitones.
+    pub fn bend_range(&self) -> f32 {
+        self.bend_range
+    }
+
+    /// The zone's master channel (1 or 16).
+    pub fn master_channel(&self) -> u8 {
+        match self.side {
+            MpeZoneSide::Lower => 1,
+            MpeZoneSide::Upper => MIDI_CHANNELS,
+        }
+    }
+
+    /// Whether `channel` is one of the zone's member channels.
+    pub fn is_member(&self, channel: u8) -> bool {
+        match self.side {
+            MpeZoneSide::Lower => (2..=1 + self.members).contains(&channel),
+            MpeZoneSide::Upper => (MIDI_CHANNELS - self.members..MIDI_CHANNELS).contains(&channel),
+        }
+    }
+}
+
+/// Convert a 14-bit MIDI pitch bend value (0–16383, center 8192) to -1.0..=1.0.
+#[inline]
+pub fn pitch_bend_from_midi(value: u16) -> f32 {
+    let centered = f32::from(value.min(16383)) - 8192.0;
+    if centered < 0.0 {
+        centered / 8192.0
+    } else {
+        centered / 8191.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_channels() {
+        let lower = MpeZone::lower(3);
+        assert_eq!(lower.master_channel(), 1);
+        assert!(!lower.is_member(1));
+        assert!((2..=4).all(|ch| lower.is_member(ch)));
+        assert!(!lower.is_member(5));
+
+        let upper = MpeZone::upper(15);
+        assert_eq!(upper.master_channel(), 16);
+        assert!((1..=15).all(|ch| upper.is_member(ch)));
+        assert!(!upper.is_member(16));
+    }
+
+    #[test]
+    fn test_from_mcm() {
+        assert_eq!(MpeZone::from_mcm(1, 15), Some(MpeZone::lower(15)));
+        assert_eq!(MpeZone::from_mcm(16, 4), Some(MpeZone::upper(4)));
+        assert_eq!(MpeZone::from_mcm(1, 0), None);
+        assert_eq!(MpeZone::from_mcm(5, 4), None);
+    }
+
+    #[test]
+    fn test_pitch_bend_from_midi() {
+        assert_eq!(pitch_bend_from_midi(0), -1.0);
+        assert_eq!(pitch_bend_from_midi(8192), 0.0);
+        assert_eq!(pitch_bend_from_midi(16383), 1.0);
+        assert_eq!(pitch_bend_from_midi(4096), -0.5);
+    }
+}
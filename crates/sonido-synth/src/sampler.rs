@@ -0,0 +1,403 @@
+//! Sample playback for synth voices.
+//!
+//! A [`Sample`] is a recorded sound (a drum hit, a note of an instrument)
+//! with the key it was recorded at, optional loop points, and whether it
+//! plays to the end regardless of note-off (one-shot). A [`SampleMap`]
+//! spreads samples across key ranges, and a [`SamplerVoice`] plays the
+//! sample mapped to a note, repitched from its root key.
+//!
+//! Voices switched to [`VoiceEngine::Sampler`](crate::VoiceEngine::Sampler)
+//! play through their own `SamplerVoice`, so samples are allocated, stolen,
+//! filtered, and shaped by the amp ADSR like any other voice in a
+//! [`VoiceManager`](crate::VoiceManager).
+//!
+//! Sample data is shared (`Arc<[f32]>`): a map loaded once is handed to every
+//! voice without copying, and nothing is allocated while playing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_synth::{Sample, SampleMap, VoiceEngine, VoiceManager};
+//!
+//! // A decaying 200 Hz blip standing in for a loaded drum hit
+//! let kick: Vec<f32> = (0..4800)
+//!     .map(|i| (i as f32 * 0.026).sin() * (1.0 - i as f32 / 4800.0))
+//!     .collect();
+//!
+//! let mut map = SampleMap::new();
+//! map.add(36, 36, Sample::mono(kick, 48000.0, 36).with_one_shot(true));
+//!
+//! let mut manager: VoiceManager<8> = VoiceManager::new(48000.0);
+//! manager.set_sample_map(map);
+//! for voice in manager.voices_mut() {
+//!     voice.set_engine(VoiceEngine::Sampler);
+//! }
+//!
+//! manager.note_on(36, 127);
+//! let (left, right) = manager.process_stereo();
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use sonido_core::StereoSamples;
+
+/// A recorded sound with its root key, loop points, and playback mode.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    left: Arc<[f32]>,
+    right: Arc<[f32]>,
+    sample_rate: f32,
+    root_note: u8,
+    /// Loop start and end in frames (end exclusive)
+    loop_points: Option<(usize, usize)>,
+    one_shot: bool,
+}
+
+impl Sample {
+    /// Mono sample recorded at `sample_rate`, sounding at its recorded pitch
+    /// on `root_note`.
+    pub fn mono(data: Vec<f32>, sample_rate: f32, root_note: u8) -> Self {
+        let data: Arc<[f32]> = data.into();
+        Self {
+            left: Arc::clone(&data),
+            right: data,
+            sample_rate,
+            root_note: root_note.min(127),
+            loop_points: None,
+            one_shot: false,
+        }
+    }
+
+    /// Stereo sample, e.g. from `sonido_io::read_wav_stereo`.
+    pub fn stereo(samples: StereoSamples, sample_rate: f32, root_note: u8) -> Self {
+        let len = samples.left.len().min(samples.right.len());
+        let mut left = samples.left;
+        let mut right = samples.right;
+        left.truncate(len);
+        right.truncate(len);
+        Self {
+            left: left.into(),
+            right: right.into(),
+            sample_rate,
+            root_note: root_note.min(127),
+            loop_points: None,
+            one_shot: false,
+        }
+    }
+
+    /// Loop frames `start..end` while the note is held (and through the
+    /// release). Out-of-range or empty loops are ignored.
+    ///
+    /// Loops stored in a WAV file come from
+    /// `sonido_io::read_wav_metadata(path)?.loop_frames()`.
+    pub fn with_loop(mut self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        self.loop_points = (start < end).then_some((start, end));
+        self
+    }
+
+    /// Play to the end regardless of note-off, as for drum hits.
+    pub fn with_one_shot(mut self, one_shot: bool) -> Self {
+        self.one_shot = one_shot;
+        self
+    }
+
+    /// Length in frames.
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Whether the sample has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Recorded sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Key that plays the sample at its recorded pitch.
+    pub fn root_note(&self) -> u8 {
+        self.root_note
+    }
+
+    /// Loop start and end in frames, if looping.
+    pub fn loop_points(&self) -> Option<(usize, usize)> {
+        self.loop_points
+    }
+
+    /// Whether the sample ignores note-off.
+    pub fn is_one_shot(&self) -> bool {
+        self.one_shot
+    }
+
+    /// Linearly interpolated stereo frame at fractional `position`.
+    #[inline]
+    fn frame(&self, position: f64) -> (f32, f32) {
+        let idx = position as usize;
+        let frac = (position - idx as f64) as f32;
+        let next = (idx + 1).min(self.len() - 1);
+        let l = self.left[idx] + (self.left[next] - self.left[idx]) * frac;
+        let r = self.right[idx] + (self.right[next] - self.right[idx]) * frac;
+        (l, r)
+    }
+}
+
+/// A key range mapped to a sample.
+#[derive(Debug, Clone)]
+pub struct SampleZone {
+    /// Lowest key of the range.
+    pub low_key: u8,
+    /// Highest key of the range (inclusive).
+    pub high_key: u8,
+    /// Sample played by keys in the range.
+    pub sample: Sample,
+}
+
+/// Samples mapped across the keyboard.
+///
+/// Zones are searched in the order they were added; the first one covering
+/// a key plays it. Keys outside every zone are silent.
+#[derive(Debug, Clone, Default)]
+pub struct SampleMap {
+    zones: Vec<SampleZone>,
+}
+
+impl SampleMap {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map keys `low_key..=high_key` to `sample`. Empty samples are ignored.
+    pub fn add(&mut self, low_key: u8, high_key: u8, sample: Sample) {
+        if sample.is_empty() {
+            return;
+        }
+        self.zones.push(SampleZone {
+            low_key: low_key.min(high_key),
+            high_key: high_key.max(low_key),
+            sample,
+        });
+    }
+
+    /// All zones, in search order.
+    pub fn zones(&self) -> &[SampleZone] {
+        &self.zones
+    }
+
+    /// Index of the zone playing `note`.
+    pub fn zone_for(&self, note: u8) -> Option<usize> {
+        self.zones
+            .iter()
+            .position(|z| (z.low_key..=z.high_key).contains(&note))
+    }
+}
+
+/// Sample playback engine for one synth voice.
+///
+/// Plays the zone of a shared [`SampleMap`] covering the note, repitched
+/// by the ratio of the voice frequency to the sample's root key (and by the
+/// ratio of its recorded rate to the output rate). Samples play at their
+/// recorded level, mono samples on both channels.
+#[derive(Debug, Clone)]
+pub struct SamplerVoice {
+    map: Option<Arc<SampleMap>>,
+    /// Zone playing, if any
+    zone: Option<usize>,
+    /// Read position in frames
+    position: f64,
+    sample_rate: f32,
+}
+
+impl SamplerVoice {
+    /// Create a sampler voice with no samples.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            map: None,
+            zone: None,
+            position: 0.0,
+            sample_rate,
+        }
+    }
+
+    /// Set the output sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Set the sample map. Takes effect from the next note.
+    pub fn set_map(&mut self, map: Option<Arc<SampleMap>>) {
+        self.map = map;
+        self.zone = None;
+    }
+
+    /// The sample map.
+    pub fn map(&self) -> Option<&Arc<SampleMap>> {
+        self.map.as_ref()
+    }
+
+    /// Start the sample mapped to `note` from the beginning.
+    pub fn note_on(&mut self, note: u8) {
+        self.zone = self.map.as_ref().and_then(|map| map.zone_for(note));
+        self.position = 0.0;
+    }
+
+    /// Stop playback.
+    pub fn reset(&mut self) {
+        self.zone = None;
+        self.position = 0.0;
+    }
+
+    /// The sample playing, if any.
+    pub fn sample(&self) -> Option<&Sample> {
+        let map = self.map.as_ref()?;
+        Some(&map.zones[self.zone?].sample)
+    }
+
+    /// Whether a sample is playing.
+    pub fn is_playing(&self) -> bool {
+        self.zone.is_some()
+    }
+
+    /// Whether the playing sample ignores note-off.
+    pub fn is_one_shot(&self) -> bool {
+        self.sample().is_some_and(Sample::is_one_shot)
+    }
+
+    /// Read position in frames of the playing sample.
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Generate one stereo frame with the voice sounding at `freq` Hz.
+    ///
+    /// Returns silence and stops once a non-looping sample runs out.
+    #[inline]
+    pub fn advance(&mut self, freq: f32) -> (f32, f32) {
+        let (Some(map), Some(zone)) = (self.map.as_ref(), self.zone) else {
+            return (0.0, 0.0);
+        };
+        let sample = &map.zones[zone].sample;
+
+        if let Some((start, end)) = sample.loop_points
+            && self.position >= end as f64
+        {
+            let length = (end - start) as f64;
+            self.position = start as f64 + (self.position - start as f64) % length;
+        }
+        if self.position >= sample.len() as f64 {
+            self.zone = None;
+            return (0.0, 0.0);
+        }
+
+        let out = sample.frame(self.position);
+        let root_freq = crate::voice::midi_to_freq(sample.root_note);
+        self.position +=
+            f64::from(sample.sample_rate / self.sample_rate) * f64::from(freq / root_freq);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    fn ramp(len: usize) -> Vec<f32> {
+        (0..len).map(|i| i as f32).collect()
+    }
+
+    fn voice_with(map: SampleMap) -> SamplerVoice {
+        let mut voice = SamplerVoice::new(48000.0);
+        voice.set_map(Some(Arc::new(map)));
+        voice
+    }
+
+    #[test]
+    fn test_key_mapping_and_repitch() {
+        let mut map = SampleMap::new();
+        map.add(0, 59, Sample::mono(ramp(100), 48000.0, 48));
+        map.add(60, 127, Sample::mono(vec![1.0; 100], 24000.0, 60));
+        let mut voice = voice_with(map);
+
+        // At the root key: one frame per sample
+        voice.note_on(48);
+        voice.advance(crate::voice::midi_to_freq(48));
+        assert_eq!(voice.advance(crate::voice::midi_to_freq(48)).0, 1.0);
+
+        // An octave up: two frames per sample
+        voice.note_on(48);
+        voice.advance(crate::voice::midi_to_freq(60));
+        let (l, r) = voice.advance(crate::voice::midi_to_freq(60));
+        assert!((l - 2.0).abs() < 1e-3 && l == r);
+
+        // The upper zone was recorded at half the output rate
+        voice.note_on(72);
+        assert_eq!(voice.sample().map(Sample::root_note), Some(60));
+        voice.advance(crate::voice::midi_to_freq(60));
+        assert!((voice.position() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_one_shot_runs_out() {
+        let mut map = SampleMap::new();
+        map.add(
+            36,
+            36,
+            Sample::mono(vec![0.5; 10], 48000.0, 36).with_one_shot(true),
+        );
+        let mut voice = voice_with(map);
+
+        voice.note_on(37);
+        assert!(!voice.is_playing(), "Unmapped keys are silent");
+
+        voice.note_on(36);
+        assert!(voice.is_one_shot());
+        let freq = crate::voice::midi_to_freq(36);
+        let played = (0..20).filter(|_| voice.advance(freq).0 != 0.0).count();
+        assert_eq!(played, 10);
+        assert!(!voice.is_playing());
+    }
+
+    #[test]
+    fn test_loop_points() {
+        let mut map = SampleMap::new();
+        map.add(0, 127, Sample::mono(ramp(8), 48000.0, 60).with_loop(4, 8));
+        let mut voice = voice_with(map);
+
+        voice.note_on(60);
+        let freq = crate::voice::midi_to_freq(60);
+        let frames: Vec<f32> = (0..14).map(|_| voice.advance(freq).0).collect();
+        assert_eq!(
+            frames,
+            [
+                0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 4.0, 5.0, 6.0, 7.0, 4.0, 5.0
+            ]
+        );
+        assert!(voice.is_playing());
+
+        assert_eq!(
+            Sample::mono(ramp(8), 48000.0, 60)
+                .with_loop(6, 2)
+                .loop_points(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stereo_sample() {
+        let stereo = StereoSamples::new(vec![1.0; 4], vec![-1.0; 4]);
+        let mut map = SampleMap::new();
+        map.add(0, 127, Sample::stereo(stereo, 48000.0, 60));
+        let mut voice = voice_with(map);
+        voice.note_on(60);
+        assert_eq!(voice.advance(crate::voice::midi_to_freq(60)), (1.0, -1.0));
+    }
+}
@@ -0,0 +1,843 @@
+//! Note sources: an arpeggiator and a step sequencer.
+//!
+//! Both generate notes on a tempo grid and send them to anything that takes
+//! note events through the [`NoteSink`] trait: [`MonophonicSynth`],
+//! [`PolyphonicSynth`], [`VoiceManager`], and [`SynthNode`]. They run one
+//! sample at a time alongside the synth, so a standalone app and a plugin
+//! drive them the same way: feed held notes in, call `advance()` once per
+//! sample before rendering the synth.
+//!
+//! Step length is a [`NoteDivision`] at the current tempo. Without a host
+//! the clock runs free at its own BPM; with one, pass the host's
+//! [`TempoContext`] each block and steps follow its tempo and, while the
+//! transport plays, its beat grid.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sonido_core::NoteDivision;
+//! use sonido_synth::{ArpMode, Arpeggiator, PolyphonicSynth};
+//!
+//! let mut synth: PolyphonicSynth<8> = PolyphonicSynth::new(48000.0);
+//! let mut arp = Arpeggiator::new(48000.0);
+//! arp.set_mode(ArpMode::UpDown);
+//! arp.set_octaves(2);
+//! arp.set_rate(NoteDivision::Sixteenth);
+//!
+//! // Hold a chord: the arpeggiator plays it one note at a time
+//! arp.note_on(60, 100);
+//! arp.note_on(64, 100);
+//! arp.note_on(67, 100);
+//!
+//! for _ in 0..48000 {
+//!     arp.advance(&mut synth);
+//!     let sample = synth.process();
+//! }
+//! ```
+
+use crate::synth::{MonophonicSynth, PolyphonicSynth, SynthNode};
+use crate::voice::VoiceManager;
+use sonido_core::{NoteDivision, TempoContext};
+
+/// Maximum notes an [`Arpeggiator`] holds at once.
+pub const MAX_HELD_NOTES: usize = 16;
+
+/// Number of steps in a [`StepSequencer`].
+pub const SEQUENCER_STEPS: usize = 16;
+
+/// Phase error (in steps) below which a host tempo context is ignored, so
+/// rounding in the host's beat position doesn't retrigger a step.
+const SYNC_TOLERANCE: f64 = 0.01;
+
+/// Slack in step phase comparisons, far below one sample.
+const PHASE_EPSILON: f64 = 1e-9;
+
+/// Receiver of note events from a note source.
+pub trait NoteSink {
+    /// Start a note.
+    fn note_on(&mut self, note: u8, velocity: u8);
+    /// Release a note.
+    fn note_off(&mut self, note: u8);
+}
+
+impl NoteSink for MonophonicSynth {
+    fn note_on(&mut self, note: u8, velocity: u8) {
+        MonophonicSynth::note_on(self, note, velocity);
+    }
+
+    fn note_off(&mut self, note: u8) {
+        MonophonicSynth::note_off(self, note);
+    }
+}
+
+impl<const N: usize> NoteSink for PolyphonicSynth<N> {
+    fn note_on(&mut self, note: u8, velocity: u8) {
+        PolyphonicSynth::note_on(self, note, velocity);
+    }
+
+    fn note_off(&mut self, note: u8) {
+        PolyphonicSynth::note_off(self, note);
+    }
+}
+
+impl<const N: usize> NoteSink for VoiceManager<N> {
+    fn note_on(&mut self, note: u8, velocity: u8) {
+        VoiceManager::note_on(self, note, velocity);
+    }
+
+    fn note_off(&mut self, note: u8) {
+        VoiceManager::note_off(self, note);
+    }
+}
+
+impl NoteSink for SynthNode {
+    fn note_on(&mut self, note: u8, velocity: u8) {
+        SynthNode::note_on(self, note, velocity);
+    }
+
+    fn note_off(&mut self, note: u8) {
+        SynthNode::note_off(self, note);
+    }
+}
+
+/// Step clock shared by the arpeggiator and sequencer.
+///
+/// `phase` runs 0.0 to 1.0 across one step. It is kept in `f64` so a free
+/// running clock doesn't drift off the sample grid over long patterns.
+#[derive(Debug, Clone)]
+struct StepClock {
+    sample_rate: f32,
+    bpm: f32,
+    division: NoteDivision,
+    phase: f64,
+    increment: f64,
+}
+
+impl StepClock {
+    fn new(sample_rate: f32) -> Self {
+        let mut clock = Self {
+            sample_rate,
+            bpm: 120.0,
+            division: NoteDivision::Sixteenth,
+            phase: 0.0,
+            increment: 0.0,
+        };
+        clock.update_increment();
+        clock
+    }
+
+    fn update_increment(&mut self) {
+        let samples = f64::from(self.sample_rate) * 60.0 / f64::from(self.bpm)
+            * f64::from(self.division.beats());
+        self.increment = 1.0 / samples.max(1.0);
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.update_increment();
+    }
+
+    fn set_bpm(&mut self, bpm: f32) {
+        self.bpm = bpm.clamp(20.0, 300.0);
+        self.update_increment();
+    }
+
+    fn set_division(&mut self, division: NoteDivision) {
+        self.division = division;
+        self.update_increment();
+    }
+
+    /// Follow a host tempo context: its tempo always, its beat grid while
+    /// the transport plays.
+    fn sync(&mut self, ctx: &TempoContext) {
+        if ctx.bpm > 0.0 && ctx.bpm != self.bpm {
+            self.set_bpm(ctx.bpm);
+        }
+        if ctx.is_playing {
+            let steps = f64::from(ctx.beat_position) / f64::from(self.division.beats());
+            let target = steps - libm::floor(steps);
+            let error = target - self.phase;
+            let error = error - libm::round(error);
+            if libm::fabs(error) > SYNC_TOLERANCE {
+                self.phase = target;
+            }
+        }
+    }
+
+    /// Whether the step has run `fraction` of its length, tolerating rounding
+    /// in the accumulated increments.
+    #[inline]
+    fn reached(&self, fraction: f64) -> bool {
+        self.phase >= fraction - PHASE_EPSILON
+    }
+
+    /// Advance one sample. Returns `true` when a new step starts.
+    #[inline]
+    fn tick(&mut self) -> bool {
+        self.phase += self.increment;
+        if self.reached(1.0) {
+            self.phase -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Order in which the arpeggiator plays held notes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArpMode {
+    /// Lowest to highest, then the next octave up.
+    #[default]
+    Up,
+    /// Highest to lowest, starting from the top octave.
+    Down,
+    /// Up, then back down without repeating the end notes.
+    UpDown,
+    /// A random held note and octave each step.
+    Random,
+    /// The order the notes were pressed.
+    Order,
+}
+
+/// Arpeggiator: plays held notes one at a time on a tempo grid.
+///
+/// ## Parameters
+/// - `mode`: Note order (default [`ArpMode::Up`])
+/// - `octaves`: Octave range (1 to 4, default 1)
+/// - `gate`: Note length as a fraction of a step (0.05 to 1.0, default 0.5)
+/// - `rate`: Step length (default sixteenth notes)
+/// - `bpm`: Free-running tempo (20 to 300, default 120), replaced by the
+///   host tempo when one is given
+///
+/// The first note plays as soon as a key goes down; releasing every key
+/// stops the pattern and releases the sounding note.
+#[derive(Debug, Clone)]
+pub struct Arpeggiator {
+    clock: StepClock,
+    mode: ArpMode,
+    octaves: u8,
+    gate: f32,
+    /// Held notes and velocities, in press order
+    held: [(u8, u8); MAX_HELD_NOTES],
+    held_count: usize,
+    /// Position in the pattern
+    position: usize,
+    /// Note currently sounding
+    sounding: Option<u8>,
+    /// Play a step on the next `advance()` rather than waiting for the clock
+    start_pending: bool,
+    rng_state: u32,
+}
+
+impl Arpeggiator {
+    /// Create a new arpeggiator.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            clock: StepClock::new(sample_rate),
+            mode: ArpMode::Up,
+            octaves: 1,
+            gate: 0.5,
+            held: [(0, 0); MAX_HELD_NOTES],
+            held_count: 0,
+            position: 0,
+            sounding: None,
+            start_pending: false,
+            rng_state: 0x2545_F491,
+        }
+    }
+
+    /// Set sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.clock.set_sample_rate(sample_rate);
+    }
+
+    /// Set note order.
+    pub fn set_mode(&mut self, mode: ArpMode) {
+        self.mode = mode;
+    }
+
+    /// Get note order.
+    pub fn mode(&self) -> ArpMode {
+        self.mode
+    }
+
+    /// Set octave range.
+    ///
+    /// Range: 1 to 4.
+    pub fn set_octaves(&mut self, octaves: u8) {
+        self.octaves = octaves.clamp(1, 4);
+    }
+
+    /// Get octave range.
+    pub fn octaves(&self) -> u8 {
+        self.octaves
+    }
+
+    /// Set note length as a fraction of a step.
+    ///
+    /// Range: 0.05 to 1.0. At 1.0 each note lasts until the next one starts.
+    pub fn set_gate(&mut self, gate: f32) {
+        self.gate = gate.clamp(0.05, 1.0);
+    }
+
+    /// Get gate length.
+    pub fn gate(&self) -> f32 {
+        self.gate
+    }
+
+    /// Set step length.
+    pub fn set_rate(&mut self, division: NoteDivision) {
+        self.clock.set_division(division);
+    }
+
+    /// Get step length.
+    pub fn rate(&self) -> NoteDivision {
+        self.clock.division
+    }
+
+    /// Set free-running tempo.
+    ///
+    /// Range: 20 to 300 BPM.
+    pub fn set_bpm(&mut self, bpm: f32) {
+        self.clock.set_bpm(bpm);
+    }
+
+    /// Get tempo.
+    pub fn bpm(&self) -> f32 {
+        self.clock.bpm
+    }
+
+    /// Follow the host's tempo and, while its transport plays, its beat grid.
+    ///
+    /// Call once per block.
+    pub fn set_tempo_context(&mut self, ctx: &TempoContext) {
+        self.clock.sync(ctx);
+    }
+
+    /// Hold a note.
+    ///
+    /// Notes past [`MAX_HELD_NOTES`] are ignored.
+    pub fn note_on(&mut self, note: u8, velocity: u8) {
+        if self.held_notes().any(|n| n == note) || self.held_count == MAX_HELD_NOTES {
+            return;
+        }
+        if self.held_count == 0 {
+            self.position = 0;
+            self.start_pending = true;
+        }
+        self.held[self.held_count] = (note, velocity);
+        self.held_count += 1;
+    }
+
+    /// Release a held note.
+    pub fn note_off(&mut self, note: u8) {
+        let idx = self.held_notes().position(|n| n == note);
+        if let Some(idx) = idx {
+            self.held.copy_within(idx + 1..self.held_count, idx);
+            self.held_count -= 1;
+        }
+    }
+
+    /// Held notes, in press order.
+    pub fn held_notes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.held[..self.held_count].iter().map(|&(note, _)| note)
+    }
+
+    /// Note currently sounding, if any.
+    pub fn sounding(&self) -> Option<u8> {
+        self.sounding
+    }
+
+    /// Advance one sample, sending any note events to `sink`.
+    #[inline]
+    pub fn advance<S: NoteSink>(&mut self, sink: &mut S) {
+        let step = self.clock.tick() || self.start_pending;
+
+        if let Some(note) = self.sounding
+            && (step || self.held_count == 0 || self.clock.reached(f64::from(self.gate)))
+        {
+            sink.note_off(note);
+            self.sounding = None;
+        }
+
+        if step && self.held_count > 0 {
+            if self.start_pending {
+                self.clock.phase = 0.0;
+                self.start_pending = false;
+            }
+            let (note, velocity) = self.next_note();
+            sink.note_on(note, velocity);
+            self.sounding = Some(note);
+        }
+    }
+
+    /// Release the sounding note to `sink` and forget held notes.
+    pub fn stop<S: NoteSink>(&mut self, sink: &mut S) {
+        if let Some(note) = self.sounding.take() {
+            sink.note_off(note);
+        }
+        self.held_count = 0;
+        self.start_pending = false;
+    }
+
+    /// Clear held notes and pattern position without sending note-offs.
+    pub fn reset(&mut self) {
+        self.held_count = 0;
+        self.position = 0;
+        self.sounding = None;
+        self.start_pending = false;
+        self.clock.phase = 0.0;
+    }
+
+    /// Pick the next note of the pattern and move the position on.
+    fn next_note(&mut self) -> (u8, u8) {
+        let count = self.held_count;
+        let mut sorted = self.held;
+        sorted[..count].sort_unstable_by_key(|&(note, _)| note);
+
+        let len = count * self.octaves as usize;
+        let idx = match self.mode {
+            ArpMode::Up | ArpMode::Order => self.position % len,
+            ArpMode::Down => len - 1 - self.position % len,
+            ArpMode::UpDown if len > 1 => {
+                let cycle = 2 * len - 2;
+                let pos = self.position % cycle;
+                if pos < len { pos } else { cycle - pos }
+            }
+            ArpMode::UpDown => 0,
+            ArpMode::Random => self.next_random() as usize % len,
+        };
+        self.position = self.position.wrapping_add(1);
+
+        let notes = if self.mode == ArpMode::Order {
+            &self.held
+        } else {
+            &sorted
+        };
+        let (note, velocity) = notes[idx % count];
+        let octave = (idx / count) as u8;
+        (note.saturating_add(12 * octave).min(127), velocity)
+    }
+
+    fn next_random(&mut self) -> u32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state = x;
+        x
+    }
+}
+
+/// One step of a [`StepSequencer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// MIDI note number.
+    pub note: u8,
+    /// MIDI velocity (1 to 127).
+    pub velocity: u8,
+    /// Whether the step plays a note.
+    pub gate: bool,
+}
+
+impl Default for Step {
+    fn default() -> Self {
+        Self {
+            note: 60,
+            velocity: 100,
+            gate: true,
+        }
+    }
+}
+
+/// 16-step pitch/gate sequencer.
+///
+/// Each step holds a note, velocity, and gate; steps with the gate off are
+/// rests. The pattern loops over the first `length` steps while running.
+///
+/// ## Parameters
+/// - `length`: Steps in the loop (1 to 16, default 16)
+/// - `gate`: Note length as a fraction of a step (0.05 to 1.0, default 0.5)
+/// - `rate`: Step length (default sixteenth notes)
+/// - `bpm`: Free-running tempo (20 to 300, default 120)
+/// - `transpose`: Semitones added to every step (-48 to 48, default 0)
+///
+/// # Example
+///
+/// ```rust
+/// use sonido_synth::{MonophonicSynth, Step, StepSequencer};
+///
+/// let mut synth = MonophonicSynth::new(48000.0);
+/// let mut seq = StepSequencer::new(48000.0);
+/// seq.set_length(4);
+/// for (i, note) in [36, 48, 43, 46].into_iter().enumerate() {
+///     seq.set_step(i, Step { note, velocity: 100, gate: true });
+/// }
+/// seq.start();
+///
+/// for _ in 0..48000 {
+///     seq.advance(&mut synth);
+///     let sample = synth.process();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StepSequencer {
+    clock: StepClock,
+    steps: [Step; SEQUENCER_STEPS],
+    length: usize,
+    gate: f32,
+    transpose: i8,
+    /// Step that plays next
+    position: usize,
+    running: bool,
+    sounding: Option<u8>,
+    start_pending: bool,
+}
+
+impl StepSequencer {
+    /// Create a stopped sequencer with every step on middle C.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            clock: StepClock::new(sample_rate),
+            steps: [Step::default(); SEQUENCER_STEPS],
+            length: SEQUENCER_STEPS,
+            gate: 0.5,
+            transpose: 0,
+            position: 0,
+            running: false,
+            sounding: None,
+            start_pending: false,
+        }
+    }
+
+    /// Set sample rate.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.clock.set_sample_rate(sample_rate);
+    }
+
+    /// Set one step. Out-of-range indices are ignored.
+    pub fn set_step(&mut self, index: usize, step: Step) {
+        if let Some(s) = self.steps.get_mut(index) {
+            *s = step;
+        }
+    }
+
+    /// Get one step.
+    pub fn step(&self, index: usize) -> Option<Step> {
+        self.steps.get(index).copied()
+    }
+
+    /// All steps.
+    pub fn steps(&self) -> &[Step; SEQUENCER_STEPS] {
+        &self.steps
+    }
+
+    /// Set the number of steps in the loop.
+    ///
+    /// Range: 1 to 16.
+    pub fn set_length(&mut self, length: usize) {
+        self.length = length.clamp(1, SEQUENCER_STEPS);
+        self.position %= self.length;
+    }
+
+    /// Get loop length.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Set note length as a fraction of a step.
+    ///
+    /// Range: 0.05 to 1.0.
+    pub fn set_gate(&mut self, gate: f32) {
+        self.gate = gate.clamp(0.05, 1.0);
+    }
+
+    /// Get gate length.
+    pub fn gate(&self) -> f32 {
+        self.gate
+    }
+
+    /// Set semitones added to every step, e.g. from a played key.
+    ///
+    /// Range: -48 to 48.
+    pub fn set_transpose(&mut self, semitones: i8) {
+        self.transpose = semitones.clamp(-48, 48);
+    }
+
+    /// Get transpose.
+    pub fn transpose(&self) -> i8 {
+        self.transpose
+    }
+
+    /// Set step length.
+    pub fn set_rate(&mut self, division: NoteDivision) {
+        self.clock.set_division(division);
+    }
+
+    /// Get step length.
+    pub fn rate(&self) -> NoteDivision {
+        self.clock.division
+    }
+
+    /// Set free-running tempo.
+    ///
+    /// Range: 20 to 300 BPM.
+    pub fn set_bpm(&mut self, bpm: f32) {
+        self.clock.set_bpm(bpm);
+    }
+
+    /// Get tempo.
+    pub fn bpm(&self) -> f32 {
+        self.clock.bpm
+    }
+
+    /// Follow the host's tempo and, while its transport plays, its beat grid.
+    ///
+    /// Call once per block.
+    pub fn set_tempo_context(&mut self, ctx: &TempoContext) {
+        self.clock.sync(ctx);
+    }
+
+    /// Start from the first step on the next `advance()`.
+    pub fn start(&mut self) {
+        self.running = true;
+        self.position = 0;
+        self.start_pending = true;
+    }
+
+    /// Stop; the sounding note is released on the next `advance()`.
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.start_pending = false;
+    }
+
+    /// Whether the sequencer is running.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Step that plays next.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Advance one sample, sending any note events to `sink`.
+    #[inline]
+    pub fn advance<S: NoteSink>(&mut self, sink: &mut S) {
+        let step = self.running && (self.clock.tick() || self.start_pending);
+
+        if let Some(note) = self.sounding
+            && (step || !self.running || self.clock.reached(f64::from(self.gate)))
+        {
+            sink.note_off(note);
+            self.sounding = None;
+        }
+
+        if step {
+            if self.start_pending {
+                self.clock.phase = 0.0;
+                self.start_pending = false;
+            }
+            let current = self.steps[self.position];
+            self.position = (self.position + 1) % self.length;
+            if current.gate {
+                let note =
+                    (i16::from(current.note) + i16::from(self.transpose)).clamp(0, 127) as u8;
+                sink.note_on(note, current.velocity);
+                self.sounding = Some(note);
+            }
+        }
+    }
+
+    /// Stop and return to the first step without sending note-offs.
+    pub fn reset(&mut self) {
+        self.running = false;
+        self.position = 0;
+        self.sounding = None;
+        self.start_pending = false;
+        self.clock.phase = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    /// Records note events with the sample they arrived on.
+    #[derive(Default)]
+    struct Recorder {
+        sample: usize,
+        events: Vec<(usize, bool, u8)>,
+    }
+
+    impl NoteSink for Recorder {
+        fn note_on(&mut self, note: u8, _velocity: u8) {
+            self.events.push((self.sample, true, note));
+        }
+
+        fn note_off(&mut self, note: u8) {
+            self.events.push((self.sample, false, note));
+        }
+    }
+
+    fn run_arp(arp: &mut Arpeggiator, samples: usize) -> Recorder {
+        let mut rec = Recorder::default();
+        for i in 0..samples {
+            rec.sample = i;
+            arp.advance(&mut rec);
+        }
+        rec
+    }
+
+    fn notes_on(rec: &Recorder) -> Vec<u8> {
+        rec.events
+            .iter()
+            .filter(|(_, on, _)| *on)
+            .map(|&(_, _, note)| note)
+            .collect()
+    }
+
+    /// Arpeggiator at 120 BPM sixteenths: 6000 samples per step at 48 kHz.
+    fn arp_with(mode: ArpMode, octaves: u8, notes: &[u8]) -> Arpeggiator {
+        let mut arp = Arpeggiator::new(48000.0);
+        arp.set_mode(mode);
+        arp.set_octaves(octaves);
+        for &note in notes {
+            arp.note_on(note, 100);
+        }
+        arp
+    }
+
+    #[test]
+    fn test_arp_modes() {
+        let chord = [64, 60, 67];
+        let steps = 6000 * 6;
+
+        let up = run_arp(&mut arp_with(ArpMode::Up, 1, &chord), steps);
+        assert_eq!(notes_on(&up), [60, 64, 67, 60, 64, 67]);
+
+        let down = run_arp(&mut arp_with(ArpMode::Down, 2, &chord), steps);
+        assert_eq!(notes_on(&down), [79, 76, 72, 67, 64, 60]);
+
+        let updown = run_arp(&mut arp_with(ArpMode::UpDown, 1, &chord), steps);
+        assert_eq!(notes_on(&updown), [60, 64, 67, 64, 60, 64]);
+
+        let order = run_arp(&mut arp_with(ArpMode::Order, 2, &chord), steps);
+        assert_eq!(notes_on(&order), [64, 60, 67, 76, 72, 79]);
+
+        let random = run_arp(&mut arp_with(ArpMode::Random, 2, &chord), steps * 4);
+        let played = notes_on(&random);
+        assert_eq!(played.len(), 24);
+        assert!(played.iter().all(|n| [60, 64, 67, 72, 76, 79].contains(n)));
+    }
+
+    #[test]
+    fn test_arp_gate_and_release() {
+        let mut arp = arp_with(ArpMode::Up, 1, &[60]);
+        arp.set_gate(0.25);
+        let rec = run_arp(&mut arp, 6000 * 2);
+        // On at the start of each step, off a quarter step later
+        assert_eq!(
+            rec.events,
+            [
+                (0, true, 60),
+                (1500, false, 60),
+                (6000, true, 60),
+                (7500, false, 60)
+            ]
+        );
+
+        // Releasing every key releases the sounding note right away
+        let mut arp = arp_with(ArpMode::Up, 1, &[60, 64]);
+        let mut rec = Recorder::default();
+        arp.advance(&mut rec);
+        arp.note_off(60);
+        arp.note_off(64);
+        arp.advance(&mut rec);
+        assert_eq!(rec.events, [(0, true, 60), (0, false, 60)]);
+        assert_eq!(arp.sounding(), None);
+    }
+
+    #[test]
+    fn test_arp_follows_host_tempo() {
+        let mut arp = arp_with(ArpMode::Up, 1, &[60, 62]);
+        arp.set_rate(NoteDivision::Eighth);
+        arp.set_tempo_context(&TempoContext {
+            bpm: 60.0,
+            ..TempoContext::default()
+        });
+        assert_eq!(arp.bpm(), 60.0);
+
+        // 60 BPM eighths: 24000 samples per step
+        let rec = run_arp(&mut arp, 48000);
+        let starts: Vec<usize> = rec.events.iter().filter(|e| e.1).map(|e| e.0).collect();
+        assert_eq!(starts, [0, 24000]);
+    }
+
+    #[test]
+    fn test_step_sequencer_loop() {
+        let mut seq = StepSequencer::new(48000.0);
+        seq.set_length(3);
+        seq.set_step(
+            0,
+            Step {
+                note: 36,
+                velocity: 100,
+                gate: true,
+            },
+        );
+        seq.set_step(
+            1,
+            Step {
+                note: 38,
+                velocity: 100,
+                gate: false,
+            },
+        );
+        seq.set_step(
+            2,
+            Step {
+                note: 40,
+                velocity: 100,
+                gate: true,
+            },
+        );
+        seq.set_transpose(12);
+
+        let mut rec = Recorder::default();
+        for i in 0..6000 * 6 {
+            rec.sample = i;
+            seq.advance(&mut rec);
+        }
+        assert!(rec.events.is_empty(), "Stopped sequencer plays nothing");
+
+        seq.start();
+        for i in 0..6000 * 6 {
+            rec.sample = i;
+            seq.advance(&mut rec);
+        }
+        // Step 2 is a rest
+        assert_eq!(notes_on(&rec), [48, 52, 48, 52]);
+
+        seq.stop();
+        seq.advance(&mut rec);
+        assert_eq!(rec.events.last().map(|e| e.1), Some(false));
+    }
+
+    #[test]
+    fn test_step_sequencer_drives_synth() {
+        let mut synth: PolyphonicSynth<4> = PolyphonicSynth::new(48000.0);
+        let mut seq = StepSequencer::new(48000.0);
+        seq.start();
+        let mut sum = 0.0;
+        for _ in 0..4800 {
+            seq.advance(&mut synth);
+            sum += synth.process().abs();
+        }
+        assert!(sum > 1.0, "Sequencer should play the synth");
+    }
+}
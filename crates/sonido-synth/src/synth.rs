@@ -4,9 +4,44 @@
 //! with modulation, filtering, and voice management.
 
 use crate::envelope::AdsrEnvelope;
+use crate::fm::Fm4Algorithm;
+use crate::mod_matrix::ModulationRoute;
+use crate::mpe::MpeZone;
 use crate::oscillator::{Oscillator, OscillatorWaveform};
-use crate::voice::{VoiceAllocationMode, VoiceManager, cents_to_ratio, midi_to_freq};
-use sonido_core::{Effect, Lfo, LfoWaveform, StateVariableFilter, SvfOutput};
+use crate::sampler::SampleMap;
+use crate::voice::{VoiceAllocationMode, VoiceEngine, VoiceManager, cents_to_ratio, midi_to_freq};
+use sonido_core::{
+    Effect, Lfo, LfoWaveform, ParamDescriptor, ParamFlags, ParamId, ParamScale, ParamUnit,
+    ParameterInfo, SmoothingStyle, StateVariableFilter, SvfOutput, db_to_linear, impl_params,
+};
+
+/// Maximum keys [`MonophonicSynth`] remembers for last-note priority.
+const MONO_NOTE_STACK: usize = 16;
+
+/// How glide time relates to the interval being covered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GlideMode {
+    /// Every glide takes the glide time, whatever the interval.
+    #[default]
+    ConstantTime,
+    /// The glide time covers one octave; wider intervals take longer.
+    ConstantRate,
+}
+
+/// Shape of the pitch movement during a glide.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GlideCurve {
+    /// Fast at first, slowing into the target note (one-pole, like an RC
+    /// portamento circuit). The glide time is the time constant.
+    #[default]
+    Exponential,
+    /// Constant speed in semitones, arriving exactly at the glide time.
+    Linear,
+}
+
+const GLIDE_MODE_LABELS: &[&str] = &["Time", "Rate"];
+const GLIDE_CURVE_LABELS: &[&str] = &["Exp", "Linear"];
+const OFF_ON_LABELS: &[&str] = &["Off", "On"];
 
 /// A monophonic synthesizer.
 ///
@@ -19,7 +54,27 @@ use sonido_core::{Effect, Lfo, LfoWaveform, StateVariableFilter, SvfOutput};
 /// - State variable filter with envelope modulation
 /// - Two LFOs for modulation
 /// - ADSR envelopes for amplitude and filter
-/// - Glide/portamento between notes
+/// - Glide/portamento between notes, with constant-time or constant-rate
+///   timing, exponential or linear curves, and optional legato-only glide
+/// - Last-note priority: releasing a key returns to the previous held key
+///
+/// # Glide and Legato
+///
+/// A note played while another key is held is *legato*. With `legato_glide`
+/// off, any note glides from the previous pitch while the amp envelope is
+/// still sounding; with it on, only legato notes glide. `retrigger` decides
+/// whether legato notes restart the envelopes or continue them. Notes played
+/// with no key held always restart the envelopes.
+///
+/// The glide settings are exposed through [`ParameterInfo`]:
+///
+/// | Index | Parameter | Range | Default |
+/// |-------|-----------|-------|---------|
+/// | 0 | Glide Time | 0–2000 ms | 0 |
+/// | 1 | Glide Mode | Time/Rate | Time |
+/// | 2 | Glide Curve | Exp/Linear | Exp |
+/// | 3 | Legato Glide | Off/On | Off |
+/// | 4 | Retrigger | Off/On | On |
 ///
 /// # Example
 ///
@@ -60,18 +115,27 @@ pub struct MonophonicSynth {
 
     /// Sample rate
     sample_rate: f32,
-    /// Current note
-    current_note: u8,
-    /// Target note (for glide)
-    target_note: u8,
-    /// Current frequency (for glide)
+    /// Current pitch in MIDI note units (fractional while gliding)
+    current_pitch: f32,
+    /// Pitch being glided to
+    target_pitch: f32,
+    /// Current frequency, following `current_pitch`
     current_freq: f32,
-    /// Target frequency
-    target_freq: f32,
     /// Glide time in seconds
     glide_time: f32,
-    /// Glide coefficient
+    /// Exponential glide coefficient for the current glide
     glide_coeff: f32,
+    /// Linear glide step in semitones per sample for the current glide
+    glide_step: f32,
+    glide_mode: GlideMode,
+    glide_curve: GlideCurve,
+    /// Glide only between overlapping notes
+    legato_glide: bool,
+    /// Restart envelopes on legato notes
+    retrigger: bool,
+    /// Held keys, oldest first
+    held: [u8; MONO_NOTE_STACK],
+    held_count: usize,
 
     // Oscillator parameters
     osc2_detune: f32,
@@ -108,12 +172,18 @@ impl MonophonicSynth {
             lfo1: Lfo::new(sample_rate, 5.0),
             lfo2: Lfo::new(sample_rate, 0.5),
             sample_rate,
-            current_note: 60,
-            target_note: 60,
+            current_pitch: 60.0,
+            target_pitch: 60.0,
             current_freq: 261.63,
-            target_freq: 261.63,
             glide_time: 0.0,
-            glide_coeff: 1.0,
+            glide_coeff: 0.0,
+            glide_step: 0.0,
+            glide_mode: GlideMode::ConstantTime,
+            glide_curve: GlideCurve::Exponential,
+            legato_glide: false,
+            retrigger: true,
+            held: [0; MONO_NOTE_STACK],
+            held_count: 0,
             osc2_detune: 0.0,
             osc_mix: 0.0,
             filter_cutoff: 1000.0,
@@ -145,7 +215,6 @@ impl MonophonicSynth {
         self.filter_env.set_sample_rate(sample_rate);
         self.lfo1.set_sample_rate(sample_rate);
         self.lfo2.set_sample_rate(sample_rate);
-        self.update_glide_coeff();
     }
 
     // Oscillator settings
@@ -285,47 +354,152 @@ impl MonophonicSynth {
     // Glide settings
 
     /// Set glide time in milliseconds.
+    ///
+    /// Range: 0.0 to 2000.0. Below 1 ms, notes change pitch instantly.
     pub fn set_glide_time(&mut self, ms: f32) {
-        self.glide_time = ms / 1000.0;
-        self.update_glide_coeff();
+        self.glide_time = ms.clamp(0.0, 2000.0) / 1000.0;
     }
 
-    fn update_glide_coeff(&mut self) {
-        if self.glide_time > 0.001 {
-            let samples = self.glide_time * self.sample_rate;
-            self.glide_coeff = libm::expf(-1.0 / samples);
-        } else {
-            self.glide_coeff = 0.0; // Instant
+    /// Get glide time in milliseconds.
+    pub fn glide_time(&self) -> f32 {
+        self.glide_time * 1000.0
+    }
+
+    /// Set whether glide time is per glide or per octave.
+    pub fn set_glide_mode(&mut self, mode: GlideMode) {
+        self.glide_mode = mode;
+    }
+
+    /// Get glide timing mode.
+    pub fn glide_mode(&self) -> GlideMode {
+        self.glide_mode
+    }
+
+    /// Set the glide curve.
+    pub fn set_glide_curve(&mut self, curve: GlideCurve) {
+        self.glide_curve = curve;
+    }
+
+    /// Get the glide curve.
+    pub fn glide_curve(&self) -> GlideCurve {
+        self.glide_curve
+    }
+
+    /// Set whether only legato notes glide.
+    pub fn set_legato_glide(&mut self, legato: bool) {
+        self.legato_glide = legato;
+    }
+
+    /// Get legato-only glide.
+    pub fn legato_glide(&self) -> bool {
+        self.legato_glide
+    }
+
+    /// Set whether legato notes restart the envelopes.
+    pub fn set_retrigger(&mut self, retrigger: bool) {
+        self.retrigger = retrigger;
+    }
+
+    /// Get envelope retrigger on legato notes.
+    pub fn retrigger(&self) -> bool {
+        self.retrigger
+    }
+
+    /// Start moving toward `note`, gliding if `glide` is set and a glide
+    /// time is configured.
+    fn move_to(&mut self, note: u8, glide: bool) {
+        self.target_pitch = f32::from(note);
+        if !glide || self.glide_time <= 0.001 {
+            self.current_pitch = self.target_pitch;
+            self.current_freq = midi_to_freq(note);
+            return;
+        }
+
+        let interval = (self.target_pitch - self.current_pitch).abs();
+        let mut samples = self.glide_time * self.sample_rate;
+        if self.glide_mode == GlideMode::ConstantRate {
+            samples *= interval / 12.0;
         }
+        let samples = samples.max(1.0);
+        self.glide_coeff = libm::expf(-1.0 / samples);
+        self.glide_step = interval / samples;
     }
 
     /// Trigger a note.
     pub fn note_on(&mut self, note: u8, _velocity: u8) {
-        self.target_note = note;
-        self.target_freq = midi_to_freq(note);
-
-        if self.glide_coeff == 0.0 || !self.amp_env.is_active() {
-            self.current_freq = self.target_freq;
-            self.current_note = note;
+        let legato = self.held_count > 0;
+        if let Some(idx) = self.held[..self.held_count].iter().position(|&n| n == note) {
+            self.held.copy_within(idx + 1..self.held_count, idx);
+            self.held_count -= 1;
+        }
+        if self.held_count == MONO_NOTE_STACK {
+            self.held.copy_within(1.., 0);
+            self.held_count -= 1;
         }
+        self.held[self.held_count] = note;
+        self.held_count += 1;
+
+        let glide = if self.legato_glide {
+            legato
+        } else {
+            self.amp_env.is_active()
+        };
+        self.move_to(note, glide);
 
-        self.amp_env.gate_on();
-        self.filter_env.gate_on();
+        if !legato || self.retrigger {
+            self.amp_env.gate_on();
+            self.filter_env.gate_on();
+        }
     }
 
-    /// Release the current note.
-    pub fn note_off(&mut self, _note: u8) {
-        self.amp_env.gate_off();
-        self.filter_env.gate_off();
+    /// Release a note.
+    ///
+    /// Releasing the sounding note while other keys are held returns to the
+    /// most recent of them as a legato note; releasing the last key releases
+    /// the envelopes.
+    pub fn note_off(&mut self, note: u8) {
+        let Some(idx) = self.held[..self.held_count].iter().position(|&n| n == note) else {
+            if self.held_count == 0 {
+                self.amp_env.gate_off();
+                self.filter_env.gate_off();
+            }
+            return;
+        };
+
+        let was_sounding = idx + 1 == self.held_count;
+        self.held.copy_within(idx + 1..self.held_count, idx);
+        self.held_count -= 1;
+
+        if self.held_count == 0 {
+            self.amp_env.gate_off();
+            self.filter_env.gate_off();
+        } else if was_sounding {
+            self.move_to(self.held[self.held_count - 1], true);
+            if self.retrigger {
+                self.amp_env.gate_on();
+                self.filter_env.gate_on();
+            }
+        }
     }
 
     /// Process one sample.
     #[inline]
     pub fn process(&mut self) -> f32 {
         // Update glide
-        if self.glide_coeff > 0.0 {
-            self.current_freq =
-                self.target_freq + (self.current_freq - self.target_freq) * self.glide_coeff;
+        if self.current_pitch != self.target_pitch {
+            let remaining = self.target_pitch - self.current_pitch;
+            let remaining = match self.glide_curve {
+                GlideCurve::Exponential => remaining * self.glide_coeff,
+                GlideCurve::Linear => {
+                    remaining - remaining.clamp(-self.glide_step, self.glide_step)
+                }
+            };
+            self.current_pitch = if remaining.abs() < 0.001 {
+                self.target_pitch
+            } else {
+                self.target_pitch - remaining
+            };
+            self.current_freq = 440.0 * libm::exp2f((self.current_pitch - 69.0) / 12.0);
         }
 
         // Get LFO values
@@ -353,7 +527,7 @@ impl MonophonicSynth {
 
         // Calculate filter modulation
         let key_track_offset = if self.filter_key_track > 0.0 {
-            (self.current_note as f32 - 60.0) * 100.0 * self.filter_key_track
+            (self.current_pitch - 60.0) * 100.0 * self.filter_key_track
         } else {
             0.0
         };
@@ -382,6 +556,63 @@ impl MonophonicSynth {
         self.filter_env.reset();
         self.lfo1.reset();
         self.lfo2.reset();
+        self.current_pitch = self.target_pitch;
+        self.held_count = 0;
+    }
+}
+
+impl_params! {
+    MonophonicSynth, this {
+        [0] ParamDescriptor::time_ms("Glide Time", "Glide", 0.0, 2000.0, 0.0)
+                .with_scale(ParamScale::Power(2.0))
+                .with_id(ParamId(5100), "mono_glide_time")
+                .with_description("Portamento time between notes; 0 disables glide."),
+            get: this.glide_time(),
+            set: |v| this.set_glide_time(v);
+
+        [1] ParamDescriptor::custom("Glide Mode", "GMode", 0.0, 1.0, 0.0)
+                .with_step(1.0)
+                .with_id(ParamId(5101), "mono_glide_mode")
+                .with_description("Time fixes glide length; Rate scales it with the interval.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(GLIDE_MODE_LABELS),
+            get: this.glide_mode as u8 as f32,
+            set: |v| this.set_glide_mode(if v >= 0.5 {
+                GlideMode::ConstantRate
+            } else {
+                GlideMode::ConstantTime
+            });
+
+        [2] ParamDescriptor::custom("Glide Curve", "GCurve", 0.0, 1.0, 0.0)
+                .with_step(1.0)
+                .with_id(ParamId(5102), "mono_glide_curve")
+                .with_description("Pitch trajectory of the glide.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(GLIDE_CURVE_LABELS),
+            get: this.glide_curve as u8 as f32,
+            set: |v| this.set_glide_curve(if v >= 0.5 {
+                GlideCurve::Linear
+            } else {
+                GlideCurve::Exponential
+            });
+
+        [3] ParamDescriptor::custom("Legato Glide", "Legato", 0.0, 1.0, 0.0)
+                .with_step(1.0)
+                .with_id(ParamId(5103), "mono_legato_glide")
+                .with_description("Glide only on legato notes, played while another key is held.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(OFF_ON_LABELS),
+            get: if this.legato_glide { 1.0 } else { 0.0 },
+            set: |v| this.set_legato_glide(v >= 0.5);
+
+        [4] ParamDescriptor::custom("Retrigger", "Retrig", 0.0, 1.0, 1.0)
+                .with_step(1.0)
+                .with_id(ParamId(5104), "mono_retrigger")
+                .with_description("Restart envelopes on legato notes instead of continuing them.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(OFF_ON_LABELS),
+            get: if this.retrigger { 1.0 } else { 0.0 },
+            set: |v| this.set_retrigger(v >= 0.5);
     }
 }
 
@@ -555,6 +786,34 @@ impl<const VOICES: usize> PolyphonicSynth<VOICES> {
         }
     }
 
+    /// Set filter envelope attack for all voices.
+    pub fn set_filter_attack(&mut self, ms: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.filter_env.set_attack_ms(ms);
+        }
+    }
+
+    /// Set filter envelope decay for all voices.
+    pub fn set_filter_decay(&mut self, ms: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.filter_env.set_decay_ms(ms);
+        }
+    }
+
+    /// Set filter envelope sustain for all voices.
+    pub fn set_filter_sustain(&mut self, level: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.filter_env.set_sustain(level);
+        }
+    }
+
+    /// Set filter envelope release for all voices.
+    pub fn set_filter_release(&mut self, ms: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.filter_env.set_release_ms(ms);
+        }
+    }
+
     /// Set LFO 1 rate.
     pub fn set_lfo1_rate(&mut self, hz: f32) {
         self.lfo1.set_frequency(hz);
@@ -575,6 +834,111 @@ impl<const VOICES: usize> PolyphonicSynth<VOICES> {
         self.lfo1_to_filter = hz;
     }
 
+    /// Set the sound source (subtractive or FM) for all voices.
+    pub fn set_engine(&mut self, engine: VoiceEngine) {
+        for voice in self.voices.voices_mut() {
+            voice.set_engine(engine);
+        }
+    }
+
+    /// Set the number of active FM operators (2 to 4) for all voices.
+    pub fn set_fm_operator_count(&mut self, count: usize) {
+        for voice in self.voices.voices_mut() {
+            voice.fm.set_operator_count(count);
+        }
+    }
+
+    /// Set the FM algorithm for all voices.
+    pub fn set_fm_algorithm(&mut self, algorithm: Fm4Algorithm) {
+        for voice in self.voices.voices_mut() {
+            voice.fm.set_algorithm(algorithm);
+        }
+    }
+
+    /// Set one FM operator's frequency ratio for all voices.
+    ///
+    /// `op`: 0 = A (carrier) to 3 = D. Out-of-range operators are ignored.
+    pub fn set_fm_ratio(&mut self, op: usize, ratio: f32) {
+        for voice in self.voices.voices_mut() {
+            if let Some(operator) = voice.fm.engine.ops.get_mut(op) {
+                operator.set_ratio(ratio);
+            }
+        }
+    }
+
+    /// Set one FM operator's modulation index for all voices.
+    pub fn set_fm_index(&mut self, op: usize, index: f32) {
+        for voice in self.voices.voices_mut() {
+            if let Some(operator) = voice.fm.engine.ops.get_mut(op) {
+                operator.set_mod_index(index);
+            }
+        }
+    }
+
+    /// Set one FM operator's envelope for all voices.
+    ///
+    /// Times in ms, sustain 0.0 to 1.0.
+    pub fn set_fm_envelope(
+        &mut self,
+        op: usize,
+        attack_ms: f32,
+        decay_ms: f32,
+        sustain: f32,
+        release_ms: f32,
+    ) {
+        for voice in self.voices.voices_mut() {
+            if let Some(env) = voice.fm.envelopes.get_mut(op) {
+                env.set_attack_ms(attack_ms);
+                env.set_decay_ms(decay_ms);
+                env.set_sustain(sustain);
+                env.set_release_ms(release_ms);
+            }
+        }
+    }
+
+    /// Give all voices a sample map, for [`VoiceEngine::Sampler`].
+    pub fn set_sample_map(&mut self, map: SampleMap) {
+        self.voices.set_sample_map(map);
+    }
+
+    /// Set the number of unison sub-voices (1 to 16) for all voices.
+    ///
+    /// Each sub-voice runs both oscillators, so unison multiplies CPU cost;
+    /// see [`set_oscillator_budget()`](Self::set_oscillator_budget).
+    pub fn set_unison_count(&mut self, count: usize) {
+        for voice in self.voices.voices_mut() {
+            voice.set_unison_count(count);
+        }
+    }
+
+    /// Set unison detune spread in cents for all voices.
+    pub fn set_unison_spread(&mut self, cents: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.set_unison_spread(cents);
+        }
+    }
+
+    /// Set unison stereo width (0.0 to 1.0) for all voices.
+    pub fn set_stereo_width(&mut self, width: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.set_stereo_width(width);
+        }
+    }
+
+    /// Set oscillator start phase randomization (0.0 to 1.0) for all voices.
+    pub fn set_phase_random(&mut self, amount: f32) {
+        for voice in self.voices.voices_mut() {
+            voice.set_phase_random(amount);
+        }
+    }
+
+    /// Cap the oscillators running across all voices, or `None` for no limit.
+    ///
+    /// See [`VoiceManager::set_oscillator_budget`].
+    pub fn set_oscillator_budget(&mut self, budget: Option<usize>) {
+        self.voices.set_oscillator_budget(budget);
+    }
+
     fn update_voice_params(&mut self) {
         for voice in self.voices.voices_mut() {
             voice.set_osc1_waveform(self.osc1_waveform);
@@ -597,6 +961,59 @@ impl<const VOICES: usize> PolyphonicSynth<VOICES> {
         self.voices.note_off(note);
     }
 
+    /// Set the MPE zone, or `None` for channel-wide expression.
+    ///
+    /// See [`VoiceManager`] for how expression reaches each voice.
+    pub fn set_mpe(&mut self, zone: Option<MpeZone>) {
+        self.voices.set_mpe(zone);
+    }
+
+    /// Get the MPE zone.
+    pub fn mpe(&self) -> Option<MpeZone> {
+        self.voices.mpe()
+    }
+
+    /// Set the master channel (or non-MPE) pitch bend range in semitones.
+    pub fn set_pitch_bend_range(&mut self, semitones: f32) {
+        self.voices.set_pitch_bend_range(semitones);
+    }
+
+    /// Trigger a note on a MIDI channel (1–16).
+    pub fn note_on_channel(&mut self, channel: u8, note: u8, velocity: u8) {
+        self.voices.note_on_channel(channel, note, velocity);
+    }
+
+    /// Release a note on a MIDI channel (1–16).
+    pub fn note_off_channel(&mut self, channel: u8, note: u8) {
+        self.voices.note_off_channel(channel, note);
+    }
+
+    /// Pitch bend (-1.0 to 1.0) on a MIDI channel.
+    pub fn pitch_bend(&mut self, channel: u8, value: f32) {
+        self.voices.pitch_bend(channel, value);
+    }
+
+    /// Channel pressure (0.0 to 1.0) on a MIDI channel.
+    pub fn channel_pressure(&mut self, channel: u8, value: f32) {
+        self.voices.channel_pressure(channel, value);
+    }
+
+    /// Timbre, CC 74 (0.0 to 1.0), on a MIDI channel.
+    pub fn timbre(&mut self, channel: u8, value: f32) {
+        self.voices.timbre(channel, value);
+    }
+
+    /// Add a modulation route to every voice's matrix, e.g. pressure
+    /// ([`ModSourceId::Aftertouch`](crate::ModSourceId::Aftertouch)) to
+    /// filter cutoff. Returns `false` if the matrices are full.
+    pub fn add_mod_route(&mut self, route: ModulationRoute) -> bool {
+        let mut added = true;
+        for voice in self.voices.voices_mut() {
+            added &= voice.mod_matrix_mut().add_route(route);
+        }
+        added
+    }
+
     /// Stop all notes.
     pub fn all_notes_off(&mut self) {
         self.voices.all_notes_off();
@@ -672,17 +1089,113 @@ impl<const VOICES: usize> PolyphonicSynth<VOICES> {
 ///
 /// `SynthNode` returns `true` from `is_true_stereo()` because the polyphonic
 /// synth spreads unison voices across the stereo field.
+///
+/// ## Parameters
+///
+/// `SynthNode` implements [`ParameterInfo`] so hosts, plugin wrappers, and
+/// GUIs can drive it through the same descriptor bridge as effects.
+/// Values apply immediately; the descriptors' [`smoothing`] tells the caller
+/// how to ramp them (the filter parameters ask for
+/// [`SmoothingStyle::Slow`] to avoid zipper noise on cutoff sweeps).
+///
+/// [`smoothing`]: ParamDescriptor::smoothing
+///
+/// | Index | Name | Range | Default |
+/// |-------|------|-------|---------|
+/// | 0 | Osc 1 Wave | Sine/Triangle/Saw/Square/Noise | Saw |
+/// | 1 | Osc 2 Wave | Sine/Triangle/Saw/Square/Noise | Saw |
+/// | 2 | Osc 2 Detune | -100–100 cents | 0 |
+/// | 3 | Osc Mix | 0–100 % | 0 |
+/// | 4 | Cutoff | 20–20000 Hz | 1000 |
+/// | 5 | Resonance | 0.5–20 | 1.0 |
+/// | 6 | Filter Env Amount | -10000–10000 Hz | 0 |
+/// | 7–10 | Amp A/D/S/R | ms / % | 10 / 100 / 70 / 200 |
+/// | 11–14 | Filter A/D/S/R | ms / % | 10 / 100 / 70 / 200 |
+/// | 15 | LFO Rate | 0.05–20 Hz | 5.0 |
+/// | 16 | LFO Wave | Sine/Triangle/Saw/Square/S&H | Sine |
+/// | 17 | LFO → Pitch | 0–12 st | 0 |
+/// | 18 | LFO → Filter | 0–5000 Hz | 0 |
+/// | 19 | Output | -6–6 dB | 0 |
 #[derive(Debug)]
 pub struct SynthNode {
     /// Internal polyphonic synthesizer (8 voices).
     synth: PolyphonicSynth<8>,
+    /// User-facing parameter values, indexed as in [`ParameterInfo`].
+    values: [f32; SYNTH_PARAM_COUNT],
+    /// Linear output gain derived from the Output parameter.
+    output_gain: f32,
+}
+
+/// Number of parameters exposed by [`SynthNode`].
+pub const SYNTH_PARAM_COUNT: usize = 20;
+
+const OSC_WAVE_LABELS: &[&str] = &["Sine", "Triangle", "Saw", "Square", "Noise"];
+const LFO_WAVE_LABELS: &[&str] = &["Sine", "Triangle", "Saw", "Square", "S&H"];
+
+fn osc_waveform_from_index(index: f32) -> OscillatorWaveform {
+    match index as u8 {
+        0 => OscillatorWaveform::Sine,
+        1 => OscillatorWaveform::Triangle,
+        3 => OscillatorWaveform::Square,
+        4 => OscillatorWaveform::Noise,
+        _ => OscillatorWaveform::Saw,
+    }
+}
+
+fn lfo_waveform_from_index(index: f32) -> LfoWaveform {
+    match index as u8 {
+        1 => LfoWaveform::Triangle,
+        2 => LfoWaveform::Saw,
+        3 => LfoWaveform::Square,
+        4 => LfoWaveform::SampleAndHold,
+        _ => LfoWaveform::Sine,
+    }
 }
 
 impl SynthNode {
     /// Create a new synthesizer graph node at the given sample rate.
+    ///
+    /// All parameters start at their descriptor defaults.
     pub fn new(sample_rate: f32) -> Self {
-        Self {
+        let mut node = Self {
             synth: PolyphonicSynth::new(sample_rate),
+            values: [0.0; SYNTH_PARAM_COUNT],
+            output_gain: 1.0,
+        };
+        for index in 0..SYNTH_PARAM_COUNT {
+            if let Some(desc) = node.param_info(index) {
+                node.set_param(index, desc.default);
+            }
+        }
+        node
+    }
+
+    /// Store a (pre-clamped) parameter value and forward it to the synth.
+    fn apply_param(&mut self, index: usize, value: f32) {
+        self.values[index] = value;
+        let synth = &mut self.synth;
+        match index {
+            0 => synth.set_osc1_waveform(osc_waveform_from_index(value)),
+            1 => synth.set_osc2_waveform(osc_waveform_from_index(value)),
+            2 => synth.set_osc2_detune(value),
+            3 => synth.set_osc_mix(value / 100.0),
+            4 => synth.set_filter_cutoff(value),
+            5 => synth.set_filter_resonance(value),
+            6 => synth.set_filter_env_amount(value),
+            7 => synth.set_amp_attack(value),
+            8 => synth.set_amp_decay(value),
+            9 => synth.set_amp_sustain(value / 100.0),
+            10 => synth.set_amp_release(value),
+            11 => synth.set_filter_attack(value),
+            12 => synth.set_filter_decay(value),
+            13 => synth.set_filter_sustain(value / 100.0),
+            14 => synth.set_filter_release(value),
+            15 => synth.set_lfo1_rate(value),
+            16 => synth.set_lfo1_waveform(lfo_waveform_from_index(value)),
+            17 => synth.set_lfo1_to_pitch(value),
+            18 => synth.set_lfo1_to_filter(value),
+            19 => self.output_gain = db_to_linear(value),
+            _ => {}
         }
     }
 
@@ -730,7 +1243,8 @@ impl Effect for SynthNode {
     /// The incoming `left`/`right` values are discarded. The synth generates
     /// audio from its internal voice pool only.
     fn process_stereo(&mut self, _left: f32, _right: f32) -> (f32, f32) {
-        self.synth.process_stereo()
+        let (l, r) = self.synth.process_stereo();
+        (l * self.output_gain, r * self.output_gain)
     }
 
     fn is_true_stereo(&self) -> bool {
@@ -746,9 +1260,190 @@ impl Effect for SynthNode {
     }
 }
 
+impl_params! {
+    SynthNode, this {
+        [0] ParamDescriptor::custom("Osc 1 Wave", "Osc1", 0.0, 4.0, 2.0)
+                .with_step(1.0)
+                .with_id(ParamId(5000), "synth_osc1_wave")
+                .with_group("Oscillators")
+                .with_description("Waveform of oscillator 1.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(OSC_WAVE_LABELS),
+            get: this.values[0],
+            set: |v| this.apply_param(0, v);
+
+        [1] ParamDescriptor::custom("Osc 2 Wave", "Osc2", 0.0, 4.0, 2.0)
+                .with_step(1.0)
+                .with_id(ParamId(5001), "synth_osc2_wave")
+                .with_group("Oscillators")
+                .with_description("Waveform of oscillator 2.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(OSC_WAVE_LABELS),
+            get: this.values[1],
+            set: |v| this.apply_param(1, v);
+
+        [2] ParamDescriptor::custom("Osc 2 Detune", "Detune", -100.0, 100.0, 0.0)
+                .with_unit(ParamUnit::Cents)
+                .with_step(1.0)
+                .with_id(ParamId(5002), "synth_osc2_detune")
+                .with_group("Oscillators")
+                .with_description("Pitch offset of oscillator 2 against oscillator 1."),
+            get: this.values[2],
+            set: |v| this.apply_param(2, v);
+
+        [3] ParamDescriptor::custom("Osc Mix", "Mix", 0.0, 100.0, 0.0)
+                .with_unit(ParamUnit::Percent)
+                .with_step(1.0)
+                .with_id(ParamId(5003), "synth_osc_mix")
+                .with_group("Oscillators")
+                .with_description("Balance from oscillator 1 (0%) to oscillator 2 (100%)."),
+            get: this.values[3],
+            set: |v| this.apply_param(3, v);
+
+        [4] ParamDescriptor::custom("Cutoff", "Cutoff", 20.0, 20000.0, 1000.0)
+                .with_unit(ParamUnit::Hertz)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5004), "synth_cutoff")
+                .with_smoothing(SmoothingStyle::Slow)
+                .with_group("Filter")
+                .with_description("Lowpass cutoff before envelope and LFO modulation."),
+            get: this.values[4],
+            set: |v| this.apply_param(4, v);
+
+        [5] ParamDescriptor::custom("Resonance", "Reso", 0.5, 20.0, 1.0)
+                .with_step(0.1)
+                .with_scale(ParamScale::Power(2.0))
+                .with_id(ParamId(5005), "synth_resonance")
+                .with_smoothing(SmoothingStyle::Slow)
+                .with_group("Filter")
+                .with_description("Filter Q; higher values emphasize the cutoff frequency."),
+            get: this.values[5],
+            set: |v| this.apply_param(5, v);
+
+        [6] ParamDescriptor::custom("Filter Env", "FEnv", -10000.0, 10000.0, 0.0)
+                .with_unit(ParamUnit::Hertz)
+                .with_step(10.0)
+                .with_id(ParamId(5006), "synth_filter_env")
+                .with_smoothing(SmoothingStyle::Slow)
+                .with_group("Filter")
+                .with_description("Cutoff offset at the envelope peak; negative sweeps down."),
+            get: this.values[6],
+            set: |v| this.apply_param(6, v);
+
+        [7] ParamDescriptor::time_ms("Amp Attack", "A", 0.1, 5000.0, 10.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5007), "synth_amp_attack")
+                .with_group("Amp Envelope")
+                .with_description("Time to reach full level after a note starts."),
+            get: this.values[7],
+            set: |v| this.apply_param(7, v);
+
+        [8] ParamDescriptor::time_ms("Amp Decay", "D", 1.0, 5000.0, 100.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5008), "synth_amp_decay")
+                .with_group("Amp Envelope")
+                .with_description("Time to fall from full level to the sustain level."),
+            get: this.values[8],
+            set: |v| this.apply_param(8, v);
+
+        [9] ParamDescriptor::custom("Amp Sustain", "S", 0.0, 100.0, 70.0)
+                .with_unit(ParamUnit::Percent)
+                .with_step(1.0)
+                .with_id(ParamId(5009), "synth_amp_sustain")
+                .with_group("Amp Envelope")
+                .with_description("Level held while the note is down."),
+            get: this.values[9],
+            set: |v| this.apply_param(9, v);
+
+        [10] ParamDescriptor::time_ms("Amp Release", "R", 1.0, 10000.0, 200.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5010), "synth_amp_release")
+                .with_group("Amp Envelope")
+                .with_description("Time to fade out after the note ends."),
+            get: this.values[10],
+            set: |v| this.apply_param(10, v);
+
+        [11] ParamDescriptor::time_ms("Filter Attack", "FA", 0.1, 5000.0, 10.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5011), "synth_filter_attack")
+                .with_group("Filter Envelope")
+                .with_description("Time for the filter envelope to reach its peak."),
+            get: this.values[11],
+            set: |v| this.apply_param(11, v);
+
+        [12] ParamDescriptor::time_ms("Filter Decay", "FD", 1.0, 5000.0, 100.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5012), "synth_filter_decay")
+                .with_group("Filter Envelope")
+                .with_description("Time for the filter envelope to fall to sustain."),
+            get: this.values[12],
+            set: |v| this.apply_param(12, v);
+
+        [13] ParamDescriptor::custom("Filter Sustain", "FS", 0.0, 100.0, 70.0)
+                .with_unit(ParamUnit::Percent)
+                .with_step(1.0)
+                .with_id(ParamId(5013), "synth_filter_sustain")
+                .with_group("Filter Envelope")
+                .with_description("Filter envelope level held while the note is down."),
+            get: this.values[13],
+            set: |v| this.apply_param(13, v);
+
+        [14] ParamDescriptor::time_ms("Filter Release", "FR", 1.0, 10000.0, 200.0)
+                .with_scale(ParamScale::Logarithmic)
+                .with_id(ParamId(5014), "synth_filter_release")
+                .with_group("Filter Envelope")
+                .with_description("Time for the filter envelope to return after the note ends."),
+            get: this.values[14],
+            set: |v| this.apply_param(14, v);
+
+        [15] ParamDescriptor::rate_hz(0.05, 20.0, 5.0)
+                .with_id(ParamId(5015), "synth_lfo_rate")
+                .with_group("LFO")
+                .with_description("LFO speed."),
+            get: this.values[15],
+            set: |v| this.apply_param(15, v);
+
+        [16] ParamDescriptor::custom("LFO Wave", "LWave", 0.0, 4.0, 0.0)
+                .with_step(1.0)
+                .with_id(ParamId(5016), "synth_lfo_wave")
+                .with_group("LFO")
+                .with_description("LFO waveform.")
+                .with_flags(ParamFlags::AUTOMATABLE.union(ParamFlags::STEPPED))
+                .with_step_labels(LFO_WAVE_LABELS),
+            get: this.values[16],
+            set: |v| this.apply_param(16, v);
+
+        [17] ParamDescriptor::custom("LFO Pitch", "LPitch", 0.0, 12.0, 0.0)
+                .with_unit(ParamUnit::Semitones)
+                .with_step(0.01)
+                .with_id(ParamId(5017), "synth_lfo_pitch")
+                .with_group("LFO")
+                .with_description("Vibrato depth from the LFO."),
+            get: this.values[17],
+            set: |v| this.apply_param(17, v);
+
+        [18] ParamDescriptor::custom("LFO Filter", "LFilt", 0.0, 5000.0, 0.0)
+                .with_unit(ParamUnit::Hertz)
+                .with_step(10.0)
+                .with_id(ParamId(5018), "synth_lfo_filter")
+                .with_smoothing(SmoothingStyle::Slow)
+                .with_group("LFO")
+                .with_description("Cutoff modulation depth from the LFO."),
+            get: this.values[18],
+            set: |v| this.apply_param(18, v);
+
+        [19] sonido_core::gain::output_param_descriptor()
+                .with_id(ParamId(5019), "synth_output"),
+            get: this.values[19],
+            set: |v| this.apply_param(19, v);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::envelope::EnvelopeState;
+    use crate::mod_matrix::{ModDestination, ModSourceId};
 
     #[test]
     fn test_monophonic_synth_basic() {
@@ -809,6 +1504,124 @@ mod tests {
         );
     }
 
+    /// Samples until a mono synth's pitch reaches its target.
+    ///
+    /// Linear glides land within a few samples of the glide time; the pitch
+    /// accumulates in `f32`.
+    fn glide_samples(synth: &mut MonophonicSynth) -> usize {
+        let mut n = 0;
+        while synth.current_pitch != synth.target_pitch && n < 480_000 {
+            synth.process();
+            n += 1;
+        }
+        n
+    }
+
+    #[test]
+    fn test_monophonic_glide_mode_and_curve() {
+        let mut synth = MonophonicSynth::new(48000.0);
+        synth.set_glide_time(100.0);
+        synth.set_glide_curve(GlideCurve::Linear);
+
+        // Constant time: a fifth and two octaves both take 100 ms
+        synth.note_on(48, 100);
+        synth.note_on(55, 100);
+        assert!(glide_samples(&mut synth).abs_diff(4800) <= 5);
+        synth.note_on(79, 100);
+        assert!(glide_samples(&mut synth).abs_diff(4800) <= 5);
+
+        // Constant rate: 100 ms per octave
+        synth.set_glide_mode(GlideMode::ConstantRate);
+        synth.note_on(55, 100);
+        assert!(glide_samples(&mut synth).abs_diff(9600) <= 5);
+
+        // A quarter of the way in: linear has covered a quarter of the
+        // interval, exponential follows a one-pole curve
+        synth.set_glide_mode(GlideMode::ConstantTime);
+        synth.note_on(67, 100);
+        for _ in 0..1200 {
+            synth.process();
+        }
+        let linear = synth.current_pitch - 55.0;
+        assert!((linear - 3.0).abs() < 0.01, "Linear glide at {linear}");
+
+        synth.set_glide_curve(GlideCurve::Exponential);
+        synth.note_on(55, 100);
+        glide_samples(&mut synth);
+        synth.note_on(67, 100);
+        for _ in 0..1200 {
+            synth.process();
+        }
+        let exponential = synth.current_pitch - 55.0;
+        let expected = 12.0 * (1.0 - libm::expf(-0.25));
+        assert!(
+            (exponential - expected).abs() < 0.01,
+            "Exponential glide at {exponential}"
+        );
+    }
+
+    #[test]
+    fn test_monophonic_legato_glide_and_retrigger() {
+        let mut synth = MonophonicSynth::new(48000.0);
+        synth.set_glide_time(50.0);
+        synth.set_legato_glide(true);
+        synth.set_amp_release(1000.0);
+
+        // Detached notes jump, even while the previous note is releasing
+        synth.note_on(60, 100);
+        synth.note_off(60);
+        synth.process();
+        synth.note_on(64, 100);
+        assert_eq!(synth.current_pitch, 64.0);
+
+        // Overlapping notes glide
+        synth.note_on(67, 100);
+        synth.process();
+        assert!(synth.current_pitch > 64.0 && synth.current_pitch < 67.0);
+
+        // Without retrigger, legato notes continue the envelope
+        synth.set_retrigger(false);
+        glide_samples(&mut synth);
+        for _ in 0..48000 {
+            synth.process();
+        }
+        let state = synth.amp_env.state();
+        assert_ne!(state, EnvelopeState::Attack);
+        synth.note_on(72, 100);
+        assert_eq!(synth.amp_env.state(), state);
+
+        // Releasing the sounding key returns to the previous held key
+        synth.note_off(72);
+        assert_eq!(synth.target_pitch, 67.0);
+        assert!(synth.amp_env.is_active());
+        synth.note_off(64); // not sounding
+        assert_eq!(synth.target_pitch, 67.0);
+        synth.note_off(67);
+        assert_eq!(synth.amp_env.state(), EnvelopeState::Release);
+    }
+
+    #[test]
+    fn test_monophonic_glide_params() {
+        let mut synth = MonophonicSynth::new(48000.0);
+        assert_eq!(synth.param_count(), 5);
+        for index in 0..synth.param_count() {
+            let desc = synth.param_info(index).unwrap();
+            assert_eq!(synth.get_param(index), desc.default, "{}", desc.name);
+        }
+
+        synth.set_param(0, 5000.0);
+        assert_eq!(synth.glide_time(), 2000.0);
+        synth.set_param(1, 1.0);
+        assert_eq!(synth.glide_mode(), GlideMode::ConstantRate);
+        synth.set_param(2, 1.0);
+        assert_eq!(synth.glide_curve(), GlideCurve::Linear);
+        synth.set_param(3, 1.0);
+        assert!(synth.legato_glide());
+        synth.set_param(4, 0.0);
+        assert!(!synth.retrigger());
+        assert_eq!(synth.get_param(4), 0.0);
+    }
+
     #[test]
     fn test_polyphonic_synth_basic() {
         let mut synth: PolyphonicSynth<4> = PolyphonicSynth::new(48000.0);
@@ -865,6 +1678,75 @@ mod tests {
         assert_eq!(synth.active_voice_count(), 0);
     }
 
+    #[test]
+    fn test_polyphonic_synth_mpe_pressure_per_note() {
+        let mut synth: PolyphonicSynth<4> = PolyphonicSynth::new(48000.0);
+        synth.set_mpe(Some(MpeZone::lower(15)));
+        // Full pressure closes the VCA
+        assert!(synth.add_mod_route(ModulationRoute::new(
+            ModSourceId::Aftertouch,
+            ModDestination::Amplitude,
+            -1.0,
+        )));
+
+        let energy =
+            |synth: &mut PolyphonicSynth<4>| (0..1000).map(|_| synth.process().abs()).sum::<f32>();
+        synth.note_on_channel(2, 60, 100);
+        synth.channel_pressure(2, 1.0);
+        assert_eq!(energy(&mut synth), 0.0);
+
+        // Pressure on another note's channel leaves this one alone
+        synth.note_on_channel(3, 67, 100);
+        assert!(energy(&mut synth) > 0.0);
+        synth.channel_pressure(3, 1.0);
+        assert_eq!(energy(&mut synth), 0.0);
+    }
+
+    #[test]
+    fn test_polyphonic_synth_fm_engine() {
+        let mut synth: PolyphonicSynth<4> = PolyphonicSynth::new(48000.0);
+        synth.set_engine(VoiceEngine::Fm);
+        synth.set_fm_operator_count(3);
+        synth.set_fm_algorithm(Fm4Algorithm::Serial);
+        synth.set_fm_ratio(1, 2.0);
+        synth.set_fm_index(1, 3.0);
+        synth.set_fm_envelope(2, 1.0, 200.0, 0.0, 100.0);
+        synth.set_fm_ratio(7, 2.0); // ignored
+
+        synth.note_on(60, 100);
+        synth.note_on(67, 100);
+        let mut sum = 0.0;
+        for _ in 0..4800 {
+            let sample = synth.process();
+            assert!(sample.is_finite());
+            sum += sample.abs();
+        }
+        assert!(sum > 1.0, "FM synth should produce output");
+    }
+
+    #[test]
+    fn test_polyphonic_synth_unison_budget() {
+        let mut synth: PolyphonicSynth<8> = PolyphonicSynth::new(48000.0);
+        synth.set_unison_count(4);
+        synth.set_unison_spread(25.0);
+        synth.set_stereo_width(1.0);
+        synth.set_phase_random(1.0);
+        synth.set_oscillator_budget(Some(16));
+
+        for note in [48, 55, 60, 64] {
+            synth.note_on(note, 100);
+        }
+        assert_eq!(synth.active_voice_count(), 2);
+
+        let mut side = 0.0;
+        for _ in 0..4800 {
+            let (l, r) = synth.process_stereo();
+            assert!(l.is_finite() && r.is_finite());
+            side += (l - r).abs();
+        }
+        assert!(side > 1.0, "Wide unison should differ between channels");
+    }
+
     // --- SynthNode tests ---
 
     #[test]
@@ -956,4 +1838,75 @@ mod tests {
         node.all_notes_off();
         assert_eq!(node.active_voice_count(), 0);
     }
+
+    #[test]
+    fn test_synth_node_params_start_at_defaults() {
+        let node = SynthNode::new(48000.0);
+        assert_eq!(node.param_count(), SYNTH_PARAM_COUNT);
+        for i in 0..SYNTH_PARAM_COUNT {
+            let desc = node.param_info(i).unwrap();
+            assert_eq!(node.get_param(i), desc.default, "param {i} ({})", desc.name);
+        }
+        assert!(node.param_info(SYNTH_PARAM_COUNT).is_none());
+    }
+
+    #[test]
+    fn test_synth_node_param_ids_unique() {
+        let node = SynthNode::new(48000.0);
+        for i in 0..SYNTH_PARAM_COUNT {
+            let a = node.param_info(i).unwrap();
+            assert!(a.string_id.starts_with("synth_"));
+            for j in (i + 1)..SYNTH_PARAM_COUNT {
+                let b = node.param_info(j).unwrap();
+                assert_ne!(a.id, b.id);
+                assert_ne!(a.string_id, b.string_id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_synth_node_set_param_clamps() {
+        let mut node = SynthNode::new(48000.0);
+        node.set_param(4, 1.0e6);
+        assert_eq!(node.get_param(4), 20000.0);
+        node.set_param(0, -3.0);
+        assert_eq!(node.get_param(0), 0.0);
+    }
+
+    #[test]
+    fn test_synth_node_output_param_scales_level() {
+        let render = |output_db: f32| {
+            let mut node = SynthNode::new(48000.0);
+            node.set_param(19, output_db);
+            node.note_on(60, 100);
+            (0..2000)
+                .map(|_| node.process_stereo(0.0, 0.0).0.abs())
+                .fold(0.0_f32, f32::max)
+        };
+        let unity = render(0.0);
+        let quiet = render(-6.0);
+        assert!(unity > 0.0);
+        assert!(
+            (quiet / unity - db_to_linear(-6.0)).abs() < 1e-3,
+            "-6 dB output should halve the level (ratio={})",
+            quiet / unity
+        );
+    }
+
+    #[test]
+    fn test_synth_node_cutoff_param_darkens_output() {
+        // A lower cutoff should remove energy from a saw wave.
+        let energy = |cutoff: f32| {
+            let mut node = SynthNode::new(48000.0);
+            node.set_param(4, cutoff);
+            node.note_on(48, 100);
+            (0..4800)
+                .map(|_| {
+                    let (l, _) = node.process_stereo(0.0, 0.0);
+                    l * l
+                })
+                .sum::<f32>()
+        };
+        assert!(energy(200.0) < energy(8000.0));
+    }
 }
@@ -5,10 +5,18 @@
 //! polyphonic synthesizers.
 
 use crate::envelope::AdsrEnvelope;
+use crate::fm::FmVoice;
 use crate::mod_matrix::{ModDestination, ModSourceId, ModulationMatrix, ModulationValues};
+use crate::mpe::{MASTER_BEND_RANGE, MIDI_CHANNELS, MpeZone};
 use crate::oscillator::{Oscillator, OscillatorWaveform};
+use crate::sampler::{SampleMap, SamplerVoice};
 use sonido_core::{Effect, SmoothedParam, StateVariableFilter};
 
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
 /// Maximum number of unison sub-voices per voice.
 pub const MAX_UNISON: usize = 16;
 
@@ -26,6 +34,20 @@ pub enum VoiceAllocationMode {
     HighestNote,
 }
 
+/// Sound source of a [`Voice`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VoiceEngine {
+    /// Two oscillators per unison sub-voice (default)
+    #[default]
+    Subtractive,
+    /// 2–4 operator FM ([`FmVoice`]); unison and the oscillator settings
+    /// are ignored
+    Fm,
+    /// Sample playback ([`SamplerVoice`]) from the voice's sample map;
+    /// unison and the oscillator settings are ignored
+    Sampler,
+}
+
 /// A lightweight unison sub-voice — oscillator pair with pan and detune.
 ///
 /// Each sub-voice contains its own oscillator pair (osc1 + osc2) with
@@ -115,7 +137,10 @@ impl SubVoice {
 /// A single synthesizer voice with modulation matrix, portamento, and unison.
 ///
 /// Contains sub-voices (for unison), a filter, amplitude/filter envelopes,
-/// and a per-voice modulation matrix for flexible routing.
+/// and a per-voice modulation matrix for flexible routing. With
+/// [`VoiceEngine::Fm`] the sub-voices are replaced by an [`FmVoice`]; the
+/// filter, amplitude envelope, and modulation matrix apply either way, and
+/// [`ModDestination::FmIndex`] scales the FM modulation depth.
 ///
 /// ## Parameters
 /// - `osc2_detune`: Oscillator 2 detune in cents (0.0, default 0.0)
@@ -125,6 +150,7 @@ impl SubVoice {
 /// - `unison_count`: Number of unison sub-voices (1 to 16, default 1)
 /// - `unison_spread`: Detune spread in cents across unison voices (0.0 to 100.0, default 0.0)
 /// - `stereo_width`: Stereo spread of unison voices (0.0 to 1.0, default 1.0)
+/// - `phase_random`: Start phase randomization per note (0.0 to 1.0, default 0.0)
 ///
 /// # Example
 ///
@@ -153,6 +179,17 @@ pub struct Voice {
     unison_spread: f32,
     /// Stereo width for unison pan distribution (0.0 to 1.0)
     stereo_width: f32,
+    /// How far oscillator start phases are randomized on note-on (0.0 to 1.0)
+    phase_random: f32,
+    /// Xorshift state for start phases
+    rng_state: u32,
+
+    /// Sound source: sub-voice oscillators, FM, or samples
+    engine: VoiceEngine,
+    /// FM operators and their envelopes (used with `VoiceEngine::Fm`)
+    pub fm: FmVoice,
+    /// Sample playback (used with `VoiceEngine::Sampler`)
+    pub sampler: SamplerVoice,
 
     /// Filter (shared across all sub-voices)
     pub filter: StateVariableFilter,
@@ -223,6 +260,11 @@ impl Voice {
             unison_count: 1,
             unison_spread: 0.0,
             stereo_width: 1.0,
+            phase_random: 0.0,
+            rng_state: 0x9E37_79B9,
+            engine: VoiceEngine::Subtractive,
+            fm: FmVoice::new(sample_rate),
+            sampler: SamplerVoice::new(sample_rate),
             filter: StateVariableFilter::new(sample_rate),
             amp_env: AdsrEnvelope::new(sample_rate),
             filter_env: AdsrEnvelope::new(sample_rate),
@@ -258,12 +300,24 @@ impl Voice {
         for sv in &mut self.sub_voices {
             sv.set_sample_rate(sample_rate);
         }
+        self.fm.set_sample_rate(sample_rate);
+        self.sampler.set_sample_rate(sample_rate);
         self.filter.set_sample_rate(sample_rate);
         self.amp_env.set_sample_rate(sample_rate);
         self.filter_env.set_sample_rate(sample_rate);
         self.freq_target.set_sample_rate(sample_rate);
     }
 
+    /// Set the sound source.
+    pub fn set_engine(&mut self, engine: VoiceEngine) {
+        self.engine = engine;
+    }
+
+    /// Get the sound source.
+    pub fn engine(&self) -> VoiceEngine {
+        self.engine
+    }
+
     /// Trigger note on.
     ///
     /// With portamento enabled, the frequency smoothly glides from the
@@ -284,10 +338,19 @@ impl Voice {
         }
 
         // Reset sub-voice oscillators and apply frequencies
-        for sv in &mut self.sub_voices[..self.unison_count] {
+        for i in 0..self.unison_count {
+            let sv = &mut self.sub_voices[i];
             sv.set_frequency(freq, self.osc2_detune);
             sv.reset();
+            if self.phase_random > 0.0 {
+                let (phase1, phase2) = (self.next_phase(), self.next_phase());
+                let sv = &mut self.sub_voices[i];
+                sv.osc1.set_phase(phase1);
+                sv.osc2.set_phase(phase2);
+            }
         }
+        self.fm.note_on();
+        self.sampler.note_on(note);
 
         self.amp_env.gate_on();
         self.filter_env.gate_on();
@@ -298,9 +361,15 @@ impl Voice {
     }
 
     /// Trigger note off.
+    ///
+    /// One-shot samples ignore note-off and play to their end.
     pub fn note_off(&mut self) {
+        if self.engine == VoiceEngine::Sampler && self.sampler.is_one_shot() {
+            return;
+        }
         self.amp_env.gate_off();
         self.filter_env.gate_off();
+        self.fm.note_off();
     }
 
     /// Force voice to stop immediately.
@@ -308,6 +377,8 @@ impl Voice {
         self.active = false;
         self.amp_env.reset();
         self.filter_env.reset();
+        self.fm.reset();
+        self.sampler.reset();
     }
 
     /// Reset voice to initial state.
@@ -458,6 +529,47 @@ impl Voice {
         self.stereo_width
     }
 
+    /// Set how far oscillator start phases are randomized on each note.
+    ///
+    /// Range: 0.0 (every note starts at phase 0, identical attacks) to 1.0
+    /// (anywhere in the cycle, free-running). Random phases keep stacked
+    /// unison oscillators from starting in step, which otherwise gives each
+    /// note the same flanged attack.
+    pub fn set_phase_random(&mut self, amount: f32) {
+        self.phase_random = amount.clamp(0.0, 1.0);
+    }
+
+    /// Get start phase randomization.
+    pub fn phase_random(&self) -> f32 {
+        self.phase_random
+    }
+
+    /// Seed the start phase generator, so voices in a pool don't share a
+    /// sequence.
+    pub(crate) fn seed_phases(&mut self, seed: u32) {
+        self.rng_state = seed.max(1);
+    }
+
+    /// Next random start phase, scaled by `phase_random` (0.0 to < 1.0).
+    fn next_phase(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32 * self.phase_random
+    }
+
+    /// Oscillators this voice runs per sample: two per unison sub-voice, one
+    /// per FM operator, or one for sample playback. Used for voice budgeting.
+    pub fn oscillator_count(&self) -> usize {
+        match self.engine {
+            VoiceEngine::Subtractive => 2 * self.unison_count,
+            VoiceEngine::Fm => self.fm.operator_count(),
+            VoiceEngine::Sampler => 1,
+        }
+    }
+
     /// Get read access to sub-voices.
     pub fn sub_voices(&self) -> &[SubVoice] {
         &self.sub_voices[..self.unison_count]
@@ -566,6 +678,38 @@ impl Voice {
         }
     }
 
+    /// Apply pitch modulation (semitones) and sum the unison sub-voices.
+    #[inline]
+    fn advance_sub_voices(&mut self, total_pitch_mod: f32) -> (f32, f32) {
+        if total_pitch_mod.abs() > 1e-6 {
+            let pitch_ratio = cents_to_ratio(total_pitch_mod * 100.0);
+            for sv in &mut self.sub_voices[..self.unison_count] {
+                let f1 = sv.osc1.frequency() * pitch_ratio;
+                let f2 = sv.osc2.frequency() * pitch_ratio;
+                sv.osc1.set_frequency(f1);
+                sv.osc2.set_frequency(f2);
+            }
+        }
+
+        // Sum sub-voices with stereo panning
+        let mut left = 0.0_f32;
+        let mut right = 0.0_f32;
+        let count = self.unison_count;
+        for sv in &mut self.sub_voices[..count] {
+            let sample = sv.advance(self.osc_mix);
+            // Constant-power pan law: left = cos(angle), right = sin(angle)
+            // where angle = (pan + 1) * pi/4 maps [-1,1] to [0, pi/2]
+            let angle = (sv.pan + 1.0) * core::f32::consts::FRAC_PI_4;
+            let (sin_a, cos_a) = libm::sincosf(angle);
+            left += sample * cos_a;
+            right += sample * sin_a;
+        }
+
+        // Normalize gain: 1/sqrt(count) to maintain perceived loudness
+        let gain_norm = 1.0 / libm::sqrtf(count as f32);
+        (left * gain_norm, right * gain_norm)
+    }
+
     /// Process one mono sample.
     ///
     /// Sums all active unison sub-voices, applies filter with envelope + mod
@@ -593,10 +737,11 @@ impl Voice {
 
         // Advance portamento and get current base frequency
         let base_freq = self.freq_target.advance();
-
         // Update sub-voice frequencies with portamento
-        for sv in &mut self.sub_voices[..self.unison_count] {
-            sv.set_frequency(base_freq, self.osc2_detune);
+        if self.engine == VoiceEngine::Subtractive {
+            for sv in &mut self.sub_voices[..self.unison_count] {
+                sv.set_frequency(base_freq, self.osc2_detune);
+            }
         }
 
         // Advance envelopes
@@ -624,34 +769,33 @@ impl Voice {
         // Apply pitch modulation (in semitones) to sub-voice frequencies.
         // MPE per-note pitch bend is additive with global LFO/mod-matrix pitch.
         let total_pitch_mod = pitch_mod + self.external_pitch_mod + self.mpe_pitch_bend_semitones;
-        if total_pitch_mod.abs() > 1e-6 {
-            let pitch_ratio = cents_to_ratio(total_pitch_mod * 100.0);
-            for sv in &mut self.sub_voices[..self.unison_count] {
-                let f1 = sv.osc1.frequency() * pitch_ratio;
-                let f2 = sv.osc2.frequency() * pitch_ratio;
-                sv.osc1.set_frequency(f1);
-                sv.osc2.set_frequency(f2);
+        // Sub-voices apply the pitch mod to their own detuned frequencies
+        let freq = if self.engine != VoiceEngine::Subtractive && total_pitch_mod.abs() > 1e-6 {
+            base_freq * cents_to_ratio(total_pitch_mod * 100.0)
+        } else {
+            base_freq
+        };
+        let (mut left, mut right) = match self.engine {
+            VoiceEngine::Subtractive => self.advance_sub_voices(total_pitch_mod),
+            VoiceEngine::Fm => {
+                let index_mod = self
+                    .mod_matrix
+                    .get_modulation(ModDestination::FmIndex, &self.mod_values);
+                // Centered, at the same level as a single panned sub-voice
+                let sample = self.fm.advance(freq, (1.0 + index_mod).max(0.0))
+                    * core::f32::consts::FRAC_1_SQRT_2;
+                (sample, sample)
             }
-        }
-
-        // Sum sub-voices with stereo panning
-        let mut left = 0.0_f32;
-        let mut right = 0.0_f32;
-        let count = self.unison_count;
-        for sv in &mut self.sub_voices[..count] {
-            let sample = sv.advance(self.osc_mix);
-            // Constant-power pan law: left = cos(angle), right = sin(angle)
-            // where angle = (pan + 1) * pi/4 maps [-1,1] to [0, pi/2]
-            let angle = (sv.pan + 1.0) * core::f32::consts::FRAC_PI_4;
-            let (sin_a, cos_a) = libm::sincosf(angle);
-            left += sample * cos_a;
-            right += sample * sin_a;
-        }
-
-        // Normalize gain: 1/sqrt(count) to maintain perceived loudness
-        let gain_norm = 1.0 / libm::sqrtf(count as f32);
-        left *= gain_norm;
-        right *= gain_norm;
+            VoiceEngine::Sampler => {
+                let frame = self.sampler.advance(freq);
+                // The sample ran out (or the note has none): the voice is done
+                if !self.sampler.is_playing() {
+                    self.kill();
+                    return (0.0, 0.0);
+                }
+                frame
+            }
+        };
 
         // Apply filter with envelope + mod matrix + external modulation (bipolar env amount)
         let modulated_cutoff = self.filter_cutoff
@@ -674,10 +818,41 @@ impl Voice {
     }
 }
 
+/// Pitch bend, pressure, and timbre last received on one MIDI channel.
+#[derive(Clone, Copy, Debug, Default)]
+struct ChannelExpression {
+    /// Pitch bend (-1.0 to 1.0), scaled by the channel's bend range.
+    bend: f32,
+    /// Channel pressure (0.0 to 1.0).
+    pressure: f32,
+    /// CC 74 (0.0 to 1.0).
+    timbre: f32,
+}
+
 /// Voice manager for polyphonic synthesis.
 ///
 /// Manages a pool of voices and handles note allocation/stealing.
 ///
+/// ## MPE
+///
+/// With an [`MpeZone`] set, notes, pitch bend, channel pressure, and timbre
+/// (CC 74) are tracked per MIDI channel. Each voice follows the expression
+/// of the channel its note arrived on, plus pitch bend from the zone's
+/// master channel. Pressure reaches the voice's modulation matrix as
+/// [`ModSourceId::Aftertouch`], timbre as [`ModSourceId::Custom1`], and
+/// pitch bend as [`ModSourceId::PitchBend`] in addition to bending the
+/// oscillators. Without a zone, every channel acts as one shared channel
+/// that shapes all voices. See [`crate::mpe`].
+///
+/// ## Voice Budget
+///
+/// Unison multiplies the cost of every note. An oscillator budget
+/// ([`set_oscillator_budget()`](Self::set_oscillator_budget)) caps the
+/// oscillators running across all sounding voices (see
+/// [`Voice::oscillator_count`]); a note that would exceed it steals voices
+/// by the allocation mode first, so polyphony shrinks as unison grows
+/// instead of CPU load climbing.
+///
 /// # Example
 ///
 /// ```rust
@@ -705,17 +880,33 @@ pub struct VoiceManager<const N: usize> {
     age_counter: u64,
     /// Round-robin index
     round_robin_idx: usize,
+    /// MPE zone, or `None` for channel-wide expression
+    mpe: Option<MpeZone>,
+    /// Master channel (and non-MPE) pitch bend range in semitones
+    bend_range: f32,
+    /// Expression per MIDI channel, indexed by channel - 1
+    expression: [ChannelExpression; MIDI_CHANNELS as usize],
+    /// Maximum oscillators across sounding voices, or `None` for no limit
+    oscillator_budget: Option<usize>,
 }
 
 impl<const N: usize> VoiceManager<N> {
     /// Create a new voice manager with the specified number of voices.
     pub fn new(sample_rate: f32) -> Self {
         Self {
-            voices: core::array::from_fn(|_| Voice::new(sample_rate)),
+            voices: core::array::from_fn(|i| {
+                let mut voice = Voice::new(sample_rate);
+                voice.seed_phases(0x9E37_79B9 ^ (i as u32).wrapping_mul(0x85EB_CA6B));
+                voice
+            }),
             allocation_mode: VoiceAllocationMode::RoundRobin,
             sample_rate,
             age_counter: 0,
             round_robin_idx: 0,
+            mpe: None,
+            bend_range: MASTER_BEND_RANGE,
+            expression: [ChannelExpression::default(); MIDI_CHANNELS as usize],
+            oscillator_budget: None,
         }
     }
 
@@ -747,6 +938,38 @@ impl<const N: usize> VoiceManager<N> {
         self.voices.iter().filter(|v| v.is_active()).count()
     }
 
+    /// Give every voice the same sample map, for voices using
+    /// [`VoiceEngine::Sampler`]. The samples are shared, not copied.
+    pub fn set_sample_map(&mut self, map: SampleMap) {
+        let map = Arc::new(map);
+        for voice in &mut self.voices {
+            voice.sampler.set_map(Some(Arc::clone(&map)));
+        }
+    }
+
+    /// Cap the oscillators running across all sounding voices, or `None`
+    /// for no limit beyond the voice count.
+    ///
+    /// A note always gets a voice, even if it alone exceeds the budget.
+    /// Voices already sounding are left alone until the next note.
+    pub fn set_oscillator_budget(&mut self, budget: Option<usize>) {
+        self.oscillator_budget = budget;
+    }
+
+    /// Get the oscillator budget.
+    pub fn oscillator_budget(&self) -> Option<usize> {
+        self.oscillator_budget
+    }
+
+    /// Oscillators running across all sounding voices.
+    pub fn active_oscillator_count(&self) -> usize {
+        self.voices
+            .iter()
+            .filter(|v| v.is_active())
+            .map(Voice::oscillator_count)
+            .sum()
+    }
+
     /// Get mutable access to all voices (for setting parameters).
     pub fn voices_mut(&mut self) -> &mut [Voice; N] {
         &mut self.voices
@@ -757,12 +980,59 @@ impl<const N: usize> VoiceManager<N> {
         &self.voices
     }
 
+    /// Set the MPE zone, or `None` for channel-wide expression.
+    ///
+    /// Clears expression received so far.
+    pub fn set_mpe(&mut self, zone: Option<MpeZone>) {
+        self.mpe = zone;
+        self.expression = [ChannelExpression::default(); MIDI_CHANNELS as usize];
+        for i in 0..N {
+            self.apply_expression(i);
+        }
+    }
+
+    /// Get the MPE zone.
+    pub fn mpe(&self) -> Option<MpeZone> {
+        self.mpe
+    }
+
+    /// Set the pitch bend range of the master channel, or of every channel
+    /// without MPE.
+    ///
+    /// Range: 0.0 to 96.0 semitones. Default 2.0. The member channel range
+    /// is part of the [`MpeZone`].
+    pub fn set_pitch_bend_range(&mut self, semitones: f32) {
+        self.bend_range = semitones.clamp(0.0, 96.0);
+        for i in 0..N {
+            self.apply_expression(i);
+        }
+    }
+
+    /// Get the master channel pitch bend range in semitones.
+    pub fn pitch_bend_range(&self) -> f32 {
+        self.bend_range
+    }
+
     /// Trigger a note on.
+    ///
+    /// With MPE, the note plays on the master channel.
     pub fn note_on(&mut self, note: u8, velocity: u8) {
+        let channel = self.mpe.map_or(1, |zone| zone.master_channel());
+        self.note_on_channel(channel, note, velocity);
+    }
+
+    /// Trigger a note on a MIDI channel (1–16).
+    ///
+    /// The voice starts with the channel's current pitch bend, pressure,
+    /// and timbre, so expression sent just before the note applies to it.
+    pub fn note_on_channel(&mut self, channel: u8, note: u8, velocity: u8) {
         let voice_idx = self.allocate_voice(note);
         self.age_counter += 1;
-        self.voices[voice_idx].set_age(self.age_counter);
-        self.voices[voice_idx].note_on(note, velocity);
+        let voice = &mut self.voices[voice_idx];
+        voice.set_age(self.age_counter);
+        voice.set_mpe_channel(channel);
+        voice.note_on(note, velocity);
+        self.apply_expression(voice_idx);
     }
 
     /// Trigger a note off.
@@ -775,6 +1045,105 @@ impl<const N: usize> VoiceManager<N> {
         }
     }
 
+    /// Trigger a note off on a MIDI channel (1–16).
+    ///
+    /// With MPE, only a note on the same channel is released; otherwise the
+    /// channel is ignored, as in [`note_off()`](Self::note_off).
+    pub fn note_off_channel(&mut self, channel: u8, note: u8) {
+        if self.mpe.is_none() {
+            self.note_off(note);
+            return;
+        }
+        for voice in &mut self.voices {
+            if voice.is_active() && voice.note() == note && voice.mpe_channel() == channel {
+                voice.note_off();
+                return;
+            }
+        }
+    }
+
+    /// Pitch bend on a MIDI channel (1–16).
+    ///
+    /// Range: -1.0 to 1.0, scaled by the channel's bend range. See
+    /// [`pitch_bend_from_midi()`](crate::mpe::pitch_bend_from_midi) for raw
+    /// 14-bit values.
+    pub fn pitch_bend(&mut self, channel: u8, value: f32) {
+        if let Some(index) = self.expression_index(channel) {
+            self.expression[index].bend = value.clamp(-1.0, 1.0);
+            self.expression_changed(index);
+        }
+    }
+
+    /// Channel pressure on a MIDI channel (1–16).
+    ///
+    /// Range: 0.0 to 1.0.
+    pub fn channel_pressure(&mut self, channel: u8, value: f32) {
+        if let Some(index) = self.expression_index(channel) {
+            self.expression[index].pressure = value.clamp(0.0, 1.0);
+            self.expression_changed(index);
+        }
+    }
+
+    /// Timbre (CC 74) on a MIDI channel (1–16).
+    ///
+    /// Range: 0.0 to 1.0.
+    pub fn timbre(&mut self, channel: u8, value: f32) {
+        if let Some(index) = self.expression_index(channel) {
+            self.expression[index].timbre = value.clamp(0.0, 1.0);
+            self.expression_changed(index);
+        }
+    }
+
+    /// Expression slot for `channel`: its own with MPE (master or member),
+    /// the shared slot 0 without. `None` for channels outside the zone.
+    fn expression_index(&self, channel: u8) -> Option<usize> {
+        let Some(zone) = self.mpe else {
+            return Some(0);
+        };
+        (channel == zone.master_channel() || zone.is_member(channel))
+            .then(|| usize::from(channel - 1))
+    }
+
+    /// Expression slot whose pitch bend applies to every voice.
+    fn master_index(&self) -> usize {
+        self.mpe
+            .map_or(0, |zone| usize::from(zone.master_channel() - 1))
+    }
+
+    /// Push a changed channel's expression to the voices that follow it.
+    fn expression_changed(&mut self, index: usize) {
+        let master = self.master_index();
+        for i in 0..N {
+            if index == master || self.expression_index(self.voices[i].mpe_channel()) == Some(index)
+            {
+                self.apply_expression(i);
+            }
+        }
+    }
+
+    /// Set a voice's per-note expression from its channel and the master.
+    fn apply_expression(&mut self, voice_idx: usize) {
+        let master = self.master_index();
+        let channel = self.voices[voice_idx].mpe_channel();
+        let (expression, semitones) = match (self.expression_index(channel), self.mpe) {
+            (Some(index), Some(zone)) if index != master => {
+                let own = self.expression[index];
+                let master_bend = self.expression[master].bend * self.bend_range;
+                (own, own.bend * zone.bend_range() + master_bend)
+            }
+            (Some(index), _) => {
+                let own = self.expression[index];
+                (own, own.bend * self.bend_range)
+            }
+            (None, _) => (ChannelExpression::default(), 0.0),
+        };
+        let voice = &mut self.voices[voice_idx];
+        voice.set_mpe_pitch_bend(semitones);
+        voice.set_mpe_pressure(expression.pressure);
+        voice.set_mpe_slide(expression.timbre);
+        voice.set_mod_source(ModSourceId::PitchBend, expression.bend);
+    }
+
     /// Stop all notes immediately.
     pub fn all_notes_off(&mut self) {
         for voice in &mut self.voices {
@@ -789,6 +1158,7 @@ impl<const N: usize> VoiceManager<N> {
         }
         self.age_counter = 0;
         self.round_robin_idx = 0;
+        self.expression = [ChannelExpression::default(); MIDI_CHANNELS as usize];
     }
 
     /// Process one mono sample from all voices.
@@ -815,6 +1185,20 @@ impl<const N: usize> VoiceManager<N> {
     }
 
     fn allocate_voice(&mut self, _note: u8) -> usize {
+        // Over budget: free voices until the new note fits
+        if let Some(budget) = self.oscillator_budget {
+            let cost = self
+                .voices
+                .iter()
+                .find(|v| !v.is_active())
+                .unwrap_or(&self.voices[0])
+                .oscillator_count();
+            while self.active_voice_count() > 0 && self.active_oscillator_count() + cost > budget {
+                let victim = self.steal_voice();
+                self.voices[victim].kill();
+            }
+        }
+
         // First, try to find a free voice
         for (i, voice) in self.voices.iter().enumerate() {
             if !voice.is_active() {
@@ -823,30 +1207,36 @@ impl<const N: usize> VoiceManager<N> {
         }
 
         // All voices are active, need to steal one
+        self.steal_voice()
+    }
+
+    /// Pick an active voice to steal, by allocation mode.
+    fn steal_voice(&mut self) -> usize {
+        let active = self
+            .voices
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_active());
         match self.allocation_mode {
             VoiceAllocationMode::RoundRobin => {
-                let idx = self.round_robin_idx;
-                self.round_robin_idx = (self.round_robin_idx + 1) % N;
-                idx
+                for _ in 0..N {
+                    let idx = self.round_robin_idx;
+                    self.round_robin_idx = (self.round_robin_idx + 1) % N;
+                    if self.voices[idx].is_active() {
+                        return idx;
+                    }
+                }
+                0
             }
-            VoiceAllocationMode::OldestNote => self
-                .voices
-                .iter()
-                .enumerate()
+            VoiceAllocationMode::OldestNote => active
                 .min_by_key(|(_, v)| v.age())
                 .map(|(i, _)| i)
                 .unwrap_or(0),
-            VoiceAllocationMode::LowestNote => self
-                .voices
-                .iter()
-                .enumerate()
+            VoiceAllocationMode::LowestNote => active
                 .min_by_key(|(_, v)| v.note())
                 .map(|(i, _)| i)
                 .unwrap_or(0),
-            VoiceAllocationMode::HighestNote => self
-                .voices
-                .iter()
-                .enumerate()
+            VoiceAllocationMode::HighestNote => active
                 .max_by_key(|(_, v)| v.note())
                 .map(|(i, _)| i)
                 .unwrap_or(0),
@@ -879,9 +1269,11 @@ pub fn cents_to_ratio(cents: f32) -> f32 {
 #[cfg(test)]
 mod tests {
     extern crate alloc;
-    use alloc::vec::Vec;
+    use alloc::{vec, vec::Vec};
 
     use super::*;
+    use crate::envelope::EnvelopeState;
+    use crate::sampler::Sample;
 
     #[test]
     fn test_midi_to_freq_a4() {
@@ -1310,6 +1702,231 @@ mod tests {
         assert!((voice.mpe_pitch_bend() - (-96.0)).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_fm_engine_produces_output() {
+        let mut voice = Voice::new(48000.0);
+        voice.set_engine(VoiceEngine::Fm);
+        voice.set_filter_cutoff(20000.0);
+        voice.note_on(57, 127);
+
+        let mut sum = 0.0f32;
+        for _ in 0..4800 {
+            let (l, r) = voice.process_stereo();
+            assert!(l.is_finite() && r.is_finite());
+            sum += l.abs();
+        }
+        assert!(sum > 10.0, "FM voice should produce output: {sum}");
+    }
+
+    #[test]
+    fn test_fm_index_mod_destination_scales_depth() {
+        // Full velocity through FmIndex at -1 removes all modulation, which
+        // sounds the same as setting every index to zero
+        let mut routed = Voice::new(48000.0);
+        routed.set_engine(VoiceEngine::Fm);
+        routed.mod_matrix.add_route(crate::ModulationRoute::new(
+            ModSourceId::Velocity,
+            ModDestination::FmIndex,
+            -1.0,
+        ));
+        let mut flat = Voice::new(48000.0);
+        flat.set_engine(VoiceEngine::Fm);
+        for op in &mut flat.fm.engine.ops {
+            op.set_mod_index(0.0);
+        }
+
+        routed.note_on(60, 127);
+        flat.note_on(60, 127);
+        for _ in 0..1000 {
+            assert_eq!(routed.process(), flat.process());
+        }
+    }
+
+    #[test]
+    fn test_sampler_engine_plays_mapped_samples() {
+        let mut manager: VoiceManager<4> = VoiceManager::new(48000.0);
+        let mut map = SampleMap::new();
+        map.add(
+            36,
+            36,
+            Sample::mono(vec![0.5; 480], 48000.0, 36).with_one_shot(true),
+        );
+        map.add(
+            60,
+            72,
+            Sample::mono(vec![0.5; 48000], 48000.0, 60).with_loop(0, 480),
+        );
+        manager.set_sample_map(map);
+        for voice in manager.voices_mut() {
+            voice.set_engine(VoiceEngine::Sampler);
+            voice.set_filter_cutoff(20000.0);
+            voice.amp_env.set_attack_ms(0.1);
+            voice.amp_env.set_release_ms(10.0);
+        }
+
+        // One-shot: note-off is ignored, the voice ends with the sample
+        manager.note_on(36, 127);
+        manager.note_off(36);
+        let mut sum = 0.0;
+        for _ in 0..480 {
+            sum += manager.process_stereo().0.abs();
+        }
+        assert!(sum > 100.0, "One-shot should play through note-off");
+        manager.process_stereo();
+        assert_eq!(manager.active_voice_count(), 0);
+
+        // Unmapped keys end right away
+        manager.note_on(48, 127);
+        manager.process_stereo();
+        assert_eq!(manager.active_voice_count(), 0);
+
+        // Looped samples hold until released
+        manager.note_on(64, 127);
+        for _ in 0..48000 {
+            manager.process_stereo();
+        }
+        assert_eq!(manager.active_voice_count(), 1);
+        manager.note_off(64);
+        for _ in 0..48000 {
+            manager.process_stereo();
+        }
+        assert_eq!(manager.active_voice_count(), 0);
+    }
+
+    #[test]
+    fn test_phase_random_spreads_start_phases() {
+        let mut voice = Voice::new(48000.0);
+        voice.set_unison_count(4);
+        voice.note_on(60, 100);
+        assert!(voice.sub_voices().iter().all(|sv| sv.osc1.phase() == 0.0));
+
+        voice.set_phase_random(1.0);
+        voice.note_on(60, 100);
+        let phases: Vec<f32> = voice
+            .sub_voices()
+            .iter()
+            .map(|sv| sv.osc1.phase())
+            .collect();
+        assert!(phases.iter().all(|p| (0.0..1.0).contains(p)));
+        assert!(
+            phases.windows(2).all(|w| w[0] != w[1]),
+            "Sub-voices should start at different phases: {phases:?}"
+        );
+
+        voice.set_phase_random(0.25);
+        voice.note_on(60, 100);
+        assert!(voice.sub_voices().iter().all(|sv| sv.osc1.phase() < 0.25));
+    }
+
+    #[test]
+    fn test_voice_manager_oscillator_budget() {
+        let mut manager: VoiceManager<8> = VoiceManager::new(48000.0);
+        for voice in manager.voices_mut() {
+            voice.set_unison_count(4); // 8 oscillators per voice
+        }
+        manager.set_oscillator_budget(Some(24));
+        manager.set_allocation_mode(VoiceAllocationMode::OldestNote);
+
+        for note in [60, 64, 67, 72] {
+            manager.note_on(note, 100);
+        }
+        // Only three 8-oscillator voices fit; the oldest note was stolen
+        assert_eq!(manager.active_voice_count(), 3);
+        assert_eq!(manager.active_oscillator_count(), 24);
+        assert!(
+            !manager
+                .voices()
+                .iter()
+                .any(|v| v.is_active() && v.note() == 60)
+        );
+
+        // Less unison, more polyphony
+        manager.all_notes_off();
+        for voice in manager.voices_mut() {
+            voice.set_unison_count(2);
+        }
+        for note in 60..66 {
+            manager.note_on(note, 100);
+        }
+        assert_eq!(manager.active_voice_count(), 6);
+
+        // A single note over budget still plays
+        manager.set_oscillator_budget(Some(2));
+        manager.note_on(70, 100);
+        assert_eq!(manager.active_voice_count(), 1);
+    }
+
+    fn voice_for(manager: &VoiceManager<4>, note: u8) -> &Voice {
+        manager
+            .voices()
+            .iter()
+            .find(|v| v.is_active() && v.note() == note)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_voice_manager_mpe_per_note_expression() {
+        let mut manager: VoiceManager<4> = VoiceManager::new(48000.0);
+        manager.set_mpe(Some(MpeZone::lower(15)));
+
+        // Expression sent before the note applies to it
+        manager.pitch_bend(3, 0.5);
+        manager.note_on_channel(2, 60, 100);
+        manager.note_on_channel(3, 64, 100);
+        assert_eq!(voice_for(&manager, 60).mpe_pitch_bend(), 0.0);
+        assert_eq!(voice_for(&manager, 64).mpe_pitch_bend(), 24.0);
+
+        manager.channel_pressure(2, 0.8);
+        manager.timbre(2, 0.3);
+        let voice = voice_for(&manager, 60);
+        assert_eq!(voice.mpe_pressure(), 0.8);
+        assert_eq!(voice.mpe_slide(), 0.3);
+        assert_eq!(voice_for(&manager, 64).mpe_pressure(), 0.0);
+
+        // Master bend moves every note at the master range
+        manager.pitch_bend(1, 1.0);
+        assert_eq!(voice_for(&manager, 60).mpe_pitch_bend(), 2.0);
+        assert_eq!(voice_for(&manager, 64).mpe_pitch_bend(), 26.0);
+        assert_eq!(
+            voice_for(&manager, 64)
+                .mod_values()
+                .get(ModSourceId::PitchBend),
+            0.5
+        );
+
+        // Note off only matches the note's own channel
+        manager.note_off_channel(3, 60);
+        assert_ne!(
+            voice_for(&manager, 60).amp_env.state(),
+            EnvelopeState::Release
+        );
+        manager.note_off_channel(2, 60);
+        assert_eq!(
+            voice_for(&manager, 60).amp_env.state(),
+            EnvelopeState::Release
+        );
+    }
+
+    #[test]
+    fn test_voice_manager_mpe_zone_and_ranges() {
+        let mut manager: VoiceManager<4> = VoiceManager::new(48000.0);
+        manager.set_mpe(Some(MpeZone::upper(2).with_bend_range(12.0)));
+
+        manager.note_on_channel(15, 60, 100);
+        manager.note_on_channel(10, 64, 100); // outside the zone
+        manager.pitch_bend(15, -1.0);
+        manager.pitch_bend(10, 1.0);
+        assert_eq!(voice_for(&manager, 60).mpe_pitch_bend(), -12.0);
+        assert_eq!(voice_for(&manager, 64).mpe_pitch_bend(), 0.0);
+
+        // Without a zone, any channel bends every voice
+        manager.set_mpe(None);
+        manager.set_pitch_bend_range(7.0);
+        manager.pitch_bend(5, 1.0);
+        assert_eq!(voice_for(&manager, 60).mpe_pitch_bend(), 7.0);
+        assert_eq!(voice_for(&manager, 64).mpe_pitch_bend(), 7.0);
+    }
+
     #[test]
     fn test_voice_manager_stereo() {
         let mut manager: VoiceManager<4> = VoiceManager::new(48000.0);
@@ -0,0 +1,32 @@
+//! Effect test harness for Sonido.
+//!
+//! Measures how an effect sounds — frequency response per channel, THD, and
+//! latency — and compares the result against stored golden snapshots, so a
+//! DSP refactor cannot silently change existing effects or the presets built
+//! on them.
+//!
+//! - **Profile** ([`profile`]): drives an effect with tones and a noise burst
+//!   and reduces the output to an [`EffectProfile`]
+//! - **Snapshot** ([`snapshot`]): text format, tolerant comparison, and
+//!   `REGENERATE_GOLDEN=1` regeneration
+//!
+//! This crate's own `tests/snapshots.rs` covers every registered effect at
+//! default settings.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use sonido_registry::EffectRegistry;
+//! use sonido_testkit::{ProfileConfig, Tolerance, check_snapshot, profile_registered};
+//! use std::path::Path;
+//!
+//! let registry = EffectRegistry::new();
+//! let profile = profile_registered(&registry, "eq", &ProfileConfig::default()).unwrap();
+//! check_snapshot(Path::new("tests/snapshots"), &profile, &Tolerance::default()).unwrap();
+//! ```
+
+pub mod profile;
+pub mod snapshot;
+
+pub use profile::{EffectProfile, ProfileConfig, ResponsePoint, profile, profile_registered};
+pub use snapshot::{SnapshotError, Tolerance, check_snapshot, compare, snapshot_path};
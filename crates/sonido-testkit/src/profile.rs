@@ -0,0 +1,269 @@
+//! Effect measurement: frequency response, THD, and latency.
+//!
+//! Each measurement starts from a freshly reset effect and feeds the same
+//! signal to both channels. Gains are steady-state RMS ratios, so
+//! time-varying effects (chorus, tremolo) are averaged over the measurement
+//! window. Everything is deterministic: the same effect code yields the same
+//! profile bit for bit on a given platform.
+
+use sonido_analysis::distortion::ThdAnalyzer;
+use sonido_analysis::xcorr::{peak_lag, xcorr_fft};
+use sonido_core::Effect;
+use sonido_registry::EffectRegistry;
+
+/// Block size used to drive effects.
+const BLOCK_SIZE: usize = 256;
+
+/// Length of the noise burst used for latency measurement.
+const BURST_LEN: usize = 4096;
+
+/// Measurement settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileConfig {
+    /// Sample rate in Hz.
+    pub sample_rate: f32,
+    /// Test tone level in dBFS (peak).
+    pub level_dbfs: f32,
+    /// Frequencies for the response points, in Hz.
+    pub frequencies: Vec<f32>,
+    /// Samples processed before measuring, so filters, envelopes, and
+    /// smoothers settle.
+    pub settle_samples: usize,
+    /// Samples measured per tone. Also the THD FFT size, so a power of two.
+    pub measure_samples: usize,
+    /// THD test tone frequency in Hz.
+    pub thd_frequency: f32,
+    /// Largest latency the burst measurement searches, in samples.
+    pub max_latency: usize,
+}
+
+impl Default for ProfileConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 48000.0,
+            level_dbfs: -12.0,
+            frequencies: vec![
+                63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0,
+            ],
+            settle_samples: 12000,
+            measure_samples: 8192,
+            thd_frequency: 1000.0,
+            max_latency: 4096,
+        }
+    }
+}
+
+/// Gain at one frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponsePoint {
+    /// Tone frequency in Hz.
+    pub frequency: f32,
+    /// Left output level relative to the input, in dB.
+    pub left_db: f32,
+    /// Right output level relative to the input, in dB.
+    pub right_db: f32,
+}
+
+/// Measured behavior of one effect at one setting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectProfile {
+    /// Effect ID.
+    pub effect_id: String,
+    /// Sample rate the profile was measured at.
+    pub sample_rate: f32,
+    /// Latency the effect reports (`Effect::latency_samples`).
+    pub reported_latency: usize,
+    /// Lag of the output's peak correlation with a noise burst, in samples
+    /// (left channel). Wet-only delays show up here as well as true latency.
+    pub measured_latency: i32,
+    /// THD of the left output for the THD tone, in percent.
+    pub thd_percent: f32,
+    /// Gain per test frequency.
+    pub response: Vec<ResponsePoint>,
+}
+
+/// Profile a registered effect at its default settings.
+///
+/// Returns `None` if no effect has this ID.
+pub fn profile_registered(
+    registry: &EffectRegistry,
+    effect_id: &str,
+    config: &ProfileConfig,
+) -> Option<EffectProfile> {
+    let mut effect = registry.create(effect_id, config.sample_rate)?;
+    Some(profile(effect.as_mut(), effect_id, config))
+}
+
+/// Profile `effect` at its current settings.
+pub fn profile<E: Effect + ?Sized>(
+    effect: &mut E,
+    effect_id: &str,
+    config: &ProfileConfig,
+) -> EffectProfile {
+    let amplitude = 10.0_f32.powf(config.level_dbfs / 20.0);
+
+    let response = config
+        .frequencies
+        .iter()
+        .map(|&frequency| {
+            let (input_rms, left, right) = tone_response(effect, config, frequency, amplitude);
+            ResponsePoint {
+                frequency,
+                left_db: gain_db(rms(&left), input_rms),
+                right_db: gain_db(rms(&right), input_rms),
+            }
+        })
+        .collect();
+
+    let (_, left, _) = tone_response(effect, config, config.thd_frequency, amplitude);
+    let thd = ThdAnalyzer::new(config.sample_rate, config.measure_samples)
+        .analyze(&left, config.thd_frequency);
+
+    EffectProfile {
+        effect_id: effect_id.to_owned(),
+        sample_rate: config.sample_rate,
+        reported_latency: effect.latency_samples(),
+        measured_latency: burst_latency(effect, config, amplitude),
+        thd_percent: thd.thd_ratio * 100.0,
+        response,
+    }
+}
+
+/// Reset, play a tone, and return the input RMS and both output channels
+/// over the measurement window.
+fn tone_response<E: Effect + ?Sized>(
+    effect: &mut E,
+    config: &ProfileConfig,
+    frequency: f32,
+    amplitude: f32,
+) -> (f32, Vec<f32>, Vec<f32>) {
+    let step = std::f64::consts::TAU * f64::from(frequency) / f64::from(config.sample_rate);
+    let input: Vec<f32> = (0..config.settle_samples + config.measure_samples)
+        .map(|i| amplitude * (step * i as f64).sin() as f32)
+        .collect();
+    let (left, right) = run(effect, &input);
+    let start = config.settle_samples;
+    (
+        rms(&input[start..]),
+        left[start..].to_vec(),
+        right[start..].to_vec(),
+    )
+}
+
+/// Reset, play a noise burst followed by silence, and find the output lag.
+fn burst_latency<E: Effect + ?Sized>(
+    effect: &mut E,
+    config: &ProfileConfig,
+    amplitude: f32,
+) -> i32 {
+    // xorshift32: deterministic, no dependency
+    let mut state = 0x1234_5678_u32;
+    let mut input: Vec<f32> = (0..BURST_LEN)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            amplitude * (state as f32 / u32::MAX as f32 * 2.0 - 1.0)
+        })
+        .collect();
+    input.resize(BURST_LEN + config.max_latency, 0.0);
+
+    let (left, _) = run(effect, &input);
+    let correlation = xcorr_fft(&input, &left, config.max_latency);
+    peak_lag(&correlation, config.max_latency).0
+}
+
+/// Reset `effect` and process `input` on both channels in blocks.
+fn run<E: Effect + ?Sized>(effect: &mut E, input: &[f32]) -> (Vec<f32>, Vec<f32>) {
+    effect.reset();
+    let mut left = vec![0.0; input.len()];
+    let mut right = vec![0.0; input.len()];
+    for ((block, left), right) in input
+        .chunks(BLOCK_SIZE)
+        .zip(left.chunks_mut(BLOCK_SIZE))
+        .zip(right.chunks_mut(BLOCK_SIZE))
+    {
+        effect.process_block_stereo(block, block, left, right);
+    }
+    (left, right)
+}
+
+fn rms(signal: &[f32]) -> f32 {
+    let sum: f64 = signal.iter().map(|&s| f64::from(s) * f64::from(s)).sum();
+    (sum / signal.len().max(1) as f64).sqrt() as f32
+}
+
+/// Output/input level in dB, floored at -200 dB for silent output.
+fn gain_db(output_rms: f32, input_rms: f32) -> f32 {
+    20.0 * (output_rms / input_rms.max(1e-20)).max(1e-10).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pure delay with a gain, as a known reference.
+    struct DelayGain {
+        line: Vec<f32>,
+        pos: usize,
+        gain: f32,
+    }
+
+    impl DelayGain {
+        fn new(delay: usize, gain: f32) -> Self {
+            Self {
+                line: vec![0.0; delay],
+                pos: 0,
+                gain,
+            }
+        }
+    }
+
+    impl Effect for DelayGain {
+        fn process(&mut self, input: f32) -> f32 {
+            if self.line.is_empty() {
+                return input * self.gain;
+            }
+            let out = self.line[self.pos];
+            self.line[self.pos] = input;
+            self.pos = (self.pos + 1) % self.line.len();
+            out * self.gain
+        }
+
+        fn process_stereo(&mut self, left: f32, _right: f32) -> (f32, f32) {
+            let out = self.process(left);
+            (out, out * 0.5)
+        }
+
+        fn set_sample_rate(&mut self, _sample_rate: f32) {}
+
+        fn reset(&mut self) {
+            self.line.fill(0.0);
+            self.pos = 0;
+        }
+    }
+
+    #[test]
+    fn measures_known_delay_and_gain() {
+        let config = ProfileConfig::default();
+        let mut effect = DelayGain::new(37, 0.5);
+        let profile = profile(&mut effect, "delay_gain", &config);
+
+        assert_eq!(profile.measured_latency, 37);
+        assert_eq!(profile.reported_latency, 0);
+        assert!(profile.thd_percent < 0.01, "thd {}", profile.thd_percent);
+        // Low tones don't fit the window in whole periods; allow window ripple.
+        for point in &profile.response {
+            assert!((point.left_db + 6.02).abs() < 0.05, "{point:?}");
+            assert!((point.right_db + 12.04).abs() < 0.05, "{point:?}");
+        }
+    }
+
+    #[test]
+    fn unknown_effect_gives_none() {
+        let registry = EffectRegistry::new();
+        assert!(
+            profile_registered(&registry, "no-such-effect", &ProfileConfig::default()).is_none()
+        );
+    }
+}
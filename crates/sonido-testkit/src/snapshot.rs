@@ -0,0 +1,368 @@
+//! Golden snapshots of effect profiles.
+//!
+//! A snapshot is a small, diff-friendly text file holding one
+//! [`EffectProfile`]. [`check_snapshot`] compares a fresh profile against the
+//! stored one within a [`Tolerance`]; with `REGENERATE_GOLDEN` set it writes
+//! the profile instead, matching the regression tests in `sonido-effects`.
+//!
+//! ```text
+//! # sonido-testkit effect profile v1
+//! effect eq
+//! sample_rate 48000
+//! reported_latency 0
+//! measured_latency 0
+//! thd_percent 0.0001
+//! # response <frequency_hz> <left_db> <right_db>
+//! response 63 0.0000 0.0000
+//! response 125 0.0000 0.0000
+//! ```
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::profile::{EffectProfile, ResponsePoint};
+
+/// First line of every snapshot file.
+const HEADER: &str = "# sonido-testkit effect profile v1";
+
+/// Environment variable that switches [`check_snapshot`] to writing.
+pub const REGENERATE_ENV: &str = "REGENERATE_GOLDEN";
+
+/// Errors from reading, parsing, or checking a snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    /// No snapshot exists for this effect yet.
+    #[error("no snapshot at {0} (run with REGENERATE_GOLDEN=1 to create it)")]
+    Missing(PathBuf),
+    /// The snapshot file could not be read or written.
+    #[error("{path}: {source}")]
+    Io {
+        /// Snapshot file.
+        path: PathBuf,
+        /// Underlying error.
+        source: std::io::Error,
+    },
+    /// The snapshot file is malformed.
+    #[error("snapshot line {line}: {message}")]
+    Parse {
+        /// 1-based line number.
+        line: usize,
+        /// What was wrong.
+        message: String,
+    },
+    /// The profile no longer matches the snapshot.
+    #[error(
+        "{effect} changed (rerun with REGENERATE_GOLDEN=1 if intended):\n  {}",
+        differences.join("\n  ")
+    )]
+    Mismatch {
+        /// Effect ID.
+        effect: String,
+        /// One line per differing value.
+        differences: Vec<String>,
+    },
+}
+
+/// Allowed drift between a snapshot and a fresh profile.
+///
+/// Profiles are deterministic, so the defaults only absorb floating-point
+/// differences between compilers and platforms; any DSP change that alters
+/// the sound exceeds them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Largest gain difference per response point, in dB.
+    pub gain_db: f32,
+    /// Absolute THD difference always allowed, in percentage points.
+    pub thd_abs_percent: f32,
+    /// THD difference allowed relative to the snapshot value (0.02 = 2%).
+    pub thd_relative: f32,
+    /// Largest measured-latency difference, in samples.
+    pub latency_samples: u32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            gain_db: 0.05,
+            thd_abs_percent: 0.01,
+            thd_relative: 0.02,
+            latency_samples: 0,
+        }
+    }
+}
+
+impl EffectProfile {
+    /// Serialize to the snapshot text format.
+    pub fn to_snapshot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{HEADER}");
+        let _ = writeln!(out, "effect {}", self.effect_id);
+        let _ = writeln!(out, "sample_rate {}", self.sample_rate);
+        let _ = writeln!(out, "reported_latency {}", self.reported_latency);
+        let _ = writeln!(out, "measured_latency {}", self.measured_latency);
+        let _ = writeln!(out, "thd_percent {:.4}", self.thd_percent);
+        let _ = writeln!(out, "# response <frequency_hz> <left_db> <right_db>");
+        for point in &self.response {
+            let _ = writeln!(
+                out,
+                "response {} {:.4} {:.4}",
+                point.frequency, point.left_db, point.right_db
+            );
+        }
+        out
+    }
+
+    /// Parse the snapshot text format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Parse`] for unknown keys, bad numbers, or
+    /// missing fields.
+    pub fn from_snapshot(text: &str) -> Result<Self, SnapshotError> {
+        let mut effect_id = None;
+        let mut sample_rate = None;
+        let mut reported_latency = None;
+        let mut measured_latency = None;
+        let mut thd_percent = None;
+        let mut response = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let key = words.next().unwrap_or_default();
+            let values: Vec<&str> = words.collect();
+            let parse_err = |message: String| SnapshotError::Parse {
+                line: line_no,
+                message,
+            };
+            let single = || match values.as_slice() {
+                [value] => Ok(*value),
+                _ => Err(parse_err(format!("`{key}` takes one value"))),
+            };
+            let number = |text: &str| {
+                text.parse::<f32>()
+                    .map_err(|_| parse_err(format!("bad number `{text}`")))
+            };
+            match key {
+                "effect" => effect_id = Some(single()?.to_owned()),
+                "sample_rate" => sample_rate = Some(number(single()?)?),
+                "reported_latency" => {
+                    let value = single()?;
+                    reported_latency = Some(
+                        value
+                            .parse::<usize>()
+                            .map_err(|_| parse_err(format!("bad latency `{value}`")))?,
+                    );
+                }
+                "measured_latency" => {
+                    let value = single()?;
+                    measured_latency = Some(
+                        value
+                            .parse::<i32>()
+                            .map_err(|_| parse_err(format!("bad latency `{value}`")))?,
+                    );
+                }
+                "thd_percent" => thd_percent = Some(number(single()?)?),
+                "response" => match values.as_slice() {
+                    [frequency, left, right] => response.push(ResponsePoint {
+                        frequency: number(frequency)?,
+                        left_db: number(left)?,
+                        right_db: number(right)?,
+                    }),
+                    _ => return Err(parse_err("`response` takes three values".into())),
+                },
+                _ => return Err(parse_err(format!("unknown key `{key}`"))),
+            }
+        }
+
+        let missing = |field: &str| SnapshotError::Parse {
+            line: 0,
+            message: format!("missing `{field}`"),
+        };
+        Ok(Self {
+            effect_id: effect_id.ok_or_else(|| missing("effect"))?,
+            sample_rate: sample_rate.ok_or_else(|| missing("sample_rate"))?,
+            reported_latency: reported_latency.ok_or_else(|| missing("reported_latency"))?,
+            measured_latency: measured_latency.ok_or_else(|| missing("measured_latency"))?,
+            thd_percent: thd_percent.ok_or_else(|| missing("thd_percent"))?,
+            response,
+        })
+    }
+}
+
+/// Describe every difference between `golden` and `current` beyond
+/// `tolerance`. An empty list means they match.
+pub fn compare(
+    golden: &EffectProfile,
+    current: &EffectProfile,
+    tolerance: &Tolerance,
+) -> Vec<String> {
+    let mut differences = Vec::new();
+    if golden.sample_rate != current.sample_rate {
+        differences.push(format!(
+            "sample rate: {} Hz, was {} Hz",
+            current.sample_rate, golden.sample_rate
+        ));
+    }
+    if golden.reported_latency != current.reported_latency {
+        differences.push(format!(
+            "reported latency: {} samples, was {}",
+            current.reported_latency, golden.reported_latency
+        ));
+    }
+    if golden.measured_latency.abs_diff(current.measured_latency) > tolerance.latency_samples {
+        differences.push(format!(
+            "measured latency: {} samples, was {}",
+            current.measured_latency, golden.measured_latency
+        ));
+    }
+    let thd_limit = tolerance.thd_abs_percent + tolerance.thd_relative * golden.thd_percent.abs();
+    if !within(golden.thd_percent, current.thd_percent, thd_limit) {
+        differences.push(format!(
+            "THD: {:.4}%, was {:.4}%",
+            current.thd_percent, golden.thd_percent
+        ));
+    }
+
+    let golden_freqs: Vec<f32> = golden.response.iter().map(|p| p.frequency).collect();
+    let current_freqs: Vec<f32> = current.response.iter().map(|p| p.frequency).collect();
+    if golden_freqs != current_freqs {
+        differences.push(format!(
+            "response frequencies: {current_freqs:?}, was {golden_freqs:?}"
+        ));
+        return differences;
+    }
+    for (old, new) in golden.response.iter().zip(&current.response) {
+        for (channel, was, now) in [
+            ("left", old.left_db, new.left_db),
+            ("right", old.right_db, new.right_db),
+        ] {
+            if !within(was, now, tolerance.gain_db) {
+                differences.push(format!(
+                    "{} Hz {channel}: {now:+.3} dB, was {was:+.3} dB",
+                    new.frequency
+                ));
+            }
+        }
+    }
+    differences
+}
+
+/// Whether `a` and `b` differ by at most `limit`. NaN is never within.
+fn within(a: f32, b: f32, limit: f32) -> bool {
+    (a - b).abs() <= limit
+}
+
+/// Snapshot file for `effect_id` in `dir`.
+pub fn snapshot_path(dir: &Path, effect_id: &str) -> PathBuf {
+    dir.join(format!("{effect_id}.snap"))
+}
+
+/// Compare `profile` with its snapshot in `dir`, or write the snapshot when
+/// `REGENERATE_GOLDEN` is set.
+///
+/// # Errors
+///
+/// Returns [`SnapshotError::Missing`] if there is no snapshot yet,
+/// [`SnapshotError::Mismatch`] if the profile drifted beyond `tolerance`, or
+/// an I/O or parse error.
+pub fn check_snapshot(
+    dir: &Path,
+    profile: &EffectProfile,
+    tolerance: &Tolerance,
+) -> Result<(), SnapshotError> {
+    let path = snapshot_path(dir, &profile.effect_id);
+    let io_err = |source| SnapshotError::Io {
+        path: path.clone(),
+        source,
+    };
+
+    if std::env::var_os(REGENERATE_ENV).is_some() {
+        std::fs::create_dir_all(dir).map_err(io_err)?;
+        return std::fs::write(&path, profile.to_snapshot()).map_err(io_err);
+    }
+
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(SnapshotError::Missing(path));
+        }
+        Err(e) => return Err(io_err(e)),
+    };
+    let golden = EffectProfile::from_snapshot(&text)?;
+    let differences = compare(&golden, profile, tolerance);
+    if differences.is_empty() {
+        Ok(())
+    } else {
+        Err(SnapshotError::Mismatch {
+            effect: profile.effect_id.clone(),
+            differences,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> EffectProfile {
+        EffectProfile {
+            effect_id: "demo".into(),
+            sample_rate: 48000.0,
+            reported_latency: 64,
+            measured_latency: 64,
+            thd_percent: 1.25,
+            response: vec![
+                ResponsePoint {
+                    frequency: 100.0,
+                    left_db: -0.5,
+                    right_db: -0.25,
+                },
+                ResponsePoint {
+                    frequency: 1000.0,
+                    left_db: 0.0,
+                    right_db: 0.0,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn snapshot_text_round_trips() {
+        let profile = sample();
+        let parsed = EffectProfile::from_snapshot(&profile.to_snapshot()).unwrap();
+        assert_eq!(parsed, profile);
+        assert!(compare(&profile, &parsed, &Tolerance::default()).is_empty());
+    }
+
+    #[test]
+    fn compare_reports_each_change() {
+        let golden = sample();
+        let mut current = sample();
+        current.response[0].left_db = -1.5;
+        current.thd_percent = 2.0;
+        current.measured_latency = 65;
+
+        let differences = compare(&golden, &current, &Tolerance::default());
+        assert_eq!(differences.len(), 3, "{differences:?}");
+        assert!(differences.iter().any(|d| d.starts_with("100 Hz left")));
+
+        // Within tolerance
+        let mut current = sample();
+        current.response[1].right_db = 0.01;
+        current.thd_percent = 1.26;
+        assert!(compare(&golden, &current, &Tolerance::default()).is_empty());
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        let err = EffectProfile::from_snapshot("effect demo\nthd_percent lots\n").unwrap_err();
+        assert!(matches!(err, SnapshotError::Parse { line: 2, .. }), "{err}");
+        let err = EffectProfile::from_snapshot("effect demo\n").unwrap_err();
+        assert!(err.to_string().contains("sample_rate"), "{err}");
+    }
+}
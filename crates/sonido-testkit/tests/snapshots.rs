@@ -0,0 +1,71 @@
+//! Golden snapshot tests: frequency response, THD, and latency of every
+//! registered effect at default settings.
+//!
+//! Run with `REGENERATE_GOLDEN=1` to create or update snapshots after an
+//! intended change to an effect's sound:
+//! ```bash
+//! REGENERATE_GOLDEN=1 cargo test -p sonido-testkit --test snapshots
+//! ```
+
+use sonido_registry::EffectRegistry;
+use sonido_testkit::snapshot::REGENERATE_ENV;
+use sonido_testkit::{ProfileConfig, Tolerance, check_snapshot, profile_registered};
+use std::path::{Path, PathBuf};
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+}
+
+#[test]
+fn registered_effects_match_snapshots() {
+    let registry = EffectRegistry::new();
+    let config = ProfileConfig::default();
+    let tolerance = Tolerance::default();
+    let dir = snapshot_dir();
+    let ids: Vec<&str> = registry.all_effects().iter().map(|d| d.id).collect();
+
+    // Effects are independent; profile them in parallel.
+    let failures: Vec<String> = std::thread::scope(|scope| {
+        let handles: Vec<_> = ids
+            .iter()
+            .map(|&id| {
+                let (registry, config, dir) = (&registry, &config, &dir);
+                scope.spawn(move || {
+                    let profile = profile_registered(registry, id, config).expect("registered");
+                    check_snapshot(dir, &profile, &tolerance).err()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|h| h.join().expect("profile thread panicked"))
+            .map(|e| e.to_string())
+            .collect()
+    });
+
+    assert!(
+        failures.is_empty(),
+        "{} effect(s) differ from their snapshots:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn no_stale_snapshots() {
+    if std::env::var_os(REGENERATE_ENV).is_some() {
+        return;
+    }
+    let registry = EffectRegistry::new();
+    for entry in std::fs::read_dir(snapshot_dir()).expect("snapshot dir") {
+        let path = entry.expect("dir entry").path();
+        let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        assert!(
+            registry.get(id).is_some(),
+            "{} has no registered effect; delete it",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Graph DSL strings as given to `sonido process --graph` and friends.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sonido_graph_dsl::{
+    build_graph, build_graph_slug, count_nodes, parse_graph_dsl, validate_spec,
+};
+
+/// Building allocates every effect; skip huge graphs to keep runs fast.
+const MAX_BUILD_NODES: usize = 16;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let _ = build_graph_slug(text);
+    let Ok(spec) = parse_graph_dsl(text) else {
+        return;
+    };
+    if validate_spec(&spec).is_ok() && count_nodes(&spec) <= MAX_BUILD_NODES {
+        let _ = build_graph(&spec, 48000.0, 256);
+    }
+});
@@ -0,0 +1,16 @@
+//! Plugin state blobs handed to the CLAP adapter by a host.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sonido_config::PluginState;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(state) = PluginState::from_json(data) else {
+        return;
+    };
+    // Re-encoding is canonical: duplicate IDs collapse, so compare encodings.
+    let json = state.to_json().expect("decoded state encodes");
+    let again = PluginState::from_json(&json).expect("encoded state decodes");
+    assert_eq!(again.to_json().ok(), Some(json));
+});
@@ -0,0 +1,38 @@
+//! Preset files: TOML parsing, validation, and graph construction.
+//!
+//! `Preset::load` is a file read followed by `Preset::from_toml`; the target
+//! drives the parser directly and then feeds anything it accepts through the
+//! same steps the CLI and GUI take when loading a preset.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sonido_config::{Preset, validate_preset};
+
+/// Building allocates every effect; skip huge presets to keep runs fast.
+const MAX_BUILD_EFFECTS: usize = 16;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(preset) = Preset::from_toml(text) else {
+        return;
+    };
+
+    let _ = validate_preset(&preset);
+    let effect_count = preset.effects.len()
+        + preset
+            .aux
+            .iter()
+            .map(|aux| aux.effects.len())
+            .sum::<usize>();
+    if effect_count <= MAX_BUILD_EFFECTS {
+        let _ = sonido_graph_dsl::build_graph_from_preset(&preset, 48000.0, 256);
+    }
+
+    // Anything that parses must serialize and parse back unchanged.
+    let toml = preset.to_toml().expect("accepted preset serializes");
+    let reparsed = Preset::from_toml(&toml).expect("serialized preset parses");
+    assert_eq!(reparsed, preset);
+});